@@ -3,17 +3,14 @@
 
 // example to show how to print strings to the Amiga CLI
 
-// this example doesn't have proper start up code, so it won't work well when launched
-// from Workbench (doesn't respond to WBStartup message and therefore may leak memory)
+// the start up code of the amiga-sys "startup" feature calls main(), also when the program is
+// launched from Workbench, and the "panic-handler" feature shows the message of a panic
 
 use core::ffi::c_void;
-use amiga_sys;
+use amiga_sys::{self, StartupInfo};
 
 #[unsafe(no_mangle)]
-// place _start to .init section so that it is the first code block in the executable
-// (the downside is that there's one extra code hunk in the Amiga executable)
-#[unsafe(link_section = ".init")]
-extern "C" fn _start() {
+fn main(_startup: StartupInfo) -> i32 {
     unsafe {
         let execlib = amiga_sys::abs_exec_library();
 
@@ -21,14 +18,14 @@ extern "C" fn _start() {
         let doslib = amiga_sys::OpenLibrary(execlib, b"dos.library\0".as_ptr(), 0);
         if doslib == core::ptr::null_mut() {
             // null means library not found
-            return;
+            return amiga_sys::RETURN_FAIL as i32;
         }
         // get cli output file handle
         let out_handle = amiga_sys::Output(doslib);
         if out_handle == 0 {
             // 0 means no output stream (e.g. launched from workbench)
             amiga_sys::CloseLibrary(execlib, doslib);
-            return;
+            return amiga_sys::RETURN_FAIL as i32;
         }
 
         // Amiga uses the ECMA-94 text encoding, which is the same as ISO-8859-1 and Latin1.
@@ -40,17 +37,5 @@ extern "C" fn _start() {
         // we must close libraries opened with OpenLibrary()
         amiga_sys::CloseLibrary(execlib, doslib);
     }
-    return;
-}
-
-// panic handler
-
-#[panic_handler]
-fn panic(_info: &core::panic::PanicInfo) -> ! {
-    loop {}
-}
-
-#[unsafe(no_mangle)]
-pub extern "C" fn abort() -> ! {
-    loop {}
+    amiga_sys::RETURN_OK as i32
 }
@@ -1,16 +1,21 @@
 #![feature(asm_experimental_arch)]
+#![feature(allocator_api)]
 #![no_std]
 #![no_main]
 
 // tester to test various aspects of the amiga-sys bindings, such as calling library functions,
 // function pointers, etc.
 
-// this example doesn't have proper start up code, so it won't work well when launched
-// from Workbench (doesn't respond to WBStartup message and therefore may leak memory)
+// uses the start up code of the amiga-sys "startup" feature, which calls main()
 
-use core::ffi::c_void;
+extern crate alloc;
+
+use core::ffi::{CStr, c_void};
 use amiga_sys::*;
 
+#[global_allocator]
+static ALLOCATOR: AmigaAlloc = AmigaAlloc::new(MEMF_ANY);
+
 // static data loaded to chip ram
 #[unsafe(link_section = ".MEMF_CHIP")]
 static CHIP_RAM: [u8; 13] = *include_bytes!("../chipdata.txt");
@@ -27,26 +32,41 @@ struct RectWrapper {
 }
 
 #[unsafe(no_mangle)]
-// place _start to .init section so that it is the first code block in the executable
-// (the downside is that there's one extra code hunk in the Amiga executable)
-#[unsafe(link_section = ".init")]
-extern "C" fn _start() {
+fn main(startup: StartupInfo) -> i32 {
     unsafe {
         let execlib = amiga_sys::abs_exec_library();
 
         let dos = OpenLibrary(execlib, b"dos.library\0".as_ptr(), 0);
         if dos == core::ptr::null_mut() {
-            return;
+            return RETURN_FAIL as i32;
         }
         let out = Output(dos);
         if out == 0 {
+            // launched from Workbench: no output
             CloseLibrary(execlib, dos);
-            return;
+            return RETURN_FAIL as i32;
         }
 
         Write(dos, out, b"Tester!\nnot-printed\0".as_ptr() as *const c_void, 8);
 
         let mem = AvailMem(execlib, 0);
+        if startup.is_workbench() || startup.process().is_null() {
+            print(dos, out, b"startup: not cli?\n");
+        } else {
+            print(dos, out, b"startup: cli: ok\n");
+        }
+        print(dos, out, b"command name: ");
+        print(dos, out, startup.command_name().unwrap_or(b"(none)"));
+        print(dos, out, b"\narguments:");
+        for arg in startup.args() {
+            print(dos, out, b" [");
+            for c in arg.bytes() {
+                print(dos, out, &[c]);
+            }
+            print(dos, out, b"]");
+        }
+        print(dos, out, b"\n");
+
         print(dos, out, b"Avail mem: ");
         print_u32_hex(dos, out, mem, true);
 
@@ -88,6 +108,33 @@ extern "C" fn _start() {
 
         test_mathieeesingbas(dos, out);
         test_mathieeedoubbas(dos, out);
+        test_amiga_lib_macro(dos, out);
+        test_closure_hook(dos, out);
+        test_tags(dos, out);
+
+        test_cli_args(dos, out);
+
+        test_read_args(dos, out);
+
+        test_dos_error(dos, out);
+
+        test_file(dos, out);
+
+        test_read_dir(dos, out);
+
+        test_matches(dos, out);
+
+        test_alloc(dos, out);
+
+        test_chip_memory(dos, out);
+
+        test_pool(dos, out);
+
+        test_exec_lists(dos, out);
+
+        test_executor(dos, out);
+
+        test_timer(dos, out);
 
         test_rawdofmt(dos);
 
@@ -150,71 +197,619 @@ extern "C" fn _start() {
 
         CloseLibrary(execlib, dos);
     }
+    RETURN_OK as i32
 }
 
 // tests
 
 fn test_mathieeesingbas(dos: *mut Library, out: BPTR) {
+    // the library handle closes the library when it goes out of scope
+    let Some(mathlib) = MathIeeeSingBasLib::open(0) else {
+        print(dos, out, b"MISSING: no mathieeesingbas.library\n");
+        return;
+    };
     unsafe {
-        let execlib = amiga_sys::abs_exec_library();
-        let mathlib = OpenLibrary(execlib, b"mathieeesingbas.library\0".as_ptr(), 0);
-        if mathlib == core::ptr::null_mut() {
-            print(dos, out, b"MISSING: no mathieeesingbas.library\n");
-            return;
-        }
-        let fl = IEEESPFlt(mathlib, 135);
-        let fl2 = IEEESPFlt(mathlib, 2);
-        let flr = IEEESPDiv(mathlib, fl, fl2);
-        let r = IEEESPFix(mathlib, flr);
+        let fl = mathlib.IEEESPFlt(135);
+        let fl2 = mathlib.IEEESPFlt(2);
+        let flr = mathlib.IEEESPDiv(fl, fl2);
+        let r = mathlib.IEEESPFix(flr);
         compare(dos, out, r as i64, 67, b"mathieeesingbas");
-        CloseLibrary(execlib, mathlib);
     }
 }
 
 fn test_mathieeedoubbas(dos: *mut Library, out: BPTR) {
+    let Some(mathlib) = MathIeeeDoubBasLib::open(0) else {
+        print(dos, out, b"MISSING: no mathieeedoubbas.library\n");
+        return;
+    };
     unsafe {
-        let execlib = amiga_sys::abs_exec_library();
-        let mathlib = OpenLibrary(execlib, b"mathieeedoubbas.library\0".as_ptr(), 0);
-        if mathlib == core::ptr::null_mut() {
-            print(dos, out, b"MISSING: no mathieeedoubbas.library\n");
+        let fl = mathlib.IEEEDPFlt(139);
+        let fl2 = mathlib.IEEEDPFlt(2);
+        let flr = mathlib.IEEEDPDiv(fl, fl2);
+        let r = mathlib.IEEEDPFix(flr);
+        compare(dos, out, r as i64, 69, b"mathieeedoubbas");
+    }
+}
+
+amiga_lib! {
+    /// Same as `IEEEDPFix()`
+    fn MacroDPFix(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> LONG = -30 (d0-d1);
+    /// Same as `IEEEDPFlt()`
+    fn MacroDPFlt(MathIeeeDoubBasBase: *mut Library, integer: LONG) -> DOUBLE = -36 (d0);
+    /// Same as `IEEEDPDiv()`
+    fn MacroDPDiv(MathIeeeDoubBasBase: *mut Library, dividend: DOUBLE, divisor: DOUBLE) -> DOUBLE = -84 (d0-d1, d2-d3);
+}
+
+/// Tests functions declared with the `amiga_lib!` macro
+fn test_amiga_lib_macro(dos: *mut Library, out: BPTR) {
+    let Some(mathlib) = MathIeeeDoubBasLib::open(0) else {
+        print(dos, out, b"MISSING: no mathieeedoubbas.library\n");
+        return;
+    };
+    unsafe {
+        let base = mathlib.as_ptr();
+        let fl = MacroDPFlt(base, 141);
+        let fl2 = MacroDPFlt(base, 2);
+        let flr = MacroDPDiv(base, fl, fl2);
+        let r = MacroDPFix(base, flr);
+        compare(dos, out, r as i64, 70, b"amiga_lib! macro");
+    }
+}
+
+/// Tests calling a closure with CallHookPkt()
+fn test_closure_hook(dos: *mut Library, out: BPTR) {
+    let Some(utility) = UtilityLib::open(36) else {
+        print(dos, out, b"MISSING: no utility.library V36\n");
+        return;
+    };
+    let offset = 100;
+    let mut hook = core::pin::pin!(ClosureHook::new(|_hook, object, message| object as ULONG + message as ULONG + offset));
+    let r = unsafe { utility.CallHookPkt(hook.as_mut().as_mut_ptr(), 20 as APTR, 3 as APTR) };
+    compare(dos, out, r as i64, 123, b"closure hook");
+}
+
+/// Tests a tag list built with the `tags!` macro
+fn test_file(dos: *mut Library, out: BPTR) {
+    use core::fmt::Write as _;
+
+    let Some(doslib) = DosLib::open(36) else {
+        print(dos, out, b"MISSING: no dos.library V36 for File\n");
+        return;
+    };
+    if let Some(mut stdout) = stdout(&doslib) {
+        let result = "ok";
+        let _ = writeln!(stdout, "file: stdout: {result}");
+    }
+    if let Some(mut stderr) = stderr(&doslib) {
+        let _ = writeln!(stderr, "file: stderr: ok");
+    }
+
+    let name = c"ram:amiga-sys-test";
+    match File::open(&doslib, name, OpenMode::NewFile) {
+        Ok(mut file) => {
+            let written = write!(file, "{}-{}", 12, 345);
+            compare(dos, out, written.is_ok() as i64, 1, b"file: write");
+            compare(dos, out, file.seek(SeekFrom::Start(3)).unwrap_or(0) as i64, 3, b"file: seek");
+            let mut buffer = [0u8; 16];
+            let count = file.read(&mut buffer).unwrap_or(0);
+            compare(dos, out, (&buffer[..count] == b"345") as i64, 1, b"file: read");
+
+            // embedded-io traits
+            embedded_io::Seek::seek(&mut file, embedded_io::SeekFrom::End(-2)).ok();
+            let count = embedded_io::Read::read(&mut file, &mut buffer).unwrap_or(0);
+            compare(dos, out, (&buffer[..count] == b"45") as i64, 1, b"file: embedded-io");
+        }
+        Err(_) => print(dos, out, b"file: open: FAIL\n"),
+    }
+    unsafe { doslib.DeleteFile(name.as_ptr() as _) };
+
+    let missing = File::open(&doslib, c"ram:no-such-file", OpenMode::OldFile);
+    compare(dos, out, (missing.err() == Some(DosError::ObjectNotFound)) as i64, 1, b"file: not found");
+}
+
+fn test_read_dir(dos: *mut Library, out: BPTR) {
+    let Some(doslib) = DosLib::open(0) else {
+        print(dos, out, b"MISSING: no dos.library for ReadDir\n");
+        return;
+    };
+    let dir = c"ram:amiga-sys-dir";
+    let file = c"ram:amiga-sys-dir/file";
+    unsafe {
+        let lock = doslib.CreateDir(dir.as_ptr() as _);
+        if lock == 0 {
+            print(dos, out, b"read dir: CreateDir: FAIL\n");
             return;
         }
-        let fl = IEEEDPFlt(mathlib, 139);
-        let fl2 = IEEEDPFlt(mathlib, 2);
-        let flr = IEEEDPDiv(mathlib, fl, fl2);
-        let r = IEEEDPFix(mathlib, flr);
-        compare(dos, out, r as i64, 69, b"mathieeedoubbas");
-        CloseLibrary(execlib, mathlib);
-    }
-}
-
-/// Callback is a naked function, because it doesn't follow Rust or C calling convention,
-/// it follows Amiga calling convention where parameters are passed in registers
-// TODO: change this function to `extern "custom"` when a feature flag isn't needed anymore
-// https://github.com/rust-lang/rust/issues/140829
-#[unsafe(naked)]
-pub extern "C" fn rawdofmt_callback_asm() {
-    core::arch::naked_asm!(
-        // push arguments to stack, that is the calling convention
-        // for extern "C" rawdofmt_callback_rust()
-        "move.l %a3, -(%sp)", // dos library (user data)
-        "move.l %d0, -(%sp)", // char
-        "jsr {cbrust}",
-        // pop arguments
-        "lea 8(%sp), %sp",
-        "rts",
-        cbrust = sym crate::rawdofmt_callback_rust,
-    );
-}
-
-pub extern "C" fn rawdofmt_callback_rust(ch: u32, dos: *mut Library) {
-    let out = unsafe { Output(dos) };
-    if out == 0 {
+        doslib.UnLock(lock);
+        let handle = doslib.Open(file.as_ptr() as _, MODE_NEWFILE as LONG);
+        if handle != 0 {
+            doslib.Write(handle, b"12345".as_ptr() as _, 5);
+            doslib.Close(handle);
+        }
+        doslib.SetComment(file.as_ptr() as _, c"comment".as_ptr() as _);
+    }
+
+    match ReadDir::open(&doslib, dir) {
+        Ok(entries) => {
+            let mut count = 0;
+            for entry in entries {
+                match entry {
+                    Ok(entry) => {
+                        count += 1;
+                        compare(dos, out, (entry.name() == b"file") as i64, 1, b"read dir: name");
+                        compare(dos, out, entry.size() as i64, 5, b"read dir: size");
+                        compare(dos, out, entry.is_file() as i64, 1, b"read dir: type");
+                        compare(dos, out, (entry.comment() == b"comment") as i64, 1, b"read dir: comment");
+                    }
+                    Err(_) => print(dos, out, b"read dir: entry: FAIL\n"),
+                }
+            }
+            compare(dos, out, count, 1, b"read dir: count");
+        }
+        Err(_) => print(dos, out, b"read dir: open: FAIL\n"),
+    }
+    let not_dir = ReadDir::open(&doslib, file);
+    compare(dos, out, (not_dir.err() == Some(DosError::ObjectWrongType)) as i64, 1, b"read dir: file");
+
+    unsafe {
+        doslib.DeleteFile(file.as_ptr() as _);
+        doslib.DeleteFile(dir.as_ptr() as _);
+    }
+}
+
+fn test_matches(dos: *mut Library, out: BPTR) {
+    let Some(doslib) = DosLib::open(36) else {
+        print(dos, out, b"MISSING: no dos.library V36 for Matches\n");
+        return;
+    };
+    let dirs = [c"ram:amiga-sys-match", c"ram:amiga-sys-match/sub"];
+    let files = [c"ram:amiga-sys-match/a.txt", c"ram:amiga-sys-match/b.info", c"ram:amiga-sys-match/sub/c.txt"];
+    unsafe {
+        for dir in dirs {
+            let lock = doslib.CreateDir(dir.as_ptr() as _);
+            if lock != 0 {
+                doslib.UnLock(lock);
+            }
+        }
+        for file in files {
+            let handle = doslib.Open(file.as_ptr() as _, MODE_NEWFILE as LONG);
+            if handle != 0 {
+                doslib.Close(handle);
+            }
+        }
+    }
+
+    let txt: Option<alloc::vec::Vec<_>> = Matches::new(&doslib, c"ram:amiga-sys-match/#?.txt").map(|m| m.ok()).collect();
+    let txt = txt.unwrap_or_default();
+    compare(dos, out, txt.len() as i64, 1, b"matches: pattern");
+    compare(dos, out, txt.first().is_some_and(|m| m.path() == b"ram:amiga-sys-match/a.txt") as i64, 1, b"matches: path");
+
+    let all: Option<alloc::vec::Vec<_>> = Matches::new(&doslib, c"ram:amiga-sys-match/#?").recursive(true).map(|m| m.ok()).collect();
+    let all = all.unwrap_or_default();
+    compare(dos, out, all.len() as i64, 4, b"matches: recursive");
+    let nested = all.iter().any(|m| m.path() == b"ram:amiga-sys-match/sub/c.txt" && m.entry().is_file());
+    compare(dos, out, nested as i64, 1, b"matches: recursive path");
+
+    // sub doesn't match the pattern, but it's entered
+    let txt: Option<alloc::vec::Vec<_>> = Matches::new(&doslib, c"ram:amiga-sys-match/#?.txt").recursive(true).map(|m| m.ok()).collect();
+    let txt = txt.unwrap_or_default();
+    compare(dos, out, txt.len() as i64, 2, b"matches: recursive pattern");
+    let nested = txt.iter().any(|m| m.path() == b"ram:amiga-sys-match/sub/c.txt");
+    compare(dos, out, nested as i64, 1, b"matches: recursive pattern path");
+
+    let mut missing = Matches::new(&doslib, c"ram:amiga-sys-match/~(#?)");
+    compare(dos, out, missing.next().is_none() as i64, 1, b"matches: no match");
+
+    unsafe {
+        for file in files {
+            doslib.DeleteFile(file.as_ptr() as _);
+        }
+        for dir in dirs.iter().rev() {
+            doslib.DeleteFile(dir.as_ptr() as _);
+        }
+    }
+}
+
+fn test_cli_args(dos: *mut Library, out: BPTR) {
+    let mut args = CliArgs::new(b"  one\t\"two three\" \"a*\"b*Nc**\"x\"y\"z* \n ignored");
+    let one = args.next();
+    compare(dos, out, one.is_some_and(|a| a.raw() == b"one" && !a.is_quoted()) as i64, 1, b"cli args: plain");
+    let two = args.next();
+    compare(dos, out, two.is_some_and(|a| a.raw() == b"two three" && a.is_quoted()) as i64, 1, b"cli args: quoted");
+    let escaped = args.next();
+    compare(dos, out, escaped.is_some_and(|a| a.bytes().eq(*b"a\"b\nc*")) as i64, 1, b"cli args: escapes");
+    let after_quote = args.next();
+    compare(dos, out, after_quote.is_some_and(|a| a.raw() == b"x") as i64, 1, b"cli args: after quote");
+    let unquoted = args.next();
+    compare(dos, out, unquoted.is_some_and(|a| a.bytes().eq(*b"y\"z*")) as i64, 1, b"cli args: no escapes");
+    compare(dos, out, args.next().is_none() as i64, 1, b"cli args: newline");
+}
+
+read_args! {
+    /// Arguments for test_read_args()
+    struct TestArgs<'a> {
+        from: MultiArg<'a> = "FROM/M/A",
+        to: Option<&'a [u8]> = "TO/K",
+        all: bool = "ALL/S",
+        depth: Option<i32> = "DEPTH/K/N",
+        name: Option<&'a [u8]> = "NAME/K",
+    }
+}
+
+read_args! {
+    /// Arguments for test_read_args() with a /M/N item
+    struct TestNumberArgs<'a> {
+        sizes: MultiNumberArg<'a> = "SIZES/M/N",
+        name: Option<&'a [u8]> = "NAME/K",
+    }
+}
+
+fn test_read_args(dos: *mut Library, out: BPTR) {
+    let Some(doslib) = DosLib::open(36) else {
+        print(dos, out, b"MISSING: no dos.library V36\n");
+        return;
+    };
+    match TestArgs::parse(&doslib, b"a \"b c\" TO ram: DEPTH=3 ALL\n") {
+        Ok(parsed) => {
+            let args = parsed.get();
+            compare(dos, out, args.from.len() as i64, 2, b"read args: multi count");
+            compare(dos, out, (args.from.get(1) == Some(b"b c")) as i64, 1, b"read args: multi quoted");
+            compare(dos, out, (args.to == Some(b"ram:")) as i64, 1, b"read args: keyword");
+            compare(dos, out, args.all as i64, 1, b"read args: switch");
+            compare(dos, out, args.depth.unwrap_or(-1) as i64, 3, b"read args: number");
+            compare(dos, out, args.name.is_none() as i64, 1, b"read args: missing");
+        }
+        Err(_) => print(dos, out, b"read args: parse: FAIL\n"),
+    }
+    let missing = TestArgs::parse(&doslib, b"ALL\n");
+    compare(dos, out, (missing.err() == Some(DosError::RequiredArgMissing)) as i64, 1, b"read args: required");
+    match TestNumberArgs::parse(&doslib, b"4 -8 15 NAME x\n") {
+        Ok(parsed) => {
+            let args = parsed.get();
+            compare(dos, out, args.sizes.len() as i64, 3, b"read args: multi number count");
+            compare(dos, out, args.sizes.sum::<i32>() as i64, 11, b"read args: multi number values");
+            compare(dos, out, args.sizes.get(1).unwrap_or(0) as i64, -8, b"read args: multi number get");
+        }
+        Err(_) => print(dos, out, b"read args: parse multi number: FAIL\n"),
+    }
+    let empty = TestNumberArgs::parse(&doslib, b"NAME x\n");
+    compare(dos, out, empty.is_ok_and(|parsed| parsed.get().sizes.is_empty()) as i64, 1, b"read args: multi number empty");
+}
+
+fn test_dos_error(dos: *mut Library, out: BPTR) {
+    compare(dos, out, DosError::from_code(205).code() as i64, 205, b"dos error: code");
+    compare(dos, out, (DosError::from_code(205) == DosError::ObjectNotFound) as i64, 1, b"dos error: variant");
+    compare(dos, out, (DosError::from(12345) == DosError::Unknown(12345)) as i64, 1, b"dos error: unknown");
+
+    let Some(doslib) = DosLib::open(36) else {
+        print(dos, out, b"MISSING: no dos.library V36 for Fault()\n");
+        return;
+    };
+    let lock = unsafe { DosError::check(&doslib, doslib.Lock(c"ram:no-such-file".as_ptr() as _, ACCESS_READ)) };
+    compare(dos, out, (lock == Err(DosError::ObjectNotFound)) as i64, 1, b"dos error: check");
+    let message = alloc::format!("{}\n", DosError::ObjectNotFound);
+    print(dos, out, b"dos error: ");
+    print(dos, out, message.as_bytes());
+}
+
+#[repr(C, align(64))]
+struct Aligned64 {
+    value: u32,
+}
+
+fn test_alloc(dos: *mut Library, out: BPTR) {
+    use alloc::{boxed::Box, string::String, vec::Vec};
+
+    let mut numbers = Vec::new();
+    for i in 0..1000u32 {
+        numbers.push(i);
+    }
+    compare(dos, out, numbers.iter().sum::<u32>() as i64, 499500, b"alloc: vec");
+
+    let mut s = String::from("Hello");
+    s.push_str(" Amiga");
+    compare(dos, out, (s == "Hello Amiga") as i64, 1, b"alloc: string");
+
+    let aligned = Box::new(Aligned64 { value: 7 });
+    let address = &*aligned as *const Aligned64 as usize;
+    compare(dos, out, (address % 64) as i64, 0, b"alloc: alignment");
+    compare(dos, out, aligned.value as i64, 7, b"alloc: aligned value");
+
+    let zeroed: Vec<u8> = alloc::vec![0; 100];
+    compare(dos, out, zeroed.iter().all(|&b| b == 0) as i64, 1, b"alloc: zeroed");
+}
+
+fn test_chip_memory(dos: *mut Library, out: BPTR) {
+    let execlib = unsafe { amiga_sys::abs_exec_library() };
+    let Some(mut plane) = (unsafe { ChipBox::<[u8; 40 * 16]>::new_zeroed() }) else {
+        print(dos, out, b"chip box: alloc: FAIL\n");
+        return;
+    };
+    plane[1] = 0xff;
+    let mem_type = unsafe { TypeOfMem(execlib, plane.as_mut_ptr() as *const c_void) };
+    compare(dos, out, (mem_type & MEMF_CHIP != 0) as i64, 1, b"chip box: chip memory");
+    compare(dos, out, plane.iter().map(|&b| b as i64).sum(), 0xff, b"chip box: cleared");
+
+    let Some(mut sample) = ChipVec::from_slice(&[1i8, 2, 3]) else {
+        print(dos, out, b"chip vec: alloc: FAIL\n");
+        return;
+    };
+    for i in 4..=100 {
+        let _ = sample.push(i);
+    }
+    let mem_type = unsafe { TypeOfMem(execlib, sample.as_ptr() as *const c_void) };
+    compare(dos, out, (mem_type & MEMF_CHIP != 0) as i64, 1, b"chip vec: chip memory");
+    compare(dos, out, sample.iter().map(|&b| b as i64).sum(), 5050, b"chip vec: values");
+    compare(dos, out, sample.pop().unwrap_or(0) as i64, 100, b"chip vec: pop");
+}
+
+fn test_pool(dos: *mut Library, out: BPTR) {
+    let execlib = unsafe { amiga_sys::abs_exec_library() };
+    if unsafe { (*execlib).lib_Version } < 39 {
+        print(dos, out, b"MISSING: no exec.library V39 for pools\n");
+        return;
+    }
+    let Some(pool) = Pool::new(MEMF_ANY, 1024, 256) else {
+        print(dos, out, b"pool: create: FAIL\n");
+        return;
+    };
+    let mut numbers = alloc::vec::Vec::new_in(&pool);
+    for i in 0..1000u32 {
+        numbers.push(i);
+    }
+    compare(dos, out, numbers.iter().sum::<u32>() as i64, 499500, b"pool: vec");
+    let aligned = pool.alloc(Aligned64 { value: 9 });
+    let address = aligned.map(|a| a as *mut Aligned64 as usize).unwrap_or(1);
+    compare(dos, out, (address % 64) as i64, 0, b"pool: alignment");
+}
+
+#[repr(C)]
+struct ListItem {
+    value: u32,
+    node: MinNode,
+}
+
+fn empty_node() -> Node {
+    Node {
+        ln_Succ: core::ptr::null_mut(),
+        ln_Pred: core::ptr::null_mut(),
+        ln_Type: NT_UNKNOWN as UBYTE,
+        ln_Pri: 0,
+        ln_Name: core::ptr::null_mut(),
+    }
+}
+
+fn test_exec_lists(dos: *mut Library, out: BPTR) {
+    let execlib = unsafe { amiga_sys::abs_exec_library() };
+    let mut found = false;
+    unsafe {
+        Forbid(execlib);
+        let exec_base = execlib as *mut ExecBase;
+        for library in (*exec_base).LibList.iter::<Library>() {
+            if CStr::from_ptr(library.lib_Node.ln_Name as _) == c"exec.library" {
+                found = core::ptr::eq(library, execlib);
+            }
+        }
+        Permit(execlib);
+    }
+    compare(dos, out, found as i64, 1, b"list: LibList");
+
+    let mut list = MinList {
+        mlh_Head: core::ptr::null_mut(),
+        mlh_Tail: core::ptr::null_mut(),
+        mlh_TailPred: core::ptr::null_mut(),
+    };
+    list.init();
+    compare(dos, out, list.is_empty() as i64, 1, b"list: empty");
+    let empty = MinNode { mln_Succ: core::ptr::null_mut(), mln_Pred: core::ptr::null_mut() };
+    let mut items = [
+        ListItem { value: 1, node: empty },
+        ListItem { value: 2, node: empty },
+        ListItem { value: 3, node: empty },
+    ];
+    unsafe {
+        list.add_tail(&mut items[1].node);
+        list.add_tail(&mut items[2].node);
+        list.add_head(&mut items[0].node);
+        let mut order = 0;
+        for node in list.iter::<MinNode>() {
+            order = order * 10 + (*container_of!(node, ListItem, node)).value;
+        }
+        compare(dos, out, order as i64, 123, b"list: container_of");
+        items[1].node.remove();
+        let tail = list.rem_tail::<MinNode>().map(|node| (*container_of!(node.as_ptr(), ListItem, node)).value);
+        compare(dos, out, tail.unwrap_or(0) as i64, 3, b"list: remove");
+        list.rem_head::<MinNode>();
+    }
+    compare(dos, out, list.is_empty() as i64, 1, b"list: empty after remove");
+
+    let mut list = List {
+        lh_Head: core::ptr::null_mut(),
+        lh_Tail: core::ptr::null_mut(),
+        lh_TailPred: core::ptr::null_mut(),
+        lh_Type: NT_UNKNOWN as UBYTE,
+        l_pad: 0,
+    };
+    let mut nodes = [empty_node(), empty_node(), empty_node(), empty_node()];
+    unsafe {
+        NewList(&mut list);
+        for (node, priority) in nodes.iter_mut().zip([0, 5, -5, 5]) {
+            node.ln_Pri = priority;
+            list.enqueue(node);
+        }
+        let mut order = 0;
+        for node in list.iter::<Node>() {
+            let index = nodes.iter().position(|n| core::ptr::eq(n, node)).unwrap_or(9);
+            order = order * 10 + index;
+        }
+        compare(dos, out, order as i64, 1302, b"list: enqueue");
+    }
+
+    let mut typed_nodes = [empty_node(), empty_node(), empty_node()];
+    let mut typed = list.typed::<Node>();
+    for (node, priority) in typed_nodes.iter_mut().zip([1, 3, 2]) {
+        node.ln_Pri = priority;
+        typed.enqueue(node);
+    }
+    let second = typed.iter().nth(1).map(|node| node as *const Node);
+    let removed = second.and_then(|node| typed.remove(node));
+    compare(dos, out, removed.map_or(0, |node| node.ln_Pri) as i64, 2, b"list: typed remove");
+    let mut order = 0;
+    while let Some(node) = typed.rem_head() {
+        order = order * 10 + node.ln_Pri as i64;
+    }
+    compare(dos, out, order, 31, b"list: typed rem_head");
+}
+
+fn test_executor(dos: *mut Library, out: BPTR) {
+    use core::task::Poll;
+
+    let execlib = unsafe { amiga_sys::abs_exec_library() };
+    unsafe { SetSignal(execlib, SIGBREAKF_CTRL_E, SIGBREAKF_CTRL_E) };
+    let received = block_on(signals(SIGBREAKF_CTRL_E | SIGBREAKF_CTRL_F));
+    compare(dos, out, received as i64, SIGBREAKF_CTRL_E as i64, b"executor: signals");
+    let pending = unsafe { SetSignal(execlib, 0, 0) } & SIGBREAKF_CTRL_E;
+    compare(dos, out, pending as i64, 0, b"executor: signal cleared");
+
+    unsafe { SetSignal(execlib, SIGBREAKF_CTRL_F, SIGBREAKF_CTRL_F) };
+    let result = block_on(select(signals(SIGBREAKF_CTRL_E), signals(SIGBREAKF_CTRL_F)));
+    compare(dos, out, (result == Either::Right(SIGBREAKF_CTRL_F)) as i64, 1, b"executor: select");
+
+    // a signal received by a future which isn't a Signals future, like the signal of a
+    // message port, doesn't complete a Signals future of the next block_on()
+    let bit = unsafe { AllocSignal(execlib, -1) };
+    if bit < 0 {
+        print(dos, out, b"executor: AllocSignal: FAIL\n");
+        return;
+    }
+    let mask = 1 << bit;
+    unsafe { SetSignal(execlib, mask, mask) };
+    let mut polls = 0;
+    block_on(core::future::poll_fn(|cx| {
+        polls += 1;
+        if polls == 1 {
+            wake_on_signals(cx, mask);
+            return Poll::Pending;
+        }
+        Poll::Ready(())
+    }));
+    compare(dos, out, polls, 2, b"executor: port signal");
+    let mut yielded = false;
+    let yield_once = core::future::poll_fn(|cx| {
+        if yielded {
+            return Poll::Ready(());
+        }
+        yielded = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    });
+    let result = block_on(select(signals(mask), yield_once));
+    compare(dos, out, (result == Either::Right(())) as i64, 1, b"executor: no stale signal");
+    unsafe {
+        SetSignal(execlib, 0, mask);
+        FreeSignal(execlib, bit as LONG);
+    }
+}
+
+fn test_timer(dos: *mut Library, out: BPTR) {
+    use core::time::Duration;
+
+    let execlib = unsafe { amiga_sys::abs_exec_library() };
+    if unsafe { (*execlib).lib_Version } < 36 {
+        print(dos, out, b"MISSING: no exec.library V36 for Timer\n");
+        return;
+    }
+    let Some(mut timer) = Timer::open(TimerUnit::MicroHz) else {
+        print(dos, out, b"timer: open: FAIL\n");
+        return;
+    };
+    let start = timer.now();
+    timer.sleep(Duration::from_millis(100));
+    let elapsed = timer.elapsed(start).as_millis();
+    compare(dos, out, (100..1000).contains(&elapsed) as i64, 1, b"timer: sleep");
+
+    let deadline = timer.now() + Duration::from_millis(50);
+    timer.sleep_until(deadline);
+    compare(dos, out, (timer.now() >= deadline) as i64, 1, b"timer: sleep_until");
+
+    // the short request completes first and the long request is aborted
+    let Some(mut long_timer) = Timer::open(TimerUnit::VBlank) else {
+        print(dos, out, b"timer: open vblank: FAIL\n");
+        return;
+    };
+    let start = timer.now();
+    let long = long_timer.start(Duration::from_secs(10));
+    let short = timer.start(Duration::from_millis(20));
+    let result = block_on(select(long, short));
+    compare(dos, out, (result == Either::Right(())) as i64, 1, b"timer: select");
+    compare(dos, out, (timer.elapsed(start).as_secs() < 5) as i64, 1, b"timer: abort");
+
+    // a forgotten request is aborted before the next one is sent
+    core::mem::forget(long_timer.start(Duration::from_secs(10)));
+    let start = timer.now();
+    long_timer.sleep(Duration::from_millis(20));
+    compare(dos, out, (timer.elapsed(start).as_secs() < 5) as i64, 1, b"timer: forgotten request");
+
+    for unit in [TimerUnit::EClock, TimerUnit::WaitUntil, TimerUnit::WaitEClock] {
+        let Some(mut unit_timer) = Timer::open(unit) else {
+            print(dos, out, b"timer: open unit: FAIL\n");
+            continue;
+        };
+        let start = unit_timer.now();
+        unit_timer.sleep(Duration::from_millis(40));
+        let elapsed = unit_timer.elapsed(start).as_millis();
+        compare(dos, out, (20..1000).contains(&elapsed) as i64, 1, b"timer: unit sleep");
+    }
+}
+
+fn test_tags(dos: *mut Library, out: BPTR) {
+    let Some(utility) = UtilityLib::open(36) else {
+        print(dos, out, b"MISSING: no utility.library V36\n");
         return;
+    };
+    let title = c"Title";
+    let tags = tags![
+        WA_Width => 200,
+        WA_Left => -1,
+        WA_Title => title,
+        WA_CloseGadget => true,
+    ];
+    unsafe {
+        compare(dos, out, utility.GetTagData(WA_Width, 0, tags.as_ptr()) as i64, 200, b"tags: integer");
+        compare(dos, out, utility.GetTagData(WA_Left, 0, tags.as_ptr()) as i32 as i64, -1, b"tags: negative");
+        compare(dos, out, utility.GetTagData(WA_Title, 0, tags.as_ptr()) as i64, title.as_ptr() as i64, b"tags: string");
+        compare(dos, out, utility.GetTagData(WA_CloseGadget, 0, tags.as_ptr()) as i64, 1, b"tags: bool");
+        compare(dos, out, utility.GetTagData(WA_Height, 5, tags.as_ptr()) as i64, 5, b"tags: missing");
     }
-    let charr: &mut [u8; 1] = &mut [ ch as u8 ];
+
+    use amiga_sys::typed_tags::open_window;
+    let mut zoom: [WORD; 4] = [0, 0, 100, 50];
+    let tags = tags![
+        open_window::WA_Width => 300,
+        open_window::WA_MaxWidth => 400,
+        open_window::WA_Title => c"Typed",
+        open_window::WA_Activate => true,
+        open_window::WA_Zoom => &mut zoom[0],
+    ];
     unsafe {
-        Write(dos, out, charr.as_ptr() as *const c_void, charr.len() as i32);
+        compare(dos, out, utility.GetTagData(WA_Width, 0, tags.as_ptr()) as i64, 300, b"typed tags: integer");
+        compare(dos, out, utility.GetTagData(WA_MaxWidth, 0, tags.as_ptr()) as i64, 400, b"typed tags: unsigned");
+        compare(dos, out, utility.GetTagData(WA_Activate, 0, tags.as_ptr()) as i64, 1, b"typed tags: bool");
+        compare(dos, out, utility.GetTagData(WA_Zoom, 0, tags.as_ptr()) as i64, zoom.as_ptr() as i64, b"typed tags: pointer");
+    }
+}
+
+amiga_callback! {
+    /// Called by RawDoFmt() for each character, with the dos library as the user data
+    fn rawdofmt_callback(d0 ch: u32, a3 dos: *mut Library) {
+        let out = unsafe { Output(dos) };
+        if out == 0 {
+            return;
+        }
+        let charr: &mut [u8; 1] = &mut [ ch as u8 ];
+        unsafe {
+            Write(dos, out, charr.as_ptr() as *const c_void, charr.len() as i32);
+        }
     }
 }
 
@@ -224,7 +819,7 @@ fn test_rawdofmt(dos: *mut Library) {
         let datastream: &mut [u8; 4] = &mut [ 0, 123, 9, 99 ];
         let execlib = amiga_sys::abs_exec_library();
         RawDoFmt(execlib, b"RawDoFmt callback: %d: ok\n\0".as_ptr(),
-            datastream.as_ptr() as *mut c_void, rawdofmt_callback_asm as *const () as FPTR,
+            datastream.as_ptr() as *mut c_void, rawdofmt_callback as *const () as FPTR,
             dos as *mut c_void);
     }
 }
@@ -265,21 +860,21 @@ fn test_intuition_open_window(dos: *mut Library, out: BPTR) {
             CloseLibrary(execlib, intui);
             return;
         }
-        let mut active = true;
-        while active {
-            Wait(execlib, 1 << (*(*win).UserPort).mp_SigBit);
-            let msg = GetMsg(execlib, (*win).UserPort);
-            if msg == core::ptr::null_mut() {
-                continue;
-            }
-            let msgclass = (* (msg as *mut IntuiMessage) ).Class;
-            ReplyMsg(execlib, msg);
-            if msgclass == IDCMP_CLOSEWINDOW {
-                CloseWindow(intui, win);
-                active = false;
+        let closed = core::pin::pin!(async {
+            loop {
+                let msg = intui_message(win).await;
+                if msg.Class == IDCMP_CLOSEWINDOW {
+                    break;
+                }
             }
+        });
+        let result = block_on(select(closed, ctrl_c()));
+        CloseWindow(intui, win);
+        if result == Either::Left(()) {
+            print(dos, out, b"intuition open window: ok\n");
+        } else {
+            print(dos, out, b"intuition open window: ctrl-c\n");
         }
-        print(dos, out, b"intuition open window: ok\n");
         CloseLibrary(execlib, intui);
     }
 }
@@ -378,23 +973,3 @@ fn compare(dos: *mut Library, out: BPTR, lhs: i64, rhs: i64, msg: &[u8]) {
         print(dos, out, b": ok\n");
     }
 }
-
-// panic handler
-
-#[panic_handler]
-fn panic(_info: &core::panic::PanicInfo) -> ! {
-    unsafe {
-        let execlib = amiga_sys::abs_exec_library();
-        Alert(execlib, AN_Unknown + 101);
-    }
-    loop {}
-}
-
-#[unsafe(no_mangle)]
-pub extern "C" fn abort() -> ! {
-    unsafe {
-        let execlib = amiga_sys::abs_exec_library();
-        Alert(execlib, AN_Unknown + 102);
-    }
-    loop {}
-}
@@ -8,8 +8,9 @@ bindgen --version
 # block common constants (INCLUDE_VERSION, LIBRARY_MINIMUM), standard C types ending with '_t', and few missing structs (DiskFont, DiskResourceUnit, DTMethods)
 bindgen wrapper.h --rust-target nightly --rust-edition 2024 --use-core --wrap-unsafe-ops --no-include-path-detection --no-doc-comments --blocklist-item=INCLUDE_VERSION --blocklist-item=LIBRARY_MINIMUM --blocklist-item='.*_t' --blocklist-type=DiskFont --blocklist-type=DiskResourceUnit --blocklist-type=DTMethods > src/cbindings.rs -- -I../NDK3.2R4/Include_H --target=m68k-unknown-none-elf
 
-# convert functions to asm
-python3 ./scripts/convert-fns.py src/cbindings.rs ../NDK3.2R4/SFD --override=amiga-ndk3.2.0-overrides.json --doc-kickstart-version=true > src/bindings.rs
+# convert functions to asm: the functions are written to one module per library in src/libraries
+rm -rf src/libraries
+python3 ./scripts/convert-fns.py src/cbindings.rs ../NDK3.2R4/SFD --override=amiga-ndk3.2.0-overrides.json --doc-kickstart-version=true --libraries-dir=src/libraries > src/bindings.rs
 rm src/cbindings.rs
 
 # add constants missed by rust-bindgen
@@ -33,6 +34,12 @@ python3 ./scripts/add-defines.py src/bindings.rs \
     ../NDK3.2R4/Include_H/utility/*.h \
     ../NDK3.2R4/Include_H/workbench/*.h > src/constants.rs
 
+# hide the structs and constants which are newer than the minimum Kickstart version
+python3 ./scripts/add-version-cfgs.py amiga-ndk3.2.0-overrides.json src/bindings.rs src/constants.rs --libraries-dir=src/libraries --size-checks=src/lib.rs
+
+# typed tags: the value types of the tags are listed in a json file
+python3 ./scripts/typed-tags.py amiga-ndk3.2.0-tag-types.json src/bindings.rs src/constants.rs > src/typed_tags.rs
+
 # fix misnamed structs: these are errors in the original C header files
 python3 ./scripts/replace-strings.py '*mut DTMethods' '*mut DTMethod' src/bindings.rs
 python3 ./scripts/replace-strings.py '*mut DiskResourceUnit' '*mut DiscResourceUnit' src/bindings.rs
@@ -0,0 +1,214 @@
+# hides structs and constants which are too new for the minimum Kickstart version
+#
+# The NDK headers only mention the versions of the structs and constants in comments, so they are
+# derived from the functions in src/libraries instead: a struct or type which is only used by V36
+# functions is a V36 struct, and a struct which contains a V36 struct is a V36 struct too. A struct
+# which is part of an older struct is as old as that struct. The "structs", "constants" and
+# "constant_prefixes" sections of the override file list the versions which can't be derived (V33
+# for old structs like Process which are only used by newer functions), and a constant defined with
+# a newer constant (like the tags defined with TAG_USER) is as new as that constant. The struct
+# definitions, their anonymous bindgen types, layout tests, size checks and impl blocks, the type
+# aliases and the constants get the same #[cfg(not(feature = "kickstart-vNN"))] attribute as the
+# functions.
+#
+# Running the script again doesn't add the attributes twice.
+
+import sys
+import os
+import re
+import json
+import argparse
+
+# minimum Kickstart versions selectable with the "kickstart-vNN" cargo features
+kickstart_features = [33, 34, 36, 37, 38, 39, 40, 44, 45, 46]
+
+# returns the cfg attribute which hides an item too new for the selected minimum Kickstart
+def kickstart_cfg(version):
+    older = [v for v in kickstart_features if v < version]
+    if not older:
+        return ""
+    return f'#[cfg(not(feature = "kickstart-v{older[-1]}"))]'
+
+kickstart_cfg_line = re.compile(r'#\[cfg\(not\(feature = "kickstart-v[0-9]+"\)\)\]$')
+
+def eprint(str):
+    print("\033[91merror\033[0m: "+str, file=sys.stderr)
+
+def version_number(vstr):
+    return int(vstr[1:])
+
+def identifiers(text):
+    return set(re.findall(r'\b[A-Za-z_][A-Za-z0-9_]*\b', text))
+
+parser = argparse.ArgumentParser(description='Adds kickstart-vNN cfg attributes to structs and constants')
+parser.add_argument('override_file', help='json file with the versions which can\'t be derived')
+parser.add_argument('rust_files', nargs='+', help='bindings.rs and constants.rs, modified in place')
+parser.add_argument('--libraries-dir', required=True, help='directory with the library modules generated by convert-fns.py')
+parser.add_argument('--size-checks', help='lib.rs with the struct size checks, modified in place')
+args = parser.parse_args()
+
+with open(args.override_file, "r") as file:
+    overrides = json.load(file)
+structs = {name: version_number(value["version"]) for name, value in overrides.get("structs", {}).items()}
+constants = {name: version_number(value["version"]) for name, value in overrides.get("constants", {}).items()}
+constant_prefixes = {prefix: version_number(value["version"]) for prefix, value in overrides.get("constant_prefixes", {}).items()}
+
+# the versions of the functions and the types used in their signatures
+function_types = []
+for filename in sorted(os.listdir(args.libraries_dir)):
+    if filename in ("mod.rs", "global.rs"):
+        continue
+    with open(os.path.join(args.libraries_dir, filename), "r") as file:
+        lines = file.read().split("\n")
+    version = 0
+    signature = None
+    for line in lines:
+        m = re.match(r'/// libname: "[^"]*" \(V([0-9]+)\)', line)
+        if m:
+            version = int(m.group(1))
+        if line.startswith("pub unsafe fn "):
+            signature = ""
+        if signature is not None:
+            signature += line
+            if line.endswith("{"):
+                # parameter names are followed by a colon, the types aren't
+                types = identifiers(re.sub(r'\b[A-Za-z0-9_]+:(?!:)', '', signature.split("(", 1)[1]))
+                function_types.append((version, types))
+                signature = None
+                version = 0
+
+# reads the structs, unions, type aliases and constants, removing the cfg attributes of a
+# previous run
+files = {}
+type_deps = {}
+constant_deps = {}
+for filename in args.rust_files:
+    with open(filename, "r") as file:
+        lines = [line for line in file.read().split("\n") if not kickstart_cfg_line.match(line)]
+    files[filename] = lines
+    body = None
+    for line in lines:
+        m = re.match(r'pub (?:struct|union|type) ([A-Za-z0-9_]+)\b', line)
+        if m:
+            name = m.group(1)
+            body = ""
+        m = re.match(r'pub const ([A-Za-z0-9_]+): (.*)', line)
+        if m:
+            constant_deps[m.group(1)] = identifiers(m.group(2))
+        if body is not None:
+            body += line
+            if line == "}" or line.endswith(";") or line.endswith("{}"):
+                type_deps[name] = identifiers(body.split(name, 1)[1]) - {name}
+                body = None
+
+# returns the struct containing an anonymous bindgen type
+def base_type(name):
+    return name.split("__bindgen_ty_")[0]
+
+# the anonymous bindgen types are part of their struct
+struct_deps = {}
+for name, deps in type_deps.items():
+    struct_deps.setdefault(base_type(name), set()).update(set(base_type(dep) for dep in deps if dep in type_deps))
+for name in struct_deps:
+    struct_deps[name].discard(name)
+
+# the types used by functions are as old as the oldest function
+versions = dict(structs)
+for version, types in function_types:
+    for name in types:
+        if name in type_deps and name not in structs:
+            versions[name] = min(versions.get(name, version), version)
+known = set(versions)
+
+# types which aren't used by functions are as new as the newest type they contain, and types
+# contained in older types are as old as the older type
+changed = True
+while changed:
+    changed = False
+    for name, deps in struct_deps.items():
+        if name not in known:
+            version = max([versions.get(dep, 0) for dep in deps], default=0)
+            if version != versions.get(name, 0):
+                versions[name] = version
+                changed = True
+        for dep in deps:
+            if versions.get(dep, 0) > versions.get(name, 0):
+                versions[dep] = versions.get(name, 0)
+                known.add(dep)
+                changed = True
+
+def type_version(name):
+    return versions.get(base_type(name), 0)
+
+def own_constant_version(name):
+    if name in constants:
+        return constants[name]
+    for prefix, version in constant_prefixes.items():
+        if name.startswith(prefix):
+            return version
+    return 0
+
+# constants are as new as the constants and types in their definition
+constant_versions = {name: own_constant_version(name) for name in constant_deps}
+changed = True
+while changed:
+    changed = False
+    for name, deps in constant_deps.items():
+        version = max([constant_versions.get(dep, 0) for dep in deps] + [type_version(dep) for dep in deps] + [constant_versions[name]])
+        if version != constant_versions[name]:
+            constant_versions[name] = version
+            changed = True
+
+# inserts the cfg attribute before the attributes of the item at output[end]
+def insert_cfg(output, end, cfg):
+    start = end
+    while start > 0 and output[start - 1].startswith("#["):
+        start -= 1
+    if cfg != "" and cfg not in output[start:end]:
+        output.insert(start, cfg)
+
+for filename, lines in files.items():
+    output = []
+    for line in lines:
+        m = re.match(r'pub (?:struct|union|type) ([A-Za-z0-9_]+)\b', line)
+        if m:
+            insert_cfg(output, len(output), kickstart_cfg(type_version(m.group(1))))
+        m = re.match(r'impl(?:<[^>]*>)? (?:[A-Za-z0-9_:<>]+ for )?([A-Za-z0-9_]+)\b', line)
+        if m and m.group(1) in type_deps:
+            insert_cfg(output, len(output), kickstart_cfg(type_version(m.group(1))))
+        # layout test of a struct: const _: () = { ["Size of Name"]...
+        m = re.match(r'\s*\["Size of ([A-Za-z0-9_]+)"\]', line)
+        if m and output[-1] == "const _: () = {":
+            insert_cfg(output, len(output) - 1, kickstart_cfg(type_version(m.group(1))))
+        m = re.match(r'pub const ([A-Za-z0-9_]+):', line)
+        if m:
+            insert_cfg(output, len(output), kickstart_cfg(constant_versions[m.group(1)]))
+        output.append(line)
+    with open(filename, "w") as file:
+        file.write("\n".join(output))
+
+# the struct size checks in lib.rs: ["Size of Name"][...];
+if args.size_checks:
+    with open(args.size_checks, "r") as file:
+        lines = file.read().split("\n")
+    output = []
+    for line in lines:
+        m = re.match(r'(\s*)\["Size of ([A-Za-z0-9_]+)"\]', line)
+        if m:
+            if kickstart_cfg_line.match(output[-1].strip()):
+                output.pop()
+            cfg = kickstart_cfg(type_version(m.group(2)))
+            if cfg != "":
+                output.append(m.group(1) + cfg)
+        output.append(line)
+    with open(args.size_checks, "w") as file:
+        file.write("\n".join(output))
+
+for name in structs:
+    if name not in type_deps:
+        eprint(f"{args.override_file}: struct not found: {name}")
+        exit(1)
+for name in constants:
+    if name not in constant_deps:
+        eprint(f"{args.override_file}: constant not found: {name}")
+        exit(1)
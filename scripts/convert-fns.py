@@ -7,6 +7,7 @@ import re
 import json
 import os
 import argparse
+import io
 
 # stores sfd / fd entries
 sfd_entries = {}
@@ -14,6 +15,13 @@ sfd_entries = {}
 # override values
 overrides = {}
 
+# functions collected for each library: used to print the library handle types and
+# the global library base functions
+libraries = {}
+
+# generated code for each library module
+library_code = {}
+
 # V number to kickstart version number mapping
 # https://wiki.amigaos.net/wiki/AmigaOS_Versions
 def v_number_to_ks_version(vstr):
@@ -46,6 +54,22 @@ def v_number_to_ks_version(vstr):
         return vmap[vnumber]
     return "??"
 
+# minimum Kickstart versions selectable with the "kickstart-vNN" cargo features
+kickstart_features = [33, 34, 36, 37, 38, 39, 40, 44, 45, 46]
+
+# returns the cfg attribute which hides a function too new for the selected minimum Kickstart
+#
+# The "kickstart-vNN" features enable the features for the newer versions, so a V37 function is
+# hidden by "kickstart-v36" and all the lower version features.
+def kickstart_cfg(vstr):
+    if vstr == "":
+        return ""
+    vnumber = int(vstr[1:])
+    older = [v for v in kickstart_features if v < vnumber]
+    if not older:
+        return ""
+    return f'#[cfg(not(feature = "kickstart-v{older[-1]}"))]'
+
 # prints an error message
 def eprint(str):
     print("\033[91merror\033[0m: "+str, file=sys.stderr)
@@ -61,6 +85,31 @@ def override_value(category, name, field, default_value):
         return overrides[category][name][field]
     return default_value
 
+# returns the module name for a library, e.g. "dos.library" -> "dos", "button.gadget" -> "button_gadget"
+def library_module_name(libname):
+    names = libname.split("|")
+    # "ciaa.resource|ciab.resource" -> "cia.resource"
+    name = os.path.commonprefix(names) + names[0][len(os.path.commonprefix(names))+1:]
+    stem, kind = name.split(".")
+    if kind == "library":
+        return stem
+    return stem + "_" + kind
+
+# returns the cargo feature name for a library, e.g. "button.gadget" -> "button-gadget"
+def library_feature_name(libname):
+    return library_module_name(libname).replace("_", "-")
+
+# returns the name of the handle type for a library, e.g. "dos.library" -> "DosLib"
+def library_handle_name(libname, basename):
+    stem = libname.split(".")[0]
+    if stem.endswith("lib"):
+        stem = stem[:-3]
+    name = basename[:-4] if basename.endswith("Base") else basename
+    # use the base name if it has better casing (e.g. "GadToolsBase" -> "GadTools")
+    if name.lower() != stem or name.isupper():
+        name = stem[0].upper() + stem[1:]
+    return name + "Lib"
+
 def read_sfd_file(filename):
     lines = []
     short_filename = os.path.basename(filename)
@@ -305,21 +354,19 @@ def print_asm_implementation(rustfn, filename, line_number):
             "register": "a6"
         })
 
-    # write out the function with inline assembly
+    # write out the function with inline assembly to the library module
+    out = library_code.setdefault(libname, io.StringIO())
+    def emit(text):
+        print(text, file=out)
 
     asm_return_type = rustfn["return_type"]
-    asm_additional_cast = False
     asm_out = 'out("d0") asm_ret_value,'
     asm_ret_value = "asm_ret_value"
-    # u8 or i8 isn't supported by inline asm, so hack around it
-    if asm_return_type == "UBYTE":
-        asm_return_type = "i16"
-        asm_additional_cast = '            "and.w #0x00ff, %d0",' # TODO: is this correct: #0x is a constant?
-        asm_ret_value = "asm_ret_value as u8"
-    if asm_return_type == "BYTE":
-        asm_return_type = "i16"
-        asm_additional_cast = '            "ext.w %d0",'
-        asm_ret_value = "asm_ret_value as i8"
+    # u8 or i8 isn't supported by inline asm, and 16 bit outputs of d0 can crash LLVM (SIGILL)
+    # depending on the caller, so read the whole d0 and cast it
+    if asm_return_type in ("UBYTE", "BYTE", "BOOL", "WORD", "UWORD", "SHORT", "USHORT", "i16", "u16"):
+        asm_ret_value = f"asm_ret_value as {asm_return_type}"
+        asm_return_type = "u32"
     # f32 or f64 are not supported by inline asm, so convert them to u32 or (u32,u32)
     if asm_return_type == "FLOAT":
         asm_return_type = "u32"
@@ -333,6 +380,10 @@ def print_asm_implementation(rustfn, filename, line_number):
     asm_in = []
     saved_regs = []
     pre_move_instr = []
+    # the base address goes in an address register if the parameters take all data registers,
+    # otherwise LLVM may run out of registers when the function also makes calls
+    param_regs = set(reg for param in rustfn["params"] for reg in param["register"].split("-"))
+    basereg_class = "reg_addr" if all(f"d{i}" in param_regs for i in range(1, 8)) else "reg"
     for param in rustfn["params"]:
         param_name = param["name"]
         reg = param["register"]
@@ -357,7 +408,7 @@ def print_asm_implementation(rustfn, filename, line_number):
                 exit(1)
             # a6 must be passed in using a temp reg because LLVM uses it
             saved_regs.append(reg)
-            asm_in.append(f'            basereg = in(reg) {param_name},')
+            asm_in.append(f'            basereg = in({basereg_class}) {param_name},')
             pre_move_instr.append(f'            "move.l {{basereg}}, %a6",')
 
         else:
@@ -396,51 +447,341 @@ def print_asm_implementation(rustfn, filename, line_number):
             doc_comment += f' (Kickstart {v_number_to_ks_version(maxversion)})'
 
     if args.doc_comments_bool:
-        print("/// " + doc_comment)
+        emit("/// " + doc_comment)
+    cfg = kickstart_cfg(minversion)
+    if cfg != "":
+        emit(cfg)
+
+    # collect library functions for the library handle types and global library bases
+    if basename != "":
+        library = libraries.setdefault(libname, { "base": basename, "basetype": basetype, "functions": [] })
+        library["functions"].append({
+            "name": rustfn["name"],
+            "params": rust_params[1:],
+            "args": [p.split(":")[0] for p in rust_params[1:]],
+            "return_type": rustfn["return_type"],
+            "doc_comment": doc_comment,
+            "cfg": cfg,
+            "min_version": int(minversion[1:]) if minversion != "" else 0,
+            "lvo": fnoffset,
+            "registers": [p["register"] for p in rustfn["params"][1:]],
+        })
 
     # function signature
     if rustfn["return_type"] == "VOID":
-        print(f'pub unsafe fn {rustfn["name"]}({", ".join(rust_params)}) {{')
+        emit(f'pub unsafe fn {rustfn["name"]}({", ".join(rust_params)}) {{')
     else:
-        print(f'pub unsafe fn {rustfn["name"]}({", ".join(rust_params)}) -> {rustfn["return_type"]} {{')
+        emit(f'pub unsafe fn {rustfn["name"]}({", ".join(rust_params)}) -> {rustfn["return_type"]} {{')
         if asm_return_type == "(u32, u32) = (0, 0)":
-            print(f'    let mut asm_ret_value: {asm_return_type};')
+            emit(f'    let mut asm_ret_value: {asm_return_type};')
         else:
-            print(f'    let asm_ret_value: {asm_return_type};')
+            emit(f'    let asm_ret_value: {asm_return_type};')
+
+    # check the library version at runtime with the "version-check" feature in debug builds
+    if minversion != "" and libname.split(".")[-1] in ["library", "device", "gadget", "image", "class"]:
+        basearg = basename if libname.endswith(".library") else f"{basename} as *mut Library"
+        emit(f'    #[cfg(all(feature = "version-check", debug_assertions))]')
+        emit(f'    unsafe {{ crate::check_library_version({basearg}, {minversion[1:]}, "{rustfn["name"]}", "{libname}") }}')
 
     # inline assembly uses .short values because Rust inline m68k doesn't support some instructions
-    print(f'    unsafe {{')
-    print(f'        asm!(')
+    emit(f'    unsafe {{')
+    emit(f'        asm!(')
     # save d0-d1 and a0-a1 because system functions treat them as scratch registers
     if rustfn["return_type"] == "VOID":
-        print(f'            "movem.l %d0-%d1/%a0-%a1, -(%sp)",')
+        emit(f'            "movem.l %d0-%d1/%a0-%a1, -(%sp)",')
     else:
-        print(f'            "movem.l %d1/%a0-%a1, -(%sp)",')
+        emit(f'            "movem.l %d1/%a0-%a1, -(%sp)",')
     for sreg in saved_regs:
-        print(f'            "move.l %{sreg}, -(%sp)",')
+        emit(f'            "move.l %{sreg}, -(%sp)",')
     for pmi in pre_move_instr:
-        print(pmi)
+        emit(pmi)
     #print(f'            "jsr ({fnoffset}, %a6)",')
-    print(f'            ".short 0x4eae", // jsr ({fnoffset}, %a6)')
-    print(f'            ".short {fnoffset}",')
-    if asm_additional_cast:
-        print(asm_additional_cast)
+    emit(f'            ".short 0x4eae", // jsr ({fnoffset}, %a6)')
+    emit(f'            ".short {fnoffset}",')
     for sreg in reversed(saved_regs):
-        print(f'            "move.l (%sp)+, %{sreg}",')
+        emit(f'            "move.l (%sp)+, %{sreg}",')
     if rustfn["return_type"] == "VOID":
-        print(f'            "movem.l (%sp)+, %d0-%d1/%a0-%a1",')
+        emit(f'            "movem.l (%sp)+, %d0-%d1/%a0-%a1",')
     else:
-        print(f'            "movem.l (%sp)+, %d1/%a0-%a1",')
+        emit(f'            "movem.l (%sp)+, %d1/%a0-%a1",')
     for ain in asm_in:
-        print(ain)
+        emit(ain)
     if rustfn["return_type"] != "VOID":
-        print(f'            {asm_out}')
-    print(f'        );')
-    print(f'    }}')
+        emit(f'            {asm_out}')
+    emit(f'        );')
+    emit(f'    }}')
     if rustfn["return_type"] != "VOID":
-        print(f'    {asm_ret_value}')
-    print(f'}}')
-    print(f'')
+        emit(f'    {asm_ret_value}')
+    emit(f'}}')
+    emit(f'')
+
+def print_library_handle(libname, library):
+    handle = library_handle_name(libname, library["base"])
+    out = library_code[libname]
+    def emit(text):
+        print(text, file=out)
+    emit(f'/// An open "{libname}"')
+    emit(f'///')
+    emit(f'/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when')
+    emit(f'/// the handle is dropped. The library functions are available as methods.')
+    emit(f'pub struct {handle} {{')
+    emit(f'    base: ::core::ptr::NonNull<Library>,')
+    emit(f'}}')
+    emit(f'')
+    emit(f'impl {handle} {{')
+    emit(f'    /// Library name passed to `OpenLibrary()`')
+    emit(f'    pub const NAME: &\'static ::core::ffi::CStr = c"{libname}";')
+    emit(f'')
+    emit(f'    /// Opens the library')
+    emit(f'    ///')
+    emit(f'    /// Returns `None` if the library can\'t be opened or if its version is lower')
+    emit(f'    /// than `min_version`.')
+    emit(f'    pub fn open(min_version: ULONG) -> Option<Self> {{')
+    emit(f'        let base = unsafe {{')
+    emit(f'            crate::exec::OpenLibrary(crate::abs_exec_library(), Self::NAME.as_ptr() as CONST_STRPTR, min_version)')
+    emit(f'        }};')
+    emit(f'        ::core::ptr::NonNull::new(base).map(|base| Self {{ base }})')
+    emit(f'    }}')
+    emit(f'')
+    emit(f'    /// Takes the ownership of a library base opened with `OpenLibrary()`')
+    emit(f'    ///')
+    emit(f'    /// Returns `None` if `base` is null.')
+    emit(f'    ///')
+    emit(f'    /// # Safety')
+    emit(f'    ///')
+    emit(f'    /// `base` must be an open "{libname}" that isn\'t closed elsewhere.')
+    emit(f'    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {{')
+    emit(f'        ::core::ptr::NonNull::new(base).map(|base| Self {{ base }})')
+    emit(f'    }}')
+    emit(f'')
+    emit(f'    /// Releases the ownership of the library base without closing it')
+    emit(f'    pub fn into_raw(self) -> *mut Library {{')
+    emit(f'        let base = self.base.as_ptr();')
+    emit(f'        ::core::mem::forget(self);')
+    emit(f'        base')
+    emit(f'    }}')
+    emit(f'')
+    emit(f'    /// Returns the library base pointer')
+    emit(f'    pub fn as_ptr(&self) -> *mut Library {{')
+    emit(f'        self.base.as_ptr()')
+    emit(f'    }}')
+    emit(f'')
+    emit(f'    /// Returns the library version (`lib_Version`)')
+    emit(f'    pub fn version(&self) -> UWORD {{')
+    emit(f'        unsafe {{ (*self.base.as_ptr()).lib_Version }}')
+    emit(f'    }}')
+    for fn in library["functions"]:
+        params = ", ".join(["&self"] + fn["params"])
+        call_args = ", ".join(["self.base.as_ptr()"] + fn["args"])
+        emit(f'')
+        if args.doc_comments_bool:
+            emit(f'    /// {fn["doc_comment"]}')
+        if fn["cfg"] != "":
+            emit(f'    {fn["cfg"]}')
+        if fn["return_type"] == "VOID":
+            emit(f'    pub unsafe fn {fn["name"]}({params}) {{')
+        else:
+            emit(f'    pub unsafe fn {fn["name"]}({params}) -> {fn["return_type"]} {{')
+        emit(f'        unsafe {{ {fn["name"]}({call_args}) }}')
+        emit(f'    }}')
+    emit(f'}}')
+    emit(f'')
+    emit(f'impl Drop for {handle} {{')
+    emit(f'    fn drop(&mut self) {{')
+    emit(f'        unsafe {{ crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }}')
+    emit(f'    }}')
+    emit(f'}}')
+    emit(f'')
+
+def print_global_bases(out):
+    def emit(text):
+        print(text, file=out)
+    emit(f'//! Library functions which use global library bases')
+    emit(f'//!')
+    emit(f'//! The functions read their library base from a static variable (`SysBase`, `DOSBase`,')
+    emit(f'//! `GfxBase`, etc.) instead of taking it as the first parameter. `SysBase` is initialized')
+    emit(f'//! automatically, the other library bases must be set by the program, unless the')
+    emit(f'//! `auto-open` feature is enabled.')
+    emit(f'//!')
+    emit(f'//! With the `auto-open` feature, libraries are opened when one of their functions is called')
+    emit(f'//! for the first time. The library is opened with the version `AUTO_OPEN_VERSION`. With the')
+    emit(f'//! `startup` feature, the libraries are closed with `close_libraries()` when the program')
+    emit(f'//! exits, otherwise the program must call `close_libraries()` before exiting. A library')
+    emit(f'//! which the program has opened itself and stored in a global base must be closed by the')
+    emit(f'//! program and its base set back to null, so that it isn\'t closed twice.')
+    emit(f'//!')
+    emit(f'//! If a library can\'t be opened, for example a V36 library on Kickstart 1.3, the')
+    emit(f'//! `startup` feature writes `can\'t open <library>` to the CLI and exits the program with')
+    emit(f'//! `RETURN_FAIL`, without running the destructors. Without the `startup` feature, or with the')
+    emit(f'//! `panic-handler` feature, which reports the message, a missing library panics. Programs')
+    emit(f'//! which can run without a library should open it themselves and check the result.')
+    emit(f'')
+    emit(f'/* automatically generated by the amiga-sys tools */')
+    emit(f'')
+    emit(f'use crate::bindings::*;')
+    emit(f'')
+    emit(f'/// Minimum library version used when libraries are opened automatically')
+    emit(f'pub static mut AUTO_OPEN_VERSION: ULONG = 0;')
+    emit(f'')
+    emit(f'/// Closes the libraries in the global library bases and sets the bases to null')
+    emit(f'///')
+    emit(f'/// Resource, device and class bases are not touched.')
+    emit(f'pub unsafe fn close_libraries() {{')
+    # an unsafe block for each library: with no library feature enabled, a single block would
+    # be empty and trigger the unused_unsafe warning
+    for libname in sorted(libraries):
+        library = libraries[libname]
+        if libname.endswith(".library") and library["base"] != "SysBase":
+            emit(f'    #[cfg(feature = "{library_feature_name(libname)}")]')
+            emit(f'    unsafe {{ close_library(&raw mut {library["base"]}) }}')
+    emit(f'}}')
+    emit(f'')
+    emit(f'#[allow(dead_code)]')
+    emit(f'unsafe fn close_library(base: *mut *mut Library) {{')
+    emit(f'    unsafe {{')
+    emit(f'        if !(*base).is_null() {{')
+    emit(f'            crate::exec::CloseLibrary(exec::get_SysBase(), *base);')
+    emit(f'            *base = ::core::ptr::null_mut();')
+    emit(f'        }}')
+    emit(f'    }}')
+    emit(f'}}')
+    emit(f'')
+    emit(f'#[allow(dead_code)]')
+    emit(f'unsafe fn auto_open(base: *mut *mut Library, name: &::core::ffi::CStr) -> *mut Library {{')
+    emit(f'    unsafe {{')
+    emit(f'        if (*base).is_null() {{')
+    emit(f'            *base = crate::exec::OpenLibrary(exec::get_SysBase(), name.as_ptr() as CONST_STRPTR, AUTO_OPEN_VERSION);')
+    emit(f'            if (*base).is_null() {{')
+    emit(f'                #[cfg(all(feature = "auto-open", feature = "startup", not(feature = "panic-handler")))]')
+    emit(f'                crate::startup::exit_missing_library(name);')
+    emit(f'                #[cfg(not(all(feature = "auto-open", feature = "startup", not(feature = "panic-handler"))))]')
+    emit(f'                panic!("can\'t open {{}}", name.to_str().unwrap_or("library"));')
+    emit(f'            }}')
+    emit(f'        }}')
+    emit(f'        *base')
+    emit(f'    }}')
+    emit(f'}}')
+    for libname in sorted(libraries):
+        library = libraries[libname]
+        base = library["base"]
+        # functions with a base parameter named 'resource' can be called with more than one base
+        if base == "resource":
+            continue
+        module = library_module_name(libname)
+        emit(f'')
+        emit(f'#[cfg(feature = "{library_feature_name(libname)}")]')
+        emit(f'pub use {module}::*;')
+        emit(f'')
+        emit(f'#[cfg(feature = "{library_feature_name(libname)}")]')
+        # all functions may be hidden by the "kickstart-vNN" features
+        if all(fn["cfg"] != "" for fn in library["functions"]):
+            emit(f'#[allow(unused_imports, dead_code)]')
+        emit(f'mod {module} {{')
+        emit(f'    use super::*;')
+        emit(f'')
+        emit(f'    /// Global library base for "{libname}"')
+        emit(f'    pub static mut {base}: {library["basetype"]} = ::core::ptr::null_mut();')
+        emit(f'')
+        emit(f'    #[inline]')
+        emit(f'    pub(super) unsafe fn get_{base}() -> {library["basetype"]} {{')
+        emit(f'        unsafe {{')
+        if base == "SysBase":
+            emit(f'            if SysBase.is_null() {{')
+            emit(f'                SysBase = crate::abs_exec_library();')
+            emit(f'            }}')
+            emit(f'            SysBase')
+        elif libname.endswith(".library"):
+            emit(f'            if cfg!(feature = "auto-open") {{')
+            emit(f'                auto_open(&raw mut {base}, c"{libname}")')
+            emit(f'            }} else {{')
+            emit(f'                {base}')
+            emit(f'            }}')
+        else:
+            emit(f'            {base}')
+        emit(f'        }}')
+        emit(f'    }}')
+        for fn in library["functions"]:
+            params = ", ".join(fn["params"])
+            call_args = ", ".join([f"get_{base}()"] + fn["args"])
+            emit(f'')
+            if args.doc_comments_bool:
+                emit(f'    /// {fn["doc_comment"]}')
+            if fn["cfg"] != "":
+                emit(f'    {fn["cfg"]}')
+            emit(f'    #[inline]')
+            if fn["return_type"] == "VOID":
+                emit(f'    pub unsafe fn {fn["name"]}({params}) {{')
+            else:
+                emit(f'    pub unsafe fn {fn["name"]}({params}) -> {fn["return_type"]} {{')
+            emit(f'        unsafe {{ crate::{module}::{fn["name"]}({call_args}) }}')
+            emit(f'    }}')
+        emit(f'}}')
+
+# returns the name of the FunctionInfo table of a library, e.g. "dos.library" -> "DOS_FUNCTIONS"
+def function_info_name(libname):
+    return library_module_name(libname).upper() + "_FUNCTIONS"
+
+def print_function_info(libname, file):
+    libnames = " and ".join(f'"{name}"' for name in libname.split("|"))
+    for fn in libraries[libname]["functions"]:
+        print(f'/// Library vector offset of `{fn["name"]}()`', file=file)
+        print(f'pub const LVO_{fn["name"]}: i16 = {fn["lvo"]};', file=file)
+    print(f'', file=file)
+    print(f'/// Functions of {libnames}', file=file)
+    print(f'pub static {function_info_name(libname)}: &[crate::FunctionInfo] = &[', file=file)
+    for fn in libraries[libname]["functions"]:
+        registers = ", ".join(f'"{reg}"' for reg in fn["registers"])
+        print(f'    crate::FunctionInfo {{ name: "{fn["name"]}", lvo: LVO_{fn["name"]}, registers: &[{registers}], min_version: {fn["min_version"]} }},', file=file)
+    print(f'];', file=file)
+
+def write_library_modules(dirpath):
+    os.makedirs(dirpath, exist_ok=True)
+    for libname in sorted(libraries):
+        module = library_module_name(libname)
+        with open(os.path.join(dirpath, module + ".rs"), "w") as file:
+            libnames = " and ".join(f'"{name}"' for name in libname.split("|"))
+            print(f'//! Functions of {libnames}', file=file)
+            print(f'', file=file)
+            print(f'/* automatically generated by the amiga-sys tools */', file=file)
+            print(f'', file=file)
+            # all functions may be hidden by the "kickstart-vNN" features
+            if all(fn["cfg"] != "" for fn in libraries[libname]["functions"]):
+                print(f'#![allow(unused_imports)]', file=file)
+                print(f'', file=file)
+            print(f'use crate::bindings::*;', file=file)
+            print(f'use core::arch::asm;', file=file)
+            print(f'', file=file)
+            file.write(library_code[libname].getvalue())
+            print_function_info(libname, file)
+    with open(os.path.join(dirpath, "global.rs"), "w") as file:
+        print_global_bases(file)
+    with open(os.path.join(dirpath, "mod.rs"), "w") as file:
+        print(f'/* automatically generated by the amiga-sys tools */', file=file)
+        print(f'', file=file)
+        for libname in sorted(libraries, key=library_module_name):
+            module = library_module_name(libname)
+            print(f'#[cfg(feature = "{library_feature_name(libname)}")]', file=file)
+            print(f'pub mod {module};', file=file)
+            print(f'#[cfg(feature = "{library_feature_name(libname)}")]', file=file)
+            if all(fn["cfg"] != "" for fn in libraries[libname]["functions"]):
+                print(f'#[allow(unused_imports)]', file=file)
+            print(f'pub use {module}::*;', file=file)
+            print(f'', file=file)
+        print(f'#[cfg(feature = "global-bases")]', file=file)
+        print(f'pub mod global;', file=file)
+        print(f'', file=file)
+        print(f'/// Returns information about a library function', file=file)
+        print(f'///', file=file)
+        print(f'/// Only the functions of the libraries enabled with cargo features are found.', file=file)
+        print(f'pub fn find_function(name: &str) -> Option<&\'static crate::FunctionInfo> {{', file=file)
+        for libname in sorted(libraries, key=library_module_name):
+            print(f'    #[cfg(feature = "{library_feature_name(libname)}")]', file=file)
+            print(f'    if let Some(info) = {function_info_name(libname)}.iter().find(|info| info.name == name) {{', file=file)
+            print(f'        return Some(info);', file=file)
+            print(f'    }}', file=file)
+        print(f'    let _ = name;', file=file)
+        print(f'    None', file=file)
+        print(f'}}', file=file)
 
 ## main
 
@@ -457,6 +798,8 @@ parser.add_argument('--doc-kickstart-version', dest='doc_ks_version_bool',
                     help='add Kickstart version to doc comments [default: false]')
 parser.add_argument('--override', nargs=1, type=str, dest='overridejson',
                     help='filename of the override file')
+parser.add_argument('--libraries-dir', nargs=1, type=str, dest='libraries_dir', required=True,
+                    help='folder for the generated library modules')
 args = parser.parse_args()
 
 # read overrides.json file: it overrides values defined in sfd files
@@ -482,9 +825,8 @@ with open(bfilename, 'r') as file:
         blines.append({ "line": line, "line_number": line_number })
 
 # print some additional definitions
-print('use core::arch::asm;')
 # TODO: is this correct way to define function pointers that match Amiga function pointers (FPTR)?
-print('\n/// A function pointer with the Amiga calling convention')
+print('/// A function pointer with the Amiga calling convention')
 print('///')
 print('/// Parameters and the return value are passed in the CPU registers (this is not the "C"')
 print('/// calling convention). The ROM kernel manuals have documented which registers to use.')
@@ -565,3 +907,11 @@ for lineobj in blines:
         #eprint(f"bindings.rs:{line_number}: rust-bindgen generated __IncompleteArrayField: {line}")
         #exit(1)
     print(line)
+
+# add handle types for the libraries opened with OpenLibrary()
+for libname in sorted(libraries):
+    if libname.endswith(".library"):
+        print_library_handle(libname, libraries[libname])
+
+# write library modules and functions using global library bases
+write_library_modules(args.libraries_dir[0])
@@ -0,0 +1,78 @@
+# generates typed tag definitions (TypedTag<T>) from a json file which lists the value types of the tags
+
+import sys
+import re
+import json
+import argparse
+
+def eprint(*args, **kwargs):
+    print(*args, file=sys.stderr, **kwargs)
+
+parser = argparse.ArgumentParser(description='Generates typed tag definitions for amiga-sys')
+parser.add_argument('tag_types_file',
+                    help='json file with the value types of the tags grouped by function or class')
+parser.add_argument('rust_files', nargs='+',
+                    help='bindings.rs and constants.rs defining the tags as u32 constants')
+args = parser.parse_args()
+
+# collect the names of the constants and types, which the tags and their value types refer to,
+# and the kickstart-vNN cfg attributes of the constants and types added by add-version-cfgs.py
+constants = set()
+types = set(["i8", "u8", "i16", "u16", "i32", "u32", "isize", "usize"])
+item_cfgs = {}
+for filename in args.rust_files:
+    with open(filename, "r") as file:
+        cfg = ""
+        for line in file:
+            m = re.match(r'pub const ([A-Za-z0-9_]+): u32 = ', line)
+            if m:
+                constants.add(m.group(1))
+            m = re.match(r'pub (?:struct|type|union) ([A-Za-z0-9_]+)\b', line)
+            if m:
+                types.add(m.group(1))
+            m = re.match(r'pub (?:const|struct|type|union) ([A-Za-z0-9_]+)\b', line)
+            if m:
+                if cfg != "":
+                    item_cfgs[m.group(1)] = cfg
+            if line.startswith("#[cfg("):
+                cfg = line.strip()
+            elif not line.startswith("#["):
+                cfg = ""
+
+# returns the Kickstart version in a kickstart-vNN cfg attribute
+def cfg_version(cfg):
+    m = re.search(r'kickstart-v([0-9]+)', cfg)
+    return int(m.group(1)) if m else 0
+
+# a typed tag is hidden when its tag or its value type is hidden
+def tag_cfg(tag, value_type):
+    base_type = value_type.replace("*mut ", "").replace("*const ", "")
+    return max([item_cfgs.get(tag, ""), item_cfgs.get(base_type, "")], key=cfg_version)
+
+with open(args.tag_types_file, "r") as file:
+    groups = json.load(file)
+
+print(f'/* automatically generated by the amiga-sys tools */')
+for module, group in groups.items():
+    # a module whose tags are all hidden gets the cfg of its oldest tags, and only the newer tags
+    # get their own cfg
+    module_cfg = min([tag_cfg(tag, value_type) for tag, value_type in group["tags"].items()], key=cfg_version)
+    print(f'')
+    print(f'/// {group["doc"]}')
+    if module_cfg != "":
+        print(module_cfg)
+    print(f'pub mod {module} {{')
+    print(f'    use crate::*;')
+    print(f'')
+    for tag, value_type in group["tags"].items():
+        if not tag in constants:
+            eprint(f"{args.tag_types_file}: {module}: tag not found: {tag}")
+            exit(1)
+        base_type = value_type.replace("*mut ", "").replace("*const ", "")
+        if not base_type in types:
+            eprint(f"{args.tag_types_file}: {module}: {tag}: type not found: {value_type}")
+            exit(1)
+        if tag_cfg(tag, value_type) != module_cfg:
+            print(f'    {tag_cfg(tag, value_type)}')
+        print(f'    pub const {tag}: TypedTag<{value_type}> = TypedTag::new(crate::{tag});')
+    print(f'}}')
@@ -0,0 +1,250 @@
+use crate::{APTR, List, MEMF_CLEAR, MinList, MinNode, Node, ULONG};
+use core::alloc::{GlobalAlloc, Layout};
+use core::ptr::null_mut;
+
+/// Alignment of the memory returned by `AllocMem()`
+const ALLOC_MEM_ALIGN: usize = 8;
+
+/// Header before the memory allocated with `AllocMem()`, which links it in `ALLOC_MEM_BLOCKS`
+#[repr(C)]
+struct AllocMemHeader {
+    node: MinNode,
+    /// Size of the block, including the header
+    size: ULONG,
+}
+
+/// Size of the header, rounded up to keep the `AllocMem()` alignment
+const ALLOC_MEM_HEADER_SIZE: usize = size_of::<AllocMemHeader>().next_multiple_of(ALLOC_MEM_ALIGN);
+
+/// Blocks of the global allocator allocated with `AllocMem()` and not freed yet, initialized
+/// on the first allocation
+static mut ALLOC_MEM_BLOCKS: MinList = MinList {
+    mlh_Head: null_mut(),
+    mlh_Tail: null_mut(),
+    mlh_TailPred: null_mut(),
+};
+
+/// Alignment of the memory returned by `AllocPooled()`
+#[cfg(not(feature = "kickstart-v38"))]
+const ALLOC_POOLED_ALIGN: usize = 4;
+
+/// Pool of the global allocator, created on the first allocation
+#[cfg(not(feature = "kickstart-v38"))]
+static mut ALLOC_POOL: APTR = null_mut();
+
+/// Global allocator using the exec memory functions
+///
+/// Enabled with the `alloc` feature. Allocates with `AllocMem()`, or from a private memory
+/// pool with `AllocPooled()`, so that `alloc::vec::Vec`, `alloc::string::String`, etc.
+/// can be used:
+///
+/// ```ignore
+/// extern crate alloc;
+///
+/// #[global_allocator]
+/// static ALLOCATOR: AmigaAlloc = AmigaAlloc::new(MEMF_ANY);
+/// ```
+///
+/// The memory flags select the memory type, for example `MEMF_FAST` fails instead of
+/// returning chip memory when there's no fast memory. Alignments greater than the
+/// alignment of the exec functions are handled by allocating extra memory.
+///
+/// The memory which the program didn't free is freed by [`delete_alloc_pool()`], which the
+/// `startup` feature calls when `main()` returns.
+pub struct AmigaAlloc {
+    flags: ULONG,
+    puddle_size: ULONG,
+    #[cfg_attr(feature = "kickstart-v38", allow(dead_code))]
+    threshold: ULONG,
+}
+
+impl AmigaAlloc {
+    /// Creates an allocator which allocates with `AllocMem()` using the memory flags
+    ///
+    /// Each block has a header which links it in a list, so that the blocks which haven't been
+    /// freed can be freed by [`delete_alloc_pool()`].
+    pub const fn new(flags: ULONG) -> Self {
+        Self {
+            flags,
+            puddle_size: 0,
+            threshold: 0,
+        }
+    }
+
+    /// Creates an allocator which allocates from a private memory pool (V39)
+    ///
+    /// The pool is created with `CreatePool()` on the first allocation. Allocations larger
+    /// than `threshold` get their own puddle. All the memory of the pool is freed at once
+    /// by [`delete_alloc_pool()`], which the `startup` feature calls when `main()` returns,
+    /// so that memory isn't leaked even if some allocations are never freed.
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub const fn with_pool(flags: ULONG, puddle_size: ULONG, threshold: ULONG) -> Self {
+        Self {
+            flags,
+            puddle_size,
+            threshold,
+        }
+    }
+
+    fn uses_pool(&self) -> bool {
+        self.puddle_size != 0
+    }
+
+    fn guaranteed_align(&self) -> usize {
+        #[cfg(not(feature = "kickstart-v38"))]
+        if self.uses_pool() {
+            return ALLOC_POOLED_ALIGN;
+        }
+        ALLOC_MEM_ALIGN
+    }
+
+    /// Allocates `size` bytes with the exec alignment
+    unsafe fn alloc_raw(&self, size: usize, flags: ULONG) -> *mut u8 {
+        unsafe {
+            let exec = crate::abs_exec_library();
+            #[cfg(not(feature = "kickstart-v38"))]
+            if self.uses_pool() {
+                // pools can't be used by several tasks at the same time
+                crate::Forbid(exec);
+                let pool = &raw mut ALLOC_POOL;
+                if (*pool).is_null() {
+                    *pool = crate::CreatePool(exec, flags, self.puddle_size, self.threshold);
+                }
+                let memory = if (*pool).is_null() {
+                    null_mut()
+                } else {
+                    crate::AllocPooled(exec, *pool, size as ULONG)
+                };
+                crate::Permit(exec);
+                return memory as *mut u8;
+            }
+            let size = size + ALLOC_MEM_HEADER_SIZE;
+            let header = crate::AllocMem(exec, size as ULONG, flags) as *mut AllocMemHeader;
+            if header.is_null() {
+                return null_mut();
+            }
+            (*header).size = size as ULONG;
+            crate::Forbid(exec);
+            let blocks = &raw mut ALLOC_MEM_BLOCKS;
+            if (*blocks).mlh_Head.is_null() {
+                // empty list, like NewList()
+                (*blocks).mlh_Head = &raw mut (*blocks).mlh_Tail as *mut MinNode;
+                (*blocks).mlh_TailPred = blocks as *mut MinNode;
+            }
+            crate::AddTail(exec, blocks as *mut List, header as *mut Node);
+            crate::Permit(exec);
+            (header as *mut u8).add(ALLOC_MEM_HEADER_SIZE)
+        }
+    }
+
+    /// Frees memory allocated with `alloc_raw()`
+    unsafe fn free_raw(&self, memory: *mut u8, size: usize) {
+        unsafe {
+            let exec = crate::abs_exec_library();
+            #[cfg(not(feature = "kickstart-v38"))]
+            if self.uses_pool() {
+                crate::Forbid(exec);
+                let pool = ALLOC_POOL;
+                // the pool may have been deleted already, together with the memory
+                if !pool.is_null() {
+                    crate::FreePooled(exec, pool, memory as APTR, size as ULONG);
+                }
+                crate::Permit(exec);
+                return;
+            }
+            let header = memory.sub(ALLOC_MEM_HEADER_SIZE) as *mut AllocMemHeader;
+            crate::Forbid(exec);
+            crate::Remove(exec, header as *mut Node);
+            crate::Permit(exec);
+            crate::FreeMem(
+                exec,
+                header as APTR,
+                (size + ALLOC_MEM_HEADER_SIZE) as ULONG,
+            )
+        }
+    }
+
+    /// Returns the size to allocate for the layout
+    fn raw_size(&self, layout: Layout) -> usize {
+        if layout.align() <= self.guaranteed_align() {
+            layout.size()
+        } else {
+            // room for aligning and for the pointer to the allocated memory
+            layout.size() + layout.align()
+        }
+    }
+
+    unsafe fn alloc_with_flags(&self, layout: Layout, flags: ULONG) -> *mut u8 {
+        let memory = unsafe { self.alloc_raw(self.raw_size(layout), flags) };
+        if memory.is_null() || layout.align() <= self.guaranteed_align() {
+            return memory;
+        }
+        // align up and leave at least one pointer before the aligned memory
+        let aligned = ((memory as usize + layout.align()) & !(layout.align() - 1)) as *mut u8;
+        unsafe { (aligned as *mut *mut u8).sub(1).write_unaligned(memory) };
+        aligned
+    }
+}
+
+unsafe impl GlobalAlloc for AmigaAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { self.alloc_with_flags(layout, self.flags) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if self.uses_pool() {
+            // pools clear only if the pool was created with MEMF_CLEAR
+            let memory = unsafe { self.alloc(layout) };
+            if !memory.is_null() {
+                unsafe { memory.write_bytes(0, layout.size()) };
+            }
+            return memory;
+        }
+        unsafe { self.alloc_with_flags(layout, self.flags | MEMF_CLEAR) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let memory = if layout.align() <= self.guaranteed_align() {
+            ptr
+        } else {
+            unsafe { (ptr as *mut *mut u8).sub(1).read_unaligned() }
+        };
+        unsafe { self.free_raw(memory, self.raw_size(layout)) }
+    }
+}
+
+/// Frees the memory of the global [`AmigaAlloc`] which hasn't been freed
+///
+/// Deletes the pool, which frees all its memory, and frees the blocks allocated with
+/// `AllocMem()`. Called by the `startup` feature when `main()` returns. Without the `startup`
+/// feature, call this before exiting the program.
+///
+/// # Safety
+///
+/// The memory allocated before must not be used afterwards. Allocating creates a new pool,
+/// and memory allocated before must not be freed.
+pub unsafe fn delete_alloc_pool() {
+    unsafe {
+        let exec = crate::abs_exec_library();
+        crate::Forbid(exec);
+        let blocks = &raw mut ALLOC_MEM_BLOCKS;
+        if !(*blocks).mlh_Head.is_null() {
+            loop {
+                let header = crate::RemHead(exec, blocks as *mut List) as *mut AllocMemHeader;
+                if header.is_null() {
+                    break;
+                }
+                crate::FreeMem(exec, header as APTR, (*header).size);
+            }
+        }
+        #[cfg(not(feature = "kickstart-v38"))]
+        {
+            let pool = &raw mut ALLOC_POOL;
+            if !(*pool).is_null() {
+                crate::DeletePool(exec, *pool);
+                *pool = null_mut();
+            }
+        }
+        crate::Permit(exec);
+    }
+}
@@ -0,0 +1,317 @@
+/// Declares library functions which are called with the Amiga calling convention
+///
+/// Useful for third-party libraries, such as reqtools.library or bsdsocket.library, which
+/// aren't included in these bindings. Each function is declared with its parameters, the
+/// library vector offset (LVO) and the registers of the parameters. The first parameter is
+/// the library base, which is passed in a6, and it isn't included in the register list.
+/// A `DOUBLE` parameter is passed in two registers, for example `d0-d1`.
+///
+/// The functions are `unsafe` and they save the scratch registers in the same way as the
+/// functions of the system libraries. Registers a4 and a5 are supported, a6 and a7 aren't.
+/// The crate using the macro needs `#![feature(asm_experimental_arch)]`.
+///
+/// ```ignore
+/// amiga_lib! {
+///     /// Allocates a requester structure
+///     pub fn rtAllocRequestA(ReqToolsBase: *mut Library, type_: ULONG, taglist: *mut TagItem) -> APTR = -30 (d0, a0);
+///     pub fn rtFreeRequest(ReqToolsBase: *mut Library, req: APTR) = -36 (a1);
+/// }
+/// ```
+///
+/// The parameters can have any integer, pointer or float type. The return value can have
+/// any integer, pointer or float type: `UBYTE`, `BYTE`, `WORD` etc. are read from the lower
+/// bits of d0 and `DOUBLE` from d0-d1.
+#[macro_export]
+macro_rules! amiga_lib {
+    // convert the registers to string literals, so that they can be used in asm!()
+    (@regs $info:tt $args:tt [$($done:tt)*] d0 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d0"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d1 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d1"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d2 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d2"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d3 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d3"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d4 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d4"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d5 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d5"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d6 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d6"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] d7 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "d7"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] a0 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "a0"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] a1 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "a1"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] a2 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "a2"] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] a3 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* "a3"] $($rest)*) };
+    // a4 and a5 must be passed in using a temp reg because LLVM uses them
+    (@regs $info:tt $args:tt [$($done:tt)*] a4 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* a4] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] a5 $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* a5] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] , $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* ,] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] - $($rest:tt)*) => { $crate::amiga_lib!(@regs $info $args [$($done)* -] $($rest)*) };
+    (@regs $info:tt $args:tt [$($done:tt)*] $reg:tt $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!("amiga_lib!: unsupported register: ", ::core::stringify!($reg)))
+    };
+    (@regs $info:tt ($($args:tt)*) [$($done:tt)*]) => {
+        $crate::amiga_lib!(@operands $info [] [] [] [] ($($done)*) ($($args)*))
+    };
+
+    // collect the asm!() operands and the instructions saving and loading a4 and a5
+    (@operands $info:tt [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]
+        ($reg1:tt - $reg2:tt $(, $($regs:tt)*)?) ($arg:ident $(, $($args:tt)*)?)) => {
+        $crate::amiga_lib!(@operands $info [$($save)*] [$($load)*] [$($restore)*]
+            [$($ops)* in($reg1) $crate::AsmArgPair::into_regs($arg).0, in($reg2) $crate::AsmArgPair::into_regs($arg).1,]
+            ($($($regs)*)?) ($($($args)*)?))
+    };
+    (@operands $info:tt [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]
+        (a4 $(, $($regs:tt)*)?) ($arg:ident $(, $($args:tt)*)?)) => {
+        $crate::amiga_lib!(@operands $info
+            [$($save)* "move.l %a4, -(%sp)",] [$($load)* "move.l {a4reg}, %a4",] ["move.l (%sp)+, %a4", $($restore)*]
+            [$($ops)* a4reg = in(reg) $crate::AsmArg::into_reg($arg),]
+            ($($($regs)*)?) ($($($args)*)?))
+    };
+    (@operands $info:tt [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]
+        (a5 $(, $($regs:tt)*)?) ($arg:ident $(, $($args:tt)*)?)) => {
+        $crate::amiga_lib!(@operands $info
+            [$($save)* "move.l %a5, -(%sp)",] [$($load)* "move.l {a5reg}, %a5",] ["move.l (%sp)+, %a5", $($restore)*]
+            [$($ops)* a5reg = in(reg) $crate::AsmArg::into_reg($arg),]
+            ($($($regs)*)?) ($($($args)*)?))
+    };
+    (@operands $info:tt [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]
+        ($reg:tt $(, $($regs:tt)*)?) ($arg:ident $(, $($args:tt)*)?)) => {
+        $crate::amiga_lib!(@operands $info [$($save)*] [$($load)*] [$($restore)*]
+            [$($ops)* in($reg) $crate::AsmArg::into_reg($arg),]
+            ($($($regs)*)?) ($($($args)*)?))
+    };
+    (@operands $info:tt $save:tt $load:tt $restore:tt $ops:tt () ()) => {
+        $crate::amiga_lib!(@call $info $save $load $restore $ops)
+    };
+    (@operands $info:tt $save:tt $load:tt $restore:tt $ops:tt $regs:tt $args:tt) => {
+        ::core::compile_error!("amiga_lib!: the number of registers doesn't match the number of parameters")
+    };
+
+    // inline assembly uses .short values because Rust inline m68k doesn't support some instructions
+    (@call ($base:ident $lvo:literal []) [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]) => {
+        unsafe {
+            ::core::arch::asm!(
+                // save d0-d1 and a0-a1 because system functions treat them as scratch registers
+                "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+                "move.l %a6, -(%sp)",
+                $($save)*
+                "move.l {basereg}, %a6",
+                $($load)*
+                ".short 0x4eae", // jsr (lvo, %a6)
+                ".short {lvo}",
+                $($restore)*
+                "move.l (%sp)+, %a6",
+                "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+                basereg = in(reg) $base,
+                lvo = const $lvo,
+                $($ops)*
+            );
+        }
+    };
+    (@call ($base:ident $lvo:literal [$ret:ty]) [$($save:tt)*] [$($load:tt)*] [$($restore:tt)*] [$($ops:tt)*]) => {{
+        let d0: u32;
+        let d1: u32;
+        unsafe {
+            ::core::arch::asm!(
+                // save a0-a1 because system functions treat them as scratch registers,
+                // d0-d1 are outputs because DOUBLE values are returned in d0-d1
+                "movem.l %a0-%a1, -(%sp)",
+                "move.l %a6, -(%sp)",
+                $($save)*
+                "move.l {basereg}, %a6",
+                $($load)*
+                ".short 0x4eae", // jsr (lvo, %a6)
+                ".short {lvo}",
+                $($restore)*
+                "move.l (%sp)+, %a6",
+                "movem.l (%sp)+, %a0-%a1",
+                basereg = in(reg) $base,
+                lvo = const $lvo,
+                $($ops)*
+                lateout("d0") d0,
+                lateout("d1") d1,
+            );
+        }
+        <$ret as $crate::AsmReturn>::from_regs(d0, d1)
+    }};
+
+    // function declarations
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($base:ident: $base_type:ty $(, $arg:ident: $arg_type:ty)* $(,)?) $(-> $ret:ty)?
+            = $lvo:literal ($($reg:tt)*);
+        $($rest:tt)*
+    ) => {
+        $(#[$attr])*
+        #[allow(non_snake_case)]
+        $vis unsafe fn $name($base: $base_type $(, $arg: $arg_type)*) $(-> $ret)? {
+            $crate::amiga_lib!(@regs ($base $lvo [$($ret)?]) ($($arg),*) [] $($reg)*)
+        }
+        $crate::amiga_lib!($($rest)*);
+    };
+}
+
+/// Declares callback functions which are called with the Amiga calling convention
+///
+/// System functions call hooks, `RawDoFmt()` character procedures, interrupt code, etc.
+/// with the parameters in registers. Each parameter of the callback is declared with its
+/// register. The macro declares a naked function which pushes the registers to the stack,
+/// calls the function body and returns its return value in d0. The function's address
+/// can be stored in `FPTR` fields and parameters, such as `h_Entry` of [`Hook`](crate::Hook):
+///
+/// ```ignore
+/// amiga_callback! {
+///     /// Called by RawDoFmt() for each character
+///     fn put_char(d0 ch: u32, a3 data: APTR) {
+///         // ...
+///     }
+/// }
+///
+/// RawDoFmt(execlib, fmt, args, put_char as *const () as FPTR, data);
+/// ```
+///
+/// The parameters and the return value can have any integer, pointer or `FLOAT` type.
+/// The callback preserves registers d2-d7 and a2-a6. The body is in a hidden module with
+/// the same name as the function, so it can use the items of the surrounding module.
+/// The crate using the macro needs `#![feature(asm_experimental_arch)]`.
+#[macro_export]
+macro_rules! amiga_callback {
+    // push the registers in reverse order, so that the first parameter is at the top of the stack
+    (@naked $info:tt [$($push:tt)*] $reg:ident $($regs:ident)*) => {
+        $crate::amiga_callback!(@naked $info
+            [::core::concat!("move.l %", ::core::stringify!($reg), ", -(%sp)"), $($push)*] $($regs)*);
+    };
+    (@naked [$(#[$attr:meta])* $vis:vis $name:ident ($($arg:ident)*)] [$($push:tt)*]) => {
+        $(#[$attr])*
+        #[unsafe(naked)]
+        $vis extern "C" fn $name() {
+            ::core::arch::naked_asm!(
+                $($push)*
+                "jsr {entry}",
+                // pop the parameters
+                "lea ({size}, %sp), %sp",
+                "rts",
+                entry = sym $name::entry,
+                size = const 4 * <[&str]>::len(&[$(::core::stringify!($arg)),*]),
+            )
+        }
+    };
+
+    // function declarations
+    () => {};
+    (
+        $(#[$attr:meta])*
+        $vis:vis fn $name:ident($($reg:ident $arg:ident: $arg_type:ty),* $(,)?) $(-> $ret:ty)? $body:block
+        $($rest:tt)*
+    ) => {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[allow(non_snake_case)]
+            fn body($($arg: $arg_type),*) $(-> $ret)? $body
+
+            #[allow(non_snake_case)]
+            pub(super) extern "C" fn entry($($arg: u32),*) -> u32 {
+                $crate::AsmArg::into_reg(body($(<$arg_type as $crate::AsmReturn>::from_regs($arg, 0)),*))
+            }
+        }
+        $crate::amiga_callback!(@naked [$(#[$attr])* $vis $name ($($arg)*)] [] $($reg)*);
+        $crate::amiga_callback!($($rest)*);
+    };
+}
+
+/// Converts a parameter of [`amiga_lib!`] functions or the return value of
+/// [`amiga_callback!`] functions to a register value
+#[doc(hidden)]
+pub trait AsmArg {
+    fn into_reg(self) -> u32;
+}
+
+/// Converts a `DOUBLE` parameter of [`amiga_lib!`] functions to two register values
+#[doc(hidden)]
+pub trait AsmArgPair {
+    fn into_regs(self) -> (u32, u32);
+}
+
+/// Converts the d0 and d1 registers to the return value of [`amiga_lib!`] functions or
+/// a register to a parameter of [`amiga_callback!`] functions
+#[doc(hidden)]
+pub trait AsmReturn {
+    fn from_regs(d0: u32, d1: u32) -> Self;
+}
+
+macro_rules! impl_asm_integer {
+    ($($t:ty),*) => {
+        $(
+            impl AsmArg for $t {
+                fn into_reg(self) -> u32 {
+                    self as u32
+                }
+            }
+
+            impl AsmReturn for $t {
+                fn from_regs(d0: u32, _d1: u32) -> Self {
+                    d0 as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_asm_integer!(i8, u8, i16, u16, i32, u32, isize, usize);
+
+impl AsmArg for () {
+    fn into_reg(self) -> u32 {
+        0
+    }
+}
+
+impl<T> AsmArg for *const T {
+    fn into_reg(self) -> u32 {
+        self as u32
+    }
+}
+
+impl<T> AsmArg for *mut T {
+    fn into_reg(self) -> u32 {
+        self as u32
+    }
+}
+
+impl<T> AsmReturn for *const T {
+    fn from_regs(d0: u32, _d1: u32) -> Self {
+        d0 as usize as *const T
+    }
+}
+
+impl<T> AsmReturn for *mut T {
+    fn from_regs(d0: u32, _d1: u32) -> Self {
+        d0 as usize as *mut T
+    }
+}
+
+impl AsmArg for f32 {
+    fn into_reg(self) -> u32 {
+        self.to_bits()
+    }
+}
+
+impl AsmReturn for f32 {
+    fn from_regs(d0: u32, _d1: u32) -> Self {
+        f32::from_bits(d0)
+    }
+}
+
+impl AsmArgPair for f64 {
+    fn into_regs(self) -> (u32, u32) {
+        (
+            (self.to_bits() >> 32) as u32,
+            (self.to_bits() & 0xffff_ffff) as u32,
+        )
+    }
+}
+
+impl AsmReturn for f64 {
+    fn from_regs(d0: u32, d1: u32) -> Self {
+        f64::from_bits(((d0 as u64) << 32) | d1 as u64)
+    }
+}
@@ -1,5 +1,3 @@
-use core::arch::asm;
-
 /// A function pointer with the Amiga calling convention
 ///
 /// Parameters and the return value are passed in the CPU registers (this is not the "C"
@@ -638,17 +636,29 @@ pub const ITEM_ERROR: i32 = -1;
 pub const ITEM_NOTHING: u32 = 0;
 pub const ITEM_UNQUOTED: u32 = 1;
 pub const ITEM_QUOTED: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_FILEHANDLE: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_EXALLCONTROL: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_FIB: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_STDPKT: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_CLI: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DOS_RDARGS: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAB_STDIN: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAF_STDIN: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAB_NOALLOC: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAF_NOALLOC: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAB_NOPROMPT: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RDAF_NOPROMPT: u32 = 4;
 pub const MAX_TEMPLATE_ITEMS: u32 = 100;
 pub const MAX_MULTIARGS: u32 = 128;
@@ -829,10 +839,15 @@ pub const OM_ADDMEMBER: u32 = 265;
 pub const OM_REMMEMBER: u32 = 266;
 pub const OPUF_INTERIM: u32 = 1;
 pub const CLF_INLIST: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_DONE: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_END: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_IGNORE: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_MORE: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_SKIP: u32 = 3;
 pub const TAGFILTER_AND: u32 = 0;
 pub const TAGFILTER_NOT: u32 = 1;
@@ -908,30 +923,51 @@ pub const CBD_CURRENTWRITEID: u32 = 11;
 pub const CBD_CHANGEHOOK: u32 = 12;
 pub const CBERR_OBSOLETEID: u32 = 1;
 pub const PRIMARY_CLIP: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_READ: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_WRITE: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_RWBITS: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_FSEEK: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_RSEEK: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFF_RESERVED: u32 = 4294901760;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_EOF: i32 = -1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_EOC: i32 = -2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_NOSCOPE: i32 = -3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_NOMEM: i32 = -4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_READ: i32 = -5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_WRITE: i32 = -6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_SEEK: i32 = -7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_MANGLED: i32 = -8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_SYNTAX: i32 = -9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_NOTIFF: i32 = -10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFERR_NOHOOK: i32 = -11;
 pub const IFF_RETURN2CLIENT: i32 = -12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFPARSE_SCAN: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFPARSE_STEP: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFPARSE_RAWSTEP: u32 = 2;
 pub const IFFSLI_ROOT: u32 = 1;
 pub const IFFSLI_TOP: u32 = 2;
 pub const IFFSLI_PROP: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IFFSIZE_UNKNOWN: i32 = -1;
 pub const IFFCMD_INIT: u32 = 0;
 pub const IFFCMD_CLEANUP: u32 = 1;
@@ -1331,24 +1367,43 @@ pub const DBLPALEXTRALORESHAMLACE_KEY: u32 = 662021;
 pub const DBLPALEXTRALORESEHBLACE_KEY: u32 = 660101;
 pub const DBLPALEXTRALORESDPFLACE_KEY: u32 = 660997;
 pub const DBLPALEXTRALORESDPF2LACE_KEY: u32 = 661061;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_DIPFMustHave: u32 = 2147483649;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_DIPFMustNotHave: u32 = 2147483650;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_ViewPort: u32 = 2147483651;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_NominalWidth: u32 = 2147483652;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_NominalHeight: u32 = 2147483653;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_DesiredWidth: u32 = 2147483654;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_DesiredHeight: u32 = 2147483655;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_Depth: u32 = 2147483656;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_MonitorID: u32 = 2147483657;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_SourceID: u32 = 2147483658;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_RedBits: u32 = 2147483659;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_BlueBits: u32 = 2147483660;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_GreenBits: u32 = 2147483661;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_GfxPrivate: u32 = 2147483662;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTAG_DISP: u32 = 2147483648;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTAG_DIMS: u32 = 2147487744;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTAG_MNTR: u32 = 2147491840;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTAG_NAME: u32 = 2147495936;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTAG_VEC: u32 = 2147500032;
 pub const DI_AVAIL_NOCHIPS: u32 = 1;
 pub const DI_AVAIL_NOMONITOR: u32 = 2;
@@ -1469,7 +1524,9 @@ pub const PRECISION_EXACT: i32 = -1;
 pub const PRECISION_IMAGE: u32 = 0;
 pub const PRECISION_ICON: u32 = 16;
 pub const PRECISION_GUI: u32 = 32;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const OBP_Precision: u32 = 2214592512;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const OBP_FailIfBad: u32 = 2214592513;
 pub const MVP_OK: u32 = 0;
 pub const MVP_NO_MEM: u32 = 1;
@@ -2437,24 +2494,43 @@ pub const DLT_VOLUME: u32 = 2;
 pub const DLT_LATE: u32 = 3;
 pub const DLT_NONBINDING: u32 = 4;
 pub const DLT_PRIVATE: i32 = -1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DVPB_UNLOCK: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DVPF_UNLOCK: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DVPB_ASSIGN: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DVPF_ASSIGN: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_DEVICES: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_DEVICES: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_VOLUMES: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_VOLUMES: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_ASSIGNS: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_ASSIGNS: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_ENTRY: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_ENTRY: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_DELETE: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_DELETE: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_READ: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_READ: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDB_WRITE: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_WRITE: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LDF_ALL: u32 = 28;
 pub const REPORT_STREAM: u32 = 0;
 pub const REPORT_TASK: u32 = 1;
@@ -3230,31 +3306,57 @@ pub const FORMAT_USA: u32 = 2;
 pub const FORMAT_CDN: u32 = 3;
 pub const FORMAT_DEF: u32 = 4;
 pub const FORMAT_MAX: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_DOWILD: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_DOWILD: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_ITSWILD: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_ITSWILD: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_DODIR: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_DODIR: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_DIDDIR: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_DIDDIR: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_NOMEMERR: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_NOMEMERR: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_DODOT: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_DODOT: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_DirChanged: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_DirChanged: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APB_FollowHLinks: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const APF_FollowHLinks: u32 = 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDB_PatternBit: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDF_PatternBit: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDB_ExaminedBit: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDF_ExaminedBit: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDB_Completed: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDF_Completed: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDB_AllBit: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDF_AllBit: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDB_Single: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DDF_Single: u32 = 16;
 pub const P_ANY: u32 = 128;
 pub const P_SINGLE: u32 = 129;
@@ -3324,12 +3426,19 @@ pub const EXT_RELREF32: u32 = 136;
 pub const EXT_RELCOMMON: u32 = 137;
 pub const EXT_ABSREF16: u32 = 138;
 pub const EXT_ABSREF8: u32 = 139;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_NAME: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_TYPE: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_SIZE: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_PROTECTION: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_DATE: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_COMMENT: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ED_OWNER: u32 = 7;
 pub const DE_TABLESIZE: u32 = 0;
 pub const DE_SIZEBLOCK: u32 = 1;
@@ -3357,15 +3466,25 @@ pub const ENVF_SUPERFLOPPY: u32 = 131072;
 pub const ENVF_DISABLENSD: u32 = 262144;
 pub const NOTIFY_CLASS: u32 = 1073741824;
 pub const NOTIFY_CODE: u32 = 4660;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRF_SEND_MESSAGE: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRF_SEND_SIGNAL: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRF_WAIT_REPLY: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRF_NOTIFY_INITIAL: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRF_MAGIC: u32 = 2147483648;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRB_SEND_MESSAGE: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRB_SEND_SIGNAL: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRB_WAIT_REPLY: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRB_NOTIFY_INITIAL: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NRB_MAGIC: u32 = 31;
 pub const NR_HANDLER_FLAGS: u32 = 4294901760;
 pub const REC_EXCLUSIVE: u32 = 0;
@@ -3380,15 +3499,25 @@ pub const LV_VAR: u32 = 0;
 pub const LV_ALIAS: u32 = 1;
 pub const LVB_IGNORE: u32 = 7;
 pub const LVF_IGNORE: u32 = 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVB_GLOBAL_ONLY: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVF_GLOBAL_ONLY: u32 = 256;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVB_LOCAL_ONLY: u32 = 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVF_LOCAL_ONLY: u32 = 512;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVB_BINARY_VAR: u32 = 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVF_BINARY_VAR: u32 = 1024;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVB_DONT_NULL_TERM: u32 = 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GVF_DONT_NULL_TERM: u32 = 2048;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GVB_SAVE_VAR: u32 = 12;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GVF_SAVE_VAR: u32 = 4096;
 pub const SG_DEFAULTMAXCHARS: u32 = 128;
 pub const LORIENT_NONE: u32 = 0;
@@ -3800,6 +3929,7 @@ pub const LINEENDING_ASIMPORT: u32 = 3;
 pub const PRESERVE_COLORS: u32 = 1;
 pub const AVOID_FLICKER: u32 = 2;
 pub const IGNORE_MCOMPAT: u32 = 4;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const BIDTAG_COERCE: u32 = 1;
 pub const BORDERHIT: u32 = 0;
 pub const TOPHIT: u32 = 1;
@@ -3909,93 +4039,180 @@ pub const DMAB_MASTER: u32 = 9;
 pub const DMAB_BLITHOG: u32 = 10;
 pub const DMAB_BLTDONE: u32 = 14;
 pub const DMAB_BLTNZERO: u32 = 13;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_Font: u32 = 2147483648;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_APen: u32 = 2147483650;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_BPen: u32 = 2147483651;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_DrMd: u32 = 2147483652;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_OutLinePen: u32 = 2147483653;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_OutlinePen: u32 = 2147483653;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_WriteMask: u32 = 2147483654;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_MaxPen: u32 = 2147483655;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const RPTAG_DrawBounds: u32 = 2147483656;
 pub const SPRITE_ATTACHED: u32 = 128;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_Width: u32 = 2164260864;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_XReplication: u32 = 2164260866;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_YReplication: u32 = 2164260868;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_OutputHeight: u32 = 2164260870;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_Attached: u32 = 2164260872;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const SPRITEA_OldDataFormat: u32 = 2164260874;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GSTAG_SPRITE_NUM: u32 = 2181038112;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GSTAG_ATTACHED: u32 = 2181038114;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GSTAG_SOFTSPRITE: u32 = 2181038116;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const GSTAG_SCANDOUBLED: u32 = 2197815296;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_END_CM: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMAKEY_CLR: u32 = 2147483648;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMAKEY_SET: u32 = 2147483649;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BITPLANEKEY_CLR: u32 = 2147483650;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BITPLANEKEY_SET: u32 = 2147483651;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERBLANK_CLR: u32 = 2147483652;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERBLANK_SET: u32 = 2147483653;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERNOTRANS_CLR: u32 = 2147483654;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERNOTRANS_SET: u32 = 2147483655;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMA_PEN_CLR: u32 = 2147483656;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMA_PEN_SET: u32 = 2147483657;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMA_PLANE_SET: u32 = 2147483658;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_ATTACH_CM_SET: u32 = 2147483659;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_NEXTBUF_CM: u32 = 2147483660;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_CM_CLR: u32 = 2147483661;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_CM_SET: u32 = 2147483662;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_NORMAL_DISP_GET: u32 = 2147483663;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_NORMAL_DISP_SET: u32 = 2147483664;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_COERCE_DISP_GET: u32 = 2147483665;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_COERCE_DISP_SET: u32 = 2147483666;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_VIEWPORTEXTRA_GET: u32 = 2147483667;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_VIEWPORTEXTRA_SET: u32 = 2147483668;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMAKEY_GET: u32 = 2147483669;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BITPLANEKEY_GET: u32 = 2147483670;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERBLANK_GET: u32 = 2147483671;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERNOTRANS_GET: u32 = 2147483672;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMA_PEN_GET: u32 = 2147483673;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_CHROMA_PLANE_GET: u32 = 2147483674;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_ATTACH_CM_GET: u32 = 2147483675;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_CM_GET: u32 = 2147483676;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_ITEMS_GET: u32 = 2147483677;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_ITEMS_SET: u32 = 2147483678;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BATCH_ITEMS_ADD: u32 = 2147483679;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_VPMODEID_GET: u32 = 2147483680;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_VPMODEID_SET: u32 = 2147483681;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_VPMODEID_CLR: u32 = 2147483682;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_USERCLIP_GET: u32 = 2147483683;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_USERCLIP_SET: u32 = 2147483684;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_USERCLIP_CLR: u32 = 2147483685;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF1_BASE_GET: u32 = 2147483686;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF2_BASE_GET: u32 = 2147483687;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPEVEN_BASE_GET: u32 = 2147483688;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPODD_BASE_GET: u32 = 2147483689;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF1_BASE_SET: u32 = 2147483690;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF2_BASE_SET: u32 = 2147483691;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPEVEN_BASE_SET: u32 = 2147483692;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPODD_BASE_SET: u32 = 2147483693;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERSPRITE_GET: u32 = 2147483694;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERSPRITE_SET: u32 = 2147483695;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_BORDERSPRITE_CLR: u32 = 2147483696;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPRITERESN_SET: u32 = 2147483697;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_SPRITERESN_GET: u32 = 2147483698;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF1_TO_SPRITEPRI_SET: u32 = 2147483699;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF1_TO_SPRITEPRI_GET: u32 = 2147483700;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF2_TO_SPRITEPRI_SET: u32 = 2147483701;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_PF2_TO_SPRITEPRI_GET: u32 = 2147483702;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_IMMEDIATE: u32 = 2147483703;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_FULLPALETTE_SET: u32 = 2147483704;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_FULLPALETTE_GET: u32 = 2147483705;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_FULLPALETTE_CLR: u32 = 2147483706;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_DEFSPRITERESN_SET: u32 = 2147483707;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VTAG_DEFSPRITERESN_GET: u32 = 2147483708;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_IntermediateCLUpdate: u32 = 2147483776;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_IntermediateCLUpdate_Query: u32 = 2147483777;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_NoColorPaletteLoad: u32 = 2147483778;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_NoColorPaletteLoad_Query: u32 = 2147483779;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_DUALPF_Disable: u32 = 2147483780;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const VC_DUALPF_Disable_Query: u32 = 2147483781;
 pub const ADKB_SETCLR: u32 = 15;
 pub const ADKB_PRECOMP1: u32 = 14;
@@ -4430,26 +4647,47 @@ pub const HTNF_RESERVED3: u32 = 16;
 pub const HTNF_CLEAN: u32 = 32;
 pub const HTNF_DONE: u32 = 64;
 pub const AslName: &[u8; 12] = b"asl.library\0";
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FileRequest: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FontRequest: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_ScreenModeRequest: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_FILTERFUNC: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_INTUIFUNC: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_DOSAVEMODE: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_PRIVATEIDCMP: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_DOMULTISELECT: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_DOPATTERNS: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_FILTERFUNC: u32 = 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_INTUIFUNC: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_DOSAVEMODE: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_PRIVATEIDCMP: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_DOMULTISELECT: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_DOPATTERNS: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_DRAWERSONLY: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_FILTERDRAWERS: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRB_REJECTICONS: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_DRAWERSONLY: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_FILTERDRAWERS: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FRF_REJECTICONS: u32 = 4;
 pub const ASLFRSORTBY_Name: u32 = 0;
 pub const ASLFRSORTBY_Date: u32 = 1;
@@ -4459,25 +4697,42 @@ pub const ASLFRSORTDRAWERS_Mix: u32 = 1;
 pub const ASLFRSORTDRAWERS_Last: u32 = 2;
 pub const ASLFRSORTORDER_Ascend: u32 = 0;
 pub const ASLFRSORTORDER_Descend: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_DOFRONTPEN: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_DOBACKPEN: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_DOSTYLE: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_DODRAWMODE: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_FIXEDWIDTHONLY: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_PRIVATEIDCMP: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_INTUIFUNC: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOB_FILTERFUNC: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_DOFRONTPEN: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_DOBACKPEN: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_DOSTYLE: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_DODRAWMODE: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_FIXEDWIDTHONLY: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_PRIVATEIDCMP: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_INTUIFUNC: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FOF_FILTERFUNC: u32 = 128;
 pub const FO_SPECIALMODE_NONE: u32 = 0;
 pub const FO_SPECIALMODE_OUTLINE: u32 = 1;
 pub const FO_SPECIALMODE_SHADOW: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_SEMAPHORE_NAME: &[u8; 12] = b"asl.library\0";
 pub const ASLPOS_DefaultPosition: u32 = 0;
 pub const ASLPOS_CenterWindow: u32 = 1;
@@ -4502,9 +4757,13 @@ pub const FILF_SAVE: u32 = 32;
 pub const FILF_NEWIDCMP: u32 = 16;
 pub const FILF_MULTISELECT: u32 = 8;
 pub const FILF_PATGAD: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FIL1B_NOFILES: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FIL1B_MATCHDIRS: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FIL1F_NOFILES: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FIL1F_MATCHDIRS: u32 = 2;
 pub const FONB_FRONTCOLOR: u32 = 0;
 pub const FONB_BACKCOLOR: u32 = 1;
@@ -4514,13 +4773,21 @@ pub const FONB_FIXEDWIDTH: u32 = 4;
 pub const FONB_NEWIDCMP: u32 = 5;
 pub const FONB_DOMSGFUNC: u32 = 6;
 pub const FONB_DOWILDFUNC: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_FRONTCOLOR: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_BACKCOLOR: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_STYLES: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_DRAWMODE: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_FIXEDWIDTH: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_NEWIDCMP: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_DOMSGFUNC: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FONF_DOWILDFUNC: u32 = 128;
 pub const NB_VERSION: u32 = 5;
 pub const CBD_NAMELEN: u32 = 24;
@@ -4529,25 +4796,45 @@ pub const CBD_DESCRLEN: u32 = 40;
 pub const NBU_DUPLICATE: u32 = 0;
 pub const NBU_UNIQUE: u32 = 1;
 pub const NBU_NOTIFY: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const COF_SHOW_HIDE: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_INVALID: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_FILTER: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_TYPEFILTER: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_SEND: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_SIGNAL: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_TRANSLATE: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_BROKER: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_DEBUG: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_CUSTOM: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CX_ZERO: u32 = 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXM_IEVENT: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXM_COMMAND: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_DISABLE: u32 = 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_ENABLE: u32 = 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_APPEAR: u32 = 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_DISAPPEAR: u32 = 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_KILL: u32 = 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_LIST_CHG: u32 = 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CXCMD_UNIQUE: u32 = 25;
 pub const IX_VERSION: u32 = 2;
 pub const IXSYM_SHIFT: u32 = 1;
@@ -4657,19 +4944,33 @@ pub const EBB_SILENTSTART: u32 = 6;
 pub const EBF_SILENTSTART: u32 = 64;
 pub const EBB_START_CC0: u32 = 7;
 pub const EBF_START_CC0: u32 = 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GENERIC_KIND: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_KIND: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_KIND: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_KIND: u32 = 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_KIND: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const MX_KIND: u32 = 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NUMBER_KIND: u32 = 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CYCLE_KIND: u32 = 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_KIND: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_KIND: u32 = 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_KIND: u32 = 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRING_KIND: u32 = 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TEXT_KIND: u32 = 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NUM_KINDS: u32 = 14;
 pub const ARROWIDCMP: u32 = 4194408;
 pub const BUTTONIDCMP: u32 = 64;
@@ -4684,28 +4985,48 @@ pub const SCROLLERIDCMP: u32 = 112;
 pub const SLIDERIDCMP: u32 = 112;
 pub const STRINGIDCMP: u32 = 64;
 pub const TEXTIDCMP: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLACETEXT_LEFT: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLACETEXT_RIGHT: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLACETEXT_ABOVE: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLACETEXT_BELOW: u32 = 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLACETEXT_IN: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NG_HIGHLABEL: u32 = 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NG_GRIDLAYOUT: u32 = 128;
 pub const MENU_IMAGE: u32 = 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_TITLE: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_ITEM: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_SUB: u32 = 3;
 pub const IM_ITEM: u32 = 130;
 pub const IM_SUB: u32 = 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_END: u32 = 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_IGNORE: u32 = 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_MENUDISABLED: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_ITEMDISABLED: u32 = 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_COMMANDSTRING: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NM_FLAGMASK: i32 = -199;
+#[cfg(not(feature = "kickstart-v38"))]
 pub const NM_FLAGMASK_V39: i32 = -195;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMENU_TRIMMED: u32 = 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMENU_INVALID: u32 = 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMENU_NOMEM: u32 = 3;
 pub const MX_WIDTH: u32 = 17;
 pub const MX_HEIGHT: u32 = 9;
@@ -4721,6 +5042,7 @@ pub const BBFT_DISPLAY: u32 = 6;
 pub const BBFT_CTXTFRAME: u32 = 7;
 pub const INTERWIDTH: u32 = 8;
 pub const INTERHEIGHT: u32 = 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NWAY_KIND: u32 = 7;
 pub const NWAYIDCMP: u32 = 64;
 pub const GADTOOLBIT: u32 = 32768;
@@ -5752,6 +6074,7 @@ const _: () = {
     ["Offset of field: Task::tc_MemEntry"][::core::mem::offset_of!(Task, tc_MemEntry) - 74usize];
     ["Offset of field: Task::tc_UserData"][::core::mem::offset_of!(Task, tc_UserData) - 88usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct StackSwapStruct {
@@ -5759,6 +6082,7 @@ pub struct StackSwapStruct {
     pub stk_Upper: ULONG,
     pub stk_Pointer: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of StackSwapStruct"][::core::mem::size_of::<StackSwapStruct>() - 12usize];
@@ -6315,6 +6639,7 @@ const _: () = {
         [::core::mem::offset_of!(InfoData, id_VolumeNode) - 28usize];
     ["Offset of field: InfoData::id_InUse"][::core::mem::offset_of!(InfoData, id_InUse) - 32usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CSource {
@@ -6322,6 +6647,7 @@ pub struct CSource {
     pub CS_Length: LONG,
     pub CS_CurChr: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CSource"][::core::mem::size_of::<CSource>() - 12usize];
@@ -6330,6 +6656,7 @@ const _: () = {
     ["Offset of field: CSource::CS_Length"][::core::mem::offset_of!(CSource, CS_Length) - 4usize];
     ["Offset of field: CSource::CS_CurChr"][::core::mem::offset_of!(CSource, CS_CurChr) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct RDArgs {
@@ -6340,6 +6667,7 @@ pub struct RDArgs {
     pub RDA_ExtHelp: STRPTR,
     pub RDA_Flags: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of RDArgs"][::core::mem::size_of::<RDArgs>() - 32usize];
@@ -6390,6 +6718,7 @@ const _: () = {
     ["Offset of field: RexxArg::ra_Hash"][::core::mem::offset_of!(RexxArg, ra_Hash) - 7usize];
     ["Offset of field: RexxArg::ra_Buff"][::core::mem::offset_of!(RexxArg, ra_Buff) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct RexxMsg {
@@ -6407,6 +6736,7 @@ pub struct RexxMsg {
     pub rm_Stdout: LONG,
     pub rm_avail: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of RexxMsg"][::core::mem::size_of::<RexxMsg>() - 128usize];
@@ -6642,6 +6972,7 @@ const _: () = {
     ["Offset of field: Hook::h_Data"][::core::mem::offset_of!(Hook, h_Data) - 16usize];
 };
 pub type HOOKFUNC = FPTR;
+#[cfg(not(feature = "kickstart-v39"))]
 pub type Object = ULONG;
 pub type ClassID = STRPTR;
 #[repr(C)]
@@ -6656,7 +6987,9 @@ const _: () = {
     ["Offset of field: _bindgen_ty_1::MethodID"]
         [::core::mem::offset_of!(_bindgen_ty_1, MethodID) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 pub type Msg = *mut _bindgen_ty_1;
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct opSet {
@@ -6664,6 +6997,7 @@ pub struct opSet {
     pub ops_AttrList: *mut TagItem,
     pub ops_GInfo: *mut GadgetInfo,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of opSet"][::core::mem::size_of::<opSet>() - 12usize];
@@ -6672,6 +7006,7 @@ const _: () = {
     ["Offset of field: opSet::ops_AttrList"][::core::mem::offset_of!(opSet, ops_AttrList) - 4usize];
     ["Offset of field: opSet::ops_GInfo"][::core::mem::offset_of!(opSet, ops_GInfo) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct opUpdate {
@@ -6680,6 +7015,7 @@ pub struct opUpdate {
     pub opu_GInfo: *mut GadgetInfo,
     pub opu_Flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of opUpdate"][::core::mem::size_of::<opUpdate>() - 16usize];
@@ -6720,12 +7056,14 @@ const _: () = {
     ["Offset of field: opAddTail::opat_List"]
         [::core::mem::offset_of!(opAddTail, opat_List) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct opMember {
     pub MethodID: ULONG,
     pub opam_Object: *mut Object,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of opMember"][::core::mem::size_of::<opMember>() - 8usize];
@@ -7051,6 +7389,7 @@ const _: () = {
     ["Offset of field: ClipHookMsg::chm_ClipID"]
         [::core::mem::offset_of!(ClipHookMsg, chm_ClipID) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct IFFHandle {
@@ -7058,6 +7397,7 @@ pub struct IFFHandle {
     pub iff_Flags: ULONG,
     pub iff_Depth: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of IFFHandle"][::core::mem::size_of::<IFFHandle>() - 12usize];
@@ -7087,6 +7427,7 @@ const _: () = {
     ["Offset of field: IFFStreamCmd::sc_NBytes"]
         [::core::mem::offset_of!(IFFStreamCmd, sc_NBytes) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ContextNode {
@@ -7096,6 +7437,7 @@ pub struct ContextNode {
     pub cn_Size: LONG,
     pub cn_Scan: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ContextNode"][::core::mem::size_of::<ContextNode>() - 24usize];
@@ -7110,6 +7452,7 @@ const _: () = {
     ["Offset of field: ContextNode::cn_Scan"]
         [::core::mem::offset_of!(ContextNode, cn_Scan) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct LocalContextItem {
@@ -7118,6 +7461,7 @@ pub struct LocalContextItem {
     pub lci_Type: ULONG,
     pub lci_Ident: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of LocalContextItem"][::core::mem::size_of::<LocalContextItem>() - 20usize];
@@ -7131,12 +7475,14 @@ const _: () = {
     ["Offset of field: LocalContextItem::lci_Ident"]
         [::core::mem::offset_of!(LocalContextItem, lci_Ident) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct StoredProperty {
     pub sp_Size: LONG,
     pub sp_Data: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of StoredProperty"][::core::mem::size_of::<StoredProperty>() - 8usize];
@@ -7146,6 +7492,7 @@ const _: () = {
     ["Offset of field: StoredProperty::sp_Data"]
         [::core::mem::offset_of!(StoredProperty, sp_Data) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CollectionItem {
@@ -7153,6 +7500,7 @@ pub struct CollectionItem {
     pub ci_Size: LONG,
     pub ci_Data: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CollectionItem"][::core::mem::size_of::<CollectionItem>() - 12usize];
@@ -7164,6 +7512,7 @@ const _: () = {
     ["Offset of field: CollectionItem::ci_Data"]
         [::core::mem::offset_of!(CollectionItem, ci_Data) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ClipboardHandle {
@@ -7171,6 +7520,7 @@ pub struct ClipboardHandle {
     pub cbh_CBport: MsgPort,
     pub cbh_SatisfyPort: MsgPort,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ClipboardHandle"][::core::mem::size_of::<ClipboardHandle>() - 120usize];
@@ -7221,6 +7571,7 @@ const _: () = {
     ["Offset of field: DataTypeHeader::dth_Priority"]
         [::core::mem::offset_of!(DataTypeHeader, dth_Priority) - 30usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DTHookContext {
@@ -7235,6 +7586,7 @@ pub struct DTHookContext {
     pub dthc_Buffer: STRPTR,
     pub dthc_BufferLength: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DTHookContext"][::core::mem::size_of::<DTHookContext>() - 40usize];
@@ -7275,6 +7627,7 @@ const _: () = {
     ["Offset of field: Tool::tn_Flags"][::core::mem::offset_of!(Tool, tn_Flags) - 2usize];
     ["Offset of field: Tool::tn_Program"][::core::mem::offset_of!(Tool, tn_Program) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DataType {
@@ -7286,6 +7639,7 @@ pub struct DataType {
     pub dtn_AttrList: *mut TagItem,
     pub dtn_Length: ULONG,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DataType"][::core::mem::size_of::<DataType>() - 58usize];
@@ -7847,6 +8201,7 @@ const _: () = {
     ["Offset of field: SpecialMonitor::vsync"]
         [::core::mem::offset_of!(SpecialMonitor, vsync) - 54usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 pub type DisplayInfoHandle = APTR;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -7868,6 +8223,7 @@ const _: () = {
     ["Offset of field: QueryHeader::Length"]
         [::core::mem::offset_of!(QueryHeader, Length) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DisplayInfo {
@@ -7886,6 +8242,7 @@ pub struct DisplayInfo {
     pub pad2: [UBYTE; 5usize],
     pub reserved: [ULONG; 2usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DisplayInfo"][::core::mem::size_of::<DisplayInfo>() - 56usize];
@@ -7963,6 +8320,7 @@ const _: () = {
     ["Offset of field: DimensionInfo::reserved"]
         [::core::mem::offset_of!(DimensionInfo, reserved) - 80usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct MonitorInfo {
@@ -7981,6 +8339,7 @@ pub struct MonitorInfo {
     pub PreferredModeID: ULONG,
     pub reserved: [ULONG; 2usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of MonitorInfo"][::core::mem::size_of::<MonitorInfo>() - 96usize];
@@ -8011,6 +8370,7 @@ const _: () = {
     ["Offset of field: MonitorInfo::reserved"]
         [::core::mem::offset_of!(MonitorInfo, reserved) - 88usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NameInfo {
@@ -8018,6 +8378,7 @@ pub struct NameInfo {
     pub Name: [UBYTE; 32usize],
     pub reserved: [ULONG; 2usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NameInfo"][::core::mem::size_of::<NameInfo>() - 56usize];
@@ -8026,6 +8387,7 @@ const _: () = {
     ["Offset of field: NameInfo::Name"][::core::mem::offset_of!(NameInfo, Name) - 16usize];
     ["Offset of field: NameInfo::reserved"][::core::mem::offset_of!(NameInfo, reserved) - 48usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct VecInfo {
@@ -8036,6 +8398,7 @@ pub struct VecInfo {
     pub pad: [UWORD; 3usize],
     pub reserved: [ULONG; 2usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of VecInfo"][::core::mem::size_of::<VecInfo>() - 40usize];
@@ -8372,6 +8735,7 @@ const _: () = {
     ["Offset of field: View::DxOffset"][::core::mem::offset_of!(View, DxOffset) - 14usize];
     ["Offset of field: View::Modes"][::core::mem::offset_of!(View, Modes) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ViewExtra {
@@ -8380,6 +8744,7 @@ pub struct ViewExtra {
     pub Monitor: *mut MonitorSpec,
     pub TopLine: UWORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ViewExtra"][::core::mem::size_of::<ViewExtra>() - 34usize];
@@ -8538,6 +8903,7 @@ const _: () = {
     ["Offset of field: PaletteExtra::pe_SharableColors"]
         [::core::mem::offset_of!(PaletteExtra, pe_SharableColors) - 66usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DBufInfo {
@@ -8556,6 +8922,7 @@ pub struct DBufInfo {
     pub dbi_BeamPos1: UWORD,
     pub dbi_BeamPos2: UWORD,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DBufInfo"][::core::mem::size_of::<DBufInfo>() - 84usize];
@@ -8978,6 +9345,7 @@ const _: () = {
     ["Offset of field: ColorTextFont::ctf_CharData"]
         [::core::mem::offset_of!(ColorTextFont, ctf_CharData) - 64usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct TextExtent {
@@ -8985,6 +9353,7 @@ pub struct TextExtent {
     pub te_Height: UWORD,
     pub te_Extent: Rectangle,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of TextExtent"][::core::mem::size_of::<TextExtent>() - 12usize];
@@ -9054,12 +9423,14 @@ const _: () = {
 };
 pub type TimeVal_Type = timeval;
 pub type TimeRequest_Type = timerequest;
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct EClockVal {
     pub ev_hi: ULONG,
     pub ev_lo: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of EClockVal"][::core::mem::size_of::<EClockVal>() - 8usize];
@@ -10018,6 +10389,7 @@ const _: () = {
     ["Offset of field: ExtNewWindow::Extension"]
         [::core::mem::offset_of!(ExtNewWindow, Extension) - 48usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DrawInfo {
@@ -10033,12 +10405,14 @@ pub struct DrawInfo {
     pub dri_Screen: *mut Screen,
     pub dri_Reserved: [ULONG; 4usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DrawInfo__bindgen_ty_1 {
     pub X: UWORD,
     pub Y: UWORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DrawInfo__bindgen_ty_1"][::core::mem::size_of::<DrawInfo__bindgen_ty_1>() - 4usize];
@@ -10049,6 +10423,7 @@ const _: () = {
     ["Offset of field: DrawInfo__bindgen_ty_1::Y"]
         [::core::mem::offset_of!(DrawInfo__bindgen_ty_1, Y) - 2usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DrawInfo"][::core::mem::size_of::<DrawInfo>() - 50usize];
@@ -10268,12 +10643,14 @@ const _: () = {
     ["Offset of field: PubScreenNode::psn_SigBit"]
         [::core::mem::offset_of!(PubScreenNode, psn_SigBit) - 28usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ScreenBuffer {
     pub sb_BitMap: *mut BitMap,
     pub sb_DBufInfo: *mut DBufInfo,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ScreenBuffer"][::core::mem::size_of::<ScreenBuffer>() - 8usize];
@@ -10491,6 +10868,7 @@ const _: () = {
     ["Offset of field: ColorSpec::Green"][::core::mem::offset_of!(ColorSpec, Green) - 4usize];
     ["Offset of field: ColorSpec::Blue"][::core::mem::offset_of!(ColorSpec, Blue) - 6usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct EasyStruct {
@@ -10500,6 +10878,7 @@ pub struct EasyStruct {
     pub es_TextFormat: CONST_STRPTR,
     pub es_GadgetFormat: CONST_STRPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of EasyStruct"][::core::mem::size_of::<EasyStruct>() - 20usize];
@@ -10799,6 +11178,7 @@ const _: () = {
     ["Offset of field: IOPrtErrReq::io_Hook"]
         [::core::mem::offset_of!(IOPrtErrReq, io_Hook) - 32usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct PrtErrMsg {
@@ -10809,6 +11189,7 @@ pub struct PrtErrMsg {
     pub pe_IDCMP: *mut ULONG,
     pub pe_ArgList: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of PrtErrMsg"][::core::mem::size_of::<PrtErrMsg>() - 24usize];
@@ -11209,6 +11590,7 @@ const _: () = {
         [::core::mem::offset_of!(RootNode, rn_ShellSegment) - 48usize];
     ["Offset of field: RootNode::rn_Flags"][::core::mem::offset_of!(RootNode, rn_Flags) - 52usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CliProcList {
@@ -11216,6 +11598,7 @@ pub struct CliProcList {
     pub cpl_First: LONG,
     pub cpl_Array: *mut *mut MsgPort,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CliProcList"][::core::mem::size_of::<CliProcList>() - 16usize];
@@ -11401,6 +11784,7 @@ const _: () = {
         [::core::mem::offset_of!(DevInfo, dvi_GlobVec) - 36usize];
     ["Offset of field: DevInfo::dvi_Name"][::core::mem::offset_of!(DevInfo, dvi_Name) - 40usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct DosList {
@@ -11411,6 +11795,7 @@ pub struct DosList {
     pub dol_misc: DosList__bindgen_ty_1,
     pub dol_Name: BSTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union DosList__bindgen_ty_1 {
@@ -11418,6 +11803,7 @@ pub union DosList__bindgen_ty_1 {
     pub dol_volume: DosList__bindgen_ty_1__bindgen_ty_2,
     pub dol_assign: DosList__bindgen_ty_1__bindgen_ty_3,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DosList__bindgen_ty_1__bindgen_ty_1 {
@@ -11428,6 +11814,7 @@ pub struct DosList__bindgen_ty_1__bindgen_ty_1 {
     pub dol_SegList: BPTR,
     pub dol_GlobVec: BPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DosList__bindgen_ty_1__bindgen_ty_1"]
@@ -11447,6 +11834,7 @@ const _: () = {
     ["Offset of field: DosList__bindgen_ty_1__bindgen_ty_1::dol_GlobVec"]
         [::core::mem::offset_of!(DosList__bindgen_ty_1__bindgen_ty_1, dol_GlobVec) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DosList__bindgen_ty_1__bindgen_ty_2 {
@@ -11454,6 +11842,7 @@ pub struct DosList__bindgen_ty_1__bindgen_ty_2 {
     pub dol_LockList: BPTR,
     pub dol_DiskType: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DosList__bindgen_ty_1__bindgen_ty_2"]
@@ -11467,12 +11856,14 @@ const _: () = {
     ["Offset of field: DosList__bindgen_ty_1__bindgen_ty_2::dol_DiskType"]
         [::core::mem::offset_of!(DosList__bindgen_ty_1__bindgen_ty_2, dol_DiskType) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DosList__bindgen_ty_1__bindgen_ty_3 {
     pub dol_AssignName: STRPTR,
     pub dol_List: *mut AssignList,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DosList__bindgen_ty_1__bindgen_ty_3"]
@@ -11484,6 +11875,7 @@ const _: () = {
     ["Offset of field: DosList__bindgen_ty_1__bindgen_ty_3::dol_List"]
         [::core::mem::offset_of!(DosList__bindgen_ty_1__bindgen_ty_3, dol_List) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DosList__bindgen_ty_1"][::core::mem::size_of::<DosList__bindgen_ty_1>() - 24usize];
@@ -11496,6 +11888,7 @@ const _: () = {
     ["Offset of field: DosList__bindgen_ty_1::dol_assign"]
         [::core::mem::offset_of!(DosList__bindgen_ty_1, dol_assign) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DosList"][::core::mem::size_of::<DosList>() - 44usize];
@@ -11507,12 +11900,14 @@ const _: () = {
     ["Offset of field: DosList::dol_misc"][::core::mem::offset_of!(DosList, dol_misc) - 16usize];
     ["Offset of field: DosList::dol_Name"][::core::mem::offset_of!(DosList, dol_Name) - 40usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AssignList {
     pub al_Next: *mut AssignList,
     pub al_Lock: BPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AssignList"][::core::mem::size_of::<AssignList>() - 8usize];
@@ -11520,6 +11915,7 @@ const _: () = {
     ["Offset of field: AssignList::al_Next"][::core::mem::offset_of!(AssignList, al_Next) - 0usize];
     ["Offset of field: AssignList::al_Lock"][::core::mem::offset_of!(AssignList, al_Lock) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DevProc {
@@ -11528,6 +11924,7 @@ pub struct DevProc {
     pub dvp_Flags: ULONG,
     pub dvp_DevNode: *mut DosList,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DevProc"][::core::mem::size_of::<DevProc>() - 16usize];
@@ -11855,6 +12252,7 @@ const _: () = {
     ["Offset of field: DTSpecialInfo::si_HorizUnit"]
         [::core::mem::offset_of!(DTSpecialInfo, si_HorizUnit) - 86usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DTMethod {
@@ -11862,6 +12260,7 @@ pub struct DTMethod {
     pub dtm_Command: STRPTR,
     pub dtm_Method: ULONG,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DTMethod"][::core::mem::size_of::<DTMethod>() - 12usize];
@@ -11928,12 +12327,14 @@ const _: () = {
     ["Offset of field: FrameInfo::fri_Flags"]
         [::core::mem::offset_of!(FrameInfo, fri_Flags) - 32usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtGeneral {
     pub MethodID: ULONG,
     pub dtg_GInfo: *mut GadgetInfo,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtGeneral"][::core::mem::size_of::<dtGeneral>() - 8usize];
@@ -11942,6 +12343,7 @@ const _: () = {
     ["Offset of field: dtGeneral::dtg_GInfo"]
         [::core::mem::offset_of!(dtGeneral, dtg_GInfo) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtSelect {
@@ -11949,6 +12351,7 @@ pub struct dtSelect {
     pub dts_GInfo: *mut GadgetInfo,
     pub dts_Select: Rectangle,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtSelect"][::core::mem::size_of::<dtSelect>() - 16usize];
@@ -11958,6 +12361,7 @@ const _: () = {
     ["Offset of field: dtSelect::dts_Select"]
         [::core::mem::offset_of!(dtSelect, dts_Select) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtFrameBox {
@@ -11968,6 +12372,7 @@ pub struct dtFrameBox {
     pub dtf_SizeFrameInfo: ULONG,
     pub dtf_FrameFlags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtFrameBox"][::core::mem::size_of::<dtFrameBox>() - 24usize];
@@ -11985,6 +12390,7 @@ const _: () = {
     ["Offset of field: dtFrameBox::dtf_FrameFlags"]
         [::core::mem::offset_of!(dtFrameBox, dtf_FrameFlags) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtGoto {
@@ -11993,6 +12399,7 @@ pub struct dtGoto {
     pub dtg_NodeName: STRPTR,
     pub dtg_AttrList: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtGoto"][::core::mem::size_of::<dtGoto>() - 16usize];
@@ -12004,6 +12411,7 @@ const _: () = {
     ["Offset of field: dtGoto::dtg_AttrList"]
         [::core::mem::offset_of!(dtGoto, dtg_AttrList) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtTrigger {
@@ -12012,6 +12420,7 @@ pub struct dtTrigger {
     pub dtt_Function: ULONG,
     pub dtt_Data: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtTrigger"][::core::mem::size_of::<dtTrigger>() - 16usize];
@@ -12039,6 +12448,7 @@ const _: () = {
     ["Offset of field: printerIO::iodrp"][::core::mem::offset_of!(printerIO, iodrp) - 0usize];
     ["Offset of field: printerIO::iopc"][::core::mem::offset_of!(printerIO, iopc) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtPrint {
@@ -12047,6 +12457,7 @@ pub struct dtPrint {
     pub dtp_PIO: *mut printerIO,
     pub dtp_AttrList: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtPrint"][::core::mem::size_of::<dtPrint>() - 16usize];
@@ -12087,6 +12498,7 @@ const _: () = {
     ["Offset of field: dtDraw::dtd_AttrList"]
         [::core::mem::offset_of!(dtDraw, dtd_AttrList) - 32usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct dtWrite {
@@ -12096,6 +12508,7 @@ pub struct dtWrite {
     pub dtw_Mode: ULONG,
     pub dtw_AttrList: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of dtWrite"][::core::mem::size_of::<dtWrite>() - 20usize];
@@ -12226,6 +12639,7 @@ const _: () = {
     ["Offset of field: BitMapHeader::bmh_PageHeight"]
         [::core::mem::offset_of!(BitMapHeader, bmh_PageHeight) - 18usize];
 };
+#[cfg(not(feature = "kickstart-v40"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 #[repr(align(2))]
@@ -12234,6 +12648,7 @@ pub struct ColorRegister {
     pub green: UBYTE,
     pub blue: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v40"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ColorRegister"][::core::mem::size_of::<ColorRegister>() - 4usize];
@@ -13580,6 +13995,7 @@ const _: () = {
     ["Offset of field: TrackFileChecksum::tfc_low"]
         [::core::mem::offset_of!(TrackFileChecksum, tfc_low) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v46"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct TrackFileUnitData {
@@ -13604,6 +14020,7 @@ pub struct TrackFileUnitData {
     pub tfud_CacheAccesses: ULONG,
     pub tfud_CacheMisses: ULONG,
 }
+#[cfg(not(feature = "kickstart-v46"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of TrackFileUnitData"][::core::mem::size_of::<TrackFileUnitData>() - 106usize];
@@ -13776,6 +14193,7 @@ const _: () = {
     ["Offset of field: AvailFontsHeader::afh_NumEntries"]
         [::core::mem::offset_of!(AvailFontsHeader, afh_NumEntries) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v46"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct EGlyphEngine {
@@ -13783,6 +14201,7 @@ pub struct EGlyphEngine {
     pub ege_BulletBase: *mut Library,
     pub ege_GlyphEngine: *mut GlyphEngine,
 }
+#[cfg(not(feature = "kickstart-v46"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of EGlyphEngine"][::core::mem::size_of::<EGlyphEngine>() - 12usize];
@@ -13794,6 +14213,7 @@ const _: () = {
     ["Offset of field: EGlyphEngine::ege_GlyphEngine"]
         [::core::mem::offset_of!(EGlyphEngine, ege_GlyphEngine) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v46"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct OutlineFont {
@@ -13805,6 +14225,7 @@ pub struct OutlineFont {
     pub olf_Reserved: APTR,
     pub olf_UserData: APTR,
 }
+#[cfg(not(feature = "kickstart-v46"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of OutlineFont"][::core::mem::size_of::<OutlineFont>() - 36usize];
@@ -13824,12 +14245,14 @@ const _: () = {
     ["Offset of field: OutlineFont::olf_UserData"]
         [::core::mem::offset_of!(OutlineFont, olf_UserData) - 32usize];
 };
+#[cfg(not(feature = "kickstart-v37"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GlyphEngine {
     pub gle_Library: *mut Library,
     pub gle_Name: STRPTR,
 }
+#[cfg(not(feature = "kickstart-v37"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GlyphEngine"][::core::mem::size_of::<GlyphEngine>() - 8usize];
@@ -13926,6 +14349,7 @@ const _: () = {
     ["Offset of field: GlyphWidthEntry32::gwe32_Code"]
         [::core::mem::offset_of!(GlyphWidthEntry32, gwe32_Code) - 14usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DateTime {
@@ -13936,6 +14360,7 @@ pub struct DateTime {
     pub dat_StrDate: STRPTR,
     pub dat_StrTime: STRPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DateTime"][::core::mem::size_of::<DateTime>() - 26usize];
@@ -13952,6 +14377,7 @@ const _: () = {
     ["Offset of field: DateTime::dat_StrTime"]
         [::core::mem::offset_of!(DateTime, dat_StrTime) - 22usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AnchorPath {
@@ -13965,6 +14391,7 @@ pub struct AnchorPath {
     pub ap_Info: FileInfoBlock,
     pub ap_Buf: [TEXT; 1usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AnchorPath"][::core::mem::size_of::<AnchorPath>() - 282usize];
@@ -13985,6 +14412,7 @@ const _: () = {
         [::core::mem::offset_of!(AnchorPath, ap_Info) - 20usize];
     ["Offset of field: AnchorPath::ap_Buf"][::core::mem::offset_of!(AnchorPath, ap_Buf) - 280usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AChain {
@@ -13995,6 +14423,7 @@ pub struct AChain {
     pub an_Flags: BYTE,
     pub an_String: [TEXT; 1usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AChain"][::core::mem::size_of::<AChain>() - 274usize];
@@ -14006,6 +14435,7 @@ const _: () = {
     ["Offset of field: AChain::an_Flags"][::core::mem::offset_of!(AChain, an_Flags) - 272usize];
     ["Offset of field: AChain::an_String"][::core::mem::offset_of!(AChain, an_String) - 273usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ExAllData {
@@ -14021,6 +14451,7 @@ pub struct ExAllData {
     pub ed_OwnerUID: UWORD,
     pub ed_OwnerGID: UWORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ExAllData"][::core::mem::size_of::<ExAllData>() - 40usize];
@@ -14041,6 +14472,7 @@ const _: () = {
     ["Offset of field: ExAllData::ed_OwnerGID"]
         [::core::mem::offset_of!(ExAllData, ed_OwnerGID) - 38usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ExAllControl {
@@ -14049,6 +14481,7 @@ pub struct ExAllControl {
     pub eac_MatchString: STRPTR,
     pub eac_MatchFunc: *mut Hook,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ExAllControl"][::core::mem::size_of::<ExAllControl>() - 16usize];
@@ -14188,6 +14621,7 @@ const _: () = {
     ["Offset of field: DeviceNode::dn_Name"]
         [::core::mem::offset_of!(DeviceNode, dn_Name) - 40usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NotifyMessage {
@@ -14198,6 +14632,7 @@ pub struct NotifyMessage {
     pub nm_DoNotTouch: ULONG,
     pub nm_DoNotTouch2: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NotifyMessage"][::core::mem::size_of::<NotifyMessage>() - 38usize];
@@ -14215,6 +14650,7 @@ const _: () = {
     ["Offset of field: NotifyMessage::nm_DoNotTouch2"]
         [::core::mem::offset_of!(NotifyMessage, nm_DoNotTouch2) - 34usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub struct NotifyRequest {
@@ -14227,17 +14663,20 @@ pub struct NotifyRequest {
     pub nr_MsgCount: ULONG,
     pub nr_Handler: *mut MsgPort,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Copy, Clone)]
 pub union NotifyRequest__bindgen_ty_1 {
     pub nr_Msg: NotifyRequest__bindgen_ty_1__bindgen_ty_1,
     pub nr_Signal: NotifyRequest__bindgen_ty_1__bindgen_ty_2,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NotifyRequest__bindgen_ty_1__bindgen_ty_1 {
     pub nr_Port: *mut MsgPort,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NotifyRequest__bindgen_ty_1__bindgen_ty_1"]
@@ -14247,6 +14686,7 @@ const _: () = {
     ["Offset of field: NotifyRequest__bindgen_ty_1__bindgen_ty_1::nr_Port"]
         [::core::mem::offset_of!(NotifyRequest__bindgen_ty_1__bindgen_ty_1, nr_Port) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NotifyRequest__bindgen_ty_1__bindgen_ty_2 {
@@ -14254,6 +14694,7 @@ pub struct NotifyRequest__bindgen_ty_1__bindgen_ty_2 {
     pub nr_SignalNum: UBYTE,
     pub nr_pad: [UBYTE; 3usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NotifyRequest__bindgen_ty_1__bindgen_ty_2"]
@@ -14267,6 +14708,7 @@ const _: () = {
     ["Offset of field: NotifyRequest__bindgen_ty_1__bindgen_ty_2::nr_pad"]
         [::core::mem::offset_of!(NotifyRequest__bindgen_ty_1__bindgen_ty_2, nr_pad) - 5usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NotifyRequest__bindgen_ty_1"]
@@ -14278,6 +14720,7 @@ const _: () = {
     ["Offset of field: NotifyRequest__bindgen_ty_1::nr_Signal"]
         [::core::mem::offset_of!(NotifyRequest__bindgen_ty_1, nr_Signal) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NotifyRequest"][::core::mem::size_of::<NotifyRequest>() - 48usize];
@@ -14299,6 +14742,7 @@ const _: () = {
     ["Offset of field: NotifyRequest::nr_Handler"]
         [::core::mem::offset_of!(NotifyRequest, nr_Handler) - 44usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct RecordLock {
@@ -14307,6 +14751,7 @@ pub struct RecordLock {
     pub rec_Length: ULONG,
     pub rec_Mode: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of RecordLock"][::core::mem::size_of::<RecordLock>() - 16usize];
@@ -14402,6 +14847,7 @@ const _: () = {
     ["Offset of field: HistoryNode::hn_Line"]
         [::core::mem::offset_of!(HistoryNode, hn_Line) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct LocalVar {
@@ -14410,6 +14856,7 @@ pub struct LocalVar {
     pub lv_Value: STRPTR,
     pub lv_Len: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of LocalVar"][::core::mem::size_of::<LocalVar>() - 24usize];
@@ -14419,6 +14866,7 @@ const _: () = {
     ["Offset of field: LocalVar::lv_Value"][::core::mem::offset_of!(LocalVar, lv_Value) - 16usize];
     ["Offset of field: LocalVar::lv_Len"][::core::mem::offset_of!(LocalVar, lv_Len) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpHitTest {
@@ -14426,12 +14874,14 @@ pub struct gpHitTest {
     pub gpht_GInfo: *mut GadgetInfo,
     pub gpht_Mouse: gpHitTest__bindgen_ty_1,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpHitTest__bindgen_ty_1 {
     pub X: WORD,
     pub Y: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpHitTest__bindgen_ty_1"][::core::mem::size_of::<gpHitTest__bindgen_ty_1>() - 4usize];
@@ -14442,6 +14892,7 @@ const _: () = {
     ["Offset of field: gpHitTest__bindgen_ty_1::Y"]
         [::core::mem::offset_of!(gpHitTest__bindgen_ty_1, Y) - 2usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpHitTest"][::core::mem::size_of::<gpHitTest>() - 12usize];
@@ -14452,6 +14903,7 @@ const _: () = {
     ["Offset of field: gpHitTest::gpht_Mouse"]
         [::core::mem::offset_of!(gpHitTest, gpht_Mouse) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpRender {
@@ -14460,6 +14912,7 @@ pub struct gpRender {
     pub gpr_RPort: *mut RastPort,
     pub gpr_Redraw: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpRender"][::core::mem::size_of::<gpRender>() - 16usize];
@@ -14470,6 +14923,7 @@ const _: () = {
     ["Offset of field: gpRender::gpr_Redraw"]
         [::core::mem::offset_of!(gpRender, gpr_Redraw) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpInput {
@@ -14480,12 +14934,14 @@ pub struct gpInput {
     pub gpi_Mouse: gpInput__bindgen_ty_1,
     pub gpi_TabletData: *mut TabletData,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpInput__bindgen_ty_1 {
     pub X: WORD,
     pub Y: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpInput__bindgen_ty_1"][::core::mem::size_of::<gpInput__bindgen_ty_1>() - 4usize];
@@ -14496,6 +14952,7 @@ const _: () = {
     ["Offset of field: gpInput__bindgen_ty_1::Y"]
         [::core::mem::offset_of!(gpInput__bindgen_ty_1, Y) - 2usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpInput"][::core::mem::size_of::<gpInput>() - 24usize];
@@ -14509,6 +14966,7 @@ const _: () = {
     ["Offset of field: gpInput::gpi_TabletData"]
         [::core::mem::offset_of!(gpInput, gpi_TabletData) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpGoInactive {
@@ -14516,6 +14974,7 @@ pub struct gpGoInactive {
     pub gpgi_GInfo: *mut GadgetInfo,
     pub gpgi_Abort: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpGoInactive"][::core::mem::size_of::<gpGoInactive>() - 12usize];
@@ -14527,6 +14986,7 @@ const _: () = {
     ["Offset of field: gpGoInactive::gpgi_Abort"]
         [::core::mem::offset_of!(gpGoInactive, gpgi_Abort) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpLayout {
@@ -14534,6 +14994,7 @@ pub struct gpLayout {
     pub gpl_GInfo: *mut GadgetInfo,
     pub gpl_Initial: ULONG,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpLayout"][::core::mem::size_of::<gpLayout>() - 12usize];
@@ -14543,6 +15004,7 @@ const _: () = {
     ["Offset of field: gpLayout::gpl_Initial"]
         [::core::mem::offset_of!(gpLayout, gpl_Initial) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpDomain {
@@ -14553,6 +15015,7 @@ pub struct gpDomain {
     pub gpd_Domain: IBox,
     pub gpd_Attrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpDomain"][::core::mem::size_of::<gpDomain>() - 28usize];
@@ -14567,6 +15030,7 @@ const _: () = {
     ["Offset of field: gpDomain::gpd_Attrs"]
         [::core::mem::offset_of!(gpDomain, gpd_Attrs) - 24usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpKeyTest {
@@ -14575,6 +15039,7 @@ pub struct gpKeyTest {
     pub gpkt_IMsg: *mut IntuiMessage,
     pub gpkt_VanillaKey: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpKeyTest"][::core::mem::size_of::<gpKeyTest>() - 16usize];
@@ -14587,6 +15052,7 @@ const _: () = {
     ["Offset of field: gpKeyTest::gpkt_VanillaKey"]
         [::core::mem::offset_of!(gpKeyTest, gpkt_VanillaKey) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpKeyInput {
@@ -14595,6 +15061,7 @@ pub struct gpKeyInput {
     pub gpk_IEvent: *mut InputEvent,
     pub gpk_Termination: *mut LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpKeyInput"][::core::mem::size_of::<gpKeyInput>() - 16usize];
@@ -14608,6 +15075,7 @@ const _: () = {
     ["Offset of field: gpKeyInput::gpk_Termination"]
         [::core::mem::offset_of!(gpKeyInput, gpk_Termination) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpKeyGoInactive {
@@ -14615,6 +15083,7 @@ pub struct gpKeyGoInactive {
     pub gpki_GInfo: *mut GadgetInfo,
     pub gpki_Abort: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpKeyGoInactive"][::core::mem::size_of::<gpKeyGoInactive>() - 12usize];
@@ -14626,6 +15095,7 @@ const _: () = {
     ["Offset of field: gpKeyGoInactive::gpki_Abort"]
         [::core::mem::offset_of!(gpKeyGoInactive, gpki_Abort) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct impFrameBox {
@@ -14635,6 +15105,7 @@ pub struct impFrameBox {
     pub imp_DrInfo: *mut DrawInfo,
     pub imp_FrameFlags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of impFrameBox"][::core::mem::size_of::<impFrameBox>() - 20usize];
@@ -14650,6 +15121,7 @@ const _: () = {
     ["Offset of field: impFrameBox::imp_FrameFlags"]
         [::core::mem::offset_of!(impFrameBox, imp_FrameFlags) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct impDraw {
@@ -14660,12 +15132,14 @@ pub struct impDraw {
     pub imp_DrInfo: *mut DrawInfo,
     pub imp_Dimensions: impDraw__bindgen_ty_2,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct impDraw__bindgen_ty_1 {
     pub X: WORD,
     pub Y: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of impDraw__bindgen_ty_1"][::core::mem::size_of::<impDraw__bindgen_ty_1>() - 4usize];
@@ -14676,12 +15150,14 @@ const _: () = {
     ["Offset of field: impDraw__bindgen_ty_1::Y"]
         [::core::mem::offset_of!(impDraw__bindgen_ty_1, Y) - 2usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct impDraw__bindgen_ty_2 {
     pub Width: WORD,
     pub Height: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of impDraw__bindgen_ty_2"][::core::mem::size_of::<impDraw__bindgen_ty_2>() - 4usize];
@@ -14692,6 +15168,7 @@ const _: () = {
     ["Offset of field: impDraw__bindgen_ty_2::Height"]
         [::core::mem::offset_of!(impDraw__bindgen_ty_2, Height) - 2usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of impDraw"][::core::mem::size_of::<impDraw>() - 24usize];
@@ -14808,6 +15285,7 @@ const _: () = {
     ["Offset of field: impHitTest::imp_Dimensions"]
         [::core::mem::offset_of!(impHitTest, imp_Dimensions) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct impDomainFrame {
@@ -14818,6 +15296,7 @@ pub struct impDomainFrame {
     pub imp_Domain: IBox,
     pub imp_Attrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of impDomainFrame"][::core::mem::size_of::<impDomainFrame>() - 28usize];
@@ -14835,6 +15314,7 @@ const _: () = {
     ["Offset of field: impDomainFrame::imp_Attrs"]
         [::core::mem::offset_of!(impDomainFrame, imp_Attrs) - 24usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ColorWheelHSB {
@@ -14842,6 +15322,7 @@ pub struct ColorWheelHSB {
     pub cw_Saturation: ULONG,
     pub cw_Brightness: ULONG,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ColorWheelHSB"][::core::mem::size_of::<ColorWheelHSB>() - 12usize];
@@ -14853,6 +15334,7 @@ const _: () = {
     ["Offset of field: ColorWheelHSB::cw_Brightness"]
         [::core::mem::offset_of!(ColorWheelHSB, cw_Brightness) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ColorWheelRGB {
@@ -14860,6 +15342,7 @@ pub struct ColorWheelRGB {
     pub cw_Green: ULONG,
     pub cw_Blue: ULONG,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ColorWheelRGB"][::core::mem::size_of::<ColorWheelRGB>() - 12usize];
@@ -14971,6 +15454,7 @@ const _: () = {
     ["Offset of field: WeightObject::wb_Reserved2"]
         [::core::mem::offset_of!(WeightObject, wb_Reserved2) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct LayoutLimits {
@@ -14979,6 +15463,7 @@ pub struct LayoutLimits {
     pub MaxWidth: UWORD,
     pub MaxHeight: UWORD,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of LayoutLimits"][::core::mem::size_of::<LayoutLimits>() - 8usize];
@@ -14992,6 +15477,7 @@ const _: () = {
     ["Offset of field: LayoutLimits::MaxHeight"]
         [::core::mem::offset_of!(LayoutLimits, MaxHeight) - 6usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lmAddChild {
@@ -15000,6 +15486,7 @@ pub struct lmAddChild {
     pub lm_Object: *mut Object,
     pub lm_ObjectAttrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lmAddChild"][::core::mem::size_of::<lmAddChild>() - 16usize];
@@ -15013,6 +15500,7 @@ const _: () = {
     ["Offset of field: lmAddChild::lm_ObjectAttrs"]
         [::core::mem::offset_of!(lmAddChild, lm_ObjectAttrs) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lmAddImage {
@@ -15021,6 +15509,7 @@ pub struct lmAddImage {
     pub lm_Object: *mut Object,
     pub lm_ObjectAttrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lmAddImage"][::core::mem::size_of::<lmAddImage>() - 16usize];
@@ -15034,6 +15523,7 @@ const _: () = {
     ["Offset of field: lmAddImage::lm_ObjectAttrs"]
         [::core::mem::offset_of!(lmAddImage, lm_ObjectAttrs) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lmRemoveChild {
@@ -15041,6 +15531,7 @@ pub struct lmRemoveChild {
     pub lm_Window: *mut Window,
     pub lm_Object: *mut Object,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lmRemoveChild"][::core::mem::size_of::<lmRemoveChild>() - 12usize];
@@ -15052,6 +15543,7 @@ const _: () = {
     ["Offset of field: lmRemoveChild::lm_Object"]
         [::core::mem::offset_of!(lmRemoveChild, lm_Object) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lmModifyChild {
@@ -15060,6 +15552,7 @@ pub struct lmModifyChild {
     pub lm_Object: *mut Object,
     pub lm_ObjectAttrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lmModifyChild"][::core::mem::size_of::<lmModifyChild>() - 16usize];
@@ -15073,6 +15566,7 @@ const _: () = {
     ["Offset of field: lmModifyChild::lm_ObjectAttrs"]
         [::core::mem::offset_of!(lmModifyChild, lm_ObjectAttrs) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbAddNode {
@@ -15081,6 +15575,7 @@ pub struct lbAddNode {
     pub lba_Node: *mut Node,
     pub lba_NodeAttrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbAddNode"][::core::mem::size_of::<lbAddNode>() - 16usize];
@@ -15092,6 +15587,7 @@ const _: () = {
     ["Offset of field: lbAddNode::lba_NodeAttrs"]
         [::core::mem::offset_of!(lbAddNode, lba_NodeAttrs) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbRemNode {
@@ -15099,6 +15595,7 @@ pub struct lbRemNode {
     pub lbr_GInfo: *mut GadgetInfo,
     pub lbr_Node: *mut Node,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbRemNode"][::core::mem::size_of::<lbRemNode>() - 12usize];
@@ -15108,6 +15605,7 @@ const _: () = {
         [::core::mem::offset_of!(lbRemNode, lbr_GInfo) - 4usize];
     ["Offset of field: lbRemNode::lbr_Node"][::core::mem::offset_of!(lbRemNode, lbr_Node) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbEditNode {
@@ -15116,6 +15614,7 @@ pub struct lbEditNode {
     pub lbe_Node: *mut Node,
     pub lbe_NodeAttrs: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbEditNode"][::core::mem::size_of::<lbEditNode>() - 16usize];
@@ -15129,6 +15628,7 @@ const _: () = {
     ["Offset of field: lbEditNode::lbe_NodeAttrs"]
         [::core::mem::offset_of!(lbEditNode, lbe_NodeAttrs) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbSort {
@@ -15138,6 +15638,7 @@ pub struct lbSort {
     pub lbs_Direction: ULONG,
     pub lbs_CompareHook: *mut Hook,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbSort"][::core::mem::size_of::<lbSort>() - 20usize];
@@ -15150,6 +15651,7 @@ const _: () = {
     ["Offset of field: lbSort::lbs_CompareHook"]
         [::core::mem::offset_of!(lbSort, lbs_CompareHook) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbShowChildren {
@@ -15158,6 +15660,7 @@ pub struct lbShowChildren {
     pub lbsc_Node: *mut Node,
     pub lbsc_Depth: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbShowChildren"][::core::mem::size_of::<lbShowChildren>() - 14usize];
@@ -15171,6 +15674,7 @@ const _: () = {
     ["Offset of field: lbShowChildren::lbsc_Depth"]
         [::core::mem::offset_of!(lbShowChildren, lbsc_Depth) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct lbHideChildren {
@@ -15179,6 +15683,7 @@ pub struct lbHideChildren {
     pub lbhc_Node: *mut Node,
     pub lbhc_Depth: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of lbHideChildren"][::core::mem::size_of::<lbHideChildren>() - 14usize];
@@ -15192,6 +15697,7 @@ const _: () = {
     ["Offset of field: lbHideChildren::lbhc_Depth"]
         [::core::mem::offset_of!(lbHideChildren, lbhc_Depth) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct LBDrawMsg {
@@ -15201,6 +15707,7 @@ pub struct LBDrawMsg {
     pub lbdm_Bounds: Rectangle,
     pub lbdm_State: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of LBDrawMsg"][::core::mem::size_of::<LBDrawMsg>() - 24usize];
@@ -15281,6 +15788,7 @@ const _: () = {
     ["Offset of field: LBSortMsg::lbsm_Direction"]
         [::core::mem::offset_of!(LBSortMsg, lbsm_Direction) - 26usize];
 };
+#[cfg(not(feature = "kickstart-v44"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ColumnInfo {
@@ -15288,6 +15796,7 @@ pub struct ColumnInfo {
     pub ci_Title: STRPTR,
     pub ci_Flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v44"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ColumnInfo"][::core::mem::size_of::<ColumnInfo>() - 10usize];
@@ -15353,6 +15862,7 @@ const _: () = {
     ["Offset of field: ListLabelNode::lvn_Selected"]
         [::core::mem::offset_of!(ListLabelNode, lvn_Selected) - 46usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct PBoxDrawMsg {
@@ -15364,6 +15874,7 @@ pub struct PBoxDrawMsg {
     pub pbdm_Color: ULONG,
     pub pbdm_Gadget: *mut Gadget,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of PBoxDrawMsg"][::core::mem::size_of::<PBoxDrawMsg>() - 32usize];
@@ -15383,6 +15894,7 @@ const _: () = {
     ["Offset of field: PBoxDrawMsg::pbdm_Gadget"]
         [::core::mem::offset_of!(PBoxDrawMsg, pbdm_Gadget) - 28usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GadgetInfo {
@@ -15396,12 +15908,14 @@ pub struct GadgetInfo {
     pub gi_DrInfo: *mut DrawInfo,
     pub gi_Reserved: [ULONG; 6usize],
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GadgetInfo__bindgen_ty_1 {
     pub DetailPen: UBYTE,
     pub BlockPen: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GadgetInfo__bindgen_ty_1"]
@@ -15413,6 +15927,7 @@ const _: () = {
     ["Offset of field: GadgetInfo__bindgen_ty_1::BlockPen"]
         [::core::mem::offset_of!(GadgetInfo__bindgen_ty_1, BlockPen) - 1usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GadgetInfo"][::core::mem::size_of::<GadgetInfo>() - 58usize];
@@ -15449,12 +15964,14 @@ const _: () = {
     ["Offset of field: PGX::pgx_Container"][::core::mem::offset_of!(PGX, pgx_Container) - 0usize];
     ["Offset of field: PGX::pgx_NewKnob"][::core::mem::offset_of!(PGX, pgx_NewKnob) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct spGeneral {
     pub MethodID: ULONG,
     pub GInfo: GadgetInfo,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of spGeneral"][::core::mem::size_of::<spGeneral>() - 62usize];
@@ -15462,6 +15979,7 @@ const _: () = {
     ["Offset of field: spGeneral::MethodID"][::core::mem::offset_of!(spGeneral, MethodID) - 0usize];
     ["Offset of field: spGeneral::GInfo"][::core::mem::offset_of!(spGeneral, GInfo) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct spScrollRaster {
@@ -15470,6 +15988,7 @@ pub struct spScrollRaster {
     pub DeltaX: LONG,
     pub DeltaY: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of spScrollRaster"][::core::mem::size_of::<spScrollRaster>() - 16usize];
@@ -15483,6 +16002,7 @@ const _: () = {
     ["Offset of field: spScrollRaster::DeltaY"]
         [::core::mem::offset_of!(spScrollRaster, DeltaY) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct sbSetNodeAttrs {
@@ -15491,6 +16011,7 @@ pub struct sbSetNodeAttrs {
     pub sb_Node: *mut Node,
     pub sb_AttrList: *mut TagItem,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of sbSetNodeAttrs"][::core::mem::size_of::<sbSetNodeAttrs>() - 16usize];
@@ -15557,6 +16078,7 @@ const _: () = {
     ["Offset of field: ChangeListener::Reserved5"]
         [::core::mem::offset_of!(ChangeListener, Reserved5) - 28usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_ARexxCmd {
@@ -15564,6 +16086,7 @@ pub struct GP_TEXTEDITOR_ARexxCmd {
     pub GInfo: *mut GadgetInfo,
     pub command: STRPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_ARexxCmd"][::core::mem::size_of::<GP_TEXTEDITOR_ARexxCmd>() - 12usize];
@@ -15576,6 +16099,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_ARexxCmd::command"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_ARexxCmd, command) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_BlockInfo {
@@ -15586,6 +16110,7 @@ pub struct GP_TEXTEDITOR_BlockInfo {
     pub stopx: *mut ULONG,
     pub stopy: *mut ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_BlockInfo"]
@@ -15605,12 +16130,14 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_BlockInfo::stopy"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_BlockInfo, stopy) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_ClearText {
     pub MethodID: ULONG,
     pub GInfo: *mut GadgetInfo,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_ClearText"][::core::mem::size_of::<GP_TEXTEDITOR_ClearText>() - 8usize];
@@ -15621,12 +16148,14 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_ClearText::GInfo"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_ClearText, GInfo) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_ExportText {
     pub MethodID: ULONG,
     pub GInfo: *mut GadgetInfo,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_ExportText"]
@@ -15655,6 +16184,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_HandleError::errorcode"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_HandleError, errorcode) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_InsertText {
@@ -15663,6 +16193,7 @@ pub struct GP_TEXTEDITOR_InsertText {
     pub text: STRPTR,
     pub pos: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_InsertText"]
@@ -15678,6 +16209,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_InsertText::pos"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_InsertText, pos) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_MarkText {
@@ -15688,6 +16220,7 @@ pub struct GP_TEXTEDITOR_MarkText {
     pub stop_crsr_x: ULONG,
     pub stop_crsr_y: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_MarkText"][::core::mem::size_of::<GP_TEXTEDITOR_MarkText>() - 24usize];
@@ -15706,6 +16239,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_MarkText::stop_crsr_y"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_MarkText, stop_crsr_y) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_Replace {
@@ -15714,6 +16248,7 @@ pub struct GP_TEXTEDITOR_Replace {
     pub newstring: STRPTR,
     pub flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_Replace"][::core::mem::size_of::<GP_TEXTEDITOR_Replace>() - 16usize];
@@ -15728,6 +16263,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_Replace::flags"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_Replace, flags) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_Search {
@@ -15736,6 +16272,7 @@ pub struct GP_TEXTEDITOR_Search {
     pub string: STRPTR,
     pub flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_Search"][::core::mem::size_of::<GP_TEXTEDITOR_Search>() - 16usize];
@@ -15766,6 +16303,7 @@ const _: () = {
     ["Offset of field: GP_TEXTEDITOR_AddChangeListener::listener"]
         [::core::mem::offset_of!(GP_TEXTEDITOR_AddChangeListener, listener) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct GP_TEXTEDITOR_ReplaceAll {
@@ -15775,6 +16313,7 @@ pub struct GP_TEXTEDITOR_ReplaceAll {
     pub newstring: STRPTR,
     pub flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of GP_TEXTEDITOR_ReplaceAll"]
@@ -16392,6 +16931,7 @@ const _: () = {
     ["Offset of field: Region::RegionRectangle"]
         [::core::mem::offset_of!(Region, RegionRectangle) - 8usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct BitScaleArgs {
@@ -16415,6 +16955,7 @@ pub struct BitScaleArgs {
     pub bsa_Reserved1: LONG,
     pub bsa_Reserved2: LONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of BitScaleArgs"][::core::mem::size_of::<BitScaleArgs>() - 48usize];
@@ -16479,6 +17020,7 @@ const _: () = {
     ["Offset of field: SimpleSprite::y"][::core::mem::offset_of!(SimpleSprite, y) - 8usize];
     ["Offset of field: SimpleSprite::num"][::core::mem::offset_of!(SimpleSprite, num) - 10usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ExtSprite {
@@ -16486,6 +17028,7 @@ pub struct ExtSprite {
     pub es_wordwidth: UWORD,
     pub es_flags: UWORD,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ExtSprite"][::core::mem::size_of::<ExtSprite>() - 16usize];
@@ -16679,6 +17222,7 @@ const _: () = {
     ["Offset of field: StringExtend::Reserved"]
         [::core::mem::offset_of!(StringExtend, Reserved) - 20usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct SGWork {
@@ -16696,6 +17240,7 @@ pub struct SGWork {
     pub GadgetInfo: *mut GadgetInfo,
     pub EditOp: UWORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of SGWork"][::core::mem::size_of::<SGWork>() - 44usize];
@@ -16715,6 +17260,7 @@ const _: () = {
     ["Offset of field: SGWork::EditOp"][::core::mem::offset_of!(SGWork, EditOp) - 42usize];
 };
 pub type AMIGAGUIDECONTEXT = *mut ::core::ffi::c_void;
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AmigaGuideMsg {
@@ -16728,6 +17274,7 @@ pub struct AmigaGuideMsg {
     pub agm_System1: APTR,
     pub agm_System2: APTR,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AmigaGuideMsg"][::core::mem::size_of::<AmigaGuideMsg>() - 52usize];
@@ -16751,6 +17298,7 @@ const _: () = {
     ["Offset of field: AmigaGuideMsg::agm_System2"]
         [::core::mem::offset_of!(AmigaGuideMsg, agm_System2) - 48usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NewAmigaGuide {
@@ -16768,6 +17316,7 @@ pub struct NewAmigaGuide {
     pub nag_Extens: *mut TagItem,
     pub nag_Client: *mut ::core::ffi::c_void,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NewAmigaGuide"][::core::mem::size_of::<NewAmigaGuide>() - 52usize];
@@ -16962,6 +17511,7 @@ const _: () = {
     ["Offset of field: WBArg::wa_Lock"][::core::mem::offset_of!(WBArg, wa_Lock) - 0usize];
     ["Offset of field: WBArg::wa_Name"][::core::mem::offset_of!(WBArg, wa_Name) - 4usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct FileRequester {
@@ -16980,6 +17530,7 @@ pub struct FileRequester {
     pub fr_Reserved3: [UBYTE; 8usize],
     pub fr_Pattern: STRPTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of FileRequester"][::core::mem::size_of::<FileRequester>() - 56usize];
@@ -17181,6 +17732,7 @@ const _: () = {
     ["Offset of field: AslSemaphore::as_RelativeHeight"]
         [::core::mem::offset_of!(AslSemaphore, as_RelativeHeight) - 61usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NewBroker {
@@ -17194,6 +17746,7 @@ pub struct NewBroker {
     pub nb_Port: *mut MsgPort,
     pub nb_ReservedChannel: WORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NewBroker"][::core::mem::size_of::<NewBroker>() - 26usize];
@@ -17213,7 +17766,9 @@ const _: () = {
     ["Offset of field: NewBroker::nb_ReservedChannel"]
         [::core::mem::offset_of!(NewBroker, nb_ReservedChannel) - 24usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 pub type CxObj = LONG;
+#[cfg(not(feature = "kickstart-v34"))]
 pub type CxMsg = LONG;
 pub type PFL = FPTR;
 #[repr(C)]
@@ -17246,6 +17801,7 @@ const _: () = {
     ["Offset of field: InputXpression::ix_QualSame"]
         [::core::mem::offset_of!(InputXpression, ix_QualSame) - 10usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 pub type IX = InputXpression;
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
@@ -17484,6 +18040,7 @@ const _: () = {
     ["Offset of field: ExpansionBase::MountList"]
         [::core::mem::offset_of!(ExpansionBase, MountList) - 74usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NewGadget {
@@ -17498,6 +18055,7 @@ pub struct NewGadget {
     pub ng_VisualInfo: APTR,
     pub ng_UserData: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NewGadget"][::core::mem::size_of::<NewGadget>() - 30usize];
@@ -17522,6 +18080,7 @@ const _: () = {
     ["Offset of field: NewGadget::ng_UserData"]
         [::core::mem::offset_of!(NewGadget, ng_UserData) - 26usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NewMenu {
@@ -17532,6 +18091,7 @@ pub struct NewMenu {
     pub nm_MutualExclude: LONG,
     pub nm_UserData: APTR,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NewMenu"][::core::mem::size_of::<NewMenu>() - 20usize];
@@ -17545,6 +18105,7 @@ const _: () = {
     ["Offset of field: NewMenu::nm_UserData"]
         [::core::mem::offset_of!(NewMenu, nm_UserData) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct LVDrawMsg {
@@ -17554,6 +18115,7 @@ pub struct LVDrawMsg {
     pub lvdm_Bounds: Rectangle,
     pub lvdm_State: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of LVDrawMsg"][::core::mem::size_of::<LVDrawMsg>() - 24usize];
@@ -17584,6 +18146,7 @@ const _: () = {
     ["Offset of field: LocaleBase::lb_SysPatches"]
         [::core::mem::offset_of!(LocaleBase, lb_SysPatches) - 34usize];
 };
+#[cfg(not(feature = "kickstart-v37"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Locale {
@@ -17631,6 +18194,7 @@ pub struct Locale {
     pub loc_MonNegativeCSPos: UBYTE,
     pub loc_Reserved3: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v37"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of Locale"][::core::mem::size_of::<Locale>() - 168usize];
@@ -17720,6 +18284,7 @@ const _: () = {
     ["Offset of field: Locale::loc_Reserved3"]
         [::core::mem::offset_of!(Locale, loc_Reserved3) - 167usize];
 };
+#[cfg(not(feature = "kickstart-v37"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Catalog {
@@ -17730,6 +18295,7 @@ pub struct Catalog {
     pub cat_Version: UWORD,
     pub cat_Revision: UWORD,
 }
+#[cfg(not(feature = "kickstart-v37"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of Catalog"][::core::mem::size_of::<Catalog>() - 28usize];
@@ -17745,12 +18311,14 @@ const _: () = {
     ["Offset of field: Catalog::cat_Revision"]
         [::core::mem::offset_of!(Catalog, cat_Revision) - 26usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct KeyQuery {
     pub kq_KeyCode: UWORD,
     pub kq_Pressed: BOOL,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of KeyQuery"][::core::mem::size_of::<KeyQuery>() - 4usize];
@@ -17817,12 +18385,14 @@ const _: () = {
     ["Offset of field: MathIEEEResource::MathIEEEResource_ExtTransInit"]
         [::core::mem::offset_of!(MathIEEEResource, MathIEEEResource_ExtTransInit) - 40usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NVInfo {
     pub nvi_MaxStorage: ULONG,
     pub nvi_FreeStorage: ULONG,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NVInfo"][::core::mem::size_of::<NVInfo>() - 8usize];
@@ -17850,6 +18420,7 @@ const _: () = {
     ["Offset of field: NVEntry::nve_Protection"]
         [::core::mem::offset_of!(NVEntry, nve_Protection) - 16usize];
 };
+#[cfg(not(feature = "kickstart-v36"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Conductor {
@@ -17865,6 +18436,7 @@ pub struct Conductor {
     pub cdt_Flags: UWORD,
     pub cdt_State: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v36"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of Conductor"][::core::mem::size_of::<Conductor>() - 54usize];
@@ -17891,6 +18463,7 @@ const _: () = {
     ["Offset of field: Conductor::cdt_State"]
         [::core::mem::offset_of!(Conductor, cdt_State) - 52usize];
 };
+#[cfg(not(feature = "kickstart-v36"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct Player {
@@ -17906,6 +18479,7 @@ pub struct Player {
     pub pl_PlayerID: UWORD,
     pub pl_Flags: UWORD,
 }
+#[cfg(not(feature = "kickstart-v36"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of Player"][::core::mem::size_of::<Player>() - 44usize];
@@ -19039,6 +19613,7 @@ const _: () = {
     ["Offset of field: SpecialPens::sp_LightPen"]
         [::core::mem::offset_of!(SpecialPens, sp_LightPen) - 6usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct gpClipRect {
@@ -19047,6 +19622,7 @@ pub struct gpClipRect {
     pub gpc_ClipRect: *mut Rectangle,
     pub gpc_Flags: ULONG,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of gpClipRect"][::core::mem::size_of::<gpClipRect>() - 16usize];
@@ -19126,6 +19702,7 @@ const _: () = {
     ["Offset of field: UIPrefs::cap_LabelAttr"]
         [::core::mem::offset_of!(UIPrefs, cap_LabelAttr) - 336usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CardHandle {
@@ -19135,6 +19712,7 @@ pub struct CardHandle {
     pub cah_CardStatus: *mut Interrupt,
     pub cah_CardFlags: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CardHandle"][::core::mem::size_of::<CardHandle>() - 28usize];
@@ -19150,6 +19728,7 @@ const _: () = {
     ["Offset of field: CardHandle::cah_CardFlags"]
         [::core::mem::offset_of!(CardHandle, cah_CardFlags) - 26usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct DeviceTData {
@@ -19158,6 +19737,7 @@ pub struct DeviceTData {
     pub dtd_DTtype: UBYTE,
     pub dtd_DTflags: UBYTE,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of DeviceTData"][::core::mem::size_of::<DeviceTData>() - 10usize];
@@ -19171,6 +19751,7 @@ const _: () = {
     ["Offset of field: DeviceTData::dtd_DTflags"]
         [::core::mem::offset_of!(DeviceTData, dtd_DTflags) - 9usize];
 };
+#[cfg(not(feature = "kickstart-v39"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct CardMemoryMap {
@@ -19181,6 +19762,7 @@ pub struct CardMemoryMap {
     pub cmm_AttributeMemSize: ULONG,
     pub cmm_IOMemSize: ULONG,
 }
+#[cfg(not(feature = "kickstart-v39"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of CardMemoryMap"][::core::mem::size_of::<CardMemoryMap>() - 24usize];
@@ -19395,6 +19977,7 @@ const _: () = {
     ["Offset of field: RexxMsgPort::rmp_ReplyList"]
         [::core::mem::offset_of!(RexxMsgPort, rmp_ReplyList) - 66usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct ClockData {
@@ -19406,6 +19989,7 @@ pub struct ClockData {
     pub year: UWORD,
     pub wday: UWORD,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of ClockData"][::core::mem::size_of::<ClockData>() - 14usize];
@@ -19418,11 +20002,13 @@ const _: () = {
     ["Offset of field: ClockData::year"][::core::mem::offset_of!(ClockData, year) - 10usize];
     ["Offset of field: ClockData::wday"][::core::mem::offset_of!(ClockData, wday) - 12usize];
 };
+#[cfg(not(feature = "kickstart-v38"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct NamedObject {
     pub no_Object: APTR,
 }
+#[cfg(not(feature = "kickstart-v38"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of NamedObject"][::core::mem::size_of::<NamedObject>() - 4usize];
@@ -19630,11 +20216,13 @@ const _: () = {
     ["Offset of field: AppMessage::am_Reserved"]
         [::core::mem::offset_of!(AppMessage, am_Reserved) - 54usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AppWindow {
     pub aw_PRIVATE: *mut ::core::ffi::c_void,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AppWindow"][::core::mem::size_of::<AppWindow>() - 4usize];
@@ -19642,11 +20230,13 @@ const _: () = {
     ["Offset of field: AppWindow::aw_PRIVATE"]
         [::core::mem::offset_of!(AppWindow, aw_PRIVATE) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v40"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AppWindowDropZone {
     pub awdz_PRIVATE: *mut ::core::ffi::c_void,
 }
+#[cfg(not(feature = "kickstart-v40"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AppWindowDropZone"][::core::mem::size_of::<AppWindowDropZone>() - 4usize];
@@ -19654,22 +20244,26 @@ const _: () = {
     ["Offset of field: AppWindowDropZone::awdz_PRIVATE"]
         [::core::mem::offset_of!(AppWindowDropZone, awdz_PRIVATE) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AppIcon {
     pub ai_PRIVATE: *mut ::core::ffi::c_void,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AppIcon"][::core::mem::size_of::<AppIcon>() - 4usize];
     ["Alignment of AppIcon"][::core::mem::align_of::<AppIcon>() - 2usize];
     ["Offset of field: AppIcon::ai_PRIVATE"][::core::mem::offset_of!(AppIcon, ai_PRIVATE) - 0usize];
 };
+#[cfg(not(feature = "kickstart-v34"))]
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct AppMenuItem {
     pub ami_PRIVATE: *mut ::core::ffi::c_void,
 }
+#[cfg(not(feature = "kickstart-v34"))]
 #[allow(clippy::unnecessary_operation, clippy::identity_op)]
 const _: () = {
     ["Size of AppMenuItem"][::core::mem::size_of::<AppMenuItem>() - 4usize];
@@ -19946,26082 +20540,6 @@ const _: () = {
     ["Offset of field: TextInputMsg::tim_Prompt"]
         [::core::mem::offset_of!(TextInputMsg, tim_Prompt) - 8usize];
 };
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn LockAmigaGuideBase(AmigaGuideBase: *mut Library, handle: APTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn UnlockAmigaGuideBase(AmigaGuideBase: *mut Library, key: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("d0") key,
-        );
-    }
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn OpenAmigaGuideA(
-    AmigaGuideBase: *mut Library,
-    nag: *mut NewAmigaGuide,
-    attrs: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") nag,
-            in("a1") attrs,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn OpenAmigaGuideAsyncA(
-    AmigaGuideBase: *mut Library,
-    nag: *mut NewAmigaGuide,
-    attrs: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") nag,
-            in("d0") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn CloseAmigaGuide(AmigaGuideBase: *mut Library, cl: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-        );
-    }
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn AmigaGuideSignal(AmigaGuideBase: *mut Library, cl: APTR) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetAmigaGuideMsg(AmigaGuideBase: *mut Library, cl: APTR) -> *mut AmigaGuideMsg {
-    let asm_ret_value: *mut AmigaGuideMsg;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn ReplyAmigaGuideMsg(AmigaGuideBase: *mut Library, amsg: *mut AmigaGuideMsg) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") amsg,
-        );
-    }
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn SetAmigaGuideContextA(
-    AmigaGuideBase: *mut Library,
-    cl: APTR,
-    id: ULONG,
-    attrs: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            in("d0") id,
-            in("d1") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn SendAmigaGuideContextA(
-    AmigaGuideBase: *mut Library,
-    cl: APTR,
-    attrs: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            in("d0") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn SendAmigaGuideCmdA(
-    AmigaGuideBase: *mut Library,
-    cl: APTR,
-    cmd: STRPTR,
-    attrs: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            in("d0") cmd,
-            in("d1") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn SetAmigaGuideAttrsA(
-    AmigaGuideBase: *mut Library,
-    cl: APTR,
-    attrs: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") cl,
-            in("a1") attrs,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetAmigaGuideAttr(
-    AmigaGuideBase: *mut Library,
-    tag1: Tag,
-    cl: APTR,
-    storage: *mut ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("d0") tag1,
-            in("a0") cl,
-            in("a1") storage,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn LoadXRef(AmigaGuideBase: *mut Library, lock: BPTR, name: STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") lock,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn ExpungeXRef(AmigaGuideBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-        );
-    }
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn AddAmigaGuideHostA(
-    AmigaGuideBase: *mut Library,
-    h: *mut Hook,
-    name: CONST_STRPTR,
-    attrs: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") h,
-            in("d0") name,
-            in("a1") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn RemoveAmigaGuideHostA(
-    AmigaGuideBase: *mut Library,
-    hh: APTR,
-    attrs: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("a0") hh,
-            in("a1") attrs,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetAmigaGuideString(AmigaGuideBase: *mut Library, id: LONG) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AmigaGuideBase,
-            in("d0") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "arexx.class" (V40) (Kickstart 3.1)
-pub unsafe fn AREXX_GetClass(ARexxBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ARexxBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocFileRequest(AslBase: *mut Library) -> *mut FileRequester {
-    let asm_ret_value: *mut FileRequester;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeFileRequest(AslBase: *mut Library, fileReq: *mut FileRequester) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") fileReq,
-        );
-    }
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn RequestFile(AslBase: *mut Library, fileReq: *mut FileRequester) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") fileReq,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocAslRequest(
-    AslBase: *mut Library,
-    reqType: ULONG,
-    tagList: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("d0") reqType,
-            in("a0") tagList,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeAslRequest(AslBase: *mut Library, requester: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") requester,
-        );
-    }
-}
-
-/// libname: "asl.library" (V36) (Kickstart 2.0)
-pub unsafe fn AslRequest(AslBase: *mut Library, requester: APTR, tagList: *const TagItem) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") requester,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn AbortAslRequest(AslBase: *mut Library, requester: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") requester,
-        );
-    }
-}
-
-/// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn ActivateAslRequest(AslBase: *mut Library, requester: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) AslBase,
-            in("a0") requester,
-        );
-    }
-}
-
-/// libname: "battclock.resource" (V36) (Kickstart 2.0)
-pub unsafe fn ResetBattClock(BattClockBase: *mut ::core::ffi::c_void) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) BattClockBase,
-        );
-    }
-}
-
-/// libname: "battclock.resource" (V36) (Kickstart 2.0)
-pub unsafe fn ReadBattClock(BattClockBase: *mut ::core::ffi::c_void) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BattClockBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "battclock.resource" (V36) (Kickstart 2.0)
-pub unsafe fn WriteBattClock(BattClockBase: *mut ::core::ffi::c_void, time: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) BattClockBase,
-            in("d0") time,
-        );
-    }
-}
-
-/// libname: "battmem.resource" (V36) (Kickstart 2.0)
-pub unsafe fn ObtainBattSemaphore(BattMemBase: *mut ::core::ffi::c_void) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) BattMemBase,
-        );
-    }
-}
-
-/// libname: "battmem.resource" (V36) (Kickstart 2.0)
-pub unsafe fn ReleaseBattSemaphore(BattMemBase: *mut ::core::ffi::c_void) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) BattMemBase,
-        );
-    }
-}
-
-/// libname: "battmem.resource" (V36) (Kickstart 2.0)
-pub unsafe fn ReadBattMem(
-    BattMemBase: *mut ::core::ffi::c_void,
-    buffer: APTR,
-    offset: ULONG,
-    length: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BattMemBase,
-            in("a0") buffer,
-            in("d0") offset,
-            in("d1") length,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "battmem.resource" (V36) (Kickstart 2.0)
-pub unsafe fn WriteBattMem(
-    BattMemBase: *mut ::core::ffi::c_void,
-    buffer: CONST_APTR,
-    offset: ULONG,
-    length: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-24, %a6)
-            ".short -24",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BattMemBase,
-            in("a0") buffer,
-            in("d0") offset,
-            in("d1") length,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bevel.image" (V40) (Kickstart 3.1)
-pub unsafe fn BEVEL_GetClass(BevelBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BevelBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bitmap.image" (V40) (Kickstart 3.1)
-pub unsafe fn BITMAP_GetClass(BitMapBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BitMapBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bullet.library" (V38) (Kickstart 2.1)
-pub unsafe fn OpenEngine(BulletBase: *mut Library) -> *mut GlyphEngine {
-    let asm_ret_value: *mut GlyphEngine;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BulletBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bullet.library" (V38) (Kickstart 2.1)
-pub unsafe fn CloseEngine(BulletBase: *mut Library, glyphEngine: *mut GlyphEngine) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) BulletBase,
-            in("a0") glyphEngine,
-        );
-    }
-}
-
-/// libname: "bullet.library" (V38) (Kickstart 2.1)
-pub unsafe fn SetInfoA(
-    BulletBase: *mut Library,
-    glyphEngine: *mut GlyphEngine,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BulletBase,
-            in("a0") glyphEngine,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bullet.library" (V38) (Kickstart 2.1)
-pub unsafe fn ObtainInfoA(
-    BulletBase: *mut Library,
-    glyphEngine: *mut GlyphEngine,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BulletBase,
-            in("a0") glyphEngine,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "bullet.library" (V38) (Kickstart 2.1)
-pub unsafe fn ReleaseInfoA(
-    BulletBase: *mut Library,
-    glyphEngine: *mut GlyphEngine,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) BulletBase,
-            in("a0") glyphEngine,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "button.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn BUTTON_GetClass(ButtonBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ButtonBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn OwnCard(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-) -> *mut CardHandle {
-    let asm_ret_value: *mut CardHandle;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn ReleaseCard(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    flags: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("d0") flags,
-        );
-    }
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn GetCardMap(CardResource: *mut ::core::ffi::c_void) -> *mut CardMemoryMap {
-    let asm_ret_value: *mut CardMemoryMap;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn BeginCardAccess(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-24, %a6)
-            ".short -24",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn EndCardAccess(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn ReadCardStatus(CardResource: *mut ::core::ffi::c_void) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardResetRemove(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    flag: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("d0") flag,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardMiscControl(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    control_bits: ULONG,
-) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("d1") control_bits,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardAccessSpeed(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    nanoseconds: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("d0") nanoseconds,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardProgramVoltage(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    voltage: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("d0") voltage,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardResetCard(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CopyTuple(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-    buffer: *mut UBYTE,
-    tuplecode: ULONG,
-    size: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a1") handle,
-            in("a0") buffer,
-            in("d1") tuplecode,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn DeviceTuple(
-    CardResource: *mut ::core::ffi::c_void,
-    tuple_data: *const UBYTE,
-    storage: *mut DeviceTData,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a0") tuple_data,
-            in("a1") storage,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn IfAmigaXIP(
-    CardResource: *mut ::core::ffi::c_void,
-    handle: *mut CardHandle,
-) -> *mut Resident {
-    let asm_ret_value: *mut Resident;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            in("a2") handle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardForceChange(CardResource: *mut ::core::ffi::c_void) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardChangeCount(CardResource: *mut ::core::ffi::c_void) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "card.resource" (V40) (Kickstart 3.1)
-pub unsafe fn CardInterface(CardResource: *mut ::core::ffi::c_void) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CardResource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "checkbox.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn CHECKBOX_GetClass(CheckBoxBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CheckBoxBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn CHOOSER_GetClass(ChooserBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn AllocChooserNodeA(
-    ChooserBase: *mut ::core::ffi::c_void,
-    tags: *mut TagItem,
-) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeChooserNode(ChooserBase: *mut ::core::ffi::c_void, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetChooserNodeAttrsA(
-    ChooserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GetChooserNodeAttrsA(
-    ChooserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn ShowChooser(
-    ChooserBase: *mut ::core::ffi::c_void,
-    o: *mut Object,
-    w: *mut Window,
-    xpos: ULONG,
-    ypos: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") o,
-            in("a1") w,
-            in("d0") xpos,
-            in("d1") ypos,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn HideChooser(ChooserBase: *mut ::core::ffi::c_void, o: *mut Object, w: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ChooserBase,
-            in("a0") o,
-            in("a1") w,
-        );
-    }
-}
-
-/// libname: "ciaa.resource|ciab.resource"
-pub unsafe fn AddICRVector(
-    resource: *mut Library,
-    iCRBit: LONG,
-    interrupt: *mut Interrupt,
-) -> *mut Interrupt {
-    let asm_ret_value: *mut Interrupt;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) resource,
-            in("d0") iCRBit,
-            in("a1") interrupt,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "ciaa.resource|ciab.resource"
-pub unsafe fn RemICRVector(resource: *mut Library, iCRBit: LONG, interrupt: *mut Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) resource,
-            in("d0") iCRBit,
-            in("a1") interrupt,
-        );
-    }
-}
-
-/// libname: "ciaa.resource|ciab.resource"
-pub unsafe fn AbleICR(resource: *mut Library, mask: LONG) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) resource,
-            in("d0") mask,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "ciaa.resource|ciab.resource"
-pub unsafe fn SetICR(resource: *mut Library, mask: LONG) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-24, %a6)
-            ".short -24",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) resource,
-            in("d0") mask,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn CLICKTAB_GetClass(ClickTabBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ClickTabBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn AllocClickTabNodeA(
-    ClickTabBase: *mut ::core::ffi::c_void,
-    tags: *mut TagItem,
-) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ClickTabBase,
-            in("a0") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeClickTabNode(ClickTabBase: *mut ::core::ffi::c_void, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ClickTabBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetClickTabNodeAttrsA(
-    ClickTabBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ClickTabBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GetClickTabNodeAttrsA(
-    ClickTabBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ClickTabBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
-pub unsafe fn ConvertHSBToRGB(
-    ColorWheelBase: *mut ::core::ffi::c_void,
-    hsb: *const ColorWheelHSB,
-    rgb: *mut ColorWheelRGB,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ColorWheelBase,
-            in("a0") hsb,
-            in("a1") rgb,
-        );
-    }
-}
-
-/// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
-pub unsafe fn ConvertRGBToHSB(
-    ColorWheelBase: *mut ::core::ffi::c_void,
-    rgb: *const ColorWheelRGB,
-    hsb: *mut ColorWheelHSB,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ColorWheelBase,
-            in("a0") rgb,
-            in("a1") hsb,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateCxObj(
-    CxBase: *mut Library,
-    type_: ULONG,
-    arg1: LONG,
-    arg2: LONG,
-) -> *mut CxObj {
-    let asm_ret_value: *mut CxObj;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("d0") type_,
-            in("a0") arg1,
-            in("a1") arg2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxBroker(CxBase: *mut Library, nb: *const NewBroker, error: *mut LONG) -> *mut CxObj {
-    let asm_ret_value: *mut CxObj;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") nb,
-            in("d0") error,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn ActivateCxObj(CxBase: *mut Library, co: *mut CxObj, flag: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-            in("d0") flag,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteCxObj(CxBase: *mut Library, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteCxObjAll(CxBase: *mut Library, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxObjType(CxBase: *mut Library, co: *const CxObj) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxObjError(CxBase: *mut Library, co: *const CxObj) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn ClearCxObjError(CxBase: *mut Library, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetCxObjPri(CxBase: *mut Library, co: *mut CxObj, pri: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-            in("d0") pri,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn AttachCxObj(CxBase: *mut Library, headObj: *mut CxObj, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") headObj,
-            in("a1") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn EnqueueCxObj(CxBase: *mut Library, headObj: *mut CxObj, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") headObj,
-            in("a1") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn InsertCxObj(
-    CxBase: *mut Library,
-    headObj: *mut CxObj,
-    co: *mut CxObj,
-    pred: *mut CxObj,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") headObj,
-            in("a1") co,
-            in("a2") pred,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemoveCxObj(CxBase: *mut Library, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetTranslate(CxBase: *mut Library, translator: *mut CxObj, events: *mut InputEvent) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") translator,
-            in("a1") events,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetFilter(CxBase: *mut Library, filter: *mut CxObj, text: CONST_STRPTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") filter,
-            in("a1") text,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetFilterIX(CxBase: *mut Library, filter: *mut CxObj, ix: *const IX) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") filter,
-            in("a1") ix,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn ParseIX(CxBase: *mut Library, description: CONST_STRPTR, ix: *mut IX) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") description,
-            in("a1") ix,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxMsgType(CxBase: *mut Library, cxm: *const CxMsg) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxMsgData(CxBase: *mut Library, cxm: *const CxMsg) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn CxMsgID(CxBase: *mut Library, cxm: *const CxMsg) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn DivertCxMsg(
-    CxBase: *mut Library,
-    cxm: *mut CxMsg,
-    headObj: *mut CxObj,
-    returnObj: *mut CxObj,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-            in("a1") headObj,
-            in("a2") returnObj,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn RouteCxMsg(CxBase: *mut Library, cxm: *mut CxMsg, co: *mut CxObj) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-            in("a1") co,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn DisposeCxMsg(CxBase: *mut Library, cxm: *mut CxMsg) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") cxm,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn InvertKeyMap(
-    CxBase: *mut Library,
-    ansiCode: ULONG,
-    event: *mut InputEvent,
-    km: *const KeyMap,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("d0") ansiCode,
-            in("a0") event,
-            in("a1") km,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "commodities.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddIEvents(CxBase: *mut Library, events: *mut InputEvent) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") events,
-        );
-    }
-}
-
-/// libname: "commodities.library" (V38) (Kickstart 2.1)
-pub unsafe fn MatchIX(CxBase: *mut Library, event: *const InputEvent, ix: *const IX) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) CxBase,
-            in("a0") event,
-            in("a1") ix,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "console.device"
-pub unsafe fn CDInputHandler(
-    ConsoleDevice: *mut ::core::ffi::c_void,
-    events: *const InputEvent,
-    consoleDevice: *mut Library,
-) -> *mut InputEvent {
-    let asm_ret_value: *mut InputEvent;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ConsoleDevice,
-            in("a0") events,
-            in("a1") consoleDevice,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "console.device"
-pub unsafe fn RawKeyConvert(
-    ConsoleDevice: *mut ::core::ffi::c_void,
-    events: *const InputEvent,
-    buffer: STRPTR,
-    length: LONG,
-    keyMap: *const KeyMap,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ConsoleDevice,
-            in("a0") events,
-            in("a1") buffer,
-            in("d1") length,
-            in("a2") keyMap,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn ObtainDataTypeA(
-    DataTypesBase: *mut Library,
-    type_: ULONG,
-    handle: APTR,
-    attrs: *const TagItem,
-) -> *mut DataType {
-    let asm_ret_value: *mut DataType;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("d0") type_,
-            in("a0") handle,
-            in("a1") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn ReleaseDataType(DataTypesBase: *mut Library, dt: *mut DataType) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") dt,
-        );
-    }
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn NewDTObjectA(
-    DataTypesBase: *mut Library,
-    name: CONST_STRPTR,
-    attrs: *const TagItem,
-) -> *mut Object {
-    let asm_ret_value: *mut Object;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("d0") name,
-            in("a0") attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn DisposeDTObject(DataTypesBase: *mut Library, o: *mut Object) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-        );
-    }
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn SetDTAttrsA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    win: *mut Window,
-    req: *mut Requester,
-    attrs: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") win,
-            in("a2") req,
-            in("a3") attrs,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetDTAttrsA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    attrs: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a2") attrs,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn AddDTObject(
-    DataTypesBase: *mut Library,
-    win: *mut Window,
-    req: *mut Requester,
-    o: *mut Object,
-    pos: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") win,
-            in("a1") req,
-            in("a2") o,
-            in("d0") pos,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn RefreshDTObjectA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    win: *mut Window,
-    req: *mut Requester,
-    attrs: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") win,
-            in("a2") req,
-            in("a3") attrs,
-        );
-    }
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn DoAsyncLayout(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    gpl: *mut gpLayout,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") gpl,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn DoDTMethodA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    win: *mut Window,
-    req: *mut Requester,
-    msg: Msg,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") win,
-            in("a2") req,
-            in("a3") msg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn RemoveDTObject(
-    DataTypesBase: *mut Library,
-    win: *mut Window,
-    o: *mut Object,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") win,
-            in("a1") o,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetDTMethods(DataTypesBase: *mut Library, object: *const Object) -> *mut ULONG {
-    let asm_ret_value: *mut ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") object,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetDTTriggerMethods(
-    DataTypesBase: *mut Library,
-    object: *mut Object,
-) -> *mut DTMethod {
-    let asm_ret_value: *mut DTMethod;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") object,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn PrintDTObjectA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    w: *mut Window,
-    r: *mut Requester,
-    msg: *mut dtPrint,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") w,
-            in("a2") r,
-            in("a3") msg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetDTString(DataTypesBase: *mut Library, id: ULONG) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("d0") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn FindMethod(
-    DataTypesBase: *mut Library,
-    methods: *const ULONG,
-    searchmethodid: ULONG,
-) -> *mut ULONG {
-    let asm_ret_value: *mut ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") methods,
-            in("a1") searchmethodid,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn FindTriggerMethod(
-    DataTypesBase: *mut Library,
-    dtm: *const DTMethod,
-    command: CONST_STRPTR,
-    method: ULONG,
-) -> *mut DTMethod {
-    let asm_ret_value: *mut DTMethod;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") dtm,
-            in("a1") command,
-            in("d0") method,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn CopyDTMethods(
-    DataTypesBase: *mut Library,
-    methods: *const ULONG,
-    include: *const ULONG,
-    exclude: *const ULONG,
-) -> *mut ULONG {
-    let asm_ret_value: *mut ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") methods,
-            in("a1") include,
-            in("a2") exclude,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn CopyDTTriggerMethods(
-    DataTypesBase: *mut Library,
-    methods: *const DTMethod,
-    include: *const DTMethod,
-    exclude: *const DTMethod,
-) -> *mut DTMethod {
-    let asm_ret_value: *mut DTMethod;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-276, %a6)
-            ".short -276",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") methods,
-            in("a1") include,
-            in("a2") exclude,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn FreeDTMethods(
-    DataTypesBase: *mut Library,
-    methods: APTR,
-) -> *mut ::core::ffi::c_void {
-    let asm_ret_value: *mut ::core::ffi::c_void;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-282, %a6)
-            ".short -282",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") methods,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn GetDTTriggerMethodDataFlags(
-    DataTypesBase: *mut Library,
-    triggermethod: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-288, %a6)
-            ".short -288",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("d0") triggermethod,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SaveDTObjectA(
-    DataTypesBase: *mut Library,
-    o: *mut Object,
-    win: *mut Window,
-    req: *mut Requester,
-    file: CONST_STRPTR,
-    mode: ULONG,
-    saveicon: LONG,
-    attrs: *mut TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a4, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a4reg}, %a4",
-            ".short 0x4eae", // jsr (-294, %a6)
-            ".short -294",
-            "move.l (%sp)+, %a4",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            in("a1") win,
-            in("a2") req,
-            in("a3") file,
-            in("d0") mode,
-            in("d1") saveicon,
-            a4reg = in(reg) attrs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn StartDragSelect(DataTypesBase: *mut Library, o: *mut Object) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-300, %a6)
-            ".short -300",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DataTypesBase,
-            in("a0") o,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn DATEBROWSER_GetClass(DateBrowserBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DateBrowserBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn JulianWeekDay(
-    DateBrowserBase: *mut ::core::ffi::c_void,
-    day: ULONG,
-    month: ULONG,
-    year: LONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DateBrowserBase,
-            in("d0") day,
-            in("d1") month,
-            in("d2") year,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn JulianMonthDays(
-    DateBrowserBase: *mut ::core::ffi::c_void,
-    month: ULONG,
-    year: LONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DateBrowserBase,
-            in("d0") month,
-            in("d1") year,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn JulianLeapYear(DateBrowserBase: *mut ::core::ffi::c_void, year: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DateBrowserBase,
-            in("d0") year,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "disk.resource"
-pub unsafe fn AllocUnit(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-            in("d0") unitNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "disk.resource"
-pub unsafe fn FreeUnit(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-            in("d0") unitNum,
-        );
-    }
-}
-
-/// libname: "disk.resource"
-pub unsafe fn GetUnit(
-    DiskBase: *mut ::core::ffi::c_void,
-    unitPointer: *mut DiscResourceUnit,
-) -> *mut DiscResourceUnit {
-    let asm_ret_value: *mut DiscResourceUnit;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-            in("a1") unitPointer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "disk.resource"
-pub unsafe fn GiveUnit(DiskBase: *mut ::core::ffi::c_void) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-24, %a6)
-            ".short -24",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-        );
-    }
-}
-
-/// libname: "disk.resource"
-pub unsafe fn GetUnitID(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-            in("d0") unitNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "disk.resource" (V37) (Kickstart 2.04)
-pub unsafe fn ReadUnitID(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskBase,
-            in("d0") unitNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library"
-pub unsafe fn OpenDiskFont(DiskfontBase: *mut Library, textAttr: *mut TextAttr) -> *mut TextFont {
-    let asm_ret_value: *mut TextFont;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") textAttr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library"
-pub unsafe fn AvailFonts(
-    DiskfontBase: *mut Library,
-    buffer: *mut AvailFontsHeader,
-    bufBytes: LONG,
-    flags: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") buffer,
-            in("d0") bufBytes,
-            in("d1") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V34) (Kickstart 1.3)
-pub unsafe fn NewFontContents(
-    DiskfontBase: *mut Library,
-    fontsLock: BPTR,
-    fontName: CONST_STRPTR,
-) -> *mut FontContentsHeader {
-    let asm_ret_value: *mut FontContentsHeader;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") fontsLock,
-            in("a1") fontName,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V34) (Kickstart 1.3)
-pub unsafe fn DisposeFontContents(
-    DiskfontBase: *mut Library,
-    fontContentsHeader: *mut FontContentsHeader,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a1") fontContentsHeader,
-        );
-    }
-}
-
-/// libname: "diskfont.library" (V36) (Kickstart 2.0)
-pub unsafe fn NewScaledDiskFont(
-    DiskfontBase: *mut Library,
-    sourceFont: *mut TextFont,
-    destTextAttr: *mut TextAttr,
-) -> *mut DiskFontHeader {
-    let asm_ret_value: *mut DiskFontHeader;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") sourceFont,
-            in("a1") destTextAttr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn GetDiskFontCtrl(DiskfontBase: *mut Library, tagid: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("d0") tagid,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn SetDiskFontCtrlA(DiskfontBase: *mut Library, taglist: *const TagItem) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") taglist,
-        );
-    }
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn EOpenEngine(DiskfontBase: *mut Library, eEngine: *mut EGlyphEngine) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") eEngine,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ECloseEngine(DiskfontBase: *mut Library, eEngine: *mut EGlyphEngine) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") eEngine,
-        );
-    }
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ESetInfoA(
-    DiskfontBase: *mut Library,
-    eEngine: *mut EGlyphEngine,
-    taglist: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") eEngine,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn EObtainInfoA(
-    DiskfontBase: *mut Library,
-    eEngine: *mut EGlyphEngine,
-    taglist: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") eEngine,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn EReleaseInfoA(
-    DiskfontBase: *mut Library,
-    eEngine: *mut EGlyphEngine,
-    taglist: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") eEngine,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn OpenOutlineFont(
-    DiskfontBase: *mut Library,
-    name: CONST_STRPTR,
-    list: *mut List,
-    flags: ULONG,
-) -> *mut OutlineFont {
-    let asm_ret_value: *mut OutlineFont;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") name,
-            in("a1") list,
-            in("d0") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn CloseOutlineFont(DiskfontBase: *mut Library, olf: *mut OutlineFont, list: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") olf,
-            in("a1") list,
-        );
-    }
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn WriteFontContents(
-    DiskfontBase: *mut Library,
-    fontsLock: BPTR,
-    fontName: CONST_STRPTR,
-    fontContentsHeader: *const FontContentsHeader,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") fontsLock,
-            in("a1") fontName,
-            in("a2") fontContentsHeader,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn WriteDiskFontHeaderA(
-    DiskfontBase: *mut Library,
-    font: *const TextFont,
-    fileName: CONST_STRPTR,
-    tagList: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("a0") font,
-            in("a1") fileName,
-            in("a2") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ObtainCharsetInfo(
-    DiskfontBase: *mut Library,
-    knownTag: ULONG,
-    knownValue: ULONG,
-    wantedTag: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DiskfontBase,
-            in("d0") knownTag,
-            in("d1") knownValue,
-            in("d2") wantedTag,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Open(DOSBase: *mut Library, name: CONST_STRPTR, accessMode: LONG) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") accessMode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Close(DOSBase: *mut Library, file: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Read(DOSBase: *mut Library, file: BPTR, buffer: APTR, length: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") buffer,
-            in("d3") length,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Write(DOSBase: *mut Library, file: BPTR, buffer: CONST_APTR, length: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") buffer,
-            in("d3") length,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Input(DOSBase: *mut Library) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Output(DOSBase: *mut Library) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Seek(DOSBase: *mut Library, file: BPTR, position: LONG, offset: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") position,
-            in("d3") offset,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn DeleteFile(DOSBase: *mut Library, name: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Rename(DOSBase: *mut Library, oldName: CONST_STRPTR, newName: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") oldName,
-            in("d2") newName,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Lock(DOSBase: *mut Library, name: CONST_STRPTR, type_: LONG) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") type_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn UnLock(DOSBase: *mut Library, lock: BPTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-        );
-    }
-}
-
-/// libname: "dos.library"
-pub unsafe fn DupLock(DOSBase: *mut Library, lock: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Examine(
-    DOSBase: *mut Library,
-    lock: BPTR,
-    fileInfoBlock: *mut FileInfoBlock,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") fileInfoBlock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn ExNext(DOSBase: *mut Library, lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") fileInfoBlock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Info(DOSBase: *mut Library, lock: BPTR, parameterBlock: *mut InfoData) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") parameterBlock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn CreateDir(DOSBase: *mut Library, name: CONST_STRPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn CurrentDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn IoErr(DOSBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn CreateProc(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    pri: LONG,
-    segList: BPTR,
-    stackSize: LONG,
-) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") pri,
-            in("d3") segList,
-            in("d4") stackSize,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Exit(DOSBase: *mut Library, returnCode: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") returnCode,
-        );
-    }
-}
-
-/// libname: "dos.library"
-pub unsafe fn LoadSeg(DOSBase: *mut Library, name: CONST_STRPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn UnLoadSeg(DOSBase: *mut Library, seglist: BPTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") seglist,
-        );
-    }
-}
-
-/// libname: "dos.library"
-pub unsafe fn DeviceProc(DOSBase: *mut Library, name: CONST_STRPTR) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn SetComment(DOSBase: *mut Library, name: CONST_STRPTR, comment: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") comment,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn SetProtection(DOSBase: *mut Library, name: CONST_STRPTR, protect: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") protect,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn DateStamp(DOSBase: *mut Library, date: *mut DateStamp) -> *mut DateStamp {
-    let asm_ret_value: *mut DateStamp;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") date,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Delay(DOSBase: *mut Library, timeout: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") timeout,
-        );
-    }
-}
-
-/// libname: "dos.library"
-pub unsafe fn WaitForChar(DOSBase: *mut Library, file: BPTR, timeout: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") timeout,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn ParentDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn IsInteractive(DOSBase: *mut Library, file: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library"
-pub unsafe fn Execute(
-    DOSBase: *mut Library,
-    string: CONST_STRPTR,
-    file: BPTR,
-    file2: BPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") string,
-            in("d2") file,
-            in("d3") file2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocDosObject(DOSBase: *mut Library, type_: ULONG, tags: *const TagItem) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") type_,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocDosObjectTagList(
-    DOSBase: *mut Library,
-    type_: ULONG,
-    tags: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") type_,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeDosObject(DOSBase: *mut Library, type_: ULONG, ptr: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") type_,
-            in("d2") ptr,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt(
-    DOSBase: *mut Library,
-    port: *mut MsgPort,
-    action: LONG,
-    arg1: LONG,
-    arg2: LONG,
-    arg3: LONG,
-    arg4: LONG,
-    arg5: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            in("d3") arg1,
-            in("d4") arg2,
-            in("d5") arg3,
-            in("d6") arg4,
-            in("d7") arg5,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt0(DOSBase: *mut Library, port: *mut MsgPort, action: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt1(DOSBase: *mut Library, port: *mut MsgPort, action: LONG, arg1: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            in("d3") arg1,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt2(
-    DOSBase: *mut Library,
-    port: *mut MsgPort,
-    action: LONG,
-    arg1: LONG,
-    arg2: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            in("d3") arg1,
-            in("d4") arg2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt3(
-    DOSBase: *mut Library,
-    port: *mut MsgPort,
-    action: LONG,
-    arg1: LONG,
-    arg2: LONG,
-    arg3: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            in("d3") arg1,
-            in("d4") arg2,
-            in("d5") arg3,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DoPkt4(
-    DOSBase: *mut Library,
-    port: *mut MsgPort,
-    action: LONG,
-    arg1: LONG,
-    arg2: LONG,
-    arg3: LONG,
-    arg4: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") action,
-            in("d3") arg1,
-            in("d4") arg2,
-            in("d5") arg3,
-            in("d6") arg4,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SendPkt(
-    DOSBase: *mut Library,
-    dp: *mut DosPacket,
-    port: *mut MsgPort,
-    replyport: *mut MsgPort,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dp,
-            in("d2") port,
-            in("d3") replyport,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn WaitPkt(DOSBase: *mut Library) -> *mut DosPacket {
-    let asm_ret_value: *mut DosPacket;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReplyPkt(DOSBase: *mut Library, dp: *mut DosPacket, res1: LONG, res2: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dp,
-            in("d2") res1,
-            in("d3") res2,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AbortPkt(DOSBase: *mut Library, port: *mut MsgPort, pkt: *mut DosPacket) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") pkt,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockRecord(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    offset: ULONG,
-    length: ULONG,
-    mode: ULONG,
-    timeout: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") offset,
-            in("d3") length,
-            in("d4") mode,
-            in("d5") timeout,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockRecords(
-    DOSBase: *mut Library,
-    recArray: *const RecordLock,
-    timeout: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-276, %a6)
-            ".short -276",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") recArray,
-            in("d2") timeout,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnLockRecord(DOSBase: *mut Library, fh: BPTR, offset: ULONG, length: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-282, %a6)
-            ".short -282",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") offset,
-            in("d3") length,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnLockRecords(DOSBase: *mut Library, recArray: *const RecordLock) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-288, %a6)
-            ".short -288",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") recArray,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SelectInput(DOSBase: *mut Library, fh: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-294, %a6)
-            ".short -294",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SelectOutput(DOSBase: *mut Library, fh: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-300, %a6)
-            ".short -300",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FGetC(DOSBase: *mut Library, fh: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-306, %a6)
-            ".short -306",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FPutC(DOSBase: *mut Library, fh: BPTR, ch: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-312, %a6)
-            ".short -312",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") ch,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnGetC(DOSBase: *mut Library, fh: BPTR, character: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-318, %a6)
-            ".short -318",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") character,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FRead(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    block: APTR,
-    blocklen: ULONG,
-    number: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-324, %a6)
-            ".short -324",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") block,
-            in("d3") blocklen,
-            in("d4") number,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FWrite(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    block: CONST_APTR,
-    blocklen: ULONG,
-    number: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-330, %a6)
-            ".short -330",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") block,
-            in("d3") blocklen,
-            in("d4") number,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FGets(DOSBase: *mut Library, fh: BPTR, buf: STRPTR, buflen: ULONG) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-336, %a6)
-            ".short -336",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") buf,
-            in("d3") buflen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FPuts(DOSBase: *mut Library, fh: BPTR, str_: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-342, %a6)
-            ".short -342",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") str_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn VFWritef(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    format: CONST_STRPTR,
-    argarray: *const LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-348, %a6)
-            ".short -348",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") format,
-            in("d3") argarray,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn VFPrintf(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    format: CONST_STRPTR,
-    argarray: CONST_APTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-354, %a6)
-            ".short -354",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") format,
-            in("d3") argarray,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Flush(DOSBase: *mut Library, fh: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-360, %a6)
-            ".short -360",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetVBuf(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    buff: STRPTR,
-    type_: LONG,
-    size: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-366, %a6)
-            ".short -366",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") buff,
-            in("d3") type_,
-            in("d4") size,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DupLockFromFH(DOSBase: *mut Library, fh: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-372, %a6)
-            ".short -372",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenFromLock(DOSBase: *mut Library, lock: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-378, %a6)
-            ".short -378",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ParentOfFH(DOSBase: *mut Library, fh: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-384, %a6)
-            ".short -384",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ExamineFH(DOSBase: *mut Library, fh: BPTR, fib: *mut FileInfoBlock) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-390, %a6)
-            ".short -390",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") fib,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetFileDate(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    date: *const DateStamp,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-396, %a6)
-            ".short -396",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") date,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn NameFromLock(DOSBase: *mut Library, lock: BPTR, buffer: STRPTR, len: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-402, %a6)
-            ".short -402",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") buffer,
-            in("d3") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn NameFromFH(DOSBase: *mut Library, fh: BPTR, buffer: STRPTR, len: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-408, %a6)
-            ".short -408",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") buffer,
-            in("d3") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SplitName(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    separator: ULONG,
-    buf: STRPTR,
-    oldpos: LONG,
-    size: LONG,
-) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-414, %a6)
-            ".short -414",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") separator,
-            in("d3") buf,
-            in("d4") oldpos,
-            in("d5") size,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SameLock(DOSBase: *mut Library, lock1: BPTR, lock2: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-420, %a6)
-            ".short -420",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock1,
-            in("d2") lock2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetMode(DOSBase: *mut Library, fh: BPTR, mode: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-426, %a6)
-            ".short -426",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") mode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ExAll(
-    DOSBase: *mut Library,
-    lock: BPTR,
-    buffer: *mut ExAllData,
-    size: LONG,
-    data: LONG,
-    control: *mut ExAllControl,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-432, %a6)
-            ".short -432",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") buffer,
-            in("d3") size,
-            in("d4") data,
-            in("d5") control,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadLink(
-    DOSBase: *mut Library,
-    port: *mut MsgPort,
-    lock: BPTR,
-    path: CONST_STRPTR,
-    buffer: STRPTR,
-    size: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-438, %a6)
-            ".short -438",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") port,
-            in("d2") lock,
-            in("d3") path,
-            in("d4") buffer,
-            in("d5") size,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MakeLink(DOSBase: *mut Library, name: CONST_STRPTR, dest: LONG, soft: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-444, %a6)
-            ".short -444",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") dest,
-            in("d3") soft,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ChangeMode(DOSBase: *mut Library, type_: LONG, fh: BPTR, newmode: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") type_,
-            in("d2") fh,
-            in("d3") newmode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetFileSize(DOSBase: *mut Library, fh: BPTR, pos: LONG, mode: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            in("d2") pos,
-            in("d3") mode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetIoErr(DOSBase: *mut Library, result: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-462, %a6)
-            ".short -462",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") result,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Fault(
-    DOSBase: *mut Library,
-    code: LONG,
-    header: CONST_STRPTR,
-    buffer: STRPTR,
-    len: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-468, %a6)
-            ".short -468",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") code,
-            in("d2") header,
-            in("d3") buffer,
-            in("d4") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn PrintFault(DOSBase: *mut Library, code: LONG, header: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-474, %a6)
-            ".short -474",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") code,
-            in("d2") header,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ErrorReport(
-    DOSBase: *mut Library,
-    code: LONG,
-    type_: LONG,
-    arg1: ULONG,
-    device: *mut MsgPort,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-480, %a6)
-            ".short -480",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") code,
-            in("d2") type_,
-            in("d3") arg1,
-            in("d4") device,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Cli(DOSBase: *mut Library) -> *mut CommandLineInterface {
-    let asm_ret_value: *mut CommandLineInterface;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-492, %a6)
-            ".short -492",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateNewProc(DOSBase: *mut Library, tags: *const TagItem) -> *mut Process {
-    let asm_ret_value: *mut Process;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateNewProcTagList(DOSBase: *mut Library, tags: *const TagItem) -> *mut Process {
-    let asm_ret_value: *mut Process;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn RunCommand(
-    DOSBase: *mut Library,
-    seg: BPTR,
-    stack: LONG,
-    paramptr: CONST_STRPTR,
-    paramlen: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-504, %a6)
-            ".short -504",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") seg,
-            in("d2") stack,
-            in("d3") paramptr,
-            in("d4") paramlen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetConsoleTask(DOSBase: *mut Library) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-510, %a6)
-            ".short -510",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetConsoleTask(DOSBase: *mut Library, task: *mut MsgPort) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-516, %a6)
-            ".short -516",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") task,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetFileSysTask(DOSBase: *mut Library) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-522, %a6)
-            ".short -522",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetFileSysTask(DOSBase: *mut Library, task: *mut MsgPort) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-528, %a6)
-            ".short -528",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") task,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetArgStr(DOSBase: *mut Library) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-534, %a6)
-            ".short -534",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetArgStr(DOSBase: *mut Library, string: STRPTR) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-540, %a6)
-            ".short -540",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") string,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindCliProc(DOSBase: *mut Library, num: ULONG) -> *mut Process {
-    let asm_ret_value: *mut Process;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-546, %a6)
-            ".short -546",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") num,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MaxCli(DOSBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-552, %a6)
-            ".short -552",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetCurrentDirName(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-558, %a6)
-            ".short -558",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetCurrentDirName(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-564, %a6)
-            ".short -564",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") buf,
-            in("d2") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetProgramName(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-570, %a6)
-            ".short -570",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetProgramName(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-576, %a6)
-            ".short -576",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") buf,
-            in("d2") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetPrompt(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-582, %a6)
-            ".short -582",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetPrompt(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-588, %a6)
-            ".short -588",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") buf,
-            in("d2") len,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetProgramDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-594, %a6)
-            ".short -594",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetProgramDir(DOSBase: *mut Library) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-600, %a6)
-            ".short -600",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SystemTagList(
-    DOSBase: *mut Library,
-    command: CONST_STRPTR,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-606, %a6)
-            ".short -606",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") command,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn System(DOSBase: *mut Library, command: CONST_STRPTR, tags: *const TagItem) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-606, %a6)
-            ".short -606",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") command,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AssignLock(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-612, %a6)
-            ".short -612",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AssignLate(DOSBase: *mut Library, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-618, %a6)
-            ".short -618",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") path,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AssignPath(DOSBase: *mut Library, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-624, %a6)
-            ".short -624",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") path,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AssignAdd(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-630, %a6)
-            ".short -630",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemAssignList(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-636, %a6)
-            ".short -636",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") lock,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetDeviceProc(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    dp: *mut DevProc,
-) -> *mut DevProc {
-    let asm_ret_value: *mut DevProc;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-642, %a6)
-            ".short -642",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") dp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeDeviceProc(DOSBase: *mut Library, dp: *mut DevProc) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-648, %a6)
-            ".short -648",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dp,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockDosList(DOSBase: *mut Library, flags: ULONG) -> *mut DosList {
-    let asm_ret_value: *mut DosList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-654, %a6)
-            ".short -654",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnLockDosList(DOSBase: *mut Library, flags: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-660, %a6)
-            ".short -660",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") flags,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AttemptLockDosList(DOSBase: *mut Library, flags: ULONG) -> *mut DosList {
-    let asm_ret_value: *mut DosList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-666, %a6)
-            ".short -666",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemDosEntry(DOSBase: *mut Library, dlist: *mut DosList) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-672, %a6)
-            ".short -672",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dlist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddDosEntry(DOSBase: *mut Library, dlist: *mut DosList) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-678, %a6)
-            ".short -678",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dlist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindDosEntry(
-    DOSBase: *mut Library,
-    dlist: *const DosList,
-    name: CONST_STRPTR,
-    flags: ULONG,
-) -> *mut DosList {
-    let asm_ret_value: *mut DosList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-684, %a6)
-            ".short -684",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dlist,
-            in("d2") name,
-            in("d3") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn NextDosEntry(
-    DOSBase: *mut Library,
-    dlist: *const DosList,
-    flags: ULONG,
-) -> *mut DosList {
-    let asm_ret_value: *mut DosList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-690, %a6)
-            ".short -690",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dlist,
-            in("d2") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MakeDosEntry(DOSBase: *mut Library, name: CONST_STRPTR, type_: LONG) -> *mut DosList {
-    let asm_ret_value: *mut DosList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-696, %a6)
-            ".short -696",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") type_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeDosEntry(DOSBase: *mut Library, dlist: *mut DosList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-702, %a6)
-            ".short -702",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dlist,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn IsFileSystem(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-708, %a6)
-            ".short -708",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Format(
-    DOSBase: *mut Library,
-    filesystem: CONST_STRPTR,
-    volumename: CONST_STRPTR,
-    dostype: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-714, %a6)
-            ".short -714",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") filesystem,
-            in("d2") volumename,
-            in("d3") dostype,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Relabel(DOSBase: *mut Library, drive: CONST_STRPTR, newname: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-720, %a6)
-            ".short -720",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") drive,
-            in("d2") newname,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn Inhibit(DOSBase: *mut Library, name: CONST_STRPTR, onoff: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-726, %a6)
-            ".short -726",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") onoff,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddBuffers(DOSBase: *mut Library, name: CONST_STRPTR, number: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-732, %a6)
-            ".short -732",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") number,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CompareDates(
-    DOSBase: *mut Library,
-    date1: *const DateStamp,
-    date2: *const DateStamp,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-738, %a6)
-            ".short -738",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") date1,
-            in("d2") date2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DateToStr(DOSBase: *mut Library, datetime: *mut DateTime) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-744, %a6)
-            ".short -744",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") datetime,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn StrToDate(DOSBase: *mut Library, datetime: *mut DateTime) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-750, %a6)
-            ".short -750",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") datetime,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn InternalLoadSeg(
-    DOSBase: *mut Library,
-    fh: BPTR,
-    table: BPTR,
-    funcarray: *const LONG,
-    stack: *mut LONG,
-) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-756, %a6)
-            ".short -756",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d0") fh,
-            in("a0") table,
-            in("a1") funcarray,
-            in("a2") stack,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn InternalUnLoadSeg(DOSBase: *mut Library, seglist: BPTR, freefunc: FPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-762, %a6)
-            ".short -762",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") seglist,
-            in("a1") freefunc,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn NewLoadSeg(DOSBase: *mut Library, file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-768, %a6)
-            ".short -768",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn NewLoadSegTagList(
-    DOSBase: *mut Library,
-    file: CONST_STRPTR,
-    tags: *const TagItem,
-) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-768, %a6)
-            ".short -768",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") file,
-            in("d2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddSegment(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    seg: BPTR,
-    system: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-774, %a6)
-            ".short -774",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") seg,
-            in("d3") system,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindSegment(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    seg: *const Segment,
-    system: LONG,
-) -> *mut Segment {
-    let asm_ret_value: *mut Segment;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-780, %a6)
-            ".short -780",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") seg,
-            in("d3") system,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemSegment(DOSBase: *mut Library, seg: *mut Segment) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-786, %a6)
-            ".short -786",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") seg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CheckSignal(DOSBase: *mut Library, mask: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-792, %a6)
-            ".short -792",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") mask,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadArgs(
-    DOSBase: *mut Library,
-    arg_template: CONST_STRPTR,
-    array: *mut LONG,
-    args: *mut RDArgs,
-) -> *mut RDArgs {
-    let asm_ret_value: *mut RDArgs;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-798, %a6)
-            ".short -798",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") arg_template,
-            in("d2") array,
-            in("d3") args,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindArg(
-    DOSBase: *mut Library,
-    keyword: CONST_STRPTR,
-    arg_template: CONST_STRPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-804, %a6)
-            ".short -804",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") keyword,
-            in("d2") arg_template,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadItem(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    maxchars: LONG,
-    cSource: *mut CSource,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-810, %a6)
-            ".short -810",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") maxchars,
-            in("d3") cSource,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn StrToLong(DOSBase: *mut Library, string: CONST_STRPTR, value: *mut LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-816, %a6)
-            ".short -816",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") string,
-            in("d2") value,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MatchFirst(
-    DOSBase: *mut Library,
-    pat: CONST_STRPTR,
-    anchor: *mut AnchorPath,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-822, %a6)
-            ".short -822",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") pat,
-            in("d2") anchor,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MatchNext(DOSBase: *mut Library, anchor: *mut AnchorPath) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-828, %a6)
-            ".short -828",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") anchor,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MatchEnd(DOSBase: *mut Library, anchor: *mut AnchorPath) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-834, %a6)
-            ".short -834",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") anchor,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn ParsePattern(
-    DOSBase: *mut Library,
-    pat: CONST_STRPTR,
-    patbuf: *mut UBYTE,
-    patbuflen: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-840, %a6)
-            ".short -840",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") pat,
-            in("d2") patbuf,
-            in("d3") patbuflen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn MatchPattern(
-    DOSBase: *mut Library,
-    patbuf: *const UBYTE,
-    str_: CONST_STRPTR,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-846, %a6)
-            ".short -846",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") patbuf,
-            in("d2") str_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeArgs(DOSBase: *mut Library, args: *mut RDArgs) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-858, %a6)
-            ".short -858",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") args,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FilePart(DOSBase: *mut Library, path: CONST_STRPTR) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-870, %a6)
-            ".short -870",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") path,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn PathPart(DOSBase: *mut Library, path: CONST_STRPTR) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-876, %a6)
-            ".short -876",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") path,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddPart(
-    DOSBase: *mut Library,
-    dirname: STRPTR,
-    filename: CONST_STRPTR,
-    size: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-882, %a6)
-            ".short -882",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") dirname,
-            in("d2") filename,
-            in("d3") size,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn StartNotify(DOSBase: *mut Library, notify: *mut NotifyRequest) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-888, %a6)
-            ".short -888",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") notify,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn EndNotify(DOSBase: *mut Library, notify: *mut NotifyRequest) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-894, %a6)
-            ".short -894",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") notify,
-        );
-    }
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetVar(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    buffer: CONST_STRPTR,
-    size: LONG,
-    flags: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-900, %a6)
-            ".short -900",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") buffer,
-            in("d3") size,
-            in("d4") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetVar(
-    DOSBase: *mut Library,
-    name: CONST_STRPTR,
-    buffer: STRPTR,
-    size: LONG,
-    flags: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-906, %a6)
-            ".short -906",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") buffer,
-            in("d3") size,
-            in("d4") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteVar(DOSBase: *mut Library, name: CONST_STRPTR, flags: ULONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-912, %a6)
-            ".short -912",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") flags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindVar(DOSBase: *mut Library, name: CONST_STRPTR, type_: ULONG) -> *mut LocalVar {
-    let asm_ret_value: *mut LocalVar;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-918, %a6)
-            ".short -918",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") type_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CliInitNewcli(DOSBase: *mut Library, dp: *mut DosPacket) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-930, %a6)
-            ".short -930",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("a0") dp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn CliInitRun(DOSBase: *mut Library, dp: *mut DosPacket) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-936, %a6)
-            ".short -936",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("a0") dp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn WriteChars(DOSBase: *mut Library, buf: CONST_STRPTR, buflen: ULONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-942, %a6)
-            ".short -942",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") buf,
-            in("d2") buflen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn PutStr(DOSBase: *mut Library, str_: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-948, %a6)
-            ".short -948",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") str_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V36) (Kickstart 2.0)
-pub unsafe fn VPrintf(DOSBase: *mut Library, format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-954, %a6)
-            ".short -954",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") format,
-            in("d2") argarray,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V37) (Kickstart 2.04)
-pub unsafe fn ParsePatternNoCase(
-    DOSBase: *mut Library,
-    pat: CONST_STRPTR,
-    patbuf: *mut UBYTE,
-    patbuflen: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-966, %a6)
-            ".short -966",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") pat,
-            in("d2") patbuf,
-            in("d3") patbuflen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V37) (Kickstart 2.04)
-pub unsafe fn MatchPatternNoCase(
-    DOSBase: *mut Library,
-    patbuf: *const UBYTE,
-    str_: CONST_STRPTR,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-972, %a6)
-            ".short -972",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") patbuf,
-            in("d2") str_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V37) (Kickstart 2.04)
-pub unsafe fn SameDevice(DOSBase: *mut Library, lock1: BPTR, lock2: BPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-984, %a6)
-            ".short -984",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock1,
-            in("d2") lock2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V39) (Kickstart 3.0)
-pub unsafe fn ExAllEnd(
-    DOSBase: *mut Library,
-    lock: BPTR,
-    buffer: *mut ExAllData,
-    size: LONG,
-    data: LONG,
-    control: *mut ExAllControl,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-990, %a6)
-            ".short -990",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") lock,
-            in("d2") buffer,
-            in("d3") size,
-            in("d4") data,
-            in("d5") control,
-        );
-    }
-}
-
-/// libname: "dos.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetOwner(DOSBase: *mut Library, name: CONST_STRPTR, owner_info: LONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-996, %a6)
-            ".short -996",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") name,
-            in("d2") owner_info,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn VolumeRequestHook(DOSBase: *mut Library, vol: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1014, %a6)
-            ".short -1014",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") vol,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn GetCurrentDir(DOSBase: *mut Library) -> BPTR {
-    let asm_ret_value: BPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1026, %a6)
-            ".short -1026",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn PutErrStr(DOSBase: *mut Library, str_: CONST_STRPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1128, %a6)
-            ".short -1128",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") str_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ErrorOutput(DOSBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1134, %a6)
-            ".short -1134",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SelectError(DOSBase: *mut Library, fh: BPTR) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1140, %a6)
-            ".short -1140",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") fh,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn DoShellMethodTagList(
-    DOSBase: *mut Library,
-    method: ULONG,
-    tags: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1152, %a6)
-            ".short -1152",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d0") method,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ScanStackToken(DOSBase: *mut Library, seg: BPTR, defaultstack: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1158, %a6)
-            ".short -1158",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DOSBase,
-            in("d1") seg,
-            in("d2") defaultstack,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "drawlist.image" (V40) (Kickstart 3.1)
-pub unsafe fn DRAWLIST_GetClass(DrawListBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) DrawListBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Supervisor(SysBase: *mut Library, userFunction: FPTR) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a5, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a5reg}, %a5",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a5",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            a5reg = in(reg) userFunction,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn InitCode(SysBase: *mut Library, startClass: ULONG, version: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") startClass,
-            in("d1") version,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn InitStruct(SysBase: *mut Library, initTable: CONST_APTR, memory: APTR, size: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") initTable,
-            in("a2") memory,
-            in("d0") size,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn MakeLibrary(
-    SysBase: *mut Library,
-    funcInit: CONST_APTR,
-    structInit: CONST_APTR,
-    libInit: FPTR,
-    dataSize: ULONG,
-    segList: ULONG,
-) -> *mut Library {
-    let asm_ret_value: *mut Library;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") funcInit,
-            in("a1") structInit,
-            in("a2") libInit,
-            in("d0") dataSize,
-            in("d1") segList,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn MakeFunctions(
-    SysBase: *mut Library,
-    target: APTR,
-    functionArray: CONST_APTR,
-    funcDispBase: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") target,
-            in("a1") functionArray,
-            in("a2") funcDispBase,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn FindResident(SysBase: *mut Library, name: CONST_STRPTR) -> *mut Resident {
-    let asm_ret_value: *mut Resident;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn InitResident(
-    SysBase: *mut Library,
-    resident: *const Resident,
-    segList: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") resident,
-            in("d1") segList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Alert(SysBase: *mut Library, alertNum: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d7") alertNum,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Debug(SysBase: *mut Library, flags: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") flags,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Disable(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Enable(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Forbid(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Permit(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetSR(SysBase: *mut Library, newSR: ULONG, mask: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") newSR,
-            in("d1") mask,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn SuperState(SysBase: *mut Library) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn UserState(SysBase: *mut Library, sysStack: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") sysStack,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetIntVector(
-    SysBase: *mut Library,
-    intNumber: LONG,
-    interrupt: *mut Interrupt,
-) -> *mut Interrupt {
-    let asm_ret_value: *mut Interrupt;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") intNumber,
-            in("a1") interrupt,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddIntServer(SysBase: *mut Library, intNumber: LONG, interrupt: *mut Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") intNumber,
-            in("a1") interrupt,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemIntServer(SysBase: *mut Library, intNumber: LONG, interrupt: *mut Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") intNumber,
-            in("a1") interrupt,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Cause(SysBase: *mut Library, interrupt: *const Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") interrupt,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Allocate(SysBase: *mut Library, freeList: *mut MemHeader, byteSize: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") freeList,
-            in("d0") byteSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Deallocate(
-    SysBase: *mut Library,
-    freeList: *mut MemHeader,
-    memoryBlock: APTR,
-    byteSize: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") freeList,
-            in("a1") memoryBlock,
-            in("d0") byteSize,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AllocMem(SysBase: *mut Library, byteSize: ULONG, requirements: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") byteSize,
-            in("d1") requirements,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn AllocAbs(SysBase: *mut Library, byteSize: ULONG, location: APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") byteSize,
-            in("a1") location,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn FreeMem(SysBase: *mut Library, memoryBlock: APTR, byteSize: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") memoryBlock,
-            in("d0") byteSize,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AvailMem(SysBase: *mut Library, requirements: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d1") requirements,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn AllocEntry(SysBase: *mut Library, entry: *const MemList) -> *mut MemList {
-    let asm_ret_value: *mut MemList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") entry,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn FreeEntry(SysBase: *mut Library, entry: *mut MemList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") entry,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Insert(SysBase: *mut Library, list: *mut List, node: *mut Node, pred: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            in("a1") node,
-            in("a2") pred,
-        );
-    }
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn InsertMinNode(
-    SysBase: *mut Library,
-    minlist: *mut MinList,
-    minnode: *mut MinNode,
-    minpred: *mut MinNode,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-            in("a1") minnode,
-            in("a2") minpred,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddHead(SysBase: *mut Library, list: *mut List, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            in("a1") node,
-        );
-    }
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn AddHeadMinList(SysBase: *mut Library, minlist: *mut MinList, minnode: *mut MinNode) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-            in("a1") minnode,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddTail(SysBase: *mut Library, list: *mut List, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            in("a1") node,
-        );
-    }
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn AddTailMinList(SysBase: *mut Library, minlist: *mut MinList, minnode: *mut MinNode) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-            in("a1") minnode,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn Remove(SysBase: *mut Library, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") node,
-        );
-    }
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn RemoveMinNode(SysBase: *mut Library, minnode: *mut MinNode) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") minnode,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemHead(SysBase: *mut Library, list: *mut List) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn RemHeadMinList(SysBase: *mut Library, minlist: *mut MinList) -> *mut MinNode {
-    let asm_ret_value: *mut MinNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemTail(SysBase: *mut Library, list: *mut List) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn RemTailMinList(SysBase: *mut Library, minlist: *mut MinList) -> *mut MinNode {
-    let asm_ret_value: *mut MinNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Enqueue(SysBase: *mut Library, list: *mut List, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            in("a1") node,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn FindName(SysBase: *mut Library, list: *mut List, name: CONST_STRPTR) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-276, %a6)
-            ".short -276",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") list,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddTask(SysBase: *mut Library, task: *mut Task, initPC: APTR, finalPC: APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-282, %a6)
-            ".short -282",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") task,
-            in("a2") initPC,
-            in("a3") finalPC,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemTask(SysBase: *mut Library, task: *mut Task) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-288, %a6)
-            ".short -288",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") task,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn FindTask(SysBase: *mut Library, name: CONST_STRPTR) -> *mut Task {
-    let asm_ret_value: *mut Task;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-294, %a6)
-            ".short -294",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetTaskPri(SysBase: *mut Library, task: *mut Task, priority: LONG) -> BYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-300, %a6)
-            ".short -300",
-            "ext.w %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") task,
-            in("d0") priority,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as i8
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetSignal(SysBase: *mut Library, newSignals: ULONG, signalSet: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-306, %a6)
-            ".short -306",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") newSignals,
-            in("d1") signalSet,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetExcept(SysBase: *mut Library, newSignals: ULONG, signalSet: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-312, %a6)
-            ".short -312",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") newSignals,
-            in("d1") signalSet,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Wait(SysBase: *mut Library, signalSet: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-318, %a6)
-            ".short -318",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") signalSet,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Signal(SysBase: *mut Library, task: *mut Task, signalSet: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-324, %a6)
-            ".short -324",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") task,
-            in("d0") signalSet,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AllocSignal(SysBase: *mut Library, signalNum: LONG) -> BYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-330, %a6)
-            ".short -330",
-            "ext.w %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") signalNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as i8
-}
-
-/// libname: "exec.library"
-pub unsafe fn FreeSignal(SysBase: *mut Library, signalNum: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-336, %a6)
-            ".short -336",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") signalNum,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AllocTrap(SysBase: *mut Library, trapNum: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-342, %a6)
-            ".short -342",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") trapNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn FreeTrap(SysBase: *mut Library, trapNum: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-348, %a6)
-            ".short -348",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") trapNum,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddPort(SysBase: *mut Library, port: *mut MsgPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-354, %a6)
-            ".short -354",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") port,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemPort(SysBase: *mut Library, port: *mut MsgPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-360, %a6)
-            ".short -360",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") port,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn PutMsg(SysBase: *mut Library, port: *mut MsgPort, message: *mut Message) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-366, %a6)
-            ".short -366",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") port,
-            in("a1") message,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn GetMsg(SysBase: *mut Library, port: *mut MsgPort) -> *mut Message {
-    let asm_ret_value: *mut Message;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-372, %a6)
-            ".short -372",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") port,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn ReplyMsg(SysBase: *mut Library, message: *mut Message) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-378, %a6)
-            ".short -378",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") message,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn WaitPort(SysBase: *mut Library, port: *mut MsgPort) -> *mut Message {
-    let asm_ret_value: *mut Message;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-384, %a6)
-            ".short -384",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") port,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn FindPort(SysBase: *mut Library, name: CONST_STRPTR) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-390, %a6)
-            ".short -390",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddLibrary(SysBase: *mut Library, library: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-396, %a6)
-            ".short -396",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") library,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemLibrary(SysBase: *mut Library, library: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-402, %a6)
-            ".short -402",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") library,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn OldOpenLibrary(SysBase: *mut Library, libName: CONST_STRPTR) -> *mut Library {
-    let asm_ret_value: *mut Library;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-408, %a6)
-            ".short -408",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") libName,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn CloseLibrary(SysBase: *mut Library, library: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-414, %a6)
-            ".short -414",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") library,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn SetFunction(
-    SysBase: *mut Library,
-    library: *mut Library,
-    funcOffset: LONG,
-    newFunction: FPTR,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-420, %a6)
-            ".short -420",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") library,
-            in("a0") funcOffset,
-            in("d0") newFunction,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn SumLibrary(SysBase: *mut Library, library: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-426, %a6)
-            ".short -426",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") library,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddDevice(SysBase: *mut Library, device: *mut Device) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-432, %a6)
-            ".short -432",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") device,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemDevice(SysBase: *mut Library, device: *mut Device) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-438, %a6)
-            ".short -438",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") device,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn OpenDevice(
-    SysBase: *mut Library,
-    devName: CONST_STRPTR,
-    unit: ULONG,
-    ioRequest: *mut IORequest,
-    flags: ULONG,
-) -> BYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-444, %a6)
-            ".short -444",
-            "ext.w %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") devName,
-            in("d0") unit,
-            in("a1") ioRequest,
-            in("d1") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as i8
-}
-
-/// libname: "exec.library"
-pub unsafe fn CloseDevice(SysBase: *mut Library, ioRequest: *mut IORequest) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn DoIO(SysBase: *mut Library, ioRequest: *mut IORequest) -> BYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "ext.w %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as i8
-}
-
-/// libname: "exec.library"
-pub unsafe fn SendIO(SysBase: *mut Library, ioRequest: *mut IORequest) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-462, %a6)
-            ".short -462",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn CheckIO(SysBase: *mut Library, ioRequest: *const IORequest) -> *mut IORequest {
-    let asm_ret_value: *mut IORequest;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-468, %a6)
-            ".short -468",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn WaitIO(SysBase: *mut Library, ioRequest: *mut IORequest) -> BYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-474, %a6)
-            ".short -474",
-            "ext.w %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as i8
-}
-
-/// libname: "exec.library"
-pub unsafe fn AbortIO(SysBase: *mut Library, ioRequest: *mut IORequest) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-480, %a6)
-            ".short -480",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") ioRequest,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn AddResource(SysBase: *mut Library, resource: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-486, %a6)
-            ".short -486",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") resource,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn RemResource(SysBase: *mut Library, resource: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-492, %a6)
-            ".short -492",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") resource,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn OpenResource(SysBase: *mut Library, resName: CONST_STRPTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") resName,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn RawDoFmt(
-    SysBase: *mut Library,
-    formatString: CONST_STRPTR,
-    dataStream: APTR,
-    putChProc: FPTR,
-    putChData: APTR,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-522, %a6)
-            ".short -522",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") formatString,
-            in("a1") dataStream,
-            in("a2") putChProc,
-            in("a3") putChData,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn GetCC(SysBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-528, %a6)
-            ".short -528",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn TypeOfMem(SysBase: *mut Library, address: CONST_APTR) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-534, %a6)
-            ".short -534",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") address,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Procure(
-    SysBase: *mut Library,
-    sigSem: *mut SignalSemaphore,
-    bidMsg: *mut SemaphoreMessage,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-540, %a6)
-            ".short -540",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-            in("a1") bidMsg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library"
-pub unsafe fn Vacate(
-    SysBase: *mut Library,
-    sigSem: *mut SignalSemaphore,
-    bidMsg: *mut SemaphoreMessage,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-546, %a6)
-            ".short -546",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-            in("a1") bidMsg,
-        );
-    }
-}
-
-/// libname: "exec.library"
-pub unsafe fn OpenLibrary(
-    SysBase: *mut Library,
-    libName: CONST_STRPTR,
-    version: ULONG,
-) -> *mut Library {
-    let asm_ret_value: *mut Library;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-552, %a6)
-            ".short -552",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") libName,
-            in("d0") version,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn InitSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-558, %a6)
-            ".short -558",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn ObtainSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-564, %a6)
-            ".short -564",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn ReleaseSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-570, %a6)
-            ".short -570",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn AttemptSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-576, %a6)
-            ".short -576",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn ObtainSemaphoreList(SysBase: *mut Library, sigSem: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-582, %a6)
-            ".short -582",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn ReleaseSemaphoreList(SysBase: *mut Library, sigSem: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-588, %a6)
-            ".short -588",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn FindSemaphore(SysBase: *mut Library, name: CONST_STRPTR) -> *mut SignalSemaphore {
-    let asm_ret_value: *mut SignalSemaphore;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-594, %a6)
-            ".short -594",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn AddSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-600, %a6)
-            ".short -600",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn RemSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-606, %a6)
-            ".short -606",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn SumKickData(SysBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-612, %a6)
-            ".short -612",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn AddMemList(
-    SysBase: *mut Library,
-    size: ULONG,
-    attributes: ULONG,
-    pri: LONG,
-    base: APTR,
-    name: STRPTR,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-618, %a6)
-            ".short -618",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") size,
-            in("d1") attributes,
-            in("d2") pri,
-            in("a0") base,
-            in("a1") name,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn CopyMem(SysBase: *mut Library, source: CONST_APTR, dest: APTR, size: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-624, %a6)
-            ".short -624",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") source,
-            in("a1") dest,
-            in("d0") size,
-        );
-    }
-}
-
-/// libname: "exec.library" (V33) (Kickstart 1.2)
-pub unsafe fn CopyMemQuick(SysBase: *mut Library, source: CONST_APTR, dest: APTR, size: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-630, %a6)
-            ".short -630",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") source,
-            in("a1") dest,
-            in("d0") size,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CacheClearU(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-636, %a6)
-            ".short -636",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CacheClearE(SysBase: *mut Library, address: APTR, length: ULONG, caches: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-642, %a6)
-            ".short -642",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") address,
-            in("d0") length,
-            in("d1") caches,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CacheControl(SysBase: *mut Library, cacheBits: ULONG, cacheMask: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-648, %a6)
-            ".short -648",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") cacheBits,
-            in("d1") cacheMask,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateIORequest(SysBase: *mut Library, port: *mut MsgPort, size: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-654, %a6)
-            ".short -654",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") port,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteIORequest(SysBase: *mut Library, iorequest: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-660, %a6)
-            ".short -660",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") iorequest,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateMsgPort(SysBase: *mut Library) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-666, %a6)
-            ".short -666",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteMsgPort(SysBase: *mut Library, port: *mut MsgPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-672, %a6)
-            ".short -672",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") port,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn ObtainSemaphoreShared(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-678, %a6)
-            ".short -678",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocVec(SysBase: *mut Library, byteSize: ULONG, requirements: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-684, %a6)
-            ".short -684",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") byteSize,
-            in("d1") requirements,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeVec(SysBase: *mut Library, memoryBlock: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-690, %a6)
-            ".short -690",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") memoryBlock,
-        );
-    }
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn CreatePool(
-    SysBase: *mut Library,
-    requirements: ULONG,
-    puddleSize: ULONG,
-    threshSize: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-696, %a6)
-            ".short -696",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("d0") requirements,
-            in("d1") puddleSize,
-            in("d2") threshSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn DeletePool(SysBase: *mut Library, poolHeader: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-702, %a6)
-            ".short -702",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") poolHeader,
-        );
-    }
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocPooled(SysBase: *mut Library, poolHeader: APTR, memSize: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-708, %a6)
-            ".short -708",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") poolHeader,
-            in("d0") memSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreePooled(SysBase: *mut Library, poolHeader: APTR, memory: APTR, memSize: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-714, %a6)
-            ".short -714",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") poolHeader,
-            in("a1") memory,
-            in("d0") memSize,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn AttemptSemaphoreShared(SysBase: *mut Library, sigSem: *mut SignalSemaphore) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-720, %a6)
-            ".short -720",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") sigSem,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn ColdReboot(SysBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-726, %a6)
-            ".short -726",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn StackSwap(SysBase: *mut Library, newStack: *mut StackSwapStruct) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-732, %a6)
-            ".short -732",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") newStack,
-        );
-    }
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CachePreDMA(
-    SysBase: *mut Library,
-    address: CONST_APTR,
-    length: *mut ULONG,
-    flags: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-762, %a6)
-            ".short -762",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") address,
-            in("a1") length,
-            in("d0") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V36) (Kickstart 2.0)
-pub unsafe fn CachePostDMA(
-    SysBase: *mut Library,
-    address: CONST_APTR,
-    length: *mut ULONG,
-    flags: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-768, %a6)
-            ".short -768",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") address,
-            in("a1") length,
-            in("d0") flags,
-        );
-    }
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn AddMemHandler(SysBase: *mut Library, memhand: *mut Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-774, %a6)
-            ".short -774",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") memhand,
-        );
-    }
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn RemMemHandler(SysBase: *mut Library, memhand: *mut Interrupt) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-780, %a6)
-            ".short -780",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a1") memhand,
-        );
-    }
-}
-
-/// libname: "exec.library" (V39) (Kickstart 3.0)
-pub unsafe fn ObtainQuickVector(SysBase: *mut Library, interruptCode: APTR) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-786, %a6)
-            ".short -786",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") interruptCode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "exec.library" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn NewMinList(SysBase: *mut Library, minlist: *mut MinList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-828, %a6)
-            ".short -828",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SysBase,
-            in("a0") minlist,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn AddConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") configDev,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddBootNode(
-    ExpansionBase: *mut Library,
-    bootPri: LONG,
-    flags: ULONG,
-    deviceNode: *mut DeviceNode,
-    configDev: *mut ConfigDev,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") bootPri,
-            in("d1") flags,
-            in("a0") deviceNode,
-            in("a1") configDev,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn AllocBoardMem(ExpansionBase: *mut Library, slotSpec: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") slotSpec,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn AllocConfigDev(ExpansionBase: *mut Library) -> *mut ConfigDev {
-    let asm_ret_value: *mut ConfigDev;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn AllocExpansionMem(
-    ExpansionBase: *mut Library,
-    numSlots: ULONG,
-    slotAlign: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") numSlots,
-            in("d1") slotAlign,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ConfigBoard(ExpansionBase: *mut Library, board: APTR, configDev: *mut ConfigDev) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") board,
-            in("a1") configDev,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ConfigChain(ExpansionBase: *mut Library, baseAddr: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") baseAddr,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn FindConfigDev(
-    ExpansionBase: *mut Library,
-    oldConfigDev: *const ConfigDev,
-    manufacturer: LONG,
-    product: LONG,
-) -> *mut ConfigDev {
-    let asm_ret_value: *mut ConfigDev;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") oldConfigDev,
-            in("d0") manufacturer,
-            in("d1") product,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn FreeBoardMem(ExpansionBase: *mut Library, startSlot: ULONG, slotSpec: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") startSlot,
-            in("d1") slotSpec,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn FreeConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") configDev,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn FreeExpansionMem(ExpansionBase: *mut Library, startSlot: ULONG, numSlots: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") startSlot,
-            in("d1") numSlots,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ReadExpansionByte(
-    ExpansionBase: *mut Library,
-    board: CONST_APTR,
-    offset: ULONG,
-) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") board,
-            in("d0") offset,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ReadExpansionRom(
-    ExpansionBase: *mut Library,
-    board: CONST_APTR,
-    configDev: *mut ConfigDev,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") board,
-            in("a1") configDev,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn RemConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") configDev,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn WriteExpansionByte(
-    ExpansionBase: *mut Library,
-    board: APTR,
-    offset: ULONG,
-    byte: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") board,
-            in("d0") offset,
-            in("d1") byte,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ObtainConfigBinding(ExpansionBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn ReleaseConfigBinding(ExpansionBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn SetCurrentBinding(
-    ExpansionBase: *mut Library,
-    currentBinding: *mut CurrentBinding,
-    bindingSize: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") currentBinding,
-            in("d0") bindingSize,
-        );
-    }
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn GetCurrentBinding(
-    ExpansionBase: *mut Library,
-    currentBinding: *const CurrentBinding,
-    bindingSize: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") currentBinding,
-            in("d0") bindingSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn MakeDosNode(ExpansionBase: *mut Library, parmPacket: CONST_APTR) -> *mut DeviceNode {
-    let asm_ret_value: *mut DeviceNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("a0") parmPacket,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "expansion.library" (V33) (Kickstart 1.2)
-pub unsafe fn AddDosNode(
-    ExpansionBase: *mut Library,
-    bootPri: LONG,
-    flags: ULONG,
-    deviceNode: *mut DeviceNode,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ExpansionBase,
-            in("d0") bootPri,
-            in("d1") flags,
-            in("a0") deviceNode,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "fuelgauge.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FUELGAUGE_GetClass(FuelGaugeBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) FuelGaugeBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateGadgetA(
-    GadToolsBase: *mut Library,
-    kind: ULONG,
-    gad: *mut Gadget,
-    ng: *mut NewGadget,
-    taglist: *const TagItem,
-) -> *mut Gadget {
-    let asm_ret_value: *mut Gadget;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("d0") kind,
-            in("a0") gad,
-            in("a1") ng,
-            in("a2") taglist,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeGadgets(GadToolsBase: *mut Library, gad: *mut Gadget) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") gad,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_SetGadgetAttrsA(
-    GadToolsBase: *mut Library,
-    gad: *mut Gadget,
-    win: *mut Window,
-    req: *mut Requester,
-    taglist: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") gad,
-            in("a1") win,
-            in("a2") req,
-            in("a3") taglist,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateMenusA(
-    GadToolsBase: *mut Library,
-    newmenu: *const NewMenu,
-    taglist: *mut TagItem,
-) -> *mut Menu {
-    let asm_ret_value: *mut Menu;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") newmenu,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeMenus(GadToolsBase: *mut Library, menu: *mut Menu) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") menu,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn LayoutMenuItemsA(
-    GadToolsBase: *mut Library,
-    firstitem: *mut MenuItem,
-    vi: APTR,
-    taglist: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") firstitem,
-            in("a1") vi,
-            in("a2") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn LayoutMenusA(
-    GadToolsBase: *mut Library,
-    firstmenu: *mut Menu,
-    vi: APTR,
-    taglist: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") firstmenu,
-            in("a1") vi,
-            in("a2") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_GetIMsg(GadToolsBase: *mut Library, iport: *mut MsgPort) -> *mut IntuiMessage {
-    let asm_ret_value: *mut IntuiMessage;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") iport,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_ReplyIMsg(GadToolsBase: *mut Library, imsg: *mut IntuiMessage) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a1") imsg,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_RefreshWindow(GadToolsBase: *mut Library, win: *mut Window, req: *mut Requester) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") win,
-            in("a1") req,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_BeginRefresh(GadToolsBase: *mut Library, win: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") win,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_EndRefresh(GadToolsBase: *mut Library, win: *mut Window, complete: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") win,
-            in("d0") complete,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_FilterIMsg(
-    GadToolsBase: *mut Library,
-    imsg: *const IntuiMessage,
-) -> *mut IntuiMessage {
-    let asm_ret_value: *mut IntuiMessage;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a1") imsg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GT_PostFilterIMsg(
-    GadToolsBase: *mut Library,
-    imsg: *mut IntuiMessage,
-) -> *mut IntuiMessage {
-    let asm_ret_value: *mut IntuiMessage;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a1") imsg,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateContext(GadToolsBase: *mut Library, glistptr: *mut *mut Gadget) -> *mut Gadget {
-    let asm_ret_value: *mut Gadget;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") glistptr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn DrawBevelBoxA(
-    GadToolsBase: *mut Library,
-    rport: *mut RastPort,
-    left: LONG,
-    top: LONG,
-    width: LONG,
-    height: LONG,
-    taglist: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") rport,
-            in("d0") left,
-            in("d1") top,
-            in("d2") width,
-            in("d3") height,
-            in("a1") taglist,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetVisualInfoA(
-    GadToolsBase: *mut Library,
-    screen: *mut Screen,
-    taglist: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") screen,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeVisualInfo(GadToolsBase: *mut Library, vi: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") vi,
-        );
-    }
-}
-
-/// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SetDesignFontA(
-    GadToolsBase: *mut Library,
-    vi: APTR,
-    tattr: *mut TextAttr,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") vi,
-            in("a1") tattr,
-            in("a2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ScaleGadgetRectA(
-    GadToolsBase: *mut Library,
-    ng: *mut NewGadget,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") ng,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "gadtools.library" (V39) (Kickstart 3.0)
-pub unsafe fn GT_GetGadgetAttrsA(
-    GadToolsBase: *mut Library,
-    gad: *mut Gadget,
-    win: *mut Window,
-    req: *mut Requester,
-    taglist: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GadToolsBase,
-            in("a0") gad,
-            in("a1") win,
-            in("a2") req,
-            in("a3") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "getcolor.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GETCOLOR_GetClass(GetColorBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GetColorBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "getfile.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GETFILE_GetClass(GetFileBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GetFileBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "getfont.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GETFONT_GetClass(GetFontBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GetFontBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "getscreenmode.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GETSCREENMODE_GetClass(GetScreenModeBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GetScreenModeBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "glyph.image" (V40) (Kickstart 3.1)
-pub unsafe fn GLYPH_GetClass(GlyphBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GlyphBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltBitMap(
-    GfxBase: *mut Library,
-    srcBitMap: *const BitMap,
-    xSrc: LONG,
-    ySrc: LONG,
-    destBitMap: *mut BitMap,
-    xDest: LONG,
-    yDest: LONG,
-    xSize: LONG,
-    ySize: LONG,
-    minterm: ULONG,
-    mask: ULONG,
-    tempA: PLANEPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcBitMap,
-            in("d0") xSrc,
-            in("d1") ySrc,
-            in("a1") destBitMap,
-            in("d2") xDest,
-            in("d3") yDest,
-            in("d4") xSize,
-            in("d5") ySize,
-            in("d6") minterm,
-            in("d7") mask,
-            in("a2") tempA,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltTemplate(
-    GfxBase: *mut Library,
-    source: PLANEPTR,
-    xSrc: LONG,
-    srcMod: LONG,
-    destRP: *mut RastPort,
-    xDest: LONG,
-    yDest: LONG,
-    xSize: LONG,
-    ySize: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") source,
-            in("d0") xSrc,
-            in("d1") srcMod,
-            in("a1") destRP,
-            in("d2") xDest,
-            in("d3") yDest,
-            in("d4") xSize,
-            in("d5") ySize,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ClearEOL(GfxBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ClearScreen(GfxBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn TextLength(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    string: CONST_STRPTR,
-    count: ULONG,
-) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") string,
-            in("d0") count,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn Text(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    string: CONST_STRPTR,
-    count: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") string,
-            in("d0") count,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetFont(GfxBase: *mut Library, rp: *mut RastPort, textFont: *mut TextFont) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") textFont,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn OpenFont(GfxBase: *mut Library, textAttr: *const TextAttr) -> *mut TextFont {
-    let asm_ret_value: *mut TextFont;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") textAttr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn CloseFont(GfxBase: *mut Library, textFont: *mut TextFont) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") textFont,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AskSoftStyle(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetSoftStyle(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    style: ULONG,
-    enable: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") style,
-            in("d1") enable,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AddBob(GfxBase: *mut Library, bob: *mut Bob, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bob,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AddVSprite(GfxBase: *mut Library, vSprite: *mut VSprite, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vSprite,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn DoCollision(GfxBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn DrawGList(GfxBase: *mut Library, rp: *mut RastPort, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitGels(
-    GfxBase: *mut Library,
-    head: *mut VSprite,
-    tail: *mut VSprite,
-    gelsInfo: *mut GelsInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") head,
-            in("a1") tail,
-            in("a2") gelsInfo,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitMasks(GfxBase: *mut Library, vSprite: *mut VSprite) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vSprite,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn RemIBob(GfxBase: *mut Library, bob: *mut Bob, rp: *mut RastPort, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bob,
-            in("a1") rp,
-            in("a2") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn RemVSprite(GfxBase: *mut Library, vSprite: *mut VSprite) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vSprite,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetCollision(
-    GfxBase: *mut Library,
-    num: ULONG,
-    routine: FPTR,
-    gelsInfo: *mut GelsInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") num,
-            in("a0") routine,
-            in("a1") gelsInfo,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SortGList(GfxBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AddAnimOb(
-    GfxBase: *mut Library,
-    anOb: *mut AnimOb,
-    anKey: *mut *mut AnimOb,
-    rp: *mut RastPort,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") anOb,
-            in("a1") anKey,
-            in("a2") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn Animate(GfxBase: *mut Library, anKey: *mut *mut AnimOb, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") anKey,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn GetGBuffers(
-    GfxBase: *mut Library,
-    anOb: *mut AnimOb,
-    rp: *mut RastPort,
-    flag: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") anOb,
-            in("a1") rp,
-            in("d0") flag,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitGMasks(GfxBase: *mut Library, anOb: *mut AnimOb) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") anOb,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn DrawEllipse(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xCenter: LONG,
-    yCenter: LONG,
-    a: LONG,
-    b: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") xCenter,
-            in("d1") yCenter,
-            in("d2") a,
-            in("d3") b,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AreaEllipse(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xCenter: LONG,
-    yCenter: LONG,
-    a: LONG,
-    b: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") xCenter,
-            in("d1") yCenter,
-            in("d2") a,
-            in("d3") b,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn LoadRGB4(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    colors: *const UWORD,
-    count: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") colors,
-            in("d0") count,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitRastPort(GfxBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitVPort(GfxBase: *mut Library, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn MrgCop(GfxBase: *mut Library, view: *mut View) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") view,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn MakeVPort(GfxBase: *mut Library, view: *mut View, vp: *mut ViewPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") view,
-            in("a1") vp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn LoadView(GfxBase: *mut Library, view: *mut View) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") view,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn WaitBlit(GfxBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetRast(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") pen,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn Move(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn Draw(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AreaMove(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AreaDraw(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AreaEnd(GfxBase: *mut Library, rp: *mut RastPort) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn WaitTOF(GfxBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn QBlit(GfxBase: *mut Library, blit: *mut bltnode) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-276, %a6)
-            ".short -276",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") blit,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitArea(
-    GfxBase: *mut Library,
-    areaInfo: *mut AreaInfo,
-    vectorBuffer: APTR,
-    maxVectors: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-282, %a6)
-            ".short -282",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") areaInfo,
-            in("a1") vectorBuffer,
-            in("d0") maxVectors,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetRGB4(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    index: LONG,
-    red: ULONG,
-    green: ULONG,
-    blue: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-288, %a6)
-            ".short -288",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("d0") index,
-            in("d1") red,
-            in("d2") green,
-            in("d3") blue,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn QBSBlit(GfxBase: *mut Library, blit: *mut bltnode) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-294, %a6)
-            ".short -294",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") blit,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltClear(GfxBase: *mut Library, memBlock: PLANEPTR, byteCount: ULONG, flags: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-300, %a6)
-            ".short -300",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") memBlock,
-            in("d0") byteCount,
-            in("d1") flags,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn RectFill(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-306, %a6)
-            ".short -306",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") xMin,
-            in("d1") yMin,
-            in("d2") xMax,
-            in("d3") yMax,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltPattern(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    mask: PLANEPTR,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-    maskBPR: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-312, %a6)
-            ".short -312",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") mask,
-            in("d0") xMin,
-            in("d1") yMin,
-            in("d2") xMax,
-            in("d3") yMax,
-            in("d4") maskBPR,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ReadPixel(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-318, %a6)
-            ".short -318",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn WritePixel(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-324, %a6)
-            ".short -324",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn Flood(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    mode: ULONG,
-    x: LONG,
-    y: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-330, %a6)
-            ".short -330",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d2") mode,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn PolyDraw(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    count: LONG,
-    polyTable: *const WORD,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-336, %a6)
-            ".short -336",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") count,
-            in("a0") polyTable,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetAPen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-342, %a6)
-            ".short -342",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") pen,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetBPen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-348, %a6)
-            ".short -348",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") pen,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetDrMd(GfxBase: *mut Library, rp: *mut RastPort, drawMode: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-354, %a6)
-            ".short -354",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") drawMode,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitView(GfxBase: *mut Library, view: *mut View) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-360, %a6)
-            ".short -360",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") view,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn CBump(GfxBase: *mut Library, copList: *mut UCopList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-366, %a6)
-            ".short -366",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") copList,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn CMove(
-    GfxBase: *mut Library,
-    copList: *mut UCopList,
-    destination: APTR,
-    data: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-372, %a6)
-            ".short -372",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") copList,
-            in("d0") destination,
-            in("d1") data,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn CWait(GfxBase: *mut Library, copList: *mut UCopList, v: LONG, h: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-378, %a6)
-            ".short -378",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") copList,
-            in("d0") v,
-            in("d1") h,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn VBeamPos(GfxBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-384, %a6)
-            ".short -384",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitBitMap(
-    GfxBase: *mut Library,
-    bitMap: *mut BitMap,
-    depth: LONG,
-    width: LONG,
-    height: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-390, %a6)
-            ".short -390",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bitMap,
-            in("d0") depth,
-            in("d1") width,
-            in("d2") height,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ScrollRaster(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    dx: LONG,
-    dy: LONG,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-396, %a6)
-            ".short -396",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") dx,
-            in("d1") dy,
-            in("d2") xMin,
-            in("d3") yMin,
-            in("d4") xMax,
-            in("d5") yMax,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn WaitBOVP(GfxBase: *mut Library, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-402, %a6)
-            ".short -402",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn GetSprite(GfxBase: *mut Library, sprite: *mut SimpleSprite, num: LONG) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-408, %a6)
-            ".short -408",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") sprite,
-            in("d0") num,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeSprite(GfxBase: *mut Library, num: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-414, %a6)
-            ".short -414",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") num,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ChangeSprite(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    sprite: *mut SimpleSprite,
-    newData: *mut UWORD,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-420, %a6)
-            ".short -420",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") sprite,
-            in("a2") newData,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn MoveSprite(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    sprite: *mut SimpleSprite,
-    x: LONG,
-    y: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-426, %a6)
-            ".short -426",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") sprite,
-            in("d0") x,
-            in("d1") y,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn LockLayerRom(GfxBase: *mut Library, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a5, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a5reg}, %a5",
-            ".short 0x4eae", // jsr (-432, %a6)
-            ".short -432",
-            "move.l (%sp)+, %a5",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            a5reg = in(reg) layer,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn UnlockLayerRom(GfxBase: *mut Library, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a5, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a5reg}, %a5",
-            ".short 0x4eae", // jsr (-438, %a6)
-            ".short -438",
-            "move.l (%sp)+, %a5",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            a5reg = in(reg) layer,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SyncSBitMap(GfxBase: *mut Library, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-444, %a6)
-            ".short -444",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") layer,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn CopySBitMap(GfxBase: *mut Library, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") layer,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn OwnBlitter(GfxBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn DisownBlitter(GfxBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-462, %a6)
-            ".short -462",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn InitTmpRas(
-    GfxBase: *mut Library,
-    tmpRas: *mut TmpRas,
-    buffer: PLANEPTR,
-    size: LONG,
-) -> *mut TmpRas {
-    let asm_ret_value: *mut TmpRas;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-468, %a6)
-            ".short -468",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") tmpRas,
-            in("a1") buffer,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AskFont(GfxBase: *mut Library, rp: *mut RastPort, textAttr: *mut TextAttr) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-474, %a6)
-            ".short -474",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") textAttr,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AddFont(GfxBase: *mut Library, textFont: *mut TextFont) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-480, %a6)
-            ".short -480",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") textFont,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn RemFont(GfxBase: *mut Library, textFont: *mut TextFont) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-486, %a6)
-            ".short -486",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") textFont,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AllocRaster(GfxBase: *mut Library, width: ULONG, height: ULONG) -> PLANEPTR {
-    let asm_ret_value: PLANEPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-492, %a6)
-            ".short -492",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") width,
-            in("d1") height,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeRaster(GfxBase: *mut Library, p: PLANEPTR, width: ULONG, height: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") p,
-            in("d0") width,
-            in("d1") height,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AndRectRegion(
-    GfxBase: *mut Library,
-    region: *mut Region,
-    rectangle: *const Rectangle,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-504, %a6)
-            ".short -504",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-            in("a1") rectangle,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn OrRectRegion(
-    GfxBase: *mut Library,
-    region: *mut Region,
-    rectangle: *const Rectangle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-510, %a6)
-            ".short -510",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-            in("a1") rectangle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn NewRegion(GfxBase: *mut Library) -> *mut Region {
-    let asm_ret_value: *mut Region;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-516, %a6)
-            ".short -516",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ClearRectRegion(
-    GfxBase: *mut Library,
-    region: *mut Region,
-    rectangle: *const Rectangle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-522, %a6)
-            ".short -522",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-            in("a1") rectangle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ClearRegion(GfxBase: *mut Library, region: *mut Region) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-528, %a6)
-            ".short -528",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn DisposeRegion(GfxBase: *mut Library, region: *mut Region) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-534, %a6)
-            ".short -534",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeVPortCopLists(GfxBase: *mut Library, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-540, %a6)
-            ".short -540",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeCopList(GfxBase: *mut Library, copList: *mut CopList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-546, %a6)
-            ".short -546",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") copList,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ClipBlit(
-    GfxBase: *mut Library,
-    srcRP: *mut RastPort,
-    xSrc: LONG,
-    ySrc: LONG,
-    destRP: *mut RastPort,
-    xDest: LONG,
-    yDest: LONG,
-    xSize: LONG,
-    ySize: LONG,
-    minterm: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-552, %a6)
-            ".short -552",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcRP,
-            in("d0") xSrc,
-            in("d1") ySrc,
-            in("a1") destRP,
-            in("d2") xDest,
-            in("d3") yDest,
-            in("d4") xSize,
-            in("d5") ySize,
-            in("d6") minterm,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn XorRectRegion(
-    GfxBase: *mut Library,
-    region: *mut Region,
-    rectangle: *const Rectangle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-558, %a6)
-            ".short -558",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") region,
-            in("a1") rectangle,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeCprList(GfxBase: *mut Library, cprList: *mut cprlist) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-564, %a6)
-            ".short -564",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cprList,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn GetColorMap(GfxBase: *mut Library, entries: LONG) -> *mut ColorMap {
-    let asm_ret_value: *mut ColorMap;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-570, %a6)
-            ".short -570",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") entries,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeColorMap(GfxBase: *mut Library, colorMap: *mut ColorMap) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-576, %a6)
-            ".short -576",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") colorMap,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn GetRGB4(GfxBase: *mut Library, colorMap: *mut ColorMap, entry: LONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-582, %a6)
-            ".short -582",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") colorMap,
-            in("d0") entry,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn ScrollVPort(GfxBase: *mut Library, vp: *mut ViewPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-588, %a6)
-            ".short -588",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn UCopperListInit(
-    GfxBase: *mut Library,
-    uCopList: *mut UCopList,
-    n: LONG,
-) -> *mut CopList {
-    let asm_ret_value: *mut CopList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-594, %a6)
-            ".short -594",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") uCopList,
-            in("d0") n,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn FreeGBuffers(
-    GfxBase: *mut Library,
-    anOb: *mut AnimOb,
-    rp: *mut RastPort,
-    flag: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-600, %a6)
-            ".short -600",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") anOb,
-            in("a1") rp,
-            in("d0") flag,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltBitMapRastPort(
-    GfxBase: *mut Library,
-    srcBitMap: *const BitMap,
-    xSrc: LONG,
-    ySrc: LONG,
-    destRP: *mut RastPort,
-    xDest: LONG,
-    yDest: LONG,
-    xSize: LONG,
-    ySize: LONG,
-    minterm: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-606, %a6)
-            ".short -606",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcBitMap,
-            in("d0") xSrc,
-            in("d1") ySrc,
-            in("a1") destRP,
-            in("d2") xDest,
-            in("d3") yDest,
-            in("d4") xSize,
-            in("d5") ySize,
-            in("d6") minterm,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn OrRegionRegion(
-    GfxBase: *mut Library,
-    srcRegion: *const Region,
-    destRegion: *mut Region,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-612, %a6)
-            ".short -612",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcRegion,
-            in("a1") destRegion,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn XorRegionRegion(
-    GfxBase: *mut Library,
-    srcRegion: *const Region,
-    destRegion: *mut Region,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-618, %a6)
-            ".short -618",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcRegion,
-            in("a1") destRegion,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AndRegionRegion(
-    GfxBase: *mut Library,
-    srcRegion: *const Region,
-    destRegion: *mut Region,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-624, %a6)
-            ".short -624",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcRegion,
-            in("a1") destRegion,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library"
-pub unsafe fn SetRGB4CM(
-    GfxBase: *mut Library,
-    colorMap: *mut ColorMap,
-    index: LONG,
-    red: ULONG,
-    green: ULONG,
-    blue: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-630, %a6)
-            ".short -630",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") colorMap,
-            in("d0") index,
-            in("d1") red,
-            in("d2") green,
-            in("d3") blue,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn BltMaskBitMapRastPort(
-    GfxBase: *mut Library,
-    srcBitMap: *const BitMap,
-    xSrc: LONG,
-    ySrc: LONG,
-    destRP: *mut RastPort,
-    xDest: LONG,
-    yDest: LONG,
-    xSize: LONG,
-    ySize: LONG,
-    minterm: ULONG,
-    bltMask: PLANEPTR,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-636, %a6)
-            ".short -636",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") srcBitMap,
-            in("d0") xSrc,
-            in("d1") ySrc,
-            in("a1") destRP,
-            in("d2") xDest,
-            in("d3") yDest,
-            in("d4") xSize,
-            in("d5") ySize,
-            in("d6") minterm,
-            in("a2") bltMask,
-        );
-    }
-}
-
-/// libname: "graphics.library"
-pub unsafe fn AttemptLockLayerRom(GfxBase: *mut Library, layer: *mut Layer) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a5, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a5reg}, %a5",
-            ".short 0x4eae", // jsr (-654, %a6)
-            ".short -654",
-            "move.l (%sp)+, %a5",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            a5reg = in(reg) layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GfxNew(GfxBase: *mut Library, gfxNodeType: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-660, %a6)
-            ".short -660",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") gfxNodeType,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GfxFree(GfxBase: *mut Library, gfxNodePtr: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-666, %a6)
-            ".short -666",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") gfxNodePtr,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GfxAssociate(GfxBase: *mut Library, associateNode: APTR, gfxNodePtr: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-672, %a6)
-            ".short -672",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") associateNode,
-            in("a1") gfxNodePtr,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn BitMapScale(GfxBase: *mut Library, bitScaleArgs: *mut BitScaleArgs) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-678, %a6)
-            ".short -678",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bitScaleArgs,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn ScalerDiv(
-    GfxBase: *mut Library,
-    factor: ULONG,
-    numerator: ULONG,
-    denominator: ULONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-684, %a6)
-            ".short -684",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") factor,
-            in("d1") numerator,
-            in("d2") denominator,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn TextExtent(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    string: CONST_STRPTR,
-    count: LONG,
-    textExtent: *mut TextExtent,
-) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-690, %a6)
-            ".short -690",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") string,
-            in("d0") count,
-            in("a2") textExtent,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn TextFit(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    string: CONST_STRPTR,
-    strLen: ULONG,
-    textExtent: *const TextExtent,
-    constrainingExtent: *const TextExtent,
-    strDirection: LONG,
-    constrainingBitWidth: ULONG,
-    constrainingBitHeight: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-696, %a6)
-            ".short -696",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("a0") string,
-            in("d0") strLen,
-            in("a2") textExtent,
-            in("a3") constrainingExtent,
-            in("d1") strDirection,
-            in("d2") constrainingBitWidth,
-            in("d3") constrainingBitHeight,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GfxLookUp(GfxBase: *mut Library, associateNode: CONST_APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-702, %a6)
-            ".short -702",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") associateNode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn VideoControl(
-    GfxBase: *mut Library,
-    colorMap: *mut ColorMap,
-    tagarray: *mut TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-708, %a6)
-            ".short -708",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") colorMap,
-            in("a1") tagarray,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenMonitor(
-    GfxBase: *mut Library,
-    monitorName: CONST_STRPTR,
-    displayID: ULONG,
-) -> *mut MonitorSpec {
-    let asm_ret_value: *mut MonitorSpec;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-714, %a6)
-            ".short -714",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") monitorName,
-            in("d0") displayID,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn CloseMonitor(GfxBase: *mut Library, monitorSpec: *mut MonitorSpec) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-720, %a6)
-            ".short -720",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") monitorSpec,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindDisplayInfo(GfxBase: *mut Library, displayID: ULONG) -> DisplayInfoHandle {
-    let asm_ret_value: DisplayInfoHandle;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-726, %a6)
-            ".short -726",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") displayID,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn NextDisplayInfo(GfxBase: *mut Library, displayID: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-732, %a6)
-            ".short -732",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") displayID,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetDisplayInfoData(
-    GfxBase: *mut Library,
-    handle: DisplayInfoHandle,
-    buf: APTR,
-    size: ULONG,
-    tagID: ULONG,
-    displayID: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-756, %a6)
-            ".short -756",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") handle,
-            in("a1") buf,
-            in("d0") size,
-            in("d1") tagID,
-            in("d2") displayID,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn FontExtent(
-    GfxBase: *mut Library,
-    font: *const TextFont,
-    fontExtent: *mut TextExtent,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-762, %a6)
-            ".short -762",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") font,
-            in("a1") fontExtent,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadPixelLine8(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xstart: ULONG,
-    ystart: ULONG,
-    width: ULONG,
-    array: *mut UBYTE,
-    tempRP: *mut RastPort,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-768, %a6)
-            ".short -768",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") xstart,
-            in("d1") ystart,
-            in("d2") width,
-            in("a2") array,
-            in("a1") tempRP,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn WritePixelLine8(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xstart: ULONG,
-    ystart: ULONG,
-    width: ULONG,
-    array: *mut UBYTE,
-    tempRP: *mut RastPort,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-774, %a6)
-            ".short -774",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") xstart,
-            in("d1") ystart,
-            in("d2") width,
-            in("a2") array,
-            in("a1") tempRP,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadPixelArray8(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xstart: ULONG,
-    ystart: ULONG,
-    xstop: ULONG,
-    ystop: ULONG,
-    array: *mut UBYTE,
-    temprp: *mut RastPort,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-780, %a6)
-            ".short -780",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") xstart,
-            in("d1") ystart,
-            in("d2") xstop,
-            in("d3") ystop,
-            in("a2") array,
-            in("a1") temprp,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn WritePixelArray8(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xstart: ULONG,
-    ystart: ULONG,
-    xstop: ULONG,
-    ystop: ULONG,
-    array: *mut UBYTE,
-    temprp: *mut RastPort,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-786, %a6)
-            ".short -786",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") xstart,
-            in("d1") ystart,
-            in("d2") xstop,
-            in("d3") ystop,
-            in("a2") array,
-            in("a1") temprp,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetVPModeID(GfxBase: *mut Library, vp: *const ViewPort) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-792, %a6)
-            ".short -792",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn ModeNotAvailable(GfxBase: *mut Library, modeID: ULONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-798, %a6)
-            ".short -798",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") modeID,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn WeighTAMatch(
-    GfxBase: *mut Library,
-    reqTextAttr: *const TextAttr,
-    targetTextAttr: *const TextAttr,
-    targetTags: *const TagItem,
-) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-804, %a6)
-            ".short -804",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") reqTextAttr,
-            in("a1") targetTextAttr,
-            in("a2") targetTags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn EraseRect(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-810, %a6)
-            ".short -810",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") xMin,
-            in("d1") yMin,
-            in("d2") xMax,
-            in("d3") yMax,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn ExtendFont(
-    GfxBase: *mut Library,
-    font: *mut TextFont,
-    fontTags: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-816, %a6)
-            ".short -816",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") font,
-            in("a1") fontTags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V36) (Kickstart 2.0)
-pub unsafe fn StripFont(GfxBase: *mut Library, font: *mut TextFont) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-822, %a6)
-            ".short -822",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") font,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn CalcIVG(GfxBase: *mut Library, v: *mut View, vp: *mut ViewPort) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-828, %a6)
-            ".short -828",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") v,
-            in("a1") vp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn AttachPalExtra(GfxBase: *mut Library, cm: *mut ColorMap, vp: *mut ViewPort) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-834, %a6)
-            ".short -834",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("a1") vp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ObtainBestPenA(
-    GfxBase: *mut Library,
-    cm: *mut ColorMap,
-    r: ULONG,
-    g: ULONG,
-    b: ULONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-840, %a6)
-            ".short -840",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("d1") r,
-            in("d2") g,
-            in("d3") b,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetRGB32(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    n: ULONG,
-    r: ULONG,
-    g: ULONG,
-    b: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-852, %a6)
-            ".short -852",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("d0") n,
-            in("d1") r,
-            in("d2") g,
-            in("d3") b,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetAPen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-858, %a6)
-            ".short -858",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetBPen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-864, %a6)
-            ".short -864",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetDrMd(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-870, %a6)
-            ".short -870",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetOutlinePen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-876, %a6)
-            ".short -876",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn LoadRGB32(GfxBase: *mut Library, vp: *mut ViewPort, table: *const ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-882, %a6)
-            ".short -882",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") table,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetChipRev(GfxBase: *mut Library, want: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-888, %a6)
-            ".short -888",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") want,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetABPenDrMd(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    apen: ULONG,
-    bpen: ULONG,
-    drawmode: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-894, %a6)
-            ".short -894",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") apen,
-            in("d1") bpen,
-            in("d2") drawmode,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetRGB32(
-    GfxBase: *mut Library,
-    cm: *const ColorMap,
-    firstcolor: ULONG,
-    ncolors: ULONG,
-    table: *mut ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-900, %a6)
-            ".short -900",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("d0") firstcolor,
-            in("d1") ncolors,
-            in("a1") table,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocBitMap(
-    GfxBase: *mut Library,
-    sizex: ULONG,
-    sizey: ULONG,
-    depth: ULONG,
-    flags: ULONG,
-    friend_bitmap: *const BitMap,
-) -> *mut BitMap {
-    let asm_ret_value: *mut BitMap;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-918, %a6)
-            ".short -918",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("d0") sizex,
-            in("d1") sizey,
-            in("d2") depth,
-            in("d3") flags,
-            in("a0") friend_bitmap,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreeBitMap(GfxBase: *mut Library, bm: *mut BitMap) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-924, %a6)
-            ".short -924",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bm,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetExtSpriteA(
-    GfxBase: *mut Library,
-    ss: *mut ExtSprite,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-930, %a6)
-            ".short -930",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a2") ss,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn CoerceMode(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    monitorid: ULONG,
-    flags: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-936, %a6)
-            ".short -936",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("d0") monitorid,
-            in("d1") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ChangeVPBitMap(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    bm: *mut BitMap,
-    db: *mut DBufInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-942, %a6)
-            ".short -942",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") bm,
-            in("a2") db,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ReleasePen(GfxBase: *mut Library, cm: *mut ColorMap, n: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-948, %a6)
-            ".short -948",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("d0") n,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ObtainPen(
-    GfxBase: *mut Library,
-    cm: *mut ColorMap,
-    n: ULONG,
-    r: ULONG,
-    g: ULONG,
-    b: ULONG,
-    f: LONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-954, %a6)
-            ".short -954",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("d0") n,
-            in("d1") r,
-            in("d2") g,
-            in("d3") b,
-            in("d4") f,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetBitMapAttr(GfxBase: *mut Library, bm: *const BitMap, attrnum: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-960, %a6)
-            ".short -960",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") bm,
-            in("d1") attrnum,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocDBufInfo(GfxBase: *mut Library, vp: *mut ViewPort) -> *mut DBufInfo {
-    let asm_ret_value: *mut DBufInfo;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-966, %a6)
-            ".short -966",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreeDBufInfo(GfxBase: *mut Library, dbi: *mut DBufInfo) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-972, %a6)
-            ".short -972",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") dbi,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetOutlinePen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-978, %a6)
-            ".short -978",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") pen,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetWriteMask(GfxBase: *mut Library, rp: *mut RastPort, msk: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-984, %a6)
-            ".short -984",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") msk,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetMaxPen(GfxBase: *mut Library, rp: *mut RastPort, maxpen: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-990, %a6)
-            ".short -990",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") maxpen,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetRGB32CM(
-    GfxBase: *mut Library,
-    cm: *mut ColorMap,
-    n: ULONG,
-    r: ULONG,
-    g: ULONG,
-    b: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-996, %a6)
-            ".short -996",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") cm,
-            in("d0") n,
-            in("d1") r,
-            in("d2") g,
-            in("d3") b,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ScrollRasterBF(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    dx: LONG,
-    dy: LONG,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1002, %a6)
-            ".short -1002",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a1") rp,
-            in("d0") dx,
-            in("d1") dy,
-            in("d2") xMin,
-            in("d3") yMin,
-            in("d4") xMax,
-            in("d5") yMax,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn FindColor(
-    GfxBase: *mut Library,
-    cm: *mut ColorMap,
-    r: ULONG,
-    g: ULONG,
-    b: ULONG,
-    maxcolor: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1008, %a6)
-            ".short -1008",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a3") cm,
-            in("d1") r,
-            in("d2") g,
-            in("d3") b,
-            in("d4") maxcolor,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocSpriteDataA(
-    GfxBase: *mut Library,
-    bm: *const BitMap,
-    tags: *const TagItem,
-) -> *mut ExtSprite {
-    let asm_ret_value: *mut ExtSprite;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1020, %a6)
-            ".short -1020",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a2") bm,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn ChangeExtSpriteA(
-    GfxBase: *mut Library,
-    vp: *mut ViewPort,
-    oldsprite: *mut ExtSprite,
-    newsprite: *mut ExtSprite,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1026, %a6)
-            ".short -1026",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") vp,
-            in("a1") oldsprite,
-            in("a2") newsprite,
-            in("a3") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreeSpriteData(GfxBase: *mut Library, sp: *mut ExtSprite) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1032, %a6)
-            ".short -1032",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a2") sp,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetRPAttrsA(GfxBase: *mut Library, rp: *mut RastPort, tags: *const TagItem) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1038, %a6)
-            ".short -1038",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetRPAttrsA(GfxBase: *mut Library, rp: *mut RastPort, tags: *const TagItem) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1044, %a6)
-            ".short -1044",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "graphics.library" (V39) (Kickstart 3.0)
-pub unsafe fn BestModeIDA(GfxBase: *mut Library, tags: *const TagItem) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1050, %a6)
-            ".short -1050",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "graphics.library" (V40) (Kickstart 3.1)
-pub unsafe fn WriteChunkyPixels(
-    GfxBase: *mut Library,
-    rp: *mut RastPort,
-    xstart: ULONG,
-    ystart: ULONG,
-    xstop: ULONG,
-    ystop: ULONG,
-    array: *mut UBYTE,
-    bytesperrow: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1056, %a6)
-            ".short -1056",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) GfxBase,
-            in("a0") rp,
-            in("d0") xstart,
-            in("d1") ystart,
-            in("d2") xstop,
-            in("d3") ystop,
-            in("a2") array,
-            in("d4") bytesperrow,
-        );
-    }
-}
-
-/// libname: "icon.library"
-pub unsafe fn FreeFreeList(IconBase: *mut Library, freelist: *mut FreeList) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") freelist,
-        );
-    }
-}
-
-/// libname: "icon.library"
-pub unsafe fn AddFreeList(
-    IconBase: *mut Library,
-    freelist: *mut FreeList,
-    mem: CONST_APTR,
-    size: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") freelist,
-            in("a1") mem,
-            in("a2") size,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library"
-pub unsafe fn GetDiskObject(IconBase: *mut Library, name: CONST_STRPTR) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library"
-pub unsafe fn PutDiskObject(
-    IconBase: *mut Library,
-    name: CONST_STRPTR,
-    diskobj: *const DiskObject,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            in("a1") diskobj,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library"
-pub unsafe fn FreeDiskObject(IconBase: *mut Library, diskobj: *mut DiskObject) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") diskobj,
-        );
-    }
-}
-
-/// libname: "icon.library"
-pub unsafe fn FindToolType(
-    IconBase: *mut Library,
-    toolTypeArray: *mut CONST_STRPTR,
-    typeName: CONST_STRPTR,
-) -> *mut UBYTE {
-    let asm_ret_value: *mut UBYTE;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") toolTypeArray,
-            in("a1") typeName,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library"
-pub unsafe fn MatchToolValue(
-    IconBase: *mut Library,
-    typeString: CONST_STRPTR,
-    value: CONST_STRPTR,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") typeString,
-            in("a1") value,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library"
-pub unsafe fn BumpRevision(
-    IconBase: *mut Library,
-    newname: STRPTR,
-    oldname: CONST_STRPTR,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") newname,
-            in("a1") oldname,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeAlloc(
-    IconBase: *mut Library,
-    free: *mut FreeList,
-    len: ULONG,
-    type_: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") free,
-            in("a1") len,
-            in("a2") type_,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetDefDiskObject(IconBase: *mut Library, type_: LONG) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("d0") type_,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V36) (Kickstart 2.0)
-pub unsafe fn PutDefDiskObject(IconBase: *mut Library, diskObject: *const DiskObject) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") diskObject,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetDiskObjectNew(IconBase: *mut Library, name: CONST_STRPTR) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V37) (Kickstart 2.04)
-pub unsafe fn DeleteDiskObject(IconBase: *mut Library, name: CONST_STRPTR) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn FreeFree(IconBase: *mut Library, fl: *mut FreeList, address: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") fl,
-            in("a1") address,
-        );
-    }
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn DupDiskObjectA(
-    IconBase: *mut Library,
-    diskObject: *const DiskObject,
-    tags: *const TagItem,
-) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") diskObject,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn IconControlA(
-    IconBase: *mut Library,
-    icon: *mut DiskObject,
-    tags: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") icon,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn DrawIconStateA(
-    IconBase: *mut Library,
-    rp: *mut RastPort,
-    icon: *const DiskObject,
-    label: CONST_STRPTR,
-    leftOffset: LONG,
-    topOffset: LONG,
-    state: ULONG,
-    tags: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") rp,
-            in("a1") icon,
-            in("a2") label,
-            in("d0") leftOffset,
-            in("d1") topOffset,
-            in("d2") state,
-            in("a3") tags,
-        );
-    }
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn GetIconRectangleA(
-    IconBase: *mut Library,
-    rp: *mut RastPort,
-    icon: *const DiskObject,
-    label: CONST_STRPTR,
-    rect: *mut Rectangle,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a4, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a4reg}, %a4",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a4",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") rp,
-            in("a1") icon,
-            in("a2") label,
-            in("a3") rect,
-            a4reg = in(reg) tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn NewDiskObject(IconBase: *mut Library, type_: LONG) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("d0") type_,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn GetIconTagList(
-    IconBase: *mut Library,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> *mut DiskObject {
-    let asm_ret_value: *mut DiskObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn PutIconTagList(
-    IconBase: *mut Library,
-    name: CONST_STRPTR,
-    icon: *const DiskObject,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") name,
-            in("a1") icon,
-            in("a2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn LayoutIconA(
-    IconBase: *mut Library,
-    icon: *mut DiskObject,
-    screen: *mut Screen,
-    tags: *mut TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") icon,
-            in("a1") screen,
-            in("a2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn ChangeToSelectedIconColor(IconBase: *mut Library, cr: *mut ColorRegister) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") cr,
-        );
-    }
-}
-
-/// libname: "icon.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn BumpRevisionLength(
-    IconBase: *mut Library,
-    newname: STRPTR,
-    oldname: CONST_STRPTR,
-    maxLength: ULONG,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IconBase,
-            in("a0") newname,
-            in("a1") oldname,
-            in("d0") maxLength,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocIFF(IFFParseBase: *mut Library) -> *mut IFFHandle {
-    let asm_ret_value: *mut IFFHandle;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle, rwMode: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") rwMode,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn ParseIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle, control: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") control,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn CloseIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadChunkBytes(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    buf: APTR,
-    numBytes: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") buf,
-            in("d0") numBytes,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn WriteChunkBytes(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    buf: CONST_APTR,
-    numBytes: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") buf,
-            in("d0") numBytes,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReadChunkRecords(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    buf: APTR,
-    bytesPerRecord: LONG,
-    numRecords: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") buf,
-            in("d0") bytesPerRecord,
-            in("d1") numRecords,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn WriteChunkRecords(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    buf: CONST_APTR,
-    bytesPerRecord: LONG,
-    numRecords: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") buf,
-            in("d0") bytesPerRecord,
-            in("d1") numRecords,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn PushChunk(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-    size: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            in("d2") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn PopChunk(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn EntryHandler(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-    position: LONG,
-    handler: *mut Hook,
-    object: APTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            in("d2") position,
-            in("a1") handler,
-            in("a2") object,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn ExitHandler(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-    position: LONG,
-    handler: *mut Hook,
-    object: APTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            in("d2") position,
-            in("a1") handler,
-            in("a2") object,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn PropChunk(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn PropChunks(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    propArray: *const LONG,
-    numPairs: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") propArray,
-            in("d0") numPairs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn StopChunk(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn StopChunks(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    propArray: *const LONG,
-    numPairs: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") propArray,
-            in("d0") numPairs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn CollectionChunk(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn CollectionChunks(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    propArray: *const LONG,
-    numPairs: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") propArray,
-            in("d0") numPairs,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn StopOnExit(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindProp(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> *mut StoredProperty {
-    let asm_ret_value: *mut StoredProperty;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindCollection(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-) -> *mut CollectionItem {
-    let asm_ret_value: *mut CollectionItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindPropContext(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> *mut ContextNode {
-    let asm_ret_value: *mut ContextNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn CurrentChunk(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> *mut ContextNode {
-    let asm_ret_value: *mut ContextNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn ParentChunk(
-    IFFParseBase: *mut Library,
-    contextNode: *mut ContextNode,
-) -> *mut ContextNode {
-    let asm_ret_value: *mut ContextNode;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") contextNode,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocLocalItem(
-    IFFParseBase: *mut Library,
-    type_: LONG,
-    id: LONG,
-    ident: LONG,
-    dataSize: LONG,
-) -> *mut LocalContextItem {
-    let asm_ret_value: *mut LocalContextItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("d0") type_,
-            in("d1") id,
-            in("d2") ident,
-            in("d3") dataSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn LocalItemData(IFFParseBase: *mut Library, localItem: *mut LocalContextItem) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") localItem,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetLocalItemPurge(
-    IFFParseBase: *mut Library,
-    localItem: *mut LocalContextItem,
-    purgeHook: *mut Hook,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") localItem,
-            in("a1") purgeHook,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeLocalItem(IFFParseBase: *mut Library, localItem: *mut LocalContextItem) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") localItem,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindLocalItem(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    type_: LONG,
-    id: LONG,
-    ident: LONG,
-) -> *mut LocalContextItem {
-    let asm_ret_value: *mut LocalContextItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") type_,
-            in("d1") id,
-            in("d2") ident,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn StoreLocalItem(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    localItem: *mut LocalContextItem,
-    position: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") localItem,
-            in("d0") position,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn StoreItemInContext(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    localItem: *mut LocalContextItem,
-    contextNode: *mut ContextNode,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("a1") localItem,
-            in("a2") contextNode,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn InitIFF(
-    IFFParseBase: *mut Library,
-    iff: *mut IFFHandle,
-    flags: LONG,
-    streamHook: *mut Hook,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-            in("d0") flags,
-            in("a1") streamHook,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn InitIFFasDOS(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn InitIFFasClip(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") iff,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenClipboard(IFFParseBase: *mut Library, unitNumber: LONG) -> *mut ClipboardHandle {
-    let asm_ret_value: *mut ClipboardHandle;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("d0") unitNumber,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn CloseClipboard(IFFParseBase: *mut Library, clipHandle: *mut ClipboardHandle) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("a0") clipHandle,
-        );
-    }
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn GoodID(IFFParseBase: *mut Library, id: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("d0") id,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn GoodType(IFFParseBase: *mut Library, type_: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("d0") type_,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "iffparse.library" (V36) (Kickstart 2.0)
-pub unsafe fn IDtoStr(IFFParseBase: *mut Library, id: LONG, buf: STRPTR) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IFFParseBase,
-            in("d0") id,
-            in("a0") buf,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "input.device" (V36) (Kickstart 2.0)
-pub unsafe fn PeekQualifier(InputBase: *mut ::core::ffi::c_void) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) InputBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "integer.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn INTEGER_GetClass(IntegerBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntegerBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OpenIntuition(IntuitionBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn Intuition(IntuitionBase: *mut Library, iEvent: *mut InputEvent) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") iEvent,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn AddGadget(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    gadget: *mut Gadget,
-    position: ULONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") gadget,
-            in("d0") position,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ClearDMRequest(IntuitionBase: *mut Library, window: *mut Window) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ClearMenuStrip(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ClearPointer(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn CloseScreen(IntuitionBase: *mut Library, screen: *mut Screen) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn CloseWindow(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn CloseWorkBench(IntuitionBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn CurrentTime(IntuitionBase: *mut Library, seconds: *mut ULONG, micros: *mut ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") seconds,
-            in("a1") micros,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn DisplayAlert(
-    IntuitionBase: *mut Library,
-    alertNumber: ULONG,
-    string: CONST_STRPTR,
-    height: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") alertNumber,
-            in("a0") string,
-            in("d1") height,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn DisplayBeep(IntuitionBase: *mut Library, screen: *mut Screen) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn DoubleClick(
-    IntuitionBase: *mut Library,
-    sSeconds: ULONG,
-    sMicros: ULONG,
-    cSeconds: ULONG,
-    cMicros: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") sSeconds,
-            in("d1") sMicros,
-            in("d2") cSeconds,
-            in("d3") cMicros,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn DrawBorder(
-    IntuitionBase: *mut Library,
-    rp: *mut RastPort,
-    border: *const Border,
-    leftOffset: LONG,
-    topOffset: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-            in("a1") border,
-            in("d0") leftOffset,
-            in("d1") topOffset,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn DrawImage(
-    IntuitionBase: *mut Library,
-    rp: *mut RastPort,
-    image: *const Image,
-    leftOffset: LONG,
-    topOffset: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-            in("a1") image,
-            in("d0") leftOffset,
-            in("d1") topOffset,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn EndRequest(
-    IntuitionBase: *mut Library,
-    requester: *mut Requester,
-    window: *mut Window,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") requester,
-            in("a1") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn GetDefPrefs(
-    IntuitionBase: *mut Library,
-    preferences: *mut Preferences,
-    size: LONG,
-) -> *mut Preferences {
-    let asm_ret_value: *mut Preferences;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") preferences,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn GetPrefs(
-    IntuitionBase: *mut Library,
-    preferences: *mut Preferences,
-    size: LONG,
-) -> *mut Preferences {
-    let asm_ret_value: *mut Preferences;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") preferences,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn InitRequester(IntuitionBase: *mut Library, requester: *mut Requester) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") requester,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ItemAddress(
-    IntuitionBase: *mut Library,
-    menuStrip: *const Menu,
-    menuNumber: ULONG,
-) -> *mut MenuItem {
-    let asm_ret_value: *mut MenuItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") menuStrip,
-            in("d0") menuNumber,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ModifyIDCMP(IntuitionBase: *mut Library, window: *mut Window, flags: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ModifyProp(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    flags: ULONG,
-    horizPot: ULONG,
-    vertPot: ULONG,
-    horizBody: ULONG,
-    vertBody: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-            in("d0") flags,
-            in("d1") horizPot,
-            in("d2") vertPot,
-            in("d3") horizBody,
-            in("d4") vertBody,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn MoveScreen(IntuitionBase: *mut Library, screen: *mut Screen, dx: LONG, dy: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("d0") dx,
-            in("d1") dy,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn MoveWindow(IntuitionBase: *mut Library, window: *mut Window, dx: LONG, dy: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") dx,
-            in("d1") dy,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OffGadget(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OffMenu(IntuitionBase: *mut Library, window: *mut Window, menuNumber: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") menuNumber,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OnGadget(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OnMenu(IntuitionBase: *mut Library, window: *mut Window, menuNumber: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") menuNumber,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OpenScreen(IntuitionBase: *mut Library, newScreen: *const NewScreen) -> *mut Screen {
-    let asm_ret_value: *mut Screen;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") newScreen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OpenWindow(IntuitionBase: *mut Library, newWindow: *const NewWindow) -> *mut Window {
-    let asm_ret_value: *mut Window;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") newWindow,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn OpenWorkBench(IntuitionBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn PrintIText(
-    IntuitionBase: *mut Library,
-    rp: *mut RastPort,
-    iText: *const IntuiText,
-    left: LONG,
-    top: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-            in("a1") iText,
-            in("d0") left,
-            in("d1") top,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn RefreshGadgets(
-    IntuitionBase: *mut Library,
-    gadgets: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadgets,
-            in("a1") window,
-            in("a2") requester,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn RemoveGadget(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    gadget: *mut Gadget,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") gadget,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ReportMouse(IntuitionBase: *mut Library, flag: LONG, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") flag,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ReportMouse1(IntuitionBase: *mut Library, window: *mut Window, flag: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") flag,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn Request(
-    IntuitionBase: *mut Library,
-    requester: *mut Requester,
-    window: *mut Window,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") requester,
-            in("a1") window,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ScreenToBack(IntuitionBase: *mut Library, screen: *mut Screen) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ScreenToFront(IntuitionBase: *mut Library, screen: *mut Screen) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SetDMRequest(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    requester: *mut Requester,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") requester,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SetMenuStrip(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    menu: *mut Menu,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") menu,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SetPointer(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    pointer: *mut UWORD,
-    height: LONG,
-    width: LONG,
-    xOffset: LONG,
-    yOffset: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") pointer,
-            in("d0") height,
-            in("d1") width,
-            in("d2") xOffset,
-            in("d3") yOffset,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SetWindowTitles(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    windowTitle: CONST_STRPTR,
-    screenTitle: CONST_STRPTR,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-276, %a6)
-            ".short -276",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") windowTitle,
-            in("a2") screenTitle,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ShowTitle(IntuitionBase: *mut Library, screen: *mut Screen, showIt: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-282, %a6)
-            ".short -282",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("d0") showIt,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SizeWindow(IntuitionBase: *mut Library, window: *mut Window, dx: LONG, dy: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-288, %a6)
-            ".short -288",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") dx,
-            in("d1") dy,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ViewAddress(IntuitionBase: *mut Library) -> *mut View {
-    let asm_ret_value: *mut View;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-294, %a6)
-            ".short -294",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn ViewPortAddress(IntuitionBase: *mut Library, window: *const Window) -> *mut ViewPort {
-    let asm_ret_value: *mut ViewPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-300, %a6)
-            ".short -300",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn WindowToBack(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-306, %a6)
-            ".short -306",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn WindowToFront(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-312, %a6)
-            ".short -312",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn WindowLimits(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    widthMin: LONG,
-    heightMin: LONG,
-    widthMax: ULONG,
-    heightMax: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-318, %a6)
-            ".short -318",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") widthMin,
-            in("d1") heightMin,
-            in("d2") widthMax,
-            in("d3") heightMax,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn SetPrefs(
-    IntuitionBase: *mut Library,
-    preferences: *const Preferences,
-    size: LONG,
-    inform: LONG,
-) -> *mut Preferences {
-    let asm_ret_value: *mut Preferences;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-324, %a6)
-            ".short -324",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") preferences,
-            in("d0") size,
-            in("d1") inform,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn IntuiTextLength(IntuitionBase: *mut Library, iText: *const IntuiText) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-330, %a6)
-            ".short -330",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") iText,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn WBenchToBack(IntuitionBase: *mut Library) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-336, %a6)
-            ".short -336",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn WBenchToFront(IntuitionBase: *mut Library) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-342, %a6)
-            ".short -342",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn AutoRequest(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    body: *const IntuiText,
-    posText: *const IntuiText,
-    negText: *const IntuiText,
-    pFlag: ULONG,
-    nFlag: ULONG,
-    width: ULONG,
-    height: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-348, %a6)
-            ".short -348",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") body,
-            in("a2") posText,
-            in("a3") negText,
-            in("d0") pFlag,
-            in("d1") nFlag,
-            in("d2") width,
-            in("d3") height,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn BeginRefresh(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-354, %a6)
-            ".short -354",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn BuildSysRequest(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    body: *const IntuiText,
-    posText: *const IntuiText,
-    negText: *const IntuiText,
-    flags: ULONG,
-    width: ULONG,
-    height: ULONG,
-) -> *mut Window {
-    let asm_ret_value: *mut Window;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-360, %a6)
-            ".short -360",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") body,
-            in("a2") posText,
-            in("a3") negText,
-            in("d0") flags,
-            in("d1") width,
-            in("d2") height,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn EndRefresh(IntuitionBase: *mut Library, window: *mut Window, complete: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-366, %a6)
-            ".short -366",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") complete,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn FreeSysRequest(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-372, %a6)
-            ".short -372",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn MakeScreen(IntuitionBase: *mut Library, screen: *mut Screen) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-378, %a6)
-            ".short -378",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn RemakeDisplay(IntuitionBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-384, %a6)
-            ".short -384",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn RethinkDisplay(IntuitionBase: *mut Library) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-390, %a6)
-            ".short -390",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn AllocRemember(
-    IntuitionBase: *mut Library,
-    rememberKey: *mut *mut Remember,
-    size: ULONG,
-    flags: ULONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-396, %a6)
-            ".short -396",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rememberKey,
-            in("d0") size,
-            in("d1") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn AlohaWorkbench(IntuitionBase: *mut Library, wbport: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-402, %a6)
-            ".short -402",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") wbport,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn FreeRemember(
-    IntuitionBase: *mut Library,
-    rememberKey: *mut *mut Remember,
-    reallyForget: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-408, %a6)
-            ".short -408",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rememberKey,
-            in("d0") reallyForget,
-        );
-    }
-}
-
-/// libname: "intuition.library"
-pub unsafe fn LockIBase(IntuitionBase: *mut Library, dontknow: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-414, %a6)
-            ".short -414",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") dontknow,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library"
-pub unsafe fn UnlockIBase(IntuitionBase: *mut Library, ibLock: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-420, %a6)
-            ".short -420",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") ibLock,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn GetScreenData(
-    IntuitionBase: *mut Library,
-    buffer: APTR,
-    size: ULONG,
-    type_: ULONG,
-    screen: *const Screen,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-426, %a6)
-            ".short -426",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") buffer,
-            in("d0") size,
-            in("d1") type_,
-            in("a1") screen,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn RefreshGList(
-    IntuitionBase: *mut Library,
-    gadgets: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    numGad: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-432, %a6)
-            ".short -432",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadgets,
-            in("a1") window,
-            in("a2") requester,
-            in("d0") numGad,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn AddGList(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    gadget: *mut Gadget,
-    position: ULONG,
-    numGad: LONG,
-    requester: *mut Requester,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-438, %a6)
-            ".short -438",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") gadget,
-            in("d0") position,
-            in("d1") numGad,
-            in("a2") requester,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn RemoveGList(
-    IntuitionBase: *mut Library,
-    remPtr: *mut Window,
-    gadget: *mut Gadget,
-    numGad: LONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-444, %a6)
-            ".short -444",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") remPtr,
-            in("a1") gadget,
-            in("d0") numGad,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn ActivateWindow(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn RefreshWindowFrame(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn ActivateGadget(
-    IntuitionBase: *mut Library,
-    gadgets: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-462, %a6)
-            ".short -462",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadgets,
-            in("a1") window,
-            in("a2") requester,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V33) (Kickstart 1.2)
-pub unsafe fn NewModifyProp(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    flags: ULONG,
-    horizPot: ULONG,
-    vertPot: ULONG,
-    horizBody: ULONG,
-    vertBody: ULONG,
-    numGad: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-468, %a6)
-            ".short -468",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-            in("d0") flags,
-            in("d1") horizPot,
-            in("d2") vertPot,
-            in("d3") horizBody,
-            in("d4") vertBody,
-            in("d5") numGad,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn QueryOverscan(
-    IntuitionBase: *mut Library,
-    displayID: ULONG,
-    rect: *mut Rectangle,
-    oScanType: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-474, %a6)
-            ".short -474",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") displayID,
-            in("a1") rect,
-            in("d0") oScanType,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn MoveWindowInFrontOf(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    behindWindow: *mut Window,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-480, %a6)
-            ".short -480",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") behindWindow,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn ChangeWindowBox(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    left: LONG,
-    top: LONG,
-    width: LONG,
-    height: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-486, %a6)
-            ".short -486",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") left,
-            in("d1") top,
-            in("d2") width,
-            in("d3") height,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetEditHook(IntuitionBase: *mut Library, hook: *mut Hook) -> *mut Hook {
-    let asm_ret_value: *mut Hook;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-492, %a6)
-            ".short -492",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") hook,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetMouseQueue(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    queueLength: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("d0") queueLength,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn ZipWindow(IntuitionBase: *mut Library, window: *mut Window) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-504, %a6)
-            ".short -504",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockPubScreen(IntuitionBase: *mut Library, name: CONST_STRPTR) -> *mut Screen {
-    let asm_ret_value: *mut Screen;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-510, %a6)
-            ".short -510",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnlockPubScreen(
-    IntuitionBase: *mut Library,
-    name: CONST_STRPTR,
-    screen: *mut Screen,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-516, %a6)
-            ".short -516",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") name,
-            in("a1") screen,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockPubScreenList(IntuitionBase: *mut Library) -> *mut List {
-    let asm_ret_value: *mut List;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-522, %a6)
-            ".short -522",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnlockPubScreenList(IntuitionBase: *mut Library) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-528, %a6)
-            ".short -528",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn NextPubScreen(
-    IntuitionBase: *mut Library,
-    screen: *const Screen,
-    namebuf: STRPTR,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-534, %a6)
-            ".short -534",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("a1") namebuf,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetDefaultPubScreen(IntuitionBase: *mut Library, name: CONST_STRPTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-540, %a6)
-            ".short -540",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") name,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetPubScreenModes(IntuitionBase: *mut Library, modes: ULONG) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-546, %a6)
-            ".short -546",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") modes,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn PubScreenStatus(
-    IntuitionBase: *mut Library,
-    screen: *mut Screen,
-    statusFlags: ULONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-552, %a6)
-            ".short -552",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("d0") statusFlags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn ObtainGIRPort(IntuitionBase: *mut Library, gInfo: *mut GadgetInfo) -> *mut RastPort {
-    let asm_ret_value: *mut RastPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-558, %a6)
-            ".short -558",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gInfo,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn ReleaseGIRPort(IntuitionBase: *mut Library, rp: *mut RastPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-564, %a6)
-            ".short -564",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn GadgetMouse(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    gInfo: *mut GadgetInfo,
-    mousePoint: *mut WORD,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-570, %a6)
-            ".short -570",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") gInfo,
-            in("a2") mousePoint,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetDefaultPubScreen(IntuitionBase: *mut Library, nameBuffer: STRPTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-582, %a6)
-            ".short -582",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") nameBuffer,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn EasyRequestArgs(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    easyStruct: *const EasyStruct,
-    idcmpPtr: *mut ULONG,
-    args: CONST_APTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-588, %a6)
-            ".short -588",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") easyStruct,
-            in("a2") idcmpPtr,
-            in("a3") args,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn BuildEasyRequestArgs(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    easyStruct: *const EasyStruct,
-    idcmp: ULONG,
-    args: CONST_APTR,
-) -> *mut Window {
-    let asm_ret_value: *mut Window;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-594, %a6)
-            ".short -594",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") easyStruct,
-            in("d0") idcmp,
-            in("a3") args,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SysReqHandler(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    idcmpPtr: *mut ULONG,
-    waitInput: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-600, %a6)
-            ".short -600",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") idcmpPtr,
-            in("d0") waitInput,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenWindowTagList(
-    IntuitionBase: *mut Library,
-    newWindow: *const NewWindow,
-    tagList: *const TagItem,
-) -> *mut Window {
-    let asm_ret_value: *mut Window;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-606, %a6)
-            ".short -606",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") newWindow,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn OpenScreenTagList(
-    IntuitionBase: *mut Library,
-    newScreen: *const NewScreen,
-    tagList: *const TagItem,
-) -> *mut Screen {
-    let asm_ret_value: *mut Screen;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-612, %a6)
-            ".short -612",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") newScreen,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn DrawImageState(
-    IntuitionBase: *mut Library,
-    rp: *mut RastPort,
-    image: *const Image,
-    leftOffset: LONG,
-    topOffset: LONG,
-    state: ULONG,
-    drawInfo: *mut DrawInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-618, %a6)
-            ".short -618",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-            in("a1") image,
-            in("d0") leftOffset,
-            in("d1") topOffset,
-            in("d2") state,
-            in("a2") drawInfo,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn PointInImage(IntuitionBase: *mut Library, point: ULONG, image: *const Image) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-624, %a6)
-            ".short -624",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") point,
-            in("a0") image,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn EraseImage(
-    IntuitionBase: *mut Library,
-    rp: *mut RastPort,
-    image: *const Image,
-    leftOffset: LONG,
-    topOffset: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-630, %a6)
-            ".short -630",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") rp,
-            in("a1") image,
-            in("d0") leftOffset,
-            in("d1") topOffset,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn NewObjectA(
-    IntuitionBase: *mut Library,
-    classPtr: *mut IClass,
-    classID: CONST_STRPTR,
-    tagList: *const TagItem,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-636, %a6)
-            ".short -636",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") classPtr,
-            in("a1") classID,
-            in("a2") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn DisposeObject(IntuitionBase: *mut Library, object: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-642, %a6)
-            ".short -642",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") object,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetAttrsA(
-    IntuitionBase: *mut Library,
-    object: APTR,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-648, %a6)
-            ".short -648",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") object,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetAttr(
-    IntuitionBase: *mut Library,
-    attrID: ULONG,
-    object: APTR,
-    storagePtr: *mut ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-654, %a6)
-            ".short -654",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") attrID,
-            in("a0") object,
-            in("a1") storagePtr,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetGadgetAttrsA(
-    IntuitionBase: *mut Library,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-660, %a6)
-            ".short -660",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-            in("a3") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn NextObject(IntuitionBase: *mut Library, objectPtrPtr: CONST_APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-666, %a6)
-            ".short -666",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") objectPtrPtr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn MakeClass(
-    IntuitionBase: *mut Library,
-    classID: CONST_STRPTR,
-    superClassID: CONST_STRPTR,
-    superClassPtr: *const IClass,
-    instanceSize: ULONG,
-    flags: ULONG,
-) -> *mut IClass {
-    let asm_ret_value: *mut IClass;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-678, %a6)
-            ".short -678",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") classID,
-            in("a1") superClassID,
-            in("a2") superClassPtr,
-            in("d0") instanceSize,
-            in("d1") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddClass(IntuitionBase: *mut Library, classPtr: *mut IClass) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-684, %a6)
-            ".short -684",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") classPtr,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetScreenDrawInfo(IntuitionBase: *mut Library, screen: *mut Screen) -> *mut DrawInfo {
-    let asm_ret_value: *mut DrawInfo;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-690, %a6)
-            ".short -690",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeScreenDrawInfo(
-    IntuitionBase: *mut Library,
-    screen: *mut Screen,
-    drawInfo: *mut DrawInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-696, %a6)
-            ".short -696",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("a1") drawInfo,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn ResetMenuStrip(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    menu: *mut Menu,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-702, %a6)
-            ".short -702",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") menu,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemoveClass(IntuitionBase: *mut Library, classPtr: *mut IClass) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-708, %a6)
-            ".short -708",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") classPtr,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeClass(IntuitionBase: *mut Library, classPtr: *mut IClass) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-714, %a6)
-            ".short -714",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") classPtr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocScreenBuffer(
-    IntuitionBase: *mut Library,
-    sc: *mut Screen,
-    bm: *mut BitMap,
-    flags: ULONG,
-) -> *mut ScreenBuffer {
-    let asm_ret_value: *mut ScreenBuffer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-768, %a6)
-            ".short -768",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") sc,
-            in("a1") bm,
-            in("d0") flags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreeScreenBuffer(
-    IntuitionBase: *mut Library,
-    sc: *mut Screen,
-    sb: *mut ScreenBuffer,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-774, %a6)
-            ".short -774",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") sc,
-            in("a1") sb,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn ChangeScreenBuffer(
-    IntuitionBase: *mut Library,
-    sc: *mut Screen,
-    sb: *mut ScreenBuffer,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-780, %a6)
-            ".short -780",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") sc,
-            in("a1") sb,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn ScreenDepth(
-    IntuitionBase: *mut Library,
-    screen: *mut Screen,
-    flags: ULONG,
-    reserved: APTR,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-786, %a6)
-            ".short -786",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("d0") flags,
-            in("a1") reserved,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn ScreenPosition(
-    IntuitionBase: *mut Library,
-    screen: *mut Screen,
-    flags: ULONG,
-    x1: LONG,
-    y1: LONG,
-    x2: LONG,
-    y2: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-792, %a6)
-            ".short -792",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") screen,
-            in("d0") flags,
-            in("d1") x1,
-            in("d2") y1,
-            in("d3") x2,
-            in("d4") y2,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn ScrollWindowRaster(
-    IntuitionBase: *mut Library,
-    win: *mut Window,
-    dx: LONG,
-    dy: LONG,
-    xMin: LONG,
-    yMin: LONG,
-    xMax: LONG,
-    yMax: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-798, %a6)
-            ".short -798",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a1") win,
-            in("d0") dx,
-            in("d1") dy,
-            in("d2") xMin,
-            in("d3") yMin,
-            in("d4") xMax,
-            in("d5") yMax,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn LendMenus(
-    IntuitionBase: *mut Library,
-    fromwindow: *mut Window,
-    towindow: *mut Window,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-804, %a6)
-            ".short -804",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") fromwindow,
-            in("a1") towindow,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn DoGadgetMethodA(
-    IntuitionBase: *mut Library,
-    gad: *mut Gadget,
-    win: *mut Window,
-    req: *mut Requester,
-    message: Msg,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-810, %a6)
-            ".short -810",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") gad,
-            in("a1") win,
-            in("a2") req,
-            in("a3") message,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn SetWindowPointerA(
-    IntuitionBase: *mut Library,
-    win: *mut Window,
-    taglist: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-816, %a6)
-            ".short -816",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") win,
-            in("a1") taglist,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn TimedDisplayAlert(
-    IntuitionBase: *mut Library,
-    alertNumber: ULONG,
-    string: CONST_STRPTR,
-    height: ULONG,
-    time: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-822, %a6)
-            ".short -822",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("d0") alertNumber,
-            in("a0") string,
-            in("d1") height,
-            in("a1") time,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V39) (Kickstart 3.0)
-pub unsafe fn HelpControl(IntuitionBase: *mut Library, win: *mut Window, flags: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-828, %a6)
-            ".short -828",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") win,
-            in("d0") flags,
-        );
-    }
-}
-
-/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ShowWindow(
-    IntuitionBase: *mut Library,
-    window: *mut Window,
-    other: *mut Window,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-834, %a6)
-            ".short -834",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            in("a1") other,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn HideWindow(IntuitionBase: *mut Library, window: *mut Window) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-840, %a6)
-            ".short -840",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") window,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn IntuitionControlA(
-    IntuitionBase: *mut Library,
-    object: APTR,
-    taglist: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-1212, %a6)
-            ".short -1212",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) IntuitionBase,
-            in("a0") object,
-            in("a1") taglist,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "keymap.library" (V36) (Kickstart 2.0)
-pub unsafe fn SetKeyMapDefault(KeymapBase: *mut Library, keyMap: *mut KeyMap) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) KeymapBase,
-            in("a0") keyMap,
-        );
-    }
-}
-
-/// libname: "keymap.library" (V36) (Kickstart 2.0)
-pub unsafe fn AskKeyMapDefault(KeymapBase: *mut Library) -> *mut KeyMap {
-    let asm_ret_value: *mut KeyMap;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) KeymapBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "keymap.library" (V36) (Kickstart 2.0)
-pub unsafe fn MapRawKey(
-    KeymapBase: *mut Library,
-    event: *const InputEvent,
-    buffer: STRPTR,
-    length: LONG,
-    keyMap: *const KeyMap,
-) -> WORD {
-    let asm_ret_value: WORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) KeymapBase,
-            in("a0") event,
-            in("a1") buffer,
-            in("d1") length,
-            in("a2") keyMap,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "keymap.library" (V36) (Kickstart 2.0)
-pub unsafe fn MapANSI(
-    KeymapBase: *mut Library,
-    string: CONST_STRPTR,
-    count: LONG,
-    buffer: STRPTR,
-    length: LONG,
-    keyMap: *const KeyMap,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) KeymapBase,
-            in("a0") string,
-            in("d0") count,
-            in("a1") buffer,
-            in("d1") length,
-            in("a2") keyMap,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "label.image" (V40) (Kickstart 3.1)
-pub unsafe fn LABEL_GetClass(LabelBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LabelBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn InitLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn CreateUpfrontLayer(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    bm: *mut BitMap,
-    x0: LONG,
-    y0: LONG,
-    x1: LONG,
-    y1: LONG,
-    flags: LONG,
-    bm2: *mut BitMap,
-) -> *mut Layer {
-    let asm_ret_value: *mut Layer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") bm,
-            in("d0") x0,
-            in("d1") y0,
-            in("d2") x1,
-            in("d3") y1,
-            in("d4") flags,
-            in("a2") bm2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn CreateBehindLayer(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    bm: *mut BitMap,
-    x0: LONG,
-    y0: LONG,
-    x1: LONG,
-    y1: LONG,
-    flags: LONG,
-    bm2: *mut BitMap,
-) -> *mut Layer {
-    let asm_ret_value: *mut Layer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") bm,
-            in("d0") x0,
-            in("d1") y0,
-            in("d2") x1,
-            in("d3") y1,
-            in("d4") flags,
-            in("a2") bm2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn UpfrontLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn BehindLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn MoveLayer(
-    LayersBase: *mut Library,
-    dummy: LONG,
-    layer: *mut Layer,
-    dx: LONG,
-    dy: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            in("d0") dx,
-            in("d1") dy,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn SizeLayer(
-    LayersBase: *mut Library,
-    dummy: LONG,
-    layer: *mut Layer,
-    dx: LONG,
-    dy: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            in("d0") dx,
-            in("d1") dy,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn ScrollLayer(
-    LayersBase: *mut Library,
-    dummy: LONG,
-    layer: *mut Layer,
-    dx: LONG,
-    dy: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            in("d0") dx,
-            in("d1") dy,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn BeginUpdate(LayersBase: *mut Library, l: *mut Layer) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") l,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn EndUpdate(LayersBase: *mut Library, layer: *mut Layer, flag: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("d0") flag,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn DeleteLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn LockLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") dummy,
-            in("a1") layer,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn UnlockLayer(LayersBase: *mut Library, layer: *mut Layer) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn LockLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn UnlockLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn LockLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn SwapBitsRastPortClipRect(
-    LayersBase: *mut Library,
-    rp: *mut RastPort,
-    cr: *mut ClipRect,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") rp,
-            in("a1") cr,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn WhichLayer(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    x: LONG,
-    y: LONG,
-) -> *mut Layer {
-    let asm_ret_value: *mut Layer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("d0") x,
-            in("d1") y,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn UnlockLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn NewLayerInfo(LayersBase: *mut Library) -> *mut Layer_Info {
-    let asm_ret_value: *mut Layer_Info;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn DisposeLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn FattenLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn ThinLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-        );
-    }
-}
-
-/// libname: "layers.library"
-pub unsafe fn MoveLayerInFrontOf(
-    LayersBase: *mut Library,
-    layer_to_move: *mut Layer,
-    other_layer: *mut Layer,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer_to_move,
-            in("a1") other_layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library"
-pub unsafe fn InstallClipRegion(
-    LayersBase: *mut Library,
-    layer: *mut Layer,
-    region: *mut Region,
-) -> *mut Region {
-    let asm_ret_value: *mut Region;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("a1") region,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V36) (Kickstart 2.0)
-pub unsafe fn MoveSizeLayer(
-    LayersBase: *mut Library,
-    layer: *mut Layer,
-    dx: LONG,
-    dy: LONG,
-    dw: LONG,
-    dh: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("d0") dx,
-            in("d1") dy,
-            in("d2") dw,
-            in("d3") dh,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateUpfrontHookLayer(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    bm: *mut BitMap,
-    x0: LONG,
-    y0: LONG,
-    x1: LONG,
-    y1: LONG,
-    flags: LONG,
-    hook: *mut Hook,
-    bm2: *mut BitMap,
-) -> *mut Layer {
-    let asm_ret_value: *mut Layer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") bm,
-            in("d0") x0,
-            in("d1") y0,
-            in("d2") x1,
-            in("d3") y1,
-            in("d4") flags,
-            in("a3") hook,
-            in("a2") bm2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateBehindHookLayer(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    bm: *mut BitMap,
-    x0: LONG,
-    y0: LONG,
-    x1: LONG,
-    y1: LONG,
-    flags: LONG,
-    hook: *mut Hook,
-    bm2: *mut BitMap,
-) -> *mut Layer {
-    let asm_ret_value: *mut Layer;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-192, %a6)
-            ".short -192",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") bm,
-            in("d0") x0,
-            in("d1") y0,
-            in("d2") x1,
-            in("d3") y1,
-            in("d4") flags,
-            in("a3") hook,
-            in("a2") bm2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V36) (Kickstart 2.0)
-pub unsafe fn InstallLayerHook(
-    LayersBase: *mut Library,
-    layer: *mut Layer,
-    hook: *mut Hook,
-) -> *mut Hook {
-    let asm_ret_value: *mut Hook;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("a1") hook,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V39) (Kickstart 3.0)
-pub unsafe fn InstallLayerInfoHook(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    hook: *mut Hook,
-) -> *mut Hook {
-    let asm_ret_value: *mut Hook;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") hook,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V39) (Kickstart 3.0)
-pub unsafe fn SortLayerCR(LayersBase: *mut Library, layer: *mut Layer, dx: LONG, dy: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("d0") dx,
-            in("d1") dy,
-        );
-    }
-}
-
-/// libname: "layers.library" (V39) (Kickstart 3.0)
-pub unsafe fn DoHookClipRects(
-    LayersBase: *mut Library,
-    hook: *mut Hook,
-    rport: *mut RastPort,
-    rect: *const Rectangle,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") hook,
-            in("a1") rport,
-            in("a2") rect,
-        );
-    }
-}
-
-/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn LayerOccluded(LayersBase: *mut Library, layer: *mut Layer) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn HideLayer(LayersBase: *mut Library, layer: *mut Layer) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn ShowLayer(
-    LayersBase: *mut Library,
-    layer: *mut Layer,
-    in_front_of: *mut Layer,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") layer,
-            in("a1") in_front_of,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SetLayerInfoBounds(
-    LayersBase: *mut Library,
-    li: *mut Layer_Info,
-    bounds: *const Rectangle,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayersBase,
-            in("a0") li,
-            in("a1") bounds,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn LAYOUT_GetClass(LayoutBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn ActivateLayoutGadget(
-    LayoutBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    object: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-            in("d0") object,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FlushLayoutDomainCache(LayoutBase: *mut ::core::ffi::c_void, gadget: *mut Gadget) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-        );
-    }
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn RethinkLayout(
-    LayoutBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    window: *mut Window,
-    requester: *mut Requester,
-    refresh: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-            in("a1") window,
-            in("a2") requester,
-            in("d0") refresh,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn LayoutLimits(
-    LayoutBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    limits: *mut LayoutLimits,
-    font: *mut TextFont,
-    screen: *mut Screen,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-            in("a1") limits,
-            in("a2") font,
-            in("a3") screen,
-        );
-    }
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn PAGE_GetClass(LayoutBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetPageGadgetAttrsA(
-    LayoutBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    object: *mut Object,
-    window: *mut Window,
-    requester: *mut Requester,
-    tags: *mut TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a4, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a4reg}, %a4",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a4",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-            in("a1") object,
-            in("a2") window,
-            in("a3") requester,
-            a4reg = in(reg) tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "layout.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn RefreshPageGadget(
-    LayoutBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    object: *mut Object,
-    window: *mut Window,
-    requester: *mut Requester,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LayoutBase,
-            in("a0") gadget,
-            in("a1") object,
-            in("a2") window,
-            in("a3") requester,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn LISTBROWSER_GetClass(ListBrowserBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn AllocListBrowserNodeA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    columns: ULONG,
-    tags: *mut TagItem,
-) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("d0") columns,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeListBrowserNode(ListBrowserBase: *mut ::core::ffi::c_void, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetListBrowserNodeAttrsA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GetListBrowserNodeAttrsA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn ListBrowserSelectAll(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") list,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn ShowListBrowserNodeChildren(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    depth: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") node,
-            in("d0") depth,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn HideListBrowserNodeChildren(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn ShowAllListBrowserChildren(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    list: *mut List,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") list,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn HideAllListBrowserChildren(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    list: *mut List,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") list,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeListBrowserList(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") list,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn AllocLBColumnInfoA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    columns: ULONG,
-    tags: *mut TagItem,
-) -> *mut ColumnInfo {
-    let asm_ret_value: *mut ColumnInfo;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("d0") columns,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn SetLBColumnInfoAttrsA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    columninfo: *mut ColumnInfo,
-    tags: *mut TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a1") columninfo,
-            in("a0") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn GetLBColumnInfoAttrsA(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    columninfo: *mut ColumnInfo,
-    tags: *mut TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a1") columninfo,
-            in("a0") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn FreeLBColumnInfo(
-    ListBrowserBase: *mut ::core::ffi::c_void,
-    columninfo: *mut ColumnInfo,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") columninfo,
-        );
-    }
-}
-
-/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
-pub unsafe fn ListBrowserClearAll(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) ListBrowserBase,
-            in("a0") list,
-        );
-    }
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn CloseCatalog(LocaleBase: *mut Library, catalog: *mut Catalog) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") catalog,
-        );
-    }
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn CloseLocale(LocaleBase: *mut Library, locale: *mut Locale) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-        );
-    }
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn ConvToLower(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    character: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn ConvToUpper(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    character: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn FormatDate(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    fmtTemplate: CONST_STRPTR,
-    date: *const DateStamp,
-    putCharFunc: *mut Hook,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") fmtTemplate,
-            in("a2") date,
-            in("a3") putCharFunc,
-        );
-    }
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn FormatString(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    fmtTemplate: CONST_STRPTR,
-    dataStream: APTR,
-    putCharFunc: *mut Hook,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") fmtTemplate,
-            in("a2") dataStream,
-            in("a3") putCharFunc,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn GetCatalogStr(
-    LocaleBase: *mut Library,
-    catalog: *const Catalog,
-    stringNum: LONG,
-    defaultString: CONST_STRPTR,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") catalog,
-            in("d0") stringNum,
-            in("a1") defaultString,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn GetLocaleStr(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    stringNum: ULONG,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") stringNum,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsAlNum(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsAlpha(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsCntrl(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsDigit(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsGraph(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsLower(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsPrint(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsPunct(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsSpace(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsUpper(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn IsXDigit(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn OpenCatalogA(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> *mut Catalog {
-    let asm_ret_value: *mut Catalog;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") name,
-            in("a2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn OpenLocale(LocaleBase: *mut Library, name: CONST_STRPTR) -> *mut Locale {
-    let asm_ret_value: *mut Locale;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn ParseDate(
-    LocaleBase: *mut Library,
-    locale: *const Locale,
-    date: *mut DateStamp,
-    fmtTemplate: CONST_STRPTR,
-    getCharFunc: *mut Hook,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") date,
-            in("a2") fmtTemplate,
-            in("a3") getCharFunc,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn StrConvert(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    string: CONST_STRPTR,
-    buffer: APTR,
-    bufferSize: ULONG,
-    type_: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") string,
-            in("a2") buffer,
-            in("d0") bufferSize,
-            in("d1") type_,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "locale.library" (V38) (Kickstart 2.1)
-pub unsafe fn StrnCmp(
-    LocaleBase: *mut Library,
-    locale: *mut Locale,
-    string1: CONST_STRPTR,
-    string2: CONST_STRPTR,
-    length: LONG,
-    type_: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LocaleBase,
-            in("a0") locale,
-            in("a1") string1,
-            in("a2") string2,
-            in("d0") length,
-            in("d1") type_,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn ReadJoyPort(LowLevelBase: *mut Library, port: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("d0") port,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetLanguageSelection(LowLevelBase: *mut Library) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetKey(LowLevelBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn QueryKeys(LowLevelBase: *mut Library, queryArray: *mut KeyQuery, arraySize: LONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a0") queryArray,
-            in("d1") arraySize,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn AddKBInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a0") intRoutine,
-            in("a1") intData,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn RemKBInt(LowLevelBase: *mut Library, intHandle: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") intHandle,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn SystemControlA(LowLevelBase: *mut Library, tagList: *const TagItem) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn AddTimerInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a0") intRoutine,
-            in("a1") intData,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn RemTimerInt(LowLevelBase: *mut Library, intHandle: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") intHandle,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn StopTimerInt(LowLevelBase: *mut Library, intHandle: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") intHandle,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn StartTimerInt(
-    LowLevelBase: *mut Library,
-    intHandle: APTR,
-    timeInterval: ULONG,
-    continuous: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") intHandle,
-            in("d0") timeInterval,
-            in("d1") continuous,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn ElapsedTime(LowLevelBase: *mut Library, context: *mut EClockVal) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a0") context,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn AddVBlankInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a0") intRoutine,
-            in("a1") intData,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn RemVBlankInt(LowLevelBase: *mut Library, intHandle: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("a1") intHandle,
-        );
-    }
-}
-
-/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
-pub unsafe fn SetJoyPortAttrsA(
-    LowLevelBase: *mut Library,
-    portNumber: ULONG,
-    tagList: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) LowLevelBase,
-            in("d0") portNumber,
-            in("a1") tagList,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPFix(MathBase: *mut Library, parm: FLOAT) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPFlt(MathBase: *mut Library, integer: LONG) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") integer,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPCmp(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") leftParm.to_bits(),
-            in("d0") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPTst(MathBase: *mut Library, parm: FLOAT) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") parm.to_bits(),
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPAbs(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPNeg(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPAdd(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") leftParm.to_bits(),
-            in("d0") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPSub(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") leftParm.to_bits(),
-            in("d0") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPMul(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") leftParm.to_bits(),
-            in("d0") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library"
-pub unsafe fn SPDiv(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d1") leftParm.to_bits(),
-            in("d0") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library" (V33) (Kickstart 1.2)
-pub unsafe fn SPFloor(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathffp.library" (V33) (Kickstart 1.2)
-pub unsafe fn SPCeil(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPFix(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPFlt(MathIeeeDoubBasBase: *mut Library, integer: LONG) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") integer,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPCmp(
-    MathIeeeDoubBasBase: *mut Library,
-    leftParm: DOUBLE,
-    rightParm: DOUBLE,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (leftParm.to_bits() >> 32) as u32,
-            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
-            in("d2") (rightParm.to_bits() >> 32) as u32,
-            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPTst(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPAbs(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPNeg(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPAdd(
-    MathIeeeDoubBasBase: *mut Library,
-    leftParm: DOUBLE,
-    rightParm: DOUBLE,
-) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (leftParm.to_bits() >> 32) as u32,
-            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
-            in("d2") (rightParm.to_bits() >> 32) as u32,
-            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPSub(
-    MathIeeeDoubBasBase: *mut Library,
-    leftParm: DOUBLE,
-    rightParm: DOUBLE,
-) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (leftParm.to_bits() >> 32) as u32,
-            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
-            in("d2") (rightParm.to_bits() >> 32) as u32,
-            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPMul(
-    MathIeeeDoubBasBase: *mut Library,
-    factor1: DOUBLE,
-    factor2: DOUBLE,
-) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (factor1.to_bits() >> 32) as u32,
-            in("d1") (factor1.to_bits() & 0xffff_ffff) as u32,
-            in("d2") (factor2.to_bits() >> 32) as u32,
-            in("d3") (factor2.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library"
-pub unsafe fn IEEEDPDiv(
-    MathIeeeDoubBasBase: *mut Library,
-    dividend: DOUBLE,
-    divisor: DOUBLE,
-) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (dividend.to_bits() >> 32) as u32,
-            in("d1") (dividend.to_bits() & 0xffff_ffff) as u32,
-            in("d2") (divisor.to_bits() >> 32) as u32,
-            in("d3") (divisor.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
-pub unsafe fn IEEEDPFloor(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
-pub unsafe fn IEEEDPCeil(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubBasBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPAtan(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPSin(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPCos(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPTan(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPSincos(
-    MathIeeeDoubTransBase: *mut Library,
-    pf2: *mut DOUBLE,
-    parm: DOUBLE,
-) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("a0") pf2,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPSinh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPCosh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPTanh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPExp(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPLog(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPPow(MathIeeeDoubTransBase: *mut Library, SPExp: DOUBLE, arg: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d2") (SPExp.to_bits() >> 32) as u32,
-            in("d3") (SPExp.to_bits() & 0xffff_ffff) as u32,
-            in("d0") (arg.to_bits() >> 32) as u32,
-            in("d1") (arg.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPSqrt(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPTieee(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPFieee(MathIeeeDoubTransBase: *mut Library, single: FLOAT) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") single.to_bits(),
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPAsin(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPAcos(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeedoubtrans.library"
-pub unsafe fn IEEEDPLog10(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
-    let mut asm_ret_value: (u32, u32) = (0, 0);
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeDoubTransBase,
-            in("d0") (parm.to_bits() >> 32) as u32,
-            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
-            lateout("d0") asm_ret_value.0,
-            lateout("d1") asm_ret_value.1,
-        );
-    }
-    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPFix(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPFlt(MathIeeeSingBasBase: *mut Library, integer: LONG) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") integer,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPCmp(
-    MathIeeeSingBasBase: *mut Library,
-    leftParm: FLOAT,
-    rightParm: FLOAT,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") leftParm.to_bits(),
-            in("d1") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPTst(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPAbs(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPNeg(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPAdd(
-    MathIeeeSingBasBase: *mut Library,
-    leftParm: FLOAT,
-    rightParm: FLOAT,
-) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") leftParm.to_bits(),
-            in("d1") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPSub(
-    MathIeeeSingBasBase: *mut Library,
-    leftParm: FLOAT,
-    rightParm: FLOAT,
-) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") leftParm.to_bits(),
-            in("d1") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPMul(
-    MathIeeeSingBasBase: *mut Library,
-    leftParm: FLOAT,
-    rightParm: FLOAT,
-) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") leftParm.to_bits(),
-            in("d1") rightParm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPDiv(
-    MathIeeeSingBasBase: *mut Library,
-    dividend: FLOAT,
-    divisor: FLOAT,
-) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") dividend.to_bits(),
-            in("d1") divisor.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPFloor(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPCeil(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingBasBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPAtan(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPSin(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPCos(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPTan(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPSincos(
-    MathIeeeSingTransBase: *mut Library,
-    cosptr: *mut FLOAT,
-    parm: FLOAT,
-) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("a0") cosptr,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPSinh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPCosh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPTanh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPExp(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPLog(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPPow(MathIeeeSingTransBase: *mut Library, SPExp: FLOAT, arg: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d1") SPExp.to_bits(),
-            in("d0") arg.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPSqrt(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPTieee(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPFieee(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPAsin(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPAcos(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
-pub unsafe fn IEEESPLog10(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathIeeeSingTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPAtan(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPSin(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPCos(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPTan(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPSincos(MathTransBase: *mut Library, cosResult: *mut FLOAT, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d1") cosResult,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPSinh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPCosh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPTanh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPExp(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPLog(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPPow(MathTransBase: *mut Library, power: FLOAT, arg: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d1") power.to_bits(),
-            in("d0") arg.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPSqrt(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPTieee(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library"
-pub unsafe fn SPFieee(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
-pub unsafe fn SPAsin(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
-pub unsafe fn SPAcos(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
-pub unsafe fn SPLog10(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
-    let asm_ret_value: u32;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MathTransBase,
-            in("d0") parm.to_bits(),
-            lateout("d0") asm_ret_value,
-        );
-    }
-    f32::from_bits(asm_ret_value)
-}
-
-/// libname: "misc.resource"
-pub unsafe fn AllocMiscResource(
-    MiscBase: *mut ::core::ffi::c_void,
-    unitNum: ULONG,
-    name: CONST_STRPTR,
-) -> *mut UBYTE {
-    let asm_ret_value: *mut UBYTE;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) MiscBase,
-            in("d0") unitNum,
-            in("a1") name,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "misc.resource"
-pub unsafe fn FreeMiscResource(MiscBase: *mut ::core::ffi::c_void, unitNum: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) MiscBase,
-            in("d0") unitNum,
-        );
-    }
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetCopyNV(
-    NVBase: *mut Library,
-    appName: CONST_STRPTR,
-    itemName: CONST_STRPTR,
-    killRequesters: LONG,
-) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") appName,
-            in("a1") itemName,
-            in("d1") killRequesters,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn FreeNVData(NVBase: *mut Library, data: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") data,
-        );
-    }
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn StoreNV(
-    NVBase: *mut Library,
-    appName: CONST_STRPTR,
-    itemName: CONST_STRPTR,
-    data: CONST_APTR,
-    length: ULONG,
-    killRequesters: LONG,
-) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") appName,
-            in("a1") itemName,
-            in("a2") data,
-            in("d0") length,
-            in("d1") killRequesters,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn DeleteNV(
-    NVBase: *mut Library,
-    appName: CONST_STRPTR,
-    itemName: CONST_STRPTR,
-    killRequesters: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") appName,
-            in("a1") itemName,
-            in("d1") killRequesters,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetNVInfo(NVBase: *mut Library, killRequesters: LONG) -> *mut NVInfo {
-    let asm_ret_value: *mut NVInfo;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("d1") killRequesters,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn GetNVList(
-    NVBase: *mut Library,
-    appName: CONST_STRPTR,
-    killRequesters: LONG,
-) -> *mut MinList {
-    let asm_ret_value: *mut MinList;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") appName,
-            in("d1") killRequesters,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
-pub unsafe fn SetNVProtection(
-    NVBase: *mut Library,
-    appName: CONST_STRPTR,
-    itemName: CONST_STRPTR,
-    mask: LONG,
-    killRequesters: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) NVBase,
-            in("a0") appName,
-            in("a1") itemName,
-            in("d2") mask,
-            in("d1") killRequesters,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "palette.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn PALETTE_GetClass(PaletteBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) PaletteBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "penmap.image" (V40) (Kickstart 3.1)
-pub unsafe fn PENMAP_GetClass(PenMapBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) PenMapBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "potgo.resource"
-pub unsafe fn AllocPotBits(PotgoBase: *mut ::core::ffi::c_void, bits: ULONG) -> UWORD {
-    let asm_ret_value: UWORD;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-6, %a6)
-            ".short -6",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) PotgoBase,
-            in("d0") bits,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "potgo.resource"
-pub unsafe fn FreePotBits(PotgoBase: *mut ::core::ffi::c_void, bits: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-12, %a6)
-            ".short -12",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) PotgoBase,
-            in("d0") bits,
-        );
-    }
-}
-
-/// libname: "potgo.resource"
-pub unsafe fn WritePotgo(PotgoBase: *mut ::core::ffi::c_void, word: ULONG, mask: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-18, %a6)
-            ".short -18",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) PotgoBase,
-            in("d0") word,
-            in("d1") mask,
-        );
-    }
-}
-
-/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn RADIOBUTTON_GetClass(RadioButtonBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RadioButtonBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn AllocRadioButtonNodeA(
-    RadioButtonBase: *mut ::core::ffi::c_void,
-    columns: ULONG,
-    tags: *mut TagItem,
-) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RadioButtonBase,
-            in("d0") columns,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeRadioButtonNode(RadioButtonBase: *mut ::core::ffi::c_void, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RadioButtonBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetRadioButtonNodeAttrsA(
-    RadioButtonBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RadioButtonBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GetRadioButtonNodeAttrsA(
-    RadioButtonBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RadioButtonBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "ramdrive.device" (V34) (Kickstart 1.3)
-pub unsafe fn KillRAD0(RamdriveDevice: *mut ::core::ffi::c_void) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RamdriveDevice,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "ramdrive.device" (V36) (Kickstart 2.0)
-pub unsafe fn KillRAD(RamdriveDevice: *mut ::core::ffi::c_void, unit: ULONG) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RamdriveDevice,
-            in("d0") unit,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn LockRealTime(RealTimeBase: *mut Library, lockType: ULONG) -> APTR {
-    let asm_ret_value: APTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("d0") lockType,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn UnlockRealTime(RealTimeBase: *mut Library, lock: APTR) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") lock,
-        );
-    }
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn CreatePlayerA(RealTimeBase: *mut Library, tagList: *const TagItem) -> *mut Player {
-    let asm_ret_value: *mut Player;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn DeletePlayer(RealTimeBase: *mut Library, player: *mut Player) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") player,
-        );
-    }
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn SetPlayerAttrsA(
-    RealTimeBase: *mut Library,
-    player: *mut Player,
-    tagList: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") player,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn SetConductorState(
-    RealTimeBase: *mut Library,
-    player: *mut Player,
-    state: ULONG,
-    time: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") player,
-            in("d0") state,
-            in("d1") time,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn ExternalSync(
-    RealTimeBase: *mut Library,
-    player: *mut Player,
-    minTime: LONG,
-    maxTime: LONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") player,
-            in("d0") minTime,
-            in("d1") maxTime,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn NextConductor(
-    RealTimeBase: *mut Library,
-    previousConductor: *const Conductor,
-) -> *mut Conductor {
-    let asm_ret_value: *mut Conductor;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") previousConductor,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn FindConductor(RealTimeBase: *mut Library, name: CONST_STRPTR) -> *mut Conductor {
-    let asm_ret_value: *mut Conductor;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") name,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "realtime.library" (V37) (Kickstart 2.04)
-pub unsafe fn GetPlayerAttrsA(
-    RealTimeBase: *mut Library,
-    player: *mut Player,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RealTimeBase,
-            in("a0") player,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "requester.class" (V40) (Kickstart 3.1)
-pub unsafe fn REQUESTER_GetClass(RequesterBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RequesterBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateArgstring(
-    RexxSysBase: *mut Library,
-    string: CONST_STRPTR,
-    length: ULONG,
-) -> *mut UBYTE {
-    let asm_ret_value: *mut UBYTE;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") string,
-            in("d0") length,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteArgstring(RexxSysBase: *mut Library, argstring: *mut UBYTE) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") argstring,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn LengthArgstring(RexxSysBase: *mut Library, argstring: *const UBYTE) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") argstring,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn CreateRexxMsg(
-    RexxSysBase: *mut Library,
-    port: *mut MsgPort,
-    extension: CONST_STRPTR,
-    host: CONST_STRPTR,
-) -> *mut RexxMsg {
-    let asm_ret_value: *mut RexxMsg;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") port,
-            in("a1") extension,
-            in("d0") host,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn DeleteRexxMsg(RexxSysBase: *mut Library, packet: *mut RexxMsg) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") packet,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn ClearRexxMsg(RexxSysBase: *mut Library, msgptr: *mut RexxMsg, count: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") msgptr,
-            in("d0") count,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn FillRexxMsg(
-    RexxSysBase: *mut Library,
-    msgptr: *mut RexxMsg,
-    count: ULONG,
-    mask: ULONG,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") msgptr,
-            in("d0") count,
-            in("d1") mask,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn IsRexxMsg(RexxSysBase: *mut Library, msgptr: *const RexxMsg) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") msgptr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn LockRexxBase(RexxSysBase: *mut Library, resource: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("d0") resource,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
-pub unsafe fn UnlockRexxBase(RexxSysBase: *mut Library, resource: ULONG) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("d0") resource,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn CreateRexxHostPort(
-    RexxSysBase: *mut Library,
-    basename: CONST_STRPTR,
-) -> *mut MsgPort {
-    let asm_ret_value: *mut MsgPort;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-480, %a6)
-            ".short -480",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") basename,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn DeleteRexxHostPort(RexxSysBase: *mut Library, port: *mut MsgPort) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-486, %a6)
-            ".short -486",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") port,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn GetRexxVarFromMsg(
-    RexxSysBase: *mut Library,
-    var: CONST_STRPTR,
-    msgptr: *const RexxMsg,
-    value: STRPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-492, %a6)
-            ".short -492",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") var,
-            in("a2") msgptr,
-            in("a1") value,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SetRexxVarFromMsg(
-    RexxSysBase: *mut Library,
-    var: CONST_STRPTR,
-    msgptr: *mut RexxMsg,
-    value: CONST_STRPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-498, %a6)
-            ".short -498",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") var,
-            in("a2") msgptr,
-            in("a1") value,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn LaunchRexxScript(
-    RexxSysBase: *mut Library,
-    script: CONST_STRPTR,
-    replyport: *mut MsgPort,
-    extension: CONST_STRPTR,
-    input: BPTR,
-    output: BPTR,
-) -> *mut RexxMsg {
-    let asm_ret_value: *mut RexxMsg;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-504, %a6)
-            ".short -504",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") script,
-            in("a1") replyport,
-            in("a2") extension,
-            in("d1") input,
-            in("d2") output,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn FreeRexxMsg(RexxSysBase: *mut Library, msgptr: *mut RexxMsg) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-510, %a6)
-            ".short -510",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") msgptr,
-        );
-    }
-}
-
-/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn GetRexxBufferFromMsg(
-    RexxSysBase: *mut Library,
-    var: CONST_STRPTR,
-    msgptr: *const RexxMsg,
-    buffer: STRPTR,
-    buffer_size: ULONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-516, %a6)
-            ".short -516",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) RexxSysBase,
-            in("a0") var,
-            in("a2") msgptr,
-            in("a1") buffer,
-            in("d0") buffer_size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "scroller.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SCROLLER_GetClass(ScrollerBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ScrollerBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "sketchboard.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SKETCHBOARD_GetClass(SketchBoardBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SketchBoardBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "slider.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SLIDER_GetClass(SliderBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SliderBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "space.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SPACE_GetClass(SpaceBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SpaceBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SPEEDBAR_GetClass(SpeedBarBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SpeedBarBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn AllocSpeedButtonNodeA(
-    SpeedBarBase: *mut ::core::ffi::c_void,
-    number: ULONG,
-    tags: *mut TagItem,
-) -> *mut Node {
-    let asm_ret_value: *mut Node;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) SpeedBarBase,
-            in("d0") number,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn FreeSpeedButtonNode(SpeedBarBase: *mut ::core::ffi::c_void, node: *mut Node) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SpeedBarBase,
-            in("a0") node,
-        );
-    }
-}
-
-/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn SetSpeedButtonNodeAttrsA(
-    SpeedBarBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SpeedBarBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn GetSpeedButtonNodeAttrsA(
-    SpeedBarBase: *mut ::core::ffi::c_void,
-    node: *mut Node,
-    tags: *mut TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) SpeedBarBase,
-            in("a0") node,
-            in("a1") tags,
-        );
-    }
-}
-
-/// libname: "string.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn STRING_GetClass(StringBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) StringBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "texteditor.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn TEXTEDITOR_GetClass(TextFieldBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TextFieldBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "texteditor.gadget" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn HighlightSetFormat(
-    TextFieldBase: *mut ::core::ffi::c_void,
-    object: APTR,
-    pos: ULONG,
-    end: ULONG,
-    style: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) TextFieldBase,
-            in("a0") object,
-            in("d0") pos,
-            in("d1") end,
-            in("d2") style,
-        );
-    }
-}
-
-/// libname: "timer.device"
-pub unsafe fn AddTime(
-    TimerBase: *mut ::core::ffi::c_void,
-    dest: *mut TimeVal_Type,
-    src: *const TimeVal_Type,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) TimerBase,
-            in("a0") dest,
-            in("a1") src,
-        );
-    }
-}
-
-/// libname: "timer.device"
-pub unsafe fn SubTime(
-    TimerBase: *mut ::core::ffi::c_void,
-    dest: *mut TimeVal_Type,
-    src: *const TimeVal_Type,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) TimerBase,
-            in("a0") dest,
-            in("a1") src,
-        );
-    }
-}
-
-/// libname: "timer.device"
-pub unsafe fn CmpTime(
-    TimerBase: *mut ::core::ffi::c_void,
-    dest: *const TimeVal_Type,
-    src: *const TimeVal_Type,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TimerBase,
-            in("a0") dest,
-            in("a1") src,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "timer.device" (V36) (Kickstart 2.0)
-pub unsafe fn ReadEClock(TimerBase: *mut ::core::ffi::c_void, dest: *mut EClockVal) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TimerBase,
-            in("a0") dest,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "timer.device" (V36) (Kickstart 2.0)
-pub unsafe fn GetSysTime(TimerBase: *mut ::core::ffi::c_void, dest: *mut TimeVal_Type) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) TimerBase,
-            in("a0") dest,
-        );
-    }
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFStartUnitTagList(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFStopUnitTagList(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFInsertMediaTagList(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFEjectMediaTagList(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFGetUnitData(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-) -> *mut TrackFileUnitData {
-    let asm_ret_value: *mut TrackFileUnitData;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFFreeUnitData(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    tfud: *mut TrackFileUnitData,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("a0") tfud,
-        );
-    }
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFChangeUnitTagList(
-    TrackFileBase: *mut ::core::ffi::c_void,
-    which_unit: LONG,
-    tags: *const TagItem,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") which_unit,
-            in("a0") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn TFExamineFileSize(TrackFileBase: *mut ::core::ffi::c_void, file_size: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TrackFileBase,
-            in("d0") file_size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "translator.library"
-pub unsafe fn Translate(
-    TranslatorBase: *mut Library,
-    inputString: CONST_STRPTR,
-    inputLength: LONG,
-    outputBuffer: STRPTR,
-    bufferSize: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) TranslatorBase,
-            in("a0") inputString,
-            in("d0") inputLength,
-            in("a1") outputBuffer,
-            in("d1") bufferSize,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn FindTagItem(
-    UtilityBase: *mut Library,
-    tagVal: Tag,
-    tagList: *const TagItem,
-) -> *mut TagItem {
-    let asm_ret_value: *mut TagItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") tagVal,
-            in("a0") tagList,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn GetTagData(
-    UtilityBase: *mut Library,
-    tagValue: Tag,
-    defaultVal: ULONG,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") tagValue,
-            in("d1") defaultVal,
-            in("a0") tagList,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn PackBoolTags(
-    UtilityBase: *mut Library,
-    initialFlags: ULONG,
-    tagList: *const TagItem,
-    boolMap: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") initialFlags,
-            in("a0") tagList,
-            in("a1") boolMap,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn NextTagItem(
-    UtilityBase: *mut Library,
-    tagListPtr: *mut *mut TagItem,
-) -> *mut TagItem {
-    let asm_ret_value: *mut TagItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") tagListPtr,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn FilterTagChanges(
-    UtilityBase: *mut Library,
-    changeList: *mut TagItem,
-    originalList: *mut TagItem,
-    apply: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") changeList,
-            in("a1") originalList,
-            in("d0") apply,
-        );
-    }
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn MapTags(
-    UtilityBase: *mut Library,
-    tagList: *mut TagItem,
-    mapList: *const TagItem,
-    mapType: ULONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") tagList,
-            in("a1") mapList,
-            in("d0") mapType,
-        );
-    }
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn AllocateTagItems(UtilityBase: *mut Library, numTags: ULONG) -> *mut TagItem {
-    let asm_ret_value: *mut TagItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") numTags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn CloneTagItems(UtilityBase: *mut Library, tagList: *const TagItem) -> *mut TagItem {
-    let asm_ret_value: *mut TagItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn FreeTagItems(UtilityBase: *mut Library, tagList: *mut TagItem) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") tagList,
-        );
-    }
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn RefreshTagItemClones(
-    UtilityBase: *mut Library,
-    clone: *mut TagItem,
-    original: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-84, %a6)
-            ".short -84",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") clone,
-            in("a1") original,
-        );
-    }
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn TagInArray(UtilityBase: *mut Library, tagValue: Tag, tagArray: *const Tag) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") tagValue,
-            in("a0") tagArray,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn FilterTagItems(
-    UtilityBase: *mut Library,
-    tagList: *mut TagItem,
-    filterArray: *const Tag,
-    logic: ULONG,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") tagList,
-            in("a1") filterArray,
-            in("d0") logic,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn CallHookPkt(
-    UtilityBase: *mut Library,
-    hook: *mut Hook,
-    object: APTR,
-    paramPacket: APTR,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") hook,
-            in("a2") object,
-            in("a1") paramPacket,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn Amiga2Date(UtilityBase: *mut Library, seconds: ULONG, result: *mut ClockData) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") seconds,
-            in("a0") result,
-        );
-    }
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn Date2Amiga(UtilityBase: *mut Library, date: *const ClockData) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") date,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn CheckDate(UtilityBase: *mut Library, date: *const ClockData) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") date,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn SMult32(UtilityBase: *mut Library, arg1: LONG, arg2: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") arg1,
-            in("d1") arg2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn UMult32(UtilityBase: *mut Library, arg1: ULONG, arg2: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-144, %a6)
-            ".short -144",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") arg1,
-            in("d1") arg2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn SDivMod32(UtilityBase: *mut Library, dividend: LONG, divisor: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-150, %a6)
-            ".short -150",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") dividend,
-            in("d1") divisor,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V36) (Kickstart 2.0)
-pub unsafe fn UDivMod32(UtilityBase: *mut Library, dividend: ULONG, divisor: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-156, %a6)
-            ".short -156",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") dividend,
-            in("d1") divisor,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V37) (Kickstart 2.04)
-pub unsafe fn Stricmp(
-    UtilityBase: *mut Library,
-    string1: CONST_STRPTR,
-    string2: CONST_STRPTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-162, %a6)
-            ".short -162",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") string1,
-            in("a1") string2,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V37) (Kickstart 2.04)
-pub unsafe fn Strnicmp(
-    UtilityBase: *mut Library,
-    string1: CONST_STRPTR,
-    string2: CONST_STRPTR,
-    length: LONG,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-168, %a6)
-            ".short -168",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") string1,
-            in("a1") string2,
-            in("d0") length,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V37) (Kickstart 2.04)
-pub unsafe fn ToUpper(UtilityBase: *mut Library, character: ULONG) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-174, %a6)
-            ".short -174",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "utility.library" (V37) (Kickstart 2.04)
-pub unsafe fn ToLower(UtilityBase: *mut Library, character: ULONG) -> UBYTE {
-    let asm_ret_value: i16;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-180, %a6)
-            ".short -180",
-            "and.w #0x00ff, %d0",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") character,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value as u8
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn ApplyTagChanges(
-    UtilityBase: *mut Library,
-    list: *mut TagItem,
-    changeList: *const TagItem,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-186, %a6)
-            ".short -186",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") list,
-            in("a1") changeList,
-        );
-    }
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn SMult64(UtilityBase: *mut Library, arg1: LONG, arg2: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-198, %a6)
-            ".short -198",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") arg1,
-            in("d1") arg2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn UMult64(UtilityBase: *mut Library, arg1: ULONG, arg2: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-204, %a6)
-            ".short -204",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d0") arg1,
-            in("d1") arg2,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn PackStructureTags(
-    UtilityBase: *mut Library,
-    pack: APTR,
-    packTable: *const ULONG,
-    tagList: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-210, %a6)
-            ".short -210",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") pack,
-            in("a1") packTable,
-            in("a2") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn UnpackStructureTags(
-    UtilityBase: *mut Library,
-    pack: CONST_APTR,
-    packTable: *const ULONG,
-    tagList: *mut TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-216, %a6)
-            ".short -216",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") pack,
-            in("a1") packTable,
-            in("a2") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn AddNamedObject(
-    UtilityBase: *mut Library,
-    nameSpace: *mut NamedObject,
-    object: *mut NamedObject,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-222, %a6)
-            ".short -222",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") nameSpace,
-            in("a1") object,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn AllocNamedObjectA(
-    UtilityBase: *mut Library,
-    name: CONST_STRPTR,
-    tagList: *const TagItem,
-) -> *mut NamedObject {
-    let asm_ret_value: *mut NamedObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-228, %a6)
-            ".short -228",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") name,
-            in("a1") tagList,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn AttemptRemNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-234, %a6)
-            ".short -234",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") object,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn FindNamedObject(
-    UtilityBase: *mut Library,
-    nameSpace: *mut NamedObject,
-    name: CONST_STRPTR,
-    lastObject: *const NamedObject,
-) -> *mut NamedObject {
-    let asm_ret_value: *mut NamedObject;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-240, %a6)
-            ".short -240",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") nameSpace,
-            in("a1") name,
-            in("a2") lastObject,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn FreeNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-246, %a6)
-            ".short -246",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") object,
-        );
-    }
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn NamedObjectName(UtilityBase: *mut Library, object: *mut NamedObject) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-252, %a6)
-            ".short -252",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") object,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn ReleaseNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-258, %a6)
-            ".short -258",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") object,
-        );
-    }
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn RemNamedObject(
-    UtilityBase: *mut Library,
-    object: *mut NamedObject,
-    message: *mut Message,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-264, %a6)
-            ".short -264",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") object,
-            in("a1") message,
-        );
-    }
-}
-
-/// libname: "utility.library" (V39) (Kickstart 3.0)
-pub unsafe fn GetUniqueID(UtilityBase: *mut Library) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-270, %a6)
-            ".short -270",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn VSNPrintf(
-    UtilityBase: *mut Library,
-    buffer: STRPTR,
-    bufsize: ULONG,
-    fmt: CONST_STRPTR,
-    data: CONST_APTR,
-) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-312, %a6)
-            ".short -312",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a0") buffer,
-            in("d0") bufsize,
-            in("a1") fmt,
-            in("a2") data,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn Strncpy(
-    UtilityBase: *mut Library,
-    dst: STRPTR,
-    src: CONST_STRPTR,
-    size: ULONG,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-438, %a6)
-            ".short -438",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a1") dst,
-            in("a0") src,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn Strncat(
-    UtilityBase: *mut Library,
-    dst: STRPTR,
-    src: CONST_STRPTR,
-    size: ULONG,
-) -> STRPTR {
-    let asm_ret_value: STRPTR;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-444, %a6)
-            ".short -444",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("a1") dst,
-            in("a0") src,
-            in("d0") size,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn SDivMod64(UtilityBase: *mut Library, hi: LONG, lo: LONG, divisor: LONG) -> LONG {
-    let asm_ret_value: LONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-450, %a6)
-            ".short -450",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d1") hi,
-            in("d0") lo,
-            in("d2") divisor,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn UDivMod64(UtilityBase: *mut Library, hi: ULONG, lo: ULONG, divisor: ULONG) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-456, %a6)
-            ".short -456",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) UtilityBase,
-            in("d1") hi,
-            in("d0") lo,
-            in("d2") divisor,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn VIRTUAL_GetClass(VirtualBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) VirtualBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn RefreshVirtualGadget(
-    VirtualBase: *mut ::core::ffi::c_void,
-    gadget: *mut Gadget,
-    obj: *mut Object,
-    window: *mut Window,
-    requester: *mut Requester,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-36, %a6)
-            ".short -36",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) VirtualBase,
-            in("a0") gadget,
-            in("a1") obj,
-            in("a2") window,
-            in("a3") requester,
-        );
-    }
-}
-
-/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn RethinkVirtualSize(
-    VirtualBase: *mut ::core::ffi::c_void,
-    virt_obj: *mut Object,
-    rootlayout: *mut Object,
-    font: *mut TextFont,
-    screen: *mut Screen,
-    layoutlimits: *mut LayoutLimits,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-42, %a6)
-            ".short -42",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) VirtualBase,
-            in("a0") virt_obj,
-            in("a1") rootlayout,
-            in("a2") font,
-            in("a3") screen,
-            in("d0") layoutlimits,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn UpdateWorkbench(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    lock: BPTR,
-    action: LONG,
-) {
-    unsafe {
-        asm!(
-            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") lock,
-            in("d0") action,
-        );
-    }
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddAppWindowA(
-    WorkbenchBase: *mut Library,
-    id: ULONG,
-    userdata: ULONG,
-    window: *mut Window,
-    msgport: *mut MsgPort,
-    taglist: *const TagItem,
-) -> *mut AppWindow {
-    let asm_ret_value: *mut AppWindow;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-48, %a6)
-            ".short -48",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("d0") id,
-            in("d1") userdata,
-            in("a0") window,
-            in("a1") msgport,
-            in("a2") taglist,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemoveAppWindow(WorkbenchBase: *mut Library, appWindow: *mut AppWindow) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-54, %a6)
-            ".short -54",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") appWindow,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddAppIconA(
-    WorkbenchBase: *mut Library,
-    id: ULONG,
-    userdata: ULONG,
-    text: CONST_STRPTR,
-    msgport: *mut MsgPort,
-    lock: BPTR,
-    diskobj: *mut DiskObject,
-    taglist: *const TagItem,
-) -> *mut AppIcon {
-    let asm_ret_value: *mut AppIcon;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l %a4, -(%sp)",
-            "move.l {basereg}, %a6",
-            "move.l {a4reg}, %a4",
-            ".short 0x4eae", // jsr (-60, %a6)
-            ".short -60",
-            "move.l (%sp)+, %a4",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("d0") id,
-            in("d1") userdata,
-            in("a0") text,
-            in("a1") msgport,
-            in("a2") lock,
-            in("a3") diskobj,
-            a4reg = in(reg) taglist,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemoveAppIcon(WorkbenchBase: *mut Library, appIcon: *mut AppIcon) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-66, %a6)
-            ".short -66",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") appIcon,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn AddAppMenuItemA(
-    WorkbenchBase: *mut Library,
-    id: ULONG,
-    userdata: ULONG,
-    text: CONST_STRPTR,
-    msgport: *mut MsgPort,
-    taglist: *const TagItem,
-) -> *mut AppMenuItem {
-    let asm_ret_value: *mut AppMenuItem;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-72, %a6)
-            ".short -72",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("d0") id,
-            in("d1") userdata,
-            in("a0") text,
-            in("a1") msgport,
-            in("a2") taglist,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V36) (Kickstart 2.0)
-pub unsafe fn RemoveAppMenuItem(
-    WorkbenchBase: *mut Library,
-    appMenuItem: *mut AppMenuItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-78, %a6)
-            ".short -78",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") appMenuItem,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V39) (Kickstart 3.0)
-pub unsafe fn WBInfo(
-    WorkbenchBase: *mut Library,
-    lock: BPTR,
-    name: CONST_STRPTR,
-    screen: *mut Screen,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-90, %a6)
-            ".short -90",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") lock,
-            in("a1") name,
-            in("a2") screen,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn OpenWorkbenchObjectA(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-96, %a6)
-            ".short -96",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn CloseWorkbenchObjectA(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-102, %a6)
-            ".short -102",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn WorkbenchControlA(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-108, %a6)
-            ".short -108",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn AddAppWindowDropZoneA(
-    WorkbenchBase: *mut Library,
-    aw: *mut AppWindow,
-    id: ULONG,
-    userdata: ULONG,
-    tags: *const TagItem,
-) -> *mut AppWindowDropZone {
-    let asm_ret_value: *mut AppWindowDropZone;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-114, %a6)
-            ".short -114",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") aw,
-            in("d0") id,
-            in("d1") userdata,
-            in("a1") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn RemoveAppWindowDropZone(
-    WorkbenchBase: *mut Library,
-    aw: *mut AppWindow,
-    dropZone: *mut AppWindowDropZone,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-120, %a6)
-            ".short -120",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") aw,
-            in("a1") dropZone,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn ChangeWorkbenchSelectionA(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    hook: *mut Hook,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-126, %a6)
-            ".short -126",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") hook,
-            in("a2") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
-pub unsafe fn MakeWorkbenchObjectVisibleA(
-    WorkbenchBase: *mut Library,
-    name: CONST_STRPTR,
-    tags: *const TagItem,
-) -> BOOL {
-    let asm_ret_value: BOOL;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-132, %a6)
-            ".short -132",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") name,
-            in("a1") tags,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "workbench.library" (V47) (Kickstart 3.2 / 2020)
-pub unsafe fn WhichWorkbenchObjectA(
-    WorkbenchBase: *mut Library,
-    window: *mut Window,
-    x: LONG,
-    y: LONG,
-    tags: *const TagItem,
-) -> ULONG {
-    let asm_ret_value: ULONG;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-138, %a6)
-            ".short -138",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WorkbenchBase,
-            in("a0") window,
-            in("d0") x,
-            in("d1") y,
-            in("a1") tags,
-            lateout("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "window.class" (V40) (Kickstart 3.1)
-pub unsafe fn WINDOW_GetClass(WindowBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) WindowBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
-/// libname: "listview.gadget" (V40) (Kickstart 3.1)
-pub unsafe fn LISTVIEW_GetClass(ListViewBase: *mut ::core::ffi::c_void) -> *mut Class {
-    let asm_ret_value: *mut Class;
-    unsafe {
-        asm!(
-            "movem.l %d1/%a0-%a1, -(%sp)",
-            "move.l %a6, -(%sp)",
-            "move.l {basereg}, %a6",
-            ".short 0x4eae", // jsr (-30, %a6)
-            ".short -30",
-            "move.l (%sp)+, %a6",
-            "movem.l (%sp)+, %d1/%a0-%a1",
-            basereg = in(reg) ListViewBase,
-            out("d0") asm_ret_value,
-        );
-    }
-    asm_ret_value
-}
-
 #[repr(C)]
 #[derive(Debug, Copy, Clone)]
 pub struct PrinterUnit {
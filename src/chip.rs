@@ -0,0 +1,308 @@
+use crate::{APTR, MEMF_CHIP, MEMF_CLEAR, ULONG};
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of};
+use core::ops::{Deref, DerefMut};
+use core::ptr::NonNull;
+
+/// Alignment of the memory returned by `AllocMem()`
+const ALLOC_MEM_ALIGN: usize = 8;
+
+/// Allocates cleared chip memory for `count` values of `T`
+///
+/// Returns a dangling pointer for zero bytes. Returns `None` if the allocation fails or if
+/// `TypeOfMem()` says that the memory isn't chip memory.
+fn alloc_chip<T>(count: usize) -> Option<NonNull<T>> {
+    assert!(
+        align_of::<T>() <= ALLOC_MEM_ALIGN,
+        "alignment too large for AllocMem()"
+    );
+    let size = size_of::<T>().checked_mul(count)?;
+    if size == 0 {
+        return Some(NonNull::dangling());
+    }
+    let size = ULONG::try_from(size).ok()?;
+    unsafe {
+        let exec = crate::abs_exec_library();
+        let memory = NonNull::new(crate::AllocMem(exec, size, MEMF_CHIP | MEMF_CLEAR))?;
+        if crate::TypeOfMem(exec, memory.as_ptr()) & MEMF_CHIP == 0 {
+            crate::FreeMem(exec, memory.as_ptr(), size);
+            return None;
+        }
+        Some(memory.cast())
+    }
+}
+
+/// Frees memory allocated with `alloc_chip()`
+unsafe fn free_chip<T>(memory: NonNull<T>, count: usize) {
+    let size = size_of::<T>() * count;
+    if size != 0 {
+        unsafe {
+            crate::FreeMem(
+                crate::abs_exec_library(),
+                memory.as_ptr() as APTR,
+                size as ULONG,
+            )
+        }
+    }
+}
+
+/// A value in chip memory, freed when dropped
+///
+/// Chip memory is the memory which the custom chips can access: bitplanes, sprites, audio
+/// samples, copper lists, etc. The memory is allocated with `AllocMem(MEMF_CHIP | MEMF_CLEAR)`
+/// and checked with `TypeOfMem()`:
+///
+/// ```ignore
+/// let mut plane = ChipBox::new([0u8; 40 * 256]).unwrap();
+/// bitmap.Planes[0] = plane.as_mut_ptr().cast();
+/// ```
+///
+/// The value is constructed on the stack and moved to chip memory. Large buffers can be
+/// allocated with [`ChipBox::new_zeroed()`] or [`ChipVec`] instead. The alignment of `T`
+/// can be at most 8.
+pub struct ChipBox<T> {
+    memory: NonNull<T>,
+    owns_value: PhantomData<T>,
+}
+
+impl<T> ChipBox<T> {
+    /// Moves the value to chip memory
+    ///
+    /// Returns `None` if there isn't enough chip memory.
+    pub fn new(value: T) -> Option<Self> {
+        let memory = alloc_chip::<T>(1)?;
+        unsafe { memory.as_ptr().write(value) };
+        Some(Self {
+            memory,
+            owns_value: PhantomData,
+        })
+    }
+
+    /// Allocates a value with all bytes zero, without constructing it on the stack
+    ///
+    /// Returns `None` if there isn't enough chip memory.
+    ///
+    /// # Safety
+    ///
+    /// All bytes zero must be a valid value of `T`, as it is for integers and arrays of
+    /// integers.
+    pub unsafe fn new_zeroed() -> Option<Self> {
+        let memory = alloc_chip::<T>(1)?;
+        Some(Self {
+            memory,
+            owns_value: PhantomData,
+        })
+    }
+
+    /// Returns a pointer to the value
+    pub fn as_ptr(&self) -> *const T {
+        self.memory.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the value, for example for `BitMap.Planes` after a cast
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.memory.as_ptr()
+    }
+}
+
+impl<T> Deref for ChipBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { self.memory.as_ref() }
+    }
+}
+
+impl<T> DerefMut for ChipBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { self.memory.as_mut() }
+    }
+}
+
+impl<T> Drop for ChipBox<T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.memory.as_ptr().drop_in_place();
+            free_chip(self.memory, 1);
+        }
+    }
+}
+
+/// A growable array in chip memory, freed when dropped
+///
+/// Like [`ChipBox`], the memory is allocated with `AllocMem(MEMF_CHIP | MEMF_CLEAR)` and
+/// checked with `TypeOfMem()`. Growing the array copies it to a new allocation, which changes
+/// the address returned by `as_ptr()`: allocate the capacity in advance if the system
+/// keeps a pointer to the data.
+///
+/// ```ignore
+/// let sample = ChipVec::from_slice(include_bytes!("beep.raw")).unwrap();
+/// audio_request.ioa_Data = sample.as_ptr() as *mut UBYTE;
+/// audio_request.ioa_Length = sample.len() as ULONG;
+/// ```
+pub struct ChipVec<T> {
+    memory: NonNull<T>,
+    capacity: usize,
+    len: usize,
+    owns_values: PhantomData<T>,
+}
+
+impl<T> ChipVec<T> {
+    /// Creates an empty array without allocating
+    pub const fn new() -> Self {
+        Self {
+            memory: NonNull::dangling(),
+            capacity: 0,
+            len: 0,
+            owns_values: PhantomData,
+        }
+    }
+
+    /// Creates an empty array with chip memory for `capacity` values
+    ///
+    /// Returns `None` if there isn't enough chip memory.
+    pub fn with_capacity(capacity: usize) -> Option<Self> {
+        let memory = alloc_chip::<T>(capacity)?;
+        Some(Self {
+            memory,
+            capacity,
+            len: 0,
+            owns_values: PhantomData,
+        })
+    }
+
+    /// Creates an array of `len` values with all bytes zero
+    ///
+    /// Returns `None` if there isn't enough chip memory.
+    ///
+    /// # Safety
+    ///
+    /// All bytes zero must be a valid value of `T`, as it is for integers.
+    pub unsafe fn zeroed(len: usize) -> Option<Self> {
+        let mut vec = Self::with_capacity(len)?;
+        vec.len = len;
+        Some(vec)
+    }
+
+    /// Copies the values to chip memory
+    ///
+    /// Returns `None` if there isn't enough chip memory.
+    pub fn from_slice(values: &[T]) -> Option<Self>
+    where
+        T: Copy,
+    {
+        let mut vec = Self::with_capacity(values.len())?;
+        unsafe {
+            vec.memory
+                .as_ptr()
+                .copy_from_nonoverlapping(values.as_ptr(), values.len())
+        };
+        vec.len = values.len();
+        Some(vec)
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the array is empty
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of values which fit in the allocated memory
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Reserves memory for at least `additional` more values
+    ///
+    /// Returns `false` if there isn't enough chip memory: the array is unchanged.
+    pub fn reserve(&mut self, additional: usize) -> bool {
+        let Some(needed) = self.len.checked_add(additional) else {
+            return false;
+        };
+        if needed <= self.capacity {
+            return true;
+        }
+        let capacity = needed.max(self.capacity * 2);
+        let Some(memory) = alloc_chip::<T>(capacity) else {
+            return false;
+        };
+        unsafe {
+            memory
+                .as_ptr()
+                .copy_from_nonoverlapping(self.memory.as_ptr(), self.len);
+            free_chip(self.memory, self.capacity);
+        }
+        self.memory = memory;
+        self.capacity = capacity;
+        true
+    }
+
+    /// Appends a value
+    ///
+    /// Returns the value back if there isn't enough chip memory.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if !self.reserve(1) {
+            return Err(value);
+        }
+        unsafe { self.memory.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last value
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        Some(unsafe { self.memory.as_ptr().add(self.len).read() })
+    }
+
+    /// Removes all values, keeping the memory
+    pub fn clear(&mut self) {
+        let values = core::ptr::slice_from_raw_parts_mut(self.memory.as_ptr(), self.len);
+        self.len = 0;
+        unsafe { values.drop_in_place() };
+    }
+
+    /// Returns a pointer to the first value, for example for `IOAudio.ioa_Data` after a cast
+    pub fn as_ptr(&self) -> *const T {
+        self.memory.as_ptr()
+    }
+
+    /// Returns a mutable pointer to the first value, for example for `SimpleSprite.posctldata`
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.memory.as_ptr()
+    }
+}
+
+impl<T> Default for ChipVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for ChipVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe { core::slice::from_raw_parts(self.memory.as_ptr(), self.len) }
+    }
+}
+
+impl<T> DerefMut for ChipVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe { core::slice::from_raw_parts_mut(self.memory.as_ptr(), self.len) }
+    }
+}
+
+impl<T> Drop for ChipVec<T> {
+    fn drop(&mut self) {
+        self.clear();
+        unsafe { free_chip(self.memory, self.capacity) };
+    }
+}
@@ -0,0 +1,123 @@
+/// Iterator which splits a CLI argument line into arguments with the AmigaDOS quoting rules
+///
+/// The arguments are separated by spaces or tabs and the line ends at a newline. An argument
+/// starting with `"` ends at the next `"` and can contain spaces and the escapes `*"` (quote),
+/// `*N` (newline), `*E` (escape) and `**` (asterisk). Outside quotes `*` is a normal character.
+///
+/// ```ignore
+/// let mut args = CliArgs::new(b"copy \"my file\" RAM:\n");
+/// assert_eq!(args.next().unwrap().raw(), b"copy");
+/// assert!(args.next().unwrap().bytes().eq(*b"my file"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CliArgs<'a> {
+    line: &'a [u8],
+}
+
+impl<'a> CliArgs<'a> {
+    /// Creates an iterator over the arguments of an argument line
+    pub fn new(line: &'a [u8]) -> Self {
+        Self { line }
+    }
+}
+
+impl<'a> Iterator for CliArgs<'a> {
+    type Item = CliArg<'a>;
+
+    fn next(&mut self) -> Option<CliArg<'a>> {
+        let start = self.line.iter().position(|&c| c != b' ' && c != b'\t')?;
+        let line = &self.line[start..];
+        match line[0] {
+            b'\n' | 0 => {
+                self.line = &[];
+                None
+            }
+            b'"' => {
+                // find the closing quote, skipping the escaped characters
+                let mut end = 1;
+                while end < line.len() && line[end] != b'"' && line[end] != b'\n' {
+                    let escape =
+                        line[end] == b'*' && line.get(end + 1).is_some_and(|&c| c != b'\n');
+                    end += if escape { 2 } else { 1 };
+                }
+                // skip the closing quote, but not a newline ending an unterminated argument
+                let next = if line.get(end) == Some(&b'"') {
+                    end + 1
+                } else {
+                    end
+                };
+                self.line = &line[next..];
+                Some(CliArg {
+                    raw: &line[1..end],
+                    quoted: true,
+                })
+            }
+            _ => {
+                let end = line
+                    .iter()
+                    .position(|&c| c == b' ' || c == b'\t' || c == b'\n' || c == 0)
+                    .unwrap_or(line.len());
+                self.line = &line[end..];
+                Some(CliArg {
+                    raw: &line[..end],
+                    quoted: false,
+                })
+            }
+        }
+    }
+}
+
+/// An argument of a CLI argument line returned by [`CliArgs`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CliArg<'a> {
+    raw: &'a [u8],
+    quoted: bool,
+}
+
+impl<'a> CliArg<'a> {
+    /// Returns the argument as it is in the line: without the quotes, but with the escapes
+    pub fn raw(&self) -> &'a [u8] {
+        self.raw
+    }
+
+    /// Returns `true` if the argument was in quotes
+    pub fn is_quoted(&self) -> bool {
+        self.quoted
+    }
+
+    /// Returns the characters of the argument with the escapes replaced
+    pub fn bytes(&self) -> CliArgBytes<'a> {
+        CliArgBytes {
+            raw: self.raw,
+            quoted: self.quoted,
+        }
+    }
+}
+
+/// Iterator over the characters of a [`CliArg`] with the escapes replaced
+#[derive(Clone, Debug)]
+pub struct CliArgBytes<'a> {
+    raw: &'a [u8],
+    quoted: bool,
+}
+
+impl Iterator for CliArgBytes<'_> {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        let (&c, rest) = self.raw.split_first()?;
+        self.raw = rest;
+        if !self.quoted || c != b'*' {
+            return Some(c);
+        }
+        let Some((&escaped, rest)) = self.raw.split_first() else {
+            return Some(c);
+        };
+        self.raw = rest;
+        Some(match escaped {
+            b'N' | b'n' => b'\n',
+            b'E' | b'e' => 0x1b,
+            _ => escaped,
+        })
+    }
+}
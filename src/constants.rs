@@ -8,159 +8,311 @@ const fn MAKE_ID(c1: char, c2: char, c3: char, c4: char) -> u32 {
 const fn MAKE_SID(c1: char, c2: char, c3: char, c4: char) -> u32 {
     ((c1 as u32) << 24) | ((c2 as u32) << 16) | ((c3 as u32) << 8) | (c4 as u32)
 }
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TAG_USER: u32 = 1 << 31;
 pub const BBID_DOS: [u8; 4] = [b'D', b'O', b'S', 0];
 pub const BBID_KICK: [u8; 4] = [b'K', b'I', b'C', b'K'];
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_Dummy: u32 = REACTION_Dummy + 0x30000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_HostName: u32 = AREXX_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_DefExtension: u32 = AREXX_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_Commands: u32 = AREXX_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_ErrorCode: u32 = AREXX_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_SigMask: u32 = AREXX_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_NoSlot: u32 = AREXX_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_ReplyHook: u32 = AREXX_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_MsgPort: u32 = AREXX_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AREXX_DefExtention: u32 = AREXX_DefExtension;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_Dummy: u32 = REACTION_Dummy + 0x45000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_Dummy: u32 = REQ_Dummy + 0x100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Dummy: u32 = REQ_Dummy + 0x200;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Dummy: u32 = REQ_Dummy + 0x300;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_Type: u32 = REQ_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_TitleText: u32 = REQ_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_BodyText: u32 = REQ_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_GadgetText: u32 = REQ_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_ReturnCode: u32 = REQ_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_TabSize: u32 = REQ_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_Image: u32 = REQ_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_VarArgs: u32 = REQ_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_EvenButtons: u32 = REQ_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_WrapBorder: u32 = REQ_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_TimeOutSecs: u32 = REQ_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_IDCMP_Ptr: u32 = REQ_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_Inactive: u32 = REQ_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_CharSet: u32 = REQ_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_StayOnTop: u32 = REQ_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQ_ForceFocus: u32 = REQ_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Minimum: u32 = REQI_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Maximum: u32 = REQI_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Invisible: u32 = REQI_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Number: u32 = REQI_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Arrows: u32 = REQI_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_MaxChars: u32 = REQI_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_MinText: u32 = REQI_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_MaxText: u32 = REQI_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_Mark: u32 = REQI_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQI_ReturnEnds: u32 = REQI_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_AllowEmpty: u32 = REQS_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_Invisible: u32 = REQI_Invisible;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_Buffer: u32 = REQS_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_ShowDefault: u32 = REQS_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_MaxChars: u32 = REQS_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_ChooserArray: u32 = REQS_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_ChooserActive: u32 = REQS_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_Mark: u32 = REQS_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQS_ReturnEnds: u32 = REQS_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Total: u32 = REQP_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Current: u32 = REQP_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_AbortText: u32 = REQ_GadgetText;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_ProgressText: u32 = REQ_BodyText;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_OpenInactive: u32 = REQP_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_NoText: u32 = REQP_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Dynamic: u32 = REQP_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_CenterWindow: u32 = REQP_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_LastPosition: u32 = REQP_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Percent: u32 = REQP_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_Ticks: u32 = REQP_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REQP_ShortTicks: u32 = REQP_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_WindowBox: u32 = WA_Dummy + 0x3b;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Dummy: u32 = REACTION_Dummy + 0x25000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Window: u32 = WINDOW_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_SigMask: u32 = WINDOW_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_MenuStrip: u32 = WINDOW_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Layout: u32 = WINDOW_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_ParentLayout: u32 = WINDOW_Layout;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_ParentGroup: u32 = WINDOW_Layout;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_UserData: u32 = WINDOW_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_SharedPort: u32 = WINDOW_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Zoom: u32 = WINDOW_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_FrontBack: u32 = WINDOW_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Activate: u32 = WINDOW_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_LockWidth: u32 = WINDOW_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_LockHeight: u32 = WINDOW_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_AppPort: u32 = WINDOW_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Position: u32 = WINDOW_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IDCMPHook: u32 = WINDOW_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IDCMPHookBits: u32 = WINDOW_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_GadgetUserData: u32 = WINDOW_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_InterpretUserData: u32 = WINDOW_GadgetUserData;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_MenuUserData: u32 = WINDOW_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IconTitle: u32 = WINDOW_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_AppMsgHook: u32 = WINDOW_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Icon: u32 = WINDOW_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_AppWindow: u32 = WINDOW_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_GadgetHelp: u32 = WINDOW_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IconifyGadget: u32 = WINDOW_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_TextAttr: u32 = WINDOW_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_BackFillName: u32 = WINDOW_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_RefWindow: u32 = WINDOW_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_InputEvent: u32 = WINDOW_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_HintInfo: u32 = WINDOW_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_KillWindow: u32 = WINDOW_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Application: u32 = WINDOW_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_InterpretIDCMPHook: u32 = WINDOW_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Parent: u32 = WINDOW_Dummy + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_PreRefreshHook: u32 = WINDOW_Dummy + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_PostRefreshHook: u32 = WINDOW_Dummy + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_AppWindowPtr: u32 = WINDOW_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IconNoDispose: u32 = WINDOW_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_NewMenu: u32 = WINDOW_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Qualifier: u32 = WINDOW_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_CharSet: u32 = WINDOW_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_BuiltInScroll: u32 = WINDOW_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_IDCMPSnoopHook: u32 = WINDOW_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_ShowingHint: u32 = WINDOW_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_NewPrefsHook: u32 = WINDOW_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_Iconifiable: u32 = WINDOW_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_PopupGadget: u32 = WINDOW_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_PopupHook: u32 = WINDOW_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_PopupItem: u32 = WINDOW_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_JumpScreensMenu: u32 = WINDOW_Dummy + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_UniqueID: u32 = WINDOW_Dummy + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_MenuAddress: u32 = WINDOW_Dummy + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_MenuType: u32 = WINDOW_Dummy + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_MenuContext: u32 = WINDOW_Dummy + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_VertProp: u32 = WINDOW_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_VertObject: u32 = WINDOW_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_HorizProp: u32 = WINDOW_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WINDOW_HorizObject: u32 = WINDOW_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Dummy: u32 = DTA_Dummy + 600;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_ModeID: u32 = PDTA_ModeID;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_KeyFrame: u32 = PDTA_BitMap;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_ColorRegisters: u32 = PDTA_ColorRegisters;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_CRegs: u32 = PDTA_CRegs;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_GRegs: u32 = PDTA_GRegs;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_ColorTable: u32 = PDTA_ColorTable;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_ColorTable2: u32 = PDTA_ColorTable2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Allocated: u32 = PDTA_Allocated;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_NumColors: u32 = PDTA_NumColors;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_NumAlloc: u32 = PDTA_NumAlloc;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Remap: u32 = PDTA_Remap;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Screen: u32 = PDTA_Screen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Width: u32 = ADTA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Height: u32 = ADTA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Depth: u32 = ADTA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Frames: u32 = ADTA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Frame: u32 = ADTA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_FramesPerSecond: u32 = ADTA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_FrameIncrement: u32 = ADTA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_PreloadFrameCount: u32 = ADTA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Sample: u32 = SDTA_Sample;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_SampleLength: u32 = SDTA_SampleLength;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Period: u32 = SDTA_Period;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Volume: u32 = SDTA_Volume;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_Cycles: u32 = SDTA_Cycles;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_LeftSample: u32 = SDTA_LeftSample;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_RightSample: u32 = SDTA_RightSample;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADTA_SamplesPerSec: u32 = SDTA_SamplesPerSec;
 pub const ID_ANIM: u32 = MAKE_ID('A', 'N', 'I', 'M');
 pub const ID_ANHD: u32 = MAKE_ID('A', 'N', 'H', 'D');
@@ -178,109 +330,211 @@ pub const GID_ANIMATION: u32 = MAKE_ID('a', 'n', 'i', 'm');
 pub const GID_MOVIE: u32 = MAKE_ID('m', 'o', 'v', 'i');
 pub const ID_CODE: u32 = MAKE_ID('D', 'T', 'C', 'D');
 pub const ID_TOOL: u32 = MAKE_ID('D', 'T', 'T', 'L');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TOOLA_Dummy: u32 = TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TOOLA_Program: u32 = TOOLA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TOOLA_Which: u32 = TOOLA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TOOLA_LaunchType: u32 = TOOLA_Dummy + 3;
 pub const ID_TAGS: u32 = MAKE_ID('D', 'T', 'T', 'G');
 pub const ID_NAME: u32 = MAKE_ID('N', 'A', 'M', 'E');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Dummy: u32 = TAG_USER + 0x1000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TextAttr: u32 = DTA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TopVert: u32 = DTA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_VisibleVert: u32 = DTA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TotalVert: u32 = DTA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_VertUnit: u32 = DTA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TopHoriz: u32 = DTA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_VisibleHoriz: u32 = DTA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TotalHoriz: u32 = DTA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_HorizUnit: u32 = DTA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_NodeName: u32 = DTA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Title: u32 = DTA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TriggerMethods: u32 = DTA_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Data: u32 = DTA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TextFont: u32 = DTA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Methods: u32 = DTA_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_PrinterStatus: u32 = DTA_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_PrinterProc: u32 = DTA_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_LayoutProc: u32 = DTA_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Busy: u32 = DTA_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Sync: u32 = DTA_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_BaseName: u32 = DTA_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_GroupID: u32 = DTA_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ErrorLevel: u32 = DTA_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ErrorNumber: u32 = DTA_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ErrorString: u32 = DTA_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Conductor: u32 = DTA_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ControlPanel: u32 = DTA_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Immediate: u32 = DTA_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Repeat: u32 = DTA_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_SourceAddress: u32 = DTA_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_SourceSize: u32 = DTA_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Reserved: u32 = DTA_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Class: u32 = DTA_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Name: u32 = DTA_Dummy + 100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_SourceType: u32 = DTA_Dummy + 101;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Handle: u32 = DTA_Dummy + 102;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_DataType: u32 = DTA_Dummy + 103;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Domain: u32 = DTA_Dummy + 104;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Left: u32 = DTA_Dummy + 105;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Top: u32 = DTA_Dummy + 106;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Width: u32 = DTA_Dummy + 107;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Height: u32 = DTA_Dummy + 108;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjName: u32 = DTA_Dummy + 109;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjAuthor: u32 = DTA_Dummy + 110;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjAnnotation: u32 = DTA_Dummy + 111;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjCopyright: u32 = DTA_Dummy + 112;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjVersion: u32 = DTA_Dummy + 113;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ObjectID: u32 = DTA_Dummy + 114;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_UserData: u32 = DTA_Dummy + 115;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_FrameInfo: u32 = DTA_Dummy + 116;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_RelRight: u32 = DTA_Dummy + 117;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_RelBottom: u32 = DTA_Dummy + 118;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_RelWidth: u32 = DTA_Dummy + 119;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_RelHeight: u32 = DTA_Dummy + 120;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_SelectDomain: u32 = DTA_Dummy + 121;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TotalPVert: u32 = DTA_Dummy + 122;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_TotalPHoriz: u32 = DTA_Dummy + 123;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_NominalVert: u32 = DTA_Dummy + 124;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_NominalHoriz: u32 = DTA_Dummy + 125;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_DestCols: u32 = DTA_Dummy + 400;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_DestRows: u32 = DTA_Dummy + 401;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_Special: u32 = DTA_Dummy + 402;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_RastPort: u32 = DTA_Dummy + 403;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DTA_ARexxPortName: u32 = DTA_Dummy + 404;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ModeID: u32 = DTA_Dummy + 200;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_BitMapHeader: u32 = DTA_Dummy + 201;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_BitMap: u32 = DTA_Dummy + 202;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ColorRegisters: u32 = DTA_Dummy + 203;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_CRegs: u32 = DTA_Dummy + 204;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_GRegs: u32 = DTA_Dummy + 205;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ColorTable: u32 = DTA_Dummy + 206;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ColorTable2: u32 = DTA_Dummy + 207;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_Allocated: u32 = DTA_Dummy + 208;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_NumColors: u32 = DTA_Dummy + 209;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_NumAlloc: u32 = DTA_Dummy + 210;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_Remap: u32 = DTA_Dummy + 211;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_Screen: u32 = DTA_Dummy + 212;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_FreeSourceBitMap: u32 = DTA_Dummy + 213;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_Grab: u32 = DTA_Dummy + 214;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_DestBitMap: u32 = DTA_Dummy + 215;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ClassBitMap: u32 = DTA_Dummy + 216;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_NumSparse: u32 = DTA_Dummy + 217;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_SparseTable: u32 = DTA_Dummy + 218;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_WhichPicture: u32 = DTA_Dummy + 219;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_GetNumPictures: u32 = DTA_Dummy + 220;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_MaxDitherPens: u32 = DTA_Dummy + 221;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_DitherQuality: u32 = DTA_Dummy + 222;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_AllocatedPens: u32 = DTA_Dummy + 223;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ScaleQuality: u32 = DTA_Dummy + 224;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_SourceMode: u32 = DTA_Dummy + 250;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_DestMode: u32 = DTA_Dummy + 251;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_UseFriendBitMap: u32 = DTA_Dummy + 255;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_MaskPlane: u32 = DTA_Dummy + 258;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ForeignPlaceholder1: u32 = DTA_Dummy + 259;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_ObtainPixelBuffer: u32 = DTA_Dummy + 260;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_SubClassRendersAll: u32 = DTA_Dummy + 261;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PDTA_AlphaChannel: u32 = DTA_Dummy + 256;
 pub const ID_ILBM: u32 = MAKE_ID('I', 'L', 'B', 'M');
 pub const ID_BMHD: u32 = MAKE_ID('B', 'M', 'H', 'D');
@@ -291,65 +545,120 @@ pub const ID_SPRT: u32 = MAKE_ID('S', 'P', 'R', 'T');
 pub const ID_DEST: u32 = MAKE_ID('D', 'E', 'S', 'T');
 pub const ID_CAMG: u32 = MAKE_ID('C', 'A', 'M', 'G');
 pub const ID_BODY: u32 = MAKE_ID('B', 'O', 'D', 'Y');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Dummy: u32 = DTA_Dummy + 500;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_VoiceHeader: u32 = SDTA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Sample: u32 = SDTA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SampleLength: u32 = SDTA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Period: u32 = SDTA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Volume: u32 = SDTA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Cycles: u32 = SDTA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SignalTask: u32 = SDTA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SignalBit: u32 = SDTA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SignalBitMask: u32 = SDTA_SignalBit;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Continuous: u32 = SDTA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SignalBitNumber: u32 = SDTA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SamplesPerSec: u32 = SDTA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_ReplayPeriod: u32 = SDTA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_LeftSample: u32 = SDTA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_RightSample: u32 = SDTA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_Pan: u32 = SDTA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_FreeSampleData: u32 = SDTA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_SyncSampleChange: u32 = SDTA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SDTA_BitsPerSample: u32 = SDTA_Dummy + 18;
 pub const ID_8SVX: u32 = MAKE_ID('8', 'S', 'V', 'X');
 pub const ID_VHDR: u32 = MAKE_ID('V', 'H', 'D', 'R');
 pub const ID_CHAN: u32 = MAKE_ID('C', 'H', 'A', 'N');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_Buffer: u32 = DTA_Dummy + 300;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_BufferLen: u32 = DTA_Dummy + 301;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_LineList: u32 = DTA_Dummy + 302;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_WordSelect: u32 = DTA_Dummy + 303;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_WordDelim: u32 = DTA_Dummy + 304;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDTA_WordWrap: u32 = DTA_Dummy + 305;
 pub const ID_FTXT: u32 = MAKE_ID('F', 'T', 'X', 'T');
 pub const ID_CHRS: u32 = MAKE_ID('C', 'H', 'R', 'S');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_Dummy: u32 = TAG_USER + 0x60000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_ICCProfile: u32 = DRPA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_ICCName: u32 = DRPA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_NoColCorrect: u32 = DRPA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_SourceHook: u32 = DRPA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_AspectX: u32 = DRPA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRPA_AspectY: u32 = DRPA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PPRA_Dummy: u32 = TAG_USER + 0x70000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PPRA_Window: u32 = PPRA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PPRA_Screen: u32 = PPRA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PPRA_PubScreen: u32 = PPRA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_Dummy: u32 = TAG_USER + 0x50000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_8BitGuns: u32 = PRTA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_ConvertSource: u32 = PRTA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_FloydDithering: u32 = PRTA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_AntiAlias: u32 = PRTA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_ColorCorrection: u32 = PRTA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_NoIO: u32 = PRTA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_NewColor: u32 = PRTA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_ColorSize: u32 = PRTA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_NoScaling: u32 = PRTA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_DitherNames: u32 = PRTA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_ShadingNames: u32 = PRTA_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_ColorCorrect: u32 = PRTA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_DensityInfo: u32 = PRTA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_LeftBorder: u32 = PRTA_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_TopBorder: u32 = PRTA_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_MixBWColor: u32 = PRTA_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PRTA_Preferences: u32 = PRTA_Dummy + 40;
 pub const SerErr_InvBaud: u32 = 3;
 pub const SerErr_NotOpen: u32 = 7;
@@ -374,802 +683,1593 @@ pub const IOSERB_ABORT: u32 = 5;
 pub const IOSERF_ABORT: u32 = 1 << 5;
 pub const IOSERB_ACTIVE: u32 = 4;
 pub const IOSERF_ACTIVE: u32 = 1 << 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_Dummy: u32 = TAG_USER + 0x20200400;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_ImageFileName: u32 = TF_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_ImageFileHandle: u32 = TF_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_WriteProtected: u32 = TF_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_DriveType: u32 = TF_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_Timeout: u32 = TF_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_EnableChecksums: u32 = TF_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_TagItemFailed: u32 = TF_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_MaxCacheMemory: u32 = TF_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_EnableUnitCache: u32 = TF_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TF_PrefillUnitCache: u32 = TF_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TA_CharSet: u32 = 3 | TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Level0: u32 = TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Level1: u32 = TAG_USER | 0x1000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Level2: u32 = TAG_USER | 0x2000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Level3: u32 = TAG_USER | 0x3000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_DeviceDPI: u32 = OT_Level0 | 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_DotSize: u32 = OT_Level0 | 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_PointHeight: u32 = OT_Level0 | 0x08;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SetFactor: u32 = OT_Level0 | 0x09;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_ShearSin: u32 = OT_Level0 | 0x0a;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_ShearCos: u32 = OT_Level0 | 0x0b;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_RotateSin: u32 = OT_Level0 | 0x0c;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_RotateCos: u32 = OT_Level0 | 0x0d;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_EmboldenX: u32 = OT_Level0 | 0x0e;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_EmboldenY: u32 = OT_Level0 | 0x0f;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_PointSize: u32 = OT_Level0 | 0x10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphCode: u32 = OT_Level0 | 0x11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphCode2: u32 = OT_Level0 | 0x12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphCode_32: u32 = OT_Level0 | 0x18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphCode2_32: u32 = OT_Level0 | 0x19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphWidth: u32 = OT_Level0 | 0x13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_OTagPath: u32 = OT_Level0 | OT_Indirect | 0x14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_OTagList: u32 = OT_Level0 | OT_Indirect | 0x15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_MemPtr: u32 = OT_Level0 | OT_Indirect | 0x16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_MemSize: u32 = OT_Level0 | 0x17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphMap: u32 = OT_Level0 | OT_Indirect | 0x20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphMap8Bit: u32 = OT_Level0 | OT_Indirect | 0x1a;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_WidthList: u32 = OT_Level0 | OT_Indirect | 0x21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_WidthList32: u32 = OT_Level0 | OT_Indirect | 0x1b;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_TextKernPair: u32 = OT_Level0 | OT_Indirect | 0x22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_DesignKernPair: u32 = OT_Level0 | OT_Indirect | 0x23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_UnderLined: u32 = OT_Level0 | 0x24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_StrikeThrough: u32 = OT_Level0 | 0x25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_BaseLine: u32 = OT_Level0 | OT_Indirect | 0x1c;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_NumGlyphs: u32 = OT_Level0 | OT_Indirect | 0x1d;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_NumKernPairs: u32 = OT_Level0 | OT_Indirect | 0x1e;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_HasGlyphs: u32 = OT_Level0 | OT_Indirect | 0x1f;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_GlyphName: u32 = OT_Level0 | OT_Indirect | 0x26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_FileIdent: u32 = OT_Level1 | 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Engine: u32 = OT_Level1 | OT_Indirect | 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Family: u32 = OT_Level1 | OT_Indirect | 0x03;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_BName: u32 = OT_Level2 | OT_Indirect | 0x05;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_IName: u32 = OT_Level2 | OT_Indirect | 0x06;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_BIName: u32 = OT_Level2 | OT_Indirect | 0x07;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_RName: u32 = OT_Level2 | OT_Indirect | 0x09;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SymbolSet: u32 = OT_Level1 | 0x10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_YSizeFactor: u32 = OT_Level1 | 0x11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SpaceWidth: u32 = OT_Level2 | 0x12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_IsFixed: u32 = OT_Level2 | 0x13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_IsUnderlined: u32 = OT_Level2 | 0x23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SerifFlag: u32 = OT_Level1 | 0x14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_StemWeight: u32 = OT_Level1 | 0x15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SlantStyle: u32 = OT_Level1 | 0x16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_HorizStyle: u32 = OT_Level1 | 0x17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SpaceFactor: u32 = OT_Level2 | 0x18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_InhibitAlgoStyle: u32 = OT_Level2 | 0x19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_AvailSizes: u32 = OT_Level1 | OT_Indirect | 0x20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_BMSize: u32 = OT_Level2 | 0x21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_SpecCount: u32 = OT_Level1 | 0x100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Spec: u32 = OT_Level1 | 0x100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OT_Spec1: u32 = OT_Level1 | 0x101;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_BASE: u32 = TAG_USER + 0x0B000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_XDPI: u32 = DFCTRL_BASE + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_YDPI: u32 = DFCTRL_BASE + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_XDOTP: u32 = DFCTRL_BASE + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_YDOTP: u32 = DFCTRL_BASE + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_CACHE: u32 = DFCTRL_BASE + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_SORTMODE: u32 = DFCTRL_BASE + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_CACHEFLUSH: u32 = DFCTRL_BASE + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCTRL_CHARSET: u32 = DFCTRL_BASE + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCS_NUMBER: u32 = DFCTRL_BASE + 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCS_NEXTNUMBER: u32 = DFCTRL_BASE + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCS_NAME: u32 = DFCTRL_BASE + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCS_MIMENAME: u32 = DFCTRL_BASE + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DFCS_MAPTABLE: u32 = DFCTRL_BASE + 4;
 pub const SIGBREAKF_CTRL_F: u32 = 1 << SIGBREAKB_CTRL_F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_Dummy: u32 = TAG_USER + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_Input: u32 = SYS_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_Output: u32 = SYS_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_Asynch: u32 = SYS_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_UserShell: u32 = SYS_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_CustomShell: u32 = SYS_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_Error: u32 = SYS_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_ExecuteInputStream: u32 = SYS_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_CmdStream: u32 = SYS_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_InName: u32 = SYS_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_OutName: u32 = SYS_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYS_CmdName: u32 = SYS_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Dummy: u32 = TAG_USER + 1000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Seglist: u32 = NP_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_FreeSeglist: u32 = NP_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Entry: u32 = NP_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Input: u32 = NP_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Output: u32 = NP_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CloseInput: u32 = NP_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CloseOutput: u32 = NP_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Error: u32 = NP_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CloseError: u32 = NP_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CurrentDir: u32 = NP_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_StackSize: u32 = NP_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Name: u32 = NP_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Priority: u32 = NP_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_ConsoleTask: u32 = NP_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_WindowPtr: u32 = NP_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_HomeDir: u32 = NP_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CopyVars: u32 = NP_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Cli: u32 = NP_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Path: u32 = NP_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_CommandName: u32 = NP_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Arguments: u32 = NP_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_NotifyOnDeath: u32 = NP_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_Synchronous: u32 = NP_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_ExitCode: u32 = NP_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NP_ExitData: u32 = NP_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_Dummy: u32 = TAG_USER + 2000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_FH_Mode: u32 = ADO_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_DirLen: u32 = ADO_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_CommNameLen: u32 = ADO_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_CommFileLen: u32 = ADO_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ADO_PromptLen: u32 = ADO_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SHELL_DUMMY: u32 = TAG_USER + 3000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SHELL_FGETS_FULL: u32 = SHELL_DUMMY + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SHELL_ADDH_LINE: u32 = SHELL_DUMMY + 2;
 pub const SHELL_METH_METHODS: u32 = MAKE_SID('L', 'I', 'S', 'T');
 pub const SHELL_METH_GETHIST: u32 = MAKE_SID('G', 'E', 'T', 'H');
 pub const SHELL_METH_CLRHIST: u32 = MAKE_SID('C', 'L', 'R', 'H');
 pub const SHELL_METH_ADDHIST: u32 = MAKE_SID('A', 'D', 'D', 'H');
 pub const SHELL_METH_FGETS: u32 = MAKE_SID('G', 'E', 'T', 'S');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Dummy: u32 = TAG_USER + 0x04000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_PushButton: u32 = BUTTON_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Glyph: u32 = BUTTON_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Array: u32 = BUTTON_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_TextPen: u32 = BUTTON_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_FillPen: u32 = BUTTON_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_FillTextPen: u32 = BUTTON_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_BackgroundPen: u32 = BUTTON_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Current: u32 = BUTTON_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_RenderImage: u32 = GA_Image;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_SelectImage: u32 = GA_SelectRender;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_BevelStyle: u32 = BUTTON_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Transparent: u32 = BUTTON_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Justification: u32 = BUTTON_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_SoftStyle: u32 = BUTTON_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_AutoButton: u32 = BUTTON_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_VarArgs: u32 = BUTTON_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_DomainString: u32 = BUTTON_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_Integer: u32 = BUTTON_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_BitMap: u32 = BUTTON_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_TextPadding: u32 = BUTTON_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_AnimButton: u32 = BUTTON_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_AnimImages: u32 = BUTTON_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_SelAnimImages: u32 = BUTTON_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_MaxAnimImages: u32 = BUTTON_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_AnimImageNumber: u32 = BUTTON_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_AddAnimImageNumber: u32 = BUTTON_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_SubAnimImageNumber: u32 = BUTTON_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_EraseBackground: u32 = BUTTON_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_HorizAlignment: u32 = BUTTON_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BUTTON_VertAlignment: u32 = BUTTON_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_Dummy: u32 = REACTION_Dummy + 0x11000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_TextPen: u32 = CHECKBOX_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_FillTextPen: u32 = CHECKBOX_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_BackgroundPen: u32 = CHECKBOX_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_BevelStyle: u32 = CHECKBOX_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_TextPlace: u32 = CHECKBOX_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_Checked: u32 = GA_Selected;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHECKBOX_Invert: u32 = CHECKBOX_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_Dummy: u32 = TAG_USER + 0x5001500;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_Text: u32 = CNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_Image: u32 = CNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_SelImage: u32 = CNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_UserData: u32 = CNA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_Separator: u32 = CNA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_Disabled: u32 = CNA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_BGPen: u32 = CNA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_FGPen: u32 = CNA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_ReadOnly: u32 = CNA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CNA_CopyText: u32 = CNA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Dummy: u32 = REACTION_Dummy + 0x0001000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_PopUp: u32 = CHOOSER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_DropDown: u32 = CHOOSER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Title: u32 = CHOOSER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Labels: u32 = CHOOSER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Active: u32 = CHOOSER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Selected: u32 = CHOOSER_Active;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Width: u32 = CHOOSER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_AutoFit: u32 = CHOOSER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_MaxLabels: u32 = CHOOSER_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Offset: u32 = CHOOSER_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Hidden: u32 = CHOOSER_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_LabelArray: u32 = CHOOSER_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_Justification: u32 = CHOOSER_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_ImageJustification: u32 = CHOOSER_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_SelectedNode: u32 = CHOOSER_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHOOSER_DeactivateOnMostRawKeys: u32 = CHOOSER_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Dummy: u32 = TAG_USER + 0x010000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_UserData: u32 = TNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Enabled: u32 = TNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Spacing: u32 = TNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Highlight: u32 = TNA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Image: u32 = TNA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_SelImage: u32 = TNA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Text: u32 = TNA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Number: u32 = TNA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_TextPen: u32 = TNA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Disabled: u32 = TNA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_Flagged: u32 = TNA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_HintInfo: u32 = TNA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_CloseGadget: u32 = TNA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_SoftStyle: u32 = TNA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TNA_HelpText: u32 = TNA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Dummy: u32 = REACTION_Dummy + 0x27000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Labels: u32 = CLICKTAB_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Current: u32 = CLICKTAB_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_CurrentNode: u32 = CLICKTAB_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Orientation: u32 = CLICKTAB_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_PageGroup: u32 = CLICKTAB_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_PageGroupBackFill: u32 = CLICKTAB_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_LabelTruncate: u32 = CLICKTAB_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_FlagImage: u32 = CLICKTAB_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_EvenSize: u32 = CLICKTAB_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Total: u32 = CLICKTAB_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_PageGroupBorder: u32 = CLICKTAB_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_PageBorder: u32 = CLICKTAB_PageGroupBorder;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_AutoFit: u32 = CLICKTAB_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_AutoTabNumbering: u32 = CLICKTAB_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_CloseImage: u32 = CLICKTAB_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_Closed: u32 = CLICKTAB_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_NodeClosed: u32 = CLICKTAB_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_ClosePlacement: u32 = CLICKTAB_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_ChooserFlagImage: u32 = CLICKTAB_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_MinorLabelChange: u32 = CLICKTAB_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CLICKTAB_TabsOffsetAsLayoutSpacing: u32 = CLICKTAB_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Dummy: u32 = TAG_USER + 0x04000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Hue: u32 = WHEEL_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Saturation: u32 = WHEEL_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Brightness: u32 = WHEEL_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_HSB: u32 = WHEEL_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Red: u32 = WHEEL_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Green: u32 = WHEEL_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Blue: u32 = WHEEL_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_RGB: u32 = WHEEL_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Screen: u32 = WHEEL_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Abbrv: u32 = WHEEL_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_Donation: u32 = WHEEL_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_BevelBox: u32 = WHEEL_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_GradientSlider: u32 = WHEEL_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_MaxPens: u32 = WHEEL_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WHEEL_KeepAspect: u32 = WHEEL_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_Dummy: u32 = REACTION_Dummy + 0x61000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_Day: u32 = DATEBROWSER_Dummy;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_Month: u32 = DATEBROWSER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_Year: u32 = DATEBROWSER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_SelectedDays: u32 = DATEBROWSER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_WeekDay: u32 = DATEBROWSER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_FirstWDay: u32 = DATEBROWSER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_NumDays: u32 = DATEBROWSER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_ShowTitle: u32 = DATEBROWSER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_MultiSelect: u32 = DATEBROWSER_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_DayTitles: u32 = DATEBROWSER_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_WeekTitle: u32 = DATEBROWSER_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DATEBROWSER_CalendarType: u32 = DATEBROWSER_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Dummy: u32 = REACTION_Dummy + 0x12000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Min: u32 = FUELGAUGE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Max: u32 = FUELGAUGE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Level: u32 = FUELGAUGE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Orientation: u32 = FUELGAUGE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Percent: u32 = FUELGAUGE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Ticks: u32 = FUELGAUGE_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_ShortTicks: u32 = FUELGAUGE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_TickSize: u32 = FUELGAUGE_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_TickPen: u32 = FUELGAUGE_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_PercentPen: u32 = FUELGAUGE_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_FillPen: u32 = FUELGAUGE_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_EmptyPen: u32 = FUELGAUGE_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_VarArgs: u32 = FUELGAUGE_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const FUELGAUGE_Justification: u32 = FUELGAUGE_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Dummy: u32 = REACTION_Dummy + 0x43000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_TitleText: u32 = GETCOLOR_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Screen: u32 = GETCOLOR_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Color: u32 = GETCOLOR_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Red: u32 = GETCOLOR_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Green: u32 = GETCOLOR_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Blue: u32 = GETCOLOR_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Hue: u32 = GETCOLOR_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Saturation: u32 = GETCOLOR_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Brightness: u32 = GETCOLOR_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_RGB: u32 = GETCOLOR_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_HSB: u32 = GETCOLOR_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_ColorWheel: u32 = GETCOLOR_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_RGBSliders: u32 = GETCOLOR_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_HSBSliders: u32 = GETCOLOR_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_SwitchMode: u32 = GETCOLOR_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_Initial: u32 = GETCOLOR_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_ShowRGB: u32 = GETCOLOR_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_ShowHSB: u32 = GETCOLOR_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETCOLOR_SmallTextAttr: u32 = GETCOLOR_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Dummy: u32 = REACTION_Dummy + 0x60000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_TitleText: u32 = GETFILE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_LeftEdge: u32 = GETFILE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_TopEdge: u32 = GETFILE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Width: u32 = GETFILE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Height: u32 = GETFILE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_File: u32 = GETFILE_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Drawer: u32 = GETFILE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FullFile: u32 = GETFILE_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FullFileExpand: u32 = GETFILE_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Pattern: u32 = GETFILE_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_DoSaveMode: u32 = GETFILE_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_DoMultiSelect: u32 = GETFILE_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_DoPatterns: u32 = GETFILE_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_DrawersOnly: u32 = GETFILE_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FilterFunc: u32 = GETFILE_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_RejectIcons: u32 = GETFILE_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_RejectPattern: u32 = GETFILE_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_AcceptPattern: u32 = GETFILE_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FilterDrawers: u32 = GETFILE_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_Filelist: u32 = GETFILE_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_LBNodeStructs: u32 = GETFILE_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_ReadOnly: u32 = GETFILE_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FilePartOnly: u32 = GETFILE_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_AllowEmptyFileSelection: u32 = GETFILE_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_FilterHook: u32 = GETFILE_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFILE_InitialShowVolumes: u32 = GETFILE_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_Dummy: u32 = REACTION_Dummy + 0x40000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_TextAttr: u32 = GETFONT_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoFrontPen: u32 = GETFONT_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoBackPen: u32 = GETFONT_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoStyle: u32 = GETFONT_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoDrawMode: u32 = GETFONT_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_MinHeight: u32 = GETFONT_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_MaxHeight: u32 = GETFONT_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_FixedWidthOnly: u32 = GETFONT_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_TitleText: u32 = GETFONT_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_Height: u32 = GETFONT_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_Width: u32 = GETFONT_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_LeftEdge: u32 = GETFONT_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_TopEdge: u32 = GETFONT_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_FrontPen: u32 = GETFONT_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_BackPen: u32 = GETFONT_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DrawMode: u32 = GETFONT_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_MaxFrontPen: u32 = GETFONT_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_MaxBackPen: u32 = GETFONT_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_ModeList: u32 = GETFONT_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_FrontPens: u32 = GETFONT_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_BackPens: u32 = GETFONT_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SoftStyle: u32 = GETFONT_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SampleText: u32 = GETFONT_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoCharSet: u32 = GETFONT_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_CharSet: u32 = GETFONT_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_OTagOnly: u32 = GETFONT_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_ScalableOnly: u32 = GETFONT_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_DoSpecialMode: u32 = GETFONT_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SpecialMode: u32 = GETFONT_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_FilterFunc: u32 = GETFONT_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_CSFilterFunc: u32 = GETFONT_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SpecialModeBitMap: u32 = GETFONT_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SpecialModeBitMapWidth: u32 = GETFONT_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETFONT_SpecialModeBitMapHeight: u32 = GETFONT_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_Dummy: u32 = REACTION_Dummy + 0x41000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_TitleText: u32 = GETSCREENMODE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_Height: u32 = GETSCREENMODE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_Width: u32 = GETSCREENMODE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_LeftEdge: u32 = GETSCREENMODE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_TopEdge: u32 = GETSCREENMODE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DisplayID: u32 = GETSCREENMODE_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DisplayWidth: u32 = GETSCREENMODE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DisplayHeight: u32 = GETSCREENMODE_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DisplayDepth: u32 = GETSCREENMODE_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_OverscanType: u32 = GETSCREENMODE_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_AutoScroll: u32 = GETSCREENMODE_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_InfoOpened: u32 = GETSCREENMODE_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_InfoLeftEdge: u32 = GETSCREENMODE_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_InfoTopEdge: u32 = GETSCREENMODE_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DoWidth: u32 = GETSCREENMODE_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DoHeight: u32 = GETSCREENMODE_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DoDepth: u32 = GETSCREENMODE_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DoOverscanType: u32 = GETSCREENMODE_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_DoAutoScroll: u32 = GETSCREENMODE_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_PropertyFlags: u32 = GETSCREENMODE_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_PropertyMask: u32 = GETSCREENMODE_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MinWidth: u32 = GETSCREENMODE_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MaxWidth: u32 = GETSCREENMODE_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MinHeight: u32 = GETSCREENMODE_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MaxHeight: u32 = GETSCREENMODE_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MinDepth: u32 = GETSCREENMODE_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_MaxDepth: u32 = GETSCREENMODE_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_FilterFunc: u32 = GETSCREENMODE_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GETSCREENMODE_CustomSMList: u32 = GETSCREENMODE_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_Dummy: u32 = TAG_USER + 0x05000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_MaxVal: u32 = GRAD_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_CurVal: u32 = GRAD_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_SkipVal: u32 = GRAD_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_KnobPixels: u32 = GRAD_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GRAD_PenArray: u32 = GRAD_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_Dummy: u32 = REACTION_Dummy + 0x0002000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_Number: u32 = INTEGER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_MaxChars: u32 = INTEGER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_Minimum: u32 = INTEGER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_Maximum: u32 = INTEGER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_Arrows: u32 = INTEGER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_MinVisible: u32 = INTEGER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const INTEGER_SkipVal: u32 = INTEGER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Dummy: u32 = REACTION_Dummy + 0x7000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Orientation: u32 = LAYOUT_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_FixedHoriz: u32 = LAYOUT_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_FixedVert: u32 = LAYOUT_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_HorizAlignment: u32 = LAYOUT_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_VertAlignment: u32 = LAYOUT_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_ShrinkWrap: u32 = LAYOUT_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_EvenSize: u32 = LAYOUT_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_InnerSpacing: u32 = LAYOUT_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_HorizSpacing: u32 = LAYOUT_InnerSpacing;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_VertSpacing: u32 = LAYOUT_InnerSpacing;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_TopSpacing: u32 = LAYOUT_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_BottomSpacing: u32 = LAYOUT_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LeftSpacing: u32 = LAYOUT_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RightSpacing: u32 = LAYOUT_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_BevelState: u32 = LAYOUT_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_BevelStyle: u32 = LAYOUT_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Label: u32 = LAYOUT_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LabelImage: u32 = LAYOUT_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LabelPlace: u32 = LAYOUT_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RemoveChild: u32 = LAYOUT_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_AddChild: u32 = LAYOUT_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_AddImage: u32 = LAYOUT_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_ModifyChild: u32 = LAYOUT_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RelVerify: u32 = LAYOUT_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RelCode: u32 = LAYOUT_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Parent: u32 = LAYOUT_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_DeferLayout: u32 = LAYOUT_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RequestLayout: u32 = LAYOUT_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RequestRefresh: u32 = LAYOUT_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_TextPen: u32 = LAYOUT_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_FillPen: u32 = LAYOUT_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_FillPattern: u32 = LAYOUT_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_PageBackFill: u32 = LAYOUT_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_BackFill: u32 = GA_BackFill;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_TabVerify: u32 = LAYOUT_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LabelColumn: u32 = LAYOUT_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LabelWidth: u32 = LAYOUT_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_AlignLabels: u32 = LAYOUT_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_SpaceInner: u32 = LAYOUT_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_SpaceOuter: u32 = LAYOUT_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_RelAddress: u32 = LAYOUT_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_HelpHit: u32 = LAYOUT_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_HelpGadget: u32 = LAYOUT_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_DisposeLabels: u32 = LAYOUT_Dummy;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Inverted: u32 = LAYOUT_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_WeightBar: u32 = LAYOUT_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_NoLayout: u32 = LAYOUT_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LayoutBackFill: u32 = LAYOUT_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_LayoutMagic: u32 = LAYOUT_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_CharSet: u32 = LAYOUT_Dummy + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_Immediate: u32 = LAYOUT_Dummy + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUT_DoImmediate: u32 = LAYOUT_Dummy + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_Dummy: u32 = LAYOUT_Dummy + 0x100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_MinWidth: u32 = CHILD_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_MinHeight: u32 = CHILD_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_NominalSize: u32 = CHILD_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_MaxWidth: u32 = CHILD_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_MaxHeight: u32 = CHILD_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_WeightedWidth: u32 = CHILD_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_WeightedHeight: u32 = CHILD_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_ReplaceObject: u32 = CHILD_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_ReplaceImage: u32 = LAYOUT_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_CacheDomain: u32 = CHILD_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_WeightMinimum: u32 = CHILD_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_Label: u32 = CHILD_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_NoDispose: u32 = CHILD_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_ScaleHeight: u32 = CHILD_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_ScaleWidth: u32 = CHILD_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_WeightBar: u32 = LAYOUT_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const CHILD_DataType: u32 = CHILD_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_Dummy: u32 = LAYOUT_Dummy + 0x200;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_Add: u32 = PAGE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_Remove: u32 = PAGE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_Current: u32 = PAGE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_FixedVert: u32 = PAGE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_FixedHoriz: u32 = PAGE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_Transparent: u32 = PAGE_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PAGE_NoDispose: u32 = PAGE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Dummy: u32 = TAG_USER + 0x5003500;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Selected: u32 = LBNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Flags: u32 = LBNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_UserData: u32 = LBNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Column: u32 = LBNA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_Text: u32 = LBNA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_Integer: u32 = LBNA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_FGPen: u32 = LBNA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_BGPen: u32 = LBNA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_Image: u32 = LBNA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_SelImage: u32 = LBNA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_HorizJustify: u32 = LBNA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_Justification: u32 = LBNCA_HorizJustify;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Generation: u32 = LBNA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_Editable: u32 = LBNA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_MaxChars: u32 = LBNA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_CopyText: u32 = LBNA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_CheckBox: u32 = LBNA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Checked: u32 = LBNA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_NodeSize: u32 = LBNA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_EditTags: u32 = LBNA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_RenderHook: u32 = LBNA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_HookHeight: u32 = LBNA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_MemPool: u32 = LBNA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_NumColumns: u32 = LBNA_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNA_Priority: u32 = LBNA_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_CopyInteger: u32 = LBNA_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_WordWrap: u32 = LBNA_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_VertJustify: u32 = LBNA_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_FillPen: u32 = LBNA_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_MemPool: u32 = LBNA_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Column: u32 = LBNA_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Title: u32 = LBNA_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Weight: u32 = LBNA_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Width: u32 = LBNA_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Flags: u32 = LBNA_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBNCA_SoftStyle: u32 = LBNA_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_UserData: u32 = LBNA_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_AutoSort: u32 = LBNA_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_SortDirection: u32 = LBNA_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_CompareHook: u32 = LBNA_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Sortable: u32 = LBNA_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_DraggableSeparator: u32 = LBNA_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_Separator: u32 = LBNA_Dummy + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_SortArrow: u32 = LBNA_Dummy + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_CopyTitle: u32 = LBNA_Dummy + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_SoftStyle: u32 = LBNA_Dummy + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LBCIA_HorizJustify: u32 = LBNA_Dummy + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Dummy: u32 = REACTION_Dummy + 0x0003000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Top: u32 = LISTBROWSER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Reserved1: u32 = LISTBROWSER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Labels: u32 = LISTBROWSER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Selected: u32 = LISTBROWSER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_SelectedNode: u32 = LISTBROWSER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MultiSelect: u32 = LISTBROWSER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VertSeparators: u32 = LISTBROWSER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Separators: u32 = LISTBROWSER_VertSeparators;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_ColumnInfo: u32 = LISTBROWSER_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MakeVisible: u32 = LISTBROWSER_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VirtualWidth: u32 = LISTBROWSER_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Borderless: u32 = LISTBROWSER_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VerticalProp: u32 = LISTBROWSER_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HorizontalProp: u32 = LISTBROWSER_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Left: u32 = LISTBROWSER_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Reserved2: u32 = LISTBROWSER_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_AutoFit: u32 = LISTBROWSER_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_ColumnTitles: u32 = LISTBROWSER_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_ShowSelected: u32 = LISTBROWSER_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VPropTotal: u32 = LISTBROWSER_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VPropTop: u32 = LISTBROWSER_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_VPropVisible: u32 = LISTBROWSER_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HPropTotal: u32 = LISTBROWSER_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HPropTop: u32 = LISTBROWSER_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HPropVisible: u32 = LISTBROWSER_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MouseX: u32 = LISTBROWSER_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MouseY: u32 = LISTBROWSER_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Hierarchical: u32 = LISTBROWSER_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_ShowImage: u32 = LISTBROWSER_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HideImage: u32 = LISTBROWSER_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_LeafImage: u32 = LISTBROWSER_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_ScrollRaster: u32 = LISTBROWSER_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Spacing: u32 = LISTBROWSER_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Editable: u32 = LISTBROWSER_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_EditNode: u32 = LISTBROWSER_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_EditColumn: u32 = LISTBROWSER_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_EditTags: u32 = LISTBROWSER_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Position: u32 = LISTBROWSER_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_RelEvent: u32 = LISTBROWSER_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_NumSelected: u32 = LISTBROWSER_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_RelColumn: u32 = LISTBROWSER_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HorizSeparators: u32 = LISTBROWSER_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_CheckImage: u32 = LISTBROWSER_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_UncheckedImage: u32 = LISTBROWSER_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_TotalNodes: u32 = LISTBROWSER_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MinNodeSize: u32 = LISTBROWSER_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_TitleClickable: u32 = LISTBROWSER_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MinVisible: u32 = LISTBROWSER_Dummy + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Reserved6: u32 = LISTBROWSER_Dummy + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Reserved7: u32 = LISTBROWSER_Dummy + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_PersistSelect: u32 = LISTBROWSER_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_CursorSelect: u32 = LISTBROWSER_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_CursorNode: u32 = LISTBROWSER_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_FastRender: u32 = LISTBROWSER_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_TotalVisibleNodes: u32 = LISTBROWSER_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_WrapText: u32 = LISTBROWSER_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MemPool: u32 = LISTBROWSER_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Bottom: u32 = LISTBROWSER_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_MakeNodeVisible: u32 = LISTBROWSER_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_AutoVirtualWidth: u32 = LISTBROWSER_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_HintInfoHook: u32 = LISTBROWSER_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_SortColumn: u32 = LISTBROWSER_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_Striping: u32 = LISTBROWSER_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_AllowNoSelection: u32 = LISTBROWSER_Dummy + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_AutoWheel: u32 = LISTBROWSER_Dummy + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_StayActive: u32 = LISTBROWSER_Dummy + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTBROWSER_EditTrigger: u32 = LISTBROWSER_Dummy + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Dummy: u32 = TAG_USER + 0x04000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Labels: u32 = LISTVIEW_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Top: u32 = LISTVIEW_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Visible: u32 = LISTVIEW_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Total: u32 = LISTVIEW_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ShowSelected: u32 = LISTVIEW_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Selected: u32 = LISTVIEW_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_SelectedNode: u32 = LISTVIEW_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_MakeVisible: u32 = LISTVIEW_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_MultiSelect: u32 = LISTVIEW_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ItemHeight: u32 = LISTVIEW_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_CallHook: u32 = LISTVIEW_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ScrollUp: u32 = LISTVIEW_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ScrollDown: u32 = LISTVIEW_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ScrollLeft: u32 = LISTVIEW_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_ScrollRight: u32 = LISTVIEW_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LISTVIEW_Spacing: u32 = LISTVIEW_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_Dummy: u32 = REACTION_Dummy + 0x0004000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_Colour: u32 = PALETTE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_ColourOffset: u32 = PALETTE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_ColourTable: u32 = PALETTE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_NumColours: u32 = PALETTE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_MinColours: u32 = PALETTE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_RenderHook: u32 = PALETTE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_Color: u32 = PALETTE_Colour;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_ColorOffset: u32 = PALETTE_ColourOffset;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_ColorTable: u32 = PALETTE_ColourTable;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PALETTE_NumColors: u32 = PALETTE_NumColours;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_Dummy: u32 = TAG_USER + 0x020000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_UserData: u32 = RBNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_Labels: u32 = RBNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_Label: u32 = RBNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_HintInfo: u32 = RBNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RBNA_Disabled: u32 = RBNA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_Dummy: u32 = REACTION_Dummy + 0x14000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_Labels: u32 = RADIOBUTTON_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_Strings: u32 = RADIOBUTTON_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_Spacing: u32 = RADIOBUTTON_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_Selected: u32 = RADIOBUTTON_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_LabelPlace: u32 = RADIOBUTTON_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const RADIOBUTTON_LabelArray: u32 = RADIOBUTTON_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Dummy: u32 = REACTION_Dummy + 0x0005000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Top: u32 = SCROLLER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Visible: u32 = SCROLLER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Total: u32 = SCROLLER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Orientation: u32 = SCROLLER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Arrows: u32 = SCROLLER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_Stretch: u32 = SCROLLER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_ArrowDelta: u32 = SCROLLER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_SignalTask: u32 = SCROLLER_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCROLLER_SignalTaskBit: u32 = SCROLLER_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SKETCHBOARD_Dummy: u32 = REACTION_Dummy + 0x24600;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_BitMap: u32 = SKETCHBOARD_Dummy + 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_MouseX: u32 = SKETCHBOARD_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_MouseY: u32 = SKETCHBOARD_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_Width: u32 = SKETCHBOARD_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_Height: u32 = SKETCHBOARD_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_APen: u32 = SKETCHBOARD_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_Tool: u32 = SKETCHBOARD_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_UndoAvailable: u32 = SKETCHBOARD_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_RedoAvailable: u32 = SKETCHBOARD_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_ShowGrid: u32 = SKETCHBOARD_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_Scale: u32 = SKETCHBOARD_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_AfPt: u32 = SKETCHBOARD_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_AfPtSize: u32 = SKETCHBOARD_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_HPropTotal: u32 = SKETCHBOARD_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_HPropTop: u32 = SKETCHBOARD_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_HPropVisible: u32 = SKETCHBOARD_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_VPropTotal: u32 = SKETCHBOARD_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_VPropTop: u32 = SKETCHBOARD_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_VPropVisible: u32 = SKETCHBOARD_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_HotSpot: u32 = SKETCHBOARD_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_Changed: u32 = SKETCHBOARD_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_RastPort: u32 = SKETCHBOARD_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_SketchBoardRect: u32 = SKETCHBOARD_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_SubWidth: u32 = SKETCHBOARD_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_SubHeight: u32 = SKETCHBOARD_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_DoubleScaleX: u32 = SKETCHBOARD_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_DoubleScaleY: u32 = SKETCHBOARD_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_TopLayerBitmap: u32 = SKETCHBOARD_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_TopLayerWidth: u32 = SKETCHBOARD_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_WithBevel: u32 = SKETCHBOARD_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_ScrollWheelResponse: u32 = SKETCHBOARD_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_MaxUndoSteps: u32 = SKETCHBOARD_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_UpdatedBitMap: u32 = SKETCHBOARD_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SGA_MaxScale: u32 = SKETCHBOARD_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Dummy: u32 = REACTION_Dummy + 0x0028000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Min: u32 = SLIDER_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Max: u32 = SLIDER_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Level: u32 = SLIDER_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Orientation: u32 = SLIDER_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_DispHook: u32 = SLIDER_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Ticks: u32 = SLIDER_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_ShortTicks: u32 = SLIDER_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_TickSize: u32 = SLIDER_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_KnobImage: u32 = SLIDER_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_BodyFill: u32 = SLIDER_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_BodyImage: u32 = SLIDER_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Gradient: u32 = SLIDER_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_PenArray: u32 = SLIDER_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_Invert: u32 = SLIDER_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_KnobDelta: u32 = SLIDER_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelFormat: u32 = SLIDER_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelPlace: u32 = SLIDER_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelJustify: u32 = SLIDER_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelDomain: u32 = SLIDER_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelSpace: u32 = SLIDER_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelHook: u32 = SLIDER_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_LevelMaxLen: u32 = SLIDER_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_NotifyDisable: u32 = SLIDER_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SLIDER_InitDispHook: u32 = SLIDER_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_Dummy: u32 = REACTION_Dummy + 0x9000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_MinHeight: u32 = SPACE_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_MinWidth: u32 = SPACE_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_MouseX: u32 = SPACE_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_MouseY: u32 = SPACE_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_Transparent: u32 = SPACE_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_AreaBox: u32 = SPACE_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_RenderHook: u32 = SPACE_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_BevelStyle: u32 = SPACE_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_DomainBevel: u32 = SPACE_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPACE_RenderBox: u32 = SPACE_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Dummy: u32 = TAG_USER + 0x010000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Left: u32 = SBNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Top: u32 = SBNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Width: u32 = SBNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Height: u32 = SBNA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_UserData: u32 = SBNA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Enabled: u32 = SBNA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Spacing: u32 = SBNA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Highlight: u32 = SBNA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Image: u32 = SBNA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_SelImage: u32 = SBNA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Help: u32 = SBNA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Toggle: u32 = SBNA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Selected: u32 = SBNA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_MXGroup: u32 = SBNA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Disabled: u32 = SBNA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Text: u32 = SBNA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Spacer: u32 = SBNA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_Separator: u32 = SBNA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_HintInfo: u32 = SBNA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SBNA_ButtonID: u32 = SBNA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Dummy: u32 = REACTION_Dummy + 0x13000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Buttons: u32 = SPEEDBAR_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Orientation: u32 = SPEEDBAR_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Background: u32 = SPEEDBAR_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Window: u32 = SPEEDBAR_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_StrumBar: u32 = SPEEDBAR_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_OnButton: u32 = SPEEDBAR_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_OffButton: u32 = SPEEDBAR_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_ScrollLeft: u32 = SPEEDBAR_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_ScrollRight: u32 = SPEEDBAR_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Top: u32 = SPEEDBAR_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Visible: u32 = SPEEDBAR_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Total: u32 = SPEEDBAR_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Help: u32 = SPEEDBAR_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_BevelStyle: u32 = SPEEDBAR_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Selected: u32 = SPEEDBAR_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_SelectedNode: u32 = SPEEDBAR_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_EvenSize: u32 = SPEEDBAR_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_Font: u32 = SPEEDBAR_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_TopNode: u32 = SPEEDBAR_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_ButtonType: u32 = SPEEDBAR_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_HorizPadding: u32 = SPEEDBAR_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_VertPadding: u32 = SPEEDBAR_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SPEEDBAR_ButtonBevelStyle: u32 = SPEEDBAR_Dummy + 0x801;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_MinVisible: u32 = REACTION_Dummy + 0x0055000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_HookType: u32 = REACTION_Dummy + 0x0055001;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_RelVerifySpecial: u32 = REACTION_Dummy + 0x005500a;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_GetBlockPos: u32 = REACTION_Dummy + 0x0055010;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Mark: u32 = REACTION_Dummy + 0x0055011;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_AllowMarking: u32 = REACTION_Dummy + 0x0055012;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_InterimUpdates: u32 = REACTION_Dummy + 0x0055013;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_ASLTags: u32 = REACTION_Dummy + 0x0055013;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_MarkActive: u32 = REACTION_Dummy + 0x0055014;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_DisablePopup: u32 = REACTION_Dummy + 0x0055015;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_MinVal: u32 = REACTION_Dummy + 0x0055016;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_MaxVal: u32 = REACTION_Dummy + 0x0055017;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABS_Dummy: u32 = TAG_USER + 0x04000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABS_Labels: u32 = TABS_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABS_Current: u32 = TABS_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_Dummy: u32 = TAG_USER + 0x05000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_Mode: u32 = TDECK_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_Paused: u32 = TDECK_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_Tape: u32 = TDECK_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_Frames: u32 = TDECK_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TDECK_CurrentFrame: u32 = TDECK_Dummy + 12;
 pub const GA_TEXTEDITOR_Contents: u32 = TEXTEDITOR_Dummy + 0x02;
 pub const GA_TEXTEDITOR_CursorX: u32 = TEXTEDITOR_Dummy + 0x04;
@@ -1237,51 +2337,97 @@ pub const GA_TEXTEDITOR_BevelStyle: u32 = TEXTEDITOR_Dummy + 0x47;
 pub const GA_TEXTEDITOR_Transparent: u32 = TEXTEDITOR_Dummy + 0x48;
 pub const GA_TEXTEDITOR_MaxUndoLevels: u32 = TEXTEDITOR_Dummy + 0x49;
 pub const GA_TEXTEDITOR_MaxUndoSize: u32 = TEXTEDITOR_Dummy + 0x4A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_Base: u32 = REACTION_Dummy + 0x24500;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_Contents: u32 = VIRTUALA_Base + 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_Scroller: u32 = VIRTUALA_Base + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_ScrollX: u32 = VIRTUALA_Base + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_ScrollY: u32 = VIRTUALA_Base + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_VisibleX: u32 = VIRTUALA_Base + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_VisibleY: u32 = VIRTUALA_Base + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TopX: u32 = VIRTUALA_Base + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TopY: u32 = VIRTUALA_Base + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TotalX: u32 = VIRTUALA_Base + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TotalY: u32 = VIRTUALA_Base + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_ScrollerX: u32 = VIRTUALA_Base + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_ScrollerY: u32 = VIRTUALA_Base + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_InputScroll: u32 = VIRTUALA_Base + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_NoDispose: u32 = VIRTUALA_Base + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TargetX: u32 = VIRTUALA_Base + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_TargetY: u32 = VIRTUALA_Base + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_MapX: u32 = VIRTUALA_Base + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const VIRTUALA_MapY: u32 = VIRTUALA_Base + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Friend: u32 = TAG_USER + 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Depth: u32 = TAG_USER + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_PixelFormat: u32 = TAG_USER + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Clear: u32 = TAG_USER + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Displayable: u32 = TAG_USER + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_NoMemory: u32 = TAG_USER + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_NoSprite: u32 = TAG_USER + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_ModeWidth: u32 = TAG_USER + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_ModeHeight: u32 = TAG_USER + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Alignment: u32 = TAG_USER + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_ConstantBytesPerRow: u32 = TAG_USER + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_UserPrivate: u32 = TAG_USER + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_DisplayID: u32 = TAG_USER + 0x32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_BitmapInvisible: u32 = TAG_USER + 0x37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_BitmapColors: u32 = TAG_USER + 0x29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_BitmapColors32: u32 = TAG_USER + 0x43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_BitmapErrorCode: u32 = TAG_USER + 0x2A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private1: u32 = TAG_USER + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private2: u32 = TAG_USER + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private3: u32 = TAG_USER + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private4: u32 = TAG_USER + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private5: u32 = TAG_USER + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private6: u32 = TAG_USER + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_Private7: u32 = TAG_USER + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_RenderFunc: u32 = TAG_USER + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_SaveFunc: u32 = TAG_USER + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BMATags_UserData: u32 = TAG_USER + 14;
 pub const SPECIAL_FLAGS: u32 =
     DIPF_IS_DUALPF | DIPF_IS_PF2PRI | DIPF_IS_HAM | DIPF_IS_EXTRAHALFBRITE;
@@ -1290,692 +2436,1379 @@ pub const SPECIAL_BEAMCON: u32 =
     VARVBLANK | LOLDIS | VARVSYNC | VARHSYNC | VARBEAM | CSBLANK | VSYNCTRUE;
 pub const VGA70_BEAMCON: u32 = SPECIAL_BEAMCON ^ VSYNCTRUE;
 pub const BROADCAST_BEAMCON: u32 = LOLDIS | CSBLANK;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TA_DeviceDPI: u32 = 1 | TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_Dummy: u32 = REACTION_Dummy + 0x16000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_Style: u32 = BEVEL_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_Label: u32 = BEVEL_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_LabelImage: u32 = BEVEL_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_LabelPlace: u32 = BEVEL_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_InnerTop: u32 = BEVEL_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_InnerLeft: u32 = BEVEL_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_InnerWidth: u32 = BEVEL_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_InnerHeight: u32 = BEVEL_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_HorizSize: u32 = BEVEL_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_HorzSize: u32 = BEVEL_HorizSize;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_VertSize: u32 = BEVEL_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_FillPen: u32 = BEVEL_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_FillPattern: u32 = BEVEL_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_TextPen: u32 = BEVEL_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_Transparent: u32 = BEVEL_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_SoftStyle: u32 = BEVEL_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_ColorMap: u32 = BEVEL_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_ColourMap: u32 = BEVEL_ColorMap;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BEVEL_Flags: u32 = BEVEL_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Dummy: u32 = REACTION_Dummy + 0x19000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SourceFile: u32 = BITMAP_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Screen: u32 = BITMAP_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Precision: u32 = BITMAP_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Masking: u32 = BITMAP_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_BitMap: u32 = BITMAP_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Width: u32 = BITMAP_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Height: u32 = BITMAP_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_MaskPlane: u32 = BITMAP_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectSourceFile: u32 = BITMAP_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectBitMap: u32 = BITMAP_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectWidth: u32 = BITMAP_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectHeight: u32 = BITMAP_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectMaskPlane: u32 = BITMAP_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_OffsetX: u32 = BITMAP_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_OffsetY: u32 = BITMAP_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectOffsetX: u32 = BITMAP_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectOffsetY: u32 = BITMAP_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_Transparent: u32 = BITMAP_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledSourceFile: u32 = BITMAP_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledBitMap: u32 = BITMAP_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledWidth: u32 = BITMAP_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledHeight: u32 = BITMAP_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledMaskPlane: u32 = BITMAP_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledOffsetX: u32 = BITMAP_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledOffsetY: u32 = BITMAP_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_HasAlpha: u32 = BITMAP_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_AlphaBitMap: u32 = BITMAP_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_SelectAlphaBitMap: u32 = BITMAP_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const BITMAP_DisabledAlphaBitMap: u32 = BITMAP_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRAWLIST_Dummy: u32 = REACTION_Dummy + 0x17000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRAWLIST_Directives: u32 = DRAWLIST_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRAWLIST_RefHeight: u32 = DRAWLIST_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRAWLIST_RefWidth: u32 = DRAWLIST_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const DRAWLIST_DrawInfo: u32 = DRAWLIST_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GLYPH_Dummy: u32 = REACTION_Dummy + 0x15000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GLYPH_Glyph: u32 = GLYPH_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GLYPH_DrawInfo: u32 = GLYPH_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Dummy: u32 = REACTION_Dummy + 0x0006000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_DrawInfo: u32 = SYSIA_DrawInfo;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Text: u32 = LABEL_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Image: u32 = LABEL_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Mapping: u32 = LABEL_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Justification: u32 = LABEL_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Key: u32 = LABEL_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_Underscore: u32 = LABEL_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_DisposeImage: u32 = LABEL_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_SoftStyle: u32 = LABEL_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_VerticalSpacing: u32 = LABEL_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_VerticalAlignment: u32 = LABEL_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_WordWrap: u32 = LABEL_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LABEL_MenuMode: u32 = LABEL_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Dummy: u32 = TAG_USER + 0x04000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Pairs: u32 = LED_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Values: u32 = LED_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Colon: u32 = LED_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Negative: u32 = LED_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Signed: u32 = LED_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Time: u32 = LED_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Hexadecimal: u32 = LED_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LED_Raw: u32 = LED_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Dummy: u32 = REACTION_Dummy + 0x18000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_SelectBGPen: u32 = PENMAP_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_SelectData: u32 = PENMAP_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_RenderBGPen: u32 = IA_BGPen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_RenderData: u32 = IA_Data;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Palette: u32 = PENMAP_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Screen: u32 = PENMAP_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_ImageType: u32 = PENMAP_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Transparent: u32 = PENMAP_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Precision: u32 = PENMAP_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_ColorMap: u32 = PENMAP_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_MaskBlit: u32 = PENMAP_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_Compressed: u32 = PENMAP_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_MaskPlane: u32 = PENMAP_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PENMAP_SelectMaskPlane: u32 = PENMAP_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Dummy: u32 = TAG_USER + 0x30000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Left: u32 = GA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelRight: u32 = GA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Top: u32 = GA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelBottom: u32 = GA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Width: u32 = GA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelWidth: u32 = GA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Height: u32 = GA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelHeight: u32 = GA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Text: u32 = GA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Image: u32 = GA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Border: u32 = GA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SelectRender: u32 = GA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Highlight: u32 = GA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Disabled: u32 = GA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_GZZGadget: u32 = GA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ID: u32 = GA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_UserData: u32 = GA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SpecialInfo: u32 = GA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Selected: u32 = GA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_EndGadget: u32 = GA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Immediate: u32 = GA_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelVerify: u32 = GA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_FollowMouse: u32 = GA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RightBorder: u32 = GA_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_LeftBorder: u32 = GA_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TopBorder: u32 = GA_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_BottomBorder: u32 = GA_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ToggleSelect: u32 = GA_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SysGadget: u32 = GA_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SysGType: u32 = GA_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Previous: u32 = GA_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Next: u32 = GA_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_DrawInfo: u32 = GA_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_IntuiText: u32 = GA_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_LabelImage: u32 = GA_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TabCycle: u32 = GA_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_GadgetHelp: u32 = GA_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Bounds: u32 = GA_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RelSpecial: u32 = GA_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TextAttr: u32 = GA_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ReadOnly: u32 = GA_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Underscore: u32 = GA_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ActivateKey: u32 = GA_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_BackFill: u32 = GA_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_GadgetHelpText: u32 = GA_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_UserInput: u32 = GA_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_Hidden: u32 = GA_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_CustomMousePointer: u32 = GA_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_PointerType: u32 = GA_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ParentHidden: u32 = GA_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Dummy: u32 = TAG_USER + 0x31000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Freedom: u32 = PGA_Dummy + 0x0001;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Borderless: u32 = PGA_Dummy + 0x0002;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_HorizPot: u32 = PGA_Dummy + 0x0003;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_HorizBody: u32 = PGA_Dummy + 0x0004;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_VertPot: u32 = PGA_Dummy + 0x0005;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_VertBody: u32 = PGA_Dummy + 0x0006;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Total: u32 = PGA_Dummy + 0x0007;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Visible: u32 = PGA_Dummy + 0x0008;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_Top: u32 = PGA_Dummy + 0x0009;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_NewLook: u32 = PGA_Dummy + 0x000A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_KnobImage: u32 = PGA_Dummy + 0x000D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Dummy: u32 = TAG_USER + 0x32000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_MaxChars: u32 = STRINGA_Dummy + 0x0001;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Buffer: u32 = STRINGA_Dummy + 0x0002;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_UndoBuffer: u32 = STRINGA_Dummy + 0x0003;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_WorkBuffer: u32 = STRINGA_Dummy + 0x0004;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_BufferPos: u32 = STRINGA_Dummy + 0x0005;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_DispPos: u32 = STRINGA_Dummy + 0x0006;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_AltKeyMap: u32 = STRINGA_Dummy + 0x0007;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Font: u32 = STRINGA_Dummy + 0x0008;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Pens: u32 = STRINGA_Dummy + 0x0009;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_ActivePens: u32 = STRINGA_Dummy + 0x000A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_EditHook: u32 = STRINGA_Dummy + 0x000B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_EditModes: u32 = STRINGA_Dummy + 0x000C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_ReplaceMode: u32 = STRINGA_Dummy + 0x000D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_FixedFieldMode: u32 = STRINGA_Dummy + 0x000E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_NoFilterMode: u32 = STRINGA_Dummy + 0x000F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_Justification: u32 = STRINGA_Dummy + 0x0010;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_LongVal: u32 = STRINGA_Dummy + 0x0011;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_TextVal: u32 = STRINGA_Dummy + 0x0012;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const STRINGA_ExitHelp: u32 = STRINGA_Dummy + 0x0013;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_Dummy: u32 = TAG_USER + 0x38000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_LayoutObj: u32 = LAYOUTA_Dummy + 0x0001;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_Spacing: u32 = LAYOUTA_Dummy + 0x0002;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_Orientation: u32 = LAYOUTA_Dummy + 0x0003;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_ChildMaxWidth: u32 = LAYOUTA_Dummy + 0x0004;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_ChildMaxHeight: u32 = LAYOUTA_Dummy + 0x0005;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICA_Dummy: u32 = TAG_USER + 0x40000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICA_TARGET: u32 = ICA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICA_MAP: u32 = ICA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICSPECIAL_CODE: u32 = ICA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Dummy: u32 = TAG_USER + 0x20000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Left: u32 = IA_Dummy + 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Top: u32 = IA_Dummy + 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Width: u32 = IA_Dummy + 0x03;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Height: u32 = IA_Dummy + 0x04;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_FGPen: u32 = IA_Dummy + 0x05;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_BGPen: u32 = IA_Dummy + 0x06;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Data: u32 = IA_Dummy + 0x07;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_LineWidth: u32 = IA_Dummy + 0x08;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Pens: u32 = IA_Dummy + 0x0E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Resolution: u32 = IA_Dummy + 0x0F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_APattern: u32 = IA_Dummy + 0x10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_APatSize: u32 = IA_Dummy + 0x11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Mode: u32 = IA_Dummy + 0x12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Font: u32 = IA_Dummy + 0x13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Outline: u32 = IA_Dummy + 0x14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Recessed: u32 = IA_Dummy + 0x15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_DoubleEmboss: u32 = IA_Dummy + 0x16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_EdgesOnly: u32 = IA_Dummy + 0x17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_Size: u32 = IA_Dummy + 0x0B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_Depth: u32 = IA_Dummy + 0x0C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_Which: u32 = IA_Dummy + 0x0D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_DrawInfo: u32 = IA_Dummy + 0x18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_Pens: u32 = IA_Pens;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_ShadowPen: u32 = IA_Dummy + 0x09;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_HighlightPen: u32 = IA_Dummy + 0x0A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SYSIA_ReferenceFont: u32 = IA_Dummy + 0x19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_SupportsDisable: u32 = IA_Dummy + 0x1a;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_FrameType: u32 = IA_Dummy + 0x1b;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Underscore: u32 = IA_Dummy + 0x1c;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Scalable: u32 = IA_Dummy + 0x1d;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_ActivateKey: u32 = IA_Dummy + 0x1e;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Screen: u32 = IA_Dummy + 0x1f;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Precision: u32 = IA_Dummy + 0x20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Orientation: u32 = IA_Dummy + 0x23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_Label: u32 = IA_Dummy + 0x28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_EraseBackground: u32 = IA_Dummy + 0x2D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_LabelPen: u32 = IA_Dummy + 0x38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Dummy: u32 = TAG_USER + 99;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Left: u32 = WA_Dummy + 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Top: u32 = WA_Dummy + 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Width: u32 = WA_Dummy + 0x03;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Height: u32 = WA_Dummy + 0x04;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_DetailPen: u32 = WA_Dummy + 0x05;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_BlockPen: u32 = WA_Dummy + 0x06;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_IDCMP: u32 = WA_Dummy + 0x07;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Flags: u32 = WA_Dummy + 0x08;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Gadgets: u32 = WA_Dummy + 0x09;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Checkmark: u32 = WA_Dummy + 0x0A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Title: u32 = WA_Dummy + 0x0B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_ScreenTitle: u32 = WA_Dummy + 0x0C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_CustomScreen: u32 = WA_Dummy + 0x0D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SuperBitMap: u32 = WA_Dummy + 0x0E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MinWidth: u32 = WA_Dummy + 0x0F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MinHeight: u32 = WA_Dummy + 0x10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MaxWidth: u32 = WA_Dummy + 0x11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MaxHeight: u32 = WA_Dummy + 0x12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_InnerWidth: u32 = WA_Dummy + 0x13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_InnerHeight: u32 = WA_Dummy + 0x14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_PubScreenName: u32 = WA_Dummy + 0x15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_PubScreen: u32 = WA_Dummy + 0x16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_PubScreenFallBack: u32 = WA_Dummy + 0x17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_WindowName: u32 = WA_Dummy + 0x18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Colors: u32 = WA_Dummy + 0x19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Zoom: u32 = WA_Dummy + 0x1A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MouseQueue: u32 = WA_Dummy + 0x1B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_BackFill: u32 = WA_Dummy + 0x1C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_RptQueue: u32 = WA_Dummy + 0x1D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SizeGadget: u32 = WA_Dummy + 0x1E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_DragBar: u32 = WA_Dummy + 0x1F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_DepthGadget: u32 = WA_Dummy + 0x20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_CloseGadget: u32 = WA_Dummy + 0x21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Backdrop: u32 = WA_Dummy + 0x22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_ReportMouse: u32 = WA_Dummy + 0x23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_NoCareRefresh: u32 = WA_Dummy + 0x24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Borderless: u32 = WA_Dummy + 0x25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Activate: u32 = WA_Dummy + 0x26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_RMBTrap: u32 = WA_Dummy + 0x27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_WBenchWindow: u32 = WA_Dummy + 0x28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SimpleRefresh: u32 = WA_Dummy + 0x29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SmartRefresh: u32 = WA_Dummy + 0x2A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SizeBRight: u32 = WA_Dummy + 0x2B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_SizeBBottom: u32 = WA_Dummy + 0x2C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_AutoAdjust: u32 = WA_Dummy + 0x2D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_GimmeZeroZero: u32 = WA_Dummy + 0x2E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_MenuHelp: u32 = WA_Dummy + 0x2F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_NewLookMenus: u32 = WA_Dummy + 0x30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_AmigaKey: u32 = WA_Dummy + 0x31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_NotifyDepth: u32 = WA_Dummy + 0x32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Pointer: u32 = WA_Dummy + 0x34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_BusyPointer: u32 = WA_Dummy + 0x35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_PointerDelay: u32 = WA_Dummy + 0x36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_TabletMessages: u32 = WA_Dummy + 0x37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_HelpGroup: u32 = WA_Dummy + 0x38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_HelpGroupWindow: u32 = WA_Dummy + 0x39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_Hidden: u32 = WA_Dummy + 0x3c;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_PointerType: u32 = WA_Dummy + 0x50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WA_IconifyGadget: u32 = WA_Dummy + 0x60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICTRL_Dummy: u32 = TAG_USER + 0x1C000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_Dummy: u32 = TAG_USER + 0x3A000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_TabletZ: u32 = TABLETA_Dummy + 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_RangeZ: u32 = TABLETA_Dummy + 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_AngleX: u32 = TABLETA_Dummy + 0x03;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_AngleY: u32 = TABLETA_Dummy + 0x04;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_AngleZ: u32 = TABLETA_Dummy + 0x05;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_Pressure: u32 = TABLETA_Dummy + 0x06;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_ButtonBits: u32 = TABLETA_Dummy + 0x07;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_InProximity: u32 = TABLETA_Dummy + 0x08;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_ResolutionX: u32 = TABLETA_Dummy + 0x09;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const TABLETA_ResolutionY: u32 = TABLETA_Dummy + 0x0A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_LEFT: u32 = GA_Left;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RELRIGHT: u32 = GA_RelRight;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TOP: u32 = GA_Top;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RELBOTTOM: u32 = GA_RelBottom;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_WIDTH: u32 = GA_Width;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RELWIDTH: u32 = GA_RelWidth;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_HEIGHT: u32 = GA_Height;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RELHEIGHT: u32 = GA_RelHeight;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TEXT: u32 = GA_Text;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_IMAGE: u32 = GA_Image;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_BORDER: u32 = GA_Border;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SELECTRENDER: u32 = GA_SelectRender;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_HIGHLIGHT: u32 = GA_Highlight;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_DISABLED: u32 = GA_Disabled;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_GZZGADGET: u32 = GA_GZZGadget;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_USERDATA: u32 = GA_UserData;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SPECIALINFO: u32 = GA_SpecialInfo;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SELECTED: u32 = GA_Selected;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_ENDGADGET: u32 = GA_EndGadget;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_IMMEDIATE: u32 = GA_Immediate;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RELVERIFY: u32 = GA_RelVerify;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_FOLLOWMOUSE: u32 = GA_FollowMouse;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_RIGHTBORDER: u32 = GA_RightBorder;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_LEFTBORDER: u32 = GA_LeftBorder;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TOPBORDER: u32 = GA_TopBorder;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_BOTTOMBORDER: u32 = GA_BottomBorder;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_TOGGLESELECT: u32 = GA_ToggleSelect;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SYSGADGET: u32 = GA_SysGadget;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_SYSGTYPE: u32 = GA_SysGType;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_PREVIOUS: u32 = GA_Previous;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_NEXT: u32 = GA_Next;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_DRAWINFO: u32 = GA_DrawInfo;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_INTUITEXT: u32 = GA_IntuiText;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GA_LABELIMAGE: u32 = GA_LabelImage;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_FREEDOM: u32 = PGA_Freedom;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_BORDERLESS: u32 = PGA_Borderless;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_HORIZPOT: u32 = PGA_HorizPot;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_HORIZBODY: u32 = PGA_HorizBody;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_VERTPOT: u32 = PGA_VertPot;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_VERTBODY: u32 = PGA_VertBody;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_TOTAL: u32 = PGA_Total;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_VISIBLE: u32 = PGA_Visible;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PGA_TOP: u32 = PGA_Top;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_LAYOUTOBJ: u32 = LAYOUTA_LayoutObj;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_SPACING: u32 = LAYOUTA_Spacing;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const LAYOUTA_ORIENTATION: u32 = LAYOUTA_Orientation;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IMAGE_ATTRIBUTES: u32 = IA_Dummy;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_LEFT: u32 = IA_Left;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_TOP: u32 = IA_Top;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_WIDTH: u32 = IA_Width;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_HEIGHT: u32 = IA_Height;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_FGPEN: u32 = IA_FGPen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_BGPEN: u32 = IA_BGPen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_DATA: u32 = IA_Data;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_LINEWIDTH: u32 = IA_LineWidth;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_PENS: u32 = IA_Pens;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_RESOLUTION: u32 = IA_Resolution;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_APATTERN: u32 = IA_APattern;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_APATSIZE: u32 = IA_APatSize;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_MODE: u32 = IA_Mode;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_FONT: u32 = IA_Font;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_OUTLINE: u32 = IA_Outline;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_RECESSED: u32 = IA_Recessed;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_DOUBLEEMBOSS: u32 = IA_DoubleEmboss;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_EDGESONLY: u32 = IA_EdgesOnly;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_SHADOWPEN: u32 = IA_ShadowPen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const IA_HIGHLIGHTPEN: u32 = IA_HighlightPen;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_Dummy: u32 = TAG_USER + 0x39000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_BitMap: u32 = POINTERA_Dummy + 0x01;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_XOffset: u32 = POINTERA_Dummy + 0x02;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_YOffset: u32 = POINTERA_Dummy + 0x03;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_WordWidth: u32 = POINTERA_Dummy + 0x04;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_XResolution: u32 = POINTERA_Dummy + 0x05;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const POINTERA_YResolution: u32 = POINTERA_Dummy + 0x06;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Dummy: u32 = TAG_USER + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Left: u32 = SA_Dummy + 0x0001;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Top: u32 = SA_Dummy + 0x0002;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Width: u32 = SA_Dummy + 0x0003;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Height: u32 = SA_Dummy + 0x0004;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Depth: u32 = SA_Dummy + 0x0005;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_DetailPen: u32 = SA_Dummy + 0x0006;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_BlockPen: u32 = SA_Dummy + 0x0007;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Title: u32 = SA_Dummy + 0x0008;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Colors: u32 = SA_Dummy + 0x0009;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_ErrorCode: u32 = SA_Dummy + 0x000A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Font: u32 = SA_Dummy + 0x000B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_SysFont: u32 = SA_Dummy + 0x000C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Type: u32 = SA_Dummy + 0x000D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_BitMap: u32 = SA_Dummy + 0x000E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_PubName: u32 = SA_Dummy + 0x000F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_PubSig: u32 = SA_Dummy + 0x0010;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_PubTask: u32 = SA_Dummy + 0x0011;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_DisplayID: u32 = SA_Dummy + 0x0012;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_DClip: u32 = SA_Dummy + 0x0013;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Overscan: u32 = SA_Dummy + 0x0014;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Obsolete1: u32 = SA_Dummy + 0x0015;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_ShowTitle: u32 = SA_Dummy + 0x0016;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Behind: u32 = SA_Dummy + 0x0017;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Quiet: u32 = SA_Dummy + 0x0018;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_AutoScroll: u32 = SA_Dummy + 0x0019;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Pens: u32 = SA_Dummy + 0x001A;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_FullPalette: u32 = SA_Dummy + 0x001B;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_ColorMapEntries: u32 = SA_Dummy + 0x001C;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Parent: u32 = SA_Dummy + 0x001D;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Draggable: u32 = SA_Dummy + 0x001E;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Exclusive: u32 = SA_Dummy + 0x001F;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_SharePens: u32 = SA_Dummy + 0x0020;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_BackFill: u32 = SA_Dummy + 0x0021;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Interleaved: u32 = SA_Dummy + 0x0022;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Colors32: u32 = SA_Dummy + 0x0023;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_VideoControl: u32 = SA_Dummy + 0x0024;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_FrontChild: u32 = SA_Dummy + 0x0025;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_BackChild: u32 = SA_Dummy + 0x0026;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_LikeWorkbench: u32 = SA_Dummy + 0x0027;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_Reserved: u32 = SA_Dummy + 0x0028;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_MinimizeISG: u32 = SA_Dummy + 0x0029;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SA_OffScreenDragging: u32 = SA_Dummy + 0x002a;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const NSTAG_EXT_VPMODE: u32 = TAG_USER | 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Dummy: u32 = TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Path: u32 = AGA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_XRefList: u32 = AGA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Activate: u32 = AGA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Context: u32 = AGA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_HelpGroup: u32 = AGA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Reserved1: u32 = AGA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Reserved2: u32 = AGA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Reserved3: u32 = AGA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_ARexxPort: u32 = AGA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_ARexxPortName: u32 = AGA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const AGA_Secure: u32 = AGA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const HTNA_Dummy: u32 = TAG_USER;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const HTNA_Screen: u32 = HTNA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const HTNA_Pens: u32 = HTNA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const HTNA_Rectangle: u32 = HTNA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const HTNA_HelpGroup: u32 = HTNA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_TB: u32 = TAG_USER + 0x80000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Window: u32 = ASL_TB + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Screen: u32 = ASL_TB + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_PubScreenName: u32 = ASL_TB + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_PrivateIDCMP: u32 = ASL_TB + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_IntuiMsgFunc: u32 = ASL_TB + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_SleepWindow: u32 = ASL_TB + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_UserData: u32 = ASL_TB + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_PopToFront: u32 = ASL_TB + 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Activate: u32 = ASL_TB + 132;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_TextAttr: u32 = ASL_TB + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Locale: u32 = ASL_TB + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_TitleText: u32 = ASL_TB + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_PositiveText: u32 = ASL_TB + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_NegativeText: u32 = ASL_TB + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialLeftEdge: u32 = ASL_TB + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialTopEdge: u32 = ASL_TB + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialWidth: u32 = ASL_TB + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialHeight: u32 = ASL_TB + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialFile: u32 = ASL_TB + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialDrawer: u32 = ASL_TB + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialPattern: u32 = ASL_TB + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_InitialShowVolumes: u32 = ASL_TB + 130;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Flags1: u32 = ASL_TB + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_Flags2: u32 = ASL_TB + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_DoSaveMode: u32 = ASL_TB + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_DoMultiSelect: u32 = ASL_TB + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_DoPatterns: u32 = ASL_TB + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_DrawersOnly: u32 = ASL_TB + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_FilterFunc: u32 = ASL_TB + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_RejectIcons: u32 = ASL_TB + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_RejectPattern: u32 = ASL_TB + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_AcceptPattern: u32 = ASL_TB + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_FilterDrawers: u32 = ASL_TB + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_HookFunc: u32 = ASL_TB + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_SetSortBy: u32 = ASL_TB + 124;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_GetSortBy: u32 = ASL_TB + 125;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_SetSortDrawers: u32 = ASL_TB + 126;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_GetSortDrawers: u32 = ASL_TB + 127;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_SetSortOrder: u32 = ASL_TB + 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFR_GetSortOrder: u32 = ASL_TB + 129;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_Window: u32 = ASL_TB + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_Screen: u32 = ASL_TB + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_PubScreenName: u32 = ASL_TB + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_PrivateIDCMP: u32 = ASL_TB + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_IntuiMsgFunc: u32 = ASL_TB + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_SleepWindow: u32 = ASL_TB + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_UserData: u32 = ASL_TB + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_PopToFront: u32 = ASL_TB + 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_Activate: u32 = ASL_TB + 132;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_TextAttr: u32 = ASL_TB + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_Locale: u32 = ASL_TB + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_TitleText: u32 = ASL_TB + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_PositiveText: u32 = ASL_TB + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_NegativeText: u32 = ASL_TB + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialLeftEdge: u32 = ASL_TB + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialTopEdge: u32 = ASL_TB + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialWidth: u32 = ASL_TB + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialHeight: u32 = ASL_TB + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialName: u32 = ASL_TB + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialSize: u32 = ASL_TB + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialStyle: u32 = ASL_TB + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialFlags: u32 = ASL_TB + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialFrontPen: u32 = ASL_TB + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialBackPen: u32 = ASL_TB + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialDrawMode: u32 = ASL_TB + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialSpecMode: u32 = ASL_TB + 139;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_InitialCharSet: u32 = ASL_TB + 134;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_Flags: u32 = ASL_TB + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoFrontPen: u32 = ASL_TB + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoBackPen: u32 = ASL_TB + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoStyle: u32 = ASL_TB + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoDrawMode: u32 = ASL_TB + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoSpecialMode: u32 = ASL_TB + 140;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_DoCharSet: u32 = ASL_TB + 135;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_SampleText: u32 = ASL_TB + 133;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_FixedWidthOnly: u32 = ASL_TB + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_OTagOnly: u32 = ASL_TB + 136;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_ScalableOnly: u32 = ASL_TB + 137;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_MinHeight: u32 = ASL_TB + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_MaxHeight: u32 = ASL_TB + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_FilterFunc: u32 = ASL_TB + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_CSFilterFunc: u32 = ASL_TB + 138;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_HookFunc: u32 = ASL_TB + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_MaxFrontPen: u32 = ASL_TB + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_MaxBackPen: u32 = ASL_TB + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_ModeList: u32 = ASL_TB + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_FrontPens: u32 = ASL_TB + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLFO_BackPens: u32 = ASL_TB + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_Window: u32 = ASL_TB + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_Screen: u32 = ASL_TB + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PubScreenName: u32 = ASL_TB + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PrivateIDCMP: u32 = ASL_TB + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_IntuiMsgFunc: u32 = ASL_TB + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_SleepWindow: u32 = ASL_TB + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_UserData: u32 = ASL_TB + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PopToFront: u32 = ASL_TB + 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_Activate: u32 = ASL_TB + 132;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_TextAttr: u32 = ASL_TB + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_Locale: u32 = ASL_TB + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_TitleText: u32 = ASL_TB + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PositiveText: u32 = ASL_TB + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_NegativeText: u32 = ASL_TB + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialLeftEdge: u32 = ASL_TB + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialTopEdge: u32 = ASL_TB + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialWidth: u32 = ASL_TB + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialHeight: u32 = ASL_TB + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialDisplayID: u32 = ASL_TB + 100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialDisplayWidth: u32 = ASL_TB + 101;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialDisplayHeight: u32 = ASL_TB + 102;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialDisplayDepth: u32 = ASL_TB + 103;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialOverscanType: u32 = ASL_TB + 104;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialAutoScroll: u32 = ASL_TB + 105;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialInfoOpened: u32 = ASL_TB + 106;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialInfoLeftEdge: u32 = ASL_TB + 107;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_InitialInfoTopEdge: u32 = ASL_TB + 108;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_DoWidth: u32 = ASL_TB + 109;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_DoHeight: u32 = ASL_TB + 110;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_DoDepth: u32 = ASL_TB + 111;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_DoOverscanType: u32 = ASL_TB + 112;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_DoAutoScroll: u32 = ASL_TB + 113;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PropertyFlags: u32 = ASL_TB + 114;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_PropertyMask: u32 = ASL_TB + 115;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MinWidth: u32 = ASL_TB + 116;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MaxWidth: u32 = ASL_TB + 117;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MinHeight: u32 = ASL_TB + 118;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MaxHeight: u32 = ASL_TB + 119;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MinDepth: u32 = ASL_TB + 120;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_MaxDepth: u32 = ASL_TB + 121;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_FilterFunc: u32 = ASL_TB + 122;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASLSM_CustomSMList: u32 = ASL_TB + 123;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_LAST_TAG: u32 = ASL_TB + 140;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Dummy: u32 = TAG_USER + 0x80000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Hail: u32 = ASL_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Window: u32 = ASL_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_LeftEdge: u32 = ASL_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_TopEdge: u32 = ASL_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Width: u32 = ASL_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Height: u32 = ASL_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_HookFunc: u32 = ASL_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_File: u32 = ASL_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Dir: u32 = ASL_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FontName: u32 = ASL_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FontHeight: u32 = ASL_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FontStyles: u32 = ASL_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FontFlags: u32 = ASL_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FrontPen: u32 = ASL_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_BackPen: u32 = ASL_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_MinHeight: u32 = ASL_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_MaxHeight: u32 = ASL_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_OKText: u32 = ASL_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_CancelText: u32 = ASL_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_FuncFlags: u32 = ASL_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_ModeList: u32 = ASL_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_ExtFlags1: u32 = ASL_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ASL_Pattern: u32 = ASL_FontName;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_TagBase: u32 = TAG_USER + 0x80000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_NewWindow: u32 = GT_TagBase + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_NWTags: u32 = GT_TagBase + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_Private0: u32 = GT_TagBase + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTCB_Checked: u32 = GT_TagBase + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_Top: u32 = GT_TagBase + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_Labels: u32 = GT_TagBase + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_ReadOnly: u32 = GT_TagBase + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_ScrollWidth: u32 = GT_TagBase + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_Labels: u32 = GT_TagBase + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_Active: u32 = GT_TagBase + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_Text: u32 = GT_TagBase + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_CopyText: u32 = GT_TagBase + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_Number: u32 = GT_TagBase + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTCY_Labels: u32 = GT_TagBase + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTCY_Active: u32 = GT_TagBase + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_Depth: u32 = GT_TagBase + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_Color: u32 = GT_TagBase + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_ColorOffset: u32 = GT_TagBase + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_IndicatorWidth: u32 = GT_TagBase + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_IndicatorHeight: u32 = GT_TagBase + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSC_Top: u32 = GT_TagBase + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSC_Total: u32 = GT_TagBase + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSC_Visible: u32 = GT_TagBase + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSC_Overlap: u32 = GT_TagBase + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_Min: u32 = GT_TagBase + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_Max: u32 = GT_TagBase + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_Level: u32 = GT_TagBase + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_MaxLevelLen: u32 = GT_TagBase + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_LevelFormat: u32 = GT_TagBase + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_LevelPlace: u32 = GT_TagBase + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_DispFunc: u32 = GT_TagBase + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTST_String: u32 = GT_TagBase + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTST_MaxChars: u32 = GT_TagBase + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTIN_Number: u32 = GT_TagBase + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTIN_MaxChars: u32 = GT_TagBase + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_TextAttr: u32 = GT_TagBase + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_FrontPen: u32 = GT_TagBase + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_Recessed: u32 = GT_TagBase + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_VisualInfo: u32 = GT_TagBase + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_ShowSelected: u32 = GT_TagBase + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_Selected: u32 = GT_TagBase + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_Reserved1: u32 = GT_TagBase + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_Border: u32 = GT_TagBase + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_Border: u32 = GT_TagBase + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSC_Arrows: u32 = GT_TagBase + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_Menu: u32 = GT_TagBase + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_Spacing: u32 = GT_TagBase + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_FullMenu: u32 = GT_TagBase + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_SecondaryError: u32 = GT_TagBase + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_Underscore: u32 = GT_TagBase + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTST_EditHook: u32 = GT_TagBase + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTIN_EditHook: u32 = GTST_EditHook;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_Checkmark: u32 = GT_TagBase + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_AmigaKey: u32 = GT_TagBase + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_NewLookMenus: u32 = GT_TagBase + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTCB_Scaled: u32 = GT_TagBase + 68;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_Scaled: u32 = GT_TagBase + 69;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_NumColors: u32 = GT_TagBase + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_TitlePlace: u32 = GT_TagBase + 71;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_FrontPen: u32 = GT_TagBase + 72;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_BackPen: u32 = GT_TagBase + 73;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_Justification: u32 = GT_TagBase + 74;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_FrontPen: u32 = GT_TagBase + 72;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_BackPen: u32 = GT_TagBase + 73;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_Justification: u32 = GT_TagBase + 74;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_Format: u32 = GT_TagBase + 75;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_MaxNumberLen: u32 = GT_TagBase + 76;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_FrameType: u32 = GT_TagBase + 77;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_MakeVisible: u32 = GT_TagBase + 78;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_ItemHeight: u32 = GT_TagBase + 79;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_MaxPixelLen: u32 = GT_TagBase + 80;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTSL_Justification: u32 = GT_TagBase + 81;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTPA_ColorTable: u32 = GT_TagBase + 82;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_CallBack: u32 = GT_TagBase + 83;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_MaxPen: u32 = GT_TagBase + 84;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTTX_Clipped: u32 = GT_TagBase + 85;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNM_Clipped: u32 = GT_TagBase + 85;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_reserved1: u32 = GT_TagBase + 90;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMN_reserved1: u32 = GT_TagBase + 91;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_Total: u32 = GT_TagBase + 92;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTLV_Visible: u32 = GT_TagBase + 93;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_Scale: u32 = GT_TagBase + 92;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_Headline: u32 = GT_TagBase + 93;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_HeadlinePen: u32 = GT_TagBase + 94;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTBB_HeadlineFont: u32 = GT_TagBase + 95;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_LeftBorder: u32 = GT_TagBase + 96;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_TopBorder: u32 = GT_TagBase + 97;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_AlignRight: u32 = GT_TagBase + 98;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_AlignBottom: u32 = GT_TagBase + 99;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_MinFontWidth: u32 = GT_TagBase + 100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTVI_MinFontHeight: u32 = GT_TagBase + 101;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTMX_ScaledSpacing: u32 = GT_TagBase + 102;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GT_Reserved0: u32 = GTST_EditHook;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNW_Labels: u32 = GTCY_Labels;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const GTNW_Active: u32 = GTCY_Active;
 pub const ID_FORM: u32 = MAKE_ID('F', 'O', 'R', 'M');
 pub const ID_LIST: u32 = MAKE_ID('L', 'I', 'S', 'T');
@@ -1986,36 +3819,67 @@ pub const IFFLCI_PROP: u32 = MAKE_ID('p', 'r', 'o', 'p');
 pub const IFFLCI_COLLECTION: u32 = MAKE_ID('c', 'o', 'l', 'l');
 pub const IFFLCI_ENTRYHANDLER: u32 = MAKE_ID('e', 'n', 'h', 'd');
 pub const IFFLCI_EXITHANDLER: u32 = MAKE_ID('e', 'x', 'h', 'd');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OC_TagBase: u32 = TAG_USER + 0x90000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OC_BuiltInLanguage: u32 = OC_TagBase + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OC_BuiltInCodeSet: u32 = OC_TagBase + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OC_Version: u32 = OC_TagBase + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const OC_Language: u32 = OC_TagBase + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SJA_Dummy: u32 = TAG_USER + 0xc00100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SJA_Type: u32 = SJA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SJA_Reinitialize: u32 = SJA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_Dummy: u32 = TAG_USER + 0x00C00000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_TakeOverSys: u32 = SCON_Dummy + 0;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_KillReq: u32 = SCON_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_CDReboot: u32 = SCON_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_StopInput: u32 = SCON_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_AddCreateKeys: u32 = SCON_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const SCON_RemCreateKeys: u32 = SCON_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Base: u32 = TAG_USER + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Hook: u32 = PLAYER_Base + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Name: u32 = PLAYER_Base + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Priority: u32 = PLAYER_Base + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Conductor: u32 = PLAYER_Base + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Ready: u32 = PLAYER_Base + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_AlarmTime: u32 = PLAYER_Base + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Alarm: u32 = PLAYER_Base + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_AlarmSigTask: u32 = PLAYER_Base + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_AlarmSigBit: u32 = PLAYER_Base + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Conducted: u32 = PLAYER_Base + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_Quiet: u32 = PLAYER_Base + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_UserData: u32 = PLAYER_Base + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_ID: u32 = PLAYER_Base + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_ExtSync: u32 = PLAYER_Base + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const PLAYER_ErrorCode: u32 = PLAYER_Base + 15;
 pub const ID_ASL: u32 = MAKE_ID('A', 'S', 'L', ' ');
 pub const ID_FONT: u32 = MAKE_ID('F', 'O', 'N', 'T');
@@ -2042,263 +3906,523 @@ pub const ID_PTRN: u32 = MAKE_ID('P', 'T', 'R', 'N');
 pub const ID_WBNC: u32 = MAKE_ID('W', 'B', 'N', 'C');
 pub const ID_WBHD: u32 = MAKE_ID('W', 'B', 'H', 'D');
 pub const ID_WBTF: u32 = MAKE_ID('W', 'B', 'T', 'F');
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REACTION_Dummy: u32 = TAG_USER + 0x5000000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REACTION_TextAttr: u32 = REACTION_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REACTION_ChangePrefs: u32 = REACTION_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REACTION_SpecialPens: u32 = REACTION_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const REACTION_Justification: u32 = REACTION_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Dummy: u32 = TAG_USER + 0x9000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_ErrorCode: u32 = ICONA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_ErrorTagItem: u32 = ICONA_Dummy + 75;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalScreen: u32 = ICONA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalScreen: u32 = ICONA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalPrecision: u32 = ICONA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalPrecision: u32 = ICONA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalEmbossRect: u32 = ICONA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalEmbossRect: u32 = ICONA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalFrameless: u32 = ICONA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalFrameless: u32 = ICONA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalNewIconsSupport: u32 = ICONA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalNewIconsSupport: u32 = ICONA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalColorIconSupport: u32 = ICONA_Dummy + 77;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalColorIconSupport: u32 = ICONA_Dummy + 78;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalIdentifyHook: u32 = ICONA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalIdentifyHook: u32 = ICONA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetGlobalMaxNameLength: u32 = ICONA_Dummy + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetGlobalMaxNameLength: u32 = ICONA_Dummy + 68;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetImageMask1: u32 = ICONA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetImageMask2: u32 = ICONA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetTransparentColor1: u32 = ICONA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetTransparentColor1: u32 = ICONA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetTransparentColor2: u32 = ICONA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetTransparentColor2: u32 = ICONA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetPalette1: u32 = ICONA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetPalette1: u32 = ICONA_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetPalette2: u32 = ICONA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetPalette2: u32 = ICONA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetPaletteSize1: u32 = ICONA_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetPaletteSize1: u32 = ICONA_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetPaletteSize2: u32 = ICONA_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetPaletteSize2: u32 = ICONA_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetImageData1: u32 = ICONA_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetImageData1: u32 = ICONA_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetImageData2: u32 = ICONA_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetImageData2: u32 = ICONA_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetFrameless: u32 = ICONA_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetFrameless: u32 = ICONA_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetNewIconsSupport: u32 = ICONA_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetNewIconsSupport: u32 = ICONA_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetAspectRatio: u32 = ICONA_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetAspectRatio: u32 = ICONA_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetWidth: u32 = ICONA_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetWidth: u32 = ICONA_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_SetHeight: u32 = ICONA_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetHeight: u32 = ICONA_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_IsPaletteMapped: u32 = ICONA_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_GetScreen: u32 = ICONA_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_HasRealImage2: u32 = ICONA_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_IsNewIcon: u32 = ICONA_Dummy + 79;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONCTRLA_IsNativeIcon: u32 = ICONA_Dummy + 80;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_GetDefaultType: u32 = ICONA_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_GetDefaultName: u32 = ICONA_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_FailIfUnavailable: u32 = ICONA_Dummy + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_GetPaletteMappedIcon: u32 = ICONA_Dummy + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_IsDefaultIcon: u32 = ICONA_Dummy + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_RemapIcon: u32 = ICONA_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_GenerateImageMasks: u32 = ICONA_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_Label: u32 = ICONA_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_Screen: u32 = ICONA_Dummy + 69;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_IdentifyBuffer: u32 = ICONA_Dummy + 122;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONGETA_IdentifyOnly: u32 = ICONA_Dummy + 123;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_NotifyWorkbench: u32 = ICONA_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_PutDefaultType: u32 = ICONA_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_PutDefaultName: u32 = ICONA_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_DropPlanarIconImage: u32 = ICONA_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_DropChunkyIconImage: u32 = ICONA_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_DropNewIconToolTypes: u32 = ICONA_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_OptimizeImageSpace: u32 = ICONA_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_OnlyUpdatePosition: u32 = ICONA_Dummy + 72;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONPUTA_PreserveOldIconImages: u32 = ICONA_Dummy + 84;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateDrawerData: u32 = ICONA_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateImages: u32 = ICONA_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateImageData: u32 = ICONA_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateDefaultTool: u32 = ICONA_Dummy + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateToolTypes: u32 = ICONA_Dummy + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_DuplicateToolWindow: u32 = ICONA_Dummy + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDUPA_ActivateImageData: u32 = ICONA_Dummy + 82;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_DrawInfo: u32 = ICONA_Dummy + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_Frameless: u32 = ICONA_Dummy + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_EraseBackground: u32 = ICONA_Dummy + 71;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_Borderless: u32 = ICONA_Dummy + 83;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_IsLink: u32 = ICONA_Dummy + 89;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_LabelShadow: u32 = ICONA_Dummy + 93;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONDRAWA_LabelOutline: u32 = ICONA_Dummy + 94;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved1: u32 = ICONA_Dummy + 73;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved2: u32 = ICONA_Dummy + 74;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved3: u32 = ICONA_Dummy + 76;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved4: u32 = ICONA_Dummy + 81;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved5: u32 = ICONA_Dummy + 85;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved6: u32 = ICONA_Dummy + 86;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved7: u32 = ICONA_Dummy + 87;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_Reserved8: u32 = ICONA_Dummy + 88;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const ICONA_LAST_TAG: u32 = ICONA_Dummy + 123;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Dummy: u32 = TAG_USER + 0xA000;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsOpen: u32 = WBA_Dummy + 1;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsCopy: u32 = WBA_Dummy + 2;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsRename: u32 = WBA_Dummy + 3;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsInformation: u32 = WBA_Dummy + 4;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsSnapshot: u32 = WBA_Dummy + 5;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsUnSnapshot: u32 = WBA_Dummy + 6;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsLeaveOut: u32 = WBA_Dummy + 7;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsPutAway: u32 = WBA_Dummy + 8;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsDelete: u32 = WBA_Dummy + 9;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsFormatDisk: u32 = WBA_Dummy + 10;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsEjectDisk: u32 = WBA_Dummy + 158;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_SupportsEmptyTrash: u32 = WBA_Dummy + 11;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_PropagatePosition: u32 = WBA_Dummy + 12;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_RenderHook: u32 = WBA_Dummy + 13;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPICONA_NotifySelectState: u32 = WBA_Dummy + 14;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPMENUA_CommandKeyString: u32 = WBA_Dummy + 15;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPMENUA_GetKey: u32 = WBA_Dummy + 65;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPMENUA_UseKey: u32 = WBA_Dummy + 66;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBAPPMENUA_GetTitleKey: u32 = WBA_Dummy + 77;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOPENA_ArgLock: u32 = WBA_Dummy + 16;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOPENA_ArgName: u32 = WBA_Dummy + 17;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOPENA_Show: u32 = WBA_Dummy + 75;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOPENA_ViewBy: u32 = WBA_Dummy + 76;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_IsOpen: u32 = WBA_Dummy + 18;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_DuplicateSearchPath: u32 = WBA_Dummy + 19;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_FreeSearchPath: u32 = WBA_Dummy + 20;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetDefaultStackSize: u32 = WBA_Dummy + 21;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetDefaultStackSize: u32 = WBA_Dummy + 22;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_RedrawAppIcon: u32 = WBA_Dummy + 23;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetProgramList: u32 = WBA_Dummy + 24;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_FreeProgramList: u32 = WBA_Dummy + 25;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetSelectedIconList: u32 = WBA_Dummy + 36;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_FreeSelectedIconList: u32 = WBA_Dummy + 37;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetOpenDrawerList: u32 = WBA_Dummy + 38;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_FreeOpenDrawerList: u32 = WBA_Dummy + 39;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetHiddenDeviceList: u32 = WBA_Dummy + 42;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_FreeHiddenDeviceList: u32 = WBA_Dummy + 43;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_AddHiddenDeviceName: u32 = WBA_Dummy + 44;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_RemoveHiddenDeviceName: u32 = WBA_Dummy + 45;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetTypeRestartTime: u32 = WBA_Dummy + 47;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetTypeRestartTime: u32 = WBA_Dummy + 48;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetCopyHook: u32 = WBA_Dummy + 69;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetCopyHook: u32 = WBA_Dummy + 70;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetDeleteHook: u32 = WBA_Dummy + 71;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetDeleteHook: u32 = WBA_Dummy + 72;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetTextInputHook: u32 = WBA_Dummy + 73;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetTextInputHook: u32 = WBA_Dummy + 74;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_AddSetupCleanupHook: u32 = WBA_Dummy + 78;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_RemSetupCleanupHook: u32 = WBA_Dummy + 79;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetGlobalFlags: u32 = WBA_Dummy + 130;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetGlobalFlags: u32 = WBA_Dummy + 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_GetDiskInfoHook: u32 = WBA_Dummy + 159;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBCTRLA_SetDiskInfoHook: u32 = WBA_Dummy + 160;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Left: u32 = WBA_Dummy + 26;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_RelRight: u32 = WBA_Dummy + 27;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Top: u32 = WBA_Dummy + 28;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_RelBottom: u32 = WBA_Dummy + 29;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Width: u32 = WBA_Dummy + 30;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_RelWidth: u32 = WBA_Dummy + 31;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Height: u32 = WBA_Dummy + 32;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_RelHeight: u32 = WBA_Dummy + 33;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Box: u32 = WBA_Dummy + 34;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBDZA_Hook: u32 = WBA_Dummy + 35;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Type: u32 = WBA_Dummy + 86;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Left: u32 = WBA_Dummy + 87;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Top: u32 = WBA_Dummy + 88;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Width: u32 = WBA_Dummy + 89;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Height: u32 = WBA_Dummy + 90;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_State: u32 = WBA_Dummy + 91;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_IsFake: u32 = WBA_Dummy + 92;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_Name: u32 = WBA_Dummy + 93;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_NameSize: u32 = WBA_Dummy + 94;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_FullPath: u32 = WBA_Dummy + 95;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_FullPathSize: u32 = WBA_Dummy + 96;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_IsLink: u32 = WBA_Dummy + 97;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_DrawerPath: u32 = WBA_Dummy + 98;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_DrawerPathSize: u32 = WBA_Dummy + 99;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_DrawerFlags: u32 = WBA_Dummy + 116;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBOBJA_DrawerModes: u32 = WBA_Dummy + 117;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved1: u32 = WBA_Dummy + 40;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved2: u32 = WBA_Dummy + 41;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved3: u32 = WBA_Dummy + 46;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved4: u32 = WBA_Dummy + 49;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved5: u32 = WBA_Dummy + 50;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved6: u32 = WBA_Dummy + 51;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved7: u32 = WBA_Dummy + 52;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved8: u32 = WBA_Dummy + 53;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved9: u32 = WBA_Dummy + 54;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved10: u32 = WBA_Dummy + 55;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved11: u32 = WBA_Dummy + 56;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved12: u32 = WBA_Dummy + 57;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved13: u32 = WBA_Dummy + 58;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved14: u32 = WBA_Dummy + 59;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved15: u32 = WBA_Dummy + 60;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved16: u32 = WBA_Dummy + 61;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved17: u32 = WBA_Dummy + 62;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved18: u32 = WBA_Dummy + 63;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved19: u32 = WBA_Dummy + 64;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved20: u32 = WBA_Dummy + 67;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved21: u32 = WBA_Dummy + 68;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved22: u32 = WBA_Dummy + 80;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved23: u32 = WBA_Dummy + 81;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved24: u32 = WBA_Dummy + 82;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved25: u32 = WBA_Dummy + 83;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved26: u32 = WBA_Dummy + 84;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved27: u32 = WBA_Dummy + 85;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved28: u32 = WBA_Dummy + 100;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved29: u32 = WBA_Dummy + 101;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved30: u32 = WBA_Dummy + 102;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved31: u32 = WBA_Dummy + 103;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved32: u32 = WBA_Dummy + 104;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved33: u32 = WBA_Dummy + 105;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved34: u32 = WBA_Dummy + 108;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved35: u32 = WBA_Dummy + 109;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved36: u32 = WBA_Dummy + 112;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved37: u32 = WBA_Dummy + 113;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved38: u32 = WBA_Dummy + 114;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved39: u32 = WBA_Dummy + 115;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved40: u32 = WBA_Dummy + 118;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved41: u32 = WBA_Dummy + 119;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved42: u32 = WBA_Dummy + 120;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved43: u32 = WBA_Dummy + 121;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved44: u32 = WBA_Dummy + 122;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved45: u32 = WBA_Dummy + 123;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved46: u32 = WBA_Dummy + 124;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved47: u32 = WBA_Dummy + 125;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved48: u32 = WBA_Dummy + 126;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved49: u32 = WBA_Dummy + 127;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved50: u32 = WBA_Dummy + 128;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved51: u32 = WBA_Dummy + 129;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved52: u32 = WBA_Dummy + 130;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved53: u32 = WBA_Dummy + 131;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved54: u32 = WBA_Dummy + 132;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved55: u32 = WBA_Dummy + 133;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved56: u32 = WBA_Dummy + 134;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved57: u32 = WBA_Dummy + 135;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved58: u32 = WBA_Dummy + 136;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved59: u32 = WBA_Dummy + 137;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved60: u32 = WBA_Dummy + 138;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved61: u32 = WBA_Dummy + 139;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved62: u32 = WBA_Dummy + 140;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved63: u32 = WBA_Dummy + 141;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved64: u32 = WBA_Dummy + 142;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved65: u32 = WBA_Dummy + 143;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved66: u32 = WBA_Dummy + 144;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved67: u32 = WBA_Dummy + 145;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved68: u32 = WBA_Dummy + 146;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved69: u32 = WBA_Dummy + 147;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved70: u32 = WBA_Dummy + 148;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved71: u32 = WBA_Dummy + 149;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved72: u32 = WBA_Dummy + 150;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved73: u32 = WBA_Dummy + 151;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved74: u32 = WBA_Dummy + 152;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved75: u32 = WBA_Dummy + 153;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved76: u32 = WBA_Dummy + 154;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved77: u32 = WBA_Dummy + 155;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved78: u32 = WBA_Dummy + 156;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_Reserved79: u32 = WBA_Dummy + 157;
+#[cfg(not(feature = "kickstart-v34"))]
 pub const WBA_LAST_TAG: u32 = WBA_Dummy + 160;
@@ -0,0 +1,359 @@
+use crate::{
+    ACCESS_READ, APTR, BPTR, DateStamp, DosError, DosLib, FileInfoBlock, LONG, MEMF_ANY,
+    ST_SOFTLINK, ULONG,
+};
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+#[cfg(not(feature = "kickstart-v34"))]
+use crate::{DOS_EXALLCONTROL, ED_COMMENT, ExAllControl, ExAllData};
+
+/// Size of the buffer of `ExAll()`
+#[cfg(not(feature = "kickstart-v34"))]
+const EX_ALL_BUFFER_SIZE: usize = 2048;
+
+/// An entry of a directory, returned by [`ReadDir`]
+///
+/// The entry is a copy of the `FileInfoBlock` or the `ExAllData`, so it doesn't borrow the
+/// iterator.
+#[derive(Debug, Clone)]
+pub struct DirEntry {
+    name: [u8; 108],
+    name_len: usize,
+    comment: [u8; 80],
+    comment_len: usize,
+    entry_type: LONG,
+    size: ULONG,
+    protection: ULONG,
+    date: DateStamp,
+}
+
+impl DirEntry {
+    /// Returns the name of the file or the directory
+    pub fn name(&self) -> &[u8] {
+        &self.name[..self.name_len]
+    }
+
+    /// Returns the file comment, which is empty if the file has no comment
+    pub fn comment(&self) -> &[u8] {
+        &self.comment[..self.comment_len]
+    }
+
+    /// Returns the entry type: `ST_FILE`, `ST_USERDIR`, `ST_SOFTLINK`, etc.
+    ///
+    /// Positive types are directories and negative types are files.
+    pub fn entry_type(&self) -> LONG {
+        self.entry_type
+    }
+
+    /// Returns `true` if the entry is a directory or a hard link to a directory
+    pub fn is_dir(&self) -> bool {
+        self.entry_type > 0 && self.entry_type != ST_SOFTLINK as LONG
+    }
+
+    /// Returns `true` if the entry is a file or a hard link to a file
+    pub fn is_file(&self) -> bool {
+        self.entry_type < 0
+    }
+
+    /// Returns `true` if the entry is a soft link
+    pub fn is_soft_link(&self) -> bool {
+        self.entry_type == ST_SOFTLINK as LONG
+    }
+
+    /// Returns the size of a file in bytes
+    pub fn size(&self) -> ULONG {
+        self.size
+    }
+
+    /// Returns the protection bits: `FIBF_READ`, `FIBF_ARCHIVE`, etc.
+    ///
+    /// The bits `FIBF_READ`, `FIBF_WRITE`, `FIBF_EXECUTE` and `FIBF_DELETE` are set when the
+    /// operation is *not* allowed.
+    pub fn protection(&self) -> ULONG {
+        self.protection
+    }
+
+    /// Returns the date of the last change
+    pub fn date(&self) -> DateStamp {
+        self.date
+    }
+
+    /// Copies the entry from a `FileInfoBlock`
+    pub(crate) fn from_fib(fib: &FileInfoBlock) -> Self {
+        let (name, name_len) = copy_c_string(fib.fib_FileName.as_ptr());
+        let (comment, comment_len) = copy_c_string(fib.fib_Comment.as_ptr());
+        Self {
+            name,
+            name_len,
+            comment,
+            comment_len,
+            entry_type: fib.fib_DirEntryType,
+            size: fib.fib_Size as ULONG,
+            protection: fib.fib_Protection as ULONG,
+            date: fib.fib_Date,
+        }
+    }
+
+    /// Copies the entry from an `ExAllData` returned with `ED_COMMENT`
+    #[cfg(not(feature = "kickstart-v34"))]
+    fn from_ex_all_data(data: &ExAllData) -> Self {
+        let (name, name_len) = copy_c_string(data.ed_Name);
+        let (comment, comment_len) = if data.ed_Comment.is_null() {
+            ([0; 80], 0)
+        } else {
+            copy_c_string(data.ed_Comment)
+        };
+        Self {
+            name,
+            name_len,
+            comment,
+            comment_len,
+            entry_type: data.ed_Type,
+            size: data.ed_Size,
+            protection: data.ed_Prot,
+            date: DateStamp {
+                ds_Days: data.ed_Days as LONG,
+                ds_Minute: data.ed_Mins as LONG,
+                ds_Tick: data.ed_Ticks as LONG,
+            },
+        }
+    }
+}
+
+/// Copies a null-terminated string, truncating it to the array
+fn copy_c_string<const N: usize>(string: *const u8) -> ([u8; N], usize) {
+    let mut array = [0; N];
+    let bytes = unsafe { CStr::from_ptr(string as _) }.to_bytes();
+    let len = bytes.len().min(N);
+    array[..len].copy_from_slice(&bytes[..len]);
+    (array, len)
+}
+
+/// `ExAll()` buffers and the state of the scan
+#[cfg(not(feature = "kickstart-v34"))]
+struct ExAllScan {
+    control: NonNull<ExAllControl>,
+    buffer: NonNull<ExAllData>,
+    /// Next entry in the buffer
+    next: *mut ExAllData,
+    /// `ExAll()` has more entries
+    more: bool,
+    /// `ExAll()` has returned entries
+    started: bool,
+}
+
+/// Iterator over the entries of a directory
+///
+/// The directory is read with `ExAll()` (V36), or with `Examine()` and `ExNext()` before
+/// V36 and with file systems which don't support `ExAll()`. The `FileInfoBlock` and the
+/// `ExAll()` buffer are allocated with `AllocMem()`, which aligns them to 4 bytes as
+/// dos.library requires:
+///
+/// ```ignore
+/// for entry in ReadDir::open(&dos, c"sys:")? {
+///     let entry = entry?;
+///     if entry.is_dir() {
+///         writeln!(out, "{} (dir)", entry.name().escape_ascii())?;
+///     }
+/// }
+/// ```
+///
+/// The lock and the buffers are freed when the iterator is dropped.
+pub struct ReadDir<'d> {
+    dos: &'d DosLib,
+    lock: BPTR,
+    fib: NonNull<FileInfoBlock>,
+    #[cfg(not(feature = "kickstart-v34"))]
+    ex_all: Option<ExAllScan>,
+    done: bool,
+}
+
+impl<'d> ReadDir<'d> {
+    /// Locks the directory with `Lock()` and examines it with `Examine()`
+    ///
+    /// Returns the `IoErr()` error if the directory can't be locked, or
+    /// `DosError::ObjectWrongType` if it's a file.
+    pub fn open(dos: &'d DosLib, path: &CStr) -> Result<Self, DosError> {
+        let lock = unsafe { DosError::check(dos, dos.Lock(path.as_ptr() as _, ACCESS_READ))? };
+        let Some(fib) = alloc_mem::<FileInfoBlock>(size_of::<FileInfoBlock>()) else {
+            unsafe { dos.UnLock(lock) };
+            return Err(DosError::NoFreeStore);
+        };
+        #[cfg_attr(feature = "kickstart-v34", allow(unused_mut))]
+        let mut dir = Self {
+            dos,
+            lock,
+            fib,
+            #[cfg(not(feature = "kickstart-v34"))]
+            ex_all: None,
+            done: false,
+        };
+        unsafe {
+            DosError::check(dos, dos.Examine(lock, fib.as_ptr()))?;
+            if dir.fib.as_ref().fib_DirEntryType <= 0 {
+                return Err(DosError::ObjectWrongType);
+            }
+        }
+        #[cfg(not(feature = "kickstart-v34"))]
+        if dos.version() >= 36 {
+            dir.ex_all = dir.alloc_ex_all();
+        }
+        Ok(dir)
+    }
+
+    /// Allocates the `ExAll()` buffers, returns `None` if there isn't enough memory
+    #[cfg(not(feature = "kickstart-v34"))]
+    fn alloc_ex_all(&self) -> Option<ExAllScan> {
+        let control = unsafe { self.dos.AllocDosObject(DOS_EXALLCONTROL, core::ptr::null()) };
+        let control = NonNull::new(control as *mut ExAllControl)?;
+        let Some(buffer) = alloc_mem::<ExAllData>(EX_ALL_BUFFER_SIZE) else {
+            unsafe {
+                self.dos
+                    .FreeDosObject(DOS_EXALLCONTROL, control.as_ptr() as APTR)
+            };
+            return None;
+        };
+        Some(ExAllScan {
+            control,
+            buffer,
+            next: core::ptr::null_mut(),
+            more: true,
+            started: false,
+        })
+    }
+
+    /// Returns the next entry from the `ExAll()` buffer, calling `ExAll()` when it's empty
+    #[cfg(not(feature = "kickstart-v34"))]
+    fn next_ex_all(
+        dos: &DosLib,
+        lock: BPTR,
+        scan: &mut ExAllScan,
+    ) -> Option<Result<DirEntry, DosError>> {
+        unsafe {
+            loop {
+                if let Some(data) = scan.next.as_ref() {
+                    scan.next = data.ed_Next;
+                    return Some(Ok(DirEntry::from_ex_all_data(data)));
+                }
+                if !scan.more {
+                    return None;
+                }
+                scan.more = dos.ExAll(
+                    lock,
+                    scan.buffer.as_ptr(),
+                    EX_ALL_BUFFER_SIZE as LONG,
+                    ED_COMMENT as LONG,
+                    scan.control.as_ptr(),
+                ) != 0;
+                if !scan.more {
+                    let error = DosError::last(dos);
+                    if error != DosError::NoMoreEntries {
+                        return Some(Err(error));
+                    }
+                }
+                if scan.control.as_ref().eac_Entries > 0 {
+                    scan.next = scan.buffer.as_ptr();
+                    scan.started = true;
+                }
+            }
+        }
+    }
+
+    /// Ends the `ExAll()` scan and frees its buffers
+    #[cfg(not(feature = "kickstart-v34"))]
+    fn free_ex_all(&mut self) {
+        let Some(mut scan) = self.ex_all.take() else {
+            return;
+        };
+        unsafe {
+            if scan.more {
+                #[cfg(not(feature = "kickstart-v38"))]
+                if self.dos.version() >= 39 {
+                    self.dos.ExAllEnd(
+                        self.lock,
+                        scan.buffer.as_ptr(),
+                        EX_ALL_BUFFER_SIZE as LONG,
+                        ED_COMMENT as LONG,
+                        scan.control.as_ptr(),
+                    );
+                    scan.more = false;
+                }
+                // before V39, the scan ends when ExAll() returns false
+                while scan.more {
+                    scan.more = self.dos.ExAll(
+                        self.lock,
+                        scan.buffer.as_ptr(),
+                        EX_ALL_BUFFER_SIZE as LONG,
+                        ED_COMMENT as LONG,
+                        scan.control.as_ptr(),
+                    ) != 0;
+                }
+            }
+            self.dos
+                .FreeDosObject(DOS_EXALLCONTROL, scan.control.as_ptr() as APTR);
+            free_mem(scan.buffer, EX_ALL_BUFFER_SIZE);
+        }
+    }
+}
+
+impl Iterator for ReadDir<'_> {
+    type Item = Result<DirEntry, DosError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        #[cfg(not(feature = "kickstart-v34"))]
+        if let Some(scan) = &mut self.ex_all {
+            let started = scan.started;
+            match Self::next_ex_all(self.dos, self.lock, scan) {
+                // the file system doesn't support ExAll(): use ExNext() instead
+                Some(Err(DosError::ActionNotKnown)) if !started => self.free_ex_all(),
+                Some(Ok(entry)) => return Some(Ok(entry)),
+                result => {
+                    self.done = true;
+                    return result;
+                }
+            }
+        }
+        unsafe {
+            if self.dos.ExNext(self.lock, self.fib.as_ptr()) == 0 {
+                self.done = true;
+                return match DosError::last(self.dos) {
+                    DosError::NoMoreEntries => None,
+                    error => Some(Err(error)),
+                };
+            }
+            Some(Ok(DirEntry::from_fib(self.fib.as_ref())))
+        }
+    }
+}
+
+impl Drop for ReadDir<'_> {
+    fn drop(&mut self) {
+        #[cfg(not(feature = "kickstart-v34"))]
+        self.free_ex_all();
+        unsafe {
+            free_mem(self.fib, size_of::<FileInfoBlock>());
+            self.dos.UnLock(self.lock);
+        }
+    }
+}
+
+/// Allocates memory with `AllocMem()`, which is aligned to 8 bytes
+fn alloc_mem<T>(size: usize) -> Option<NonNull<T>> {
+    let memory = unsafe { crate::AllocMem(crate::abs_exec_library(), size as ULONG, MEMF_ANY) };
+    NonNull::new(memory as *mut T)
+}
+
+/// Frees memory allocated with `alloc_mem()`
+unsafe fn free_mem<T>(memory: NonNull<T>, size: usize) {
+    unsafe {
+        crate::FreeMem(
+            crate::abs_exec_library(),
+            memory.as_ptr() as APTR,
+            size as ULONG,
+        )
+    }
+}
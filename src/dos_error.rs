@@ -0,0 +1,277 @@
+use crate::DosLib;
+use crate::bindings::*;
+use core::fmt;
+
+macro_rules! dos_errors {
+    ($($(#[$doc:meta])* $variant:ident = $code:ident,)*) => {
+        /// Error code of dos.library, returned by `IoErr()`
+        ///
+        /// The `ERROR_*` codes have their own variants and other codes are kept in
+        /// `Unknown`, so that the code can always be converted back with [`DosError::code()`].
+        /// `Display` writes the message of `Fault()` (V36), which is localized by locale.library:
+        ///
+        /// ```ignore
+        /// let lock = unsafe { DosError::check(&dos, dos.Lock(name.as_ptr(), ACCESS_READ)) };
+        /// match lock {
+        ///     Ok(lock) => {}
+        ///     Err(DosError::ObjectNotFound) => {}
+        ///     Err(error) => writeln!(out, "can't lock: {error}")?,
+        /// }
+        /// ```
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        pub enum DosError {
+            $($(#[$doc])* $variant,)*
+            /// A code which isn't one of the `ERROR_*` codes
+            Unknown(LONG),
+        }
+
+        impl DosError {
+            /// Returns the error of the code
+            pub fn from_code(code: LONG) -> Self {
+                $(if code == $code as LONG {
+                    return DosError::$variant;
+                })*
+                DosError::Unknown(code)
+            }
+
+            /// Returns the error code
+            pub fn code(self) -> LONG {
+                match self {
+                    $(DosError::$variant => $code as LONG,)*
+                    DosError::Unknown(code) => code,
+                }
+            }
+        }
+    };
+}
+
+dos_errors! {
+    /// `ERROR_NO_FREE_STORE` (103)
+    NoFreeStore = ERROR_NO_FREE_STORE,
+    /// `ERROR_TASK_TABLE_FULL` (105)
+    TaskTableFull = ERROR_TASK_TABLE_FULL,
+    /// `ERROR_BAD_TEMPLATE` (114)
+    BadTemplate = ERROR_BAD_TEMPLATE,
+    /// `ERROR_BAD_NUMBER` (115)
+    BadNumber = ERROR_BAD_NUMBER,
+    /// `ERROR_REQUIRED_ARG_MISSING` (116)
+    RequiredArgMissing = ERROR_REQUIRED_ARG_MISSING,
+    /// `ERROR_KEY_NEEDS_ARG` (117)
+    KeyNeedsArg = ERROR_KEY_NEEDS_ARG,
+    /// `ERROR_TOO_MANY_ARGS` (118)
+    TooManyArgs = ERROR_TOO_MANY_ARGS,
+    /// `ERROR_UNMATCHED_QUOTES` (119)
+    UnmatchedQuotes = ERROR_UNMATCHED_QUOTES,
+    /// `ERROR_LINE_TOO_LONG` (120)
+    LineTooLong = ERROR_LINE_TOO_LONG,
+    /// `ERROR_FILE_NOT_OBJECT` (121)
+    FileNotObject = ERROR_FILE_NOT_OBJECT,
+    /// `ERROR_INVALID_RESIDENT_LIBRARY` (122)
+    InvalidResidentLibrary = ERROR_INVALID_RESIDENT_LIBRARY,
+    /// `ERROR_NO_DEFAULT_DIR` (201)
+    NoDefaultDir = ERROR_NO_DEFAULT_DIR,
+    /// `ERROR_OBJECT_IN_USE` (202)
+    ObjectInUse = ERROR_OBJECT_IN_USE,
+    /// `ERROR_OBJECT_EXISTS` (203)
+    ObjectExists = ERROR_OBJECT_EXISTS,
+    /// `ERROR_DIR_NOT_FOUND` (204)
+    DirNotFound = ERROR_DIR_NOT_FOUND,
+    /// `ERROR_OBJECT_NOT_FOUND` (205)
+    ObjectNotFound = ERROR_OBJECT_NOT_FOUND,
+    /// `ERROR_BAD_STREAM_NAME` (206)
+    BadStreamName = ERROR_BAD_STREAM_NAME,
+    /// `ERROR_OBJECT_TOO_LARGE` (207)
+    ObjectTooLarge = ERROR_OBJECT_TOO_LARGE,
+    /// `ERROR_ACTION_NOT_KNOWN` (209)
+    ActionNotKnown = ERROR_ACTION_NOT_KNOWN,
+    /// `ERROR_INVALID_COMPONENT_NAME` (210)
+    InvalidComponentName = ERROR_INVALID_COMPONENT_NAME,
+    /// `ERROR_INVALID_LOCK` (211)
+    InvalidLock = ERROR_INVALID_LOCK,
+    /// `ERROR_OBJECT_WRONG_TYPE` (212)
+    ObjectWrongType = ERROR_OBJECT_WRONG_TYPE,
+    /// `ERROR_DISK_NOT_VALIDATED` (213)
+    DiskNotValidated = ERROR_DISK_NOT_VALIDATED,
+    /// `ERROR_DISK_WRITE_PROTECTED` (214)
+    DiskWriteProtected = ERROR_DISK_WRITE_PROTECTED,
+    /// `ERROR_RENAME_ACROSS_DEVICES` (215)
+    RenameAcrossDevices = ERROR_RENAME_ACROSS_DEVICES,
+    /// `ERROR_DIRECTORY_NOT_EMPTY` (216)
+    DirectoryNotEmpty = ERROR_DIRECTORY_NOT_EMPTY,
+    /// `ERROR_TOO_MANY_LEVELS` (217)
+    TooManyLevels = ERROR_TOO_MANY_LEVELS,
+    /// `ERROR_DEVICE_NOT_MOUNTED` (218)
+    DeviceNotMounted = ERROR_DEVICE_NOT_MOUNTED,
+    /// `ERROR_SEEK_ERROR` (219)
+    SeekError = ERROR_SEEK_ERROR,
+    /// `ERROR_COMMENT_TOO_BIG` (220)
+    CommentTooBig = ERROR_COMMENT_TOO_BIG,
+    /// `ERROR_DISK_FULL` (221)
+    DiskFull = ERROR_DISK_FULL,
+    /// `ERROR_DELETE_PROTECTED` (222)
+    DeleteProtected = ERROR_DELETE_PROTECTED,
+    /// `ERROR_WRITE_PROTECTED` (223)
+    WriteProtected = ERROR_WRITE_PROTECTED,
+    /// `ERROR_READ_PROTECTED` (224)
+    ReadProtected = ERROR_READ_PROTECTED,
+    /// `ERROR_NOT_A_DOS_DISK` (225)
+    NotADosDisk = ERROR_NOT_A_DOS_DISK,
+    /// `ERROR_NO_DISK` (226)
+    NoDisk = ERROR_NO_DISK,
+    /// `ERROR_NO_MORE_ENTRIES` (232)
+    NoMoreEntries = ERROR_NO_MORE_ENTRIES,
+    /// `ERROR_IS_SOFT_LINK` (233)
+    IsSoftLink = ERROR_IS_SOFT_LINK,
+    /// `ERROR_OBJECT_LINKED` (234)
+    ObjectLinked = ERROR_OBJECT_LINKED,
+    /// `ERROR_BAD_HUNK` (235)
+    BadHunk = ERROR_BAD_HUNK,
+    /// `ERROR_NOT_IMPLEMENTED` (236)
+    NotImplemented = ERROR_NOT_IMPLEMENTED,
+    /// `ERROR_RECORD_NOT_LOCKED` (240)
+    RecordNotLocked = ERROR_RECORD_NOT_LOCKED,
+    /// `ERROR_LOCK_COLLISION` (241)
+    LockCollision = ERROR_LOCK_COLLISION,
+    /// `ERROR_LOCK_TIMEOUT` (242)
+    LockTimeout = ERROR_LOCK_TIMEOUT,
+    /// `ERROR_UNLOCK_ERROR` (243)
+    UnlockError = ERROR_UNLOCK_ERROR,
+    /// `ERROR_BUFFER_OVERFLOW` (303)
+    BufferOverflow = ERROR_BUFFER_OVERFLOW,
+    /// `ERROR_BREAK` (304)
+    Break = ERROR_BREAK,
+    /// `ERROR_NOT_EXECUTABLE` (305)
+    NotExecutable = ERROR_NOT_EXECUTABLE,
+}
+
+impl DosError {
+    /// Returns the error of the last failed dos.library call with `IoErr()`
+    ///
+    /// # Safety
+    ///
+    /// Must be called by a process, not by a task.
+    pub unsafe fn last(dos: &DosLib) -> Self {
+        Self::from_code(unsafe { dos.IoErr() })
+    }
+
+    /// Returns the value, or the error from `IoErr()` if the value is zero or null
+    ///
+    /// Most dos.library functions return zero or null on failure and set the error code,
+    /// which is read right after the call:
+    ///
+    /// ```ignore
+    /// let file = unsafe { DosError::check(&dos, dos.Open(name.as_ptr(), MODE_OLDFILE))? };
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// Must be called by a process, not by a task.
+    pub unsafe fn check<T: DosReturn>(dos: &DosLib, value: T) -> Result<T, Self> {
+        if value.is_failure() {
+            Err(unsafe { Self::last(dos) })
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+impl From<LONG> for DosError {
+    fn from(code: LONG) -> Self {
+        Self::from_code(code)
+    }
+}
+
+impl From<DosError> for LONG {
+    fn from(error: DosError) -> Self {
+        error.code()
+    }
+}
+
+impl fmt::Display for DosError {
+    /// Writes the message of `Fault()`, or the code if dos.library V36 isn't available
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        #[cfg(not(feature = "kickstart-v34"))]
+        if let Some(dos) = DosLib::open(36) {
+            // messages are at most 80 characters
+            let mut buffer = [0u8; 84];
+            unsafe {
+                dos.Fault(
+                    self.code(),
+                    core::ptr::null(),
+                    buffer.as_mut_ptr(),
+                    buffer.len() as LONG,
+                )
+            };
+            let message = core::ffi::CStr::from_bytes_until_nul(&buffer).unwrap_or_default();
+            if !message.is_empty() {
+                // the messages are ISO-8859-1, which maps directly to chars
+                for &c in message.to_bytes() {
+                    fmt::Write::write_char(f, c as char)?;
+                }
+                return Ok(());
+            }
+        }
+        write!(f, "DOS error {}", self.code())
+    }
+}
+
+impl core::error::Error for DosError {}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Error for DosError {
+    fn kind(&self) -> embedded_io::ErrorKind {
+        use embedded_io::ErrorKind;
+        match self {
+            DosError::ObjectNotFound | DosError::DirNotFound | DosError::DeviceNotMounted => {
+                ErrorKind::NotFound
+            }
+            DosError::ReadProtected
+            | DosError::WriteProtected
+            | DosError::DeleteProtected
+            | DosError::DiskWriteProtected => ErrorKind::PermissionDenied,
+            DosError::ObjectExists => ErrorKind::AlreadyExists,
+            DosError::NoFreeStore => ErrorKind::OutOfMemory,
+            DosError::Break => ErrorKind::Interrupted,
+            DosError::ActionNotKnown | DosError::NotImplemented => ErrorKind::Unsupported,
+            DosError::BadNumber
+            | DosError::BadStreamName
+            | DosError::InvalidComponentName
+            | DosError::InvalidLock
+            | DosError::ObjectWrongType
+            | DosError::SeekError => ErrorKind::InvalidInput,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
+/// Return value of a dos.library function which is zero or null on failure
+///
+/// Implemented for `LONG` (including `BPTR`), `BOOL` and pointers.
+pub trait DosReturn: Copy {
+    /// Returns `true` if the value means that the call failed
+    fn is_failure(self) -> bool;
+}
+
+impl DosReturn for LONG {
+    fn is_failure(self) -> bool {
+        self == 0
+    }
+}
+
+impl DosReturn for BOOL {
+    fn is_failure(self) -> bool {
+        self == 0
+    }
+}
+
+impl<T> DosReturn for *mut T {
+    fn is_failure(self) -> bool {
+        self.is_null()
+    }
+}
+
+impl<T> DosReturn for *const T {
+    fn is_failure(self) -> bool {
+        self.is_null()
+    }
+}
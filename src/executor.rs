@@ -0,0 +1,309 @@
+use crate::{BYTE, IORequest, IntuiMessage, Message, MsgPort, SIGBREAKF_CTRL_C, ULONG, Window};
+use core::future::Future;
+use core::pin::{Pin, pin};
+use core::ptr::{NonNull, null};
+use core::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+/// Signals which the pending futures wait for, collected while the future is polled
+static mut WAIT_SIGNALS: ULONG = 0;
+/// Signals which the pending `Signals` futures wait for, a subset of `WAIT_SIGNALS`
+static mut SIGNALS_MASK: ULONG = 0;
+/// Signals of `SIGNALS_MASK` returned by `Wait()` which haven't been taken by a `Signals`
+/// future
+static mut RECEIVED_SIGNALS: ULONG = 0;
+/// Set by `Waker::wake()`: the future is polled again without waiting
+static mut WOKEN: bool = false;
+
+static WAKER_VTABLE: RawWakerVTable =
+    RawWakerVTable::new(clone_waker, wake_waker, wake_waker, drop_waker);
+
+unsafe fn clone_waker(_data: *const ()) -> RawWaker {
+    RawWaker::new(null(), &WAKER_VTABLE)
+}
+
+unsafe fn wake_waker(_data: *const ()) {
+    unsafe { WOKEN = true }
+}
+
+unsafe fn drop_waker(_data: *const ()) {}
+
+/// Runs the future to completion, waiting for signals with `Wait()` while it's pending
+///
+/// The executor is single-threaded and it doesn't allocate: several event sources are
+/// combined into one future with [`select()`] or with `async` blocks. When the future is
+/// pending, the task sleeps in `Wait()` with the signals of the pending futures, for example
+/// the `mp_SigBit` of a window's `UserPort`, and the future is polled again when one of the
+/// signals is received:
+///
+/// ```ignore
+/// let closed = pin!(async {
+///     loop {
+///         let message = unsafe { intui_message(window) }.await;
+///         if message.Class == IDCMP_CLOSEWINDOW {
+///             break;
+///         }
+///     }
+/// });
+/// match block_on(select(closed, ctrl_c())) {
+///     Either::Left(()) => {} // close gadget
+///     Either::Right(_) => {} // Ctrl-C
+/// }
+/// ```
+///
+/// A pending future must either wait for signals with [`wake_on_signals()`] or call
+/// `Waker::wake()`, otherwise `Wait()` never returns. `wake()` must be called by the same
+/// task, so that the futures of other tasks or interrupts should signal the task instead.
+/// `block_on()` must not be called by a future which is run by `block_on()`.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = unsafe { Waker::from_raw(RawWaker::new(null(), &WAKER_VTABLE)) };
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        unsafe {
+            WAIT_SIGNALS = 0;
+            SIGNALS_MASK = 0;
+            WOKEN = false;
+        }
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            unsafe {
+                // the signals of the dropped Signals futures are set again for later futures
+                let exec = crate::abs_exec_library();
+                crate::SetSignal(exec, RECEIVED_SIGNALS, RECEIVED_SIGNALS);
+                RECEIVED_SIGNALS = 0;
+            }
+            return output;
+        }
+        unsafe {
+            if !WOKEN {
+                // Wait() clears the received signals, which are kept for Signals futures.
+                // The other signals, like the signals of message ports, aren't kept: the
+                // futures check their port.
+                let received = crate::Wait(crate::abs_exec_library(), WAIT_SIGNALS);
+                RECEIVED_SIGNALS |= received & SIGNALS_MASK;
+            }
+        }
+    }
+}
+
+/// Makes [`block_on()`] wait for the signals before polling the future again
+///
+/// Called by a future which returns `Poll::Pending` until the signals are received. If the
+/// future isn't run by `block_on()`, the future is woken immediately, so that it's polled
+/// again.
+pub fn wake_on_signals(cx: &mut Context, signals: ULONG) {
+    if cx.waker().vtable() == &WAKER_VTABLE {
+        unsafe { WAIT_SIGNALS |= signals };
+    } else {
+        cx.waker().wake_by_ref();
+    }
+}
+
+/// Future which waits for signals, returned by [`signals()`] and [`ctrl_c()`]
+///
+/// The output is the received signals of the mask. The signals are cleared.
+pub struct Signals {
+    mask: ULONG,
+}
+
+/// Returns a future which waits for any of the signals of the mask
+///
+/// The signals are usually allocated with `AllocSignal()` or they are the break signals,
+/// like `SIGBREAKF_CTRL_E`. A signal which has been received before the future is polled
+/// completes the future immediately.
+pub fn signals(mask: ULONG) -> Signals {
+    Signals { mask }
+}
+
+/// Returns a future which waits for Ctrl-C, the break signal `SIGBREAKF_CTRL_C`
+///
+/// The CLI sends the signal when the user presses Ctrl-C or uses the `Break` command.
+pub fn ctrl_c() -> Signals {
+    signals(SIGBREAKF_CTRL_C)
+}
+
+impl Future for Signals {
+    type Output = ULONG;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<ULONG> {
+        unsafe {
+            // takes the signals which Wait() has cleared or which are still set
+            let set = crate::SetSignal(crate::abs_exec_library(), 0, self.mask);
+            let received = (RECEIVED_SIGNALS | set) & self.mask;
+            RECEIVED_SIGNALS &= !received;
+            if received != 0 {
+                return Poll::Ready(received);
+            }
+        }
+        if cx.waker().vtable() == &WAKER_VTABLE {
+            unsafe { SIGNALS_MASK |= self.mask };
+        }
+        wake_on_signals(cx, self.mask);
+        Poll::Pending
+    }
+}
+
+/// Future which gets a message from a message port, returned by [`next_message()`]
+pub struct NextMessage {
+    port: *mut MsgPort,
+}
+
+/// Returns a future which gets the next message from the message port with `GetMsg()`
+///
+/// The message must be replied with `ReplyMsg()`, unless it's a reply to a message which
+/// the program has sent.
+///
+/// # Safety
+///
+/// The port must be a signalling port (`PA_SIGNAL`) of the task, and it must stay valid
+/// while the future is used.
+pub unsafe fn next_message(port: *mut MsgPort) -> NextMessage {
+    NextMessage { port }
+}
+
+impl Future for NextMessage {
+    type Output = NonNull<Message>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<NonNull<Message>> {
+        let message = unsafe { crate::GetMsg(crate::abs_exec_library(), self.port) };
+        match NonNull::new(message) {
+            Some(message) => Poll::Ready(message),
+            None => {
+                // a message which arrives after GetMsg() sets the signal again
+                wake_on_signals(cx, 1 << unsafe { (*self.port).mp_SigBit });
+                Poll::Pending
+            }
+        }
+    }
+}
+
+/// Future which gets an IDCMP message of a window, returned by [`intui_message()`]
+pub struct NextIntuiMessage {
+    next: NextMessage,
+}
+
+/// Returns a future which gets the next message from the `UserPort` of the window
+///
+/// The output is a copy of the message, which has already been replied. The pointers of
+/// the message are valid only as long as the system keeps the data: for example `IAddress`
+/// of `IDCMP_GADGETUP` points to the gadget, but the tag list of `IDCMP_IDCMPUPDATE` has
+/// already been freed.
+///
+/// # Safety
+///
+/// The window must have been opened by the task with IDCMP flags, and it must not be closed
+/// while the future is used.
+pub unsafe fn intui_message(window: *mut Window) -> NextIntuiMessage {
+    NextIntuiMessage {
+        next: unsafe { next_message((*window).UserPort) },
+    }
+}
+
+impl Future for NextIntuiMessage {
+    type Output = IntuiMessage;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<IntuiMessage> {
+        Pin::new(&mut self.next).poll(cx).map(|message| unsafe {
+            let copy = *(message.as_ptr() as *const IntuiMessage);
+            crate::ReplyMsg(crate::abs_exec_library(), message.as_ptr());
+            copy
+        })
+    }
+}
+
+/// Future which waits for an I/O request to complete, returned by [`io_completion()`]
+///
+/// Dropping the future before the request has completed aborts the request with
+/// `AbortIO()` and waits for it with `WaitIO()`, so that the request can be reused.
+pub struct IoCompletion {
+    request: *mut IORequest,
+    done: bool,
+}
+
+/// Returns a future which waits for the I/O request sent with `SendIO()` to complete
+///
+/// The output is `io_Error`. The reply message is removed from the reply port with
+/// `WaitIO()`. For example, a `timerequest` of `timer.device`:
+///
+/// ```ignore
+/// request.tr_node.io_Command = TR_ADDREQUEST as UWORD;
+/// request.tr_time = timeval { tv_secs: 1, tv_micro: 0 };
+/// SendIO(exec, &mut request.tr_node);
+/// block_on(select(io_completion(&mut request.tr_node), ctrl_c()));
+/// ```
+///
+/// # Safety
+///
+/// The request must have been sent with `SendIO()`, its reply port must be a signalling
+/// port of the task, and the request must stay valid while the future is used.
+pub unsafe fn io_completion(request: *mut IORequest) -> IoCompletion {
+    IoCompletion {
+        request,
+        done: false,
+    }
+}
+
+impl Future for IoCompletion {
+    type Output = BYTE;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<BYTE> {
+        unsafe {
+            let exec = crate::abs_exec_library();
+            if !self.done && crate::CheckIO(exec, self.request).is_null() {
+                let port = (*self.request).io_Message.mn_ReplyPort;
+                wake_on_signals(cx, 1 << (*port).mp_SigBit);
+                return Poll::Pending;
+            }
+            self.done = true;
+            // removes the reply from the port
+            Poll::Ready(crate::WaitIO(exec, self.request))
+        }
+    }
+}
+
+impl Drop for IoCompletion {
+    fn drop(&mut self) {
+        if !self.done {
+            unsafe {
+                let exec = crate::abs_exec_library();
+                crate::AbortIO(exec, self.request);
+                crate::WaitIO(exec, self.request);
+            }
+        }
+    }
+}
+
+/// Output of [`select()`]: the output of the future which completed first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Either<A, B> {
+    Left(A),
+    Right(B),
+}
+
+/// Future which completes when either future completes, returned by [`select()`]
+pub struct Select<A, B> {
+    left: A,
+    right: B,
+}
+
+/// Returns a future which completes with the output of the first completed future
+///
+/// The left future is polled first. The other future isn't completed: dropping it cancels
+/// it, for example [`IoCompletion`] aborts its request. `async` blocks must be pinned with
+/// `core::pin::pin!()` first.
+pub fn select<A: Future + Unpin, B: Future + Unpin>(left: A, right: B) -> Select<A, B> {
+    Select { left, right }
+}
+
+impl<A: Future + Unpin, B: Future + Unpin> Future for Select<A, B> {
+    type Output = Either<A::Output, B::Output>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        if let Poll::Ready(output) = Pin::new(&mut self.left).poll(cx) {
+            return Poll::Ready(Either::Left(output));
+        }
+        if let Poll::Ready(output) = Pin::new(&mut self.right).poll(cx) {
+            return Poll::Ready(Either::Right(output));
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,277 @@
+use crate::{
+    BPTR, DosError, DosLib, LONG, MODE_NEWFILE, MODE_OLDFILE, MODE_READWRITE, NT_PROCESS,
+    OFFSET_BEGINNING, OFFSET_CURRENT, OFFSET_END, Process,
+};
+use core::ffi::CStr;
+use core::fmt;
+
+/// Mode of [`File::open()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenMode {
+    /// `MODE_OLDFILE`: opens an existing file for reading and writing
+    OldFile,
+    /// `MODE_NEWFILE`: creates a new file, replacing an existing file
+    NewFile,
+    /// `MODE_READWRITE`: opens a file for reading and writing, creating it if it doesn't
+    /// exist (V36)
+    ReadWrite,
+}
+
+impl OpenMode {
+    fn mode(self) -> LONG {
+        match self {
+            OpenMode::OldFile => MODE_OLDFILE as LONG,
+            OpenMode::NewFile => MODE_NEWFILE as LONG,
+            OpenMode::ReadWrite => MODE_READWRITE as LONG,
+        }
+    }
+}
+
+/// Position of [`File::seek()`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeekFrom {
+    /// Bytes from the start of the file (`OFFSET_BEGINNING`)
+    Start(u64),
+    /// Bytes from the end of the file (`OFFSET_END`)
+    End(i64),
+    /// Bytes from the current position (`OFFSET_CURRENT`)
+    Current(i64),
+}
+
+/// A dos.library file handle, closed with `Close()` when dropped
+///
+/// The file is read and written with the unbuffered `Read()` and `Write()`. `File`
+/// implements `core::fmt::Write`, and the `embedded_io` traits `Read`, `Write` and `Seek`
+/// with the `embedded-io` feature:
+///
+/// ```ignore
+/// use core::fmt::Write;
+///
+/// let mut file = File::open(&dos, c"ram:test.txt", OpenMode::NewFile)?;
+/// writeln!(file, "{} + {} = {}", 1, 2, 1 + 2)?;
+///
+/// if let Some(mut out) = stdout(&dos) {
+///     writeln!(out, "written")?;
+/// }
+/// ```
+///
+/// The handles of [`stdin()`], [`stdout()`] and [`stderr()`] belong to the process and they
+/// aren't closed when dropped.
+pub struct File<'d> {
+    dos: &'d DosLib,
+    handle: BPTR,
+    close_on_drop: bool,
+}
+
+impl<'d> File<'d> {
+    /// Opens the file with `Open()`
+    ///
+    /// Returns the `IoErr()` error if the file can't be opened.
+    pub fn open(dos: &'d DosLib, name: &CStr, mode: OpenMode) -> Result<Self, DosError> {
+        let handle = unsafe { DosError::check(dos, dos.Open(name.as_ptr() as _, mode.mode()))? };
+        Ok(Self {
+            dos,
+            handle,
+            close_on_drop: true,
+        })
+    }
+
+    /// Takes the ownership of a file handle, which is closed when dropped
+    ///
+    /// # Safety
+    ///
+    /// The handle must be an open file handle which isn't closed elsewhere.
+    pub unsafe fn from_raw(dos: &'d DosLib, handle: BPTR) -> Self {
+        Self {
+            dos,
+            handle,
+            close_on_drop: true,
+        }
+    }
+
+    /// Returns the file handle, which can be passed to the dos.library functions
+    pub fn as_raw(&self) -> BPTR {
+        self.handle
+    }
+
+    /// Releases the ownership of the file handle without closing it
+    pub fn into_raw(self) -> BPTR {
+        let handle = self.handle;
+        core::mem::forget(self);
+        handle
+    }
+
+    /// Borrows a handle of the process, which isn't closed when dropped
+    fn borrowed(dos: &'d DosLib, handle: BPTR) -> Option<Self> {
+        (handle != 0).then_some(Self {
+            dos,
+            handle,
+            close_on_drop: false,
+        })
+    }
+
+    /// Reads bytes with `Read()`
+    ///
+    /// Returns the number of bytes read, which is 0 at the end of the file.
+    pub fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DosError> {
+        let length = buffer.len().min(LONG::MAX as usize) as LONG;
+        let count = unsafe { self.dos.Read(self.handle, buffer.as_mut_ptr() as _, length) };
+        if count < 0 {
+            return Err(unsafe { DosError::last(self.dos) });
+        }
+        Ok(count as usize)
+    }
+
+    /// Writes bytes with `Write()`
+    ///
+    /// Returns the number of bytes written.
+    pub fn write(&mut self, buffer: &[u8]) -> Result<usize, DosError> {
+        let length = buffer.len().min(LONG::MAX as usize) as LONG;
+        let count = unsafe { self.dos.Write(self.handle, buffer.as_ptr() as _, length) };
+        if count < 0 {
+            return Err(unsafe { DosError::last(self.dos) });
+        }
+        Ok(count as usize)
+    }
+
+    /// Writes all the bytes, calling `Write()` until they have been written
+    pub fn write_all(&mut self, mut buffer: &[u8]) -> Result<(), DosError> {
+        while !buffer.is_empty() {
+            match self.write(buffer)? {
+                // nothing written: the device can't take more
+                0 => return Err(DosError::DiskFull),
+                count => buffer = &buffer[count..],
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the buffered output of `FWrite()`, `FPuts()`, etc. with `Flush()` (V36)
+    ///
+    /// `read()` and `write()` aren't buffered, so this is only needed after the buffered
+    /// functions. Does nothing with the `kickstart-v34` feature.
+    pub fn flush(&mut self) -> Result<(), DosError> {
+        #[cfg(not(feature = "kickstart-v34"))]
+        if self.dos.version() >= 36 {
+            unsafe { DosError::check(self.dos, self.dos.Flush(self.handle))? };
+        }
+        Ok(())
+    }
+
+    /// Moves the position with `Seek()` and returns the new position
+    ///
+    /// Positions and offsets larger than `LONG` return `DosError::SeekError`.
+    pub fn seek(&mut self, position: SeekFrom) -> Result<u64, DosError> {
+        let (offset, mode) = match position {
+            SeekFrom::Start(offset) => (LONG::try_from(offset), OFFSET_BEGINNING),
+            SeekFrom::End(offset) => (LONG::try_from(offset), OFFSET_END as LONG),
+            SeekFrom::Current(offset) => (LONG::try_from(offset), OFFSET_CURRENT as LONG),
+        };
+        let offset = offset.map_err(|_| DosError::SeekError)?;
+        unsafe {
+            // Seek() returns the old position
+            if self.dos.Seek(self.handle, offset, mode) < 0 {
+                return Err(DosError::last(self.dos));
+            }
+            let position = self.dos.Seek(self.handle, 0, OFFSET_CURRENT as LONG);
+            if position < 0 {
+                return Err(DosError::last(self.dos));
+            }
+            Ok(position as u64)
+        }
+    }
+}
+
+impl Drop for File<'_> {
+    fn drop(&mut self) {
+        if self.close_on_drop {
+            unsafe { self.dos.Close(self.handle) };
+        }
+    }
+}
+
+impl fmt::Write for File<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.write_all(s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+/// Returns the input of the process from `Input()`
+///
+/// Returns `None` if the process has no input, for example when started from Workbench.
+pub fn stdin(dos: &DosLib) -> Option<File<'_>> {
+    File::borrowed(dos, unsafe { dos.Input() })
+}
+
+/// Returns the output of the process from `Output()`
+///
+/// Returns `None` if the process has no output, for example when started from Workbench.
+pub fn stdout(dos: &DosLib) -> Option<File<'_>> {
+    File::borrowed(dos, unsafe { dos.Output() })
+}
+
+/// Returns the error output of the process from `ErrorOutput()` (V47)
+///
+/// Before V47, the error output is `pr_CES` of the process (V36). Returns the output of
+/// [`stdout()`] if the process has no error output, which is usual before V47, and `None` if
+/// it's called by a task which isn't a process.
+pub fn stderr(dos: &DosLib) -> Option<File<'_>> {
+    #[cfg(not(feature = "kickstart-v46"))]
+    if dos.version() >= 47 {
+        return File::borrowed(dos, unsafe { dos.ErrorOutput() }).or_else(|| stdout(dos));
+    }
+    // pr_CES was added in V36
+    if dos.version() < 36 {
+        return stdout(dos);
+    }
+    let handle = unsafe {
+        let task = crate::FindTask(crate::abs_exec_library(), core::ptr::null());
+        // a task has no pr_CES
+        if u32::from((*task).tc_Node.ln_Type) != NT_PROCESS {
+            return None;
+        }
+        (*(task as *mut Process)).pr_CES
+    };
+    File::borrowed(dos, handle).or_else(|| stdout(dos))
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::ErrorType for File<'_> {
+    type Error = DosError;
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Read for File<'_> {
+    fn read(&mut self, buffer: &mut [u8]) -> Result<usize, DosError> {
+        File::read(self, buffer)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Write for File<'_> {
+    fn write(&mut self, buffer: &[u8]) -> Result<usize, DosError> {
+        // embedded-io doesn't allow writing nothing of a non-empty buffer
+        match File::write(self, buffer)? {
+            0 if !buffer.is_empty() => Err(DosError::DiskFull),
+            written => Ok(written),
+        }
+    }
+
+    fn flush(&mut self) -> Result<(), DosError> {
+        File::flush(self)
+    }
+}
+
+#[cfg(feature = "embedded-io")]
+impl embedded_io::Seek for File<'_> {
+    fn seek(&mut self, position: embedded_io::SeekFrom) -> Result<u64, DosError> {
+        File::seek(
+            self,
+            match position {
+                embedded_io::SeekFrom::Start(offset) => SeekFrom::Start(offset),
+                embedded_io::SeekFrom::End(offset) => SeekFrom::End(offset),
+                embedded_io::SeekFrom::Current(offset) => SeekFrom::Current(offset),
+            },
+        )
+    }
+}
@@ -0,0 +1,61 @@
+/// Information about a library function
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FunctionInfo {
+    /// Function name
+    pub name: &'static str,
+    /// Library vector offset: the function is called with `jsr lvo(a6)`
+    pub lvo: i16,
+    /// Registers of the parameters, without the library base in a6
+    ///
+    /// A `DOUBLE` parameter uses two registers, for example `"d0-d1"`.
+    pub registers: &'static [&'static str],
+    /// Minimum library version, 0 if the function is available in all versions
+    pub min_version: u16,
+}
+
+/// Returns the minimum library version needed to call a function
+///
+/// For example, `function_min_version("GetCurrentDir")` returns `Some(47)`. Returns `None`
+/// if the function isn't found: only the functions of the libraries enabled with cargo
+/// features are found.
+pub fn function_min_version(name: &str) -> Option<u16> {
+    crate::find_function(name).map(|info| info.min_version)
+}
+
+/// Panics if the library is older than the minimum version
+///
+/// Called by the library functions in debug builds when the `version-check` feature is enabled.
+/// Inlined so that the library call doesn't need to keep its arguments alive across a function
+/// call: only the cold panic path calls a function.
+#[cfg(feature = "version-check")]
+#[doc(hidden)]
+#[inline(always)]
+pub unsafe fn check_library_version(
+    base: *mut crate::Library,
+    min_version: u16,
+    name: &str,
+    libname: &str,
+) {
+    if base.is_null() || unsafe { (*base).lib_Version } < min_version {
+        unsafe { library_version_panic(base, min_version, name, libname) }
+    }
+}
+
+#[cfg(feature = "version-check")]
+#[cold]
+#[inline(never)]
+unsafe fn library_version_panic(
+    base: *mut crate::Library,
+    min_version: u16,
+    name: &str,
+    libname: &str,
+) -> ! {
+    if base.is_null() {
+        panic!("{}(): {} base is null", name, libname);
+    }
+    let version = unsafe { (*base).lib_Version };
+    panic!(
+        "{}() needs {} V{}, but the library version is V{}",
+        name, libname, min_version, version
+    );
+}
@@ -0,0 +1,73 @@
+use crate::{APTR, Hook, MinNode, ULONG};
+use core::marker::PhantomPinned;
+use core::pin::Pin;
+
+/// A [`Hook`] which calls a Rust closure
+///
+/// The closure is called with the hook, the object and the message, which the system
+/// passes in registers a0, a2 and a1. `h_Entry` points to a trampoline which calls the
+/// closure and `h_Data` is null, so it can be set through the pointer to the hook.
+///
+/// The hook is pinned to get its pointer, so that it can't be moved afterwards, for example
+/// with `core::pin::pin!` or `Box::pin`:
+///
+/// ```ignore
+/// let mut hook = pin!(ClosureHook::new(|_hook, _object, message| message as ULONG + 1));
+/// let hook_ptr = hook.as_mut().as_mut_ptr();
+/// let result = unsafe { CallHookPkt(utility, hook_ptr, null_mut(), 41 as APTR) };
+/// ```
+///
+/// The hook must not be dropped while the system uses it, for example while a BOOPSI object
+/// or a layer has a pointer to it.
+#[repr(C)]
+pub struct ClosureHook<F> {
+    hook: Hook,
+    closure: F,
+    /// the system has a pointer to the hook
+    pinned: PhantomPinned,
+}
+
+impl<F: Fn(*mut Hook, APTR, APTR) -> ULONG> ClosureHook<F> {
+    /// Creates a hook which calls the closure
+    pub fn new(closure: F) -> Self {
+        let call: extern "C" fn(*mut Hook, APTR, APTR) -> ULONG = call_closure::<F>;
+        Self {
+            hook: Hook {
+                h_MinNode: MinNode {
+                    mln_Succ: core::ptr::null_mut(),
+                    mln_Pred: core::ptr::null_mut(),
+                },
+                h_Entry: hook_entry as *const () as usize,
+                h_SubEntry: call as *const () as usize,
+                h_Data: core::ptr::null_mut(),
+            },
+            closure,
+            pinned: PhantomPinned,
+        }
+    }
+
+    /// Returns a pointer to the hook, which can be passed to the system functions
+    pub fn as_mut_ptr(self: Pin<&mut Self>) -> *mut Hook {
+        // the hook isn't moved out of the pin
+        unsafe { &raw mut self.get_unchecked_mut().hook }
+    }
+}
+
+/// Calls the closure of a `ClosureHook<F>`: the hook is the first field of the struct
+extern "C" fn call_closure<F: Fn(*mut Hook, APTR, APTR) -> ULONG>(
+    hook: *mut Hook,
+    object: APTR,
+    message: APTR,
+) -> ULONG {
+    let closure = unsafe { &(*(hook as *const ClosureHook<F>)).closure };
+    closure(hook, object, message)
+}
+
+crate::amiga_callback! {
+    /// Calls `h_SubEntry` like `HookEntry` of amiga.lib
+    fn hook_entry(a0 hook: *mut Hook, a2 object: APTR, a1 message: APTR) -> ULONG {
+        let sub_entry: extern "C" fn(*mut Hook, APTR, APTR) -> ULONG =
+            unsafe { core::mem::transmute((*hook).h_SubEntry) };
+        sub_entry(hook, object, message)
+    }
+}
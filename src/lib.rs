@@ -1,10 +1,37 @@
 //! Unsafe bindings for the Amiga (m68k) system libraries.
 //!
+//! The library functions are in modules named after the library, such as [`exec`] and [`dos`].
+//! Each module is enabled by a cargo feature of the same name and all the functions are
+//! also re-exported at the crate root.
+//!
 //! Most functions take a pointer to its library as the first parameter.
 //! Call `abs_exec_library()` to get a pointer to the Exec library and call `OpenLibrary()`
 //! to open other libraries.
+//!
+//! Alternatively, libraries can be opened with library handles, such as `DosLib::open()`.
+//! The handles have the library functions as methods and they close the library when dropped:
+//!
+//! ```ignore
+//! let Some(dos) = DosLib::open(36) else { return };
+//! let out = unsafe { dos.Output() };
+//! ```
+//!
+//! With the `global-bases` feature, the `global` module has the library functions without
+//! the library base parameter. They read the library base from a global variable, such as
+//! `global::DOSBase`. With the `auto-open` feature, the libraries are opened on first use.
+//!
+//! With the `startup` feature, the crate provides the `_start` entry point, which handles
+//! Workbench start up and calls the program's `main()` with a `StartupInfo`. With the `alloc`
+//! feature, `AmigaAlloc` is a global allocator using the exec memory functions. The
+//! `panic-handler` feature provides a panic handler and `abort()`, which show the panic message
+//! and exit the program.
 
 #![feature(asm_experimental_arch)]
+// for the Allocator implementation of Pool
+#![cfg_attr(
+    all(feature = "exec", not(feature = "kickstart-v38")),
+    feature(allocator_api)
+)]
 #![allow(non_upper_case_globals)]
 #![allow(non_camel_case_types)]
 #![allow(non_snake_case)]
@@ -15,6 +42,140 @@ mod bindings;
 #[cfg(any(target_arch = "m68k", doc))]
 pub use bindings::*;
 
+#[cfg(any(target_arch = "m68k", doc))]
+mod libraries;
+#[cfg(any(target_arch = "m68k", doc))]
+#[allow(unused_imports)] // when all library features are disabled
+pub use libraries::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod function_info;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use function_info::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod amiga_lib;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use amiga_lib::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod hook;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use hook::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod tags;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use tags::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod list;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use list::*;
+
+#[cfg(any(target_arch = "m68k", doc))]
+mod cli_args;
+#[cfg(any(target_arch = "m68k", doc))]
+pub use cli_args::*;
+
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+mod dos_error;
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+pub use dos_error::*;
+
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+mod file;
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+pub use file::*;
+
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+mod dir;
+#[cfg(all(feature = "dos", any(target_arch = "m68k", doc)))]
+pub use dir::*;
+
+#[cfg(all(
+    feature = "dos",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+mod matches;
+#[cfg(all(
+    feature = "dos",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+pub use matches::*;
+
+/// The `embedded-io` crate, whose traits are implemented by [`File`]
+#[cfg(feature = "embedded-io")]
+pub use embedded_io;
+
+#[cfg(all(
+    feature = "dos",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+mod read_args;
+#[cfg(all(
+    feature = "dos",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+pub use read_args::*;
+
+#[cfg(all(feature = "exec", any(target_arch = "m68k", doc)))]
+mod chip;
+#[cfg(all(feature = "exec", any(target_arch = "m68k", doc)))]
+pub use chip::*;
+
+#[cfg(all(
+    feature = "exec",
+    not(feature = "kickstart-v38"),
+    any(target_arch = "m68k", doc)
+))]
+mod pool;
+#[cfg(all(
+    feature = "exec",
+    not(feature = "kickstart-v38"),
+    any(target_arch = "m68k", doc)
+))]
+pub use pool::*;
+
+#[cfg(all(feature = "exec", any(target_arch = "m68k", doc)))]
+mod executor;
+#[cfg(all(feature = "exec", any(target_arch = "m68k", doc)))]
+pub use executor::*;
+
+#[cfg(all(
+    feature = "timer-device",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+mod timer;
+#[cfg(all(
+    feature = "timer-device",
+    not(feature = "kickstart-v34"),
+    any(target_arch = "m68k", doc)
+))]
+pub use timer::*;
+
+#[cfg(all(feature = "alloc", any(target_arch = "m68k", doc)))]
+mod allocator;
+#[cfg(all(feature = "alloc", any(target_arch = "m68k", doc)))]
+pub use allocator::*;
+
+#[cfg(all(feature = "startup", any(target_arch = "m68k", doc)))]
+mod startup;
+#[cfg(all(feature = "startup", any(target_arch = "m68k", doc)))]
+pub use startup::*;
+
+#[cfg(all(feature = "panic-handler", target_arch = "m68k"))]
+mod panic_handler;
+
+/// Typed tags grouped by the function or class which accepts them, for use with [`tags!`]
+#[cfg(any(target_arch = "m68k", doc))]
+pub mod typed_tags;
+
 #[cfg(any(target_arch = "m68k", doc))]
 mod constants;
 #[cfg(any(target_arch = "m68k", doc))]
@@ -29,19 +190,26 @@ pub unsafe fn abs_exec_library() -> *mut Library {
 // ensure structs have correct sizes: these sizes have been taken from NDK3.1 STRUCTURE.OFFSETS
 #[cfg(any(target_arch = "m68k", doc))]
 const _: () = {
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AChain"][::core::mem::size_of::<AChain>() - 274usize];
     ["Size of AmigaGuideHost"][::core::mem::size_of::<AmigaGuideHost>() - 40usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of AmigaGuideMsg"][::core::mem::size_of::<AmigaGuideMsg>() - 52usize];
     ["Size of AnalogSignalInterval"][::core::mem::size_of::<AnalogSignalInterval>() - 4usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AnchorPath"][::core::mem::size_of::<AnchorPath>() - 282usize];
     ["Size of AnimComp"][::core::mem::size_of::<AnimComp>() - 38usize];
     ["Size of AnimHeader"][::core::mem::size_of::<AnimHeader>() - 40usize];
     ["Size of AnimOb"][::core::mem::size_of::<AnimOb>() - 42usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AppIcon"][::core::mem::size_of::<AppIcon>() - 4usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AppMenuItem"][::core::mem::size_of::<AppMenuItem>() - 4usize];
     ["Size of AppMessage"][::core::mem::size_of::<AppMessage>() - 86usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AppWindow"][::core::mem::size_of::<AppWindow>() - 4usize];
     ["Size of AreaInfo"][::core::mem::size_of::<AreaInfo>() - 24usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of AssignList"][::core::mem::size_of::<AssignList>() - 8usize];
     //["Size of AudChannel"][::core::mem::size_of::<AudChannel>() - 16usize]; // not found in NDK3.2
     ["Size of AvailFonts"][::core::mem::size_of::<AvailFonts>() - 10usize];
@@ -50,6 +218,7 @@ const _: () = {
     ["Size of BadBlockEntry"][::core::mem::size_of::<BadBlockEntry>() - 8usize];
     ["Size of BitMap"][::core::mem::size_of::<BitMap>() - 40usize];
     ["Size of BitMapHeader"][::core::mem::size_of::<BitMapHeader>() - 20usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of BitScaleArgs"][::core::mem::size_of::<BitScaleArgs>() - 48usize];
     ["Size of Bob"][::core::mem::size_of::<Bob>() - 32usize];
     ["Size of BoolInfo"][::core::mem::size_of::<BoolInfo>() - 10usize];
@@ -59,49 +228,69 @@ const _: () = {
     ["Size of CDInfo"][::core::mem::size_of::<CDInfo>() - 34usize];
     ["Size of CDXL"][::core::mem::size_of::<CDXL>() - 28usize];
     ["Size of CIA"][::core::mem::size_of::<CIA>() - 3842usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of CSource"][::core::mem::size_of::<CSource>() - 12usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of CardHandle"][::core::mem::size_of::<CardHandle>() - 28usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of CardMemoryMap"][::core::mem::size_of::<CardMemoryMap>() - 24usize];
+    #[cfg(not(feature = "kickstart-v37"))]
     ["Size of Catalog"][::core::mem::size_of::<Catalog>() - 28usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of CliProcList"][::core::mem::size_of::<CliProcList>() - 16usize];
     ["Size of ClipHookMsg"][::core::mem::size_of::<ClipHookMsg>() - 12usize];
     ["Size of ClipRect"][::core::mem::size_of::<ClipRect>() - 36usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ClipboardHandle"][::core::mem::size_of::<ClipboardHandle>() - 120usize];
     ["Size of ClipboardUnitPartial"][::core::mem::size_of::<ClipboardUnitPartial>() - 18usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ClockData"][::core::mem::size_of::<ClockData>() - 14usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of CollectionItem"][::core::mem::size_of::<CollectionItem>() - 12usize];
     ["Size of ColorFontColors"][::core::mem::size_of::<ColorFontColors>() - 8usize];
     ["Size of ColorMap"][::core::mem::size_of::<ColorMap>() - 52usize];
+    #[cfg(not(feature = "kickstart-v40"))]
     ["Size of ColorRegister"][::core::mem::size_of::<ColorRegister>() - 4usize];
     ["Size of ColorSpec"][::core::mem::size_of::<ColorSpec>() - 8usize];
     ["Size of ColorTextFont"][::core::mem::size_of::<ColorTextFont>() - 96usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of ColorWheelHSB"][::core::mem::size_of::<ColorWheelHSB>() - 12usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of ColorWheelRGB"][::core::mem::size_of::<ColorWheelRGB>() - 12usize];
     ["Size of CommandLineInterface"][::core::mem::size_of::<CommandLineInterface>() - 64usize];
     ["Size of ConUnit"][::core::mem::size_of::<ConUnit>() - 296usize];
+    #[cfg(not(feature = "kickstart-v36"))]
     ["Size of Conductor"][::core::mem::size_of::<Conductor>() - 54usize];
     ["Size of ConfigDev"][::core::mem::size_of::<ConfigDev>() - 68usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ContextNode"][::core::mem::size_of::<ContextNode>() - 24usize];
     ["Size of CopIns"][::core::mem::size_of::<CopIns>() - 6usize];
     ["Size of CopList"][::core::mem::size_of::<CopList>() - 38usize];
     ["Size of CountryPrefs"][::core::mem::size_of::<CountryPrefs>() - 504usize];
     ["Size of CurrentBinding"][::core::mem::size_of::<CurrentBinding>() - 16usize];
     ["Size of Custom"][::core::mem::size_of::<Custom>() - 510usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of DBufInfo"][::core::mem::size_of::<DBufInfo>() - 84usize];
     ["Size of DBufPacket"][::core::mem::size_of::<DBufPacket>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DTHookContext"][::core::mem::size_of::<DTHookContext>() - 40usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of DTMethod"][::core::mem::size_of::<DTMethod>() - 12usize];
     ["Size of DTSpecialInfo"][::core::mem::size_of::<DTSpecialInfo>() - 90usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of DataType"][::core::mem::size_of::<DataType>() - 58usize];
     ["Size of DataTypeHeader"][::core::mem::size_of::<DataTypeHeader>() - 32usize];
     ["Size of DateStamp"][::core::mem::size_of::<DateStamp>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DateTime"][::core::mem::size_of::<DateTime>() - 26usize];
     ["Size of DevInfo"][::core::mem::size_of::<DevInfo>() - 44usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DevProc"][::core::mem::size_of::<DevProc>() - 16usize];
     ["Size of Device"][::core::mem::size_of::<Device>() - 34usize];
     ["Size of DeviceData"][::core::mem::size_of::<DeviceData>() - 52usize];
     ["Size of DeviceList"][::core::mem::size_of::<DeviceList>() - 44usize];
     ["Size of DeviceNode"][::core::mem::size_of::<DeviceNode>() - 44usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of DeviceTData"][::core::mem::size_of::<DeviceTData>() - 10usize];
     ["Size of DiagArea"][::core::mem::size_of::<DiagArea>() - 14usize];
     ["Size of DimensionInfo"][::core::mem::size_of::<DimensionInfo>() - 88usize];
@@ -109,20 +298,27 @@ const _: () = {
     ["Size of DiscResourceUnit"][::core::mem::size_of::<DiscResourceUnit>() - 86usize];
     ["Size of DiskFontHeader"][::core::mem::size_of::<DiskFontHeader>() - 106usize];
     ["Size of DiskObject"][::core::mem::size_of::<DiskObject>() - 78usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DisplayInfo"][::core::mem::size_of::<DisplayInfo>() - 56usize];
     ["Size of DisplayMode"][::core::mem::size_of::<DisplayMode>() - 106usize];
     ["Size of DosEnvec"][::core::mem::size_of::<DosEnvec>() - 80usize];
     ["Size of DosInfo"][::core::mem::size_of::<DosInfo>() - 158usize];
     ["Size of DosLibrary"][::core::mem::size_of::<DosLibrary>() - 70usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DosList"][::core::mem::size_of::<DosList>() - 44usize];
     ["Size of DosPacket"][::core::mem::size_of::<DosPacket>() - 48usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of DrawInfo"][::core::mem::size_of::<DrawInfo>() - 50usize];
     ["Size of DrawerData"][::core::mem::size_of::<DrawerData>() - 62usize];
     ["Size of DriveGeometry"][::core::mem::size_of::<DriveGeometry>() - 32usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of EClockVal"][::core::mem::size_of::<EClockVal>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of EasyStruct"][::core::mem::size_of::<EasyStruct>() - 20usize];
     ["Size of ErrorString"][::core::mem::size_of::<ErrorString>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ExAllControl"][::core::mem::size_of::<ExAllControl>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ExAllData"][::core::mem::size_of::<ExAllData>() - 40usize];
     ["Size of ExecBase"][::core::mem::size_of::<ExecBase>() - 632usize];
     ["Size of ExpansionBase"][::core::mem::size_of::<ExpansionBase>() - 88usize];
@@ -132,11 +328,13 @@ const _: () = {
     ["Size of ExtIntuiMessage"][::core::mem::size_of::<ExtIntuiMessage>() - 56usize];
     ["Size of ExtNewScreen"][::core::mem::size_of::<ExtNewScreen>() - 36usize];
     ["Size of ExtNewWindow"][::core::mem::size_of::<ExtNewWindow>() - 52usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of ExtSprite"][::core::mem::size_of::<ExtSprite>() - 16usize];
     ["Size of ExtendedNode"][::core::mem::size_of::<ExtendedNode>() - 24usize];
     ["Size of FileHandle"][::core::mem::size_of::<FileHandle>() - 44usize];
     ["Size of FileInfoBlock"][::core::mem::size_of::<FileInfoBlock>() - 260usize];
     ["Size of FileLock"][::core::mem::size_of::<FileLock>() - 20usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of FileRequester"][::core::mem::size_of::<FileRequester>() - 56usize];
     ["Size of FileSysEntry"][::core::mem::size_of::<FileSysEntry>() - 62usize];
     ["Size of FileSysHeaderBlock"][::core::mem::size_of::<FileSysHeaderBlock>() - 256usize];
@@ -149,10 +347,12 @@ const _: () = {
     ["Size of FrameInfo"][::core::mem::size_of::<FrameInfo>() - 36usize];
     ["Size of FreeList"][::core::mem::size_of::<FreeList>() - 16usize];
     ["Size of Gadget"][::core::mem::size_of::<Gadget>() - 44usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of GadgetInfo"][::core::mem::size_of::<GadgetInfo>() - 58usize];
     ["Size of GamePortTrigger"][::core::mem::size_of::<GamePortTrigger>() - 8usize];
     ["Size of GelsInfo"][::core::mem::size_of::<GelsInfo>() - 38usize];
     ["Size of GfxBase"][::core::mem::size_of::<GfxBase>() - 552usize]; // fixed for NDK3.2
+    #[cfg(not(feature = "kickstart-v37"))]
     ["Size of GlyphEngine"][::core::mem::size_of::<GlyphEngine>() - 8usize];
     ["Size of GlyphMap"][::core::mem::size_of::<GlyphMap>() - 36usize];
     ["Size of GlyphWidthEntry"][::core::mem::size_of::<GlyphWidthEntry>() - 14usize];
@@ -162,6 +362,7 @@ const _: () = {
     ["Size of IENewTablet"][::core::mem::size_of::<IENewTablet>() - 32usize];
     ["Size of IEPointerPixel"][::core::mem::size_of::<IEPointerPixel>() - 8usize];
     ["Size of IEPointerTablet"][::core::mem::size_of::<IEPointerTablet>() - 10usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of IFFHandle"][::core::mem::size_of::<IFFHandle>() - 12usize];
     ["Size of IFFStreamCmd"][::core::mem::size_of::<IFFStreamCmd>() - 12usize];
     ["Size of IOAudio"][::core::mem::size_of::<IOAudio>() - 68usize];
@@ -190,7 +391,9 @@ const _: () = {
     ["Size of KeyMap"][::core::mem::size_of::<KeyMap>() - 32usize];
     ["Size of KeyMapNode"][::core::mem::size_of::<KeyMapNode>() - 46usize];
     ["Size of KeyMapResource"][::core::mem::size_of::<KeyMapResource>() - 28usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of KeyQuery"][::core::mem::size_of::<KeyQuery>() - 4usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of LVDrawMsg"][::core::mem::size_of::<LVDrawMsg>() - 24usize];
     ["Size of Layer"][::core::mem::size_of::<Layer>() - 160usize];
     ["Size of Layer_Info"][::core::mem::size_of::<Layer_Info>() - 102usize];
@@ -198,8 +401,11 @@ const _: () = {
     ["Size of Line"][::core::mem::size_of::<Line>() - 36usize];
     ["Size of List"][::core::mem::size_of::<List>() - 14usize];
     ["Size of LoadSegBlock"][::core::mem::size_of::<LoadSegBlock>() - 512usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of LocalContextItem"][::core::mem::size_of::<LocalContextItem>() - 20usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of LocalVar"][::core::mem::size_of::<LocalVar>() - 24usize];
+    #[cfg(not(feature = "kickstart-v37"))]
     ["Size of Locale"][::core::mem::size_of::<Locale>() - 168usize];
     ["Size of LocaleBase"][::core::mem::size_of::<LocaleBase>() - 36usize];
     ["Size of LocalePrefs"][::core::mem::size_of::<LocalePrefs>() - 860usize];
@@ -215,22 +421,32 @@ const _: () = {
     ["Size of Message"][::core::mem::size_of::<Message>() - 20usize];
     ["Size of MinList"][::core::mem::size_of::<MinList>() - 12usize];
     ["Size of MinNode"][::core::mem::size_of::<MinNode>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of MonitorInfo"][::core::mem::size_of::<MonitorInfo>() - 96usize];
     ["Size of MonitorSpec"][::core::mem::size_of::<MonitorSpec>() - 160usize];
     ["Size of MsgPort"][::core::mem::size_of::<MsgPort>() - 34usize];
     ["Size of NVEntry"][::core::mem::size_of::<NVEntry>() - 20usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of NVInfo"][::core::mem::size_of::<NVInfo>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NameInfo"][::core::mem::size_of::<NameInfo>() - 56usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of NamedObject"][::core::mem::size_of::<NamedObject>() - 4usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of NewAmigaGuide"][::core::mem::size_of::<NewAmigaGuide>() - 52usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NewBroker"][::core::mem::size_of::<NewBroker>() - 26usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NewGadget"][::core::mem::size_of::<NewGadget>() - 30usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NewMenu"][::core::mem::size_of::<NewMenu>() - 20usize];
     ["Size of NewScreen"][::core::mem::size_of::<NewScreen>() - 32usize];
     ["Size of NewWindow"][::core::mem::size_of::<NewWindow>() - 48usize];
     ["Size of NexxStr"][::core::mem::size_of::<NexxStr>() - 16usize];
     ["Size of Node"][::core::mem::size_of::<Node>() - 14usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NotifyMessage"][::core::mem::size_of::<NotifyMessage>() - 38usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of NotifyRequest"][::core::mem::size_of::<NotifyRequest>() - 48usize];
     ["Size of OldDrawerData"][::core::mem::size_of::<OldDrawerData>() - 56usize];
     ["Size of OverscanPrefs"][::core::mem::size_of::<OverscanPrefs>() - 36usize];
@@ -238,6 +454,7 @@ const _: () = {
     ["Size of PaletteExtra"][::core::mem::size_of::<PaletteExtra>() - 68usize];
     ["Size of PalettePrefs"][::core::mem::size_of::<PalettePrefs>() - 400usize];
     ["Size of PartitionBlock"][::core::mem::size_of::<PartitionBlock>() - 256usize];
+    #[cfg(not(feature = "kickstart-v36"))]
     ["Size of Player"][::core::mem::size_of::<Player>() - 44usize];
     ["Size of PointerPrefs"][::core::mem::size_of::<PointerPrefs>() - 32usize];
     ["Size of PrefHeader"][::core::mem::size_of::<PrefHeader>() - 6usize];
@@ -255,12 +472,14 @@ const _: () = {
     ["Size of PubScreenNode"][::core::mem::size_of::<PubScreenNode>() - 30usize];
     ["Size of QCode"][::core::mem::size_of::<QCode>() - 12usize];
     ["Size of QueryHeader"][::core::mem::size_of::<QueryHeader>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of RDArgs"][::core::mem::size_of::<RDArgs>() - 32usize];
     ["Size of RGBTable"][::core::mem::size_of::<RGBTable>() - 4usize];
     ["Size of RMSF"][::core::mem::size_of::<RMSF>() - 4usize];
     ["Size of RasInfo"][::core::mem::size_of::<RasInfo>() - 12usize];
     ["Size of RastPort"][::core::mem::size_of::<RastPort>() - 100usize];
     ["Size of RealTimeBase"][::core::mem::size_of::<RealTimeBase>() - 48usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of RecordLock"][::core::mem::size_of::<RecordLock>() - 16usize];
     ["Size of Rect32"][::core::mem::size_of::<Rect32>() - 16usize];
     ["Size of Rectangle"][::core::mem::size_of::<Rectangle>() - 8usize];
@@ -270,6 +489,7 @@ const _: () = {
     ["Size of Requester"][::core::mem::size_of::<Requester>() - 112usize];
     ["Size of Resident"][::core::mem::size_of::<Resident>() - 26usize];
     ["Size of RexxArg"][::core::mem::size_of::<RexxArg>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of RexxMsg"][::core::mem::size_of::<RexxMsg>() - 128usize];
     ["Size of RexxMsgPort"][::core::mem::size_of::<RexxMsgPort>() - 80usize];
     ["Size of RexxRsrc"][::core::mem::size_of::<RexxRsrc>() - 32usize];
@@ -278,9 +498,11 @@ const _: () = {
     ["Size of RootNode"][::core::mem::size_of::<RootNode>() - 56usize];
     ["Size of RxsLib"][::core::mem::size_of::<RxsLib>() - 256usize]; // fixed for NDK3.2
     ["Size of SCSICmd"][::core::mem::size_of::<SCSICmd>() - 30usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of SGWork"][::core::mem::size_of::<SGWork>() - 44usize];
     ["Size of SatisfyMsg"][::core::mem::size_of::<SatisfyMsg>() - 26usize];
     ["Size of Screen"][::core::mem::size_of::<Screen>() - 346usize];
+    #[cfg(not(feature = "kickstart-v38"))]
     ["Size of ScreenBuffer"][::core::mem::size_of::<ScreenBuffer>() - 8usize];
     ["Size of ScreenModePrefs"][::core::mem::size_of::<ScreenModePrefs>() - 28usize];
     ["Size of ScreenModeRequester"][::core::mem::size_of::<ScreenModeRequester>() - 48usize];
@@ -296,8 +518,10 @@ const _: () = {
     ["Size of SpecialMonitor"][::core::mem::size_of::<SpecialMonitor>() - 58usize];
     //["Size of SpriteDef"][::core::mem::size_of::<SpriteDef>() - 8usize]; // not found in NDK3.2
     ["Size of SrcNode"][::core::mem::size_of::<SrcNode>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of StackSwapStruct"][::core::mem::size_of::<StackSwapStruct>() - 12usize];
     ["Size of StandardPacket"][::core::mem::size_of::<StandardPacket>() - 68usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of StoredProperty"][::core::mem::size_of::<StoredProperty>() - 8usize];
     ["Size of StringExtend"][::core::mem::size_of::<StringExtend>() - 36usize];
     ["Size of StringInfo"][::core::mem::size_of::<StringInfo>() - 36usize];
@@ -313,6 +537,7 @@ const _: () = {
     ["Size of TagItem"][::core::mem::size_of::<TagItem>() - 8usize];
     ["Size of Task"][::core::mem::size_of::<Task>() - 92usize];
     ["Size of TextAttr"][::core::mem::size_of::<TextAttr>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of TextExtent"][::core::mem::size_of::<TextExtent>() - 12usize];
     ["Size of TextFont"][::core::mem::size_of::<TextFont>() - 52usize];
     ["Size of TextFontExtension"][::core::mem::size_of::<TextFontExtension>() - 24usize];
@@ -323,8 +548,10 @@ const _: () = {
     ["Size of Unit"][::core::mem::size_of::<Unit>() - 38usize];
     ["Size of UtilityBase"][::core::mem::size_of::<UtilityBase>() - 36usize];
     ["Size of VSprite"][::core::mem::size_of::<VSprite>() - 60usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of VecInfo"][::core::mem::size_of::<VecInfo>() - 40usize];
     ["Size of View"][::core::mem::size_of::<View>() - 18usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of ViewExtra"][::core::mem::size_of::<ViewExtra>() - 34usize];
     ["Size of ViewPort"][::core::mem::size_of::<ViewPort>() - 40usize];
     ["Size of ViewPortExtra"][::core::mem::size_of::<ViewPortExtra>() - 66usize];
@@ -342,20 +569,34 @@ const _: () = {
     ["Size of copinit"][::core::mem::size_of::<copinit>() - 192usize];
     ["Size of cprlist"][::core::mem::size_of::<cprlist>() - 10usize];
     ["Size of dtDraw"][::core::mem::size_of::<dtDraw>() - 36usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtFrameBox"][::core::mem::size_of::<dtFrameBox>() - 24usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtGeneral"][::core::mem::size_of::<dtGeneral>() - 8usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtGoto"][::core::mem::size_of::<dtGoto>() - 16usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of dtPrint"][::core::mem::size_of::<dtPrint>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtSelect"][::core::mem::size_of::<dtSelect>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtTrigger"][::core::mem::size_of::<dtTrigger>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of dtWrite"][::core::mem::size_of::<dtWrite>() - 20usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of gpGoInactive"][::core::mem::size_of::<gpGoInactive>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of gpHitTest"][::core::mem::size_of::<gpHitTest>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of gpInput"][::core::mem::size_of::<gpInput>() - 24usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of gpLayout"][::core::mem::size_of::<gpLayout>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of gpRender"][::core::mem::size_of::<gpRender>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of impDraw"][::core::mem::size_of::<impDraw>() - 24usize];
     ["Size of impErase"][::core::mem::size_of::<impErase>() - 16usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of impFrameBox"][::core::mem::size_of::<impFrameBox>() - 20usize];
     ["Size of impHitTest"][::core::mem::size_of::<impHitTest>() - 12usize];
     ["Size of mouth_rb"][::core::mem::size_of::<mouth_rb>() - 92usize];
@@ -364,9 +605,12 @@ const _: () = {
     ["Size of opExpungeNode"][::core::mem::size_of::<opExpungeNode>() - 8usize];
     ["Size of opFindHost"][::core::mem::size_of::<opFindHost>() - 28usize];
     ["Size of opGet"][::core::mem::size_of::<opGet>() - 12usize];
+    #[cfg(not(feature = "kickstart-v39"))]
     ["Size of opMember"][::core::mem::size_of::<opMember>() - 8usize];
     ["Size of opNodeIO"][::core::mem::size_of::<opNodeIO>() - 28usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of opSet"][::core::mem::size_of::<opSet>() - 12usize];
+    #[cfg(not(feature = "kickstart-v34"))]
     ["Size of opUpdate"][::core::mem::size_of::<opUpdate>() - 16usize];
     ["Size of pmState"][::core::mem::size_of::<pmState>() - 8usize];
     ["Size of pmTime"][::core::mem::size_of::<pmTime>() - 8usize];
@@ -0,0 +1,887 @@
+//! Functions of "amigaguide.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn LockAmigaGuideBase(AmigaGuideBase: *mut Library, handle: APTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "LockAmigaGuideBase",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn UnlockAmigaGuideBase(AmigaGuideBase: *mut Library, key: LONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "UnlockAmigaGuideBase",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("d0") key,
+        );
+    }
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn OpenAmigaGuideA(
+    AmigaGuideBase: *mut Library,
+    nag: *mut NewAmigaGuide,
+    attrs: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "OpenAmigaGuideA", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") nag,
+            in("a1") attrs,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn OpenAmigaGuideAsyncA(
+    AmigaGuideBase: *mut Library,
+    nag: *mut NewAmigaGuide,
+    attrs: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "OpenAmigaGuideAsyncA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") nag,
+            in("d0") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CloseAmigaGuide(AmigaGuideBase: *mut Library, cl: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "CloseAmigaGuide", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+        );
+    }
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AmigaGuideSignal(AmigaGuideBase: *mut Library, cl: APTR) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "AmigaGuideSignal", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetAmigaGuideMsg(AmigaGuideBase: *mut Library, cl: APTR) -> *mut AmigaGuideMsg {
+    let asm_ret_value: *mut AmigaGuideMsg;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "GetAmigaGuideMsg", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ReplyAmigaGuideMsg(AmigaGuideBase: *mut Library, amsg: *mut AmigaGuideMsg) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "ReplyAmigaGuideMsg",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") amsg,
+        );
+    }
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetAmigaGuideContextA(
+    AmigaGuideBase: *mut Library,
+    cl: APTR,
+    id: ULONG,
+    attrs: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "SetAmigaGuideContextA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            in("d0") id,
+            in("d1") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SendAmigaGuideContextA(
+    AmigaGuideBase: *mut Library,
+    cl: APTR,
+    attrs: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "SendAmigaGuideContextA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            in("d0") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SendAmigaGuideCmdA(
+    AmigaGuideBase: *mut Library,
+    cl: APTR,
+    cmd: STRPTR,
+    attrs: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "SendAmigaGuideCmdA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            in("d0") cmd,
+            in("d1") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetAmigaGuideAttrsA(
+    AmigaGuideBase: *mut Library,
+    cl: APTR,
+    attrs: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "SetAmigaGuideAttrsA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") cl,
+            in("a1") attrs,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetAmigaGuideAttr(
+    AmigaGuideBase: *mut Library,
+    tag1: Tag,
+    cl: APTR,
+    storage: *mut ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "GetAmigaGuideAttr",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("d0") tag1,
+            in("a0") cl,
+            in("a1") storage,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn LoadXRef(AmigaGuideBase: *mut Library, lock: BPTR, name: STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "LoadXRef", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") lock,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ExpungeXRef(AmigaGuideBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AmigaGuideBase, 40, "ExpungeXRef", "amigaguide.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+        );
+    }
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AddAmigaGuideHostA(
+    AmigaGuideBase: *mut Library,
+    h: *mut Hook,
+    name: CONST_STRPTR,
+    attrs: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "AddAmigaGuideHostA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") h,
+            in("d0") name,
+            in("a1") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RemoveAmigaGuideHostA(
+    AmigaGuideBase: *mut Library,
+    hh: APTR,
+    attrs: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "RemoveAmigaGuideHostA",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("a0") hh,
+            in("a1") attrs,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetAmigaGuideString(AmigaGuideBase: *mut Library, id: LONG) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            AmigaGuideBase,
+            40,
+            "GetAmigaGuideString",
+            "amigaguide.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AmigaGuideBase,
+            in("d0") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "amigaguide.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct AmigaGuideLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl AmigaGuideLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"amigaguide.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "amigaguide.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn LockAmigaGuideBase(&self, handle: APTR) -> LONG {
+        unsafe { LockAmigaGuideBase(self.base.as_ptr(), handle) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn UnlockAmigaGuideBase(&self, key: LONG) {
+        unsafe { UnlockAmigaGuideBase(self.base.as_ptr(), key) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn OpenAmigaGuideA(&self, nag: *mut NewAmigaGuide, attrs: *const TagItem) -> APTR {
+        unsafe { OpenAmigaGuideA(self.base.as_ptr(), nag, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn OpenAmigaGuideAsyncA(
+        &self,
+        nag: *mut NewAmigaGuide,
+        attrs: *const TagItem,
+    ) -> APTR {
+        unsafe { OpenAmigaGuideAsyncA(self.base.as_ptr(), nag, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn CloseAmigaGuide(&self, cl: APTR) {
+        unsafe { CloseAmigaGuide(self.base.as_ptr(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AmigaGuideSignal(&self, cl: APTR) -> ULONG {
+        unsafe { AmigaGuideSignal(self.base.as_ptr(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetAmigaGuideMsg(&self, cl: APTR) -> *mut AmigaGuideMsg {
+        unsafe { GetAmigaGuideMsg(self.base.as_ptr(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ReplyAmigaGuideMsg(&self, amsg: *mut AmigaGuideMsg) {
+        unsafe { ReplyAmigaGuideMsg(self.base.as_ptr(), amsg) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SetAmigaGuideContextA(&self, cl: APTR, id: ULONG, attrs: *const TagItem) -> LONG {
+        unsafe { SetAmigaGuideContextA(self.base.as_ptr(), cl, id, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SendAmigaGuideContextA(&self, cl: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { SendAmigaGuideContextA(self.base.as_ptr(), cl, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SendAmigaGuideCmdA(&self, cl: APTR, cmd: STRPTR, attrs: *const TagItem) -> LONG {
+        unsafe { SendAmigaGuideCmdA(self.base.as_ptr(), cl, cmd, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SetAmigaGuideAttrsA(&self, cl: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { SetAmigaGuideAttrsA(self.base.as_ptr(), cl, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetAmigaGuideAttr(&self, tag1: Tag, cl: APTR, storage: *mut ULONG) -> LONG {
+        unsafe { GetAmigaGuideAttr(self.base.as_ptr(), tag1, cl, storage) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn LoadXRef(&self, lock: BPTR, name: STRPTR) -> LONG {
+        unsafe { LoadXRef(self.base.as_ptr(), lock, name) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ExpungeXRef(&self) {
+        unsafe { ExpungeXRef(self.base.as_ptr()) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AddAmigaGuideHostA(
+        &self,
+        h: *mut Hook,
+        name: CONST_STRPTR,
+        attrs: *const TagItem,
+    ) -> APTR {
+        unsafe { AddAmigaGuideHostA(self.base.as_ptr(), h, name, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RemoveAmigaGuideHostA(&self, hh: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { RemoveAmigaGuideHostA(self.base.as_ptr(), hh, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetAmigaGuideString(&self, id: LONG) -> STRPTR {
+        unsafe { GetAmigaGuideString(self.base.as_ptr(), id) }
+    }
+}
+
+impl Drop for AmigaGuideLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `LockAmigaGuideBase()`
+pub const LVO_LockAmigaGuideBase: i16 = -36;
+/// Library vector offset of `UnlockAmigaGuideBase()`
+pub const LVO_UnlockAmigaGuideBase: i16 = -42;
+/// Library vector offset of `OpenAmigaGuideA()`
+pub const LVO_OpenAmigaGuideA: i16 = -54;
+/// Library vector offset of `OpenAmigaGuideAsyncA()`
+pub const LVO_OpenAmigaGuideAsyncA: i16 = -60;
+/// Library vector offset of `CloseAmigaGuide()`
+pub const LVO_CloseAmigaGuide: i16 = -66;
+/// Library vector offset of `AmigaGuideSignal()`
+pub const LVO_AmigaGuideSignal: i16 = -72;
+/// Library vector offset of `GetAmigaGuideMsg()`
+pub const LVO_GetAmigaGuideMsg: i16 = -78;
+/// Library vector offset of `ReplyAmigaGuideMsg()`
+pub const LVO_ReplyAmigaGuideMsg: i16 = -84;
+/// Library vector offset of `SetAmigaGuideContextA()`
+pub const LVO_SetAmigaGuideContextA: i16 = -90;
+/// Library vector offset of `SendAmigaGuideContextA()`
+pub const LVO_SendAmigaGuideContextA: i16 = -96;
+/// Library vector offset of `SendAmigaGuideCmdA()`
+pub const LVO_SendAmigaGuideCmdA: i16 = -102;
+/// Library vector offset of `SetAmigaGuideAttrsA()`
+pub const LVO_SetAmigaGuideAttrsA: i16 = -108;
+/// Library vector offset of `GetAmigaGuideAttr()`
+pub const LVO_GetAmigaGuideAttr: i16 = -114;
+/// Library vector offset of `LoadXRef()`
+pub const LVO_LoadXRef: i16 = -126;
+/// Library vector offset of `ExpungeXRef()`
+pub const LVO_ExpungeXRef: i16 = -132;
+/// Library vector offset of `AddAmigaGuideHostA()`
+pub const LVO_AddAmigaGuideHostA: i16 = -138;
+/// Library vector offset of `RemoveAmigaGuideHostA()`
+pub const LVO_RemoveAmigaGuideHostA: i16 = -144;
+/// Library vector offset of `GetAmigaGuideString()`
+pub const LVO_GetAmigaGuideString: i16 = -210;
+
+/// Functions of "amigaguide.library"
+pub static AMIGAGUIDE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "LockAmigaGuideBase",
+        lvo: LVO_LockAmigaGuideBase,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "UnlockAmigaGuideBase",
+        lvo: LVO_UnlockAmigaGuideBase,
+        registers: &["d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "OpenAmigaGuideA",
+        lvo: LVO_OpenAmigaGuideA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "OpenAmigaGuideAsyncA",
+        lvo: LVO_OpenAmigaGuideAsyncA,
+        registers: &["a0", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CloseAmigaGuide",
+        lvo: LVO_CloseAmigaGuide,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AmigaGuideSignal",
+        lvo: LVO_AmigaGuideSignal,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetAmigaGuideMsg",
+        lvo: LVO_GetAmigaGuideMsg,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ReplyAmigaGuideMsg",
+        lvo: LVO_ReplyAmigaGuideMsg,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetAmigaGuideContextA",
+        lvo: LVO_SetAmigaGuideContextA,
+        registers: &["a0", "d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SendAmigaGuideContextA",
+        lvo: LVO_SendAmigaGuideContextA,
+        registers: &["a0", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SendAmigaGuideCmdA",
+        lvo: LVO_SendAmigaGuideCmdA,
+        registers: &["a0", "d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetAmigaGuideAttrsA",
+        lvo: LVO_SetAmigaGuideAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetAmigaGuideAttr",
+        lvo: LVO_GetAmigaGuideAttr,
+        registers: &["d0", "a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "LoadXRef",
+        lvo: LVO_LoadXRef,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ExpungeXRef",
+        lvo: LVO_ExpungeXRef,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AddAmigaGuideHostA",
+        lvo: LVO_AddAmigaGuideHostA,
+        registers: &["a0", "d0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RemoveAmigaGuideHostA",
+        lvo: LVO_RemoveAmigaGuideHostA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetAmigaGuideString",
+        lvo: LVO_GetAmigaGuideString,
+        registers: &["d0"],
+        min_version: 40,
+    },
+];
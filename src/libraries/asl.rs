@@ -0,0 +1,380 @@
+//! Functions of "asl.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocFileRequest(AslBase: *mut Library) -> *mut FileRequester {
+    let asm_ret_value: *mut FileRequester;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "AllocFileRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeFileRequest(AslBase: *mut Library, fileReq: *mut FileRequester) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "FreeFileRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") fileReq,
+        );
+    }
+}
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RequestFile(AslBase: *mut Library, fileReq: *mut FileRequester) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "RequestFile", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") fileReq,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocAslRequest(
+    AslBase: *mut Library,
+    reqType: ULONG,
+    tagList: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "AllocAslRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("d0") reqType,
+            in("a0") tagList,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeAslRequest(AslBase: *mut Library, requester: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "FreeAslRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") requester,
+        );
+    }
+}
+
+/// libname: "asl.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AslRequest(AslBase: *mut Library, requester: APTR, tagList: *const TagItem) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 36, "AslRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") requester,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn AbortAslRequest(AslBase: *mut Library, requester: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 44, "AbortAslRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") requester,
+        );
+    }
+}
+
+/// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn ActivateAslRequest(AslBase: *mut Library, requester: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(AslBase, 44, "ActivateAslRequest", "asl.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) AslBase,
+            in("a0") requester,
+        );
+    }
+}
+
+/// An open "asl.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct AslLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl AslLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"asl.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "asl.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocFileRequest(&self) -> *mut FileRequester {
+        unsafe { AllocFileRequest(self.base.as_ptr()) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeFileRequest(&self, fileReq: *mut FileRequester) {
+        unsafe { FreeFileRequest(self.base.as_ptr(), fileReq) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RequestFile(&self, fileReq: *mut FileRequester) -> BOOL {
+        unsafe { RequestFile(self.base.as_ptr(), fileReq) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocAslRequest(&self, reqType: ULONG, tagList: *const TagItem) -> APTR {
+        unsafe { AllocAslRequest(self.base.as_ptr(), reqType, tagList) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeAslRequest(&self, requester: APTR) {
+        unsafe { FreeAslRequest(self.base.as_ptr(), requester) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AslRequest(&self, requester: APTR, tagList: *const TagItem) -> BOOL {
+        unsafe { AslRequest(self.base.as_ptr(), requester, tagList) }
+    }
+
+    /// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn AbortAslRequest(&self, requester: APTR) {
+        unsafe { AbortAslRequest(self.base.as_ptr(), requester) }
+    }
+
+    /// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn ActivateAslRequest(&self, requester: APTR) {
+        unsafe { ActivateAslRequest(self.base.as_ptr(), requester) }
+    }
+}
+
+impl Drop for AslLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `AllocFileRequest()`
+pub const LVO_AllocFileRequest: i16 = -30;
+/// Library vector offset of `FreeFileRequest()`
+pub const LVO_FreeFileRequest: i16 = -36;
+/// Library vector offset of `RequestFile()`
+pub const LVO_RequestFile: i16 = -42;
+/// Library vector offset of `AllocAslRequest()`
+pub const LVO_AllocAslRequest: i16 = -48;
+/// Library vector offset of `FreeAslRequest()`
+pub const LVO_FreeAslRequest: i16 = -54;
+/// Library vector offset of `AslRequest()`
+pub const LVO_AslRequest: i16 = -60;
+/// Library vector offset of `AbortAslRequest()`
+pub const LVO_AbortAslRequest: i16 = -78;
+/// Library vector offset of `ActivateAslRequest()`
+pub const LVO_ActivateAslRequest: i16 = -84;
+
+/// Functions of "asl.library"
+pub static ASL_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AllocFileRequest",
+        lvo: LVO_AllocFileRequest,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeFileRequest",
+        lvo: LVO_FreeFileRequest,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RequestFile",
+        lvo: LVO_RequestFile,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocAslRequest",
+        lvo: LVO_AllocAslRequest,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeAslRequest",
+        lvo: LVO_FreeAslRequest,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AslRequest",
+        lvo: LVO_AslRequest,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AbortAslRequest",
+        lvo: LVO_AbortAslRequest,
+        registers: &["a0"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "ActivateAslRequest",
+        lvo: LVO_ActivateAslRequest,
+        registers: &["a0"],
+        min_version: 44,
+    },
+];
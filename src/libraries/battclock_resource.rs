@@ -0,0 +1,92 @@
+//! Functions of "battclock.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "battclock.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ResetBattClock(BattClockBase: *mut ::core::ffi::c_void) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) BattClockBase,
+        );
+    }
+}
+
+/// libname: "battclock.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadBattClock(BattClockBase: *mut ::core::ffi::c_void) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BattClockBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "battclock.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WriteBattClock(BattClockBase: *mut ::core::ffi::c_void, time: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) BattClockBase,
+            in("d0") time,
+        );
+    }
+}
+
+/// Library vector offset of `ResetBattClock()`
+pub const LVO_ResetBattClock: i16 = -6;
+/// Library vector offset of `ReadBattClock()`
+pub const LVO_ReadBattClock: i16 = -12;
+/// Library vector offset of `WriteBattClock()`
+pub const LVO_WriteBattClock: i16 = -18;
+
+/// Functions of "battclock.resource"
+pub static BATTCLOCK_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "ResetBattClock",
+        lvo: LVO_ResetBattClock,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadBattClock",
+        lvo: LVO_ReadBattClock,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WriteBattClock",
+        lvo: LVO_WriteBattClock,
+        registers: &["d0"],
+        min_version: 36,
+    },
+];
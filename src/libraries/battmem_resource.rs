@@ -0,0 +1,135 @@
+//! Functions of "battmem.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "battmem.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ObtainBattSemaphore(BattMemBase: *mut ::core::ffi::c_void) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) BattMemBase,
+        );
+    }
+}
+
+/// libname: "battmem.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReleaseBattSemaphore(BattMemBase: *mut ::core::ffi::c_void) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) BattMemBase,
+        );
+    }
+}
+
+/// libname: "battmem.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadBattMem(
+    BattMemBase: *mut ::core::ffi::c_void,
+    buffer: APTR,
+    offset: ULONG,
+    length: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BattMemBase,
+            in("a0") buffer,
+            in("d0") offset,
+            in("d1") length,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "battmem.resource" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WriteBattMem(
+    BattMemBase: *mut ::core::ffi::c_void,
+    buffer: CONST_APTR,
+    offset: ULONG,
+    length: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-24, %a6)
+            ".short -24",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BattMemBase,
+            in("a0") buffer,
+            in("d0") offset,
+            in("d1") length,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `ObtainBattSemaphore()`
+pub const LVO_ObtainBattSemaphore: i16 = -6;
+/// Library vector offset of `ReleaseBattSemaphore()`
+pub const LVO_ReleaseBattSemaphore: i16 = -12;
+/// Library vector offset of `ReadBattMem()`
+pub const LVO_ReadBattMem: i16 = -18;
+/// Library vector offset of `WriteBattMem()`
+pub const LVO_WriteBattMem: i16 = -24;
+
+/// Functions of "battmem.resource"
+pub static BATTMEM_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "ObtainBattSemaphore",
+        lvo: LVO_ObtainBattSemaphore,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseBattSemaphore",
+        lvo: LVO_ReleaseBattSemaphore,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadBattMem",
+        lvo: LVO_ReadBattMem,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WriteBattMem",
+        lvo: LVO_WriteBattMem,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+];
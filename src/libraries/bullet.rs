@@ -0,0 +1,289 @@
+//! Functions of "bullet.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "bullet.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn OpenEngine(BulletBase: *mut Library) -> *mut GlyphEngine {
+    let asm_ret_value: *mut GlyphEngine;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(BulletBase, 38, "OpenEngine", "bullet.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BulletBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "bullet.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn CloseEngine(BulletBase: *mut Library, glyphEngine: *mut GlyphEngine) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(BulletBase, 38, "CloseEngine", "bullet.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) BulletBase,
+            in("a0") glyphEngine,
+        );
+    }
+}
+
+/// libname: "bullet.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn SetInfoA(
+    BulletBase: *mut Library,
+    glyphEngine: *mut GlyphEngine,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(BulletBase, 38, "SetInfoA", "bullet.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BulletBase,
+            in("a0") glyphEngine,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "bullet.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn ObtainInfoA(
+    BulletBase: *mut Library,
+    glyphEngine: *mut GlyphEngine,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(BulletBase, 38, "ObtainInfoA", "bullet.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BulletBase,
+            in("a0") glyphEngine,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "bullet.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn ReleaseInfoA(
+    BulletBase: *mut Library,
+    glyphEngine: *mut GlyphEngine,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(BulletBase, 38, "ReleaseInfoA", "bullet.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) BulletBase,
+            in("a0") glyphEngine,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "bullet.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct BulletLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl BulletLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"bullet.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "bullet.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn OpenEngine(&self) -> *mut GlyphEngine {
+        unsafe { OpenEngine(self.base.as_ptr()) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn CloseEngine(&self, glyphEngine: *mut GlyphEngine) {
+        unsafe { CloseEngine(self.base.as_ptr(), glyphEngine) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn SetInfoA(&self, glyphEngine: *mut GlyphEngine, tagList: *const TagItem) -> ULONG {
+        unsafe { SetInfoA(self.base.as_ptr(), glyphEngine, tagList) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn ObtainInfoA(
+        &self,
+        glyphEngine: *mut GlyphEngine,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { ObtainInfoA(self.base.as_ptr(), glyphEngine, tagList) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn ReleaseInfoA(
+        &self,
+        glyphEngine: *mut GlyphEngine,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { ReleaseInfoA(self.base.as_ptr(), glyphEngine, tagList) }
+    }
+}
+
+impl Drop for BulletLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `OpenEngine()`
+pub const LVO_OpenEngine: i16 = -30;
+/// Library vector offset of `CloseEngine()`
+pub const LVO_CloseEngine: i16 = -36;
+/// Library vector offset of `SetInfoA()`
+pub const LVO_SetInfoA: i16 = -42;
+/// Library vector offset of `ObtainInfoA()`
+pub const LVO_ObtainInfoA: i16 = -48;
+/// Library vector offset of `ReleaseInfoA()`
+pub const LVO_ReleaseInfoA: i16 = -54;
+
+/// Functions of "bullet.library"
+pub static BULLET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "OpenEngine",
+        lvo: LVO_OpenEngine,
+        registers: &[],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "CloseEngine",
+        lvo: LVO_CloseEngine,
+        registers: &["a0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "SetInfoA",
+        lvo: LVO_SetInfoA,
+        registers: &["a0", "a1"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "ObtainInfoA",
+        lvo: LVO_ObtainInfoA,
+        registers: &["a0", "a1"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseInfoA",
+        lvo: LVO_ReleaseInfoA,
+        registers: &["a0", "a1"],
+        min_version: 38,
+    },
+];
@@ -0,0 +1,552 @@
+//! Functions of "card.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn OwnCard(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+) -> *mut CardHandle {
+    let asm_ret_value: *mut CardHandle;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ReleaseCard(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    flags: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("d0") flags,
+        );
+    }
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetCardMap(CardResource: *mut ::core::ffi::c_void) -> *mut CardMemoryMap {
+    let asm_ret_value: *mut CardMemoryMap;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn BeginCardAccess(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-24, %a6)
+            ".short -24",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn EndCardAccess(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ReadCardStatus(CardResource: *mut ::core::ffi::c_void) -> UBYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardResetRemove(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    flag: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("d0") flag,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardMiscControl(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    control_bits: ULONG,
+) -> UBYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("d1") control_bits,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardAccessSpeed(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    nanoseconds: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("d0") nanoseconds,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardProgramVoltage(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    voltage: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("d0") voltage,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardResetCard(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CopyTuple(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+    buffer: *mut UBYTE,
+    tuplecode: ULONG,
+    size: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a1") handle,
+            in("a0") buffer,
+            in("d1") tuplecode,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DeviceTuple(
+    CardResource: *mut ::core::ffi::c_void,
+    tuple_data: *const UBYTE,
+    storage: *mut DeviceTData,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a0") tuple_data,
+            in("a1") storage,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn IfAmigaXIP(
+    CardResource: *mut ::core::ffi::c_void,
+    handle: *mut CardHandle,
+) -> *mut Resident {
+    let asm_ret_value: *mut Resident;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            in("a2") handle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardForceChange(CardResource: *mut ::core::ffi::c_void) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardChangeCount(CardResource: *mut ::core::ffi::c_void) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "card.resource" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CardInterface(CardResource: *mut ::core::ffi::c_void) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CardResource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `OwnCard()`
+pub const LVO_OwnCard: i16 = -6;
+/// Library vector offset of `ReleaseCard()`
+pub const LVO_ReleaseCard: i16 = -12;
+/// Library vector offset of `GetCardMap()`
+pub const LVO_GetCardMap: i16 = -18;
+/// Library vector offset of `BeginCardAccess()`
+pub const LVO_BeginCardAccess: i16 = -24;
+/// Library vector offset of `EndCardAccess()`
+pub const LVO_EndCardAccess: i16 = -30;
+/// Library vector offset of `ReadCardStatus()`
+pub const LVO_ReadCardStatus: i16 = -36;
+/// Library vector offset of `CardResetRemove()`
+pub const LVO_CardResetRemove: i16 = -42;
+/// Library vector offset of `CardMiscControl()`
+pub const LVO_CardMiscControl: i16 = -48;
+/// Library vector offset of `CardAccessSpeed()`
+pub const LVO_CardAccessSpeed: i16 = -54;
+/// Library vector offset of `CardProgramVoltage()`
+pub const LVO_CardProgramVoltage: i16 = -60;
+/// Library vector offset of `CardResetCard()`
+pub const LVO_CardResetCard: i16 = -66;
+/// Library vector offset of `CopyTuple()`
+pub const LVO_CopyTuple: i16 = -72;
+/// Library vector offset of `DeviceTuple()`
+pub const LVO_DeviceTuple: i16 = -78;
+/// Library vector offset of `IfAmigaXIP()`
+pub const LVO_IfAmigaXIP: i16 = -84;
+/// Library vector offset of `CardForceChange()`
+pub const LVO_CardForceChange: i16 = -90;
+/// Library vector offset of `CardChangeCount()`
+pub const LVO_CardChangeCount: i16 = -96;
+/// Library vector offset of `CardInterface()`
+pub const LVO_CardInterface: i16 = -102;
+
+/// Functions of "card.resource"
+pub static CARD_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "OwnCard",
+        lvo: LVO_OwnCard,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseCard",
+        lvo: LVO_ReleaseCard,
+        registers: &["a1", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetCardMap",
+        lvo: LVO_GetCardMap,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "BeginCardAccess",
+        lvo: LVO_BeginCardAccess,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "EndCardAccess",
+        lvo: LVO_EndCardAccess,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ReadCardStatus",
+        lvo: LVO_ReadCardStatus,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardResetRemove",
+        lvo: LVO_CardResetRemove,
+        registers: &["a1", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardMiscControl",
+        lvo: LVO_CardMiscControl,
+        registers: &["a1", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardAccessSpeed",
+        lvo: LVO_CardAccessSpeed,
+        registers: &["a1", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardProgramVoltage",
+        lvo: LVO_CardProgramVoltage,
+        registers: &["a1", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardResetCard",
+        lvo: LVO_CardResetCard,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CopyTuple",
+        lvo: LVO_CopyTuple,
+        registers: &["a1", "a0", "d1", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "DeviceTuple",
+        lvo: LVO_DeviceTuple,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "IfAmigaXIP",
+        lvo: LVO_IfAmigaXIP,
+        registers: &["a2"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardForceChange",
+        lvo: LVO_CardForceChange,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardChangeCount",
+        lvo: LVO_CardChangeCount,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "CardInterface",
+        lvo: LVO_CardInterface,
+        registers: &[],
+        min_version: 40,
+    },
+];
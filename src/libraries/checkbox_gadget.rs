@@ -0,0 +1,48 @@
+//! Functions of "checkbox.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "checkbox.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CHECKBOX_GetClass(CheckBoxBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            CheckBoxBase as *mut Library,
+            40,
+            "CHECKBOX_GetClass",
+            "checkbox.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CheckBoxBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `CHECKBOX_GetClass()`
+pub const LVO_CHECKBOX_GetClass: i16 = -30;
+
+/// Functions of "checkbox.gadget"
+pub static CHECKBOX_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[crate::FunctionInfo {
+    name: "CHECKBOX_GetClass",
+    lvo: LVO_CHECKBOX_GetClass,
+    registers: &[],
+    min_version: 40,
+}];
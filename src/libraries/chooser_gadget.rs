@@ -0,0 +1,289 @@
+//! Functions of "chooser.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CHOOSER_GetClass(ChooserBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "CHOOSER_GetClass",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AllocChooserNodeA(
+    ChooserBase: *mut ::core::ffi::c_void,
+    tags: *mut TagItem,
+) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "AllocChooserNodeA",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeChooserNode(ChooserBase: *mut ::core::ffi::c_void, node: *mut Node) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "FreeChooserNode",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetChooserNodeAttrsA(
+    ChooserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "SetChooserNodeAttrsA",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetChooserNodeAttrsA(
+    ChooserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "GetChooserNodeAttrsA",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ShowChooser(
+    ChooserBase: *mut ::core::ffi::c_void,
+    o: *mut Object,
+    w: *mut Window,
+    xpos: ULONG,
+    ypos: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "ShowChooser",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") o,
+            in("a1") w,
+            in("d0") xpos,
+            in("d1") ypos,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn HideChooser(ChooserBase: *mut ::core::ffi::c_void, o: *mut Object, w: *mut Window) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ChooserBase as *mut Library,
+            40,
+            "HideChooser",
+            "chooser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ChooserBase,
+            in("a0") o,
+            in("a1") w,
+        );
+    }
+}
+
+/// Library vector offset of `CHOOSER_GetClass()`
+pub const LVO_CHOOSER_GetClass: i16 = -30;
+/// Library vector offset of `AllocChooserNodeA()`
+pub const LVO_AllocChooserNodeA: i16 = -36;
+/// Library vector offset of `FreeChooserNode()`
+pub const LVO_FreeChooserNode: i16 = -42;
+/// Library vector offset of `SetChooserNodeAttrsA()`
+pub const LVO_SetChooserNodeAttrsA: i16 = -48;
+/// Library vector offset of `GetChooserNodeAttrsA()`
+pub const LVO_GetChooserNodeAttrsA: i16 = -54;
+/// Library vector offset of `ShowChooser()`
+pub const LVO_ShowChooser: i16 = -60;
+/// Library vector offset of `HideChooser()`
+pub const LVO_HideChooser: i16 = -66;
+
+/// Functions of "chooser.gadget"
+pub static CHOOSER_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CHOOSER_GetClass",
+        lvo: LVO_CHOOSER_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocChooserNodeA",
+        lvo: LVO_AllocChooserNodeA,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeChooserNode",
+        lvo: LVO_FreeChooserNode,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetChooserNodeAttrsA",
+        lvo: LVO_SetChooserNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetChooserNodeAttrsA",
+        lvo: LVO_GetChooserNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ShowChooser",
+        lvo: LVO_ShowChooser,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "HideChooser",
+        lvo: LVO_HideChooser,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+];
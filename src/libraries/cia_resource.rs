@@ -0,0 +1,126 @@
+//! Functions of "ciaa.resource" and "ciab.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "ciaa.resource|ciab.resource"
+pub unsafe fn AddICRVector(
+    resource: *mut Library,
+    iCRBit: LONG,
+    interrupt: *mut Interrupt,
+) -> *mut Interrupt {
+    let asm_ret_value: *mut Interrupt;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) resource,
+            in("d0") iCRBit,
+            in("a1") interrupt,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "ciaa.resource|ciab.resource"
+pub unsafe fn RemICRVector(resource: *mut Library, iCRBit: LONG, interrupt: *mut Interrupt) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) resource,
+            in("d0") iCRBit,
+            in("a1") interrupt,
+        );
+    }
+}
+
+/// libname: "ciaa.resource|ciab.resource"
+pub unsafe fn AbleICR(resource: *mut Library, mask: LONG) -> WORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) resource,
+            in("d0") mask,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "ciaa.resource|ciab.resource"
+pub unsafe fn SetICR(resource: *mut Library, mask: LONG) -> WORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-24, %a6)
+            ".short -24",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) resource,
+            in("d0") mask,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// Library vector offset of `AddICRVector()`
+pub const LVO_AddICRVector: i16 = -6;
+/// Library vector offset of `RemICRVector()`
+pub const LVO_RemICRVector: i16 = -12;
+/// Library vector offset of `AbleICR()`
+pub const LVO_AbleICR: i16 = -18;
+/// Library vector offset of `SetICR()`
+pub const LVO_SetICR: i16 = -24;
+
+/// Functions of "ciaa.resource" and "ciab.resource"
+pub static CIA_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AddICRVector",
+        lvo: LVO_AddICRVector,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemICRVector",
+        lvo: LVO_RemICRVector,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AbleICR",
+        lvo: LVO_AbleICR,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetICR",
+        lvo: LVO_SetICR,
+        registers: &["d0"],
+        min_version: 0,
+    },
+];
@@ -0,0 +1,206 @@
+//! Functions of "clicktab.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn CLICKTAB_GetClass(ClickTabBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ClickTabBase as *mut Library,
+            40,
+            "CLICKTAB_GetClass",
+            "clicktab.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ClickTabBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AllocClickTabNodeA(
+    ClickTabBase: *mut ::core::ffi::c_void,
+    tags: *mut TagItem,
+) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ClickTabBase as *mut Library,
+            40,
+            "AllocClickTabNodeA",
+            "clicktab.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ClickTabBase,
+            in("a0") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeClickTabNode(ClickTabBase: *mut ::core::ffi::c_void, node: *mut Node) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ClickTabBase as *mut Library,
+            40,
+            "FreeClickTabNode",
+            "clicktab.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ClickTabBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetClickTabNodeAttrsA(
+    ClickTabBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ClickTabBase as *mut Library,
+            40,
+            "SetClickTabNodeAttrsA",
+            "clicktab.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ClickTabBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetClickTabNodeAttrsA(
+    ClickTabBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ClickTabBase as *mut Library,
+            40,
+            "GetClickTabNodeAttrsA",
+            "clicktab.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ClickTabBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// Library vector offset of `CLICKTAB_GetClass()`
+pub const LVO_CLICKTAB_GetClass: i16 = -30;
+/// Library vector offset of `AllocClickTabNodeA()`
+pub const LVO_AllocClickTabNodeA: i16 = -36;
+/// Library vector offset of `FreeClickTabNode()`
+pub const LVO_FreeClickTabNode: i16 = -42;
+/// Library vector offset of `SetClickTabNodeAttrsA()`
+pub const LVO_SetClickTabNodeAttrsA: i16 = -48;
+/// Library vector offset of `GetClickTabNodeAttrsA()`
+pub const LVO_GetClickTabNodeAttrsA: i16 = -54;
+
+/// Functions of "clicktab.gadget"
+pub static CLICKTAB_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CLICKTAB_GetClass",
+        lvo: LVO_CLICKTAB_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocClickTabNodeA",
+        lvo: LVO_AllocClickTabNodeA,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeClickTabNode",
+        lvo: LVO_FreeClickTabNode,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetClickTabNodeAttrsA",
+        lvo: LVO_SetClickTabNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetClickTabNodeAttrsA",
+        lvo: LVO_GetClickTabNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+];
@@ -0,0 +1,93 @@
+//! Functions of "colorwheel.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ConvertHSBToRGB(
+    ColorWheelBase: *mut ::core::ffi::c_void,
+    hsb: *const ColorWheelHSB,
+    rgb: *mut ColorWheelRGB,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ColorWheelBase as *mut Library,
+            39,
+            "ConvertHSBToRGB",
+            "colorwheel.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ColorWheelBase,
+            in("a0") hsb,
+            in("a1") rgb,
+        );
+    }
+}
+
+/// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ConvertRGBToHSB(
+    ColorWheelBase: *mut ::core::ffi::c_void,
+    rgb: *const ColorWheelRGB,
+    hsb: *mut ColorWheelHSB,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ColorWheelBase as *mut Library,
+            39,
+            "ConvertRGBToHSB",
+            "colorwheel.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ColorWheelBase,
+            in("a0") rgb,
+            in("a1") hsb,
+        );
+    }
+}
+
+/// Library vector offset of `ConvertHSBToRGB()`
+pub const LVO_ConvertHSBToRGB: i16 = -30;
+/// Library vector offset of `ConvertRGBToHSB()`
+pub const LVO_ConvertRGBToHSB: i16 = -36;
+
+/// Functions of "colorwheel.gadget"
+pub static COLORWHEEL_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "ConvertHSBToRGB",
+        lvo: LVO_ConvertHSBToRGB,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ConvertRGBToHSB",
+        lvo: LVO_ConvertRGBToHSB,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+];
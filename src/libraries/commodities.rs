@@ -0,0 +1,1091 @@
+//! Functions of "commodities.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateCxObj(
+    CxBase: *mut Library,
+    type_: ULONG,
+    arg1: LONG,
+    arg2: LONG,
+) -> *mut CxObj {
+    let asm_ret_value: *mut CxObj;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CreateCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("d0") type_,
+            in("a0") arg1,
+            in("a1") arg2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxBroker(CxBase: *mut Library, nb: *const NewBroker, error: *mut LONG) -> *mut CxObj {
+    let asm_ret_value: *mut CxObj;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxBroker", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") nb,
+            in("d0") error,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ActivateCxObj(CxBase: *mut Library, co: *mut CxObj, flag: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "ActivateCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+            in("d0") flag,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteCxObj(CxBase: *mut Library, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "DeleteCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteCxObjAll(CxBase: *mut Library, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "DeleteCxObjAll", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxObjType(CxBase: *mut Library, co: *const CxObj) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxObjType", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxObjError(CxBase: *mut Library, co: *const CxObj) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxObjError", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ClearCxObjError(CxBase: *mut Library, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "ClearCxObjError", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetCxObjPri(CxBase: *mut Library, co: *mut CxObj, pri: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "SetCxObjPri", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+            in("d0") pri,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AttachCxObj(CxBase: *mut Library, headObj: *mut CxObj, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "AttachCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") headObj,
+            in("a1") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EnqueueCxObj(CxBase: *mut Library, headObj: *mut CxObj, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "EnqueueCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") headObj,
+            in("a1") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InsertCxObj(
+    CxBase: *mut Library,
+    headObj: *mut CxObj,
+    co: *mut CxObj,
+    pred: *mut CxObj,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "InsertCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") headObj,
+            in("a1") co,
+            in("a2") pred,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemoveCxObj(CxBase: *mut Library, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "RemoveCxObj", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetTranslate(CxBase: *mut Library, translator: *mut CxObj, events: *mut InputEvent) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "SetTranslate", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") translator,
+            in("a1") events,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetFilter(CxBase: *mut Library, filter: *mut CxObj, text: CONST_STRPTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "SetFilter", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") filter,
+            in("a1") text,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetFilterIX(CxBase: *mut Library, filter: *mut CxObj, ix: *const IX) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "SetFilterIX", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") filter,
+            in("a1") ix,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ParseIX(CxBase: *mut Library, description: CONST_STRPTR, ix: *mut IX) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "ParseIX", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") description,
+            in("a1") ix,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxMsgType(CxBase: *mut Library, cxm: *const CxMsg) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxMsgType", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxMsgData(CxBase: *mut Library, cxm: *const CxMsg) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxMsgData", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CxMsgID(CxBase: *mut Library, cxm: *const CxMsg) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "CxMsgID", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DivertCxMsg(
+    CxBase: *mut Library,
+    cxm: *mut CxMsg,
+    headObj: *mut CxObj,
+    returnObj: *mut CxObj,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "DivertCxMsg", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+            in("a1") headObj,
+            in("a2") returnObj,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RouteCxMsg(CxBase: *mut Library, cxm: *mut CxMsg, co: *mut CxObj) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "RouteCxMsg", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+            in("a1") co,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DisposeCxMsg(CxBase: *mut Library, cxm: *mut CxMsg) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "DisposeCxMsg", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") cxm,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InvertKeyMap(
+    CxBase: *mut Library,
+    ansiCode: ULONG,
+    event: *mut InputEvent,
+    km: *const KeyMap,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "InvertKeyMap", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("d0") ansiCode,
+            in("a0") event,
+            in("a1") km,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "commodities.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddIEvents(CxBase: *mut Library, events: *mut InputEvent) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 36, "AddIEvents", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") events,
+        );
+    }
+}
+
+/// libname: "commodities.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn MatchIX(CxBase: *mut Library, event: *const InputEvent, ix: *const IX) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(CxBase, 38, "MatchIX", "commodities.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) CxBase,
+            in("a0") event,
+            in("a1") ix,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// An open "commodities.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct CommoditiesLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl CommoditiesLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"commodities.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "commodities.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateCxObj(&self, type_: ULONG, arg1: LONG, arg2: LONG) -> *mut CxObj {
+        unsafe { CreateCxObj(self.base.as_ptr(), type_, arg1, arg2) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxBroker(&self, nb: *const NewBroker, error: *mut LONG) -> *mut CxObj {
+        unsafe { CxBroker(self.base.as_ptr(), nb, error) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ActivateCxObj(&self, co: *mut CxObj, flag: LONG) -> LONG {
+        unsafe { ActivateCxObj(self.base.as_ptr(), co, flag) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteCxObj(&self, co: *mut CxObj) {
+        unsafe { DeleteCxObj(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteCxObjAll(&self, co: *mut CxObj) {
+        unsafe { DeleteCxObjAll(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxObjType(&self, co: *const CxObj) -> ULONG {
+        unsafe { CxObjType(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxObjError(&self, co: *const CxObj) -> LONG {
+        unsafe { CxObjError(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ClearCxObjError(&self, co: *mut CxObj) {
+        unsafe { ClearCxObjError(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetCxObjPri(&self, co: *mut CxObj, pri: LONG) -> LONG {
+        unsafe { SetCxObjPri(self.base.as_ptr(), co, pri) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AttachCxObj(&self, headObj: *mut CxObj, co: *mut CxObj) {
+        unsafe { AttachCxObj(self.base.as_ptr(), headObj, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EnqueueCxObj(&self, headObj: *mut CxObj, co: *mut CxObj) {
+        unsafe { EnqueueCxObj(self.base.as_ptr(), headObj, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InsertCxObj(&self, headObj: *mut CxObj, co: *mut CxObj, pred: *mut CxObj) {
+        unsafe { InsertCxObj(self.base.as_ptr(), headObj, co, pred) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemoveCxObj(&self, co: *mut CxObj) {
+        unsafe { RemoveCxObj(self.base.as_ptr(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetTranslate(&self, translator: *mut CxObj, events: *mut InputEvent) {
+        unsafe { SetTranslate(self.base.as_ptr(), translator, events) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetFilter(&self, filter: *mut CxObj, text: CONST_STRPTR) {
+        unsafe { SetFilter(self.base.as_ptr(), filter, text) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetFilterIX(&self, filter: *mut CxObj, ix: *const IX) {
+        unsafe { SetFilterIX(self.base.as_ptr(), filter, ix) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ParseIX(&self, description: CONST_STRPTR, ix: *mut IX) -> LONG {
+        unsafe { ParseIX(self.base.as_ptr(), description, ix) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxMsgType(&self, cxm: *const CxMsg) -> ULONG {
+        unsafe { CxMsgType(self.base.as_ptr(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxMsgData(&self, cxm: *const CxMsg) -> APTR {
+        unsafe { CxMsgData(self.base.as_ptr(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CxMsgID(&self, cxm: *const CxMsg) -> LONG {
+        unsafe { CxMsgID(self.base.as_ptr(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DivertCxMsg(&self, cxm: *mut CxMsg, headObj: *mut CxObj, returnObj: *mut CxObj) {
+        unsafe { DivertCxMsg(self.base.as_ptr(), cxm, headObj, returnObj) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RouteCxMsg(&self, cxm: *mut CxMsg, co: *mut CxObj) {
+        unsafe { RouteCxMsg(self.base.as_ptr(), cxm, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DisposeCxMsg(&self, cxm: *mut CxMsg) {
+        unsafe { DisposeCxMsg(self.base.as_ptr(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InvertKeyMap(
+        &self,
+        ansiCode: ULONG,
+        event: *mut InputEvent,
+        km: *const KeyMap,
+    ) -> BOOL {
+        unsafe { InvertKeyMap(self.base.as_ptr(), ansiCode, event, km) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddIEvents(&self, events: *mut InputEvent) {
+        unsafe { AddIEvents(self.base.as_ptr(), events) }
+    }
+
+    /// libname: "commodities.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn MatchIX(&self, event: *const InputEvent, ix: *const IX) -> BOOL {
+        unsafe { MatchIX(self.base.as_ptr(), event, ix) }
+    }
+}
+
+impl Drop for CommoditiesLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `CreateCxObj()`
+pub const LVO_CreateCxObj: i16 = -30;
+/// Library vector offset of `CxBroker()`
+pub const LVO_CxBroker: i16 = -36;
+/// Library vector offset of `ActivateCxObj()`
+pub const LVO_ActivateCxObj: i16 = -42;
+/// Library vector offset of `DeleteCxObj()`
+pub const LVO_DeleteCxObj: i16 = -48;
+/// Library vector offset of `DeleteCxObjAll()`
+pub const LVO_DeleteCxObjAll: i16 = -54;
+/// Library vector offset of `CxObjType()`
+pub const LVO_CxObjType: i16 = -60;
+/// Library vector offset of `CxObjError()`
+pub const LVO_CxObjError: i16 = -66;
+/// Library vector offset of `ClearCxObjError()`
+pub const LVO_ClearCxObjError: i16 = -72;
+/// Library vector offset of `SetCxObjPri()`
+pub const LVO_SetCxObjPri: i16 = -78;
+/// Library vector offset of `AttachCxObj()`
+pub const LVO_AttachCxObj: i16 = -84;
+/// Library vector offset of `EnqueueCxObj()`
+pub const LVO_EnqueueCxObj: i16 = -90;
+/// Library vector offset of `InsertCxObj()`
+pub const LVO_InsertCxObj: i16 = -96;
+/// Library vector offset of `RemoveCxObj()`
+pub const LVO_RemoveCxObj: i16 = -102;
+/// Library vector offset of `SetTranslate()`
+pub const LVO_SetTranslate: i16 = -114;
+/// Library vector offset of `SetFilter()`
+pub const LVO_SetFilter: i16 = -120;
+/// Library vector offset of `SetFilterIX()`
+pub const LVO_SetFilterIX: i16 = -126;
+/// Library vector offset of `ParseIX()`
+pub const LVO_ParseIX: i16 = -132;
+/// Library vector offset of `CxMsgType()`
+pub const LVO_CxMsgType: i16 = -138;
+/// Library vector offset of `CxMsgData()`
+pub const LVO_CxMsgData: i16 = -144;
+/// Library vector offset of `CxMsgID()`
+pub const LVO_CxMsgID: i16 = -150;
+/// Library vector offset of `DivertCxMsg()`
+pub const LVO_DivertCxMsg: i16 = -156;
+/// Library vector offset of `RouteCxMsg()`
+pub const LVO_RouteCxMsg: i16 = -162;
+/// Library vector offset of `DisposeCxMsg()`
+pub const LVO_DisposeCxMsg: i16 = -168;
+/// Library vector offset of `InvertKeyMap()`
+pub const LVO_InvertKeyMap: i16 = -174;
+/// Library vector offset of `AddIEvents()`
+pub const LVO_AddIEvents: i16 = -180;
+/// Library vector offset of `MatchIX()`
+pub const LVO_MatchIX: i16 = -204;
+
+/// Functions of "commodities.library"
+pub static COMMODITIES_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CreateCxObj",
+        lvo: LVO_CreateCxObj,
+        registers: &["d0", "a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxBroker",
+        lvo: LVO_CxBroker,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ActivateCxObj",
+        lvo: LVO_ActivateCxObj,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteCxObj",
+        lvo: LVO_DeleteCxObj,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteCxObjAll",
+        lvo: LVO_DeleteCxObjAll,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxObjType",
+        lvo: LVO_CxObjType,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxObjError",
+        lvo: LVO_CxObjError,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ClearCxObjError",
+        lvo: LVO_ClearCxObjError,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetCxObjPri",
+        lvo: LVO_SetCxObjPri,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AttachCxObj",
+        lvo: LVO_AttachCxObj,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EnqueueCxObj",
+        lvo: LVO_EnqueueCxObj,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InsertCxObj",
+        lvo: LVO_InsertCxObj,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemoveCxObj",
+        lvo: LVO_RemoveCxObj,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetTranslate",
+        lvo: LVO_SetTranslate,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetFilter",
+        lvo: LVO_SetFilter,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetFilterIX",
+        lvo: LVO_SetFilterIX,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParseIX",
+        lvo: LVO_ParseIX,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxMsgType",
+        lvo: LVO_CxMsgType,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxMsgData",
+        lvo: LVO_CxMsgData,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CxMsgID",
+        lvo: LVO_CxMsgID,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DivertCxMsg",
+        lvo: LVO_DivertCxMsg,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RouteCxMsg",
+        lvo: LVO_RouteCxMsg,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DisposeCxMsg",
+        lvo: LVO_DisposeCxMsg,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InvertKeyMap",
+        lvo: LVO_InvertKeyMap,
+        registers: &["d0", "a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddIEvents",
+        lvo: LVO_AddIEvents,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MatchIX",
+        lvo: LVO_MatchIX,
+        registers: &["a0", "a1"],
+        min_version: 38,
+    },
+];
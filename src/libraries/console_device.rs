@@ -0,0 +1,81 @@
+//! Functions of "console.device"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "console.device"
+pub unsafe fn CDInputHandler(
+    ConsoleDevice: *mut ::core::ffi::c_void,
+    events: *const InputEvent,
+    consoleDevice: *mut Library,
+) -> *mut InputEvent {
+    let asm_ret_value: *mut InputEvent;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ConsoleDevice,
+            in("a0") events,
+            in("a1") consoleDevice,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "console.device"
+pub unsafe fn RawKeyConvert(
+    ConsoleDevice: *mut ::core::ffi::c_void,
+    events: *const InputEvent,
+    buffer: STRPTR,
+    length: LONG,
+    keyMap: *const KeyMap,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ConsoleDevice,
+            in("a0") events,
+            in("a1") buffer,
+            in("d1") length,
+            in("a2") keyMap,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `CDInputHandler()`
+pub const LVO_CDInputHandler: i16 = -42;
+/// Library vector offset of `RawKeyConvert()`
+pub const LVO_RawKeyConvert: i16 = -48;
+
+/// Functions of "console.device"
+pub static CONSOLE_DEVICE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CDInputHandler",
+        lvo: LVO_CDInputHandler,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RawKeyConvert",
+        lvo: LVO_RawKeyConvert,
+        registers: &["a0", "a1", "d1", "a2"],
+        min_version: 0,
+    },
+];
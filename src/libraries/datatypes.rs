@@ -0,0 +1,1162 @@
+//! Functions of "datatypes.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ObtainDataTypeA(
+    DataTypesBase: *mut Library,
+    type_: ULONG,
+    handle: APTR,
+    attrs: *const TagItem,
+) -> *mut DataType {
+    let asm_ret_value: *mut DataType;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "ObtainDataTypeA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("d0") type_,
+            in("a0") handle,
+            in("a1") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ReleaseDataType(DataTypesBase: *mut Library, dt: *mut DataType) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "ReleaseDataType", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") dt,
+        );
+    }
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn NewDTObjectA(
+    DataTypesBase: *mut Library,
+    name: CONST_STRPTR,
+    attrs: *const TagItem,
+) -> *mut Object {
+    let asm_ret_value: *mut Object;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "NewDTObjectA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("d0") name,
+            in("a0") attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DisposeDTObject(DataTypesBase: *mut Library, o: *mut Object) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "DisposeDTObject", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+        );
+    }
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetDTAttrsA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    win: *mut Window,
+    req: *mut Requester,
+    attrs: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "SetDTAttrsA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") win,
+            in("a2") req,
+            in("a3") attrs,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetDTAttrsA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    attrs: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "GetDTAttrsA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a2") attrs,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AddDTObject(
+    DataTypesBase: *mut Library,
+    win: *mut Window,
+    req: *mut Requester,
+    o: *mut Object,
+    pos: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "AddDTObject", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") win,
+            in("a1") req,
+            in("a2") o,
+            in("d0") pos,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RefreshDTObjectA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    win: *mut Window,
+    req: *mut Requester,
+    attrs: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "RefreshDTObjectA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") win,
+            in("a2") req,
+            in("a3") attrs,
+        );
+    }
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DoAsyncLayout(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    gpl: *mut gpLayout,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "DoAsyncLayout", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") gpl,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DoDTMethodA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    win: *mut Window,
+    req: *mut Requester,
+    msg: Msg,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "DoDTMethodA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") win,
+            in("a2") req,
+            in("a3") msg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RemoveDTObject(
+    DataTypesBase: *mut Library,
+    win: *mut Window,
+    o: *mut Object,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "RemoveDTObject", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") win,
+            in("a1") o,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetDTMethods(DataTypesBase: *mut Library, object: *const Object) -> *mut ULONG {
+    let asm_ret_value: *mut ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "GetDTMethods", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") object,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetDTTriggerMethods(
+    DataTypesBase: *mut Library,
+    object: *mut Object,
+) -> *mut DTMethod {
+    let asm_ret_value: *mut DTMethod;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DataTypesBase,
+            40,
+            "GetDTTriggerMethods",
+            "datatypes.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") object,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn PrintDTObjectA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    w: *mut Window,
+    r: *mut Requester,
+    msg: *mut dtPrint,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "PrintDTObjectA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") w,
+            in("a2") r,
+            in("a3") msg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetDTString(DataTypesBase: *mut Library, id: ULONG) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 40, "GetDTString", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("d0") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn FindMethod(
+    DataTypesBase: *mut Library,
+    methods: *const ULONG,
+    searchmethodid: ULONG,
+) -> *mut ULONG {
+    let asm_ret_value: *mut ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "FindMethod", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") methods,
+            in("a1") searchmethodid,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn FindTriggerMethod(
+    DataTypesBase: *mut Library,
+    dtm: *const DTMethod,
+    command: CONST_STRPTR,
+    method: ULONG,
+) -> *mut DTMethod {
+    let asm_ret_value: *mut DTMethod;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "FindTriggerMethod", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") dtm,
+            in("a1") command,
+            in("d0") method,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn CopyDTMethods(
+    DataTypesBase: *mut Library,
+    methods: *const ULONG,
+    include: *const ULONG,
+    exclude: *const ULONG,
+) -> *mut ULONG {
+    let asm_ret_value: *mut ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "CopyDTMethods", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") methods,
+            in("a1") include,
+            in("a2") exclude,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn CopyDTTriggerMethods(
+    DataTypesBase: *mut Library,
+    methods: *const DTMethod,
+    include: *const DTMethod,
+    exclude: *const DTMethod,
+) -> *mut DTMethod {
+    let asm_ret_value: *mut DTMethod;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DataTypesBase,
+            47,
+            "CopyDTTriggerMethods",
+            "datatypes.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-276, %a6)
+            ".short -276",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") methods,
+            in("a1") include,
+            in("a2") exclude,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn FreeDTMethods(
+    DataTypesBase: *mut Library,
+    methods: APTR,
+) -> *mut ::core::ffi::c_void {
+    let asm_ret_value: *mut ::core::ffi::c_void;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "FreeDTMethods", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-282, %a6)
+            ".short -282",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") methods,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn GetDTTriggerMethodDataFlags(
+    DataTypesBase: *mut Library,
+    triggermethod: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DataTypesBase,
+            47,
+            "GetDTTriggerMethodDataFlags",
+            "datatypes.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-288, %a6)
+            ".short -288",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("d0") triggermethod,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SaveDTObjectA(
+    DataTypesBase: *mut Library,
+    o: *mut Object,
+    win: *mut Window,
+    req: *mut Requester,
+    file: CONST_STRPTR,
+    mode: ULONG,
+    saveicon: LONG,
+    attrs: *mut TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "SaveDTObjectA", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a4, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a4reg}, %a4",
+            ".short 0x4eae", // jsr (-294, %a6)
+            ".short -294",
+            "move.l (%sp)+, %a4",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            in("a1") win,
+            in("a2") req,
+            in("a3") file,
+            in("d0") mode,
+            in("d1") saveicon,
+            a4reg = in(reg) attrs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn StartDragSelect(DataTypesBase: *mut Library, o: *mut Object) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DataTypesBase, 47, "StartDragSelect", "datatypes.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-300, %a6)
+            ".short -300",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DataTypesBase,
+            in("a0") o,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "datatypes.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct DataTypesLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl DataTypesLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"datatypes.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "datatypes.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ObtainDataTypeA(
+        &self,
+        type_: ULONG,
+        handle: APTR,
+        attrs: *const TagItem,
+    ) -> *mut DataType {
+        unsafe { ObtainDataTypeA(self.base.as_ptr(), type_, handle, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ReleaseDataType(&self, dt: *mut DataType) {
+        unsafe { ReleaseDataType(self.base.as_ptr(), dt) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn NewDTObjectA(&self, name: CONST_STRPTR, attrs: *const TagItem) -> *mut Object {
+        unsafe { NewDTObjectA(self.base.as_ptr(), name, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn DisposeDTObject(&self, o: *mut Object) {
+        unsafe { DisposeDTObject(self.base.as_ptr(), o) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SetDTAttrsA(
+        &self,
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        attrs: *const TagItem,
+    ) -> ULONG {
+        unsafe { SetDTAttrsA(self.base.as_ptr(), o, win, req, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetDTAttrsA(&self, o: *mut Object, attrs: *const TagItem) -> ULONG {
+        unsafe { GetDTAttrsA(self.base.as_ptr(), o, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AddDTObject(
+        &self,
+        win: *mut Window,
+        req: *mut Requester,
+        o: *mut Object,
+        pos: LONG,
+    ) -> LONG {
+        unsafe { AddDTObject(self.base.as_ptr(), win, req, o, pos) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RefreshDTObjectA(
+        &self,
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        attrs: *const TagItem,
+    ) {
+        unsafe { RefreshDTObjectA(self.base.as_ptr(), o, win, req, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn DoAsyncLayout(&self, o: *mut Object, gpl: *mut gpLayout) -> ULONG {
+        unsafe { DoAsyncLayout(self.base.as_ptr(), o, gpl) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn DoDTMethodA(
+        &self,
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        msg: Msg,
+    ) -> ULONG {
+        unsafe { DoDTMethodA(self.base.as_ptr(), o, win, req, msg) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RemoveDTObject(&self, win: *mut Window, o: *mut Object) -> LONG {
+        unsafe { RemoveDTObject(self.base.as_ptr(), win, o) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetDTMethods(&self, object: *const Object) -> *mut ULONG {
+        unsafe { GetDTMethods(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetDTTriggerMethods(&self, object: *mut Object) -> *mut DTMethod {
+        unsafe { GetDTTriggerMethods(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn PrintDTObjectA(
+        &self,
+        o: *mut Object,
+        w: *mut Window,
+        r: *mut Requester,
+        msg: *mut dtPrint,
+    ) -> ULONG {
+        unsafe { PrintDTObjectA(self.base.as_ptr(), o, w, r, msg) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetDTString(&self, id: ULONG) -> STRPTR {
+        unsafe { GetDTString(self.base.as_ptr(), id) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn FindMethod(&self, methods: *const ULONG, searchmethodid: ULONG) -> *mut ULONG {
+        unsafe { FindMethod(self.base.as_ptr(), methods, searchmethodid) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn FindTriggerMethod(
+        &self,
+        dtm: *const DTMethod,
+        command: CONST_STRPTR,
+        method: ULONG,
+    ) -> *mut DTMethod {
+        unsafe { FindTriggerMethod(self.base.as_ptr(), dtm, command, method) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn CopyDTMethods(
+        &self,
+        methods: *const ULONG,
+        include: *const ULONG,
+        exclude: *const ULONG,
+    ) -> *mut ULONG {
+        unsafe { CopyDTMethods(self.base.as_ptr(), methods, include, exclude) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn CopyDTTriggerMethods(
+        &self,
+        methods: *const DTMethod,
+        include: *const DTMethod,
+        exclude: *const DTMethod,
+    ) -> *mut DTMethod {
+        unsafe { CopyDTTriggerMethods(self.base.as_ptr(), methods, include, exclude) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn FreeDTMethods(&self, methods: APTR) -> *mut ::core::ffi::c_void {
+        unsafe { FreeDTMethods(self.base.as_ptr(), methods) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn GetDTTriggerMethodDataFlags(&self, triggermethod: ULONG) -> ULONG {
+        unsafe { GetDTTriggerMethodDataFlags(self.base.as_ptr(), triggermethod) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SaveDTObjectA(
+        &self,
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        file: CONST_STRPTR,
+        mode: ULONG,
+        saveicon: LONG,
+        attrs: *mut TagItem,
+    ) -> ULONG {
+        unsafe { SaveDTObjectA(self.base.as_ptr(), o, win, req, file, mode, saveicon, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn StartDragSelect(&self, o: *mut Object) -> ULONG {
+        unsafe { StartDragSelect(self.base.as_ptr(), o) }
+    }
+}
+
+impl Drop for DataTypesLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `ObtainDataTypeA()`
+pub const LVO_ObtainDataTypeA: i16 = -36;
+/// Library vector offset of `ReleaseDataType()`
+pub const LVO_ReleaseDataType: i16 = -42;
+/// Library vector offset of `NewDTObjectA()`
+pub const LVO_NewDTObjectA: i16 = -48;
+/// Library vector offset of `DisposeDTObject()`
+pub const LVO_DisposeDTObject: i16 = -54;
+/// Library vector offset of `SetDTAttrsA()`
+pub const LVO_SetDTAttrsA: i16 = -60;
+/// Library vector offset of `GetDTAttrsA()`
+pub const LVO_GetDTAttrsA: i16 = -66;
+/// Library vector offset of `AddDTObject()`
+pub const LVO_AddDTObject: i16 = -72;
+/// Library vector offset of `RefreshDTObjectA()`
+pub const LVO_RefreshDTObjectA: i16 = -78;
+/// Library vector offset of `DoAsyncLayout()`
+pub const LVO_DoAsyncLayout: i16 = -84;
+/// Library vector offset of `DoDTMethodA()`
+pub const LVO_DoDTMethodA: i16 = -90;
+/// Library vector offset of `RemoveDTObject()`
+pub const LVO_RemoveDTObject: i16 = -96;
+/// Library vector offset of `GetDTMethods()`
+pub const LVO_GetDTMethods: i16 = -102;
+/// Library vector offset of `GetDTTriggerMethods()`
+pub const LVO_GetDTTriggerMethods: i16 = -108;
+/// Library vector offset of `PrintDTObjectA()`
+pub const LVO_PrintDTObjectA: i16 = -114;
+/// Library vector offset of `GetDTString()`
+pub const LVO_GetDTString: i16 = -138;
+/// Library vector offset of `FindMethod()`
+pub const LVO_FindMethod: i16 = -258;
+/// Library vector offset of `FindTriggerMethod()`
+pub const LVO_FindTriggerMethod: i16 = -264;
+/// Library vector offset of `CopyDTMethods()`
+pub const LVO_CopyDTMethods: i16 = -270;
+/// Library vector offset of `CopyDTTriggerMethods()`
+pub const LVO_CopyDTTriggerMethods: i16 = -276;
+/// Library vector offset of `FreeDTMethods()`
+pub const LVO_FreeDTMethods: i16 = -282;
+/// Library vector offset of `GetDTTriggerMethodDataFlags()`
+pub const LVO_GetDTTriggerMethodDataFlags: i16 = -288;
+/// Library vector offset of `SaveDTObjectA()`
+pub const LVO_SaveDTObjectA: i16 = -294;
+/// Library vector offset of `StartDragSelect()`
+pub const LVO_StartDragSelect: i16 = -300;
+
+/// Functions of "datatypes.library"
+pub static DATATYPES_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "ObtainDataTypeA",
+        lvo: LVO_ObtainDataTypeA,
+        registers: &["d0", "a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseDataType",
+        lvo: LVO_ReleaseDataType,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "NewDTObjectA",
+        lvo: LVO_NewDTObjectA,
+        registers: &["d0", "a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "DisposeDTObject",
+        lvo: LVO_DisposeDTObject,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetDTAttrsA",
+        lvo: LVO_SetDTAttrsA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetDTAttrsA",
+        lvo: LVO_GetDTAttrsA,
+        registers: &["a0", "a2"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AddDTObject",
+        lvo: LVO_AddDTObject,
+        registers: &["a0", "a1", "a2", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RefreshDTObjectA",
+        lvo: LVO_RefreshDTObjectA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "DoAsyncLayout",
+        lvo: LVO_DoAsyncLayout,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "DoDTMethodA",
+        lvo: LVO_DoDTMethodA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RemoveDTObject",
+        lvo: LVO_RemoveDTObject,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetDTMethods",
+        lvo: LVO_GetDTMethods,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetDTTriggerMethods",
+        lvo: LVO_GetDTTriggerMethods,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "PrintDTObjectA",
+        lvo: LVO_PrintDTObjectA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetDTString",
+        lvo: LVO_GetDTString,
+        registers: &["d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FindMethod",
+        lvo: LVO_FindMethod,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "FindTriggerMethod",
+        lvo: LVO_FindTriggerMethod,
+        registers: &["a0", "a1", "d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "CopyDTMethods",
+        lvo: LVO_CopyDTMethods,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "CopyDTTriggerMethods",
+        lvo: LVO_CopyDTTriggerMethods,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "FreeDTMethods",
+        lvo: LVO_FreeDTMethods,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "GetDTTriggerMethodDataFlags",
+        lvo: LVO_GetDTTriggerMethodDataFlags,
+        registers: &["d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "SaveDTObjectA",
+        lvo: LVO_SaveDTObjectA,
+        registers: &["a0", "a1", "a2", "a3", "d0", "d1", "a4"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "StartDragSelect",
+        lvo: LVO_StartDragSelect,
+        registers: &["a0"],
+        min_version: 47,
+    },
+];
@@ -0,0 +1,176 @@
+//! Functions of "datebrowser.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DATEBROWSER_GetClass(DateBrowserBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DateBrowserBase as *mut Library,
+            40,
+            "DATEBROWSER_GetClass",
+            "datebrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DateBrowserBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn JulianWeekDay(
+    DateBrowserBase: *mut ::core::ffi::c_void,
+    day: ULONG,
+    month: ULONG,
+    year: LONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DateBrowserBase as *mut Library,
+            40,
+            "JulianWeekDay",
+            "datebrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DateBrowserBase,
+            in("d0") day,
+            in("d1") month,
+            in("d2") year,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn JulianMonthDays(
+    DateBrowserBase: *mut ::core::ffi::c_void,
+    month: ULONG,
+    year: LONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DateBrowserBase as *mut Library,
+            40,
+            "JulianMonthDays",
+            "datebrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DateBrowserBase,
+            in("d0") month,
+            in("d1") year,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn JulianLeapYear(DateBrowserBase: *mut ::core::ffi::c_void, year: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            DateBrowserBase as *mut Library,
+            40,
+            "JulianLeapYear",
+            "datebrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DateBrowserBase,
+            in("d0") year,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// Library vector offset of `DATEBROWSER_GetClass()`
+pub const LVO_DATEBROWSER_GetClass: i16 = -30;
+/// Library vector offset of `JulianWeekDay()`
+pub const LVO_JulianWeekDay: i16 = -36;
+/// Library vector offset of `JulianMonthDays()`
+pub const LVO_JulianMonthDays: i16 = -42;
+/// Library vector offset of `JulianLeapYear()`
+pub const LVO_JulianLeapYear: i16 = -48;
+
+/// Functions of "datebrowser.gadget"
+pub static DATEBROWSER_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "DATEBROWSER_GetClass",
+        lvo: LVO_DATEBROWSER_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "JulianWeekDay",
+        lvo: LVO_JulianWeekDay,
+        registers: &["d0", "d1", "d2"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "JulianMonthDays",
+        lvo: LVO_JulianMonthDays,
+        registers: &["d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "JulianLeapYear",
+        lvo: LVO_JulianLeapYear,
+        registers: &["d0"],
+        min_version: 40,
+    },
+];
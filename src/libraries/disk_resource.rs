@@ -0,0 +1,176 @@
+//! Functions of "disk.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "disk.resource"
+pub unsafe fn AllocUnit(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+            in("d0") unitNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "disk.resource"
+pub unsafe fn FreeUnit(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+            in("d0") unitNum,
+        );
+    }
+}
+
+/// libname: "disk.resource"
+pub unsafe fn GetUnit(
+    DiskBase: *mut ::core::ffi::c_void,
+    unitPointer: *mut DiscResourceUnit,
+) -> *mut DiscResourceUnit {
+    let asm_ret_value: *mut DiscResourceUnit;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+            in("a1") unitPointer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "disk.resource"
+pub unsafe fn GiveUnit(DiskBase: *mut ::core::ffi::c_void) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-24, %a6)
+            ".short -24",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+        );
+    }
+}
+
+/// libname: "disk.resource"
+pub unsafe fn GetUnitID(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+            in("d0") unitNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "disk.resource" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn ReadUnitID(DiskBase: *mut ::core::ffi::c_void, unitNum: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskBase,
+            in("d0") unitNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `AllocUnit()`
+pub const LVO_AllocUnit: i16 = -6;
+/// Library vector offset of `FreeUnit()`
+pub const LVO_FreeUnit: i16 = -12;
+/// Library vector offset of `GetUnit()`
+pub const LVO_GetUnit: i16 = -18;
+/// Library vector offset of `GiveUnit()`
+pub const LVO_GiveUnit: i16 = -24;
+/// Library vector offset of `GetUnitID()`
+pub const LVO_GetUnitID: i16 = -30;
+/// Library vector offset of `ReadUnitID()`
+pub const LVO_ReadUnitID: i16 = -36;
+
+/// Functions of "disk.resource"
+pub static DISK_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AllocUnit",
+        lvo: LVO_AllocUnit,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeUnit",
+        lvo: LVO_FreeUnit,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetUnit",
+        lvo: LVO_GetUnit,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GiveUnit",
+        lvo: LVO_GiveUnit,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetUnitID",
+        lvo: LVO_GetUnitID,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReadUnitID",
+        lvo: LVO_ReadUnitID,
+        registers: &["d0"],
+        min_version: 37,
+    },
+];
@@ -0,0 +1,817 @@
+//! Functions of "diskfont.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "diskfont.library"
+pub unsafe fn OpenDiskFont(DiskfontBase: *mut Library, textAttr: *mut TextAttr) -> *mut TextFont {
+    let asm_ret_value: *mut TextFont;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") textAttr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library"
+pub unsafe fn AvailFonts(
+    DiskfontBase: *mut Library,
+    buffer: *mut AvailFontsHeader,
+    bufBytes: LONG,
+    flags: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") buffer,
+            in("d0") bufBytes,
+            in("d1") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V34) (Kickstart 1.3)
+#[cfg(not(feature = "kickstart-v33"))]
+pub unsafe fn NewFontContents(
+    DiskfontBase: *mut Library,
+    fontsLock: BPTR,
+    fontName: CONST_STRPTR,
+) -> *mut FontContentsHeader {
+    let asm_ret_value: *mut FontContentsHeader;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 34, "NewFontContents", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") fontsLock,
+            in("a1") fontName,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V34) (Kickstart 1.3)
+#[cfg(not(feature = "kickstart-v33"))]
+pub unsafe fn DisposeFontContents(
+    DiskfontBase: *mut Library,
+    fontContentsHeader: *mut FontContentsHeader,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 34, "DisposeFontContents", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a1") fontContentsHeader,
+        );
+    }
+}
+
+/// libname: "diskfont.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NewScaledDiskFont(
+    DiskfontBase: *mut Library,
+    sourceFont: *mut TextFont,
+    destTextAttr: *mut TextAttr,
+) -> *mut DiskFontHeader {
+    let asm_ret_value: *mut DiskFontHeader;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 36, "NewScaledDiskFont", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") sourceFont,
+            in("a1") destTextAttr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn GetDiskFontCtrl(DiskfontBase: *mut Library, tagid: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 45, "GetDiskFontCtrl", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("d0") tagid,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn SetDiskFontCtrlA(DiskfontBase: *mut Library, taglist: *const TagItem) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 45, "SetDiskFontCtrlA", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") taglist,
+        );
+    }
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn EOpenEngine(DiskfontBase: *mut Library, eEngine: *mut EGlyphEngine) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "EOpenEngine", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") eEngine,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ECloseEngine(DiskfontBase: *mut Library, eEngine: *mut EGlyphEngine) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "ECloseEngine", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") eEngine,
+        );
+    }
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ESetInfoA(
+    DiskfontBase: *mut Library,
+    eEngine: *mut EGlyphEngine,
+    taglist: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "ESetInfoA", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") eEngine,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn EObtainInfoA(
+    DiskfontBase: *mut Library,
+    eEngine: *mut EGlyphEngine,
+    taglist: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "EObtainInfoA", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") eEngine,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn EReleaseInfoA(
+    DiskfontBase: *mut Library,
+    eEngine: *mut EGlyphEngine,
+    taglist: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "EReleaseInfoA", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") eEngine,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn OpenOutlineFont(
+    DiskfontBase: *mut Library,
+    name: CONST_STRPTR,
+    list: *mut List,
+    flags: ULONG,
+) -> *mut OutlineFont {
+    let asm_ret_value: *mut OutlineFont;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "OpenOutlineFont", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") name,
+            in("a1") list,
+            in("d0") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn CloseOutlineFont(DiskfontBase: *mut Library, olf: *mut OutlineFont, list: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "CloseOutlineFont", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") olf,
+            in("a1") list,
+        );
+    }
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn WriteFontContents(
+    DiskfontBase: *mut Library,
+    fontsLock: BPTR,
+    fontName: CONST_STRPTR,
+    fontContentsHeader: *const FontContentsHeader,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "WriteFontContents", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") fontsLock,
+            in("a1") fontName,
+            in("a2") fontContentsHeader,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn WriteDiskFontHeaderA(
+    DiskfontBase: *mut Library,
+    font: *const TextFont,
+    fileName: CONST_STRPTR,
+    tagList: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "WriteDiskFontHeaderA", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("a0") font,
+            in("a1") fileName,
+            in("a2") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ObtainCharsetInfo(
+    DiskfontBase: *mut Library,
+    knownTag: ULONG,
+    knownValue: ULONG,
+    wantedTag: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DiskfontBase, 47, "ObtainCharsetInfo", "diskfont.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DiskfontBase,
+            in("d0") knownTag,
+            in("d1") knownValue,
+            in("d2") wantedTag,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "diskfont.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct DiskfontLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl DiskfontLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"diskfont.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "diskfont.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "diskfont.library"
+    pub unsafe fn OpenDiskFont(&self, textAttr: *mut TextAttr) -> *mut TextFont {
+        unsafe { OpenDiskFont(self.base.as_ptr(), textAttr) }
+    }
+
+    /// libname: "diskfont.library"
+    pub unsafe fn AvailFonts(
+        &self,
+        buffer: *mut AvailFontsHeader,
+        bufBytes: LONG,
+        flags: ULONG,
+    ) -> LONG {
+        unsafe { AvailFonts(self.base.as_ptr(), buffer, bufBytes, flags) }
+    }
+
+    /// libname: "diskfont.library" (V34) (Kickstart 1.3)
+    #[cfg(not(feature = "kickstart-v33"))]
+    pub unsafe fn NewFontContents(
+        &self,
+        fontsLock: BPTR,
+        fontName: CONST_STRPTR,
+    ) -> *mut FontContentsHeader {
+        unsafe { NewFontContents(self.base.as_ptr(), fontsLock, fontName) }
+    }
+
+    /// libname: "diskfont.library" (V34) (Kickstart 1.3)
+    #[cfg(not(feature = "kickstart-v33"))]
+    pub unsafe fn DisposeFontContents(&self, fontContentsHeader: *mut FontContentsHeader) {
+        unsafe { DisposeFontContents(self.base.as_ptr(), fontContentsHeader) }
+    }
+
+    /// libname: "diskfont.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NewScaledDiskFont(
+        &self,
+        sourceFont: *mut TextFont,
+        destTextAttr: *mut TextAttr,
+    ) -> *mut DiskFontHeader {
+        unsafe { NewScaledDiskFont(self.base.as_ptr(), sourceFont, destTextAttr) }
+    }
+
+    /// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    pub unsafe fn GetDiskFontCtrl(&self, tagid: LONG) -> LONG {
+        unsafe { GetDiskFontCtrl(self.base.as_ptr(), tagid) }
+    }
+
+    /// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    pub unsafe fn SetDiskFontCtrlA(&self, taglist: *const TagItem) {
+        unsafe { SetDiskFontCtrlA(self.base.as_ptr(), taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn EOpenEngine(&self, eEngine: *mut EGlyphEngine) -> LONG {
+        unsafe { EOpenEngine(self.base.as_ptr(), eEngine) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ECloseEngine(&self, eEngine: *mut EGlyphEngine) {
+        unsafe { ECloseEngine(self.base.as_ptr(), eEngine) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ESetInfoA(&self, eEngine: *mut EGlyphEngine, taglist: *const TagItem) -> ULONG {
+        unsafe { ESetInfoA(self.base.as_ptr(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn EObtainInfoA(
+        &self,
+        eEngine: *mut EGlyphEngine,
+        taglist: *const TagItem,
+    ) -> ULONG {
+        unsafe { EObtainInfoA(self.base.as_ptr(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn EReleaseInfoA(
+        &self,
+        eEngine: *mut EGlyphEngine,
+        taglist: *const TagItem,
+    ) -> ULONG {
+        unsafe { EReleaseInfoA(self.base.as_ptr(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn OpenOutlineFont(
+        &self,
+        name: CONST_STRPTR,
+        list: *mut List,
+        flags: ULONG,
+    ) -> *mut OutlineFont {
+        unsafe { OpenOutlineFont(self.base.as_ptr(), name, list, flags) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn CloseOutlineFont(&self, olf: *mut OutlineFont, list: *mut List) {
+        unsafe { CloseOutlineFont(self.base.as_ptr(), olf, list) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn WriteFontContents(
+        &self,
+        fontsLock: BPTR,
+        fontName: CONST_STRPTR,
+        fontContentsHeader: *const FontContentsHeader,
+    ) -> LONG {
+        unsafe { WriteFontContents(self.base.as_ptr(), fontsLock, fontName, fontContentsHeader) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn WriteDiskFontHeaderA(
+        &self,
+        font: *const TextFont,
+        fileName: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> LONG {
+        unsafe { WriteDiskFontHeaderA(self.base.as_ptr(), font, fileName, tagList) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ObtainCharsetInfo(
+        &self,
+        knownTag: ULONG,
+        knownValue: ULONG,
+        wantedTag: ULONG,
+    ) -> ULONG {
+        unsafe { ObtainCharsetInfo(self.base.as_ptr(), knownTag, knownValue, wantedTag) }
+    }
+}
+
+impl Drop for DiskfontLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `OpenDiskFont()`
+pub const LVO_OpenDiskFont: i16 = -30;
+/// Library vector offset of `AvailFonts()`
+pub const LVO_AvailFonts: i16 = -36;
+/// Library vector offset of `NewFontContents()`
+pub const LVO_NewFontContents: i16 = -42;
+/// Library vector offset of `DisposeFontContents()`
+pub const LVO_DisposeFontContents: i16 = -48;
+/// Library vector offset of `NewScaledDiskFont()`
+pub const LVO_NewScaledDiskFont: i16 = -54;
+/// Library vector offset of `GetDiskFontCtrl()`
+pub const LVO_GetDiskFontCtrl: i16 = -60;
+/// Library vector offset of `SetDiskFontCtrlA()`
+pub const LVO_SetDiskFontCtrlA: i16 = -66;
+/// Library vector offset of `EOpenEngine()`
+pub const LVO_EOpenEngine: i16 = -72;
+/// Library vector offset of `ECloseEngine()`
+pub const LVO_ECloseEngine: i16 = -78;
+/// Library vector offset of `ESetInfoA()`
+pub const LVO_ESetInfoA: i16 = -84;
+/// Library vector offset of `EObtainInfoA()`
+pub const LVO_EObtainInfoA: i16 = -90;
+/// Library vector offset of `EReleaseInfoA()`
+pub const LVO_EReleaseInfoA: i16 = -96;
+/// Library vector offset of `OpenOutlineFont()`
+pub const LVO_OpenOutlineFont: i16 = -102;
+/// Library vector offset of `CloseOutlineFont()`
+pub const LVO_CloseOutlineFont: i16 = -108;
+/// Library vector offset of `WriteFontContents()`
+pub const LVO_WriteFontContents: i16 = -114;
+/// Library vector offset of `WriteDiskFontHeaderA()`
+pub const LVO_WriteDiskFontHeaderA: i16 = -120;
+/// Library vector offset of `ObtainCharsetInfo()`
+pub const LVO_ObtainCharsetInfo: i16 = -126;
+
+/// Functions of "diskfont.library"
+pub static DISKFONT_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "OpenDiskFont",
+        lvo: LVO_OpenDiskFont,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AvailFonts",
+        lvo: LVO_AvailFonts,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "NewFontContents",
+        lvo: LVO_NewFontContents,
+        registers: &["a0", "a1"],
+        min_version: 34,
+    },
+    crate::FunctionInfo {
+        name: "DisposeFontContents",
+        lvo: LVO_DisposeFontContents,
+        registers: &["a1"],
+        min_version: 34,
+    },
+    crate::FunctionInfo {
+        name: "NewScaledDiskFont",
+        lvo: LVO_NewScaledDiskFont,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDiskFontCtrl",
+        lvo: LVO_GetDiskFontCtrl,
+        registers: &["d0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "SetDiskFontCtrlA",
+        lvo: LVO_SetDiskFontCtrlA,
+        registers: &["a0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "EOpenEngine",
+        lvo: LVO_EOpenEngine,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ECloseEngine",
+        lvo: LVO_ECloseEngine,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ESetInfoA",
+        lvo: LVO_ESetInfoA,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "EObtainInfoA",
+        lvo: LVO_EObtainInfoA,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "EReleaseInfoA",
+        lvo: LVO_EReleaseInfoA,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "OpenOutlineFont",
+        lvo: LVO_OpenOutlineFont,
+        registers: &["a0", "a1", "d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "CloseOutlineFont",
+        lvo: LVO_CloseOutlineFont,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "WriteFontContents",
+        lvo: LVO_WriteFontContents,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "WriteDiskFontHeaderA",
+        lvo: LVO_WriteDiskFontHeaderA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ObtainCharsetInfo",
+        lvo: LVO_ObtainCharsetInfo,
+        registers: &["d0", "d1", "d2"],
+        min_version: 47,
+    },
+];
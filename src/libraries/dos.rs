@@ -0,0 +1,7030 @@
+//! Functions of "dos.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "dos.library"
+pub unsafe fn Open(DOSBase: *mut Library, name: CONST_STRPTR, accessMode: LONG) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") accessMode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Close(DOSBase: *mut Library, file: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Read(DOSBase: *mut Library, file: BPTR, buffer: APTR, length: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") buffer,
+            in("d3") length,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Write(DOSBase: *mut Library, file: BPTR, buffer: CONST_APTR, length: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") buffer,
+            in("d3") length,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Input(DOSBase: *mut Library) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Output(DOSBase: *mut Library) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Seek(DOSBase: *mut Library, file: BPTR, position: LONG, offset: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") position,
+            in("d3") offset,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn DeleteFile(DOSBase: *mut Library, name: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Rename(DOSBase: *mut Library, oldName: CONST_STRPTR, newName: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") oldName,
+            in("d2") newName,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Lock(DOSBase: *mut Library, name: CONST_STRPTR, type_: LONG) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") type_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn UnLock(DOSBase: *mut Library, lock: BPTR) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+        );
+    }
+}
+
+/// libname: "dos.library"
+pub unsafe fn DupLock(DOSBase: *mut Library, lock: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Examine(
+    DOSBase: *mut Library,
+    lock: BPTR,
+    fileInfoBlock: *mut FileInfoBlock,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") fileInfoBlock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn ExNext(DOSBase: *mut Library, lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") fileInfoBlock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Info(DOSBase: *mut Library, lock: BPTR, parameterBlock: *mut InfoData) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") parameterBlock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn CreateDir(DOSBase: *mut Library, name: CONST_STRPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn CurrentDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn IoErr(DOSBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn CreateProc(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    pri: LONG,
+    segList: BPTR,
+    stackSize: LONG,
+) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") pri,
+            in("d3") segList,
+            in("d4") stackSize,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Exit(DOSBase: *mut Library, returnCode: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") returnCode,
+        );
+    }
+}
+
+/// libname: "dos.library"
+pub unsafe fn LoadSeg(DOSBase: *mut Library, name: CONST_STRPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn UnLoadSeg(DOSBase: *mut Library, seglist: BPTR) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") seglist,
+        );
+    }
+}
+
+/// libname: "dos.library"
+pub unsafe fn DeviceProc(DOSBase: *mut Library, name: CONST_STRPTR) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn SetComment(DOSBase: *mut Library, name: CONST_STRPTR, comment: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") comment,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn SetProtection(DOSBase: *mut Library, name: CONST_STRPTR, protect: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") protect,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn DateStamp(DOSBase: *mut Library, date: *mut DateStamp) -> *mut DateStamp {
+    let asm_ret_value: *mut DateStamp;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") date,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Delay(DOSBase: *mut Library, timeout: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") timeout,
+        );
+    }
+}
+
+/// libname: "dos.library"
+pub unsafe fn WaitForChar(DOSBase: *mut Library, file: BPTR, timeout: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") timeout,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn ParentDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn IsInteractive(DOSBase: *mut Library, file: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library"
+pub unsafe fn Execute(
+    DOSBase: *mut Library,
+    string: CONST_STRPTR,
+    file: BPTR,
+    file2: BPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") string,
+            in("d2") file,
+            in("d3") file2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocDosObject(DOSBase: *mut Library, type_: ULONG, tags: *const TagItem) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AllocDosObject", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") type_,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocDosObjectTagList(
+    DOSBase: *mut Library,
+    type_: ULONG,
+    tags: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AllocDosObjectTagList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") type_,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeDosObject(DOSBase: *mut Library, type_: ULONG, ptr: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FreeDosObject", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") type_,
+            in("d2") ptr,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt(
+    DOSBase: *mut Library,
+    port: *mut MsgPort,
+    action: LONG,
+    arg1: LONG,
+    arg2: LONG,
+    arg3: LONG,
+    arg4: LONG,
+    arg5: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg_addr) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            in("d3") arg1,
+            in("d4") arg2,
+            in("d5") arg3,
+            in("d6") arg4,
+            in("d7") arg5,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt0(DOSBase: *mut Library, port: *mut MsgPort, action: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt0", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt1(DOSBase: *mut Library, port: *mut MsgPort, action: LONG, arg1: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt1", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            in("d3") arg1,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt2(
+    DOSBase: *mut Library,
+    port: *mut MsgPort,
+    action: LONG,
+    arg1: LONG,
+    arg2: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt2", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            in("d3") arg1,
+            in("d4") arg2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt3(
+    DOSBase: *mut Library,
+    port: *mut MsgPort,
+    action: LONG,
+    arg1: LONG,
+    arg2: LONG,
+    arg3: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt3", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            in("d3") arg1,
+            in("d4") arg2,
+            in("d5") arg3,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DoPkt4(
+    DOSBase: *mut Library,
+    port: *mut MsgPort,
+    action: LONG,
+    arg1: LONG,
+    arg2: LONG,
+    arg3: LONG,
+    arg4: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DoPkt4", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") action,
+            in("d3") arg1,
+            in("d4") arg2,
+            in("d5") arg3,
+            in("d6") arg4,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SendPkt(
+    DOSBase: *mut Library,
+    dp: *mut DosPacket,
+    port: *mut MsgPort,
+    replyport: *mut MsgPort,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SendPkt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dp,
+            in("d2") port,
+            in("d3") replyport,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WaitPkt(DOSBase: *mut Library) -> *mut DosPacket {
+    let asm_ret_value: *mut DosPacket;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "WaitPkt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReplyPkt(DOSBase: *mut Library, dp: *mut DosPacket, res1: LONG, res2: LONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ReplyPkt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dp,
+            in("d2") res1,
+            in("d3") res2,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AbortPkt(DOSBase: *mut Library, port: *mut MsgPort, pkt: *mut DosPacket) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AbortPkt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") pkt,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockRecord(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    offset: ULONG,
+    length: ULONG,
+    mode: ULONG,
+    timeout: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "LockRecord", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") offset,
+            in("d3") length,
+            in("d4") mode,
+            in("d5") timeout,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockRecords(
+    DOSBase: *mut Library,
+    recArray: *const RecordLock,
+    timeout: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "LockRecords", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-276, %a6)
+            ".short -276",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") recArray,
+            in("d2") timeout,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnLockRecord(DOSBase: *mut Library, fh: BPTR, offset: ULONG, length: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "UnLockRecord", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-282, %a6)
+            ".short -282",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") offset,
+            in("d3") length,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnLockRecords(DOSBase: *mut Library, recArray: *const RecordLock) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "UnLockRecords", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-288, %a6)
+            ".short -288",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") recArray,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SelectInput(DOSBase: *mut Library, fh: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SelectInput", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-294, %a6)
+            ".short -294",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SelectOutput(DOSBase: *mut Library, fh: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SelectOutput", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-300, %a6)
+            ".short -300",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FGetC(DOSBase: *mut Library, fh: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FGetC", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-306, %a6)
+            ".short -306",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FPutC(DOSBase: *mut Library, fh: BPTR, ch: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FPutC", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-312, %a6)
+            ".short -312",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") ch,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnGetC(DOSBase: *mut Library, fh: BPTR, character: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "UnGetC", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-318, %a6)
+            ".short -318",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") character,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FRead(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    block: APTR,
+    blocklen: ULONG,
+    number: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FRead", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-324, %a6)
+            ".short -324",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") block,
+            in("d3") blocklen,
+            in("d4") number,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FWrite(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    block: CONST_APTR,
+    blocklen: ULONG,
+    number: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FWrite", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-330, %a6)
+            ".short -330",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") block,
+            in("d3") blocklen,
+            in("d4") number,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FGets(DOSBase: *mut Library, fh: BPTR, buf: STRPTR, buflen: ULONG) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FGets", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-336, %a6)
+            ".short -336",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") buf,
+            in("d3") buflen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FPuts(DOSBase: *mut Library, fh: BPTR, str_: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FPuts", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-342, %a6)
+            ".short -342",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") str_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn VFWritef(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    format: CONST_STRPTR,
+    argarray: *const LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "VFWritef", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-348, %a6)
+            ".short -348",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") format,
+            in("d3") argarray,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn VFPrintf(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    format: CONST_STRPTR,
+    argarray: CONST_APTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "VFPrintf", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-354, %a6)
+            ".short -354",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") format,
+            in("d3") argarray,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Flush(DOSBase: *mut Library, fh: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Flush", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-360, %a6)
+            ".short -360",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetVBuf(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    buff: STRPTR,
+    type_: LONG,
+    size: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetVBuf", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-366, %a6)
+            ".short -366",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") buff,
+            in("d3") type_,
+            in("d4") size,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DupLockFromFH(DOSBase: *mut Library, fh: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DupLockFromFH", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-372, %a6)
+            ".short -372",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenFromLock(DOSBase: *mut Library, lock: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "OpenFromLock", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-378, %a6)
+            ".short -378",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ParentOfFH(DOSBase: *mut Library, fh: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ParentOfFH", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-384, %a6)
+            ".short -384",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ExamineFH(DOSBase: *mut Library, fh: BPTR, fib: *mut FileInfoBlock) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ExamineFH", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-390, %a6)
+            ".short -390",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") fib,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetFileDate(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    date: *const DateStamp,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetFileDate", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-396, %a6)
+            ".short -396",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") date,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NameFromLock(DOSBase: *mut Library, lock: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "NameFromLock", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-402, %a6)
+            ".short -402",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") buffer,
+            in("d3") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NameFromFH(DOSBase: *mut Library, fh: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "NameFromFH", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-408, %a6)
+            ".short -408",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") buffer,
+            in("d3") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SplitName(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    separator: ULONG,
+    buf: STRPTR,
+    oldpos: LONG,
+    size: LONG,
+) -> WORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SplitName", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-414, %a6)
+            ".short -414",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") separator,
+            in("d3") buf,
+            in("d4") oldpos,
+            in("d5") size,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SameLock(DOSBase: *mut Library, lock1: BPTR, lock2: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SameLock", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-420, %a6)
+            ".short -420",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock1,
+            in("d2") lock2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetMode(DOSBase: *mut Library, fh: BPTR, mode: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetMode", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-426, %a6)
+            ".short -426",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") mode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ExAll(
+    DOSBase: *mut Library,
+    lock: BPTR,
+    buffer: *mut ExAllData,
+    size: LONG,
+    data: LONG,
+    control: *mut ExAllControl,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ExAll", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-432, %a6)
+            ".short -432",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") buffer,
+            in("d3") size,
+            in("d4") data,
+            in("d5") control,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadLink(
+    DOSBase: *mut Library,
+    port: *mut MsgPort,
+    lock: BPTR,
+    path: CONST_STRPTR,
+    buffer: STRPTR,
+    size: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ReadLink", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-438, %a6)
+            ".short -438",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") port,
+            in("d2") lock,
+            in("d3") path,
+            in("d4") buffer,
+            in("d5") size,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MakeLink(DOSBase: *mut Library, name: CONST_STRPTR, dest: LONG, soft: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MakeLink", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-444, %a6)
+            ".short -444",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") dest,
+            in("d3") soft,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ChangeMode(DOSBase: *mut Library, type_: LONG, fh: BPTR, newmode: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ChangeMode", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") type_,
+            in("d2") fh,
+            in("d3") newmode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetFileSize(DOSBase: *mut Library, fh: BPTR, pos: LONG, mode: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetFileSize", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            in("d2") pos,
+            in("d3") mode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetIoErr(DOSBase: *mut Library, result: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetIoErr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-462, %a6)
+            ".short -462",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") result,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Fault(
+    DOSBase: *mut Library,
+    code: LONG,
+    header: CONST_STRPTR,
+    buffer: STRPTR,
+    len: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Fault", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-468, %a6)
+            ".short -468",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") code,
+            in("d2") header,
+            in("d3") buffer,
+            in("d4") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PrintFault(DOSBase: *mut Library, code: LONG, header: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "PrintFault", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-474, %a6)
+            ".short -474",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") code,
+            in("d2") header,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ErrorReport(
+    DOSBase: *mut Library,
+    code: LONG,
+    type_: LONG,
+    arg1: ULONG,
+    device: *mut MsgPort,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ErrorReport", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-480, %a6)
+            ".short -480",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") code,
+            in("d2") type_,
+            in("d3") arg1,
+            in("d4") device,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Cli(DOSBase: *mut Library) -> *mut CommandLineInterface {
+    let asm_ret_value: *mut CommandLineInterface;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Cli", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-492, %a6)
+            ".short -492",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateNewProc(DOSBase: *mut Library, tags: *const TagItem) -> *mut Process {
+    let asm_ret_value: *mut Process;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CreateNewProc", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateNewProcTagList(DOSBase: *mut Library, tags: *const TagItem) -> *mut Process {
+    let asm_ret_value: *mut Process;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CreateNewProcTagList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RunCommand(
+    DOSBase: *mut Library,
+    seg: BPTR,
+    stack: LONG,
+    paramptr: CONST_STRPTR,
+    paramlen: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "RunCommand", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-504, %a6)
+            ".short -504",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") seg,
+            in("d2") stack,
+            in("d3") paramptr,
+            in("d4") paramlen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetConsoleTask(DOSBase: *mut Library) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetConsoleTask", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-510, %a6)
+            ".short -510",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetConsoleTask(DOSBase: *mut Library, task: *mut MsgPort) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetConsoleTask", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-516, %a6)
+            ".short -516",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") task,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetFileSysTask(DOSBase: *mut Library) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetFileSysTask", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-522, %a6)
+            ".short -522",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetFileSysTask(DOSBase: *mut Library, task: *mut MsgPort) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetFileSysTask", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-528, %a6)
+            ".short -528",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") task,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetArgStr(DOSBase: *mut Library) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetArgStr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-534, %a6)
+            ".short -534",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetArgStr(DOSBase: *mut Library, string: STRPTR) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetArgStr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-540, %a6)
+            ".short -540",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") string,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindCliProc(DOSBase: *mut Library, num: ULONG) -> *mut Process {
+    let asm_ret_value: *mut Process;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FindCliProc", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-546, %a6)
+            ".short -546",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") num,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MaxCli(DOSBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MaxCli", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-552, %a6)
+            ".short -552",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetCurrentDirName(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetCurrentDirName", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-558, %a6)
+            ".short -558",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetCurrentDirName(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetCurrentDirName", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-564, %a6)
+            ".short -564",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") buf,
+            in("d2") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetProgramName(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetProgramName", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-570, %a6)
+            ".short -570",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetProgramName(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetProgramName", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-576, %a6)
+            ".short -576",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") buf,
+            in("d2") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetPrompt(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetPrompt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-582, %a6)
+            ".short -582",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetPrompt(DOSBase: *mut Library, buf: STRPTR, len: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetPrompt", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-588, %a6)
+            ".short -588",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") buf,
+            in("d2") len,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetProgramDir(DOSBase: *mut Library, lock: BPTR) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetProgramDir", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-594, %a6)
+            ".short -594",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetProgramDir(DOSBase: *mut Library) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetProgramDir", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-600, %a6)
+            ".short -600",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SystemTagList(
+    DOSBase: *mut Library,
+    command: CONST_STRPTR,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SystemTagList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-606, %a6)
+            ".short -606",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") command,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn System(DOSBase: *mut Library, command: CONST_STRPTR, tags: *const TagItem) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "System", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-606, %a6)
+            ".short -606",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") command,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AssignLock(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AssignLock", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-612, %a6)
+            ".short -612",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AssignLate(DOSBase: *mut Library, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AssignLate", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-618, %a6)
+            ".short -618",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") path,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AssignPath(DOSBase: *mut Library, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AssignPath", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-624, %a6)
+            ".short -624",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") path,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AssignAdd(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AssignAdd", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-630, %a6)
+            ".short -630",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemAssignList(DOSBase: *mut Library, name: CONST_STRPTR, lock: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "RemAssignList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-636, %a6)
+            ".short -636",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") lock,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetDeviceProc(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    dp: *mut DevProc,
+) -> *mut DevProc {
+    let asm_ret_value: *mut DevProc;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetDeviceProc", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-642, %a6)
+            ".short -642",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") dp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeDeviceProc(DOSBase: *mut Library, dp: *mut DevProc) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FreeDeviceProc", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-648, %a6)
+            ".short -648",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dp,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockDosList(DOSBase: *mut Library, flags: ULONG) -> *mut DosList {
+    let asm_ret_value: *mut DosList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "LockDosList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-654, %a6)
+            ".short -654",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnLockDosList(DOSBase: *mut Library, flags: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "UnLockDosList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-660, %a6)
+            ".short -660",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") flags,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AttemptLockDosList(DOSBase: *mut Library, flags: ULONG) -> *mut DosList {
+    let asm_ret_value: *mut DosList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AttemptLockDosList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-666, %a6)
+            ".short -666",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemDosEntry(DOSBase: *mut Library, dlist: *mut DosList) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "RemDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-672, %a6)
+            ".short -672",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dlist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddDosEntry(DOSBase: *mut Library, dlist: *mut DosList) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AddDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-678, %a6)
+            ".short -678",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dlist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindDosEntry(
+    DOSBase: *mut Library,
+    dlist: *const DosList,
+    name: CONST_STRPTR,
+    flags: ULONG,
+) -> *mut DosList {
+    let asm_ret_value: *mut DosList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FindDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-684, %a6)
+            ".short -684",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dlist,
+            in("d2") name,
+            in("d3") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NextDosEntry(
+    DOSBase: *mut Library,
+    dlist: *const DosList,
+    flags: ULONG,
+) -> *mut DosList {
+    let asm_ret_value: *mut DosList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "NextDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-690, %a6)
+            ".short -690",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dlist,
+            in("d2") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MakeDosEntry(DOSBase: *mut Library, name: CONST_STRPTR, type_: LONG) -> *mut DosList {
+    let asm_ret_value: *mut DosList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MakeDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-696, %a6)
+            ".short -696",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") type_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeDosEntry(DOSBase: *mut Library, dlist: *mut DosList) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FreeDosEntry", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-702, %a6)
+            ".short -702",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dlist,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IsFileSystem(DOSBase: *mut Library, name: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "IsFileSystem", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-708, %a6)
+            ".short -708",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Format(
+    DOSBase: *mut Library,
+    filesystem: CONST_STRPTR,
+    volumename: CONST_STRPTR,
+    dostype: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Format", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-714, %a6)
+            ".short -714",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") filesystem,
+            in("d2") volumename,
+            in("d3") dostype,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Relabel(DOSBase: *mut Library, drive: CONST_STRPTR, newname: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Relabel", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-720, %a6)
+            ".short -720",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") drive,
+            in("d2") newname,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Inhibit(DOSBase: *mut Library, name: CONST_STRPTR, onoff: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "Inhibit", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-726, %a6)
+            ".short -726",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") onoff,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddBuffers(DOSBase: *mut Library, name: CONST_STRPTR, number: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AddBuffers", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-732, %a6)
+            ".short -732",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") number,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CompareDates(
+    DOSBase: *mut Library,
+    date1: *const DateStamp,
+    date2: *const DateStamp,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CompareDates", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-738, %a6)
+            ".short -738",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") date1,
+            in("d2") date2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DateToStr(DOSBase: *mut Library, datetime: *mut DateTime) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DateToStr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-744, %a6)
+            ".short -744",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") datetime,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StrToDate(DOSBase: *mut Library, datetime: *mut DateTime) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "StrToDate", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-750, %a6)
+            ".short -750",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") datetime,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InternalLoadSeg(
+    DOSBase: *mut Library,
+    fh: BPTR,
+    table: BPTR,
+    funcarray: *const LONG,
+    stack: *mut LONG,
+) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "InternalLoadSeg", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-756, %a6)
+            ".short -756",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d0") fh,
+            in("a0") table,
+            in("a1") funcarray,
+            in("a2") stack,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InternalUnLoadSeg(DOSBase: *mut Library, seglist: BPTR, freefunc: FPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "InternalUnLoadSeg", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-762, %a6)
+            ".short -762",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") seglist,
+            in("a1") freefunc,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NewLoadSeg(DOSBase: *mut Library, file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "NewLoadSeg", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-768, %a6)
+            ".short -768",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NewLoadSegTagList(
+    DOSBase: *mut Library,
+    file: CONST_STRPTR,
+    tags: *const TagItem,
+) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "NewLoadSegTagList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-768, %a6)
+            ".short -768",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") file,
+            in("d2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddSegment(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    seg: BPTR,
+    system: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AddSegment", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-774, %a6)
+            ".short -774",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") seg,
+            in("d3") system,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindSegment(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    seg: *const Segment,
+    system: LONG,
+) -> *mut Segment {
+    let asm_ret_value: *mut Segment;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FindSegment", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-780, %a6)
+            ".short -780",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") seg,
+            in("d3") system,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemSegment(DOSBase: *mut Library, seg: *mut Segment) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "RemSegment", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-786, %a6)
+            ".short -786",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") seg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CheckSignal(DOSBase: *mut Library, mask: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CheckSignal", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-792, %a6)
+            ".short -792",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") mask,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadArgs(
+    DOSBase: *mut Library,
+    arg_template: CONST_STRPTR,
+    array: *mut LONG,
+    args: *mut RDArgs,
+) -> *mut RDArgs {
+    let asm_ret_value: *mut RDArgs;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ReadArgs", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-798, %a6)
+            ".short -798",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") arg_template,
+            in("d2") array,
+            in("d3") args,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindArg(
+    DOSBase: *mut Library,
+    keyword: CONST_STRPTR,
+    arg_template: CONST_STRPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FindArg", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-804, %a6)
+            ".short -804",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") keyword,
+            in("d2") arg_template,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadItem(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    maxchars: LONG,
+    cSource: *mut CSource,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ReadItem", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-810, %a6)
+            ".short -810",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") maxchars,
+            in("d3") cSource,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StrToLong(DOSBase: *mut Library, string: CONST_STRPTR, value: *mut LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "StrToLong", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-816, %a6)
+            ".short -816",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") string,
+            in("d2") value,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MatchFirst(
+    DOSBase: *mut Library,
+    pat: CONST_STRPTR,
+    anchor: *mut AnchorPath,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MatchFirst", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-822, %a6)
+            ".short -822",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") pat,
+            in("d2") anchor,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MatchNext(DOSBase: *mut Library, anchor: *mut AnchorPath) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MatchNext", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-828, %a6)
+            ".short -828",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") anchor,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MatchEnd(DOSBase: *mut Library, anchor: *mut AnchorPath) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MatchEnd", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-834, %a6)
+            ".short -834",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") anchor,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ParsePattern(
+    DOSBase: *mut Library,
+    pat: CONST_STRPTR,
+    patbuf: *mut UBYTE,
+    patbuflen: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "ParsePattern", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-840, %a6)
+            ".short -840",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") pat,
+            in("d2") patbuf,
+            in("d3") patbuflen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MatchPattern(
+    DOSBase: *mut Library,
+    patbuf: *const UBYTE,
+    str_: CONST_STRPTR,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "MatchPattern", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-846, %a6)
+            ".short -846",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") patbuf,
+            in("d2") str_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeArgs(DOSBase: *mut Library, args: *mut RDArgs) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FreeArgs", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-858, %a6)
+            ".short -858",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") args,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FilePart(DOSBase: *mut Library, path: CONST_STRPTR) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FilePart", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-870, %a6)
+            ".short -870",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") path,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PathPart(DOSBase: *mut Library, path: CONST_STRPTR) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "PathPart", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-876, %a6)
+            ".short -876",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") path,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddPart(
+    DOSBase: *mut Library,
+    dirname: STRPTR,
+    filename: CONST_STRPTR,
+    size: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "AddPart", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-882, %a6)
+            ".short -882",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") dirname,
+            in("d2") filename,
+            in("d3") size,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StartNotify(DOSBase: *mut Library, notify: *mut NotifyRequest) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "StartNotify", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-888, %a6)
+            ".short -888",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") notify,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EndNotify(DOSBase: *mut Library, notify: *mut NotifyRequest) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "EndNotify", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-894, %a6)
+            ".short -894",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") notify,
+        );
+    }
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetVar(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    buffer: CONST_STRPTR,
+    size: LONG,
+    flags: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "SetVar", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-900, %a6)
+            ".short -900",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") buffer,
+            in("d3") size,
+            in("d4") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetVar(
+    DOSBase: *mut Library,
+    name: CONST_STRPTR,
+    buffer: STRPTR,
+    size: LONG,
+    flags: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "GetVar", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-906, %a6)
+            ".short -906",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") buffer,
+            in("d3") size,
+            in("d4") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteVar(DOSBase: *mut Library, name: CONST_STRPTR, flags: ULONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "DeleteVar", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-912, %a6)
+            ".short -912",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") flags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindVar(DOSBase: *mut Library, name: CONST_STRPTR, type_: ULONG) -> *mut LocalVar {
+    let asm_ret_value: *mut LocalVar;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "FindVar", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-918, %a6)
+            ".short -918",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") type_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CliInitNewcli(DOSBase: *mut Library, dp: *mut DosPacket) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CliInitNewcli", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-930, %a6)
+            ".short -930",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("a0") dp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CliInitRun(DOSBase: *mut Library, dp: *mut DosPacket) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "CliInitRun", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-936, %a6)
+            ".short -936",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("a0") dp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WriteChars(DOSBase: *mut Library, buf: CONST_STRPTR, buflen: ULONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "WriteChars", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-942, %a6)
+            ".short -942",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") buf,
+            in("d2") buflen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PutStr(DOSBase: *mut Library, str_: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "PutStr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-948, %a6)
+            ".short -948",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") str_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn VPrintf(DOSBase: *mut Library, format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 36, "VPrintf", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-954, %a6)
+            ".short -954",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") format,
+            in("d2") argarray,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn ParsePatternNoCase(
+    DOSBase: *mut Library,
+    pat: CONST_STRPTR,
+    patbuf: *mut UBYTE,
+    patbuflen: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 37, "ParsePatternNoCase", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-966, %a6)
+            ".short -966",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") pat,
+            in("d2") patbuf,
+            in("d3") patbuflen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn MatchPatternNoCase(
+    DOSBase: *mut Library,
+    patbuf: *const UBYTE,
+    str_: CONST_STRPTR,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 37, "MatchPatternNoCase", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-972, %a6)
+            ".short -972",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") patbuf,
+            in("d2") str_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn SameDevice(DOSBase: *mut Library, lock1: BPTR, lock2: BPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 37, "SameDevice", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-984, %a6)
+            ".short -984",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock1,
+            in("d2") lock2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ExAllEnd(
+    DOSBase: *mut Library,
+    lock: BPTR,
+    buffer: *mut ExAllData,
+    size: LONG,
+    data: LONG,
+    control: *mut ExAllControl,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 39, "ExAllEnd", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-990, %a6)
+            ".short -990",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") lock,
+            in("d2") buffer,
+            in("d3") size,
+            in("d4") data,
+            in("d5") control,
+        );
+    }
+}
+
+/// libname: "dos.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetOwner(DOSBase: *mut Library, name: CONST_STRPTR, owner_info: LONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 39, "SetOwner", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-996, %a6)
+            ".short -996",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") name,
+            in("d2") owner_info,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn VolumeRequestHook(DOSBase: *mut Library, vol: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "VolumeRequestHook", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1014, %a6)
+            ".short -1014",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") vol,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn GetCurrentDir(DOSBase: *mut Library) -> BPTR {
+    let asm_ret_value: BPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "GetCurrentDir", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1026, %a6)
+            ".short -1026",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn PutErrStr(DOSBase: *mut Library, str_: CONST_STRPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "PutErrStr", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1128, %a6)
+            ".short -1128",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") str_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ErrorOutput(DOSBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "ErrorOutput", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1134, %a6)
+            ".short -1134",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SelectError(DOSBase: *mut Library, fh: BPTR) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "SelectError", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1140, %a6)
+            ".short -1140",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") fh,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn DoShellMethodTagList(
+    DOSBase: *mut Library,
+    method: ULONG,
+    tags: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "DoShellMethodTagList", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1152, %a6)
+            ".short -1152",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d0") method,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ScanStackToken(DOSBase: *mut Library, seg: BPTR, defaultstack: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(DOSBase, 47, "ScanStackToken", "dos.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1158, %a6)
+            ".short -1158",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) DOSBase,
+            in("d1") seg,
+            in("d2") defaultstack,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "dos.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct DosLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl DosLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"dos.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "dos.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Open(&self, name: CONST_STRPTR, accessMode: LONG) -> BPTR {
+        unsafe { Open(self.base.as_ptr(), name, accessMode) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Close(&self, file: BPTR) -> LONG {
+        unsafe { Close(self.base.as_ptr(), file) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Read(&self, file: BPTR, buffer: APTR, length: LONG) -> LONG {
+        unsafe { Read(self.base.as_ptr(), file, buffer, length) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Write(&self, file: BPTR, buffer: CONST_APTR, length: LONG) -> LONG {
+        unsafe { Write(self.base.as_ptr(), file, buffer, length) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Input(&self) -> BPTR {
+        unsafe { Input(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Output(&self) -> BPTR {
+        unsafe { Output(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Seek(&self, file: BPTR, position: LONG, offset: LONG) -> LONG {
+        unsafe { Seek(self.base.as_ptr(), file, position, offset) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn DeleteFile(&self, name: CONST_STRPTR) -> LONG {
+        unsafe { DeleteFile(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Rename(&self, oldName: CONST_STRPTR, newName: CONST_STRPTR) -> LONG {
+        unsafe { Rename(self.base.as_ptr(), oldName, newName) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Lock(&self, name: CONST_STRPTR, type_: LONG) -> BPTR {
+        unsafe { Lock(self.base.as_ptr(), name, type_) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn UnLock(&self, lock: BPTR) {
+        unsafe { UnLock(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn DupLock(&self, lock: BPTR) -> BPTR {
+        unsafe { DupLock(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Examine(&self, lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
+        unsafe { Examine(self.base.as_ptr(), lock, fileInfoBlock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn ExNext(&self, lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
+        unsafe { ExNext(self.base.as_ptr(), lock, fileInfoBlock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Info(&self, lock: BPTR, parameterBlock: *mut InfoData) -> LONG {
+        unsafe { Info(self.base.as_ptr(), lock, parameterBlock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn CreateDir(&self, name: CONST_STRPTR) -> BPTR {
+        unsafe { CreateDir(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn CurrentDir(&self, lock: BPTR) -> BPTR {
+        unsafe { CurrentDir(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn IoErr(&self) -> LONG {
+        unsafe { IoErr(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn CreateProc(
+        &self,
+        name: CONST_STRPTR,
+        pri: LONG,
+        segList: BPTR,
+        stackSize: LONG,
+    ) -> *mut MsgPort {
+        unsafe { CreateProc(self.base.as_ptr(), name, pri, segList, stackSize) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Exit(&self, returnCode: LONG) {
+        unsafe { Exit(self.base.as_ptr(), returnCode) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn LoadSeg(&self, name: CONST_STRPTR) -> BPTR {
+        unsafe { LoadSeg(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn UnLoadSeg(&self, seglist: BPTR) {
+        unsafe { UnLoadSeg(self.base.as_ptr(), seglist) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn DeviceProc(&self, name: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { DeviceProc(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn SetComment(&self, name: CONST_STRPTR, comment: CONST_STRPTR) -> LONG {
+        unsafe { SetComment(self.base.as_ptr(), name, comment) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn SetProtection(&self, name: CONST_STRPTR, protect: LONG) -> LONG {
+        unsafe { SetProtection(self.base.as_ptr(), name, protect) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn DateStamp(&self, date: *mut DateStamp) -> *mut DateStamp {
+        unsafe { DateStamp(self.base.as_ptr(), date) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Delay(&self, timeout: LONG) {
+        unsafe { Delay(self.base.as_ptr(), timeout) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn WaitForChar(&self, file: BPTR, timeout: LONG) -> LONG {
+        unsafe { WaitForChar(self.base.as_ptr(), file, timeout) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn ParentDir(&self, lock: BPTR) -> BPTR {
+        unsafe { ParentDir(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn IsInteractive(&self, file: BPTR) -> LONG {
+        unsafe { IsInteractive(self.base.as_ptr(), file) }
+    }
+
+    /// libname: "dos.library"
+    pub unsafe fn Execute(&self, string: CONST_STRPTR, file: BPTR, file2: BPTR) -> LONG {
+        unsafe { Execute(self.base.as_ptr(), string, file, file2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocDosObject(&self, type_: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { AllocDosObject(self.base.as_ptr(), type_, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocDosObjectTagList(&self, type_: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { AllocDosObjectTagList(self.base.as_ptr(), type_, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeDosObject(&self, type_: ULONG, ptr: APTR) {
+        unsafe { FreeDosObject(self.base.as_ptr(), type_, ptr) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt(
+        &self,
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+        arg4: LONG,
+        arg5: LONG,
+    ) -> LONG {
+        unsafe {
+            DoPkt(
+                self.base.as_ptr(),
+                port,
+                action,
+                arg1,
+                arg2,
+                arg3,
+                arg4,
+                arg5,
+            )
+        }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt0(&self, port: *mut MsgPort, action: LONG) -> LONG {
+        unsafe { DoPkt0(self.base.as_ptr(), port, action) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt1(&self, port: *mut MsgPort, action: LONG, arg1: LONG) -> LONG {
+        unsafe { DoPkt1(self.base.as_ptr(), port, action, arg1) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt2(&self, port: *mut MsgPort, action: LONG, arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { DoPkt2(self.base.as_ptr(), port, action, arg1, arg2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt3(
+        &self,
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+    ) -> LONG {
+        unsafe { DoPkt3(self.base.as_ptr(), port, action, arg1, arg2, arg3) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DoPkt4(
+        &self,
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+        arg4: LONG,
+    ) -> LONG {
+        unsafe { DoPkt4(self.base.as_ptr(), port, action, arg1, arg2, arg3, arg4) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SendPkt(&self, dp: *mut DosPacket, port: *mut MsgPort, replyport: *mut MsgPort) {
+        unsafe { SendPkt(self.base.as_ptr(), dp, port, replyport) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WaitPkt(&self) -> *mut DosPacket {
+        unsafe { WaitPkt(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReplyPkt(&self, dp: *mut DosPacket, res1: LONG, res2: LONG) {
+        unsafe { ReplyPkt(self.base.as_ptr(), dp, res1, res2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AbortPkt(&self, port: *mut MsgPort, pkt: *mut DosPacket) {
+        unsafe { AbortPkt(self.base.as_ptr(), port, pkt) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockRecord(
+        &self,
+        fh: BPTR,
+        offset: ULONG,
+        length: ULONG,
+        mode: ULONG,
+        timeout: ULONG,
+    ) -> BOOL {
+        unsafe { LockRecord(self.base.as_ptr(), fh, offset, length, mode, timeout) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockRecords(&self, recArray: *const RecordLock, timeout: ULONG) -> BOOL {
+        unsafe { LockRecords(self.base.as_ptr(), recArray, timeout) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnLockRecord(&self, fh: BPTR, offset: ULONG, length: ULONG) -> BOOL {
+        unsafe { UnLockRecord(self.base.as_ptr(), fh, offset, length) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnLockRecords(&self, recArray: *const RecordLock) -> BOOL {
+        unsafe { UnLockRecords(self.base.as_ptr(), recArray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SelectInput(&self, fh: BPTR) -> BPTR {
+        unsafe { SelectInput(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SelectOutput(&self, fh: BPTR) -> BPTR {
+        unsafe { SelectOutput(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FGetC(&self, fh: BPTR) -> LONG {
+        unsafe { FGetC(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FPutC(&self, fh: BPTR, ch: LONG) -> LONG {
+        unsafe { FPutC(self.base.as_ptr(), fh, ch) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnGetC(&self, fh: BPTR, character: LONG) -> LONG {
+        unsafe { UnGetC(self.base.as_ptr(), fh, character) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FRead(&self, fh: BPTR, block: APTR, blocklen: ULONG, number: ULONG) -> LONG {
+        unsafe { FRead(self.base.as_ptr(), fh, block, blocklen, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FWrite(
+        &self,
+        fh: BPTR,
+        block: CONST_APTR,
+        blocklen: ULONG,
+        number: ULONG,
+    ) -> LONG {
+        unsafe { FWrite(self.base.as_ptr(), fh, block, blocklen, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FGets(&self, fh: BPTR, buf: STRPTR, buflen: ULONG) -> STRPTR {
+        unsafe { FGets(self.base.as_ptr(), fh, buf, buflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FPuts(&self, fh: BPTR, str_: CONST_STRPTR) -> LONG {
+        unsafe { FPuts(self.base.as_ptr(), fh, str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn VFWritef(&self, fh: BPTR, format: CONST_STRPTR, argarray: *const LONG) {
+        unsafe { VFWritef(self.base.as_ptr(), fh, format, argarray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn VFPrintf(&self, fh: BPTR, format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
+        unsafe { VFPrintf(self.base.as_ptr(), fh, format, argarray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Flush(&self, fh: BPTR) -> LONG {
+        unsafe { Flush(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetVBuf(&self, fh: BPTR, buff: STRPTR, type_: LONG, size: LONG) -> LONG {
+        unsafe { SetVBuf(self.base.as_ptr(), fh, buff, type_, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DupLockFromFH(&self, fh: BPTR) -> BPTR {
+        unsafe { DupLockFromFH(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenFromLock(&self, lock: BPTR) -> BPTR {
+        unsafe { OpenFromLock(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ParentOfFH(&self, fh: BPTR) -> BPTR {
+        unsafe { ParentOfFH(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ExamineFH(&self, fh: BPTR, fib: *mut FileInfoBlock) -> BOOL {
+        unsafe { ExamineFH(self.base.as_ptr(), fh, fib) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetFileDate(&self, name: CONST_STRPTR, date: *const DateStamp) -> LONG {
+        unsafe { SetFileDate(self.base.as_ptr(), name, date) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NameFromLock(&self, lock: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+        unsafe { NameFromLock(self.base.as_ptr(), lock, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NameFromFH(&self, fh: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+        unsafe { NameFromFH(self.base.as_ptr(), fh, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SplitName(
+        &self,
+        name: CONST_STRPTR,
+        separator: ULONG,
+        buf: STRPTR,
+        oldpos: LONG,
+        size: LONG,
+    ) -> WORD {
+        unsafe { SplitName(self.base.as_ptr(), name, separator, buf, oldpos, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SameLock(&self, lock1: BPTR, lock2: BPTR) -> LONG {
+        unsafe { SameLock(self.base.as_ptr(), lock1, lock2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetMode(&self, fh: BPTR, mode: LONG) -> LONG {
+        unsafe { SetMode(self.base.as_ptr(), fh, mode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ExAll(
+        &self,
+        lock: BPTR,
+        buffer: *mut ExAllData,
+        size: LONG,
+        data: LONG,
+        control: *mut ExAllControl,
+    ) -> LONG {
+        unsafe { ExAll(self.base.as_ptr(), lock, buffer, size, data, control) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadLink(
+        &self,
+        port: *mut MsgPort,
+        lock: BPTR,
+        path: CONST_STRPTR,
+        buffer: STRPTR,
+        size: ULONG,
+    ) -> LONG {
+        unsafe { ReadLink(self.base.as_ptr(), port, lock, path, buffer, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MakeLink(&self, name: CONST_STRPTR, dest: LONG, soft: LONG) -> LONG {
+        unsafe { MakeLink(self.base.as_ptr(), name, dest, soft) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ChangeMode(&self, type_: LONG, fh: BPTR, newmode: LONG) -> LONG {
+        unsafe { ChangeMode(self.base.as_ptr(), type_, fh, newmode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetFileSize(&self, fh: BPTR, pos: LONG, mode: LONG) -> LONG {
+        unsafe { SetFileSize(self.base.as_ptr(), fh, pos, mode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetIoErr(&self, result: LONG) -> LONG {
+        unsafe { SetIoErr(self.base.as_ptr(), result) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Fault(
+        &self,
+        code: LONG,
+        header: CONST_STRPTR,
+        buffer: STRPTR,
+        len: LONG,
+    ) -> BOOL {
+        unsafe { Fault(self.base.as_ptr(), code, header, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PrintFault(&self, code: LONG, header: CONST_STRPTR) -> BOOL {
+        unsafe { PrintFault(self.base.as_ptr(), code, header) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ErrorReport(
+        &self,
+        code: LONG,
+        type_: LONG,
+        arg1: ULONG,
+        device: *mut MsgPort,
+    ) -> LONG {
+        unsafe { ErrorReport(self.base.as_ptr(), code, type_, arg1, device) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Cli(&self) -> *mut CommandLineInterface {
+        unsafe { Cli(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateNewProc(&self, tags: *const TagItem) -> *mut Process {
+        unsafe { CreateNewProc(self.base.as_ptr(), tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateNewProcTagList(&self, tags: *const TagItem) -> *mut Process {
+        unsafe { CreateNewProcTagList(self.base.as_ptr(), tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RunCommand(
+        &self,
+        seg: BPTR,
+        stack: LONG,
+        paramptr: CONST_STRPTR,
+        paramlen: LONG,
+    ) -> LONG {
+        unsafe { RunCommand(self.base.as_ptr(), seg, stack, paramptr, paramlen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetConsoleTask(&self) -> *mut MsgPort {
+        unsafe { GetConsoleTask(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetConsoleTask(&self, task: *mut MsgPort) -> *mut MsgPort {
+        unsafe { SetConsoleTask(self.base.as_ptr(), task) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetFileSysTask(&self) -> *mut MsgPort {
+        unsafe { GetFileSysTask(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetFileSysTask(&self, task: *mut MsgPort) -> *mut MsgPort {
+        unsafe { SetFileSysTask(self.base.as_ptr(), task) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetArgStr(&self) -> STRPTR {
+        unsafe { GetArgStr(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetArgStr(&self, string: STRPTR) -> STRPTR {
+        unsafe { SetArgStr(self.base.as_ptr(), string) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindCliProc(&self, num: ULONG) -> *mut Process {
+        unsafe { FindCliProc(self.base.as_ptr(), num) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MaxCli(&self) -> ULONG {
+        unsafe { MaxCli(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetCurrentDirName(&self, name: CONST_STRPTR) -> BOOL {
+        unsafe { SetCurrentDirName(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetCurrentDirName(&self, buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { GetCurrentDirName(self.base.as_ptr(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetProgramName(&self, name: CONST_STRPTR) -> BOOL {
+        unsafe { SetProgramName(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetProgramName(&self, buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { GetProgramName(self.base.as_ptr(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetPrompt(&self, name: CONST_STRPTR) -> BOOL {
+        unsafe { SetPrompt(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetPrompt(&self, buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { GetPrompt(self.base.as_ptr(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetProgramDir(&self, lock: BPTR) -> BPTR {
+        unsafe { SetProgramDir(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetProgramDir(&self) -> BPTR {
+        unsafe { GetProgramDir(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SystemTagList(&self, command: CONST_STRPTR, tags: *const TagItem) -> LONG {
+        unsafe { SystemTagList(self.base.as_ptr(), command, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn System(&self, command: CONST_STRPTR, tags: *const TagItem) -> LONG {
+        unsafe { System(self.base.as_ptr(), command, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AssignLock(&self, name: CONST_STRPTR, lock: BPTR) -> LONG {
+        unsafe { AssignLock(self.base.as_ptr(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AssignLate(&self, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+        unsafe { AssignLate(self.base.as_ptr(), name, path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AssignPath(&self, name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+        unsafe { AssignPath(self.base.as_ptr(), name, path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AssignAdd(&self, name: CONST_STRPTR, lock: BPTR) -> BOOL {
+        unsafe { AssignAdd(self.base.as_ptr(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemAssignList(&self, name: CONST_STRPTR, lock: BPTR) -> LONG {
+        unsafe { RemAssignList(self.base.as_ptr(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetDeviceProc(&self, name: CONST_STRPTR, dp: *mut DevProc) -> *mut DevProc {
+        unsafe { GetDeviceProc(self.base.as_ptr(), name, dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeDeviceProc(&self, dp: *mut DevProc) {
+        unsafe { FreeDeviceProc(self.base.as_ptr(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockDosList(&self, flags: ULONG) -> *mut DosList {
+        unsafe { LockDosList(self.base.as_ptr(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnLockDosList(&self, flags: ULONG) {
+        unsafe { UnLockDosList(self.base.as_ptr(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AttemptLockDosList(&self, flags: ULONG) -> *mut DosList {
+        unsafe { AttemptLockDosList(self.base.as_ptr(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemDosEntry(&self, dlist: *mut DosList) -> BOOL {
+        unsafe { RemDosEntry(self.base.as_ptr(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddDosEntry(&self, dlist: *mut DosList) -> LONG {
+        unsafe { AddDosEntry(self.base.as_ptr(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindDosEntry(
+        &self,
+        dlist: *const DosList,
+        name: CONST_STRPTR,
+        flags: ULONG,
+    ) -> *mut DosList {
+        unsafe { FindDosEntry(self.base.as_ptr(), dlist, name, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NextDosEntry(&self, dlist: *const DosList, flags: ULONG) -> *mut DosList {
+        unsafe { NextDosEntry(self.base.as_ptr(), dlist, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MakeDosEntry(&self, name: CONST_STRPTR, type_: LONG) -> *mut DosList {
+        unsafe { MakeDosEntry(self.base.as_ptr(), name, type_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeDosEntry(&self, dlist: *mut DosList) {
+        unsafe { FreeDosEntry(self.base.as_ptr(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IsFileSystem(&self, name: CONST_STRPTR) -> BOOL {
+        unsafe { IsFileSystem(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Format(
+        &self,
+        filesystem: CONST_STRPTR,
+        volumename: CONST_STRPTR,
+        dostype: ULONG,
+    ) -> BOOL {
+        unsafe { Format(self.base.as_ptr(), filesystem, volumename, dostype) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Relabel(&self, drive: CONST_STRPTR, newname: CONST_STRPTR) -> LONG {
+        unsafe { Relabel(self.base.as_ptr(), drive, newname) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Inhibit(&self, name: CONST_STRPTR, onoff: LONG) -> LONG {
+        unsafe { Inhibit(self.base.as_ptr(), name, onoff) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddBuffers(&self, name: CONST_STRPTR, number: LONG) -> LONG {
+        unsafe { AddBuffers(self.base.as_ptr(), name, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CompareDates(&self, date1: *const DateStamp, date2: *const DateStamp) -> LONG {
+        unsafe { CompareDates(self.base.as_ptr(), date1, date2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DateToStr(&self, datetime: *mut DateTime) -> LONG {
+        unsafe { DateToStr(self.base.as_ptr(), datetime) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StrToDate(&self, datetime: *mut DateTime) -> LONG {
+        unsafe { StrToDate(self.base.as_ptr(), datetime) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InternalLoadSeg(
+        &self,
+        fh: BPTR,
+        table: BPTR,
+        funcarray: *const LONG,
+        stack: *mut LONG,
+    ) -> BPTR {
+        unsafe { InternalLoadSeg(self.base.as_ptr(), fh, table, funcarray, stack) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InternalUnLoadSeg(&self, seglist: BPTR, freefunc: FPTR) -> BOOL {
+        unsafe { InternalUnLoadSeg(self.base.as_ptr(), seglist, freefunc) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NewLoadSeg(&self, file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
+        unsafe { NewLoadSeg(self.base.as_ptr(), file, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NewLoadSegTagList(&self, file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
+        unsafe { NewLoadSegTagList(self.base.as_ptr(), file, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddSegment(&self, name: CONST_STRPTR, seg: BPTR, system: LONG) -> LONG {
+        unsafe { AddSegment(self.base.as_ptr(), name, seg, system) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindSegment(
+        &self,
+        name: CONST_STRPTR,
+        seg: *const Segment,
+        system: LONG,
+    ) -> *mut Segment {
+        unsafe { FindSegment(self.base.as_ptr(), name, seg, system) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemSegment(&self, seg: *mut Segment) -> LONG {
+        unsafe { RemSegment(self.base.as_ptr(), seg) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CheckSignal(&self, mask: LONG) -> LONG {
+        unsafe { CheckSignal(self.base.as_ptr(), mask) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadArgs(
+        &self,
+        arg_template: CONST_STRPTR,
+        array: *mut LONG,
+        args: *mut RDArgs,
+    ) -> *mut RDArgs {
+        unsafe { ReadArgs(self.base.as_ptr(), arg_template, array, args) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindArg(&self, keyword: CONST_STRPTR, arg_template: CONST_STRPTR) -> LONG {
+        unsafe { FindArg(self.base.as_ptr(), keyword, arg_template) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadItem(
+        &self,
+        name: CONST_STRPTR,
+        maxchars: LONG,
+        cSource: *mut CSource,
+    ) -> LONG {
+        unsafe { ReadItem(self.base.as_ptr(), name, maxchars, cSource) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StrToLong(&self, string: CONST_STRPTR, value: *mut LONG) -> LONG {
+        unsafe { StrToLong(self.base.as_ptr(), string, value) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MatchFirst(&self, pat: CONST_STRPTR, anchor: *mut AnchorPath) -> LONG {
+        unsafe { MatchFirst(self.base.as_ptr(), pat, anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MatchNext(&self, anchor: *mut AnchorPath) -> LONG {
+        unsafe { MatchNext(self.base.as_ptr(), anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MatchEnd(&self, anchor: *mut AnchorPath) {
+        unsafe { MatchEnd(self.base.as_ptr(), anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ParsePattern(
+        &self,
+        pat: CONST_STRPTR,
+        patbuf: *mut UBYTE,
+        patbuflen: LONG,
+    ) -> LONG {
+        unsafe { ParsePattern(self.base.as_ptr(), pat, patbuf, patbuflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MatchPattern(&self, patbuf: *const UBYTE, str_: CONST_STRPTR) -> BOOL {
+        unsafe { MatchPattern(self.base.as_ptr(), patbuf, str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeArgs(&self, args: *mut RDArgs) {
+        unsafe { FreeArgs(self.base.as_ptr(), args) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FilePart(&self, path: CONST_STRPTR) -> STRPTR {
+        unsafe { FilePart(self.base.as_ptr(), path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PathPart(&self, path: CONST_STRPTR) -> STRPTR {
+        unsafe { PathPart(self.base.as_ptr(), path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddPart(&self, dirname: STRPTR, filename: CONST_STRPTR, size: ULONG) -> BOOL {
+        unsafe { AddPart(self.base.as_ptr(), dirname, filename, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StartNotify(&self, notify: *mut NotifyRequest) -> BOOL {
+        unsafe { StartNotify(self.base.as_ptr(), notify) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EndNotify(&self, notify: *mut NotifyRequest) {
+        unsafe { EndNotify(self.base.as_ptr(), notify) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetVar(
+        &self,
+        name: CONST_STRPTR,
+        buffer: CONST_STRPTR,
+        size: LONG,
+        flags: LONG,
+    ) -> BOOL {
+        unsafe { SetVar(self.base.as_ptr(), name, buffer, size, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetVar(
+        &self,
+        name: CONST_STRPTR,
+        buffer: STRPTR,
+        size: LONG,
+        flags: LONG,
+    ) -> LONG {
+        unsafe { GetVar(self.base.as_ptr(), name, buffer, size, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteVar(&self, name: CONST_STRPTR, flags: ULONG) -> LONG {
+        unsafe { DeleteVar(self.base.as_ptr(), name, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindVar(&self, name: CONST_STRPTR, type_: ULONG) -> *mut LocalVar {
+        unsafe { FindVar(self.base.as_ptr(), name, type_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CliInitNewcli(&self, dp: *mut DosPacket) -> LONG {
+        unsafe { CliInitNewcli(self.base.as_ptr(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CliInitRun(&self, dp: *mut DosPacket) -> LONG {
+        unsafe { CliInitRun(self.base.as_ptr(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WriteChars(&self, buf: CONST_STRPTR, buflen: ULONG) -> LONG {
+        unsafe { WriteChars(self.base.as_ptr(), buf, buflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PutStr(&self, str_: CONST_STRPTR) -> LONG {
+        unsafe { PutStr(self.base.as_ptr(), str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn VPrintf(&self, format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
+        unsafe { VPrintf(self.base.as_ptr(), format, argarray) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn ParsePatternNoCase(
+        &self,
+        pat: CONST_STRPTR,
+        patbuf: *mut UBYTE,
+        patbuflen: LONG,
+    ) -> LONG {
+        unsafe { ParsePatternNoCase(self.base.as_ptr(), pat, patbuf, patbuflen) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn MatchPatternNoCase(&self, patbuf: *const UBYTE, str_: CONST_STRPTR) -> BOOL {
+        unsafe { MatchPatternNoCase(self.base.as_ptr(), patbuf, str_) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn SameDevice(&self, lock1: BPTR, lock2: BPTR) -> BOOL {
+        unsafe { SameDevice(self.base.as_ptr(), lock1, lock2) }
+    }
+
+    /// libname: "dos.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ExAllEnd(
+        &self,
+        lock: BPTR,
+        buffer: *mut ExAllData,
+        size: LONG,
+        data: LONG,
+        control: *mut ExAllControl,
+    ) {
+        unsafe { ExAllEnd(self.base.as_ptr(), lock, buffer, size, data, control) }
+    }
+
+    /// libname: "dos.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetOwner(&self, name: CONST_STRPTR, owner_info: LONG) -> BOOL {
+        unsafe { SetOwner(self.base.as_ptr(), name, owner_info) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn VolumeRequestHook(&self, vol: CONST_STRPTR) -> LONG {
+        unsafe { VolumeRequestHook(self.base.as_ptr(), vol) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn GetCurrentDir(&self) -> BPTR {
+        unsafe { GetCurrentDir(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn PutErrStr(&self, str_: CONST_STRPTR) -> LONG {
+        unsafe { PutErrStr(self.base.as_ptr(), str_) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ErrorOutput(&self) -> LONG {
+        unsafe { ErrorOutput(self.base.as_ptr()) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SelectError(&self, fh: BPTR) -> LONG {
+        unsafe { SelectError(self.base.as_ptr(), fh) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn DoShellMethodTagList(&self, method: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { DoShellMethodTagList(self.base.as_ptr(), method, tags) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ScanStackToken(&self, seg: BPTR, defaultstack: LONG) -> LONG {
+        unsafe { ScanStackToken(self.base.as_ptr(), seg, defaultstack) }
+    }
+}
+
+impl Drop for DosLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `Open()`
+pub const LVO_Open: i16 = -30;
+/// Library vector offset of `Close()`
+pub const LVO_Close: i16 = -36;
+/// Library vector offset of `Read()`
+pub const LVO_Read: i16 = -42;
+/// Library vector offset of `Write()`
+pub const LVO_Write: i16 = -48;
+/// Library vector offset of `Input()`
+pub const LVO_Input: i16 = -54;
+/// Library vector offset of `Output()`
+pub const LVO_Output: i16 = -60;
+/// Library vector offset of `Seek()`
+pub const LVO_Seek: i16 = -66;
+/// Library vector offset of `DeleteFile()`
+pub const LVO_DeleteFile: i16 = -72;
+/// Library vector offset of `Rename()`
+pub const LVO_Rename: i16 = -78;
+/// Library vector offset of `Lock()`
+pub const LVO_Lock: i16 = -84;
+/// Library vector offset of `UnLock()`
+pub const LVO_UnLock: i16 = -90;
+/// Library vector offset of `DupLock()`
+pub const LVO_DupLock: i16 = -96;
+/// Library vector offset of `Examine()`
+pub const LVO_Examine: i16 = -102;
+/// Library vector offset of `ExNext()`
+pub const LVO_ExNext: i16 = -108;
+/// Library vector offset of `Info()`
+pub const LVO_Info: i16 = -114;
+/// Library vector offset of `CreateDir()`
+pub const LVO_CreateDir: i16 = -120;
+/// Library vector offset of `CurrentDir()`
+pub const LVO_CurrentDir: i16 = -126;
+/// Library vector offset of `IoErr()`
+pub const LVO_IoErr: i16 = -132;
+/// Library vector offset of `CreateProc()`
+pub const LVO_CreateProc: i16 = -138;
+/// Library vector offset of `Exit()`
+pub const LVO_Exit: i16 = -144;
+/// Library vector offset of `LoadSeg()`
+pub const LVO_LoadSeg: i16 = -150;
+/// Library vector offset of `UnLoadSeg()`
+pub const LVO_UnLoadSeg: i16 = -156;
+/// Library vector offset of `DeviceProc()`
+pub const LVO_DeviceProc: i16 = -174;
+/// Library vector offset of `SetComment()`
+pub const LVO_SetComment: i16 = -180;
+/// Library vector offset of `SetProtection()`
+pub const LVO_SetProtection: i16 = -186;
+/// Library vector offset of `DateStamp()`
+pub const LVO_DateStamp: i16 = -192;
+/// Library vector offset of `Delay()`
+pub const LVO_Delay: i16 = -198;
+/// Library vector offset of `WaitForChar()`
+pub const LVO_WaitForChar: i16 = -204;
+/// Library vector offset of `ParentDir()`
+pub const LVO_ParentDir: i16 = -210;
+/// Library vector offset of `IsInteractive()`
+pub const LVO_IsInteractive: i16 = -216;
+/// Library vector offset of `Execute()`
+pub const LVO_Execute: i16 = -222;
+/// Library vector offset of `AllocDosObject()`
+pub const LVO_AllocDosObject: i16 = -228;
+/// Library vector offset of `AllocDosObjectTagList()`
+pub const LVO_AllocDosObjectTagList: i16 = -228;
+/// Library vector offset of `FreeDosObject()`
+pub const LVO_FreeDosObject: i16 = -234;
+/// Library vector offset of `DoPkt()`
+pub const LVO_DoPkt: i16 = -240;
+/// Library vector offset of `DoPkt0()`
+pub const LVO_DoPkt0: i16 = -240;
+/// Library vector offset of `DoPkt1()`
+pub const LVO_DoPkt1: i16 = -240;
+/// Library vector offset of `DoPkt2()`
+pub const LVO_DoPkt2: i16 = -240;
+/// Library vector offset of `DoPkt3()`
+pub const LVO_DoPkt3: i16 = -240;
+/// Library vector offset of `DoPkt4()`
+pub const LVO_DoPkt4: i16 = -240;
+/// Library vector offset of `SendPkt()`
+pub const LVO_SendPkt: i16 = -246;
+/// Library vector offset of `WaitPkt()`
+pub const LVO_WaitPkt: i16 = -252;
+/// Library vector offset of `ReplyPkt()`
+pub const LVO_ReplyPkt: i16 = -258;
+/// Library vector offset of `AbortPkt()`
+pub const LVO_AbortPkt: i16 = -264;
+/// Library vector offset of `LockRecord()`
+pub const LVO_LockRecord: i16 = -270;
+/// Library vector offset of `LockRecords()`
+pub const LVO_LockRecords: i16 = -276;
+/// Library vector offset of `UnLockRecord()`
+pub const LVO_UnLockRecord: i16 = -282;
+/// Library vector offset of `UnLockRecords()`
+pub const LVO_UnLockRecords: i16 = -288;
+/// Library vector offset of `SelectInput()`
+pub const LVO_SelectInput: i16 = -294;
+/// Library vector offset of `SelectOutput()`
+pub const LVO_SelectOutput: i16 = -300;
+/// Library vector offset of `FGetC()`
+pub const LVO_FGetC: i16 = -306;
+/// Library vector offset of `FPutC()`
+pub const LVO_FPutC: i16 = -312;
+/// Library vector offset of `UnGetC()`
+pub const LVO_UnGetC: i16 = -318;
+/// Library vector offset of `FRead()`
+pub const LVO_FRead: i16 = -324;
+/// Library vector offset of `FWrite()`
+pub const LVO_FWrite: i16 = -330;
+/// Library vector offset of `FGets()`
+pub const LVO_FGets: i16 = -336;
+/// Library vector offset of `FPuts()`
+pub const LVO_FPuts: i16 = -342;
+/// Library vector offset of `VFWritef()`
+pub const LVO_VFWritef: i16 = -348;
+/// Library vector offset of `VFPrintf()`
+pub const LVO_VFPrintf: i16 = -354;
+/// Library vector offset of `Flush()`
+pub const LVO_Flush: i16 = -360;
+/// Library vector offset of `SetVBuf()`
+pub const LVO_SetVBuf: i16 = -366;
+/// Library vector offset of `DupLockFromFH()`
+pub const LVO_DupLockFromFH: i16 = -372;
+/// Library vector offset of `OpenFromLock()`
+pub const LVO_OpenFromLock: i16 = -378;
+/// Library vector offset of `ParentOfFH()`
+pub const LVO_ParentOfFH: i16 = -384;
+/// Library vector offset of `ExamineFH()`
+pub const LVO_ExamineFH: i16 = -390;
+/// Library vector offset of `SetFileDate()`
+pub const LVO_SetFileDate: i16 = -396;
+/// Library vector offset of `NameFromLock()`
+pub const LVO_NameFromLock: i16 = -402;
+/// Library vector offset of `NameFromFH()`
+pub const LVO_NameFromFH: i16 = -408;
+/// Library vector offset of `SplitName()`
+pub const LVO_SplitName: i16 = -414;
+/// Library vector offset of `SameLock()`
+pub const LVO_SameLock: i16 = -420;
+/// Library vector offset of `SetMode()`
+pub const LVO_SetMode: i16 = -426;
+/// Library vector offset of `ExAll()`
+pub const LVO_ExAll: i16 = -432;
+/// Library vector offset of `ReadLink()`
+pub const LVO_ReadLink: i16 = -438;
+/// Library vector offset of `MakeLink()`
+pub const LVO_MakeLink: i16 = -444;
+/// Library vector offset of `ChangeMode()`
+pub const LVO_ChangeMode: i16 = -450;
+/// Library vector offset of `SetFileSize()`
+pub const LVO_SetFileSize: i16 = -456;
+/// Library vector offset of `SetIoErr()`
+pub const LVO_SetIoErr: i16 = -462;
+/// Library vector offset of `Fault()`
+pub const LVO_Fault: i16 = -468;
+/// Library vector offset of `PrintFault()`
+pub const LVO_PrintFault: i16 = -474;
+/// Library vector offset of `ErrorReport()`
+pub const LVO_ErrorReport: i16 = -480;
+/// Library vector offset of `Cli()`
+pub const LVO_Cli: i16 = -492;
+/// Library vector offset of `CreateNewProc()`
+pub const LVO_CreateNewProc: i16 = -498;
+/// Library vector offset of `CreateNewProcTagList()`
+pub const LVO_CreateNewProcTagList: i16 = -498;
+/// Library vector offset of `RunCommand()`
+pub const LVO_RunCommand: i16 = -504;
+/// Library vector offset of `GetConsoleTask()`
+pub const LVO_GetConsoleTask: i16 = -510;
+/// Library vector offset of `SetConsoleTask()`
+pub const LVO_SetConsoleTask: i16 = -516;
+/// Library vector offset of `GetFileSysTask()`
+pub const LVO_GetFileSysTask: i16 = -522;
+/// Library vector offset of `SetFileSysTask()`
+pub const LVO_SetFileSysTask: i16 = -528;
+/// Library vector offset of `GetArgStr()`
+pub const LVO_GetArgStr: i16 = -534;
+/// Library vector offset of `SetArgStr()`
+pub const LVO_SetArgStr: i16 = -540;
+/// Library vector offset of `FindCliProc()`
+pub const LVO_FindCliProc: i16 = -546;
+/// Library vector offset of `MaxCli()`
+pub const LVO_MaxCli: i16 = -552;
+/// Library vector offset of `SetCurrentDirName()`
+pub const LVO_SetCurrentDirName: i16 = -558;
+/// Library vector offset of `GetCurrentDirName()`
+pub const LVO_GetCurrentDirName: i16 = -564;
+/// Library vector offset of `SetProgramName()`
+pub const LVO_SetProgramName: i16 = -570;
+/// Library vector offset of `GetProgramName()`
+pub const LVO_GetProgramName: i16 = -576;
+/// Library vector offset of `SetPrompt()`
+pub const LVO_SetPrompt: i16 = -582;
+/// Library vector offset of `GetPrompt()`
+pub const LVO_GetPrompt: i16 = -588;
+/// Library vector offset of `SetProgramDir()`
+pub const LVO_SetProgramDir: i16 = -594;
+/// Library vector offset of `GetProgramDir()`
+pub const LVO_GetProgramDir: i16 = -600;
+/// Library vector offset of `SystemTagList()`
+pub const LVO_SystemTagList: i16 = -606;
+/// Library vector offset of `System()`
+pub const LVO_System: i16 = -606;
+/// Library vector offset of `AssignLock()`
+pub const LVO_AssignLock: i16 = -612;
+/// Library vector offset of `AssignLate()`
+pub const LVO_AssignLate: i16 = -618;
+/// Library vector offset of `AssignPath()`
+pub const LVO_AssignPath: i16 = -624;
+/// Library vector offset of `AssignAdd()`
+pub const LVO_AssignAdd: i16 = -630;
+/// Library vector offset of `RemAssignList()`
+pub const LVO_RemAssignList: i16 = -636;
+/// Library vector offset of `GetDeviceProc()`
+pub const LVO_GetDeviceProc: i16 = -642;
+/// Library vector offset of `FreeDeviceProc()`
+pub const LVO_FreeDeviceProc: i16 = -648;
+/// Library vector offset of `LockDosList()`
+pub const LVO_LockDosList: i16 = -654;
+/// Library vector offset of `UnLockDosList()`
+pub const LVO_UnLockDosList: i16 = -660;
+/// Library vector offset of `AttemptLockDosList()`
+pub const LVO_AttemptLockDosList: i16 = -666;
+/// Library vector offset of `RemDosEntry()`
+pub const LVO_RemDosEntry: i16 = -672;
+/// Library vector offset of `AddDosEntry()`
+pub const LVO_AddDosEntry: i16 = -678;
+/// Library vector offset of `FindDosEntry()`
+pub const LVO_FindDosEntry: i16 = -684;
+/// Library vector offset of `NextDosEntry()`
+pub const LVO_NextDosEntry: i16 = -690;
+/// Library vector offset of `MakeDosEntry()`
+pub const LVO_MakeDosEntry: i16 = -696;
+/// Library vector offset of `FreeDosEntry()`
+pub const LVO_FreeDosEntry: i16 = -702;
+/// Library vector offset of `IsFileSystem()`
+pub const LVO_IsFileSystem: i16 = -708;
+/// Library vector offset of `Format()`
+pub const LVO_Format: i16 = -714;
+/// Library vector offset of `Relabel()`
+pub const LVO_Relabel: i16 = -720;
+/// Library vector offset of `Inhibit()`
+pub const LVO_Inhibit: i16 = -726;
+/// Library vector offset of `AddBuffers()`
+pub const LVO_AddBuffers: i16 = -732;
+/// Library vector offset of `CompareDates()`
+pub const LVO_CompareDates: i16 = -738;
+/// Library vector offset of `DateToStr()`
+pub const LVO_DateToStr: i16 = -744;
+/// Library vector offset of `StrToDate()`
+pub const LVO_StrToDate: i16 = -750;
+/// Library vector offset of `InternalLoadSeg()`
+pub const LVO_InternalLoadSeg: i16 = -756;
+/// Library vector offset of `InternalUnLoadSeg()`
+pub const LVO_InternalUnLoadSeg: i16 = -762;
+/// Library vector offset of `NewLoadSeg()`
+pub const LVO_NewLoadSeg: i16 = -768;
+/// Library vector offset of `NewLoadSegTagList()`
+pub const LVO_NewLoadSegTagList: i16 = -768;
+/// Library vector offset of `AddSegment()`
+pub const LVO_AddSegment: i16 = -774;
+/// Library vector offset of `FindSegment()`
+pub const LVO_FindSegment: i16 = -780;
+/// Library vector offset of `RemSegment()`
+pub const LVO_RemSegment: i16 = -786;
+/// Library vector offset of `CheckSignal()`
+pub const LVO_CheckSignal: i16 = -792;
+/// Library vector offset of `ReadArgs()`
+pub const LVO_ReadArgs: i16 = -798;
+/// Library vector offset of `FindArg()`
+pub const LVO_FindArg: i16 = -804;
+/// Library vector offset of `ReadItem()`
+pub const LVO_ReadItem: i16 = -810;
+/// Library vector offset of `StrToLong()`
+pub const LVO_StrToLong: i16 = -816;
+/// Library vector offset of `MatchFirst()`
+pub const LVO_MatchFirst: i16 = -822;
+/// Library vector offset of `MatchNext()`
+pub const LVO_MatchNext: i16 = -828;
+/// Library vector offset of `MatchEnd()`
+pub const LVO_MatchEnd: i16 = -834;
+/// Library vector offset of `ParsePattern()`
+pub const LVO_ParsePattern: i16 = -840;
+/// Library vector offset of `MatchPattern()`
+pub const LVO_MatchPattern: i16 = -846;
+/// Library vector offset of `FreeArgs()`
+pub const LVO_FreeArgs: i16 = -858;
+/// Library vector offset of `FilePart()`
+pub const LVO_FilePart: i16 = -870;
+/// Library vector offset of `PathPart()`
+pub const LVO_PathPart: i16 = -876;
+/// Library vector offset of `AddPart()`
+pub const LVO_AddPart: i16 = -882;
+/// Library vector offset of `StartNotify()`
+pub const LVO_StartNotify: i16 = -888;
+/// Library vector offset of `EndNotify()`
+pub const LVO_EndNotify: i16 = -894;
+/// Library vector offset of `SetVar()`
+pub const LVO_SetVar: i16 = -900;
+/// Library vector offset of `GetVar()`
+pub const LVO_GetVar: i16 = -906;
+/// Library vector offset of `DeleteVar()`
+pub const LVO_DeleteVar: i16 = -912;
+/// Library vector offset of `FindVar()`
+pub const LVO_FindVar: i16 = -918;
+/// Library vector offset of `CliInitNewcli()`
+pub const LVO_CliInitNewcli: i16 = -930;
+/// Library vector offset of `CliInitRun()`
+pub const LVO_CliInitRun: i16 = -936;
+/// Library vector offset of `WriteChars()`
+pub const LVO_WriteChars: i16 = -942;
+/// Library vector offset of `PutStr()`
+pub const LVO_PutStr: i16 = -948;
+/// Library vector offset of `VPrintf()`
+pub const LVO_VPrintf: i16 = -954;
+/// Library vector offset of `ParsePatternNoCase()`
+pub const LVO_ParsePatternNoCase: i16 = -966;
+/// Library vector offset of `MatchPatternNoCase()`
+pub const LVO_MatchPatternNoCase: i16 = -972;
+/// Library vector offset of `SameDevice()`
+pub const LVO_SameDevice: i16 = -984;
+/// Library vector offset of `ExAllEnd()`
+pub const LVO_ExAllEnd: i16 = -990;
+/// Library vector offset of `SetOwner()`
+pub const LVO_SetOwner: i16 = -996;
+/// Library vector offset of `VolumeRequestHook()`
+pub const LVO_VolumeRequestHook: i16 = -1014;
+/// Library vector offset of `GetCurrentDir()`
+pub const LVO_GetCurrentDir: i16 = -1026;
+/// Library vector offset of `PutErrStr()`
+pub const LVO_PutErrStr: i16 = -1128;
+/// Library vector offset of `ErrorOutput()`
+pub const LVO_ErrorOutput: i16 = -1134;
+/// Library vector offset of `SelectError()`
+pub const LVO_SelectError: i16 = -1140;
+/// Library vector offset of `DoShellMethodTagList()`
+pub const LVO_DoShellMethodTagList: i16 = -1152;
+/// Library vector offset of `ScanStackToken()`
+pub const LVO_ScanStackToken: i16 = -1158;
+
+/// Functions of "dos.library"
+pub static DOS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "Open",
+        lvo: LVO_Open,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Close",
+        lvo: LVO_Close,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Read",
+        lvo: LVO_Read,
+        registers: &["d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Write",
+        lvo: LVO_Write,
+        registers: &["d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Input",
+        lvo: LVO_Input,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Output",
+        lvo: LVO_Output,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Seek",
+        lvo: LVO_Seek,
+        registers: &["d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DeleteFile",
+        lvo: LVO_DeleteFile,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Rename",
+        lvo: LVO_Rename,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Lock",
+        lvo: LVO_Lock,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnLock",
+        lvo: LVO_UnLock,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DupLock",
+        lvo: LVO_DupLock,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Examine",
+        lvo: LVO_Examine,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ExNext",
+        lvo: LVO_ExNext,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Info",
+        lvo: LVO_Info,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CreateDir",
+        lvo: LVO_CreateDir,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CurrentDir",
+        lvo: LVO_CurrentDir,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IoErr",
+        lvo: LVO_IoErr,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CreateProc",
+        lvo: LVO_CreateProc,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Exit",
+        lvo: LVO_Exit,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LoadSeg",
+        lvo: LVO_LoadSeg,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnLoadSeg",
+        lvo: LVO_UnLoadSeg,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DeviceProc",
+        lvo: LVO_DeviceProc,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetComment",
+        lvo: LVO_SetComment,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetProtection",
+        lvo: LVO_SetProtection,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DateStamp",
+        lvo: LVO_DateStamp,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Delay",
+        lvo: LVO_Delay,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitForChar",
+        lvo: LVO_WaitForChar,
+        registers: &["d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ParentDir",
+        lvo: LVO_ParentDir,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IsInteractive",
+        lvo: LVO_IsInteractive,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Execute",
+        lvo: LVO_Execute,
+        registers: &["d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocDosObject",
+        lvo: LVO_AllocDosObject,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocDosObjectTagList",
+        lvo: LVO_AllocDosObjectTagList,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeDosObject",
+        lvo: LVO_FreeDosObject,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt",
+        lvo: LVO_DoPkt,
+        registers: &["d1", "d2", "d3", "d4", "d5", "d6", "d7"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt0",
+        lvo: LVO_DoPkt0,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt1",
+        lvo: LVO_DoPkt1,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt2",
+        lvo: LVO_DoPkt2,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt3",
+        lvo: LVO_DoPkt3,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DoPkt4",
+        lvo: LVO_DoPkt4,
+        registers: &["d1", "d2", "d3", "d4", "d5", "d6"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SendPkt",
+        lvo: LVO_SendPkt,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WaitPkt",
+        lvo: LVO_WaitPkt,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReplyPkt",
+        lvo: LVO_ReplyPkt,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AbortPkt",
+        lvo: LVO_AbortPkt,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockRecord",
+        lvo: LVO_LockRecord,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockRecords",
+        lvo: LVO_LockRecords,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnLockRecord",
+        lvo: LVO_UnLockRecord,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnLockRecords",
+        lvo: LVO_UnLockRecords,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SelectInput",
+        lvo: LVO_SelectInput,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SelectOutput",
+        lvo: LVO_SelectOutput,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FGetC",
+        lvo: LVO_FGetC,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FPutC",
+        lvo: LVO_FPutC,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnGetC",
+        lvo: LVO_UnGetC,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FRead",
+        lvo: LVO_FRead,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FWrite",
+        lvo: LVO_FWrite,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FGets",
+        lvo: LVO_FGets,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FPuts",
+        lvo: LVO_FPuts,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "VFWritef",
+        lvo: LVO_VFWritef,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "VFPrintf",
+        lvo: LVO_VFPrintf,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Flush",
+        lvo: LVO_Flush,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetVBuf",
+        lvo: LVO_SetVBuf,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DupLockFromFH",
+        lvo: LVO_DupLockFromFH,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenFromLock",
+        lvo: LVO_OpenFromLock,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParentOfFH",
+        lvo: LVO_ParentOfFH,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ExamineFH",
+        lvo: LVO_ExamineFH,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetFileDate",
+        lvo: LVO_SetFileDate,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NameFromLock",
+        lvo: LVO_NameFromLock,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NameFromFH",
+        lvo: LVO_NameFromFH,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SplitName",
+        lvo: LVO_SplitName,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SameLock",
+        lvo: LVO_SameLock,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetMode",
+        lvo: LVO_SetMode,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ExAll",
+        lvo: LVO_ExAll,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadLink",
+        lvo: LVO_ReadLink,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MakeLink",
+        lvo: LVO_MakeLink,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ChangeMode",
+        lvo: LVO_ChangeMode,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetFileSize",
+        lvo: LVO_SetFileSize,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetIoErr",
+        lvo: LVO_SetIoErr,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Fault",
+        lvo: LVO_Fault,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PrintFault",
+        lvo: LVO_PrintFault,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ErrorReport",
+        lvo: LVO_ErrorReport,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Cli",
+        lvo: LVO_Cli,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateNewProc",
+        lvo: LVO_CreateNewProc,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateNewProcTagList",
+        lvo: LVO_CreateNewProcTagList,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RunCommand",
+        lvo: LVO_RunCommand,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetConsoleTask",
+        lvo: LVO_GetConsoleTask,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetConsoleTask",
+        lvo: LVO_SetConsoleTask,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetFileSysTask",
+        lvo: LVO_GetFileSysTask,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetFileSysTask",
+        lvo: LVO_SetFileSysTask,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetArgStr",
+        lvo: LVO_GetArgStr,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetArgStr",
+        lvo: LVO_SetArgStr,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindCliProc",
+        lvo: LVO_FindCliProc,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MaxCli",
+        lvo: LVO_MaxCli,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetCurrentDirName",
+        lvo: LVO_SetCurrentDirName,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetCurrentDirName",
+        lvo: LVO_GetCurrentDirName,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetProgramName",
+        lvo: LVO_SetProgramName,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetProgramName",
+        lvo: LVO_GetProgramName,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetPrompt",
+        lvo: LVO_SetPrompt,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetPrompt",
+        lvo: LVO_GetPrompt,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetProgramDir",
+        lvo: LVO_SetProgramDir,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetProgramDir",
+        lvo: LVO_GetProgramDir,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SystemTagList",
+        lvo: LVO_SystemTagList,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "System",
+        lvo: LVO_System,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AssignLock",
+        lvo: LVO_AssignLock,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AssignLate",
+        lvo: LVO_AssignLate,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AssignPath",
+        lvo: LVO_AssignPath,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AssignAdd",
+        lvo: LVO_AssignAdd,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemAssignList",
+        lvo: LVO_RemAssignList,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDeviceProc",
+        lvo: LVO_GetDeviceProc,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeDeviceProc",
+        lvo: LVO_FreeDeviceProc,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockDosList",
+        lvo: LVO_LockDosList,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnLockDosList",
+        lvo: LVO_UnLockDosList,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AttemptLockDosList",
+        lvo: LVO_AttemptLockDosList,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemDosEntry",
+        lvo: LVO_RemDosEntry,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddDosEntry",
+        lvo: LVO_AddDosEntry,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindDosEntry",
+        lvo: LVO_FindDosEntry,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NextDosEntry",
+        lvo: LVO_NextDosEntry,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MakeDosEntry",
+        lvo: LVO_MakeDosEntry,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeDosEntry",
+        lvo: LVO_FreeDosEntry,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IsFileSystem",
+        lvo: LVO_IsFileSystem,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Format",
+        lvo: LVO_Format,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Relabel",
+        lvo: LVO_Relabel,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Inhibit",
+        lvo: LVO_Inhibit,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddBuffers",
+        lvo: LVO_AddBuffers,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CompareDates",
+        lvo: LVO_CompareDates,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DateToStr",
+        lvo: LVO_DateToStr,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StrToDate",
+        lvo: LVO_StrToDate,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InternalLoadSeg",
+        lvo: LVO_InternalLoadSeg,
+        registers: &["d0", "a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InternalUnLoadSeg",
+        lvo: LVO_InternalUnLoadSeg,
+        registers: &["d1", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NewLoadSeg",
+        lvo: LVO_NewLoadSeg,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NewLoadSegTagList",
+        lvo: LVO_NewLoadSegTagList,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddSegment",
+        lvo: LVO_AddSegment,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindSegment",
+        lvo: LVO_FindSegment,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemSegment",
+        lvo: LVO_RemSegment,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CheckSignal",
+        lvo: LVO_CheckSignal,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadArgs",
+        lvo: LVO_ReadArgs,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindArg",
+        lvo: LVO_FindArg,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadItem",
+        lvo: LVO_ReadItem,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StrToLong",
+        lvo: LVO_StrToLong,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MatchFirst",
+        lvo: LVO_MatchFirst,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MatchNext",
+        lvo: LVO_MatchNext,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MatchEnd",
+        lvo: LVO_MatchEnd,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParsePattern",
+        lvo: LVO_ParsePattern,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MatchPattern",
+        lvo: LVO_MatchPattern,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeArgs",
+        lvo: LVO_FreeArgs,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FilePart",
+        lvo: LVO_FilePart,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PathPart",
+        lvo: LVO_PathPart,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddPart",
+        lvo: LVO_AddPart,
+        registers: &["d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StartNotify",
+        lvo: LVO_StartNotify,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EndNotify",
+        lvo: LVO_EndNotify,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetVar",
+        lvo: LVO_SetVar,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetVar",
+        lvo: LVO_GetVar,
+        registers: &["d1", "d2", "d3", "d4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteVar",
+        lvo: LVO_DeleteVar,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindVar",
+        lvo: LVO_FindVar,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CliInitNewcli",
+        lvo: LVO_CliInitNewcli,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CliInitRun",
+        lvo: LVO_CliInitRun,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WriteChars",
+        lvo: LVO_WriteChars,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PutStr",
+        lvo: LVO_PutStr,
+        registers: &["d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "VPrintf",
+        lvo: LVO_VPrintf,
+        registers: &["d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParsePatternNoCase",
+        lvo: LVO_ParsePatternNoCase,
+        registers: &["d1", "d2", "d3"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "MatchPatternNoCase",
+        lvo: LVO_MatchPatternNoCase,
+        registers: &["d1", "d2"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "SameDevice",
+        lvo: LVO_SameDevice,
+        registers: &["d1", "d2"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "ExAllEnd",
+        lvo: LVO_ExAllEnd,
+        registers: &["d1", "d2", "d3", "d4", "d5"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetOwner",
+        lvo: LVO_SetOwner,
+        registers: &["d1", "d2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "VolumeRequestHook",
+        lvo: LVO_VolumeRequestHook,
+        registers: &["d1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "GetCurrentDir",
+        lvo: LVO_GetCurrentDir,
+        registers: &[],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "PutErrStr",
+        lvo: LVO_PutErrStr,
+        registers: &["d1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ErrorOutput",
+        lvo: LVO_ErrorOutput,
+        registers: &[],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "SelectError",
+        lvo: LVO_SelectError,
+        registers: &["d1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "DoShellMethodTagList",
+        lvo: LVO_DoShellMethodTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ScanStackToken",
+        lvo: LVO_ScanStackToken,
+        registers: &["d1", "d2"],
+        min_version: 47,
+    },
+];
@@ -0,0 +1,4305 @@
+//! Functions of "exec.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "exec.library"
+pub unsafe fn Supervisor(SysBase: *mut Library, userFunction: FPTR) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a5, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a5reg}, %a5",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a5",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            a5reg = in(reg) userFunction,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn InitCode(SysBase: *mut Library, startClass: ULONG, version: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") startClass,
+            in("d1") version,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn InitStruct(SysBase: *mut Library, initTable: CONST_APTR, memory: APTR, size: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") initTable,
+            in("a2") memory,
+            in("d0") size,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn MakeLibrary(
+    SysBase: *mut Library,
+    funcInit: CONST_APTR,
+    structInit: CONST_APTR,
+    libInit: FPTR,
+    dataSize: ULONG,
+    segList: ULONG,
+) -> *mut Library {
+    let asm_ret_value: *mut Library;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") funcInit,
+            in("a1") structInit,
+            in("a2") libInit,
+            in("d0") dataSize,
+            in("d1") segList,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn MakeFunctions(
+    SysBase: *mut Library,
+    target: APTR,
+    functionArray: CONST_APTR,
+    funcDispBase: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") target,
+            in("a1") functionArray,
+            in("a2") funcDispBase,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn FindResident(SysBase: *mut Library, name: CONST_STRPTR) -> *mut Resident {
+    let asm_ret_value: *mut Resident;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn InitResident(
+    SysBase: *mut Library,
+    resident: *const Resident,
+    segList: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") resident,
+            in("d1") segList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Alert(SysBase: *mut Library, alertNum: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d7") alertNum,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Debug(SysBase: *mut Library, flags: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") flags,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Disable(SysBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Enable(SysBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Forbid(SysBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Permit(SysBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetSR(SysBase: *mut Library, newSR: ULONG, mask: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") newSR,
+            in("d1") mask,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn SuperState(SysBase: *mut Library) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn UserState(SysBase: *mut Library, sysStack: APTR) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") sysStack,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetIntVector(
+    SysBase: *mut Library,
+    intNumber: LONG,
+    interrupt: *mut Interrupt,
+) -> *mut Interrupt {
+    let asm_ret_value: *mut Interrupt;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") intNumber,
+            in("a1") interrupt,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddIntServer(SysBase: *mut Library, intNumber: LONG, interrupt: *mut Interrupt) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") intNumber,
+            in("a1") interrupt,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemIntServer(SysBase: *mut Library, intNumber: LONG, interrupt: *mut Interrupt) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") intNumber,
+            in("a1") interrupt,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Cause(SysBase: *mut Library, interrupt: *const Interrupt) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") interrupt,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Allocate(SysBase: *mut Library, freeList: *mut MemHeader, byteSize: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") freeList,
+            in("d0") byteSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Deallocate(
+    SysBase: *mut Library,
+    freeList: *mut MemHeader,
+    memoryBlock: APTR,
+    byteSize: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") freeList,
+            in("a1") memoryBlock,
+            in("d0") byteSize,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AllocMem(SysBase: *mut Library, byteSize: ULONG, requirements: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") byteSize,
+            in("d1") requirements,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn AllocAbs(SysBase: *mut Library, byteSize: ULONG, location: APTR) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") byteSize,
+            in("a1") location,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn FreeMem(SysBase: *mut Library, memoryBlock: APTR, byteSize: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") memoryBlock,
+            in("d0") byteSize,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AvailMem(SysBase: *mut Library, requirements: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d1") requirements,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn AllocEntry(SysBase: *mut Library, entry: *const MemList) -> *mut MemList {
+    let asm_ret_value: *mut MemList;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") entry,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn FreeEntry(SysBase: *mut Library, entry: *mut MemList) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") entry,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Insert(SysBase: *mut Library, list: *mut List, node: *mut Node, pred: *mut Node) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            in("a1") node,
+            in("a2") pred,
+        );
+    }
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn InsertMinNode(
+    SysBase: *mut Library,
+    minlist: *mut MinList,
+    minnode: *mut MinNode,
+    minpred: *mut MinNode,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "InsertMinNode", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+            in("a1") minnode,
+            in("a2") minpred,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddHead(SysBase: *mut Library, list: *mut List, node: *mut Node) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            in("a1") node,
+        );
+    }
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn AddHeadMinList(SysBase: *mut Library, minlist: *mut MinList, minnode: *mut MinNode) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "AddHeadMinList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+            in("a1") minnode,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddTail(SysBase: *mut Library, list: *mut List, node: *mut Node) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            in("a1") node,
+        );
+    }
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn AddTailMinList(SysBase: *mut Library, minlist: *mut MinList, minnode: *mut MinNode) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "AddTailMinList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+            in("a1") minnode,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn Remove(SysBase: *mut Library, node: *mut Node) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") node,
+        );
+    }
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn RemoveMinNode(SysBase: *mut Library, minnode: *mut MinNode) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "RemoveMinNode", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") minnode,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemHead(SysBase: *mut Library, list: *mut List) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn RemHeadMinList(SysBase: *mut Library, minlist: *mut MinList) -> *mut MinNode {
+    let asm_ret_value: *mut MinNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "RemHeadMinList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemTail(SysBase: *mut Library, list: *mut List) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn RemTailMinList(SysBase: *mut Library, minlist: *mut MinList) -> *mut MinNode {
+    let asm_ret_value: *mut MinNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 47, "RemTailMinList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Enqueue(SysBase: *mut Library, list: *mut List, node: *mut Node) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            in("a1") node,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn FindName(SysBase: *mut Library, list: *mut List, name: CONST_STRPTR) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-276, %a6)
+            ".short -276",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") list,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddTask(SysBase: *mut Library, task: *mut Task, initPC: APTR, finalPC: APTR) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-282, %a6)
+            ".short -282",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") task,
+            in("a2") initPC,
+            in("a3") finalPC,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemTask(SysBase: *mut Library, task: *mut Task) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-288, %a6)
+            ".short -288",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") task,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn FindTask(SysBase: *mut Library, name: CONST_STRPTR) -> *mut Task {
+    let asm_ret_value: *mut Task;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-294, %a6)
+            ".short -294",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetTaskPri(SysBase: *mut Library, task: *mut Task, priority: LONG) -> BYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-300, %a6)
+            ".short -300",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") task,
+            in("d0") priority,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BYTE
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetSignal(SysBase: *mut Library, newSignals: ULONG, signalSet: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-306, %a6)
+            ".short -306",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") newSignals,
+            in("d1") signalSet,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetExcept(SysBase: *mut Library, newSignals: ULONG, signalSet: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-312, %a6)
+            ".short -312",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") newSignals,
+            in("d1") signalSet,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Wait(SysBase: *mut Library, signalSet: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-318, %a6)
+            ".short -318",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") signalSet,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Signal(SysBase: *mut Library, task: *mut Task, signalSet: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-324, %a6)
+            ".short -324",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") task,
+            in("d0") signalSet,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AllocSignal(SysBase: *mut Library, signalNum: LONG) -> BYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-330, %a6)
+            ".short -330",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") signalNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BYTE
+}
+
+/// libname: "exec.library"
+pub unsafe fn FreeSignal(SysBase: *mut Library, signalNum: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-336, %a6)
+            ".short -336",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") signalNum,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AllocTrap(SysBase: *mut Library, trapNum: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-342, %a6)
+            ".short -342",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") trapNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn FreeTrap(SysBase: *mut Library, trapNum: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-348, %a6)
+            ".short -348",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") trapNum,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddPort(SysBase: *mut Library, port: *mut MsgPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-354, %a6)
+            ".short -354",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") port,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemPort(SysBase: *mut Library, port: *mut MsgPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-360, %a6)
+            ".short -360",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") port,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn PutMsg(SysBase: *mut Library, port: *mut MsgPort, message: *mut Message) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-366, %a6)
+            ".short -366",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") port,
+            in("a1") message,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn GetMsg(SysBase: *mut Library, port: *mut MsgPort) -> *mut Message {
+    let asm_ret_value: *mut Message;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-372, %a6)
+            ".short -372",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") port,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn ReplyMsg(SysBase: *mut Library, message: *mut Message) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-378, %a6)
+            ".short -378",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") message,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn WaitPort(SysBase: *mut Library, port: *mut MsgPort) -> *mut Message {
+    let asm_ret_value: *mut Message;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-384, %a6)
+            ".short -384",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") port,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn FindPort(SysBase: *mut Library, name: CONST_STRPTR) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-390, %a6)
+            ".short -390",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddLibrary(SysBase: *mut Library, library: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-396, %a6)
+            ".short -396",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") library,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemLibrary(SysBase: *mut Library, library: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-402, %a6)
+            ".short -402",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") library,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn OldOpenLibrary(SysBase: *mut Library, libName: CONST_STRPTR) -> *mut Library {
+    let asm_ret_value: *mut Library;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-408, %a6)
+            ".short -408",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") libName,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn CloseLibrary(SysBase: *mut Library, library: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-414, %a6)
+            ".short -414",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") library,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn SetFunction(
+    SysBase: *mut Library,
+    library: *mut Library,
+    funcOffset: LONG,
+    newFunction: FPTR,
+) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-420, %a6)
+            ".short -420",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") library,
+            in("a0") funcOffset,
+            in("d0") newFunction,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn SumLibrary(SysBase: *mut Library, library: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-426, %a6)
+            ".short -426",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") library,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddDevice(SysBase: *mut Library, device: *mut Device) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-432, %a6)
+            ".short -432",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") device,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemDevice(SysBase: *mut Library, device: *mut Device) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-438, %a6)
+            ".short -438",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") device,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn OpenDevice(
+    SysBase: *mut Library,
+    devName: CONST_STRPTR,
+    unit: ULONG,
+    ioRequest: *mut IORequest,
+    flags: ULONG,
+) -> BYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-444, %a6)
+            ".short -444",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") devName,
+            in("d0") unit,
+            in("a1") ioRequest,
+            in("d1") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BYTE
+}
+
+/// libname: "exec.library"
+pub unsafe fn CloseDevice(SysBase: *mut Library, ioRequest: *mut IORequest) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn DoIO(SysBase: *mut Library, ioRequest: *mut IORequest) -> BYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BYTE
+}
+
+/// libname: "exec.library"
+pub unsafe fn SendIO(SysBase: *mut Library, ioRequest: *mut IORequest) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-462, %a6)
+            ".short -462",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn CheckIO(SysBase: *mut Library, ioRequest: *const IORequest) -> *mut IORequest {
+    let asm_ret_value: *mut IORequest;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-468, %a6)
+            ".short -468",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn WaitIO(SysBase: *mut Library, ioRequest: *mut IORequest) -> BYTE {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-474, %a6)
+            ".short -474",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BYTE
+}
+
+/// libname: "exec.library"
+pub unsafe fn AbortIO(SysBase: *mut Library, ioRequest: *mut IORequest) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-480, %a6)
+            ".short -480",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") ioRequest,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn AddResource(SysBase: *mut Library, resource: APTR) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-486, %a6)
+            ".short -486",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") resource,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn RemResource(SysBase: *mut Library, resource: APTR) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-492, %a6)
+            ".short -492",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") resource,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn OpenResource(SysBase: *mut Library, resName: CONST_STRPTR) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") resName,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn RawDoFmt(
+    SysBase: *mut Library,
+    formatString: CONST_STRPTR,
+    dataStream: APTR,
+    putChProc: FPTR,
+    putChData: APTR,
+) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-522, %a6)
+            ".short -522",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") formatString,
+            in("a1") dataStream,
+            in("a2") putChProc,
+            in("a3") putChData,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn GetCC(SysBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-528, %a6)
+            ".short -528",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn TypeOfMem(SysBase: *mut Library, address: CONST_APTR) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-534, %a6)
+            ".short -534",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") address,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Procure(
+    SysBase: *mut Library,
+    sigSem: *mut SignalSemaphore,
+    bidMsg: *mut SemaphoreMessage,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-540, %a6)
+            ".short -540",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+            in("a1") bidMsg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library"
+pub unsafe fn Vacate(
+    SysBase: *mut Library,
+    sigSem: *mut SignalSemaphore,
+    bidMsg: *mut SemaphoreMessage,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-546, %a6)
+            ".short -546",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+            in("a1") bidMsg,
+        );
+    }
+}
+
+/// libname: "exec.library"
+pub unsafe fn OpenLibrary(
+    SysBase: *mut Library,
+    libName: CONST_STRPTR,
+    version: ULONG,
+) -> *mut Library {
+    let asm_ret_value: *mut Library;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-552, %a6)
+            ".short -552",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") libName,
+            in("d0") version,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn InitSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "InitSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-558, %a6)
+            ".short -558",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn ObtainSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "ObtainSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-564, %a6)
+            ".short -564",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn ReleaseSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "ReleaseSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-570, %a6)
+            ".short -570",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn AttemptSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "AttemptSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-576, %a6)
+            ".short -576",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn ObtainSemaphoreList(SysBase: *mut Library, sigSem: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "ObtainSemaphoreList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-582, %a6)
+            ".short -582",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn ReleaseSemaphoreList(SysBase: *mut Library, sigSem: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "ReleaseSemaphoreList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-588, %a6)
+            ".short -588",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn FindSemaphore(SysBase: *mut Library, name: CONST_STRPTR) -> *mut SignalSemaphore {
+    let asm_ret_value: *mut SignalSemaphore;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "FindSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-594, %a6)
+            ".short -594",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn AddSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "AddSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-600, %a6)
+            ".short -600",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn RemSemaphore(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "RemSemaphore", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-606, %a6)
+            ".short -606",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn SumKickData(SysBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "SumKickData", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-612, %a6)
+            ".short -612",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn AddMemList(
+    SysBase: *mut Library,
+    size: ULONG,
+    attributes: ULONG,
+    pri: LONG,
+    base: APTR,
+    name: STRPTR,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "AddMemList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-618, %a6)
+            ".short -618",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") size,
+            in("d1") attributes,
+            in("d2") pri,
+            in("a0") base,
+            in("a1") name,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn CopyMem(SysBase: *mut Library, source: CONST_APTR, dest: APTR, size: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "CopyMem", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-624, %a6)
+            ".short -624",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") source,
+            in("a1") dest,
+            in("d0") size,
+        );
+    }
+}
+
+/// libname: "exec.library" (V33) (Kickstart 1.2)
+pub unsafe fn CopyMemQuick(SysBase: *mut Library, source: CONST_APTR, dest: APTR, size: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 33, "CopyMemQuick", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-630, %a6)
+            ".short -630",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") source,
+            in("a1") dest,
+            in("d0") size,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CacheClearU(SysBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CacheClearU", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-636, %a6)
+            ".short -636",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CacheClearE(SysBase: *mut Library, address: APTR, length: ULONG, caches: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CacheClearE", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-642, %a6)
+            ".short -642",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") address,
+            in("d0") length,
+            in("d1") caches,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CacheControl(SysBase: *mut Library, cacheBits: ULONG, cacheMask: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CacheControl", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-648, %a6)
+            ".short -648",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") cacheBits,
+            in("d1") cacheMask,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateIORequest(SysBase: *mut Library, port: *mut MsgPort, size: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CreateIORequest", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-654, %a6)
+            ".short -654",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") port,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteIORequest(SysBase: *mut Library, iorequest: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "DeleteIORequest", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-660, %a6)
+            ".short -660",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") iorequest,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateMsgPort(SysBase: *mut Library) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CreateMsgPort", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-666, %a6)
+            ".short -666",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteMsgPort(SysBase: *mut Library, port: *mut MsgPort) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "DeleteMsgPort", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-672, %a6)
+            ".short -672",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") port,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ObtainSemaphoreShared(SysBase: *mut Library, sigSem: *mut SignalSemaphore) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "ObtainSemaphoreShared", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-678, %a6)
+            ".short -678",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocVec(SysBase: *mut Library, byteSize: ULONG, requirements: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "AllocVec", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-684, %a6)
+            ".short -684",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") byteSize,
+            in("d1") requirements,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeVec(SysBase: *mut Library, memoryBlock: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "FreeVec", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-690, %a6)
+            ".short -690",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") memoryBlock,
+        );
+    }
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn CreatePool(
+    SysBase: *mut Library,
+    requirements: ULONG,
+    puddleSize: ULONG,
+    threshSize: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "CreatePool", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-696, %a6)
+            ".short -696",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("d0") requirements,
+            in("d1") puddleSize,
+            in("d2") threshSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn DeletePool(SysBase: *mut Library, poolHeader: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "DeletePool", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-702, %a6)
+            ".short -702",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") poolHeader,
+        );
+    }
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocPooled(SysBase: *mut Library, poolHeader: APTR, memSize: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "AllocPooled", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-708, %a6)
+            ".short -708",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") poolHeader,
+            in("d0") memSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreePooled(SysBase: *mut Library, poolHeader: APTR, memory: APTR, memSize: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "FreePooled", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-714, %a6)
+            ".short -714",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") poolHeader,
+            in("a1") memory,
+            in("d0") memSize,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AttemptSemaphoreShared(SysBase: *mut Library, sigSem: *mut SignalSemaphore) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "AttemptSemaphoreShared", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-720, %a6)
+            ".short -720",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") sigSem,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ColdReboot(SysBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "ColdReboot", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-726, %a6)
+            ".short -726",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StackSwap(SysBase: *mut Library, newStack: *mut StackSwapStruct) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "StackSwap", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-732, %a6)
+            ".short -732",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") newStack,
+        );
+    }
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CachePreDMA(
+    SysBase: *mut Library,
+    address: CONST_APTR,
+    length: *mut ULONG,
+    flags: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CachePreDMA", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-762, %a6)
+            ".short -762",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") address,
+            in("a1") length,
+            in("d0") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CachePostDMA(
+    SysBase: *mut Library,
+    address: CONST_APTR,
+    length: *mut ULONG,
+    flags: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 36, "CachePostDMA", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-768, %a6)
+            ".short -768",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") address,
+            in("a1") length,
+            in("d0") flags,
+        );
+    }
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AddMemHandler(SysBase: *mut Library, memhand: *mut Interrupt) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "AddMemHandler", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-774, %a6)
+            ".short -774",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") memhand,
+        );
+    }
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn RemMemHandler(SysBase: *mut Library, memhand: *mut Interrupt) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "RemMemHandler", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-780, %a6)
+            ".short -780",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a1") memhand,
+        );
+    }
+}
+
+/// libname: "exec.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ObtainQuickVector(SysBase: *mut Library, interruptCode: APTR) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 39, "ObtainQuickVector", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-786, %a6)
+            ".short -786",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") interruptCode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "exec.library" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn NewMinList(SysBase: *mut Library, minlist: *mut MinList) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(SysBase, 45, "NewMinList", "exec.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-828, %a6)
+            ".short -828",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SysBase,
+            in("a0") minlist,
+        );
+    }
+}
+
+/// An open "exec.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct ExecLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl ExecLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"exec.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "exec.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Supervisor(&self, userFunction: FPTR) -> ULONG {
+        unsafe { Supervisor(self.base.as_ptr(), userFunction) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn InitCode(&self, startClass: ULONG, version: ULONG) {
+        unsafe { InitCode(self.base.as_ptr(), startClass, version) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn InitStruct(&self, initTable: CONST_APTR, memory: APTR, size: ULONG) {
+        unsafe { InitStruct(self.base.as_ptr(), initTable, memory, size) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn MakeLibrary(
+        &self,
+        funcInit: CONST_APTR,
+        structInit: CONST_APTR,
+        libInit: FPTR,
+        dataSize: ULONG,
+        segList: ULONG,
+    ) -> *mut Library {
+        unsafe {
+            MakeLibrary(
+                self.base.as_ptr(),
+                funcInit,
+                structInit,
+                libInit,
+                dataSize,
+                segList,
+            )
+        }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn MakeFunctions(
+        &self,
+        target: APTR,
+        functionArray: CONST_APTR,
+        funcDispBase: ULONG,
+    ) {
+        unsafe { MakeFunctions(self.base.as_ptr(), target, functionArray, funcDispBase) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FindResident(&self, name: CONST_STRPTR) -> *mut Resident {
+        unsafe { FindResident(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn InitResident(&self, resident: *const Resident, segList: ULONG) -> APTR {
+        unsafe { InitResident(self.base.as_ptr(), resident, segList) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Alert(&self, alertNum: ULONG) {
+        unsafe { Alert(self.base.as_ptr(), alertNum) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Debug(&self, flags: ULONG) {
+        unsafe { Debug(self.base.as_ptr(), flags) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Disable(&self) {
+        unsafe { Disable(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Enable(&self) {
+        unsafe { Enable(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Forbid(&self) {
+        unsafe { Forbid(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Permit(&self) {
+        unsafe { Permit(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetSR(&self, newSR: ULONG, mask: ULONG) -> ULONG {
+        unsafe { SetSR(self.base.as_ptr(), newSR, mask) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SuperState(&self) -> APTR {
+        unsafe { SuperState(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn UserState(&self, sysStack: APTR) {
+        unsafe { UserState(self.base.as_ptr(), sysStack) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetIntVector(
+        &self,
+        intNumber: LONG,
+        interrupt: *mut Interrupt,
+    ) -> *mut Interrupt {
+        unsafe { SetIntVector(self.base.as_ptr(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddIntServer(&self, intNumber: LONG, interrupt: *mut Interrupt) {
+        unsafe { AddIntServer(self.base.as_ptr(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemIntServer(&self, intNumber: LONG, interrupt: *mut Interrupt) {
+        unsafe { RemIntServer(self.base.as_ptr(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Cause(&self, interrupt: *const Interrupt) {
+        unsafe { Cause(self.base.as_ptr(), interrupt) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Allocate(&self, freeList: *mut MemHeader, byteSize: ULONG) -> APTR {
+        unsafe { Allocate(self.base.as_ptr(), freeList, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Deallocate(&self, freeList: *mut MemHeader, memoryBlock: APTR, byteSize: ULONG) {
+        unsafe { Deallocate(self.base.as_ptr(), freeList, memoryBlock, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AllocMem(&self, byteSize: ULONG, requirements: ULONG) -> APTR {
+        unsafe { AllocMem(self.base.as_ptr(), byteSize, requirements) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AllocAbs(&self, byteSize: ULONG, location: APTR) -> APTR {
+        unsafe { AllocAbs(self.base.as_ptr(), byteSize, location) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FreeMem(&self, memoryBlock: APTR, byteSize: ULONG) {
+        unsafe { FreeMem(self.base.as_ptr(), memoryBlock, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AvailMem(&self, requirements: ULONG) -> ULONG {
+        unsafe { AvailMem(self.base.as_ptr(), requirements) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AllocEntry(&self, entry: *const MemList) -> *mut MemList {
+        unsafe { AllocEntry(self.base.as_ptr(), entry) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FreeEntry(&self, entry: *mut MemList) {
+        unsafe { FreeEntry(self.base.as_ptr(), entry) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Insert(&self, list: *mut List, node: *mut Node, pred: *mut Node) {
+        unsafe { Insert(self.base.as_ptr(), list, node, pred) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn InsertMinNode(
+        &self,
+        minlist: *mut MinList,
+        minnode: *mut MinNode,
+        minpred: *mut MinNode,
+    ) {
+        unsafe { InsertMinNode(self.base.as_ptr(), minlist, minnode, minpred) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddHead(&self, list: *mut List, node: *mut Node) {
+        unsafe { AddHead(self.base.as_ptr(), list, node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn AddHeadMinList(&self, minlist: *mut MinList, minnode: *mut MinNode) {
+        unsafe { AddHeadMinList(self.base.as_ptr(), minlist, minnode) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddTail(&self, list: *mut List, node: *mut Node) {
+        unsafe { AddTail(self.base.as_ptr(), list, node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn AddTailMinList(&self, minlist: *mut MinList, minnode: *mut MinNode) {
+        unsafe { AddTailMinList(self.base.as_ptr(), minlist, minnode) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Remove(&self, node: *mut Node) {
+        unsafe { Remove(self.base.as_ptr(), node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn RemoveMinNode(&self, minnode: *mut MinNode) {
+        unsafe { RemoveMinNode(self.base.as_ptr(), minnode) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemHead(&self, list: *mut List) -> *mut Node {
+        unsafe { RemHead(self.base.as_ptr(), list) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn RemHeadMinList(&self, minlist: *mut MinList) -> *mut MinNode {
+        unsafe { RemHeadMinList(self.base.as_ptr(), minlist) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemTail(&self, list: *mut List) -> *mut Node {
+        unsafe { RemTail(self.base.as_ptr(), list) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn RemTailMinList(&self, minlist: *mut MinList) -> *mut MinNode {
+        unsafe { RemTailMinList(self.base.as_ptr(), minlist) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Enqueue(&self, list: *mut List, node: *mut Node) {
+        unsafe { Enqueue(self.base.as_ptr(), list, node) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FindName(&self, list: *mut List, name: CONST_STRPTR) -> *mut Node {
+        unsafe { FindName(self.base.as_ptr(), list, name) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddTask(&self, task: *mut Task, initPC: APTR, finalPC: APTR) -> APTR {
+        unsafe { AddTask(self.base.as_ptr(), task, initPC, finalPC) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemTask(&self, task: *mut Task) {
+        unsafe { RemTask(self.base.as_ptr(), task) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FindTask(&self, name: CONST_STRPTR) -> *mut Task {
+        unsafe { FindTask(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetTaskPri(&self, task: *mut Task, priority: LONG) -> BYTE {
+        unsafe { SetTaskPri(self.base.as_ptr(), task, priority) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetSignal(&self, newSignals: ULONG, signalSet: ULONG) -> ULONG {
+        unsafe { SetSignal(self.base.as_ptr(), newSignals, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetExcept(&self, newSignals: ULONG, signalSet: ULONG) -> ULONG {
+        unsafe { SetExcept(self.base.as_ptr(), newSignals, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Wait(&self, signalSet: ULONG) -> ULONG {
+        unsafe { Wait(self.base.as_ptr(), signalSet) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Signal(&self, task: *mut Task, signalSet: ULONG) {
+        unsafe { Signal(self.base.as_ptr(), task, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AllocSignal(&self, signalNum: LONG) -> BYTE {
+        unsafe { AllocSignal(self.base.as_ptr(), signalNum) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FreeSignal(&self, signalNum: LONG) {
+        unsafe { FreeSignal(self.base.as_ptr(), signalNum) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AllocTrap(&self, trapNum: LONG) -> LONG {
+        unsafe { AllocTrap(self.base.as_ptr(), trapNum) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FreeTrap(&self, trapNum: LONG) {
+        unsafe { FreeTrap(self.base.as_ptr(), trapNum) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddPort(&self, port: *mut MsgPort) {
+        unsafe { AddPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemPort(&self, port: *mut MsgPort) {
+        unsafe { RemPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn PutMsg(&self, port: *mut MsgPort, message: *mut Message) {
+        unsafe { PutMsg(self.base.as_ptr(), port, message) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn GetMsg(&self, port: *mut MsgPort) -> *mut Message {
+        unsafe { GetMsg(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn ReplyMsg(&self, message: *mut Message) {
+        unsafe { ReplyMsg(self.base.as_ptr(), message) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn WaitPort(&self, port: *mut MsgPort) -> *mut Message {
+        unsafe { WaitPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn FindPort(&self, name: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { FindPort(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddLibrary(&self, library: *mut Library) {
+        unsafe { AddLibrary(self.base.as_ptr(), library) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemLibrary(&self, library: *mut Library) {
+        unsafe { RemLibrary(self.base.as_ptr(), library) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn OldOpenLibrary(&self, libName: CONST_STRPTR) -> *mut Library {
+        unsafe { OldOpenLibrary(self.base.as_ptr(), libName) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn CloseLibrary(&self, library: *mut Library) {
+        unsafe { CloseLibrary(self.base.as_ptr(), library) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SetFunction(
+        &self,
+        library: *mut Library,
+        funcOffset: LONG,
+        newFunction: FPTR,
+    ) -> APTR {
+        unsafe { SetFunction(self.base.as_ptr(), library, funcOffset, newFunction) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SumLibrary(&self, library: *mut Library) {
+        unsafe { SumLibrary(self.base.as_ptr(), library) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddDevice(&self, device: *mut Device) {
+        unsafe { AddDevice(self.base.as_ptr(), device) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemDevice(&self, device: *mut Device) {
+        unsafe { RemDevice(self.base.as_ptr(), device) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn OpenDevice(
+        &self,
+        devName: CONST_STRPTR,
+        unit: ULONG,
+        ioRequest: *mut IORequest,
+        flags: ULONG,
+    ) -> BYTE {
+        unsafe { OpenDevice(self.base.as_ptr(), devName, unit, ioRequest, flags) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn CloseDevice(&self, ioRequest: *mut IORequest) {
+        unsafe { CloseDevice(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn DoIO(&self, ioRequest: *mut IORequest) -> BYTE {
+        unsafe { DoIO(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn SendIO(&self, ioRequest: *mut IORequest) {
+        unsafe { SendIO(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn CheckIO(&self, ioRequest: *const IORequest) -> *mut IORequest {
+        unsafe { CheckIO(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn WaitIO(&self, ioRequest: *mut IORequest) -> BYTE {
+        unsafe { WaitIO(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AbortIO(&self, ioRequest: *mut IORequest) {
+        unsafe { AbortIO(self.base.as_ptr(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn AddResource(&self, resource: APTR) {
+        unsafe { AddResource(self.base.as_ptr(), resource) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RemResource(&self, resource: APTR) {
+        unsafe { RemResource(self.base.as_ptr(), resource) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn OpenResource(&self, resName: CONST_STRPTR) -> APTR {
+        unsafe { OpenResource(self.base.as_ptr(), resName) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn RawDoFmt(
+        &self,
+        formatString: CONST_STRPTR,
+        dataStream: APTR,
+        putChProc: FPTR,
+        putChData: APTR,
+    ) -> APTR {
+        unsafe {
+            RawDoFmt(
+                self.base.as_ptr(),
+                formatString,
+                dataStream,
+                putChProc,
+                putChData,
+            )
+        }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn GetCC(&self) -> ULONG {
+        unsafe { GetCC(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn TypeOfMem(&self, address: CONST_APTR) -> ULONG {
+        unsafe { TypeOfMem(self.base.as_ptr(), address) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Procure(
+        &self,
+        sigSem: *mut SignalSemaphore,
+        bidMsg: *mut SemaphoreMessage,
+    ) -> ULONG {
+        unsafe { Procure(self.base.as_ptr(), sigSem, bidMsg) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn Vacate(&self, sigSem: *mut SignalSemaphore, bidMsg: *mut SemaphoreMessage) {
+        unsafe { Vacate(self.base.as_ptr(), sigSem, bidMsg) }
+    }
+
+    /// libname: "exec.library"
+    pub unsafe fn OpenLibrary(&self, libName: CONST_STRPTR, version: ULONG) -> *mut Library {
+        unsafe { OpenLibrary(self.base.as_ptr(), libName, version) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn InitSemaphore(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { InitSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ObtainSemaphore(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { ObtainSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ReleaseSemaphore(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { ReleaseSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AttemptSemaphore(&self, sigSem: *mut SignalSemaphore) -> ULONG {
+        unsafe { AttemptSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ObtainSemaphoreList(&self, sigSem: *mut List) {
+        unsafe { ObtainSemaphoreList(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ReleaseSemaphoreList(&self, sigSem: *mut List) {
+        unsafe { ReleaseSemaphoreList(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn FindSemaphore(&self, name: CONST_STRPTR) -> *mut SignalSemaphore {
+        unsafe { FindSemaphore(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AddSemaphore(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { AddSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn RemSemaphore(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { RemSemaphore(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn SumKickData(&self) -> ULONG {
+        unsafe { SumKickData(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AddMemList(
+        &self,
+        size: ULONG,
+        attributes: ULONG,
+        pri: LONG,
+        base: APTR,
+        name: STRPTR,
+    ) {
+        unsafe { AddMemList(self.base.as_ptr(), size, attributes, pri, base, name) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn CopyMem(&self, source: CONST_APTR, dest: APTR, size: ULONG) {
+        unsafe { CopyMem(self.base.as_ptr(), source, dest, size) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    pub unsafe fn CopyMemQuick(&self, source: CONST_APTR, dest: APTR, size: ULONG) {
+        unsafe { CopyMemQuick(self.base.as_ptr(), source, dest, size) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CacheClearU(&self) {
+        unsafe { CacheClearU(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CacheClearE(&self, address: APTR, length: ULONG, caches: ULONG) {
+        unsafe { CacheClearE(self.base.as_ptr(), address, length, caches) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CacheControl(&self, cacheBits: ULONG, cacheMask: ULONG) -> ULONG {
+        unsafe { CacheControl(self.base.as_ptr(), cacheBits, cacheMask) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateIORequest(&self, port: *mut MsgPort, size: ULONG) -> APTR {
+        unsafe { CreateIORequest(self.base.as_ptr(), port, size) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteIORequest(&self, iorequest: APTR) {
+        unsafe { DeleteIORequest(self.base.as_ptr(), iorequest) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateMsgPort(&self) -> *mut MsgPort {
+        unsafe { CreateMsgPort(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteMsgPort(&self, port: *mut MsgPort) {
+        unsafe { DeleteMsgPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ObtainSemaphoreShared(&self, sigSem: *mut SignalSemaphore) {
+        unsafe { ObtainSemaphoreShared(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocVec(&self, byteSize: ULONG, requirements: ULONG) -> APTR {
+        unsafe { AllocVec(self.base.as_ptr(), byteSize, requirements) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeVec(&self, memoryBlock: APTR) {
+        unsafe { FreeVec(self.base.as_ptr(), memoryBlock) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn CreatePool(
+        &self,
+        requirements: ULONG,
+        puddleSize: ULONG,
+        threshSize: ULONG,
+    ) -> APTR {
+        unsafe { CreatePool(self.base.as_ptr(), requirements, puddleSize, threshSize) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn DeletePool(&self, poolHeader: APTR) {
+        unsafe { DeletePool(self.base.as_ptr(), poolHeader) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocPooled(&self, poolHeader: APTR, memSize: ULONG) -> APTR {
+        unsafe { AllocPooled(self.base.as_ptr(), poolHeader, memSize) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreePooled(&self, poolHeader: APTR, memory: APTR, memSize: ULONG) {
+        unsafe { FreePooled(self.base.as_ptr(), poolHeader, memory, memSize) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AttemptSemaphoreShared(&self, sigSem: *mut SignalSemaphore) -> ULONG {
+        unsafe { AttemptSemaphoreShared(self.base.as_ptr(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ColdReboot(&self) {
+        unsafe { ColdReboot(self.base.as_ptr()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StackSwap(&self, newStack: *mut StackSwapStruct) {
+        unsafe { StackSwap(self.base.as_ptr(), newStack) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CachePreDMA(
+        &self,
+        address: CONST_APTR,
+        length: *mut ULONG,
+        flags: ULONG,
+    ) -> APTR {
+        unsafe { CachePreDMA(self.base.as_ptr(), address, length, flags) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CachePostDMA(&self, address: CONST_APTR, length: *mut ULONG, flags: ULONG) {
+        unsafe { CachePostDMA(self.base.as_ptr(), address, length, flags) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AddMemHandler(&self, memhand: *mut Interrupt) {
+        unsafe { AddMemHandler(self.base.as_ptr(), memhand) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn RemMemHandler(&self, memhand: *mut Interrupt) {
+        unsafe { RemMemHandler(self.base.as_ptr(), memhand) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ObtainQuickVector(&self, interruptCode: APTR) -> ULONG {
+        unsafe { ObtainQuickVector(self.base.as_ptr(), interruptCode) }
+    }
+
+    /// libname: "exec.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    pub unsafe fn NewMinList(&self, minlist: *mut MinList) {
+        unsafe { NewMinList(self.base.as_ptr(), minlist) }
+    }
+}
+
+impl Drop for ExecLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `Supervisor()`
+pub const LVO_Supervisor: i16 = -30;
+/// Library vector offset of `InitCode()`
+pub const LVO_InitCode: i16 = -72;
+/// Library vector offset of `InitStruct()`
+pub const LVO_InitStruct: i16 = -78;
+/// Library vector offset of `MakeLibrary()`
+pub const LVO_MakeLibrary: i16 = -84;
+/// Library vector offset of `MakeFunctions()`
+pub const LVO_MakeFunctions: i16 = -90;
+/// Library vector offset of `FindResident()`
+pub const LVO_FindResident: i16 = -96;
+/// Library vector offset of `InitResident()`
+pub const LVO_InitResident: i16 = -102;
+/// Library vector offset of `Alert()`
+pub const LVO_Alert: i16 = -108;
+/// Library vector offset of `Debug()`
+pub const LVO_Debug: i16 = -114;
+/// Library vector offset of `Disable()`
+pub const LVO_Disable: i16 = -120;
+/// Library vector offset of `Enable()`
+pub const LVO_Enable: i16 = -126;
+/// Library vector offset of `Forbid()`
+pub const LVO_Forbid: i16 = -132;
+/// Library vector offset of `Permit()`
+pub const LVO_Permit: i16 = -138;
+/// Library vector offset of `SetSR()`
+pub const LVO_SetSR: i16 = -144;
+/// Library vector offset of `SuperState()`
+pub const LVO_SuperState: i16 = -150;
+/// Library vector offset of `UserState()`
+pub const LVO_UserState: i16 = -156;
+/// Library vector offset of `SetIntVector()`
+pub const LVO_SetIntVector: i16 = -162;
+/// Library vector offset of `AddIntServer()`
+pub const LVO_AddIntServer: i16 = -168;
+/// Library vector offset of `RemIntServer()`
+pub const LVO_RemIntServer: i16 = -174;
+/// Library vector offset of `Cause()`
+pub const LVO_Cause: i16 = -180;
+/// Library vector offset of `Allocate()`
+pub const LVO_Allocate: i16 = -186;
+/// Library vector offset of `Deallocate()`
+pub const LVO_Deallocate: i16 = -192;
+/// Library vector offset of `AllocMem()`
+pub const LVO_AllocMem: i16 = -198;
+/// Library vector offset of `AllocAbs()`
+pub const LVO_AllocAbs: i16 = -204;
+/// Library vector offset of `FreeMem()`
+pub const LVO_FreeMem: i16 = -210;
+/// Library vector offset of `AvailMem()`
+pub const LVO_AvailMem: i16 = -216;
+/// Library vector offset of `AllocEntry()`
+pub const LVO_AllocEntry: i16 = -222;
+/// Library vector offset of `FreeEntry()`
+pub const LVO_FreeEntry: i16 = -228;
+/// Library vector offset of `Insert()`
+pub const LVO_Insert: i16 = -234;
+/// Library vector offset of `InsertMinNode()`
+pub const LVO_InsertMinNode: i16 = -234;
+/// Library vector offset of `AddHead()`
+pub const LVO_AddHead: i16 = -240;
+/// Library vector offset of `AddHeadMinList()`
+pub const LVO_AddHeadMinList: i16 = -240;
+/// Library vector offset of `AddTail()`
+pub const LVO_AddTail: i16 = -246;
+/// Library vector offset of `AddTailMinList()`
+pub const LVO_AddTailMinList: i16 = -246;
+/// Library vector offset of `Remove()`
+pub const LVO_Remove: i16 = -252;
+/// Library vector offset of `RemoveMinNode()`
+pub const LVO_RemoveMinNode: i16 = -252;
+/// Library vector offset of `RemHead()`
+pub const LVO_RemHead: i16 = -258;
+/// Library vector offset of `RemHeadMinList()`
+pub const LVO_RemHeadMinList: i16 = -258;
+/// Library vector offset of `RemTail()`
+pub const LVO_RemTail: i16 = -264;
+/// Library vector offset of `RemTailMinList()`
+pub const LVO_RemTailMinList: i16 = -264;
+/// Library vector offset of `Enqueue()`
+pub const LVO_Enqueue: i16 = -270;
+/// Library vector offset of `FindName()`
+pub const LVO_FindName: i16 = -276;
+/// Library vector offset of `AddTask()`
+pub const LVO_AddTask: i16 = -282;
+/// Library vector offset of `RemTask()`
+pub const LVO_RemTask: i16 = -288;
+/// Library vector offset of `FindTask()`
+pub const LVO_FindTask: i16 = -294;
+/// Library vector offset of `SetTaskPri()`
+pub const LVO_SetTaskPri: i16 = -300;
+/// Library vector offset of `SetSignal()`
+pub const LVO_SetSignal: i16 = -306;
+/// Library vector offset of `SetExcept()`
+pub const LVO_SetExcept: i16 = -312;
+/// Library vector offset of `Wait()`
+pub const LVO_Wait: i16 = -318;
+/// Library vector offset of `Signal()`
+pub const LVO_Signal: i16 = -324;
+/// Library vector offset of `AllocSignal()`
+pub const LVO_AllocSignal: i16 = -330;
+/// Library vector offset of `FreeSignal()`
+pub const LVO_FreeSignal: i16 = -336;
+/// Library vector offset of `AllocTrap()`
+pub const LVO_AllocTrap: i16 = -342;
+/// Library vector offset of `FreeTrap()`
+pub const LVO_FreeTrap: i16 = -348;
+/// Library vector offset of `AddPort()`
+pub const LVO_AddPort: i16 = -354;
+/// Library vector offset of `RemPort()`
+pub const LVO_RemPort: i16 = -360;
+/// Library vector offset of `PutMsg()`
+pub const LVO_PutMsg: i16 = -366;
+/// Library vector offset of `GetMsg()`
+pub const LVO_GetMsg: i16 = -372;
+/// Library vector offset of `ReplyMsg()`
+pub const LVO_ReplyMsg: i16 = -378;
+/// Library vector offset of `WaitPort()`
+pub const LVO_WaitPort: i16 = -384;
+/// Library vector offset of `FindPort()`
+pub const LVO_FindPort: i16 = -390;
+/// Library vector offset of `AddLibrary()`
+pub const LVO_AddLibrary: i16 = -396;
+/// Library vector offset of `RemLibrary()`
+pub const LVO_RemLibrary: i16 = -402;
+/// Library vector offset of `OldOpenLibrary()`
+pub const LVO_OldOpenLibrary: i16 = -408;
+/// Library vector offset of `CloseLibrary()`
+pub const LVO_CloseLibrary: i16 = -414;
+/// Library vector offset of `SetFunction()`
+pub const LVO_SetFunction: i16 = -420;
+/// Library vector offset of `SumLibrary()`
+pub const LVO_SumLibrary: i16 = -426;
+/// Library vector offset of `AddDevice()`
+pub const LVO_AddDevice: i16 = -432;
+/// Library vector offset of `RemDevice()`
+pub const LVO_RemDevice: i16 = -438;
+/// Library vector offset of `OpenDevice()`
+pub const LVO_OpenDevice: i16 = -444;
+/// Library vector offset of `CloseDevice()`
+pub const LVO_CloseDevice: i16 = -450;
+/// Library vector offset of `DoIO()`
+pub const LVO_DoIO: i16 = -456;
+/// Library vector offset of `SendIO()`
+pub const LVO_SendIO: i16 = -462;
+/// Library vector offset of `CheckIO()`
+pub const LVO_CheckIO: i16 = -468;
+/// Library vector offset of `WaitIO()`
+pub const LVO_WaitIO: i16 = -474;
+/// Library vector offset of `AbortIO()`
+pub const LVO_AbortIO: i16 = -480;
+/// Library vector offset of `AddResource()`
+pub const LVO_AddResource: i16 = -486;
+/// Library vector offset of `RemResource()`
+pub const LVO_RemResource: i16 = -492;
+/// Library vector offset of `OpenResource()`
+pub const LVO_OpenResource: i16 = -498;
+/// Library vector offset of `RawDoFmt()`
+pub const LVO_RawDoFmt: i16 = -522;
+/// Library vector offset of `GetCC()`
+pub const LVO_GetCC: i16 = -528;
+/// Library vector offset of `TypeOfMem()`
+pub const LVO_TypeOfMem: i16 = -534;
+/// Library vector offset of `Procure()`
+pub const LVO_Procure: i16 = -540;
+/// Library vector offset of `Vacate()`
+pub const LVO_Vacate: i16 = -546;
+/// Library vector offset of `OpenLibrary()`
+pub const LVO_OpenLibrary: i16 = -552;
+/// Library vector offset of `InitSemaphore()`
+pub const LVO_InitSemaphore: i16 = -558;
+/// Library vector offset of `ObtainSemaphore()`
+pub const LVO_ObtainSemaphore: i16 = -564;
+/// Library vector offset of `ReleaseSemaphore()`
+pub const LVO_ReleaseSemaphore: i16 = -570;
+/// Library vector offset of `AttemptSemaphore()`
+pub const LVO_AttemptSemaphore: i16 = -576;
+/// Library vector offset of `ObtainSemaphoreList()`
+pub const LVO_ObtainSemaphoreList: i16 = -582;
+/// Library vector offset of `ReleaseSemaphoreList()`
+pub const LVO_ReleaseSemaphoreList: i16 = -588;
+/// Library vector offset of `FindSemaphore()`
+pub const LVO_FindSemaphore: i16 = -594;
+/// Library vector offset of `AddSemaphore()`
+pub const LVO_AddSemaphore: i16 = -600;
+/// Library vector offset of `RemSemaphore()`
+pub const LVO_RemSemaphore: i16 = -606;
+/// Library vector offset of `SumKickData()`
+pub const LVO_SumKickData: i16 = -612;
+/// Library vector offset of `AddMemList()`
+pub const LVO_AddMemList: i16 = -618;
+/// Library vector offset of `CopyMem()`
+pub const LVO_CopyMem: i16 = -624;
+/// Library vector offset of `CopyMemQuick()`
+pub const LVO_CopyMemQuick: i16 = -630;
+/// Library vector offset of `CacheClearU()`
+pub const LVO_CacheClearU: i16 = -636;
+/// Library vector offset of `CacheClearE()`
+pub const LVO_CacheClearE: i16 = -642;
+/// Library vector offset of `CacheControl()`
+pub const LVO_CacheControl: i16 = -648;
+/// Library vector offset of `CreateIORequest()`
+pub const LVO_CreateIORequest: i16 = -654;
+/// Library vector offset of `DeleteIORequest()`
+pub const LVO_DeleteIORequest: i16 = -660;
+/// Library vector offset of `CreateMsgPort()`
+pub const LVO_CreateMsgPort: i16 = -666;
+/// Library vector offset of `DeleteMsgPort()`
+pub const LVO_DeleteMsgPort: i16 = -672;
+/// Library vector offset of `ObtainSemaphoreShared()`
+pub const LVO_ObtainSemaphoreShared: i16 = -678;
+/// Library vector offset of `AllocVec()`
+pub const LVO_AllocVec: i16 = -684;
+/// Library vector offset of `FreeVec()`
+pub const LVO_FreeVec: i16 = -690;
+/// Library vector offset of `CreatePool()`
+pub const LVO_CreatePool: i16 = -696;
+/// Library vector offset of `DeletePool()`
+pub const LVO_DeletePool: i16 = -702;
+/// Library vector offset of `AllocPooled()`
+pub const LVO_AllocPooled: i16 = -708;
+/// Library vector offset of `FreePooled()`
+pub const LVO_FreePooled: i16 = -714;
+/// Library vector offset of `AttemptSemaphoreShared()`
+pub const LVO_AttemptSemaphoreShared: i16 = -720;
+/// Library vector offset of `ColdReboot()`
+pub const LVO_ColdReboot: i16 = -726;
+/// Library vector offset of `StackSwap()`
+pub const LVO_StackSwap: i16 = -732;
+/// Library vector offset of `CachePreDMA()`
+pub const LVO_CachePreDMA: i16 = -762;
+/// Library vector offset of `CachePostDMA()`
+pub const LVO_CachePostDMA: i16 = -768;
+/// Library vector offset of `AddMemHandler()`
+pub const LVO_AddMemHandler: i16 = -774;
+/// Library vector offset of `RemMemHandler()`
+pub const LVO_RemMemHandler: i16 = -780;
+/// Library vector offset of `ObtainQuickVector()`
+pub const LVO_ObtainQuickVector: i16 = -786;
+/// Library vector offset of `NewMinList()`
+pub const LVO_NewMinList: i16 = -828;
+
+/// Functions of "exec.library"
+pub static EXEC_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "Supervisor",
+        lvo: LVO_Supervisor,
+        registers: &["a5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitCode",
+        lvo: LVO_InitCode,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitStruct",
+        lvo: LVO_InitStruct,
+        registers: &["a1", "a2", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MakeLibrary",
+        lvo: LVO_MakeLibrary,
+        registers: &["a0", "a1", "a2", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MakeFunctions",
+        lvo: LVO_MakeFunctions,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FindResident",
+        lvo: LVO_FindResident,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitResident",
+        lvo: LVO_InitResident,
+        registers: &["a1", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Alert",
+        lvo: LVO_Alert,
+        registers: &["d7"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Debug",
+        lvo: LVO_Debug,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Disable",
+        lvo: LVO_Disable,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Enable",
+        lvo: LVO_Enable,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Forbid",
+        lvo: LVO_Forbid,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Permit",
+        lvo: LVO_Permit,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetSR",
+        lvo: LVO_SetSR,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SuperState",
+        lvo: LVO_SuperState,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UserState",
+        lvo: LVO_UserState,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetIntVector",
+        lvo: LVO_SetIntVector,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddIntServer",
+        lvo: LVO_AddIntServer,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemIntServer",
+        lvo: LVO_RemIntServer,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Cause",
+        lvo: LVO_Cause,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Allocate",
+        lvo: LVO_Allocate,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Deallocate",
+        lvo: LVO_Deallocate,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocMem",
+        lvo: LVO_AllocMem,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocAbs",
+        lvo: LVO_AllocAbs,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeMem",
+        lvo: LVO_FreeMem,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AvailMem",
+        lvo: LVO_AvailMem,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocEntry",
+        lvo: LVO_AllocEntry,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeEntry",
+        lvo: LVO_FreeEntry,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Insert",
+        lvo: LVO_Insert,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InsertMinNode",
+        lvo: LVO_InsertMinNode,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "AddHead",
+        lvo: LVO_AddHead,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddHeadMinList",
+        lvo: LVO_AddHeadMinList,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "AddTail",
+        lvo: LVO_AddTail,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddTailMinList",
+        lvo: LVO_AddTailMinList,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "Remove",
+        lvo: LVO_Remove,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemoveMinNode",
+        lvo: LVO_RemoveMinNode,
+        registers: &["a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "RemHead",
+        lvo: LVO_RemHead,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemHeadMinList",
+        lvo: LVO_RemHeadMinList,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "RemTail",
+        lvo: LVO_RemTail,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemTailMinList",
+        lvo: LVO_RemTailMinList,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "Enqueue",
+        lvo: LVO_Enqueue,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FindName",
+        lvo: LVO_FindName,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddTask",
+        lvo: LVO_AddTask,
+        registers: &["a1", "a2", "a3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemTask",
+        lvo: LVO_RemTask,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FindTask",
+        lvo: LVO_FindTask,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetTaskPri",
+        lvo: LVO_SetTaskPri,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetSignal",
+        lvo: LVO_SetSignal,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetExcept",
+        lvo: LVO_SetExcept,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Wait",
+        lvo: LVO_Wait,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Signal",
+        lvo: LVO_Signal,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocSignal",
+        lvo: LVO_AllocSignal,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeSignal",
+        lvo: LVO_FreeSignal,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocTrap",
+        lvo: LVO_AllocTrap,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeTrap",
+        lvo: LVO_FreeTrap,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddPort",
+        lvo: LVO_AddPort,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemPort",
+        lvo: LVO_RemPort,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "PutMsg",
+        lvo: LVO_PutMsg,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetMsg",
+        lvo: LVO_GetMsg,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReplyMsg",
+        lvo: LVO_ReplyMsg,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitPort",
+        lvo: LVO_WaitPort,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FindPort",
+        lvo: LVO_FindPort,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddLibrary",
+        lvo: LVO_AddLibrary,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemLibrary",
+        lvo: LVO_RemLibrary,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OldOpenLibrary",
+        lvo: LVO_OldOpenLibrary,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseLibrary",
+        lvo: LVO_CloseLibrary,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetFunction",
+        lvo: LVO_SetFunction,
+        registers: &["a1", "a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SumLibrary",
+        lvo: LVO_SumLibrary,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddDevice",
+        lvo: LVO_AddDevice,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemDevice",
+        lvo: LVO_RemDevice,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenDevice",
+        lvo: LVO_OpenDevice,
+        registers: &["a0", "d0", "a1", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseDevice",
+        lvo: LVO_CloseDevice,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DoIO",
+        lvo: LVO_DoIO,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SendIO",
+        lvo: LVO_SendIO,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CheckIO",
+        lvo: LVO_CheckIO,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitIO",
+        lvo: LVO_WaitIO,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AbortIO",
+        lvo: LVO_AbortIO,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddResource",
+        lvo: LVO_AddResource,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemResource",
+        lvo: LVO_RemResource,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenResource",
+        lvo: LVO_OpenResource,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RawDoFmt",
+        lvo: LVO_RawDoFmt,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetCC",
+        lvo: LVO_GetCC,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "TypeOfMem",
+        lvo: LVO_TypeOfMem,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Procure",
+        lvo: LVO_Procure,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Vacate",
+        lvo: LVO_Vacate,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenLibrary",
+        lvo: LVO_OpenLibrary,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitSemaphore",
+        lvo: LVO_InitSemaphore,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ObtainSemaphore",
+        lvo: LVO_ObtainSemaphore,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseSemaphore",
+        lvo: LVO_ReleaseSemaphore,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AttemptSemaphore",
+        lvo: LVO_AttemptSemaphore,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ObtainSemaphoreList",
+        lvo: LVO_ObtainSemaphoreList,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseSemaphoreList",
+        lvo: LVO_ReleaseSemaphoreList,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "FindSemaphore",
+        lvo: LVO_FindSemaphore,
+        registers: &["a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AddSemaphore",
+        lvo: LVO_AddSemaphore,
+        registers: &["a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "RemSemaphore",
+        lvo: LVO_RemSemaphore,
+        registers: &["a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "SumKickData",
+        lvo: LVO_SumKickData,
+        registers: &[],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AddMemList",
+        lvo: LVO_AddMemList,
+        registers: &["d0", "d1", "d2", "a0", "a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "CopyMem",
+        lvo: LVO_CopyMem,
+        registers: &["a0", "a1", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "CopyMemQuick",
+        lvo: LVO_CopyMemQuick,
+        registers: &["a0", "a1", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "CacheClearU",
+        lvo: LVO_CacheClearU,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CacheClearE",
+        lvo: LVO_CacheClearE,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CacheControl",
+        lvo: LVO_CacheControl,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateIORequest",
+        lvo: LVO_CreateIORequest,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteIORequest",
+        lvo: LVO_DeleteIORequest,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateMsgPort",
+        lvo: LVO_CreateMsgPort,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteMsgPort",
+        lvo: LVO_DeleteMsgPort,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ObtainSemaphoreShared",
+        lvo: LVO_ObtainSemaphoreShared,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocVec",
+        lvo: LVO_AllocVec,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeVec",
+        lvo: LVO_FreeVec,
+        registers: &["a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreatePool",
+        lvo: LVO_CreatePool,
+        registers: &["d0", "d1", "d2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "DeletePool",
+        lvo: LVO_DeletePool,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AllocPooled",
+        lvo: LVO_AllocPooled,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreePooled",
+        lvo: LVO_FreePooled,
+        registers: &["a0", "a1", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AttemptSemaphoreShared",
+        lvo: LVO_AttemptSemaphoreShared,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ColdReboot",
+        lvo: LVO_ColdReboot,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StackSwap",
+        lvo: LVO_StackSwap,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CachePreDMA",
+        lvo: LVO_CachePreDMA,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CachePostDMA",
+        lvo: LVO_CachePostDMA,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddMemHandler",
+        lvo: LVO_AddMemHandler,
+        registers: &["a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "RemMemHandler",
+        lvo: LVO_RemMemHandler,
+        registers: &["a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ObtainQuickVector",
+        lvo: LVO_ObtainQuickVector,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "NewMinList",
+        lvo: LVO_NewMinList,
+        registers: &["a0"],
+        min_version: 45,
+    },
+];
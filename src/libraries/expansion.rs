@@ -0,0 +1,902 @@
+//! Functions of "expansion.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn AddConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "AddConfigDev", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") configDev,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddBootNode(
+    ExpansionBase: *mut Library,
+    bootPri: LONG,
+    flags: ULONG,
+    deviceNode: *mut DeviceNode,
+    configDev: *mut ConfigDev,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 36, "AddBootNode", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") bootPri,
+            in("d1") flags,
+            in("a0") deviceNode,
+            in("a1") configDev,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn AllocBoardMem(ExpansionBase: *mut Library, slotSpec: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "AllocBoardMem", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") slotSpec,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn AllocConfigDev(ExpansionBase: *mut Library) -> *mut ConfigDev {
+    let asm_ret_value: *mut ConfigDev;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "AllocConfigDev", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn AllocExpansionMem(
+    ExpansionBase: *mut Library,
+    numSlots: ULONG,
+    slotAlign: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "AllocExpansionMem", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") numSlots,
+            in("d1") slotAlign,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ConfigBoard(ExpansionBase: *mut Library, board: APTR, configDev: *mut ConfigDev) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "ConfigBoard", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") board,
+            in("a1") configDev,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ConfigChain(ExpansionBase: *mut Library, baseAddr: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "ConfigChain", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") baseAddr,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn FindConfigDev(
+    ExpansionBase: *mut Library,
+    oldConfigDev: *const ConfigDev,
+    manufacturer: LONG,
+    product: LONG,
+) -> *mut ConfigDev {
+    let asm_ret_value: *mut ConfigDev;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "FindConfigDev", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") oldConfigDev,
+            in("d0") manufacturer,
+            in("d1") product,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn FreeBoardMem(ExpansionBase: *mut Library, startSlot: ULONG, slotSpec: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "FreeBoardMem", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") startSlot,
+            in("d1") slotSpec,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn FreeConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "FreeConfigDev", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") configDev,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn FreeExpansionMem(ExpansionBase: *mut Library, startSlot: ULONG, numSlots: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "FreeExpansionMem", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") startSlot,
+            in("d1") numSlots,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ReadExpansionByte(
+    ExpansionBase: *mut Library,
+    board: CONST_APTR,
+    offset: ULONG,
+) -> UBYTE {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "ReadExpansionByte", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") board,
+            in("d0") offset,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ReadExpansionRom(
+    ExpansionBase: *mut Library,
+    board: CONST_APTR,
+    configDev: *mut ConfigDev,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "ReadExpansionRom", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") board,
+            in("a1") configDev,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn RemConfigDev(ExpansionBase: *mut Library, configDev: *mut ConfigDev) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "RemConfigDev", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") configDev,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn WriteExpansionByte(
+    ExpansionBase: *mut Library,
+    board: APTR,
+    offset: ULONG,
+    byte: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "WriteExpansionByte", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") board,
+            in("d0") offset,
+            in("d1") byte,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ObtainConfigBinding(ExpansionBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ExpansionBase,
+            33,
+            "ObtainConfigBinding",
+            "expansion.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn ReleaseConfigBinding(ExpansionBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ExpansionBase,
+            33,
+            "ReleaseConfigBinding",
+            "expansion.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn SetCurrentBinding(
+    ExpansionBase: *mut Library,
+    currentBinding: *mut CurrentBinding,
+    bindingSize: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "SetCurrentBinding", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") currentBinding,
+            in("d0") bindingSize,
+        );
+    }
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn GetCurrentBinding(
+    ExpansionBase: *mut Library,
+    currentBinding: *const CurrentBinding,
+    bindingSize: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "GetCurrentBinding", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") currentBinding,
+            in("d0") bindingSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn MakeDosNode(ExpansionBase: *mut Library, parmPacket: CONST_APTR) -> *mut DeviceNode {
+    let asm_ret_value: *mut DeviceNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "MakeDosNode", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("a0") parmPacket,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "expansion.library" (V33) (Kickstart 1.2)
+pub unsafe fn AddDosNode(
+    ExpansionBase: *mut Library,
+    bootPri: LONG,
+    flags: ULONG,
+    deviceNode: *mut DeviceNode,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(ExpansionBase, 33, "AddDosNode", "expansion.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ExpansionBase,
+            in("d0") bootPri,
+            in("d1") flags,
+            in("a0") deviceNode,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// An open "expansion.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct ExpansionLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl ExpansionLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"expansion.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "expansion.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AddConfigDev(&self, configDev: *mut ConfigDev) {
+        unsafe { AddConfigDev(self.base.as_ptr(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddBootNode(
+        &self,
+        bootPri: LONG,
+        flags: ULONG,
+        deviceNode: *mut DeviceNode,
+        configDev: *mut ConfigDev,
+    ) -> BOOL {
+        unsafe { AddBootNode(self.base.as_ptr(), bootPri, flags, deviceNode, configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AllocBoardMem(&self, slotSpec: ULONG) {
+        unsafe { AllocBoardMem(self.base.as_ptr(), slotSpec) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AllocConfigDev(&self) -> *mut ConfigDev {
+        unsafe { AllocConfigDev(self.base.as_ptr()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AllocExpansionMem(&self, numSlots: ULONG, slotAlign: ULONG) -> APTR {
+        unsafe { AllocExpansionMem(self.base.as_ptr(), numSlots, slotAlign) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ConfigBoard(&self, board: APTR, configDev: *mut ConfigDev) {
+        unsafe { ConfigBoard(self.base.as_ptr(), board, configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ConfigChain(&self, baseAddr: APTR) {
+        unsafe { ConfigChain(self.base.as_ptr(), baseAddr) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn FindConfigDev(
+        &self,
+        oldConfigDev: *const ConfigDev,
+        manufacturer: LONG,
+        product: LONG,
+    ) -> *mut ConfigDev {
+        unsafe { FindConfigDev(self.base.as_ptr(), oldConfigDev, manufacturer, product) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn FreeBoardMem(&self, startSlot: ULONG, slotSpec: ULONG) {
+        unsafe { FreeBoardMem(self.base.as_ptr(), startSlot, slotSpec) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn FreeConfigDev(&self, configDev: *mut ConfigDev) {
+        unsafe { FreeConfigDev(self.base.as_ptr(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn FreeExpansionMem(&self, startSlot: ULONG, numSlots: ULONG) {
+        unsafe { FreeExpansionMem(self.base.as_ptr(), startSlot, numSlots) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ReadExpansionByte(&self, board: CONST_APTR, offset: ULONG) -> UBYTE {
+        unsafe { ReadExpansionByte(self.base.as_ptr(), board, offset) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ReadExpansionRom(&self, board: CONST_APTR, configDev: *mut ConfigDev) {
+        unsafe { ReadExpansionRom(self.base.as_ptr(), board, configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn RemConfigDev(&self, configDev: *mut ConfigDev) {
+        unsafe { RemConfigDev(self.base.as_ptr(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn WriteExpansionByte(&self, board: APTR, offset: ULONG, byte: ULONG) {
+        unsafe { WriteExpansionByte(self.base.as_ptr(), board, offset, byte) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ObtainConfigBinding(&self) {
+        unsafe { ObtainConfigBinding(self.base.as_ptr()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ReleaseConfigBinding(&self) {
+        unsafe { ReleaseConfigBinding(self.base.as_ptr()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn SetCurrentBinding(
+        &self,
+        currentBinding: *mut CurrentBinding,
+        bindingSize: ULONG,
+    ) {
+        unsafe { SetCurrentBinding(self.base.as_ptr(), currentBinding, bindingSize) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn GetCurrentBinding(
+        &self,
+        currentBinding: *const CurrentBinding,
+        bindingSize: ULONG,
+    ) -> ULONG {
+        unsafe { GetCurrentBinding(self.base.as_ptr(), currentBinding, bindingSize) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn MakeDosNode(&self, parmPacket: CONST_APTR) -> *mut DeviceNode {
+        unsafe { MakeDosNode(self.base.as_ptr(), parmPacket) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AddDosNode(
+        &self,
+        bootPri: LONG,
+        flags: ULONG,
+        deviceNode: *mut DeviceNode,
+    ) -> BOOL {
+        unsafe { AddDosNode(self.base.as_ptr(), bootPri, flags, deviceNode) }
+    }
+}
+
+impl Drop for ExpansionLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `AddConfigDev()`
+pub const LVO_AddConfigDev: i16 = -30;
+/// Library vector offset of `AddBootNode()`
+pub const LVO_AddBootNode: i16 = -36;
+/// Library vector offset of `AllocBoardMem()`
+pub const LVO_AllocBoardMem: i16 = -42;
+/// Library vector offset of `AllocConfigDev()`
+pub const LVO_AllocConfigDev: i16 = -48;
+/// Library vector offset of `AllocExpansionMem()`
+pub const LVO_AllocExpansionMem: i16 = -54;
+/// Library vector offset of `ConfigBoard()`
+pub const LVO_ConfigBoard: i16 = -60;
+/// Library vector offset of `ConfigChain()`
+pub const LVO_ConfigChain: i16 = -66;
+/// Library vector offset of `FindConfigDev()`
+pub const LVO_FindConfigDev: i16 = -72;
+/// Library vector offset of `FreeBoardMem()`
+pub const LVO_FreeBoardMem: i16 = -78;
+/// Library vector offset of `FreeConfigDev()`
+pub const LVO_FreeConfigDev: i16 = -84;
+/// Library vector offset of `FreeExpansionMem()`
+pub const LVO_FreeExpansionMem: i16 = -90;
+/// Library vector offset of `ReadExpansionByte()`
+pub const LVO_ReadExpansionByte: i16 = -96;
+/// Library vector offset of `ReadExpansionRom()`
+pub const LVO_ReadExpansionRom: i16 = -102;
+/// Library vector offset of `RemConfigDev()`
+pub const LVO_RemConfigDev: i16 = -108;
+/// Library vector offset of `WriteExpansionByte()`
+pub const LVO_WriteExpansionByte: i16 = -114;
+/// Library vector offset of `ObtainConfigBinding()`
+pub const LVO_ObtainConfigBinding: i16 = -120;
+/// Library vector offset of `ReleaseConfigBinding()`
+pub const LVO_ReleaseConfigBinding: i16 = -126;
+/// Library vector offset of `SetCurrentBinding()`
+pub const LVO_SetCurrentBinding: i16 = -132;
+/// Library vector offset of `GetCurrentBinding()`
+pub const LVO_GetCurrentBinding: i16 = -138;
+/// Library vector offset of `MakeDosNode()`
+pub const LVO_MakeDosNode: i16 = -144;
+/// Library vector offset of `AddDosNode()`
+pub const LVO_AddDosNode: i16 = -150;
+
+/// Functions of "expansion.library"
+pub static EXPANSION_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AddConfigDev",
+        lvo: LVO_AddConfigDev,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AddBootNode",
+        lvo: LVO_AddBootNode,
+        registers: &["d0", "d1", "a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocBoardMem",
+        lvo: LVO_AllocBoardMem,
+        registers: &["d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AllocConfigDev",
+        lvo: LVO_AllocConfigDev,
+        registers: &[],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AllocExpansionMem",
+        lvo: LVO_AllocExpansionMem,
+        registers: &["d0", "d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ConfigBoard",
+        lvo: LVO_ConfigBoard,
+        registers: &["a0", "a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ConfigChain",
+        lvo: LVO_ConfigChain,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "FindConfigDev",
+        lvo: LVO_FindConfigDev,
+        registers: &["a0", "d0", "d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "FreeBoardMem",
+        lvo: LVO_FreeBoardMem,
+        registers: &["d0", "d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "FreeConfigDev",
+        lvo: LVO_FreeConfigDev,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "FreeExpansionMem",
+        lvo: LVO_FreeExpansionMem,
+        registers: &["d0", "d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ReadExpansionByte",
+        lvo: LVO_ReadExpansionByte,
+        registers: &["a0", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ReadExpansionRom",
+        lvo: LVO_ReadExpansionRom,
+        registers: &["a0", "a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "RemConfigDev",
+        lvo: LVO_RemConfigDev,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "WriteExpansionByte",
+        lvo: LVO_WriteExpansionByte,
+        registers: &["a0", "d0", "d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ObtainConfigBinding",
+        lvo: LVO_ObtainConfigBinding,
+        registers: &[],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseConfigBinding",
+        lvo: LVO_ReleaseConfigBinding,
+        registers: &[],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "SetCurrentBinding",
+        lvo: LVO_SetCurrentBinding,
+        registers: &["a0", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "GetCurrentBinding",
+        lvo: LVO_GetCurrentBinding,
+        registers: &["a0", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "MakeDosNode",
+        lvo: LVO_MakeDosNode,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AddDosNode",
+        lvo: LVO_AddDosNode,
+        registers: &["d0", "d1", "a0"],
+        min_version: 33,
+    },
+];
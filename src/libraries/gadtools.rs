@@ -0,0 +1,992 @@
+//! Functions of "gadtools.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateGadgetA(
+    GadToolsBase: *mut Library,
+    kind: ULONG,
+    gad: *mut Gadget,
+    ng: *mut NewGadget,
+    taglist: *const TagItem,
+) -> *mut Gadget {
+    let asm_ret_value: *mut Gadget;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "CreateGadgetA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("d0") kind,
+            in("a0") gad,
+            in("a1") ng,
+            in("a2") taglist,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeGadgets(GadToolsBase: *mut Library, gad: *mut Gadget) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "FreeGadgets", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") gad,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_SetGadgetAttrsA(
+    GadToolsBase: *mut Library,
+    gad: *mut Gadget,
+    win: *mut Window,
+    req: *mut Requester,
+    taglist: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_SetGadgetAttrsA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") gad,
+            in("a1") win,
+            in("a2") req,
+            in("a3") taglist,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateMenusA(
+    GadToolsBase: *mut Library,
+    newmenu: *const NewMenu,
+    taglist: *mut TagItem,
+) -> *mut Menu {
+    let asm_ret_value: *mut Menu;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "CreateMenusA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") newmenu,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeMenus(GadToolsBase: *mut Library, menu: *mut Menu) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "FreeMenus", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") menu,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LayoutMenuItemsA(
+    GadToolsBase: *mut Library,
+    firstitem: *mut MenuItem,
+    vi: APTR,
+    taglist: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "LayoutMenuItemsA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") firstitem,
+            in("a1") vi,
+            in("a2") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LayoutMenusA(
+    GadToolsBase: *mut Library,
+    firstmenu: *mut Menu,
+    vi: APTR,
+    taglist: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "LayoutMenusA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") firstmenu,
+            in("a1") vi,
+            in("a2") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_GetIMsg(GadToolsBase: *mut Library, iport: *mut MsgPort) -> *mut IntuiMessage {
+    let asm_ret_value: *mut IntuiMessage;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_GetIMsg", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") iport,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_ReplyIMsg(GadToolsBase: *mut Library, imsg: *mut IntuiMessage) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_ReplyIMsg", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a1") imsg,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_RefreshWindow(GadToolsBase: *mut Library, win: *mut Window, req: *mut Requester) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_RefreshWindow", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") win,
+            in("a1") req,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_BeginRefresh(GadToolsBase: *mut Library, win: *mut Window) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_BeginRefresh", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") win,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_EndRefresh(GadToolsBase: *mut Library, win: *mut Window, complete: LONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_EndRefresh", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") win,
+            in("d0") complete,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_FilterIMsg(
+    GadToolsBase: *mut Library,
+    imsg: *const IntuiMessage,
+) -> *mut IntuiMessage {
+    let asm_ret_value: *mut IntuiMessage;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_FilterIMsg", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a1") imsg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GT_PostFilterIMsg(
+    GadToolsBase: *mut Library,
+    imsg: *mut IntuiMessage,
+) -> *mut IntuiMessage {
+    let asm_ret_value: *mut IntuiMessage;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GT_PostFilterIMsg", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a1") imsg,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateContext(GadToolsBase: *mut Library, glistptr: *mut *mut Gadget) -> *mut Gadget {
+    let asm_ret_value: *mut Gadget;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "CreateContext", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") glistptr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DrawBevelBoxA(
+    GadToolsBase: *mut Library,
+    rport: *mut RastPort,
+    left: LONG,
+    top: LONG,
+    width: LONG,
+    height: LONG,
+    taglist: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "DrawBevelBoxA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") rport,
+            in("d0") left,
+            in("d1") top,
+            in("d2") width,
+            in("d3") height,
+            in("a1") taglist,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetVisualInfoA(
+    GadToolsBase: *mut Library,
+    screen: *mut Screen,
+    taglist: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "GetVisualInfoA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") screen,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeVisualInfo(GadToolsBase: *mut Library, vi: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 36, "FreeVisualInfo", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") vi,
+        );
+    }
+}
+
+/// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SetDesignFontA(
+    GadToolsBase: *mut Library,
+    vi: APTR,
+    tattr: *mut TextAttr,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 47, "SetDesignFontA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") vi,
+            in("a1") tattr,
+            in("a2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ScaleGadgetRectA(
+    GadToolsBase: *mut Library,
+    ng: *mut NewGadget,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 47, "ScaleGadgetRectA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") ng,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "gadtools.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GT_GetGadgetAttrsA(
+    GadToolsBase: *mut Library,
+    gad: *mut Gadget,
+    win: *mut Window,
+    req: *mut Requester,
+    taglist: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GadToolsBase, 39, "GT_GetGadgetAttrsA", "gadtools.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GadToolsBase,
+            in("a0") gad,
+            in("a1") win,
+            in("a2") req,
+            in("a3") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "gadtools.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct GadToolsLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl GadToolsLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"gadtools.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "gadtools.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateGadgetA(
+        &self,
+        kind: ULONG,
+        gad: *mut Gadget,
+        ng: *mut NewGadget,
+        taglist: *const TagItem,
+    ) -> *mut Gadget {
+        unsafe { CreateGadgetA(self.base.as_ptr(), kind, gad, ng, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeGadgets(&self, gad: *mut Gadget) {
+        unsafe { FreeGadgets(self.base.as_ptr(), gad) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_SetGadgetAttrsA(
+        &self,
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        taglist: *const TagItem,
+    ) {
+        unsafe { GT_SetGadgetAttrsA(self.base.as_ptr(), gad, win, req, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateMenusA(&self, newmenu: *const NewMenu, taglist: *mut TagItem) -> *mut Menu {
+        unsafe { CreateMenusA(self.base.as_ptr(), newmenu, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeMenus(&self, menu: *mut Menu) {
+        unsafe { FreeMenus(self.base.as_ptr(), menu) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LayoutMenuItemsA(
+        &self,
+        firstitem: *mut MenuItem,
+        vi: APTR,
+        taglist: *const TagItem,
+    ) -> BOOL {
+        unsafe { LayoutMenuItemsA(self.base.as_ptr(), firstitem, vi, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LayoutMenusA(
+        &self,
+        firstmenu: *mut Menu,
+        vi: APTR,
+        taglist: *const TagItem,
+    ) -> BOOL {
+        unsafe { LayoutMenusA(self.base.as_ptr(), firstmenu, vi, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_GetIMsg(&self, iport: *mut MsgPort) -> *mut IntuiMessage {
+        unsafe { GT_GetIMsg(self.base.as_ptr(), iport) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_ReplyIMsg(&self, imsg: *mut IntuiMessage) {
+        unsafe { GT_ReplyIMsg(self.base.as_ptr(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_RefreshWindow(&self, win: *mut Window, req: *mut Requester) {
+        unsafe { GT_RefreshWindow(self.base.as_ptr(), win, req) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_BeginRefresh(&self, win: *mut Window) {
+        unsafe { GT_BeginRefresh(self.base.as_ptr(), win) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_EndRefresh(&self, win: *mut Window, complete: LONG) {
+        unsafe { GT_EndRefresh(self.base.as_ptr(), win, complete) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_FilterIMsg(&self, imsg: *const IntuiMessage) -> *mut IntuiMessage {
+        unsafe { GT_FilterIMsg(self.base.as_ptr(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GT_PostFilterIMsg(&self, imsg: *mut IntuiMessage) -> *mut IntuiMessage {
+        unsafe { GT_PostFilterIMsg(self.base.as_ptr(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateContext(&self, glistptr: *mut *mut Gadget) -> *mut Gadget {
+        unsafe { CreateContext(self.base.as_ptr(), glistptr) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DrawBevelBoxA(
+        &self,
+        rport: *mut RastPort,
+        left: LONG,
+        top: LONG,
+        width: LONG,
+        height: LONG,
+        taglist: *const TagItem,
+    ) {
+        unsafe { DrawBevelBoxA(self.base.as_ptr(), rport, left, top, width, height, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetVisualInfoA(&self, screen: *mut Screen, taglist: *const TagItem) -> APTR {
+        unsafe { GetVisualInfoA(self.base.as_ptr(), screen, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeVisualInfo(&self, vi: APTR) {
+        unsafe { FreeVisualInfo(self.base.as_ptr(), vi) }
+    }
+
+    /// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SetDesignFontA(
+        &self,
+        vi: APTR,
+        tattr: *mut TextAttr,
+        tags: *const TagItem,
+    ) -> LONG {
+        unsafe { SetDesignFontA(self.base.as_ptr(), vi, tattr, tags) }
+    }
+
+    /// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ScaleGadgetRectA(&self, ng: *mut NewGadget, tags: *const TagItem) -> LONG {
+        unsafe { ScaleGadgetRectA(self.base.as_ptr(), ng, tags) }
+    }
+
+    /// libname: "gadtools.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GT_GetGadgetAttrsA(
+        &self,
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        taglist: *const TagItem,
+    ) -> LONG {
+        unsafe { GT_GetGadgetAttrsA(self.base.as_ptr(), gad, win, req, taglist) }
+    }
+}
+
+impl Drop for GadToolsLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `CreateGadgetA()`
+pub const LVO_CreateGadgetA: i16 = -30;
+/// Library vector offset of `FreeGadgets()`
+pub const LVO_FreeGadgets: i16 = -36;
+/// Library vector offset of `GT_SetGadgetAttrsA()`
+pub const LVO_GT_SetGadgetAttrsA: i16 = -42;
+/// Library vector offset of `CreateMenusA()`
+pub const LVO_CreateMenusA: i16 = -48;
+/// Library vector offset of `FreeMenus()`
+pub const LVO_FreeMenus: i16 = -54;
+/// Library vector offset of `LayoutMenuItemsA()`
+pub const LVO_LayoutMenuItemsA: i16 = -60;
+/// Library vector offset of `LayoutMenusA()`
+pub const LVO_LayoutMenusA: i16 = -66;
+/// Library vector offset of `GT_GetIMsg()`
+pub const LVO_GT_GetIMsg: i16 = -72;
+/// Library vector offset of `GT_ReplyIMsg()`
+pub const LVO_GT_ReplyIMsg: i16 = -78;
+/// Library vector offset of `GT_RefreshWindow()`
+pub const LVO_GT_RefreshWindow: i16 = -84;
+/// Library vector offset of `GT_BeginRefresh()`
+pub const LVO_GT_BeginRefresh: i16 = -90;
+/// Library vector offset of `GT_EndRefresh()`
+pub const LVO_GT_EndRefresh: i16 = -96;
+/// Library vector offset of `GT_FilterIMsg()`
+pub const LVO_GT_FilterIMsg: i16 = -102;
+/// Library vector offset of `GT_PostFilterIMsg()`
+pub const LVO_GT_PostFilterIMsg: i16 = -108;
+/// Library vector offset of `CreateContext()`
+pub const LVO_CreateContext: i16 = -114;
+/// Library vector offset of `DrawBevelBoxA()`
+pub const LVO_DrawBevelBoxA: i16 = -120;
+/// Library vector offset of `GetVisualInfoA()`
+pub const LVO_GetVisualInfoA: i16 = -126;
+/// Library vector offset of `FreeVisualInfo()`
+pub const LVO_FreeVisualInfo: i16 = -132;
+/// Library vector offset of `SetDesignFontA()`
+pub const LVO_SetDesignFontA: i16 = -138;
+/// Library vector offset of `ScaleGadgetRectA()`
+pub const LVO_ScaleGadgetRectA: i16 = -144;
+/// Library vector offset of `GT_GetGadgetAttrsA()`
+pub const LVO_GT_GetGadgetAttrsA: i16 = -174;
+
+/// Functions of "gadtools.library"
+pub static GADTOOLS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CreateGadgetA",
+        lvo: LVO_CreateGadgetA,
+        registers: &["d0", "a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeGadgets",
+        lvo: LVO_FreeGadgets,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_SetGadgetAttrsA",
+        lvo: LVO_GT_SetGadgetAttrsA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateMenusA",
+        lvo: LVO_CreateMenusA,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeMenus",
+        lvo: LVO_FreeMenus,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LayoutMenuItemsA",
+        lvo: LVO_LayoutMenuItemsA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LayoutMenusA",
+        lvo: LVO_LayoutMenusA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_GetIMsg",
+        lvo: LVO_GT_GetIMsg,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_ReplyIMsg",
+        lvo: LVO_GT_ReplyIMsg,
+        registers: &["a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_RefreshWindow",
+        lvo: LVO_GT_RefreshWindow,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_BeginRefresh",
+        lvo: LVO_GT_BeginRefresh,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_EndRefresh",
+        lvo: LVO_GT_EndRefresh,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_FilterIMsg",
+        lvo: LVO_GT_FilterIMsg,
+        registers: &["a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GT_PostFilterIMsg",
+        lvo: LVO_GT_PostFilterIMsg,
+        registers: &["a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateContext",
+        lvo: LVO_CreateContext,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DrawBevelBoxA",
+        lvo: LVO_DrawBevelBoxA,
+        registers: &["a0", "d0", "d1", "d2", "d3", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetVisualInfoA",
+        lvo: LVO_GetVisualInfoA,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeVisualInfo",
+        lvo: LVO_FreeVisualInfo,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetDesignFontA",
+        lvo: LVO_SetDesignFontA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ScaleGadgetRectA",
+        lvo: LVO_ScaleGadgetRectA,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "GT_GetGadgetAttrsA",
+        lvo: LVO_GT_GetGadgetAttrsA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 39,
+    },
+];
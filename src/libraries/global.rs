@@ -0,0 +1,11010 @@
+//! Library functions which use global library bases
+//!
+//! The functions read their library base from a static variable (`SysBase`, `DOSBase`,
+//! `GfxBase`, etc.) instead of taking it as the first parameter. `SysBase` is initialized
+//! automatically, the other library bases must be set by the program, unless the
+//! `auto-open` feature is enabled.
+//!
+//! With the `auto-open` feature, libraries are opened when one of their functions is called
+//! for the first time. The library is opened with the version `AUTO_OPEN_VERSION`. With the
+//! `startup` feature, the libraries are closed with `close_libraries()` when the program
+//! exits, otherwise the program must call `close_libraries()` before exiting. A library
+//! which the program has opened itself and stored in a global base must be closed by the
+//! program and its base set back to null, so that it isn't closed twice.
+//!
+//! If a library can't be opened, for example a V36 library on Kickstart 1.3, the
+//! `startup` feature writes `can't open <library>` to the CLI and exits the program with
+//! `RETURN_FAIL`, without running the destructors. Without the `startup` feature, or with the
+//! `panic-handler` feature, which reports the message, a missing library panics. Programs
+//! which can run without a library should open it themselves and check the result.
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+
+/// Minimum library version used when libraries are opened automatically
+pub static mut AUTO_OPEN_VERSION: ULONG = 0;
+
+/// Closes the libraries in the global library bases and sets the bases to null
+///
+/// Resource, device and class bases are not touched.
+pub unsafe fn close_libraries() {
+    #[cfg(feature = "amigaguide")]
+    unsafe {
+        close_library(&raw mut AmigaGuideBase)
+    }
+    #[cfg(feature = "asl")]
+    unsafe {
+        close_library(&raw mut AslBase)
+    }
+    #[cfg(feature = "bullet")]
+    unsafe {
+        close_library(&raw mut BulletBase)
+    }
+    #[cfg(feature = "commodities")]
+    unsafe {
+        close_library(&raw mut CxBase)
+    }
+    #[cfg(feature = "datatypes")]
+    unsafe {
+        close_library(&raw mut DataTypesBase)
+    }
+    #[cfg(feature = "diskfont")]
+    unsafe {
+        close_library(&raw mut DiskfontBase)
+    }
+    #[cfg(feature = "dos")]
+    unsafe {
+        close_library(&raw mut DOSBase)
+    }
+    #[cfg(feature = "expansion")]
+    unsafe {
+        close_library(&raw mut ExpansionBase)
+    }
+    #[cfg(feature = "gadtools")]
+    unsafe {
+        close_library(&raw mut GadToolsBase)
+    }
+    #[cfg(feature = "graphics")]
+    unsafe {
+        close_library(&raw mut GfxBase)
+    }
+    #[cfg(feature = "icon")]
+    unsafe {
+        close_library(&raw mut IconBase)
+    }
+    #[cfg(feature = "iffparse")]
+    unsafe {
+        close_library(&raw mut IFFParseBase)
+    }
+    #[cfg(feature = "intuition")]
+    unsafe {
+        close_library(&raw mut IntuitionBase)
+    }
+    #[cfg(feature = "keymap")]
+    unsafe {
+        close_library(&raw mut KeymapBase)
+    }
+    #[cfg(feature = "layers")]
+    unsafe {
+        close_library(&raw mut LayersBase)
+    }
+    #[cfg(feature = "locale")]
+    unsafe {
+        close_library(&raw mut LocaleBase)
+    }
+    #[cfg(feature = "lowlevel")]
+    unsafe {
+        close_library(&raw mut LowLevelBase)
+    }
+    #[cfg(feature = "mathffp")]
+    unsafe {
+        close_library(&raw mut MathBase)
+    }
+    #[cfg(feature = "mathieeedoubbas")]
+    unsafe {
+        close_library(&raw mut MathIeeeDoubBasBase)
+    }
+    #[cfg(feature = "mathieeedoubtrans")]
+    unsafe {
+        close_library(&raw mut MathIeeeDoubTransBase)
+    }
+    #[cfg(feature = "mathieeesingbas")]
+    unsafe {
+        close_library(&raw mut MathIeeeSingBasBase)
+    }
+    #[cfg(feature = "mathieeesingtrans")]
+    unsafe {
+        close_library(&raw mut MathIeeeSingTransBase)
+    }
+    #[cfg(feature = "mathtrans")]
+    unsafe {
+        close_library(&raw mut MathTransBase)
+    }
+    #[cfg(feature = "nonvolatile")]
+    unsafe {
+        close_library(&raw mut NVBase)
+    }
+    #[cfg(feature = "realtime")]
+    unsafe {
+        close_library(&raw mut RealTimeBase)
+    }
+    #[cfg(feature = "rexxsyslib")]
+    unsafe {
+        close_library(&raw mut RexxSysBase)
+    }
+    #[cfg(feature = "translator")]
+    unsafe {
+        close_library(&raw mut TranslatorBase)
+    }
+    #[cfg(feature = "utility")]
+    unsafe {
+        close_library(&raw mut UtilityBase)
+    }
+    #[cfg(feature = "workbench")]
+    unsafe {
+        close_library(&raw mut WorkbenchBase)
+    }
+}
+
+#[allow(dead_code)]
+unsafe fn close_library(base: *mut *mut Library) {
+    unsafe {
+        if !(*base).is_null() {
+            crate::exec::CloseLibrary(exec::get_SysBase(), *base);
+            *base = ::core::ptr::null_mut();
+        }
+    }
+}
+
+#[allow(dead_code)]
+unsafe fn auto_open(base: *mut *mut Library, name: &::core::ffi::CStr) -> *mut Library {
+    unsafe {
+        if (*base).is_null() {
+            *base = crate::exec::OpenLibrary(
+                exec::get_SysBase(),
+                name.as_ptr() as CONST_STRPTR,
+                AUTO_OPEN_VERSION,
+            );
+            if (*base).is_null() {
+                #[cfg(all(
+                    feature = "auto-open",
+                    feature = "startup",
+                    not(feature = "panic-handler")
+                ))]
+                crate::startup::exit_missing_library(name);
+                #[cfg(not(all(
+                    feature = "auto-open",
+                    feature = "startup",
+                    not(feature = "panic-handler")
+                )))]
+                panic!("can't open {}", name.to_str().unwrap_or("library"));
+            }
+        }
+        *base
+    }
+}
+
+#[cfg(feature = "amigaguide")]
+pub use amigaguide::*;
+
+#[cfg(feature = "amigaguide")]
+#[allow(unused_imports, dead_code)]
+mod amigaguide {
+    use super::*;
+
+    /// Global library base for "amigaguide.library"
+    pub static mut AmigaGuideBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_AmigaGuideBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut AmigaGuideBase, c"amigaguide.library")
+            } else {
+                AmigaGuideBase
+            }
+        }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LockAmigaGuideBase(handle: APTR) -> LONG {
+        unsafe { crate::amigaguide::LockAmigaGuideBase(get_AmigaGuideBase(), handle) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn UnlockAmigaGuideBase(key: LONG) {
+        unsafe { crate::amigaguide::UnlockAmigaGuideBase(get_AmigaGuideBase(), key) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn OpenAmigaGuideA(nag: *mut NewAmigaGuide, attrs: *const TagItem) -> APTR {
+        unsafe { crate::amigaguide::OpenAmigaGuideA(get_AmigaGuideBase(), nag, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn OpenAmigaGuideAsyncA(nag: *mut NewAmigaGuide, attrs: *const TagItem) -> APTR {
+        unsafe { crate::amigaguide::OpenAmigaGuideAsyncA(get_AmigaGuideBase(), nag, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CloseAmigaGuide(cl: APTR) {
+        unsafe { crate::amigaguide::CloseAmigaGuide(get_AmigaGuideBase(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AmigaGuideSignal(cl: APTR) -> ULONG {
+        unsafe { crate::amigaguide::AmigaGuideSignal(get_AmigaGuideBase(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetAmigaGuideMsg(cl: APTR) -> *mut AmigaGuideMsg {
+        unsafe { crate::amigaguide::GetAmigaGuideMsg(get_AmigaGuideBase(), cl) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ReplyAmigaGuideMsg(amsg: *mut AmigaGuideMsg) {
+        unsafe { crate::amigaguide::ReplyAmigaGuideMsg(get_AmigaGuideBase(), amsg) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetAmigaGuideContextA(cl: APTR, id: ULONG, attrs: *const TagItem) -> LONG {
+        unsafe { crate::amigaguide::SetAmigaGuideContextA(get_AmigaGuideBase(), cl, id, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SendAmigaGuideContextA(cl: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { crate::amigaguide::SendAmigaGuideContextA(get_AmigaGuideBase(), cl, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SendAmigaGuideCmdA(cl: APTR, cmd: STRPTR, attrs: *const TagItem) -> LONG {
+        unsafe { crate::amigaguide::SendAmigaGuideCmdA(get_AmigaGuideBase(), cl, cmd, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetAmigaGuideAttrsA(cl: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { crate::amigaguide::SetAmigaGuideAttrsA(get_AmigaGuideBase(), cl, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetAmigaGuideAttr(tag1: Tag, cl: APTR, storage: *mut ULONG) -> LONG {
+        unsafe { crate::amigaguide::GetAmigaGuideAttr(get_AmigaGuideBase(), tag1, cl, storage) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LoadXRef(lock: BPTR, name: STRPTR) -> LONG {
+        unsafe { crate::amigaguide::LoadXRef(get_AmigaGuideBase(), lock, name) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ExpungeXRef() {
+        unsafe { crate::amigaguide::ExpungeXRef(get_AmigaGuideBase()) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AddAmigaGuideHostA(
+        h: *mut Hook,
+        name: CONST_STRPTR,
+        attrs: *const TagItem,
+    ) -> APTR {
+        unsafe { crate::amigaguide::AddAmigaGuideHostA(get_AmigaGuideBase(), h, name, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RemoveAmigaGuideHostA(hh: APTR, attrs: *const TagItem) -> LONG {
+        unsafe { crate::amigaguide::RemoveAmigaGuideHostA(get_AmigaGuideBase(), hh, attrs) }
+    }
+
+    /// libname: "amigaguide.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetAmigaGuideString(id: LONG) -> STRPTR {
+        unsafe { crate::amigaguide::GetAmigaGuideString(get_AmigaGuideBase(), id) }
+    }
+}
+
+#[cfg(feature = "arexx-class")]
+pub use arexx_class::*;
+
+#[cfg(feature = "arexx-class")]
+#[allow(unused_imports, dead_code)]
+mod arexx_class {
+    use super::*;
+
+    /// Global library base for "arexx.class"
+    pub static mut ARexxBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ARexxBase() -> *mut ::core::ffi::c_void {
+        unsafe { ARexxBase }
+    }
+
+    /// libname: "arexx.class" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AREXX_GetClass() -> *mut Class {
+        unsafe { crate::arexx_class::AREXX_GetClass(get_ARexxBase()) }
+    }
+}
+
+#[cfg(feature = "asl")]
+pub use asl::*;
+
+#[cfg(feature = "asl")]
+#[allow(unused_imports, dead_code)]
+mod asl {
+    use super::*;
+
+    /// Global library base for "asl.library"
+    pub static mut AslBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_AslBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut AslBase, c"asl.library")
+            } else {
+                AslBase
+            }
+        }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocFileRequest() -> *mut FileRequester {
+        unsafe { crate::asl::AllocFileRequest(get_AslBase()) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeFileRequest(fileReq: *mut FileRequester) {
+        unsafe { crate::asl::FreeFileRequest(get_AslBase(), fileReq) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RequestFile(fileReq: *mut FileRequester) -> BOOL {
+        unsafe { crate::asl::RequestFile(get_AslBase(), fileReq) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocAslRequest(reqType: ULONG, tagList: *const TagItem) -> APTR {
+        unsafe { crate::asl::AllocAslRequest(get_AslBase(), reqType, tagList) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeAslRequest(requester: APTR) {
+        unsafe { crate::asl::FreeAslRequest(get_AslBase(), requester) }
+    }
+
+    /// libname: "asl.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AslRequest(requester: APTR, tagList: *const TagItem) -> BOOL {
+        unsafe { crate::asl::AslRequest(get_AslBase(), requester, tagList) }
+    }
+
+    /// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn AbortAslRequest(requester: APTR) {
+        unsafe { crate::asl::AbortAslRequest(get_AslBase(), requester) }
+    }
+
+    /// libname: "asl.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn ActivateAslRequest(requester: APTR) {
+        unsafe { crate::asl::ActivateAslRequest(get_AslBase(), requester) }
+    }
+}
+
+#[cfg(feature = "battclock-resource")]
+pub use battclock_resource::*;
+
+#[cfg(feature = "battclock-resource")]
+#[allow(unused_imports, dead_code)]
+mod battclock_resource {
+    use super::*;
+
+    /// Global library base for "battclock.resource"
+    pub static mut BattClockBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_BattClockBase() -> *mut ::core::ffi::c_void {
+        unsafe { BattClockBase }
+    }
+
+    /// libname: "battclock.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ResetBattClock() {
+        unsafe { crate::battclock_resource::ResetBattClock(get_BattClockBase()) }
+    }
+
+    /// libname: "battclock.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadBattClock() -> ULONG {
+        unsafe { crate::battclock_resource::ReadBattClock(get_BattClockBase()) }
+    }
+
+    /// libname: "battclock.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WriteBattClock(time: ULONG) {
+        unsafe { crate::battclock_resource::WriteBattClock(get_BattClockBase(), time) }
+    }
+}
+
+#[cfg(feature = "battmem-resource")]
+pub use battmem_resource::*;
+
+#[cfg(feature = "battmem-resource")]
+#[allow(unused_imports, dead_code)]
+mod battmem_resource {
+    use super::*;
+
+    /// Global library base for "battmem.resource"
+    pub static mut BattMemBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_BattMemBase() -> *mut ::core::ffi::c_void {
+        unsafe { BattMemBase }
+    }
+
+    /// libname: "battmem.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ObtainBattSemaphore() {
+        unsafe { crate::battmem_resource::ObtainBattSemaphore(get_BattMemBase()) }
+    }
+
+    /// libname: "battmem.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReleaseBattSemaphore() {
+        unsafe { crate::battmem_resource::ReleaseBattSemaphore(get_BattMemBase()) }
+    }
+
+    /// libname: "battmem.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadBattMem(buffer: APTR, offset: ULONG, length: ULONG) -> ULONG {
+        unsafe { crate::battmem_resource::ReadBattMem(get_BattMemBase(), buffer, offset, length) }
+    }
+
+    /// libname: "battmem.resource" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WriteBattMem(buffer: CONST_APTR, offset: ULONG, length: ULONG) -> ULONG {
+        unsafe { crate::battmem_resource::WriteBattMem(get_BattMemBase(), buffer, offset, length) }
+    }
+}
+
+#[cfg(feature = "bevel-image")]
+pub use bevel_image::*;
+
+#[cfg(feature = "bevel-image")]
+#[allow(unused_imports, dead_code)]
+mod bevel_image {
+    use super::*;
+
+    /// Global library base for "bevel.image"
+    pub static mut BevelBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_BevelBase() -> *mut ::core::ffi::c_void {
+        unsafe { BevelBase }
+    }
+
+    /// libname: "bevel.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn BEVEL_GetClass() -> *mut Class {
+        unsafe { crate::bevel_image::BEVEL_GetClass(get_BevelBase()) }
+    }
+}
+
+#[cfg(feature = "bitmap-image")]
+pub use bitmap_image::*;
+
+#[cfg(feature = "bitmap-image")]
+#[allow(unused_imports, dead_code)]
+mod bitmap_image {
+    use super::*;
+
+    /// Global library base for "bitmap.image"
+    pub static mut BitMapBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_BitMapBase() -> *mut ::core::ffi::c_void {
+        unsafe { BitMapBase }
+    }
+
+    /// libname: "bitmap.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn BITMAP_GetClass() -> *mut Class {
+        unsafe { crate::bitmap_image::BITMAP_GetClass(get_BitMapBase()) }
+    }
+}
+
+#[cfg(feature = "bullet")]
+pub use bullet::*;
+
+#[cfg(feature = "bullet")]
+#[allow(unused_imports, dead_code)]
+mod bullet {
+    use super::*;
+
+    /// Global library base for "bullet.library"
+    pub static mut BulletBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_BulletBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut BulletBase, c"bullet.library")
+            } else {
+                BulletBase
+            }
+        }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn OpenEngine() -> *mut GlyphEngine {
+        unsafe { crate::bullet::OpenEngine(get_BulletBase()) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn CloseEngine(glyphEngine: *mut GlyphEngine) {
+        unsafe { crate::bullet::CloseEngine(get_BulletBase(), glyphEngine) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn SetInfoA(glyphEngine: *mut GlyphEngine, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::bullet::SetInfoA(get_BulletBase(), glyphEngine, tagList) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn ObtainInfoA(glyphEngine: *mut GlyphEngine, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::bullet::ObtainInfoA(get_BulletBase(), glyphEngine, tagList) }
+    }
+
+    /// libname: "bullet.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn ReleaseInfoA(glyphEngine: *mut GlyphEngine, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::bullet::ReleaseInfoA(get_BulletBase(), glyphEngine, tagList) }
+    }
+}
+
+#[cfg(feature = "button-gadget")]
+pub use button_gadget::*;
+
+#[cfg(feature = "button-gadget")]
+#[allow(unused_imports, dead_code)]
+mod button_gadget {
+    use super::*;
+
+    /// Global library base for "button.gadget"
+    pub static mut ButtonBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ButtonBase() -> *mut ::core::ffi::c_void {
+        unsafe { ButtonBase }
+    }
+
+    /// libname: "button.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn BUTTON_GetClass() -> *mut Class {
+        unsafe { crate::button_gadget::BUTTON_GetClass(get_ButtonBase()) }
+    }
+}
+
+#[cfg(feature = "card-resource")]
+pub use card_resource::*;
+
+#[cfg(feature = "card-resource")]
+#[allow(unused_imports, dead_code)]
+mod card_resource {
+    use super::*;
+
+    /// Global library base for "card.resource"
+    pub static mut CardResource: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_CardResource() -> *mut ::core::ffi::c_void {
+        unsafe { CardResource }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn OwnCard(handle: *mut CardHandle) -> *mut CardHandle {
+        unsafe { crate::card_resource::OwnCard(get_CardResource(), handle) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ReleaseCard(handle: *mut CardHandle, flags: ULONG) {
+        unsafe { crate::card_resource::ReleaseCard(get_CardResource(), handle, flags) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetCardMap() -> *mut CardMemoryMap {
+        unsafe { crate::card_resource::GetCardMap(get_CardResource()) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn BeginCardAccess(handle: *mut CardHandle) -> BOOL {
+        unsafe { crate::card_resource::BeginCardAccess(get_CardResource(), handle) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn EndCardAccess(handle: *mut CardHandle) -> BOOL {
+        unsafe { crate::card_resource::EndCardAccess(get_CardResource(), handle) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ReadCardStatus() -> UBYTE {
+        unsafe { crate::card_resource::ReadCardStatus(get_CardResource()) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardResetRemove(handle: *mut CardHandle, flag: ULONG) -> BOOL {
+        unsafe { crate::card_resource::CardResetRemove(get_CardResource(), handle, flag) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardMiscControl(handle: *mut CardHandle, control_bits: ULONG) -> UBYTE {
+        unsafe { crate::card_resource::CardMiscControl(get_CardResource(), handle, control_bits) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardAccessSpeed(handle: *mut CardHandle, nanoseconds: ULONG) -> ULONG {
+        unsafe { crate::card_resource::CardAccessSpeed(get_CardResource(), handle, nanoseconds) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardProgramVoltage(handle: *mut CardHandle, voltage: ULONG) -> LONG {
+        unsafe { crate::card_resource::CardProgramVoltage(get_CardResource(), handle, voltage) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardResetCard(handle: *mut CardHandle) -> BOOL {
+        unsafe { crate::card_resource::CardResetCard(get_CardResource(), handle) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CopyTuple(
+        handle: *mut CardHandle,
+        buffer: *mut UBYTE,
+        tuplecode: ULONG,
+        size: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::card_resource::CopyTuple(get_CardResource(), handle, buffer, tuplecode, size)
+        }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DeviceTuple(tuple_data: *const UBYTE, storage: *mut DeviceTData) -> ULONG {
+        unsafe { crate::card_resource::DeviceTuple(get_CardResource(), tuple_data, storage) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn IfAmigaXIP(handle: *mut CardHandle) -> *mut Resident {
+        unsafe { crate::card_resource::IfAmigaXIP(get_CardResource(), handle) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardForceChange() -> BOOL {
+        unsafe { crate::card_resource::CardForceChange(get_CardResource()) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardChangeCount() -> ULONG {
+        unsafe { crate::card_resource::CardChangeCount(get_CardResource()) }
+    }
+
+    /// libname: "card.resource" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CardInterface() -> ULONG {
+        unsafe { crate::card_resource::CardInterface(get_CardResource()) }
+    }
+}
+
+#[cfg(feature = "checkbox-gadget")]
+pub use checkbox_gadget::*;
+
+#[cfg(feature = "checkbox-gadget")]
+#[allow(unused_imports, dead_code)]
+mod checkbox_gadget {
+    use super::*;
+
+    /// Global library base for "checkbox.gadget"
+    pub static mut CheckBoxBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_CheckBoxBase() -> *mut ::core::ffi::c_void {
+        unsafe { CheckBoxBase }
+    }
+
+    /// libname: "checkbox.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CHECKBOX_GetClass() -> *mut Class {
+        unsafe { crate::checkbox_gadget::CHECKBOX_GetClass(get_CheckBoxBase()) }
+    }
+}
+
+#[cfg(feature = "chooser-gadget")]
+pub use chooser_gadget::*;
+
+#[cfg(feature = "chooser-gadget")]
+#[allow(unused_imports, dead_code)]
+mod chooser_gadget {
+    use super::*;
+
+    /// Global library base for "chooser.gadget"
+    pub static mut ChooserBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ChooserBase() -> *mut ::core::ffi::c_void {
+        unsafe { ChooserBase }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CHOOSER_GetClass() -> *mut Class {
+        unsafe { crate::chooser_gadget::CHOOSER_GetClass(get_ChooserBase()) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AllocChooserNodeA(tags: *mut TagItem) -> *mut Node {
+        unsafe { crate::chooser_gadget::AllocChooserNodeA(get_ChooserBase(), tags) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeChooserNode(node: *mut Node) {
+        unsafe { crate::chooser_gadget::FreeChooserNode(get_ChooserBase(), node) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetChooserNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::chooser_gadget::SetChooserNodeAttrsA(get_ChooserBase(), node, tags) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetChooserNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::chooser_gadget::GetChooserNodeAttrsA(get_ChooserBase(), node, tags) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ShowChooser(o: *mut Object, w: *mut Window, xpos: ULONG, ypos: ULONG) -> ULONG {
+        unsafe { crate::chooser_gadget::ShowChooser(get_ChooserBase(), o, w, xpos, ypos) }
+    }
+
+    /// libname: "chooser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn HideChooser(o: *mut Object, w: *mut Window) {
+        unsafe { crate::chooser_gadget::HideChooser(get_ChooserBase(), o, w) }
+    }
+}
+
+#[cfg(feature = "clicktab-gadget")]
+pub use clicktab_gadget::*;
+
+#[cfg(feature = "clicktab-gadget")]
+#[allow(unused_imports, dead_code)]
+mod clicktab_gadget {
+    use super::*;
+
+    /// Global library base for "clicktab.gadget"
+    pub static mut ClickTabBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ClickTabBase() -> *mut ::core::ffi::c_void {
+        unsafe { ClickTabBase }
+    }
+
+    /// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn CLICKTAB_GetClass() -> *mut Class {
+        unsafe { crate::clicktab_gadget::CLICKTAB_GetClass(get_ClickTabBase()) }
+    }
+
+    /// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AllocClickTabNodeA(tags: *mut TagItem) -> *mut Node {
+        unsafe { crate::clicktab_gadget::AllocClickTabNodeA(get_ClickTabBase(), tags) }
+    }
+
+    /// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeClickTabNode(node: *mut Node) {
+        unsafe { crate::clicktab_gadget::FreeClickTabNode(get_ClickTabBase(), node) }
+    }
+
+    /// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetClickTabNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::clicktab_gadget::SetClickTabNodeAttrsA(get_ClickTabBase(), node, tags) }
+    }
+
+    /// libname: "clicktab.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetClickTabNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::clicktab_gadget::GetClickTabNodeAttrsA(get_ClickTabBase(), node, tags) }
+    }
+}
+
+#[cfg(feature = "colorwheel-gadget")]
+pub use colorwheel_gadget::*;
+
+#[cfg(feature = "colorwheel-gadget")]
+#[allow(unused_imports, dead_code)]
+mod colorwheel_gadget {
+    use super::*;
+
+    /// Global library base for "colorwheel.gadget"
+    pub static mut ColorWheelBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ColorWheelBase() -> *mut ::core::ffi::c_void {
+        unsafe { ColorWheelBase }
+    }
+
+    /// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ConvertHSBToRGB(hsb: *const ColorWheelHSB, rgb: *mut ColorWheelRGB) {
+        unsafe { crate::colorwheel_gadget::ConvertHSBToRGB(get_ColorWheelBase(), hsb, rgb) }
+    }
+
+    /// libname: "colorwheel.gadget" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ConvertRGBToHSB(rgb: *const ColorWheelRGB, hsb: *mut ColorWheelHSB) {
+        unsafe { crate::colorwheel_gadget::ConvertRGBToHSB(get_ColorWheelBase(), rgb, hsb) }
+    }
+}
+
+#[cfg(feature = "commodities")]
+pub use commodities::*;
+
+#[cfg(feature = "commodities")]
+#[allow(unused_imports, dead_code)]
+mod commodities {
+    use super::*;
+
+    /// Global library base for "commodities.library"
+    pub static mut CxBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_CxBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut CxBase, c"commodities.library")
+            } else {
+                CxBase
+            }
+        }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateCxObj(type_: ULONG, arg1: LONG, arg2: LONG) -> *mut CxObj {
+        unsafe { crate::commodities::CreateCxObj(get_CxBase(), type_, arg1, arg2) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxBroker(nb: *const NewBroker, error: *mut LONG) -> *mut CxObj {
+        unsafe { crate::commodities::CxBroker(get_CxBase(), nb, error) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ActivateCxObj(co: *mut CxObj, flag: LONG) -> LONG {
+        unsafe { crate::commodities::ActivateCxObj(get_CxBase(), co, flag) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteCxObj(co: *mut CxObj) {
+        unsafe { crate::commodities::DeleteCxObj(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteCxObjAll(co: *mut CxObj) {
+        unsafe { crate::commodities::DeleteCxObjAll(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxObjType(co: *const CxObj) -> ULONG {
+        unsafe { crate::commodities::CxObjType(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxObjError(co: *const CxObj) -> LONG {
+        unsafe { crate::commodities::CxObjError(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ClearCxObjError(co: *mut CxObj) {
+        unsafe { crate::commodities::ClearCxObjError(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetCxObjPri(co: *mut CxObj, pri: LONG) -> LONG {
+        unsafe { crate::commodities::SetCxObjPri(get_CxBase(), co, pri) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AttachCxObj(headObj: *mut CxObj, co: *mut CxObj) {
+        unsafe { crate::commodities::AttachCxObj(get_CxBase(), headObj, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EnqueueCxObj(headObj: *mut CxObj, co: *mut CxObj) {
+        unsafe { crate::commodities::EnqueueCxObj(get_CxBase(), headObj, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InsertCxObj(headObj: *mut CxObj, co: *mut CxObj, pred: *mut CxObj) {
+        unsafe { crate::commodities::InsertCxObj(get_CxBase(), headObj, co, pred) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemoveCxObj(co: *mut CxObj) {
+        unsafe { crate::commodities::RemoveCxObj(get_CxBase(), co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetTranslate(translator: *mut CxObj, events: *mut InputEvent) {
+        unsafe { crate::commodities::SetTranslate(get_CxBase(), translator, events) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetFilter(filter: *mut CxObj, text: CONST_STRPTR) {
+        unsafe { crate::commodities::SetFilter(get_CxBase(), filter, text) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetFilterIX(filter: *mut CxObj, ix: *const IX) {
+        unsafe { crate::commodities::SetFilterIX(get_CxBase(), filter, ix) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ParseIX(description: CONST_STRPTR, ix: *mut IX) -> LONG {
+        unsafe { crate::commodities::ParseIX(get_CxBase(), description, ix) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxMsgType(cxm: *const CxMsg) -> ULONG {
+        unsafe { crate::commodities::CxMsgType(get_CxBase(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxMsgData(cxm: *const CxMsg) -> APTR {
+        unsafe { crate::commodities::CxMsgData(get_CxBase(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CxMsgID(cxm: *const CxMsg) -> LONG {
+        unsafe { crate::commodities::CxMsgID(get_CxBase(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DivertCxMsg(cxm: *mut CxMsg, headObj: *mut CxObj, returnObj: *mut CxObj) {
+        unsafe { crate::commodities::DivertCxMsg(get_CxBase(), cxm, headObj, returnObj) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RouteCxMsg(cxm: *mut CxMsg, co: *mut CxObj) {
+        unsafe { crate::commodities::RouteCxMsg(get_CxBase(), cxm, co) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DisposeCxMsg(cxm: *mut CxMsg) {
+        unsafe { crate::commodities::DisposeCxMsg(get_CxBase(), cxm) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InvertKeyMap(ansiCode: ULONG, event: *mut InputEvent, km: *const KeyMap) -> BOOL {
+        unsafe { crate::commodities::InvertKeyMap(get_CxBase(), ansiCode, event, km) }
+    }
+
+    /// libname: "commodities.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddIEvents(events: *mut InputEvent) {
+        unsafe { crate::commodities::AddIEvents(get_CxBase(), events) }
+    }
+
+    /// libname: "commodities.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn MatchIX(event: *const InputEvent, ix: *const IX) -> BOOL {
+        unsafe { crate::commodities::MatchIX(get_CxBase(), event, ix) }
+    }
+}
+
+#[cfg(feature = "console-device")]
+pub use console_device::*;
+
+#[cfg(feature = "console-device")]
+mod console_device {
+    use super::*;
+
+    /// Global library base for "console.device"
+    pub static mut ConsoleDevice: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ConsoleDevice() -> *mut ::core::ffi::c_void {
+        unsafe { ConsoleDevice }
+    }
+
+    /// libname: "console.device"
+    #[inline]
+    pub unsafe fn CDInputHandler(
+        events: *const InputEvent,
+        consoleDevice: *mut Library,
+    ) -> *mut InputEvent {
+        unsafe { crate::console_device::CDInputHandler(get_ConsoleDevice(), events, consoleDevice) }
+    }
+
+    /// libname: "console.device"
+    #[inline]
+    pub unsafe fn RawKeyConvert(
+        events: *const InputEvent,
+        buffer: STRPTR,
+        length: LONG,
+        keyMap: *const KeyMap,
+    ) -> LONG {
+        unsafe {
+            crate::console_device::RawKeyConvert(
+                get_ConsoleDevice(),
+                events,
+                buffer,
+                length,
+                keyMap,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "datatypes")]
+pub use datatypes::*;
+
+#[cfg(feature = "datatypes")]
+#[allow(unused_imports, dead_code)]
+mod datatypes {
+    use super::*;
+
+    /// Global library base for "datatypes.library"
+    pub static mut DataTypesBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DataTypesBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut DataTypesBase, c"datatypes.library")
+            } else {
+                DataTypesBase
+            }
+        }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ObtainDataTypeA(
+        type_: ULONG,
+        handle: APTR,
+        attrs: *const TagItem,
+    ) -> *mut DataType {
+        unsafe { crate::datatypes::ObtainDataTypeA(get_DataTypesBase(), type_, handle, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ReleaseDataType(dt: *mut DataType) {
+        unsafe { crate::datatypes::ReleaseDataType(get_DataTypesBase(), dt) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn NewDTObjectA(name: CONST_STRPTR, attrs: *const TagItem) -> *mut Object {
+        unsafe { crate::datatypes::NewDTObjectA(get_DataTypesBase(), name, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DisposeDTObject(o: *mut Object) {
+        unsafe { crate::datatypes::DisposeDTObject(get_DataTypesBase(), o) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetDTAttrsA(
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        attrs: *const TagItem,
+    ) -> ULONG {
+        unsafe { crate::datatypes::SetDTAttrsA(get_DataTypesBase(), o, win, req, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetDTAttrsA(o: *mut Object, attrs: *const TagItem) -> ULONG {
+        unsafe { crate::datatypes::GetDTAttrsA(get_DataTypesBase(), o, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AddDTObject(
+        win: *mut Window,
+        req: *mut Requester,
+        o: *mut Object,
+        pos: LONG,
+    ) -> LONG {
+        unsafe { crate::datatypes::AddDTObject(get_DataTypesBase(), win, req, o, pos) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RefreshDTObjectA(
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        attrs: *const TagItem,
+    ) {
+        unsafe { crate::datatypes::RefreshDTObjectA(get_DataTypesBase(), o, win, req, attrs) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DoAsyncLayout(o: *mut Object, gpl: *mut gpLayout) -> ULONG {
+        unsafe { crate::datatypes::DoAsyncLayout(get_DataTypesBase(), o, gpl) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DoDTMethodA(
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        msg: Msg,
+    ) -> ULONG {
+        unsafe { crate::datatypes::DoDTMethodA(get_DataTypesBase(), o, win, req, msg) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RemoveDTObject(win: *mut Window, o: *mut Object) -> LONG {
+        unsafe { crate::datatypes::RemoveDTObject(get_DataTypesBase(), win, o) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetDTMethods(object: *const Object) -> *mut ULONG {
+        unsafe { crate::datatypes::GetDTMethods(get_DataTypesBase(), object) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetDTTriggerMethods(object: *mut Object) -> *mut DTMethod {
+        unsafe { crate::datatypes::GetDTTriggerMethods(get_DataTypesBase(), object) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn PrintDTObjectA(
+        o: *mut Object,
+        w: *mut Window,
+        r: *mut Requester,
+        msg: *mut dtPrint,
+    ) -> ULONG {
+        unsafe { crate::datatypes::PrintDTObjectA(get_DataTypesBase(), o, w, r, msg) }
+    }
+
+    /// libname: "datatypes.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetDTString(id: ULONG) -> STRPTR {
+        unsafe { crate::datatypes::GetDTString(get_DataTypesBase(), id) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn FindMethod(methods: *const ULONG, searchmethodid: ULONG) -> *mut ULONG {
+        unsafe { crate::datatypes::FindMethod(get_DataTypesBase(), methods, searchmethodid) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn FindTriggerMethod(
+        dtm: *const DTMethod,
+        command: CONST_STRPTR,
+        method: ULONG,
+    ) -> *mut DTMethod {
+        unsafe { crate::datatypes::FindTriggerMethod(get_DataTypesBase(), dtm, command, method) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn CopyDTMethods(
+        methods: *const ULONG,
+        include: *const ULONG,
+        exclude: *const ULONG,
+    ) -> *mut ULONG {
+        unsafe { crate::datatypes::CopyDTMethods(get_DataTypesBase(), methods, include, exclude) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn CopyDTTriggerMethods(
+        methods: *const DTMethod,
+        include: *const DTMethod,
+        exclude: *const DTMethod,
+    ) -> *mut DTMethod {
+        unsafe {
+            crate::datatypes::CopyDTTriggerMethods(get_DataTypesBase(), methods, include, exclude)
+        }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn FreeDTMethods(methods: APTR) -> *mut ::core::ffi::c_void {
+        unsafe { crate::datatypes::FreeDTMethods(get_DataTypesBase(), methods) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn GetDTTriggerMethodDataFlags(triggermethod: ULONG) -> ULONG {
+        unsafe { crate::datatypes::GetDTTriggerMethodDataFlags(get_DataTypesBase(), triggermethod) }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SaveDTObjectA(
+        o: *mut Object,
+        win: *mut Window,
+        req: *mut Requester,
+        file: CONST_STRPTR,
+        mode: ULONG,
+        saveicon: LONG,
+        attrs: *mut TagItem,
+    ) -> ULONG {
+        unsafe {
+            crate::datatypes::SaveDTObjectA(
+                get_DataTypesBase(),
+                o,
+                win,
+                req,
+                file,
+                mode,
+                saveicon,
+                attrs,
+            )
+        }
+    }
+
+    /// libname: "datatypes.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn StartDragSelect(o: *mut Object) -> ULONG {
+        unsafe { crate::datatypes::StartDragSelect(get_DataTypesBase(), o) }
+    }
+}
+
+#[cfg(feature = "datebrowser-gadget")]
+pub use datebrowser_gadget::*;
+
+#[cfg(feature = "datebrowser-gadget")]
+#[allow(unused_imports, dead_code)]
+mod datebrowser_gadget {
+    use super::*;
+
+    /// Global library base for "datebrowser.gadget"
+    pub static mut DateBrowserBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DateBrowserBase() -> *mut ::core::ffi::c_void {
+        unsafe { DateBrowserBase }
+    }
+
+    /// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DATEBROWSER_GetClass() -> *mut Class {
+        unsafe { crate::datebrowser_gadget::DATEBROWSER_GetClass(get_DateBrowserBase()) }
+    }
+
+    /// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn JulianWeekDay(day: ULONG, month: ULONG, year: LONG) -> UWORD {
+        unsafe { crate::datebrowser_gadget::JulianWeekDay(get_DateBrowserBase(), day, month, year) }
+    }
+
+    /// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn JulianMonthDays(month: ULONG, year: LONG) -> UWORD {
+        unsafe { crate::datebrowser_gadget::JulianMonthDays(get_DateBrowserBase(), month, year) }
+    }
+
+    /// libname: "datebrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn JulianLeapYear(year: LONG) -> BOOL {
+        unsafe { crate::datebrowser_gadget::JulianLeapYear(get_DateBrowserBase(), year) }
+    }
+}
+
+#[cfg(feature = "disk-resource")]
+pub use disk_resource::*;
+
+#[cfg(feature = "disk-resource")]
+mod disk_resource {
+    use super::*;
+
+    /// Global library base for "disk.resource"
+    pub static mut DiskBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DiskBase() -> *mut ::core::ffi::c_void {
+        unsafe { DiskBase }
+    }
+
+    /// libname: "disk.resource"
+    #[inline]
+    pub unsafe fn AllocUnit(unitNum: LONG) -> BOOL {
+        unsafe { crate::disk_resource::AllocUnit(get_DiskBase(), unitNum) }
+    }
+
+    /// libname: "disk.resource"
+    #[inline]
+    pub unsafe fn FreeUnit(unitNum: LONG) {
+        unsafe { crate::disk_resource::FreeUnit(get_DiskBase(), unitNum) }
+    }
+
+    /// libname: "disk.resource"
+    #[inline]
+    pub unsafe fn GetUnit(unitPointer: *mut DiscResourceUnit) -> *mut DiscResourceUnit {
+        unsafe { crate::disk_resource::GetUnit(get_DiskBase(), unitPointer) }
+    }
+
+    /// libname: "disk.resource"
+    #[inline]
+    pub unsafe fn GiveUnit() {
+        unsafe { crate::disk_resource::GiveUnit(get_DiskBase()) }
+    }
+
+    /// libname: "disk.resource"
+    #[inline]
+    pub unsafe fn GetUnitID(unitNum: LONG) -> LONG {
+        unsafe { crate::disk_resource::GetUnitID(get_DiskBase(), unitNum) }
+    }
+
+    /// libname: "disk.resource" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn ReadUnitID(unitNum: LONG) -> LONG {
+        unsafe { crate::disk_resource::ReadUnitID(get_DiskBase(), unitNum) }
+    }
+}
+
+#[cfg(feature = "diskfont")]
+pub use diskfont::*;
+
+#[cfg(feature = "diskfont")]
+mod diskfont {
+    use super::*;
+
+    /// Global library base for "diskfont.library"
+    pub static mut DiskfontBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DiskfontBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut DiskfontBase, c"diskfont.library")
+            } else {
+                DiskfontBase
+            }
+        }
+    }
+
+    /// libname: "diskfont.library"
+    #[inline]
+    pub unsafe fn OpenDiskFont(textAttr: *mut TextAttr) -> *mut TextFont {
+        unsafe { crate::diskfont::OpenDiskFont(get_DiskfontBase(), textAttr) }
+    }
+
+    /// libname: "diskfont.library"
+    #[inline]
+    pub unsafe fn AvailFonts(buffer: *mut AvailFontsHeader, bufBytes: LONG, flags: ULONG) -> LONG {
+        unsafe { crate::diskfont::AvailFonts(get_DiskfontBase(), buffer, bufBytes, flags) }
+    }
+
+    /// libname: "diskfont.library" (V34) (Kickstart 1.3)
+    #[cfg(not(feature = "kickstart-v33"))]
+    #[inline]
+    pub unsafe fn NewFontContents(
+        fontsLock: BPTR,
+        fontName: CONST_STRPTR,
+    ) -> *mut FontContentsHeader {
+        unsafe { crate::diskfont::NewFontContents(get_DiskfontBase(), fontsLock, fontName) }
+    }
+
+    /// libname: "diskfont.library" (V34) (Kickstart 1.3)
+    #[cfg(not(feature = "kickstart-v33"))]
+    #[inline]
+    pub unsafe fn DisposeFontContents(fontContentsHeader: *mut FontContentsHeader) {
+        unsafe { crate::diskfont::DisposeFontContents(get_DiskfontBase(), fontContentsHeader) }
+    }
+
+    /// libname: "diskfont.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NewScaledDiskFont(
+        sourceFont: *mut TextFont,
+        destTextAttr: *mut TextAttr,
+    ) -> *mut DiskFontHeader {
+        unsafe { crate::diskfont::NewScaledDiskFont(get_DiskfontBase(), sourceFont, destTextAttr) }
+    }
+
+    /// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn GetDiskFontCtrl(tagid: LONG) -> LONG {
+        unsafe { crate::diskfont::GetDiskFontCtrl(get_DiskfontBase(), tagid) }
+    }
+
+    /// libname: "diskfont.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn SetDiskFontCtrlA(taglist: *const TagItem) {
+        unsafe { crate::diskfont::SetDiskFontCtrlA(get_DiskfontBase(), taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn EOpenEngine(eEngine: *mut EGlyphEngine) -> LONG {
+        unsafe { crate::diskfont::EOpenEngine(get_DiskfontBase(), eEngine) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ECloseEngine(eEngine: *mut EGlyphEngine) {
+        unsafe { crate::diskfont::ECloseEngine(get_DiskfontBase(), eEngine) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ESetInfoA(eEngine: *mut EGlyphEngine, taglist: *const TagItem) -> ULONG {
+        unsafe { crate::diskfont::ESetInfoA(get_DiskfontBase(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn EObtainInfoA(eEngine: *mut EGlyphEngine, taglist: *const TagItem) -> ULONG {
+        unsafe { crate::diskfont::EObtainInfoA(get_DiskfontBase(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn EReleaseInfoA(eEngine: *mut EGlyphEngine, taglist: *const TagItem) -> ULONG {
+        unsafe { crate::diskfont::EReleaseInfoA(get_DiskfontBase(), eEngine, taglist) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn OpenOutlineFont(
+        name: CONST_STRPTR,
+        list: *mut List,
+        flags: ULONG,
+    ) -> *mut OutlineFont {
+        unsafe { crate::diskfont::OpenOutlineFont(get_DiskfontBase(), name, list, flags) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn CloseOutlineFont(olf: *mut OutlineFont, list: *mut List) {
+        unsafe { crate::diskfont::CloseOutlineFont(get_DiskfontBase(), olf, list) }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn WriteFontContents(
+        fontsLock: BPTR,
+        fontName: CONST_STRPTR,
+        fontContentsHeader: *const FontContentsHeader,
+    ) -> LONG {
+        unsafe {
+            crate::diskfont::WriteFontContents(
+                get_DiskfontBase(),
+                fontsLock,
+                fontName,
+                fontContentsHeader,
+            )
+        }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn WriteDiskFontHeaderA(
+        font: *const TextFont,
+        fileName: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> LONG {
+        unsafe {
+            crate::diskfont::WriteDiskFontHeaderA(get_DiskfontBase(), font, fileName, tagList)
+        }
+    }
+
+    /// libname: "diskfont.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ObtainCharsetInfo(knownTag: ULONG, knownValue: ULONG, wantedTag: ULONG) -> ULONG {
+        unsafe {
+            crate::diskfont::ObtainCharsetInfo(get_DiskfontBase(), knownTag, knownValue, wantedTag)
+        }
+    }
+}
+
+#[cfg(feature = "dos")]
+pub use dos::*;
+
+#[cfg(feature = "dos")]
+mod dos {
+    use super::*;
+
+    /// Global library base for "dos.library"
+    pub static mut DOSBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DOSBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut DOSBase, c"dos.library")
+            } else {
+                DOSBase
+            }
+        }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Open(name: CONST_STRPTR, accessMode: LONG) -> BPTR {
+        unsafe { crate::dos::Open(get_DOSBase(), name, accessMode) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Close(file: BPTR) -> LONG {
+        unsafe { crate::dos::Close(get_DOSBase(), file) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Read(file: BPTR, buffer: APTR, length: LONG) -> LONG {
+        unsafe { crate::dos::Read(get_DOSBase(), file, buffer, length) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Write(file: BPTR, buffer: CONST_APTR, length: LONG) -> LONG {
+        unsafe { crate::dos::Write(get_DOSBase(), file, buffer, length) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Input() -> BPTR {
+        unsafe { crate::dos::Input(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Output() -> BPTR {
+        unsafe { crate::dos::Output(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Seek(file: BPTR, position: LONG, offset: LONG) -> LONG {
+        unsafe { crate::dos::Seek(get_DOSBase(), file, position, offset) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn DeleteFile(name: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::DeleteFile(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Rename(oldName: CONST_STRPTR, newName: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::Rename(get_DOSBase(), oldName, newName) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Lock(name: CONST_STRPTR, type_: LONG) -> BPTR {
+        unsafe { crate::dos::Lock(get_DOSBase(), name, type_) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn UnLock(lock: BPTR) {
+        unsafe { crate::dos::UnLock(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn DupLock(lock: BPTR) -> BPTR {
+        unsafe { crate::dos::DupLock(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Examine(lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
+        unsafe { crate::dos::Examine(get_DOSBase(), lock, fileInfoBlock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn ExNext(lock: BPTR, fileInfoBlock: *mut FileInfoBlock) -> LONG {
+        unsafe { crate::dos::ExNext(get_DOSBase(), lock, fileInfoBlock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Info(lock: BPTR, parameterBlock: *mut InfoData) -> LONG {
+        unsafe { crate::dos::Info(get_DOSBase(), lock, parameterBlock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn CreateDir(name: CONST_STRPTR) -> BPTR {
+        unsafe { crate::dos::CreateDir(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn CurrentDir(lock: BPTR) -> BPTR {
+        unsafe { crate::dos::CurrentDir(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn IoErr() -> LONG {
+        unsafe { crate::dos::IoErr(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn CreateProc(
+        name: CONST_STRPTR,
+        pri: LONG,
+        segList: BPTR,
+        stackSize: LONG,
+    ) -> *mut MsgPort {
+        unsafe { crate::dos::CreateProc(get_DOSBase(), name, pri, segList, stackSize) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Exit(returnCode: LONG) {
+        unsafe { crate::dos::Exit(get_DOSBase(), returnCode) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn LoadSeg(name: CONST_STRPTR) -> BPTR {
+        unsafe { crate::dos::LoadSeg(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn UnLoadSeg(seglist: BPTR) {
+        unsafe { crate::dos::UnLoadSeg(get_DOSBase(), seglist) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn DeviceProc(name: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { crate::dos::DeviceProc(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn SetComment(name: CONST_STRPTR, comment: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::SetComment(get_DOSBase(), name, comment) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn SetProtection(name: CONST_STRPTR, protect: LONG) -> LONG {
+        unsafe { crate::dos::SetProtection(get_DOSBase(), name, protect) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn DateStamp(date: *mut DateStamp) -> *mut DateStamp {
+        unsafe { crate::dos::DateStamp(get_DOSBase(), date) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Delay(timeout: LONG) {
+        unsafe { crate::dos::Delay(get_DOSBase(), timeout) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn WaitForChar(file: BPTR, timeout: LONG) -> LONG {
+        unsafe { crate::dos::WaitForChar(get_DOSBase(), file, timeout) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn ParentDir(lock: BPTR) -> BPTR {
+        unsafe { crate::dos::ParentDir(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn IsInteractive(file: BPTR) -> LONG {
+        unsafe { crate::dos::IsInteractive(get_DOSBase(), file) }
+    }
+
+    /// libname: "dos.library"
+    #[inline]
+    pub unsafe fn Execute(string: CONST_STRPTR, file: BPTR, file2: BPTR) -> LONG {
+        unsafe { crate::dos::Execute(get_DOSBase(), string, file, file2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocDosObject(type_: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { crate::dos::AllocDosObject(get_DOSBase(), type_, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocDosObjectTagList(type_: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { crate::dos::AllocDosObjectTagList(get_DOSBase(), type_, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeDosObject(type_: ULONG, ptr: APTR) {
+        unsafe { crate::dos::FreeDosObject(get_DOSBase(), type_, ptr) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt(
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+        arg4: LONG,
+        arg5: LONG,
+    ) -> LONG {
+        unsafe { crate::dos::DoPkt(get_DOSBase(), port, action, arg1, arg2, arg3, arg4, arg5) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt0(port: *mut MsgPort, action: LONG) -> LONG {
+        unsafe { crate::dos::DoPkt0(get_DOSBase(), port, action) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt1(port: *mut MsgPort, action: LONG, arg1: LONG) -> LONG {
+        unsafe { crate::dos::DoPkt1(get_DOSBase(), port, action, arg1) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt2(port: *mut MsgPort, action: LONG, arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { crate::dos::DoPkt2(get_DOSBase(), port, action, arg1, arg2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt3(
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+    ) -> LONG {
+        unsafe { crate::dos::DoPkt3(get_DOSBase(), port, action, arg1, arg2, arg3) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DoPkt4(
+        port: *mut MsgPort,
+        action: LONG,
+        arg1: LONG,
+        arg2: LONG,
+        arg3: LONG,
+        arg4: LONG,
+    ) -> LONG {
+        unsafe { crate::dos::DoPkt4(get_DOSBase(), port, action, arg1, arg2, arg3, arg4) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SendPkt(dp: *mut DosPacket, port: *mut MsgPort, replyport: *mut MsgPort) {
+        unsafe { crate::dos::SendPkt(get_DOSBase(), dp, port, replyport) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WaitPkt() -> *mut DosPacket {
+        unsafe { crate::dos::WaitPkt(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReplyPkt(dp: *mut DosPacket, res1: LONG, res2: LONG) {
+        unsafe { crate::dos::ReplyPkt(get_DOSBase(), dp, res1, res2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AbortPkt(port: *mut MsgPort, pkt: *mut DosPacket) {
+        unsafe { crate::dos::AbortPkt(get_DOSBase(), port, pkt) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockRecord(
+        fh: BPTR,
+        offset: ULONG,
+        length: ULONG,
+        mode: ULONG,
+        timeout: ULONG,
+    ) -> BOOL {
+        unsafe { crate::dos::LockRecord(get_DOSBase(), fh, offset, length, mode, timeout) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockRecords(recArray: *const RecordLock, timeout: ULONG) -> BOOL {
+        unsafe { crate::dos::LockRecords(get_DOSBase(), recArray, timeout) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnLockRecord(fh: BPTR, offset: ULONG, length: ULONG) -> BOOL {
+        unsafe { crate::dos::UnLockRecord(get_DOSBase(), fh, offset, length) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnLockRecords(recArray: *const RecordLock) -> BOOL {
+        unsafe { crate::dos::UnLockRecords(get_DOSBase(), recArray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SelectInput(fh: BPTR) -> BPTR {
+        unsafe { crate::dos::SelectInput(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SelectOutput(fh: BPTR) -> BPTR {
+        unsafe { crate::dos::SelectOutput(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FGetC(fh: BPTR) -> LONG {
+        unsafe { crate::dos::FGetC(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FPutC(fh: BPTR, ch: LONG) -> LONG {
+        unsafe { crate::dos::FPutC(get_DOSBase(), fh, ch) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnGetC(fh: BPTR, character: LONG) -> LONG {
+        unsafe { crate::dos::UnGetC(get_DOSBase(), fh, character) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FRead(fh: BPTR, block: APTR, blocklen: ULONG, number: ULONG) -> LONG {
+        unsafe { crate::dos::FRead(get_DOSBase(), fh, block, blocklen, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FWrite(fh: BPTR, block: CONST_APTR, blocklen: ULONG, number: ULONG) -> LONG {
+        unsafe { crate::dos::FWrite(get_DOSBase(), fh, block, blocklen, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FGets(fh: BPTR, buf: STRPTR, buflen: ULONG) -> STRPTR {
+        unsafe { crate::dos::FGets(get_DOSBase(), fh, buf, buflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FPuts(fh: BPTR, str_: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::FPuts(get_DOSBase(), fh, str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn VFWritef(fh: BPTR, format: CONST_STRPTR, argarray: *const LONG) {
+        unsafe { crate::dos::VFWritef(get_DOSBase(), fh, format, argarray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn VFPrintf(fh: BPTR, format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
+        unsafe { crate::dos::VFPrintf(get_DOSBase(), fh, format, argarray) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Flush(fh: BPTR) -> LONG {
+        unsafe { crate::dos::Flush(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetVBuf(fh: BPTR, buff: STRPTR, type_: LONG, size: LONG) -> LONG {
+        unsafe { crate::dos::SetVBuf(get_DOSBase(), fh, buff, type_, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DupLockFromFH(fh: BPTR) -> BPTR {
+        unsafe { crate::dos::DupLockFromFH(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenFromLock(lock: BPTR) -> BPTR {
+        unsafe { crate::dos::OpenFromLock(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ParentOfFH(fh: BPTR) -> BPTR {
+        unsafe { crate::dos::ParentOfFH(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ExamineFH(fh: BPTR, fib: *mut FileInfoBlock) -> BOOL {
+        unsafe { crate::dos::ExamineFH(get_DOSBase(), fh, fib) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetFileDate(name: CONST_STRPTR, date: *const DateStamp) -> LONG {
+        unsafe { crate::dos::SetFileDate(get_DOSBase(), name, date) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NameFromLock(lock: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+        unsafe { crate::dos::NameFromLock(get_DOSBase(), lock, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NameFromFH(fh: BPTR, buffer: STRPTR, len: LONG) -> LONG {
+        unsafe { crate::dos::NameFromFH(get_DOSBase(), fh, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SplitName(
+        name: CONST_STRPTR,
+        separator: ULONG,
+        buf: STRPTR,
+        oldpos: LONG,
+        size: LONG,
+    ) -> WORD {
+        unsafe { crate::dos::SplitName(get_DOSBase(), name, separator, buf, oldpos, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SameLock(lock1: BPTR, lock2: BPTR) -> LONG {
+        unsafe { crate::dos::SameLock(get_DOSBase(), lock1, lock2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetMode(fh: BPTR, mode: LONG) -> LONG {
+        unsafe { crate::dos::SetMode(get_DOSBase(), fh, mode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ExAll(
+        lock: BPTR,
+        buffer: *mut ExAllData,
+        size: LONG,
+        data: LONG,
+        control: *mut ExAllControl,
+    ) -> LONG {
+        unsafe { crate::dos::ExAll(get_DOSBase(), lock, buffer, size, data, control) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadLink(
+        port: *mut MsgPort,
+        lock: BPTR,
+        path: CONST_STRPTR,
+        buffer: STRPTR,
+        size: ULONG,
+    ) -> LONG {
+        unsafe { crate::dos::ReadLink(get_DOSBase(), port, lock, path, buffer, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MakeLink(name: CONST_STRPTR, dest: LONG, soft: LONG) -> LONG {
+        unsafe { crate::dos::MakeLink(get_DOSBase(), name, dest, soft) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ChangeMode(type_: LONG, fh: BPTR, newmode: LONG) -> LONG {
+        unsafe { crate::dos::ChangeMode(get_DOSBase(), type_, fh, newmode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetFileSize(fh: BPTR, pos: LONG, mode: LONG) -> LONG {
+        unsafe { crate::dos::SetFileSize(get_DOSBase(), fh, pos, mode) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetIoErr(result: LONG) -> LONG {
+        unsafe { crate::dos::SetIoErr(get_DOSBase(), result) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Fault(code: LONG, header: CONST_STRPTR, buffer: STRPTR, len: LONG) -> BOOL {
+        unsafe { crate::dos::Fault(get_DOSBase(), code, header, buffer, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PrintFault(code: LONG, header: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::PrintFault(get_DOSBase(), code, header) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ErrorReport(code: LONG, type_: LONG, arg1: ULONG, device: *mut MsgPort) -> LONG {
+        unsafe { crate::dos::ErrorReport(get_DOSBase(), code, type_, arg1, device) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Cli() -> *mut CommandLineInterface {
+        unsafe { crate::dos::Cli(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateNewProc(tags: *const TagItem) -> *mut Process {
+        unsafe { crate::dos::CreateNewProc(get_DOSBase(), tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateNewProcTagList(tags: *const TagItem) -> *mut Process {
+        unsafe { crate::dos::CreateNewProcTagList(get_DOSBase(), tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RunCommand(
+        seg: BPTR,
+        stack: LONG,
+        paramptr: CONST_STRPTR,
+        paramlen: LONG,
+    ) -> LONG {
+        unsafe { crate::dos::RunCommand(get_DOSBase(), seg, stack, paramptr, paramlen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetConsoleTask() -> *mut MsgPort {
+        unsafe { crate::dos::GetConsoleTask(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetConsoleTask(task: *mut MsgPort) -> *mut MsgPort {
+        unsafe { crate::dos::SetConsoleTask(get_DOSBase(), task) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetFileSysTask() -> *mut MsgPort {
+        unsafe { crate::dos::GetFileSysTask(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetFileSysTask(task: *mut MsgPort) -> *mut MsgPort {
+        unsafe { crate::dos::SetFileSysTask(get_DOSBase(), task) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetArgStr() -> STRPTR {
+        unsafe { crate::dos::GetArgStr(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetArgStr(string: STRPTR) -> STRPTR {
+        unsafe { crate::dos::SetArgStr(get_DOSBase(), string) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindCliProc(num: ULONG) -> *mut Process {
+        unsafe { crate::dos::FindCliProc(get_DOSBase(), num) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MaxCli() -> ULONG {
+        unsafe { crate::dos::MaxCli(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetCurrentDirName(name: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::SetCurrentDirName(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetCurrentDirName(buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { crate::dos::GetCurrentDirName(get_DOSBase(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetProgramName(name: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::SetProgramName(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetProgramName(buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { crate::dos::GetProgramName(get_DOSBase(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetPrompt(name: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::SetPrompt(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetPrompt(buf: STRPTR, len: LONG) -> BOOL {
+        unsafe { crate::dos::GetPrompt(get_DOSBase(), buf, len) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetProgramDir(lock: BPTR) -> BPTR {
+        unsafe { crate::dos::SetProgramDir(get_DOSBase(), lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetProgramDir() -> BPTR {
+        unsafe { crate::dos::GetProgramDir(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SystemTagList(command: CONST_STRPTR, tags: *const TagItem) -> LONG {
+        unsafe { crate::dos::SystemTagList(get_DOSBase(), command, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn System(command: CONST_STRPTR, tags: *const TagItem) -> LONG {
+        unsafe { crate::dos::System(get_DOSBase(), command, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AssignLock(name: CONST_STRPTR, lock: BPTR) -> LONG {
+        unsafe { crate::dos::AssignLock(get_DOSBase(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AssignLate(name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::AssignLate(get_DOSBase(), name, path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AssignPath(name: CONST_STRPTR, path: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::AssignPath(get_DOSBase(), name, path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AssignAdd(name: CONST_STRPTR, lock: BPTR) -> BOOL {
+        unsafe { crate::dos::AssignAdd(get_DOSBase(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemAssignList(name: CONST_STRPTR, lock: BPTR) -> LONG {
+        unsafe { crate::dos::RemAssignList(get_DOSBase(), name, lock) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetDeviceProc(name: CONST_STRPTR, dp: *mut DevProc) -> *mut DevProc {
+        unsafe { crate::dos::GetDeviceProc(get_DOSBase(), name, dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeDeviceProc(dp: *mut DevProc) {
+        unsafe { crate::dos::FreeDeviceProc(get_DOSBase(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockDosList(flags: ULONG) -> *mut DosList {
+        unsafe { crate::dos::LockDosList(get_DOSBase(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnLockDosList(flags: ULONG) {
+        unsafe { crate::dos::UnLockDosList(get_DOSBase(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AttemptLockDosList(flags: ULONG) -> *mut DosList {
+        unsafe { crate::dos::AttemptLockDosList(get_DOSBase(), flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemDosEntry(dlist: *mut DosList) -> BOOL {
+        unsafe { crate::dos::RemDosEntry(get_DOSBase(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddDosEntry(dlist: *mut DosList) -> LONG {
+        unsafe { crate::dos::AddDosEntry(get_DOSBase(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindDosEntry(
+        dlist: *const DosList,
+        name: CONST_STRPTR,
+        flags: ULONG,
+    ) -> *mut DosList {
+        unsafe { crate::dos::FindDosEntry(get_DOSBase(), dlist, name, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NextDosEntry(dlist: *const DosList, flags: ULONG) -> *mut DosList {
+        unsafe { crate::dos::NextDosEntry(get_DOSBase(), dlist, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MakeDosEntry(name: CONST_STRPTR, type_: LONG) -> *mut DosList {
+        unsafe { crate::dos::MakeDosEntry(get_DOSBase(), name, type_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeDosEntry(dlist: *mut DosList) {
+        unsafe { crate::dos::FreeDosEntry(get_DOSBase(), dlist) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IsFileSystem(name: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::IsFileSystem(get_DOSBase(), name) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Format(
+        filesystem: CONST_STRPTR,
+        volumename: CONST_STRPTR,
+        dostype: ULONG,
+    ) -> BOOL {
+        unsafe { crate::dos::Format(get_DOSBase(), filesystem, volumename, dostype) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Relabel(drive: CONST_STRPTR, newname: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::Relabel(get_DOSBase(), drive, newname) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Inhibit(name: CONST_STRPTR, onoff: LONG) -> LONG {
+        unsafe { crate::dos::Inhibit(get_DOSBase(), name, onoff) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddBuffers(name: CONST_STRPTR, number: LONG) -> LONG {
+        unsafe { crate::dos::AddBuffers(get_DOSBase(), name, number) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CompareDates(date1: *const DateStamp, date2: *const DateStamp) -> LONG {
+        unsafe { crate::dos::CompareDates(get_DOSBase(), date1, date2) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DateToStr(datetime: *mut DateTime) -> LONG {
+        unsafe { crate::dos::DateToStr(get_DOSBase(), datetime) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StrToDate(datetime: *mut DateTime) -> LONG {
+        unsafe { crate::dos::StrToDate(get_DOSBase(), datetime) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InternalLoadSeg(
+        fh: BPTR,
+        table: BPTR,
+        funcarray: *const LONG,
+        stack: *mut LONG,
+    ) -> BPTR {
+        unsafe { crate::dos::InternalLoadSeg(get_DOSBase(), fh, table, funcarray, stack) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InternalUnLoadSeg(seglist: BPTR, freefunc: FPTR) -> BOOL {
+        unsafe { crate::dos::InternalUnLoadSeg(get_DOSBase(), seglist, freefunc) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NewLoadSeg(file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
+        unsafe { crate::dos::NewLoadSeg(get_DOSBase(), file, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NewLoadSegTagList(file: CONST_STRPTR, tags: *const TagItem) -> BPTR {
+        unsafe { crate::dos::NewLoadSegTagList(get_DOSBase(), file, tags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddSegment(name: CONST_STRPTR, seg: BPTR, system: LONG) -> LONG {
+        unsafe { crate::dos::AddSegment(get_DOSBase(), name, seg, system) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindSegment(
+        name: CONST_STRPTR,
+        seg: *const Segment,
+        system: LONG,
+    ) -> *mut Segment {
+        unsafe { crate::dos::FindSegment(get_DOSBase(), name, seg, system) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemSegment(seg: *mut Segment) -> LONG {
+        unsafe { crate::dos::RemSegment(get_DOSBase(), seg) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CheckSignal(mask: LONG) -> LONG {
+        unsafe { crate::dos::CheckSignal(get_DOSBase(), mask) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadArgs(
+        arg_template: CONST_STRPTR,
+        array: *mut LONG,
+        args: *mut RDArgs,
+    ) -> *mut RDArgs {
+        unsafe { crate::dos::ReadArgs(get_DOSBase(), arg_template, array, args) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindArg(keyword: CONST_STRPTR, arg_template: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::FindArg(get_DOSBase(), keyword, arg_template) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadItem(name: CONST_STRPTR, maxchars: LONG, cSource: *mut CSource) -> LONG {
+        unsafe { crate::dos::ReadItem(get_DOSBase(), name, maxchars, cSource) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StrToLong(string: CONST_STRPTR, value: *mut LONG) -> LONG {
+        unsafe { crate::dos::StrToLong(get_DOSBase(), string, value) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MatchFirst(pat: CONST_STRPTR, anchor: *mut AnchorPath) -> LONG {
+        unsafe { crate::dos::MatchFirst(get_DOSBase(), pat, anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MatchNext(anchor: *mut AnchorPath) -> LONG {
+        unsafe { crate::dos::MatchNext(get_DOSBase(), anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MatchEnd(anchor: *mut AnchorPath) {
+        unsafe { crate::dos::MatchEnd(get_DOSBase(), anchor) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ParsePattern(pat: CONST_STRPTR, patbuf: *mut UBYTE, patbuflen: LONG) -> LONG {
+        unsafe { crate::dos::ParsePattern(get_DOSBase(), pat, patbuf, patbuflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MatchPattern(patbuf: *const UBYTE, str_: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::MatchPattern(get_DOSBase(), patbuf, str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeArgs(args: *mut RDArgs) {
+        unsafe { crate::dos::FreeArgs(get_DOSBase(), args) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FilePart(path: CONST_STRPTR) -> STRPTR {
+        unsafe { crate::dos::FilePart(get_DOSBase(), path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PathPart(path: CONST_STRPTR) -> STRPTR {
+        unsafe { crate::dos::PathPart(get_DOSBase(), path) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddPart(dirname: STRPTR, filename: CONST_STRPTR, size: ULONG) -> BOOL {
+        unsafe { crate::dos::AddPart(get_DOSBase(), dirname, filename, size) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StartNotify(notify: *mut NotifyRequest) -> BOOL {
+        unsafe { crate::dos::StartNotify(get_DOSBase(), notify) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EndNotify(notify: *mut NotifyRequest) {
+        unsafe { crate::dos::EndNotify(get_DOSBase(), notify) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetVar(
+        name: CONST_STRPTR,
+        buffer: CONST_STRPTR,
+        size: LONG,
+        flags: LONG,
+    ) -> BOOL {
+        unsafe { crate::dos::SetVar(get_DOSBase(), name, buffer, size, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetVar(name: CONST_STRPTR, buffer: STRPTR, size: LONG, flags: LONG) -> LONG {
+        unsafe { crate::dos::GetVar(get_DOSBase(), name, buffer, size, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteVar(name: CONST_STRPTR, flags: ULONG) -> LONG {
+        unsafe { crate::dos::DeleteVar(get_DOSBase(), name, flags) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindVar(name: CONST_STRPTR, type_: ULONG) -> *mut LocalVar {
+        unsafe { crate::dos::FindVar(get_DOSBase(), name, type_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CliInitNewcli(dp: *mut DosPacket) -> LONG {
+        unsafe { crate::dos::CliInitNewcli(get_DOSBase(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CliInitRun(dp: *mut DosPacket) -> LONG {
+        unsafe { crate::dos::CliInitRun(get_DOSBase(), dp) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WriteChars(buf: CONST_STRPTR, buflen: ULONG) -> LONG {
+        unsafe { crate::dos::WriteChars(get_DOSBase(), buf, buflen) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PutStr(str_: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::PutStr(get_DOSBase(), str_) }
+    }
+
+    /// libname: "dos.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn VPrintf(format: CONST_STRPTR, argarray: CONST_APTR) -> LONG {
+        unsafe { crate::dos::VPrintf(get_DOSBase(), format, argarray) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn ParsePatternNoCase(
+        pat: CONST_STRPTR,
+        patbuf: *mut UBYTE,
+        patbuflen: LONG,
+    ) -> LONG {
+        unsafe { crate::dos::ParsePatternNoCase(get_DOSBase(), pat, patbuf, patbuflen) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn MatchPatternNoCase(patbuf: *const UBYTE, str_: CONST_STRPTR) -> BOOL {
+        unsafe { crate::dos::MatchPatternNoCase(get_DOSBase(), patbuf, str_) }
+    }
+
+    /// libname: "dos.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn SameDevice(lock1: BPTR, lock2: BPTR) -> BOOL {
+        unsafe { crate::dos::SameDevice(get_DOSBase(), lock1, lock2) }
+    }
+
+    /// libname: "dos.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ExAllEnd(
+        lock: BPTR,
+        buffer: *mut ExAllData,
+        size: LONG,
+        data: LONG,
+        control: *mut ExAllControl,
+    ) {
+        unsafe { crate::dos::ExAllEnd(get_DOSBase(), lock, buffer, size, data, control) }
+    }
+
+    /// libname: "dos.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetOwner(name: CONST_STRPTR, owner_info: LONG) -> BOOL {
+        unsafe { crate::dos::SetOwner(get_DOSBase(), name, owner_info) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn VolumeRequestHook(vol: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::VolumeRequestHook(get_DOSBase(), vol) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn GetCurrentDir() -> BPTR {
+        unsafe { crate::dos::GetCurrentDir(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn PutErrStr(str_: CONST_STRPTR) -> LONG {
+        unsafe { crate::dos::PutErrStr(get_DOSBase(), str_) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ErrorOutput() -> LONG {
+        unsafe { crate::dos::ErrorOutput(get_DOSBase()) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SelectError(fh: BPTR) -> LONG {
+        unsafe { crate::dos::SelectError(get_DOSBase(), fh) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn DoShellMethodTagList(method: ULONG, tags: *const TagItem) -> APTR {
+        unsafe { crate::dos::DoShellMethodTagList(get_DOSBase(), method, tags) }
+    }
+
+    /// libname: "dos.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ScanStackToken(seg: BPTR, defaultstack: LONG) -> LONG {
+        unsafe { crate::dos::ScanStackToken(get_DOSBase(), seg, defaultstack) }
+    }
+}
+
+#[cfg(feature = "drawlist-image")]
+pub use drawlist_image::*;
+
+#[cfg(feature = "drawlist-image")]
+#[allow(unused_imports, dead_code)]
+mod drawlist_image {
+    use super::*;
+
+    /// Global library base for "drawlist.image"
+    pub static mut DrawListBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_DrawListBase() -> *mut ::core::ffi::c_void {
+        unsafe { DrawListBase }
+    }
+
+    /// libname: "drawlist.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DRAWLIST_GetClass() -> *mut Class {
+        unsafe { crate::drawlist_image::DRAWLIST_GetClass(get_DrawListBase()) }
+    }
+}
+
+#[cfg(feature = "exec")]
+pub use exec::*;
+
+#[cfg(feature = "exec")]
+mod exec {
+    use super::*;
+
+    /// Global library base for "exec.library"
+    pub static mut SysBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_SysBase() -> *mut Library {
+        unsafe {
+            if SysBase.is_null() {
+                SysBase = crate::abs_exec_library();
+            }
+            SysBase
+        }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Supervisor(userFunction: FPTR) -> ULONG {
+        unsafe { crate::exec::Supervisor(get_SysBase(), userFunction) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn InitCode(startClass: ULONG, version: ULONG) {
+        unsafe { crate::exec::InitCode(get_SysBase(), startClass, version) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn InitStruct(initTable: CONST_APTR, memory: APTR, size: ULONG) {
+        unsafe { crate::exec::InitStruct(get_SysBase(), initTable, memory, size) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn MakeLibrary(
+        funcInit: CONST_APTR,
+        structInit: CONST_APTR,
+        libInit: FPTR,
+        dataSize: ULONG,
+        segList: ULONG,
+    ) -> *mut Library {
+        unsafe {
+            crate::exec::MakeLibrary(
+                get_SysBase(),
+                funcInit,
+                structInit,
+                libInit,
+                dataSize,
+                segList,
+            )
+        }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn MakeFunctions(target: APTR, functionArray: CONST_APTR, funcDispBase: ULONG) {
+        unsafe { crate::exec::MakeFunctions(get_SysBase(), target, functionArray, funcDispBase) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FindResident(name: CONST_STRPTR) -> *mut Resident {
+        unsafe { crate::exec::FindResident(get_SysBase(), name) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn InitResident(resident: *const Resident, segList: ULONG) -> APTR {
+        unsafe { crate::exec::InitResident(get_SysBase(), resident, segList) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Alert(alertNum: ULONG) {
+        unsafe { crate::exec::Alert(get_SysBase(), alertNum) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Debug(flags: ULONG) {
+        unsafe { crate::exec::Debug(get_SysBase(), flags) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Disable() {
+        unsafe { crate::exec::Disable(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Enable() {
+        unsafe { crate::exec::Enable(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Forbid() {
+        unsafe { crate::exec::Forbid(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Permit() {
+        unsafe { crate::exec::Permit(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetSR(newSR: ULONG, mask: ULONG) -> ULONG {
+        unsafe { crate::exec::SetSR(get_SysBase(), newSR, mask) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SuperState() -> APTR {
+        unsafe { crate::exec::SuperState(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn UserState(sysStack: APTR) {
+        unsafe { crate::exec::UserState(get_SysBase(), sysStack) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetIntVector(intNumber: LONG, interrupt: *mut Interrupt) -> *mut Interrupt {
+        unsafe { crate::exec::SetIntVector(get_SysBase(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddIntServer(intNumber: LONG, interrupt: *mut Interrupt) {
+        unsafe { crate::exec::AddIntServer(get_SysBase(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemIntServer(intNumber: LONG, interrupt: *mut Interrupt) {
+        unsafe { crate::exec::RemIntServer(get_SysBase(), intNumber, interrupt) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Cause(interrupt: *const Interrupt) {
+        unsafe { crate::exec::Cause(get_SysBase(), interrupt) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Allocate(freeList: *mut MemHeader, byteSize: ULONG) -> APTR {
+        unsafe { crate::exec::Allocate(get_SysBase(), freeList, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Deallocate(freeList: *mut MemHeader, memoryBlock: APTR, byteSize: ULONG) {
+        unsafe { crate::exec::Deallocate(get_SysBase(), freeList, memoryBlock, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AllocMem(byteSize: ULONG, requirements: ULONG) -> APTR {
+        unsafe { crate::exec::AllocMem(get_SysBase(), byteSize, requirements) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AllocAbs(byteSize: ULONG, location: APTR) -> APTR {
+        unsafe { crate::exec::AllocAbs(get_SysBase(), byteSize, location) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FreeMem(memoryBlock: APTR, byteSize: ULONG) {
+        unsafe { crate::exec::FreeMem(get_SysBase(), memoryBlock, byteSize) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AvailMem(requirements: ULONG) -> ULONG {
+        unsafe { crate::exec::AvailMem(get_SysBase(), requirements) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AllocEntry(entry: *const MemList) -> *mut MemList {
+        unsafe { crate::exec::AllocEntry(get_SysBase(), entry) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FreeEntry(entry: *mut MemList) {
+        unsafe { crate::exec::FreeEntry(get_SysBase(), entry) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Insert(list: *mut List, node: *mut Node, pred: *mut Node) {
+        unsafe { crate::exec::Insert(get_SysBase(), list, node, pred) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn InsertMinNode(
+        minlist: *mut MinList,
+        minnode: *mut MinNode,
+        minpred: *mut MinNode,
+    ) {
+        unsafe { crate::exec::InsertMinNode(get_SysBase(), minlist, minnode, minpred) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddHead(list: *mut List, node: *mut Node) {
+        unsafe { crate::exec::AddHead(get_SysBase(), list, node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn AddHeadMinList(minlist: *mut MinList, minnode: *mut MinNode) {
+        unsafe { crate::exec::AddHeadMinList(get_SysBase(), minlist, minnode) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddTail(list: *mut List, node: *mut Node) {
+        unsafe { crate::exec::AddTail(get_SysBase(), list, node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn AddTailMinList(minlist: *mut MinList, minnode: *mut MinNode) {
+        unsafe { crate::exec::AddTailMinList(get_SysBase(), minlist, minnode) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Remove(node: *mut Node) {
+        unsafe { crate::exec::Remove(get_SysBase(), node) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn RemoveMinNode(minnode: *mut MinNode) {
+        unsafe { crate::exec::RemoveMinNode(get_SysBase(), minnode) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemHead(list: *mut List) -> *mut Node {
+        unsafe { crate::exec::RemHead(get_SysBase(), list) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn RemHeadMinList(minlist: *mut MinList) -> *mut MinNode {
+        unsafe { crate::exec::RemHeadMinList(get_SysBase(), minlist) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemTail(list: *mut List) -> *mut Node {
+        unsafe { crate::exec::RemTail(get_SysBase(), list) }
+    }
+
+    /// libname: "exec.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn RemTailMinList(minlist: *mut MinList) -> *mut MinNode {
+        unsafe { crate::exec::RemTailMinList(get_SysBase(), minlist) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Enqueue(list: *mut List, node: *mut Node) {
+        unsafe { crate::exec::Enqueue(get_SysBase(), list, node) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FindName(list: *mut List, name: CONST_STRPTR) -> *mut Node {
+        unsafe { crate::exec::FindName(get_SysBase(), list, name) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddTask(task: *mut Task, initPC: APTR, finalPC: APTR) -> APTR {
+        unsafe { crate::exec::AddTask(get_SysBase(), task, initPC, finalPC) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemTask(task: *mut Task) {
+        unsafe { crate::exec::RemTask(get_SysBase(), task) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FindTask(name: CONST_STRPTR) -> *mut Task {
+        unsafe { crate::exec::FindTask(get_SysBase(), name) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetTaskPri(task: *mut Task, priority: LONG) -> BYTE {
+        unsafe { crate::exec::SetTaskPri(get_SysBase(), task, priority) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetSignal(newSignals: ULONG, signalSet: ULONG) -> ULONG {
+        unsafe { crate::exec::SetSignal(get_SysBase(), newSignals, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetExcept(newSignals: ULONG, signalSet: ULONG) -> ULONG {
+        unsafe { crate::exec::SetExcept(get_SysBase(), newSignals, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Wait(signalSet: ULONG) -> ULONG {
+        unsafe { crate::exec::Wait(get_SysBase(), signalSet) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Signal(task: *mut Task, signalSet: ULONG) {
+        unsafe { crate::exec::Signal(get_SysBase(), task, signalSet) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AllocSignal(signalNum: LONG) -> BYTE {
+        unsafe { crate::exec::AllocSignal(get_SysBase(), signalNum) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FreeSignal(signalNum: LONG) {
+        unsafe { crate::exec::FreeSignal(get_SysBase(), signalNum) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AllocTrap(trapNum: LONG) -> LONG {
+        unsafe { crate::exec::AllocTrap(get_SysBase(), trapNum) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FreeTrap(trapNum: LONG) {
+        unsafe { crate::exec::FreeTrap(get_SysBase(), trapNum) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddPort(port: *mut MsgPort) {
+        unsafe { crate::exec::AddPort(get_SysBase(), port) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemPort(port: *mut MsgPort) {
+        unsafe { crate::exec::RemPort(get_SysBase(), port) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn PutMsg(port: *mut MsgPort, message: *mut Message) {
+        unsafe { crate::exec::PutMsg(get_SysBase(), port, message) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn GetMsg(port: *mut MsgPort) -> *mut Message {
+        unsafe { crate::exec::GetMsg(get_SysBase(), port) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn ReplyMsg(message: *mut Message) {
+        unsafe { crate::exec::ReplyMsg(get_SysBase(), message) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn WaitPort(port: *mut MsgPort) -> *mut Message {
+        unsafe { crate::exec::WaitPort(get_SysBase(), port) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn FindPort(name: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { crate::exec::FindPort(get_SysBase(), name) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddLibrary(library: *mut Library) {
+        unsafe { crate::exec::AddLibrary(get_SysBase(), library) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemLibrary(library: *mut Library) {
+        unsafe { crate::exec::RemLibrary(get_SysBase(), library) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn OldOpenLibrary(libName: CONST_STRPTR) -> *mut Library {
+        unsafe { crate::exec::OldOpenLibrary(get_SysBase(), libName) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn CloseLibrary(library: *mut Library) {
+        unsafe { crate::exec::CloseLibrary(get_SysBase(), library) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SetFunction(library: *mut Library, funcOffset: LONG, newFunction: FPTR) -> APTR {
+        unsafe { crate::exec::SetFunction(get_SysBase(), library, funcOffset, newFunction) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SumLibrary(library: *mut Library) {
+        unsafe { crate::exec::SumLibrary(get_SysBase(), library) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddDevice(device: *mut Device) {
+        unsafe { crate::exec::AddDevice(get_SysBase(), device) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemDevice(device: *mut Device) {
+        unsafe { crate::exec::RemDevice(get_SysBase(), device) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn OpenDevice(
+        devName: CONST_STRPTR,
+        unit: ULONG,
+        ioRequest: *mut IORequest,
+        flags: ULONG,
+    ) -> BYTE {
+        unsafe { crate::exec::OpenDevice(get_SysBase(), devName, unit, ioRequest, flags) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn CloseDevice(ioRequest: *mut IORequest) {
+        unsafe { crate::exec::CloseDevice(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn DoIO(ioRequest: *mut IORequest) -> BYTE {
+        unsafe { crate::exec::DoIO(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn SendIO(ioRequest: *mut IORequest) {
+        unsafe { crate::exec::SendIO(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn CheckIO(ioRequest: *const IORequest) -> *mut IORequest {
+        unsafe { crate::exec::CheckIO(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn WaitIO(ioRequest: *mut IORequest) -> BYTE {
+        unsafe { crate::exec::WaitIO(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AbortIO(ioRequest: *mut IORequest) {
+        unsafe { crate::exec::AbortIO(get_SysBase(), ioRequest) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn AddResource(resource: APTR) {
+        unsafe { crate::exec::AddResource(get_SysBase(), resource) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RemResource(resource: APTR) {
+        unsafe { crate::exec::RemResource(get_SysBase(), resource) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn OpenResource(resName: CONST_STRPTR) -> APTR {
+        unsafe { crate::exec::OpenResource(get_SysBase(), resName) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn RawDoFmt(
+        formatString: CONST_STRPTR,
+        dataStream: APTR,
+        putChProc: FPTR,
+        putChData: APTR,
+    ) -> APTR {
+        unsafe {
+            crate::exec::RawDoFmt(
+                get_SysBase(),
+                formatString,
+                dataStream,
+                putChProc,
+                putChData,
+            )
+        }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn GetCC() -> ULONG {
+        unsafe { crate::exec::GetCC(get_SysBase()) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn TypeOfMem(address: CONST_APTR) -> ULONG {
+        unsafe { crate::exec::TypeOfMem(get_SysBase(), address) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Procure(sigSem: *mut SignalSemaphore, bidMsg: *mut SemaphoreMessage) -> ULONG {
+        unsafe { crate::exec::Procure(get_SysBase(), sigSem, bidMsg) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn Vacate(sigSem: *mut SignalSemaphore, bidMsg: *mut SemaphoreMessage) {
+        unsafe { crate::exec::Vacate(get_SysBase(), sigSem, bidMsg) }
+    }
+
+    /// libname: "exec.library"
+    #[inline]
+    pub unsafe fn OpenLibrary(libName: CONST_STRPTR, version: ULONG) -> *mut Library {
+        unsafe { crate::exec::OpenLibrary(get_SysBase(), libName, version) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn InitSemaphore(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::InitSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ObtainSemaphore(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::ObtainSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ReleaseSemaphore(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::ReleaseSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AttemptSemaphore(sigSem: *mut SignalSemaphore) -> ULONG {
+        unsafe { crate::exec::AttemptSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ObtainSemaphoreList(sigSem: *mut List) {
+        unsafe { crate::exec::ObtainSemaphoreList(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ReleaseSemaphoreList(sigSem: *mut List) {
+        unsafe { crate::exec::ReleaseSemaphoreList(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn FindSemaphore(name: CONST_STRPTR) -> *mut SignalSemaphore {
+        unsafe { crate::exec::FindSemaphore(get_SysBase(), name) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AddSemaphore(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::AddSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn RemSemaphore(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::RemSemaphore(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn SumKickData() -> ULONG {
+        unsafe { crate::exec::SumKickData(get_SysBase()) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AddMemList(size: ULONG, attributes: ULONG, pri: LONG, base: APTR, name: STRPTR) {
+        unsafe { crate::exec::AddMemList(get_SysBase(), size, attributes, pri, base, name) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn CopyMem(source: CONST_APTR, dest: APTR, size: ULONG) {
+        unsafe { crate::exec::CopyMem(get_SysBase(), source, dest, size) }
+    }
+
+    /// libname: "exec.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn CopyMemQuick(source: CONST_APTR, dest: APTR, size: ULONG) {
+        unsafe { crate::exec::CopyMemQuick(get_SysBase(), source, dest, size) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CacheClearU() {
+        unsafe { crate::exec::CacheClearU(get_SysBase()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CacheClearE(address: APTR, length: ULONG, caches: ULONG) {
+        unsafe { crate::exec::CacheClearE(get_SysBase(), address, length, caches) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CacheControl(cacheBits: ULONG, cacheMask: ULONG) -> ULONG {
+        unsafe { crate::exec::CacheControl(get_SysBase(), cacheBits, cacheMask) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateIORequest(port: *mut MsgPort, size: ULONG) -> APTR {
+        unsafe { crate::exec::CreateIORequest(get_SysBase(), port, size) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteIORequest(iorequest: APTR) {
+        unsafe { crate::exec::DeleteIORequest(get_SysBase(), iorequest) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateMsgPort() -> *mut MsgPort {
+        unsafe { crate::exec::CreateMsgPort(get_SysBase()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteMsgPort(port: *mut MsgPort) {
+        unsafe { crate::exec::DeleteMsgPort(get_SysBase(), port) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ObtainSemaphoreShared(sigSem: *mut SignalSemaphore) {
+        unsafe { crate::exec::ObtainSemaphoreShared(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocVec(byteSize: ULONG, requirements: ULONG) -> APTR {
+        unsafe { crate::exec::AllocVec(get_SysBase(), byteSize, requirements) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeVec(memoryBlock: APTR) {
+        unsafe { crate::exec::FreeVec(get_SysBase(), memoryBlock) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn CreatePool(requirements: ULONG, puddleSize: ULONG, threshSize: ULONG) -> APTR {
+        unsafe { crate::exec::CreatePool(get_SysBase(), requirements, puddleSize, threshSize) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn DeletePool(poolHeader: APTR) {
+        unsafe { crate::exec::DeletePool(get_SysBase(), poolHeader) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocPooled(poolHeader: APTR, memSize: ULONG) -> APTR {
+        unsafe { crate::exec::AllocPooled(get_SysBase(), poolHeader, memSize) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreePooled(poolHeader: APTR, memory: APTR, memSize: ULONG) {
+        unsafe { crate::exec::FreePooled(get_SysBase(), poolHeader, memory, memSize) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AttemptSemaphoreShared(sigSem: *mut SignalSemaphore) -> ULONG {
+        unsafe { crate::exec::AttemptSemaphoreShared(get_SysBase(), sigSem) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ColdReboot() {
+        unsafe { crate::exec::ColdReboot(get_SysBase()) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StackSwap(newStack: *mut StackSwapStruct) {
+        unsafe { crate::exec::StackSwap(get_SysBase(), newStack) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CachePreDMA(address: CONST_APTR, length: *mut ULONG, flags: ULONG) -> APTR {
+        unsafe { crate::exec::CachePreDMA(get_SysBase(), address, length, flags) }
+    }
+
+    /// libname: "exec.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CachePostDMA(address: CONST_APTR, length: *mut ULONG, flags: ULONG) {
+        unsafe { crate::exec::CachePostDMA(get_SysBase(), address, length, flags) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AddMemHandler(memhand: *mut Interrupt) {
+        unsafe { crate::exec::AddMemHandler(get_SysBase(), memhand) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn RemMemHandler(memhand: *mut Interrupt) {
+        unsafe { crate::exec::RemMemHandler(get_SysBase(), memhand) }
+    }
+
+    /// libname: "exec.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ObtainQuickVector(interruptCode: APTR) -> ULONG {
+        unsafe { crate::exec::ObtainQuickVector(get_SysBase(), interruptCode) }
+    }
+
+    /// libname: "exec.library" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn NewMinList(minlist: *mut MinList) {
+        unsafe { crate::exec::NewMinList(get_SysBase(), minlist) }
+    }
+}
+
+#[cfg(feature = "expansion")]
+pub use expansion::*;
+
+#[cfg(feature = "expansion")]
+mod expansion {
+    use super::*;
+
+    /// Global library base for "expansion.library"
+    pub static mut ExpansionBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ExpansionBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut ExpansionBase, c"expansion.library")
+            } else {
+                ExpansionBase
+            }
+        }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AddConfigDev(configDev: *mut ConfigDev) {
+        unsafe { crate::expansion::AddConfigDev(get_ExpansionBase(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddBootNode(
+        bootPri: LONG,
+        flags: ULONG,
+        deviceNode: *mut DeviceNode,
+        configDev: *mut ConfigDev,
+    ) -> BOOL {
+        unsafe {
+            crate::expansion::AddBootNode(
+                get_ExpansionBase(),
+                bootPri,
+                flags,
+                deviceNode,
+                configDev,
+            )
+        }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AllocBoardMem(slotSpec: ULONG) {
+        unsafe { crate::expansion::AllocBoardMem(get_ExpansionBase(), slotSpec) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AllocConfigDev() -> *mut ConfigDev {
+        unsafe { crate::expansion::AllocConfigDev(get_ExpansionBase()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AllocExpansionMem(numSlots: ULONG, slotAlign: ULONG) -> APTR {
+        unsafe { crate::expansion::AllocExpansionMem(get_ExpansionBase(), numSlots, slotAlign) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ConfigBoard(board: APTR, configDev: *mut ConfigDev) {
+        unsafe { crate::expansion::ConfigBoard(get_ExpansionBase(), board, configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ConfigChain(baseAddr: APTR) {
+        unsafe { crate::expansion::ConfigChain(get_ExpansionBase(), baseAddr) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn FindConfigDev(
+        oldConfigDev: *const ConfigDev,
+        manufacturer: LONG,
+        product: LONG,
+    ) -> *mut ConfigDev {
+        unsafe {
+            crate::expansion::FindConfigDev(
+                get_ExpansionBase(),
+                oldConfigDev,
+                manufacturer,
+                product,
+            )
+        }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn FreeBoardMem(startSlot: ULONG, slotSpec: ULONG) {
+        unsafe { crate::expansion::FreeBoardMem(get_ExpansionBase(), startSlot, slotSpec) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn FreeConfigDev(configDev: *mut ConfigDev) {
+        unsafe { crate::expansion::FreeConfigDev(get_ExpansionBase(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn FreeExpansionMem(startSlot: ULONG, numSlots: ULONG) {
+        unsafe { crate::expansion::FreeExpansionMem(get_ExpansionBase(), startSlot, numSlots) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ReadExpansionByte(board: CONST_APTR, offset: ULONG) -> UBYTE {
+        unsafe { crate::expansion::ReadExpansionByte(get_ExpansionBase(), board, offset) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ReadExpansionRom(board: CONST_APTR, configDev: *mut ConfigDev) {
+        unsafe { crate::expansion::ReadExpansionRom(get_ExpansionBase(), board, configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn RemConfigDev(configDev: *mut ConfigDev) {
+        unsafe { crate::expansion::RemConfigDev(get_ExpansionBase(), configDev) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn WriteExpansionByte(board: APTR, offset: ULONG, byte: ULONG) {
+        unsafe { crate::expansion::WriteExpansionByte(get_ExpansionBase(), board, offset, byte) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ObtainConfigBinding() {
+        unsafe { crate::expansion::ObtainConfigBinding(get_ExpansionBase()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ReleaseConfigBinding() {
+        unsafe { crate::expansion::ReleaseConfigBinding(get_ExpansionBase()) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn SetCurrentBinding(currentBinding: *mut CurrentBinding, bindingSize: ULONG) {
+        unsafe {
+            crate::expansion::SetCurrentBinding(get_ExpansionBase(), currentBinding, bindingSize)
+        }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn GetCurrentBinding(
+        currentBinding: *const CurrentBinding,
+        bindingSize: ULONG,
+    ) -> ULONG {
+        unsafe {
+            crate::expansion::GetCurrentBinding(get_ExpansionBase(), currentBinding, bindingSize)
+        }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn MakeDosNode(parmPacket: CONST_APTR) -> *mut DeviceNode {
+        unsafe { crate::expansion::MakeDosNode(get_ExpansionBase(), parmPacket) }
+    }
+
+    /// libname: "expansion.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AddDosNode(bootPri: LONG, flags: ULONG, deviceNode: *mut DeviceNode) -> BOOL {
+        unsafe { crate::expansion::AddDosNode(get_ExpansionBase(), bootPri, flags, deviceNode) }
+    }
+}
+
+#[cfg(feature = "fuelgauge-gadget")]
+pub use fuelgauge_gadget::*;
+
+#[cfg(feature = "fuelgauge-gadget")]
+#[allow(unused_imports, dead_code)]
+mod fuelgauge_gadget {
+    use super::*;
+
+    /// Global library base for "fuelgauge.gadget"
+    pub static mut FuelGaugeBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_FuelGaugeBase() -> *mut ::core::ffi::c_void {
+        unsafe { FuelGaugeBase }
+    }
+
+    /// libname: "fuelgauge.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FUELGAUGE_GetClass() -> *mut Class {
+        unsafe { crate::fuelgauge_gadget::FUELGAUGE_GetClass(get_FuelGaugeBase()) }
+    }
+}
+
+#[cfg(feature = "gadtools")]
+pub use gadtools::*;
+
+#[cfg(feature = "gadtools")]
+#[allow(unused_imports, dead_code)]
+mod gadtools {
+    use super::*;
+
+    /// Global library base for "gadtools.library"
+    pub static mut GadToolsBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GadToolsBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut GadToolsBase, c"gadtools.library")
+            } else {
+                GadToolsBase
+            }
+        }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateGadgetA(
+        kind: ULONG,
+        gad: *mut Gadget,
+        ng: *mut NewGadget,
+        taglist: *const TagItem,
+    ) -> *mut Gadget {
+        unsafe { crate::gadtools::CreateGadgetA(get_GadToolsBase(), kind, gad, ng, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeGadgets(gad: *mut Gadget) {
+        unsafe { crate::gadtools::FreeGadgets(get_GadToolsBase(), gad) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_SetGadgetAttrsA(
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        taglist: *const TagItem,
+    ) {
+        unsafe { crate::gadtools::GT_SetGadgetAttrsA(get_GadToolsBase(), gad, win, req, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateMenusA(newmenu: *const NewMenu, taglist: *mut TagItem) -> *mut Menu {
+        unsafe { crate::gadtools::CreateMenusA(get_GadToolsBase(), newmenu, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeMenus(menu: *mut Menu) {
+        unsafe { crate::gadtools::FreeMenus(get_GadToolsBase(), menu) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LayoutMenuItemsA(
+        firstitem: *mut MenuItem,
+        vi: APTR,
+        taglist: *const TagItem,
+    ) -> BOOL {
+        unsafe { crate::gadtools::LayoutMenuItemsA(get_GadToolsBase(), firstitem, vi, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LayoutMenusA(firstmenu: *mut Menu, vi: APTR, taglist: *const TagItem) -> BOOL {
+        unsafe { crate::gadtools::LayoutMenusA(get_GadToolsBase(), firstmenu, vi, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_GetIMsg(iport: *mut MsgPort) -> *mut IntuiMessage {
+        unsafe { crate::gadtools::GT_GetIMsg(get_GadToolsBase(), iport) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_ReplyIMsg(imsg: *mut IntuiMessage) {
+        unsafe { crate::gadtools::GT_ReplyIMsg(get_GadToolsBase(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_RefreshWindow(win: *mut Window, req: *mut Requester) {
+        unsafe { crate::gadtools::GT_RefreshWindow(get_GadToolsBase(), win, req) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_BeginRefresh(win: *mut Window) {
+        unsafe { crate::gadtools::GT_BeginRefresh(get_GadToolsBase(), win) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_EndRefresh(win: *mut Window, complete: LONG) {
+        unsafe { crate::gadtools::GT_EndRefresh(get_GadToolsBase(), win, complete) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_FilterIMsg(imsg: *const IntuiMessage) -> *mut IntuiMessage {
+        unsafe { crate::gadtools::GT_FilterIMsg(get_GadToolsBase(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GT_PostFilterIMsg(imsg: *mut IntuiMessage) -> *mut IntuiMessage {
+        unsafe { crate::gadtools::GT_PostFilterIMsg(get_GadToolsBase(), imsg) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateContext(glistptr: *mut *mut Gadget) -> *mut Gadget {
+        unsafe { crate::gadtools::CreateContext(get_GadToolsBase(), glistptr) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DrawBevelBoxA(
+        rport: *mut RastPort,
+        left: LONG,
+        top: LONG,
+        width: LONG,
+        height: LONG,
+        taglist: *const TagItem,
+    ) {
+        unsafe {
+            crate::gadtools::DrawBevelBoxA(
+                get_GadToolsBase(),
+                rport,
+                left,
+                top,
+                width,
+                height,
+                taglist,
+            )
+        }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetVisualInfoA(screen: *mut Screen, taglist: *const TagItem) -> APTR {
+        unsafe { crate::gadtools::GetVisualInfoA(get_GadToolsBase(), screen, taglist) }
+    }
+
+    /// libname: "gadtools.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeVisualInfo(vi: APTR) {
+        unsafe { crate::gadtools::FreeVisualInfo(get_GadToolsBase(), vi) }
+    }
+
+    /// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SetDesignFontA(vi: APTR, tattr: *mut TextAttr, tags: *const TagItem) -> LONG {
+        unsafe { crate::gadtools::SetDesignFontA(get_GadToolsBase(), vi, tattr, tags) }
+    }
+
+    /// libname: "gadtools.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ScaleGadgetRectA(ng: *mut NewGadget, tags: *const TagItem) -> LONG {
+        unsafe { crate::gadtools::ScaleGadgetRectA(get_GadToolsBase(), ng, tags) }
+    }
+
+    /// libname: "gadtools.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GT_GetGadgetAttrsA(
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        taglist: *const TagItem,
+    ) -> LONG {
+        unsafe { crate::gadtools::GT_GetGadgetAttrsA(get_GadToolsBase(), gad, win, req, taglist) }
+    }
+}
+
+#[cfg(feature = "getcolor-gadget")]
+pub use getcolor_gadget::*;
+
+#[cfg(feature = "getcolor-gadget")]
+#[allow(unused_imports, dead_code)]
+mod getcolor_gadget {
+    use super::*;
+
+    /// Global library base for "getcolor.gadget"
+    pub static mut GetColorBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GetColorBase() -> *mut ::core::ffi::c_void {
+        unsafe { GetColorBase }
+    }
+
+    /// libname: "getcolor.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GETCOLOR_GetClass() -> *mut Class {
+        unsafe { crate::getcolor_gadget::GETCOLOR_GetClass(get_GetColorBase()) }
+    }
+}
+
+#[cfg(feature = "getfile-gadget")]
+pub use getfile_gadget::*;
+
+#[cfg(feature = "getfile-gadget")]
+#[allow(unused_imports, dead_code)]
+mod getfile_gadget {
+    use super::*;
+
+    /// Global library base for "getfile.gadget"
+    pub static mut GetFileBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GetFileBase() -> *mut ::core::ffi::c_void {
+        unsafe { GetFileBase }
+    }
+
+    /// libname: "getfile.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GETFILE_GetClass() -> *mut Class {
+        unsafe { crate::getfile_gadget::GETFILE_GetClass(get_GetFileBase()) }
+    }
+}
+
+#[cfg(feature = "getfont-gadget")]
+pub use getfont_gadget::*;
+
+#[cfg(feature = "getfont-gadget")]
+#[allow(unused_imports, dead_code)]
+mod getfont_gadget {
+    use super::*;
+
+    /// Global library base for "getfont.gadget"
+    pub static mut GetFontBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GetFontBase() -> *mut ::core::ffi::c_void {
+        unsafe { GetFontBase }
+    }
+
+    /// libname: "getfont.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GETFONT_GetClass() -> *mut Class {
+        unsafe { crate::getfont_gadget::GETFONT_GetClass(get_GetFontBase()) }
+    }
+}
+
+#[cfg(feature = "getscreenmode-gadget")]
+pub use getscreenmode_gadget::*;
+
+#[cfg(feature = "getscreenmode-gadget")]
+#[allow(unused_imports, dead_code)]
+mod getscreenmode_gadget {
+    use super::*;
+
+    /// Global library base for "getscreenmode.gadget"
+    pub static mut GetScreenModeBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GetScreenModeBase() -> *mut ::core::ffi::c_void {
+        unsafe { GetScreenModeBase }
+    }
+
+    /// libname: "getscreenmode.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GETSCREENMODE_GetClass() -> *mut Class {
+        unsafe { crate::getscreenmode_gadget::GETSCREENMODE_GetClass(get_GetScreenModeBase()) }
+    }
+}
+
+#[cfg(feature = "glyph-image")]
+pub use glyph_image::*;
+
+#[cfg(feature = "glyph-image")]
+#[allow(unused_imports, dead_code)]
+mod glyph_image {
+    use super::*;
+
+    /// Global library base for "glyph.image"
+    pub static mut GlyphBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GlyphBase() -> *mut ::core::ffi::c_void {
+        unsafe { GlyphBase }
+    }
+
+    /// libname: "glyph.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GLYPH_GetClass() -> *mut Class {
+        unsafe { crate::glyph_image::GLYPH_GetClass(get_GlyphBase()) }
+    }
+}
+
+#[cfg(feature = "graphics")]
+pub use graphics::*;
+
+#[cfg(feature = "graphics")]
+mod graphics {
+    use super::*;
+
+    /// Global library base for "graphics.library"
+    pub static mut GfxBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_GfxBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut GfxBase, c"graphics.library")
+            } else {
+                GfxBase
+            }
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltBitMap(
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destBitMap: *mut BitMap,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+        mask: ULONG,
+        tempA: PLANEPTR,
+    ) -> LONG {
+        unsafe {
+            crate::graphics::BltBitMap(
+                get_GfxBase(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destBitMap,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+                mask,
+                tempA,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltTemplate(
+        source: PLANEPTR,
+        xSrc: LONG,
+        srcMod: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+    ) {
+        unsafe {
+            crate::graphics::BltTemplate(
+                get_GfxBase(),
+                source,
+                xSrc,
+                srcMod,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ClearEOL(rp: *mut RastPort) {
+        unsafe { crate::graphics::ClearEOL(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ClearScreen(rp: *mut RastPort) {
+        unsafe { crate::graphics::ClearScreen(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn TextLength(rp: *mut RastPort, string: CONST_STRPTR, count: ULONG) -> WORD {
+        unsafe { crate::graphics::TextLength(get_GfxBase(), rp, string, count) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn Text(rp: *mut RastPort, string: CONST_STRPTR, count: ULONG) -> LONG {
+        unsafe { crate::graphics::Text(get_GfxBase(), rp, string, count) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetFont(rp: *mut RastPort, textFont: *mut TextFont) -> LONG {
+        unsafe { crate::graphics::SetFont(get_GfxBase(), rp, textFont) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn OpenFont(textAttr: *const TextAttr) -> *mut TextFont {
+        unsafe { crate::graphics::OpenFont(get_GfxBase(), textAttr) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn CloseFont(textFont: *mut TextFont) {
+        unsafe { crate::graphics::CloseFont(get_GfxBase(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AskSoftStyle(rp: *mut RastPort) -> ULONG {
+        unsafe { crate::graphics::AskSoftStyle(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetSoftStyle(rp: *mut RastPort, style: ULONG, enable: ULONG) -> ULONG {
+        unsafe { crate::graphics::SetSoftStyle(get_GfxBase(), rp, style, enable) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AddBob(bob: *mut Bob, rp: *mut RastPort) {
+        unsafe { crate::graphics::AddBob(get_GfxBase(), bob, rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AddVSprite(vSprite: *mut VSprite, rp: *mut RastPort) {
+        unsafe { crate::graphics::AddVSprite(get_GfxBase(), vSprite, rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn DoCollision(rp: *mut RastPort) {
+        unsafe { crate::graphics::DoCollision(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn DrawGList(rp: *mut RastPort, vp: *mut ViewPort) {
+        unsafe { crate::graphics::DrawGList(get_GfxBase(), rp, vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitGels(head: *mut VSprite, tail: *mut VSprite, gelsInfo: *mut GelsInfo) {
+        unsafe { crate::graphics::InitGels(get_GfxBase(), head, tail, gelsInfo) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitMasks(vSprite: *mut VSprite) {
+        unsafe { crate::graphics::InitMasks(get_GfxBase(), vSprite) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn RemIBob(bob: *mut Bob, rp: *mut RastPort, vp: *mut ViewPort) {
+        unsafe { crate::graphics::RemIBob(get_GfxBase(), bob, rp, vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn RemVSprite(vSprite: *mut VSprite) {
+        unsafe { crate::graphics::RemVSprite(get_GfxBase(), vSprite) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetCollision(num: ULONG, routine: FPTR, gelsInfo: *mut GelsInfo) {
+        unsafe { crate::graphics::SetCollision(get_GfxBase(), num, routine, gelsInfo) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SortGList(rp: *mut RastPort) {
+        unsafe { crate::graphics::SortGList(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AddAnimOb(anOb: *mut AnimOb, anKey: *mut *mut AnimOb, rp: *mut RastPort) {
+        unsafe { crate::graphics::AddAnimOb(get_GfxBase(), anOb, anKey, rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn Animate(anKey: *mut *mut AnimOb, rp: *mut RastPort) {
+        unsafe { crate::graphics::Animate(get_GfxBase(), anKey, rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn GetGBuffers(anOb: *mut AnimOb, rp: *mut RastPort, flag: LONG) -> BOOL {
+        unsafe { crate::graphics::GetGBuffers(get_GfxBase(), anOb, rp, flag) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitGMasks(anOb: *mut AnimOb) {
+        unsafe { crate::graphics::InitGMasks(get_GfxBase(), anOb) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn DrawEllipse(rp: *mut RastPort, xCenter: LONG, yCenter: LONG, a: LONG, b: LONG) {
+        unsafe { crate::graphics::DrawEllipse(get_GfxBase(), rp, xCenter, yCenter, a, b) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AreaEllipse(
+        rp: *mut RastPort,
+        xCenter: LONG,
+        yCenter: LONG,
+        a: LONG,
+        b: LONG,
+    ) -> LONG {
+        unsafe { crate::graphics::AreaEllipse(get_GfxBase(), rp, xCenter, yCenter, a, b) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn LoadRGB4(vp: *mut ViewPort, colors: *const UWORD, count: LONG) {
+        unsafe { crate::graphics::LoadRGB4(get_GfxBase(), vp, colors, count) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitRastPort(rp: *mut RastPort) {
+        unsafe { crate::graphics::InitRastPort(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitVPort(vp: *mut ViewPort) {
+        unsafe { crate::graphics::InitVPort(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn MrgCop(view: *mut View) -> ULONG {
+        unsafe { crate::graphics::MrgCop(get_GfxBase(), view) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn MakeVPort(view: *mut View, vp: *mut ViewPort) -> ULONG {
+        unsafe { crate::graphics::MakeVPort(get_GfxBase(), view, vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn LoadView(view: *mut View) {
+        unsafe { crate::graphics::LoadView(get_GfxBase(), view) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn WaitBlit() {
+        unsafe { crate::graphics::WaitBlit(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetRast(rp: *mut RastPort, pen: ULONG) {
+        unsafe { crate::graphics::SetRast(get_GfxBase(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn Move(rp: *mut RastPort, x: LONG, y: LONG) {
+        unsafe { crate::graphics::Move(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn Draw(rp: *mut RastPort, x: LONG, y: LONG) {
+        unsafe { crate::graphics::Draw(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AreaMove(rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { crate::graphics::AreaMove(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AreaDraw(rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { crate::graphics::AreaDraw(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AreaEnd(rp: *mut RastPort) -> LONG {
+        unsafe { crate::graphics::AreaEnd(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn WaitTOF() {
+        unsafe { crate::graphics::WaitTOF(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn QBlit(blit: *mut bltnode) {
+        unsafe { crate::graphics::QBlit(get_GfxBase(), blit) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitArea(areaInfo: *mut AreaInfo, vectorBuffer: APTR, maxVectors: LONG) {
+        unsafe { crate::graphics::InitArea(get_GfxBase(), areaInfo, vectorBuffer, maxVectors) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetRGB4(vp: *mut ViewPort, index: LONG, red: ULONG, green: ULONG, blue: ULONG) {
+        unsafe { crate::graphics::SetRGB4(get_GfxBase(), vp, index, red, green, blue) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn QBSBlit(blit: *mut bltnode) {
+        unsafe { crate::graphics::QBSBlit(get_GfxBase(), blit) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltClear(memBlock: PLANEPTR, byteCount: ULONG, flags: ULONG) {
+        unsafe { crate::graphics::BltClear(get_GfxBase(), memBlock, byteCount, flags) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn RectFill(rp: *mut RastPort, xMin: LONG, yMin: LONG, xMax: LONG, yMax: LONG) {
+        unsafe { crate::graphics::RectFill(get_GfxBase(), rp, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltPattern(
+        rp: *mut RastPort,
+        mask: PLANEPTR,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+        maskBPR: ULONG,
+    ) {
+        unsafe {
+            crate::graphics::BltPattern(get_GfxBase(), rp, mask, xMin, yMin, xMax, yMax, maskBPR)
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ReadPixel(rp: *mut RastPort, x: LONG, y: LONG) -> ULONG {
+        unsafe { crate::graphics::ReadPixel(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn WritePixel(rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { crate::graphics::WritePixel(get_GfxBase(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn Flood(rp: *mut RastPort, mode: ULONG, x: LONG, y: LONG) -> BOOL {
+        unsafe { crate::graphics::Flood(get_GfxBase(), rp, mode, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn PolyDraw(rp: *mut RastPort, count: LONG, polyTable: *const WORD) {
+        unsafe { crate::graphics::PolyDraw(get_GfxBase(), rp, count, polyTable) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetAPen(rp: *mut RastPort, pen: ULONG) {
+        unsafe { crate::graphics::SetAPen(get_GfxBase(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetBPen(rp: *mut RastPort, pen: ULONG) {
+        unsafe { crate::graphics::SetBPen(get_GfxBase(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetDrMd(rp: *mut RastPort, drawMode: ULONG) {
+        unsafe { crate::graphics::SetDrMd(get_GfxBase(), rp, drawMode) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitView(view: *mut View) {
+        unsafe { crate::graphics::InitView(get_GfxBase(), view) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn CBump(copList: *mut UCopList) {
+        unsafe { crate::graphics::CBump(get_GfxBase(), copList) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn CMove(copList: *mut UCopList, destination: APTR, data: LONG) -> LONG {
+        unsafe { crate::graphics::CMove(get_GfxBase(), copList, destination, data) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn CWait(copList: *mut UCopList, v: LONG, h: LONG) -> LONG {
+        unsafe { crate::graphics::CWait(get_GfxBase(), copList, v, h) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn VBeamPos() -> LONG {
+        unsafe { crate::graphics::VBeamPos(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitBitMap(bitMap: *mut BitMap, depth: LONG, width: LONG, height: LONG) {
+        unsafe { crate::graphics::InitBitMap(get_GfxBase(), bitMap, depth, width, height) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ScrollRaster(
+        rp: *mut RastPort,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { crate::graphics::ScrollRaster(get_GfxBase(), rp, dx, dy, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn WaitBOVP(vp: *mut ViewPort) {
+        unsafe { crate::graphics::WaitBOVP(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn GetSprite(sprite: *mut SimpleSprite, num: LONG) -> WORD {
+        unsafe { crate::graphics::GetSprite(get_GfxBase(), sprite, num) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeSprite(num: LONG) {
+        unsafe { crate::graphics::FreeSprite(get_GfxBase(), num) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ChangeSprite(vp: *mut ViewPort, sprite: *mut SimpleSprite, newData: *mut UWORD) {
+        unsafe { crate::graphics::ChangeSprite(get_GfxBase(), vp, sprite, newData) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn MoveSprite(vp: *mut ViewPort, sprite: *mut SimpleSprite, x: LONG, y: LONG) {
+        unsafe { crate::graphics::MoveSprite(get_GfxBase(), vp, sprite, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn LockLayerRom(layer: *mut Layer) {
+        unsafe { crate::graphics::LockLayerRom(get_GfxBase(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn UnlockLayerRom(layer: *mut Layer) {
+        unsafe { crate::graphics::UnlockLayerRom(get_GfxBase(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SyncSBitMap(layer: *mut Layer) {
+        unsafe { crate::graphics::SyncSBitMap(get_GfxBase(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn CopySBitMap(layer: *mut Layer) {
+        unsafe { crate::graphics::CopySBitMap(get_GfxBase(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn OwnBlitter() {
+        unsafe { crate::graphics::OwnBlitter(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn DisownBlitter() {
+        unsafe { crate::graphics::DisownBlitter(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn InitTmpRas(tmpRas: *mut TmpRas, buffer: PLANEPTR, size: LONG) -> *mut TmpRas {
+        unsafe { crate::graphics::InitTmpRas(get_GfxBase(), tmpRas, buffer, size) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AskFont(rp: *mut RastPort, textAttr: *mut TextAttr) {
+        unsafe { crate::graphics::AskFont(get_GfxBase(), rp, textAttr) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AddFont(textFont: *mut TextFont) {
+        unsafe { crate::graphics::AddFont(get_GfxBase(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn RemFont(textFont: *mut TextFont) {
+        unsafe { crate::graphics::RemFont(get_GfxBase(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AllocRaster(width: ULONG, height: ULONG) -> PLANEPTR {
+        unsafe { crate::graphics::AllocRaster(get_GfxBase(), width, height) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeRaster(p: PLANEPTR, width: ULONG, height: ULONG) {
+        unsafe { crate::graphics::FreeRaster(get_GfxBase(), p, width, height) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AndRectRegion(region: *mut Region, rectangle: *const Rectangle) {
+        unsafe { crate::graphics::AndRectRegion(get_GfxBase(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn OrRectRegion(region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { crate::graphics::OrRectRegion(get_GfxBase(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn NewRegion() -> *mut Region {
+        unsafe { crate::graphics::NewRegion(get_GfxBase()) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ClearRectRegion(region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { crate::graphics::ClearRectRegion(get_GfxBase(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ClearRegion(region: *mut Region) {
+        unsafe { crate::graphics::ClearRegion(get_GfxBase(), region) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn DisposeRegion(region: *mut Region) {
+        unsafe { crate::graphics::DisposeRegion(get_GfxBase(), region) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeVPortCopLists(vp: *mut ViewPort) {
+        unsafe { crate::graphics::FreeVPortCopLists(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeCopList(copList: *mut CopList) {
+        unsafe { crate::graphics::FreeCopList(get_GfxBase(), copList) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ClipBlit(
+        srcRP: *mut RastPort,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+    ) {
+        unsafe {
+            crate::graphics::ClipBlit(
+                get_GfxBase(),
+                srcRP,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn XorRectRegion(region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { crate::graphics::XorRectRegion(get_GfxBase(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeCprList(cprList: *mut cprlist) {
+        unsafe { crate::graphics::FreeCprList(get_GfxBase(), cprList) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn GetColorMap(entries: LONG) -> *mut ColorMap {
+        unsafe { crate::graphics::GetColorMap(get_GfxBase(), entries) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeColorMap(colorMap: *mut ColorMap) {
+        unsafe { crate::graphics::FreeColorMap(get_GfxBase(), colorMap) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn GetRGB4(colorMap: *mut ColorMap, entry: LONG) -> ULONG {
+        unsafe { crate::graphics::GetRGB4(get_GfxBase(), colorMap, entry) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn ScrollVPort(vp: *mut ViewPort) {
+        unsafe { crate::graphics::ScrollVPort(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn UCopperListInit(uCopList: *mut UCopList, n: LONG) -> *mut CopList {
+        unsafe { crate::graphics::UCopperListInit(get_GfxBase(), uCopList, n) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn FreeGBuffers(anOb: *mut AnimOb, rp: *mut RastPort, flag: LONG) {
+        unsafe { crate::graphics::FreeGBuffers(get_GfxBase(), anOb, rp, flag) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltBitMapRastPort(
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+    ) {
+        unsafe {
+            crate::graphics::BltBitMapRastPort(
+                get_GfxBase(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn OrRegionRegion(srcRegion: *const Region, destRegion: *mut Region) -> BOOL {
+        unsafe { crate::graphics::OrRegionRegion(get_GfxBase(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn XorRegionRegion(srcRegion: *const Region, destRegion: *mut Region) -> BOOL {
+        unsafe { crate::graphics::XorRegionRegion(get_GfxBase(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AndRegionRegion(srcRegion: *const Region, destRegion: *mut Region) -> BOOL {
+        unsafe { crate::graphics::AndRegionRegion(get_GfxBase(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn SetRGB4CM(
+        colorMap: *mut ColorMap,
+        index: LONG,
+        red: ULONG,
+        green: ULONG,
+        blue: ULONG,
+    ) {
+        unsafe { crate::graphics::SetRGB4CM(get_GfxBase(), colorMap, index, red, green, blue) }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn BltMaskBitMapRastPort(
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+        bltMask: PLANEPTR,
+    ) {
+        unsafe {
+            crate::graphics::BltMaskBitMapRastPort(
+                get_GfxBase(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+                bltMask,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    #[inline]
+    pub unsafe fn AttemptLockLayerRom(layer: *mut Layer) -> BOOL {
+        unsafe { crate::graphics::AttemptLockLayerRom(get_GfxBase(), layer) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GfxNew(gfxNodeType: ULONG) -> APTR {
+        unsafe { crate::graphics::GfxNew(get_GfxBase(), gfxNodeType) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GfxFree(gfxNodePtr: APTR) {
+        unsafe { crate::graphics::GfxFree(get_GfxBase(), gfxNodePtr) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GfxAssociate(associateNode: APTR, gfxNodePtr: APTR) {
+        unsafe { crate::graphics::GfxAssociate(get_GfxBase(), associateNode, gfxNodePtr) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn BitMapScale(bitScaleArgs: *mut BitScaleArgs) {
+        unsafe { crate::graphics::BitMapScale(get_GfxBase(), bitScaleArgs) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ScalerDiv(factor: ULONG, numerator: ULONG, denominator: ULONG) -> UWORD {
+        unsafe { crate::graphics::ScalerDiv(get_GfxBase(), factor, numerator, denominator) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn TextExtent(
+        rp: *mut RastPort,
+        string: CONST_STRPTR,
+        count: LONG,
+        textExtent: *mut TextExtent,
+    ) -> WORD {
+        unsafe { crate::graphics::TextExtent(get_GfxBase(), rp, string, count, textExtent) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn TextFit(
+        rp: *mut RastPort,
+        string: CONST_STRPTR,
+        strLen: ULONG,
+        textExtent: *const TextExtent,
+        constrainingExtent: *const TextExtent,
+        strDirection: LONG,
+        constrainingBitWidth: ULONG,
+        constrainingBitHeight: ULONG,
+    ) -> ULONG {
+        unsafe {
+            crate::graphics::TextFit(
+                get_GfxBase(),
+                rp,
+                string,
+                strLen,
+                textExtent,
+                constrainingExtent,
+                strDirection,
+                constrainingBitWidth,
+                constrainingBitHeight,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GfxLookUp(associateNode: CONST_APTR) -> APTR {
+        unsafe { crate::graphics::GfxLookUp(get_GfxBase(), associateNode) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn VideoControl(colorMap: *mut ColorMap, tagarray: *mut TagItem) -> BOOL {
+        unsafe { crate::graphics::VideoControl(get_GfxBase(), colorMap, tagarray) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenMonitor(monitorName: CONST_STRPTR, displayID: ULONG) -> *mut MonitorSpec {
+        unsafe { crate::graphics::OpenMonitor(get_GfxBase(), monitorName, displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CloseMonitor(monitorSpec: *mut MonitorSpec) -> BOOL {
+        unsafe { crate::graphics::CloseMonitor(get_GfxBase(), monitorSpec) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindDisplayInfo(displayID: ULONG) -> DisplayInfoHandle {
+        unsafe { crate::graphics::FindDisplayInfo(get_GfxBase(), displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NextDisplayInfo(displayID: ULONG) -> ULONG {
+        unsafe { crate::graphics::NextDisplayInfo(get_GfxBase(), displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetDisplayInfoData(
+        handle: DisplayInfoHandle,
+        buf: APTR,
+        size: ULONG,
+        tagID: ULONG,
+        displayID: ULONG,
+    ) -> ULONG {
+        unsafe {
+            crate::graphics::GetDisplayInfoData(get_GfxBase(), handle, buf, size, tagID, displayID)
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FontExtent(font: *const TextFont, fontExtent: *mut TextExtent) {
+        unsafe { crate::graphics::FontExtent(get_GfxBase(), font, fontExtent) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadPixelLine8(
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        width: ULONG,
+        array: *mut UBYTE,
+        tempRP: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            crate::graphics::ReadPixelLine8(get_GfxBase(), rp, xstart, ystart, width, array, tempRP)
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WritePixelLine8(
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        width: ULONG,
+        array: *mut UBYTE,
+        tempRP: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            crate::graphics::WritePixelLine8(
+                get_GfxBase(),
+                rp,
+                xstart,
+                ystart,
+                width,
+                array,
+                tempRP,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadPixelArray8(
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        temprp: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            crate::graphics::ReadPixelArray8(
+                get_GfxBase(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                temprp,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WritePixelArray8(
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        temprp: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            crate::graphics::WritePixelArray8(
+                get_GfxBase(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                temprp,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetVPModeID(vp: *const ViewPort) -> LONG {
+        unsafe { crate::graphics::GetVPModeID(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ModeNotAvailable(modeID: ULONG) -> LONG {
+        unsafe { crate::graphics::ModeNotAvailable(get_GfxBase(), modeID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WeighTAMatch(
+        reqTextAttr: *const TextAttr,
+        targetTextAttr: *const TextAttr,
+        targetTags: *const TagItem,
+    ) -> WORD {
+        unsafe {
+            crate::graphics::WeighTAMatch(get_GfxBase(), reqTextAttr, targetTextAttr, targetTags)
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EraseRect(rp: *mut RastPort, xMin: LONG, yMin: LONG, xMax: LONG, yMax: LONG) {
+        unsafe { crate::graphics::EraseRect(get_GfxBase(), rp, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ExtendFont(font: *mut TextFont, fontTags: *const TagItem) -> ULONG {
+        unsafe { crate::graphics::ExtendFont(get_GfxBase(), font, fontTags) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StripFont(font: *mut TextFont) {
+        unsafe { crate::graphics::StripFont(get_GfxBase(), font) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn CalcIVG(v: *mut View, vp: *mut ViewPort) -> UWORD {
+        unsafe { crate::graphics::CalcIVG(get_GfxBase(), v, vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AttachPalExtra(cm: *mut ColorMap, vp: *mut ViewPort) -> LONG {
+        unsafe { crate::graphics::AttachPalExtra(get_GfxBase(), cm, vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ObtainBestPenA(
+        cm: *mut ColorMap,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        tags: *const TagItem,
+    ) -> LONG {
+        unsafe { crate::graphics::ObtainBestPenA(get_GfxBase(), cm, r, g, b, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetRGB32(vp: *mut ViewPort, n: ULONG, r: ULONG, g: ULONG, b: ULONG) {
+        unsafe { crate::graphics::SetRGB32(get_GfxBase(), vp, n, r, g, b) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetAPen(rp: *mut RastPort) -> ULONG {
+        unsafe { crate::graphics::GetAPen(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetBPen(rp: *mut RastPort) -> ULONG {
+        unsafe { crate::graphics::GetBPen(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetDrMd(rp: *mut RastPort) -> ULONG {
+        unsafe { crate::graphics::GetDrMd(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetOutlinePen(rp: *mut RastPort) -> ULONG {
+        unsafe { crate::graphics::GetOutlinePen(get_GfxBase(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn LoadRGB32(vp: *mut ViewPort, table: *const ULONG) {
+        unsafe { crate::graphics::LoadRGB32(get_GfxBase(), vp, table) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetChipRev(want: ULONG) -> ULONG {
+        unsafe { crate::graphics::SetChipRev(get_GfxBase(), want) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetABPenDrMd(rp: *mut RastPort, apen: ULONG, bpen: ULONG, drawmode: ULONG) {
+        unsafe { crate::graphics::SetABPenDrMd(get_GfxBase(), rp, apen, bpen, drawmode) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetRGB32(
+        cm: *const ColorMap,
+        firstcolor: ULONG,
+        ncolors: ULONG,
+        table: *mut ULONG,
+    ) {
+        unsafe { crate::graphics::GetRGB32(get_GfxBase(), cm, firstcolor, ncolors, table) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocBitMap(
+        sizex: ULONG,
+        sizey: ULONG,
+        depth: ULONG,
+        flags: ULONG,
+        friend_bitmap: *const BitMap,
+    ) -> *mut BitMap {
+        unsafe {
+            crate::graphics::AllocBitMap(get_GfxBase(), sizex, sizey, depth, flags, friend_bitmap)
+        }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreeBitMap(bm: *mut BitMap) {
+        unsafe { crate::graphics::FreeBitMap(get_GfxBase(), bm) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetExtSpriteA(ss: *mut ExtSprite, tags: *const TagItem) -> LONG {
+        unsafe { crate::graphics::GetExtSpriteA(get_GfxBase(), ss, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn CoerceMode(vp: *mut ViewPort, monitorid: ULONG, flags: ULONG) -> ULONG {
+        unsafe { crate::graphics::CoerceMode(get_GfxBase(), vp, monitorid, flags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ChangeVPBitMap(vp: *mut ViewPort, bm: *mut BitMap, db: *mut DBufInfo) {
+        unsafe { crate::graphics::ChangeVPBitMap(get_GfxBase(), vp, bm, db) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ReleasePen(cm: *mut ColorMap, n: ULONG) {
+        unsafe { crate::graphics::ReleasePen(get_GfxBase(), cm, n) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ObtainPen(
+        cm: *mut ColorMap,
+        n: ULONG,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        f: LONG,
+    ) -> ULONG {
+        unsafe { crate::graphics::ObtainPen(get_GfxBase(), cm, n, r, g, b, f) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetBitMapAttr(bm: *const BitMap, attrnum: ULONG) -> ULONG {
+        unsafe { crate::graphics::GetBitMapAttr(get_GfxBase(), bm, attrnum) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocDBufInfo(vp: *mut ViewPort) -> *mut DBufInfo {
+        unsafe { crate::graphics::AllocDBufInfo(get_GfxBase(), vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreeDBufInfo(dbi: *mut DBufInfo) {
+        unsafe { crate::graphics::FreeDBufInfo(get_GfxBase(), dbi) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetOutlinePen(rp: *mut RastPort, pen: ULONG) -> ULONG {
+        unsafe { crate::graphics::SetOutlinePen(get_GfxBase(), rp, pen) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetWriteMask(rp: *mut RastPort, msk: ULONG) -> ULONG {
+        unsafe { crate::graphics::SetWriteMask(get_GfxBase(), rp, msk) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetMaxPen(rp: *mut RastPort, maxpen: ULONG) {
+        unsafe { crate::graphics::SetMaxPen(get_GfxBase(), rp, maxpen) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetRGB32CM(cm: *mut ColorMap, n: ULONG, r: ULONG, g: ULONG, b: ULONG) {
+        unsafe { crate::graphics::SetRGB32CM(get_GfxBase(), cm, n, r, g, b) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ScrollRasterBF(
+        rp: *mut RastPort,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe {
+            crate::graphics::ScrollRasterBF(get_GfxBase(), rp, dx, dy, xMin, yMin, xMax, yMax)
+        }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FindColor(
+        cm: *mut ColorMap,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        maxcolor: LONG,
+    ) -> LONG {
+        unsafe { crate::graphics::FindColor(get_GfxBase(), cm, r, g, b, maxcolor) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocSpriteDataA(bm: *const BitMap, tags: *const TagItem) -> *mut ExtSprite {
+        unsafe { crate::graphics::AllocSpriteDataA(get_GfxBase(), bm, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ChangeExtSpriteA(
+        vp: *mut ViewPort,
+        oldsprite: *mut ExtSprite,
+        newsprite: *mut ExtSprite,
+        tags: *const TagItem,
+    ) -> LONG {
+        unsafe { crate::graphics::ChangeExtSpriteA(get_GfxBase(), vp, oldsprite, newsprite, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreeSpriteData(sp: *mut ExtSprite) {
+        unsafe { crate::graphics::FreeSpriteData(get_GfxBase(), sp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetRPAttrsA(rp: *mut RastPort, tags: *const TagItem) {
+        unsafe { crate::graphics::SetRPAttrsA(get_GfxBase(), rp, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetRPAttrsA(rp: *mut RastPort, tags: *const TagItem) {
+        unsafe { crate::graphics::GetRPAttrsA(get_GfxBase(), rp, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn BestModeIDA(tags: *const TagItem) -> ULONG {
+        unsafe { crate::graphics::BestModeIDA(get_GfxBase(), tags) }
+    }
+
+    /// libname: "graphics.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn WriteChunkyPixels(
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        bytesperrow: LONG,
+    ) {
+        unsafe {
+            crate::graphics::WriteChunkyPixels(
+                get_GfxBase(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                bytesperrow,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "icon")]
+pub use icon::*;
+
+#[cfg(feature = "icon")]
+mod icon {
+    use super::*;
+
+    /// Global library base for "icon.library"
+    pub static mut IconBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_IconBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut IconBase, c"icon.library")
+            } else {
+                IconBase
+            }
+        }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn FreeFreeList(freelist: *mut FreeList) {
+        unsafe { crate::icon::FreeFreeList(get_IconBase(), freelist) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn AddFreeList(freelist: *mut FreeList, mem: CONST_APTR, size: ULONG) -> BOOL {
+        unsafe { crate::icon::AddFreeList(get_IconBase(), freelist, mem, size) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn GetDiskObject(name: CONST_STRPTR) -> *mut DiskObject {
+        unsafe { crate::icon::GetDiskObject(get_IconBase(), name) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn PutDiskObject(name: CONST_STRPTR, diskobj: *const DiskObject) -> BOOL {
+        unsafe { crate::icon::PutDiskObject(get_IconBase(), name, diskobj) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn FreeDiskObject(diskobj: *mut DiskObject) {
+        unsafe { crate::icon::FreeDiskObject(get_IconBase(), diskobj) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn FindToolType(
+        toolTypeArray: *mut CONST_STRPTR,
+        typeName: CONST_STRPTR,
+    ) -> *mut UBYTE {
+        unsafe { crate::icon::FindToolType(get_IconBase(), toolTypeArray, typeName) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn MatchToolValue(typeString: CONST_STRPTR, value: CONST_STRPTR) -> BOOL {
+        unsafe { crate::icon::MatchToolValue(get_IconBase(), typeString, value) }
+    }
+
+    /// libname: "icon.library"
+    #[inline]
+    pub unsafe fn BumpRevision(newname: STRPTR, oldname: CONST_STRPTR) -> STRPTR {
+        unsafe { crate::icon::BumpRevision(get_IconBase(), newname, oldname) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeAlloc(free: *mut FreeList, len: ULONG, type_: ULONG) -> APTR {
+        unsafe { crate::icon::FreeAlloc(get_IconBase(), free, len, type_) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetDefDiskObject(type_: LONG) -> *mut DiskObject {
+        unsafe { crate::icon::GetDefDiskObject(get_IconBase(), type_) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PutDefDiskObject(diskObject: *const DiskObject) -> BOOL {
+        unsafe { crate::icon::PutDefDiskObject(get_IconBase(), diskObject) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetDiskObjectNew(name: CONST_STRPTR) -> *mut DiskObject {
+        unsafe { crate::icon::GetDiskObjectNew(get_IconBase(), name) }
+    }
+
+    /// libname: "icon.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn DeleteDiskObject(name: CONST_STRPTR) -> BOOL {
+        unsafe { crate::icon::DeleteDiskObject(get_IconBase(), name) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn FreeFree(fl: *mut FreeList, address: APTR) {
+        unsafe { crate::icon::FreeFree(get_IconBase(), fl, address) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn DupDiskObjectA(
+        diskObject: *const DiskObject,
+        tags: *const TagItem,
+    ) -> *mut DiskObject {
+        unsafe { crate::icon::DupDiskObjectA(get_IconBase(), diskObject, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn IconControlA(icon: *mut DiskObject, tags: *const TagItem) -> ULONG {
+        unsafe { crate::icon::IconControlA(get_IconBase(), icon, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn DrawIconStateA(
+        rp: *mut RastPort,
+        icon: *const DiskObject,
+        label: CONST_STRPTR,
+        leftOffset: LONG,
+        topOffset: LONG,
+        state: ULONG,
+        tags: *const TagItem,
+    ) {
+        unsafe {
+            crate::icon::DrawIconStateA(
+                get_IconBase(),
+                rp,
+                icon,
+                label,
+                leftOffset,
+                topOffset,
+                state,
+                tags,
+            )
+        }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn GetIconRectangleA(
+        rp: *mut RastPort,
+        icon: *const DiskObject,
+        label: CONST_STRPTR,
+        rect: *mut Rectangle,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { crate::icon::GetIconRectangleA(get_IconBase(), rp, icon, label, rect, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn NewDiskObject(type_: LONG) -> *mut DiskObject {
+        unsafe { crate::icon::NewDiskObject(get_IconBase(), type_) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn GetIconTagList(name: CONST_STRPTR, tags: *const TagItem) -> *mut DiskObject {
+        unsafe { crate::icon::GetIconTagList(get_IconBase(), name, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn PutIconTagList(
+        name: CONST_STRPTR,
+        icon: *const DiskObject,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { crate::icon::PutIconTagList(get_IconBase(), name, icon, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn LayoutIconA(
+        icon: *mut DiskObject,
+        screen: *mut Screen,
+        tags: *mut TagItem,
+    ) -> BOOL {
+        unsafe { crate::icon::LayoutIconA(get_IconBase(), icon, screen, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn ChangeToSelectedIconColor(cr: *mut ColorRegister) {
+        unsafe { crate::icon::ChangeToSelectedIconColor(get_IconBase(), cr) }
+    }
+
+    /// libname: "icon.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn BumpRevisionLength(
+        newname: STRPTR,
+        oldname: CONST_STRPTR,
+        maxLength: ULONG,
+    ) -> STRPTR {
+        unsafe { crate::icon::BumpRevisionLength(get_IconBase(), newname, oldname, maxLength) }
+    }
+}
+
+#[cfg(feature = "iffparse")]
+pub use iffparse::*;
+
+#[cfg(feature = "iffparse")]
+#[allow(unused_imports, dead_code)]
+mod iffparse {
+    use super::*;
+
+    /// Global library base for "iffparse.library"
+    pub static mut IFFParseBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_IFFParseBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut IFFParseBase, c"iffparse.library")
+            } else {
+                IFFParseBase
+            }
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocIFF() -> *mut IFFHandle {
+        unsafe { crate::iffparse::AllocIFF(get_IFFParseBase()) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenIFF(iff: *mut IFFHandle, rwMode: LONG) -> LONG {
+        unsafe { crate::iffparse::OpenIFF(get_IFFParseBase(), iff, rwMode) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ParseIFF(iff: *mut IFFHandle, control: LONG) -> LONG {
+        unsafe { crate::iffparse::ParseIFF(get_IFFParseBase(), iff, control) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CloseIFF(iff: *mut IFFHandle) {
+        unsafe { crate::iffparse::CloseIFF(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeIFF(iff: *mut IFFHandle) {
+        unsafe { crate::iffparse::FreeIFF(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadChunkBytes(iff: *mut IFFHandle, buf: APTR, numBytes: LONG) -> LONG {
+        unsafe { crate::iffparse::ReadChunkBytes(get_IFFParseBase(), iff, buf, numBytes) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WriteChunkBytes(iff: *mut IFFHandle, buf: CONST_APTR, numBytes: LONG) -> LONG {
+        unsafe { crate::iffparse::WriteChunkBytes(get_IFFParseBase(), iff, buf, numBytes) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadChunkRecords(
+        iff: *mut IFFHandle,
+        buf: APTR,
+        bytesPerRecord: LONG,
+        numRecords: LONG,
+    ) -> LONG {
+        unsafe {
+            crate::iffparse::ReadChunkRecords(
+                get_IFFParseBase(),
+                iff,
+                buf,
+                bytesPerRecord,
+                numRecords,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn WriteChunkRecords(
+        iff: *mut IFFHandle,
+        buf: CONST_APTR,
+        bytesPerRecord: LONG,
+        numRecords: LONG,
+    ) -> LONG {
+        unsafe {
+            crate::iffparse::WriteChunkRecords(
+                get_IFFParseBase(),
+                iff,
+                buf,
+                bytesPerRecord,
+                numRecords,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PushChunk(iff: *mut IFFHandle, type_: LONG, id: LONG, size: LONG) -> LONG {
+        unsafe { crate::iffparse::PushChunk(get_IFFParseBase(), iff, type_, id, size) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PopChunk(iff: *mut IFFHandle) -> LONG {
+        unsafe { crate::iffparse::PopChunk(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EntryHandler(
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        position: LONG,
+        handler: *mut Hook,
+        object: APTR,
+    ) -> LONG {
+        unsafe {
+            crate::iffparse::EntryHandler(
+                get_IFFParseBase(),
+                iff,
+                type_,
+                id,
+                position,
+                handler,
+                object,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ExitHandler(
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        position: LONG,
+        handler: *mut Hook,
+        object: APTR,
+    ) -> LONG {
+        unsafe {
+            crate::iffparse::ExitHandler(
+                get_IFFParseBase(),
+                iff,
+                type_,
+                id,
+                position,
+                handler,
+                object,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PropChunk(iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { crate::iffparse::PropChunk(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PropChunks(iff: *mut IFFHandle, propArray: *const LONG, numPairs: LONG) -> LONG {
+        unsafe { crate::iffparse::PropChunks(get_IFFParseBase(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StopChunk(iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { crate::iffparse::StopChunk(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StopChunks(iff: *mut IFFHandle, propArray: *const LONG, numPairs: LONG) -> LONG {
+        unsafe { crate::iffparse::StopChunks(get_IFFParseBase(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CollectionChunk(iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { crate::iffparse::CollectionChunk(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CollectionChunks(
+        iff: *mut IFFHandle,
+        propArray: *const LONG,
+        numPairs: LONG,
+    ) -> LONG {
+        unsafe { crate::iffparse::CollectionChunks(get_IFFParseBase(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StopOnExit(iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { crate::iffparse::StopOnExit(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindProp(iff: *mut IFFHandle, type_: LONG, id: LONG) -> *mut StoredProperty {
+        unsafe { crate::iffparse::FindProp(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindCollection(
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+    ) -> *mut CollectionItem {
+        unsafe { crate::iffparse::FindCollection(get_IFFParseBase(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindPropContext(iff: *mut IFFHandle) -> *mut ContextNode {
+        unsafe { crate::iffparse::FindPropContext(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CurrentChunk(iff: *mut IFFHandle) -> *mut ContextNode {
+        unsafe { crate::iffparse::CurrentChunk(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ParentChunk(contextNode: *mut ContextNode) -> *mut ContextNode {
+        unsafe { crate::iffparse::ParentChunk(get_IFFParseBase(), contextNode) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocLocalItem(
+        type_: LONG,
+        id: LONG,
+        ident: LONG,
+        dataSize: LONG,
+    ) -> *mut LocalContextItem {
+        unsafe { crate::iffparse::AllocLocalItem(get_IFFParseBase(), type_, id, ident, dataSize) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LocalItemData(localItem: *mut LocalContextItem) -> APTR {
+        unsafe { crate::iffparse::LocalItemData(get_IFFParseBase(), localItem) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetLocalItemPurge(localItem: *mut LocalContextItem, purgeHook: *mut Hook) {
+        unsafe { crate::iffparse::SetLocalItemPurge(get_IFFParseBase(), localItem, purgeHook) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeLocalItem(localItem: *mut LocalContextItem) {
+        unsafe { crate::iffparse::FreeLocalItem(get_IFFParseBase(), localItem) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindLocalItem(
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        ident: LONG,
+    ) -> *mut LocalContextItem {
+        unsafe { crate::iffparse::FindLocalItem(get_IFFParseBase(), iff, type_, id, ident) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StoreLocalItem(
+        iff: *mut IFFHandle,
+        localItem: *mut LocalContextItem,
+        position: LONG,
+    ) -> LONG {
+        unsafe { crate::iffparse::StoreLocalItem(get_IFFParseBase(), iff, localItem, position) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn StoreItemInContext(
+        iff: *mut IFFHandle,
+        localItem: *mut LocalContextItem,
+        contextNode: *mut ContextNode,
+    ) {
+        unsafe {
+            crate::iffparse::StoreItemInContext(get_IFFParseBase(), iff, localItem, contextNode)
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InitIFF(iff: *mut IFFHandle, flags: LONG, streamHook: *mut Hook) {
+        unsafe { crate::iffparse::InitIFF(get_IFFParseBase(), iff, flags, streamHook) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InitIFFasDOS(iff: *mut IFFHandle) {
+        unsafe { crate::iffparse::InitIFFasDOS(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InitIFFasClip(iff: *mut IFFHandle) {
+        unsafe { crate::iffparse::InitIFFasClip(get_IFFParseBase(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenClipboard(unitNumber: LONG) -> *mut ClipboardHandle {
+        unsafe { crate::iffparse::OpenClipboard(get_IFFParseBase(), unitNumber) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CloseClipboard(clipHandle: *mut ClipboardHandle) {
+        unsafe { crate::iffparse::CloseClipboard(get_IFFParseBase(), clipHandle) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GoodID(id: LONG) -> LONG {
+        unsafe { crate::iffparse::GoodID(get_IFFParseBase(), id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GoodType(type_: LONG) -> LONG {
+        unsafe { crate::iffparse::GoodType(get_IFFParseBase(), type_) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IDtoStr(id: LONG, buf: STRPTR) -> STRPTR {
+        unsafe { crate::iffparse::IDtoStr(get_IFFParseBase(), id, buf) }
+    }
+}
+
+#[cfg(feature = "input-device")]
+pub use input_device::*;
+
+#[cfg(feature = "input-device")]
+#[allow(unused_imports, dead_code)]
+mod input_device {
+    use super::*;
+
+    /// Global library base for "input.device"
+    pub static mut InputBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_InputBase() -> *mut ::core::ffi::c_void {
+        unsafe { InputBase }
+    }
+
+    /// libname: "input.device" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PeekQualifier() -> UWORD {
+        unsafe { crate::input_device::PeekQualifier(get_InputBase()) }
+    }
+}
+
+#[cfg(feature = "integer-gadget")]
+pub use integer_gadget::*;
+
+#[cfg(feature = "integer-gadget")]
+#[allow(unused_imports, dead_code)]
+mod integer_gadget {
+    use super::*;
+
+    /// Global library base for "integer.gadget"
+    pub static mut IntegerBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_IntegerBase() -> *mut ::core::ffi::c_void {
+        unsafe { IntegerBase }
+    }
+
+    /// libname: "integer.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn INTEGER_GetClass() -> *mut Class {
+        unsafe { crate::integer_gadget::INTEGER_GetClass(get_IntegerBase()) }
+    }
+}
+
+#[cfg(feature = "intuition")]
+pub use intuition::*;
+
+#[cfg(feature = "intuition")]
+mod intuition {
+    use super::*;
+
+    /// Global library base for "intuition.library"
+    pub static mut IntuitionBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_IntuitionBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut IntuitionBase, c"intuition.library")
+            } else {
+                IntuitionBase
+            }
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OpenIntuition() {
+        unsafe { crate::intuition::OpenIntuition(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn Intuition(iEvent: *mut InputEvent) {
+        unsafe { crate::intuition::Intuition(get_IntuitionBase(), iEvent) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn AddGadget(window: *mut Window, gadget: *mut Gadget, position: ULONG) -> UWORD {
+        unsafe { crate::intuition::AddGadget(get_IntuitionBase(), window, gadget, position) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ClearDMRequest(window: *mut Window) -> BOOL {
+        unsafe { crate::intuition::ClearDMRequest(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ClearMenuStrip(window: *mut Window) {
+        unsafe { crate::intuition::ClearMenuStrip(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ClearPointer(window: *mut Window) {
+        unsafe { crate::intuition::ClearPointer(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn CloseScreen(screen: *mut Screen) -> BOOL {
+        unsafe { crate::intuition::CloseScreen(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn CloseWindow(window: *mut Window) {
+        unsafe { crate::intuition::CloseWindow(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn CloseWorkBench() -> LONG {
+        unsafe { crate::intuition::CloseWorkBench(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn CurrentTime(seconds: *mut ULONG, micros: *mut ULONG) {
+        unsafe { crate::intuition::CurrentTime(get_IntuitionBase(), seconds, micros) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn DisplayAlert(alertNumber: ULONG, string: CONST_STRPTR, height: ULONG) -> BOOL {
+        unsafe { crate::intuition::DisplayAlert(get_IntuitionBase(), alertNumber, string, height) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn DisplayBeep(screen: *mut Screen) {
+        unsafe { crate::intuition::DisplayBeep(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn DoubleClick(
+        sSeconds: ULONG,
+        sMicros: ULONG,
+        cSeconds: ULONG,
+        cMicros: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::intuition::DoubleClick(get_IntuitionBase(), sSeconds, sMicros, cSeconds, cMicros)
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn DrawBorder(
+        rp: *mut RastPort,
+        border: *const Border,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe {
+            crate::intuition::DrawBorder(get_IntuitionBase(), rp, border, leftOffset, topOffset)
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn DrawImage(
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe {
+            crate::intuition::DrawImage(get_IntuitionBase(), rp, image, leftOffset, topOffset)
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn EndRequest(requester: *mut Requester, window: *mut Window) {
+        unsafe { crate::intuition::EndRequest(get_IntuitionBase(), requester, window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn GetDefPrefs(preferences: *mut Preferences, size: LONG) -> *mut Preferences {
+        unsafe { crate::intuition::GetDefPrefs(get_IntuitionBase(), preferences, size) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn GetPrefs(preferences: *mut Preferences, size: LONG) -> *mut Preferences {
+        unsafe { crate::intuition::GetPrefs(get_IntuitionBase(), preferences, size) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn InitRequester(requester: *mut Requester) {
+        unsafe { crate::intuition::InitRequester(get_IntuitionBase(), requester) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ItemAddress(menuStrip: *const Menu, menuNumber: ULONG) -> *mut MenuItem {
+        unsafe { crate::intuition::ItemAddress(get_IntuitionBase(), menuStrip, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ModifyIDCMP(window: *mut Window, flags: ULONG) -> BOOL {
+        unsafe { crate::intuition::ModifyIDCMP(get_IntuitionBase(), window, flags) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ModifyProp(
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        flags: ULONG,
+        horizPot: ULONG,
+        vertPot: ULONG,
+        horizBody: ULONG,
+        vertBody: ULONG,
+    ) {
+        unsafe {
+            crate::intuition::ModifyProp(
+                get_IntuitionBase(),
+                gadget,
+                window,
+                requester,
+                flags,
+                horizPot,
+                vertPot,
+                horizBody,
+                vertBody,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn MoveScreen(screen: *mut Screen, dx: LONG, dy: LONG) {
+        unsafe { crate::intuition::MoveScreen(get_IntuitionBase(), screen, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn MoveWindow(window: *mut Window, dx: LONG, dy: LONG) {
+        unsafe { crate::intuition::MoveWindow(get_IntuitionBase(), window, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OffGadget(gadget: *mut Gadget, window: *mut Window, requester: *mut Requester) {
+        unsafe { crate::intuition::OffGadget(get_IntuitionBase(), gadget, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OffMenu(window: *mut Window, menuNumber: ULONG) {
+        unsafe { crate::intuition::OffMenu(get_IntuitionBase(), window, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OnGadget(gadget: *mut Gadget, window: *mut Window, requester: *mut Requester) {
+        unsafe { crate::intuition::OnGadget(get_IntuitionBase(), gadget, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OnMenu(window: *mut Window, menuNumber: ULONG) {
+        unsafe { crate::intuition::OnMenu(get_IntuitionBase(), window, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OpenScreen(newScreen: *const NewScreen) -> *mut Screen {
+        unsafe { crate::intuition::OpenScreen(get_IntuitionBase(), newScreen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OpenWindow(newWindow: *const NewWindow) -> *mut Window {
+        unsafe { crate::intuition::OpenWindow(get_IntuitionBase(), newWindow) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn OpenWorkBench() -> ULONG {
+        unsafe { crate::intuition::OpenWorkBench(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn PrintIText(rp: *mut RastPort, iText: *const IntuiText, left: LONG, top: LONG) {
+        unsafe { crate::intuition::PrintIText(get_IntuitionBase(), rp, iText, left, top) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn RefreshGadgets(
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe { crate::intuition::RefreshGadgets(get_IntuitionBase(), gadgets, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn RemoveGadget(window: *mut Window, gadget: *mut Gadget) -> UWORD {
+        unsafe { crate::intuition::RemoveGadget(get_IntuitionBase(), window, gadget) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ReportMouse(flag: LONG, window: *mut Window) {
+        unsafe { crate::intuition::ReportMouse(get_IntuitionBase(), flag, window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ReportMouse1(window: *mut Window, flag: LONG) {
+        unsafe { crate::intuition::ReportMouse1(get_IntuitionBase(), window, flag) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn Request(requester: *mut Requester, window: *mut Window) -> BOOL {
+        unsafe { crate::intuition::Request(get_IntuitionBase(), requester, window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ScreenToBack(screen: *mut Screen) {
+        unsafe { crate::intuition::ScreenToBack(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ScreenToFront(screen: *mut Screen) {
+        unsafe { crate::intuition::ScreenToFront(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SetDMRequest(window: *mut Window, requester: *mut Requester) -> BOOL {
+        unsafe { crate::intuition::SetDMRequest(get_IntuitionBase(), window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SetMenuStrip(window: *mut Window, menu: *mut Menu) -> BOOL {
+        unsafe { crate::intuition::SetMenuStrip(get_IntuitionBase(), window, menu) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SetPointer(
+        window: *mut Window,
+        pointer: *mut UWORD,
+        height: LONG,
+        width: LONG,
+        xOffset: LONG,
+        yOffset: LONG,
+    ) {
+        unsafe {
+            crate::intuition::SetPointer(
+                get_IntuitionBase(),
+                window,
+                pointer,
+                height,
+                width,
+                xOffset,
+                yOffset,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SetWindowTitles(
+        window: *mut Window,
+        windowTitle: CONST_STRPTR,
+        screenTitle: CONST_STRPTR,
+    ) {
+        unsafe {
+            crate::intuition::SetWindowTitles(get_IntuitionBase(), window, windowTitle, screenTitle)
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ShowTitle(screen: *mut Screen, showIt: LONG) {
+        unsafe { crate::intuition::ShowTitle(get_IntuitionBase(), screen, showIt) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SizeWindow(window: *mut Window, dx: LONG, dy: LONG) {
+        unsafe { crate::intuition::SizeWindow(get_IntuitionBase(), window, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ViewAddress() -> *mut View {
+        unsafe { crate::intuition::ViewAddress(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn ViewPortAddress(window: *const Window) -> *mut ViewPort {
+        unsafe { crate::intuition::ViewPortAddress(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn WindowToBack(window: *mut Window) {
+        unsafe { crate::intuition::WindowToBack(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn WindowToFront(window: *mut Window) {
+        unsafe { crate::intuition::WindowToFront(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn WindowLimits(
+        window: *mut Window,
+        widthMin: LONG,
+        heightMin: LONG,
+        widthMax: ULONG,
+        heightMax: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::intuition::WindowLimits(
+                get_IntuitionBase(),
+                window,
+                widthMin,
+                heightMin,
+                widthMax,
+                heightMax,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn SetPrefs(
+        preferences: *const Preferences,
+        size: LONG,
+        inform: LONG,
+    ) -> *mut Preferences {
+        unsafe { crate::intuition::SetPrefs(get_IntuitionBase(), preferences, size, inform) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn IntuiTextLength(iText: *const IntuiText) -> LONG {
+        unsafe { crate::intuition::IntuiTextLength(get_IntuitionBase(), iText) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn WBenchToBack() -> BOOL {
+        unsafe { crate::intuition::WBenchToBack(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn WBenchToFront() -> BOOL {
+        unsafe { crate::intuition::WBenchToFront(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn AutoRequest(
+        window: *mut Window,
+        body: *const IntuiText,
+        posText: *const IntuiText,
+        negText: *const IntuiText,
+        pFlag: ULONG,
+        nFlag: ULONG,
+        width: ULONG,
+        height: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::intuition::AutoRequest(
+                get_IntuitionBase(),
+                window,
+                body,
+                posText,
+                negText,
+                pFlag,
+                nFlag,
+                width,
+                height,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn BeginRefresh(window: *mut Window) {
+        unsafe { crate::intuition::BeginRefresh(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn BuildSysRequest(
+        window: *mut Window,
+        body: *const IntuiText,
+        posText: *const IntuiText,
+        negText: *const IntuiText,
+        flags: ULONG,
+        width: ULONG,
+        height: ULONG,
+    ) -> *mut Window {
+        unsafe {
+            crate::intuition::BuildSysRequest(
+                get_IntuitionBase(),
+                window,
+                body,
+                posText,
+                negText,
+                flags,
+                width,
+                height,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn EndRefresh(window: *mut Window, complete: LONG) {
+        unsafe { crate::intuition::EndRefresh(get_IntuitionBase(), window, complete) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn FreeSysRequest(window: *mut Window) {
+        unsafe { crate::intuition::FreeSysRequest(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn MakeScreen(screen: *mut Screen) -> LONG {
+        unsafe { crate::intuition::MakeScreen(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn RemakeDisplay() -> LONG {
+        unsafe { crate::intuition::RemakeDisplay(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn RethinkDisplay() -> LONG {
+        unsafe { crate::intuition::RethinkDisplay(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn AllocRemember(
+        rememberKey: *mut *mut Remember,
+        size: ULONG,
+        flags: ULONG,
+    ) -> APTR {
+        unsafe { crate::intuition::AllocRemember(get_IntuitionBase(), rememberKey, size, flags) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn AlohaWorkbench(wbport: LONG) {
+        unsafe { crate::intuition::AlohaWorkbench(get_IntuitionBase(), wbport) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn FreeRemember(rememberKey: *mut *mut Remember, reallyForget: LONG) {
+        unsafe { crate::intuition::FreeRemember(get_IntuitionBase(), rememberKey, reallyForget) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn LockIBase(dontknow: ULONG) -> ULONG {
+        unsafe { crate::intuition::LockIBase(get_IntuitionBase(), dontknow) }
+    }
+
+    /// libname: "intuition.library"
+    #[inline]
+    pub unsafe fn UnlockIBase(ibLock: ULONG) {
+        unsafe { crate::intuition::UnlockIBase(get_IntuitionBase(), ibLock) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn GetScreenData(
+        buffer: APTR,
+        size: ULONG,
+        type_: ULONG,
+        screen: *const Screen,
+    ) -> LONG {
+        unsafe { crate::intuition::GetScreenData(get_IntuitionBase(), buffer, size, type_, screen) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn RefreshGList(
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        numGad: LONG,
+    ) {
+        unsafe {
+            crate::intuition::RefreshGList(get_IntuitionBase(), gadgets, window, requester, numGad)
+        }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn AddGList(
+        window: *mut Window,
+        gadget: *mut Gadget,
+        position: ULONG,
+        numGad: LONG,
+        requester: *mut Requester,
+    ) -> UWORD {
+        unsafe {
+            crate::intuition::AddGList(
+                get_IntuitionBase(),
+                window,
+                gadget,
+                position,
+                numGad,
+                requester,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn RemoveGList(remPtr: *mut Window, gadget: *mut Gadget, numGad: LONG) -> UWORD {
+        unsafe { crate::intuition::RemoveGList(get_IntuitionBase(), remPtr, gadget, numGad) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ActivateWindow(window: *mut Window) {
+        unsafe { crate::intuition::ActivateWindow(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn RefreshWindowFrame(window: *mut Window) {
+        unsafe { crate::intuition::RefreshWindowFrame(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn ActivateGadget(
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) -> BOOL {
+        unsafe { crate::intuition::ActivateGadget(get_IntuitionBase(), gadgets, window, requester) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn NewModifyProp(
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        flags: ULONG,
+        horizPot: ULONG,
+        vertPot: ULONG,
+        horizBody: ULONG,
+        vertBody: ULONG,
+        numGad: LONG,
+    ) {
+        unsafe {
+            crate::intuition::NewModifyProp(
+                get_IntuitionBase(),
+                gadget,
+                window,
+                requester,
+                flags,
+                horizPot,
+                vertPot,
+                horizBody,
+                vertBody,
+                numGad,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn QueryOverscan(displayID: ULONG, rect: *mut Rectangle, oScanType: LONG) -> LONG {
+        unsafe { crate::intuition::QueryOverscan(get_IntuitionBase(), displayID, rect, oScanType) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MoveWindowInFrontOf(window: *mut Window, behindWindow: *mut Window) {
+        unsafe { crate::intuition::MoveWindowInFrontOf(get_IntuitionBase(), window, behindWindow) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ChangeWindowBox(
+        window: *mut Window,
+        left: LONG,
+        top: LONG,
+        width: LONG,
+        height: LONG,
+    ) {
+        unsafe {
+            crate::intuition::ChangeWindowBox(get_IntuitionBase(), window, left, top, width, height)
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetEditHook(hook: *mut Hook) -> *mut Hook {
+        unsafe { crate::intuition::SetEditHook(get_IntuitionBase(), hook) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetMouseQueue(window: *mut Window, queueLength: ULONG) -> LONG {
+        unsafe { crate::intuition::SetMouseQueue(get_IntuitionBase(), window, queueLength) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ZipWindow(window: *mut Window) {
+        unsafe { crate::intuition::ZipWindow(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockPubScreen(name: CONST_STRPTR) -> *mut Screen {
+        unsafe { crate::intuition::LockPubScreen(get_IntuitionBase(), name) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnlockPubScreen(name: CONST_STRPTR, screen: *mut Screen) {
+        unsafe { crate::intuition::UnlockPubScreen(get_IntuitionBase(), name, screen) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockPubScreenList() -> *mut List {
+        unsafe { crate::intuition::LockPubScreenList(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnlockPubScreenList() {
+        unsafe { crate::intuition::UnlockPubScreenList(get_IntuitionBase()) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NextPubScreen(screen: *const Screen, namebuf: STRPTR) -> STRPTR {
+        unsafe { crate::intuition::NextPubScreen(get_IntuitionBase(), screen, namebuf) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetDefaultPubScreen(name: CONST_STRPTR) {
+        unsafe { crate::intuition::SetDefaultPubScreen(get_IntuitionBase(), name) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetPubScreenModes(modes: ULONG) -> UWORD {
+        unsafe { crate::intuition::SetPubScreenModes(get_IntuitionBase(), modes) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PubScreenStatus(screen: *mut Screen, statusFlags: ULONG) -> UWORD {
+        unsafe { crate::intuition::PubScreenStatus(get_IntuitionBase(), screen, statusFlags) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ObtainGIRPort(gInfo: *mut GadgetInfo) -> *mut RastPort {
+        unsafe { crate::intuition::ObtainGIRPort(get_IntuitionBase(), gInfo) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReleaseGIRPort(rp: *mut RastPort) {
+        unsafe { crate::intuition::ReleaseGIRPort(get_IntuitionBase(), rp) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GadgetMouse(gadget: *mut Gadget, gInfo: *mut GadgetInfo, mousePoint: *mut WORD) {
+        unsafe { crate::intuition::GadgetMouse(get_IntuitionBase(), gadget, gInfo, mousePoint) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetDefaultPubScreen(nameBuffer: STRPTR) {
+        unsafe { crate::intuition::GetDefaultPubScreen(get_IntuitionBase(), nameBuffer) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EasyRequestArgs(
+        window: *mut Window,
+        easyStruct: *const EasyStruct,
+        idcmpPtr: *mut ULONG,
+        args: CONST_APTR,
+    ) -> LONG {
+        unsafe {
+            crate::intuition::EasyRequestArgs(
+                get_IntuitionBase(),
+                window,
+                easyStruct,
+                idcmpPtr,
+                args,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn BuildEasyRequestArgs(
+        window: *mut Window,
+        easyStruct: *const EasyStruct,
+        idcmp: ULONG,
+        args: CONST_APTR,
+    ) -> *mut Window {
+        unsafe {
+            crate::intuition::BuildEasyRequestArgs(
+                get_IntuitionBase(),
+                window,
+                easyStruct,
+                idcmp,
+                args,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SysReqHandler(
+        window: *mut Window,
+        idcmpPtr: *mut ULONG,
+        waitInput: LONG,
+    ) -> LONG {
+        unsafe { crate::intuition::SysReqHandler(get_IntuitionBase(), window, idcmpPtr, waitInput) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenWindowTagList(
+        newWindow: *const NewWindow,
+        tagList: *const TagItem,
+    ) -> *mut Window {
+        unsafe { crate::intuition::OpenWindowTagList(get_IntuitionBase(), newWindow, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn OpenScreenTagList(
+        newScreen: *const NewScreen,
+        tagList: *const TagItem,
+    ) -> *mut Screen {
+        unsafe { crate::intuition::OpenScreenTagList(get_IntuitionBase(), newScreen, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DrawImageState(
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+        state: ULONG,
+        drawInfo: *mut DrawInfo,
+    ) {
+        unsafe {
+            crate::intuition::DrawImageState(
+                get_IntuitionBase(),
+                rp,
+                image,
+                leftOffset,
+                topOffset,
+                state,
+                drawInfo,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PointInImage(point: ULONG, image: *const Image) -> BOOL {
+        unsafe { crate::intuition::PointInImage(get_IntuitionBase(), point, image) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn EraseImage(
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe {
+            crate::intuition::EraseImage(get_IntuitionBase(), rp, image, leftOffset, topOffset)
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NewObjectA(
+        classPtr: *mut IClass,
+        classID: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> APTR {
+        unsafe { crate::intuition::NewObjectA(get_IntuitionBase(), classPtr, classID, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DisposeObject(object: APTR) {
+        unsafe { crate::intuition::DisposeObject(get_IntuitionBase(), object) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetAttrsA(object: APTR, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::intuition::SetAttrsA(get_IntuitionBase(), object, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetAttr(attrID: ULONG, object: APTR, storagePtr: *mut ULONG) -> ULONG {
+        unsafe { crate::intuition::GetAttr(get_IntuitionBase(), attrID, object, storagePtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetGadgetAttrsA(
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe {
+            crate::intuition::SetGadgetAttrsA(
+                get_IntuitionBase(),
+                gadget,
+                window,
+                requester,
+                tagList,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NextObject(objectPtrPtr: CONST_APTR) -> APTR {
+        unsafe { crate::intuition::NextObject(get_IntuitionBase(), objectPtrPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MakeClass(
+        classID: CONST_STRPTR,
+        superClassID: CONST_STRPTR,
+        superClassPtr: *const IClass,
+        instanceSize: ULONG,
+        flags: ULONG,
+    ) -> *mut IClass {
+        unsafe {
+            crate::intuition::MakeClass(
+                get_IntuitionBase(),
+                classID,
+                superClassID,
+                superClassPtr,
+                instanceSize,
+                flags,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddClass(classPtr: *mut IClass) {
+        unsafe { crate::intuition::AddClass(get_IntuitionBase(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetScreenDrawInfo(screen: *mut Screen) -> *mut DrawInfo {
+        unsafe { crate::intuition::GetScreenDrawInfo(get_IntuitionBase(), screen) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeScreenDrawInfo(screen: *mut Screen, drawInfo: *mut DrawInfo) {
+        unsafe { crate::intuition::FreeScreenDrawInfo(get_IntuitionBase(), screen, drawInfo) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ResetMenuStrip(window: *mut Window, menu: *mut Menu) -> BOOL {
+        unsafe { crate::intuition::ResetMenuStrip(get_IntuitionBase(), window, menu) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemoveClass(classPtr: *mut IClass) {
+        unsafe { crate::intuition::RemoveClass(get_IntuitionBase(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeClass(classPtr: *mut IClass) -> BOOL {
+        unsafe { crate::intuition::FreeClass(get_IntuitionBase(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocScreenBuffer(
+        sc: *mut Screen,
+        bm: *mut BitMap,
+        flags: ULONG,
+    ) -> *mut ScreenBuffer {
+        unsafe { crate::intuition::AllocScreenBuffer(get_IntuitionBase(), sc, bm, flags) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreeScreenBuffer(sc: *mut Screen, sb: *mut ScreenBuffer) {
+        unsafe { crate::intuition::FreeScreenBuffer(get_IntuitionBase(), sc, sb) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ChangeScreenBuffer(sc: *mut Screen, sb: *mut ScreenBuffer) -> ULONG {
+        unsafe { crate::intuition::ChangeScreenBuffer(get_IntuitionBase(), sc, sb) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ScreenDepth(screen: *mut Screen, flags: ULONG, reserved: APTR) {
+        unsafe { crate::intuition::ScreenDepth(get_IntuitionBase(), screen, flags, reserved) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ScreenPosition(
+        screen: *mut Screen,
+        flags: ULONG,
+        x1: LONG,
+        y1: LONG,
+        x2: LONG,
+        y2: LONG,
+    ) {
+        unsafe {
+            crate::intuition::ScreenPosition(get_IntuitionBase(), screen, flags, x1, y1, x2, y2)
+        }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ScrollWindowRaster(
+        win: *mut Window,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe {
+            crate::intuition::ScrollWindowRaster(
+                get_IntuitionBase(),
+                win,
+                dx,
+                dy,
+                xMin,
+                yMin,
+                xMax,
+                yMax,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn LendMenus(fromwindow: *mut Window, towindow: *mut Window) {
+        unsafe { crate::intuition::LendMenus(get_IntuitionBase(), fromwindow, towindow) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn DoGadgetMethodA(
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        message: Msg,
+    ) -> ULONG {
+        unsafe { crate::intuition::DoGadgetMethodA(get_IntuitionBase(), gad, win, req, message) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SetWindowPointerA(win: *mut Window, taglist: *const TagItem) {
+        unsafe { crate::intuition::SetWindowPointerA(get_IntuitionBase(), win, taglist) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn TimedDisplayAlert(
+        alertNumber: ULONG,
+        string: CONST_STRPTR,
+        height: ULONG,
+        time: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::intuition::TimedDisplayAlert(
+                get_IntuitionBase(),
+                alertNumber,
+                string,
+                height,
+                time,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn HelpControl(win: *mut Window, flags: ULONG) {
+        unsafe { crate::intuition::HelpControl(get_IntuitionBase(), win, flags) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ShowWindow(window: *mut Window, other: *mut Window) -> BOOL {
+        unsafe { crate::intuition::ShowWindow(get_IntuitionBase(), window, other) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn HideWindow(window: *mut Window) -> BOOL {
+        unsafe { crate::intuition::HideWindow(get_IntuitionBase(), window) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn IntuitionControlA(object: APTR, taglist: *const TagItem) -> ULONG {
+        unsafe { crate::intuition::IntuitionControlA(get_IntuitionBase(), object, taglist) }
+    }
+}
+
+#[cfg(feature = "keymap")]
+pub use keymap::*;
+
+#[cfg(feature = "keymap")]
+#[allow(unused_imports, dead_code)]
+mod keymap {
+    use super::*;
+
+    /// Global library base for "keymap.library"
+    pub static mut KeymapBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_KeymapBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut KeymapBase, c"keymap.library")
+            } else {
+                KeymapBase
+            }
+        }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SetKeyMapDefault(keyMap: *mut KeyMap) {
+        unsafe { crate::keymap::SetKeyMapDefault(get_KeymapBase(), keyMap) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AskKeyMapDefault() -> *mut KeyMap {
+        unsafe { crate::keymap::AskKeyMapDefault(get_KeymapBase()) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MapRawKey(
+        event: *const InputEvent,
+        buffer: STRPTR,
+        length: LONG,
+        keyMap: *const KeyMap,
+    ) -> WORD {
+        unsafe { crate::keymap::MapRawKey(get_KeymapBase(), event, buffer, length, keyMap) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MapANSI(
+        string: CONST_STRPTR,
+        count: LONG,
+        buffer: STRPTR,
+        length: LONG,
+        keyMap: *const KeyMap,
+    ) -> LONG {
+        unsafe { crate::keymap::MapANSI(get_KeymapBase(), string, count, buffer, length, keyMap) }
+    }
+}
+
+#[cfg(feature = "label-image")]
+pub use label_image::*;
+
+#[cfg(feature = "label-image")]
+#[allow(unused_imports, dead_code)]
+mod label_image {
+    use super::*;
+
+    /// Global library base for "label.image"
+    pub static mut LabelBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_LabelBase() -> *mut ::core::ffi::c_void {
+        unsafe { LabelBase }
+    }
+
+    /// libname: "label.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LABEL_GetClass() -> *mut Class {
+        unsafe { crate::label_image::LABEL_GetClass(get_LabelBase()) }
+    }
+}
+
+#[cfg(feature = "layers")]
+pub use layers::*;
+
+#[cfg(feature = "layers")]
+mod layers {
+    use super::*;
+
+    /// Global library base for "layers.library"
+    pub static mut LayersBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_LayersBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut LayersBase, c"layers.library")
+            } else {
+                LayersBase
+            }
+        }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn InitLayers(li: *mut Layer_Info) {
+        unsafe { crate::layers::InitLayers(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn CreateUpfrontLayer(
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            crate::layers::CreateUpfrontLayer(get_LayersBase(), li, bm, x0, y0, x1, y1, flags, bm2)
+        }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn CreateBehindLayer(
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            crate::layers::CreateBehindLayer(get_LayersBase(), li, bm, x0, y0, x1, y1, flags, bm2)
+        }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn UpfrontLayer(dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { crate::layers::UpfrontLayer(get_LayersBase(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn BehindLayer(dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { crate::layers::BehindLayer(get_LayersBase(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn MoveLayer(dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) -> LONG {
+        unsafe { crate::layers::MoveLayer(get_LayersBase(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn SizeLayer(dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) -> LONG {
+        unsafe { crate::layers::SizeLayer(get_LayersBase(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn ScrollLayer(dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) {
+        unsafe { crate::layers::ScrollLayer(get_LayersBase(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn BeginUpdate(l: *mut Layer) -> LONG {
+        unsafe { crate::layers::BeginUpdate(get_LayersBase(), l) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn EndUpdate(layer: *mut Layer, flag: ULONG) {
+        unsafe { crate::layers::EndUpdate(get_LayersBase(), layer, flag) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn DeleteLayer(dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { crate::layers::DeleteLayer(get_LayersBase(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn LockLayer(dummy: LONG, layer: *mut Layer) {
+        unsafe { crate::layers::LockLayer(get_LayersBase(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn UnlockLayer(layer: *mut Layer) {
+        unsafe { crate::layers::UnlockLayer(get_LayersBase(), layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn LockLayers(li: *mut Layer_Info) {
+        unsafe { crate::layers::LockLayers(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn UnlockLayers(li: *mut Layer_Info) {
+        unsafe { crate::layers::UnlockLayers(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn LockLayerInfo(li: *mut Layer_Info) {
+        unsafe { crate::layers::LockLayerInfo(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn SwapBitsRastPortClipRect(rp: *mut RastPort, cr: *mut ClipRect) {
+        unsafe { crate::layers::SwapBitsRastPortClipRect(get_LayersBase(), rp, cr) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn WhichLayer(li: *mut Layer_Info, x: LONG, y: LONG) -> *mut Layer {
+        unsafe { crate::layers::WhichLayer(get_LayersBase(), li, x, y) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn UnlockLayerInfo(li: *mut Layer_Info) {
+        unsafe { crate::layers::UnlockLayerInfo(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn NewLayerInfo() -> *mut Layer_Info {
+        unsafe { crate::layers::NewLayerInfo(get_LayersBase()) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn DisposeLayerInfo(li: *mut Layer_Info) {
+        unsafe { crate::layers::DisposeLayerInfo(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn FattenLayerInfo(li: *mut Layer_Info) -> LONG {
+        unsafe { crate::layers::FattenLayerInfo(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn ThinLayerInfo(li: *mut Layer_Info) {
+        unsafe { crate::layers::ThinLayerInfo(get_LayersBase(), li) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn MoveLayerInFrontOf(layer_to_move: *mut Layer, other_layer: *mut Layer) -> LONG {
+        unsafe { crate::layers::MoveLayerInFrontOf(get_LayersBase(), layer_to_move, other_layer) }
+    }
+
+    /// libname: "layers.library"
+    #[inline]
+    pub unsafe fn InstallClipRegion(layer: *mut Layer, region: *mut Region) -> *mut Region {
+        unsafe { crate::layers::InstallClipRegion(get_LayersBase(), layer, region) }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MoveSizeLayer(layer: *mut Layer, dx: LONG, dy: LONG, dw: LONG, dh: LONG) -> LONG {
+        unsafe { crate::layers::MoveSizeLayer(get_LayersBase(), layer, dx, dy, dw, dh) }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateUpfrontHookLayer(
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        hook: *mut Hook,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            crate::layers::CreateUpfrontHookLayer(
+                get_LayersBase(),
+                li,
+                bm,
+                x0,
+                y0,
+                x1,
+                y1,
+                flags,
+                hook,
+                bm2,
+            )
+        }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateBehindHookLayer(
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        hook: *mut Hook,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            crate::layers::CreateBehindHookLayer(
+                get_LayersBase(),
+                li,
+                bm,
+                x0,
+                y0,
+                x1,
+                y1,
+                flags,
+                hook,
+                bm2,
+            )
+        }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn InstallLayerHook(layer: *mut Layer, hook: *mut Hook) -> *mut Hook {
+        unsafe { crate::layers::InstallLayerHook(get_LayersBase(), layer, hook) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn InstallLayerInfoHook(li: *mut Layer_Info, hook: *mut Hook) -> *mut Hook {
+        unsafe { crate::layers::InstallLayerInfoHook(get_LayersBase(), li, hook) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SortLayerCR(layer: *mut Layer, dx: LONG, dy: LONG) {
+        unsafe { crate::layers::SortLayerCR(get_LayersBase(), layer, dx, dy) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn DoHookClipRects(hook: *mut Hook, rport: *mut RastPort, rect: *const Rectangle) {
+        unsafe { crate::layers::DoHookClipRects(get_LayersBase(), hook, rport, rect) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn LayerOccluded(layer: *mut Layer) -> BOOL {
+        unsafe { crate::layers::LayerOccluded(get_LayersBase(), layer) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn HideLayer(layer: *mut Layer) -> LONG {
+        unsafe { crate::layers::HideLayer(get_LayersBase(), layer) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn ShowLayer(layer: *mut Layer, in_front_of: *mut Layer) -> LONG {
+        unsafe { crate::layers::ShowLayer(get_LayersBase(), layer, in_front_of) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SetLayerInfoBounds(li: *mut Layer_Info, bounds: *const Rectangle) -> BOOL {
+        unsafe { crate::layers::SetLayerInfoBounds(get_LayersBase(), li, bounds) }
+    }
+}
+
+#[cfg(feature = "layout-gadget")]
+pub use layout_gadget::*;
+
+#[cfg(feature = "layout-gadget")]
+#[allow(unused_imports, dead_code)]
+mod layout_gadget {
+    use super::*;
+
+    /// Global library base for "layout.gadget"
+    pub static mut LayoutBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_LayoutBase() -> *mut ::core::ffi::c_void {
+        unsafe { LayoutBase }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LAYOUT_GetClass() -> *mut Class {
+        unsafe { crate::layout_gadget::LAYOUT_GetClass(get_LayoutBase()) }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ActivateLayoutGadget(
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        object: ULONG,
+    ) -> BOOL {
+        unsafe {
+            crate::layout_gadget::ActivateLayoutGadget(
+                get_LayoutBase(),
+                gadget,
+                window,
+                requester,
+                object,
+            )
+        }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FlushLayoutDomainCache(gadget: *mut Gadget) {
+        unsafe { crate::layout_gadget::FlushLayoutDomainCache(get_LayoutBase(), gadget) }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RethinkLayout(
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        refresh: LONG,
+    ) -> BOOL {
+        unsafe {
+            crate::layout_gadget::RethinkLayout(
+                get_LayoutBase(),
+                gadget,
+                window,
+                requester,
+                refresh,
+            )
+        }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LayoutLimits(
+        gadget: *mut Gadget,
+        limits: *mut LayoutLimits,
+        font: *mut TextFont,
+        screen: *mut Screen,
+    ) {
+        unsafe {
+            crate::layout_gadget::LayoutLimits(get_LayoutBase(), gadget, limits, font, screen)
+        }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn PAGE_GetClass() -> *mut Class {
+        unsafe { crate::layout_gadget::PAGE_GetClass(get_LayoutBase()) }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetPageGadgetAttrsA(
+        gadget: *mut Gadget,
+        object: *mut Object,
+        window: *mut Window,
+        requester: *mut Requester,
+        tags: *mut TagItem,
+    ) -> ULONG {
+        unsafe {
+            crate::layout_gadget::SetPageGadgetAttrsA(
+                get_LayoutBase(),
+                gadget,
+                object,
+                window,
+                requester,
+                tags,
+            )
+        }
+    }
+
+    /// libname: "layout.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RefreshPageGadget(
+        gadget: *mut Gadget,
+        object: *mut Object,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe {
+            crate::layout_gadget::RefreshPageGadget(
+                get_LayoutBase(),
+                gadget,
+                object,
+                window,
+                requester,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "listbrowser-gadget")]
+pub use listbrowser_gadget::*;
+
+#[cfg(feature = "listbrowser-gadget")]
+#[allow(unused_imports, dead_code)]
+mod listbrowser_gadget {
+    use super::*;
+
+    /// Global library base for "listbrowser.gadget"
+    pub static mut ListBrowserBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ListBrowserBase() -> *mut ::core::ffi::c_void {
+        unsafe { ListBrowserBase }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LISTBROWSER_GetClass() -> *mut Class {
+        unsafe { crate::listbrowser_gadget::LISTBROWSER_GetClass(get_ListBrowserBase()) }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AllocListBrowserNodeA(columns: ULONG, tags: *mut TagItem) -> *mut Node {
+        unsafe {
+            crate::listbrowser_gadget::AllocListBrowserNodeA(get_ListBrowserBase(), columns, tags)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeListBrowserNode(node: *mut Node) {
+        unsafe { crate::listbrowser_gadget::FreeListBrowserNode(get_ListBrowserBase(), node) }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetListBrowserNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe {
+            crate::listbrowser_gadget::SetListBrowserNodeAttrsA(get_ListBrowserBase(), node, tags)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetListBrowserNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe {
+            crate::listbrowser_gadget::GetListBrowserNodeAttrsA(get_ListBrowserBase(), node, tags)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ListBrowserSelectAll(list: *mut List) {
+        unsafe { crate::listbrowser_gadget::ListBrowserSelectAll(get_ListBrowserBase(), list) }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ShowListBrowserNodeChildren(node: *mut Node, depth: LONG) {
+        unsafe {
+            crate::listbrowser_gadget::ShowListBrowserNodeChildren(
+                get_ListBrowserBase(),
+                node,
+                depth,
+            )
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn HideListBrowserNodeChildren(node: *mut Node) {
+        unsafe {
+            crate::listbrowser_gadget::HideListBrowserNodeChildren(get_ListBrowserBase(), node)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ShowAllListBrowserChildren(list: *mut List) {
+        unsafe {
+            crate::listbrowser_gadget::ShowAllListBrowserChildren(get_ListBrowserBase(), list)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn HideAllListBrowserChildren(list: *mut List) {
+        unsafe {
+            crate::listbrowser_gadget::HideAllListBrowserChildren(get_ListBrowserBase(), list)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeListBrowserList(list: *mut List) {
+        unsafe { crate::listbrowser_gadget::FreeListBrowserList(get_ListBrowserBase(), list) }
+    }
+
+    /// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn AllocLBColumnInfoA(columns: ULONG, tags: *mut TagItem) -> *mut ColumnInfo {
+        unsafe {
+            crate::listbrowser_gadget::AllocLBColumnInfoA(get_ListBrowserBase(), columns, tags)
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn SetLBColumnInfoAttrsA(columninfo: *mut ColumnInfo, tags: *mut TagItem) -> LONG {
+        unsafe {
+            crate::listbrowser_gadget::SetLBColumnInfoAttrsA(
+                get_ListBrowserBase(),
+                columninfo,
+                tags,
+            )
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn GetLBColumnInfoAttrsA(columninfo: *mut ColumnInfo, tags: *mut TagItem) -> LONG {
+        unsafe {
+            crate::listbrowser_gadget::GetLBColumnInfoAttrsA(
+                get_ListBrowserBase(),
+                columninfo,
+                tags,
+            )
+        }
+    }
+
+    /// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn FreeLBColumnInfo(columninfo: *mut ColumnInfo) {
+        unsafe { crate::listbrowser_gadget::FreeLBColumnInfo(get_ListBrowserBase(), columninfo) }
+    }
+
+    /// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+    #[cfg(not(feature = "kickstart-v44"))]
+    #[inline]
+    pub unsafe fn ListBrowserClearAll(list: *mut List) {
+        unsafe { crate::listbrowser_gadget::ListBrowserClearAll(get_ListBrowserBase(), list) }
+    }
+}
+
+#[cfg(feature = "listview-gadget")]
+pub use listview_gadget::*;
+
+#[cfg(feature = "listview-gadget")]
+#[allow(unused_imports, dead_code)]
+mod listview_gadget {
+    use super::*;
+
+    /// Global library base for "listview.gadget"
+    pub static mut ListViewBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ListViewBase() -> *mut ::core::ffi::c_void {
+        unsafe { ListViewBase }
+    }
+
+    /// libname: "listview.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn LISTVIEW_GetClass() -> *mut Class {
+        unsafe { crate::listview_gadget::LISTVIEW_GetClass(get_ListViewBase()) }
+    }
+}
+
+#[cfg(feature = "locale")]
+pub use locale::*;
+
+#[cfg(feature = "locale")]
+#[allow(unused_imports, dead_code)]
+mod locale {
+    use super::*;
+
+    /// Global library base for "locale.library"
+    pub static mut LocaleBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_LocaleBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut LocaleBase, c"locale.library")
+            } else {
+                LocaleBase
+            }
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn CloseCatalog(catalog: *mut Catalog) {
+        unsafe { crate::locale::CloseCatalog(get_LocaleBase(), catalog) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn CloseLocale(locale: *mut Locale) {
+        unsafe { crate::locale::CloseLocale(get_LocaleBase(), locale) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn ConvToLower(locale: *mut Locale, character: ULONG) -> ULONG {
+        unsafe { crate::locale::ConvToLower(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn ConvToUpper(locale: *mut Locale, character: ULONG) -> ULONG {
+        unsafe { crate::locale::ConvToUpper(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn FormatDate(
+        locale: *mut Locale,
+        fmtTemplate: CONST_STRPTR,
+        date: *const DateStamp,
+        putCharFunc: *mut Hook,
+    ) {
+        unsafe {
+            crate::locale::FormatDate(get_LocaleBase(), locale, fmtTemplate, date, putCharFunc)
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn FormatString(
+        locale: *mut Locale,
+        fmtTemplate: CONST_STRPTR,
+        dataStream: APTR,
+        putCharFunc: *mut Hook,
+    ) -> APTR {
+        unsafe {
+            crate::locale::FormatString(
+                get_LocaleBase(),
+                locale,
+                fmtTemplate,
+                dataStream,
+                putCharFunc,
+            )
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn GetCatalogStr(
+        catalog: *const Catalog,
+        stringNum: LONG,
+        defaultString: CONST_STRPTR,
+    ) -> STRPTR {
+        unsafe { crate::locale::GetCatalogStr(get_LocaleBase(), catalog, stringNum, defaultString) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn GetLocaleStr(locale: *mut Locale, stringNum: ULONG) -> STRPTR {
+        unsafe { crate::locale::GetLocaleStr(get_LocaleBase(), locale, stringNum) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsAlNum(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsAlNum(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsAlpha(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsAlpha(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsCntrl(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsCntrl(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsDigit(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsDigit(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsGraph(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsGraph(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsLower(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsLower(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsPrint(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsPrint(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsPunct(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsPunct(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsSpace(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsSpace(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsUpper(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsUpper(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn IsXDigit(locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { crate::locale::IsXDigit(get_LocaleBase(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn OpenCatalogA(
+        locale: *mut Locale,
+        name: CONST_STRPTR,
+        tags: *const TagItem,
+    ) -> *mut Catalog {
+        unsafe { crate::locale::OpenCatalogA(get_LocaleBase(), locale, name, tags) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn OpenLocale(name: CONST_STRPTR) -> *mut Locale {
+        unsafe { crate::locale::OpenLocale(get_LocaleBase(), name) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn ParseDate(
+        locale: *const Locale,
+        date: *mut DateStamp,
+        fmtTemplate: CONST_STRPTR,
+        getCharFunc: *mut Hook,
+    ) -> BOOL {
+        unsafe {
+            crate::locale::ParseDate(get_LocaleBase(), locale, date, fmtTemplate, getCharFunc)
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn StrConvert(
+        locale: *mut Locale,
+        string: CONST_STRPTR,
+        buffer: APTR,
+        bufferSize: ULONG,
+        type_: ULONG,
+    ) -> ULONG {
+        unsafe {
+            crate::locale::StrConvert(get_LocaleBase(), locale, string, buffer, bufferSize, type_)
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    #[inline]
+    pub unsafe fn StrnCmp(
+        locale: *mut Locale,
+        string1: CONST_STRPTR,
+        string2: CONST_STRPTR,
+        length: LONG,
+        type_: ULONG,
+    ) -> LONG {
+        unsafe { crate::locale::StrnCmp(get_LocaleBase(), locale, string1, string2, length, type_) }
+    }
+}
+
+#[cfg(feature = "lowlevel")]
+pub use lowlevel::*;
+
+#[cfg(feature = "lowlevel")]
+#[allow(unused_imports, dead_code)]
+mod lowlevel {
+    use super::*;
+
+    /// Global library base for "lowlevel.library"
+    pub static mut LowLevelBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_LowLevelBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut LowLevelBase, c"lowlevel.library")
+            } else {
+                LowLevelBase
+            }
+        }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ReadJoyPort(port: ULONG) -> ULONG {
+        unsafe { crate::lowlevel::ReadJoyPort(get_LowLevelBase(), port) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetLanguageSelection() -> UBYTE {
+        unsafe { crate::lowlevel::GetLanguageSelection(get_LowLevelBase()) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetKey() -> ULONG {
+        unsafe { crate::lowlevel::GetKey(get_LowLevelBase()) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn QueryKeys(queryArray: *mut KeyQuery, arraySize: LONG) {
+        unsafe { crate::lowlevel::QueryKeys(get_LowLevelBase(), queryArray, arraySize) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AddKBInt(intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { crate::lowlevel::AddKBInt(get_LowLevelBase(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RemKBInt(intHandle: APTR) {
+        unsafe { crate::lowlevel::RemKBInt(get_LowLevelBase(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SystemControlA(tagList: *const TagItem) -> ULONG {
+        unsafe { crate::lowlevel::SystemControlA(get_LowLevelBase(), tagList) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AddTimerInt(intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { crate::lowlevel::AddTimerInt(get_LowLevelBase(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RemTimerInt(intHandle: APTR) {
+        unsafe { crate::lowlevel::RemTimerInt(get_LowLevelBase(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn StopTimerInt(intHandle: APTR) {
+        unsafe { crate::lowlevel::StopTimerInt(get_LowLevelBase(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn StartTimerInt(intHandle: APTR, timeInterval: ULONG, continuous: LONG) {
+        unsafe {
+            crate::lowlevel::StartTimerInt(get_LowLevelBase(), intHandle, timeInterval, continuous)
+        }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn ElapsedTime(context: *mut EClockVal) -> ULONG {
+        unsafe { crate::lowlevel::ElapsedTime(get_LowLevelBase(), context) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AddVBlankInt(intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { crate::lowlevel::AddVBlankInt(get_LowLevelBase(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RemVBlankInt(intHandle: APTR) {
+        unsafe { crate::lowlevel::RemVBlankInt(get_LowLevelBase(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetJoyPortAttrsA(portNumber: ULONG, tagList: *const TagItem) -> BOOL {
+        unsafe { crate::lowlevel::SetJoyPortAttrsA(get_LowLevelBase(), portNumber, tagList) }
+    }
+}
+
+#[cfg(feature = "mathffp")]
+pub use mathffp::*;
+
+#[cfg(feature = "mathffp")]
+mod mathffp {
+    use super::*;
+
+    /// Global library base for "mathffp.library"
+    pub static mut MathBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathBase, c"mathffp.library")
+            } else {
+                MathBase
+            }
+        }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPFix(parm: FLOAT) -> LONG {
+        unsafe { crate::mathffp::SPFix(get_MathBase(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPFlt(integer: LONG) -> FLOAT {
+        unsafe { crate::mathffp::SPFlt(get_MathBase(), integer) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPCmp(leftParm: FLOAT, rightParm: FLOAT) -> LONG {
+        unsafe { crate::mathffp::SPCmp(get_MathBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPTst(parm: FLOAT) -> LONG {
+        unsafe { crate::mathffp::SPTst(get_MathBase(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPAbs(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPAbs(get_MathBase(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPNeg(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPNeg(get_MathBase(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPAdd(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPAdd(get_MathBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPSub(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPSub(get_MathBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPMul(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPMul(get_MathBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    #[inline]
+    pub unsafe fn SPDiv(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPDiv(get_MathBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn SPFloor(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPFloor(get_MathBase(), parm) }
+    }
+
+    /// libname: "mathffp.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn SPCeil(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathffp::SPCeil(get_MathBase(), parm) }
+    }
+}
+
+#[cfg(feature = "mathieeedoubbas")]
+pub use mathieeedoubbas::*;
+
+#[cfg(feature = "mathieeedoubbas")]
+mod mathieeedoubbas {
+    use super::*;
+
+    /// Global library base for "mathieeedoubbas.library"
+    pub static mut MathIeeeDoubBasBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathIeeeDoubBasBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathIeeeDoubBasBase, c"mathieeedoubbas.library")
+            } else {
+                MathIeeeDoubBasBase
+            }
+        }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPFix(parm: DOUBLE) -> LONG {
+        unsafe { crate::mathieeedoubbas::IEEEDPFix(get_MathIeeeDoubBasBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPFlt(integer: LONG) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPFlt(get_MathIeeeDoubBasBase(), integer) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPCmp(leftParm: DOUBLE, rightParm: DOUBLE) -> LONG {
+        unsafe { crate::mathieeedoubbas::IEEEDPCmp(get_MathIeeeDoubBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPTst(parm: DOUBLE) -> LONG {
+        unsafe { crate::mathieeedoubbas::IEEEDPTst(get_MathIeeeDoubBasBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPAbs(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPAbs(get_MathIeeeDoubBasBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPNeg(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPNeg(get_MathIeeeDoubBasBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPAdd(leftParm: DOUBLE, rightParm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPAdd(get_MathIeeeDoubBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPSub(leftParm: DOUBLE, rightParm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPSub(get_MathIeeeDoubBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPMul(factor1: DOUBLE, factor2: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPMul(get_MathIeeeDoubBasBase(), factor1, factor2) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    #[inline]
+    pub unsafe fn IEEEDPDiv(dividend: DOUBLE, divisor: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPDiv(get_MathIeeeDoubBasBase(), dividend, divisor) }
+    }
+
+    /// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn IEEEDPFloor(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPFloor(get_MathIeeeDoubBasBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+    #[inline]
+    pub unsafe fn IEEEDPCeil(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubbas::IEEEDPCeil(get_MathIeeeDoubBasBase(), parm) }
+    }
+}
+
+#[cfg(feature = "mathieeedoubtrans")]
+pub use mathieeedoubtrans::*;
+
+#[cfg(feature = "mathieeedoubtrans")]
+mod mathieeedoubtrans {
+    use super::*;
+
+    /// Global library base for "mathieeedoubtrans.library"
+    pub static mut MathIeeeDoubTransBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathIeeeDoubTransBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathIeeeDoubTransBase, c"mathieeedoubtrans.library")
+            } else {
+                MathIeeeDoubTransBase
+            }
+        }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPAtan(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPAtan(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPSin(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPSin(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPCos(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPCos(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPTan(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPTan(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPSincos(pf2: *mut DOUBLE, parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPSincos(get_MathIeeeDoubTransBase(), pf2, parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPSinh(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPSinh(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPCosh(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPCosh(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPTanh(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPTanh(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPExp(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPExp(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPLog(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPLog(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPPow(SPExp: DOUBLE, arg: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPPow(get_MathIeeeDoubTransBase(), SPExp, arg) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPSqrt(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPSqrt(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPTieee(parm: DOUBLE) -> FLOAT {
+        unsafe { crate::mathieeedoubtrans::IEEEDPTieee(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPFieee(single: FLOAT) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPFieee(get_MathIeeeDoubTransBase(), single) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPAsin(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPAsin(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPAcos(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPAcos(get_MathIeeeDoubTransBase(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    #[inline]
+    pub unsafe fn IEEEDPLog10(parm: DOUBLE) -> DOUBLE {
+        unsafe { crate::mathieeedoubtrans::IEEEDPLog10(get_MathIeeeDoubTransBase(), parm) }
+    }
+}
+
+#[cfg(feature = "mathieeesingbas")]
+pub use mathieeesingbas::*;
+
+#[cfg(feature = "mathieeesingbas")]
+#[allow(unused_imports, dead_code)]
+mod mathieeesingbas {
+    use super::*;
+
+    /// Global library base for "mathieeesingbas.library"
+    pub static mut MathIeeeSingBasBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathIeeeSingBasBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathIeeeSingBasBase, c"mathieeesingbas.library")
+            } else {
+                MathIeeeSingBasBase
+            }
+        }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPFix(parm: FLOAT) -> LONG {
+        unsafe { crate::mathieeesingbas::IEEESPFix(get_MathIeeeSingBasBase(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPFlt(integer: LONG) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPFlt(get_MathIeeeSingBasBase(), integer) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPCmp(leftParm: FLOAT, rightParm: FLOAT) -> LONG {
+        unsafe { crate::mathieeesingbas::IEEESPCmp(get_MathIeeeSingBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPTst(parm: FLOAT) -> LONG {
+        unsafe { crate::mathieeesingbas::IEEESPTst(get_MathIeeeSingBasBase(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPAbs(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPAbs(get_MathIeeeSingBasBase(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPNeg(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPNeg(get_MathIeeeSingBasBase(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPAdd(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPAdd(get_MathIeeeSingBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPSub(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPSub(get_MathIeeeSingBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPMul(leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPMul(get_MathIeeeSingBasBase(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPDiv(dividend: FLOAT, divisor: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPDiv(get_MathIeeeSingBasBase(), dividend, divisor) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPFloor(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPFloor(get_MathIeeeSingBasBase(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPCeil(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingbas::IEEESPCeil(get_MathIeeeSingBasBase(), parm) }
+    }
+}
+
+#[cfg(feature = "mathieeesingtrans")]
+pub use mathieeesingtrans::*;
+
+#[cfg(feature = "mathieeesingtrans")]
+#[allow(unused_imports, dead_code)]
+mod mathieeesingtrans {
+    use super::*;
+
+    /// Global library base for "mathieeesingtrans.library"
+    pub static mut MathIeeeSingTransBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathIeeeSingTransBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathIeeeSingTransBase, c"mathieeesingtrans.library")
+            } else {
+                MathIeeeSingTransBase
+            }
+        }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPAtan(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPAtan(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPSin(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPSin(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPCos(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPCos(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPTan(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPTan(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPSincos(cosptr: *mut FLOAT, parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPSincos(get_MathIeeeSingTransBase(), cosptr, parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPSinh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPSinh(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPCosh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPCosh(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPTanh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPTanh(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPExp(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPExp(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPLog(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPLog(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPPow(SPExp: FLOAT, arg: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPPow(get_MathIeeeSingTransBase(), SPExp, arg) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPSqrt(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPSqrt(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPTieee(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPTieee(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPFieee(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPFieee(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPAsin(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPAsin(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPAcos(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPAcos(get_MathIeeeSingTransBase(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IEEESPLog10(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathieeesingtrans::IEEESPLog10(get_MathIeeeSingTransBase(), parm) }
+    }
+}
+
+#[cfg(feature = "mathtrans")]
+pub use mathtrans::*;
+
+#[cfg(feature = "mathtrans")]
+mod mathtrans {
+    use super::*;
+
+    /// Global library base for "mathtrans.library"
+    pub static mut MathTransBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MathTransBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut MathTransBase, c"mathtrans.library")
+            } else {
+                MathTransBase
+            }
+        }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPAtan(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPAtan(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPSin(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPSin(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPCos(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPCos(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPTan(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPTan(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPSincos(cosResult: *mut FLOAT, parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPSincos(get_MathTransBase(), cosResult, parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPSinh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPSinh(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPCosh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPCosh(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPTanh(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPTanh(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPExp(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPExp(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPLog(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPLog(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPPow(power: FLOAT, arg: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPPow(get_MathTransBase(), power, arg) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPSqrt(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPSqrt(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPTieee(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPTieee(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    #[inline]
+    pub unsafe fn SPFieee(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPFieee(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    #[inline]
+    pub unsafe fn SPAsin(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPAsin(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    #[inline]
+    pub unsafe fn SPAcos(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPAcos(get_MathTransBase(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    #[inline]
+    pub unsafe fn SPLog10(parm: FLOAT) -> FLOAT {
+        unsafe { crate::mathtrans::SPLog10(get_MathTransBase(), parm) }
+    }
+}
+
+#[cfg(feature = "misc-resource")]
+pub use misc_resource::*;
+
+#[cfg(feature = "misc-resource")]
+mod misc_resource {
+    use super::*;
+
+    /// Global library base for "misc.resource"
+    pub static mut MiscBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_MiscBase() -> *mut ::core::ffi::c_void {
+        unsafe { MiscBase }
+    }
+
+    /// libname: "misc.resource"
+    #[inline]
+    pub unsafe fn AllocMiscResource(unitNum: ULONG, name: CONST_STRPTR) -> *mut UBYTE {
+        unsafe { crate::misc_resource::AllocMiscResource(get_MiscBase(), unitNum, name) }
+    }
+
+    /// libname: "misc.resource"
+    #[inline]
+    pub unsafe fn FreeMiscResource(unitNum: ULONG) {
+        unsafe { crate::misc_resource::FreeMiscResource(get_MiscBase(), unitNum) }
+    }
+}
+
+#[cfg(feature = "nonvolatile")]
+pub use nonvolatile::*;
+
+#[cfg(feature = "nonvolatile")]
+#[allow(unused_imports, dead_code)]
+mod nonvolatile {
+    use super::*;
+
+    /// Global library base for "nonvolatile.library"
+    pub static mut NVBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_NVBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut NVBase, c"nonvolatile.library")
+            } else {
+                NVBase
+            }
+        }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetCopyNV(
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        killRequesters: LONG,
+    ) -> APTR {
+        unsafe { crate::nonvolatile::GetCopyNV(get_NVBase(), appName, itemName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeNVData(data: APTR) {
+        unsafe { crate::nonvolatile::FreeNVData(get_NVBase(), data) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn StoreNV(
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        data: CONST_APTR,
+        length: ULONG,
+        killRequesters: LONG,
+    ) -> UWORD {
+        unsafe {
+            crate::nonvolatile::StoreNV(
+                get_NVBase(),
+                appName,
+                itemName,
+                data,
+                length,
+                killRequesters,
+            )
+        }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn DeleteNV(
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        killRequesters: LONG,
+    ) -> BOOL {
+        unsafe { crate::nonvolatile::DeleteNV(get_NVBase(), appName, itemName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetNVInfo(killRequesters: LONG) -> *mut NVInfo {
+        unsafe { crate::nonvolatile::GetNVInfo(get_NVBase(), killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetNVList(appName: CONST_STRPTR, killRequesters: LONG) -> *mut MinList {
+        unsafe { crate::nonvolatile::GetNVList(get_NVBase(), appName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetNVProtection(
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        mask: LONG,
+        killRequesters: LONG,
+    ) -> BOOL {
+        unsafe {
+            crate::nonvolatile::SetNVProtection(
+                get_NVBase(),
+                appName,
+                itemName,
+                mask,
+                killRequesters,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "palette-gadget")]
+pub use palette_gadget::*;
+
+#[cfg(feature = "palette-gadget")]
+#[allow(unused_imports, dead_code)]
+mod palette_gadget {
+    use super::*;
+
+    /// Global library base for "palette.gadget"
+    pub static mut PaletteBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_PaletteBase() -> *mut ::core::ffi::c_void {
+        unsafe { PaletteBase }
+    }
+
+    /// libname: "palette.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn PALETTE_GetClass() -> *mut Class {
+        unsafe { crate::palette_gadget::PALETTE_GetClass(get_PaletteBase()) }
+    }
+}
+
+#[cfg(feature = "penmap-image")]
+pub use penmap_image::*;
+
+#[cfg(feature = "penmap-image")]
+#[allow(unused_imports, dead_code)]
+mod penmap_image {
+    use super::*;
+
+    /// Global library base for "penmap.image"
+    pub static mut PenMapBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_PenMapBase() -> *mut ::core::ffi::c_void {
+        unsafe { PenMapBase }
+    }
+
+    /// libname: "penmap.image" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn PENMAP_GetClass() -> *mut Class {
+        unsafe { crate::penmap_image::PENMAP_GetClass(get_PenMapBase()) }
+    }
+}
+
+#[cfg(feature = "potgo-resource")]
+pub use potgo_resource::*;
+
+#[cfg(feature = "potgo-resource")]
+mod potgo_resource {
+    use super::*;
+
+    /// Global library base for "potgo.resource"
+    pub static mut PotgoBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_PotgoBase() -> *mut ::core::ffi::c_void {
+        unsafe { PotgoBase }
+    }
+
+    /// libname: "potgo.resource"
+    #[inline]
+    pub unsafe fn AllocPotBits(bits: ULONG) -> UWORD {
+        unsafe { crate::potgo_resource::AllocPotBits(get_PotgoBase(), bits) }
+    }
+
+    /// libname: "potgo.resource"
+    #[inline]
+    pub unsafe fn FreePotBits(bits: ULONG) {
+        unsafe { crate::potgo_resource::FreePotBits(get_PotgoBase(), bits) }
+    }
+
+    /// libname: "potgo.resource"
+    #[inline]
+    pub unsafe fn WritePotgo(word: ULONG, mask: ULONG) {
+        unsafe { crate::potgo_resource::WritePotgo(get_PotgoBase(), word, mask) }
+    }
+}
+
+#[cfg(feature = "radiobutton-gadget")]
+pub use radiobutton_gadget::*;
+
+#[cfg(feature = "radiobutton-gadget")]
+#[allow(unused_imports, dead_code)]
+mod radiobutton_gadget {
+    use super::*;
+
+    /// Global library base for "radiobutton.gadget"
+    pub static mut RadioButtonBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_RadioButtonBase() -> *mut ::core::ffi::c_void {
+        unsafe { RadioButtonBase }
+    }
+
+    /// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RADIOBUTTON_GetClass() -> *mut Class {
+        unsafe { crate::radiobutton_gadget::RADIOBUTTON_GetClass(get_RadioButtonBase()) }
+    }
+
+    /// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AllocRadioButtonNodeA(columns: ULONG, tags: *mut TagItem) -> *mut Node {
+        unsafe {
+            crate::radiobutton_gadget::AllocRadioButtonNodeA(get_RadioButtonBase(), columns, tags)
+        }
+    }
+
+    /// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeRadioButtonNode(node: *mut Node) {
+        unsafe { crate::radiobutton_gadget::FreeRadioButtonNode(get_RadioButtonBase(), node) }
+    }
+
+    /// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetRadioButtonNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe {
+            crate::radiobutton_gadget::SetRadioButtonNodeAttrsA(get_RadioButtonBase(), node, tags)
+        }
+    }
+
+    /// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetRadioButtonNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe {
+            crate::radiobutton_gadget::GetRadioButtonNodeAttrsA(get_RadioButtonBase(), node, tags)
+        }
+    }
+}
+
+#[cfg(feature = "ramdrive-device")]
+pub use ramdrive_device::*;
+
+#[cfg(feature = "ramdrive-device")]
+#[allow(unused_imports, dead_code)]
+mod ramdrive_device {
+    use super::*;
+
+    /// Global library base for "ramdrive.device"
+    pub static mut RamdriveDevice: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_RamdriveDevice() -> *mut ::core::ffi::c_void {
+        unsafe { RamdriveDevice }
+    }
+
+    /// libname: "ramdrive.device" (V34) (Kickstart 1.3)
+    #[cfg(not(feature = "kickstart-v33"))]
+    #[inline]
+    pub unsafe fn KillRAD0() -> STRPTR {
+        unsafe { crate::ramdrive_device::KillRAD0(get_RamdriveDevice()) }
+    }
+
+    /// libname: "ramdrive.device" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn KillRAD(unit: ULONG) -> STRPTR {
+        unsafe { crate::ramdrive_device::KillRAD(get_RamdriveDevice(), unit) }
+    }
+}
+
+#[cfg(feature = "realtime")]
+pub use realtime::*;
+
+#[cfg(feature = "realtime")]
+#[allow(unused_imports, dead_code)]
+mod realtime {
+    use super::*;
+
+    /// Global library base for "realtime.library"
+    pub static mut RealTimeBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_RealTimeBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut RealTimeBase, c"realtime.library")
+            } else {
+                RealTimeBase
+            }
+        }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn LockRealTime(lockType: ULONG) -> APTR {
+        unsafe { crate::realtime::LockRealTime(get_RealTimeBase(), lockType) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn UnlockRealTime(lock: APTR) {
+        unsafe { crate::realtime::UnlockRealTime(get_RealTimeBase(), lock) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn CreatePlayerA(tagList: *const TagItem) -> *mut Player {
+        unsafe { crate::realtime::CreatePlayerA(get_RealTimeBase(), tagList) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn DeletePlayer(player: *mut Player) {
+        unsafe { crate::realtime::DeletePlayer(get_RealTimeBase(), player) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn SetPlayerAttrsA(player: *mut Player, tagList: *const TagItem) -> BOOL {
+        unsafe { crate::realtime::SetPlayerAttrsA(get_RealTimeBase(), player, tagList) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn SetConductorState(player: *mut Player, state: ULONG, time: LONG) -> LONG {
+        unsafe { crate::realtime::SetConductorState(get_RealTimeBase(), player, state, time) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn ExternalSync(player: *mut Player, minTime: LONG, maxTime: LONG) -> BOOL {
+        unsafe { crate::realtime::ExternalSync(get_RealTimeBase(), player, minTime, maxTime) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn NextConductor(previousConductor: *const Conductor) -> *mut Conductor {
+        unsafe { crate::realtime::NextConductor(get_RealTimeBase(), previousConductor) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn FindConductor(name: CONST_STRPTR) -> *mut Conductor {
+        unsafe { crate::realtime::FindConductor(get_RealTimeBase(), name) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn GetPlayerAttrsA(player: *mut Player, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::realtime::GetPlayerAttrsA(get_RealTimeBase(), player, tagList) }
+    }
+}
+
+#[cfg(feature = "requester-class")]
+pub use requester_class::*;
+
+#[cfg(feature = "requester-class")]
+#[allow(unused_imports, dead_code)]
+mod requester_class {
+    use super::*;
+
+    /// Global library base for "requester.class"
+    pub static mut RequesterBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_RequesterBase() -> *mut ::core::ffi::c_void {
+        unsafe { RequesterBase }
+    }
+
+    /// libname: "requester.class" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn REQUESTER_GetClass() -> *mut Class {
+        unsafe { crate::requester_class::REQUESTER_GetClass(get_RequesterBase()) }
+    }
+}
+
+#[cfg(feature = "rexxsyslib")]
+pub use rexxsyslib::*;
+
+#[cfg(feature = "rexxsyslib")]
+#[allow(unused_imports, dead_code)]
+mod rexxsyslib {
+    use super::*;
+
+    /// Global library base for "rexxsyslib.library"
+    pub static mut RexxSysBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_RexxSysBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut RexxSysBase, c"rexxsyslib.library")
+            } else {
+                RexxSysBase
+            }
+        }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateArgstring(string: CONST_STRPTR, length: ULONG) -> *mut UBYTE {
+        unsafe { crate::rexxsyslib::CreateArgstring(get_RexxSysBase(), string, length) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteArgstring(argstring: *mut UBYTE) {
+        unsafe { crate::rexxsyslib::DeleteArgstring(get_RexxSysBase(), argstring) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LengthArgstring(argstring: *const UBYTE) -> ULONG {
+        unsafe { crate::rexxsyslib::LengthArgstring(get_RexxSysBase(), argstring) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CreateRexxMsg(
+        port: *mut MsgPort,
+        extension: CONST_STRPTR,
+        host: CONST_STRPTR,
+    ) -> *mut RexxMsg {
+        unsafe { crate::rexxsyslib::CreateRexxMsg(get_RexxSysBase(), port, extension, host) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn DeleteRexxMsg(packet: *mut RexxMsg) {
+        unsafe { crate::rexxsyslib::DeleteRexxMsg(get_RexxSysBase(), packet) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ClearRexxMsg(msgptr: *mut RexxMsg, count: ULONG) {
+        unsafe { crate::rexxsyslib::ClearRexxMsg(get_RexxSysBase(), msgptr, count) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FillRexxMsg(msgptr: *mut RexxMsg, count: ULONG, mask: ULONG) -> BOOL {
+        unsafe { crate::rexxsyslib::FillRexxMsg(get_RexxSysBase(), msgptr, count, mask) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn IsRexxMsg(msgptr: *const RexxMsg) -> BOOL {
+        unsafe { crate::rexxsyslib::IsRexxMsg(get_RexxSysBase(), msgptr) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn LockRexxBase(resource: ULONG) {
+        unsafe { crate::rexxsyslib::LockRexxBase(get_RexxSysBase(), resource) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UnlockRexxBase(resource: ULONG) {
+        unsafe { crate::rexxsyslib::UnlockRexxBase(get_RexxSysBase(), resource) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn CreateRexxHostPort(basename: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { crate::rexxsyslib::CreateRexxHostPort(get_RexxSysBase(), basename) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn DeleteRexxHostPort(port: *mut MsgPort) {
+        unsafe { crate::rexxsyslib::DeleteRexxHostPort(get_RexxSysBase(), port) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn GetRexxVarFromMsg(
+        var: CONST_STRPTR,
+        msgptr: *const RexxMsg,
+        value: STRPTR,
+    ) -> LONG {
+        unsafe { crate::rexxsyslib::GetRexxVarFromMsg(get_RexxSysBase(), var, msgptr, value) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SetRexxVarFromMsg(
+        var: CONST_STRPTR,
+        msgptr: *mut RexxMsg,
+        value: CONST_STRPTR,
+    ) -> LONG {
+        unsafe { crate::rexxsyslib::SetRexxVarFromMsg(get_RexxSysBase(), var, msgptr, value) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn LaunchRexxScript(
+        script: CONST_STRPTR,
+        replyport: *mut MsgPort,
+        extension: CONST_STRPTR,
+        input: BPTR,
+        output: BPTR,
+    ) -> *mut RexxMsg {
+        unsafe {
+            crate::rexxsyslib::LaunchRexxScript(
+                get_RexxSysBase(),
+                script,
+                replyport,
+                extension,
+                input,
+                output,
+            )
+        }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn FreeRexxMsg(msgptr: *mut RexxMsg) {
+        unsafe { crate::rexxsyslib::FreeRexxMsg(get_RexxSysBase(), msgptr) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn GetRexxBufferFromMsg(
+        var: CONST_STRPTR,
+        msgptr: *const RexxMsg,
+        buffer: STRPTR,
+        buffer_size: ULONG,
+    ) -> LONG {
+        unsafe {
+            crate::rexxsyslib::GetRexxBufferFromMsg(
+                get_RexxSysBase(),
+                var,
+                msgptr,
+                buffer,
+                buffer_size,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "scroller-gadget")]
+pub use scroller_gadget::*;
+
+#[cfg(feature = "scroller-gadget")]
+#[allow(unused_imports, dead_code)]
+mod scroller_gadget {
+    use super::*;
+
+    /// Global library base for "scroller.gadget"
+    pub static mut ScrollerBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_ScrollerBase() -> *mut ::core::ffi::c_void {
+        unsafe { ScrollerBase }
+    }
+
+    /// libname: "scroller.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SCROLLER_GetClass() -> *mut Class {
+        unsafe { crate::scroller_gadget::SCROLLER_GetClass(get_ScrollerBase()) }
+    }
+}
+
+#[cfg(feature = "sketchboard-gadget")]
+pub use sketchboard_gadget::*;
+
+#[cfg(feature = "sketchboard-gadget")]
+#[allow(unused_imports, dead_code)]
+mod sketchboard_gadget {
+    use super::*;
+
+    /// Global library base for "sketchboard.gadget"
+    pub static mut SketchBoardBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_SketchBoardBase() -> *mut ::core::ffi::c_void {
+        unsafe { SketchBoardBase }
+    }
+
+    /// libname: "sketchboard.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SKETCHBOARD_GetClass() -> *mut Class {
+        unsafe { crate::sketchboard_gadget::SKETCHBOARD_GetClass(get_SketchBoardBase()) }
+    }
+}
+
+#[cfg(feature = "slider-gadget")]
+pub use slider_gadget::*;
+
+#[cfg(feature = "slider-gadget")]
+#[allow(unused_imports, dead_code)]
+mod slider_gadget {
+    use super::*;
+
+    /// Global library base for "slider.gadget"
+    pub static mut SliderBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_SliderBase() -> *mut ::core::ffi::c_void {
+        unsafe { SliderBase }
+    }
+
+    /// libname: "slider.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SLIDER_GetClass() -> *mut Class {
+        unsafe { crate::slider_gadget::SLIDER_GetClass(get_SliderBase()) }
+    }
+}
+
+#[cfg(feature = "space-gadget")]
+pub use space_gadget::*;
+
+#[cfg(feature = "space-gadget")]
+#[allow(unused_imports, dead_code)]
+mod space_gadget {
+    use super::*;
+
+    /// Global library base for "space.gadget"
+    pub static mut SpaceBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_SpaceBase() -> *mut ::core::ffi::c_void {
+        unsafe { SpaceBase }
+    }
+
+    /// libname: "space.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SPACE_GetClass() -> *mut Class {
+        unsafe { crate::space_gadget::SPACE_GetClass(get_SpaceBase()) }
+    }
+}
+
+#[cfg(feature = "speedbar-gadget")]
+pub use speedbar_gadget::*;
+
+#[cfg(feature = "speedbar-gadget")]
+#[allow(unused_imports, dead_code)]
+mod speedbar_gadget {
+    use super::*;
+
+    /// Global library base for "speedbar.gadget"
+    pub static mut SpeedBarBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_SpeedBarBase() -> *mut ::core::ffi::c_void {
+        unsafe { SpeedBarBase }
+    }
+
+    /// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SPEEDBAR_GetClass() -> *mut Class {
+        unsafe { crate::speedbar_gadget::SPEEDBAR_GetClass(get_SpeedBarBase()) }
+    }
+
+    /// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn AllocSpeedButtonNodeA(number: ULONG, tags: *mut TagItem) -> *mut Node {
+        unsafe { crate::speedbar_gadget::AllocSpeedButtonNodeA(get_SpeedBarBase(), number, tags) }
+    }
+
+    /// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn FreeSpeedButtonNode(node: *mut Node) {
+        unsafe { crate::speedbar_gadget::FreeSpeedButtonNode(get_SpeedBarBase(), node) }
+    }
+
+    /// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn SetSpeedButtonNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::speedbar_gadget::SetSpeedButtonNodeAttrsA(get_SpeedBarBase(), node, tags) }
+    }
+
+    /// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn GetSpeedButtonNodeAttrsA(node: *mut Node, tags: *mut TagItem) {
+        unsafe { crate::speedbar_gadget::GetSpeedButtonNodeAttrsA(get_SpeedBarBase(), node, tags) }
+    }
+}
+
+#[cfg(feature = "string-gadget")]
+pub use string_gadget::*;
+
+#[cfg(feature = "string-gadget")]
+#[allow(unused_imports, dead_code)]
+mod string_gadget {
+    use super::*;
+
+    /// Global library base for "string.gadget"
+    pub static mut StringBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_StringBase() -> *mut ::core::ffi::c_void {
+        unsafe { StringBase }
+    }
+
+    /// libname: "string.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn STRING_GetClass() -> *mut Class {
+        unsafe { crate::string_gadget::STRING_GetClass(get_StringBase()) }
+    }
+}
+
+#[cfg(feature = "texteditor-gadget")]
+pub use texteditor_gadget::*;
+
+#[cfg(feature = "texteditor-gadget")]
+#[allow(unused_imports, dead_code)]
+mod texteditor_gadget {
+    use super::*;
+
+    /// Global library base for "texteditor.gadget"
+    pub static mut TextFieldBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_TextFieldBase() -> *mut ::core::ffi::c_void {
+        unsafe { TextFieldBase }
+    }
+
+    /// libname: "texteditor.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn TEXTEDITOR_GetClass() -> *mut Class {
+        unsafe { crate::texteditor_gadget::TEXTEDITOR_GetClass(get_TextFieldBase()) }
+    }
+
+    /// libname: "texteditor.gadget" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn HighlightSetFormat(object: APTR, pos: ULONG, end: ULONG, style: ULONG) {
+        unsafe {
+            crate::texteditor_gadget::HighlightSetFormat(
+                get_TextFieldBase(),
+                object,
+                pos,
+                end,
+                style,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "timer-device")]
+pub use timer_device::*;
+
+#[cfg(feature = "timer-device")]
+mod timer_device {
+    use super::*;
+
+    /// Global library base for "timer.device"
+    pub static mut TimerBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_TimerBase() -> *mut ::core::ffi::c_void {
+        unsafe { TimerBase }
+    }
+
+    /// libname: "timer.device"
+    #[inline]
+    pub unsafe fn AddTime(dest: *mut TimeVal_Type, src: *const TimeVal_Type) {
+        unsafe { crate::timer_device::AddTime(get_TimerBase(), dest, src) }
+    }
+
+    /// libname: "timer.device"
+    #[inline]
+    pub unsafe fn SubTime(dest: *mut TimeVal_Type, src: *const TimeVal_Type) {
+        unsafe { crate::timer_device::SubTime(get_TimerBase(), dest, src) }
+    }
+
+    /// libname: "timer.device"
+    #[inline]
+    pub unsafe fn CmpTime(dest: *const TimeVal_Type, src: *const TimeVal_Type) -> LONG {
+        unsafe { crate::timer_device::CmpTime(get_TimerBase(), dest, src) }
+    }
+
+    /// libname: "timer.device" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn ReadEClock(dest: *mut EClockVal) -> ULONG {
+        unsafe { crate::timer_device::ReadEClock(get_TimerBase(), dest) }
+    }
+
+    /// libname: "timer.device" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetSysTime(dest: *mut TimeVal_Type) {
+        unsafe { crate::timer_device::GetSysTime(get_TimerBase(), dest) }
+    }
+}
+
+#[cfg(feature = "trackfile-device")]
+pub use trackfile_device::*;
+
+#[cfg(feature = "trackfile-device")]
+#[allow(unused_imports, dead_code)]
+mod trackfile_device {
+    use super::*;
+
+    /// Global library base for "trackfile.device"
+    pub static mut TrackFileBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_TrackFileBase() -> *mut ::core::ffi::c_void {
+        unsafe { TrackFileBase }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFStartUnitTagList(which_unit: LONG, tags: *const TagItem) -> LONG {
+        unsafe {
+            crate::trackfile_device::TFStartUnitTagList(get_TrackFileBase(), which_unit, tags)
+        }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFStopUnitTagList(which_unit: LONG, tags: *const TagItem) -> LONG {
+        unsafe { crate::trackfile_device::TFStopUnitTagList(get_TrackFileBase(), which_unit, tags) }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFInsertMediaTagList(which_unit: LONG, tags: *const TagItem) -> LONG {
+        unsafe {
+            crate::trackfile_device::TFInsertMediaTagList(get_TrackFileBase(), which_unit, tags)
+        }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFEjectMediaTagList(which_unit: LONG, tags: *const TagItem) -> LONG {
+        unsafe {
+            crate::trackfile_device::TFEjectMediaTagList(get_TrackFileBase(), which_unit, tags)
+        }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFGetUnitData(which_unit: LONG) -> *mut TrackFileUnitData {
+        unsafe { crate::trackfile_device::TFGetUnitData(get_TrackFileBase(), which_unit) }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFFreeUnitData(tfud: *mut TrackFileUnitData) {
+        unsafe { crate::trackfile_device::TFFreeUnitData(get_TrackFileBase(), tfud) }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFChangeUnitTagList(which_unit: LONG, tags: *const TagItem) -> LONG {
+        unsafe {
+            crate::trackfile_device::TFChangeUnitTagList(get_TrackFileBase(), which_unit, tags)
+        }
+    }
+
+    /// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn TFExamineFileSize(file_size: LONG) -> LONG {
+        unsafe { crate::trackfile_device::TFExamineFileSize(get_TrackFileBase(), file_size) }
+    }
+}
+
+#[cfg(feature = "translator")]
+pub use translator::*;
+
+#[cfg(feature = "translator")]
+mod translator {
+    use super::*;
+
+    /// Global library base for "translator.library"
+    pub static mut TranslatorBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_TranslatorBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut TranslatorBase, c"translator.library")
+            } else {
+                TranslatorBase
+            }
+        }
+    }
+
+    /// libname: "translator.library"
+    #[inline]
+    pub unsafe fn Translate(
+        inputString: CONST_STRPTR,
+        inputLength: LONG,
+        outputBuffer: STRPTR,
+        bufferSize: LONG,
+    ) -> LONG {
+        unsafe {
+            crate::translator::Translate(
+                get_TranslatorBase(),
+                inputString,
+                inputLength,
+                outputBuffer,
+                bufferSize,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "utility")]
+pub use utility::*;
+
+#[cfg(feature = "utility")]
+#[allow(unused_imports, dead_code)]
+mod utility {
+    use super::*;
+
+    /// Global library base for "utility.library"
+    pub static mut UtilityBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_UtilityBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut UtilityBase, c"utility.library")
+            } else {
+                UtilityBase
+            }
+        }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FindTagItem(tagVal: Tag, tagList: *const TagItem) -> *mut TagItem {
+        unsafe { crate::utility::FindTagItem(get_UtilityBase(), tagVal, tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn GetTagData(tagValue: Tag, defaultVal: ULONG, tagList: *const TagItem) -> ULONG {
+        unsafe { crate::utility::GetTagData(get_UtilityBase(), tagValue, defaultVal, tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn PackBoolTags(
+        initialFlags: ULONG,
+        tagList: *const TagItem,
+        boolMap: *const TagItem,
+    ) -> ULONG {
+        unsafe { crate::utility::PackBoolTags(get_UtilityBase(), initialFlags, tagList, boolMap) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn NextTagItem(tagListPtr: *mut *mut TagItem) -> *mut TagItem {
+        unsafe { crate::utility::NextTagItem(get_UtilityBase(), tagListPtr) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FilterTagChanges(
+        changeList: *mut TagItem,
+        originalList: *mut TagItem,
+        apply: ULONG,
+    ) {
+        unsafe {
+            crate::utility::FilterTagChanges(get_UtilityBase(), changeList, originalList, apply)
+        }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn MapTags(tagList: *mut TagItem, mapList: *const TagItem, mapType: ULONG) {
+        unsafe { crate::utility::MapTags(get_UtilityBase(), tagList, mapList, mapType) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AllocateTagItems(numTags: ULONG) -> *mut TagItem {
+        unsafe { crate::utility::AllocateTagItems(get_UtilityBase(), numTags) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CloneTagItems(tagList: *const TagItem) -> *mut TagItem {
+        unsafe { crate::utility::CloneTagItems(get_UtilityBase(), tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FreeTagItems(tagList: *mut TagItem) {
+        unsafe { crate::utility::FreeTagItems(get_UtilityBase(), tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RefreshTagItemClones(clone: *mut TagItem, original: *const TagItem) {
+        unsafe { crate::utility::RefreshTagItemClones(get_UtilityBase(), clone, original) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn TagInArray(tagValue: Tag, tagArray: *const Tag) -> BOOL {
+        unsafe { crate::utility::TagInArray(get_UtilityBase(), tagValue, tagArray) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn FilterTagItems(
+        tagList: *mut TagItem,
+        filterArray: *const Tag,
+        logic: ULONG,
+    ) -> ULONG {
+        unsafe { crate::utility::FilterTagItems(get_UtilityBase(), tagList, filterArray, logic) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CallHookPkt(hook: *mut Hook, object: APTR, paramPacket: APTR) -> ULONG {
+        unsafe { crate::utility::CallHookPkt(get_UtilityBase(), hook, object, paramPacket) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Amiga2Date(seconds: ULONG, result: *mut ClockData) {
+        unsafe { crate::utility::Amiga2Date(get_UtilityBase(), seconds, result) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn Date2Amiga(date: *const ClockData) -> ULONG {
+        unsafe { crate::utility::Date2Amiga(get_UtilityBase(), date) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn CheckDate(date: *const ClockData) -> ULONG {
+        unsafe { crate::utility::CheckDate(get_UtilityBase(), date) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SMult32(arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { crate::utility::SMult32(get_UtilityBase(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UMult32(arg1: ULONG, arg2: ULONG) -> ULONG {
+        unsafe { crate::utility::UMult32(get_UtilityBase(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn SDivMod32(dividend: LONG, divisor: LONG) -> LONG {
+        unsafe { crate::utility::SDivMod32(get_UtilityBase(), dividend, divisor) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UDivMod32(dividend: ULONG, divisor: ULONG) -> ULONG {
+        unsafe { crate::utility::UDivMod32(get_UtilityBase(), dividend, divisor) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn Stricmp(string1: CONST_STRPTR, string2: CONST_STRPTR) -> LONG {
+        unsafe { crate::utility::Stricmp(get_UtilityBase(), string1, string2) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn Strnicmp(string1: CONST_STRPTR, string2: CONST_STRPTR, length: LONG) -> LONG {
+        unsafe { crate::utility::Strnicmp(get_UtilityBase(), string1, string2, length) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn ToUpper(character: ULONG) -> UBYTE {
+        unsafe { crate::utility::ToUpper(get_UtilityBase(), character) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    #[inline]
+    pub unsafe fn ToLower(character: ULONG) -> UBYTE {
+        unsafe { crate::utility::ToLower(get_UtilityBase(), character) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ApplyTagChanges(list: *mut TagItem, changeList: *const TagItem) {
+        unsafe { crate::utility::ApplyTagChanges(get_UtilityBase(), list, changeList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn SMult64(arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { crate::utility::SMult64(get_UtilityBase(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn UMult64(arg1: ULONG, arg2: ULONG) -> ULONG {
+        unsafe { crate::utility::UMult64(get_UtilityBase(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn PackStructureTags(
+        pack: APTR,
+        packTable: *const ULONG,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { crate::utility::PackStructureTags(get_UtilityBase(), pack, packTable, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn UnpackStructureTags(
+        pack: CONST_APTR,
+        packTable: *const ULONG,
+        tagList: *mut TagItem,
+    ) -> ULONG {
+        unsafe { crate::utility::UnpackStructureTags(get_UtilityBase(), pack, packTable, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AddNamedObject(nameSpace: *mut NamedObject, object: *mut NamedObject) -> BOOL {
+        unsafe { crate::utility::AddNamedObject(get_UtilityBase(), nameSpace, object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AllocNamedObjectA(
+        name: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> *mut NamedObject {
+        unsafe { crate::utility::AllocNamedObjectA(get_UtilityBase(), name, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn AttemptRemNamedObject(object: *mut NamedObject) -> LONG {
+        unsafe { crate::utility::AttemptRemNamedObject(get_UtilityBase(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FindNamedObject(
+        nameSpace: *mut NamedObject,
+        name: CONST_STRPTR,
+        lastObject: *const NamedObject,
+    ) -> *mut NamedObject {
+        unsafe { crate::utility::FindNamedObject(get_UtilityBase(), nameSpace, name, lastObject) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn FreeNamedObject(object: *mut NamedObject) {
+        unsafe { crate::utility::FreeNamedObject(get_UtilityBase(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn NamedObjectName(object: *mut NamedObject) -> STRPTR {
+        unsafe { crate::utility::NamedObjectName(get_UtilityBase(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn ReleaseNamedObject(object: *mut NamedObject) {
+        unsafe { crate::utility::ReleaseNamedObject(get_UtilityBase(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn RemNamedObject(object: *mut NamedObject, message: *mut Message) {
+        unsafe { crate::utility::RemNamedObject(get_UtilityBase(), object, message) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn GetUniqueID() -> ULONG {
+        unsafe { crate::utility::GetUniqueID(get_UtilityBase()) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn VSNPrintf(
+        buffer: STRPTR,
+        bufsize: ULONG,
+        fmt: CONST_STRPTR,
+        data: CONST_APTR,
+    ) -> LONG {
+        unsafe { crate::utility::VSNPrintf(get_UtilityBase(), buffer, bufsize, fmt, data) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn Strncpy(dst: STRPTR, src: CONST_STRPTR, size: ULONG) -> STRPTR {
+        unsafe { crate::utility::Strncpy(get_UtilityBase(), dst, src, size) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn Strncat(dst: STRPTR, src: CONST_STRPTR, size: ULONG) -> STRPTR {
+        unsafe { crate::utility::Strncat(get_UtilityBase(), dst, src, size) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn SDivMod64(hi: LONG, lo: LONG, divisor: LONG) -> LONG {
+        unsafe { crate::utility::SDivMod64(get_UtilityBase(), hi, lo, divisor) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn UDivMod64(hi: ULONG, lo: ULONG, divisor: ULONG) -> ULONG {
+        unsafe { crate::utility::UDivMod64(get_UtilityBase(), hi, lo, divisor) }
+    }
+}
+
+#[cfg(feature = "virtual-gadget")]
+pub use virtual_gadget::*;
+
+#[cfg(feature = "virtual-gadget")]
+#[allow(unused_imports, dead_code)]
+mod virtual_gadget {
+    use super::*;
+
+    /// Global library base for "virtual.gadget"
+    pub static mut VirtualBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_VirtualBase() -> *mut ::core::ffi::c_void {
+        unsafe { VirtualBase }
+    }
+
+    /// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn VIRTUAL_GetClass() -> *mut Class {
+        unsafe { crate::virtual_gadget::VIRTUAL_GetClass(get_VirtualBase()) }
+    }
+
+    /// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RefreshVirtualGadget(
+        gadget: *mut Gadget,
+        obj: *mut Object,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe {
+            crate::virtual_gadget::RefreshVirtualGadget(
+                get_VirtualBase(),
+                gadget,
+                obj,
+                window,
+                requester,
+            )
+        }
+    }
+
+    /// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn RethinkVirtualSize(
+        virt_obj: *mut Object,
+        rootlayout: *mut Object,
+        font: *mut TextFont,
+        screen: *mut Screen,
+        layoutlimits: *mut LayoutLimits,
+    ) -> BOOL {
+        unsafe {
+            crate::virtual_gadget::RethinkVirtualSize(
+                get_VirtualBase(),
+                virt_obj,
+                rootlayout,
+                font,
+                screen,
+                layoutlimits,
+            )
+        }
+    }
+}
+
+#[cfg(feature = "window-class")]
+pub use window_class::*;
+
+#[cfg(feature = "window-class")]
+#[allow(unused_imports, dead_code)]
+mod window_class {
+    use super::*;
+
+    /// Global library base for "window.class"
+    pub static mut WindowBase: *mut ::core::ffi::c_void = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_WindowBase() -> *mut ::core::ffi::c_void {
+        unsafe { WindowBase }
+    }
+
+    /// libname: "window.class" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    #[inline]
+    pub unsafe fn WINDOW_GetClass() -> *mut Class {
+        unsafe { crate::window_class::WINDOW_GetClass(get_WindowBase()) }
+    }
+}
+
+#[cfg(feature = "workbench")]
+pub use workbench::*;
+
+#[cfg(feature = "workbench")]
+#[allow(unused_imports, dead_code)]
+mod workbench {
+    use super::*;
+
+    /// Global library base for "workbench.library"
+    pub static mut WorkbenchBase: *mut Library = ::core::ptr::null_mut();
+
+    #[inline]
+    pub(super) unsafe fn get_WorkbenchBase() -> *mut Library {
+        unsafe {
+            if cfg!(feature = "auto-open") {
+                auto_open(&raw mut WorkbenchBase, c"workbench.library")
+            } else {
+                WorkbenchBase
+            }
+        }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn UpdateWorkbench(name: CONST_STRPTR, lock: BPTR, action: LONG) {
+        unsafe { crate::workbench::UpdateWorkbench(get_WorkbenchBase(), name, lock, action) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddAppWindowA(
+        id: ULONG,
+        userdata: ULONG,
+        window: *mut Window,
+        msgport: *mut MsgPort,
+        taglist: *const TagItem,
+    ) -> *mut AppWindow {
+        unsafe {
+            crate::workbench::AddAppWindowA(
+                get_WorkbenchBase(),
+                id,
+                userdata,
+                window,
+                msgport,
+                taglist,
+            )
+        }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemoveAppWindow(appWindow: *mut AppWindow) -> BOOL {
+        unsafe { crate::workbench::RemoveAppWindow(get_WorkbenchBase(), appWindow) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddAppIconA(
+        id: ULONG,
+        userdata: ULONG,
+        text: CONST_STRPTR,
+        msgport: *mut MsgPort,
+        lock: BPTR,
+        diskobj: *mut DiskObject,
+        taglist: *const TagItem,
+    ) -> *mut AppIcon {
+        unsafe {
+            crate::workbench::AddAppIconA(
+                get_WorkbenchBase(),
+                id,
+                userdata,
+                text,
+                msgport,
+                lock,
+                diskobj,
+                taglist,
+            )
+        }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemoveAppIcon(appIcon: *mut AppIcon) -> BOOL {
+        unsafe { crate::workbench::RemoveAppIcon(get_WorkbenchBase(), appIcon) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn AddAppMenuItemA(
+        id: ULONG,
+        userdata: ULONG,
+        text: CONST_STRPTR,
+        msgport: *mut MsgPort,
+        taglist: *const TagItem,
+    ) -> *mut AppMenuItem {
+        unsafe {
+            crate::workbench::AddAppMenuItemA(
+                get_WorkbenchBase(),
+                id,
+                userdata,
+                text,
+                msgport,
+                taglist,
+            )
+        }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    #[inline]
+    pub unsafe fn RemoveAppMenuItem(appMenuItem: *mut AppMenuItem) -> BOOL {
+        unsafe { crate::workbench::RemoveAppMenuItem(get_WorkbenchBase(), appMenuItem) }
+    }
+
+    /// libname: "workbench.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    #[inline]
+    pub unsafe fn WBInfo(lock: BPTR, name: CONST_STRPTR, screen: *mut Screen) -> ULONG {
+        unsafe { crate::workbench::WBInfo(get_WorkbenchBase(), lock, name, screen) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn OpenWorkbenchObjectA(name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { crate::workbench::OpenWorkbenchObjectA(get_WorkbenchBase(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn CloseWorkbenchObjectA(name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { crate::workbench::CloseWorkbenchObjectA(get_WorkbenchBase(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn WorkbenchControlA(name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { crate::workbench::WorkbenchControlA(get_WorkbenchBase(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn AddAppWindowDropZoneA(
+        aw: *mut AppWindow,
+        id: ULONG,
+        userdata: ULONG,
+        tags: *const TagItem,
+    ) -> *mut AppWindowDropZone {
+        unsafe {
+            crate::workbench::AddAppWindowDropZoneA(get_WorkbenchBase(), aw, id, userdata, tags)
+        }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn RemoveAppWindowDropZone(
+        aw: *mut AppWindow,
+        dropZone: *mut AppWindowDropZone,
+    ) -> BOOL {
+        unsafe { crate::workbench::RemoveAppWindowDropZone(get_WorkbenchBase(), aw, dropZone) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn ChangeWorkbenchSelectionA(
+        name: CONST_STRPTR,
+        hook: *mut Hook,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe {
+            crate::workbench::ChangeWorkbenchSelectionA(get_WorkbenchBase(), name, hook, tags)
+        }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    #[inline]
+    pub unsafe fn MakeWorkbenchObjectVisibleA(name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { crate::workbench::MakeWorkbenchObjectVisibleA(get_WorkbenchBase(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    #[inline]
+    pub unsafe fn WhichWorkbenchObjectA(
+        window: *mut Window,
+        x: LONG,
+        y: LONG,
+        tags: *const TagItem,
+    ) -> ULONG {
+        unsafe { crate::workbench::WhichWorkbenchObjectA(get_WorkbenchBase(), window, x, y, tags) }
+    }
+}
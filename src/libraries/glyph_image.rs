@@ -0,0 +1,48 @@
+//! Functions of "glyph.image"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "glyph.image" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GLYPH_GetClass(GlyphBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            GlyphBase as *mut Library,
+            40,
+            "GLYPH_GetClass",
+            "glyph.image",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GlyphBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `GLYPH_GetClass()`
+pub const LVO_GLYPH_GetClass: i16 = -30;
+
+/// Functions of "glyph.image"
+pub static GLYPH_IMAGE_FUNCTIONS: &[crate::FunctionInfo] = &[crate::FunctionInfo {
+    name: "GLYPH_GetClass",
+    lvo: LVO_GLYPH_GetClass,
+    registers: &[],
+    min_version: 40,
+}];
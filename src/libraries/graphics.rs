@@ -0,0 +1,6636 @@
+//! Functions of "graphics.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "graphics.library"
+pub unsafe fn BltBitMap(
+    GfxBase: *mut Library,
+    srcBitMap: *const BitMap,
+    xSrc: LONG,
+    ySrc: LONG,
+    destBitMap: *mut BitMap,
+    xDest: LONG,
+    yDest: LONG,
+    xSize: LONG,
+    ySize: LONG,
+    minterm: ULONG,
+    mask: ULONG,
+    tempA: PLANEPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg_addr) GfxBase,
+            in("a0") srcBitMap,
+            in("d0") xSrc,
+            in("d1") ySrc,
+            in("a1") destBitMap,
+            in("d2") xDest,
+            in("d3") yDest,
+            in("d4") xSize,
+            in("d5") ySize,
+            in("d6") minterm,
+            in("d7") mask,
+            in("a2") tempA,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn BltTemplate(
+    GfxBase: *mut Library,
+    source: PLANEPTR,
+    xSrc: LONG,
+    srcMod: LONG,
+    destRP: *mut RastPort,
+    xDest: LONG,
+    yDest: LONG,
+    xSize: LONG,
+    ySize: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") source,
+            in("d0") xSrc,
+            in("d1") srcMod,
+            in("a1") destRP,
+            in("d2") xDest,
+            in("d3") yDest,
+            in("d4") xSize,
+            in("d5") ySize,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ClearEOL(GfxBase: *mut Library, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ClearScreen(GfxBase: *mut Library, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn TextLength(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    string: CONST_STRPTR,
+    count: ULONG,
+) -> WORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") string,
+            in("d0") count,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "graphics.library"
+pub unsafe fn Text(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    string: CONST_STRPTR,
+    count: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") string,
+            in("d0") count,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetFont(GfxBase: *mut Library, rp: *mut RastPort, textFont: *mut TextFont) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") textFont,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn OpenFont(GfxBase: *mut Library, textAttr: *const TextAttr) -> *mut TextFont {
+    let asm_ret_value: *mut TextFont;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") textAttr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn CloseFont(GfxBase: *mut Library, textFont: *mut TextFont) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") textFont,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AskSoftStyle(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetSoftStyle(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    style: ULONG,
+    enable: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") style,
+            in("d1") enable,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AddBob(GfxBase: *mut Library, bob: *mut Bob, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bob,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AddVSprite(GfxBase: *mut Library, vSprite: *mut VSprite, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vSprite,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn DoCollision(GfxBase: *mut Library, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn DrawGList(GfxBase: *mut Library, rp: *mut RastPort, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitGels(
+    GfxBase: *mut Library,
+    head: *mut VSprite,
+    tail: *mut VSprite,
+    gelsInfo: *mut GelsInfo,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") head,
+            in("a1") tail,
+            in("a2") gelsInfo,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitMasks(GfxBase: *mut Library, vSprite: *mut VSprite) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vSprite,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn RemIBob(GfxBase: *mut Library, bob: *mut Bob, rp: *mut RastPort, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bob,
+            in("a1") rp,
+            in("a2") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn RemVSprite(GfxBase: *mut Library, vSprite: *mut VSprite) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vSprite,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetCollision(
+    GfxBase: *mut Library,
+    num: ULONG,
+    routine: FPTR,
+    gelsInfo: *mut GelsInfo,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") num,
+            in("a0") routine,
+            in("a1") gelsInfo,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SortGList(GfxBase: *mut Library, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AddAnimOb(
+    GfxBase: *mut Library,
+    anOb: *mut AnimOb,
+    anKey: *mut *mut AnimOb,
+    rp: *mut RastPort,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") anOb,
+            in("a1") anKey,
+            in("a2") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn Animate(GfxBase: *mut Library, anKey: *mut *mut AnimOb, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") anKey,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn GetGBuffers(
+    GfxBase: *mut Library,
+    anOb: *mut AnimOb,
+    rp: *mut RastPort,
+    flag: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") anOb,
+            in("a1") rp,
+            in("d0") flag,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitGMasks(GfxBase: *mut Library, anOb: *mut AnimOb) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") anOb,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn DrawEllipse(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xCenter: LONG,
+    yCenter: LONG,
+    a: LONG,
+    b: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") xCenter,
+            in("d1") yCenter,
+            in("d2") a,
+            in("d3") b,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AreaEllipse(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xCenter: LONG,
+    yCenter: LONG,
+    a: LONG,
+    b: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") xCenter,
+            in("d1") yCenter,
+            in("d2") a,
+            in("d3") b,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn LoadRGB4(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    colors: *const UWORD,
+    count: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") colors,
+            in("d0") count,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitRastPort(GfxBase: *mut Library, rp: *mut RastPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitVPort(GfxBase: *mut Library, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn MrgCop(GfxBase: *mut Library, view: *mut View) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") view,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn MakeVPort(GfxBase: *mut Library, view: *mut View, vp: *mut ViewPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") view,
+            in("a1") vp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn LoadView(GfxBase: *mut Library, view: *mut View) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") view,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn WaitBlit(GfxBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetRast(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") pen,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn Move(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn Draw(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AreaMove(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AreaDraw(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AreaEnd(GfxBase: *mut Library, rp: *mut RastPort) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn WaitTOF(GfxBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn QBlit(GfxBase: *mut Library, blit: *mut bltnode) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-276, %a6)
+            ".short -276",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") blit,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitArea(
+    GfxBase: *mut Library,
+    areaInfo: *mut AreaInfo,
+    vectorBuffer: APTR,
+    maxVectors: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-282, %a6)
+            ".short -282",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") areaInfo,
+            in("a1") vectorBuffer,
+            in("d0") maxVectors,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetRGB4(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    index: LONG,
+    red: ULONG,
+    green: ULONG,
+    blue: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-288, %a6)
+            ".short -288",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("d0") index,
+            in("d1") red,
+            in("d2") green,
+            in("d3") blue,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn QBSBlit(GfxBase: *mut Library, blit: *mut bltnode) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-294, %a6)
+            ".short -294",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") blit,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn BltClear(GfxBase: *mut Library, memBlock: PLANEPTR, byteCount: ULONG, flags: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-300, %a6)
+            ".short -300",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") memBlock,
+            in("d0") byteCount,
+            in("d1") flags,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn RectFill(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-306, %a6)
+            ".short -306",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") xMin,
+            in("d1") yMin,
+            in("d2") xMax,
+            in("d3") yMax,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn BltPattern(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    mask: PLANEPTR,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+    maskBPR: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-312, %a6)
+            ".short -312",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") mask,
+            in("d0") xMin,
+            in("d1") yMin,
+            in("d2") xMax,
+            in("d3") yMax,
+            in("d4") maskBPR,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ReadPixel(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-318, %a6)
+            ".short -318",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn WritePixel(GfxBase: *mut Library, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-324, %a6)
+            ".short -324",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn Flood(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    mode: ULONG,
+    x: LONG,
+    y: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-330, %a6)
+            ".short -330",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d2") mode,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn PolyDraw(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    count: LONG,
+    polyTable: *const WORD,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-336, %a6)
+            ".short -336",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") count,
+            in("a0") polyTable,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetAPen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-342, %a6)
+            ".short -342",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") pen,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetBPen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-348, %a6)
+            ".short -348",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") pen,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetDrMd(GfxBase: *mut Library, rp: *mut RastPort, drawMode: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-354, %a6)
+            ".short -354",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") drawMode,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitView(GfxBase: *mut Library, view: *mut View) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-360, %a6)
+            ".short -360",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") view,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn CBump(GfxBase: *mut Library, copList: *mut UCopList) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-366, %a6)
+            ".short -366",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") copList,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn CMove(
+    GfxBase: *mut Library,
+    copList: *mut UCopList,
+    destination: APTR,
+    data: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-372, %a6)
+            ".short -372",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") copList,
+            in("d0") destination,
+            in("d1") data,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn CWait(GfxBase: *mut Library, copList: *mut UCopList, v: LONG, h: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-378, %a6)
+            ".short -378",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") copList,
+            in("d0") v,
+            in("d1") h,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn VBeamPos(GfxBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-384, %a6)
+            ".short -384",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitBitMap(
+    GfxBase: *mut Library,
+    bitMap: *mut BitMap,
+    depth: LONG,
+    width: LONG,
+    height: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-390, %a6)
+            ".short -390",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bitMap,
+            in("d0") depth,
+            in("d1") width,
+            in("d2") height,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ScrollRaster(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    dx: LONG,
+    dy: LONG,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-396, %a6)
+            ".short -396",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") dx,
+            in("d1") dy,
+            in("d2") xMin,
+            in("d3") yMin,
+            in("d4") xMax,
+            in("d5") yMax,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn WaitBOVP(GfxBase: *mut Library, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-402, %a6)
+            ".short -402",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn GetSprite(GfxBase: *mut Library, sprite: *mut SimpleSprite, num: LONG) -> WORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-408, %a6)
+            ".short -408",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") sprite,
+            in("d0") num,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeSprite(GfxBase: *mut Library, num: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-414, %a6)
+            ".short -414",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") num,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ChangeSprite(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    sprite: *mut SimpleSprite,
+    newData: *mut UWORD,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-420, %a6)
+            ".short -420",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") sprite,
+            in("a2") newData,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn MoveSprite(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    sprite: *mut SimpleSprite,
+    x: LONG,
+    y: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-426, %a6)
+            ".short -426",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") sprite,
+            in("d0") x,
+            in("d1") y,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn LockLayerRom(GfxBase: *mut Library, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a5, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a5reg}, %a5",
+            ".short 0x4eae", // jsr (-432, %a6)
+            ".short -432",
+            "move.l (%sp)+, %a5",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            a5reg = in(reg) layer,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn UnlockLayerRom(GfxBase: *mut Library, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a5, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a5reg}, %a5",
+            ".short 0x4eae", // jsr (-438, %a6)
+            ".short -438",
+            "move.l (%sp)+, %a5",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            a5reg = in(reg) layer,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SyncSBitMap(GfxBase: *mut Library, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-444, %a6)
+            ".short -444",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") layer,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn CopySBitMap(GfxBase: *mut Library, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") layer,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn OwnBlitter(GfxBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn DisownBlitter(GfxBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-462, %a6)
+            ".short -462",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn InitTmpRas(
+    GfxBase: *mut Library,
+    tmpRas: *mut TmpRas,
+    buffer: PLANEPTR,
+    size: LONG,
+) -> *mut TmpRas {
+    let asm_ret_value: *mut TmpRas;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-468, %a6)
+            ".short -468",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") tmpRas,
+            in("a1") buffer,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AskFont(GfxBase: *mut Library, rp: *mut RastPort, textAttr: *mut TextAttr) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-474, %a6)
+            ".short -474",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") textAttr,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AddFont(GfxBase: *mut Library, textFont: *mut TextFont) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-480, %a6)
+            ".short -480",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") textFont,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn RemFont(GfxBase: *mut Library, textFont: *mut TextFont) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-486, %a6)
+            ".short -486",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") textFont,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AllocRaster(GfxBase: *mut Library, width: ULONG, height: ULONG) -> PLANEPTR {
+    let asm_ret_value: PLANEPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-492, %a6)
+            ".short -492",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") width,
+            in("d1") height,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeRaster(GfxBase: *mut Library, p: PLANEPTR, width: ULONG, height: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") p,
+            in("d0") width,
+            in("d1") height,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AndRectRegion(
+    GfxBase: *mut Library,
+    region: *mut Region,
+    rectangle: *const Rectangle,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-504, %a6)
+            ".short -504",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+            in("a1") rectangle,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn OrRectRegion(
+    GfxBase: *mut Library,
+    region: *mut Region,
+    rectangle: *const Rectangle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-510, %a6)
+            ".short -510",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+            in("a1") rectangle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn NewRegion(GfxBase: *mut Library) -> *mut Region {
+    let asm_ret_value: *mut Region;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-516, %a6)
+            ".short -516",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ClearRectRegion(
+    GfxBase: *mut Library,
+    region: *mut Region,
+    rectangle: *const Rectangle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-522, %a6)
+            ".short -522",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+            in("a1") rectangle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ClearRegion(GfxBase: *mut Library, region: *mut Region) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-528, %a6)
+            ".short -528",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn DisposeRegion(GfxBase: *mut Library, region: *mut Region) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-534, %a6)
+            ".short -534",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeVPortCopLists(GfxBase: *mut Library, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-540, %a6)
+            ".short -540",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeCopList(GfxBase: *mut Library, copList: *mut CopList) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-546, %a6)
+            ".short -546",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") copList,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ClipBlit(
+    GfxBase: *mut Library,
+    srcRP: *mut RastPort,
+    xSrc: LONG,
+    ySrc: LONG,
+    destRP: *mut RastPort,
+    xDest: LONG,
+    yDest: LONG,
+    xSize: LONG,
+    ySize: LONG,
+    minterm: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-552, %a6)
+            ".short -552",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcRP,
+            in("d0") xSrc,
+            in("d1") ySrc,
+            in("a1") destRP,
+            in("d2") xDest,
+            in("d3") yDest,
+            in("d4") xSize,
+            in("d5") ySize,
+            in("d6") minterm,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn XorRectRegion(
+    GfxBase: *mut Library,
+    region: *mut Region,
+    rectangle: *const Rectangle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-558, %a6)
+            ".short -558",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") region,
+            in("a1") rectangle,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeCprList(GfxBase: *mut Library, cprList: *mut cprlist) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-564, %a6)
+            ".short -564",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cprList,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn GetColorMap(GfxBase: *mut Library, entries: LONG) -> *mut ColorMap {
+    let asm_ret_value: *mut ColorMap;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-570, %a6)
+            ".short -570",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") entries,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeColorMap(GfxBase: *mut Library, colorMap: *mut ColorMap) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-576, %a6)
+            ".short -576",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") colorMap,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn GetRGB4(GfxBase: *mut Library, colorMap: *mut ColorMap, entry: LONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-582, %a6)
+            ".short -582",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") colorMap,
+            in("d0") entry,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn ScrollVPort(GfxBase: *mut Library, vp: *mut ViewPort) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-588, %a6)
+            ".short -588",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn UCopperListInit(
+    GfxBase: *mut Library,
+    uCopList: *mut UCopList,
+    n: LONG,
+) -> *mut CopList {
+    let asm_ret_value: *mut CopList;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-594, %a6)
+            ".short -594",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") uCopList,
+            in("d0") n,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library"
+pub unsafe fn FreeGBuffers(
+    GfxBase: *mut Library,
+    anOb: *mut AnimOb,
+    rp: *mut RastPort,
+    flag: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-600, %a6)
+            ".short -600",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") anOb,
+            in("a1") rp,
+            in("d0") flag,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn BltBitMapRastPort(
+    GfxBase: *mut Library,
+    srcBitMap: *const BitMap,
+    xSrc: LONG,
+    ySrc: LONG,
+    destRP: *mut RastPort,
+    xDest: LONG,
+    yDest: LONG,
+    xSize: LONG,
+    ySize: LONG,
+    minterm: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-606, %a6)
+            ".short -606",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcBitMap,
+            in("d0") xSrc,
+            in("d1") ySrc,
+            in("a1") destRP,
+            in("d2") xDest,
+            in("d3") yDest,
+            in("d4") xSize,
+            in("d5") ySize,
+            in("d6") minterm,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn OrRegionRegion(
+    GfxBase: *mut Library,
+    srcRegion: *const Region,
+    destRegion: *mut Region,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-612, %a6)
+            ".short -612",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcRegion,
+            in("a1") destRegion,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn XorRegionRegion(
+    GfxBase: *mut Library,
+    srcRegion: *const Region,
+    destRegion: *mut Region,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-618, %a6)
+            ".short -618",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcRegion,
+            in("a1") destRegion,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AndRegionRegion(
+    GfxBase: *mut Library,
+    srcRegion: *const Region,
+    destRegion: *mut Region,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-624, %a6)
+            ".short -624",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcRegion,
+            in("a1") destRegion,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library"
+pub unsafe fn SetRGB4CM(
+    GfxBase: *mut Library,
+    colorMap: *mut ColorMap,
+    index: LONG,
+    red: ULONG,
+    green: ULONG,
+    blue: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-630, %a6)
+            ".short -630",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") colorMap,
+            in("d0") index,
+            in("d1") red,
+            in("d2") green,
+            in("d3") blue,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn BltMaskBitMapRastPort(
+    GfxBase: *mut Library,
+    srcBitMap: *const BitMap,
+    xSrc: LONG,
+    ySrc: LONG,
+    destRP: *mut RastPort,
+    xDest: LONG,
+    yDest: LONG,
+    xSize: LONG,
+    ySize: LONG,
+    minterm: ULONG,
+    bltMask: PLANEPTR,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-636, %a6)
+            ".short -636",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") srcBitMap,
+            in("d0") xSrc,
+            in("d1") ySrc,
+            in("a1") destRP,
+            in("d2") xDest,
+            in("d3") yDest,
+            in("d4") xSize,
+            in("d5") ySize,
+            in("d6") minterm,
+            in("a2") bltMask,
+        );
+    }
+}
+
+/// libname: "graphics.library"
+pub unsafe fn AttemptLockLayerRom(GfxBase: *mut Library, layer: *mut Layer) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a5, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a5reg}, %a5",
+            ".short 0x4eae", // jsr (-654, %a6)
+            ".short -654",
+            "move.l (%sp)+, %a5",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            a5reg = in(reg) layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GfxNew(GfxBase: *mut Library, gfxNodeType: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GfxNew", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-660, %a6)
+            ".short -660",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") gfxNodeType,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GfxFree(GfxBase: *mut Library, gfxNodePtr: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GfxFree", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-666, %a6)
+            ".short -666",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") gfxNodePtr,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GfxAssociate(GfxBase: *mut Library, associateNode: APTR, gfxNodePtr: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GfxAssociate", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-672, %a6)
+            ".short -672",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") associateNode,
+            in("a1") gfxNodePtr,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn BitMapScale(GfxBase: *mut Library, bitScaleArgs: *mut BitScaleArgs) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "BitMapScale", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-678, %a6)
+            ".short -678",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bitScaleArgs,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ScalerDiv(
+    GfxBase: *mut Library,
+    factor: ULONG,
+    numerator: ULONG,
+    denominator: ULONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "ScalerDiv", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-684, %a6)
+            ".short -684",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") factor,
+            in("d1") numerator,
+            in("d2") denominator,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn TextExtent(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    string: CONST_STRPTR,
+    count: LONG,
+    textExtent: *mut TextExtent,
+) -> WORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "TextExtent", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-690, %a6)
+            ".short -690",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") string,
+            in("d0") count,
+            in("a2") textExtent,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn TextFit(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    string: CONST_STRPTR,
+    strLen: ULONG,
+    textExtent: *const TextExtent,
+    constrainingExtent: *const TextExtent,
+    strDirection: LONG,
+    constrainingBitWidth: ULONG,
+    constrainingBitHeight: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "TextFit", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-696, %a6)
+            ".short -696",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("a0") string,
+            in("d0") strLen,
+            in("a2") textExtent,
+            in("a3") constrainingExtent,
+            in("d1") strDirection,
+            in("d2") constrainingBitWidth,
+            in("d3") constrainingBitHeight,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GfxLookUp(GfxBase: *mut Library, associateNode: CONST_APTR) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GfxLookUp", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-702, %a6)
+            ".short -702",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") associateNode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn VideoControl(
+    GfxBase: *mut Library,
+    colorMap: *mut ColorMap,
+    tagarray: *mut TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "VideoControl", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-708, %a6)
+            ".short -708",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") colorMap,
+            in("a1") tagarray,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenMonitor(
+    GfxBase: *mut Library,
+    monitorName: CONST_STRPTR,
+    displayID: ULONG,
+) -> *mut MonitorSpec {
+    let asm_ret_value: *mut MonitorSpec;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "OpenMonitor", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-714, %a6)
+            ".short -714",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") monitorName,
+            in("d0") displayID,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CloseMonitor(GfxBase: *mut Library, monitorSpec: *mut MonitorSpec) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "CloseMonitor", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-720, %a6)
+            ".short -720",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") monitorSpec,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindDisplayInfo(GfxBase: *mut Library, displayID: ULONG) -> DisplayInfoHandle {
+    let asm_ret_value: DisplayInfoHandle;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "FindDisplayInfo", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-726, %a6)
+            ".short -726",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") displayID,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NextDisplayInfo(GfxBase: *mut Library, displayID: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "NextDisplayInfo", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-732, %a6)
+            ".short -732",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") displayID,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetDisplayInfoData(
+    GfxBase: *mut Library,
+    handle: DisplayInfoHandle,
+    buf: APTR,
+    size: ULONG,
+    tagID: ULONG,
+    displayID: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GetDisplayInfoData", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-756, %a6)
+            ".short -756",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") handle,
+            in("a1") buf,
+            in("d0") size,
+            in("d1") tagID,
+            in("d2") displayID,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FontExtent(
+    GfxBase: *mut Library,
+    font: *const TextFont,
+    fontExtent: *mut TextExtent,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "FontExtent", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-762, %a6)
+            ".short -762",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") font,
+            in("a1") fontExtent,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadPixelLine8(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xstart: ULONG,
+    ystart: ULONG,
+    width: ULONG,
+    array: *mut UBYTE,
+    tempRP: *mut RastPort,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "ReadPixelLine8", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-768, %a6)
+            ".short -768",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") xstart,
+            in("d1") ystart,
+            in("d2") width,
+            in("a2") array,
+            in("a1") tempRP,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WritePixelLine8(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xstart: ULONG,
+    ystart: ULONG,
+    width: ULONG,
+    array: *mut UBYTE,
+    tempRP: *mut RastPort,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "WritePixelLine8", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-774, %a6)
+            ".short -774",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") xstart,
+            in("d1") ystart,
+            in("d2") width,
+            in("a2") array,
+            in("a1") tempRP,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadPixelArray8(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xstart: ULONG,
+    ystart: ULONG,
+    xstop: ULONG,
+    ystop: ULONG,
+    array: *mut UBYTE,
+    temprp: *mut RastPort,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "ReadPixelArray8", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-780, %a6)
+            ".short -780",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") xstart,
+            in("d1") ystart,
+            in("d2") xstop,
+            in("d3") ystop,
+            in("a2") array,
+            in("a1") temprp,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WritePixelArray8(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xstart: ULONG,
+    ystart: ULONG,
+    xstop: ULONG,
+    ystop: ULONG,
+    array: *mut UBYTE,
+    temprp: *mut RastPort,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "WritePixelArray8", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-786, %a6)
+            ".short -786",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") xstart,
+            in("d1") ystart,
+            in("d2") xstop,
+            in("d3") ystop,
+            in("a2") array,
+            in("a1") temprp,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetVPModeID(GfxBase: *mut Library, vp: *const ViewPort) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "GetVPModeID", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-792, %a6)
+            ".short -792",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ModeNotAvailable(GfxBase: *mut Library, modeID: ULONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "ModeNotAvailable", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-798, %a6)
+            ".short -798",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") modeID,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WeighTAMatch(
+    GfxBase: *mut Library,
+    reqTextAttr: *const TextAttr,
+    targetTextAttr: *const TextAttr,
+    targetTags: *const TagItem,
+) -> WORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "WeighTAMatch", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-804, %a6)
+            ".short -804",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") reqTextAttr,
+            in("a1") targetTextAttr,
+            in("a2") targetTags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EraseRect(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "EraseRect", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-810, %a6)
+            ".short -810",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") xMin,
+            in("d1") yMin,
+            in("d2") xMax,
+            in("d3") yMax,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ExtendFont(
+    GfxBase: *mut Library,
+    font: *mut TextFont,
+    fontTags: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "ExtendFont", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-816, %a6)
+            ".short -816",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") font,
+            in("a1") fontTags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StripFont(GfxBase: *mut Library, font: *mut TextFont) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 36, "StripFont", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-822, %a6)
+            ".short -822",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") font,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn CalcIVG(GfxBase: *mut Library, v: *mut View, vp: *mut ViewPort) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "CalcIVG", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-828, %a6)
+            ".short -828",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") v,
+            in("a1") vp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AttachPalExtra(GfxBase: *mut Library, cm: *mut ColorMap, vp: *mut ViewPort) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "AttachPalExtra", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-834, %a6)
+            ".short -834",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("a1") vp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ObtainBestPenA(
+    GfxBase: *mut Library,
+    cm: *mut ColorMap,
+    r: ULONG,
+    g: ULONG,
+    b: ULONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ObtainBestPenA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-840, %a6)
+            ".short -840",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("d1") r,
+            in("d2") g,
+            in("d3") b,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetRGB32(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    n: ULONG,
+    r: ULONG,
+    g: ULONG,
+    b: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetRGB32", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-852, %a6)
+            ".short -852",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("d0") n,
+            in("d1") r,
+            in("d2") g,
+            in("d3") b,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetAPen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetAPen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-858, %a6)
+            ".short -858",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetBPen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetBPen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-864, %a6)
+            ".short -864",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetDrMd(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetDrMd", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-870, %a6)
+            ".short -870",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetOutlinePen(GfxBase: *mut Library, rp: *mut RastPort) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetOutlinePen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-876, %a6)
+            ".short -876",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn LoadRGB32(GfxBase: *mut Library, vp: *mut ViewPort, table: *const ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "LoadRGB32", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-882, %a6)
+            ".short -882",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") table,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetChipRev(GfxBase: *mut Library, want: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetChipRev", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-888, %a6)
+            ".short -888",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") want,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetABPenDrMd(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    apen: ULONG,
+    bpen: ULONG,
+    drawmode: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetABPenDrMd", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-894, %a6)
+            ".short -894",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") apen,
+            in("d1") bpen,
+            in("d2") drawmode,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetRGB32(
+    GfxBase: *mut Library,
+    cm: *const ColorMap,
+    firstcolor: ULONG,
+    ncolors: ULONG,
+    table: *mut ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetRGB32", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-900, %a6)
+            ".short -900",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("d0") firstcolor,
+            in("d1") ncolors,
+            in("a1") table,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocBitMap(
+    GfxBase: *mut Library,
+    sizex: ULONG,
+    sizey: ULONG,
+    depth: ULONG,
+    flags: ULONG,
+    friend_bitmap: *const BitMap,
+) -> *mut BitMap {
+    let asm_ret_value: *mut BitMap;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "AllocBitMap", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-918, %a6)
+            ".short -918",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("d0") sizex,
+            in("d1") sizey,
+            in("d2") depth,
+            in("d3") flags,
+            in("a0") friend_bitmap,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreeBitMap(GfxBase: *mut Library, bm: *mut BitMap) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "FreeBitMap", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-924, %a6)
+            ".short -924",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bm,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetExtSpriteA(
+    GfxBase: *mut Library,
+    ss: *mut ExtSprite,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetExtSpriteA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-930, %a6)
+            ".short -930",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a2") ss,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn CoerceMode(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    monitorid: ULONG,
+    flags: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "CoerceMode", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-936, %a6)
+            ".short -936",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("d0") monitorid,
+            in("d1") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ChangeVPBitMap(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    bm: *mut BitMap,
+    db: *mut DBufInfo,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ChangeVPBitMap", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-942, %a6)
+            ".short -942",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") bm,
+            in("a2") db,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ReleasePen(GfxBase: *mut Library, cm: *mut ColorMap, n: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ReleasePen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-948, %a6)
+            ".short -948",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("d0") n,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ObtainPen(
+    GfxBase: *mut Library,
+    cm: *mut ColorMap,
+    n: ULONG,
+    r: ULONG,
+    g: ULONG,
+    b: ULONG,
+    f: LONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ObtainPen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-954, %a6)
+            ".short -954",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("d0") n,
+            in("d1") r,
+            in("d2") g,
+            in("d3") b,
+            in("d4") f,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetBitMapAttr(GfxBase: *mut Library, bm: *const BitMap, attrnum: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetBitMapAttr", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-960, %a6)
+            ".short -960",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") bm,
+            in("d1") attrnum,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocDBufInfo(GfxBase: *mut Library, vp: *mut ViewPort) -> *mut DBufInfo {
+    let asm_ret_value: *mut DBufInfo;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "AllocDBufInfo", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-966, %a6)
+            ".short -966",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreeDBufInfo(GfxBase: *mut Library, dbi: *mut DBufInfo) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "FreeDBufInfo", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-972, %a6)
+            ".short -972",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") dbi,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetOutlinePen(GfxBase: *mut Library, rp: *mut RastPort, pen: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetOutlinePen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-978, %a6)
+            ".short -978",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") pen,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetWriteMask(GfxBase: *mut Library, rp: *mut RastPort, msk: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetWriteMask", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-984, %a6)
+            ".short -984",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") msk,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetMaxPen(GfxBase: *mut Library, rp: *mut RastPort, maxpen: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetMaxPen", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-990, %a6)
+            ".short -990",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") maxpen,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetRGB32CM(
+    GfxBase: *mut Library,
+    cm: *mut ColorMap,
+    n: ULONG,
+    r: ULONG,
+    g: ULONG,
+    b: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetRGB32CM", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-996, %a6)
+            ".short -996",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") cm,
+            in("d0") n,
+            in("d1") r,
+            in("d2") g,
+            in("d3") b,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ScrollRasterBF(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    dx: LONG,
+    dy: LONG,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ScrollRasterBF", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1002, %a6)
+            ".short -1002",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a1") rp,
+            in("d0") dx,
+            in("d1") dy,
+            in("d2") xMin,
+            in("d3") yMin,
+            in("d4") xMax,
+            in("d5") yMax,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FindColor(
+    GfxBase: *mut Library,
+    cm: *mut ColorMap,
+    r: ULONG,
+    g: ULONG,
+    b: ULONG,
+    maxcolor: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "FindColor", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1008, %a6)
+            ".short -1008",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a3") cm,
+            in("d1") r,
+            in("d2") g,
+            in("d3") b,
+            in("d4") maxcolor,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocSpriteDataA(
+    GfxBase: *mut Library,
+    bm: *const BitMap,
+    tags: *const TagItem,
+) -> *mut ExtSprite {
+    let asm_ret_value: *mut ExtSprite;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "AllocSpriteDataA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1020, %a6)
+            ".short -1020",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a2") bm,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ChangeExtSpriteA(
+    GfxBase: *mut Library,
+    vp: *mut ViewPort,
+    oldsprite: *mut ExtSprite,
+    newsprite: *mut ExtSprite,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "ChangeExtSpriteA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1026, %a6)
+            ".short -1026",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") vp,
+            in("a1") oldsprite,
+            in("a2") newsprite,
+            in("a3") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreeSpriteData(GfxBase: *mut Library, sp: *mut ExtSprite) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "FreeSpriteData", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1032, %a6)
+            ".short -1032",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a2") sp,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetRPAttrsA(GfxBase: *mut Library, rp: *mut RastPort, tags: *const TagItem) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "SetRPAttrsA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1038, %a6)
+            ".short -1038",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetRPAttrsA(GfxBase: *mut Library, rp: *mut RastPort, tags: *const TagItem) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "GetRPAttrsA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1044, %a6)
+            ".short -1044",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "graphics.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn BestModeIDA(GfxBase: *mut Library, tags: *const TagItem) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 39, "BestModeIDA", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1050, %a6)
+            ".short -1050",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "graphics.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn WriteChunkyPixels(
+    GfxBase: *mut Library,
+    rp: *mut RastPort,
+    xstart: ULONG,
+    ystart: ULONG,
+    xstop: ULONG,
+    ystop: ULONG,
+    array: *mut UBYTE,
+    bytesperrow: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(GfxBase, 40, "WriteChunkyPixels", "graphics.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1056, %a6)
+            ".short -1056",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) GfxBase,
+            in("a0") rp,
+            in("d0") xstart,
+            in("d1") ystart,
+            in("d2") xstop,
+            in("d3") ystop,
+            in("a2") array,
+            in("d4") bytesperrow,
+        );
+    }
+}
+
+/// An open "graphics.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct GraphicsLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl GraphicsLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"graphics.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "graphics.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltBitMap(
+        &self,
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destBitMap: *mut BitMap,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+        mask: ULONG,
+        tempA: PLANEPTR,
+    ) -> LONG {
+        unsafe {
+            BltBitMap(
+                self.base.as_ptr(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destBitMap,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+                mask,
+                tempA,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltTemplate(
+        &self,
+        source: PLANEPTR,
+        xSrc: LONG,
+        srcMod: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+    ) {
+        unsafe {
+            BltTemplate(
+                self.base.as_ptr(),
+                source,
+                xSrc,
+                srcMod,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ClearEOL(&self, rp: *mut RastPort) {
+        unsafe { ClearEOL(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ClearScreen(&self, rp: *mut RastPort) {
+        unsafe { ClearScreen(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn TextLength(&self, rp: *mut RastPort, string: CONST_STRPTR, count: ULONG) -> WORD {
+        unsafe { TextLength(self.base.as_ptr(), rp, string, count) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn Text(&self, rp: *mut RastPort, string: CONST_STRPTR, count: ULONG) -> LONG {
+        unsafe { Text(self.base.as_ptr(), rp, string, count) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetFont(&self, rp: *mut RastPort, textFont: *mut TextFont) -> LONG {
+        unsafe { SetFont(self.base.as_ptr(), rp, textFont) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn OpenFont(&self, textAttr: *const TextAttr) -> *mut TextFont {
+        unsafe { OpenFont(self.base.as_ptr(), textAttr) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn CloseFont(&self, textFont: *mut TextFont) {
+        unsafe { CloseFont(self.base.as_ptr(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AskSoftStyle(&self, rp: *mut RastPort) -> ULONG {
+        unsafe { AskSoftStyle(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetSoftStyle(&self, rp: *mut RastPort, style: ULONG, enable: ULONG) -> ULONG {
+        unsafe { SetSoftStyle(self.base.as_ptr(), rp, style, enable) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AddBob(&self, bob: *mut Bob, rp: *mut RastPort) {
+        unsafe { AddBob(self.base.as_ptr(), bob, rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AddVSprite(&self, vSprite: *mut VSprite, rp: *mut RastPort) {
+        unsafe { AddVSprite(self.base.as_ptr(), vSprite, rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn DoCollision(&self, rp: *mut RastPort) {
+        unsafe { DoCollision(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn DrawGList(&self, rp: *mut RastPort, vp: *mut ViewPort) {
+        unsafe { DrawGList(self.base.as_ptr(), rp, vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitGels(&self, head: *mut VSprite, tail: *mut VSprite, gelsInfo: *mut GelsInfo) {
+        unsafe { InitGels(self.base.as_ptr(), head, tail, gelsInfo) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitMasks(&self, vSprite: *mut VSprite) {
+        unsafe { InitMasks(self.base.as_ptr(), vSprite) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn RemIBob(&self, bob: *mut Bob, rp: *mut RastPort, vp: *mut ViewPort) {
+        unsafe { RemIBob(self.base.as_ptr(), bob, rp, vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn RemVSprite(&self, vSprite: *mut VSprite) {
+        unsafe { RemVSprite(self.base.as_ptr(), vSprite) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetCollision(&self, num: ULONG, routine: FPTR, gelsInfo: *mut GelsInfo) {
+        unsafe { SetCollision(self.base.as_ptr(), num, routine, gelsInfo) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SortGList(&self, rp: *mut RastPort) {
+        unsafe { SortGList(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AddAnimOb(&self, anOb: *mut AnimOb, anKey: *mut *mut AnimOb, rp: *mut RastPort) {
+        unsafe { AddAnimOb(self.base.as_ptr(), anOb, anKey, rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn Animate(&self, anKey: *mut *mut AnimOb, rp: *mut RastPort) {
+        unsafe { Animate(self.base.as_ptr(), anKey, rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn GetGBuffers(&self, anOb: *mut AnimOb, rp: *mut RastPort, flag: LONG) -> BOOL {
+        unsafe { GetGBuffers(self.base.as_ptr(), anOb, rp, flag) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitGMasks(&self, anOb: *mut AnimOb) {
+        unsafe { InitGMasks(self.base.as_ptr(), anOb) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn DrawEllipse(
+        &self,
+        rp: *mut RastPort,
+        xCenter: LONG,
+        yCenter: LONG,
+        a: LONG,
+        b: LONG,
+    ) {
+        unsafe { DrawEllipse(self.base.as_ptr(), rp, xCenter, yCenter, a, b) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AreaEllipse(
+        &self,
+        rp: *mut RastPort,
+        xCenter: LONG,
+        yCenter: LONG,
+        a: LONG,
+        b: LONG,
+    ) -> LONG {
+        unsafe { AreaEllipse(self.base.as_ptr(), rp, xCenter, yCenter, a, b) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn LoadRGB4(&self, vp: *mut ViewPort, colors: *const UWORD, count: LONG) {
+        unsafe { LoadRGB4(self.base.as_ptr(), vp, colors, count) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitRastPort(&self, rp: *mut RastPort) {
+        unsafe { InitRastPort(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitVPort(&self, vp: *mut ViewPort) {
+        unsafe { InitVPort(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn MrgCop(&self, view: *mut View) -> ULONG {
+        unsafe { MrgCop(self.base.as_ptr(), view) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn MakeVPort(&self, view: *mut View, vp: *mut ViewPort) -> ULONG {
+        unsafe { MakeVPort(self.base.as_ptr(), view, vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn LoadView(&self, view: *mut View) {
+        unsafe { LoadView(self.base.as_ptr(), view) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn WaitBlit(&self) {
+        unsafe { WaitBlit(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetRast(&self, rp: *mut RastPort, pen: ULONG) {
+        unsafe { SetRast(self.base.as_ptr(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn Move(&self, rp: *mut RastPort, x: LONG, y: LONG) {
+        unsafe { Move(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn Draw(&self, rp: *mut RastPort, x: LONG, y: LONG) {
+        unsafe { Draw(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AreaMove(&self, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { AreaMove(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AreaDraw(&self, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { AreaDraw(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AreaEnd(&self, rp: *mut RastPort) -> LONG {
+        unsafe { AreaEnd(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn WaitTOF(&self) {
+        unsafe { WaitTOF(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn QBlit(&self, blit: *mut bltnode) {
+        unsafe { QBlit(self.base.as_ptr(), blit) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitArea(&self, areaInfo: *mut AreaInfo, vectorBuffer: APTR, maxVectors: LONG) {
+        unsafe { InitArea(self.base.as_ptr(), areaInfo, vectorBuffer, maxVectors) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetRGB4(
+        &self,
+        vp: *mut ViewPort,
+        index: LONG,
+        red: ULONG,
+        green: ULONG,
+        blue: ULONG,
+    ) {
+        unsafe { SetRGB4(self.base.as_ptr(), vp, index, red, green, blue) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn QBSBlit(&self, blit: *mut bltnode) {
+        unsafe { QBSBlit(self.base.as_ptr(), blit) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltClear(&self, memBlock: PLANEPTR, byteCount: ULONG, flags: ULONG) {
+        unsafe { BltClear(self.base.as_ptr(), memBlock, byteCount, flags) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn RectFill(
+        &self,
+        rp: *mut RastPort,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { RectFill(self.base.as_ptr(), rp, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltPattern(
+        &self,
+        rp: *mut RastPort,
+        mask: PLANEPTR,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+        maskBPR: ULONG,
+    ) {
+        unsafe {
+            BltPattern(
+                self.base.as_ptr(),
+                rp,
+                mask,
+                xMin,
+                yMin,
+                xMax,
+                yMax,
+                maskBPR,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ReadPixel(&self, rp: *mut RastPort, x: LONG, y: LONG) -> ULONG {
+        unsafe { ReadPixel(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn WritePixel(&self, rp: *mut RastPort, x: LONG, y: LONG) -> LONG {
+        unsafe { WritePixel(self.base.as_ptr(), rp, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn Flood(&self, rp: *mut RastPort, mode: ULONG, x: LONG, y: LONG) -> BOOL {
+        unsafe { Flood(self.base.as_ptr(), rp, mode, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn PolyDraw(&self, rp: *mut RastPort, count: LONG, polyTable: *const WORD) {
+        unsafe { PolyDraw(self.base.as_ptr(), rp, count, polyTable) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetAPen(&self, rp: *mut RastPort, pen: ULONG) {
+        unsafe { SetAPen(self.base.as_ptr(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetBPen(&self, rp: *mut RastPort, pen: ULONG) {
+        unsafe { SetBPen(self.base.as_ptr(), rp, pen) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetDrMd(&self, rp: *mut RastPort, drawMode: ULONG) {
+        unsafe { SetDrMd(self.base.as_ptr(), rp, drawMode) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitView(&self, view: *mut View) {
+        unsafe { InitView(self.base.as_ptr(), view) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn CBump(&self, copList: *mut UCopList) {
+        unsafe { CBump(self.base.as_ptr(), copList) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn CMove(&self, copList: *mut UCopList, destination: APTR, data: LONG) -> LONG {
+        unsafe { CMove(self.base.as_ptr(), copList, destination, data) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn CWait(&self, copList: *mut UCopList, v: LONG, h: LONG) -> LONG {
+        unsafe { CWait(self.base.as_ptr(), copList, v, h) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn VBeamPos(&self) -> LONG {
+        unsafe { VBeamPos(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitBitMap(&self, bitMap: *mut BitMap, depth: LONG, width: LONG, height: LONG) {
+        unsafe { InitBitMap(self.base.as_ptr(), bitMap, depth, width, height) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ScrollRaster(
+        &self,
+        rp: *mut RastPort,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { ScrollRaster(self.base.as_ptr(), rp, dx, dy, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn WaitBOVP(&self, vp: *mut ViewPort) {
+        unsafe { WaitBOVP(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn GetSprite(&self, sprite: *mut SimpleSprite, num: LONG) -> WORD {
+        unsafe { GetSprite(self.base.as_ptr(), sprite, num) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeSprite(&self, num: LONG) {
+        unsafe { FreeSprite(self.base.as_ptr(), num) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ChangeSprite(
+        &self,
+        vp: *mut ViewPort,
+        sprite: *mut SimpleSprite,
+        newData: *mut UWORD,
+    ) {
+        unsafe { ChangeSprite(self.base.as_ptr(), vp, sprite, newData) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn MoveSprite(
+        &self,
+        vp: *mut ViewPort,
+        sprite: *mut SimpleSprite,
+        x: LONG,
+        y: LONG,
+    ) {
+        unsafe { MoveSprite(self.base.as_ptr(), vp, sprite, x, y) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn LockLayerRom(&self, layer: *mut Layer) {
+        unsafe { LockLayerRom(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn UnlockLayerRom(&self, layer: *mut Layer) {
+        unsafe { UnlockLayerRom(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SyncSBitMap(&self, layer: *mut Layer) {
+        unsafe { SyncSBitMap(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn CopySBitMap(&self, layer: *mut Layer) {
+        unsafe { CopySBitMap(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn OwnBlitter(&self) {
+        unsafe { OwnBlitter(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn DisownBlitter(&self) {
+        unsafe { DisownBlitter(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn InitTmpRas(
+        &self,
+        tmpRas: *mut TmpRas,
+        buffer: PLANEPTR,
+        size: LONG,
+    ) -> *mut TmpRas {
+        unsafe { InitTmpRas(self.base.as_ptr(), tmpRas, buffer, size) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AskFont(&self, rp: *mut RastPort, textAttr: *mut TextAttr) {
+        unsafe { AskFont(self.base.as_ptr(), rp, textAttr) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AddFont(&self, textFont: *mut TextFont) {
+        unsafe { AddFont(self.base.as_ptr(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn RemFont(&self, textFont: *mut TextFont) {
+        unsafe { RemFont(self.base.as_ptr(), textFont) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AllocRaster(&self, width: ULONG, height: ULONG) -> PLANEPTR {
+        unsafe { AllocRaster(self.base.as_ptr(), width, height) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeRaster(&self, p: PLANEPTR, width: ULONG, height: ULONG) {
+        unsafe { FreeRaster(self.base.as_ptr(), p, width, height) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AndRectRegion(&self, region: *mut Region, rectangle: *const Rectangle) {
+        unsafe { AndRectRegion(self.base.as_ptr(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn OrRectRegion(&self, region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { OrRectRegion(self.base.as_ptr(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn NewRegion(&self) -> *mut Region {
+        unsafe { NewRegion(self.base.as_ptr()) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ClearRectRegion(&self, region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { ClearRectRegion(self.base.as_ptr(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ClearRegion(&self, region: *mut Region) {
+        unsafe { ClearRegion(self.base.as_ptr(), region) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn DisposeRegion(&self, region: *mut Region) {
+        unsafe { DisposeRegion(self.base.as_ptr(), region) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeVPortCopLists(&self, vp: *mut ViewPort) {
+        unsafe { FreeVPortCopLists(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeCopList(&self, copList: *mut CopList) {
+        unsafe { FreeCopList(self.base.as_ptr(), copList) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ClipBlit(
+        &self,
+        srcRP: *mut RastPort,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+    ) {
+        unsafe {
+            ClipBlit(
+                self.base.as_ptr(),
+                srcRP,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn XorRectRegion(&self, region: *mut Region, rectangle: *const Rectangle) -> BOOL {
+        unsafe { XorRectRegion(self.base.as_ptr(), region, rectangle) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeCprList(&self, cprList: *mut cprlist) {
+        unsafe { FreeCprList(self.base.as_ptr(), cprList) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn GetColorMap(&self, entries: LONG) -> *mut ColorMap {
+        unsafe { GetColorMap(self.base.as_ptr(), entries) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeColorMap(&self, colorMap: *mut ColorMap) {
+        unsafe { FreeColorMap(self.base.as_ptr(), colorMap) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn GetRGB4(&self, colorMap: *mut ColorMap, entry: LONG) -> ULONG {
+        unsafe { GetRGB4(self.base.as_ptr(), colorMap, entry) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn ScrollVPort(&self, vp: *mut ViewPort) {
+        unsafe { ScrollVPort(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn UCopperListInit(&self, uCopList: *mut UCopList, n: LONG) -> *mut CopList {
+        unsafe { UCopperListInit(self.base.as_ptr(), uCopList, n) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn FreeGBuffers(&self, anOb: *mut AnimOb, rp: *mut RastPort, flag: LONG) {
+        unsafe { FreeGBuffers(self.base.as_ptr(), anOb, rp, flag) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltBitMapRastPort(
+        &self,
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+    ) {
+        unsafe {
+            BltBitMapRastPort(
+                self.base.as_ptr(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn OrRegionRegion(&self, srcRegion: *const Region, destRegion: *mut Region) -> BOOL {
+        unsafe { OrRegionRegion(self.base.as_ptr(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn XorRegionRegion(
+        &self,
+        srcRegion: *const Region,
+        destRegion: *mut Region,
+    ) -> BOOL {
+        unsafe { XorRegionRegion(self.base.as_ptr(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AndRegionRegion(
+        &self,
+        srcRegion: *const Region,
+        destRegion: *mut Region,
+    ) -> BOOL {
+        unsafe { AndRegionRegion(self.base.as_ptr(), srcRegion, destRegion) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn SetRGB4CM(
+        &self,
+        colorMap: *mut ColorMap,
+        index: LONG,
+        red: ULONG,
+        green: ULONG,
+        blue: ULONG,
+    ) {
+        unsafe { SetRGB4CM(self.base.as_ptr(), colorMap, index, red, green, blue) }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn BltMaskBitMapRastPort(
+        &self,
+        srcBitMap: *const BitMap,
+        xSrc: LONG,
+        ySrc: LONG,
+        destRP: *mut RastPort,
+        xDest: LONG,
+        yDest: LONG,
+        xSize: LONG,
+        ySize: LONG,
+        minterm: ULONG,
+        bltMask: PLANEPTR,
+    ) {
+        unsafe {
+            BltMaskBitMapRastPort(
+                self.base.as_ptr(),
+                srcBitMap,
+                xSrc,
+                ySrc,
+                destRP,
+                xDest,
+                yDest,
+                xSize,
+                ySize,
+                minterm,
+                bltMask,
+            )
+        }
+    }
+
+    /// libname: "graphics.library"
+    pub unsafe fn AttemptLockLayerRom(&self, layer: *mut Layer) -> BOOL {
+        unsafe { AttemptLockLayerRom(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GfxNew(&self, gfxNodeType: ULONG) -> APTR {
+        unsafe { GfxNew(self.base.as_ptr(), gfxNodeType) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GfxFree(&self, gfxNodePtr: APTR) {
+        unsafe { GfxFree(self.base.as_ptr(), gfxNodePtr) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GfxAssociate(&self, associateNode: APTR, gfxNodePtr: APTR) {
+        unsafe { GfxAssociate(self.base.as_ptr(), associateNode, gfxNodePtr) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn BitMapScale(&self, bitScaleArgs: *mut BitScaleArgs) {
+        unsafe { BitMapScale(self.base.as_ptr(), bitScaleArgs) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ScalerDiv(&self, factor: ULONG, numerator: ULONG, denominator: ULONG) -> UWORD {
+        unsafe { ScalerDiv(self.base.as_ptr(), factor, numerator, denominator) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn TextExtent(
+        &self,
+        rp: *mut RastPort,
+        string: CONST_STRPTR,
+        count: LONG,
+        textExtent: *mut TextExtent,
+    ) -> WORD {
+        unsafe { TextExtent(self.base.as_ptr(), rp, string, count, textExtent) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn TextFit(
+        &self,
+        rp: *mut RastPort,
+        string: CONST_STRPTR,
+        strLen: ULONG,
+        textExtent: *const TextExtent,
+        constrainingExtent: *const TextExtent,
+        strDirection: LONG,
+        constrainingBitWidth: ULONG,
+        constrainingBitHeight: ULONG,
+    ) -> ULONG {
+        unsafe {
+            TextFit(
+                self.base.as_ptr(),
+                rp,
+                string,
+                strLen,
+                textExtent,
+                constrainingExtent,
+                strDirection,
+                constrainingBitWidth,
+                constrainingBitHeight,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GfxLookUp(&self, associateNode: CONST_APTR) -> APTR {
+        unsafe { GfxLookUp(self.base.as_ptr(), associateNode) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn VideoControl(&self, colorMap: *mut ColorMap, tagarray: *mut TagItem) -> BOOL {
+        unsafe { VideoControl(self.base.as_ptr(), colorMap, tagarray) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenMonitor(
+        &self,
+        monitorName: CONST_STRPTR,
+        displayID: ULONG,
+    ) -> *mut MonitorSpec {
+        unsafe { OpenMonitor(self.base.as_ptr(), monitorName, displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CloseMonitor(&self, monitorSpec: *mut MonitorSpec) -> BOOL {
+        unsafe { CloseMonitor(self.base.as_ptr(), monitorSpec) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindDisplayInfo(&self, displayID: ULONG) -> DisplayInfoHandle {
+        unsafe { FindDisplayInfo(self.base.as_ptr(), displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NextDisplayInfo(&self, displayID: ULONG) -> ULONG {
+        unsafe { NextDisplayInfo(self.base.as_ptr(), displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetDisplayInfoData(
+        &self,
+        handle: DisplayInfoHandle,
+        buf: APTR,
+        size: ULONG,
+        tagID: ULONG,
+        displayID: ULONG,
+    ) -> ULONG {
+        unsafe { GetDisplayInfoData(self.base.as_ptr(), handle, buf, size, tagID, displayID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FontExtent(&self, font: *const TextFont, fontExtent: *mut TextExtent) {
+        unsafe { FontExtent(self.base.as_ptr(), font, fontExtent) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadPixelLine8(
+        &self,
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        width: ULONG,
+        array: *mut UBYTE,
+        tempRP: *mut RastPort,
+    ) -> LONG {
+        unsafe { ReadPixelLine8(self.base.as_ptr(), rp, xstart, ystart, width, array, tempRP) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WritePixelLine8(
+        &self,
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        width: ULONG,
+        array: *mut UBYTE,
+        tempRP: *mut RastPort,
+    ) -> LONG {
+        unsafe { WritePixelLine8(self.base.as_ptr(), rp, xstart, ystart, width, array, tempRP) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadPixelArray8(
+        &self,
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        temprp: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            ReadPixelArray8(
+                self.base.as_ptr(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                temprp,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WritePixelArray8(
+        &self,
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        temprp: *mut RastPort,
+    ) -> LONG {
+        unsafe {
+            WritePixelArray8(
+                self.base.as_ptr(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                temprp,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetVPModeID(&self, vp: *const ViewPort) -> LONG {
+        unsafe { GetVPModeID(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ModeNotAvailable(&self, modeID: ULONG) -> LONG {
+        unsafe { ModeNotAvailable(self.base.as_ptr(), modeID) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WeighTAMatch(
+        &self,
+        reqTextAttr: *const TextAttr,
+        targetTextAttr: *const TextAttr,
+        targetTags: *const TagItem,
+    ) -> WORD {
+        unsafe { WeighTAMatch(self.base.as_ptr(), reqTextAttr, targetTextAttr, targetTags) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EraseRect(
+        &self,
+        rp: *mut RastPort,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { EraseRect(self.base.as_ptr(), rp, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ExtendFont(&self, font: *mut TextFont, fontTags: *const TagItem) -> ULONG {
+        unsafe { ExtendFont(self.base.as_ptr(), font, fontTags) }
+    }
+
+    /// libname: "graphics.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StripFont(&self, font: *mut TextFont) {
+        unsafe { StripFont(self.base.as_ptr(), font) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn CalcIVG(&self, v: *mut View, vp: *mut ViewPort) -> UWORD {
+        unsafe { CalcIVG(self.base.as_ptr(), v, vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AttachPalExtra(&self, cm: *mut ColorMap, vp: *mut ViewPort) -> LONG {
+        unsafe { AttachPalExtra(self.base.as_ptr(), cm, vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ObtainBestPenA(
+        &self,
+        cm: *mut ColorMap,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        tags: *const TagItem,
+    ) -> LONG {
+        unsafe { ObtainBestPenA(self.base.as_ptr(), cm, r, g, b, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetRGB32(&self, vp: *mut ViewPort, n: ULONG, r: ULONG, g: ULONG, b: ULONG) {
+        unsafe { SetRGB32(self.base.as_ptr(), vp, n, r, g, b) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetAPen(&self, rp: *mut RastPort) -> ULONG {
+        unsafe { GetAPen(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetBPen(&self, rp: *mut RastPort) -> ULONG {
+        unsafe { GetBPen(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetDrMd(&self, rp: *mut RastPort) -> ULONG {
+        unsafe { GetDrMd(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetOutlinePen(&self, rp: *mut RastPort) -> ULONG {
+        unsafe { GetOutlinePen(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn LoadRGB32(&self, vp: *mut ViewPort, table: *const ULONG) {
+        unsafe { LoadRGB32(self.base.as_ptr(), vp, table) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetChipRev(&self, want: ULONG) -> ULONG {
+        unsafe { SetChipRev(self.base.as_ptr(), want) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetABPenDrMd(
+        &self,
+        rp: *mut RastPort,
+        apen: ULONG,
+        bpen: ULONG,
+        drawmode: ULONG,
+    ) {
+        unsafe { SetABPenDrMd(self.base.as_ptr(), rp, apen, bpen, drawmode) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetRGB32(
+        &self,
+        cm: *const ColorMap,
+        firstcolor: ULONG,
+        ncolors: ULONG,
+        table: *mut ULONG,
+    ) {
+        unsafe { GetRGB32(self.base.as_ptr(), cm, firstcolor, ncolors, table) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocBitMap(
+        &self,
+        sizex: ULONG,
+        sizey: ULONG,
+        depth: ULONG,
+        flags: ULONG,
+        friend_bitmap: *const BitMap,
+    ) -> *mut BitMap {
+        unsafe {
+            AllocBitMap(
+                self.base.as_ptr(),
+                sizex,
+                sizey,
+                depth,
+                flags,
+                friend_bitmap,
+            )
+        }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreeBitMap(&self, bm: *mut BitMap) {
+        unsafe { FreeBitMap(self.base.as_ptr(), bm) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetExtSpriteA(&self, ss: *mut ExtSprite, tags: *const TagItem) -> LONG {
+        unsafe { GetExtSpriteA(self.base.as_ptr(), ss, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn CoerceMode(&self, vp: *mut ViewPort, monitorid: ULONG, flags: ULONG) -> ULONG {
+        unsafe { CoerceMode(self.base.as_ptr(), vp, monitorid, flags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ChangeVPBitMap(&self, vp: *mut ViewPort, bm: *mut BitMap, db: *mut DBufInfo) {
+        unsafe { ChangeVPBitMap(self.base.as_ptr(), vp, bm, db) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ReleasePen(&self, cm: *mut ColorMap, n: ULONG) {
+        unsafe { ReleasePen(self.base.as_ptr(), cm, n) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ObtainPen(
+        &self,
+        cm: *mut ColorMap,
+        n: ULONG,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        f: LONG,
+    ) -> ULONG {
+        unsafe { ObtainPen(self.base.as_ptr(), cm, n, r, g, b, f) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetBitMapAttr(&self, bm: *const BitMap, attrnum: ULONG) -> ULONG {
+        unsafe { GetBitMapAttr(self.base.as_ptr(), bm, attrnum) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocDBufInfo(&self, vp: *mut ViewPort) -> *mut DBufInfo {
+        unsafe { AllocDBufInfo(self.base.as_ptr(), vp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreeDBufInfo(&self, dbi: *mut DBufInfo) {
+        unsafe { FreeDBufInfo(self.base.as_ptr(), dbi) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetOutlinePen(&self, rp: *mut RastPort, pen: ULONG) -> ULONG {
+        unsafe { SetOutlinePen(self.base.as_ptr(), rp, pen) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetWriteMask(&self, rp: *mut RastPort, msk: ULONG) -> ULONG {
+        unsafe { SetWriteMask(self.base.as_ptr(), rp, msk) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetMaxPen(&self, rp: *mut RastPort, maxpen: ULONG) {
+        unsafe { SetMaxPen(self.base.as_ptr(), rp, maxpen) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetRGB32CM(&self, cm: *mut ColorMap, n: ULONG, r: ULONG, g: ULONG, b: ULONG) {
+        unsafe { SetRGB32CM(self.base.as_ptr(), cm, n, r, g, b) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ScrollRasterBF(
+        &self,
+        rp: *mut RastPort,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { ScrollRasterBF(self.base.as_ptr(), rp, dx, dy, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FindColor(
+        &self,
+        cm: *mut ColorMap,
+        r: ULONG,
+        g: ULONG,
+        b: ULONG,
+        maxcolor: LONG,
+    ) -> LONG {
+        unsafe { FindColor(self.base.as_ptr(), cm, r, g, b, maxcolor) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocSpriteDataA(
+        &self,
+        bm: *const BitMap,
+        tags: *const TagItem,
+    ) -> *mut ExtSprite {
+        unsafe { AllocSpriteDataA(self.base.as_ptr(), bm, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ChangeExtSpriteA(
+        &self,
+        vp: *mut ViewPort,
+        oldsprite: *mut ExtSprite,
+        newsprite: *mut ExtSprite,
+        tags: *const TagItem,
+    ) -> LONG {
+        unsafe { ChangeExtSpriteA(self.base.as_ptr(), vp, oldsprite, newsprite, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreeSpriteData(&self, sp: *mut ExtSprite) {
+        unsafe { FreeSpriteData(self.base.as_ptr(), sp) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetRPAttrsA(&self, rp: *mut RastPort, tags: *const TagItem) {
+        unsafe { SetRPAttrsA(self.base.as_ptr(), rp, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetRPAttrsA(&self, rp: *mut RastPort, tags: *const TagItem) {
+        unsafe { GetRPAttrsA(self.base.as_ptr(), rp, tags) }
+    }
+
+    /// libname: "graphics.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn BestModeIDA(&self, tags: *const TagItem) -> ULONG {
+        unsafe { BestModeIDA(self.base.as_ptr(), tags) }
+    }
+
+    /// libname: "graphics.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn WriteChunkyPixels(
+        &self,
+        rp: *mut RastPort,
+        xstart: ULONG,
+        ystart: ULONG,
+        xstop: ULONG,
+        ystop: ULONG,
+        array: *mut UBYTE,
+        bytesperrow: LONG,
+    ) {
+        unsafe {
+            WriteChunkyPixels(
+                self.base.as_ptr(),
+                rp,
+                xstart,
+                ystart,
+                xstop,
+                ystop,
+                array,
+                bytesperrow,
+            )
+        }
+    }
+}
+
+impl Drop for GraphicsLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `BltBitMap()`
+pub const LVO_BltBitMap: i16 = -30;
+/// Library vector offset of `BltTemplate()`
+pub const LVO_BltTemplate: i16 = -36;
+/// Library vector offset of `ClearEOL()`
+pub const LVO_ClearEOL: i16 = -42;
+/// Library vector offset of `ClearScreen()`
+pub const LVO_ClearScreen: i16 = -48;
+/// Library vector offset of `TextLength()`
+pub const LVO_TextLength: i16 = -54;
+/// Library vector offset of `Text()`
+pub const LVO_Text: i16 = -60;
+/// Library vector offset of `SetFont()`
+pub const LVO_SetFont: i16 = -66;
+/// Library vector offset of `OpenFont()`
+pub const LVO_OpenFont: i16 = -72;
+/// Library vector offset of `CloseFont()`
+pub const LVO_CloseFont: i16 = -78;
+/// Library vector offset of `AskSoftStyle()`
+pub const LVO_AskSoftStyle: i16 = -84;
+/// Library vector offset of `SetSoftStyle()`
+pub const LVO_SetSoftStyle: i16 = -90;
+/// Library vector offset of `AddBob()`
+pub const LVO_AddBob: i16 = -96;
+/// Library vector offset of `AddVSprite()`
+pub const LVO_AddVSprite: i16 = -102;
+/// Library vector offset of `DoCollision()`
+pub const LVO_DoCollision: i16 = -108;
+/// Library vector offset of `DrawGList()`
+pub const LVO_DrawGList: i16 = -114;
+/// Library vector offset of `InitGels()`
+pub const LVO_InitGels: i16 = -120;
+/// Library vector offset of `InitMasks()`
+pub const LVO_InitMasks: i16 = -126;
+/// Library vector offset of `RemIBob()`
+pub const LVO_RemIBob: i16 = -132;
+/// Library vector offset of `RemVSprite()`
+pub const LVO_RemVSprite: i16 = -138;
+/// Library vector offset of `SetCollision()`
+pub const LVO_SetCollision: i16 = -144;
+/// Library vector offset of `SortGList()`
+pub const LVO_SortGList: i16 = -150;
+/// Library vector offset of `AddAnimOb()`
+pub const LVO_AddAnimOb: i16 = -156;
+/// Library vector offset of `Animate()`
+pub const LVO_Animate: i16 = -162;
+/// Library vector offset of `GetGBuffers()`
+pub const LVO_GetGBuffers: i16 = -168;
+/// Library vector offset of `InitGMasks()`
+pub const LVO_InitGMasks: i16 = -174;
+/// Library vector offset of `DrawEllipse()`
+pub const LVO_DrawEllipse: i16 = -180;
+/// Library vector offset of `AreaEllipse()`
+pub const LVO_AreaEllipse: i16 = -186;
+/// Library vector offset of `LoadRGB4()`
+pub const LVO_LoadRGB4: i16 = -192;
+/// Library vector offset of `InitRastPort()`
+pub const LVO_InitRastPort: i16 = -198;
+/// Library vector offset of `InitVPort()`
+pub const LVO_InitVPort: i16 = -204;
+/// Library vector offset of `MrgCop()`
+pub const LVO_MrgCop: i16 = -210;
+/// Library vector offset of `MakeVPort()`
+pub const LVO_MakeVPort: i16 = -216;
+/// Library vector offset of `LoadView()`
+pub const LVO_LoadView: i16 = -222;
+/// Library vector offset of `WaitBlit()`
+pub const LVO_WaitBlit: i16 = -228;
+/// Library vector offset of `SetRast()`
+pub const LVO_SetRast: i16 = -234;
+/// Library vector offset of `Move()`
+pub const LVO_Move: i16 = -240;
+/// Library vector offset of `Draw()`
+pub const LVO_Draw: i16 = -246;
+/// Library vector offset of `AreaMove()`
+pub const LVO_AreaMove: i16 = -252;
+/// Library vector offset of `AreaDraw()`
+pub const LVO_AreaDraw: i16 = -258;
+/// Library vector offset of `AreaEnd()`
+pub const LVO_AreaEnd: i16 = -264;
+/// Library vector offset of `WaitTOF()`
+pub const LVO_WaitTOF: i16 = -270;
+/// Library vector offset of `QBlit()`
+pub const LVO_QBlit: i16 = -276;
+/// Library vector offset of `InitArea()`
+pub const LVO_InitArea: i16 = -282;
+/// Library vector offset of `SetRGB4()`
+pub const LVO_SetRGB4: i16 = -288;
+/// Library vector offset of `QBSBlit()`
+pub const LVO_QBSBlit: i16 = -294;
+/// Library vector offset of `BltClear()`
+pub const LVO_BltClear: i16 = -300;
+/// Library vector offset of `RectFill()`
+pub const LVO_RectFill: i16 = -306;
+/// Library vector offset of `BltPattern()`
+pub const LVO_BltPattern: i16 = -312;
+/// Library vector offset of `ReadPixel()`
+pub const LVO_ReadPixel: i16 = -318;
+/// Library vector offset of `WritePixel()`
+pub const LVO_WritePixel: i16 = -324;
+/// Library vector offset of `Flood()`
+pub const LVO_Flood: i16 = -330;
+/// Library vector offset of `PolyDraw()`
+pub const LVO_PolyDraw: i16 = -336;
+/// Library vector offset of `SetAPen()`
+pub const LVO_SetAPen: i16 = -342;
+/// Library vector offset of `SetBPen()`
+pub const LVO_SetBPen: i16 = -348;
+/// Library vector offset of `SetDrMd()`
+pub const LVO_SetDrMd: i16 = -354;
+/// Library vector offset of `InitView()`
+pub const LVO_InitView: i16 = -360;
+/// Library vector offset of `CBump()`
+pub const LVO_CBump: i16 = -366;
+/// Library vector offset of `CMove()`
+pub const LVO_CMove: i16 = -372;
+/// Library vector offset of `CWait()`
+pub const LVO_CWait: i16 = -378;
+/// Library vector offset of `VBeamPos()`
+pub const LVO_VBeamPos: i16 = -384;
+/// Library vector offset of `InitBitMap()`
+pub const LVO_InitBitMap: i16 = -390;
+/// Library vector offset of `ScrollRaster()`
+pub const LVO_ScrollRaster: i16 = -396;
+/// Library vector offset of `WaitBOVP()`
+pub const LVO_WaitBOVP: i16 = -402;
+/// Library vector offset of `GetSprite()`
+pub const LVO_GetSprite: i16 = -408;
+/// Library vector offset of `FreeSprite()`
+pub const LVO_FreeSprite: i16 = -414;
+/// Library vector offset of `ChangeSprite()`
+pub const LVO_ChangeSprite: i16 = -420;
+/// Library vector offset of `MoveSprite()`
+pub const LVO_MoveSprite: i16 = -426;
+/// Library vector offset of `LockLayerRom()`
+pub const LVO_LockLayerRom: i16 = -432;
+/// Library vector offset of `UnlockLayerRom()`
+pub const LVO_UnlockLayerRom: i16 = -438;
+/// Library vector offset of `SyncSBitMap()`
+pub const LVO_SyncSBitMap: i16 = -444;
+/// Library vector offset of `CopySBitMap()`
+pub const LVO_CopySBitMap: i16 = -450;
+/// Library vector offset of `OwnBlitter()`
+pub const LVO_OwnBlitter: i16 = -456;
+/// Library vector offset of `DisownBlitter()`
+pub const LVO_DisownBlitter: i16 = -462;
+/// Library vector offset of `InitTmpRas()`
+pub const LVO_InitTmpRas: i16 = -468;
+/// Library vector offset of `AskFont()`
+pub const LVO_AskFont: i16 = -474;
+/// Library vector offset of `AddFont()`
+pub const LVO_AddFont: i16 = -480;
+/// Library vector offset of `RemFont()`
+pub const LVO_RemFont: i16 = -486;
+/// Library vector offset of `AllocRaster()`
+pub const LVO_AllocRaster: i16 = -492;
+/// Library vector offset of `FreeRaster()`
+pub const LVO_FreeRaster: i16 = -498;
+/// Library vector offset of `AndRectRegion()`
+pub const LVO_AndRectRegion: i16 = -504;
+/// Library vector offset of `OrRectRegion()`
+pub const LVO_OrRectRegion: i16 = -510;
+/// Library vector offset of `NewRegion()`
+pub const LVO_NewRegion: i16 = -516;
+/// Library vector offset of `ClearRectRegion()`
+pub const LVO_ClearRectRegion: i16 = -522;
+/// Library vector offset of `ClearRegion()`
+pub const LVO_ClearRegion: i16 = -528;
+/// Library vector offset of `DisposeRegion()`
+pub const LVO_DisposeRegion: i16 = -534;
+/// Library vector offset of `FreeVPortCopLists()`
+pub const LVO_FreeVPortCopLists: i16 = -540;
+/// Library vector offset of `FreeCopList()`
+pub const LVO_FreeCopList: i16 = -546;
+/// Library vector offset of `ClipBlit()`
+pub const LVO_ClipBlit: i16 = -552;
+/// Library vector offset of `XorRectRegion()`
+pub const LVO_XorRectRegion: i16 = -558;
+/// Library vector offset of `FreeCprList()`
+pub const LVO_FreeCprList: i16 = -564;
+/// Library vector offset of `GetColorMap()`
+pub const LVO_GetColorMap: i16 = -570;
+/// Library vector offset of `FreeColorMap()`
+pub const LVO_FreeColorMap: i16 = -576;
+/// Library vector offset of `GetRGB4()`
+pub const LVO_GetRGB4: i16 = -582;
+/// Library vector offset of `ScrollVPort()`
+pub const LVO_ScrollVPort: i16 = -588;
+/// Library vector offset of `UCopperListInit()`
+pub const LVO_UCopperListInit: i16 = -594;
+/// Library vector offset of `FreeGBuffers()`
+pub const LVO_FreeGBuffers: i16 = -600;
+/// Library vector offset of `BltBitMapRastPort()`
+pub const LVO_BltBitMapRastPort: i16 = -606;
+/// Library vector offset of `OrRegionRegion()`
+pub const LVO_OrRegionRegion: i16 = -612;
+/// Library vector offset of `XorRegionRegion()`
+pub const LVO_XorRegionRegion: i16 = -618;
+/// Library vector offset of `AndRegionRegion()`
+pub const LVO_AndRegionRegion: i16 = -624;
+/// Library vector offset of `SetRGB4CM()`
+pub const LVO_SetRGB4CM: i16 = -630;
+/// Library vector offset of `BltMaskBitMapRastPort()`
+pub const LVO_BltMaskBitMapRastPort: i16 = -636;
+/// Library vector offset of `AttemptLockLayerRom()`
+pub const LVO_AttemptLockLayerRom: i16 = -654;
+/// Library vector offset of `GfxNew()`
+pub const LVO_GfxNew: i16 = -660;
+/// Library vector offset of `GfxFree()`
+pub const LVO_GfxFree: i16 = -666;
+/// Library vector offset of `GfxAssociate()`
+pub const LVO_GfxAssociate: i16 = -672;
+/// Library vector offset of `BitMapScale()`
+pub const LVO_BitMapScale: i16 = -678;
+/// Library vector offset of `ScalerDiv()`
+pub const LVO_ScalerDiv: i16 = -684;
+/// Library vector offset of `TextExtent()`
+pub const LVO_TextExtent: i16 = -690;
+/// Library vector offset of `TextFit()`
+pub const LVO_TextFit: i16 = -696;
+/// Library vector offset of `GfxLookUp()`
+pub const LVO_GfxLookUp: i16 = -702;
+/// Library vector offset of `VideoControl()`
+pub const LVO_VideoControl: i16 = -708;
+/// Library vector offset of `OpenMonitor()`
+pub const LVO_OpenMonitor: i16 = -714;
+/// Library vector offset of `CloseMonitor()`
+pub const LVO_CloseMonitor: i16 = -720;
+/// Library vector offset of `FindDisplayInfo()`
+pub const LVO_FindDisplayInfo: i16 = -726;
+/// Library vector offset of `NextDisplayInfo()`
+pub const LVO_NextDisplayInfo: i16 = -732;
+/// Library vector offset of `GetDisplayInfoData()`
+pub const LVO_GetDisplayInfoData: i16 = -756;
+/// Library vector offset of `FontExtent()`
+pub const LVO_FontExtent: i16 = -762;
+/// Library vector offset of `ReadPixelLine8()`
+pub const LVO_ReadPixelLine8: i16 = -768;
+/// Library vector offset of `WritePixelLine8()`
+pub const LVO_WritePixelLine8: i16 = -774;
+/// Library vector offset of `ReadPixelArray8()`
+pub const LVO_ReadPixelArray8: i16 = -780;
+/// Library vector offset of `WritePixelArray8()`
+pub const LVO_WritePixelArray8: i16 = -786;
+/// Library vector offset of `GetVPModeID()`
+pub const LVO_GetVPModeID: i16 = -792;
+/// Library vector offset of `ModeNotAvailable()`
+pub const LVO_ModeNotAvailable: i16 = -798;
+/// Library vector offset of `WeighTAMatch()`
+pub const LVO_WeighTAMatch: i16 = -804;
+/// Library vector offset of `EraseRect()`
+pub const LVO_EraseRect: i16 = -810;
+/// Library vector offset of `ExtendFont()`
+pub const LVO_ExtendFont: i16 = -816;
+/// Library vector offset of `StripFont()`
+pub const LVO_StripFont: i16 = -822;
+/// Library vector offset of `CalcIVG()`
+pub const LVO_CalcIVG: i16 = -828;
+/// Library vector offset of `AttachPalExtra()`
+pub const LVO_AttachPalExtra: i16 = -834;
+/// Library vector offset of `ObtainBestPenA()`
+pub const LVO_ObtainBestPenA: i16 = -840;
+/// Library vector offset of `SetRGB32()`
+pub const LVO_SetRGB32: i16 = -852;
+/// Library vector offset of `GetAPen()`
+pub const LVO_GetAPen: i16 = -858;
+/// Library vector offset of `GetBPen()`
+pub const LVO_GetBPen: i16 = -864;
+/// Library vector offset of `GetDrMd()`
+pub const LVO_GetDrMd: i16 = -870;
+/// Library vector offset of `GetOutlinePen()`
+pub const LVO_GetOutlinePen: i16 = -876;
+/// Library vector offset of `LoadRGB32()`
+pub const LVO_LoadRGB32: i16 = -882;
+/// Library vector offset of `SetChipRev()`
+pub const LVO_SetChipRev: i16 = -888;
+/// Library vector offset of `SetABPenDrMd()`
+pub const LVO_SetABPenDrMd: i16 = -894;
+/// Library vector offset of `GetRGB32()`
+pub const LVO_GetRGB32: i16 = -900;
+/// Library vector offset of `AllocBitMap()`
+pub const LVO_AllocBitMap: i16 = -918;
+/// Library vector offset of `FreeBitMap()`
+pub const LVO_FreeBitMap: i16 = -924;
+/// Library vector offset of `GetExtSpriteA()`
+pub const LVO_GetExtSpriteA: i16 = -930;
+/// Library vector offset of `CoerceMode()`
+pub const LVO_CoerceMode: i16 = -936;
+/// Library vector offset of `ChangeVPBitMap()`
+pub const LVO_ChangeVPBitMap: i16 = -942;
+/// Library vector offset of `ReleasePen()`
+pub const LVO_ReleasePen: i16 = -948;
+/// Library vector offset of `ObtainPen()`
+pub const LVO_ObtainPen: i16 = -954;
+/// Library vector offset of `GetBitMapAttr()`
+pub const LVO_GetBitMapAttr: i16 = -960;
+/// Library vector offset of `AllocDBufInfo()`
+pub const LVO_AllocDBufInfo: i16 = -966;
+/// Library vector offset of `FreeDBufInfo()`
+pub const LVO_FreeDBufInfo: i16 = -972;
+/// Library vector offset of `SetOutlinePen()`
+pub const LVO_SetOutlinePen: i16 = -978;
+/// Library vector offset of `SetWriteMask()`
+pub const LVO_SetWriteMask: i16 = -984;
+/// Library vector offset of `SetMaxPen()`
+pub const LVO_SetMaxPen: i16 = -990;
+/// Library vector offset of `SetRGB32CM()`
+pub const LVO_SetRGB32CM: i16 = -996;
+/// Library vector offset of `ScrollRasterBF()`
+pub const LVO_ScrollRasterBF: i16 = -1002;
+/// Library vector offset of `FindColor()`
+pub const LVO_FindColor: i16 = -1008;
+/// Library vector offset of `AllocSpriteDataA()`
+pub const LVO_AllocSpriteDataA: i16 = -1020;
+/// Library vector offset of `ChangeExtSpriteA()`
+pub const LVO_ChangeExtSpriteA: i16 = -1026;
+/// Library vector offset of `FreeSpriteData()`
+pub const LVO_FreeSpriteData: i16 = -1032;
+/// Library vector offset of `SetRPAttrsA()`
+pub const LVO_SetRPAttrsA: i16 = -1038;
+/// Library vector offset of `GetRPAttrsA()`
+pub const LVO_GetRPAttrsA: i16 = -1044;
+/// Library vector offset of `BestModeIDA()`
+pub const LVO_BestModeIDA: i16 = -1050;
+/// Library vector offset of `WriteChunkyPixels()`
+pub const LVO_WriteChunkyPixels: i16 = -1056;
+
+/// Functions of "graphics.library"
+pub static GRAPHICS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "BltBitMap",
+        lvo: LVO_BltBitMap,
+        registers: &[
+            "a0", "d0", "d1", "a1", "d2", "d3", "d4", "d5", "d6", "d7", "a2",
+        ],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BltTemplate",
+        lvo: LVO_BltTemplate,
+        registers: &["a0", "d0", "d1", "a1", "d2", "d3", "d4", "d5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearEOL",
+        lvo: LVO_ClearEOL,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearScreen",
+        lvo: LVO_ClearScreen,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "TextLength",
+        lvo: LVO_TextLength,
+        registers: &["a1", "a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Text",
+        lvo: LVO_Text,
+        registers: &["a1", "a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetFont",
+        lvo: LVO_SetFont,
+        registers: &["a1", "a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenFont",
+        lvo: LVO_OpenFont,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseFont",
+        lvo: LVO_CloseFont,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AskSoftStyle",
+        lvo: LVO_AskSoftStyle,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetSoftStyle",
+        lvo: LVO_SetSoftStyle,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddBob",
+        lvo: LVO_AddBob,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddVSprite",
+        lvo: LVO_AddVSprite,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DoCollision",
+        lvo: LVO_DoCollision,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DrawGList",
+        lvo: LVO_DrawGList,
+        registers: &["a1", "a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitGels",
+        lvo: LVO_InitGels,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitMasks",
+        lvo: LVO_InitMasks,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemIBob",
+        lvo: LVO_RemIBob,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemVSprite",
+        lvo: LVO_RemVSprite,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetCollision",
+        lvo: LVO_SetCollision,
+        registers: &["d0", "a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SortGList",
+        lvo: LVO_SortGList,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddAnimOb",
+        lvo: LVO_AddAnimOb,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Animate",
+        lvo: LVO_Animate,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetGBuffers",
+        lvo: LVO_GetGBuffers,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitGMasks",
+        lvo: LVO_InitGMasks,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DrawEllipse",
+        lvo: LVO_DrawEllipse,
+        registers: &["a1", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AreaEllipse",
+        lvo: LVO_AreaEllipse,
+        registers: &["a1", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LoadRGB4",
+        lvo: LVO_LoadRGB4,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitRastPort",
+        lvo: LVO_InitRastPort,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitVPort",
+        lvo: LVO_InitVPort,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MrgCop",
+        lvo: LVO_MrgCop,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MakeVPort",
+        lvo: LVO_MakeVPort,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LoadView",
+        lvo: LVO_LoadView,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitBlit",
+        lvo: LVO_WaitBlit,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetRast",
+        lvo: LVO_SetRast,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Move",
+        lvo: LVO_Move,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Draw",
+        lvo: LVO_Draw,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AreaMove",
+        lvo: LVO_AreaMove,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AreaDraw",
+        lvo: LVO_AreaDraw,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AreaEnd",
+        lvo: LVO_AreaEnd,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitTOF",
+        lvo: LVO_WaitTOF,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "QBlit",
+        lvo: LVO_QBlit,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitArea",
+        lvo: LVO_InitArea,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetRGB4",
+        lvo: LVO_SetRGB4,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "QBSBlit",
+        lvo: LVO_QBSBlit,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BltClear",
+        lvo: LVO_BltClear,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RectFill",
+        lvo: LVO_RectFill,
+        registers: &["a1", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BltPattern",
+        lvo: LVO_BltPattern,
+        registers: &["a1", "a0", "d0", "d1", "d2", "d3", "d4"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReadPixel",
+        lvo: LVO_ReadPixel,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WritePixel",
+        lvo: LVO_WritePixel,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Flood",
+        lvo: LVO_Flood,
+        registers: &["a1", "d2", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "PolyDraw",
+        lvo: LVO_PolyDraw,
+        registers: &["a1", "d0", "a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetAPen",
+        lvo: LVO_SetAPen,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetBPen",
+        lvo: LVO_SetBPen,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetDrMd",
+        lvo: LVO_SetDrMd,
+        registers: &["a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitView",
+        lvo: LVO_InitView,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CBump",
+        lvo: LVO_CBump,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CMove",
+        lvo: LVO_CMove,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CWait",
+        lvo: LVO_CWait,
+        registers: &["a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "VBeamPos",
+        lvo: LVO_VBeamPos,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitBitMap",
+        lvo: LVO_InitBitMap,
+        registers: &["a0", "d0", "d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ScrollRaster",
+        lvo: LVO_ScrollRaster,
+        registers: &["a1", "d0", "d1", "d2", "d3", "d4", "d5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WaitBOVP",
+        lvo: LVO_WaitBOVP,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetSprite",
+        lvo: LVO_GetSprite,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeSprite",
+        lvo: LVO_FreeSprite,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ChangeSprite",
+        lvo: LVO_ChangeSprite,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveSprite",
+        lvo: LVO_MoveSprite,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LockLayerRom",
+        lvo: LVO_LockLayerRom,
+        registers: &["a5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnlockLayerRom",
+        lvo: LVO_UnlockLayerRom,
+        registers: &["a5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SyncSBitMap",
+        lvo: LVO_SyncSBitMap,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CopySBitMap",
+        lvo: LVO_CopySBitMap,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OwnBlitter",
+        lvo: LVO_OwnBlitter,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DisownBlitter",
+        lvo: LVO_DisownBlitter,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitTmpRas",
+        lvo: LVO_InitTmpRas,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AskFont",
+        lvo: LVO_AskFont,
+        registers: &["a1", "a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddFont",
+        lvo: LVO_AddFont,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemFont",
+        lvo: LVO_RemFont,
+        registers: &["a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocRaster",
+        lvo: LVO_AllocRaster,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeRaster",
+        lvo: LVO_FreeRaster,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AndRectRegion",
+        lvo: LVO_AndRectRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OrRectRegion",
+        lvo: LVO_OrRectRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "NewRegion",
+        lvo: LVO_NewRegion,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearRectRegion",
+        lvo: LVO_ClearRectRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearRegion",
+        lvo: LVO_ClearRegion,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DisposeRegion",
+        lvo: LVO_DisposeRegion,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeVPortCopLists",
+        lvo: LVO_FreeVPortCopLists,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeCopList",
+        lvo: LVO_FreeCopList,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClipBlit",
+        lvo: LVO_ClipBlit,
+        registers: &["a0", "d0", "d1", "a1", "d2", "d3", "d4", "d5", "d6"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "XorRectRegion",
+        lvo: LVO_XorRectRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeCprList",
+        lvo: LVO_FreeCprList,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetColorMap",
+        lvo: LVO_GetColorMap,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeColorMap",
+        lvo: LVO_FreeColorMap,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetRGB4",
+        lvo: LVO_GetRGB4,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ScrollVPort",
+        lvo: LVO_ScrollVPort,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UCopperListInit",
+        lvo: LVO_UCopperListInit,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeGBuffers",
+        lvo: LVO_FreeGBuffers,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BltBitMapRastPort",
+        lvo: LVO_BltBitMapRastPort,
+        registers: &["a0", "d0", "d1", "a1", "d2", "d3", "d4", "d5", "d6"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OrRegionRegion",
+        lvo: LVO_OrRegionRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "XorRegionRegion",
+        lvo: LVO_XorRegionRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AndRegionRegion",
+        lvo: LVO_AndRegionRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetRGB4CM",
+        lvo: LVO_SetRGB4CM,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BltMaskBitMapRastPort",
+        lvo: LVO_BltMaskBitMapRastPort,
+        registers: &["a0", "d0", "d1", "a1", "d2", "d3", "d4", "d5", "d6", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AttemptLockLayerRom",
+        lvo: LVO_AttemptLockLayerRom,
+        registers: &["a5"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GfxNew",
+        lvo: LVO_GfxNew,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GfxFree",
+        lvo: LVO_GfxFree,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GfxAssociate",
+        lvo: LVO_GfxAssociate,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "BitMapScale",
+        lvo: LVO_BitMapScale,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ScalerDiv",
+        lvo: LVO_ScalerDiv,
+        registers: &["d0", "d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "TextExtent",
+        lvo: LVO_TextExtent,
+        registers: &["a1", "a0", "d0", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "TextFit",
+        lvo: LVO_TextFit,
+        registers: &["a1", "a0", "d0", "a2", "a3", "d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GfxLookUp",
+        lvo: LVO_GfxLookUp,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "VideoControl",
+        lvo: LVO_VideoControl,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenMonitor",
+        lvo: LVO_OpenMonitor,
+        registers: &["a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CloseMonitor",
+        lvo: LVO_CloseMonitor,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindDisplayInfo",
+        lvo: LVO_FindDisplayInfo,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NextDisplayInfo",
+        lvo: LVO_NextDisplayInfo,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDisplayInfoData",
+        lvo: LVO_GetDisplayInfoData,
+        registers: &["a0", "a1", "d0", "d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FontExtent",
+        lvo: LVO_FontExtent,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadPixelLine8",
+        lvo: LVO_ReadPixelLine8,
+        registers: &["a0", "d0", "d1", "d2", "a2", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WritePixelLine8",
+        lvo: LVO_WritePixelLine8,
+        registers: &["a0", "d0", "d1", "d2", "a2", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadPixelArray8",
+        lvo: LVO_ReadPixelArray8,
+        registers: &["a0", "d0", "d1", "d2", "d3", "a2", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WritePixelArray8",
+        lvo: LVO_WritePixelArray8,
+        registers: &["a0", "d0", "d1", "d2", "d3", "a2", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetVPModeID",
+        lvo: LVO_GetVPModeID,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ModeNotAvailable",
+        lvo: LVO_ModeNotAvailable,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WeighTAMatch",
+        lvo: LVO_WeighTAMatch,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EraseRect",
+        lvo: LVO_EraseRect,
+        registers: &["a1", "d0", "d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ExtendFont",
+        lvo: LVO_ExtendFont,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StripFont",
+        lvo: LVO_StripFont,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CalcIVG",
+        lvo: LVO_CalcIVG,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AttachPalExtra",
+        lvo: LVO_AttachPalExtra,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ObtainBestPenA",
+        lvo: LVO_ObtainBestPenA,
+        registers: &["a0", "d1", "d2", "d3", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetRGB32",
+        lvo: LVO_SetRGB32,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetAPen",
+        lvo: LVO_GetAPen,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetBPen",
+        lvo: LVO_GetBPen,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetDrMd",
+        lvo: LVO_GetDrMd,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetOutlinePen",
+        lvo: LVO_GetOutlinePen,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "LoadRGB32",
+        lvo: LVO_LoadRGB32,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetChipRev",
+        lvo: LVO_SetChipRev,
+        registers: &["d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetABPenDrMd",
+        lvo: LVO_SetABPenDrMd,
+        registers: &["a1", "d0", "d1", "d2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetRGB32",
+        lvo: LVO_GetRGB32,
+        registers: &["a0", "d0", "d1", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AllocBitMap",
+        lvo: LVO_AllocBitMap,
+        registers: &["d0", "d1", "d2", "d3", "a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreeBitMap",
+        lvo: LVO_FreeBitMap,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetExtSpriteA",
+        lvo: LVO_GetExtSpriteA,
+        registers: &["a2", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "CoerceMode",
+        lvo: LVO_CoerceMode,
+        registers: &["a0", "d0", "d1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ChangeVPBitMap",
+        lvo: LVO_ChangeVPBitMap,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ReleasePen",
+        lvo: LVO_ReleasePen,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ObtainPen",
+        lvo: LVO_ObtainPen,
+        registers: &["a0", "d0", "d1", "d2", "d3", "d4"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetBitMapAttr",
+        lvo: LVO_GetBitMapAttr,
+        registers: &["a0", "d1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AllocDBufInfo",
+        lvo: LVO_AllocDBufInfo,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreeDBufInfo",
+        lvo: LVO_FreeDBufInfo,
+        registers: &["a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetOutlinePen",
+        lvo: LVO_SetOutlinePen,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetWriteMask",
+        lvo: LVO_SetWriteMask,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetMaxPen",
+        lvo: LVO_SetMaxPen,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetRGB32CM",
+        lvo: LVO_SetRGB32CM,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ScrollRasterBF",
+        lvo: LVO_ScrollRasterBF,
+        registers: &["a1", "d0", "d1", "d2", "d3", "d4", "d5"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FindColor",
+        lvo: LVO_FindColor,
+        registers: &["a3", "d1", "d2", "d3", "d4"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AllocSpriteDataA",
+        lvo: LVO_AllocSpriteDataA,
+        registers: &["a2", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ChangeExtSpriteA",
+        lvo: LVO_ChangeExtSpriteA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreeSpriteData",
+        lvo: LVO_FreeSpriteData,
+        registers: &["a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetRPAttrsA",
+        lvo: LVO_SetRPAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetRPAttrsA",
+        lvo: LVO_GetRPAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "BestModeIDA",
+        lvo: LVO_BestModeIDA,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "WriteChunkyPixels",
+        lvo: LVO_WriteChunkyPixels,
+        registers: &["a0", "d0", "d1", "d2", "d3", "a2", "d4"],
+        min_version: 40,
+    },
+];
@@ -0,0 +1,1105 @@
+//! Functions of "icon.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "icon.library"
+pub unsafe fn FreeFreeList(IconBase: *mut Library, freelist: *mut FreeList) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") freelist,
+        );
+    }
+}
+
+/// libname: "icon.library"
+pub unsafe fn AddFreeList(
+    IconBase: *mut Library,
+    freelist: *mut FreeList,
+    mem: CONST_APTR,
+    size: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") freelist,
+            in("a1") mem,
+            in("a2") size,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library"
+pub unsafe fn GetDiskObject(IconBase: *mut Library, name: CONST_STRPTR) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library"
+pub unsafe fn PutDiskObject(
+    IconBase: *mut Library,
+    name: CONST_STRPTR,
+    diskobj: *const DiskObject,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            in("a1") diskobj,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library"
+pub unsafe fn FreeDiskObject(IconBase: *mut Library, diskobj: *mut DiskObject) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") diskobj,
+        );
+    }
+}
+
+/// libname: "icon.library"
+pub unsafe fn FindToolType(
+    IconBase: *mut Library,
+    toolTypeArray: *mut CONST_STRPTR,
+    typeName: CONST_STRPTR,
+) -> *mut UBYTE {
+    let asm_ret_value: *mut UBYTE;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") toolTypeArray,
+            in("a1") typeName,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library"
+pub unsafe fn MatchToolValue(
+    IconBase: *mut Library,
+    typeString: CONST_STRPTR,
+    value: CONST_STRPTR,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") typeString,
+            in("a1") value,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library"
+pub unsafe fn BumpRevision(
+    IconBase: *mut Library,
+    newname: STRPTR,
+    oldname: CONST_STRPTR,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") newname,
+            in("a1") oldname,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeAlloc(
+    IconBase: *mut Library,
+    free: *mut FreeList,
+    len: ULONG,
+    type_: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 36, "FreeAlloc", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") free,
+            in("a1") len,
+            in("a2") type_,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetDefDiskObject(IconBase: *mut Library, type_: LONG) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 36, "GetDefDiskObject", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("d0") type_,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PutDefDiskObject(IconBase: *mut Library, diskObject: *const DiskObject) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 36, "PutDefDiskObject", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") diskObject,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetDiskObjectNew(IconBase: *mut Library, name: CONST_STRPTR) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 36, "GetDiskObjectNew", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn DeleteDiskObject(IconBase: *mut Library, name: CONST_STRPTR) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 37, "DeleteDiskObject", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn FreeFree(IconBase: *mut Library, fl: *mut FreeList, address: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "FreeFree", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") fl,
+            in("a1") address,
+        );
+    }
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn DupDiskObjectA(
+    IconBase: *mut Library,
+    diskObject: *const DiskObject,
+    tags: *const TagItem,
+) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "DupDiskObjectA", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") diskObject,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn IconControlA(
+    IconBase: *mut Library,
+    icon: *mut DiskObject,
+    tags: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "IconControlA", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") icon,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn DrawIconStateA(
+    IconBase: *mut Library,
+    rp: *mut RastPort,
+    icon: *const DiskObject,
+    label: CONST_STRPTR,
+    leftOffset: LONG,
+    topOffset: LONG,
+    state: ULONG,
+    tags: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "DrawIconStateA", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") rp,
+            in("a1") icon,
+            in("a2") label,
+            in("d0") leftOffset,
+            in("d1") topOffset,
+            in("d2") state,
+            in("a3") tags,
+        );
+    }
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn GetIconRectangleA(
+    IconBase: *mut Library,
+    rp: *mut RastPort,
+    icon: *const DiskObject,
+    label: CONST_STRPTR,
+    rect: *mut Rectangle,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "GetIconRectangleA", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a4, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a4reg}, %a4",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a4",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") rp,
+            in("a1") icon,
+            in("a2") label,
+            in("a3") rect,
+            a4reg = in(reg) tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn NewDiskObject(IconBase: *mut Library, type_: LONG) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "NewDiskObject", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("d0") type_,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn GetIconTagList(
+    IconBase: *mut Library,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> *mut DiskObject {
+    let asm_ret_value: *mut DiskObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "GetIconTagList", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn PutIconTagList(
+    IconBase: *mut Library,
+    name: CONST_STRPTR,
+    icon: *const DiskObject,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "PutIconTagList", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") name,
+            in("a1") icon,
+            in("a2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn LayoutIconA(
+    IconBase: *mut Library,
+    icon: *mut DiskObject,
+    screen: *mut Screen,
+    tags: *mut TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "LayoutIconA", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") icon,
+            in("a1") screen,
+            in("a2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn ChangeToSelectedIconColor(IconBase: *mut Library, cr: *mut ColorRegister) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 44, "ChangeToSelectedIconColor", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") cr,
+        );
+    }
+}
+
+/// libname: "icon.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn BumpRevisionLength(
+    IconBase: *mut Library,
+    newname: STRPTR,
+    oldname: CONST_STRPTR,
+    maxLength: ULONG,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IconBase, 47, "BumpRevisionLength", "icon.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IconBase,
+            in("a0") newname,
+            in("a1") oldname,
+            in("d0") maxLength,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "icon.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct IconLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl IconLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"icon.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "icon.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn FreeFreeList(&self, freelist: *mut FreeList) {
+        unsafe { FreeFreeList(self.base.as_ptr(), freelist) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn AddFreeList(
+        &self,
+        freelist: *mut FreeList,
+        mem: CONST_APTR,
+        size: ULONG,
+    ) -> BOOL {
+        unsafe { AddFreeList(self.base.as_ptr(), freelist, mem, size) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn GetDiskObject(&self, name: CONST_STRPTR) -> *mut DiskObject {
+        unsafe { GetDiskObject(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn PutDiskObject(&self, name: CONST_STRPTR, diskobj: *const DiskObject) -> BOOL {
+        unsafe { PutDiskObject(self.base.as_ptr(), name, diskobj) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn FreeDiskObject(&self, diskobj: *mut DiskObject) {
+        unsafe { FreeDiskObject(self.base.as_ptr(), diskobj) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn FindToolType(
+        &self,
+        toolTypeArray: *mut CONST_STRPTR,
+        typeName: CONST_STRPTR,
+    ) -> *mut UBYTE {
+        unsafe { FindToolType(self.base.as_ptr(), toolTypeArray, typeName) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn MatchToolValue(&self, typeString: CONST_STRPTR, value: CONST_STRPTR) -> BOOL {
+        unsafe { MatchToolValue(self.base.as_ptr(), typeString, value) }
+    }
+
+    /// libname: "icon.library"
+    pub unsafe fn BumpRevision(&self, newname: STRPTR, oldname: CONST_STRPTR) -> STRPTR {
+        unsafe { BumpRevision(self.base.as_ptr(), newname, oldname) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeAlloc(&self, free: *mut FreeList, len: ULONG, type_: ULONG) -> APTR {
+        unsafe { FreeAlloc(self.base.as_ptr(), free, len, type_) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetDefDiskObject(&self, type_: LONG) -> *mut DiskObject {
+        unsafe { GetDefDiskObject(self.base.as_ptr(), type_) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PutDefDiskObject(&self, diskObject: *const DiskObject) -> BOOL {
+        unsafe { PutDefDiskObject(self.base.as_ptr(), diskObject) }
+    }
+
+    /// libname: "icon.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetDiskObjectNew(&self, name: CONST_STRPTR) -> *mut DiskObject {
+        unsafe { GetDiskObjectNew(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "icon.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn DeleteDiskObject(&self, name: CONST_STRPTR) -> BOOL {
+        unsafe { DeleteDiskObject(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn FreeFree(&self, fl: *mut FreeList, address: APTR) {
+        unsafe { FreeFree(self.base.as_ptr(), fl, address) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn DupDiskObjectA(
+        &self,
+        diskObject: *const DiskObject,
+        tags: *const TagItem,
+    ) -> *mut DiskObject {
+        unsafe { DupDiskObjectA(self.base.as_ptr(), diskObject, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn IconControlA(&self, icon: *mut DiskObject, tags: *const TagItem) -> ULONG {
+        unsafe { IconControlA(self.base.as_ptr(), icon, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn DrawIconStateA(
+        &self,
+        rp: *mut RastPort,
+        icon: *const DiskObject,
+        label: CONST_STRPTR,
+        leftOffset: LONG,
+        topOffset: LONG,
+        state: ULONG,
+        tags: *const TagItem,
+    ) {
+        unsafe {
+            DrawIconStateA(
+                self.base.as_ptr(),
+                rp,
+                icon,
+                label,
+                leftOffset,
+                topOffset,
+                state,
+                tags,
+            )
+        }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn GetIconRectangleA(
+        &self,
+        rp: *mut RastPort,
+        icon: *const DiskObject,
+        label: CONST_STRPTR,
+        rect: *mut Rectangle,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { GetIconRectangleA(self.base.as_ptr(), rp, icon, label, rect, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn NewDiskObject(&self, type_: LONG) -> *mut DiskObject {
+        unsafe { NewDiskObject(self.base.as_ptr(), type_) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn GetIconTagList(
+        &self,
+        name: CONST_STRPTR,
+        tags: *const TagItem,
+    ) -> *mut DiskObject {
+        unsafe { GetIconTagList(self.base.as_ptr(), name, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn PutIconTagList(
+        &self,
+        name: CONST_STRPTR,
+        icon: *const DiskObject,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { PutIconTagList(self.base.as_ptr(), name, icon, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn LayoutIconA(
+        &self,
+        icon: *mut DiskObject,
+        screen: *mut Screen,
+        tags: *mut TagItem,
+    ) -> BOOL {
+        unsafe { LayoutIconA(self.base.as_ptr(), icon, screen, tags) }
+    }
+
+    /// libname: "icon.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn ChangeToSelectedIconColor(&self, cr: *mut ColorRegister) {
+        unsafe { ChangeToSelectedIconColor(self.base.as_ptr(), cr) }
+    }
+
+    /// libname: "icon.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn BumpRevisionLength(
+        &self,
+        newname: STRPTR,
+        oldname: CONST_STRPTR,
+        maxLength: ULONG,
+    ) -> STRPTR {
+        unsafe { BumpRevisionLength(self.base.as_ptr(), newname, oldname, maxLength) }
+    }
+}
+
+impl Drop for IconLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `FreeFreeList()`
+pub const LVO_FreeFreeList: i16 = -54;
+/// Library vector offset of `AddFreeList()`
+pub const LVO_AddFreeList: i16 = -72;
+/// Library vector offset of `GetDiskObject()`
+pub const LVO_GetDiskObject: i16 = -78;
+/// Library vector offset of `PutDiskObject()`
+pub const LVO_PutDiskObject: i16 = -84;
+/// Library vector offset of `FreeDiskObject()`
+pub const LVO_FreeDiskObject: i16 = -90;
+/// Library vector offset of `FindToolType()`
+pub const LVO_FindToolType: i16 = -96;
+/// Library vector offset of `MatchToolValue()`
+pub const LVO_MatchToolValue: i16 = -102;
+/// Library vector offset of `BumpRevision()`
+pub const LVO_BumpRevision: i16 = -108;
+/// Library vector offset of `FreeAlloc()`
+pub const LVO_FreeAlloc: i16 = -114;
+/// Library vector offset of `GetDefDiskObject()`
+pub const LVO_GetDefDiskObject: i16 = -120;
+/// Library vector offset of `PutDefDiskObject()`
+pub const LVO_PutDefDiskObject: i16 = -126;
+/// Library vector offset of `GetDiskObjectNew()`
+pub const LVO_GetDiskObjectNew: i16 = -132;
+/// Library vector offset of `DeleteDiskObject()`
+pub const LVO_DeleteDiskObject: i16 = -138;
+/// Library vector offset of `FreeFree()`
+pub const LVO_FreeFree: i16 = -144;
+/// Library vector offset of `DupDiskObjectA()`
+pub const LVO_DupDiskObjectA: i16 = -150;
+/// Library vector offset of `IconControlA()`
+pub const LVO_IconControlA: i16 = -156;
+/// Library vector offset of `DrawIconStateA()`
+pub const LVO_DrawIconStateA: i16 = -162;
+/// Library vector offset of `GetIconRectangleA()`
+pub const LVO_GetIconRectangleA: i16 = -168;
+/// Library vector offset of `NewDiskObject()`
+pub const LVO_NewDiskObject: i16 = -174;
+/// Library vector offset of `GetIconTagList()`
+pub const LVO_GetIconTagList: i16 = -180;
+/// Library vector offset of `PutIconTagList()`
+pub const LVO_PutIconTagList: i16 = -186;
+/// Library vector offset of `LayoutIconA()`
+pub const LVO_LayoutIconA: i16 = -192;
+/// Library vector offset of `ChangeToSelectedIconColor()`
+pub const LVO_ChangeToSelectedIconColor: i16 = -198;
+/// Library vector offset of `BumpRevisionLength()`
+pub const LVO_BumpRevisionLength: i16 = -204;
+
+/// Functions of "icon.library"
+pub static ICON_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "FreeFreeList",
+        lvo: LVO_FreeFreeList,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddFreeList",
+        lvo: LVO_AddFreeList,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetDiskObject",
+        lvo: LVO_GetDiskObject,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "PutDiskObject",
+        lvo: LVO_PutDiskObject,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeDiskObject",
+        lvo: LVO_FreeDiskObject,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FindToolType",
+        lvo: LVO_FindToolType,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MatchToolValue",
+        lvo: LVO_MatchToolValue,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BumpRevision",
+        lvo: LVO_BumpRevision,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeAlloc",
+        lvo: LVO_FreeAlloc,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDefDiskObject",
+        lvo: LVO_GetDefDiskObject,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PutDefDiskObject",
+        lvo: LVO_PutDefDiskObject,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDiskObjectNew",
+        lvo: LVO_GetDiskObjectNew,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteDiskObject",
+        lvo: LVO_DeleteDiskObject,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "FreeFree",
+        lvo: LVO_FreeFree,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "DupDiskObjectA",
+        lvo: LVO_DupDiskObjectA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "IconControlA",
+        lvo: LVO_IconControlA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "DrawIconStateA",
+        lvo: LVO_DrawIconStateA,
+        registers: &["a0", "a1", "a2", "d0", "d1", "d2", "a3"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "GetIconRectangleA",
+        lvo: LVO_GetIconRectangleA,
+        registers: &["a0", "a1", "a2", "a3", "a4"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "NewDiskObject",
+        lvo: LVO_NewDiskObject,
+        registers: &["d0"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "GetIconTagList",
+        lvo: LVO_GetIconTagList,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "PutIconTagList",
+        lvo: LVO_PutIconTagList,
+        registers: &["a0", "a1", "a2"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "LayoutIconA",
+        lvo: LVO_LayoutIconA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "ChangeToSelectedIconColor",
+        lvo: LVO_ChangeToSelectedIconColor,
+        registers: &["a0"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "BumpRevisionLength",
+        lvo: LVO_BumpRevisionLength,
+        registers: &["a0", "a1", "d0"],
+        min_version: 47,
+    },
+];
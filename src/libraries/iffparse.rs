@@ -0,0 +1,1887 @@
+//! Functions of "iffparse.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocIFF(IFFParseBase: *mut Library) -> *mut IFFHandle {
+    let asm_ret_value: *mut IFFHandle;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "AllocIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle, rwMode: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "OpenIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") rwMode,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ParseIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle, control: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "ParseIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") control,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CloseIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "CloseIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeIFF(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FreeIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadChunkBytes(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    buf: APTR,
+    numBytes: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "ReadChunkBytes", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") buf,
+            in("d0") numBytes,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WriteChunkBytes(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    buf: CONST_APTR,
+    numBytes: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "WriteChunkBytes", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") buf,
+            in("d0") numBytes,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadChunkRecords(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    buf: APTR,
+    bytesPerRecord: LONG,
+    numRecords: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "ReadChunkRecords", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") buf,
+            in("d0") bytesPerRecord,
+            in("d1") numRecords,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn WriteChunkRecords(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    buf: CONST_APTR,
+    bytesPerRecord: LONG,
+    numRecords: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "WriteChunkRecords", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") buf,
+            in("d0") bytesPerRecord,
+            in("d1") numRecords,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PushChunk(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+    size: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "PushChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            in("d2") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PopChunk(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "PopChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EntryHandler(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+    position: LONG,
+    handler: *mut Hook,
+    object: APTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "EntryHandler", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            in("d2") position,
+            in("a1") handler,
+            in("a2") object,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ExitHandler(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+    position: LONG,
+    handler: *mut Hook,
+    object: APTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "ExitHandler", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            in("d2") position,
+            in("a1") handler,
+            in("a2") object,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PropChunk(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "PropChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PropChunks(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    propArray: *const LONG,
+    numPairs: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "PropChunks", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") propArray,
+            in("d0") numPairs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StopChunk(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "StopChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StopChunks(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    propArray: *const LONG,
+    numPairs: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "StopChunks", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") propArray,
+            in("d0") numPairs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CollectionChunk(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "CollectionChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CollectionChunks(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    propArray: *const LONG,
+    numPairs: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "CollectionChunks", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") propArray,
+            in("d0") numPairs,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StopOnExit(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "StopOnExit", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindProp(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> *mut StoredProperty {
+    let asm_ret_value: *mut StoredProperty;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FindProp", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindCollection(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+) -> *mut CollectionItem {
+    let asm_ret_value: *mut CollectionItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FindCollection", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindPropContext(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> *mut ContextNode {
+    let asm_ret_value: *mut ContextNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FindPropContext", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CurrentChunk(IFFParseBase: *mut Library, iff: *mut IFFHandle) -> *mut ContextNode {
+    let asm_ret_value: *mut ContextNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "CurrentChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ParentChunk(
+    IFFParseBase: *mut Library,
+    contextNode: *mut ContextNode,
+) -> *mut ContextNode {
+    let asm_ret_value: *mut ContextNode;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "ParentChunk", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") contextNode,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocLocalItem(
+    IFFParseBase: *mut Library,
+    type_: LONG,
+    id: LONG,
+    ident: LONG,
+    dataSize: LONG,
+) -> *mut LocalContextItem {
+    let asm_ret_value: *mut LocalContextItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "AllocLocalItem", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("d0") type_,
+            in("d1") id,
+            in("d2") ident,
+            in("d3") dataSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LocalItemData(IFFParseBase: *mut Library, localItem: *mut LocalContextItem) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "LocalItemData", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") localItem,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetLocalItemPurge(
+    IFFParseBase: *mut Library,
+    localItem: *mut LocalContextItem,
+    purgeHook: *mut Hook,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "SetLocalItemPurge", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") localItem,
+            in("a1") purgeHook,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeLocalItem(IFFParseBase: *mut Library, localItem: *mut LocalContextItem) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FreeLocalItem", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") localItem,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindLocalItem(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    type_: LONG,
+    id: LONG,
+    ident: LONG,
+) -> *mut LocalContextItem {
+    let asm_ret_value: *mut LocalContextItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "FindLocalItem", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") type_,
+            in("d1") id,
+            in("d2") ident,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StoreLocalItem(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    localItem: *mut LocalContextItem,
+    position: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "StoreLocalItem", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") localItem,
+            in("d0") position,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn StoreItemInContext(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    localItem: *mut LocalContextItem,
+    contextNode: *mut ContextNode,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "StoreItemInContext", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("a1") localItem,
+            in("a2") contextNode,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InitIFF(
+    IFFParseBase: *mut Library,
+    iff: *mut IFFHandle,
+    flags: LONG,
+    streamHook: *mut Hook,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "InitIFF", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+            in("d0") flags,
+            in("a1") streamHook,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InitIFFasDOS(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "InitIFFasDOS", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InitIFFasClip(IFFParseBase: *mut Library, iff: *mut IFFHandle) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "InitIFFasClip", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") iff,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenClipboard(IFFParseBase: *mut Library, unitNumber: LONG) -> *mut ClipboardHandle {
+    let asm_ret_value: *mut ClipboardHandle;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "OpenClipboard", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("d0") unitNumber,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CloseClipboard(IFFParseBase: *mut Library, clipHandle: *mut ClipboardHandle) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "CloseClipboard", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("a0") clipHandle,
+        );
+    }
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GoodID(IFFParseBase: *mut Library, id: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "GoodID", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("d0") id,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GoodType(IFFParseBase: *mut Library, type_: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "GoodType", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("d0") type_,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "iffparse.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IDtoStr(IFFParseBase: *mut Library, id: LONG, buf: STRPTR) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IFFParseBase, 36, "IDtoStr", "iffparse.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IFFParseBase,
+            in("d0") id,
+            in("a0") buf,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "iffparse.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct IFFParseLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl IFFParseLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"iffparse.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "iffparse.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocIFF(&self) -> *mut IFFHandle {
+        unsafe { AllocIFF(self.base.as_ptr()) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenIFF(&self, iff: *mut IFFHandle, rwMode: LONG) -> LONG {
+        unsafe { OpenIFF(self.base.as_ptr(), iff, rwMode) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ParseIFF(&self, iff: *mut IFFHandle, control: LONG) -> LONG {
+        unsafe { ParseIFF(self.base.as_ptr(), iff, control) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CloseIFF(&self, iff: *mut IFFHandle) {
+        unsafe { CloseIFF(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeIFF(&self, iff: *mut IFFHandle) {
+        unsafe { FreeIFF(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadChunkBytes(&self, iff: *mut IFFHandle, buf: APTR, numBytes: LONG) -> LONG {
+        unsafe { ReadChunkBytes(self.base.as_ptr(), iff, buf, numBytes) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WriteChunkBytes(
+        &self,
+        iff: *mut IFFHandle,
+        buf: CONST_APTR,
+        numBytes: LONG,
+    ) -> LONG {
+        unsafe { WriteChunkBytes(self.base.as_ptr(), iff, buf, numBytes) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReadChunkRecords(
+        &self,
+        iff: *mut IFFHandle,
+        buf: APTR,
+        bytesPerRecord: LONG,
+        numRecords: LONG,
+    ) -> LONG {
+        unsafe { ReadChunkRecords(self.base.as_ptr(), iff, buf, bytesPerRecord, numRecords) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn WriteChunkRecords(
+        &self,
+        iff: *mut IFFHandle,
+        buf: CONST_APTR,
+        bytesPerRecord: LONG,
+        numRecords: LONG,
+    ) -> LONG {
+        unsafe { WriteChunkRecords(self.base.as_ptr(), iff, buf, bytesPerRecord, numRecords) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PushChunk(&self, iff: *mut IFFHandle, type_: LONG, id: LONG, size: LONG) -> LONG {
+        unsafe { PushChunk(self.base.as_ptr(), iff, type_, id, size) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PopChunk(&self, iff: *mut IFFHandle) -> LONG {
+        unsafe { PopChunk(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EntryHandler(
+        &self,
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        position: LONG,
+        handler: *mut Hook,
+        object: APTR,
+    ) -> LONG {
+        unsafe {
+            EntryHandler(
+                self.base.as_ptr(),
+                iff,
+                type_,
+                id,
+                position,
+                handler,
+                object,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ExitHandler(
+        &self,
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        position: LONG,
+        handler: *mut Hook,
+        object: APTR,
+    ) -> LONG {
+        unsafe {
+            ExitHandler(
+                self.base.as_ptr(),
+                iff,
+                type_,
+                id,
+                position,
+                handler,
+                object,
+            )
+        }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PropChunk(&self, iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { PropChunk(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PropChunks(
+        &self,
+        iff: *mut IFFHandle,
+        propArray: *const LONG,
+        numPairs: LONG,
+    ) -> LONG {
+        unsafe { PropChunks(self.base.as_ptr(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StopChunk(&self, iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { StopChunk(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StopChunks(
+        &self,
+        iff: *mut IFFHandle,
+        propArray: *const LONG,
+        numPairs: LONG,
+    ) -> LONG {
+        unsafe { StopChunks(self.base.as_ptr(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CollectionChunk(&self, iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { CollectionChunk(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CollectionChunks(
+        &self,
+        iff: *mut IFFHandle,
+        propArray: *const LONG,
+        numPairs: LONG,
+    ) -> LONG {
+        unsafe { CollectionChunks(self.base.as_ptr(), iff, propArray, numPairs) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StopOnExit(&self, iff: *mut IFFHandle, type_: LONG, id: LONG) -> LONG {
+        unsafe { StopOnExit(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindProp(
+        &self,
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+    ) -> *mut StoredProperty {
+        unsafe { FindProp(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindCollection(
+        &self,
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+    ) -> *mut CollectionItem {
+        unsafe { FindCollection(self.base.as_ptr(), iff, type_, id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindPropContext(&self, iff: *mut IFFHandle) -> *mut ContextNode {
+        unsafe { FindPropContext(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CurrentChunk(&self, iff: *mut IFFHandle) -> *mut ContextNode {
+        unsafe { CurrentChunk(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ParentChunk(&self, contextNode: *mut ContextNode) -> *mut ContextNode {
+        unsafe { ParentChunk(self.base.as_ptr(), contextNode) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocLocalItem(
+        &self,
+        type_: LONG,
+        id: LONG,
+        ident: LONG,
+        dataSize: LONG,
+    ) -> *mut LocalContextItem {
+        unsafe { AllocLocalItem(self.base.as_ptr(), type_, id, ident, dataSize) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LocalItemData(&self, localItem: *mut LocalContextItem) -> APTR {
+        unsafe { LocalItemData(self.base.as_ptr(), localItem) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetLocalItemPurge(&self, localItem: *mut LocalContextItem, purgeHook: *mut Hook) {
+        unsafe { SetLocalItemPurge(self.base.as_ptr(), localItem, purgeHook) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeLocalItem(&self, localItem: *mut LocalContextItem) {
+        unsafe { FreeLocalItem(self.base.as_ptr(), localItem) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindLocalItem(
+        &self,
+        iff: *mut IFFHandle,
+        type_: LONG,
+        id: LONG,
+        ident: LONG,
+    ) -> *mut LocalContextItem {
+        unsafe { FindLocalItem(self.base.as_ptr(), iff, type_, id, ident) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StoreLocalItem(
+        &self,
+        iff: *mut IFFHandle,
+        localItem: *mut LocalContextItem,
+        position: LONG,
+    ) -> LONG {
+        unsafe { StoreLocalItem(self.base.as_ptr(), iff, localItem, position) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn StoreItemInContext(
+        &self,
+        iff: *mut IFFHandle,
+        localItem: *mut LocalContextItem,
+        contextNode: *mut ContextNode,
+    ) {
+        unsafe { StoreItemInContext(self.base.as_ptr(), iff, localItem, contextNode) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InitIFF(&self, iff: *mut IFFHandle, flags: LONG, streamHook: *mut Hook) {
+        unsafe { InitIFF(self.base.as_ptr(), iff, flags, streamHook) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InitIFFasDOS(&self, iff: *mut IFFHandle) {
+        unsafe { InitIFFasDOS(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InitIFFasClip(&self, iff: *mut IFFHandle) {
+        unsafe { InitIFFasClip(self.base.as_ptr(), iff) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenClipboard(&self, unitNumber: LONG) -> *mut ClipboardHandle {
+        unsafe { OpenClipboard(self.base.as_ptr(), unitNumber) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CloseClipboard(&self, clipHandle: *mut ClipboardHandle) {
+        unsafe { CloseClipboard(self.base.as_ptr(), clipHandle) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GoodID(&self, id: LONG) -> LONG {
+        unsafe { GoodID(self.base.as_ptr(), id) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GoodType(&self, type_: LONG) -> LONG {
+        unsafe { GoodType(self.base.as_ptr(), type_) }
+    }
+
+    /// libname: "iffparse.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IDtoStr(&self, id: LONG, buf: STRPTR) -> STRPTR {
+        unsafe { IDtoStr(self.base.as_ptr(), id, buf) }
+    }
+}
+
+impl Drop for IFFParseLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `AllocIFF()`
+pub const LVO_AllocIFF: i16 = -30;
+/// Library vector offset of `OpenIFF()`
+pub const LVO_OpenIFF: i16 = -36;
+/// Library vector offset of `ParseIFF()`
+pub const LVO_ParseIFF: i16 = -42;
+/// Library vector offset of `CloseIFF()`
+pub const LVO_CloseIFF: i16 = -48;
+/// Library vector offset of `FreeIFF()`
+pub const LVO_FreeIFF: i16 = -54;
+/// Library vector offset of `ReadChunkBytes()`
+pub const LVO_ReadChunkBytes: i16 = -60;
+/// Library vector offset of `WriteChunkBytes()`
+pub const LVO_WriteChunkBytes: i16 = -66;
+/// Library vector offset of `ReadChunkRecords()`
+pub const LVO_ReadChunkRecords: i16 = -72;
+/// Library vector offset of `WriteChunkRecords()`
+pub const LVO_WriteChunkRecords: i16 = -78;
+/// Library vector offset of `PushChunk()`
+pub const LVO_PushChunk: i16 = -84;
+/// Library vector offset of `PopChunk()`
+pub const LVO_PopChunk: i16 = -90;
+/// Library vector offset of `EntryHandler()`
+pub const LVO_EntryHandler: i16 = -102;
+/// Library vector offset of `ExitHandler()`
+pub const LVO_ExitHandler: i16 = -108;
+/// Library vector offset of `PropChunk()`
+pub const LVO_PropChunk: i16 = -114;
+/// Library vector offset of `PropChunks()`
+pub const LVO_PropChunks: i16 = -120;
+/// Library vector offset of `StopChunk()`
+pub const LVO_StopChunk: i16 = -126;
+/// Library vector offset of `StopChunks()`
+pub const LVO_StopChunks: i16 = -132;
+/// Library vector offset of `CollectionChunk()`
+pub const LVO_CollectionChunk: i16 = -138;
+/// Library vector offset of `CollectionChunks()`
+pub const LVO_CollectionChunks: i16 = -144;
+/// Library vector offset of `StopOnExit()`
+pub const LVO_StopOnExit: i16 = -150;
+/// Library vector offset of `FindProp()`
+pub const LVO_FindProp: i16 = -156;
+/// Library vector offset of `FindCollection()`
+pub const LVO_FindCollection: i16 = -162;
+/// Library vector offset of `FindPropContext()`
+pub const LVO_FindPropContext: i16 = -168;
+/// Library vector offset of `CurrentChunk()`
+pub const LVO_CurrentChunk: i16 = -174;
+/// Library vector offset of `ParentChunk()`
+pub const LVO_ParentChunk: i16 = -180;
+/// Library vector offset of `AllocLocalItem()`
+pub const LVO_AllocLocalItem: i16 = -186;
+/// Library vector offset of `LocalItemData()`
+pub const LVO_LocalItemData: i16 = -192;
+/// Library vector offset of `SetLocalItemPurge()`
+pub const LVO_SetLocalItemPurge: i16 = -198;
+/// Library vector offset of `FreeLocalItem()`
+pub const LVO_FreeLocalItem: i16 = -204;
+/// Library vector offset of `FindLocalItem()`
+pub const LVO_FindLocalItem: i16 = -210;
+/// Library vector offset of `StoreLocalItem()`
+pub const LVO_StoreLocalItem: i16 = -216;
+/// Library vector offset of `StoreItemInContext()`
+pub const LVO_StoreItemInContext: i16 = -222;
+/// Library vector offset of `InitIFF()`
+pub const LVO_InitIFF: i16 = -228;
+/// Library vector offset of `InitIFFasDOS()`
+pub const LVO_InitIFFasDOS: i16 = -234;
+/// Library vector offset of `InitIFFasClip()`
+pub const LVO_InitIFFasClip: i16 = -240;
+/// Library vector offset of `OpenClipboard()`
+pub const LVO_OpenClipboard: i16 = -246;
+/// Library vector offset of `CloseClipboard()`
+pub const LVO_CloseClipboard: i16 = -252;
+/// Library vector offset of `GoodID()`
+pub const LVO_GoodID: i16 = -258;
+/// Library vector offset of `GoodType()`
+pub const LVO_GoodType: i16 = -264;
+/// Library vector offset of `IDtoStr()`
+pub const LVO_IDtoStr: i16 = -270;
+
+/// Functions of "iffparse.library"
+pub static IFFPARSE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AllocIFF",
+        lvo: LVO_AllocIFF,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenIFF",
+        lvo: LVO_OpenIFF,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParseIFF",
+        lvo: LVO_ParseIFF,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CloseIFF",
+        lvo: LVO_CloseIFF,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeIFF",
+        lvo: LVO_FreeIFF,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadChunkBytes",
+        lvo: LVO_ReadChunkBytes,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WriteChunkBytes",
+        lvo: LVO_WriteChunkBytes,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReadChunkRecords",
+        lvo: LVO_ReadChunkRecords,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WriteChunkRecords",
+        lvo: LVO_WriteChunkRecords,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PushChunk",
+        lvo: LVO_PushChunk,
+        registers: &["a0", "d0", "d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PopChunk",
+        lvo: LVO_PopChunk,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EntryHandler",
+        lvo: LVO_EntryHandler,
+        registers: &["a0", "d0", "d1", "d2", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ExitHandler",
+        lvo: LVO_ExitHandler,
+        registers: &["a0", "d0", "d1", "d2", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PropChunk",
+        lvo: LVO_PropChunk,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PropChunks",
+        lvo: LVO_PropChunks,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StopChunk",
+        lvo: LVO_StopChunk,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StopChunks",
+        lvo: LVO_StopChunks,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CollectionChunk",
+        lvo: LVO_CollectionChunk,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CollectionChunks",
+        lvo: LVO_CollectionChunks,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StopOnExit",
+        lvo: LVO_StopOnExit,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindProp",
+        lvo: LVO_FindProp,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindCollection",
+        lvo: LVO_FindCollection,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindPropContext",
+        lvo: LVO_FindPropContext,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CurrentChunk",
+        lvo: LVO_CurrentChunk,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ParentChunk",
+        lvo: LVO_ParentChunk,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocLocalItem",
+        lvo: LVO_AllocLocalItem,
+        registers: &["d0", "d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LocalItemData",
+        lvo: LVO_LocalItemData,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetLocalItemPurge",
+        lvo: LVO_SetLocalItemPurge,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeLocalItem",
+        lvo: LVO_FreeLocalItem,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FindLocalItem",
+        lvo: LVO_FindLocalItem,
+        registers: &["a0", "d0", "d1", "d2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StoreLocalItem",
+        lvo: LVO_StoreLocalItem,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "StoreItemInContext",
+        lvo: LVO_StoreItemInContext,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InitIFF",
+        lvo: LVO_InitIFF,
+        registers: &["a0", "d0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InitIFFasDOS",
+        lvo: LVO_InitIFFasDOS,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InitIFFasClip",
+        lvo: LVO_InitIFFasClip,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenClipboard",
+        lvo: LVO_OpenClipboard,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CloseClipboard",
+        lvo: LVO_CloseClipboard,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GoodID",
+        lvo: LVO_GoodID,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GoodType",
+        lvo: LVO_GoodType,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IDtoStr",
+        lvo: LVO_IDtoStr,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+];
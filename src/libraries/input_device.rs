@@ -0,0 +1,48 @@
+//! Functions of "input.device"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "input.device" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PeekQualifier(InputBase: *mut ::core::ffi::c_void) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            InputBase as *mut Library,
+            36,
+            "PeekQualifier",
+            "input.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) InputBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// Library vector offset of `PeekQualifier()`
+pub const LVO_PeekQualifier: i16 = -42;
+
+/// Functions of "input.device"
+pub static INPUT_DEVICE_FUNCTIONS: &[crate::FunctionInfo] = &[crate::FunctionInfo {
+    name: "PeekQualifier",
+    lvo: LVO_PeekQualifier,
+    registers: &[],
+    min_version: 36,
+}];
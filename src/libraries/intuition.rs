@@ -0,0 +1,5365 @@
+//! Functions of "intuition.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "intuition.library"
+pub unsafe fn OpenIntuition(IntuitionBase: *mut Library) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn Intuition(IntuitionBase: *mut Library, iEvent: *mut InputEvent) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") iEvent,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn AddGadget(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    gadget: *mut Gadget,
+    position: ULONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") gadget,
+            in("d0") position,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ClearDMRequest(IntuitionBase: *mut Library, window: *mut Window) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ClearMenuStrip(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ClearPointer(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn CloseScreen(IntuitionBase: *mut Library, screen: *mut Screen) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn CloseWindow(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn CloseWorkBench(IntuitionBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn CurrentTime(IntuitionBase: *mut Library, seconds: *mut ULONG, micros: *mut ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") seconds,
+            in("a1") micros,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn DisplayAlert(
+    IntuitionBase: *mut Library,
+    alertNumber: ULONG,
+    string: CONST_STRPTR,
+    height: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") alertNumber,
+            in("a0") string,
+            in("d1") height,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn DisplayBeep(IntuitionBase: *mut Library, screen: *mut Screen) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn DoubleClick(
+    IntuitionBase: *mut Library,
+    sSeconds: ULONG,
+    sMicros: ULONG,
+    cSeconds: ULONG,
+    cMicros: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") sSeconds,
+            in("d1") sMicros,
+            in("d2") cSeconds,
+            in("d3") cMicros,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn DrawBorder(
+    IntuitionBase: *mut Library,
+    rp: *mut RastPort,
+    border: *const Border,
+    leftOffset: LONG,
+    topOffset: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+            in("a1") border,
+            in("d0") leftOffset,
+            in("d1") topOffset,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn DrawImage(
+    IntuitionBase: *mut Library,
+    rp: *mut RastPort,
+    image: *const Image,
+    leftOffset: LONG,
+    topOffset: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+            in("a1") image,
+            in("d0") leftOffset,
+            in("d1") topOffset,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn EndRequest(
+    IntuitionBase: *mut Library,
+    requester: *mut Requester,
+    window: *mut Window,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") requester,
+            in("a1") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn GetDefPrefs(
+    IntuitionBase: *mut Library,
+    preferences: *mut Preferences,
+    size: LONG,
+) -> *mut Preferences {
+    let asm_ret_value: *mut Preferences;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") preferences,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn GetPrefs(
+    IntuitionBase: *mut Library,
+    preferences: *mut Preferences,
+    size: LONG,
+) -> *mut Preferences {
+    let asm_ret_value: *mut Preferences;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") preferences,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn InitRequester(IntuitionBase: *mut Library, requester: *mut Requester) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") requester,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ItemAddress(
+    IntuitionBase: *mut Library,
+    menuStrip: *const Menu,
+    menuNumber: ULONG,
+) -> *mut MenuItem {
+    let asm_ret_value: *mut MenuItem;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") menuStrip,
+            in("d0") menuNumber,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ModifyIDCMP(IntuitionBase: *mut Library, window: *mut Window, flags: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ModifyProp(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    flags: ULONG,
+    horizPot: ULONG,
+    vertPot: ULONG,
+    horizBody: ULONG,
+    vertBody: ULONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+            in("d0") flags,
+            in("d1") horizPot,
+            in("d2") vertPot,
+            in("d3") horizBody,
+            in("d4") vertBody,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn MoveScreen(IntuitionBase: *mut Library, screen: *mut Screen, dx: LONG, dy: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("d0") dx,
+            in("d1") dy,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn MoveWindow(IntuitionBase: *mut Library, window: *mut Window, dx: LONG, dy: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") dx,
+            in("d1") dy,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OffGadget(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OffMenu(IntuitionBase: *mut Library, window: *mut Window, menuNumber: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") menuNumber,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OnGadget(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OnMenu(IntuitionBase: *mut Library, window: *mut Window, menuNumber: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") menuNumber,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OpenScreen(IntuitionBase: *mut Library, newScreen: *const NewScreen) -> *mut Screen {
+    let asm_ret_value: *mut Screen;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") newScreen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OpenWindow(IntuitionBase: *mut Library, newWindow: *const NewWindow) -> *mut Window {
+    let asm_ret_value: *mut Window;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") newWindow,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn OpenWorkBench(IntuitionBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn PrintIText(
+    IntuitionBase: *mut Library,
+    rp: *mut RastPort,
+    iText: *const IntuiText,
+    left: LONG,
+    top: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+            in("a1") iText,
+            in("d0") left,
+            in("d1") top,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn RefreshGadgets(
+    IntuitionBase: *mut Library,
+    gadgets: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadgets,
+            in("a1") window,
+            in("a2") requester,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn RemoveGadget(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    gadget: *mut Gadget,
+) -> UWORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") gadget,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ReportMouse(IntuitionBase: *mut Library, flag: LONG, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") flag,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ReportMouse1(IntuitionBase: *mut Library, window: *mut Window, flag: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") flag,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn Request(
+    IntuitionBase: *mut Library,
+    requester: *mut Requester,
+    window: *mut Window,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") requester,
+            in("a1") window,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ScreenToBack(IntuitionBase: *mut Library, screen: *mut Screen) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ScreenToFront(IntuitionBase: *mut Library, screen: *mut Screen) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SetDMRequest(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    requester: *mut Requester,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") requester,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SetMenuStrip(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    menu: *mut Menu,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") menu,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SetPointer(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    pointer: *mut UWORD,
+    height: LONG,
+    width: LONG,
+    xOffset: LONG,
+    yOffset: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") pointer,
+            in("d0") height,
+            in("d1") width,
+            in("d2") xOffset,
+            in("d3") yOffset,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SetWindowTitles(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    windowTitle: CONST_STRPTR,
+    screenTitle: CONST_STRPTR,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-276, %a6)
+            ".short -276",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") windowTitle,
+            in("a2") screenTitle,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ShowTitle(IntuitionBase: *mut Library, screen: *mut Screen, showIt: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-282, %a6)
+            ".short -282",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("d0") showIt,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SizeWindow(IntuitionBase: *mut Library, window: *mut Window, dx: LONG, dy: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-288, %a6)
+            ".short -288",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") dx,
+            in("d1") dy,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ViewAddress(IntuitionBase: *mut Library) -> *mut View {
+    let asm_ret_value: *mut View;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-294, %a6)
+            ".short -294",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn ViewPortAddress(IntuitionBase: *mut Library, window: *const Window) -> *mut ViewPort {
+    let asm_ret_value: *mut ViewPort;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-300, %a6)
+            ".short -300",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn WindowToBack(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-306, %a6)
+            ".short -306",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn WindowToFront(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-312, %a6)
+            ".short -312",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn WindowLimits(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    widthMin: LONG,
+    heightMin: LONG,
+    widthMax: ULONG,
+    heightMax: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-318, %a6)
+            ".short -318",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") widthMin,
+            in("d1") heightMin,
+            in("d2") widthMax,
+            in("d3") heightMax,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn SetPrefs(
+    IntuitionBase: *mut Library,
+    preferences: *const Preferences,
+    size: LONG,
+    inform: LONG,
+) -> *mut Preferences {
+    let asm_ret_value: *mut Preferences;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-324, %a6)
+            ".short -324",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") preferences,
+            in("d0") size,
+            in("d1") inform,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn IntuiTextLength(IntuitionBase: *mut Library, iText: *const IntuiText) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-330, %a6)
+            ".short -330",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") iText,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn WBenchToBack(IntuitionBase: *mut Library) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-336, %a6)
+            ".short -336",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn WBenchToFront(IntuitionBase: *mut Library) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-342, %a6)
+            ".short -342",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn AutoRequest(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    body: *const IntuiText,
+    posText: *const IntuiText,
+    negText: *const IntuiText,
+    pFlag: ULONG,
+    nFlag: ULONG,
+    width: ULONG,
+    height: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-348, %a6)
+            ".short -348",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") body,
+            in("a2") posText,
+            in("a3") negText,
+            in("d0") pFlag,
+            in("d1") nFlag,
+            in("d2") width,
+            in("d3") height,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library"
+pub unsafe fn BeginRefresh(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-354, %a6)
+            ".short -354",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn BuildSysRequest(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    body: *const IntuiText,
+    posText: *const IntuiText,
+    negText: *const IntuiText,
+    flags: ULONG,
+    width: ULONG,
+    height: ULONG,
+) -> *mut Window {
+    let asm_ret_value: *mut Window;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-360, %a6)
+            ".short -360",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") body,
+            in("a2") posText,
+            in("a3") negText,
+            in("d0") flags,
+            in("d1") width,
+            in("d2") height,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn EndRefresh(IntuitionBase: *mut Library, window: *mut Window, complete: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-366, %a6)
+            ".short -366",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") complete,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn FreeSysRequest(IntuitionBase: *mut Library, window: *mut Window) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-372, %a6)
+            ".short -372",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn MakeScreen(IntuitionBase: *mut Library, screen: *mut Screen) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-378, %a6)
+            ".short -378",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn RemakeDisplay(IntuitionBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-384, %a6)
+            ".short -384",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn RethinkDisplay(IntuitionBase: *mut Library) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-390, %a6)
+            ".short -390",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn AllocRemember(
+    IntuitionBase: *mut Library,
+    rememberKey: *mut *mut Remember,
+    size: ULONG,
+    flags: ULONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-396, %a6)
+            ".short -396",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rememberKey,
+            in("d0") size,
+            in("d1") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn AlohaWorkbench(IntuitionBase: *mut Library, wbport: LONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-402, %a6)
+            ".short -402",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") wbport,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn FreeRemember(
+    IntuitionBase: *mut Library,
+    rememberKey: *mut *mut Remember,
+    reallyForget: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-408, %a6)
+            ".short -408",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rememberKey,
+            in("d0") reallyForget,
+        );
+    }
+}
+
+/// libname: "intuition.library"
+pub unsafe fn LockIBase(IntuitionBase: *mut Library, dontknow: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-414, %a6)
+            ".short -414",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") dontknow,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library"
+pub unsafe fn UnlockIBase(IntuitionBase: *mut Library, ibLock: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-420, %a6)
+            ".short -420",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") ibLock,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn GetScreenData(
+    IntuitionBase: *mut Library,
+    buffer: APTR,
+    size: ULONG,
+    type_: ULONG,
+    screen: *const Screen,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "GetScreenData", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-426, %a6)
+            ".short -426",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") buffer,
+            in("d0") size,
+            in("d1") type_,
+            in("a1") screen,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn RefreshGList(
+    IntuitionBase: *mut Library,
+    gadgets: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    numGad: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "RefreshGList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-432, %a6)
+            ".short -432",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadgets,
+            in("a1") window,
+            in("a2") requester,
+            in("d0") numGad,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn AddGList(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    gadget: *mut Gadget,
+    position: ULONG,
+    numGad: LONG,
+    requester: *mut Requester,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "AddGList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-438, %a6)
+            ".short -438",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") gadget,
+            in("d0") position,
+            in("d1") numGad,
+            in("a2") requester,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn RemoveGList(
+    IntuitionBase: *mut Library,
+    remPtr: *mut Window,
+    gadget: *mut Gadget,
+    numGad: LONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "RemoveGList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-444, %a6)
+            ".short -444",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") remPtr,
+            in("a1") gadget,
+            in("d0") numGad,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn ActivateWindow(IntuitionBase: *mut Library, window: *mut Window) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "ActivateWindow", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn RefreshWindowFrame(IntuitionBase: *mut Library, window: *mut Window) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "RefreshWindowFrame", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn ActivateGadget(
+    IntuitionBase: *mut Library,
+    gadgets: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "ActivateGadget", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-462, %a6)
+            ".short -462",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadgets,
+            in("a1") window,
+            in("a2") requester,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V33) (Kickstart 1.2)
+pub unsafe fn NewModifyProp(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    flags: ULONG,
+    horizPot: ULONG,
+    vertPot: ULONG,
+    horizBody: ULONG,
+    vertBody: ULONG,
+    numGad: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 33, "NewModifyProp", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-468, %a6)
+            ".short -468",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+            in("d0") flags,
+            in("d1") horizPot,
+            in("d2") vertPot,
+            in("d3") horizBody,
+            in("d4") vertBody,
+            in("d5") numGad,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn QueryOverscan(
+    IntuitionBase: *mut Library,
+    displayID: ULONG,
+    rect: *mut Rectangle,
+    oScanType: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "QueryOverscan", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-474, %a6)
+            ".short -474",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") displayID,
+            in("a1") rect,
+            in("d0") oScanType,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MoveWindowInFrontOf(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    behindWindow: *mut Window,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            IntuitionBase,
+            36,
+            "MoveWindowInFrontOf",
+            "intuition.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-480, %a6)
+            ".short -480",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") behindWindow,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ChangeWindowBox(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    left: LONG,
+    top: LONG,
+    width: LONG,
+    height: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "ChangeWindowBox", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-486, %a6)
+            ".short -486",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") left,
+            in("d1") top,
+            in("d2") width,
+            in("d3") height,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetEditHook(IntuitionBase: *mut Library, hook: *mut Hook) -> *mut Hook {
+    let asm_ret_value: *mut Hook;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SetEditHook", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-492, %a6)
+            ".short -492",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") hook,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetMouseQueue(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    queueLength: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SetMouseQueue", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("d0") queueLength,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ZipWindow(IntuitionBase: *mut Library, window: *mut Window) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "ZipWindow", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-504, %a6)
+            ".short -504",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockPubScreen(IntuitionBase: *mut Library, name: CONST_STRPTR) -> *mut Screen {
+    let asm_ret_value: *mut Screen;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "LockPubScreen", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-510, %a6)
+            ".short -510",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnlockPubScreen(
+    IntuitionBase: *mut Library,
+    name: CONST_STRPTR,
+    screen: *mut Screen,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "UnlockPubScreen", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-516, %a6)
+            ".short -516",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") name,
+            in("a1") screen,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockPubScreenList(IntuitionBase: *mut Library) -> *mut List {
+    let asm_ret_value: *mut List;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "LockPubScreenList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-522, %a6)
+            ".short -522",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnlockPubScreenList(IntuitionBase: *mut Library) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            IntuitionBase,
+            36,
+            "UnlockPubScreenList",
+            "intuition.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-528, %a6)
+            ".short -528",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NextPubScreen(
+    IntuitionBase: *mut Library,
+    screen: *const Screen,
+    namebuf: STRPTR,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "NextPubScreen", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-534, %a6)
+            ".short -534",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("a1") namebuf,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetDefaultPubScreen(IntuitionBase: *mut Library, name: CONST_STRPTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            IntuitionBase,
+            36,
+            "SetDefaultPubScreen",
+            "intuition.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-540, %a6)
+            ".short -540",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") name,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetPubScreenModes(IntuitionBase: *mut Library, modes: ULONG) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SetPubScreenModes", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-546, %a6)
+            ".short -546",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") modes,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PubScreenStatus(
+    IntuitionBase: *mut Library,
+    screen: *mut Screen,
+    statusFlags: ULONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "PubScreenStatus", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-552, %a6)
+            ".short -552",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("d0") statusFlags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ObtainGIRPort(IntuitionBase: *mut Library, gInfo: *mut GadgetInfo) -> *mut RastPort {
+    let asm_ret_value: *mut RastPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "ObtainGIRPort", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-558, %a6)
+            ".short -558",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gInfo,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReleaseGIRPort(IntuitionBase: *mut Library, rp: *mut RastPort) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "ReleaseGIRPort", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-564, %a6)
+            ".short -564",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GadgetMouse(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    gInfo: *mut GadgetInfo,
+    mousePoint: *mut WORD,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "GadgetMouse", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-570, %a6)
+            ".short -570",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") gInfo,
+            in("a2") mousePoint,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetDefaultPubScreen(IntuitionBase: *mut Library, nameBuffer: STRPTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            IntuitionBase,
+            36,
+            "GetDefaultPubScreen",
+            "intuition.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-582, %a6)
+            ".short -582",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") nameBuffer,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EasyRequestArgs(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    easyStruct: *const EasyStruct,
+    idcmpPtr: *mut ULONG,
+    args: CONST_APTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "EasyRequestArgs", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-588, %a6)
+            ".short -588",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") easyStruct,
+            in("a2") idcmpPtr,
+            in("a3") args,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn BuildEasyRequestArgs(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    easyStruct: *const EasyStruct,
+    idcmp: ULONG,
+    args: CONST_APTR,
+) -> *mut Window {
+    let asm_ret_value: *mut Window;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            IntuitionBase,
+            36,
+            "BuildEasyRequestArgs",
+            "intuition.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-594, %a6)
+            ".short -594",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") easyStruct,
+            in("d0") idcmp,
+            in("a3") args,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SysReqHandler(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    idcmpPtr: *mut ULONG,
+    waitInput: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SysReqHandler", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-600, %a6)
+            ".short -600",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") idcmpPtr,
+            in("d0") waitInput,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenWindowTagList(
+    IntuitionBase: *mut Library,
+    newWindow: *const NewWindow,
+    tagList: *const TagItem,
+) -> *mut Window {
+    let asm_ret_value: *mut Window;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "OpenWindowTagList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-606, %a6)
+            ".short -606",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") newWindow,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn OpenScreenTagList(
+    IntuitionBase: *mut Library,
+    newScreen: *const NewScreen,
+    tagList: *const TagItem,
+) -> *mut Screen {
+    let asm_ret_value: *mut Screen;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "OpenScreenTagList", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-612, %a6)
+            ".short -612",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") newScreen,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DrawImageState(
+    IntuitionBase: *mut Library,
+    rp: *mut RastPort,
+    image: *const Image,
+    leftOffset: LONG,
+    topOffset: LONG,
+    state: ULONG,
+    drawInfo: *mut DrawInfo,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "DrawImageState", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-618, %a6)
+            ".short -618",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+            in("a1") image,
+            in("d0") leftOffset,
+            in("d1") topOffset,
+            in("d2") state,
+            in("a2") drawInfo,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PointInImage(IntuitionBase: *mut Library, point: ULONG, image: *const Image) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "PointInImage", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-624, %a6)
+            ".short -624",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") point,
+            in("a0") image,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn EraseImage(
+    IntuitionBase: *mut Library,
+    rp: *mut RastPort,
+    image: *const Image,
+    leftOffset: LONG,
+    topOffset: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "EraseImage", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-630, %a6)
+            ".short -630",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") rp,
+            in("a1") image,
+            in("d0") leftOffset,
+            in("d1") topOffset,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NewObjectA(
+    IntuitionBase: *mut Library,
+    classPtr: *mut IClass,
+    classID: CONST_STRPTR,
+    tagList: *const TagItem,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "NewObjectA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-636, %a6)
+            ".short -636",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") classPtr,
+            in("a1") classID,
+            in("a2") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DisposeObject(IntuitionBase: *mut Library, object: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "DisposeObject", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-642, %a6)
+            ".short -642",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") object,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetAttrsA(
+    IntuitionBase: *mut Library,
+    object: APTR,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SetAttrsA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-648, %a6)
+            ".short -648",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") object,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetAttr(
+    IntuitionBase: *mut Library,
+    attrID: ULONG,
+    object: APTR,
+    storagePtr: *mut ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "GetAttr", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-654, %a6)
+            ".short -654",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") attrID,
+            in("a0") object,
+            in("a1") storagePtr,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetGadgetAttrsA(
+    IntuitionBase: *mut Library,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "SetGadgetAttrsA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-660, %a6)
+            ".short -660",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+            in("a3") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NextObject(IntuitionBase: *mut Library, objectPtrPtr: CONST_APTR) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "NextObject", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-666, %a6)
+            ".short -666",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") objectPtrPtr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MakeClass(
+    IntuitionBase: *mut Library,
+    classID: CONST_STRPTR,
+    superClassID: CONST_STRPTR,
+    superClassPtr: *const IClass,
+    instanceSize: ULONG,
+    flags: ULONG,
+) -> *mut IClass {
+    let asm_ret_value: *mut IClass;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "MakeClass", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-678, %a6)
+            ".short -678",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") classID,
+            in("a1") superClassID,
+            in("a2") superClassPtr,
+            in("d0") instanceSize,
+            in("d1") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddClass(IntuitionBase: *mut Library, classPtr: *mut IClass) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "AddClass", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-684, %a6)
+            ".short -684",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") classPtr,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetScreenDrawInfo(IntuitionBase: *mut Library, screen: *mut Screen) -> *mut DrawInfo {
+    let asm_ret_value: *mut DrawInfo;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "GetScreenDrawInfo", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-690, %a6)
+            ".short -690",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeScreenDrawInfo(
+    IntuitionBase: *mut Library,
+    screen: *mut Screen,
+    drawInfo: *mut DrawInfo,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "FreeScreenDrawInfo", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-696, %a6)
+            ".short -696",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("a1") drawInfo,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ResetMenuStrip(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    menu: *mut Menu,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "ResetMenuStrip", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-702, %a6)
+            ".short -702",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") menu,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemoveClass(IntuitionBase: *mut Library, classPtr: *mut IClass) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "RemoveClass", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-708, %a6)
+            ".short -708",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") classPtr,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeClass(IntuitionBase: *mut Library, classPtr: *mut IClass) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 36, "FreeClass", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-714, %a6)
+            ".short -714",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") classPtr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocScreenBuffer(
+    IntuitionBase: *mut Library,
+    sc: *mut Screen,
+    bm: *mut BitMap,
+    flags: ULONG,
+) -> *mut ScreenBuffer {
+    let asm_ret_value: *mut ScreenBuffer;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "AllocScreenBuffer", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-768, %a6)
+            ".short -768",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") sc,
+            in("a1") bm,
+            in("d0") flags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreeScreenBuffer(
+    IntuitionBase: *mut Library,
+    sc: *mut Screen,
+    sb: *mut ScreenBuffer,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "FreeScreenBuffer", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-774, %a6)
+            ".short -774",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") sc,
+            in("a1") sb,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ChangeScreenBuffer(
+    IntuitionBase: *mut Library,
+    sc: *mut Screen,
+    sb: *mut ScreenBuffer,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "ChangeScreenBuffer", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-780, %a6)
+            ".short -780",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") sc,
+            in("a1") sb,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ScreenDepth(
+    IntuitionBase: *mut Library,
+    screen: *mut Screen,
+    flags: ULONG,
+    reserved: APTR,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "ScreenDepth", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-786, %a6)
+            ".short -786",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("d0") flags,
+            in("a1") reserved,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ScreenPosition(
+    IntuitionBase: *mut Library,
+    screen: *mut Screen,
+    flags: ULONG,
+    x1: LONG,
+    y1: LONG,
+    x2: LONG,
+    y2: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "ScreenPosition", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-792, %a6)
+            ".short -792",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") screen,
+            in("d0") flags,
+            in("d1") x1,
+            in("d2") y1,
+            in("d3") x2,
+            in("d4") y2,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ScrollWindowRaster(
+    IntuitionBase: *mut Library,
+    win: *mut Window,
+    dx: LONG,
+    dy: LONG,
+    xMin: LONG,
+    yMin: LONG,
+    xMax: LONG,
+    yMax: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "ScrollWindowRaster", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-798, %a6)
+            ".short -798",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a1") win,
+            in("d0") dx,
+            in("d1") dy,
+            in("d2") xMin,
+            in("d3") yMin,
+            in("d4") xMax,
+            in("d5") yMax,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn LendMenus(
+    IntuitionBase: *mut Library,
+    fromwindow: *mut Window,
+    towindow: *mut Window,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "LendMenus", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-804, %a6)
+            ".short -804",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") fromwindow,
+            in("a1") towindow,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn DoGadgetMethodA(
+    IntuitionBase: *mut Library,
+    gad: *mut Gadget,
+    win: *mut Window,
+    req: *mut Requester,
+    message: Msg,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "DoGadgetMethodA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-810, %a6)
+            ".short -810",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") gad,
+            in("a1") win,
+            in("a2") req,
+            in("a3") message,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SetWindowPointerA(
+    IntuitionBase: *mut Library,
+    win: *mut Window,
+    taglist: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "SetWindowPointerA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-816, %a6)
+            ".short -816",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") win,
+            in("a1") taglist,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn TimedDisplayAlert(
+    IntuitionBase: *mut Library,
+    alertNumber: ULONG,
+    string: CONST_STRPTR,
+    height: ULONG,
+    time: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "TimedDisplayAlert", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-822, %a6)
+            ".short -822",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("d0") alertNumber,
+            in("a0") string,
+            in("d1") height,
+            in("a1") time,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn HelpControl(IntuitionBase: *mut Library, win: *mut Window, flags: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 39, "HelpControl", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-828, %a6)
+            ".short -828",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") win,
+            in("d0") flags,
+        );
+    }
+}
+
+/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ShowWindow(
+    IntuitionBase: *mut Library,
+    window: *mut Window,
+    other: *mut Window,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 47, "ShowWindow", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-834, %a6)
+            ".short -834",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            in("a1") other,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn HideWindow(IntuitionBase: *mut Library, window: *mut Window) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 47, "HideWindow", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-840, %a6)
+            ".short -840",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") window,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn IntuitionControlA(
+    IntuitionBase: *mut Library,
+    object: APTR,
+    taglist: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(IntuitionBase, 47, "IntuitionControlA", "intuition.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-1212, %a6)
+            ".short -1212",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) IntuitionBase,
+            in("a0") object,
+            in("a1") taglist,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "intuition.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct IntuitionLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl IntuitionLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"intuition.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "intuition.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OpenIntuition(&self) {
+        unsafe { OpenIntuition(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn Intuition(&self, iEvent: *mut InputEvent) {
+        unsafe { Intuition(self.base.as_ptr(), iEvent) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn AddGadget(
+        &self,
+        window: *mut Window,
+        gadget: *mut Gadget,
+        position: ULONG,
+    ) -> UWORD {
+        unsafe { AddGadget(self.base.as_ptr(), window, gadget, position) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ClearDMRequest(&self, window: *mut Window) -> BOOL {
+        unsafe { ClearDMRequest(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ClearMenuStrip(&self, window: *mut Window) {
+        unsafe { ClearMenuStrip(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ClearPointer(&self, window: *mut Window) {
+        unsafe { ClearPointer(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn CloseScreen(&self, screen: *mut Screen) -> BOOL {
+        unsafe { CloseScreen(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn CloseWindow(&self, window: *mut Window) {
+        unsafe { CloseWindow(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn CloseWorkBench(&self) -> LONG {
+        unsafe { CloseWorkBench(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn CurrentTime(&self, seconds: *mut ULONG, micros: *mut ULONG) {
+        unsafe { CurrentTime(self.base.as_ptr(), seconds, micros) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn DisplayAlert(
+        &self,
+        alertNumber: ULONG,
+        string: CONST_STRPTR,
+        height: ULONG,
+    ) -> BOOL {
+        unsafe { DisplayAlert(self.base.as_ptr(), alertNumber, string, height) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn DisplayBeep(&self, screen: *mut Screen) {
+        unsafe { DisplayBeep(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn DoubleClick(
+        &self,
+        sSeconds: ULONG,
+        sMicros: ULONG,
+        cSeconds: ULONG,
+        cMicros: ULONG,
+    ) -> BOOL {
+        unsafe { DoubleClick(self.base.as_ptr(), sSeconds, sMicros, cSeconds, cMicros) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn DrawBorder(
+        &self,
+        rp: *mut RastPort,
+        border: *const Border,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe { DrawBorder(self.base.as_ptr(), rp, border, leftOffset, topOffset) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn DrawImage(
+        &self,
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe { DrawImage(self.base.as_ptr(), rp, image, leftOffset, topOffset) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn EndRequest(&self, requester: *mut Requester, window: *mut Window) {
+        unsafe { EndRequest(self.base.as_ptr(), requester, window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn GetDefPrefs(
+        &self,
+        preferences: *mut Preferences,
+        size: LONG,
+    ) -> *mut Preferences {
+        unsafe { GetDefPrefs(self.base.as_ptr(), preferences, size) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn GetPrefs(&self, preferences: *mut Preferences, size: LONG) -> *mut Preferences {
+        unsafe { GetPrefs(self.base.as_ptr(), preferences, size) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn InitRequester(&self, requester: *mut Requester) {
+        unsafe { InitRequester(self.base.as_ptr(), requester) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ItemAddress(&self, menuStrip: *const Menu, menuNumber: ULONG) -> *mut MenuItem {
+        unsafe { ItemAddress(self.base.as_ptr(), menuStrip, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ModifyIDCMP(&self, window: *mut Window, flags: ULONG) -> BOOL {
+        unsafe { ModifyIDCMP(self.base.as_ptr(), window, flags) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ModifyProp(
+        &self,
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        flags: ULONG,
+        horizPot: ULONG,
+        vertPot: ULONG,
+        horizBody: ULONG,
+        vertBody: ULONG,
+    ) {
+        unsafe {
+            ModifyProp(
+                self.base.as_ptr(),
+                gadget,
+                window,
+                requester,
+                flags,
+                horizPot,
+                vertPot,
+                horizBody,
+                vertBody,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn MoveScreen(&self, screen: *mut Screen, dx: LONG, dy: LONG) {
+        unsafe { MoveScreen(self.base.as_ptr(), screen, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn MoveWindow(&self, window: *mut Window, dx: LONG, dy: LONG) {
+        unsafe { MoveWindow(self.base.as_ptr(), window, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OffGadget(
+        &self,
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe { OffGadget(self.base.as_ptr(), gadget, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OffMenu(&self, window: *mut Window, menuNumber: ULONG) {
+        unsafe { OffMenu(self.base.as_ptr(), window, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OnGadget(
+        &self,
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe { OnGadget(self.base.as_ptr(), gadget, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OnMenu(&self, window: *mut Window, menuNumber: ULONG) {
+        unsafe { OnMenu(self.base.as_ptr(), window, menuNumber) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OpenScreen(&self, newScreen: *const NewScreen) -> *mut Screen {
+        unsafe { OpenScreen(self.base.as_ptr(), newScreen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OpenWindow(&self, newWindow: *const NewWindow) -> *mut Window {
+        unsafe { OpenWindow(self.base.as_ptr(), newWindow) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn OpenWorkBench(&self) -> ULONG {
+        unsafe { OpenWorkBench(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn PrintIText(
+        &self,
+        rp: *mut RastPort,
+        iText: *const IntuiText,
+        left: LONG,
+        top: LONG,
+    ) {
+        unsafe { PrintIText(self.base.as_ptr(), rp, iText, left, top) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn RefreshGadgets(
+        &self,
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) {
+        unsafe { RefreshGadgets(self.base.as_ptr(), gadgets, window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn RemoveGadget(&self, window: *mut Window, gadget: *mut Gadget) -> UWORD {
+        unsafe { RemoveGadget(self.base.as_ptr(), window, gadget) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ReportMouse(&self, flag: LONG, window: *mut Window) {
+        unsafe { ReportMouse(self.base.as_ptr(), flag, window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ReportMouse1(&self, window: *mut Window, flag: LONG) {
+        unsafe { ReportMouse1(self.base.as_ptr(), window, flag) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn Request(&self, requester: *mut Requester, window: *mut Window) -> BOOL {
+        unsafe { Request(self.base.as_ptr(), requester, window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ScreenToBack(&self, screen: *mut Screen) {
+        unsafe { ScreenToBack(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ScreenToFront(&self, screen: *mut Screen) {
+        unsafe { ScreenToFront(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SetDMRequest(&self, window: *mut Window, requester: *mut Requester) -> BOOL {
+        unsafe { SetDMRequest(self.base.as_ptr(), window, requester) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SetMenuStrip(&self, window: *mut Window, menu: *mut Menu) -> BOOL {
+        unsafe { SetMenuStrip(self.base.as_ptr(), window, menu) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SetPointer(
+        &self,
+        window: *mut Window,
+        pointer: *mut UWORD,
+        height: LONG,
+        width: LONG,
+        xOffset: LONG,
+        yOffset: LONG,
+    ) {
+        unsafe {
+            SetPointer(
+                self.base.as_ptr(),
+                window,
+                pointer,
+                height,
+                width,
+                xOffset,
+                yOffset,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SetWindowTitles(
+        &self,
+        window: *mut Window,
+        windowTitle: CONST_STRPTR,
+        screenTitle: CONST_STRPTR,
+    ) {
+        unsafe { SetWindowTitles(self.base.as_ptr(), window, windowTitle, screenTitle) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ShowTitle(&self, screen: *mut Screen, showIt: LONG) {
+        unsafe { ShowTitle(self.base.as_ptr(), screen, showIt) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SizeWindow(&self, window: *mut Window, dx: LONG, dy: LONG) {
+        unsafe { SizeWindow(self.base.as_ptr(), window, dx, dy) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ViewAddress(&self) -> *mut View {
+        unsafe { ViewAddress(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn ViewPortAddress(&self, window: *const Window) -> *mut ViewPort {
+        unsafe { ViewPortAddress(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn WindowToBack(&self, window: *mut Window) {
+        unsafe { WindowToBack(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn WindowToFront(&self, window: *mut Window) {
+        unsafe { WindowToFront(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn WindowLimits(
+        &self,
+        window: *mut Window,
+        widthMin: LONG,
+        heightMin: LONG,
+        widthMax: ULONG,
+        heightMax: ULONG,
+    ) -> BOOL {
+        unsafe {
+            WindowLimits(
+                self.base.as_ptr(),
+                window,
+                widthMin,
+                heightMin,
+                widthMax,
+                heightMax,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn SetPrefs(
+        &self,
+        preferences: *const Preferences,
+        size: LONG,
+        inform: LONG,
+    ) -> *mut Preferences {
+        unsafe { SetPrefs(self.base.as_ptr(), preferences, size, inform) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn IntuiTextLength(&self, iText: *const IntuiText) -> LONG {
+        unsafe { IntuiTextLength(self.base.as_ptr(), iText) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn WBenchToBack(&self) -> BOOL {
+        unsafe { WBenchToBack(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn WBenchToFront(&self) -> BOOL {
+        unsafe { WBenchToFront(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn AutoRequest(
+        &self,
+        window: *mut Window,
+        body: *const IntuiText,
+        posText: *const IntuiText,
+        negText: *const IntuiText,
+        pFlag: ULONG,
+        nFlag: ULONG,
+        width: ULONG,
+        height: ULONG,
+    ) -> BOOL {
+        unsafe {
+            AutoRequest(
+                self.base.as_ptr(),
+                window,
+                body,
+                posText,
+                negText,
+                pFlag,
+                nFlag,
+                width,
+                height,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn BeginRefresh(&self, window: *mut Window) {
+        unsafe { BeginRefresh(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn BuildSysRequest(
+        &self,
+        window: *mut Window,
+        body: *const IntuiText,
+        posText: *const IntuiText,
+        negText: *const IntuiText,
+        flags: ULONG,
+        width: ULONG,
+        height: ULONG,
+    ) -> *mut Window {
+        unsafe {
+            BuildSysRequest(
+                self.base.as_ptr(),
+                window,
+                body,
+                posText,
+                negText,
+                flags,
+                width,
+                height,
+            )
+        }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn EndRefresh(&self, window: *mut Window, complete: LONG) {
+        unsafe { EndRefresh(self.base.as_ptr(), window, complete) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn FreeSysRequest(&self, window: *mut Window) {
+        unsafe { FreeSysRequest(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn MakeScreen(&self, screen: *mut Screen) -> LONG {
+        unsafe { MakeScreen(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn RemakeDisplay(&self) -> LONG {
+        unsafe { RemakeDisplay(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn RethinkDisplay(&self) -> LONG {
+        unsafe { RethinkDisplay(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn AllocRemember(
+        &self,
+        rememberKey: *mut *mut Remember,
+        size: ULONG,
+        flags: ULONG,
+    ) -> APTR {
+        unsafe { AllocRemember(self.base.as_ptr(), rememberKey, size, flags) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn AlohaWorkbench(&self, wbport: LONG) {
+        unsafe { AlohaWorkbench(self.base.as_ptr(), wbport) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn FreeRemember(&self, rememberKey: *mut *mut Remember, reallyForget: LONG) {
+        unsafe { FreeRemember(self.base.as_ptr(), rememberKey, reallyForget) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn LockIBase(&self, dontknow: ULONG) -> ULONG {
+        unsafe { LockIBase(self.base.as_ptr(), dontknow) }
+    }
+
+    /// libname: "intuition.library"
+    pub unsafe fn UnlockIBase(&self, ibLock: ULONG) {
+        unsafe { UnlockIBase(self.base.as_ptr(), ibLock) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn GetScreenData(
+        &self,
+        buffer: APTR,
+        size: ULONG,
+        type_: ULONG,
+        screen: *const Screen,
+    ) -> LONG {
+        unsafe { GetScreenData(self.base.as_ptr(), buffer, size, type_, screen) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn RefreshGList(
+        &self,
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        numGad: LONG,
+    ) {
+        unsafe { RefreshGList(self.base.as_ptr(), gadgets, window, requester, numGad) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn AddGList(
+        &self,
+        window: *mut Window,
+        gadget: *mut Gadget,
+        position: ULONG,
+        numGad: LONG,
+        requester: *mut Requester,
+    ) -> UWORD {
+        unsafe {
+            AddGList(
+                self.base.as_ptr(),
+                window,
+                gadget,
+                position,
+                numGad,
+                requester,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn RemoveGList(
+        &self,
+        remPtr: *mut Window,
+        gadget: *mut Gadget,
+        numGad: LONG,
+    ) -> UWORD {
+        unsafe { RemoveGList(self.base.as_ptr(), remPtr, gadget, numGad) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ActivateWindow(&self, window: *mut Window) {
+        unsafe { ActivateWindow(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn RefreshWindowFrame(&self, window: *mut Window) {
+        unsafe { RefreshWindowFrame(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn ActivateGadget(
+        &self,
+        gadgets: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+    ) -> BOOL {
+        unsafe { ActivateGadget(self.base.as_ptr(), gadgets, window, requester) }
+    }
+
+    /// libname: "intuition.library" (V33) (Kickstart 1.2)
+    pub unsafe fn NewModifyProp(
+        &self,
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        flags: ULONG,
+        horizPot: ULONG,
+        vertPot: ULONG,
+        horizBody: ULONG,
+        vertBody: ULONG,
+        numGad: LONG,
+    ) {
+        unsafe {
+            NewModifyProp(
+                self.base.as_ptr(),
+                gadget,
+                window,
+                requester,
+                flags,
+                horizPot,
+                vertPot,
+                horizBody,
+                vertBody,
+                numGad,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn QueryOverscan(
+        &self,
+        displayID: ULONG,
+        rect: *mut Rectangle,
+        oScanType: LONG,
+    ) -> LONG {
+        unsafe { QueryOverscan(self.base.as_ptr(), displayID, rect, oScanType) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MoveWindowInFrontOf(&self, window: *mut Window, behindWindow: *mut Window) {
+        unsafe { MoveWindowInFrontOf(self.base.as_ptr(), window, behindWindow) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ChangeWindowBox(
+        &self,
+        window: *mut Window,
+        left: LONG,
+        top: LONG,
+        width: LONG,
+        height: LONG,
+    ) {
+        unsafe { ChangeWindowBox(self.base.as_ptr(), window, left, top, width, height) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetEditHook(&self, hook: *mut Hook) -> *mut Hook {
+        unsafe { SetEditHook(self.base.as_ptr(), hook) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetMouseQueue(&self, window: *mut Window, queueLength: ULONG) -> LONG {
+        unsafe { SetMouseQueue(self.base.as_ptr(), window, queueLength) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ZipWindow(&self, window: *mut Window) {
+        unsafe { ZipWindow(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockPubScreen(&self, name: CONST_STRPTR) -> *mut Screen {
+        unsafe { LockPubScreen(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnlockPubScreen(&self, name: CONST_STRPTR, screen: *mut Screen) {
+        unsafe { UnlockPubScreen(self.base.as_ptr(), name, screen) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockPubScreenList(&self) -> *mut List {
+        unsafe { LockPubScreenList(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnlockPubScreenList(&self) {
+        unsafe { UnlockPubScreenList(self.base.as_ptr()) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NextPubScreen(&self, screen: *const Screen, namebuf: STRPTR) -> STRPTR {
+        unsafe { NextPubScreen(self.base.as_ptr(), screen, namebuf) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetDefaultPubScreen(&self, name: CONST_STRPTR) {
+        unsafe { SetDefaultPubScreen(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetPubScreenModes(&self, modes: ULONG) -> UWORD {
+        unsafe { SetPubScreenModes(self.base.as_ptr(), modes) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PubScreenStatus(&self, screen: *mut Screen, statusFlags: ULONG) -> UWORD {
+        unsafe { PubScreenStatus(self.base.as_ptr(), screen, statusFlags) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ObtainGIRPort(&self, gInfo: *mut GadgetInfo) -> *mut RastPort {
+        unsafe { ObtainGIRPort(self.base.as_ptr(), gInfo) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ReleaseGIRPort(&self, rp: *mut RastPort) {
+        unsafe { ReleaseGIRPort(self.base.as_ptr(), rp) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GadgetMouse(
+        &self,
+        gadget: *mut Gadget,
+        gInfo: *mut GadgetInfo,
+        mousePoint: *mut WORD,
+    ) {
+        unsafe { GadgetMouse(self.base.as_ptr(), gadget, gInfo, mousePoint) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetDefaultPubScreen(&self, nameBuffer: STRPTR) {
+        unsafe { GetDefaultPubScreen(self.base.as_ptr(), nameBuffer) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EasyRequestArgs(
+        &self,
+        window: *mut Window,
+        easyStruct: *const EasyStruct,
+        idcmpPtr: *mut ULONG,
+        args: CONST_APTR,
+    ) -> LONG {
+        unsafe { EasyRequestArgs(self.base.as_ptr(), window, easyStruct, idcmpPtr, args) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn BuildEasyRequestArgs(
+        &self,
+        window: *mut Window,
+        easyStruct: *const EasyStruct,
+        idcmp: ULONG,
+        args: CONST_APTR,
+    ) -> *mut Window {
+        unsafe { BuildEasyRequestArgs(self.base.as_ptr(), window, easyStruct, idcmp, args) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SysReqHandler(
+        &self,
+        window: *mut Window,
+        idcmpPtr: *mut ULONG,
+        waitInput: LONG,
+    ) -> LONG {
+        unsafe { SysReqHandler(self.base.as_ptr(), window, idcmpPtr, waitInput) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenWindowTagList(
+        &self,
+        newWindow: *const NewWindow,
+        tagList: *const TagItem,
+    ) -> *mut Window {
+        unsafe { OpenWindowTagList(self.base.as_ptr(), newWindow, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn OpenScreenTagList(
+        &self,
+        newScreen: *const NewScreen,
+        tagList: *const TagItem,
+    ) -> *mut Screen {
+        unsafe { OpenScreenTagList(self.base.as_ptr(), newScreen, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DrawImageState(
+        &self,
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+        state: ULONG,
+        drawInfo: *mut DrawInfo,
+    ) {
+        unsafe {
+            DrawImageState(
+                self.base.as_ptr(),
+                rp,
+                image,
+                leftOffset,
+                topOffset,
+                state,
+                drawInfo,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PointInImage(&self, point: ULONG, image: *const Image) -> BOOL {
+        unsafe { PointInImage(self.base.as_ptr(), point, image) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn EraseImage(
+        &self,
+        rp: *mut RastPort,
+        image: *const Image,
+        leftOffset: LONG,
+        topOffset: LONG,
+    ) {
+        unsafe { EraseImage(self.base.as_ptr(), rp, image, leftOffset, topOffset) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NewObjectA(
+        &self,
+        classPtr: *mut IClass,
+        classID: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> APTR {
+        unsafe { NewObjectA(self.base.as_ptr(), classPtr, classID, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DisposeObject(&self, object: APTR) {
+        unsafe { DisposeObject(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetAttrsA(&self, object: APTR, tagList: *const TagItem) -> ULONG {
+        unsafe { SetAttrsA(self.base.as_ptr(), object, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetAttr(&self, attrID: ULONG, object: APTR, storagePtr: *mut ULONG) -> ULONG {
+        unsafe { GetAttr(self.base.as_ptr(), attrID, object, storagePtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetGadgetAttrsA(
+        &self,
+        gadget: *mut Gadget,
+        window: *mut Window,
+        requester: *mut Requester,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { SetGadgetAttrsA(self.base.as_ptr(), gadget, window, requester, tagList) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NextObject(&self, objectPtrPtr: CONST_APTR) -> APTR {
+        unsafe { NextObject(self.base.as_ptr(), objectPtrPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MakeClass(
+        &self,
+        classID: CONST_STRPTR,
+        superClassID: CONST_STRPTR,
+        superClassPtr: *const IClass,
+        instanceSize: ULONG,
+        flags: ULONG,
+    ) -> *mut IClass {
+        unsafe {
+            MakeClass(
+                self.base.as_ptr(),
+                classID,
+                superClassID,
+                superClassPtr,
+                instanceSize,
+                flags,
+            )
+        }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddClass(&self, classPtr: *mut IClass) {
+        unsafe { AddClass(self.base.as_ptr(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetScreenDrawInfo(&self, screen: *mut Screen) -> *mut DrawInfo {
+        unsafe { GetScreenDrawInfo(self.base.as_ptr(), screen) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeScreenDrawInfo(&self, screen: *mut Screen, drawInfo: *mut DrawInfo) {
+        unsafe { FreeScreenDrawInfo(self.base.as_ptr(), screen, drawInfo) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ResetMenuStrip(&self, window: *mut Window, menu: *mut Menu) -> BOOL {
+        unsafe { ResetMenuStrip(self.base.as_ptr(), window, menu) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemoveClass(&self, classPtr: *mut IClass) {
+        unsafe { RemoveClass(self.base.as_ptr(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeClass(&self, classPtr: *mut IClass) -> BOOL {
+        unsafe { FreeClass(self.base.as_ptr(), classPtr) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocScreenBuffer(
+        &self,
+        sc: *mut Screen,
+        bm: *mut BitMap,
+        flags: ULONG,
+    ) -> *mut ScreenBuffer {
+        unsafe { AllocScreenBuffer(self.base.as_ptr(), sc, bm, flags) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreeScreenBuffer(&self, sc: *mut Screen, sb: *mut ScreenBuffer) {
+        unsafe { FreeScreenBuffer(self.base.as_ptr(), sc, sb) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ChangeScreenBuffer(&self, sc: *mut Screen, sb: *mut ScreenBuffer) -> ULONG {
+        unsafe { ChangeScreenBuffer(self.base.as_ptr(), sc, sb) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ScreenDepth(&self, screen: *mut Screen, flags: ULONG, reserved: APTR) {
+        unsafe { ScreenDepth(self.base.as_ptr(), screen, flags, reserved) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ScreenPosition(
+        &self,
+        screen: *mut Screen,
+        flags: ULONG,
+        x1: LONG,
+        y1: LONG,
+        x2: LONG,
+        y2: LONG,
+    ) {
+        unsafe { ScreenPosition(self.base.as_ptr(), screen, flags, x1, y1, x2, y2) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ScrollWindowRaster(
+        &self,
+        win: *mut Window,
+        dx: LONG,
+        dy: LONG,
+        xMin: LONG,
+        yMin: LONG,
+        xMax: LONG,
+        yMax: LONG,
+    ) {
+        unsafe { ScrollWindowRaster(self.base.as_ptr(), win, dx, dy, xMin, yMin, xMax, yMax) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn LendMenus(&self, fromwindow: *mut Window, towindow: *mut Window) {
+        unsafe { LendMenus(self.base.as_ptr(), fromwindow, towindow) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn DoGadgetMethodA(
+        &self,
+        gad: *mut Gadget,
+        win: *mut Window,
+        req: *mut Requester,
+        message: Msg,
+    ) -> ULONG {
+        unsafe { DoGadgetMethodA(self.base.as_ptr(), gad, win, req, message) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SetWindowPointerA(&self, win: *mut Window, taglist: *const TagItem) {
+        unsafe { SetWindowPointerA(self.base.as_ptr(), win, taglist) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn TimedDisplayAlert(
+        &self,
+        alertNumber: ULONG,
+        string: CONST_STRPTR,
+        height: ULONG,
+        time: ULONG,
+    ) -> BOOL {
+        unsafe { TimedDisplayAlert(self.base.as_ptr(), alertNumber, string, height, time) }
+    }
+
+    /// libname: "intuition.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn HelpControl(&self, win: *mut Window, flags: ULONG) {
+        unsafe { HelpControl(self.base.as_ptr(), win, flags) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ShowWindow(&self, window: *mut Window, other: *mut Window) -> BOOL {
+        unsafe { ShowWindow(self.base.as_ptr(), window, other) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn HideWindow(&self, window: *mut Window) -> BOOL {
+        unsafe { HideWindow(self.base.as_ptr(), window) }
+    }
+
+    /// libname: "intuition.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn IntuitionControlA(&self, object: APTR, taglist: *const TagItem) -> ULONG {
+        unsafe { IntuitionControlA(self.base.as_ptr(), object, taglist) }
+    }
+}
+
+impl Drop for IntuitionLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `OpenIntuition()`
+pub const LVO_OpenIntuition: i16 = -30;
+/// Library vector offset of `Intuition()`
+pub const LVO_Intuition: i16 = -36;
+/// Library vector offset of `AddGadget()`
+pub const LVO_AddGadget: i16 = -42;
+/// Library vector offset of `ClearDMRequest()`
+pub const LVO_ClearDMRequest: i16 = -48;
+/// Library vector offset of `ClearMenuStrip()`
+pub const LVO_ClearMenuStrip: i16 = -54;
+/// Library vector offset of `ClearPointer()`
+pub const LVO_ClearPointer: i16 = -60;
+/// Library vector offset of `CloseScreen()`
+pub const LVO_CloseScreen: i16 = -66;
+/// Library vector offset of `CloseWindow()`
+pub const LVO_CloseWindow: i16 = -72;
+/// Library vector offset of `CloseWorkBench()`
+pub const LVO_CloseWorkBench: i16 = -78;
+/// Library vector offset of `CurrentTime()`
+pub const LVO_CurrentTime: i16 = -84;
+/// Library vector offset of `DisplayAlert()`
+pub const LVO_DisplayAlert: i16 = -90;
+/// Library vector offset of `DisplayBeep()`
+pub const LVO_DisplayBeep: i16 = -96;
+/// Library vector offset of `DoubleClick()`
+pub const LVO_DoubleClick: i16 = -102;
+/// Library vector offset of `DrawBorder()`
+pub const LVO_DrawBorder: i16 = -108;
+/// Library vector offset of `DrawImage()`
+pub const LVO_DrawImage: i16 = -114;
+/// Library vector offset of `EndRequest()`
+pub const LVO_EndRequest: i16 = -120;
+/// Library vector offset of `GetDefPrefs()`
+pub const LVO_GetDefPrefs: i16 = -126;
+/// Library vector offset of `GetPrefs()`
+pub const LVO_GetPrefs: i16 = -132;
+/// Library vector offset of `InitRequester()`
+pub const LVO_InitRequester: i16 = -138;
+/// Library vector offset of `ItemAddress()`
+pub const LVO_ItemAddress: i16 = -144;
+/// Library vector offset of `ModifyIDCMP()`
+pub const LVO_ModifyIDCMP: i16 = -150;
+/// Library vector offset of `ModifyProp()`
+pub const LVO_ModifyProp: i16 = -156;
+/// Library vector offset of `MoveScreen()`
+pub const LVO_MoveScreen: i16 = -162;
+/// Library vector offset of `MoveWindow()`
+pub const LVO_MoveWindow: i16 = -168;
+/// Library vector offset of `OffGadget()`
+pub const LVO_OffGadget: i16 = -174;
+/// Library vector offset of `OffMenu()`
+pub const LVO_OffMenu: i16 = -180;
+/// Library vector offset of `OnGadget()`
+pub const LVO_OnGadget: i16 = -186;
+/// Library vector offset of `OnMenu()`
+pub const LVO_OnMenu: i16 = -192;
+/// Library vector offset of `OpenScreen()`
+pub const LVO_OpenScreen: i16 = -198;
+/// Library vector offset of `OpenWindow()`
+pub const LVO_OpenWindow: i16 = -204;
+/// Library vector offset of `OpenWorkBench()`
+pub const LVO_OpenWorkBench: i16 = -210;
+/// Library vector offset of `PrintIText()`
+pub const LVO_PrintIText: i16 = -216;
+/// Library vector offset of `RefreshGadgets()`
+pub const LVO_RefreshGadgets: i16 = -222;
+/// Library vector offset of `RemoveGadget()`
+pub const LVO_RemoveGadget: i16 = -228;
+/// Library vector offset of `ReportMouse()`
+pub const LVO_ReportMouse: i16 = -234;
+/// Library vector offset of `ReportMouse1()`
+pub const LVO_ReportMouse1: i16 = -234;
+/// Library vector offset of `Request()`
+pub const LVO_Request: i16 = -240;
+/// Library vector offset of `ScreenToBack()`
+pub const LVO_ScreenToBack: i16 = -246;
+/// Library vector offset of `ScreenToFront()`
+pub const LVO_ScreenToFront: i16 = -252;
+/// Library vector offset of `SetDMRequest()`
+pub const LVO_SetDMRequest: i16 = -258;
+/// Library vector offset of `SetMenuStrip()`
+pub const LVO_SetMenuStrip: i16 = -264;
+/// Library vector offset of `SetPointer()`
+pub const LVO_SetPointer: i16 = -270;
+/// Library vector offset of `SetWindowTitles()`
+pub const LVO_SetWindowTitles: i16 = -276;
+/// Library vector offset of `ShowTitle()`
+pub const LVO_ShowTitle: i16 = -282;
+/// Library vector offset of `SizeWindow()`
+pub const LVO_SizeWindow: i16 = -288;
+/// Library vector offset of `ViewAddress()`
+pub const LVO_ViewAddress: i16 = -294;
+/// Library vector offset of `ViewPortAddress()`
+pub const LVO_ViewPortAddress: i16 = -300;
+/// Library vector offset of `WindowToBack()`
+pub const LVO_WindowToBack: i16 = -306;
+/// Library vector offset of `WindowToFront()`
+pub const LVO_WindowToFront: i16 = -312;
+/// Library vector offset of `WindowLimits()`
+pub const LVO_WindowLimits: i16 = -318;
+/// Library vector offset of `SetPrefs()`
+pub const LVO_SetPrefs: i16 = -324;
+/// Library vector offset of `IntuiTextLength()`
+pub const LVO_IntuiTextLength: i16 = -330;
+/// Library vector offset of `WBenchToBack()`
+pub const LVO_WBenchToBack: i16 = -336;
+/// Library vector offset of `WBenchToFront()`
+pub const LVO_WBenchToFront: i16 = -342;
+/// Library vector offset of `AutoRequest()`
+pub const LVO_AutoRequest: i16 = -348;
+/// Library vector offset of `BeginRefresh()`
+pub const LVO_BeginRefresh: i16 = -354;
+/// Library vector offset of `BuildSysRequest()`
+pub const LVO_BuildSysRequest: i16 = -360;
+/// Library vector offset of `EndRefresh()`
+pub const LVO_EndRefresh: i16 = -366;
+/// Library vector offset of `FreeSysRequest()`
+pub const LVO_FreeSysRequest: i16 = -372;
+/// Library vector offset of `MakeScreen()`
+pub const LVO_MakeScreen: i16 = -378;
+/// Library vector offset of `RemakeDisplay()`
+pub const LVO_RemakeDisplay: i16 = -384;
+/// Library vector offset of `RethinkDisplay()`
+pub const LVO_RethinkDisplay: i16 = -390;
+/// Library vector offset of `AllocRemember()`
+pub const LVO_AllocRemember: i16 = -396;
+/// Library vector offset of `AlohaWorkbench()`
+pub const LVO_AlohaWorkbench: i16 = -402;
+/// Library vector offset of `FreeRemember()`
+pub const LVO_FreeRemember: i16 = -408;
+/// Library vector offset of `LockIBase()`
+pub const LVO_LockIBase: i16 = -414;
+/// Library vector offset of `UnlockIBase()`
+pub const LVO_UnlockIBase: i16 = -420;
+/// Library vector offset of `GetScreenData()`
+pub const LVO_GetScreenData: i16 = -426;
+/// Library vector offset of `RefreshGList()`
+pub const LVO_RefreshGList: i16 = -432;
+/// Library vector offset of `AddGList()`
+pub const LVO_AddGList: i16 = -438;
+/// Library vector offset of `RemoveGList()`
+pub const LVO_RemoveGList: i16 = -444;
+/// Library vector offset of `ActivateWindow()`
+pub const LVO_ActivateWindow: i16 = -450;
+/// Library vector offset of `RefreshWindowFrame()`
+pub const LVO_RefreshWindowFrame: i16 = -456;
+/// Library vector offset of `ActivateGadget()`
+pub const LVO_ActivateGadget: i16 = -462;
+/// Library vector offset of `NewModifyProp()`
+pub const LVO_NewModifyProp: i16 = -468;
+/// Library vector offset of `QueryOverscan()`
+pub const LVO_QueryOverscan: i16 = -474;
+/// Library vector offset of `MoveWindowInFrontOf()`
+pub const LVO_MoveWindowInFrontOf: i16 = -480;
+/// Library vector offset of `ChangeWindowBox()`
+pub const LVO_ChangeWindowBox: i16 = -486;
+/// Library vector offset of `SetEditHook()`
+pub const LVO_SetEditHook: i16 = -492;
+/// Library vector offset of `SetMouseQueue()`
+pub const LVO_SetMouseQueue: i16 = -498;
+/// Library vector offset of `ZipWindow()`
+pub const LVO_ZipWindow: i16 = -504;
+/// Library vector offset of `LockPubScreen()`
+pub const LVO_LockPubScreen: i16 = -510;
+/// Library vector offset of `UnlockPubScreen()`
+pub const LVO_UnlockPubScreen: i16 = -516;
+/// Library vector offset of `LockPubScreenList()`
+pub const LVO_LockPubScreenList: i16 = -522;
+/// Library vector offset of `UnlockPubScreenList()`
+pub const LVO_UnlockPubScreenList: i16 = -528;
+/// Library vector offset of `NextPubScreen()`
+pub const LVO_NextPubScreen: i16 = -534;
+/// Library vector offset of `SetDefaultPubScreen()`
+pub const LVO_SetDefaultPubScreen: i16 = -540;
+/// Library vector offset of `SetPubScreenModes()`
+pub const LVO_SetPubScreenModes: i16 = -546;
+/// Library vector offset of `PubScreenStatus()`
+pub const LVO_PubScreenStatus: i16 = -552;
+/// Library vector offset of `ObtainGIRPort()`
+pub const LVO_ObtainGIRPort: i16 = -558;
+/// Library vector offset of `ReleaseGIRPort()`
+pub const LVO_ReleaseGIRPort: i16 = -564;
+/// Library vector offset of `GadgetMouse()`
+pub const LVO_GadgetMouse: i16 = -570;
+/// Library vector offset of `GetDefaultPubScreen()`
+pub const LVO_GetDefaultPubScreen: i16 = -582;
+/// Library vector offset of `EasyRequestArgs()`
+pub const LVO_EasyRequestArgs: i16 = -588;
+/// Library vector offset of `BuildEasyRequestArgs()`
+pub const LVO_BuildEasyRequestArgs: i16 = -594;
+/// Library vector offset of `SysReqHandler()`
+pub const LVO_SysReqHandler: i16 = -600;
+/// Library vector offset of `OpenWindowTagList()`
+pub const LVO_OpenWindowTagList: i16 = -606;
+/// Library vector offset of `OpenScreenTagList()`
+pub const LVO_OpenScreenTagList: i16 = -612;
+/// Library vector offset of `DrawImageState()`
+pub const LVO_DrawImageState: i16 = -618;
+/// Library vector offset of `PointInImage()`
+pub const LVO_PointInImage: i16 = -624;
+/// Library vector offset of `EraseImage()`
+pub const LVO_EraseImage: i16 = -630;
+/// Library vector offset of `NewObjectA()`
+pub const LVO_NewObjectA: i16 = -636;
+/// Library vector offset of `DisposeObject()`
+pub const LVO_DisposeObject: i16 = -642;
+/// Library vector offset of `SetAttrsA()`
+pub const LVO_SetAttrsA: i16 = -648;
+/// Library vector offset of `GetAttr()`
+pub const LVO_GetAttr: i16 = -654;
+/// Library vector offset of `SetGadgetAttrsA()`
+pub const LVO_SetGadgetAttrsA: i16 = -660;
+/// Library vector offset of `NextObject()`
+pub const LVO_NextObject: i16 = -666;
+/// Library vector offset of `MakeClass()`
+pub const LVO_MakeClass: i16 = -678;
+/// Library vector offset of `AddClass()`
+pub const LVO_AddClass: i16 = -684;
+/// Library vector offset of `GetScreenDrawInfo()`
+pub const LVO_GetScreenDrawInfo: i16 = -690;
+/// Library vector offset of `FreeScreenDrawInfo()`
+pub const LVO_FreeScreenDrawInfo: i16 = -696;
+/// Library vector offset of `ResetMenuStrip()`
+pub const LVO_ResetMenuStrip: i16 = -702;
+/// Library vector offset of `RemoveClass()`
+pub const LVO_RemoveClass: i16 = -708;
+/// Library vector offset of `FreeClass()`
+pub const LVO_FreeClass: i16 = -714;
+/// Library vector offset of `AllocScreenBuffer()`
+pub const LVO_AllocScreenBuffer: i16 = -768;
+/// Library vector offset of `FreeScreenBuffer()`
+pub const LVO_FreeScreenBuffer: i16 = -774;
+/// Library vector offset of `ChangeScreenBuffer()`
+pub const LVO_ChangeScreenBuffer: i16 = -780;
+/// Library vector offset of `ScreenDepth()`
+pub const LVO_ScreenDepth: i16 = -786;
+/// Library vector offset of `ScreenPosition()`
+pub const LVO_ScreenPosition: i16 = -792;
+/// Library vector offset of `ScrollWindowRaster()`
+pub const LVO_ScrollWindowRaster: i16 = -798;
+/// Library vector offset of `LendMenus()`
+pub const LVO_LendMenus: i16 = -804;
+/// Library vector offset of `DoGadgetMethodA()`
+pub const LVO_DoGadgetMethodA: i16 = -810;
+/// Library vector offset of `SetWindowPointerA()`
+pub const LVO_SetWindowPointerA: i16 = -816;
+/// Library vector offset of `TimedDisplayAlert()`
+pub const LVO_TimedDisplayAlert: i16 = -822;
+/// Library vector offset of `HelpControl()`
+pub const LVO_HelpControl: i16 = -828;
+/// Library vector offset of `ShowWindow()`
+pub const LVO_ShowWindow: i16 = -834;
+/// Library vector offset of `HideWindow()`
+pub const LVO_HideWindow: i16 = -840;
+/// Library vector offset of `IntuitionControlA()`
+pub const LVO_IntuitionControlA: i16 = -1212;
+
+/// Functions of "intuition.library"
+pub static INTUITION_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "OpenIntuition",
+        lvo: LVO_OpenIntuition,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Intuition",
+        lvo: LVO_Intuition,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AddGadget",
+        lvo: LVO_AddGadget,
+        registers: &["a0", "a1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearDMRequest",
+        lvo: LVO_ClearDMRequest,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearMenuStrip",
+        lvo: LVO_ClearMenuStrip,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ClearPointer",
+        lvo: LVO_ClearPointer,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseScreen",
+        lvo: LVO_CloseScreen,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseWindow",
+        lvo: LVO_CloseWindow,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CloseWorkBench",
+        lvo: LVO_CloseWorkBench,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CurrentTime",
+        lvo: LVO_CurrentTime,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DisplayAlert",
+        lvo: LVO_DisplayAlert,
+        registers: &["d0", "a0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DisplayBeep",
+        lvo: LVO_DisplayBeep,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DoubleClick",
+        lvo: LVO_DoubleClick,
+        registers: &["d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DrawBorder",
+        lvo: LVO_DrawBorder,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DrawImage",
+        lvo: LVO_DrawImage,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "EndRequest",
+        lvo: LVO_EndRequest,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetDefPrefs",
+        lvo: LVO_GetDefPrefs,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetPrefs",
+        lvo: LVO_GetPrefs,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InitRequester",
+        lvo: LVO_InitRequester,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ItemAddress",
+        lvo: LVO_ItemAddress,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ModifyIDCMP",
+        lvo: LVO_ModifyIDCMP,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ModifyProp",
+        lvo: LVO_ModifyProp,
+        registers: &["a0", "a1", "a2", "d0", "d1", "d2", "d3", "d4"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveScreen",
+        lvo: LVO_MoveScreen,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveWindow",
+        lvo: LVO_MoveWindow,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OffGadget",
+        lvo: LVO_OffGadget,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OffMenu",
+        lvo: LVO_OffMenu,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OnGadget",
+        lvo: LVO_OnGadget,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OnMenu",
+        lvo: LVO_OnMenu,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenScreen",
+        lvo: LVO_OpenScreen,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenWindow",
+        lvo: LVO_OpenWindow,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "OpenWorkBench",
+        lvo: LVO_OpenWorkBench,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "PrintIText",
+        lvo: LVO_PrintIText,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RefreshGadgets",
+        lvo: LVO_RefreshGadgets,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemoveGadget",
+        lvo: LVO_RemoveGadget,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReportMouse",
+        lvo: LVO_ReportMouse,
+        registers: &["d0", "a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReportMouse1",
+        lvo: LVO_ReportMouse1,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "Request",
+        lvo: LVO_Request,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ScreenToBack",
+        lvo: LVO_ScreenToBack,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ScreenToFront",
+        lvo: LVO_ScreenToFront,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetDMRequest",
+        lvo: LVO_SetDMRequest,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetMenuStrip",
+        lvo: LVO_SetMenuStrip,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetPointer",
+        lvo: LVO_SetPointer,
+        registers: &["a0", "a1", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetWindowTitles",
+        lvo: LVO_SetWindowTitles,
+        registers: &["a0", "a1", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ShowTitle",
+        lvo: LVO_ShowTitle,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SizeWindow",
+        lvo: LVO_SizeWindow,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ViewAddress",
+        lvo: LVO_ViewAddress,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ViewPortAddress",
+        lvo: LVO_ViewPortAddress,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WindowToBack",
+        lvo: LVO_WindowToBack,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WindowToFront",
+        lvo: LVO_WindowToFront,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WindowLimits",
+        lvo: LVO_WindowLimits,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SetPrefs",
+        lvo: LVO_SetPrefs,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IntuiTextLength",
+        lvo: LVO_IntuiTextLength,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WBenchToBack",
+        lvo: LVO_WBenchToBack,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WBenchToFront",
+        lvo: LVO_WBenchToFront,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AutoRequest",
+        lvo: LVO_AutoRequest,
+        registers: &["a0", "a1", "a2", "a3", "d0", "d1", "d2", "d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BeginRefresh",
+        lvo: LVO_BeginRefresh,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BuildSysRequest",
+        lvo: LVO_BuildSysRequest,
+        registers: &["a0", "a1", "a2", "a3", "d0", "d1", "d2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "EndRefresh",
+        lvo: LVO_EndRefresh,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeSysRequest",
+        lvo: LVO_FreeSysRequest,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MakeScreen",
+        lvo: LVO_MakeScreen,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RemakeDisplay",
+        lvo: LVO_RemakeDisplay,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "RethinkDisplay",
+        lvo: LVO_RethinkDisplay,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AllocRemember",
+        lvo: LVO_AllocRemember,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "AlohaWorkbench",
+        lvo: LVO_AlohaWorkbench,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeRemember",
+        lvo: LVO_FreeRemember,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LockIBase",
+        lvo: LVO_LockIBase,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnlockIBase",
+        lvo: LVO_UnlockIBase,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "GetScreenData",
+        lvo: LVO_GetScreenData,
+        registers: &["a0", "d0", "d1", "a1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "RefreshGList",
+        lvo: LVO_RefreshGList,
+        registers: &["a0", "a1", "a2", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "AddGList",
+        lvo: LVO_AddGList,
+        registers: &["a0", "a1", "d0", "d1", "a2"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "RemoveGList",
+        lvo: LVO_RemoveGList,
+        registers: &["a0", "a1", "d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ActivateWindow",
+        lvo: LVO_ActivateWindow,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "RefreshWindowFrame",
+        lvo: LVO_RefreshWindowFrame,
+        registers: &["a0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "ActivateGadget",
+        lvo: LVO_ActivateGadget,
+        registers: &["a0", "a1", "a2"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "NewModifyProp",
+        lvo: LVO_NewModifyProp,
+        registers: &["a0", "a1", "a2", "d0", "d1", "d2", "d3", "d4", "d5"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "QueryOverscan",
+        lvo: LVO_QueryOverscan,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MoveWindowInFrontOf",
+        lvo: LVO_MoveWindowInFrontOf,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ChangeWindowBox",
+        lvo: LVO_ChangeWindowBox,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetEditHook",
+        lvo: LVO_SetEditHook,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetMouseQueue",
+        lvo: LVO_SetMouseQueue,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ZipWindow",
+        lvo: LVO_ZipWindow,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockPubScreen",
+        lvo: LVO_LockPubScreen,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnlockPubScreen",
+        lvo: LVO_UnlockPubScreen,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockPubScreenList",
+        lvo: LVO_LockPubScreenList,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnlockPubScreenList",
+        lvo: LVO_UnlockPubScreenList,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NextPubScreen",
+        lvo: LVO_NextPubScreen,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetDefaultPubScreen",
+        lvo: LVO_SetDefaultPubScreen,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetPubScreenModes",
+        lvo: LVO_SetPubScreenModes,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PubScreenStatus",
+        lvo: LVO_PubScreenStatus,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ObtainGIRPort",
+        lvo: LVO_ObtainGIRPort,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseGIRPort",
+        lvo: LVO_ReleaseGIRPort,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GadgetMouse",
+        lvo: LVO_GadgetMouse,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetDefaultPubScreen",
+        lvo: LVO_GetDefaultPubScreen,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EasyRequestArgs",
+        lvo: LVO_EasyRequestArgs,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "BuildEasyRequestArgs",
+        lvo: LVO_BuildEasyRequestArgs,
+        registers: &["a0", "a1", "d0", "a3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SysReqHandler",
+        lvo: LVO_SysReqHandler,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenWindowTagList",
+        lvo: LVO_OpenWindowTagList,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "OpenScreenTagList",
+        lvo: LVO_OpenScreenTagList,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DrawImageState",
+        lvo: LVO_DrawImageState,
+        registers: &["a0", "a1", "d0", "d1", "d2", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PointInImage",
+        lvo: LVO_PointInImage,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "EraseImage",
+        lvo: LVO_EraseImage,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NewObjectA",
+        lvo: LVO_NewObjectA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DisposeObject",
+        lvo: LVO_DisposeObject,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetAttrsA",
+        lvo: LVO_SetAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetAttr",
+        lvo: LVO_GetAttr,
+        registers: &["d0", "a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SetGadgetAttrsA",
+        lvo: LVO_SetGadgetAttrsA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NextObject",
+        lvo: LVO_NextObject,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MakeClass",
+        lvo: LVO_MakeClass,
+        registers: &["a0", "a1", "a2", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddClass",
+        lvo: LVO_AddClass,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetScreenDrawInfo",
+        lvo: LVO_GetScreenDrawInfo,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeScreenDrawInfo",
+        lvo: LVO_FreeScreenDrawInfo,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ResetMenuStrip",
+        lvo: LVO_ResetMenuStrip,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemoveClass",
+        lvo: LVO_RemoveClass,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeClass",
+        lvo: LVO_FreeClass,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocScreenBuffer",
+        lvo: LVO_AllocScreenBuffer,
+        registers: &["a0", "a1", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreeScreenBuffer",
+        lvo: LVO_FreeScreenBuffer,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ChangeScreenBuffer",
+        lvo: LVO_ChangeScreenBuffer,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ScreenDepth",
+        lvo: LVO_ScreenDepth,
+        registers: &["a0", "d0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ScreenPosition",
+        lvo: LVO_ScreenPosition,
+        registers: &["a0", "d0", "d1", "d2", "d3", "d4"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ScrollWindowRaster",
+        lvo: LVO_ScrollWindowRaster,
+        registers: &["a1", "d0", "d1", "d2", "d3", "d4", "d5"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "LendMenus",
+        lvo: LVO_LendMenus,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "DoGadgetMethodA",
+        lvo: LVO_DoGadgetMethodA,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SetWindowPointerA",
+        lvo: LVO_SetWindowPointerA,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "TimedDisplayAlert",
+        lvo: LVO_TimedDisplayAlert,
+        registers: &["d0", "a0", "d1", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "HelpControl",
+        lvo: LVO_HelpControl,
+        registers: &["a0", "d0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ShowWindow",
+        lvo: LVO_ShowWindow,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "HideWindow",
+        lvo: LVO_HideWindow,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "IntuitionControlA",
+        lvo: LVO_IntuitionControlA,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+];
@@ -0,0 +1,260 @@
+//! Functions of "keymap.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "keymap.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SetKeyMapDefault(KeymapBase: *mut Library, keyMap: *mut KeyMap) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(KeymapBase, 36, "SetKeyMapDefault", "keymap.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) KeymapBase,
+            in("a0") keyMap,
+        );
+    }
+}
+
+/// libname: "keymap.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AskKeyMapDefault(KeymapBase: *mut Library) -> *mut KeyMap {
+    let asm_ret_value: *mut KeyMap;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(KeymapBase, 36, "AskKeyMapDefault", "keymap.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) KeymapBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "keymap.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MapRawKey(
+    KeymapBase: *mut Library,
+    event: *const InputEvent,
+    buffer: STRPTR,
+    length: LONG,
+    keyMap: *const KeyMap,
+) -> WORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(KeymapBase, 36, "MapRawKey", "keymap.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) KeymapBase,
+            in("a0") event,
+            in("a1") buffer,
+            in("d1") length,
+            in("a2") keyMap,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as WORD
+}
+
+/// libname: "keymap.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MapANSI(
+    KeymapBase: *mut Library,
+    string: CONST_STRPTR,
+    count: LONG,
+    buffer: STRPTR,
+    length: LONG,
+    keyMap: *const KeyMap,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(KeymapBase, 36, "MapANSI", "keymap.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) KeymapBase,
+            in("a0") string,
+            in("d0") count,
+            in("a1") buffer,
+            in("d1") length,
+            in("a2") keyMap,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "keymap.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct KeymapLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl KeymapLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"keymap.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "keymap.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SetKeyMapDefault(&self, keyMap: *mut KeyMap) {
+        unsafe { SetKeyMapDefault(self.base.as_ptr(), keyMap) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AskKeyMapDefault(&self) -> *mut KeyMap {
+        unsafe { AskKeyMapDefault(self.base.as_ptr()) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MapRawKey(
+        &self,
+        event: *const InputEvent,
+        buffer: STRPTR,
+        length: LONG,
+        keyMap: *const KeyMap,
+    ) -> WORD {
+        unsafe { MapRawKey(self.base.as_ptr(), event, buffer, length, keyMap) }
+    }
+
+    /// libname: "keymap.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MapANSI(
+        &self,
+        string: CONST_STRPTR,
+        count: LONG,
+        buffer: STRPTR,
+        length: LONG,
+        keyMap: *const KeyMap,
+    ) -> LONG {
+        unsafe { MapANSI(self.base.as_ptr(), string, count, buffer, length, keyMap) }
+    }
+}
+
+impl Drop for KeymapLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `SetKeyMapDefault()`
+pub const LVO_SetKeyMapDefault: i16 = -30;
+/// Library vector offset of `AskKeyMapDefault()`
+pub const LVO_AskKeyMapDefault: i16 = -36;
+/// Library vector offset of `MapRawKey()`
+pub const LVO_MapRawKey: i16 = -42;
+/// Library vector offset of `MapANSI()`
+pub const LVO_MapANSI: i16 = -48;
+
+/// Functions of "keymap.library"
+pub static KEYMAP_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "SetKeyMapDefault",
+        lvo: LVO_SetKeyMapDefault,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AskKeyMapDefault",
+        lvo: LVO_AskKeyMapDefault,
+        registers: &[],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MapRawKey",
+        lvo: LVO_MapRawKey,
+        registers: &["a0", "a1", "d1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MapANSI",
+        lvo: LVO_MapANSI,
+        registers: &["a0", "d0", "a1", "d1", "a2"],
+        min_version: 36,
+    },
+];
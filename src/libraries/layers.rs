@@ -0,0 +1,1512 @@
+//! Functions of "layers.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "layers.library"
+pub unsafe fn InitLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn CreateUpfrontLayer(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    bm: *mut BitMap,
+    x0: LONG,
+    y0: LONG,
+    x1: LONG,
+    y1: LONG,
+    flags: LONG,
+    bm2: *mut BitMap,
+) -> *mut Layer {
+    let asm_ret_value: *mut Layer;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") bm,
+            in("d0") x0,
+            in("d1") y0,
+            in("d2") x1,
+            in("d3") y1,
+            in("d4") flags,
+            in("a2") bm2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn CreateBehindLayer(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    bm: *mut BitMap,
+    x0: LONG,
+    y0: LONG,
+    x1: LONG,
+    y1: LONG,
+    flags: LONG,
+    bm2: *mut BitMap,
+) -> *mut Layer {
+    let asm_ret_value: *mut Layer;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") bm,
+            in("d0") x0,
+            in("d1") y0,
+            in("d2") x1,
+            in("d3") y1,
+            in("d4") flags,
+            in("a2") bm2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn UpfrontLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn BehindLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn MoveLayer(
+    LayersBase: *mut Library,
+    dummy: LONG,
+    layer: *mut Layer,
+    dx: LONG,
+    dy: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            in("d0") dx,
+            in("d1") dy,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn SizeLayer(
+    LayersBase: *mut Library,
+    dummy: LONG,
+    layer: *mut Layer,
+    dx: LONG,
+    dy: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            in("d0") dx,
+            in("d1") dy,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn ScrollLayer(
+    LayersBase: *mut Library,
+    dummy: LONG,
+    layer: *mut Layer,
+    dx: LONG,
+    dy: LONG,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            in("d0") dx,
+            in("d1") dy,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn BeginUpdate(LayersBase: *mut Library, l: *mut Layer) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") l,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn EndUpdate(LayersBase: *mut Library, layer: *mut Layer, flag: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("d0") flag,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn DeleteLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn LockLayer(LayersBase: *mut Library, dummy: LONG, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") dummy,
+            in("a1") layer,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn UnlockLayer(LayersBase: *mut Library, layer: *mut Layer) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn LockLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn UnlockLayers(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn LockLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn SwapBitsRastPortClipRect(
+    LayersBase: *mut Library,
+    rp: *mut RastPort,
+    cr: *mut ClipRect,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") rp,
+            in("a1") cr,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn WhichLayer(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    x: LONG,
+    y: LONG,
+) -> *mut Layer {
+    let asm_ret_value: *mut Layer;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("d0") x,
+            in("d1") y,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn UnlockLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn NewLayerInfo(LayersBase: *mut Library) -> *mut Layer_Info {
+    let asm_ret_value: *mut Layer_Info;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn DisposeLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn FattenLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn ThinLayerInfo(LayersBase: *mut Library, li: *mut Layer_Info) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+        );
+    }
+}
+
+/// libname: "layers.library"
+pub unsafe fn MoveLayerInFrontOf(
+    LayersBase: *mut Library,
+    layer_to_move: *mut Layer,
+    other_layer: *mut Layer,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer_to_move,
+            in("a1") other_layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library"
+pub unsafe fn InstallClipRegion(
+    LayersBase: *mut Library,
+    layer: *mut Layer,
+    region: *mut Region,
+) -> *mut Region {
+    let asm_ret_value: *mut Region;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("a1") region,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MoveSizeLayer(
+    LayersBase: *mut Library,
+    layer: *mut Layer,
+    dx: LONG,
+    dy: LONG,
+    dw: LONG,
+    dh: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 36, "MoveSizeLayer", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("d0") dx,
+            in("d1") dy,
+            in("d2") dw,
+            in("d3") dh,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateUpfrontHookLayer(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    bm: *mut BitMap,
+    x0: LONG,
+    y0: LONG,
+    x1: LONG,
+    y1: LONG,
+    flags: LONG,
+    hook: *mut Hook,
+    bm2: *mut BitMap,
+) -> *mut Layer {
+    let asm_ret_value: *mut Layer;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 36, "CreateUpfrontHookLayer", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") bm,
+            in("d0") x0,
+            in("d1") y0,
+            in("d2") x1,
+            in("d3") y1,
+            in("d4") flags,
+            in("a3") hook,
+            in("a2") bm2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateBehindHookLayer(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    bm: *mut BitMap,
+    x0: LONG,
+    y0: LONG,
+    x1: LONG,
+    y1: LONG,
+    flags: LONG,
+    hook: *mut Hook,
+    bm2: *mut BitMap,
+) -> *mut Layer {
+    let asm_ret_value: *mut Layer;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 36, "CreateBehindHookLayer", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-192, %a6)
+            ".short -192",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") bm,
+            in("d0") x0,
+            in("d1") y0,
+            in("d2") x1,
+            in("d3") y1,
+            in("d4") flags,
+            in("a3") hook,
+            in("a2") bm2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn InstallLayerHook(
+    LayersBase: *mut Library,
+    layer: *mut Layer,
+    hook: *mut Hook,
+) -> *mut Hook {
+    let asm_ret_value: *mut Hook;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 36, "InstallLayerHook", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("a1") hook,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn InstallLayerInfoHook(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    hook: *mut Hook,
+) -> *mut Hook {
+    let asm_ret_value: *mut Hook;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 39, "InstallLayerInfoHook", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") hook,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SortLayerCR(LayersBase: *mut Library, layer: *mut Layer, dx: LONG, dy: LONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 39, "SortLayerCR", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("d0") dx,
+            in("d1") dy,
+        );
+    }
+}
+
+/// libname: "layers.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn DoHookClipRects(
+    LayersBase: *mut Library,
+    hook: *mut Hook,
+    rport: *mut RastPort,
+    rect: *const Rectangle,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 39, "DoHookClipRects", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") hook,
+            in("a1") rport,
+            in("a2") rect,
+        );
+    }
+}
+
+/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn LayerOccluded(LayersBase: *mut Library, layer: *mut Layer) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 47, "LayerOccluded", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn HideLayer(LayersBase: *mut Library, layer: *mut Layer) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 47, "HideLayer", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn ShowLayer(
+    LayersBase: *mut Library,
+    layer: *mut Layer,
+    in_front_of: *mut Layer,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 47, "ShowLayer", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") layer,
+            in("a1") in_front_of,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SetLayerInfoBounds(
+    LayersBase: *mut Library,
+    li: *mut Layer_Info,
+    bounds: *const Rectangle,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LayersBase, 47, "SetLayerInfoBounds", "layers.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayersBase,
+            in("a0") li,
+            in("a1") bounds,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// An open "layers.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct LayersLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl LayersLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"layers.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "layers.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn InitLayers(&self, li: *mut Layer_Info) {
+        unsafe { InitLayers(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn CreateUpfrontLayer(
+        &self,
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe { CreateUpfrontLayer(self.base.as_ptr(), li, bm, x0, y0, x1, y1, flags, bm2) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn CreateBehindLayer(
+        &self,
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe { CreateBehindLayer(self.base.as_ptr(), li, bm, x0, y0, x1, y1, flags, bm2) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn UpfrontLayer(&self, dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { UpfrontLayer(self.base.as_ptr(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn BehindLayer(&self, dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { BehindLayer(self.base.as_ptr(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn MoveLayer(&self, dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) -> LONG {
+        unsafe { MoveLayer(self.base.as_ptr(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn SizeLayer(&self, dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) -> LONG {
+        unsafe { SizeLayer(self.base.as_ptr(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn ScrollLayer(&self, dummy: LONG, layer: *mut Layer, dx: LONG, dy: LONG) {
+        unsafe { ScrollLayer(self.base.as_ptr(), dummy, layer, dx, dy) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn BeginUpdate(&self, l: *mut Layer) -> LONG {
+        unsafe { BeginUpdate(self.base.as_ptr(), l) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn EndUpdate(&self, layer: *mut Layer, flag: ULONG) {
+        unsafe { EndUpdate(self.base.as_ptr(), layer, flag) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn DeleteLayer(&self, dummy: LONG, layer: *mut Layer) -> LONG {
+        unsafe { DeleteLayer(self.base.as_ptr(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn LockLayer(&self, dummy: LONG, layer: *mut Layer) {
+        unsafe { LockLayer(self.base.as_ptr(), dummy, layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn UnlockLayer(&self, layer: *mut Layer) {
+        unsafe { UnlockLayer(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn LockLayers(&self, li: *mut Layer_Info) {
+        unsafe { LockLayers(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn UnlockLayers(&self, li: *mut Layer_Info) {
+        unsafe { UnlockLayers(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn LockLayerInfo(&self, li: *mut Layer_Info) {
+        unsafe { LockLayerInfo(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn SwapBitsRastPortClipRect(&self, rp: *mut RastPort, cr: *mut ClipRect) {
+        unsafe { SwapBitsRastPortClipRect(self.base.as_ptr(), rp, cr) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn WhichLayer(&self, li: *mut Layer_Info, x: LONG, y: LONG) -> *mut Layer {
+        unsafe { WhichLayer(self.base.as_ptr(), li, x, y) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn UnlockLayerInfo(&self, li: *mut Layer_Info) {
+        unsafe { UnlockLayerInfo(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn NewLayerInfo(&self) -> *mut Layer_Info {
+        unsafe { NewLayerInfo(self.base.as_ptr()) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn DisposeLayerInfo(&self, li: *mut Layer_Info) {
+        unsafe { DisposeLayerInfo(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn FattenLayerInfo(&self, li: *mut Layer_Info) -> LONG {
+        unsafe { FattenLayerInfo(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn ThinLayerInfo(&self, li: *mut Layer_Info) {
+        unsafe { ThinLayerInfo(self.base.as_ptr(), li) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn MoveLayerInFrontOf(
+        &self,
+        layer_to_move: *mut Layer,
+        other_layer: *mut Layer,
+    ) -> LONG {
+        unsafe { MoveLayerInFrontOf(self.base.as_ptr(), layer_to_move, other_layer) }
+    }
+
+    /// libname: "layers.library"
+    pub unsafe fn InstallClipRegion(&self, layer: *mut Layer, region: *mut Region) -> *mut Region {
+        unsafe { InstallClipRegion(self.base.as_ptr(), layer, region) }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MoveSizeLayer(
+        &self,
+        layer: *mut Layer,
+        dx: LONG,
+        dy: LONG,
+        dw: LONG,
+        dh: LONG,
+    ) -> LONG {
+        unsafe { MoveSizeLayer(self.base.as_ptr(), layer, dx, dy, dw, dh) }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateUpfrontHookLayer(
+        &self,
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        hook: *mut Hook,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            CreateUpfrontHookLayer(self.base.as_ptr(), li, bm, x0, y0, x1, y1, flags, hook, bm2)
+        }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateBehindHookLayer(
+        &self,
+        li: *mut Layer_Info,
+        bm: *mut BitMap,
+        x0: LONG,
+        y0: LONG,
+        x1: LONG,
+        y1: LONG,
+        flags: LONG,
+        hook: *mut Hook,
+        bm2: *mut BitMap,
+    ) -> *mut Layer {
+        unsafe {
+            CreateBehindHookLayer(self.base.as_ptr(), li, bm, x0, y0, x1, y1, flags, hook, bm2)
+        }
+    }
+
+    /// libname: "layers.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn InstallLayerHook(&self, layer: *mut Layer, hook: *mut Hook) -> *mut Hook {
+        unsafe { InstallLayerHook(self.base.as_ptr(), layer, hook) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn InstallLayerInfoHook(&self, li: *mut Layer_Info, hook: *mut Hook) -> *mut Hook {
+        unsafe { InstallLayerInfoHook(self.base.as_ptr(), li, hook) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SortLayerCR(&self, layer: *mut Layer, dx: LONG, dy: LONG) {
+        unsafe { SortLayerCR(self.base.as_ptr(), layer, dx, dy) }
+    }
+
+    /// libname: "layers.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn DoHookClipRects(
+        &self,
+        hook: *mut Hook,
+        rport: *mut RastPort,
+        rect: *const Rectangle,
+    ) {
+        unsafe { DoHookClipRects(self.base.as_ptr(), hook, rport, rect) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn LayerOccluded(&self, layer: *mut Layer) -> BOOL {
+        unsafe { LayerOccluded(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn HideLayer(&self, layer: *mut Layer) -> LONG {
+        unsafe { HideLayer(self.base.as_ptr(), layer) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn ShowLayer(&self, layer: *mut Layer, in_front_of: *mut Layer) -> LONG {
+        unsafe { ShowLayer(self.base.as_ptr(), layer, in_front_of) }
+    }
+
+    /// libname: "layers.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SetLayerInfoBounds(&self, li: *mut Layer_Info, bounds: *const Rectangle) -> BOOL {
+        unsafe { SetLayerInfoBounds(self.base.as_ptr(), li, bounds) }
+    }
+}
+
+impl Drop for LayersLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `InitLayers()`
+pub const LVO_InitLayers: i16 = -30;
+/// Library vector offset of `CreateUpfrontLayer()`
+pub const LVO_CreateUpfrontLayer: i16 = -36;
+/// Library vector offset of `CreateBehindLayer()`
+pub const LVO_CreateBehindLayer: i16 = -42;
+/// Library vector offset of `UpfrontLayer()`
+pub const LVO_UpfrontLayer: i16 = -48;
+/// Library vector offset of `BehindLayer()`
+pub const LVO_BehindLayer: i16 = -54;
+/// Library vector offset of `MoveLayer()`
+pub const LVO_MoveLayer: i16 = -60;
+/// Library vector offset of `SizeLayer()`
+pub const LVO_SizeLayer: i16 = -66;
+/// Library vector offset of `ScrollLayer()`
+pub const LVO_ScrollLayer: i16 = -72;
+/// Library vector offset of `BeginUpdate()`
+pub const LVO_BeginUpdate: i16 = -78;
+/// Library vector offset of `EndUpdate()`
+pub const LVO_EndUpdate: i16 = -84;
+/// Library vector offset of `DeleteLayer()`
+pub const LVO_DeleteLayer: i16 = -90;
+/// Library vector offset of `LockLayer()`
+pub const LVO_LockLayer: i16 = -96;
+/// Library vector offset of `UnlockLayer()`
+pub const LVO_UnlockLayer: i16 = -102;
+/// Library vector offset of `LockLayers()`
+pub const LVO_LockLayers: i16 = -108;
+/// Library vector offset of `UnlockLayers()`
+pub const LVO_UnlockLayers: i16 = -114;
+/// Library vector offset of `LockLayerInfo()`
+pub const LVO_LockLayerInfo: i16 = -120;
+/// Library vector offset of `SwapBitsRastPortClipRect()`
+pub const LVO_SwapBitsRastPortClipRect: i16 = -126;
+/// Library vector offset of `WhichLayer()`
+pub const LVO_WhichLayer: i16 = -132;
+/// Library vector offset of `UnlockLayerInfo()`
+pub const LVO_UnlockLayerInfo: i16 = -138;
+/// Library vector offset of `NewLayerInfo()`
+pub const LVO_NewLayerInfo: i16 = -144;
+/// Library vector offset of `DisposeLayerInfo()`
+pub const LVO_DisposeLayerInfo: i16 = -150;
+/// Library vector offset of `FattenLayerInfo()`
+pub const LVO_FattenLayerInfo: i16 = -156;
+/// Library vector offset of `ThinLayerInfo()`
+pub const LVO_ThinLayerInfo: i16 = -162;
+/// Library vector offset of `MoveLayerInFrontOf()`
+pub const LVO_MoveLayerInFrontOf: i16 = -168;
+/// Library vector offset of `InstallClipRegion()`
+pub const LVO_InstallClipRegion: i16 = -174;
+/// Library vector offset of `MoveSizeLayer()`
+pub const LVO_MoveSizeLayer: i16 = -180;
+/// Library vector offset of `CreateUpfrontHookLayer()`
+pub const LVO_CreateUpfrontHookLayer: i16 = -186;
+/// Library vector offset of `CreateBehindHookLayer()`
+pub const LVO_CreateBehindHookLayer: i16 = -192;
+/// Library vector offset of `InstallLayerHook()`
+pub const LVO_InstallLayerHook: i16 = -198;
+/// Library vector offset of `InstallLayerInfoHook()`
+pub const LVO_InstallLayerInfoHook: i16 = -204;
+/// Library vector offset of `SortLayerCR()`
+pub const LVO_SortLayerCR: i16 = -210;
+/// Library vector offset of `DoHookClipRects()`
+pub const LVO_DoHookClipRects: i16 = -216;
+/// Library vector offset of `LayerOccluded()`
+pub const LVO_LayerOccluded: i16 = -222;
+/// Library vector offset of `HideLayer()`
+pub const LVO_HideLayer: i16 = -228;
+/// Library vector offset of `ShowLayer()`
+pub const LVO_ShowLayer: i16 = -234;
+/// Library vector offset of `SetLayerInfoBounds()`
+pub const LVO_SetLayerInfoBounds: i16 = -240;
+
+/// Functions of "layers.library"
+pub static LAYERS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "InitLayers",
+        lvo: LVO_InitLayers,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CreateUpfrontLayer",
+        lvo: LVO_CreateUpfrontLayer,
+        registers: &["a0", "a1", "d0", "d1", "d2", "d3", "d4", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CreateBehindLayer",
+        lvo: LVO_CreateBehindLayer,
+        registers: &["a0", "a1", "d0", "d1", "d2", "d3", "d4", "a2"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UpfrontLayer",
+        lvo: LVO_UpfrontLayer,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BehindLayer",
+        lvo: LVO_BehindLayer,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveLayer",
+        lvo: LVO_MoveLayer,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SizeLayer",
+        lvo: LVO_SizeLayer,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ScrollLayer",
+        lvo: LVO_ScrollLayer,
+        registers: &["a0", "a1", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "BeginUpdate",
+        lvo: LVO_BeginUpdate,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "EndUpdate",
+        lvo: LVO_EndUpdate,
+        registers: &["a0", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DeleteLayer",
+        lvo: LVO_DeleteLayer,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LockLayer",
+        lvo: LVO_LockLayer,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnlockLayer",
+        lvo: LVO_UnlockLayer,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LockLayers",
+        lvo: LVO_LockLayers,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnlockLayers",
+        lvo: LVO_UnlockLayers,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "LockLayerInfo",
+        lvo: LVO_LockLayerInfo,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SwapBitsRastPortClipRect",
+        lvo: LVO_SwapBitsRastPortClipRect,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WhichLayer",
+        lvo: LVO_WhichLayer,
+        registers: &["a0", "d0", "d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "UnlockLayerInfo",
+        lvo: LVO_UnlockLayerInfo,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "NewLayerInfo",
+        lvo: LVO_NewLayerInfo,
+        registers: &[],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "DisposeLayerInfo",
+        lvo: LVO_DisposeLayerInfo,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FattenLayerInfo",
+        lvo: LVO_FattenLayerInfo,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ThinLayerInfo",
+        lvo: LVO_ThinLayerInfo,
+        registers: &["a0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveLayerInFrontOf",
+        lvo: LVO_MoveLayerInFrontOf,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "InstallClipRegion",
+        lvo: LVO_InstallClipRegion,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "MoveSizeLayer",
+        lvo: LVO_MoveSizeLayer,
+        registers: &["a0", "d0", "d1", "d2", "d3"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateUpfrontHookLayer",
+        lvo: LVO_CreateUpfrontHookLayer,
+        registers: &["a0", "a1", "d0", "d1", "d2", "d3", "d4", "a3", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateBehindHookLayer",
+        lvo: LVO_CreateBehindHookLayer,
+        registers: &["a0", "a1", "d0", "d1", "d2", "d3", "d4", "a3", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InstallLayerHook",
+        lvo: LVO_InstallLayerHook,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "InstallLayerInfoHook",
+        lvo: LVO_InstallLayerInfoHook,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SortLayerCR",
+        lvo: LVO_SortLayerCR,
+        registers: &["a0", "d0", "d1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "DoHookClipRects",
+        lvo: LVO_DoHookClipRects,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "LayerOccluded",
+        lvo: LVO_LayerOccluded,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "HideLayer",
+        lvo: LVO_HideLayer,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "ShowLayer",
+        lvo: LVO_ShowLayer,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "SetLayerInfoBounds",
+        lvo: LVO_SetLayerInfoBounds,
+        registers: &["a0", "a1"],
+        min_version: 47,
+    },
+];
@@ -0,0 +1,356 @@
+//! Functions of "layout.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn LAYOUT_GetClass(LayoutBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "LAYOUT_GetClass",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ActivateLayoutGadget(
+    LayoutBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    object: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "ActivateLayoutGadget",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+            in("d0") object,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FlushLayoutDomainCache(LayoutBase: *mut ::core::ffi::c_void, gadget: *mut Gadget) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "FlushLayoutDomainCache",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+        );
+    }
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RethinkLayout(
+    LayoutBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    window: *mut Window,
+    requester: *mut Requester,
+    refresh: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "RethinkLayout",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+            in("a1") window,
+            in("a2") requester,
+            in("d0") refresh,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn LayoutLimits(
+    LayoutBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    limits: *mut LayoutLimits,
+    font: *mut TextFont,
+    screen: *mut Screen,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "LayoutLimits",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+            in("a1") limits,
+            in("a2") font,
+            in("a3") screen,
+        );
+    }
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn PAGE_GetClass(LayoutBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "PAGE_GetClass",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetPageGadgetAttrsA(
+    LayoutBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    object: *mut Object,
+    window: *mut Window,
+    requester: *mut Requester,
+    tags: *mut TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "SetPageGadgetAttrsA",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a4, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a4reg}, %a4",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a4",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+            in("a1") object,
+            in("a2") window,
+            in("a3") requester,
+            a4reg = in(reg) tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "layout.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RefreshPageGadget(
+    LayoutBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    object: *mut Object,
+    window: *mut Window,
+    requester: *mut Requester,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            LayoutBase as *mut Library,
+            40,
+            "RefreshPageGadget",
+            "layout.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LayoutBase,
+            in("a0") gadget,
+            in("a1") object,
+            in("a2") window,
+            in("a3") requester,
+        );
+    }
+}
+
+/// Library vector offset of `LAYOUT_GetClass()`
+pub const LVO_LAYOUT_GetClass: i16 = -30;
+/// Library vector offset of `ActivateLayoutGadget()`
+pub const LVO_ActivateLayoutGadget: i16 = -36;
+/// Library vector offset of `FlushLayoutDomainCache()`
+pub const LVO_FlushLayoutDomainCache: i16 = -42;
+/// Library vector offset of `RethinkLayout()`
+pub const LVO_RethinkLayout: i16 = -48;
+/// Library vector offset of `LayoutLimits()`
+pub const LVO_LayoutLimits: i16 = -54;
+/// Library vector offset of `PAGE_GetClass()`
+pub const LVO_PAGE_GetClass: i16 = -60;
+/// Library vector offset of `SetPageGadgetAttrsA()`
+pub const LVO_SetPageGadgetAttrsA: i16 = -66;
+/// Library vector offset of `RefreshPageGadget()`
+pub const LVO_RefreshPageGadget: i16 = -72;
+
+/// Functions of "layout.gadget"
+pub static LAYOUT_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "LAYOUT_GetClass",
+        lvo: LVO_LAYOUT_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ActivateLayoutGadget",
+        lvo: LVO_ActivateLayoutGadget,
+        registers: &["a0", "a1", "a2", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FlushLayoutDomainCache",
+        lvo: LVO_FlushLayoutDomainCache,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RethinkLayout",
+        lvo: LVO_RethinkLayout,
+        registers: &["a0", "a1", "a2", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "LayoutLimits",
+        lvo: LVO_LayoutLimits,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "PAGE_GetClass",
+        lvo: LVO_PAGE_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetPageGadgetAttrsA",
+        lvo: LVO_SetPageGadgetAttrsA,
+        registers: &["a0", "a1", "a2", "a3", "a4"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RefreshPageGadget",
+        lvo: LVO_RefreshPageGadget,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+];
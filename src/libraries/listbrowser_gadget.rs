@@ -0,0 +1,634 @@
+//! Functions of "listbrowser.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn LISTBROWSER_GetClass(ListBrowserBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "LISTBROWSER_GetClass",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AllocListBrowserNodeA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    columns: ULONG,
+    tags: *mut TagItem,
+) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "AllocListBrowserNodeA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("d0") columns,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeListBrowserNode(ListBrowserBase: *mut ::core::ffi::c_void, node: *mut Node) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "FreeListBrowserNode",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetListBrowserNodeAttrsA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "SetListBrowserNodeAttrsA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetListBrowserNodeAttrsA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "GetListBrowserNodeAttrsA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ListBrowserSelectAll(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "ListBrowserSelectAll",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") list,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ShowListBrowserNodeChildren(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    depth: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "ShowListBrowserNodeChildren",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") node,
+            in("d0") depth,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn HideListBrowserNodeChildren(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "HideListBrowserNodeChildren",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ShowAllListBrowserChildren(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    list: *mut List,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "ShowAllListBrowserChildren",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") list,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn HideAllListBrowserChildren(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    list: *mut List,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "HideAllListBrowserChildren",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") list,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeListBrowserList(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            40,
+            "FreeListBrowserList",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") list,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn AllocLBColumnInfoA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    columns: ULONG,
+    tags: *mut TagItem,
+) -> *mut ColumnInfo {
+    let asm_ret_value: *mut ColumnInfo;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            45,
+            "AllocLBColumnInfoA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("d0") columns,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn SetLBColumnInfoAttrsA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    columninfo: *mut ColumnInfo,
+    tags: *mut TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            45,
+            "SetLBColumnInfoAttrsA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a1") columninfo,
+            in("a0") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn GetLBColumnInfoAttrsA(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    columninfo: *mut ColumnInfo,
+    tags: *mut TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            45,
+            "GetLBColumnInfoAttrsA",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a1") columninfo,
+            in("a0") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn FreeLBColumnInfo(
+    ListBrowserBase: *mut ::core::ffi::c_void,
+    columninfo: *mut ColumnInfo,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            45,
+            "FreeLBColumnInfo",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") columninfo,
+        );
+    }
+}
+
+/// libname: "listbrowser.gadget" (V45) (Kickstart 3.9 / 2000)
+#[cfg(not(feature = "kickstart-v44"))]
+pub unsafe fn ListBrowserClearAll(ListBrowserBase: *mut ::core::ffi::c_void, list: *mut List) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            ListBrowserBase as *mut Library,
+            45,
+            "ListBrowserClearAll",
+            "listbrowser.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) ListBrowserBase,
+            in("a0") list,
+        );
+    }
+}
+
+/// Library vector offset of `LISTBROWSER_GetClass()`
+pub const LVO_LISTBROWSER_GetClass: i16 = -30;
+/// Library vector offset of `AllocListBrowserNodeA()`
+pub const LVO_AllocListBrowserNodeA: i16 = -36;
+/// Library vector offset of `FreeListBrowserNode()`
+pub const LVO_FreeListBrowserNode: i16 = -42;
+/// Library vector offset of `SetListBrowserNodeAttrsA()`
+pub const LVO_SetListBrowserNodeAttrsA: i16 = -48;
+/// Library vector offset of `GetListBrowserNodeAttrsA()`
+pub const LVO_GetListBrowserNodeAttrsA: i16 = -54;
+/// Library vector offset of `ListBrowserSelectAll()`
+pub const LVO_ListBrowserSelectAll: i16 = -60;
+/// Library vector offset of `ShowListBrowserNodeChildren()`
+pub const LVO_ShowListBrowserNodeChildren: i16 = -66;
+/// Library vector offset of `HideListBrowserNodeChildren()`
+pub const LVO_HideListBrowserNodeChildren: i16 = -72;
+/// Library vector offset of `ShowAllListBrowserChildren()`
+pub const LVO_ShowAllListBrowserChildren: i16 = -78;
+/// Library vector offset of `HideAllListBrowserChildren()`
+pub const LVO_HideAllListBrowserChildren: i16 = -84;
+/// Library vector offset of `FreeListBrowserList()`
+pub const LVO_FreeListBrowserList: i16 = -90;
+/// Library vector offset of `AllocLBColumnInfoA()`
+pub const LVO_AllocLBColumnInfoA: i16 = -96;
+/// Library vector offset of `SetLBColumnInfoAttrsA()`
+pub const LVO_SetLBColumnInfoAttrsA: i16 = -102;
+/// Library vector offset of `GetLBColumnInfoAttrsA()`
+pub const LVO_GetLBColumnInfoAttrsA: i16 = -108;
+/// Library vector offset of `FreeLBColumnInfo()`
+pub const LVO_FreeLBColumnInfo: i16 = -114;
+/// Library vector offset of `ListBrowserClearAll()`
+pub const LVO_ListBrowserClearAll: i16 = -120;
+
+/// Functions of "listbrowser.gadget"
+pub static LISTBROWSER_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "LISTBROWSER_GetClass",
+        lvo: LVO_LISTBROWSER_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocListBrowserNodeA",
+        lvo: LVO_AllocListBrowserNodeA,
+        registers: &["d0", "a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeListBrowserNode",
+        lvo: LVO_FreeListBrowserNode,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetListBrowserNodeAttrsA",
+        lvo: LVO_SetListBrowserNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetListBrowserNodeAttrsA",
+        lvo: LVO_GetListBrowserNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ListBrowserSelectAll",
+        lvo: LVO_ListBrowserSelectAll,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ShowListBrowserNodeChildren",
+        lvo: LVO_ShowListBrowserNodeChildren,
+        registers: &["a0", "d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "HideListBrowserNodeChildren",
+        lvo: LVO_HideListBrowserNodeChildren,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ShowAllListBrowserChildren",
+        lvo: LVO_ShowAllListBrowserChildren,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "HideAllListBrowserChildren",
+        lvo: LVO_HideAllListBrowserChildren,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeListBrowserList",
+        lvo: LVO_FreeListBrowserList,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocLBColumnInfoA",
+        lvo: LVO_AllocLBColumnInfoA,
+        registers: &["d0", "a0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "SetLBColumnInfoAttrsA",
+        lvo: LVO_SetLBColumnInfoAttrsA,
+        registers: &["a1", "a0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "GetLBColumnInfoAttrsA",
+        lvo: LVO_GetLBColumnInfoAttrsA,
+        registers: &["a1", "a0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "FreeLBColumnInfo",
+        lvo: LVO_FreeLBColumnInfo,
+        registers: &["a0"],
+        min_version: 45,
+    },
+    crate::FunctionInfo {
+        name: "ListBrowserClearAll",
+        lvo: LVO_ListBrowserClearAll,
+        registers: &["a0"],
+        min_version: 45,
+    },
+];
@@ -0,0 +1,1150 @@
+//! Functions of "locale.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn CloseCatalog(LocaleBase: *mut Library, catalog: *mut Catalog) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "CloseCatalog", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") catalog,
+        );
+    }
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn CloseLocale(LocaleBase: *mut Library, locale: *mut Locale) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "CloseLocale", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+        );
+    }
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn ConvToLower(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    character: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "ConvToLower", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn ConvToUpper(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    character: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "ConvToUpper", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn FormatDate(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    fmtTemplate: CONST_STRPTR,
+    date: *const DateStamp,
+    putCharFunc: *mut Hook,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "FormatDate", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") fmtTemplate,
+            in("a2") date,
+            in("a3") putCharFunc,
+        );
+    }
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn FormatString(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    fmtTemplate: CONST_STRPTR,
+    dataStream: APTR,
+    putCharFunc: *mut Hook,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "FormatString", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") fmtTemplate,
+            in("a2") dataStream,
+            in("a3") putCharFunc,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn GetCatalogStr(
+    LocaleBase: *mut Library,
+    catalog: *const Catalog,
+    stringNum: LONG,
+    defaultString: CONST_STRPTR,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "GetCatalogStr", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") catalog,
+            in("d0") stringNum,
+            in("a1") defaultString,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn GetLocaleStr(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    stringNum: ULONG,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "GetLocaleStr", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") stringNum,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsAlNum(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsAlNum", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsAlpha(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsAlpha", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsCntrl(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsCntrl", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsDigit(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsDigit", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsGraph(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsGraph", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsLower(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsLower", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsPrint(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsPrint", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsPunct(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsPunct", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsSpace(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsSpace", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsUpper(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsUpper", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn IsXDigit(LocaleBase: *mut Library, locale: *mut Locale, character: ULONG) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "IsXDigit", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn OpenCatalogA(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> *mut Catalog {
+    let asm_ret_value: *mut Catalog;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "OpenCatalogA", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") name,
+            in("a2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn OpenLocale(LocaleBase: *mut Library, name: CONST_STRPTR) -> *mut Locale {
+    let asm_ret_value: *mut Locale;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "OpenLocale", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn ParseDate(
+    LocaleBase: *mut Library,
+    locale: *const Locale,
+    date: *mut DateStamp,
+    fmtTemplate: CONST_STRPTR,
+    getCharFunc: *mut Hook,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "ParseDate", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") date,
+            in("a2") fmtTemplate,
+            in("a3") getCharFunc,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn StrConvert(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    string: CONST_STRPTR,
+    buffer: APTR,
+    bufferSize: ULONG,
+    type_: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "StrConvert", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") string,
+            in("a2") buffer,
+            in("d0") bufferSize,
+            in("d1") type_,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "locale.library" (V38) (Kickstart 2.1)
+#[cfg(not(feature = "kickstart-v37"))]
+pub unsafe fn StrnCmp(
+    LocaleBase: *mut Library,
+    locale: *mut Locale,
+    string1: CONST_STRPTR,
+    string2: CONST_STRPTR,
+    length: LONG,
+    type_: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LocaleBase, 38, "StrnCmp", "locale.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LocaleBase,
+            in("a0") locale,
+            in("a1") string1,
+            in("a2") string2,
+            in("d0") length,
+            in("d1") type_,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "locale.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct LocaleLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl LocaleLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"locale.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "locale.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn CloseCatalog(&self, catalog: *mut Catalog) {
+        unsafe { CloseCatalog(self.base.as_ptr(), catalog) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn CloseLocale(&self, locale: *mut Locale) {
+        unsafe { CloseLocale(self.base.as_ptr(), locale) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn ConvToLower(&self, locale: *mut Locale, character: ULONG) -> ULONG {
+        unsafe { ConvToLower(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn ConvToUpper(&self, locale: *mut Locale, character: ULONG) -> ULONG {
+        unsafe { ConvToUpper(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn FormatDate(
+        &self,
+        locale: *mut Locale,
+        fmtTemplate: CONST_STRPTR,
+        date: *const DateStamp,
+        putCharFunc: *mut Hook,
+    ) {
+        unsafe { FormatDate(self.base.as_ptr(), locale, fmtTemplate, date, putCharFunc) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn FormatString(
+        &self,
+        locale: *mut Locale,
+        fmtTemplate: CONST_STRPTR,
+        dataStream: APTR,
+        putCharFunc: *mut Hook,
+    ) -> APTR {
+        unsafe {
+            FormatString(
+                self.base.as_ptr(),
+                locale,
+                fmtTemplate,
+                dataStream,
+                putCharFunc,
+            )
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn GetCatalogStr(
+        &self,
+        catalog: *const Catalog,
+        stringNum: LONG,
+        defaultString: CONST_STRPTR,
+    ) -> STRPTR {
+        unsafe { GetCatalogStr(self.base.as_ptr(), catalog, stringNum, defaultString) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn GetLocaleStr(&self, locale: *mut Locale, stringNum: ULONG) -> STRPTR {
+        unsafe { GetLocaleStr(self.base.as_ptr(), locale, stringNum) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsAlNum(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsAlNum(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsAlpha(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsAlpha(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsCntrl(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsCntrl(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsDigit(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsDigit(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsGraph(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsGraph(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsLower(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsLower(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsPrint(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsPrint(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsPunct(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsPunct(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsSpace(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsSpace(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsUpper(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsUpper(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn IsXDigit(&self, locale: *mut Locale, character: ULONG) -> BOOL {
+        unsafe { IsXDigit(self.base.as_ptr(), locale, character) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn OpenCatalogA(
+        &self,
+        locale: *mut Locale,
+        name: CONST_STRPTR,
+        tags: *const TagItem,
+    ) -> *mut Catalog {
+        unsafe { OpenCatalogA(self.base.as_ptr(), locale, name, tags) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn OpenLocale(&self, name: CONST_STRPTR) -> *mut Locale {
+        unsafe { OpenLocale(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn ParseDate(
+        &self,
+        locale: *const Locale,
+        date: *mut DateStamp,
+        fmtTemplate: CONST_STRPTR,
+        getCharFunc: *mut Hook,
+    ) -> BOOL {
+        unsafe { ParseDate(self.base.as_ptr(), locale, date, fmtTemplate, getCharFunc) }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn StrConvert(
+        &self,
+        locale: *mut Locale,
+        string: CONST_STRPTR,
+        buffer: APTR,
+        bufferSize: ULONG,
+        type_: ULONG,
+    ) -> ULONG {
+        unsafe {
+            StrConvert(
+                self.base.as_ptr(),
+                locale,
+                string,
+                buffer,
+                bufferSize,
+                type_,
+            )
+        }
+    }
+
+    /// libname: "locale.library" (V38) (Kickstart 2.1)
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub unsafe fn StrnCmp(
+        &self,
+        locale: *mut Locale,
+        string1: CONST_STRPTR,
+        string2: CONST_STRPTR,
+        length: LONG,
+        type_: ULONG,
+    ) -> LONG {
+        unsafe { StrnCmp(self.base.as_ptr(), locale, string1, string2, length, type_) }
+    }
+}
+
+impl Drop for LocaleLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `CloseCatalog()`
+pub const LVO_CloseCatalog: i16 = -36;
+/// Library vector offset of `CloseLocale()`
+pub const LVO_CloseLocale: i16 = -42;
+/// Library vector offset of `ConvToLower()`
+pub const LVO_ConvToLower: i16 = -48;
+/// Library vector offset of `ConvToUpper()`
+pub const LVO_ConvToUpper: i16 = -54;
+/// Library vector offset of `FormatDate()`
+pub const LVO_FormatDate: i16 = -60;
+/// Library vector offset of `FormatString()`
+pub const LVO_FormatString: i16 = -66;
+/// Library vector offset of `GetCatalogStr()`
+pub const LVO_GetCatalogStr: i16 = -72;
+/// Library vector offset of `GetLocaleStr()`
+pub const LVO_GetLocaleStr: i16 = -78;
+/// Library vector offset of `IsAlNum()`
+pub const LVO_IsAlNum: i16 = -84;
+/// Library vector offset of `IsAlpha()`
+pub const LVO_IsAlpha: i16 = -90;
+/// Library vector offset of `IsCntrl()`
+pub const LVO_IsCntrl: i16 = -96;
+/// Library vector offset of `IsDigit()`
+pub const LVO_IsDigit: i16 = -102;
+/// Library vector offset of `IsGraph()`
+pub const LVO_IsGraph: i16 = -108;
+/// Library vector offset of `IsLower()`
+pub const LVO_IsLower: i16 = -114;
+/// Library vector offset of `IsPrint()`
+pub const LVO_IsPrint: i16 = -120;
+/// Library vector offset of `IsPunct()`
+pub const LVO_IsPunct: i16 = -126;
+/// Library vector offset of `IsSpace()`
+pub const LVO_IsSpace: i16 = -132;
+/// Library vector offset of `IsUpper()`
+pub const LVO_IsUpper: i16 = -138;
+/// Library vector offset of `IsXDigit()`
+pub const LVO_IsXDigit: i16 = -144;
+/// Library vector offset of `OpenCatalogA()`
+pub const LVO_OpenCatalogA: i16 = -150;
+/// Library vector offset of `OpenLocale()`
+pub const LVO_OpenLocale: i16 = -156;
+/// Library vector offset of `ParseDate()`
+pub const LVO_ParseDate: i16 = -162;
+/// Library vector offset of `StrConvert()`
+pub const LVO_StrConvert: i16 = -174;
+/// Library vector offset of `StrnCmp()`
+pub const LVO_StrnCmp: i16 = -180;
+
+/// Functions of "locale.library"
+pub static LOCALE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CloseCatalog",
+        lvo: LVO_CloseCatalog,
+        registers: &["a0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "CloseLocale",
+        lvo: LVO_CloseLocale,
+        registers: &["a0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "ConvToLower",
+        lvo: LVO_ConvToLower,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "ConvToUpper",
+        lvo: LVO_ConvToUpper,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "FormatDate",
+        lvo: LVO_FormatDate,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "FormatString",
+        lvo: LVO_FormatString,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "GetCatalogStr",
+        lvo: LVO_GetCatalogStr,
+        registers: &["a0", "d0", "a1"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "GetLocaleStr",
+        lvo: LVO_GetLocaleStr,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsAlNum",
+        lvo: LVO_IsAlNum,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsAlpha",
+        lvo: LVO_IsAlpha,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsCntrl",
+        lvo: LVO_IsCntrl,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsDigit",
+        lvo: LVO_IsDigit,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsGraph",
+        lvo: LVO_IsGraph,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsLower",
+        lvo: LVO_IsLower,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsPrint",
+        lvo: LVO_IsPrint,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsPunct",
+        lvo: LVO_IsPunct,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsSpace",
+        lvo: LVO_IsSpace,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsUpper",
+        lvo: LVO_IsUpper,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "IsXDigit",
+        lvo: LVO_IsXDigit,
+        registers: &["a0", "d0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "OpenCatalogA",
+        lvo: LVO_OpenCatalogA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "OpenLocale",
+        lvo: LVO_OpenLocale,
+        registers: &["a0"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "ParseDate",
+        lvo: LVO_ParseDate,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "StrConvert",
+        lvo: LVO_StrConvert,
+        registers: &["a0", "a1", "a2", "d0", "d1"],
+        min_version: 38,
+    },
+    crate::FunctionInfo {
+        name: "StrnCmp",
+        lvo: LVO_StrnCmp,
+        registers: &["a0", "a1", "a2", "d0", "d1"],
+        min_version: 38,
+    },
+];
@@ -0,0 +1,656 @@
+//! Functions of "lowlevel.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ReadJoyPort(LowLevelBase: *mut Library, port: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "ReadJoyPort", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("d0") port,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetLanguageSelection(LowLevelBase: *mut Library) -> UBYTE {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "GetLanguageSelection", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetKey(LowLevelBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "GetKey", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn QueryKeys(LowLevelBase: *mut Library, queryArray: *mut KeyQuery, arraySize: LONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "QueryKeys", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a0") queryArray,
+            in("d1") arraySize,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AddKBInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "AddKBInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a0") intRoutine,
+            in("a1") intData,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RemKBInt(LowLevelBase: *mut Library, intHandle: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "RemKBInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") intHandle,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SystemControlA(LowLevelBase: *mut Library, tagList: *const TagItem) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "SystemControlA", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AddTimerInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "AddTimerInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a0") intRoutine,
+            in("a1") intData,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RemTimerInt(LowLevelBase: *mut Library, intHandle: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "RemTimerInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") intHandle,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn StopTimerInt(LowLevelBase: *mut Library, intHandle: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "StopTimerInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") intHandle,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn StartTimerInt(
+    LowLevelBase: *mut Library,
+    intHandle: APTR,
+    timeInterval: ULONG,
+    continuous: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "StartTimerInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") intHandle,
+            in("d0") timeInterval,
+            in("d1") continuous,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn ElapsedTime(LowLevelBase: *mut Library, context: *mut EClockVal) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "ElapsedTime", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a0") context,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AddVBlankInt(LowLevelBase: *mut Library, intRoutine: APTR, intData: APTR) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "AddVBlankInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a0") intRoutine,
+            in("a1") intData,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RemVBlankInt(LowLevelBase: *mut Library, intHandle: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "RemVBlankInt", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("a1") intHandle,
+        );
+    }
+}
+
+/// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetJoyPortAttrsA(
+    LowLevelBase: *mut Library,
+    portNumber: ULONG,
+    tagList: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(LowLevelBase, 40, "SetJoyPortAttrsA", "lowlevel.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) LowLevelBase,
+            in("d0") portNumber,
+            in("a1") tagList,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// An open "lowlevel.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct LowLevelLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl LowLevelLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"lowlevel.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "lowlevel.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ReadJoyPort(&self, port: ULONG) -> ULONG {
+        unsafe { ReadJoyPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetLanguageSelection(&self) -> UBYTE {
+        unsafe { GetLanguageSelection(self.base.as_ptr()) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetKey(&self) -> ULONG {
+        unsafe { GetKey(self.base.as_ptr()) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn QueryKeys(&self, queryArray: *mut KeyQuery, arraySize: LONG) {
+        unsafe { QueryKeys(self.base.as_ptr(), queryArray, arraySize) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AddKBInt(&self, intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { AddKBInt(self.base.as_ptr(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RemKBInt(&self, intHandle: APTR) {
+        unsafe { RemKBInt(self.base.as_ptr(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SystemControlA(&self, tagList: *const TagItem) -> ULONG {
+        unsafe { SystemControlA(self.base.as_ptr(), tagList) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AddTimerInt(&self, intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { AddTimerInt(self.base.as_ptr(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RemTimerInt(&self, intHandle: APTR) {
+        unsafe { RemTimerInt(self.base.as_ptr(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn StopTimerInt(&self, intHandle: APTR) {
+        unsafe { StopTimerInt(self.base.as_ptr(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn StartTimerInt(&self, intHandle: APTR, timeInterval: ULONG, continuous: LONG) {
+        unsafe { StartTimerInt(self.base.as_ptr(), intHandle, timeInterval, continuous) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn ElapsedTime(&self, context: *mut EClockVal) -> ULONG {
+        unsafe { ElapsedTime(self.base.as_ptr(), context) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn AddVBlankInt(&self, intRoutine: APTR, intData: APTR) -> APTR {
+        unsafe { AddVBlankInt(self.base.as_ptr(), intRoutine, intData) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn RemVBlankInt(&self, intHandle: APTR) {
+        unsafe { RemVBlankInt(self.base.as_ptr(), intHandle) }
+    }
+
+    /// libname: "lowlevel.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SetJoyPortAttrsA(&self, portNumber: ULONG, tagList: *const TagItem) -> BOOL {
+        unsafe { SetJoyPortAttrsA(self.base.as_ptr(), portNumber, tagList) }
+    }
+}
+
+impl Drop for LowLevelLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `ReadJoyPort()`
+pub const LVO_ReadJoyPort: i16 = -30;
+/// Library vector offset of `GetLanguageSelection()`
+pub const LVO_GetLanguageSelection: i16 = -36;
+/// Library vector offset of `GetKey()`
+pub const LVO_GetKey: i16 = -48;
+/// Library vector offset of `QueryKeys()`
+pub const LVO_QueryKeys: i16 = -54;
+/// Library vector offset of `AddKBInt()`
+pub const LVO_AddKBInt: i16 = -60;
+/// Library vector offset of `RemKBInt()`
+pub const LVO_RemKBInt: i16 = -66;
+/// Library vector offset of `SystemControlA()`
+pub const LVO_SystemControlA: i16 = -72;
+/// Library vector offset of `AddTimerInt()`
+pub const LVO_AddTimerInt: i16 = -78;
+/// Library vector offset of `RemTimerInt()`
+pub const LVO_RemTimerInt: i16 = -84;
+/// Library vector offset of `StopTimerInt()`
+pub const LVO_StopTimerInt: i16 = -90;
+/// Library vector offset of `StartTimerInt()`
+pub const LVO_StartTimerInt: i16 = -96;
+/// Library vector offset of `ElapsedTime()`
+pub const LVO_ElapsedTime: i16 = -102;
+/// Library vector offset of `AddVBlankInt()`
+pub const LVO_AddVBlankInt: i16 = -108;
+/// Library vector offset of `RemVBlankInt()`
+pub const LVO_RemVBlankInt: i16 = -114;
+/// Library vector offset of `SetJoyPortAttrsA()`
+pub const LVO_SetJoyPortAttrsA: i16 = -132;
+
+/// Functions of "lowlevel.library"
+pub static LOWLEVEL_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "ReadJoyPort",
+        lvo: LVO_ReadJoyPort,
+        registers: &["d0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetLanguageSelection",
+        lvo: LVO_GetLanguageSelection,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetKey",
+        lvo: LVO_GetKey,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "QueryKeys",
+        lvo: LVO_QueryKeys,
+        registers: &["a0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AddKBInt",
+        lvo: LVO_AddKBInt,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RemKBInt",
+        lvo: LVO_RemKBInt,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SystemControlA",
+        lvo: LVO_SystemControlA,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AddTimerInt",
+        lvo: LVO_AddTimerInt,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RemTimerInt",
+        lvo: LVO_RemTimerInt,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "StopTimerInt",
+        lvo: LVO_StopTimerInt,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "StartTimerInt",
+        lvo: LVO_StartTimerInt,
+        registers: &["a1", "d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "ElapsedTime",
+        lvo: LVO_ElapsedTime,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AddVBlankInt",
+        lvo: LVO_AddVBlankInt,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RemVBlankInt",
+        lvo: LVO_RemVBlankInt,
+        registers: &["a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetJoyPortAttrsA",
+        lvo: LVO_SetJoyPortAttrsA,
+        registers: &["d0", "a1"],
+        min_version: 40,
+    },
+];
@@ -0,0 +1,482 @@
+//! Functions of "mathffp.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathffp.library"
+pub unsafe fn SPFix(MathBase: *mut Library, parm: FLOAT) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPFlt(MathBase: *mut Library, integer: LONG) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") integer,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPCmp(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") leftParm.to_bits(),
+            in("d0") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPTst(MathBase: *mut Library, parm: FLOAT) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") parm.to_bits(),
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPAbs(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPNeg(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPAdd(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") leftParm.to_bits(),
+            in("d0") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPSub(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") leftParm.to_bits(),
+            in("d0") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPMul(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") leftParm.to_bits(),
+            in("d0") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library"
+pub unsafe fn SPDiv(MathBase: *mut Library, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d1") leftParm.to_bits(),
+            in("d0") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library" (V33) (Kickstart 1.2)
+pub unsafe fn SPFloor(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(MathBase, 33, "SPFloor", "mathffp.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathffp.library" (V33) (Kickstart 1.2)
+pub unsafe fn SPCeil(MathBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(MathBase, 33, "SPCeil", "mathffp.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// An open "mathffp.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathffpLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathffpLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathffp.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathffp.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPFix(&self, parm: FLOAT) -> LONG {
+        unsafe { SPFix(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPFlt(&self, integer: LONG) -> FLOAT {
+        unsafe { SPFlt(self.base.as_ptr(), integer) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPCmp(&self, leftParm: FLOAT, rightParm: FLOAT) -> LONG {
+        unsafe { SPCmp(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPTst(&self, parm: FLOAT) -> LONG {
+        unsafe { SPTst(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPAbs(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPAbs(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPNeg(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPNeg(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPAdd(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { SPAdd(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPSub(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { SPSub(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPMul(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { SPMul(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library"
+    pub unsafe fn SPDiv(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { SPDiv(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathffp.library" (V33) (Kickstart 1.2)
+    pub unsafe fn SPFloor(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPFloor(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathffp.library" (V33) (Kickstart 1.2)
+    pub unsafe fn SPCeil(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPCeil(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathffpLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `SPFix()`
+pub const LVO_SPFix: i16 = -30;
+/// Library vector offset of `SPFlt()`
+pub const LVO_SPFlt: i16 = -36;
+/// Library vector offset of `SPCmp()`
+pub const LVO_SPCmp: i16 = -42;
+/// Library vector offset of `SPTst()`
+pub const LVO_SPTst: i16 = -48;
+/// Library vector offset of `SPAbs()`
+pub const LVO_SPAbs: i16 = -54;
+/// Library vector offset of `SPNeg()`
+pub const LVO_SPNeg: i16 = -60;
+/// Library vector offset of `SPAdd()`
+pub const LVO_SPAdd: i16 = -66;
+/// Library vector offset of `SPSub()`
+pub const LVO_SPSub: i16 = -72;
+/// Library vector offset of `SPMul()`
+pub const LVO_SPMul: i16 = -78;
+/// Library vector offset of `SPDiv()`
+pub const LVO_SPDiv: i16 = -84;
+/// Library vector offset of `SPFloor()`
+pub const LVO_SPFloor: i16 = -90;
+/// Library vector offset of `SPCeil()`
+pub const LVO_SPCeil: i16 = -96;
+
+/// Functions of "mathffp.library"
+pub static MATHFFP_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "SPFix",
+        lvo: LVO_SPFix,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPFlt",
+        lvo: LVO_SPFlt,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPCmp",
+        lvo: LVO_SPCmp,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPTst",
+        lvo: LVO_SPTst,
+        registers: &["d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPAbs",
+        lvo: LVO_SPAbs,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPNeg",
+        lvo: LVO_SPNeg,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPAdd",
+        lvo: LVO_SPAdd,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPSub",
+        lvo: LVO_SPSub,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPMul",
+        lvo: LVO_SPMul,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPDiv",
+        lvo: LVO_SPDiv,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPFloor",
+        lvo: LVO_SPFloor,
+        registers: &["d0"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "SPCeil",
+        lvo: LVO_SPCeil,
+        registers: &["d0"],
+        min_version: 33,
+    },
+];
@@ -0,0 +1,537 @@
+//! Functions of "mathieeedoubbas.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPFix(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPFlt(MathIeeeDoubBasBase: *mut Library, integer: LONG) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") integer,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPCmp(
+    MathIeeeDoubBasBase: *mut Library,
+    leftParm: DOUBLE,
+    rightParm: DOUBLE,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (leftParm.to_bits() >> 32) as u32,
+            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
+            in("d2") (rightParm.to_bits() >> 32) as u32,
+            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPTst(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPAbs(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPNeg(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPAdd(
+    MathIeeeDoubBasBase: *mut Library,
+    leftParm: DOUBLE,
+    rightParm: DOUBLE,
+) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (leftParm.to_bits() >> 32) as u32,
+            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
+            in("d2") (rightParm.to_bits() >> 32) as u32,
+            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPSub(
+    MathIeeeDoubBasBase: *mut Library,
+    leftParm: DOUBLE,
+    rightParm: DOUBLE,
+) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (leftParm.to_bits() >> 32) as u32,
+            in("d1") (leftParm.to_bits() & 0xffff_ffff) as u32,
+            in("d2") (rightParm.to_bits() >> 32) as u32,
+            in("d3") (rightParm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPMul(
+    MathIeeeDoubBasBase: *mut Library,
+    factor1: DOUBLE,
+    factor2: DOUBLE,
+) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (factor1.to_bits() >> 32) as u32,
+            in("d1") (factor1.to_bits() & 0xffff_ffff) as u32,
+            in("d2") (factor2.to_bits() >> 32) as u32,
+            in("d3") (factor2.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library"
+pub unsafe fn IEEEDPDiv(
+    MathIeeeDoubBasBase: *mut Library,
+    dividend: DOUBLE,
+    divisor: DOUBLE,
+) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (dividend.to_bits() >> 32) as u32,
+            in("d1") (dividend.to_bits() & 0xffff_ffff) as u32,
+            in("d2") (divisor.to_bits() >> 32) as u32,
+            in("d3") (divisor.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+pub unsafe fn IEEEDPFloor(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeDoubBasBase,
+            33,
+            "IEEEDPFloor",
+            "mathieeedoubbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+pub unsafe fn IEEEDPCeil(MathIeeeDoubBasBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeDoubBasBase,
+            33,
+            "IEEEDPCeil",
+            "mathieeedoubbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubBasBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// An open "mathieeedoubbas.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathIeeeDoubBasLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathIeeeDoubBasLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathieeedoubbas.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathieeedoubbas.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPFix(&self, parm: DOUBLE) -> LONG {
+        unsafe { IEEEDPFix(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPFlt(&self, integer: LONG) -> DOUBLE {
+        unsafe { IEEEDPFlt(self.base.as_ptr(), integer) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPCmp(&self, leftParm: DOUBLE, rightParm: DOUBLE) -> LONG {
+        unsafe { IEEEDPCmp(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPTst(&self, parm: DOUBLE) -> LONG {
+        unsafe { IEEEDPTst(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPAbs(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPAbs(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPNeg(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPNeg(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPAdd(&self, leftParm: DOUBLE, rightParm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPAdd(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPSub(&self, leftParm: DOUBLE, rightParm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPSub(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPMul(&self, factor1: DOUBLE, factor2: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPMul(self.base.as_ptr(), factor1, factor2) }
+    }
+
+    /// libname: "mathieeedoubbas.library"
+    pub unsafe fn IEEEDPDiv(&self, dividend: DOUBLE, divisor: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPDiv(self.base.as_ptr(), dividend, divisor) }
+    }
+
+    /// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+    pub unsafe fn IEEEDPFloor(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPFloor(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubbas.library" (V33) (Kickstart 1.2)
+    pub unsafe fn IEEEDPCeil(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPCeil(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathIeeeDoubBasLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `IEEEDPFix()`
+pub const LVO_IEEEDPFix: i16 = -30;
+/// Library vector offset of `IEEEDPFlt()`
+pub const LVO_IEEEDPFlt: i16 = -36;
+/// Library vector offset of `IEEEDPCmp()`
+pub const LVO_IEEEDPCmp: i16 = -42;
+/// Library vector offset of `IEEEDPTst()`
+pub const LVO_IEEEDPTst: i16 = -48;
+/// Library vector offset of `IEEEDPAbs()`
+pub const LVO_IEEEDPAbs: i16 = -54;
+/// Library vector offset of `IEEEDPNeg()`
+pub const LVO_IEEEDPNeg: i16 = -60;
+/// Library vector offset of `IEEEDPAdd()`
+pub const LVO_IEEEDPAdd: i16 = -66;
+/// Library vector offset of `IEEEDPSub()`
+pub const LVO_IEEEDPSub: i16 = -72;
+/// Library vector offset of `IEEEDPMul()`
+pub const LVO_IEEEDPMul: i16 = -78;
+/// Library vector offset of `IEEEDPDiv()`
+pub const LVO_IEEEDPDiv: i16 = -84;
+/// Library vector offset of `IEEEDPFloor()`
+pub const LVO_IEEEDPFloor: i16 = -90;
+/// Library vector offset of `IEEEDPCeil()`
+pub const LVO_IEEEDPCeil: i16 = -96;
+
+/// Functions of "mathieeedoubbas.library"
+pub static MATHIEEEDOUBBAS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "IEEEDPFix",
+        lvo: LVO_IEEEDPFix,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPFlt",
+        lvo: LVO_IEEEDPFlt,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPCmp",
+        lvo: LVO_IEEEDPCmp,
+        registers: &["d0-d1", "d2-d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPTst",
+        lvo: LVO_IEEEDPTst,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPAbs",
+        lvo: LVO_IEEEDPAbs,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPNeg",
+        lvo: LVO_IEEEDPNeg,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPAdd",
+        lvo: LVO_IEEEDPAdd,
+        registers: &["d0-d1", "d2-d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPSub",
+        lvo: LVO_IEEEDPSub,
+        registers: &["d0-d1", "d2-d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPMul",
+        lvo: LVO_IEEEDPMul,
+        registers: &["d0-d1", "d2-d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPDiv",
+        lvo: LVO_IEEEDPDiv,
+        registers: &["d0-d1", "d2-d3"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPFloor",
+        lvo: LVO_IEEEDPFloor,
+        registers: &["d0-d1"],
+        min_version: 33,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPCeil",
+        lvo: LVO_IEEEDPCeil,
+        registers: &["d0-d1"],
+        min_version: 33,
+    },
+];
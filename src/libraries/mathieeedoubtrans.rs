@@ -0,0 +1,673 @@
+//! Functions of "mathieeedoubtrans.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPAtan(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPSin(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPCos(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPTan(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPSincos(
+    MathIeeeDoubTransBase: *mut Library,
+    pf2: *mut DOUBLE,
+    parm: DOUBLE,
+) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("a0") pf2,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPSinh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPCosh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPTanh(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPExp(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPLog(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPPow(MathIeeeDoubTransBase: *mut Library, SPExp: DOUBLE, arg: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d2") (SPExp.to_bits() >> 32) as u32,
+            in("d3") (SPExp.to_bits() & 0xffff_ffff) as u32,
+            in("d0") (arg.to_bits() >> 32) as u32,
+            in("d1") (arg.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPSqrt(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPTieee(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPFieee(MathIeeeDoubTransBase: *mut Library, single: FLOAT) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") single.to_bits(),
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPAsin(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPAcos(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// libname: "mathieeedoubtrans.library"
+pub unsafe fn IEEEDPLog10(MathIeeeDoubTransBase: *mut Library, parm: DOUBLE) -> DOUBLE {
+    let mut asm_ret_value: (u32, u32) = (0, 0);
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeDoubTransBase,
+            in("d0") (parm.to_bits() >> 32) as u32,
+            in("d1") (parm.to_bits() & 0xffff_ffff) as u32,
+            lateout("d0") asm_ret_value.0,
+            lateout("d1") asm_ret_value.1,
+        );
+    }
+    f64::from_bits(((asm_ret_value.0 as u64) << 32) | asm_ret_value.1 as u64)
+}
+
+/// An open "mathieeedoubtrans.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathIeeeDoubTransLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathIeeeDoubTransLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathieeedoubtrans.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathieeedoubtrans.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPAtan(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPAtan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPSin(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPSin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPCos(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPCos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPTan(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPTan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPSincos(&self, pf2: *mut DOUBLE, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPSincos(self.base.as_ptr(), pf2, parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPSinh(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPSinh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPCosh(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPCosh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPTanh(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPTanh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPExp(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPExp(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPLog(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPLog(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPPow(&self, SPExp: DOUBLE, arg: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPPow(self.base.as_ptr(), SPExp, arg) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPSqrt(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPSqrt(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPTieee(&self, parm: DOUBLE) -> FLOAT {
+        unsafe { IEEEDPTieee(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPFieee(&self, single: FLOAT) -> DOUBLE {
+        unsafe { IEEEDPFieee(self.base.as_ptr(), single) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPAsin(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPAsin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPAcos(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPAcos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeedoubtrans.library"
+    pub unsafe fn IEEEDPLog10(&self, parm: DOUBLE) -> DOUBLE {
+        unsafe { IEEEDPLog10(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathIeeeDoubTransLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `IEEEDPAtan()`
+pub const LVO_IEEEDPAtan: i16 = -30;
+/// Library vector offset of `IEEEDPSin()`
+pub const LVO_IEEEDPSin: i16 = -36;
+/// Library vector offset of `IEEEDPCos()`
+pub const LVO_IEEEDPCos: i16 = -42;
+/// Library vector offset of `IEEEDPTan()`
+pub const LVO_IEEEDPTan: i16 = -48;
+/// Library vector offset of `IEEEDPSincos()`
+pub const LVO_IEEEDPSincos: i16 = -54;
+/// Library vector offset of `IEEEDPSinh()`
+pub const LVO_IEEEDPSinh: i16 = -60;
+/// Library vector offset of `IEEEDPCosh()`
+pub const LVO_IEEEDPCosh: i16 = -66;
+/// Library vector offset of `IEEEDPTanh()`
+pub const LVO_IEEEDPTanh: i16 = -72;
+/// Library vector offset of `IEEEDPExp()`
+pub const LVO_IEEEDPExp: i16 = -78;
+/// Library vector offset of `IEEEDPLog()`
+pub const LVO_IEEEDPLog: i16 = -84;
+/// Library vector offset of `IEEEDPPow()`
+pub const LVO_IEEEDPPow: i16 = -90;
+/// Library vector offset of `IEEEDPSqrt()`
+pub const LVO_IEEEDPSqrt: i16 = -96;
+/// Library vector offset of `IEEEDPTieee()`
+pub const LVO_IEEEDPTieee: i16 = -102;
+/// Library vector offset of `IEEEDPFieee()`
+pub const LVO_IEEEDPFieee: i16 = -108;
+/// Library vector offset of `IEEEDPAsin()`
+pub const LVO_IEEEDPAsin: i16 = -114;
+/// Library vector offset of `IEEEDPAcos()`
+pub const LVO_IEEEDPAcos: i16 = -120;
+/// Library vector offset of `IEEEDPLog10()`
+pub const LVO_IEEEDPLog10: i16 = -126;
+
+/// Functions of "mathieeedoubtrans.library"
+pub static MATHIEEEDOUBTRANS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "IEEEDPAtan",
+        lvo: LVO_IEEEDPAtan,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPSin",
+        lvo: LVO_IEEEDPSin,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPCos",
+        lvo: LVO_IEEEDPCos,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPTan",
+        lvo: LVO_IEEEDPTan,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPSincos",
+        lvo: LVO_IEEEDPSincos,
+        registers: &["a0", "d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPSinh",
+        lvo: LVO_IEEEDPSinh,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPCosh",
+        lvo: LVO_IEEEDPCosh,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPTanh",
+        lvo: LVO_IEEEDPTanh,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPExp",
+        lvo: LVO_IEEEDPExp,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPLog",
+        lvo: LVO_IEEEDPLog,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPPow",
+        lvo: LVO_IEEEDPPow,
+        registers: &["d2-d3", "d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPSqrt",
+        lvo: LVO_IEEEDPSqrt,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPTieee",
+        lvo: LVO_IEEEDPTieee,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPFieee",
+        lvo: LVO_IEEEDPFieee,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPAsin",
+        lvo: LVO_IEEEDPAsin,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPAcos",
+        lvo: LVO_IEEEDPAcos,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "IEEEDPLog10",
+        lvo: LVO_IEEEDPLog10,
+        registers: &["d0-d1"],
+        min_version: 0,
+    },
+];
@@ -0,0 +1,628 @@
+//! Functions of "mathieeesingbas.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPFix(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPFix",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPFlt(MathIeeeSingBasBase: *mut Library, integer: LONG) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPFlt",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") integer,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPCmp(
+    MathIeeeSingBasBase: *mut Library,
+    leftParm: FLOAT,
+    rightParm: FLOAT,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPCmp",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") leftParm.to_bits(),
+            in("d1") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPTst(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPTst",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPAbs(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPAbs",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPNeg(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPNeg",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPAdd(
+    MathIeeeSingBasBase: *mut Library,
+    leftParm: FLOAT,
+    rightParm: FLOAT,
+) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPAdd",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") leftParm.to_bits(),
+            in("d1") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPSub(
+    MathIeeeSingBasBase: *mut Library,
+    leftParm: FLOAT,
+    rightParm: FLOAT,
+) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPSub",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") leftParm.to_bits(),
+            in("d1") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPMul(
+    MathIeeeSingBasBase: *mut Library,
+    leftParm: FLOAT,
+    rightParm: FLOAT,
+) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPMul",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") leftParm.to_bits(),
+            in("d1") rightParm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPDiv(
+    MathIeeeSingBasBase: *mut Library,
+    dividend: FLOAT,
+    divisor: FLOAT,
+) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPDiv",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") dividend.to_bits(),
+            in("d1") divisor.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPFloor(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPFloor",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPCeil(MathIeeeSingBasBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingBasBase,
+            36,
+            "IEEESPCeil",
+            "mathieeesingbas.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingBasBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// An open "mathieeesingbas.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathIeeeSingBasLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathIeeeSingBasLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathieeesingbas.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathieeesingbas.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPFix(&self, parm: FLOAT) -> LONG {
+        unsafe { IEEESPFix(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPFlt(&self, integer: LONG) -> FLOAT {
+        unsafe { IEEESPFlt(self.base.as_ptr(), integer) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPCmp(&self, leftParm: FLOAT, rightParm: FLOAT) -> LONG {
+        unsafe { IEEESPCmp(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPTst(&self, parm: FLOAT) -> LONG {
+        unsafe { IEEESPTst(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPAbs(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPAbs(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPNeg(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPNeg(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPAdd(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { IEEESPAdd(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPSub(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { IEEESPSub(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPMul(&self, leftParm: FLOAT, rightParm: FLOAT) -> FLOAT {
+        unsafe { IEEESPMul(self.base.as_ptr(), leftParm, rightParm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPDiv(&self, dividend: FLOAT, divisor: FLOAT) -> FLOAT {
+        unsafe { IEEESPDiv(self.base.as_ptr(), dividend, divisor) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPFloor(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPFloor(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingbas.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPCeil(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPCeil(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathIeeeSingBasLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `IEEESPFix()`
+pub const LVO_IEEESPFix: i16 = -30;
+/// Library vector offset of `IEEESPFlt()`
+pub const LVO_IEEESPFlt: i16 = -36;
+/// Library vector offset of `IEEESPCmp()`
+pub const LVO_IEEESPCmp: i16 = -42;
+/// Library vector offset of `IEEESPTst()`
+pub const LVO_IEEESPTst: i16 = -48;
+/// Library vector offset of `IEEESPAbs()`
+pub const LVO_IEEESPAbs: i16 = -54;
+/// Library vector offset of `IEEESPNeg()`
+pub const LVO_IEEESPNeg: i16 = -60;
+/// Library vector offset of `IEEESPAdd()`
+pub const LVO_IEEESPAdd: i16 = -66;
+/// Library vector offset of `IEEESPSub()`
+pub const LVO_IEEESPSub: i16 = -72;
+/// Library vector offset of `IEEESPMul()`
+pub const LVO_IEEESPMul: i16 = -78;
+/// Library vector offset of `IEEESPDiv()`
+pub const LVO_IEEESPDiv: i16 = -84;
+/// Library vector offset of `IEEESPFloor()`
+pub const LVO_IEEESPFloor: i16 = -90;
+/// Library vector offset of `IEEESPCeil()`
+pub const LVO_IEEESPCeil: i16 = -96;
+
+/// Functions of "mathieeesingbas.library"
+pub static MATHIEEESINGBAS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "IEEESPFix",
+        lvo: LVO_IEEESPFix,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPFlt",
+        lvo: LVO_IEEESPFlt,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPCmp",
+        lvo: LVO_IEEESPCmp,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPTst",
+        lvo: LVO_IEEESPTst,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPAbs",
+        lvo: LVO_IEEESPAbs,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPNeg",
+        lvo: LVO_IEEESPNeg,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPAdd",
+        lvo: LVO_IEEESPAdd,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPSub",
+        lvo: LVO_IEEESPSub,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPMul",
+        lvo: LVO_IEEESPMul,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPDiv",
+        lvo: LVO_IEEESPDiv,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPFloor",
+        lvo: LVO_IEEESPFloor,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPCeil",
+        lvo: LVO_IEEESPCeil,
+        registers: &["d0"],
+        min_version: 36,
+    },
+];
@@ -0,0 +1,829 @@
+//! Functions of "mathieeesingtrans.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPAtan(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPAtan",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPSin(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPSin",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPCos(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPCos",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPTan(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPTan",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPSincos(
+    MathIeeeSingTransBase: *mut Library,
+    cosptr: *mut FLOAT,
+    parm: FLOAT,
+) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPSincos",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("a0") cosptr,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPSinh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPSinh",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPCosh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPCosh",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPTanh(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPTanh",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPExp(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPExp",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPLog(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPLog",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPPow(MathIeeeSingTransBase: *mut Library, SPExp: FLOAT, arg: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPPow",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d1") SPExp.to_bits(),
+            in("d0") arg.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPSqrt(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPSqrt",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPTieee(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPTieee",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPFieee(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPFieee",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPAsin(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPAsin",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPAcos(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPAcos",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IEEESPLog10(MathIeeeSingTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            MathIeeeSingTransBase,
+            36,
+            "IEEESPLog10",
+            "mathieeesingtrans.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathIeeeSingTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// An open "mathieeesingtrans.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathIeeeSingTransLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathIeeeSingTransLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathieeesingtrans.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathieeesingtrans.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPAtan(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPAtan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPSin(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPSin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPCos(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPCos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPTan(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPTan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPSincos(&self, cosptr: *mut FLOAT, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPSincos(self.base.as_ptr(), cosptr, parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPSinh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPSinh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPCosh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPCosh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPTanh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPTanh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPExp(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPExp(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPLog(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPLog(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPPow(&self, SPExp: FLOAT, arg: FLOAT) -> FLOAT {
+        unsafe { IEEESPPow(self.base.as_ptr(), SPExp, arg) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPSqrt(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPSqrt(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPTieee(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPTieee(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPFieee(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPFieee(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPAsin(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPAsin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPAcos(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPAcos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathieeesingtrans.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IEEESPLog10(&self, parm: FLOAT) -> FLOAT {
+        unsafe { IEEESPLog10(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathIeeeSingTransLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `IEEESPAtan()`
+pub const LVO_IEEESPAtan: i16 = -30;
+/// Library vector offset of `IEEESPSin()`
+pub const LVO_IEEESPSin: i16 = -36;
+/// Library vector offset of `IEEESPCos()`
+pub const LVO_IEEESPCos: i16 = -42;
+/// Library vector offset of `IEEESPTan()`
+pub const LVO_IEEESPTan: i16 = -48;
+/// Library vector offset of `IEEESPSincos()`
+pub const LVO_IEEESPSincos: i16 = -54;
+/// Library vector offset of `IEEESPSinh()`
+pub const LVO_IEEESPSinh: i16 = -60;
+/// Library vector offset of `IEEESPCosh()`
+pub const LVO_IEEESPCosh: i16 = -66;
+/// Library vector offset of `IEEESPTanh()`
+pub const LVO_IEEESPTanh: i16 = -72;
+/// Library vector offset of `IEEESPExp()`
+pub const LVO_IEEESPExp: i16 = -78;
+/// Library vector offset of `IEEESPLog()`
+pub const LVO_IEEESPLog: i16 = -84;
+/// Library vector offset of `IEEESPPow()`
+pub const LVO_IEEESPPow: i16 = -90;
+/// Library vector offset of `IEEESPSqrt()`
+pub const LVO_IEEESPSqrt: i16 = -96;
+/// Library vector offset of `IEEESPTieee()`
+pub const LVO_IEEESPTieee: i16 = -102;
+/// Library vector offset of `IEEESPFieee()`
+pub const LVO_IEEESPFieee: i16 = -108;
+/// Library vector offset of `IEEESPAsin()`
+pub const LVO_IEEESPAsin: i16 = -114;
+/// Library vector offset of `IEEESPAcos()`
+pub const LVO_IEEESPAcos: i16 = -120;
+/// Library vector offset of `IEEESPLog10()`
+pub const LVO_IEEESPLog10: i16 = -126;
+
+/// Functions of "mathieeesingtrans.library"
+pub static MATHIEEESINGTRANS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "IEEESPAtan",
+        lvo: LVO_IEEESPAtan,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPSin",
+        lvo: LVO_IEEESPSin,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPCos",
+        lvo: LVO_IEEESPCos,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPTan",
+        lvo: LVO_IEEESPTan,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPSincos",
+        lvo: LVO_IEEESPSincos,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPSinh",
+        lvo: LVO_IEEESPSinh,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPCosh",
+        lvo: LVO_IEEESPCosh,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPTanh",
+        lvo: LVO_IEEESPTanh,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPExp",
+        lvo: LVO_IEEESPExp,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPLog",
+        lvo: LVO_IEEESPLog,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPPow",
+        lvo: LVO_IEEESPPow,
+        registers: &["d1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPSqrt",
+        lvo: LVO_IEEESPSqrt,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPTieee",
+        lvo: LVO_IEEESPTieee,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPFieee",
+        lvo: LVO_IEEESPFieee,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPAsin",
+        lvo: LVO_IEEESPAsin,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPAcos",
+        lvo: LVO_IEEESPAcos,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IEEESPLog10",
+        lvo: LVO_IEEESPLog10,
+        registers: &["d0"],
+        min_version: 36,
+    },
+];
@@ -0,0 +1,648 @@
+//! Functions of "mathtrans.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPAtan(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPSin(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPCos(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPTan(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPSincos(MathTransBase: *mut Library, cosResult: *mut FLOAT, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d1") cosResult,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPSinh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPCosh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPTanh(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPExp(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPLog(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPPow(MathTransBase: *mut Library, power: FLOAT, arg: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d1") power.to_bits(),
+            in("d0") arg.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPSqrt(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPTieee(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library"
+pub unsafe fn SPFieee(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+pub unsafe fn SPAsin(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(MathTransBase, 31, "SPAsin", "mathtrans.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+pub unsafe fn SPAcos(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(MathTransBase, 31, "SPAcos", "mathtrans.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+pub unsafe fn SPLog10(MathTransBase: *mut Library, parm: FLOAT) -> FLOAT {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(MathTransBase, 31, "SPLog10", "mathtrans.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MathTransBase,
+            in("d0") parm.to_bits(),
+            lateout("d0") asm_ret_value,
+        );
+    }
+    f32::from_bits(asm_ret_value)
+}
+
+/// An open "mathtrans.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct MathTransLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl MathTransLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"mathtrans.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "mathtrans.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPAtan(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPAtan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPSin(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPSin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPCos(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPCos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPTan(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPTan(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPSincos(&self, cosResult: *mut FLOAT, parm: FLOAT) -> FLOAT {
+        unsafe { SPSincos(self.base.as_ptr(), cosResult, parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPSinh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPSinh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPCosh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPCosh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPTanh(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPTanh(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPExp(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPExp(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPLog(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPLog(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPPow(&self, power: FLOAT, arg: FLOAT) -> FLOAT {
+        unsafe { SPPow(self.base.as_ptr(), power, arg) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPSqrt(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPSqrt(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPTieee(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPTieee(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library"
+    pub unsafe fn SPFieee(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPFieee(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    pub unsafe fn SPAsin(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPAsin(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    pub unsafe fn SPAcos(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPAcos(self.base.as_ptr(), parm) }
+    }
+
+    /// libname: "mathtrans.library" (V31) (Kickstart 1.1)
+    pub unsafe fn SPLog10(&self, parm: FLOAT) -> FLOAT {
+        unsafe { SPLog10(self.base.as_ptr(), parm) }
+    }
+}
+
+impl Drop for MathTransLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `SPAtan()`
+pub const LVO_SPAtan: i16 = -30;
+/// Library vector offset of `SPSin()`
+pub const LVO_SPSin: i16 = -36;
+/// Library vector offset of `SPCos()`
+pub const LVO_SPCos: i16 = -42;
+/// Library vector offset of `SPTan()`
+pub const LVO_SPTan: i16 = -48;
+/// Library vector offset of `SPSincos()`
+pub const LVO_SPSincos: i16 = -54;
+/// Library vector offset of `SPSinh()`
+pub const LVO_SPSinh: i16 = -60;
+/// Library vector offset of `SPCosh()`
+pub const LVO_SPCosh: i16 = -66;
+/// Library vector offset of `SPTanh()`
+pub const LVO_SPTanh: i16 = -72;
+/// Library vector offset of `SPExp()`
+pub const LVO_SPExp: i16 = -78;
+/// Library vector offset of `SPLog()`
+pub const LVO_SPLog: i16 = -84;
+/// Library vector offset of `SPPow()`
+pub const LVO_SPPow: i16 = -90;
+/// Library vector offset of `SPSqrt()`
+pub const LVO_SPSqrt: i16 = -96;
+/// Library vector offset of `SPTieee()`
+pub const LVO_SPTieee: i16 = -102;
+/// Library vector offset of `SPFieee()`
+pub const LVO_SPFieee: i16 = -108;
+/// Library vector offset of `SPAsin()`
+pub const LVO_SPAsin: i16 = -114;
+/// Library vector offset of `SPAcos()`
+pub const LVO_SPAcos: i16 = -120;
+/// Library vector offset of `SPLog10()`
+pub const LVO_SPLog10: i16 = -126;
+
+/// Functions of "mathtrans.library"
+pub static MATHTRANS_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "SPAtan",
+        lvo: LVO_SPAtan,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPSin",
+        lvo: LVO_SPSin,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPCos",
+        lvo: LVO_SPCos,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPTan",
+        lvo: LVO_SPTan,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPSincos",
+        lvo: LVO_SPSincos,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPSinh",
+        lvo: LVO_SPSinh,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPCosh",
+        lvo: LVO_SPCosh,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPTanh",
+        lvo: LVO_SPTanh,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPExp",
+        lvo: LVO_SPExp,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPLog",
+        lvo: LVO_SPLog,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPPow",
+        lvo: LVO_SPPow,
+        registers: &["d1", "d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPSqrt",
+        lvo: LVO_SPSqrt,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPTieee",
+        lvo: LVO_SPTieee,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPFieee",
+        lvo: LVO_SPFieee,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SPAsin",
+        lvo: LVO_SPAsin,
+        registers: &["d0"],
+        min_version: 31,
+    },
+    crate::FunctionInfo {
+        name: "SPAcos",
+        lvo: LVO_SPAcos,
+        registers: &["d0"],
+        min_version: 31,
+    },
+    crate::FunctionInfo {
+        name: "SPLog10",
+        lvo: LVO_SPLog10,
+        registers: &["d0"],
+        min_version: 31,
+    },
+];
@@ -0,0 +1,69 @@
+//! Functions of "misc.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "misc.resource"
+pub unsafe fn AllocMiscResource(
+    MiscBase: *mut ::core::ffi::c_void,
+    unitNum: ULONG,
+    name: CONST_STRPTR,
+) -> *mut UBYTE {
+    let asm_ret_value: *mut UBYTE;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) MiscBase,
+            in("d0") unitNum,
+            in("a1") name,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "misc.resource"
+pub unsafe fn FreeMiscResource(MiscBase: *mut ::core::ffi::c_void, unitNum: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) MiscBase,
+            in("d0") unitNum,
+        );
+    }
+}
+
+/// Library vector offset of `AllocMiscResource()`
+pub const LVO_AllocMiscResource: i16 = -6;
+/// Library vector offset of `FreeMiscResource()`
+pub const LVO_FreeMiscResource: i16 = -12;
+
+/// Functions of "misc.resource"
+pub static MISC_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AllocMiscResource",
+        lvo: LVO_AllocMiscResource,
+        registers: &["d0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreeMiscResource",
+        lvo: LVO_FreeMiscResource,
+        registers: &["d0"],
+        min_version: 0,
+    },
+];
@@ -0,0 +1,870 @@
+/* automatically generated by the amiga-sys tools */
+
+#[cfg(feature = "amigaguide")]
+pub mod amigaguide;
+#[cfg(feature = "amigaguide")]
+#[allow(unused_imports)]
+pub use amigaguide::*;
+
+#[cfg(feature = "arexx-class")]
+pub mod arexx_class;
+#[cfg(feature = "arexx-class")]
+#[allow(unused_imports)]
+pub use arexx_class::*;
+
+#[cfg(feature = "asl")]
+pub mod asl;
+#[cfg(feature = "asl")]
+#[allow(unused_imports)]
+pub use asl::*;
+
+#[cfg(feature = "battclock-resource")]
+pub mod battclock_resource;
+#[cfg(feature = "battclock-resource")]
+#[allow(unused_imports)]
+pub use battclock_resource::*;
+
+#[cfg(feature = "battmem-resource")]
+pub mod battmem_resource;
+#[cfg(feature = "battmem-resource")]
+#[allow(unused_imports)]
+pub use battmem_resource::*;
+
+#[cfg(feature = "bevel-image")]
+pub mod bevel_image;
+#[cfg(feature = "bevel-image")]
+#[allow(unused_imports)]
+pub use bevel_image::*;
+
+#[cfg(feature = "bitmap-image")]
+pub mod bitmap_image;
+#[cfg(feature = "bitmap-image")]
+#[allow(unused_imports)]
+pub use bitmap_image::*;
+
+#[cfg(feature = "bullet")]
+pub mod bullet;
+#[cfg(feature = "bullet")]
+#[allow(unused_imports)]
+pub use bullet::*;
+
+#[cfg(feature = "button-gadget")]
+pub mod button_gadget;
+#[cfg(feature = "button-gadget")]
+#[allow(unused_imports)]
+pub use button_gadget::*;
+
+#[cfg(feature = "card-resource")]
+pub mod card_resource;
+#[cfg(feature = "card-resource")]
+#[allow(unused_imports)]
+pub use card_resource::*;
+
+#[cfg(feature = "checkbox-gadget")]
+pub mod checkbox_gadget;
+#[cfg(feature = "checkbox-gadget")]
+#[allow(unused_imports)]
+pub use checkbox_gadget::*;
+
+#[cfg(feature = "chooser-gadget")]
+pub mod chooser_gadget;
+#[cfg(feature = "chooser-gadget")]
+#[allow(unused_imports)]
+pub use chooser_gadget::*;
+
+#[cfg(feature = "cia-resource")]
+pub mod cia_resource;
+#[cfg(feature = "cia-resource")]
+pub use cia_resource::*;
+
+#[cfg(feature = "clicktab-gadget")]
+pub mod clicktab_gadget;
+#[cfg(feature = "clicktab-gadget")]
+#[allow(unused_imports)]
+pub use clicktab_gadget::*;
+
+#[cfg(feature = "colorwheel-gadget")]
+pub mod colorwheel_gadget;
+#[cfg(feature = "colorwheel-gadget")]
+#[allow(unused_imports)]
+pub use colorwheel_gadget::*;
+
+#[cfg(feature = "commodities")]
+pub mod commodities;
+#[cfg(feature = "commodities")]
+#[allow(unused_imports)]
+pub use commodities::*;
+
+#[cfg(feature = "console-device")]
+pub mod console_device;
+#[cfg(feature = "console-device")]
+pub use console_device::*;
+
+#[cfg(feature = "datatypes")]
+pub mod datatypes;
+#[cfg(feature = "datatypes")]
+#[allow(unused_imports)]
+pub use datatypes::*;
+
+#[cfg(feature = "datebrowser-gadget")]
+pub mod datebrowser_gadget;
+#[cfg(feature = "datebrowser-gadget")]
+#[allow(unused_imports)]
+pub use datebrowser_gadget::*;
+
+#[cfg(feature = "disk-resource")]
+pub mod disk_resource;
+#[cfg(feature = "disk-resource")]
+pub use disk_resource::*;
+
+#[cfg(feature = "diskfont")]
+pub mod diskfont;
+#[cfg(feature = "diskfont")]
+pub use diskfont::*;
+
+#[cfg(feature = "dos")]
+pub mod dos;
+#[cfg(feature = "dos")]
+pub use dos::*;
+
+#[cfg(feature = "drawlist-image")]
+pub mod drawlist_image;
+#[cfg(feature = "drawlist-image")]
+#[allow(unused_imports)]
+pub use drawlist_image::*;
+
+#[cfg(feature = "exec")]
+pub mod exec;
+#[cfg(feature = "exec")]
+pub use exec::*;
+
+#[cfg(feature = "expansion")]
+pub mod expansion;
+#[cfg(feature = "expansion")]
+pub use expansion::*;
+
+#[cfg(feature = "fuelgauge-gadget")]
+pub mod fuelgauge_gadget;
+#[cfg(feature = "fuelgauge-gadget")]
+#[allow(unused_imports)]
+pub use fuelgauge_gadget::*;
+
+#[cfg(feature = "gadtools")]
+pub mod gadtools;
+#[cfg(feature = "gadtools")]
+#[allow(unused_imports)]
+pub use gadtools::*;
+
+#[cfg(feature = "getcolor-gadget")]
+pub mod getcolor_gadget;
+#[cfg(feature = "getcolor-gadget")]
+#[allow(unused_imports)]
+pub use getcolor_gadget::*;
+
+#[cfg(feature = "getfile-gadget")]
+pub mod getfile_gadget;
+#[cfg(feature = "getfile-gadget")]
+#[allow(unused_imports)]
+pub use getfile_gadget::*;
+
+#[cfg(feature = "getfont-gadget")]
+pub mod getfont_gadget;
+#[cfg(feature = "getfont-gadget")]
+#[allow(unused_imports)]
+pub use getfont_gadget::*;
+
+#[cfg(feature = "getscreenmode-gadget")]
+pub mod getscreenmode_gadget;
+#[cfg(feature = "getscreenmode-gadget")]
+#[allow(unused_imports)]
+pub use getscreenmode_gadget::*;
+
+#[cfg(feature = "glyph-image")]
+pub mod glyph_image;
+#[cfg(feature = "glyph-image")]
+#[allow(unused_imports)]
+pub use glyph_image::*;
+
+#[cfg(feature = "graphics")]
+pub mod graphics;
+#[cfg(feature = "graphics")]
+pub use graphics::*;
+
+#[cfg(feature = "icon")]
+pub mod icon;
+#[cfg(feature = "icon")]
+pub use icon::*;
+
+#[cfg(feature = "iffparse")]
+pub mod iffparse;
+#[cfg(feature = "iffparse")]
+#[allow(unused_imports)]
+pub use iffparse::*;
+
+#[cfg(feature = "input-device")]
+pub mod input_device;
+#[cfg(feature = "input-device")]
+#[allow(unused_imports)]
+pub use input_device::*;
+
+#[cfg(feature = "integer-gadget")]
+pub mod integer_gadget;
+#[cfg(feature = "integer-gadget")]
+#[allow(unused_imports)]
+pub use integer_gadget::*;
+
+#[cfg(feature = "intuition")]
+pub mod intuition;
+#[cfg(feature = "intuition")]
+pub use intuition::*;
+
+#[cfg(feature = "keymap")]
+pub mod keymap;
+#[cfg(feature = "keymap")]
+#[allow(unused_imports)]
+pub use keymap::*;
+
+#[cfg(feature = "label-image")]
+pub mod label_image;
+#[cfg(feature = "label-image")]
+#[allow(unused_imports)]
+pub use label_image::*;
+
+#[cfg(feature = "layers")]
+pub mod layers;
+#[cfg(feature = "layers")]
+pub use layers::*;
+
+#[cfg(feature = "layout-gadget")]
+pub mod layout_gadget;
+#[cfg(feature = "layout-gadget")]
+#[allow(unused_imports)]
+pub use layout_gadget::*;
+
+#[cfg(feature = "listbrowser-gadget")]
+pub mod listbrowser_gadget;
+#[cfg(feature = "listbrowser-gadget")]
+#[allow(unused_imports)]
+pub use listbrowser_gadget::*;
+
+#[cfg(feature = "listview-gadget")]
+pub mod listview_gadget;
+#[cfg(feature = "listview-gadget")]
+#[allow(unused_imports)]
+pub use listview_gadget::*;
+
+#[cfg(feature = "locale")]
+pub mod locale;
+#[cfg(feature = "locale")]
+#[allow(unused_imports)]
+pub use locale::*;
+
+#[cfg(feature = "lowlevel")]
+pub mod lowlevel;
+#[cfg(feature = "lowlevel")]
+#[allow(unused_imports)]
+pub use lowlevel::*;
+
+#[cfg(feature = "mathffp")]
+pub mod mathffp;
+#[cfg(feature = "mathffp")]
+pub use mathffp::*;
+
+#[cfg(feature = "mathieeedoubbas")]
+pub mod mathieeedoubbas;
+#[cfg(feature = "mathieeedoubbas")]
+pub use mathieeedoubbas::*;
+
+#[cfg(feature = "mathieeedoubtrans")]
+pub mod mathieeedoubtrans;
+#[cfg(feature = "mathieeedoubtrans")]
+pub use mathieeedoubtrans::*;
+
+#[cfg(feature = "mathieeesingbas")]
+pub mod mathieeesingbas;
+#[cfg(feature = "mathieeesingbas")]
+#[allow(unused_imports)]
+pub use mathieeesingbas::*;
+
+#[cfg(feature = "mathieeesingtrans")]
+pub mod mathieeesingtrans;
+#[cfg(feature = "mathieeesingtrans")]
+#[allow(unused_imports)]
+pub use mathieeesingtrans::*;
+
+#[cfg(feature = "mathtrans")]
+pub mod mathtrans;
+#[cfg(feature = "mathtrans")]
+pub use mathtrans::*;
+
+#[cfg(feature = "misc-resource")]
+pub mod misc_resource;
+#[cfg(feature = "misc-resource")]
+pub use misc_resource::*;
+
+#[cfg(feature = "nonvolatile")]
+pub mod nonvolatile;
+#[cfg(feature = "nonvolatile")]
+#[allow(unused_imports)]
+pub use nonvolatile::*;
+
+#[cfg(feature = "palette-gadget")]
+pub mod palette_gadget;
+#[cfg(feature = "palette-gadget")]
+#[allow(unused_imports)]
+pub use palette_gadget::*;
+
+#[cfg(feature = "penmap-image")]
+pub mod penmap_image;
+#[cfg(feature = "penmap-image")]
+#[allow(unused_imports)]
+pub use penmap_image::*;
+
+#[cfg(feature = "potgo-resource")]
+pub mod potgo_resource;
+#[cfg(feature = "potgo-resource")]
+pub use potgo_resource::*;
+
+#[cfg(feature = "radiobutton-gadget")]
+pub mod radiobutton_gadget;
+#[cfg(feature = "radiobutton-gadget")]
+#[allow(unused_imports)]
+pub use radiobutton_gadget::*;
+
+#[cfg(feature = "ramdrive-device")]
+pub mod ramdrive_device;
+#[cfg(feature = "ramdrive-device")]
+#[allow(unused_imports)]
+pub use ramdrive_device::*;
+
+#[cfg(feature = "realtime")]
+pub mod realtime;
+#[cfg(feature = "realtime")]
+#[allow(unused_imports)]
+pub use realtime::*;
+
+#[cfg(feature = "requester-class")]
+pub mod requester_class;
+#[cfg(feature = "requester-class")]
+#[allow(unused_imports)]
+pub use requester_class::*;
+
+#[cfg(feature = "rexxsyslib")]
+pub mod rexxsyslib;
+#[cfg(feature = "rexxsyslib")]
+#[allow(unused_imports)]
+pub use rexxsyslib::*;
+
+#[cfg(feature = "scroller-gadget")]
+pub mod scroller_gadget;
+#[cfg(feature = "scroller-gadget")]
+#[allow(unused_imports)]
+pub use scroller_gadget::*;
+
+#[cfg(feature = "sketchboard-gadget")]
+pub mod sketchboard_gadget;
+#[cfg(feature = "sketchboard-gadget")]
+#[allow(unused_imports)]
+pub use sketchboard_gadget::*;
+
+#[cfg(feature = "slider-gadget")]
+pub mod slider_gadget;
+#[cfg(feature = "slider-gadget")]
+#[allow(unused_imports)]
+pub use slider_gadget::*;
+
+#[cfg(feature = "space-gadget")]
+pub mod space_gadget;
+#[cfg(feature = "space-gadget")]
+#[allow(unused_imports)]
+pub use space_gadget::*;
+
+#[cfg(feature = "speedbar-gadget")]
+pub mod speedbar_gadget;
+#[cfg(feature = "speedbar-gadget")]
+#[allow(unused_imports)]
+pub use speedbar_gadget::*;
+
+#[cfg(feature = "string-gadget")]
+pub mod string_gadget;
+#[cfg(feature = "string-gadget")]
+#[allow(unused_imports)]
+pub use string_gadget::*;
+
+#[cfg(feature = "texteditor-gadget")]
+pub mod texteditor_gadget;
+#[cfg(feature = "texteditor-gadget")]
+#[allow(unused_imports)]
+pub use texteditor_gadget::*;
+
+#[cfg(feature = "timer-device")]
+pub mod timer_device;
+#[cfg(feature = "timer-device")]
+pub use timer_device::*;
+
+#[cfg(feature = "trackfile-device")]
+pub mod trackfile_device;
+#[cfg(feature = "trackfile-device")]
+#[allow(unused_imports)]
+pub use trackfile_device::*;
+
+#[cfg(feature = "translator")]
+pub mod translator;
+#[cfg(feature = "translator")]
+pub use translator::*;
+
+#[cfg(feature = "utility")]
+pub mod utility;
+#[cfg(feature = "utility")]
+#[allow(unused_imports)]
+pub use utility::*;
+
+#[cfg(feature = "virtual-gadget")]
+pub mod virtual_gadget;
+#[cfg(feature = "virtual-gadget")]
+#[allow(unused_imports)]
+pub use virtual_gadget::*;
+
+#[cfg(feature = "window-class")]
+pub mod window_class;
+#[cfg(feature = "window-class")]
+#[allow(unused_imports)]
+pub use window_class::*;
+
+#[cfg(feature = "workbench")]
+pub mod workbench;
+#[cfg(feature = "workbench")]
+#[allow(unused_imports)]
+pub use workbench::*;
+
+#[cfg(feature = "global-bases")]
+pub mod global;
+
+/// Returns information about a library function
+///
+/// Only the functions of the libraries enabled with cargo features are found.
+pub fn find_function(name: &str) -> Option<&'static crate::FunctionInfo> {
+    #[cfg(feature = "amigaguide")]
+    if let Some(info) = AMIGAGUIDE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "arexx-class")]
+    if let Some(info) = AREXX_CLASS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "asl")]
+    if let Some(info) = ASL_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "battclock-resource")]
+    if let Some(info) = BATTCLOCK_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "battmem-resource")]
+    if let Some(info) = BATTMEM_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "bevel-image")]
+    if let Some(info) = BEVEL_IMAGE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "bitmap-image")]
+    if let Some(info) = BITMAP_IMAGE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "bullet")]
+    if let Some(info) = BULLET_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "button-gadget")]
+    if let Some(info) = BUTTON_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "card-resource")]
+    if let Some(info) = CARD_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "checkbox-gadget")]
+    if let Some(info) = CHECKBOX_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "chooser-gadget")]
+    if let Some(info) = CHOOSER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "cia-resource")]
+    if let Some(info) = CIA_RESOURCE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "clicktab-gadget")]
+    if let Some(info) = CLICKTAB_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "colorwheel-gadget")]
+    if let Some(info) = COLORWHEEL_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "commodities")]
+    if let Some(info) = COMMODITIES_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "console-device")]
+    if let Some(info) = CONSOLE_DEVICE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "datatypes")]
+    if let Some(info) = DATATYPES_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "datebrowser-gadget")]
+    if let Some(info) = DATEBROWSER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "disk-resource")]
+    if let Some(info) = DISK_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "diskfont")]
+    if let Some(info) = DISKFONT_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "dos")]
+    if let Some(info) = DOS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "drawlist-image")]
+    if let Some(info) = DRAWLIST_IMAGE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "exec")]
+    if let Some(info) = EXEC_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "expansion")]
+    if let Some(info) = EXPANSION_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "fuelgauge-gadget")]
+    if let Some(info) = FUELGAUGE_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "gadtools")]
+    if let Some(info) = GADTOOLS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "getcolor-gadget")]
+    if let Some(info) = GETCOLOR_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "getfile-gadget")]
+    if let Some(info) = GETFILE_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "getfont-gadget")]
+    if let Some(info) = GETFONT_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "getscreenmode-gadget")]
+    if let Some(info) = GETSCREENMODE_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "glyph-image")]
+    if let Some(info) = GLYPH_IMAGE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "graphics")]
+    if let Some(info) = GRAPHICS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "icon")]
+    if let Some(info) = ICON_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "iffparse")]
+    if let Some(info) = IFFPARSE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "input-device")]
+    if let Some(info) = INPUT_DEVICE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "integer-gadget")]
+    if let Some(info) = INTEGER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "intuition")]
+    if let Some(info) = INTUITION_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "keymap")]
+    if let Some(info) = KEYMAP_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "label-image")]
+    if let Some(info) = LABEL_IMAGE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "layers")]
+    if let Some(info) = LAYERS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "layout-gadget")]
+    if let Some(info) = LAYOUT_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "listbrowser-gadget")]
+    if let Some(info) = LISTBROWSER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "listview-gadget")]
+    if let Some(info) = LISTVIEW_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "locale")]
+    if let Some(info) = LOCALE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "lowlevel")]
+    if let Some(info) = LOWLEVEL_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "mathffp")]
+    if let Some(info) = MATHFFP_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "mathieeedoubbas")]
+    if let Some(info) = MATHIEEEDOUBBAS_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "mathieeedoubtrans")]
+    if let Some(info) = MATHIEEEDOUBTRANS_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "mathieeesingbas")]
+    if let Some(info) = MATHIEEESINGBAS_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "mathieeesingtrans")]
+    if let Some(info) = MATHIEEESINGTRANS_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "mathtrans")]
+    if let Some(info) = MATHTRANS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "misc-resource")]
+    if let Some(info) = MISC_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "nonvolatile")]
+    if let Some(info) = NONVOLATILE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "palette-gadget")]
+    if let Some(info) = PALETTE_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "penmap-image")]
+    if let Some(info) = PENMAP_IMAGE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "potgo-resource")]
+    if let Some(info) = POTGO_RESOURCE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "radiobutton-gadget")]
+    if let Some(info) = RADIOBUTTON_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "ramdrive-device")]
+    if let Some(info) = RAMDRIVE_DEVICE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "realtime")]
+    if let Some(info) = REALTIME_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "requester-class")]
+    if let Some(info) = REQUESTER_CLASS_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "rexxsyslib")]
+    if let Some(info) = REXXSYSLIB_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "scroller-gadget")]
+    if let Some(info) = SCROLLER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "sketchboard-gadget")]
+    if let Some(info) = SKETCHBOARD_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "slider-gadget")]
+    if let Some(info) = SLIDER_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "space-gadget")]
+    if let Some(info) = SPACE_GADGET_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "speedbar-gadget")]
+    if let Some(info) = SPEEDBAR_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "string-gadget")]
+    if let Some(info) = STRING_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "texteditor-gadget")]
+    if let Some(info) = TEXTEDITOR_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "timer-device")]
+    if let Some(info) = TIMER_DEVICE_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "trackfile-device")]
+    if let Some(info) = TRACKFILE_DEVICE_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "translator")]
+    if let Some(info) = TRANSLATOR_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "utility")]
+    if let Some(info) = UTILITY_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "virtual-gadget")]
+    if let Some(info) = VIRTUAL_GADGET_FUNCTIONS
+        .iter()
+        .find(|info| info.name == name)
+    {
+        return Some(info);
+    }
+    #[cfg(feature = "window-class")]
+    if let Some(info) = WINDOW_CLASS_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    #[cfg(feature = "workbench")]
+    if let Some(info) = WORKBENCH_FUNCTIONS.iter().find(|info| info.name == name) {
+        return Some(info);
+    }
+    let _ = name;
+    None
+}
@@ -0,0 +1,416 @@
+//! Functions of "nonvolatile.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetCopyNV(
+    NVBase: *mut Library,
+    appName: CONST_STRPTR,
+    itemName: CONST_STRPTR,
+    killRequesters: LONG,
+) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "GetCopyNV", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") appName,
+            in("a1") itemName,
+            in("d1") killRequesters,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeNVData(NVBase: *mut Library, data: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "FreeNVData", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") data,
+        );
+    }
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn StoreNV(
+    NVBase: *mut Library,
+    appName: CONST_STRPTR,
+    itemName: CONST_STRPTR,
+    data: CONST_APTR,
+    length: ULONG,
+    killRequesters: LONG,
+) -> UWORD {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "StoreNV", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") appName,
+            in("a1") itemName,
+            in("a2") data,
+            in("d0") length,
+            in("d1") killRequesters,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn DeleteNV(
+    NVBase: *mut Library,
+    appName: CONST_STRPTR,
+    itemName: CONST_STRPTR,
+    killRequesters: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "DeleteNV", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") appName,
+            in("a1") itemName,
+            in("d1") killRequesters,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetNVInfo(NVBase: *mut Library, killRequesters: LONG) -> *mut NVInfo {
+    let asm_ret_value: *mut NVInfo;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "GetNVInfo", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("d1") killRequesters,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetNVList(
+    NVBase: *mut Library,
+    appName: CONST_STRPTR,
+    killRequesters: LONG,
+) -> *mut MinList {
+    let asm_ret_value: *mut MinList;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "GetNVList", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") appName,
+            in("d1") killRequesters,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetNVProtection(
+    NVBase: *mut Library,
+    appName: CONST_STRPTR,
+    itemName: CONST_STRPTR,
+    mask: LONG,
+    killRequesters: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(NVBase, 40, "SetNVProtection", "nonvolatile.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) NVBase,
+            in("a0") appName,
+            in("a1") itemName,
+            in("d2") mask,
+            in("d1") killRequesters,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// An open "nonvolatile.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct NonvolatileLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl NonvolatileLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"nonvolatile.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "nonvolatile.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetCopyNV(
+        &self,
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        killRequesters: LONG,
+    ) -> APTR {
+        unsafe { GetCopyNV(self.base.as_ptr(), appName, itemName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn FreeNVData(&self, data: APTR) {
+        unsafe { FreeNVData(self.base.as_ptr(), data) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn StoreNV(
+        &self,
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        data: CONST_APTR,
+        length: ULONG,
+        killRequesters: LONG,
+    ) -> UWORD {
+        unsafe {
+            StoreNV(
+                self.base.as_ptr(),
+                appName,
+                itemName,
+                data,
+                length,
+                killRequesters,
+            )
+        }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn DeleteNV(
+        &self,
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        killRequesters: LONG,
+    ) -> BOOL {
+        unsafe { DeleteNV(self.base.as_ptr(), appName, itemName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetNVInfo(&self, killRequesters: LONG) -> *mut NVInfo {
+        unsafe { GetNVInfo(self.base.as_ptr(), killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn GetNVList(&self, appName: CONST_STRPTR, killRequesters: LONG) -> *mut MinList {
+        unsafe { GetNVList(self.base.as_ptr(), appName, killRequesters) }
+    }
+
+    /// libname: "nonvolatile.library" (V40) (Kickstart 3.1)
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub unsafe fn SetNVProtection(
+        &self,
+        appName: CONST_STRPTR,
+        itemName: CONST_STRPTR,
+        mask: LONG,
+        killRequesters: LONG,
+    ) -> BOOL {
+        unsafe { SetNVProtection(self.base.as_ptr(), appName, itemName, mask, killRequesters) }
+    }
+}
+
+impl Drop for NonvolatileLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `GetCopyNV()`
+pub const LVO_GetCopyNV: i16 = -30;
+/// Library vector offset of `FreeNVData()`
+pub const LVO_FreeNVData: i16 = -36;
+/// Library vector offset of `StoreNV()`
+pub const LVO_StoreNV: i16 = -42;
+/// Library vector offset of `DeleteNV()`
+pub const LVO_DeleteNV: i16 = -48;
+/// Library vector offset of `GetNVInfo()`
+pub const LVO_GetNVInfo: i16 = -54;
+/// Library vector offset of `GetNVList()`
+pub const LVO_GetNVList: i16 = -60;
+/// Library vector offset of `SetNVProtection()`
+pub const LVO_SetNVProtection: i16 = -66;
+
+/// Functions of "nonvolatile.library"
+pub static NONVOLATILE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "GetCopyNV",
+        lvo: LVO_GetCopyNV,
+        registers: &["a0", "a1", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeNVData",
+        lvo: LVO_FreeNVData,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "StoreNV",
+        lvo: LVO_StoreNV,
+        registers: &["a0", "a1", "a2", "d0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "DeleteNV",
+        lvo: LVO_DeleteNV,
+        registers: &["a0", "a1", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetNVInfo",
+        lvo: LVO_GetNVInfo,
+        registers: &["d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetNVList",
+        lvo: LVO_GetNVList,
+        registers: &["a0", "d1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetNVProtection",
+        lvo: LVO_SetNVProtection,
+        registers: &["a0", "a1", "d2", "d1"],
+        min_version: 40,
+    },
+];
@@ -0,0 +1,90 @@
+//! Functions of "potgo.resource"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "potgo.resource"
+pub unsafe fn AllocPotBits(PotgoBase: *mut ::core::ffi::c_void, bits: ULONG) -> UWORD {
+    let asm_ret_value: u32;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-6, %a6)
+            ".short -6",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) PotgoBase,
+            in("d0") bits,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UWORD
+}
+
+/// libname: "potgo.resource"
+pub unsafe fn FreePotBits(PotgoBase: *mut ::core::ffi::c_void, bits: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-12, %a6)
+            ".short -12",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) PotgoBase,
+            in("d0") bits,
+        );
+    }
+}
+
+/// libname: "potgo.resource"
+pub unsafe fn WritePotgo(PotgoBase: *mut ::core::ffi::c_void, word: ULONG, mask: ULONG) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-18, %a6)
+            ".short -18",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) PotgoBase,
+            in("d0") word,
+            in("d1") mask,
+        );
+    }
+}
+
+/// Library vector offset of `AllocPotBits()`
+pub const LVO_AllocPotBits: i16 = -6;
+/// Library vector offset of `FreePotBits()`
+pub const LVO_FreePotBits: i16 = -12;
+/// Library vector offset of `WritePotgo()`
+pub const LVO_WritePotgo: i16 = -18;
+
+/// Functions of "potgo.resource"
+pub static POTGO_RESOURCE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AllocPotBits",
+        lvo: LVO_AllocPotBits,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "FreePotBits",
+        lvo: LVO_FreePotBits,
+        registers: &["d0"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "WritePotgo",
+        lvo: LVO_WritePotgo,
+        registers: &["d0", "d1"],
+        min_version: 0,
+    },
+];
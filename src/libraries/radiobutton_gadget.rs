@@ -0,0 +1,208 @@
+//! Functions of "radiobutton.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RADIOBUTTON_GetClass(RadioButtonBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RadioButtonBase as *mut Library,
+            40,
+            "RADIOBUTTON_GetClass",
+            "radiobutton.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RadioButtonBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AllocRadioButtonNodeA(
+    RadioButtonBase: *mut ::core::ffi::c_void,
+    columns: ULONG,
+    tags: *mut TagItem,
+) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RadioButtonBase as *mut Library,
+            40,
+            "AllocRadioButtonNodeA",
+            "radiobutton.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RadioButtonBase,
+            in("d0") columns,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeRadioButtonNode(RadioButtonBase: *mut ::core::ffi::c_void, node: *mut Node) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RadioButtonBase as *mut Library,
+            40,
+            "FreeRadioButtonNode",
+            "radiobutton.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RadioButtonBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetRadioButtonNodeAttrsA(
+    RadioButtonBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RadioButtonBase as *mut Library,
+            40,
+            "SetRadioButtonNodeAttrsA",
+            "radiobutton.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RadioButtonBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "radiobutton.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetRadioButtonNodeAttrsA(
+    RadioButtonBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RadioButtonBase as *mut Library,
+            40,
+            "GetRadioButtonNodeAttrsA",
+            "radiobutton.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RadioButtonBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// Library vector offset of `RADIOBUTTON_GetClass()`
+pub const LVO_RADIOBUTTON_GetClass: i16 = -30;
+/// Library vector offset of `AllocRadioButtonNodeA()`
+pub const LVO_AllocRadioButtonNodeA: i16 = -36;
+/// Library vector offset of `FreeRadioButtonNode()`
+pub const LVO_FreeRadioButtonNode: i16 = -42;
+/// Library vector offset of `SetRadioButtonNodeAttrsA()`
+pub const LVO_SetRadioButtonNodeAttrsA: i16 = -48;
+/// Library vector offset of `GetRadioButtonNodeAttrsA()`
+pub const LVO_GetRadioButtonNodeAttrsA: i16 = -54;
+
+/// Functions of "radiobutton.gadget"
+pub static RADIOBUTTON_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "RADIOBUTTON_GetClass",
+        lvo: LVO_RADIOBUTTON_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocRadioButtonNodeA",
+        lvo: LVO_AllocRadioButtonNodeA,
+        registers: &["d0", "a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeRadioButtonNode",
+        lvo: LVO_FreeRadioButtonNode,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetRadioButtonNodeAttrsA",
+        lvo: LVO_SetRadioButtonNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetRadioButtonNodeAttrsA",
+        lvo: LVO_GetRadioButtonNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+];
@@ -0,0 +1,88 @@
+//! Functions of "ramdrive.device"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "ramdrive.device" (V34) (Kickstart 1.3)
+#[cfg(not(feature = "kickstart-v33"))]
+pub unsafe fn KillRAD0(RamdriveDevice: *mut ::core::ffi::c_void) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RamdriveDevice as *mut Library,
+            34,
+            "KillRAD0",
+            "ramdrive.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RamdriveDevice,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "ramdrive.device" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn KillRAD(RamdriveDevice: *mut ::core::ffi::c_void, unit: ULONG) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RamdriveDevice as *mut Library,
+            36,
+            "KillRAD",
+            "ramdrive.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RamdriveDevice,
+            in("d0") unit,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `KillRAD0()`
+pub const LVO_KillRAD0: i16 = -42;
+/// Library vector offset of `KillRAD()`
+pub const LVO_KillRAD: i16 = -48;
+
+/// Functions of "ramdrive.device"
+pub static RAMDRIVE_DEVICE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "KillRAD0",
+        lvo: LVO_KillRAD0,
+        registers: &[],
+        min_version: 34,
+    },
+    crate::FunctionInfo {
+        name: "KillRAD",
+        lvo: LVO_KillRAD,
+        registers: &["d0"],
+        min_version: 36,
+    },
+];
@@ -0,0 +1,486 @@
+//! Functions of "realtime.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn LockRealTime(RealTimeBase: *mut Library, lockType: ULONG) -> APTR {
+    let asm_ret_value: APTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "LockRealTime", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("d0") lockType,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn UnlockRealTime(RealTimeBase: *mut Library, lock: APTR) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "UnlockRealTime", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") lock,
+        );
+    }
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn CreatePlayerA(RealTimeBase: *mut Library, tagList: *const TagItem) -> *mut Player {
+    let asm_ret_value: *mut Player;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "CreatePlayerA", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn DeletePlayer(RealTimeBase: *mut Library, player: *mut Player) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "DeletePlayer", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") player,
+        );
+    }
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn SetPlayerAttrsA(
+    RealTimeBase: *mut Library,
+    player: *mut Player,
+    tagList: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "SetPlayerAttrsA", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") player,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn SetConductorState(
+    RealTimeBase: *mut Library,
+    player: *mut Player,
+    state: ULONG,
+    time: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "SetConductorState", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") player,
+            in("d0") state,
+            in("d1") time,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn ExternalSync(
+    RealTimeBase: *mut Library,
+    player: *mut Player,
+    minTime: LONG,
+    maxTime: LONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "ExternalSync", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") player,
+            in("d0") minTime,
+            in("d1") maxTime,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn NextConductor(
+    RealTimeBase: *mut Library,
+    previousConductor: *const Conductor,
+) -> *mut Conductor {
+    let asm_ret_value: *mut Conductor;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "NextConductor", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") previousConductor,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn FindConductor(RealTimeBase: *mut Library, name: CONST_STRPTR) -> *mut Conductor {
+    let asm_ret_value: *mut Conductor;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "FindConductor", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") name,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "realtime.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn GetPlayerAttrsA(
+    RealTimeBase: *mut Library,
+    player: *mut Player,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RealTimeBase, 37, "GetPlayerAttrsA", "realtime.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RealTimeBase,
+            in("a0") player,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "realtime.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct RealTimeLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl RealTimeLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"realtime.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "realtime.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn LockRealTime(&self, lockType: ULONG) -> APTR {
+        unsafe { LockRealTime(self.base.as_ptr(), lockType) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn UnlockRealTime(&self, lock: APTR) {
+        unsafe { UnlockRealTime(self.base.as_ptr(), lock) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn CreatePlayerA(&self, tagList: *const TagItem) -> *mut Player {
+        unsafe { CreatePlayerA(self.base.as_ptr(), tagList) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn DeletePlayer(&self, player: *mut Player) {
+        unsafe { DeletePlayer(self.base.as_ptr(), player) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn SetPlayerAttrsA(&self, player: *mut Player, tagList: *const TagItem) -> BOOL {
+        unsafe { SetPlayerAttrsA(self.base.as_ptr(), player, tagList) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn SetConductorState(&self, player: *mut Player, state: ULONG, time: LONG) -> LONG {
+        unsafe { SetConductorState(self.base.as_ptr(), player, state, time) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn ExternalSync(&self, player: *mut Player, minTime: LONG, maxTime: LONG) -> BOOL {
+        unsafe { ExternalSync(self.base.as_ptr(), player, minTime, maxTime) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn NextConductor(&self, previousConductor: *const Conductor) -> *mut Conductor {
+        unsafe { NextConductor(self.base.as_ptr(), previousConductor) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn FindConductor(&self, name: CONST_STRPTR) -> *mut Conductor {
+        unsafe { FindConductor(self.base.as_ptr(), name) }
+    }
+
+    /// libname: "realtime.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn GetPlayerAttrsA(&self, player: *mut Player, tagList: *const TagItem) -> ULONG {
+        unsafe { GetPlayerAttrsA(self.base.as_ptr(), player, tagList) }
+    }
+}
+
+impl Drop for RealTimeLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `LockRealTime()`
+pub const LVO_LockRealTime: i16 = -30;
+/// Library vector offset of `UnlockRealTime()`
+pub const LVO_UnlockRealTime: i16 = -36;
+/// Library vector offset of `CreatePlayerA()`
+pub const LVO_CreatePlayerA: i16 = -42;
+/// Library vector offset of `DeletePlayer()`
+pub const LVO_DeletePlayer: i16 = -48;
+/// Library vector offset of `SetPlayerAttrsA()`
+pub const LVO_SetPlayerAttrsA: i16 = -54;
+/// Library vector offset of `SetConductorState()`
+pub const LVO_SetConductorState: i16 = -60;
+/// Library vector offset of `ExternalSync()`
+pub const LVO_ExternalSync: i16 = -66;
+/// Library vector offset of `NextConductor()`
+pub const LVO_NextConductor: i16 = -72;
+/// Library vector offset of `FindConductor()`
+pub const LVO_FindConductor: i16 = -78;
+/// Library vector offset of `GetPlayerAttrsA()`
+pub const LVO_GetPlayerAttrsA: i16 = -84;
+
+/// Functions of "realtime.library"
+pub static REALTIME_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "LockRealTime",
+        lvo: LVO_LockRealTime,
+        registers: &["d0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "UnlockRealTime",
+        lvo: LVO_UnlockRealTime,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "CreatePlayerA",
+        lvo: LVO_CreatePlayerA,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "DeletePlayer",
+        lvo: LVO_DeletePlayer,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "SetPlayerAttrsA",
+        lvo: LVO_SetPlayerAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "SetConductorState",
+        lvo: LVO_SetConductorState,
+        registers: &["a0", "d0", "d1"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "ExternalSync",
+        lvo: LVO_ExternalSync,
+        registers: &["a0", "d0", "d1"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "NextConductor",
+        lvo: LVO_NextConductor,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "FindConductor",
+        lvo: LVO_FindConductor,
+        registers: &["a0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "GetPlayerAttrsA",
+        lvo: LVO_GetPlayerAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 37,
+    },
+];
@@ -0,0 +1,816 @@
+//! Functions of "rexxsyslib.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateArgstring(
+    RexxSysBase: *mut Library,
+    string: CONST_STRPTR,
+    length: ULONG,
+) -> *mut UBYTE {
+    let asm_ret_value: *mut UBYTE;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "CreateArgstring", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") string,
+            in("d0") length,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteArgstring(RexxSysBase: *mut Library, argstring: *mut UBYTE) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "DeleteArgstring", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") argstring,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LengthArgstring(RexxSysBase: *mut Library, argstring: *const UBYTE) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "LengthArgstring", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") argstring,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CreateRexxMsg(
+    RexxSysBase: *mut Library,
+    port: *mut MsgPort,
+    extension: CONST_STRPTR,
+    host: CONST_STRPTR,
+) -> *mut RexxMsg {
+    let asm_ret_value: *mut RexxMsg;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "CreateRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") port,
+            in("a1") extension,
+            in("d0") host,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn DeleteRexxMsg(RexxSysBase: *mut Library, packet: *mut RexxMsg) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "DeleteRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") packet,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ClearRexxMsg(RexxSysBase: *mut Library, msgptr: *mut RexxMsg, count: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "ClearRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") msgptr,
+            in("d0") count,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FillRexxMsg(
+    RexxSysBase: *mut Library,
+    msgptr: *mut RexxMsg,
+    count: ULONG,
+    mask: ULONG,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "FillRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") msgptr,
+            in("d0") count,
+            in("d1") mask,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn IsRexxMsg(RexxSysBase: *mut Library, msgptr: *const RexxMsg) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "IsRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") msgptr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn LockRexxBase(RexxSysBase: *mut Library, resource: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "LockRexxBase", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("d0") resource,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UnlockRexxBase(RexxSysBase: *mut Library, resource: ULONG) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 36, "UnlockRexxBase", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("d0") resource,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn CreateRexxHostPort(
+    RexxSysBase: *mut Library,
+    basename: CONST_STRPTR,
+) -> *mut MsgPort {
+    let asm_ret_value: *mut MsgPort;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "CreateRexxHostPort", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-480, %a6)
+            ".short -480",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") basename,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn DeleteRexxHostPort(RexxSysBase: *mut Library, port: *mut MsgPort) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "DeleteRexxHostPort", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-486, %a6)
+            ".short -486",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") port,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn GetRexxVarFromMsg(
+    RexxSysBase: *mut Library,
+    var: CONST_STRPTR,
+    msgptr: *const RexxMsg,
+    value: STRPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "GetRexxVarFromMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-492, %a6)
+            ".short -492",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") var,
+            in("a2") msgptr,
+            in("a1") value,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SetRexxVarFromMsg(
+    RexxSysBase: *mut Library,
+    var: CONST_STRPTR,
+    msgptr: *mut RexxMsg,
+    value: CONST_STRPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "SetRexxVarFromMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-498, %a6)
+            ".short -498",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") var,
+            in("a2") msgptr,
+            in("a1") value,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn LaunchRexxScript(
+    RexxSysBase: *mut Library,
+    script: CONST_STRPTR,
+    replyport: *mut MsgPort,
+    extension: CONST_STRPTR,
+    input: BPTR,
+    output: BPTR,
+) -> *mut RexxMsg {
+    let asm_ret_value: *mut RexxMsg;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "LaunchRexxScript", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-504, %a6)
+            ".short -504",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") script,
+            in("a1") replyport,
+            in("a2") extension,
+            in("d1") input,
+            in("d2") output,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn FreeRexxMsg(RexxSysBase: *mut Library, msgptr: *mut RexxMsg) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(RexxSysBase, 47, "FreeRexxMsg", "rexxsyslib.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-510, %a6)
+            ".short -510",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") msgptr,
+        );
+    }
+}
+
+/// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn GetRexxBufferFromMsg(
+    RexxSysBase: *mut Library,
+    var: CONST_STRPTR,
+    msgptr: *const RexxMsg,
+    buffer: STRPTR,
+    buffer_size: ULONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            RexxSysBase,
+            47,
+            "GetRexxBufferFromMsg",
+            "rexxsyslib.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-516, %a6)
+            ".short -516",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) RexxSysBase,
+            in("a0") var,
+            in("a2") msgptr,
+            in("a1") buffer,
+            in("d0") buffer_size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "rexxsyslib.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct RexxSysLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl RexxSysLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"rexxsyslib.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "rexxsyslib.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateArgstring(&self, string: CONST_STRPTR, length: ULONG) -> *mut UBYTE {
+        unsafe { CreateArgstring(self.base.as_ptr(), string, length) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteArgstring(&self, argstring: *mut UBYTE) {
+        unsafe { DeleteArgstring(self.base.as_ptr(), argstring) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LengthArgstring(&self, argstring: *const UBYTE) -> ULONG {
+        unsafe { LengthArgstring(self.base.as_ptr(), argstring) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CreateRexxMsg(
+        &self,
+        port: *mut MsgPort,
+        extension: CONST_STRPTR,
+        host: CONST_STRPTR,
+    ) -> *mut RexxMsg {
+        unsafe { CreateRexxMsg(self.base.as_ptr(), port, extension, host) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn DeleteRexxMsg(&self, packet: *mut RexxMsg) {
+        unsafe { DeleteRexxMsg(self.base.as_ptr(), packet) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn ClearRexxMsg(&self, msgptr: *mut RexxMsg, count: ULONG) {
+        unsafe { ClearRexxMsg(self.base.as_ptr(), msgptr, count) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FillRexxMsg(&self, msgptr: *mut RexxMsg, count: ULONG, mask: ULONG) -> BOOL {
+        unsafe { FillRexxMsg(self.base.as_ptr(), msgptr, count, mask) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn IsRexxMsg(&self, msgptr: *const RexxMsg) -> BOOL {
+        unsafe { IsRexxMsg(self.base.as_ptr(), msgptr) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn LockRexxBase(&self, resource: ULONG) {
+        unsafe { LockRexxBase(self.base.as_ptr(), resource) }
+    }
+
+    /// libname: "rexxsyslib.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UnlockRexxBase(&self, resource: ULONG) {
+        unsafe { UnlockRexxBase(self.base.as_ptr(), resource) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn CreateRexxHostPort(&self, basename: CONST_STRPTR) -> *mut MsgPort {
+        unsafe { CreateRexxHostPort(self.base.as_ptr(), basename) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn DeleteRexxHostPort(&self, port: *mut MsgPort) {
+        unsafe { DeleteRexxHostPort(self.base.as_ptr(), port) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn GetRexxVarFromMsg(
+        &self,
+        var: CONST_STRPTR,
+        msgptr: *const RexxMsg,
+        value: STRPTR,
+    ) -> LONG {
+        unsafe { GetRexxVarFromMsg(self.base.as_ptr(), var, msgptr, value) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SetRexxVarFromMsg(
+        &self,
+        var: CONST_STRPTR,
+        msgptr: *mut RexxMsg,
+        value: CONST_STRPTR,
+    ) -> LONG {
+        unsafe { SetRexxVarFromMsg(self.base.as_ptr(), var, msgptr, value) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn LaunchRexxScript(
+        &self,
+        script: CONST_STRPTR,
+        replyport: *mut MsgPort,
+        extension: CONST_STRPTR,
+        input: BPTR,
+        output: BPTR,
+    ) -> *mut RexxMsg {
+        unsafe {
+            LaunchRexxScript(
+                self.base.as_ptr(),
+                script,
+                replyport,
+                extension,
+                input,
+                output,
+            )
+        }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn FreeRexxMsg(&self, msgptr: *mut RexxMsg) {
+        unsafe { FreeRexxMsg(self.base.as_ptr(), msgptr) }
+    }
+
+    /// libname: "rexxsyslib.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn GetRexxBufferFromMsg(
+        &self,
+        var: CONST_STRPTR,
+        msgptr: *const RexxMsg,
+        buffer: STRPTR,
+        buffer_size: ULONG,
+    ) -> LONG {
+        unsafe { GetRexxBufferFromMsg(self.base.as_ptr(), var, msgptr, buffer, buffer_size) }
+    }
+}
+
+impl Drop for RexxSysLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `CreateArgstring()`
+pub const LVO_CreateArgstring: i16 = -126;
+/// Library vector offset of `DeleteArgstring()`
+pub const LVO_DeleteArgstring: i16 = -132;
+/// Library vector offset of `LengthArgstring()`
+pub const LVO_LengthArgstring: i16 = -138;
+/// Library vector offset of `CreateRexxMsg()`
+pub const LVO_CreateRexxMsg: i16 = -144;
+/// Library vector offset of `DeleteRexxMsg()`
+pub const LVO_DeleteRexxMsg: i16 = -150;
+/// Library vector offset of `ClearRexxMsg()`
+pub const LVO_ClearRexxMsg: i16 = -156;
+/// Library vector offset of `FillRexxMsg()`
+pub const LVO_FillRexxMsg: i16 = -162;
+/// Library vector offset of `IsRexxMsg()`
+pub const LVO_IsRexxMsg: i16 = -168;
+/// Library vector offset of `LockRexxBase()`
+pub const LVO_LockRexxBase: i16 = -450;
+/// Library vector offset of `UnlockRexxBase()`
+pub const LVO_UnlockRexxBase: i16 = -456;
+/// Library vector offset of `CreateRexxHostPort()`
+pub const LVO_CreateRexxHostPort: i16 = -480;
+/// Library vector offset of `DeleteRexxHostPort()`
+pub const LVO_DeleteRexxHostPort: i16 = -486;
+/// Library vector offset of `GetRexxVarFromMsg()`
+pub const LVO_GetRexxVarFromMsg: i16 = -492;
+/// Library vector offset of `SetRexxVarFromMsg()`
+pub const LVO_SetRexxVarFromMsg: i16 = -498;
+/// Library vector offset of `LaunchRexxScript()`
+pub const LVO_LaunchRexxScript: i16 = -504;
+/// Library vector offset of `FreeRexxMsg()`
+pub const LVO_FreeRexxMsg: i16 = -510;
+/// Library vector offset of `GetRexxBufferFromMsg()`
+pub const LVO_GetRexxBufferFromMsg: i16 = -516;
+
+/// Functions of "rexxsyslib.library"
+pub static REXXSYSLIB_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "CreateArgstring",
+        lvo: LVO_CreateArgstring,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteArgstring",
+        lvo: LVO_DeleteArgstring,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LengthArgstring",
+        lvo: LVO_LengthArgstring,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateRexxMsg",
+        lvo: LVO_CreateRexxMsg,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "DeleteRexxMsg",
+        lvo: LVO_DeleteRexxMsg,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "ClearRexxMsg",
+        lvo: LVO_ClearRexxMsg,
+        registers: &["a0", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FillRexxMsg",
+        lvo: LVO_FillRexxMsg,
+        registers: &["a0", "d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "IsRexxMsg",
+        lvo: LVO_IsRexxMsg,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "LockRexxBase",
+        lvo: LVO_LockRexxBase,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UnlockRexxBase",
+        lvo: LVO_UnlockRexxBase,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CreateRexxHostPort",
+        lvo: LVO_CreateRexxHostPort,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "DeleteRexxHostPort",
+        lvo: LVO_DeleteRexxHostPort,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "GetRexxVarFromMsg",
+        lvo: LVO_GetRexxVarFromMsg,
+        registers: &["a0", "a2", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "SetRexxVarFromMsg",
+        lvo: LVO_SetRexxVarFromMsg,
+        registers: &["a0", "a2", "a1"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "LaunchRexxScript",
+        lvo: LVO_LaunchRexxScript,
+        registers: &["a0", "a1", "a2", "d1", "d2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "FreeRexxMsg",
+        lvo: LVO_FreeRexxMsg,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "GetRexxBufferFromMsg",
+        lvo: LVO_GetRexxBufferFromMsg,
+        registers: &["a0", "a2", "a1", "d0"],
+        min_version: 47,
+    },
+];
@@ -0,0 +1,208 @@
+//! Functions of "speedbar.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SPEEDBAR_GetClass(SpeedBarBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            SpeedBarBase as *mut Library,
+            40,
+            "SPEEDBAR_GetClass",
+            "speedbar.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SpeedBarBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn AllocSpeedButtonNodeA(
+    SpeedBarBase: *mut ::core::ffi::c_void,
+    number: ULONG,
+    tags: *mut TagItem,
+) -> *mut Node {
+    let asm_ret_value: *mut Node;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            SpeedBarBase as *mut Library,
+            40,
+            "AllocSpeedButtonNodeA",
+            "speedbar.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) SpeedBarBase,
+            in("d0") number,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn FreeSpeedButtonNode(SpeedBarBase: *mut ::core::ffi::c_void, node: *mut Node) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            SpeedBarBase as *mut Library,
+            40,
+            "FreeSpeedButtonNode",
+            "speedbar.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SpeedBarBase,
+            in("a0") node,
+        );
+    }
+}
+
+/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn SetSpeedButtonNodeAttrsA(
+    SpeedBarBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            SpeedBarBase as *mut Library,
+            40,
+            "SetSpeedButtonNodeAttrsA",
+            "speedbar.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SpeedBarBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// libname: "speedbar.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn GetSpeedButtonNodeAttrsA(
+    SpeedBarBase: *mut ::core::ffi::c_void,
+    node: *mut Node,
+    tags: *mut TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            SpeedBarBase as *mut Library,
+            40,
+            "GetSpeedButtonNodeAttrsA",
+            "speedbar.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) SpeedBarBase,
+            in("a0") node,
+            in("a1") tags,
+        );
+    }
+}
+
+/// Library vector offset of `SPEEDBAR_GetClass()`
+pub const LVO_SPEEDBAR_GetClass: i16 = -30;
+/// Library vector offset of `AllocSpeedButtonNodeA()`
+pub const LVO_AllocSpeedButtonNodeA: i16 = -36;
+/// Library vector offset of `FreeSpeedButtonNode()`
+pub const LVO_FreeSpeedButtonNode: i16 = -42;
+/// Library vector offset of `SetSpeedButtonNodeAttrsA()`
+pub const LVO_SetSpeedButtonNodeAttrsA: i16 = -48;
+/// Library vector offset of `GetSpeedButtonNodeAttrsA()`
+pub const LVO_GetSpeedButtonNodeAttrsA: i16 = -54;
+
+/// Functions of "speedbar.gadget"
+pub static SPEEDBAR_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "SPEEDBAR_GetClass",
+        lvo: LVO_SPEEDBAR_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "AllocSpeedButtonNodeA",
+        lvo: LVO_AllocSpeedButtonNodeA,
+        registers: &["d0", "a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "FreeSpeedButtonNode",
+        lvo: LVO_FreeSpeedButtonNode,
+        registers: &["a0"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "SetSpeedButtonNodeAttrsA",
+        lvo: LVO_SetSpeedButtonNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "GetSpeedButtonNodeAttrsA",
+        lvo: LVO_GetSpeedButtonNodeAttrsA,
+        registers: &["a0", "a1"],
+        min_version: 40,
+    },
+];
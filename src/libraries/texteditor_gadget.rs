@@ -0,0 +1,94 @@
+//! Functions of "texteditor.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "texteditor.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn TEXTEDITOR_GetClass(TextFieldBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TextFieldBase as *mut Library,
+            40,
+            "TEXTEDITOR_GetClass",
+            "texteditor.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TextFieldBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "texteditor.gadget" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn HighlightSetFormat(
+    TextFieldBase: *mut ::core::ffi::c_void,
+    object: APTR,
+    pos: ULONG,
+    end: ULONG,
+    style: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TextFieldBase as *mut Library,
+            47,
+            "HighlightSetFormat",
+            "texteditor.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) TextFieldBase,
+            in("a0") object,
+            in("d0") pos,
+            in("d1") end,
+            in("d2") style,
+        );
+    }
+}
+
+/// Library vector offset of `TEXTEDITOR_GetClass()`
+pub const LVO_TEXTEDITOR_GetClass: i16 = -30;
+/// Library vector offset of `HighlightSetFormat()`
+pub const LVO_HighlightSetFormat: i16 = -36;
+
+/// Functions of "texteditor.gadget"
+pub static TEXTEDITOR_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "TEXTEDITOR_GetClass",
+        lvo: LVO_TEXTEDITOR_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "HighlightSetFormat",
+        lvo: LVO_HighlightSetFormat,
+        registers: &["a0", "d0", "d1", "d2"],
+        min_version: 47,
+    },
+];
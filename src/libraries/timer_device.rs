@@ -0,0 +1,167 @@
+//! Functions of "timer.device"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "timer.device"
+pub unsafe fn AddTime(
+    TimerBase: *mut ::core::ffi::c_void,
+    dest: *mut TimeVal_Type,
+    src: *const TimeVal_Type,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) TimerBase,
+            in("a0") dest,
+            in("a1") src,
+        );
+    }
+}
+
+/// libname: "timer.device"
+pub unsafe fn SubTime(
+    TimerBase: *mut ::core::ffi::c_void,
+    dest: *mut TimeVal_Type,
+    src: *const TimeVal_Type,
+) {
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) TimerBase,
+            in("a0") dest,
+            in("a1") src,
+        );
+    }
+}
+
+/// libname: "timer.device"
+pub unsafe fn CmpTime(
+    TimerBase: *mut ::core::ffi::c_void,
+    dest: *const TimeVal_Type,
+    src: *const TimeVal_Type,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TimerBase,
+            in("a0") dest,
+            in("a1") src,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "timer.device" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn ReadEClock(TimerBase: *mut ::core::ffi::c_void, dest: *mut EClockVal) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(TimerBase as *mut Library, 36, "ReadEClock", "timer.device")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TimerBase,
+            in("a0") dest,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "timer.device" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetSysTime(TimerBase: *mut ::core::ffi::c_void, dest: *mut TimeVal_Type) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(TimerBase as *mut Library, 36, "GetSysTime", "timer.device")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) TimerBase,
+            in("a0") dest,
+        );
+    }
+}
+
+/// Library vector offset of `AddTime()`
+pub const LVO_AddTime: i16 = -42;
+/// Library vector offset of `SubTime()`
+pub const LVO_SubTime: i16 = -48;
+/// Library vector offset of `CmpTime()`
+pub const LVO_CmpTime: i16 = -54;
+/// Library vector offset of `ReadEClock()`
+pub const LVO_ReadEClock: i16 = -60;
+/// Library vector offset of `GetSysTime()`
+pub const LVO_GetSysTime: i16 = -66;
+
+/// Functions of "timer.device"
+pub static TIMER_DEVICE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "AddTime",
+        lvo: LVO_AddTime,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "SubTime",
+        lvo: LVO_SubTime,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "CmpTime",
+        lvo: LVO_CmpTime,
+        registers: &["a0", "a1"],
+        min_version: 0,
+    },
+    crate::FunctionInfo {
+        name: "ReadEClock",
+        lvo: LVO_ReadEClock,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetSysTime",
+        lvo: LVO_GetSysTime,
+        registers: &["a0"],
+        min_version: 36,
+    },
+];
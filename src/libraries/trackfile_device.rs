@@ -0,0 +1,345 @@
+//! Functions of "trackfile.device"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFStartUnitTagList(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFStartUnitTagList",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFStopUnitTagList(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFStopUnitTagList",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFInsertMediaTagList(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFInsertMediaTagList",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFEjectMediaTagList(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFEjectMediaTagList",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFGetUnitData(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+) -> *mut TrackFileUnitData {
+    let asm_ret_value: *mut TrackFileUnitData;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFGetUnitData",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFFreeUnitData(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    tfud: *mut TrackFileUnitData,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFFreeUnitData",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("a0") tfud,
+        );
+    }
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFChangeUnitTagList(
+    TrackFileBase: *mut ::core::ffi::c_void,
+    which_unit: LONG,
+    tags: *const TagItem,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFChangeUnitTagList",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") which_unit,
+            in("a0") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "trackfile.device" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn TFExamineFileSize(TrackFileBase: *mut ::core::ffi::c_void, file_size: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            TrackFileBase as *mut Library,
+            47,
+            "TFExamineFileSize",
+            "trackfile.device",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TrackFileBase,
+            in("d0") file_size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// Library vector offset of `TFStartUnitTagList()`
+pub const LVO_TFStartUnitTagList: i16 = -42;
+/// Library vector offset of `TFStopUnitTagList()`
+pub const LVO_TFStopUnitTagList: i16 = -48;
+/// Library vector offset of `TFInsertMediaTagList()`
+pub const LVO_TFInsertMediaTagList: i16 = -54;
+/// Library vector offset of `TFEjectMediaTagList()`
+pub const LVO_TFEjectMediaTagList: i16 = -60;
+/// Library vector offset of `TFGetUnitData()`
+pub const LVO_TFGetUnitData: i16 = -66;
+/// Library vector offset of `TFFreeUnitData()`
+pub const LVO_TFFreeUnitData: i16 = -72;
+/// Library vector offset of `TFChangeUnitTagList()`
+pub const LVO_TFChangeUnitTagList: i16 = -78;
+/// Library vector offset of `TFExamineFileSize()`
+pub const LVO_TFExamineFileSize: i16 = -84;
+
+/// Functions of "trackfile.device"
+pub static TRACKFILE_DEVICE_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "TFStartUnitTagList",
+        lvo: LVO_TFStartUnitTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFStopUnitTagList",
+        lvo: LVO_TFStopUnitTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFInsertMediaTagList",
+        lvo: LVO_TFInsertMediaTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFEjectMediaTagList",
+        lvo: LVO_TFEjectMediaTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFGetUnitData",
+        lvo: LVO_TFGetUnitData,
+        registers: &["d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFFreeUnitData",
+        lvo: LVO_TFFreeUnitData,
+        registers: &["a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFChangeUnitTagList",
+        lvo: LVO_TFChangeUnitTagList,
+        registers: &["d0", "a0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "TFExamineFileSize",
+        lvo: LVO_TFExamineFileSize,
+        registers: &["d0"],
+        min_version: 47,
+    },
+];
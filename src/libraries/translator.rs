@@ -0,0 +1,127 @@
+//! Functions of "translator.library"
+
+/* automatically generated by the amiga-sys tools */
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "translator.library"
+pub unsafe fn Translate(
+    TranslatorBase: *mut Library,
+    inputString: CONST_STRPTR,
+    inputLength: LONG,
+    outputBuffer: STRPTR,
+    bufferSize: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) TranslatorBase,
+            in("a0") inputString,
+            in("d0") inputLength,
+            in("a1") outputBuffer,
+            in("d1") bufferSize,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "translator.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct TranslatorLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl TranslatorLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"translator.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "translator.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "translator.library"
+    pub unsafe fn Translate(
+        &self,
+        inputString: CONST_STRPTR,
+        inputLength: LONG,
+        outputBuffer: STRPTR,
+        bufferSize: LONG,
+    ) -> LONG {
+        unsafe {
+            Translate(
+                self.base.as_ptr(),
+                inputString,
+                inputLength,
+                outputBuffer,
+                bufferSize,
+            )
+        }
+    }
+}
+
+impl Drop for TranslatorLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `Translate()`
+pub const LVO_Translate: i16 = -30;
+
+/// Functions of "translator.library"
+pub static TRANSLATOR_FUNCTIONS: &[crate::FunctionInfo] = &[crate::FunctionInfo {
+    name: "Translate",
+    lvo: LVO_Translate,
+    registers: &["a0", "d0", "a1", "d1"],
+    min_version: 0,
+}];
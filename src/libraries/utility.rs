@@ -0,0 +1,1921 @@
+//! Functions of "utility.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FindTagItem(
+    UtilityBase: *mut Library,
+    tagVal: Tag,
+    tagList: *const TagItem,
+) -> *mut TagItem {
+    let asm_ret_value: *mut TagItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "FindTagItem", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") tagVal,
+            in("a0") tagList,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn GetTagData(
+    UtilityBase: *mut Library,
+    tagValue: Tag,
+    defaultVal: ULONG,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "GetTagData", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") tagValue,
+            in("d1") defaultVal,
+            in("a0") tagList,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn PackBoolTags(
+    UtilityBase: *mut Library,
+    initialFlags: ULONG,
+    tagList: *const TagItem,
+    boolMap: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "PackBoolTags", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") initialFlags,
+            in("a0") tagList,
+            in("a1") boolMap,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn NextTagItem(
+    UtilityBase: *mut Library,
+    tagListPtr: *mut *mut TagItem,
+) -> *mut TagItem {
+    let asm_ret_value: *mut TagItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "NextTagItem", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") tagListPtr,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FilterTagChanges(
+    UtilityBase: *mut Library,
+    changeList: *mut TagItem,
+    originalList: *mut TagItem,
+    apply: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "FilterTagChanges", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") changeList,
+            in("a1") originalList,
+            in("d0") apply,
+        );
+    }
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn MapTags(
+    UtilityBase: *mut Library,
+    tagList: *mut TagItem,
+    mapList: *const TagItem,
+    mapType: ULONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "MapTags", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") tagList,
+            in("a1") mapList,
+            in("d0") mapType,
+        );
+    }
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AllocateTagItems(UtilityBase: *mut Library, numTags: ULONG) -> *mut TagItem {
+    let asm_ret_value: *mut TagItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "AllocateTagItems", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") numTags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CloneTagItems(UtilityBase: *mut Library, tagList: *const TagItem) -> *mut TagItem {
+    let asm_ret_value: *mut TagItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "CloneTagItems", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FreeTagItems(UtilityBase: *mut Library, tagList: *mut TagItem) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "FreeTagItems", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") tagList,
+        );
+    }
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RefreshTagItemClones(
+    UtilityBase: *mut Library,
+    clone: *mut TagItem,
+    original: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "RefreshTagItemClones", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-84, %a6)
+            ".short -84",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") clone,
+            in("a1") original,
+        );
+    }
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn TagInArray(UtilityBase: *mut Library, tagValue: Tag, tagArray: *const Tag) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "TagInArray", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") tagValue,
+            in("a0") tagArray,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn FilterTagItems(
+    UtilityBase: *mut Library,
+    tagList: *mut TagItem,
+    filterArray: *const Tag,
+    logic: ULONG,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "FilterTagItems", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") tagList,
+            in("a1") filterArray,
+            in("d0") logic,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CallHookPkt(
+    UtilityBase: *mut Library,
+    hook: *mut Hook,
+    object: APTR,
+    paramPacket: APTR,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "CallHookPkt", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") hook,
+            in("a2") object,
+            in("a1") paramPacket,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Amiga2Date(UtilityBase: *mut Library, seconds: ULONG, result: *mut ClockData) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "Amiga2Date", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") seconds,
+            in("a0") result,
+        );
+    }
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn Date2Amiga(UtilityBase: *mut Library, date: *const ClockData) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "Date2Amiga", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") date,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn CheckDate(UtilityBase: *mut Library, date: *const ClockData) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "CheckDate", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") date,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SMult32(UtilityBase: *mut Library, arg1: LONG, arg2: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "SMult32", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") arg1,
+            in("d1") arg2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UMult32(UtilityBase: *mut Library, arg1: ULONG, arg2: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "UMult32", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-144, %a6)
+            ".short -144",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") arg1,
+            in("d1") arg2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn SDivMod32(UtilityBase: *mut Library, dividend: LONG, divisor: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "SDivMod32", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-150, %a6)
+            ".short -150",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") dividend,
+            in("d1") divisor,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UDivMod32(UtilityBase: *mut Library, dividend: ULONG, divisor: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 36, "UDivMod32", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-156, %a6)
+            ".short -156",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") dividend,
+            in("d1") divisor,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn Stricmp(
+    UtilityBase: *mut Library,
+    string1: CONST_STRPTR,
+    string2: CONST_STRPTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 37, "Stricmp", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-162, %a6)
+            ".short -162",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") string1,
+            in("a1") string2,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn Strnicmp(
+    UtilityBase: *mut Library,
+    string1: CONST_STRPTR,
+    string2: CONST_STRPTR,
+    length: LONG,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 37, "Strnicmp", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-168, %a6)
+            ".short -168",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") string1,
+            in("a1") string2,
+            in("d0") length,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn ToUpper(UtilityBase: *mut Library, character: ULONG) -> UBYTE {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 37, "ToUpper", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-174, %a6)
+            ".short -174",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "utility.library" (V37) (Kickstart 2.04)
+#[cfg(not(feature = "kickstart-v36"))]
+pub unsafe fn ToLower(UtilityBase: *mut Library, character: ULONG) -> UBYTE {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 37, "ToLower", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-180, %a6)
+            ".short -180",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") character,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as UBYTE
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ApplyTagChanges(
+    UtilityBase: *mut Library,
+    list: *mut TagItem,
+    changeList: *const TagItem,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "ApplyTagChanges", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-186, %a6)
+            ".short -186",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") list,
+            in("a1") changeList,
+        );
+    }
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn SMult64(UtilityBase: *mut Library, arg1: LONG, arg2: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "SMult64", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-198, %a6)
+            ".short -198",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") arg1,
+            in("d1") arg2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn UMult64(UtilityBase: *mut Library, arg1: ULONG, arg2: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "UMult64", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-204, %a6)
+            ".short -204",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d0") arg1,
+            in("d1") arg2,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn PackStructureTags(
+    UtilityBase: *mut Library,
+    pack: APTR,
+    packTable: *const ULONG,
+    tagList: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "PackStructureTags", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-210, %a6)
+            ".short -210",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") pack,
+            in("a1") packTable,
+            in("a2") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn UnpackStructureTags(
+    UtilityBase: *mut Library,
+    pack: CONST_APTR,
+    packTable: *const ULONG,
+    tagList: *mut TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "UnpackStructureTags", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-216, %a6)
+            ".short -216",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") pack,
+            in("a1") packTable,
+            in("a2") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AddNamedObject(
+    UtilityBase: *mut Library,
+    nameSpace: *mut NamedObject,
+    object: *mut NamedObject,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "AddNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-222, %a6)
+            ".short -222",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") nameSpace,
+            in("a1") object,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AllocNamedObjectA(
+    UtilityBase: *mut Library,
+    name: CONST_STRPTR,
+    tagList: *const TagItem,
+) -> *mut NamedObject {
+    let asm_ret_value: *mut NamedObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "AllocNamedObjectA", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-228, %a6)
+            ".short -228",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") name,
+            in("a1") tagList,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn AttemptRemNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "AttemptRemNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-234, %a6)
+            ".short -234",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") object,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FindNamedObject(
+    UtilityBase: *mut Library,
+    nameSpace: *mut NamedObject,
+    name: CONST_STRPTR,
+    lastObject: *const NamedObject,
+) -> *mut NamedObject {
+    let asm_ret_value: *mut NamedObject;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "FindNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-240, %a6)
+            ".short -240",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") nameSpace,
+            in("a1") name,
+            in("a2") lastObject,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn FreeNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "FreeNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-246, %a6)
+            ".short -246",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") object,
+        );
+    }
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn NamedObjectName(UtilityBase: *mut Library, object: *mut NamedObject) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "NamedObjectName", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-252, %a6)
+            ".short -252",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") object,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn ReleaseNamedObject(UtilityBase: *mut Library, object: *mut NamedObject) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "ReleaseNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-258, %a6)
+            ".short -258",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") object,
+        );
+    }
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn RemNamedObject(
+    UtilityBase: *mut Library,
+    object: *mut NamedObject,
+    message: *mut Message,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "RemNamedObject", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-264, %a6)
+            ".short -264",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") object,
+            in("a1") message,
+        );
+    }
+}
+
+/// libname: "utility.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn GetUniqueID(UtilityBase: *mut Library) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 39, "GetUniqueID", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-270, %a6)
+            ".short -270",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn VSNPrintf(
+    UtilityBase: *mut Library,
+    buffer: STRPTR,
+    bufsize: ULONG,
+    fmt: CONST_STRPTR,
+    data: CONST_APTR,
+) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 47, "VSNPrintf", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-312, %a6)
+            ".short -312",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a0") buffer,
+            in("d0") bufsize,
+            in("a1") fmt,
+            in("a2") data,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn Strncpy(
+    UtilityBase: *mut Library,
+    dst: STRPTR,
+    src: CONST_STRPTR,
+    size: ULONG,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 47, "Strncpy", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-438, %a6)
+            ".short -438",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a1") dst,
+            in("a0") src,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn Strncat(
+    UtilityBase: *mut Library,
+    dst: STRPTR,
+    src: CONST_STRPTR,
+    size: ULONG,
+) -> STRPTR {
+    let asm_ret_value: STRPTR;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 47, "Strncat", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-444, %a6)
+            ".short -444",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("a1") dst,
+            in("a0") src,
+            in("d0") size,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn SDivMod64(UtilityBase: *mut Library, hi: LONG, lo: LONG, divisor: LONG) -> LONG {
+    let asm_ret_value: LONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 47, "SDivMod64", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-450, %a6)
+            ".short -450",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d1") hi,
+            in("d0") lo,
+            in("d2") divisor,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn UDivMod64(UtilityBase: *mut Library, hi: ULONG, lo: ULONG, divisor: ULONG) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(UtilityBase, 47, "UDivMod64", "utility.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-456, %a6)
+            ".short -456",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) UtilityBase,
+            in("d1") hi,
+            in("d0") lo,
+            in("d2") divisor,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "utility.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct UtilityLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl UtilityLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"utility.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "utility.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FindTagItem(&self, tagVal: Tag, tagList: *const TagItem) -> *mut TagItem {
+        unsafe { FindTagItem(self.base.as_ptr(), tagVal, tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn GetTagData(
+        &self,
+        tagValue: Tag,
+        defaultVal: ULONG,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { GetTagData(self.base.as_ptr(), tagValue, defaultVal, tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn PackBoolTags(
+        &self,
+        initialFlags: ULONG,
+        tagList: *const TagItem,
+        boolMap: *const TagItem,
+    ) -> ULONG {
+        unsafe { PackBoolTags(self.base.as_ptr(), initialFlags, tagList, boolMap) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn NextTagItem(&self, tagListPtr: *mut *mut TagItem) -> *mut TagItem {
+        unsafe { NextTagItem(self.base.as_ptr(), tagListPtr) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FilterTagChanges(
+        &self,
+        changeList: *mut TagItem,
+        originalList: *mut TagItem,
+        apply: ULONG,
+    ) {
+        unsafe { FilterTagChanges(self.base.as_ptr(), changeList, originalList, apply) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn MapTags(&self, tagList: *mut TagItem, mapList: *const TagItem, mapType: ULONG) {
+        unsafe { MapTags(self.base.as_ptr(), tagList, mapList, mapType) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AllocateTagItems(&self, numTags: ULONG) -> *mut TagItem {
+        unsafe { AllocateTagItems(self.base.as_ptr(), numTags) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CloneTagItems(&self, tagList: *const TagItem) -> *mut TagItem {
+        unsafe { CloneTagItems(self.base.as_ptr(), tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FreeTagItems(&self, tagList: *mut TagItem) {
+        unsafe { FreeTagItems(self.base.as_ptr(), tagList) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RefreshTagItemClones(&self, clone: *mut TagItem, original: *const TagItem) {
+        unsafe { RefreshTagItemClones(self.base.as_ptr(), clone, original) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn TagInArray(&self, tagValue: Tag, tagArray: *const Tag) -> BOOL {
+        unsafe { TagInArray(self.base.as_ptr(), tagValue, tagArray) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn FilterTagItems(
+        &self,
+        tagList: *mut TagItem,
+        filterArray: *const Tag,
+        logic: ULONG,
+    ) -> ULONG {
+        unsafe { FilterTagItems(self.base.as_ptr(), tagList, filterArray, logic) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CallHookPkt(&self, hook: *mut Hook, object: APTR, paramPacket: APTR) -> ULONG {
+        unsafe { CallHookPkt(self.base.as_ptr(), hook, object, paramPacket) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Amiga2Date(&self, seconds: ULONG, result: *mut ClockData) {
+        unsafe { Amiga2Date(self.base.as_ptr(), seconds, result) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn Date2Amiga(&self, date: *const ClockData) -> ULONG {
+        unsafe { Date2Amiga(self.base.as_ptr(), date) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn CheckDate(&self, date: *const ClockData) -> ULONG {
+        unsafe { CheckDate(self.base.as_ptr(), date) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SMult32(&self, arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { SMult32(self.base.as_ptr(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UMult32(&self, arg1: ULONG, arg2: ULONG) -> ULONG {
+        unsafe { UMult32(self.base.as_ptr(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn SDivMod32(&self, dividend: LONG, divisor: LONG) -> LONG {
+        unsafe { SDivMod32(self.base.as_ptr(), dividend, divisor) }
+    }
+
+    /// libname: "utility.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UDivMod32(&self, dividend: ULONG, divisor: ULONG) -> ULONG {
+        unsafe { UDivMod32(self.base.as_ptr(), dividend, divisor) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn Stricmp(&self, string1: CONST_STRPTR, string2: CONST_STRPTR) -> LONG {
+        unsafe { Stricmp(self.base.as_ptr(), string1, string2) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn Strnicmp(
+        &self,
+        string1: CONST_STRPTR,
+        string2: CONST_STRPTR,
+        length: LONG,
+    ) -> LONG {
+        unsafe { Strnicmp(self.base.as_ptr(), string1, string2, length) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn ToUpper(&self, character: ULONG) -> UBYTE {
+        unsafe { ToUpper(self.base.as_ptr(), character) }
+    }
+
+    /// libname: "utility.library" (V37) (Kickstart 2.04)
+    #[cfg(not(feature = "kickstart-v36"))]
+    pub unsafe fn ToLower(&self, character: ULONG) -> UBYTE {
+        unsafe { ToLower(self.base.as_ptr(), character) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ApplyTagChanges(&self, list: *mut TagItem, changeList: *const TagItem) {
+        unsafe { ApplyTagChanges(self.base.as_ptr(), list, changeList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn SMult64(&self, arg1: LONG, arg2: LONG) -> LONG {
+        unsafe { SMult64(self.base.as_ptr(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn UMult64(&self, arg1: ULONG, arg2: ULONG) -> ULONG {
+        unsafe { UMult64(self.base.as_ptr(), arg1, arg2) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn PackStructureTags(
+        &self,
+        pack: APTR,
+        packTable: *const ULONG,
+        tagList: *const TagItem,
+    ) -> ULONG {
+        unsafe { PackStructureTags(self.base.as_ptr(), pack, packTable, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn UnpackStructureTags(
+        &self,
+        pack: CONST_APTR,
+        packTable: *const ULONG,
+        tagList: *mut TagItem,
+    ) -> ULONG {
+        unsafe { UnpackStructureTags(self.base.as_ptr(), pack, packTable, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AddNamedObject(
+        &self,
+        nameSpace: *mut NamedObject,
+        object: *mut NamedObject,
+    ) -> BOOL {
+        unsafe { AddNamedObject(self.base.as_ptr(), nameSpace, object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AllocNamedObjectA(
+        &self,
+        name: CONST_STRPTR,
+        tagList: *const TagItem,
+    ) -> *mut NamedObject {
+        unsafe { AllocNamedObjectA(self.base.as_ptr(), name, tagList) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn AttemptRemNamedObject(&self, object: *mut NamedObject) -> LONG {
+        unsafe { AttemptRemNamedObject(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FindNamedObject(
+        &self,
+        nameSpace: *mut NamedObject,
+        name: CONST_STRPTR,
+        lastObject: *const NamedObject,
+    ) -> *mut NamedObject {
+        unsafe { FindNamedObject(self.base.as_ptr(), nameSpace, name, lastObject) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn FreeNamedObject(&self, object: *mut NamedObject) {
+        unsafe { FreeNamedObject(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn NamedObjectName(&self, object: *mut NamedObject) -> STRPTR {
+        unsafe { NamedObjectName(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn ReleaseNamedObject(&self, object: *mut NamedObject) {
+        unsafe { ReleaseNamedObject(self.base.as_ptr(), object) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn RemNamedObject(&self, object: *mut NamedObject, message: *mut Message) {
+        unsafe { RemNamedObject(self.base.as_ptr(), object, message) }
+    }
+
+    /// libname: "utility.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn GetUniqueID(&self) -> ULONG {
+        unsafe { GetUniqueID(self.base.as_ptr()) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn VSNPrintf(
+        &self,
+        buffer: STRPTR,
+        bufsize: ULONG,
+        fmt: CONST_STRPTR,
+        data: CONST_APTR,
+    ) -> LONG {
+        unsafe { VSNPrintf(self.base.as_ptr(), buffer, bufsize, fmt, data) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn Strncpy(&self, dst: STRPTR, src: CONST_STRPTR, size: ULONG) -> STRPTR {
+        unsafe { Strncpy(self.base.as_ptr(), dst, src, size) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn Strncat(&self, dst: STRPTR, src: CONST_STRPTR, size: ULONG) -> STRPTR {
+        unsafe { Strncat(self.base.as_ptr(), dst, src, size) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn SDivMod64(&self, hi: LONG, lo: LONG, divisor: LONG) -> LONG {
+        unsafe { SDivMod64(self.base.as_ptr(), hi, lo, divisor) }
+    }
+
+    /// libname: "utility.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn UDivMod64(&self, hi: ULONG, lo: ULONG, divisor: ULONG) -> ULONG {
+        unsafe { UDivMod64(self.base.as_ptr(), hi, lo, divisor) }
+    }
+}
+
+impl Drop for UtilityLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `FindTagItem()`
+pub const LVO_FindTagItem: i16 = -30;
+/// Library vector offset of `GetTagData()`
+pub const LVO_GetTagData: i16 = -36;
+/// Library vector offset of `PackBoolTags()`
+pub const LVO_PackBoolTags: i16 = -42;
+/// Library vector offset of `NextTagItem()`
+pub const LVO_NextTagItem: i16 = -48;
+/// Library vector offset of `FilterTagChanges()`
+pub const LVO_FilterTagChanges: i16 = -54;
+/// Library vector offset of `MapTags()`
+pub const LVO_MapTags: i16 = -60;
+/// Library vector offset of `AllocateTagItems()`
+pub const LVO_AllocateTagItems: i16 = -66;
+/// Library vector offset of `CloneTagItems()`
+pub const LVO_CloneTagItems: i16 = -72;
+/// Library vector offset of `FreeTagItems()`
+pub const LVO_FreeTagItems: i16 = -78;
+/// Library vector offset of `RefreshTagItemClones()`
+pub const LVO_RefreshTagItemClones: i16 = -84;
+/// Library vector offset of `TagInArray()`
+pub const LVO_TagInArray: i16 = -90;
+/// Library vector offset of `FilterTagItems()`
+pub const LVO_FilterTagItems: i16 = -96;
+/// Library vector offset of `CallHookPkt()`
+pub const LVO_CallHookPkt: i16 = -102;
+/// Library vector offset of `Amiga2Date()`
+pub const LVO_Amiga2Date: i16 = -120;
+/// Library vector offset of `Date2Amiga()`
+pub const LVO_Date2Amiga: i16 = -126;
+/// Library vector offset of `CheckDate()`
+pub const LVO_CheckDate: i16 = -132;
+/// Library vector offset of `SMult32()`
+pub const LVO_SMult32: i16 = -138;
+/// Library vector offset of `UMult32()`
+pub const LVO_UMult32: i16 = -144;
+/// Library vector offset of `SDivMod32()`
+pub const LVO_SDivMod32: i16 = -150;
+/// Library vector offset of `UDivMod32()`
+pub const LVO_UDivMod32: i16 = -156;
+/// Library vector offset of `Stricmp()`
+pub const LVO_Stricmp: i16 = -162;
+/// Library vector offset of `Strnicmp()`
+pub const LVO_Strnicmp: i16 = -168;
+/// Library vector offset of `ToUpper()`
+pub const LVO_ToUpper: i16 = -174;
+/// Library vector offset of `ToLower()`
+pub const LVO_ToLower: i16 = -180;
+/// Library vector offset of `ApplyTagChanges()`
+pub const LVO_ApplyTagChanges: i16 = -186;
+/// Library vector offset of `SMult64()`
+pub const LVO_SMult64: i16 = -198;
+/// Library vector offset of `UMult64()`
+pub const LVO_UMult64: i16 = -204;
+/// Library vector offset of `PackStructureTags()`
+pub const LVO_PackStructureTags: i16 = -210;
+/// Library vector offset of `UnpackStructureTags()`
+pub const LVO_UnpackStructureTags: i16 = -216;
+/// Library vector offset of `AddNamedObject()`
+pub const LVO_AddNamedObject: i16 = -222;
+/// Library vector offset of `AllocNamedObjectA()`
+pub const LVO_AllocNamedObjectA: i16 = -228;
+/// Library vector offset of `AttemptRemNamedObject()`
+pub const LVO_AttemptRemNamedObject: i16 = -234;
+/// Library vector offset of `FindNamedObject()`
+pub const LVO_FindNamedObject: i16 = -240;
+/// Library vector offset of `FreeNamedObject()`
+pub const LVO_FreeNamedObject: i16 = -246;
+/// Library vector offset of `NamedObjectName()`
+pub const LVO_NamedObjectName: i16 = -252;
+/// Library vector offset of `ReleaseNamedObject()`
+pub const LVO_ReleaseNamedObject: i16 = -258;
+/// Library vector offset of `RemNamedObject()`
+pub const LVO_RemNamedObject: i16 = -264;
+/// Library vector offset of `GetUniqueID()`
+pub const LVO_GetUniqueID: i16 = -270;
+/// Library vector offset of `VSNPrintf()`
+pub const LVO_VSNPrintf: i16 = -312;
+/// Library vector offset of `Strncpy()`
+pub const LVO_Strncpy: i16 = -438;
+/// Library vector offset of `Strncat()`
+pub const LVO_Strncat: i16 = -444;
+/// Library vector offset of `SDivMod64()`
+pub const LVO_SDivMod64: i16 = -450;
+/// Library vector offset of `UDivMod64()`
+pub const LVO_UDivMod64: i16 = -456;
+
+/// Functions of "utility.library"
+pub static UTILITY_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "FindTagItem",
+        lvo: LVO_FindTagItem,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "GetTagData",
+        lvo: LVO_GetTagData,
+        registers: &["d0", "d1", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "PackBoolTags",
+        lvo: LVO_PackBoolTags,
+        registers: &["d0", "a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "NextTagItem",
+        lvo: LVO_NextTagItem,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FilterTagChanges",
+        lvo: LVO_FilterTagChanges,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "MapTags",
+        lvo: LVO_MapTags,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AllocateTagItems",
+        lvo: LVO_AllocateTagItems,
+        registers: &["d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CloneTagItems",
+        lvo: LVO_CloneTagItems,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FreeTagItems",
+        lvo: LVO_FreeTagItems,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RefreshTagItemClones",
+        lvo: LVO_RefreshTagItemClones,
+        registers: &["a0", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "TagInArray",
+        lvo: LVO_TagInArray,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "FilterTagItems",
+        lvo: LVO_FilterTagItems,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CallHookPkt",
+        lvo: LVO_CallHookPkt,
+        registers: &["a0", "a2", "a1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Amiga2Date",
+        lvo: LVO_Amiga2Date,
+        registers: &["d0", "a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Date2Amiga",
+        lvo: LVO_Date2Amiga,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "CheckDate",
+        lvo: LVO_CheckDate,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SMult32",
+        lvo: LVO_SMult32,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UMult32",
+        lvo: LVO_UMult32,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "SDivMod32",
+        lvo: LVO_SDivMod32,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "UDivMod32",
+        lvo: LVO_UDivMod32,
+        registers: &["d0", "d1"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "Stricmp",
+        lvo: LVO_Stricmp,
+        registers: &["a0", "a1"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "Strnicmp",
+        lvo: LVO_Strnicmp,
+        registers: &["a0", "a1", "d0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "ToUpper",
+        lvo: LVO_ToUpper,
+        registers: &["d0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "ToLower",
+        lvo: LVO_ToLower,
+        registers: &["d0"],
+        min_version: 37,
+    },
+    crate::FunctionInfo {
+        name: "ApplyTagChanges",
+        lvo: LVO_ApplyTagChanges,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "SMult64",
+        lvo: LVO_SMult64,
+        registers: &["d0", "d1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "UMult64",
+        lvo: LVO_UMult64,
+        registers: &["d0", "d1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "PackStructureTags",
+        lvo: LVO_PackStructureTags,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "UnpackStructureTags",
+        lvo: LVO_UnpackStructureTags,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AddNamedObject",
+        lvo: LVO_AddNamedObject,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AllocNamedObjectA",
+        lvo: LVO_AllocNamedObjectA,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "AttemptRemNamedObject",
+        lvo: LVO_AttemptRemNamedObject,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FindNamedObject",
+        lvo: LVO_FindNamedObject,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "FreeNamedObject",
+        lvo: LVO_FreeNamedObject,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "NamedObjectName",
+        lvo: LVO_NamedObjectName,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "ReleaseNamedObject",
+        lvo: LVO_ReleaseNamedObject,
+        registers: &["a0"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "RemNamedObject",
+        lvo: LVO_RemNamedObject,
+        registers: &["a0", "a1"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "GetUniqueID",
+        lvo: LVO_GetUniqueID,
+        registers: &[],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "VSNPrintf",
+        lvo: LVO_VSNPrintf,
+        registers: &["a0", "d0", "a1", "a2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "Strncpy",
+        lvo: LVO_Strncpy,
+        registers: &["a1", "a0", "d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "Strncat",
+        lvo: LVO_Strncat,
+        registers: &["a1", "a0", "d0"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "SDivMod64",
+        lvo: LVO_SDivMod64,
+        registers: &["d1", "d0", "d2"],
+        min_version: 47,
+    },
+    crate::FunctionInfo {
+        name: "UDivMod64",
+        lvo: LVO_UDivMod64,
+        registers: &["d1", "d0", "d2"],
+        min_version: 47,
+    },
+];
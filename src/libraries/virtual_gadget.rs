@@ -0,0 +1,143 @@
+//! Functions of "virtual.gadget"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn VIRTUAL_GetClass(VirtualBase: *mut ::core::ffi::c_void) -> *mut Class {
+    let asm_ret_value: *mut Class;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            VirtualBase as *mut Library,
+            40,
+            "VIRTUAL_GetClass",
+            "virtual.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) VirtualBase,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RefreshVirtualGadget(
+    VirtualBase: *mut ::core::ffi::c_void,
+    gadget: *mut Gadget,
+    obj: *mut Object,
+    window: *mut Window,
+    requester: *mut Requester,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            VirtualBase as *mut Library,
+            40,
+            "RefreshVirtualGadget",
+            "virtual.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-36, %a6)
+            ".short -36",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) VirtualBase,
+            in("a0") gadget,
+            in("a1") obj,
+            in("a2") window,
+            in("a3") requester,
+        );
+    }
+}
+
+/// libname: "virtual.gadget" (V40) (Kickstart 3.1)
+#[cfg(not(feature = "kickstart-v39"))]
+pub unsafe fn RethinkVirtualSize(
+    VirtualBase: *mut ::core::ffi::c_void,
+    virt_obj: *mut Object,
+    rootlayout: *mut Object,
+    font: *mut TextFont,
+    screen: *mut Screen,
+    layoutlimits: *mut LayoutLimits,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            VirtualBase as *mut Library,
+            40,
+            "RethinkVirtualSize",
+            "virtual.gadget",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-42, %a6)
+            ".short -42",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) VirtualBase,
+            in("a0") virt_obj,
+            in("a1") rootlayout,
+            in("a2") font,
+            in("a3") screen,
+            in("d0") layoutlimits,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// Library vector offset of `VIRTUAL_GetClass()`
+pub const LVO_VIRTUAL_GetClass: i16 = -30;
+/// Library vector offset of `RefreshVirtualGadget()`
+pub const LVO_RefreshVirtualGadget: i16 = -36;
+/// Library vector offset of `RethinkVirtualSize()`
+pub const LVO_RethinkVirtualSize: i16 = -42;
+
+/// Functions of "virtual.gadget"
+pub static VIRTUAL_GADGET_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "VIRTUAL_GetClass",
+        lvo: LVO_VIRTUAL_GetClass,
+        registers: &[],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RefreshVirtualGadget",
+        lvo: LVO_RefreshVirtualGadget,
+        registers: &["a0", "a1", "a2", "a3"],
+        min_version: 40,
+    },
+    crate::FunctionInfo {
+        name: "RethinkVirtualSize",
+        lvo: LVO_RethinkVirtualSize,
+        registers: &["a0", "a1", "a2", "a3", "d0"],
+        min_version: 40,
+    },
+];
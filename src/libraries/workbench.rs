@@ -0,0 +1,897 @@
+//! Functions of "workbench.library"
+
+/* automatically generated by the amiga-sys tools */
+
+#![allow(unused_imports)]
+
+use crate::bindings::*;
+use core::arch::asm;
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn UpdateWorkbench(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    lock: BPTR,
+    action: LONG,
+) {
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "UpdateWorkbench", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d0-%d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-30, %a6)
+            ".short -30",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d0-%d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") lock,
+            in("d0") action,
+        );
+    }
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddAppWindowA(
+    WorkbenchBase: *mut Library,
+    id: ULONG,
+    userdata: ULONG,
+    window: *mut Window,
+    msgport: *mut MsgPort,
+    taglist: *const TagItem,
+) -> *mut AppWindow {
+    let asm_ret_value: *mut AppWindow;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "AddAppWindowA", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-48, %a6)
+            ".short -48",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("d0") id,
+            in("d1") userdata,
+            in("a0") window,
+            in("a1") msgport,
+            in("a2") taglist,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemoveAppWindow(WorkbenchBase: *mut Library, appWindow: *mut AppWindow) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "RemoveAppWindow", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-54, %a6)
+            ".short -54",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") appWindow,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddAppIconA(
+    WorkbenchBase: *mut Library,
+    id: ULONG,
+    userdata: ULONG,
+    text: CONST_STRPTR,
+    msgport: *mut MsgPort,
+    lock: BPTR,
+    diskobj: *mut DiskObject,
+    taglist: *const TagItem,
+) -> *mut AppIcon {
+    let asm_ret_value: *mut AppIcon;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "AddAppIconA", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l %a4, -(%sp)",
+            "move.l {basereg}, %a6",
+            "move.l {a4reg}, %a4",
+            ".short 0x4eae", // jsr (-60, %a6)
+            ".short -60",
+            "move.l (%sp)+, %a4",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("d0") id,
+            in("d1") userdata,
+            in("a0") text,
+            in("a1") msgport,
+            in("a2") lock,
+            in("a3") diskobj,
+            a4reg = in(reg) taglist,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemoveAppIcon(WorkbenchBase: *mut Library, appIcon: *mut AppIcon) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "RemoveAppIcon", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-66, %a6)
+            ".short -66",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") appIcon,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn AddAppMenuItemA(
+    WorkbenchBase: *mut Library,
+    id: ULONG,
+    userdata: ULONG,
+    text: CONST_STRPTR,
+    msgport: *mut MsgPort,
+    taglist: *const TagItem,
+) -> *mut AppMenuItem {
+    let asm_ret_value: *mut AppMenuItem;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "AddAppMenuItemA", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-72, %a6)
+            ".short -72",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("d0") id,
+            in("d1") userdata,
+            in("a0") text,
+            in("a1") msgport,
+            in("a2") taglist,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "workbench.library" (V36) (Kickstart 2.0)
+#[cfg(not(feature = "kickstart-v34"))]
+pub unsafe fn RemoveAppMenuItem(
+    WorkbenchBase: *mut Library,
+    appMenuItem: *mut AppMenuItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 36, "RemoveAppMenuItem", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-78, %a6)
+            ".short -78",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") appMenuItem,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V39) (Kickstart 3.0)
+#[cfg(not(feature = "kickstart-v38"))]
+pub unsafe fn WBInfo(
+    WorkbenchBase: *mut Library,
+    lock: BPTR,
+    name: CONST_STRPTR,
+    screen: *mut Screen,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 39, "WBInfo", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-90, %a6)
+            ".short -90",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") lock,
+            in("a1") name,
+            in("a2") screen,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn OpenWorkbenchObjectA(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "OpenWorkbenchObjectA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-96, %a6)
+            ".short -96",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn CloseWorkbenchObjectA(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "CloseWorkbenchObjectA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-102, %a6)
+            ".short -102",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn WorkbenchControlA(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(WorkbenchBase, 44, "WorkbenchControlA", "workbench.library")
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-108, %a6)
+            ".short -108",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn AddAppWindowDropZoneA(
+    WorkbenchBase: *mut Library,
+    aw: *mut AppWindow,
+    id: ULONG,
+    userdata: ULONG,
+    tags: *const TagItem,
+) -> *mut AppWindowDropZone {
+    let asm_ret_value: *mut AppWindowDropZone;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "AddAppWindowDropZoneA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-114, %a6)
+            ".short -114",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") aw,
+            in("d0") id,
+            in("d1") userdata,
+            in("a1") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn RemoveAppWindowDropZone(
+    WorkbenchBase: *mut Library,
+    aw: *mut AppWindow,
+    dropZone: *mut AppWindowDropZone,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "RemoveAppWindowDropZone",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-120, %a6)
+            ".short -120",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") aw,
+            in("a1") dropZone,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn ChangeWorkbenchSelectionA(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    hook: *mut Hook,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "ChangeWorkbenchSelectionA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-126, %a6)
+            ".short -126",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") hook,
+            in("a2") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+#[cfg(not(feature = "kickstart-v40"))]
+pub unsafe fn MakeWorkbenchObjectVisibleA(
+    WorkbenchBase: *mut Library,
+    name: CONST_STRPTR,
+    tags: *const TagItem,
+) -> BOOL {
+    let asm_ret_value: u32;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            44,
+            "MakeWorkbenchObjectVisibleA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-132, %a6)
+            ".short -132",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") name,
+            in("a1") tags,
+            out("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value as BOOL
+}
+
+/// libname: "workbench.library" (V47) (Kickstart 3.2 / 2020)
+#[cfg(not(feature = "kickstart-v46"))]
+pub unsafe fn WhichWorkbenchObjectA(
+    WorkbenchBase: *mut Library,
+    window: *mut Window,
+    x: LONG,
+    y: LONG,
+    tags: *const TagItem,
+) -> ULONG {
+    let asm_ret_value: ULONG;
+    #[cfg(all(feature = "version-check", debug_assertions))]
+    unsafe {
+        crate::check_library_version(
+            WorkbenchBase,
+            47,
+            "WhichWorkbenchObjectA",
+            "workbench.library",
+        )
+    }
+    unsafe {
+        asm!(
+            "movem.l %d1/%a0-%a1, -(%sp)",
+            "move.l %a6, -(%sp)",
+            "move.l {basereg}, %a6",
+            ".short 0x4eae", // jsr (-138, %a6)
+            ".short -138",
+            "move.l (%sp)+, %a6",
+            "movem.l (%sp)+, %d1/%a0-%a1",
+            basereg = in(reg) WorkbenchBase,
+            in("a0") window,
+            in("d0") x,
+            in("d1") y,
+            in("a1") tags,
+            lateout("d0") asm_ret_value,
+        );
+    }
+    asm_ret_value
+}
+
+/// An open "workbench.library"
+///
+/// The library is opened with `OpenLibrary()` and closed with `CloseLibrary()` when
+/// the handle is dropped. The library functions are available as methods.
+pub struct WorkbenchLib {
+    base: ::core::ptr::NonNull<Library>,
+}
+
+impl WorkbenchLib {
+    /// Library name passed to `OpenLibrary()`
+    pub const NAME: &'static ::core::ffi::CStr = c"workbench.library";
+
+    /// Opens the library
+    ///
+    /// Returns `None` if the library can't be opened or if its version is lower
+    /// than `min_version`.
+    pub fn open(min_version: ULONG) -> Option<Self> {
+        let base = unsafe {
+            crate::exec::OpenLibrary(
+                crate::abs_exec_library(),
+                Self::NAME.as_ptr() as CONST_STRPTR,
+                min_version,
+            )
+        };
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Takes the ownership of a library base opened with `OpenLibrary()`
+    ///
+    /// Returns `None` if `base` is null.
+    ///
+    /// # Safety
+    ///
+    /// `base` must be an open "workbench.library" that isn't closed elsewhere.
+    pub unsafe fn from_raw(base: *mut Library) -> Option<Self> {
+        ::core::ptr::NonNull::new(base).map(|base| Self { base })
+    }
+
+    /// Releases the ownership of the library base without closing it
+    pub fn into_raw(self) -> *mut Library {
+        let base = self.base.as_ptr();
+        ::core::mem::forget(self);
+        base
+    }
+
+    /// Returns the library base pointer
+    pub fn as_ptr(&self) -> *mut Library {
+        self.base.as_ptr()
+    }
+
+    /// Returns the library version (`lib_Version`)
+    pub fn version(&self) -> UWORD {
+        unsafe { (*self.base.as_ptr()).lib_Version }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn UpdateWorkbench(&self, name: CONST_STRPTR, lock: BPTR, action: LONG) {
+        unsafe { UpdateWorkbench(self.base.as_ptr(), name, lock, action) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddAppWindowA(
+        &self,
+        id: ULONG,
+        userdata: ULONG,
+        window: *mut Window,
+        msgport: *mut MsgPort,
+        taglist: *const TagItem,
+    ) -> *mut AppWindow {
+        unsafe { AddAppWindowA(self.base.as_ptr(), id, userdata, window, msgport, taglist) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemoveAppWindow(&self, appWindow: *mut AppWindow) -> BOOL {
+        unsafe { RemoveAppWindow(self.base.as_ptr(), appWindow) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddAppIconA(
+        &self,
+        id: ULONG,
+        userdata: ULONG,
+        text: CONST_STRPTR,
+        msgport: *mut MsgPort,
+        lock: BPTR,
+        diskobj: *mut DiskObject,
+        taglist: *const TagItem,
+    ) -> *mut AppIcon {
+        unsafe {
+            AddAppIconA(
+                self.base.as_ptr(),
+                id,
+                userdata,
+                text,
+                msgport,
+                lock,
+                diskobj,
+                taglist,
+            )
+        }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemoveAppIcon(&self, appIcon: *mut AppIcon) -> BOOL {
+        unsafe { RemoveAppIcon(self.base.as_ptr(), appIcon) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn AddAppMenuItemA(
+        &self,
+        id: ULONG,
+        userdata: ULONG,
+        text: CONST_STRPTR,
+        msgport: *mut MsgPort,
+        taglist: *const TagItem,
+    ) -> *mut AppMenuItem {
+        unsafe { AddAppMenuItemA(self.base.as_ptr(), id, userdata, text, msgport, taglist) }
+    }
+
+    /// libname: "workbench.library" (V36) (Kickstart 2.0)
+    #[cfg(not(feature = "kickstart-v34"))]
+    pub unsafe fn RemoveAppMenuItem(&self, appMenuItem: *mut AppMenuItem) -> BOOL {
+        unsafe { RemoveAppMenuItem(self.base.as_ptr(), appMenuItem) }
+    }
+
+    /// libname: "workbench.library" (V39) (Kickstart 3.0)
+    #[cfg(not(feature = "kickstart-v38"))]
+    pub unsafe fn WBInfo(&self, lock: BPTR, name: CONST_STRPTR, screen: *mut Screen) -> ULONG {
+        unsafe { WBInfo(self.base.as_ptr(), lock, name, screen) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn OpenWorkbenchObjectA(&self, name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { OpenWorkbenchObjectA(self.base.as_ptr(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn CloseWorkbenchObjectA(&self, name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { CloseWorkbenchObjectA(self.base.as_ptr(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn WorkbenchControlA(&self, name: CONST_STRPTR, tags: *const TagItem) -> BOOL {
+        unsafe { WorkbenchControlA(self.base.as_ptr(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn AddAppWindowDropZoneA(
+        &self,
+        aw: *mut AppWindow,
+        id: ULONG,
+        userdata: ULONG,
+        tags: *const TagItem,
+    ) -> *mut AppWindowDropZone {
+        unsafe { AddAppWindowDropZoneA(self.base.as_ptr(), aw, id, userdata, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn RemoveAppWindowDropZone(
+        &self,
+        aw: *mut AppWindow,
+        dropZone: *mut AppWindowDropZone,
+    ) -> BOOL {
+        unsafe { RemoveAppWindowDropZone(self.base.as_ptr(), aw, dropZone) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn ChangeWorkbenchSelectionA(
+        &self,
+        name: CONST_STRPTR,
+        hook: *mut Hook,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { ChangeWorkbenchSelectionA(self.base.as_ptr(), name, hook, tags) }
+    }
+
+    /// libname: "workbench.library" (V44) (Kickstart 3.5 / 1999)
+    #[cfg(not(feature = "kickstart-v40"))]
+    pub unsafe fn MakeWorkbenchObjectVisibleA(
+        &self,
+        name: CONST_STRPTR,
+        tags: *const TagItem,
+    ) -> BOOL {
+        unsafe { MakeWorkbenchObjectVisibleA(self.base.as_ptr(), name, tags) }
+    }
+
+    /// libname: "workbench.library" (V47) (Kickstart 3.2 / 2020)
+    #[cfg(not(feature = "kickstart-v46"))]
+    pub unsafe fn WhichWorkbenchObjectA(
+        &self,
+        window: *mut Window,
+        x: LONG,
+        y: LONG,
+        tags: *const TagItem,
+    ) -> ULONG {
+        unsafe { WhichWorkbenchObjectA(self.base.as_ptr(), window, x, y, tags) }
+    }
+}
+
+impl Drop for WorkbenchLib {
+    fn drop(&mut self) {
+        unsafe { crate::exec::CloseLibrary(crate::abs_exec_library(), self.base.as_ptr()) }
+    }
+}
+
+/// Library vector offset of `UpdateWorkbench()`
+pub const LVO_UpdateWorkbench: i16 = -30;
+/// Library vector offset of `AddAppWindowA()`
+pub const LVO_AddAppWindowA: i16 = -48;
+/// Library vector offset of `RemoveAppWindow()`
+pub const LVO_RemoveAppWindow: i16 = -54;
+/// Library vector offset of `AddAppIconA()`
+pub const LVO_AddAppIconA: i16 = -60;
+/// Library vector offset of `RemoveAppIcon()`
+pub const LVO_RemoveAppIcon: i16 = -66;
+/// Library vector offset of `AddAppMenuItemA()`
+pub const LVO_AddAppMenuItemA: i16 = -72;
+/// Library vector offset of `RemoveAppMenuItem()`
+pub const LVO_RemoveAppMenuItem: i16 = -78;
+/// Library vector offset of `WBInfo()`
+pub const LVO_WBInfo: i16 = -90;
+/// Library vector offset of `OpenWorkbenchObjectA()`
+pub const LVO_OpenWorkbenchObjectA: i16 = -96;
+/// Library vector offset of `CloseWorkbenchObjectA()`
+pub const LVO_CloseWorkbenchObjectA: i16 = -102;
+/// Library vector offset of `WorkbenchControlA()`
+pub const LVO_WorkbenchControlA: i16 = -108;
+/// Library vector offset of `AddAppWindowDropZoneA()`
+pub const LVO_AddAppWindowDropZoneA: i16 = -114;
+/// Library vector offset of `RemoveAppWindowDropZone()`
+pub const LVO_RemoveAppWindowDropZone: i16 = -120;
+/// Library vector offset of `ChangeWorkbenchSelectionA()`
+pub const LVO_ChangeWorkbenchSelectionA: i16 = -126;
+/// Library vector offset of `MakeWorkbenchObjectVisibleA()`
+pub const LVO_MakeWorkbenchObjectVisibleA: i16 = -132;
+/// Library vector offset of `WhichWorkbenchObjectA()`
+pub const LVO_WhichWorkbenchObjectA: i16 = -138;
+
+/// Functions of "workbench.library"
+pub static WORKBENCH_FUNCTIONS: &[crate::FunctionInfo] = &[
+    crate::FunctionInfo {
+        name: "UpdateWorkbench",
+        lvo: LVO_UpdateWorkbench,
+        registers: &["a0", "a1", "d0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddAppWindowA",
+        lvo: LVO_AddAppWindowA,
+        registers: &["d0", "d1", "a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemoveAppWindow",
+        lvo: LVO_RemoveAppWindow,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddAppIconA",
+        lvo: LVO_AddAppIconA,
+        registers: &["d0", "d1", "a0", "a1", "a2", "a3", "a4"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemoveAppIcon",
+        lvo: LVO_RemoveAppIcon,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "AddAppMenuItemA",
+        lvo: LVO_AddAppMenuItemA,
+        registers: &["d0", "d1", "a0", "a1", "a2"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "RemoveAppMenuItem",
+        lvo: LVO_RemoveAppMenuItem,
+        registers: &["a0"],
+        min_version: 36,
+    },
+    crate::FunctionInfo {
+        name: "WBInfo",
+        lvo: LVO_WBInfo,
+        registers: &["a0", "a1", "a2"],
+        min_version: 39,
+    },
+    crate::FunctionInfo {
+        name: "OpenWorkbenchObjectA",
+        lvo: LVO_OpenWorkbenchObjectA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "CloseWorkbenchObjectA",
+        lvo: LVO_CloseWorkbenchObjectA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "WorkbenchControlA",
+        lvo: LVO_WorkbenchControlA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "AddAppWindowDropZoneA",
+        lvo: LVO_AddAppWindowDropZoneA,
+        registers: &["a0", "d0", "d1", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "RemoveAppWindowDropZone",
+        lvo: LVO_RemoveAppWindowDropZone,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "ChangeWorkbenchSelectionA",
+        lvo: LVO_ChangeWorkbenchSelectionA,
+        registers: &["a0", "a1", "a2"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "MakeWorkbenchObjectVisibleA",
+        lvo: LVO_MakeWorkbenchObjectVisibleA,
+        registers: &["a0", "a1"],
+        min_version: 44,
+    },
+    crate::FunctionInfo {
+        name: "WhichWorkbenchObjectA",
+        lvo: LVO_WhichWorkbenchObjectA,
+        registers: &["a0", "d0", "d1", "a1"],
+        min_version: 47,
+    },
+];
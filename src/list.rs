@@ -0,0 +1,558 @@
+use crate::{List, MinList, MinNode, Node};
+use core::iter::FusedIterator;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// A struct which starts with a [`Node`] or a [`MinNode`], so that it can be linked in an
+/// exec list
+///
+/// `Node` is the node type: lists of [`List`] need nodes with a `Node` for `ln_Pri` and
+/// `ln_Name`, lists of [`MinList`] can have nodes of either type. Structs which have the node
+/// elsewhere can be converted from the node with [`container_of!`](crate::container_of).
+///
+/// # Safety
+///
+/// The struct must be `#[repr(C)]` and the first field must be a `Node`, which is `Node`
+/// or `MinNode`, or a struct starting with it, like `lib_Node` of `Library`.
+pub unsafe trait ListNode {
+    /// `Node` or `MinNode`
+    type Node;
+}
+
+macro_rules! impl_list_node {
+    ($node:ty: $($type:ty),*) => {
+        $(unsafe impl ListNode for $type {
+            type Node = $node;
+        })*
+    };
+}
+
+impl_list_node!(MinNode: MinNode, crate::Hook, crate::SemaphoreRequest);
+impl_list_node!(
+    Node: Node,
+    crate::Library,
+    crate::Device,
+    crate::ExecBase,
+    crate::Unit,
+    crate::Task,
+    crate::Process,
+    crate::MsgPort,
+    crate::Message,
+    crate::IORequest,
+    crate::IOStdReq,
+    crate::timerequest,
+    crate::IntuiMessage,
+    crate::Interrupt,
+    crate::MemHeader,
+    crate::MemList,
+    crate::SignalSemaphore,
+    crate::TextFont
+);
+
+/// Converts a pointer to a field of a struct to a pointer to the struct
+///
+/// Used for nodes which aren't the first field of the struct: the pointers of the list
+/// point to the node, not to the struct containing it. The type of the pointer must be the
+/// type of the field:
+///
+/// ```ignore
+/// #[repr(C)]
+/// struct Item {
+///     value: i32,
+///     node: MinNode,
+/// }
+///
+/// for node in unsafe { list.iter::<MinNode>() } {
+///     let item = unsafe { &*container_of!(node, Item, node) };
+/// }
+/// ```
+///
+/// Returns `*mut` of the struct. The conversion is safe, but using the pointer is safe only
+/// if the node is the field of a struct of the type.
+#[macro_export]
+macro_rules! container_of {
+    ($ptr:expr, $type:ty, $($field:tt)+) => {{
+        let ptr = $ptr as *const _;
+        if false {
+            // checks that the pointer has the type of the field
+            let _ = |container: &$type| ::core::ptr::eq(&container.$($field)+, ptr);
+        }
+        ptr.cast::<u8>()
+            .wrapping_sub(::core::mem::offset_of!($type, $($field)+))
+            .cast::<$type>()
+            .cast_mut()
+    }};
+}
+
+/// Iterator over the nodes of a [`List`] or [`MinList`]
+///
+/// Returned by [`List::iter()`] and [`MinList::iter()`]. The successor of a node is read
+/// before the node is returned.
+pub struct ListIter<'a, T> {
+    node: *mut MinNode,
+    list: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for ListIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        unsafe {
+            // the successor of the tail sentinel lh_Tail is always null
+            let next = (*self.node).mln_Succ;
+            if next.is_null() {
+                return None;
+            }
+            let node = self.node as *const T;
+            self.node = next;
+            Some(&*node)
+        }
+    }
+}
+
+impl<T> FusedIterator for ListIter<'_, T> {}
+
+/// A [`List`] whose nodes are borrowed `&'a mut T`, with safe methods
+///
+/// Returned by [`List::typed()`]. The list and the nodes are borrowed for `'a`, so they
+/// can't move or be dropped while they are linked, and removing a node returns the borrow:
+///
+/// ```ignore
+/// let mut nodes = [node_a, node_b];
+/// let mut list = list.typed::<Node>();
+/// for node in &mut nodes {
+///     list.enqueue(node);
+/// }
+/// while let Some(node) = list.rem_head() {
+///     node.ln_Pri = 0;
+/// }
+/// ```
+///
+/// The list is made empty when the `TypedList` is dropped. The unsafe methods of `List`,
+/// which take raw pointers, can be used for lists shared with the system.
+pub struct TypedList<'a, T: ListNode<Node = Node>> {
+    list: &'a mut List,
+    nodes: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ListNode<Node = Node>> TypedList<'a, T> {
+    /// Returns `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns an iterator over the nodes, from the head to the tail
+    pub fn iter(&self) -> ListIter<'_, T> {
+        unsafe { self.list.iter() }
+    }
+
+    /// Adds the node to the head of the list, like `AddHead()`
+    pub fn add_head(&mut self, node: &'a mut T) {
+        unsafe { self.list.add_head(node as *mut T) }
+    }
+
+    /// Adds the node to the tail of the list, like `AddTail()`
+    pub fn add_tail(&mut self, node: &'a mut T) {
+        unsafe { self.list.add_tail(node as *mut T) }
+    }
+
+    /// Adds the node before the first node with a lower `ln_Pri`, like `Enqueue()`
+    pub fn enqueue(&mut self, node: &'a mut T) {
+        unsafe { self.list.enqueue(node as *mut T) }
+    }
+
+    /// Removes the node at the head of the list, like `RemHead()`
+    pub fn rem_head(&mut self) -> Option<&'a mut T> {
+        unsafe { self.list.rem_head().map(|mut node| node.as_mut()) }
+    }
+
+    /// Removes the node at the tail of the list, like `RemTail()`
+    pub fn rem_tail(&mut self) -> Option<&'a mut T> {
+        unsafe { self.list.rem_tail().map(|mut node| node.as_mut()) }
+    }
+
+    /// Removes the node from the list, like `Remove()`
+    ///
+    /// Returns `None` if the node isn't in this list.
+    pub fn remove(&mut self, node: *const T) -> Option<&'a mut T> {
+        let found = self.iter().any(|n| core::ptr::eq(n, node));
+        unsafe {
+            found.then(|| {
+                let node = node.cast_mut();
+                unlink(node as *mut MinNode);
+                &mut *node
+            })
+        }
+    }
+}
+
+impl<T: ListNode<Node = Node>> Drop for TypedList<'_, T> {
+    fn drop(&mut self) {
+        self.list.init();
+    }
+}
+
+/// A [`MinList`] whose nodes are borrowed `&'a mut T`, with safe methods
+///
+/// Returned by [`MinList::typed()`], see [`TypedList`].
+pub struct TypedMinList<'a, T: ListNode> {
+    list: &'a mut MinList,
+    nodes: PhantomData<&'a mut T>,
+}
+
+impl<'a, T: ListNode> TypedMinList<'a, T> {
+    /// Returns `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    /// Returns an iterator over the nodes, from the head to the tail
+    pub fn iter(&self) -> ListIter<'_, T> {
+        unsafe { self.list.iter() }
+    }
+
+    /// Adds the node to the head of the list
+    pub fn add_head(&mut self, node: &'a mut T) {
+        unsafe { self.list.add_head(node as *mut T) }
+    }
+
+    /// Adds the node to the tail of the list
+    pub fn add_tail(&mut self, node: &'a mut T) {
+        unsafe { self.list.add_tail(node as *mut T) }
+    }
+
+    /// Removes the node at the head of the list
+    pub fn rem_head(&mut self) -> Option<&'a mut T> {
+        unsafe { self.list.rem_head().map(|mut node| node.as_mut()) }
+    }
+
+    /// Removes the node at the tail of the list
+    pub fn rem_tail(&mut self) -> Option<&'a mut T> {
+        unsafe { self.list.rem_tail().map(|mut node| node.as_mut()) }
+    }
+
+    /// Removes the node from the list
+    ///
+    /// Returns `None` if the node isn't in this list.
+    pub fn remove(&mut self, node: *const T) -> Option<&'a mut T> {
+        let found = self.iter().any(|n| core::ptr::eq(n, node));
+        unsafe {
+            found.then(|| {
+                let node = node.cast_mut();
+                unlink(node as *mut MinNode);
+                &mut *node
+            })
+        }
+    }
+}
+
+impl<T: ListNode> Drop for TypedMinList<'_, T> {
+    fn drop(&mut self) {
+        self.list.init();
+    }
+}
+
+/// Initializes a list to be empty, like `NewList()` of amiga.lib
+///
+/// See [`List::init()`].
+///
+/// # Safety
+///
+/// The list must be valid for writes.
+pub unsafe fn NewList(list: *mut List) {
+    unsafe { (*list).init() }
+}
+
+/// Links the node between the predecessor and the successor
+unsafe fn insert_between(node: *mut MinNode, pred: *mut MinNode, succ: *mut MinNode) {
+    unsafe {
+        (*node).mln_Pred = pred;
+        (*node).mln_Succ = succ;
+        (*pred).mln_Succ = node;
+        (*succ).mln_Pred = node;
+    }
+}
+
+/// Unlinks the node from its list
+unsafe fn unlink(node: *mut MinNode) {
+    unsafe {
+        let pred = (*node).mln_Pred;
+        let succ = (*node).mln_Succ;
+        (*pred).mln_Succ = succ;
+        (*succ).mln_Pred = pred;
+    }
+}
+
+// The functions below are shared by List and MinList, whose first fields have the same
+// layout: Head, Tail (always null) and TailPred. Head and Tail form the head sentinel node,
+// Tail and TailPred the tail sentinel node.
+
+/// Makes the list empty
+unsafe fn init_header(list: *mut MinList) {
+    unsafe {
+        (*list).mlh_Head = &raw mut (*list).mlh_Tail as *mut MinNode;
+        (*list).mlh_Tail = core::ptr::null_mut();
+        (*list).mlh_TailPred = list as *mut MinNode;
+    }
+}
+
+/// Returns `true` if the list has no nodes
+unsafe fn is_empty_header(list: *const MinList) -> bool {
+    unsafe { (*list).mlh_TailPred as *const MinList == list }
+}
+
+/// Links the node after the head sentinel
+unsafe fn add_head_header(list: *mut MinList, node: *mut MinNode) {
+    unsafe { insert_between(node, list as *mut MinNode, (*list).mlh_Head) }
+}
+
+/// Links the node before the tail sentinel
+unsafe fn add_tail_header(list: *mut MinList, node: *mut MinNode) {
+    unsafe {
+        let tail = &raw mut (*list).mlh_Tail as *mut MinNode;
+        insert_between(node, (*list).mlh_TailPred, tail)
+    }
+}
+
+/// Unlinks the first node
+unsafe fn rem_head_header(list: *mut MinList) -> Option<NonNull<MinNode>> {
+    unsafe {
+        if is_empty_header(list) {
+            return None;
+        }
+        let node = (*list).mlh_Head;
+        unlink(node);
+        NonNull::new(node)
+    }
+}
+
+/// Unlinks the last node
+unsafe fn rem_tail_header(list: *mut MinList) -> Option<NonNull<MinNode>> {
+    unsafe {
+        if is_empty_header(list) {
+            return None;
+        }
+        let node = (*list).mlh_TailPred;
+        unlink(node);
+        NonNull::new(node)
+    }
+}
+
+impl List {
+    /// Initializes the list to be empty, like `NewList()` of amiga.lib
+    ///
+    /// The head and the tail of the list point into the list header, so the list must not be
+    /// moved or copied after it has been initialized.
+    pub fn init(&mut self) {
+        unsafe { init_header(self as *mut List as *mut MinList) }
+    }
+
+    /// Returns `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        unsafe { is_empty_header(self as *const List as *const MinList) }
+    }
+
+    /// Initializes the list to be empty and returns a [`TypedList`] with safe methods for
+    /// nodes of type `T`
+    pub fn typed<T: ListNode<Node = Node>>(&mut self) -> TypedList<'_, T> {
+        self.init();
+        TypedList {
+            list: self,
+            nodes: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the nodes, from the head to the tail
+    ///
+    /// Lists of the system, like `ExecBase.LibList`, must be protected with `Forbid()` or
+    /// with their semaphore while they are iterated:
+    ///
+    /// ```ignore
+    /// let exec_base = exec as *mut ExecBase;
+    /// Forbid(exec);
+    /// for library in unsafe { (*exec_base).LibList.iter::<Library>() } {
+    ///     let name = unsafe { CStr::from_ptr(library.lib_Node.ln_Name) };
+    /// }
+    /// Permit(exec);
+    /// ```
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized, the nodes must be of type `T` and the list must
+    /// not be changed while the iterator is used.
+    pub unsafe fn iter<T: ListNode<Node = Node>>(&self) -> ListIter<'_, T> {
+        ListIter {
+            node: self.lh_Head as *mut MinNode,
+            list: PhantomData,
+        }
+    }
+
+    /// Adds the node to the head of the list, like `AddHead()`
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized. The node must not be in a list and it must stay
+    /// valid and not move while it is in the list.
+    pub unsafe fn add_head<T: ListNode<Node = Node>>(&mut self, node: *mut T) {
+        unsafe { add_head_header(self as *mut List as *mut MinList, node as *mut MinNode) }
+    }
+
+    /// Adds the node to the tail of the list, like `AddTail()`
+    ///
+    /// # Safety
+    ///
+    /// See [`List::add_head()`].
+    pub unsafe fn add_tail<T: ListNode<Node = Node>>(&mut self, node: *mut T) {
+        unsafe { add_tail_header(self as *mut List as *mut MinList, node as *mut MinNode) }
+    }
+
+    /// Adds the node before the first node with a lower `ln_Pri`, like `Enqueue()`
+    ///
+    /// Nodes with the same priority are kept in the order in which they were added.
+    ///
+    /// # Safety
+    ///
+    /// See [`List::add_head()`].
+    pub unsafe fn enqueue<T: ListNode<Node = Node>>(&mut self, node: *mut T) {
+        unsafe {
+            let node = node as *mut Node;
+            let mut next = self.lh_Head;
+            // stops at the tail sentinel, which has a null successor
+            while !(*next).ln_Succ.is_null() && (*next).ln_Pri >= (*node).ln_Pri {
+                next = (*next).ln_Succ;
+            }
+            insert_between(
+                node as *mut MinNode,
+                (*next).ln_Pred as *mut MinNode,
+                next as *mut MinNode,
+            )
+        }
+    }
+
+    /// Removes the node at the head of the list, like `RemHead()`
+    ///
+    /// Returns `None` if the list is empty.
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized and the nodes must be of type `T`.
+    pub unsafe fn rem_head<T: ListNode<Node = Node>>(&mut self) -> Option<NonNull<T>> {
+        unsafe { rem_head_header(self as *mut List as *mut MinList).map(NonNull::cast) }
+    }
+
+    /// Removes the node at the tail of the list, like `RemTail()`
+    ///
+    /// Returns `None` if the list is empty.
+    ///
+    /// # Safety
+    ///
+    /// See [`List::rem_head()`].
+    pub unsafe fn rem_tail<T: ListNode<Node = Node>>(&mut self) -> Option<NonNull<T>> {
+        unsafe { rem_tail_header(self as *mut List as *mut MinList).map(NonNull::cast) }
+    }
+}
+
+impl MinList {
+    /// Initializes the list to be empty, like `NewList()` of amiga.lib
+    ///
+    /// The head and the tail of the list point into the list header, so the list must not be
+    /// moved or copied after it has been initialized.
+    pub fn init(&mut self) {
+        unsafe { init_header(self) }
+    }
+
+    /// Returns `true` if the list has no nodes
+    pub fn is_empty(&self) -> bool {
+        unsafe { is_empty_header(self) }
+    }
+
+    /// Initializes the list to be empty and returns a [`TypedMinList`] with safe methods for
+    /// nodes of type `T`
+    pub fn typed<T: ListNode>(&mut self) -> TypedMinList<'_, T> {
+        self.init();
+        TypedMinList {
+            list: self,
+            nodes: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the nodes, from the head to the tail
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized, the nodes must be of type `T` and the list must
+    /// not be changed while the iterator is used.
+    pub unsafe fn iter<T: ListNode>(&self) -> ListIter<'_, T> {
+        ListIter {
+            node: self.mlh_Head,
+            list: PhantomData,
+        }
+    }
+
+    /// Adds the node to the head of the list
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized. The node must not be in a list and it must stay
+    /// valid and not move while it is in the list.
+    pub unsafe fn add_head<T: ListNode>(&mut self, node: *mut T) {
+        unsafe { add_head_header(self, node as *mut MinNode) }
+    }
+
+    /// Adds the node to the tail of the list
+    ///
+    /// # Safety
+    ///
+    /// See [`MinList::add_head()`].
+    pub unsafe fn add_tail<T: ListNode>(&mut self, node: *mut T) {
+        unsafe { add_tail_header(self, node as *mut MinNode) }
+    }
+
+    /// Removes the node at the head of the list
+    ///
+    /// Returns `None` if the list is empty.
+    ///
+    /// # Safety
+    ///
+    /// The list must have been initialized and the nodes must be of type `T`.
+    pub unsafe fn rem_head<T: ListNode>(&mut self) -> Option<NonNull<T>> {
+        unsafe { rem_head_header(self).map(NonNull::cast) }
+    }
+
+    /// Removes the node at the tail of the list
+    ///
+    /// Returns `None` if the list is empty.
+    ///
+    /// # Safety
+    ///
+    /// See [`MinList::rem_head()`].
+    pub unsafe fn rem_tail<T: ListNode>(&mut self) -> Option<NonNull<T>> {
+        unsafe { rem_tail_header(self).map(NonNull::cast) }
+    }
+}
+
+impl Node {
+    /// Removes the node from its list, like `Remove()`
+    ///
+    /// The list isn't needed: the node is unlinked from its predecessor and successor.
+    ///
+    /// # Safety
+    ///
+    /// The node must be in a list.
+    pub unsafe fn remove(&mut self) {
+        unsafe { unlink(self as *mut Node as *mut MinNode) }
+    }
+}
+
+impl MinNode {
+    /// Removes the node from its list
+    ///
+    /// The list isn't needed: the node is unlinked from its predecessor and successor.
+    ///
+    /// # Safety
+    ///
+    /// The node must be in a list.
+    pub unsafe fn remove(&mut self) {
+        unsafe { unlink(self) }
+    }
+}
@@ -0,0 +1,267 @@
+use crate::{
+    APF_DIDDIR, APF_DODIR, APTR, AnchorPath, BYTE, DirEntry, DosError, DosLib, LONG, MEMF_ANY,
+    MEMF_CLEAR, SIGBREAKF_CTRL_C, ULONG, WORD,
+};
+use core::ffi::CStr;
+use core::ptr::NonNull;
+
+/// Size of the full path buffer after the `AnchorPath`
+const PATH_BUFFER_SIZE: usize = 256;
+
+/// A file or a directory matching the pattern, returned by [`Matches`]
+#[derive(Debug, Clone)]
+pub struct PatternMatch {
+    path: [u8; PATH_BUFFER_SIZE],
+    path_len: usize,
+    entry: DirEntry,
+}
+
+impl PatternMatch {
+    /// Returns the full path of the file, for example `sys:prefs/env-archive/sys/wbconfig.prefs`
+    ///
+    /// The path starts with the directory part of the pattern, so that it's relative to the
+    /// current directory if the pattern is.
+    pub fn path(&self) -> &[u8] {
+        &self.path[..self.path_len]
+    }
+
+    /// Returns the name, size, protection bits, etc. from the `FileInfoBlock`
+    pub fn entry(&self) -> &DirEntry {
+        &self.entry
+    }
+}
+
+/// Iterator over the files and directories matching an AmigaDOS pattern (V36)
+///
+/// The pattern is expanded with `MatchFirst()` and `MatchNext()`, which support the
+/// wildcards of the last path component: `#?.info`, `(a|b)`, `~(x)`, etc. With
+/// [`recursive()`](Matches::recursive), every subdirectory is entered with `APF_DODIR`
+/// and its matching entries follow the directory:
+///
+/// ```ignore
+/// for found in Matches::new(&dos, c"work:#?.txt").recursive(true) {
+///     let found = found?;
+///     writeln!(out, "{}", found.path().escape_ascii())?;
+/// }
+/// ```
+///
+/// The search stops with `DosError::Break` when the user presses Ctrl-C, see
+/// [`break_signals()`](Matches::break_signals). The `AnchorPath` is allocated with
+/// `AllocMem()`, which aligns its `FileInfoBlock`, and it's freed with `MatchEnd()` when
+/// the iterator is dropped.
+pub struct Matches<'d> {
+    dos: &'d DosLib,
+    pattern: &'d CStr,
+    anchor: Option<NonNull<AnchorPath>>,
+    /// Size of the memory of `anchor`, which also holds the patterns of a recursive search
+    size: usize,
+    /// Parsed pattern of the names in a recursive search, null if all names match
+    name_tokens: *const u8,
+    recursive: bool,
+    break_signals: ULONG,
+    /// The last returned entry is a directory which is entered by `MatchNext()`
+    enter_dir: bool,
+    done: bool,
+}
+
+impl<'d> Matches<'d> {
+    /// Creates an iterator over the matches of the pattern
+    ///
+    /// The pattern is expanded when the first match is requested. Without wildcards, the
+    /// iterator returns the file or the directory of the path, if it exists.
+    pub fn new(dos: &'d DosLib, pattern: &'d CStr) -> Self {
+        Self {
+            dos,
+            pattern,
+            anchor: None,
+            size: 0,
+            name_tokens: core::ptr::null(),
+            recursive: false,
+            break_signals: SIGBREAKF_CTRL_C,
+            enter_dir: false,
+            done: false,
+        }
+    }
+
+    /// Enters all the subdirectories and returns their entries matching the last component
+    /// of the pattern
+    ///
+    /// The subdirectories are entered even if their names don't match, so `work:#?.txt`
+    /// returns the `.txt` files of `work:` and of all its subdirectories. An empty last
+    /// component, like in `work:`, matches all the entries.
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = recursive;
+        self
+    }
+
+    /// Sets the break signals which stop the search with `DosError::Break` (`ap_BreakBits`)
+    ///
+    /// The default is `SIGBREAKF_CTRL_C`. 0 doesn't check the signals.
+    pub fn break_signals(mut self, signals: ULONG) -> Self {
+        self.break_signals = signals;
+        self
+    }
+
+    /// Calls `MatchFirst()`, or `MatchNext()` after the first match
+    fn match_next(&mut self) -> Result<NonNull<AnchorPath>, DosError> {
+        unsafe {
+            if let Some(anchor) = self.anchor {
+                let flags = &mut (*anchor.as_ptr()).ap_Flags;
+                if self.enter_dir {
+                    *flags |= APF_DODIR as BYTE;
+                } else {
+                    *flags &= !(APF_DODIR as BYTE);
+                }
+                return match self.dos.MatchNext(anchor.as_ptr()) {
+                    0 => Ok(anchor),
+                    error => Err(DosError::from_code(error)),
+                };
+            }
+            let (anchor, pattern) = self.alloc_anchor()?;
+            match self.dos.MatchFirst(pattern as _, anchor.as_ptr()) {
+                0 => Ok(anchor),
+                error => Err(DosError::from_code(error)),
+            }
+        }
+    }
+
+    /// Allocates the `AnchorPath` and returns the pattern for `MatchFirst()`
+    ///
+    /// A recursive search matches all the names with `#?` and the last component of the
+    /// pattern is checked by `is_name_match()`, so that the directories which don't match
+    /// are entered, too.
+    fn alloc_anchor(&mut self) -> Result<(NonNull<AnchorPath>, *const u8), DosError> {
+        let pattern = self.pattern.to_bytes();
+        let anchor_size = size_of::<AnchorPath>() + PATH_BUFFER_SIZE;
+        let name_start = pattern
+            .iter()
+            .rposition(|&c| c == b'/' || c == b':')
+            .map_or(0, |i| i + 1);
+        let name = &pattern[name_start..];
+        // search pattern (directory part + "#?"), name pattern and the tokens of ParsePattern()
+        let search_size = name_start + 3;
+        let tokens_size = 2 * name.len() + 2;
+        self.size = if self.recursive {
+            anchor_size + search_size + name.len() + 1 + tokens_size
+        } else {
+            anchor_size
+        };
+        unsafe {
+            let exec = crate::abs_exec_library();
+            let memory = crate::AllocMem(exec, self.size as ULONG, MEMF_ANY | MEMF_CLEAR);
+            let anchor = NonNull::new(memory as *mut AnchorPath).ok_or(DosError::NoFreeStore)?;
+            (*anchor.as_ptr()).ap_BreakBits = self.break_signals as _;
+            (*anchor.as_ptr()).ap_Strlen = PATH_BUFFER_SIZE as WORD;
+            self.anchor = Some(anchor);
+            if !self.recursive {
+                return Ok((anchor, pattern.as_ptr()));
+            }
+
+            // the memory is cleared, so the strings are null-terminated
+            let search = (memory as *mut u8).add(anchor_size);
+            search.copy_from_nonoverlapping(pattern.as_ptr(), name_start);
+            search
+                .add(name_start)
+                .copy_from_nonoverlapping(b"#?".as_ptr(), 2);
+            if !name.is_empty() {
+                let name_pattern = search.add(search_size);
+                name_pattern.copy_from_nonoverlapping(name.as_ptr(), name.len());
+                let tokens = name_pattern.add(name.len() + 1);
+                if self.parse_pattern(name_pattern, tokens, tokens_size as LONG) < 0 {
+                    return Err(DosError::BadTemplate);
+                }
+                self.name_tokens = tokens;
+            }
+            Ok((anchor, search))
+        }
+    }
+
+    /// Parses the pattern with `ParsePatternNoCase()` (V37) or with `ParsePattern()`
+    unsafe fn parse_pattern(&self, pattern: *const u8, tokens: *mut u8, size: LONG) -> LONG {
+        unsafe {
+            #[cfg(not(feature = "kickstart-v36"))]
+            if self.dos.version() >= 37 {
+                return self.dos.ParsePatternNoCase(pattern, tokens, size);
+            }
+            self.dos.ParsePattern(pattern, tokens, size)
+        }
+    }
+
+    /// Checks the name with the last component of the pattern in a recursive search
+    unsafe fn is_name_match(&self, name: *const u8) -> bool {
+        if self.name_tokens.is_null() {
+            return true;
+        }
+        unsafe {
+            #[cfg(not(feature = "kickstart-v36"))]
+            if self.dos.version() >= 37 {
+                return self.dos.MatchPatternNoCase(self.name_tokens, name) != 0;
+            }
+            self.dos.MatchPattern(self.name_tokens, name) != 0
+        }
+    }
+}
+
+impl Iterator for Matches<'_> {
+    type Item = Result<PatternMatch, DosError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        loop {
+            let anchor = match self.match_next() {
+                Ok(anchor) => anchor,
+                Err(error) => {
+                    self.done = true;
+                    return match error {
+                        DosError::NoMoreEntries => None,
+                        error => Some(Err(error)),
+                    };
+                }
+            };
+            unsafe {
+                let anchor = anchor.as_ptr();
+                if (*anchor).ap_Flags & APF_DIDDIR as BYTE != 0 {
+                    // MatchNext() has left the directory, which has already been returned
+                    (*anchor).ap_Flags &= !(APF_DIDDIR as BYTE);
+                    self.enter_dir = false;
+                    continue;
+                }
+                let entry = DirEntry::from_fib(&(*anchor).ap_Info);
+                self.enter_dir = self.recursive && entry.is_dir();
+                if !self.is_name_match((*anchor).ap_Info.fib_FileName.as_ptr()) {
+                    continue;
+                }
+
+                // a path which doesn't fit has no null terminator in the buffer
+                let buffer = core::ptr::addr_of!((*anchor).ap_Buf) as *const u8;
+                let buffer = core::slice::from_raw_parts(buffer, PATH_BUFFER_SIZE);
+                let Ok(full_path) = CStr::from_bytes_until_nul(buffer) else {
+                    return Some(Err(DosError::BufferOverflow));
+                };
+                let full_path = full_path.to_bytes();
+                let mut path = [0; PATH_BUFFER_SIZE];
+                path[..full_path.len()].copy_from_slice(full_path);
+                return Some(Ok(PatternMatch {
+                    path,
+                    path_len: full_path.len(),
+                    entry,
+                }));
+            }
+        }
+    }
+}
+
+impl Drop for Matches<'_> {
+    fn drop(&mut self) {
+        if let Some(anchor) = self.anchor {
+            unsafe {
+                // MatchEnd() is also needed after a failed MatchFirst()
+                self.dos.MatchEnd(anchor.as_ptr());
+                let exec = crate::abs_exec_library();
+                crate::FreeMem(exec, anchor.as_ptr() as APTR, self.size as ULONG);
+            }
+        }
+    }
+}
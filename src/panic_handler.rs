@@ -0,0 +1,131 @@
+use crate::{AN_Unknown, RETURN_FAIL};
+use core::ffi::CStr;
+use core::fmt::Write;
+use core::panic::PanicInfo;
+
+/// Set when a panic is reported, so that a panic while reporting doesn't recurse
+static mut PANICKING: bool = false;
+
+/// Null-terminated message buffer which truncates the message when full
+struct MessageBuffer {
+    buffer: [u8; 256],
+    len: usize,
+}
+
+impl MessageBuffer {
+    /// Returns the message, which ends at the first null byte
+    fn as_c_str(&self) -> &CStr {
+        CStr::from_bytes_until_nul(&self.buffer).unwrap_or_default()
+    }
+}
+
+impl Write for MessageBuffer {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        // keep the last byte for the null terminator
+        let count = s.len().min(self.buffer.len() - 1 - self.len);
+        self.buffer[self.len..self.len + count].copy_from_slice(&s.as_bytes()[..count]);
+        self.len += count;
+        Ok(())
+    }
+}
+
+/// Panic handler of the `panic-handler` feature
+///
+/// Writes the panic message and location to the CLI output. Without a CLI output, shows the
+/// message in a requester (V36) or shows a recoverable alert. Then exits the program with
+/// `RETURN_FAIL`, as if `main()` had returned.
+#[panic_handler]
+fn panic(info: &PanicInfo) -> ! {
+    unsafe {
+        if !PANICKING {
+            PANICKING = true;
+            let mut message = MessageBuffer {
+                buffer: [0; 256],
+                len: 0,
+            };
+            let _ = write!(message, "panic: {}", info.message());
+            if let Some(location) = info.location() {
+                let _ = write!(
+                    message,
+                    "\nat {}:{}:{}",
+                    location.file(),
+                    location.line(),
+                    location.column()
+                );
+            }
+            report(message.as_c_str());
+        }
+        crate::exit(RETURN_FAIL as i32)
+    }
+}
+
+/// Called by the compiler's runtime functions on fatal errors
+#[unsafe(no_mangle)]
+pub extern "C" fn abort() -> ! {
+    unsafe {
+        if !PANICKING {
+            PANICKING = true;
+            report(c"abort");
+        }
+        crate::exit(RETURN_FAIL as i32)
+    }
+}
+
+/// Shows the message to the user
+unsafe fn report(message: &CStr) {
+    unsafe {
+        let exec = crate::abs_exec_library();
+        let dos = crate::OpenLibrary(exec, c"dos.library".as_ptr() as _, 0);
+        if !dos.is_null() {
+            let out = crate::Output(dos);
+            if out != 0 {
+                let message = message.to_bytes();
+                crate::Write(dos, out, message.as_ptr() as _, message.len() as _);
+                crate::Write(dos, out, c"\n".as_ptr() as _, 1);
+                crate::CloseLibrary(exec, dos);
+                return;
+            }
+            crate::CloseLibrary(exec, dos);
+        }
+        if !show_requester(message) {
+            crate::Alert(exec, AN_Unknown);
+        }
+    }
+}
+
+/// Shows the message in an `EasyRequestArgs()` requester
+#[cfg(not(feature = "kickstart-v34"))]
+unsafe fn show_requester(message: &CStr) -> bool {
+    unsafe {
+        let exec = crate::abs_exec_library();
+        let intuition: *mut crate::Library =
+            crate::OpenLibrary(exec, c"intuition.library".as_ptr() as _, 36);
+        if intuition.is_null() {
+            return false;
+        }
+        let easy = crate::EasyStruct {
+            es_StructSize: core::mem::size_of::<crate::EasyStruct>() as _,
+            es_Flags: 0,
+            es_Title: c"Program failed".as_ptr() as _,
+            // the message is an argument, so that '%' in the message isn't a format
+            es_TextFormat: c"%s".as_ptr() as _,
+            es_GadgetFormat: c"OK".as_ptr() as _,
+        };
+        let args = [message.as_ptr()];
+        crate::EasyRequestArgs(
+            intuition,
+            core::ptr::null_mut(),
+            &easy,
+            core::ptr::null_mut(),
+            args.as_ptr() as _,
+        );
+        crate::CloseLibrary(exec, intuition);
+        true
+    }
+}
+
+/// Requesters need intuition.library V36
+#[cfg(feature = "kickstart-v34")]
+unsafe fn show_requester(_message: &CStr) -> bool {
+    false
+}
@@ -0,0 +1,126 @@
+use crate::{APTR, ULONG};
+use core::alloc::{AllocError, Allocator, Layout};
+use core::ptr::NonNull;
+
+/// Alignment of the memory returned by `AllocPooled()`
+const ALLOC_POOLED_ALIGN: usize = 4;
+
+/// A memory pool created with `CreatePool()` (V39), deleted with `DeletePool()` when dropped
+///
+/// Deleting the pool frees all the memory allocated from it at once. Values allocated with
+/// [`Pool::alloc()`] borrow the pool, and the pool implements [`Allocator`], so that
+/// collections can be allocated from it:
+///
+/// ```ignore
+/// #![feature(allocator_api)]
+/// extern crate alloc;
+///
+/// let pool = Pool::new(MEMF_ANY, 4096, 1024).unwrap();
+/// let mut numbers = alloc::vec::Vec::new_in(&pool);
+/// numbers.push(1);
+/// let point = pool.alloc(Point { x: 1, y: 2 }).unwrap();
+/// ```
+///
+/// A pool can't be used by several tasks at the same time.
+pub struct Pool {
+    header: NonNull<core::ffi::c_void>,
+}
+
+impl Pool {
+    /// Creates a pool with `CreatePool()`
+    ///
+    /// The memory is allocated with the memory flags in puddles of `puddle_size` bytes.
+    /// Allocations larger than `threshold` get their own puddle. Returns `None` if the pool
+    /// can't be created.
+    pub fn new(flags: ULONG, puddle_size: ULONG, threshold: ULONG) -> Option<Self> {
+        let header =
+            unsafe { crate::CreatePool(crate::abs_exec_library(), flags, puddle_size, threshold) };
+        NonNull::new(header).map(|header| Self { header })
+    }
+
+    /// Returns the pool header, which can be passed to `AllocPooled()` and `FreePooled()`
+    pub fn as_ptr(&self) -> APTR {
+        self.header.as_ptr()
+    }
+
+    /// Moves the value to memory allocated from the pool
+    ///
+    /// The memory is freed when the pool is dropped. The value isn't dropped. Returns the
+    /// value back if there isn't enough memory.
+    #[allow(clippy::mut_from_ref)] // each call returns a different value
+    pub fn alloc<T>(&self, value: T) -> Result<&mut T, T> {
+        match self.allocate(Layout::new::<T>()) {
+            Ok(memory) => {
+                let memory = memory.as_ptr() as *mut T;
+                unsafe {
+                    memory.write(value);
+                    Ok(&mut *memory)
+                }
+            }
+            Err(AllocError) => Err(value),
+        }
+    }
+
+    /// Returns the size to allocate for the layout
+    fn pooled_size(layout: Layout) -> usize {
+        if layout.align() <= ALLOC_POOLED_ALIGN {
+            layout.size()
+        } else {
+            // room for aligning and for the pointer to the allocated memory
+            layout.size() + layout.align()
+        }
+    }
+}
+
+unsafe impl Allocator for Pool {
+    fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+        if layout.size() == 0 {
+            let dangling = layout.align() as *mut u8;
+            return Ok(NonNull::slice_from_raw_parts(
+                NonNull::new(dangling).ok_or(AllocError)?,
+                0,
+            ));
+        }
+        let size = ULONG::try_from(Self::pooled_size(layout)).map_err(|_| AllocError)?;
+        let memory = unsafe { crate::AllocPooled(crate::abs_exec_library(), self.as_ptr(), size) }
+            as *mut u8;
+        let mut memory = NonNull::new(memory).ok_or(AllocError)?;
+        if layout.align() > ALLOC_POOLED_ALIGN {
+            // align up and leave at least one pointer before the aligned memory
+            let address = (memory.as_ptr() as usize + layout.align()) & !(layout.align() - 1);
+            let aligned = address as *mut u8;
+            unsafe {
+                (aligned as *mut *mut u8)
+                    .sub(1)
+                    .write_unaligned(memory.as_ptr())
+            };
+            memory = unsafe { NonNull::new_unchecked(aligned) };
+        }
+        Ok(NonNull::slice_from_raw_parts(memory, layout.size()))
+    }
+
+    unsafe fn deallocate(&self, ptr: NonNull<u8>, layout: Layout) {
+        if layout.size() == 0 {
+            return;
+        }
+        let memory = if layout.align() <= ALLOC_POOLED_ALIGN {
+            ptr.as_ptr()
+        } else {
+            unsafe { (ptr.as_ptr() as *mut *mut u8).sub(1).read_unaligned() }
+        };
+        unsafe {
+            crate::FreePooled(
+                crate::abs_exec_library(),
+                self.as_ptr(),
+                memory as APTR,
+                Self::pooled_size(layout) as ULONG,
+            )
+        }
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        unsafe { crate::DeletePool(crate::abs_exec_library(), self.as_ptr()) }
+    }
+}
@@ -0,0 +1,392 @@
+use crate::{CSource, DOS_RDARGS, DosError, DosLib, LONG, RDArgs, STRPTR};
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// Declares a struct for the arguments of a CLI command, parsed with `ReadArgs()`
+///
+/// Each field has a `ReadArgs()` template item and its type must match the item's modifiers:
+///
+/// | Modifier         | Field type              |
+/// |------------------|-------------------------|
+/// | `/S`, `/T`       | `bool`                  |
+/// | `/N`             | `Option<i32>`           |
+/// | none, `/K`, `/F` | `Option<&'a [u8]>`      |
+/// | `/M`             | [`MultiArg<'a>`]        |
+/// | `/M/N`           | [`MultiNumberArg<'a>`]  |
+///
+/// A mismatch is a compile error when the arguments are read. The template is the items joined
+/// with commas, so that the order of the fields is the order of the positional arguments:
+///
+/// ```ignore
+/// read_args! {
+///     /// Arguments of the command: FROM/M/A,TO/A,ALL/S,DEPTH/K/N
+///     struct Args<'a> {
+///         from: MultiArg<'a> = "FROM/M/A",
+///         to: Option<&'a [u8]> = "TO/A",
+///         all: bool = "ALL/S",
+///         depth: Option<i32> = "DEPTH/K/N",
+///     }
+/// }
+///
+/// let args = Args::read(&dos).map_err(|error| ...)?;
+/// for name in args.get().from {
+///     // ...
+/// }
+/// ```
+///
+/// `read()` returns [`ParsedArgs`], which frees the arguments with `FreeArgs()` when dropped.
+/// The strings point to memory owned by `ReadArgs()`, so they can't outlive `ParsedArgs`.
+#[macro_export]
+macro_rules! read_args {
+    (@template $first:literal $($item:literal)*) => {
+        ::core::concat!($first, $(",", $item,)* "\0")
+    };
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident<$lt:lifetime> {
+            $($field_vis:vis $field:ident: $field_type:ty = $item:literal),+ $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name<$lt> {
+            $($field_vis $field: $field_type,)+
+            #[doc(hidden)]
+            pub _lifetime: ::core::marker::PhantomData<&$lt ()>,
+        }
+
+        impl<$lt> $crate::ArgsTemplate for $name<$lt> {
+            const TEMPLATE: &'static str = $crate::read_args!(@template $($item)+);
+            type Slots = [$crate::LONG; <[&str]>::len(&[$($item),+])];
+            type Args<'b> = $name<'b>;
+
+            fn new_slots() -> Self::Slots {
+                // checked when the arguments are read
+                const {
+                    $(::core::assert!(
+                        $crate::template_item_kind($item)
+                            == <$field_type as $crate::ArgValue>::KIND,
+                        ::core::concat!(
+                            "the field type doesn't match the modifiers of the template item \"",
+                            $item, "\""
+                        ),
+                    );)+
+                }
+                [0; <[&str]>::len(&[$($item),+])]
+            }
+
+            unsafe fn from_slots<'b>(slots: &'b Self::Slots) -> $name<'b> {
+                let mut slots = slots.iter();
+                $name {
+                    $($field: unsafe { $crate::ArgValue::from_slot(*slots.next().unwrap()) },)+
+                    _lifetime: ::core::marker::PhantomData,
+                }
+            }
+        }
+
+        impl $name<'_> {
+            /// Parses the command line arguments with `ReadArgs()`
+            ///
+            /// Returns the `IoErr()` error if the arguments don't match the template.
+            #[allow(dead_code)]
+            $vis fn read(dos: &$crate::DosLib)
+                -> ::core::result::Result<$crate::ParsedArgs<'_, $name<'static>>, $crate::DosError>
+            {
+                $crate::ParsedArgs::read(dos)
+            }
+
+            /// Parses the arguments in `line` with `ReadArgs()`
+            ///
+            /// The line must end with a newline. Returns the `IoErr()` error if the arguments
+            /// don't match the template.
+            #[allow(dead_code)]
+            $vis fn parse<'d>(dos: &'d $crate::DosLib, line: &[u8])
+                -> ::core::result::Result<$crate::ParsedArgs<'d, $name<'static>>, $crate::DosError>
+            {
+                $crate::ParsedArgs::parse(dos, line)
+            }
+        }
+    };
+}
+
+/// Arguments declared with [`read_args!`]
+#[doc(hidden)]
+pub trait ArgsTemplate {
+    /// Null-terminated `ReadArgs()` template
+    const TEMPLATE: &'static str;
+    /// Array of the `ReadArgs()` results
+    type Slots: AsMut<[LONG]>;
+    /// The arguments struct borrowing the strings from the `ReadArgs()` results
+    type Args<'a>;
+
+    fn new_slots() -> Self::Slots;
+
+    /// # Safety
+    ///
+    /// The slots must have been filled by `ReadArgs()` with the template.
+    unsafe fn from_slots<'a>(slots: &'a Self::Slots) -> Self::Args<'a>;
+}
+
+/// Command line arguments parsed with `ReadArgs()`, freed with `FreeArgs()` when dropped
+///
+/// Created by the `read()` function of a struct declared with [`read_args!`].
+pub struct ParsedArgs<'d, T: ArgsTemplate> {
+    dos: &'d DosLib,
+    rdargs: NonNull<RDArgs>,
+    /// `rdargs` has been allocated with `AllocDosObject()`
+    allocated: bool,
+    slots: T::Slots,
+}
+
+impl<'d, T: ArgsTemplate> ParsedArgs<'d, T> {
+    /// Parses the command line arguments with `ReadArgs()`
+    ///
+    /// Returns the `IoErr()` error if the arguments don't match the template.
+    pub fn read(dos: &'d DosLib) -> Result<Self, DosError> {
+        unsafe { Self::read_args(dos, core::ptr::null_mut()) }
+    }
+
+    /// Parses the arguments in `line` with `ReadArgs()`
+    ///
+    /// The line must end with a newline. Returns the `IoErr()` error if the arguments don't
+    /// match the template.
+    pub fn parse(dos: &'d DosLib, line: &[u8]) -> Result<Self, DosError> {
+        unsafe {
+            let source = dos.AllocDosObject(DOS_RDARGS, core::ptr::null()) as *mut RDArgs;
+            if source.is_null() {
+                return Err(DosError::NoFreeStore);
+            }
+            // ReadArgs() copies the arguments, so the line isn't needed after parsing
+            (*source).RDA_Source = CSource {
+                CS_Buffer: line.as_ptr() as STRPTR,
+                CS_Length: line.len() as LONG,
+                CS_CurChr: 0,
+            };
+            let result = Self::read_args(dos, source);
+            if result.is_err() {
+                dos.FreeDosObject(DOS_RDARGS, source as _);
+            }
+            result
+        }
+    }
+
+    unsafe fn read_args(dos: &'d DosLib, source: *mut RDArgs) -> Result<Self, DosError> {
+        let mut slots = T::new_slots();
+        let rdargs =
+            unsafe { dos.ReadArgs(T::TEMPLATE.as_ptr(), slots.as_mut().as_mut_ptr(), source) };
+        match NonNull::new(rdargs) {
+            Some(rdargs) => Ok(Self {
+                dos,
+                rdargs,
+                allocated: !source.is_null(),
+                slots,
+            }),
+            None => Err(unsafe { DosError::last(dos) }),
+        }
+    }
+
+    /// Returns the arguments
+    pub fn get(&self) -> T::Args<'_> {
+        unsafe { T::from_slots(&self.slots) }
+    }
+}
+
+impl<T: ArgsTemplate> Drop for ParsedArgs<'_, T> {
+    fn drop(&mut self) {
+        unsafe {
+            self.dos.FreeArgs(self.rdargs.as_ptr());
+            if self.allocated {
+                self.dos
+                    .FreeDosObject(DOS_RDARGS, self.rdargs.as_ptr() as _);
+            }
+        }
+    }
+}
+
+#[doc(hidden)]
+pub const ARG_KIND_STRING: u8 = 0;
+#[doc(hidden)]
+pub const ARG_KIND_SWITCH: u8 = 1;
+#[doc(hidden)]
+pub const ARG_KIND_NUMBER: u8 = 2;
+#[doc(hidden)]
+pub const ARG_KIND_MULTI: u8 = 3;
+#[doc(hidden)]
+pub const ARG_KIND_MULTI_NUMBER: u8 = 4;
+
+/// Returns the kind of the `ReadArgs()` result of a template item, such as `"DEPTH/K/N"`
+#[doc(hidden)]
+pub const fn template_item_kind(item: &str) -> u8 {
+    let item = item.as_bytes();
+    let (mut switch, mut number, mut multi) = (false, false, false);
+    let mut i = 0;
+    while i + 1 < item.len() {
+        if item[i] == b'/' {
+            match item[i + 1].to_ascii_uppercase() {
+                b'S' | b'T' => switch = true,
+                b'N' => number = true,
+                b'M' => multi = true,
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+    match (switch, number, multi) {
+        (true, _, _) => ARG_KIND_SWITCH,
+        (false, true, true) => ARG_KIND_MULTI_NUMBER,
+        (false, true, false) => ARG_KIND_NUMBER,
+        (false, false, true) => ARG_KIND_MULTI,
+        (false, false, false) => ARG_KIND_STRING,
+    }
+}
+
+/// A field type of a [`read_args!`] struct
+#[doc(hidden)]
+pub trait ArgValue: Sized {
+    /// The kind of the template item
+    const KIND: u8;
+
+    /// Converts a `ReadArgs()` result
+    ///
+    /// # Safety
+    ///
+    /// `slot` must have been set by `ReadArgs()` for a template item of the kind.
+    unsafe fn from_slot(slot: LONG) -> Self;
+}
+
+impl ArgValue for bool {
+    const KIND: u8 = ARG_KIND_SWITCH;
+
+    unsafe fn from_slot(slot: LONG) -> Self {
+        slot != 0
+    }
+}
+
+impl ArgValue for Option<i32> {
+    const KIND: u8 = ARG_KIND_NUMBER;
+
+    unsafe fn from_slot(slot: LONG) -> Self {
+        // a pointer to the number
+        unsafe { (slot as *const LONG).as_ref().copied() }
+    }
+}
+
+impl ArgValue for Option<&[u8]> {
+    const KIND: u8 = ARG_KIND_STRING;
+
+    unsafe fn from_slot(slot: LONG) -> Self {
+        if slot == 0 {
+            return None;
+        }
+        Some(unsafe { c_str_bytes(slot as STRPTR) })
+    }
+}
+
+impl ArgValue for MultiArg<'_> {
+    const KIND: u8 = ARG_KIND_MULTI;
+
+    unsafe fn from_slot(slot: LONG) -> Self {
+        MultiArg {
+            strings: slot as *const STRPTR,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+impl ArgValue for MultiNumberArg<'_> {
+    const KIND: u8 = ARG_KIND_MULTI_NUMBER;
+
+    unsafe fn from_slot(slot: LONG) -> Self {
+        MultiNumberArg {
+            numbers: slot as *const *const LONG,
+            lifetime: PhantomData,
+        }
+    }
+}
+
+/// Returns the bytes of a null-terminated string without the terminator
+unsafe fn c_str_bytes<'a>(s: STRPTR) -> &'a [u8] {
+    unsafe { core::ffi::CStr::from_ptr(s as *const core::ffi::c_char).to_bytes() }
+}
+
+/// The strings of a `/M` template item: an iterator over the null-terminated string array
+#[derive(Copy, Clone, Debug)]
+pub struct MultiArg<'a> {
+    strings: *const STRPTR,
+    lifetime: PhantomData<&'a [u8]>,
+}
+
+impl<'a> MultiArg<'a> {
+    /// Returns the number of strings
+    pub fn len(&self) -> usize {
+        let strings = *self;
+        strings.count()
+    }
+
+    /// Returns `true` if there are no strings
+    pub fn is_empty(&self) -> bool {
+        self.strings.is_null() || unsafe { (*self.strings).is_null() }
+    }
+
+    /// Returns the string at the index
+    pub fn get(&self, index: usize) -> Option<&'a [u8]> {
+        let mut strings = *self;
+        strings.nth(index)
+    }
+}
+
+impl<'a> Iterator for MultiArg<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        if self.is_empty() {
+            return None;
+        }
+        unsafe {
+            let s = *self.strings;
+            self.strings = self.strings.add(1);
+            Some(c_str_bytes(s))
+        }
+    }
+}
+
+/// The numbers of a `/M/N` template item: an iterator over the null-terminated array of pointers
+/// to the numbers
+#[derive(Copy, Clone, Debug)]
+pub struct MultiNumberArg<'a> {
+    numbers: *const *const LONG,
+    lifetime: PhantomData<&'a LONG>,
+}
+
+impl MultiNumberArg<'_> {
+    /// Returns the number of numbers
+    pub fn len(&self) -> usize {
+        let numbers = *self;
+        numbers.count()
+    }
+
+    /// Returns `true` if there are no numbers
+    pub fn is_empty(&self) -> bool {
+        self.numbers.is_null() || unsafe { (*self.numbers).is_null() }
+    }
+
+    /// Returns the number at the index
+    pub fn get(&self, index: usize) -> Option<i32> {
+        let mut numbers = *self;
+        numbers.nth(index)
+    }
+}
+
+impl Iterator for MultiNumberArg<'_> {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        if self.is_empty() {
+            return None;
+        }
+        unsafe {
+            let n = **self.numbers;
+            self.numbers = self.numbers.add(1);
+            Some(n)
+        }
+    }
+}
@@ -0,0 +1,245 @@
+use crate::{BPTR, CliArgs, CommandLineInterface, Library, Process, WBArg, WBStartup};
+use core::ptr::null_mut;
+
+/// Information about how the program was started, passed to `main()` by the startup code
+///
+/// Enabled with the `startup` feature, which provides the `_start` entry point. The program
+/// defines `main()` without name mangling:
+///
+/// ```ignore
+/// #![no_std]
+/// #![no_main]
+///
+/// use amiga_sys::StartupInfo;
+///
+/// #[unsafe(no_mangle)]
+/// fn main(startup: StartupInfo) -> i32 {
+///     if startup.is_workbench() {
+///         // no CLI: Output() is 0
+///     }
+///     0
+/// }
+/// ```
+///
+/// The return value of `main()` is the return code of a CLI command (`RETURN_OK`,
+/// `RETURN_WARN`, etc.). When started from Workbench, the startup code replies to the
+/// `WBStartup` message after `main()` returns, and the current directory is the program's
+/// directory while `main()` runs.
+pub struct StartupInfo {
+    process: *mut Process,
+    wb_startup: *mut WBStartup,
+    arguments: &'static [u8],
+}
+
+impl StartupInfo {
+    /// Returns the process running the program
+    pub fn process(&self) -> *mut Process {
+        self.process
+    }
+
+    /// Returns `true` if the program was started from Workbench
+    pub fn is_workbench(&self) -> bool {
+        !self.wb_startup.is_null()
+    }
+
+    /// Returns the `WBStartup` message if the program was started from Workbench
+    pub fn wb_startup(&self) -> Option<&WBStartup> {
+        unsafe { self.wb_startup.as_ref() }
+    }
+
+    /// Returns the argument line of a CLI command
+    ///
+    /// The CLI passes the arguments after the command name in registers a0 (address) and d0
+    /// (length) and the startup code captures them at entry. The line usually ends with a
+    /// newline and isn't null-terminated. Empty if the program was started from Workbench.
+    pub fn raw_args(&self) -> &'static [u8] {
+        self.arguments
+    }
+
+    /// Returns an iterator over the CLI arguments split with the AmigaDOS quoting rules
+    pub fn args(&self) -> CliArgs<'static> {
+        CliArgs::new(self.arguments)
+    }
+
+    /// Returns the command name from `cli_CommandName` of the CLI structure
+    ///
+    /// The name is how the command was called, for example `c:dir`. `None` if the program
+    /// was started from Workbench.
+    pub fn command_name(&self) -> Option<&[u8]> {
+        if self.process.is_null() {
+            return None;
+        }
+        unsafe {
+            let cli = ((*self.process).pr_CLI << 2) as *const CommandLineInterface;
+            if cli.is_null() || (*cli).cli_CommandName == 0 {
+                return None;
+            }
+            // BSTR: a BPTR to the length byte followed by the characters
+            let name = ((*cli).cli_CommandName << 2) as *const u8;
+            Some(core::slice::from_raw_parts(name.add(1), *name as usize))
+        }
+    }
+
+    /// Returns the Workbench arguments: the program itself and the icons selected with it
+    ///
+    /// Empty if the program was started from the CLI.
+    pub fn wb_args(&self) -> &[WBArg] {
+        match self.wb_startup() {
+            Some(wb) if !wb.sm_ArgList.is_null() && wb.sm_NumArgs > 0 => unsafe {
+                core::slice::from_raw_parts(wb.sm_ArgList, wb.sm_NumArgs as usize)
+            },
+            _ => &[],
+        }
+    }
+}
+
+unsafe extern "Rust" {
+    /// Defined by the program with `#[unsafe(no_mangle)]`
+    fn main(startup: StartupInfo) -> i32;
+}
+
+/// Stack pointer after `_start` has saved the registers, restored by `exit()`
+static mut ENTRY_SP: u32 = 0;
+/// `WBStartup` message which is replied when the program exits
+static mut WB_STARTUP: *mut WBStartup = null_mut();
+/// dos.library opened to set the current directory of a Workbench program
+static mut DOS: *mut Library = null_mut();
+/// Current directory before the program's directory was set
+static mut OLD_DIR: BPTR = 0;
+
+/// Entry point: passes the argument line in a0 and its length in d0 to `start()`
+#[unsafe(no_mangle)]
+// place _start to .init section so that it is the first code block in the executable
+#[unsafe(link_section = ".init")]
+#[unsafe(naked)]
+extern "C" fn _start() -> i32 {
+    core::arch::naked_asm!(
+        "movem.l %d2-%d7/%a2-%a6, -(%sp)",
+        "move.l %sp, {entry_sp}",
+        "move.l %d0, -(%sp)",
+        "move.l %a0, -(%sp)",
+        "jsr {start}",
+        // pop the arguments
+        "lea (8, %sp), %sp",
+        "movem.l (%sp)+, %d2-%d7/%a2-%a6",
+        "rts",
+        entry_sp = sym ENTRY_SP,
+        start = sym start,
+    )
+}
+
+extern "C" fn start(arg_ptr: *const u8, arg_len: u32) -> i32 {
+    unsafe {
+        let exec = crate::abs_exec_library();
+        let process = crate::FindTask(exec, null_mut()) as *mut Process;
+
+        // a process without a CLI structure has been started from Workbench: the WBStartup
+        // message is waiting in the process message port
+        let mut wb_startup: *mut WBStartup = null_mut();
+        if (*process).pr_CLI == 0 {
+            crate::WaitPort(exec, &mut (*process).pr_MsgPort);
+            wb_startup = crate::GetMsg(exec, &mut (*process).pr_MsgPort) as *mut WBStartup;
+        }
+        WB_STARTUP = wb_startup;
+
+        // Workbench doesn't set the current directory: use the program's directory
+        if !wb_startup.is_null() && (*wb_startup).sm_NumArgs > 0 {
+            DOS = crate::OpenLibrary(exec, c"dos.library".as_ptr() as _, 0);
+            if !DOS.is_null() {
+                OLD_DIR = crate::CurrentDir(DOS, (*(*wb_startup).sm_ArgList).wa_Lock);
+            }
+        }
+
+        let arguments = if wb_startup.is_null() && !arg_ptr.is_null() {
+            core::slice::from_raw_parts(arg_ptr, arg_len as usize)
+        } else {
+            &[]
+        };
+
+        let return_code = main(StartupInfo {
+            process,
+            wb_startup,
+            arguments,
+        });
+        clean_up();
+        return_code
+    }
+}
+
+/// Frees the resources of the startup code and replies to the `WBStartup` message
+unsafe fn clean_up() {
+    unsafe {
+        let exec = crate::abs_exec_library();
+
+        // frees the memory which the program didn't free
+        #[cfg(feature = "alloc")]
+        crate::delete_alloc_pool();
+
+        // closes the libraries opened by the global-bases functions
+        #[cfg(feature = "auto-open")]
+        crate::global::close_libraries();
+
+        if !DOS.is_null() {
+            crate::CurrentDir(DOS, OLD_DIR);
+            crate::CloseLibrary(exec, DOS);
+            DOS = null_mut();
+        }
+
+        if !WB_STARTUP.is_null() {
+            // Workbench unloads the program when it gets the reply: Forbid() ensures that
+            // the program exits before Workbench runs. The task exit ends the Forbid().
+            crate::Forbid(exec);
+            crate::ReplyMsg(exec, WB_STARTUP as *mut crate::Message);
+            WB_STARTUP = null_mut();
+        }
+    }
+}
+
+/// Exits the program with the return code, like returning from `main()`
+///
+/// The startup code cleans up and returns to the CLI or Workbench. The destructors of the
+/// values on the stack aren't run, so the program should free its system resources first.
+///
+/// # Safety
+///
+/// Must be called by the program's own process, not by a task or process it has created.
+pub unsafe fn exit(return_code: i32) -> ! {
+    unsafe {
+        clean_up();
+        return_to_caller(return_code)
+    }
+}
+
+/// Writes `can't open <library>` to the CLI and exits with `RETURN_FAIL`
+///
+/// Called by the `auto-open` feature when a library can't be opened.
+#[cfg(all(feature = "auto-open", not(feature = "panic-handler")))]
+pub(crate) unsafe fn exit_missing_library(name: &core::ffi::CStr) -> ! {
+    unsafe {
+        let exec = crate::abs_exec_library();
+        let dos = crate::OpenLibrary(exec, c"dos.library".as_ptr() as _, 0);
+        if !dos.is_null() {
+            let out = crate::Output(dos);
+            if out != 0 {
+                let name = name.to_bytes();
+                crate::Write(dos, out, c"can't open ".as_ptr() as _, 11);
+                crate::Write(dos, out, name.as_ptr() as _, name.len() as _);
+                crate::Write(dos, out, c"\n".as_ptr() as _, 1);
+            }
+            crate::CloseLibrary(exec, dos);
+        }
+        exit(crate::RETURN_FAIL as i32)
+    }
+}
+
+/// Returns from `_start` with the return code, discarding the stack above it
+#[unsafe(naked)]
+unsafe extern "C" fn return_to_caller(return_code: i32) -> ! {
+    core::arch::naked_asm!(
+        "move.l (4, %sp), %d0",
+        "move.l {entry_sp}, %sp",
+        "movem.l (%sp)+, %d2-%d7/%a2-%a6",
+        "rts",
+        entry_sp = sym ENTRY_SP,
+    )
+}
@@ -0,0 +1,179 @@
+use crate::{BOOL, CONST_STRPTR, STRPTR, Tag, TagItem};
+use core::ffi::CStr;
+use core::marker::PhantomData;
+
+/// Builds an array of [`TagItem`](crate::TagItem)s terminated by `TAG_DONE`
+///
+/// The values can be integers, booleans, pointers, references and C strings. Call
+/// `as_ptr()` to pass the tag list to the library functions:
+///
+/// ```ignore
+/// let tags = tags![
+///     WA_Width => 200,
+///     WA_Title => c"Hello",
+///     WA_CloseGadget => true,
+/// ];
+/// let window = unsafe { OpenWindowTagList(intuition, null(), tags.as_ptr()) };
+/// ```
+///
+/// The array must live as long as the tag list is used: `tags![...].as_ptr()` is valid
+/// only in the same statement.
+///
+/// The tags can also be [`TypedTag`]s from the [`typed_tags`](crate::typed_tags) module,
+/// and then the values must have the tag's type:
+///
+/// ```ignore
+/// use amiga_sys::typed_tags::open_window;
+///
+/// let tags = tags![
+///     open_window::WA_Width => 200,
+///     open_window::WA_Title => c"Hello",
+///     // compile error: expected a number
+///     // open_window::WA_Height => c"100",
+/// ];
+/// ```
+///
+/// Only typed tags are checked. A plain tag constant like `WA_Width` accepts any value, so a
+/// value of the wrong type isn't detected. The typed tags are listed by hand and don't cover
+/// all the NDK tags yet: tags without a typed version, like the icon.library and
+/// workbench.library tags, bypass the type check.
+#[macro_export]
+macro_rules! tags {
+    ($($tag:expr => $value:expr),* $(,)?) => {
+        [
+            $($crate::TagKey::tag_item($tag, $value),)*
+            $crate::TagItem { ti_Tag: $crate::TAG_DONE, ti_Data: 0 },
+        ]
+    };
+}
+
+/// A tag whose value has the type `T`
+///
+/// The typed tags are in the [`typed_tags`](crate::typed_tags) module, grouped by the
+/// function or class which accepts them.
+pub struct TypedTag<T> {
+    id: Tag,
+    value_type: PhantomData<fn() -> T>,
+}
+
+impl<T> TypedTag<T> {
+    /// Creates a typed tag from a tag id, such as `WA_Width`
+    pub const fn new(id: Tag) -> Self {
+        Self {
+            id,
+            value_type: PhantomData,
+        }
+    }
+
+    /// Returns the tag id
+    pub const fn id(&self) -> Tag {
+        self.id
+    }
+}
+
+impl<T> Clone for TypedTag<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for TypedTag<T> {}
+
+/// A tag which can be used with the value type `V` in [`tags!`]
+pub trait TagKey<V> {
+    /// Creates a tag item with the value
+    fn tag_item(self, value: V) -> TagItem;
+}
+
+impl<V: TagValue> TagKey<V> for Tag {
+    fn tag_item(self, value: V) -> TagItem {
+        TagItem {
+            ti_Tag: self,
+            ti_Data: value.into_tag_data(),
+        }
+    }
+}
+
+impl<T, V: TagValueOf<T>> TagKey<V> for TypedTag<T> {
+    fn tag_item(self, value: V) -> TagItem {
+        TagItem {
+            ti_Tag: self.id,
+            ti_Data: value.into_tag_data(),
+        }
+    }
+}
+
+/// A value which can be stored in the `ti_Data` field of a [`TagItem`](crate::TagItem)
+pub trait TagValue {
+    /// Converts the value to `ti_Data`
+    fn into_tag_data(self) -> u32;
+}
+
+macro_rules! impl_tag_value_integer {
+    ($($t:ty),*) => {
+        $(
+            impl TagValue for $t {
+                fn into_tag_data(self) -> u32 {
+                    self as u32
+                }
+            }
+        )*
+    };
+}
+
+impl_tag_value_integer!(i8, u8, i16, u16, i32, u32, isize, usize);
+
+impl TagValue for bool {
+    /// `true` is `TRUE` (1) and `false` is `FALSE` (0)
+    fn into_tag_data(self) -> u32 {
+        self as u32
+    }
+}
+
+impl<T> TagValue for *const T {
+    fn into_tag_data(self) -> u32 {
+        self as u32
+    }
+}
+
+impl<T> TagValue for *mut T {
+    fn into_tag_data(self) -> u32 {
+        self as u32
+    }
+}
+
+impl<T> TagValue for &T {
+    fn into_tag_data(self) -> u32 {
+        self as *const T as u32
+    }
+}
+
+impl<T> TagValue for &mut T {
+    fn into_tag_data(self) -> u32 {
+        self as *mut T as u32
+    }
+}
+
+impl TagValue for &CStr {
+    fn into_tag_data(self) -> u32 {
+        self.as_ptr() as u32
+    }
+}
+
+/// A value which can be stored in a tag whose value type is `T`
+///
+/// Implemented for `T` itself, for C strings if `T` is a string pointer, for `bool` if `T` is
+/// `BOOL`, and for references if `T` is a pointer.
+pub trait TagValueOf<T>: TagValue {}
+
+impl<T: TagValue> TagValueOf<T> for T {}
+
+impl TagValueOf<CONST_STRPTR> for &CStr {}
+
+impl TagValueOf<STRPTR> for &CStr {}
+
+impl TagValueOf<BOOL> for bool {}
+
+impl<T> TagValueOf<*const T> for &T {}
+
+impl<T> TagValueOf<*mut T> for &mut T {}
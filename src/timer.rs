@@ -0,0 +1,371 @@
+use crate::{
+    EClockVal, IORequest, TIMERNAME, TR_ADDREQUEST, ULONG, UNIT_ECLOCK, UNIT_MICROHZ, UNIT_VBLANK,
+    UNIT_WAITECLOCK, UNIT_WAITUNTIL, UWORD, timerequest, timeval,
+};
+use core::future::Future;
+use core::ops::{Add, Sub};
+use core::pin::Pin;
+use core::ptr::NonNull;
+use core::task::{Context, Poll};
+use core::time::Duration;
+
+/// Unit of `timer.device`, which selects the resolution and how the time is given
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerUnit {
+    /// `UNIT_MICROHZ`: microsecond resolution, good for short delays
+    MicroHz,
+    /// `UNIT_VBLANK`: vertical blank resolution, efficient for long delays
+    VBlank,
+    /// `UNIT_ECLOCK`: E-clock resolution (V36)
+    EClock,
+    /// `UNIT_WAITUNTIL`: waits until a system time, vertical blank resolution (V36)
+    WaitUntil,
+    /// `UNIT_WAITECLOCK`: waits until an E-clock time (V36)
+    WaitEClock,
+}
+
+impl TimerUnit {
+    fn number(self) -> ULONG {
+        match self {
+            TimerUnit::MicroHz => UNIT_MICROHZ,
+            TimerUnit::VBlank => UNIT_VBLANK,
+            TimerUnit::EClock => UNIT_ECLOCK,
+            TimerUnit::WaitUntil => UNIT_WAITUNTIL,
+            TimerUnit::WaitEClock => UNIT_WAITECLOCK,
+        }
+    }
+}
+
+/// A point of time of the E-clock, which is monotonic
+///
+/// Returned by [`Timer::now()`]. The E-clock counts about 709 379 ticks per second on PAL
+/// machines and 715 909 on NTSC machines, and the frequency is stored with the ticks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Instant {
+    ticks: u64,
+    frequency: ULONG,
+}
+
+impl Instant {
+    /// Returns the E-clock ticks
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns the number of E-clock ticks per second
+    pub fn frequency(&self) -> ULONG {
+        self.frequency
+    }
+
+    /// Returns the time from the earlier instant to this instant, zero if it's later
+    pub fn duration_since(&self, earlier: Instant) -> Duration {
+        let ticks = self.ticks.saturating_sub(earlier.ticks);
+        let frequency = u64::from(self.frequency);
+        let nanos = (ticks % frequency) * 1_000_000_000 / frequency;
+        Duration::new(ticks / frequency, nanos as u32)
+    }
+
+    /// Returns the instant after the duration, `None` if the E-clock would overflow
+    pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+        let ticks = duration_to_ticks(duration, self.frequency)?;
+        Some(Instant {
+            ticks: self.ticks.checked_add(ticks)?,
+            frequency: self.frequency,
+        })
+    }
+}
+
+impl Add<Duration> for Instant {
+    type Output = Instant;
+
+    /// Panics if the E-clock would overflow
+    fn add(self, duration: Duration) -> Instant {
+        self.checked_add(duration)
+            .expect("overflow when adding duration to instant")
+    }
+}
+
+impl Sub<Instant> for Instant {
+    type Output = Duration;
+
+    /// Returns the duration since the earlier instant, zero if it's later
+    fn sub(self, earlier: Instant) -> Duration {
+        self.duration_since(earlier)
+    }
+}
+
+/// Converts the duration to E-clock ticks
+fn duration_to_ticks(duration: Duration, frequency: ULONG) -> Option<u64> {
+    let frequency = u64::from(frequency);
+    let ticks = duration.as_secs().checked_mul(frequency)?;
+    ticks.checked_add(u64::from(duration.subsec_nanos()) * frequency / 1_000_000_000)
+}
+
+/// Converts E-clock ticks to the `EClockVal` layout of `tr_time`
+fn ticks_to_timeval(ticks: u64) -> timeval {
+    timeval {
+        tv_secs: (ticks >> 32) as ULONG,
+        tv_micro: ticks as ULONG,
+    }
+}
+
+/// Converts the duration to a `timeval`, saturating at the largest number of seconds
+fn duration_to_timeval(duration: Duration) -> timeval {
+    timeval {
+        tv_secs: ULONG::try_from(duration.as_secs()).unwrap_or(ULONG::MAX),
+        tv_micro: duration.subsec_micros(),
+    }
+}
+
+/// An opened unit of `timer.device` with its message port and `timerequest`
+///
+/// Opening the timer creates the reply port and the request with `CreateMsgPort()` and
+/// `CreateIORequest()` and opens the device with `OpenDevice()`. Dropping the timer aborts
+/// the pending request and closes the device.
+///
+/// ```ignore
+/// let mut timer = Timer::open(TimerUnit::MicroHz).unwrap();
+/// let start = timer.now();
+/// timer.sleep(Duration::from_millis(100));
+/// let elapsed = timer.now() - start;
+///
+/// // waits one second unless Ctrl-C is pressed: dropping the request aborts it
+/// block_on(select(timer.start(Duration::from_secs(1)), ctrl_c()));
+/// ```
+///
+/// The duration is converted to the time format of the unit: relative time for
+/// `UNIT_MICROHZ` and `UNIT_VBLANK`, E-clock ticks for `UNIT_ECLOCK` and the system time
+/// or the E-clock time at the end of the duration for `UNIT_WAITUNTIL` and
+/// `UNIT_WAITECLOCK`. The timer can have one request at a time.
+pub struct Timer {
+    request: NonNull<timerequest>,
+    unit: TimerUnit,
+    pending: bool,
+}
+
+impl Timer {
+    /// Opens the unit of `timer.device`
+    ///
+    /// Returns `None` if the port, the request or the device can't be opened.
+    pub fn open(unit: TimerUnit) -> Option<Self> {
+        unsafe {
+            let exec = crate::abs_exec_library();
+            let port = crate::CreateMsgPort(exec);
+            if port.is_null() {
+                return None;
+            }
+            let size = core::mem::size_of::<timerequest>() as ULONG;
+            let Some(request) = NonNull::new(crate::CreateIORequest(exec, port, size)) else {
+                crate::DeleteMsgPort(exec, port);
+                return None;
+            };
+            let request = request.cast::<timerequest>();
+            let io_request = request.as_ptr() as *mut IORequest;
+            if crate::OpenDevice(exec, TIMERNAME.as_ptr(), unit.number(), io_request, 0) != 0 {
+                crate::DeleteIORequest(exec, io_request as _);
+                crate::DeleteMsgPort(exec, port);
+                return None;
+            }
+            Some(Self {
+                request,
+                unit,
+                pending: false,
+            })
+        }
+    }
+
+    /// Returns the unit of the timer
+    pub fn unit(&self) -> TimerUnit {
+        self.unit
+    }
+
+    /// Returns the device base, which is the `TimerBase` parameter of the `timer.device`
+    /// functions, such as `GetSysTime()` and `AddTime()`
+    pub fn device_base(&self) -> *mut core::ffi::c_void {
+        unsafe { (*self.request.as_ptr()).tr_node.io_Device as *mut core::ffi::c_void }
+    }
+
+    /// Returns the current E-clock time with `ReadEClock()`
+    pub fn now(&self) -> Instant {
+        let mut value = EClockVal { ev_hi: 0, ev_lo: 0 };
+        let frequency = unsafe { crate::ReadEClock(self.device_base(), &mut value) };
+        let ticks = (u64::from(value.ev_hi) << 32) | u64::from(value.ev_lo);
+        Instant { ticks, frequency }
+    }
+
+    /// Returns the time since the instant
+    pub fn elapsed(&self, since: Instant) -> Duration {
+        self.now().duration_since(since)
+    }
+
+    /// Waits for the duration with `DoIO()`
+    pub fn sleep(&mut self, duration: Duration) {
+        self.prepare(duration, None);
+        self.do_io();
+    }
+
+    /// Waits until the instant with `DoIO()`, returning immediately if the instant has passed
+    pub fn sleep_until(&mut self, deadline: Instant) {
+        self.prepare(deadline.duration_since(self.now()), Some(deadline));
+        self.do_io();
+    }
+
+    /// Sends a request which completes after the duration with `SendIO()`
+    ///
+    /// The returned request is a future, which can be run with [`block_on()`](crate::block_on)
+    /// and combined with other futures. Dropping it aborts the request if it hasn't completed.
+    pub fn start(&mut self, duration: Duration) -> TimerRequest<'_> {
+        self.prepare(duration, None);
+        self.send_io()
+    }
+
+    /// Sends a request which completes at the instant with `SendIO()`
+    ///
+    /// See [`Timer::start()`].
+    pub fn start_until(&mut self, deadline: Instant) -> TimerRequest<'_> {
+        self.prepare(deadline.duration_since(self.now()), Some(deadline));
+        self.send_io()
+    }
+
+    /// Sets the command and the time of the request
+    fn prepare(&mut self, duration: Duration, deadline: Option<Instant>) {
+        // the request is still pending if its TimerRequest has been forgotten
+        self.abort();
+        let time = match self.unit {
+            TimerUnit::MicroHz | TimerUnit::VBlank => duration_to_timeval(duration),
+            TimerUnit::EClock => {
+                let frequency = self.now().frequency;
+                ticks_to_timeval(duration_to_ticks(duration, frequency).unwrap_or(u64::MAX))
+            }
+            TimerUnit::WaitUntil => unsafe {
+                let mut time = timeval {
+                    tv_secs: 0,
+                    tv_micro: 0,
+                };
+                crate::GetSysTime(self.device_base(), &mut time);
+                crate::AddTime(
+                    self.device_base(),
+                    &mut time,
+                    &duration_to_timeval(duration),
+                );
+                time
+            },
+            TimerUnit::WaitEClock => {
+                let deadline = deadline.or_else(|| self.now().checked_add(duration));
+                ticks_to_timeval(deadline.map_or(u64::MAX, |deadline| deadline.ticks))
+            }
+        };
+        unsafe {
+            let request = self.request.as_ptr();
+            (*request).tr_node.io_Command = TR_ADDREQUEST as UWORD;
+            (*request).tr_time = time;
+        }
+    }
+
+    fn do_io(&mut self) {
+        unsafe { crate::DoIO(crate::abs_exec_library(), self.io_request()) };
+    }
+
+    fn send_io(&mut self) -> TimerRequest<'_> {
+        unsafe { crate::SendIO(crate::abs_exec_library(), self.io_request()) };
+        self.pending = true;
+        TimerRequest { timer: self }
+    }
+
+    fn io_request(&self) -> *mut IORequest {
+        self.request.as_ptr() as *mut IORequest
+    }
+
+    /// Aborts the pending request and removes its reply
+    fn abort(&mut self) {
+        if self.pending {
+            unsafe {
+                let exec = crate::abs_exec_library();
+                crate::AbortIO(exec, self.io_request());
+                crate::WaitIO(exec, self.io_request());
+            }
+            self.pending = false;
+        }
+    }
+}
+
+impl Drop for Timer {
+    fn drop(&mut self) {
+        // the request is still pending if its TimerRequest has been forgotten
+        self.abort();
+        unsafe {
+            let exec = crate::abs_exec_library();
+            let port = (*self.request.as_ptr()).tr_node.io_Message.mn_ReplyPort;
+            crate::CloseDevice(exec, self.io_request());
+            crate::DeleteIORequest(exec, self.io_request() as _);
+            crate::DeleteMsgPort(exec, port);
+        }
+    }
+}
+
+/// A request of a [`Timer`] sent with `SendIO()`, aborted with `AbortIO()` when dropped
+///
+/// Returned by [`Timer::start()`]. The request is a future which completes when the time
+/// has passed. It can also be checked and waited without an executor.
+pub struct TimerRequest<'a> {
+    timer: &'a mut Timer,
+}
+
+impl TimerRequest<'_> {
+    /// Returns `true` if the time has passed, checked with `CheckIO()`
+    pub fn is_complete(&self) -> bool {
+        !self.timer.pending
+            || unsafe {
+                !crate::CheckIO(crate::abs_exec_library(), self.timer.io_request()).is_null()
+            }
+    }
+
+    /// Returns the signal of the reply port, for waiting with `Wait()` together with other
+    /// signals
+    pub fn signal(&self) -> ULONG {
+        unsafe {
+            let port = (*self.timer.request.as_ptr())
+                .tr_node
+                .io_Message
+                .mn_ReplyPort;
+            1 << (*port).mp_SigBit
+        }
+    }
+
+    /// Waits until the time has passed with `WaitIO()`
+    pub fn wait(mut self) {
+        self.wait_io();
+    }
+
+    /// Aborts the request, which is the same as dropping it
+    pub fn abort(self) {
+        drop(self)
+    }
+
+    /// Waits for the request and removes its reply from the port
+    fn wait_io(&mut self) {
+        if self.timer.pending {
+            unsafe { crate::WaitIO(crate::abs_exec_library(), self.timer.io_request()) };
+            self.timer.pending = false;
+        }
+    }
+}
+
+impl Future for TimerRequest<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<()> {
+        if !self.is_complete() {
+            crate::wake_on_signals(cx, self.signal());
+            return Poll::Pending;
+        }
+        self.get_mut().wait_io();
+        Poll::Ready(())
+    }
+}
+
+impl Drop for TimerRequest<'_> {
+    fn drop(&mut self) {
+        self.timer.abort();
+    }
+}
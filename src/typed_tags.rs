@@ -0,0 +1,1331 @@
+/* automatically generated by the amiga-sys tools */
+
+/// Tags of `OpenWindowTagList()` (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod open_window {
+    use crate::*;
+
+    pub const WA_Left: TypedTag<LONG> = TypedTag::new(crate::WA_Left);
+    pub const WA_Top: TypedTag<LONG> = TypedTag::new(crate::WA_Top);
+    pub const WA_Width: TypedTag<LONG> = TypedTag::new(crate::WA_Width);
+    pub const WA_Height: TypedTag<LONG> = TypedTag::new(crate::WA_Height);
+    pub const WA_DetailPen: TypedTag<UBYTE> = TypedTag::new(crate::WA_DetailPen);
+    pub const WA_BlockPen: TypedTag<UBYTE> = TypedTag::new(crate::WA_BlockPen);
+    pub const WA_IDCMP: TypedTag<ULONG> = TypedTag::new(crate::WA_IDCMP);
+    pub const WA_Flags: TypedTag<ULONG> = TypedTag::new(crate::WA_Flags);
+    pub const WA_Gadgets: TypedTag<*mut Gadget> = TypedTag::new(crate::WA_Gadgets);
+    pub const WA_Checkmark: TypedTag<*mut Image> = TypedTag::new(crate::WA_Checkmark);
+    pub const WA_Title: TypedTag<CONST_STRPTR> = TypedTag::new(crate::WA_Title);
+    pub const WA_ScreenTitle: TypedTag<CONST_STRPTR> = TypedTag::new(crate::WA_ScreenTitle);
+    pub const WA_CustomScreen: TypedTag<*mut Screen> = TypedTag::new(crate::WA_CustomScreen);
+    pub const WA_SuperBitMap: TypedTag<*mut BitMap> = TypedTag::new(crate::WA_SuperBitMap);
+    pub const WA_MinWidth: TypedTag<LONG> = TypedTag::new(crate::WA_MinWidth);
+    pub const WA_MinHeight: TypedTag<LONG> = TypedTag::new(crate::WA_MinHeight);
+    pub const WA_MaxWidth: TypedTag<ULONG> = TypedTag::new(crate::WA_MaxWidth);
+    pub const WA_MaxHeight: TypedTag<ULONG> = TypedTag::new(crate::WA_MaxHeight);
+    pub const WA_InnerWidth: TypedTag<LONG> = TypedTag::new(crate::WA_InnerWidth);
+    pub const WA_InnerHeight: TypedTag<LONG> = TypedTag::new(crate::WA_InnerHeight);
+    pub const WA_PubScreenName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::WA_PubScreenName);
+    pub const WA_PubScreen: TypedTag<*mut Screen> = TypedTag::new(crate::WA_PubScreen);
+    pub const WA_PubScreenFallBack: TypedTag<BOOL> = TypedTag::new(crate::WA_PubScreenFallBack);
+    pub const WA_Colors: TypedTag<*mut ColorSpec> = TypedTag::new(crate::WA_Colors);
+    pub const WA_Zoom: TypedTag<*mut WORD> = TypedTag::new(crate::WA_Zoom);
+    pub const WA_MouseQueue: TypedTag<UWORD> = TypedTag::new(crate::WA_MouseQueue);
+    pub const WA_BackFill: TypedTag<*mut Hook> = TypedTag::new(crate::WA_BackFill);
+    pub const WA_RptQueue: TypedTag<UWORD> = TypedTag::new(crate::WA_RptQueue);
+    pub const WA_SizeGadget: TypedTag<BOOL> = TypedTag::new(crate::WA_SizeGadget);
+    pub const WA_DragBar: TypedTag<BOOL> = TypedTag::new(crate::WA_DragBar);
+    pub const WA_DepthGadget: TypedTag<BOOL> = TypedTag::new(crate::WA_DepthGadget);
+    pub const WA_CloseGadget: TypedTag<BOOL> = TypedTag::new(crate::WA_CloseGadget);
+    pub const WA_Backdrop: TypedTag<BOOL> = TypedTag::new(crate::WA_Backdrop);
+    pub const WA_ReportMouse: TypedTag<BOOL> = TypedTag::new(crate::WA_ReportMouse);
+    pub const WA_NoCareRefresh: TypedTag<BOOL> = TypedTag::new(crate::WA_NoCareRefresh);
+    pub const WA_Borderless: TypedTag<BOOL> = TypedTag::new(crate::WA_Borderless);
+    pub const WA_Activate: TypedTag<BOOL> = TypedTag::new(crate::WA_Activate);
+    pub const WA_RMBTrap: TypedTag<BOOL> = TypedTag::new(crate::WA_RMBTrap);
+    pub const WA_SimpleRefresh: TypedTag<BOOL> = TypedTag::new(crate::WA_SimpleRefresh);
+    pub const WA_SmartRefresh: TypedTag<BOOL> = TypedTag::new(crate::WA_SmartRefresh);
+    pub const WA_SizeBRight: TypedTag<BOOL> = TypedTag::new(crate::WA_SizeBRight);
+    pub const WA_SizeBBottom: TypedTag<BOOL> = TypedTag::new(crate::WA_SizeBBottom);
+    pub const WA_AutoAdjust: TypedTag<BOOL> = TypedTag::new(crate::WA_AutoAdjust);
+    pub const WA_GimmeZeroZero: TypedTag<BOOL> = TypedTag::new(crate::WA_GimmeZeroZero);
+    pub const WA_MenuHelp: TypedTag<BOOL> = TypedTag::new(crate::WA_MenuHelp);
+    pub const WA_NewLookMenus: TypedTag<BOOL> = TypedTag::new(crate::WA_NewLookMenus);
+    pub const WA_AmigaKey: TypedTag<*mut Image> = TypedTag::new(crate::WA_AmigaKey);
+    pub const WA_NotifyDepth: TypedTag<BOOL> = TypedTag::new(crate::WA_NotifyDepth);
+    pub const WA_Pointer: TypedTag<APTR> = TypedTag::new(crate::WA_Pointer);
+    pub const WA_BusyPointer: TypedTag<BOOL> = TypedTag::new(crate::WA_BusyPointer);
+    pub const WA_PointerDelay: TypedTag<BOOL> = TypedTag::new(crate::WA_PointerDelay);
+    pub const WA_TabletMessages: TypedTag<BOOL> = TypedTag::new(crate::WA_TabletMessages);
+    pub const WA_HelpGroup: TypedTag<ULONG> = TypedTag::new(crate::WA_HelpGroup);
+    pub const WA_HelpGroupWindow: TypedTag<*mut Window> = TypedTag::new(crate::WA_HelpGroupWindow);
+    pub const WA_Hidden: TypedTag<BOOL> = TypedTag::new(crate::WA_Hidden);
+    pub const WA_IconifyGadget: TypedTag<BOOL> = TypedTag::new(crate::WA_IconifyGadget);
+}
+
+/// Tags of `OpenScreenTagList()` (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod open_screen {
+    use crate::*;
+
+    pub const SA_Left: TypedTag<LONG> = TypedTag::new(crate::SA_Left);
+    pub const SA_Top: TypedTag<LONG> = TypedTag::new(crate::SA_Top);
+    pub const SA_Width: TypedTag<LONG> = TypedTag::new(crate::SA_Width);
+    pub const SA_Height: TypedTag<LONG> = TypedTag::new(crate::SA_Height);
+    pub const SA_Depth: TypedTag<LONG> = TypedTag::new(crate::SA_Depth);
+    pub const SA_DetailPen: TypedTag<UBYTE> = TypedTag::new(crate::SA_DetailPen);
+    pub const SA_BlockPen: TypedTag<UBYTE> = TypedTag::new(crate::SA_BlockPen);
+    pub const SA_Title: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SA_Title);
+    pub const SA_Colors: TypedTag<*mut ColorSpec> = TypedTag::new(crate::SA_Colors);
+    pub const SA_ErrorCode: TypedTag<*mut ULONG> = TypedTag::new(crate::SA_ErrorCode);
+    pub const SA_Font: TypedTag<*mut TextAttr> = TypedTag::new(crate::SA_Font);
+    pub const SA_SysFont: TypedTag<UWORD> = TypedTag::new(crate::SA_SysFont);
+    pub const SA_Type: TypedTag<ULONG> = TypedTag::new(crate::SA_Type);
+    pub const SA_BitMap: TypedTag<*mut BitMap> = TypedTag::new(crate::SA_BitMap);
+    pub const SA_PubName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SA_PubName);
+    pub const SA_PubSig: TypedTag<UBYTE> = TypedTag::new(crate::SA_PubSig);
+    pub const SA_PubTask: TypedTag<*mut Task> = TypedTag::new(crate::SA_PubTask);
+    pub const SA_DisplayID: TypedTag<ULONG> = TypedTag::new(crate::SA_DisplayID);
+    pub const SA_DClip: TypedTag<*mut Rectangle> = TypedTag::new(crate::SA_DClip);
+    pub const SA_Overscan: TypedTag<ULONG> = TypedTag::new(crate::SA_Overscan);
+    pub const SA_ShowTitle: TypedTag<BOOL> = TypedTag::new(crate::SA_ShowTitle);
+    pub const SA_Behind: TypedTag<BOOL> = TypedTag::new(crate::SA_Behind);
+    pub const SA_Quiet: TypedTag<BOOL> = TypedTag::new(crate::SA_Quiet);
+    pub const SA_AutoScroll: TypedTag<BOOL> = TypedTag::new(crate::SA_AutoScroll);
+    pub const SA_Pens: TypedTag<*mut UWORD> = TypedTag::new(crate::SA_Pens);
+    pub const SA_FullPalette: TypedTag<BOOL> = TypedTag::new(crate::SA_FullPalette);
+    pub const SA_ColorMapEntries: TypedTag<ULONG> = TypedTag::new(crate::SA_ColorMapEntries);
+    pub const SA_Parent: TypedTag<*mut Screen> = TypedTag::new(crate::SA_Parent);
+    pub const SA_Draggable: TypedTag<BOOL> = TypedTag::new(crate::SA_Draggable);
+    pub const SA_Exclusive: TypedTag<BOOL> = TypedTag::new(crate::SA_Exclusive);
+    pub const SA_SharePens: TypedTag<BOOL> = TypedTag::new(crate::SA_SharePens);
+    pub const SA_BackFill: TypedTag<*mut Hook> = TypedTag::new(crate::SA_BackFill);
+    pub const SA_Interleaved: TypedTag<BOOL> = TypedTag::new(crate::SA_Interleaved);
+    pub const SA_Colors32: TypedTag<*mut ULONG> = TypedTag::new(crate::SA_Colors32);
+    pub const SA_VideoControl: TypedTag<*mut TagItem> = TypedTag::new(crate::SA_VideoControl);
+    pub const SA_FrontChild: TypedTag<*mut Screen> = TypedTag::new(crate::SA_FrontChild);
+    pub const SA_BackChild: TypedTag<*mut Screen> = TypedTag::new(crate::SA_BackChild);
+    pub const SA_LikeWorkbench: TypedTag<BOOL> = TypedTag::new(crate::SA_LikeWorkbench);
+    pub const SA_MinimizeISG: TypedTag<BOOL> = TypedTag::new(crate::SA_MinimizeISG);
+    pub const SA_OffScreenDragging: TypedTag<BOOL> = TypedTag::new(crate::SA_OffScreenDragging);
+}
+
+/// Tags of `SystemTagList()` (dos.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod system {
+    use crate::*;
+
+    pub const SYS_Input: TypedTag<BPTR> = TypedTag::new(crate::SYS_Input);
+    pub const SYS_Output: TypedTag<BPTR> = TypedTag::new(crate::SYS_Output);
+    pub const SYS_Asynch: TypedTag<BOOL> = TypedTag::new(crate::SYS_Asynch);
+    pub const SYS_UserShell: TypedTag<BOOL> = TypedTag::new(crate::SYS_UserShell);
+    pub const SYS_CustomShell: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SYS_CustomShell);
+    pub const SYS_Error: TypedTag<BPTR> = TypedTag::new(crate::SYS_Error);
+    pub const SYS_CmdName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SYS_CmdName);
+}
+
+/// Tags of `CreateNewProc()` (dos.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod create_new_proc {
+    use crate::*;
+
+    pub const NP_Seglist: TypedTag<BPTR> = TypedTag::new(crate::NP_Seglist);
+    pub const NP_FreeSeglist: TypedTag<BOOL> = TypedTag::new(crate::NP_FreeSeglist);
+    pub const NP_Entry: TypedTag<FPTR> = TypedTag::new(crate::NP_Entry);
+    pub const NP_Input: TypedTag<BPTR> = TypedTag::new(crate::NP_Input);
+    pub const NP_Output: TypedTag<BPTR> = TypedTag::new(crate::NP_Output);
+    pub const NP_CloseInput: TypedTag<BOOL> = TypedTag::new(crate::NP_CloseInput);
+    pub const NP_CloseOutput: TypedTag<BOOL> = TypedTag::new(crate::NP_CloseOutput);
+    pub const NP_Error: TypedTag<BPTR> = TypedTag::new(crate::NP_Error);
+    pub const NP_CloseError: TypedTag<BOOL> = TypedTag::new(crate::NP_CloseError);
+    pub const NP_CurrentDir: TypedTag<BPTR> = TypedTag::new(crate::NP_CurrentDir);
+    pub const NP_StackSize: TypedTag<ULONG> = TypedTag::new(crate::NP_StackSize);
+    pub const NP_Name: TypedTag<CONST_STRPTR> = TypedTag::new(crate::NP_Name);
+    pub const NP_Priority: TypedTag<LONG> = TypedTag::new(crate::NP_Priority);
+    pub const NP_ConsoleTask: TypedTag<*mut MsgPort> = TypedTag::new(crate::NP_ConsoleTask);
+    pub const NP_WindowPtr: TypedTag<*mut Window> = TypedTag::new(crate::NP_WindowPtr);
+    pub const NP_HomeDir: TypedTag<BPTR> = TypedTag::new(crate::NP_HomeDir);
+    pub const NP_CopyVars: TypedTag<BOOL> = TypedTag::new(crate::NP_CopyVars);
+    pub const NP_Cli: TypedTag<BOOL> = TypedTag::new(crate::NP_Cli);
+    pub const NP_Path: TypedTag<BPTR> = TypedTag::new(crate::NP_Path);
+    pub const NP_CommandName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::NP_CommandName);
+    pub const NP_Arguments: TypedTag<CONST_STRPTR> = TypedTag::new(crate::NP_Arguments);
+    pub const NP_NotifyOnDeath: TypedTag<BOOL> = TypedTag::new(crate::NP_NotifyOnDeath);
+    pub const NP_Synchronous: TypedTag<BOOL> = TypedTag::new(crate::NP_Synchronous);
+    pub const NP_ExitCode: TypedTag<FPTR> = TypedTag::new(crate::NP_ExitCode);
+    pub const NP_ExitData: TypedTag<LONG> = TypedTag::new(crate::NP_ExitData);
+}
+
+/// Tags of `AllocAslRequest()` and `AslRequest()` for file requesters (asl.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod asl_file_requester {
+    use crate::*;
+
+    pub const ASLFR_Window: TypedTag<*mut Window> = TypedTag::new(crate::ASLFR_Window);
+    pub const ASLFR_Screen: TypedTag<*mut Screen> = TypedTag::new(crate::ASLFR_Screen);
+    pub const ASLFR_PubScreenName: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFR_PubScreenName);
+    pub const ASLFR_PrivateIDCMP: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_PrivateIDCMP);
+    pub const ASLFR_IntuiMsgFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLFR_IntuiMsgFunc);
+    pub const ASLFR_SleepWindow: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_SleepWindow);
+    pub const ASLFR_UserData: TypedTag<APTR> = TypedTag::new(crate::ASLFR_UserData);
+    pub const ASLFR_PopToFront: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_PopToFront);
+    pub const ASLFR_Activate: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_Activate);
+    pub const ASLFR_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::ASLFR_TextAttr);
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub const ASLFR_Locale: TypedTag<*mut Locale> = TypedTag::new(crate::ASLFR_Locale);
+    pub const ASLFR_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFR_TitleText);
+    pub const ASLFR_PositiveText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFR_PositiveText);
+    pub const ASLFR_NegativeText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFR_NegativeText);
+    pub const ASLFR_InitialLeftEdge: TypedTag<WORD> = TypedTag::new(crate::ASLFR_InitialLeftEdge);
+    pub const ASLFR_InitialTopEdge: TypedTag<WORD> = TypedTag::new(crate::ASLFR_InitialTopEdge);
+    pub const ASLFR_InitialWidth: TypedTag<WORD> = TypedTag::new(crate::ASLFR_InitialWidth);
+    pub const ASLFR_InitialHeight: TypedTag<WORD> = TypedTag::new(crate::ASLFR_InitialHeight);
+    pub const ASLFR_InitialFile: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFR_InitialFile);
+    pub const ASLFR_InitialDrawer: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFR_InitialDrawer);
+    pub const ASLFR_InitialPattern: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFR_InitialPattern);
+    pub const ASLFR_InitialShowVolumes: TypedTag<BOOL> =
+        TypedTag::new(crate::ASLFR_InitialShowVolumes);
+    pub const ASLFR_Flags1: TypedTag<ULONG> = TypedTag::new(crate::ASLFR_Flags1);
+    pub const ASLFR_Flags2: TypedTag<ULONG> = TypedTag::new(crate::ASLFR_Flags2);
+    pub const ASLFR_DoSaveMode: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_DoSaveMode);
+    pub const ASLFR_DoMultiSelect: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_DoMultiSelect);
+    pub const ASLFR_DoPatterns: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_DoPatterns);
+    pub const ASLFR_DrawersOnly: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_DrawersOnly);
+    pub const ASLFR_FilterFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLFR_FilterFunc);
+    pub const ASLFR_RejectIcons: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_RejectIcons);
+    pub const ASLFR_RejectPattern: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFR_RejectPattern);
+    pub const ASLFR_AcceptPattern: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFR_AcceptPattern);
+    pub const ASLFR_FilterDrawers: TypedTag<BOOL> = TypedTag::new(crate::ASLFR_FilterDrawers);
+    pub const ASLFR_HookFunc: TypedTag<FPTR> = TypedTag::new(crate::ASLFR_HookFunc);
+    pub const ASLFR_SetSortBy: TypedTag<ULONG> = TypedTag::new(crate::ASLFR_SetSortBy);
+    pub const ASLFR_SetSortDrawers: TypedTag<ULONG> = TypedTag::new(crate::ASLFR_SetSortDrawers);
+    pub const ASLFR_SetSortOrder: TypedTag<ULONG> = TypedTag::new(crate::ASLFR_SetSortOrder);
+}
+
+/// Attributes of requester.class (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod requester_class {
+    use crate::*;
+
+    pub const REQ_Type: TypedTag<ULONG> = TypedTag::new(crate::REQ_Type);
+    pub const REQ_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::REQ_TitleText);
+    pub const REQ_BodyText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::REQ_BodyText);
+    pub const REQ_GadgetText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::REQ_GadgetText);
+    pub const REQ_ReturnCode: TypedTag<ULONG> = TypedTag::new(crate::REQ_ReturnCode);
+    pub const REQ_TabSize: TypedTag<ULONG> = TypedTag::new(crate::REQ_TabSize);
+    pub const REQ_Image: TypedTag<ULONG> = TypedTag::new(crate::REQ_Image);
+    pub const REQ_VarArgs: TypedTag<APTR> = TypedTag::new(crate::REQ_VarArgs);
+    pub const REQ_EvenButtons: TypedTag<BOOL> = TypedTag::new(crate::REQ_EvenButtons);
+    pub const REQ_WrapBorder: TypedTag<ULONG> = TypedTag::new(crate::REQ_WrapBorder);
+    pub const REQ_TimeOutSecs: TypedTag<ULONG> = TypedTag::new(crate::REQ_TimeOutSecs);
+    pub const REQ_Inactive: TypedTag<BOOL> = TypedTag::new(crate::REQ_Inactive);
+    pub const REQ_CharSet: TypedTag<ULONG> = TypedTag::new(crate::REQ_CharSet);
+    pub const REQ_StayOnTop: TypedTag<BOOL> = TypedTag::new(crate::REQ_StayOnTop);
+    pub const REQ_ForceFocus: TypedTag<BOOL> = TypedTag::new(crate::REQ_ForceFocus);
+}
+
+/// Attributes of arexx.class (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod arexx_class {
+    use crate::*;
+
+    pub const AREXX_HostName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::AREXX_HostName);
+    pub const AREXX_DefExtension: TypedTag<CONST_STRPTR> = TypedTag::new(crate::AREXX_DefExtension);
+    pub const AREXX_Commands: TypedTag<*mut ARexxCmd> = TypedTag::new(crate::AREXX_Commands);
+    pub const AREXX_ErrorCode: TypedTag<*mut ULONG> = TypedTag::new(crate::AREXX_ErrorCode);
+    pub const AREXX_SigMask: TypedTag<ULONG> = TypedTag::new(crate::AREXX_SigMask);
+    pub const AREXX_NoSlot: TypedTag<BOOL> = TypedTag::new(crate::AREXX_NoSlot);
+    pub const AREXX_ReplyHook: TypedTag<*mut Hook> = TypedTag::new(crate::AREXX_ReplyHook);
+    pub const AREXX_MsgPort: TypedTag<*mut MsgPort> = TypedTag::new(crate::AREXX_MsgPort);
+}
+
+/// Attributes of gadgetclass, the base class of all BOOPSI gadgets (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod gadget_class {
+    use crate::*;
+
+    pub const GA_Left: TypedTag<LONG> = TypedTag::new(crate::GA_Left);
+    pub const GA_RelRight: TypedTag<LONG> = TypedTag::new(crate::GA_RelRight);
+    pub const GA_Top: TypedTag<LONG> = TypedTag::new(crate::GA_Top);
+    pub const GA_RelBottom: TypedTag<LONG> = TypedTag::new(crate::GA_RelBottom);
+    pub const GA_Width: TypedTag<LONG> = TypedTag::new(crate::GA_Width);
+    pub const GA_RelWidth: TypedTag<LONG> = TypedTag::new(crate::GA_RelWidth);
+    pub const GA_Height: TypedTag<LONG> = TypedTag::new(crate::GA_Height);
+    pub const GA_RelHeight: TypedTag<LONG> = TypedTag::new(crate::GA_RelHeight);
+    pub const GA_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GA_Text);
+    pub const GA_Image: TypedTag<*mut Image> = TypedTag::new(crate::GA_Image);
+    pub const GA_SelectRender: TypedTag<*mut Image> = TypedTag::new(crate::GA_SelectRender);
+    pub const GA_LabelImage: TypedTag<*mut Image> = TypedTag::new(crate::GA_LabelImage);
+    pub const GA_Border: TypedTag<*mut Border> = TypedTag::new(crate::GA_Border);
+    pub const GA_Highlight: TypedTag<UWORD> = TypedTag::new(crate::GA_Highlight);
+    pub const GA_ID: TypedTag<UWORD> = TypedTag::new(crate::GA_ID);
+    pub const GA_SysGType: TypedTag<UWORD> = TypedTag::new(crate::GA_SysGType);
+    pub const GA_Disabled: TypedTag<BOOL> = TypedTag::new(crate::GA_Disabled);
+    pub const GA_GZZGadget: TypedTag<BOOL> = TypedTag::new(crate::GA_GZZGadget);
+    pub const GA_Selected: TypedTag<BOOL> = TypedTag::new(crate::GA_Selected);
+    pub const GA_EndGadget: TypedTag<BOOL> = TypedTag::new(crate::GA_EndGadget);
+    pub const GA_Immediate: TypedTag<BOOL> = TypedTag::new(crate::GA_Immediate);
+    pub const GA_RelVerify: TypedTag<BOOL> = TypedTag::new(crate::GA_RelVerify);
+    pub const GA_FollowMouse: TypedTag<BOOL> = TypedTag::new(crate::GA_FollowMouse);
+    pub const GA_RightBorder: TypedTag<BOOL> = TypedTag::new(crate::GA_RightBorder);
+    pub const GA_LeftBorder: TypedTag<BOOL> = TypedTag::new(crate::GA_LeftBorder);
+    pub const GA_TopBorder: TypedTag<BOOL> = TypedTag::new(crate::GA_TopBorder);
+    pub const GA_BottomBorder: TypedTag<BOOL> = TypedTag::new(crate::GA_BottomBorder);
+    pub const GA_ToggleSelect: TypedTag<BOOL> = TypedTag::new(crate::GA_ToggleSelect);
+    pub const GA_SysGadget: TypedTag<BOOL> = TypedTag::new(crate::GA_SysGadget);
+    pub const GA_TabCycle: TypedTag<BOOL> = TypedTag::new(crate::GA_TabCycle);
+    pub const GA_GadgetHelp: TypedTag<BOOL> = TypedTag::new(crate::GA_GadgetHelp);
+    pub const GA_RelSpecial: TypedTag<BOOL> = TypedTag::new(crate::GA_RelSpecial);
+    pub const GA_ReadOnly: TypedTag<BOOL> = TypedTag::new(crate::GA_ReadOnly);
+    pub const GA_UserInput: TypedTag<BOOL> = TypedTag::new(crate::GA_UserInput);
+    pub const GA_Hidden: TypedTag<BOOL> = TypedTag::new(crate::GA_Hidden);
+    pub const GA_ParentHidden: TypedTag<BOOL> = TypedTag::new(crate::GA_ParentHidden);
+    pub const GA_UserData: TypedTag<APTR> = TypedTag::new(crate::GA_UserData);
+    pub const GA_SpecialInfo: TypedTag<APTR> = TypedTag::new(crate::GA_SpecialInfo);
+    pub const GA_Previous: TypedTag<*mut Gadget> = TypedTag::new(crate::GA_Previous);
+    pub const GA_Next: TypedTag<*mut Gadget> = TypedTag::new(crate::GA_Next);
+    pub const GA_DrawInfo: TypedTag<*mut DrawInfo> = TypedTag::new(crate::GA_DrawInfo);
+    pub const GA_IntuiText: TypedTag<*mut IntuiText> = TypedTag::new(crate::GA_IntuiText);
+    pub const GA_Bounds: TypedTag<*mut IBox> = TypedTag::new(crate::GA_Bounds);
+    pub const GA_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::GA_TextAttr);
+    pub const GA_Underscore: TypedTag<UBYTE> = TypedTag::new(crate::GA_Underscore);
+    pub const GA_ActivateKey: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GA_ActivateKey);
+    pub const GA_GadgetHelpText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GA_GadgetHelpText);
+    pub const GA_BackFill: TypedTag<*mut Hook> = TypedTag::new(crate::GA_BackFill);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const GA_CustomMousePointer: TypedTag<*mut Object> =
+        TypedTag::new(crate::GA_CustomMousePointer);
+    pub const GA_PointerType: TypedTag<ULONG> = TypedTag::new(crate::GA_PointerType);
+}
+
+/// Attributes of imageclass and sysiclass, the base classes of BOOPSI images (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod image_class {
+    use crate::*;
+
+    pub const IA_Left: TypedTag<WORD> = TypedTag::new(crate::IA_Left);
+    pub const IA_Top: TypedTag<WORD> = TypedTag::new(crate::IA_Top);
+    pub const IA_Width: TypedTag<WORD> = TypedTag::new(crate::IA_Width);
+    pub const IA_Height: TypedTag<WORD> = TypedTag::new(crate::IA_Height);
+    pub const IA_FGPen: TypedTag<WORD> = TypedTag::new(crate::IA_FGPen);
+    pub const IA_BGPen: TypedTag<WORD> = TypedTag::new(crate::IA_BGPen);
+    pub const IA_LineWidth: TypedTag<WORD> = TypedTag::new(crate::IA_LineWidth);
+    pub const IA_ShadowPen: TypedTag<WORD> = TypedTag::new(crate::IA_ShadowPen);
+    pub const IA_HighlightPen: TypedTag<WORD> = TypedTag::new(crate::IA_HighlightPen);
+    pub const IA_APatSize: TypedTag<WORD> = TypedTag::new(crate::IA_APatSize);
+    pub const IA_Data: TypedTag<APTR> = TypedTag::new(crate::IA_Data);
+    pub const IA_APattern: TypedTag<APTR> = TypedTag::new(crate::IA_APattern);
+    pub const IA_Pens: TypedTag<*mut UWORD> = TypedTag::new(crate::IA_Pens);
+    pub const IA_Resolution: TypedTag<ULONG> = TypedTag::new(crate::IA_Resolution);
+    pub const IA_Mode: TypedTag<ULONG> = TypedTag::new(crate::IA_Mode);
+    pub const IA_Precision: TypedTag<ULONG> = TypedTag::new(crate::IA_Precision);
+    pub const IA_Font: TypedTag<*mut TextFont> = TypedTag::new(crate::IA_Font);
+    pub const IA_Outline: TypedTag<BOOL> = TypedTag::new(crate::IA_Outline);
+    pub const IA_Recessed: TypedTag<BOOL> = TypedTag::new(crate::IA_Recessed);
+    pub const IA_DoubleEmboss: TypedTag<BOOL> = TypedTag::new(crate::IA_DoubleEmboss);
+    pub const IA_EdgesOnly: TypedTag<BOOL> = TypedTag::new(crate::IA_EdgesOnly);
+    pub const IA_SupportsDisable: TypedTag<BOOL> = TypedTag::new(crate::IA_SupportsDisable);
+    pub const IA_Scalable: TypedTag<BOOL> = TypedTag::new(crate::IA_Scalable);
+    pub const IA_EraseBackground: TypedTag<BOOL> = TypedTag::new(crate::IA_EraseBackground);
+    pub const IA_FrameType: TypedTag<UWORD> = TypedTag::new(crate::IA_FrameType);
+    pub const IA_Underscore: TypedTag<UBYTE> = TypedTag::new(crate::IA_Underscore);
+    pub const IA_ActivateKey: TypedTag<UBYTE> = TypedTag::new(crate::IA_ActivateKey);
+    pub const IA_Screen: TypedTag<*mut Screen> = TypedTag::new(crate::IA_Screen);
+    pub const IA_Label: TypedTag<CONST_STRPTR> = TypedTag::new(crate::IA_Label);
+    pub const SYSIA_Size: TypedTag<UWORD> = TypedTag::new(crate::SYSIA_Size);
+    pub const SYSIA_Which: TypedTag<UWORD> = TypedTag::new(crate::SYSIA_Which);
+    pub const SYSIA_DrawInfo: TypedTag<*mut DrawInfo> = TypedTag::new(crate::SYSIA_DrawInfo);
+    pub const SYSIA_ReferenceFont: TypedTag<*mut TextFont> =
+        TypedTag::new(crate::SYSIA_ReferenceFont);
+}
+
+/// Attributes of propgclass (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod prop_gadget {
+    use crate::*;
+
+    pub const PGA_Freedom: TypedTag<UWORD> = TypedTag::new(crate::PGA_Freedom);
+    pub const PGA_HorizPot: TypedTag<UWORD> = TypedTag::new(crate::PGA_HorizPot);
+    pub const PGA_HorizBody: TypedTag<UWORD> = TypedTag::new(crate::PGA_HorizBody);
+    pub const PGA_VertPot: TypedTag<UWORD> = TypedTag::new(crate::PGA_VertPot);
+    pub const PGA_VertBody: TypedTag<UWORD> = TypedTag::new(crate::PGA_VertBody);
+    pub const PGA_Total: TypedTag<UWORD> = TypedTag::new(crate::PGA_Total);
+    pub const PGA_Visible: TypedTag<UWORD> = TypedTag::new(crate::PGA_Visible);
+    pub const PGA_Top: TypedTag<UWORD> = TypedTag::new(crate::PGA_Top);
+    pub const PGA_Borderless: TypedTag<BOOL> = TypedTag::new(crate::PGA_Borderless);
+    pub const PGA_NewLook: TypedTag<BOOL> = TypedTag::new(crate::PGA_NewLook);
+    pub const PGA_KnobImage: TypedTag<*mut Image> = TypedTag::new(crate::PGA_KnobImage);
+}
+
+/// Attributes of strgclass (intuition.library) and string.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod string_gadget {
+    use crate::*;
+
+    pub const STRINGA_MaxChars: TypedTag<WORD> = TypedTag::new(crate::STRINGA_MaxChars);
+    pub const STRINGA_BufferPos: TypedTag<WORD> = TypedTag::new(crate::STRINGA_BufferPos);
+    pub const STRINGA_DispPos: TypedTag<WORD> = TypedTag::new(crate::STRINGA_DispPos);
+    pub const STRINGA_Buffer: TypedTag<STRPTR> = TypedTag::new(crate::STRINGA_Buffer);
+    pub const STRINGA_UndoBuffer: TypedTag<STRPTR> = TypedTag::new(crate::STRINGA_UndoBuffer);
+    pub const STRINGA_WorkBuffer: TypedTag<STRPTR> = TypedTag::new(crate::STRINGA_WorkBuffer);
+    pub const STRINGA_AltKeyMap: TypedTag<*mut KeyMap> = TypedTag::new(crate::STRINGA_AltKeyMap);
+    pub const STRINGA_Font: TypedTag<*mut TextFont> = TypedTag::new(crate::STRINGA_Font);
+    pub const STRINGA_Pens: TypedTag<LONG> = TypedTag::new(crate::STRINGA_Pens);
+    pub const STRINGA_ActivePens: TypedTag<LONG> = TypedTag::new(crate::STRINGA_ActivePens);
+    pub const STRINGA_LongVal: TypedTag<LONG> = TypedTag::new(crate::STRINGA_LongVal);
+    pub const STRINGA_EditHook: TypedTag<*mut Hook> = TypedTag::new(crate::STRINGA_EditHook);
+    pub const STRINGA_EditModes: TypedTag<ULONG> = TypedTag::new(crate::STRINGA_EditModes);
+    pub const STRINGA_HookType: TypedTag<ULONG> = TypedTag::new(crate::STRINGA_HookType);
+    pub const STRINGA_ReplaceMode: TypedTag<BOOL> = TypedTag::new(crate::STRINGA_ReplaceMode);
+    pub const STRINGA_FixedFieldMode: TypedTag<BOOL> = TypedTag::new(crate::STRINGA_FixedFieldMode);
+    pub const STRINGA_NoFilterMode: TypedTag<BOOL> = TypedTag::new(crate::STRINGA_NoFilterMode);
+    pub const STRINGA_ExitHelp: TypedTag<BOOL> = TypedTag::new(crate::STRINGA_ExitHelp);
+    pub const STRINGA_Justification: TypedTag<UWORD> = TypedTag::new(crate::STRINGA_Justification);
+    pub const STRINGA_MinVisible: TypedTag<UWORD> = TypedTag::new(crate::STRINGA_MinVisible);
+    pub const STRINGA_TextVal: TypedTag<CONST_STRPTR> = TypedTag::new(crate::STRINGA_TextVal);
+}
+
+/// Attributes of icclass, which notifies another object of attribute changes (intuition.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod ic_class {
+    use crate::*;
+
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const ICA_TARGET: TypedTag<*mut Object> = TypedTag::new(crate::ICA_TARGET);
+    pub const ICA_MAP: TypedTag<*mut TagItem> = TypedTag::new(crate::ICA_MAP);
+}
+
+/// Tags of `CreateGadgetA()` and `GT_SetGadgetAttrsA()` (gadtools.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod create_gadget {
+    use crate::*;
+
+    pub const GT_Underscore: TypedTag<UBYTE> = TypedTag::new(crate::GT_Underscore);
+    pub const GTCB_Checked: TypedTag<BOOL> = TypedTag::new(crate::GTCB_Checked);
+    pub const GTCB_Scaled: TypedTag<BOOL> = TypedTag::new(crate::GTCB_Scaled);
+    pub const GTLV_Top: TypedTag<UWORD> = TypedTag::new(crate::GTLV_Top);
+    pub const GTLV_ScrollWidth: TypedTag<UWORD> = TypedTag::new(crate::GTLV_ScrollWidth);
+    pub const GTLV_Selected: TypedTag<UWORD> = TypedTag::new(crate::GTLV_Selected);
+    pub const GTLV_ItemHeight: TypedTag<UWORD> = TypedTag::new(crate::GTLV_ItemHeight);
+    pub const GTLV_MaxPen: TypedTag<UWORD> = TypedTag::new(crate::GTLV_MaxPen);
+    pub const GTLV_Total: TypedTag<UWORD> = TypedTag::new(crate::GTLV_Total);
+    pub const GTLV_Visible: TypedTag<UWORD> = TypedTag::new(crate::GTLV_Visible);
+    pub const GTLV_MakeVisible: TypedTag<WORD> = TypedTag::new(crate::GTLV_MakeVisible);
+    pub const GTLV_Labels: TypedTag<*mut List> = TypedTag::new(crate::GTLV_Labels);
+    pub const GTLV_ReadOnly: TypedTag<BOOL> = TypedTag::new(crate::GTLV_ReadOnly);
+    pub const GTLV_ShowSelected: TypedTag<*mut Gadget> = TypedTag::new(crate::GTLV_ShowSelected);
+    pub const GTLV_CallBack: TypedTag<*mut Hook> = TypedTag::new(crate::GTLV_CallBack);
+    pub const GTMX_Labels: TypedTag<*mut CONST_STRPTR> = TypedTag::new(crate::GTMX_Labels);
+    pub const GTCY_Labels: TypedTag<*mut CONST_STRPTR> = TypedTag::new(crate::GTCY_Labels);
+    pub const GTMX_Active: TypedTag<UWORD> = TypedTag::new(crate::GTMX_Active);
+    pub const GTMX_Spacing: TypedTag<UWORD> = TypedTag::new(crate::GTMX_Spacing);
+    pub const GTCY_Active: TypedTag<UWORD> = TypedTag::new(crate::GTCY_Active);
+    pub const GTMX_Scaled: TypedTag<BOOL> = TypedTag::new(crate::GTMX_Scaled);
+    pub const GTMX_TitlePlace: TypedTag<ULONG> = TypedTag::new(crate::GTMX_TitlePlace);
+    pub const GTST_String: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GTST_String);
+    pub const GTTX_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GTTX_Text);
+    pub const GTSL_LevelFormat: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GTSL_LevelFormat);
+    pub const GTNM_Format: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GTNM_Format);
+    pub const GTST_MaxChars: TypedTag<UWORD> = TypedTag::new(crate::GTST_MaxChars);
+    pub const GTIN_MaxChars: TypedTag<UWORD> = TypedTag::new(crate::GTIN_MaxChars);
+    pub const GTSL_MaxLevelLen: TypedTag<UWORD> = TypedTag::new(crate::GTSL_MaxLevelLen);
+    pub const GTSC_Arrows: TypedTag<UWORD> = TypedTag::new(crate::GTSC_Arrows);
+    pub const GTST_EditHook: TypedTag<*mut Hook> = TypedTag::new(crate::GTST_EditHook);
+    pub const GTIN_EditHook: TypedTag<*mut Hook> = TypedTag::new(crate::GTIN_EditHook);
+    pub const GTIN_Number: TypedTag<LONG> = TypedTag::new(crate::GTIN_Number);
+    pub const GTNM_Number: TypedTag<LONG> = TypedTag::new(crate::GTNM_Number);
+    pub const GTSL_Min: TypedTag<WORD> = TypedTag::new(crate::GTSL_Min);
+    pub const GTSL_Max: TypedTag<WORD> = TypedTag::new(crate::GTSL_Max);
+    pub const GTSL_Level: TypedTag<WORD> = TypedTag::new(crate::GTSL_Level);
+    pub const GTSC_Top: TypedTag<WORD> = TypedTag::new(crate::GTSC_Top);
+    pub const GTSC_Total: TypedTag<WORD> = TypedTag::new(crate::GTSC_Total);
+    pub const GTSC_Visible: TypedTag<WORD> = TypedTag::new(crate::GTSC_Visible);
+    pub const GTSL_LevelPlace: TypedTag<ULONG> = TypedTag::new(crate::GTSL_LevelPlace);
+    pub const GTSL_MaxPixelLen: TypedTag<ULONG> = TypedTag::new(crate::GTSL_MaxPixelLen);
+    pub const GTNM_MaxNumberLen: TypedTag<ULONG> = TypedTag::new(crate::GTNM_MaxNumberLen);
+    pub const GTSL_DispFunc: TypedTag<FPTR> = TypedTag::new(crate::GTSL_DispFunc);
+    pub const GTSL_Justification: TypedTag<UBYTE> = TypedTag::new(crate::GTSL_Justification);
+    pub const GTTX_FrontPen: TypedTag<UBYTE> = TypedTag::new(crate::GTTX_FrontPen);
+    pub const GTTX_BackPen: TypedTag<UBYTE> = TypedTag::new(crate::GTTX_BackPen);
+    pub const GTTX_Justification: TypedTag<UBYTE> = TypedTag::new(crate::GTTX_Justification);
+    pub const GTNM_FrontPen: TypedTag<UBYTE> = TypedTag::new(crate::GTNM_FrontPen);
+    pub const GTNM_BackPen: TypedTag<UBYTE> = TypedTag::new(crate::GTNM_BackPen);
+    pub const GTNM_Justification: TypedTag<UBYTE> = TypedTag::new(crate::GTNM_Justification);
+    pub const GTPA_Depth: TypedTag<UWORD> = TypedTag::new(crate::GTPA_Depth);
+    pub const GTPA_IndicatorWidth: TypedTag<UWORD> = TypedTag::new(crate::GTPA_IndicatorWidth);
+    pub const GTPA_IndicatorHeight: TypedTag<UWORD> = TypedTag::new(crate::GTPA_IndicatorHeight);
+    pub const GTPA_NumColors: TypedTag<UWORD> = TypedTag::new(crate::GTPA_NumColors);
+    pub const GTPA_Color: TypedTag<UBYTE> = TypedTag::new(crate::GTPA_Color);
+    pub const GTPA_ColorOffset: TypedTag<UBYTE> = TypedTag::new(crate::GTPA_ColorOffset);
+    pub const GTPA_ColorTable: TypedTag<*mut UBYTE> = TypedTag::new(crate::GTPA_ColorTable);
+    pub const GTTX_CopyText: TypedTag<BOOL> = TypedTag::new(crate::GTTX_CopyText);
+    pub const GTTX_Border: TypedTag<BOOL> = TypedTag::new(crate::GTTX_Border);
+    pub const GTTX_Clipped: TypedTag<BOOL> = TypedTag::new(crate::GTTX_Clipped);
+    pub const GTNM_Border: TypedTag<BOOL> = TypedTag::new(crate::GTNM_Border);
+    pub const GTNM_Clipped: TypedTag<BOOL> = TypedTag::new(crate::GTNM_Clipped);
+}
+
+/// Tags of `CreateMenusA()` and `LayoutMenusA()` (gadtools.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod create_menus {
+    use crate::*;
+
+    pub const GTMN_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::GTMN_TextAttr);
+    pub const GTMN_FrontPen: TypedTag<ULONG> = TypedTag::new(crate::GTMN_FrontPen);
+    pub const GTMN_Menu: TypedTag<*mut Menu> = TypedTag::new(crate::GTMN_Menu);
+    pub const GTMN_FullMenu: TypedTag<BOOL> = TypedTag::new(crate::GTMN_FullMenu);
+    pub const GTMN_NewLookMenus: TypedTag<BOOL> = TypedTag::new(crate::GTMN_NewLookMenus);
+    pub const GTMN_SecondaryError: TypedTag<*mut ULONG> = TypedTag::new(crate::GTMN_SecondaryError);
+    pub const GTMN_Checkmark: TypedTag<*mut Image> = TypedTag::new(crate::GTMN_Checkmark);
+    pub const GTMN_AmigaKey: TypedTag<*mut Image> = TypedTag::new(crate::GTMN_AmigaKey);
+}
+
+/// Tags of `AllocAslRequest()` and `AslRequest()` for font requesters (asl.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod asl_font_requester {
+    use crate::*;
+
+    pub const ASLFO_Window: TypedTag<*mut Window> = TypedTag::new(crate::ASLFO_Window);
+    pub const ASLFO_Screen: TypedTag<*mut Screen> = TypedTag::new(crate::ASLFO_Screen);
+    pub const ASLFO_PubScreenName: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLFO_PubScreenName);
+    pub const ASLFO_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFO_TitleText);
+    pub const ASLFO_PositiveText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFO_PositiveText);
+    pub const ASLFO_NegativeText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFO_NegativeText);
+    pub const ASLFO_InitialName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFO_InitialName);
+    pub const ASLFO_SampleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLFO_SampleText);
+    pub const ASLFO_PrivateIDCMP: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_PrivateIDCMP);
+    pub const ASLFO_SleepWindow: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_SleepWindow);
+    pub const ASLFO_PopToFront: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_PopToFront);
+    pub const ASLFO_Activate: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_Activate);
+    pub const ASLFO_IntuiMsgFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLFO_IntuiMsgFunc);
+    pub const ASLFO_FilterFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLFO_FilterFunc);
+    pub const ASLFO_UserData: TypedTag<APTR> = TypedTag::new(crate::ASLFO_UserData);
+    pub const ASLFO_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::ASLFO_TextAttr);
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub const ASLFO_Locale: TypedTag<*mut Locale> = TypedTag::new(crate::ASLFO_Locale);
+    pub const ASLFO_InitialLeftEdge: TypedTag<WORD> = TypedTag::new(crate::ASLFO_InitialLeftEdge);
+    pub const ASLFO_InitialTopEdge: TypedTag<WORD> = TypedTag::new(crate::ASLFO_InitialTopEdge);
+    pub const ASLFO_InitialWidth: TypedTag<WORD> = TypedTag::new(crate::ASLFO_InitialWidth);
+    pub const ASLFO_InitialHeight: TypedTag<WORD> = TypedTag::new(crate::ASLFO_InitialHeight);
+    pub const ASLFO_InitialSize: TypedTag<UWORD> = TypedTag::new(crate::ASLFO_InitialSize);
+    pub const ASLFO_MinHeight: TypedTag<UWORD> = TypedTag::new(crate::ASLFO_MinHeight);
+    pub const ASLFO_MaxHeight: TypedTag<UWORD> = TypedTag::new(crate::ASLFO_MaxHeight);
+    pub const ASLFO_MaxFrontPen: TypedTag<UWORD> = TypedTag::new(crate::ASLFO_MaxFrontPen);
+    pub const ASLFO_MaxBackPen: TypedTag<UWORD> = TypedTag::new(crate::ASLFO_MaxBackPen);
+    pub const ASLFO_InitialStyle: TypedTag<UBYTE> = TypedTag::new(crate::ASLFO_InitialStyle);
+    pub const ASLFO_InitialFlags: TypedTag<UBYTE> = TypedTag::new(crate::ASLFO_InitialFlags);
+    pub const ASLFO_InitialFrontPen: TypedTag<UBYTE> = TypedTag::new(crate::ASLFO_InitialFrontPen);
+    pub const ASLFO_InitialBackPen: TypedTag<UBYTE> = TypedTag::new(crate::ASLFO_InitialBackPen);
+    pub const ASLFO_InitialDrawMode: TypedTag<UBYTE> = TypedTag::new(crate::ASLFO_InitialDrawMode);
+    pub const ASLFO_Flags: TypedTag<ULONG> = TypedTag::new(crate::ASLFO_Flags);
+    pub const ASLFO_DoFrontPen: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_DoFrontPen);
+    pub const ASLFO_DoBackPen: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_DoBackPen);
+    pub const ASLFO_DoStyle: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_DoStyle);
+    pub const ASLFO_DoDrawMode: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_DoDrawMode);
+    pub const ASLFO_FixedWidthOnly: TypedTag<BOOL> = TypedTag::new(crate::ASLFO_FixedWidthOnly);
+    pub const ASLFO_HookFunc: TypedTag<FPTR> = TypedTag::new(crate::ASLFO_HookFunc);
+    pub const ASLFO_ModeList: TypedTag<*mut CONST_STRPTR> = TypedTag::new(crate::ASLFO_ModeList);
+    pub const ASLFO_FrontPens: TypedTag<*mut UBYTE> = TypedTag::new(crate::ASLFO_FrontPens);
+    pub const ASLFO_BackPens: TypedTag<*mut UBYTE> = TypedTag::new(crate::ASLFO_BackPens);
+}
+
+/// Tags of `AllocAslRequest()` and `AslRequest()` for screen mode requesters (asl.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod asl_screen_mode_requester {
+    use crate::*;
+
+    pub const ASLSM_Window: TypedTag<*mut Window> = TypedTag::new(crate::ASLSM_Window);
+    pub const ASLSM_Screen: TypedTag<*mut Screen> = TypedTag::new(crate::ASLSM_Screen);
+    pub const ASLSM_PubScreenName: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::ASLSM_PubScreenName);
+    pub const ASLSM_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLSM_TitleText);
+    pub const ASLSM_PositiveText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLSM_PositiveText);
+    pub const ASLSM_NegativeText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::ASLSM_NegativeText);
+    pub const ASLSM_PrivateIDCMP: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_PrivateIDCMP);
+    pub const ASLSM_SleepWindow: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_SleepWindow);
+    pub const ASLSM_PopToFront: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_PopToFront);
+    pub const ASLSM_Activate: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_Activate);
+    pub const ASLSM_IntuiMsgFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLSM_IntuiMsgFunc);
+    pub const ASLSM_FilterFunc: TypedTag<*mut Hook> = TypedTag::new(crate::ASLSM_FilterFunc);
+    pub const ASLSM_UserData: TypedTag<APTR> = TypedTag::new(crate::ASLSM_UserData);
+    pub const ASLSM_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::ASLSM_TextAttr);
+    #[cfg(not(feature = "kickstart-v37"))]
+    pub const ASLSM_Locale: TypedTag<*mut Locale> = TypedTag::new(crate::ASLSM_Locale);
+    pub const ASLSM_InitialLeftEdge: TypedTag<WORD> = TypedTag::new(crate::ASLSM_InitialLeftEdge);
+    pub const ASLSM_InitialTopEdge: TypedTag<WORD> = TypedTag::new(crate::ASLSM_InitialTopEdge);
+    pub const ASLSM_InitialWidth: TypedTag<WORD> = TypedTag::new(crate::ASLSM_InitialWidth);
+    pub const ASLSM_InitialHeight: TypedTag<WORD> = TypedTag::new(crate::ASLSM_InitialHeight);
+    pub const ASLSM_InitialDisplayID: TypedTag<ULONG> =
+        TypedTag::new(crate::ASLSM_InitialDisplayID);
+    pub const ASLSM_InitialDisplayWidth: TypedTag<ULONG> =
+        TypedTag::new(crate::ASLSM_InitialDisplayWidth);
+    pub const ASLSM_InitialDisplayHeight: TypedTag<ULONG> =
+        TypedTag::new(crate::ASLSM_InitialDisplayHeight);
+    pub const ASLSM_InitialDisplayDepth: TypedTag<UWORD> =
+        TypedTag::new(crate::ASLSM_InitialDisplayDepth);
+    pub const ASLSM_InitialOverscanType: TypedTag<UWORD> =
+        TypedTag::new(crate::ASLSM_InitialOverscanType);
+    pub const ASLSM_MinDepth: TypedTag<UWORD> = TypedTag::new(crate::ASLSM_MinDepth);
+    pub const ASLSM_MaxDepth: TypedTag<UWORD> = TypedTag::new(crate::ASLSM_MaxDepth);
+    pub const ASLSM_InitialAutoScroll: TypedTag<BOOL> =
+        TypedTag::new(crate::ASLSM_InitialAutoScroll);
+    pub const ASLSM_InitialInfoOpened: TypedTag<BOOL> =
+        TypedTag::new(crate::ASLSM_InitialInfoOpened);
+    pub const ASLSM_InitialInfoLeftEdge: TypedTag<WORD> =
+        TypedTag::new(crate::ASLSM_InitialInfoLeftEdge);
+    pub const ASLSM_InitialInfoTopEdge: TypedTag<WORD> =
+        TypedTag::new(crate::ASLSM_InitialInfoTopEdge);
+    pub const ASLSM_DoWidth: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_DoWidth);
+    pub const ASLSM_DoHeight: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_DoHeight);
+    pub const ASLSM_DoDepth: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_DoDepth);
+    pub const ASLSM_DoOverscanType: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_DoOverscanType);
+    pub const ASLSM_DoAutoScroll: TypedTag<BOOL> = TypedTag::new(crate::ASLSM_DoAutoScroll);
+    pub const ASLSM_PropertyFlags: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_PropertyFlags);
+    pub const ASLSM_PropertyMask: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_PropertyMask);
+    pub const ASLSM_MinWidth: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_MinWidth);
+    pub const ASLSM_MaxWidth: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_MaxWidth);
+    pub const ASLSM_MinHeight: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_MinHeight);
+    pub const ASLSM_MaxHeight: TypedTag<ULONG> = TypedTag::new(crate::ASLSM_MaxHeight);
+    pub const ASLSM_CustomSMList: TypedTag<*mut List> = TypedTag::new(crate::ASLSM_CustomSMList);
+}
+
+/// Attributes of datatypesclass, the base class of all data type objects (datatypes.library)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod datatypes {
+    use crate::*;
+
+    pub const DTA_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::DTA_TextAttr);
+    pub const DTA_TopVert: TypedTag<LONG> = TypedTag::new(crate::DTA_TopVert);
+    pub const DTA_VisibleVert: TypedTag<LONG> = TypedTag::new(crate::DTA_VisibleVert);
+    pub const DTA_TotalVert: TypedTag<LONG> = TypedTag::new(crate::DTA_TotalVert);
+    pub const DTA_VertUnit: TypedTag<LONG> = TypedTag::new(crate::DTA_VertUnit);
+    pub const DTA_TopHoriz: TypedTag<LONG> = TypedTag::new(crate::DTA_TopHoriz);
+    pub const DTA_VisibleHoriz: TypedTag<LONG> = TypedTag::new(crate::DTA_VisibleHoriz);
+    pub const DTA_TotalHoriz: TypedTag<LONG> = TypedTag::new(crate::DTA_TotalHoriz);
+    pub const DTA_HorizUnit: TypedTag<LONG> = TypedTag::new(crate::DTA_HorizUnit);
+    pub const DTA_TotalPVert: TypedTag<LONG> = TypedTag::new(crate::DTA_TotalPVert);
+    pub const DTA_TotalPHoriz: TypedTag<LONG> = TypedTag::new(crate::DTA_TotalPHoriz);
+    pub const DTA_NominalVert: TypedTag<LONG> = TypedTag::new(crate::DTA_NominalVert);
+    pub const DTA_NominalHoriz: TypedTag<LONG> = TypedTag::new(crate::DTA_NominalHoriz);
+    pub const DTA_ErrorLevel: TypedTag<LONG> = TypedTag::new(crate::DTA_ErrorLevel);
+    pub const DTA_ErrorNumber: TypedTag<LONG> = TypedTag::new(crate::DTA_ErrorNumber);
+    pub const DTA_PrinterStatus: TypedTag<LONG> = TypedTag::new(crate::DTA_PrinterStatus);
+    pub const DTA_NodeName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_NodeName);
+    pub const DTA_Title: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_Title);
+    pub const DTA_BaseName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_BaseName);
+    pub const DTA_ErrorString: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ErrorString);
+    pub const DTA_Conductor: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_Conductor);
+    pub const DTA_Name: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_Name);
+    pub const DTA_ARexxPortName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ARexxPortName);
+    pub const DTA_ObjName: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ObjName);
+    pub const DTA_ObjAuthor: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ObjAuthor);
+    pub const DTA_ObjAnnotation: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ObjAnnotation);
+    pub const DTA_ObjCopyright: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ObjCopyright);
+    pub const DTA_ObjVersion: TypedTag<CONST_STRPTR> = TypedTag::new(crate::DTA_ObjVersion);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const DTA_TriggerMethods: TypedTag<*mut DTMethod> =
+        TypedTag::new(crate::DTA_TriggerMethods);
+    pub const DTA_Data: TypedTag<APTR> = TypedTag::new(crate::DTA_Data);
+    pub const DTA_SourceAddress: TypedTag<APTR> = TypedTag::new(crate::DTA_SourceAddress);
+    pub const DTA_UserData: TypedTag<APTR> = TypedTag::new(crate::DTA_UserData);
+    pub const DTA_TextFont: TypedTag<*mut TextFont> = TypedTag::new(crate::DTA_TextFont);
+    pub const DTA_Methods: TypedTag<*mut ULONG> = TypedTag::new(crate::DTA_Methods);
+    pub const DTA_Busy: TypedTag<BOOL> = TypedTag::new(crate::DTA_Busy);
+    pub const DTA_Sync: TypedTag<BOOL> = TypedTag::new(crate::DTA_Sync);
+    pub const DTA_ControlPanel: TypedTag<BOOL> = TypedTag::new(crate::DTA_ControlPanel);
+    pub const DTA_Immediate: TypedTag<BOOL> = TypedTag::new(crate::DTA_Immediate);
+    pub const DTA_Repeat: TypedTag<BOOL> = TypedTag::new(crate::DTA_Repeat);
+    pub const DTA_GroupID: TypedTag<ULONG> = TypedTag::new(crate::DTA_GroupID);
+    pub const DTA_SourceSize: TypedTag<ULONG> = TypedTag::new(crate::DTA_SourceSize);
+    pub const DTA_SourceType: TypedTag<ULONG> = TypedTag::new(crate::DTA_SourceType);
+    pub const DTA_ObjectID: TypedTag<ULONG> = TypedTag::new(crate::DTA_ObjectID);
+    pub const DTA_Handle: TypedTag<BPTR> = TypedTag::new(crate::DTA_Handle);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const DTA_DataType: TypedTag<*mut DataType> = TypedTag::new(crate::DTA_DataType);
+    pub const DTA_Domain: TypedTag<*mut IBox> = TypedTag::new(crate::DTA_Domain);
+    pub const DTA_SelectDomain: TypedTag<*mut IBox> = TypedTag::new(crate::DTA_SelectDomain);
+    pub const DTA_FrameInfo: TypedTag<*mut FrameInfo> = TypedTag::new(crate::DTA_FrameInfo);
+}
+
+/// Attributes of window.class (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod window_class {
+    use crate::*;
+
+    pub const WINDOW_Window: TypedTag<*mut Window> = TypedTag::new(crate::WINDOW_Window);
+    pub const WINDOW_RefWindow: TypedTag<*mut Window> = TypedTag::new(crate::WINDOW_RefWindow);
+    pub const WINDOW_SigMask: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_SigMask);
+    pub const WINDOW_FrontBack: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_FrontBack);
+    pub const WINDOW_Position: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_Position);
+    pub const WINDOW_IDCMPHookBits: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_IDCMPHookBits);
+    pub const WINDOW_GadgetUserData: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_GadgetUserData);
+    pub const WINDOW_MenuUserData: TypedTag<ULONG> = TypedTag::new(crate::WINDOW_MenuUserData);
+    pub const WINDOW_MenuStrip: TypedTag<*mut Menu> = TypedTag::new(crate::WINDOW_MenuStrip);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const WINDOW_Layout: TypedTag<*mut Object> = TypedTag::new(crate::WINDOW_Layout);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const WINDOW_ParentLayout: TypedTag<*mut Object> =
+        TypedTag::new(crate::WINDOW_ParentLayout);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const WINDOW_ParentGroup: TypedTag<*mut Object> = TypedTag::new(crate::WINDOW_ParentGroup);
+    pub const WINDOW_UserData: TypedTag<APTR> = TypedTag::new(crate::WINDOW_UserData);
+    pub const WINDOW_SharedPort: TypedTag<*mut MsgPort> = TypedTag::new(crate::WINDOW_SharedPort);
+    pub const WINDOW_AppPort: TypedTag<*mut MsgPort> = TypedTag::new(crate::WINDOW_AppPort);
+    pub const WINDOW_Zoom: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_Zoom);
+    pub const WINDOW_Activate: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_Activate);
+    pub const WINDOW_LockWidth: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_LockWidth);
+    pub const WINDOW_LockHeight: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_LockHeight);
+    pub const WINDOW_AppWindow: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_AppWindow);
+    pub const WINDOW_GadgetHelp: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_GadgetHelp);
+    pub const WINDOW_IconifyGadget: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_IconifyGadget);
+    pub const WINDOW_IconNoDispose: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_IconNoDispose);
+    pub const WINDOW_JumpScreensMenu: TypedTag<BOOL> = TypedTag::new(crate::WINDOW_JumpScreensMenu);
+    pub const WINDOW_IDCMPHook: TypedTag<*mut Hook> = TypedTag::new(crate::WINDOW_IDCMPHook);
+    pub const WINDOW_AppMsgHook: TypedTag<*mut Hook> = TypedTag::new(crate::WINDOW_AppMsgHook);
+    pub const WINDOW_IconTitle: TypedTag<CONST_STRPTR> = TypedTag::new(crate::WINDOW_IconTitle);
+    pub const WINDOW_BackFillName: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::WINDOW_BackFillName);
+    pub const WINDOW_UniqueID: TypedTag<CONST_STRPTR> = TypedTag::new(crate::WINDOW_UniqueID);
+    pub const WINDOW_Icon: TypedTag<*mut DiskObject> = TypedTag::new(crate::WINDOW_Icon);
+    pub const WINDOW_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::WINDOW_TextAttr);
+    pub const WINDOW_InputEvent: TypedTag<*mut InputEvent> =
+        TypedTag::new(crate::WINDOW_InputEvent);
+    pub const WINDOW_HintInfo: TypedTag<*mut HintInfo> = TypedTag::new(crate::WINDOW_HintInfo);
+    pub const WINDOW_NewMenu: TypedTag<*mut NewMenu> = TypedTag::new(crate::WINDOW_NewMenu);
+    pub const WINDOW_AppWindowPtr: TypedTag<*mut AppWindow> =
+        TypedTag::new(crate::WINDOW_AppWindowPtr);
+}
+
+/// Attributes of layout.gadget and of its children (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod layout_gadget {
+    use crate::*;
+
+    pub const LAYOUT_Orientation: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_Orientation);
+    pub const LAYOUT_HorizAlignment: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_HorizAlignment);
+    pub const LAYOUT_VertAlignment: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_VertAlignment);
+    pub const LAYOUT_LabelPlace: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_LabelPlace);
+    pub const LAYOUT_BevelState: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_BevelState);
+    pub const LAYOUT_BevelStyle: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_BevelStyle);
+    pub const LAYOUT_InnerSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_InnerSpacing);
+    pub const LAYOUT_HorizSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_HorizSpacing);
+    pub const LAYOUT_VertSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_VertSpacing);
+    pub const LAYOUT_TopSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_TopSpacing);
+    pub const LAYOUT_BottomSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_BottomSpacing);
+    pub const LAYOUT_LeftSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_LeftSpacing);
+    pub const LAYOUT_RightSpacing: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_RightSpacing);
+    pub const LAYOUT_LabelColumn: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_LabelColumn);
+    pub const LAYOUT_LabelWidth: TypedTag<ULONG> = TypedTag::new(crate::LAYOUT_LabelWidth);
+    pub const LAYOUT_FixedHoriz: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_FixedHoriz);
+    pub const LAYOUT_FixedVert: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_FixedVert);
+    pub const LAYOUT_ShrinkWrap: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_ShrinkWrap);
+    pub const LAYOUT_EvenSize: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_EvenSize);
+    pub const LAYOUT_RelVerify: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_RelVerify);
+    pub const LAYOUT_DeferLayout: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_DeferLayout);
+    pub const LAYOUT_RequestLayout: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_RequestLayout);
+    pub const LAYOUT_RequestRefresh: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_RequestRefresh);
+    pub const LAYOUT_SpaceInner: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_SpaceInner);
+    pub const LAYOUT_SpaceOuter: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_SpaceOuter);
+    pub const LAYOUT_Inverted: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_Inverted);
+    pub const LAYOUT_WeightBar: TypedTag<BOOL> = TypedTag::new(crate::LAYOUT_WeightBar);
+    pub const LAYOUT_Label: TypedTag<CONST_STRPTR> = TypedTag::new(crate::LAYOUT_Label);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_LabelImage: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_LabelImage);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_RemoveChild: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_RemoveChild);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_AddChild: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_AddChild);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_AddImage: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_AddImage);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_ModifyChild: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_ModifyChild);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_Parent: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_Parent);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const LAYOUT_AlignLabels: TypedTag<*mut Object> = TypedTag::new(crate::LAYOUT_AlignLabels);
+    pub const LAYOUT_TextPen: TypedTag<LONG> = TypedTag::new(crate::LAYOUT_TextPen);
+    pub const LAYOUT_FillPen: TypedTag<LONG> = TypedTag::new(crate::LAYOUT_FillPen);
+    pub const LAYOUT_FillPattern: TypedTag<*mut UWORD> = TypedTag::new(crate::LAYOUT_FillPattern);
+    pub const LAYOUT_BackFill: TypedTag<*mut Hook> = TypedTag::new(crate::LAYOUT_BackFill);
+    pub const CHILD_MinWidth: TypedTag<UWORD> = TypedTag::new(crate::CHILD_MinWidth);
+    pub const CHILD_MinHeight: TypedTag<UWORD> = TypedTag::new(crate::CHILD_MinHeight);
+    pub const CHILD_MaxWidth: TypedTag<UWORD> = TypedTag::new(crate::CHILD_MaxWidth);
+    pub const CHILD_MaxHeight: TypedTag<UWORD> = TypedTag::new(crate::CHILD_MaxHeight);
+    pub const CHILD_WeightedWidth: TypedTag<UWORD> = TypedTag::new(crate::CHILD_WeightedWidth);
+    pub const CHILD_WeightedHeight: TypedTag<UWORD> = TypedTag::new(crate::CHILD_WeightedHeight);
+    pub const CHILD_ScaleWidth: TypedTag<UWORD> = TypedTag::new(crate::CHILD_ScaleWidth);
+    pub const CHILD_ScaleHeight: TypedTag<UWORD> = TypedTag::new(crate::CHILD_ScaleHeight);
+    pub const CHILD_NominalSize: TypedTag<BOOL> = TypedTag::new(crate::CHILD_NominalSize);
+    pub const CHILD_CacheDomain: TypedTag<BOOL> = TypedTag::new(crate::CHILD_CacheDomain);
+    pub const CHILD_WeightMinimum: TypedTag<BOOL> = TypedTag::new(crate::CHILD_WeightMinimum);
+    pub const CHILD_NoDispose: TypedTag<BOOL> = TypedTag::new(crate::CHILD_NoDispose);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const CHILD_ReplaceObject: TypedTag<*mut Object> =
+        TypedTag::new(crate::CHILD_ReplaceObject);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const CHILD_ReplaceImage: TypedTag<*mut Object> = TypedTag::new(crate::CHILD_ReplaceImage);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const CHILD_Label: TypedTag<*mut Object> = TypedTag::new(crate::CHILD_Label);
+}
+
+/// Attributes of button.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod button_gadget {
+    use crate::*;
+
+    pub const BUTTON_PushButton: TypedTag<BOOL> = TypedTag::new(crate::BUTTON_PushButton);
+    pub const BUTTON_Transparent: TypedTag<BOOL> = TypedTag::new(crate::BUTTON_Transparent);
+    pub const BUTTON_TextPen: TypedTag<LONG> = TypedTag::new(crate::BUTTON_TextPen);
+    pub const BUTTON_FillPen: TypedTag<LONG> = TypedTag::new(crate::BUTTON_FillPen);
+    pub const BUTTON_FillTextPen: TypedTag<LONG> = TypedTag::new(crate::BUTTON_FillTextPen);
+    pub const BUTTON_BackgroundPen: TypedTag<LONG> = TypedTag::new(crate::BUTTON_BackgroundPen);
+    pub const BUTTON_RenderImage: TypedTag<*mut Image> = TypedTag::new(crate::BUTTON_RenderImage);
+    pub const BUTTON_SelectImage: TypedTag<*mut Image> = TypedTag::new(crate::BUTTON_SelectImage);
+    pub const BUTTON_BevelStyle: TypedTag<UWORD> = TypedTag::new(crate::BUTTON_BevelStyle);
+    pub const BUTTON_Justification: TypedTag<UWORD> = TypedTag::new(crate::BUTTON_Justification);
+    pub const BUTTON_AutoButton: TypedTag<UWORD> = TypedTag::new(crate::BUTTON_AutoButton);
+    pub const BUTTON_SoftStyle: TypedTag<UBYTE> = TypedTag::new(crate::BUTTON_SoftStyle);
+    pub const BUTTON_VarArgs: TypedTag<APTR> = TypedTag::new(crate::BUTTON_VarArgs);
+    pub const BUTTON_DomainString: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::BUTTON_DomainString);
+    pub const BUTTON_Integer: TypedTag<LONG> = TypedTag::new(crate::BUTTON_Integer);
+    pub const BUTTON_BitMap: TypedTag<*mut BitMap> = TypedTag::new(crate::BUTTON_BitMap);
+}
+
+/// Attributes of checkbox.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod checkbox_gadget {
+    use crate::*;
+
+    pub const CHECKBOX_TextPen: TypedTag<WORD> = TypedTag::new(crate::CHECKBOX_TextPen);
+    pub const CHECKBOX_FillTextPen: TypedTag<WORD> = TypedTag::new(crate::CHECKBOX_FillTextPen);
+    pub const CHECKBOX_BackgroundPen: TypedTag<WORD> = TypedTag::new(crate::CHECKBOX_BackgroundPen);
+    pub const CHECKBOX_TextPlace: TypedTag<ULONG> = TypedTag::new(crate::CHECKBOX_TextPlace);
+    pub const CHECKBOX_Checked: TypedTag<BOOL> = TypedTag::new(crate::CHECKBOX_Checked);
+}
+
+/// Attributes of chooser.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod chooser_gadget {
+    use crate::*;
+
+    pub const CHOOSER_PopUp: TypedTag<BOOL> = TypedTag::new(crate::CHOOSER_PopUp);
+    pub const CHOOSER_DropDown: TypedTag<BOOL> = TypedTag::new(crate::CHOOSER_DropDown);
+    pub const CHOOSER_AutoFit: TypedTag<BOOL> = TypedTag::new(crate::CHOOSER_AutoFit);
+    pub const CHOOSER_Hidden: TypedTag<BOOL> = TypedTag::new(crate::CHOOSER_Hidden);
+    pub const CHOOSER_Title: TypedTag<CONST_STRPTR> = TypedTag::new(crate::CHOOSER_Title);
+    pub const CHOOSER_Labels: TypedTag<*mut List> = TypedTag::new(crate::CHOOSER_Labels);
+    pub const CHOOSER_Active: TypedTag<WORD> = TypedTag::new(crate::CHOOSER_Active);
+    pub const CHOOSER_Selected: TypedTag<WORD> = TypedTag::new(crate::CHOOSER_Selected);
+    pub const CHOOSER_Width: TypedTag<WORD> = TypedTag::new(crate::CHOOSER_Width);
+    pub const CHOOSER_MaxLabels: TypedTag<WORD> = TypedTag::new(crate::CHOOSER_MaxLabels);
+    pub const CHOOSER_Offset: TypedTag<WORD> = TypedTag::new(crate::CHOOSER_Offset);
+    pub const CHOOSER_LabelArray: TypedTag<*mut CONST_STRPTR> =
+        TypedTag::new(crate::CHOOSER_LabelArray);
+    pub const CHOOSER_Justification: TypedTag<ULONG> = TypedTag::new(crate::CHOOSER_Justification);
+    pub const CHOOSER_SelectedNode: TypedTag<*mut Node> =
+        TypedTag::new(crate::CHOOSER_SelectedNode);
+}
+
+/// Tags of `AllocChooserNodeA()` and `SetChooserNodeAttrsA()` (chooser.gadget)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod chooser_node {
+    use crate::*;
+
+    pub const CNA_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::CNA_Text);
+    pub const CNA_Image: TypedTag<*mut Image> = TypedTag::new(crate::CNA_Image);
+    pub const CNA_SelImage: TypedTag<*mut Image> = TypedTag::new(crate::CNA_SelImage);
+    pub const CNA_UserData: TypedTag<APTR> = TypedTag::new(crate::CNA_UserData);
+    pub const CNA_Separator: TypedTag<BOOL> = TypedTag::new(crate::CNA_Separator);
+    pub const CNA_Disabled: TypedTag<BOOL> = TypedTag::new(crate::CNA_Disabled);
+    pub const CNA_ReadOnly: TypedTag<BOOL> = TypedTag::new(crate::CNA_ReadOnly);
+    pub const CNA_CopyText: TypedTag<BOOL> = TypedTag::new(crate::CNA_CopyText);
+    pub const CNA_BGPen: TypedTag<WORD> = TypedTag::new(crate::CNA_BGPen);
+    pub const CNA_FGPen: TypedTag<WORD> = TypedTag::new(crate::CNA_FGPen);
+}
+
+/// Attributes of clicktab.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod clicktab_gadget {
+    use crate::*;
+
+    pub const CLICKTAB_Labels: TypedTag<*mut List> = TypedTag::new(crate::CLICKTAB_Labels);
+    pub const CLICKTAB_Current: TypedTag<LONG> = TypedTag::new(crate::CLICKTAB_Current);
+    pub const CLICKTAB_Total: TypedTag<LONG> = TypedTag::new(crate::CLICKTAB_Total);
+    pub const CLICKTAB_CurrentNode: TypedTag<*mut Node> =
+        TypedTag::new(crate::CLICKTAB_CurrentNode);
+    pub const CLICKTAB_Orientation: TypedTag<ULONG> = TypedTag::new(crate::CLICKTAB_Orientation);
+    #[cfg(not(feature = "kickstart-v39"))]
+    pub const CLICKTAB_PageGroup: TypedTag<*mut Object> = TypedTag::new(crate::CLICKTAB_PageGroup);
+    pub const CLICKTAB_PageGroupBackFill: TypedTag<*mut Hook> =
+        TypedTag::new(crate::CLICKTAB_PageGroupBackFill);
+    pub const CLICKTAB_LabelTruncate: TypedTag<BOOL> = TypedTag::new(crate::CLICKTAB_LabelTruncate);
+    pub const CLICKTAB_EvenSize: TypedTag<BOOL> = TypedTag::new(crate::CLICKTAB_EvenSize);
+}
+
+/// Tags of `AllocClickTabNodeA()` and `SetClickTabNodeAttrsA()` (clicktab.gadget)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod clicktab_node {
+    use crate::*;
+
+    pub const TNA_UserData: TypedTag<APTR> = TypedTag::new(crate::TNA_UserData);
+    pub const TNA_Enabled: TypedTag<BOOL> = TypedTag::new(crate::TNA_Enabled);
+    pub const TNA_Highlight: TypedTag<BOOL> = TypedTag::new(crate::TNA_Highlight);
+    pub const TNA_Disabled: TypedTag<BOOL> = TypedTag::new(crate::TNA_Disabled);
+    pub const TNA_Flagged: TypedTag<BOOL> = TypedTag::new(crate::TNA_Flagged);
+    pub const TNA_CloseGadget: TypedTag<BOOL> = TypedTag::new(crate::TNA_CloseGadget);
+    pub const TNA_Spacing: TypedTag<WORD> = TypedTag::new(crate::TNA_Spacing);
+    pub const TNA_TextPen: TypedTag<WORD> = TypedTag::new(crate::TNA_TextPen);
+    pub const TNA_Image: TypedTag<*mut Image> = TypedTag::new(crate::TNA_Image);
+    pub const TNA_SelImage: TypedTag<*mut Image> = TypedTag::new(crate::TNA_SelImage);
+    pub const TNA_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::TNA_Text);
+    pub const TNA_HintInfo: TypedTag<CONST_STRPTR> = TypedTag::new(crate::TNA_HintInfo);
+    pub const TNA_Number: TypedTag<LONG> = TypedTag::new(crate::TNA_Number);
+}
+
+/// Attributes of integer.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod integer_gadget {
+    use crate::*;
+
+    pub const INTEGER_Number: TypedTag<LONG> = TypedTag::new(crate::INTEGER_Number);
+    pub const INTEGER_Minimum: TypedTag<LONG> = TypedTag::new(crate::INTEGER_Minimum);
+    pub const INTEGER_Maximum: TypedTag<LONG> = TypedTag::new(crate::INTEGER_Maximum);
+    pub const INTEGER_SkipVal: TypedTag<LONG> = TypedTag::new(crate::INTEGER_SkipVal);
+    pub const INTEGER_MaxChars: TypedTag<WORD> = TypedTag::new(crate::INTEGER_MaxChars);
+    pub const INTEGER_MinVisible: TypedTag<WORD> = TypedTag::new(crate::INTEGER_MinVisible);
+    pub const INTEGER_Arrows: TypedTag<BOOL> = TypedTag::new(crate::INTEGER_Arrows);
+}
+
+/// Attributes of slider.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod slider_gadget {
+    use crate::*;
+
+    pub const SLIDER_Min: TypedTag<LONG> = TypedTag::new(crate::SLIDER_Min);
+    pub const SLIDER_Max: TypedTag<LONG> = TypedTag::new(crate::SLIDER_Max);
+    pub const SLIDER_Level: TypedTag<LONG> = TypedTag::new(crate::SLIDER_Level);
+    pub const SLIDER_Ticks: TypedTag<LONG> = TypedTag::new(crate::SLIDER_Ticks);
+    pub const SLIDER_Orientation: TypedTag<ULONG> = TypedTag::new(crate::SLIDER_Orientation);
+    pub const SLIDER_LevelPlace: TypedTag<ULONG> = TypedTag::new(crate::SLIDER_LevelPlace);
+    pub const SLIDER_DispHook: TypedTag<*mut Hook> = TypedTag::new(crate::SLIDER_DispHook);
+    pub const SLIDER_LevelHook: TypedTag<*mut Hook> = TypedTag::new(crate::SLIDER_LevelHook);
+    pub const SLIDER_ShortTicks: TypedTag<BOOL> = TypedTag::new(crate::SLIDER_ShortTicks);
+    pub const SLIDER_Invert: TypedTag<BOOL> = TypedTag::new(crate::SLIDER_Invert);
+    pub const SLIDER_TickSize: TypedTag<WORD> = TypedTag::new(crate::SLIDER_TickSize);
+    pub const SLIDER_BodyFill: TypedTag<WORD> = TypedTag::new(crate::SLIDER_BodyFill);
+    pub const SLIDER_KnobDelta: TypedTag<WORD> = TypedTag::new(crate::SLIDER_KnobDelta);
+    pub const SLIDER_KnobImage: TypedTag<*mut Image> = TypedTag::new(crate::SLIDER_KnobImage);
+    pub const SLIDER_BodyImage: TypedTag<*mut Image> = TypedTag::new(crate::SLIDER_BodyImage);
+    pub const SLIDER_LevelFormat: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SLIDER_LevelFormat);
+    pub const SLIDER_LevelDomain: TypedTag<CONST_STRPTR> = TypedTag::new(crate::SLIDER_LevelDomain);
+    pub const SLIDER_LevelJustify: TypedTag<UWORD> = TypedTag::new(crate::SLIDER_LevelJustify);
+    pub const SLIDER_LevelMaxLen: TypedTag<UWORD> = TypedTag::new(crate::SLIDER_LevelMaxLen);
+}
+
+/// Attributes of scroller.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod scroller_gadget {
+    use crate::*;
+
+    pub const SCROLLER_Top: TypedTag<LONG> = TypedTag::new(crate::SCROLLER_Top);
+    pub const SCROLLER_Visible: TypedTag<LONG> = TypedTag::new(crate::SCROLLER_Visible);
+    pub const SCROLLER_Total: TypedTag<LONG> = TypedTag::new(crate::SCROLLER_Total);
+    pub const SCROLLER_Orientation: TypedTag<ULONG> = TypedTag::new(crate::SCROLLER_Orientation);
+    pub const SCROLLER_SignalTaskBit: TypedTag<ULONG> =
+        TypedTag::new(crate::SCROLLER_SignalTaskBit);
+    pub const SCROLLER_Arrows: TypedTag<BOOL> = TypedTag::new(crate::SCROLLER_Arrows);
+    pub const SCROLLER_Stretch: TypedTag<BOOL> = TypedTag::new(crate::SCROLLER_Stretch);
+    pub const SCROLLER_ArrowDelta: TypedTag<WORD> = TypedTag::new(crate::SCROLLER_ArrowDelta);
+    pub const SCROLLER_SignalTask: TypedTag<*mut Task> = TypedTag::new(crate::SCROLLER_SignalTask);
+}
+
+/// Attributes of fuelgauge.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod fuelgauge_gadget {
+    use crate::*;
+
+    pub const FUELGAUGE_Min: TypedTag<LONG> = TypedTag::new(crate::FUELGAUGE_Min);
+    pub const FUELGAUGE_Max: TypedTag<LONG> = TypedTag::new(crate::FUELGAUGE_Max);
+    pub const FUELGAUGE_Level: TypedTag<LONG> = TypedTag::new(crate::FUELGAUGE_Level);
+    pub const FUELGAUGE_Orientation: TypedTag<ULONG> = TypedTag::new(crate::FUELGAUGE_Orientation);
+    pub const FUELGAUGE_Percent: TypedTag<BOOL> = TypedTag::new(crate::FUELGAUGE_Percent);
+    pub const FUELGAUGE_ShortTicks: TypedTag<BOOL> = TypedTag::new(crate::FUELGAUGE_ShortTicks);
+    pub const FUELGAUGE_Ticks: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_Ticks);
+    pub const FUELGAUGE_TickSize: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_TickSize);
+    pub const FUELGAUGE_TickPen: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_TickPen);
+    pub const FUELGAUGE_PercentPen: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_PercentPen);
+    pub const FUELGAUGE_FillPen: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_FillPen);
+    pub const FUELGAUGE_EmptyPen: TypedTag<WORD> = TypedTag::new(crate::FUELGAUGE_EmptyPen);
+    pub const FUELGAUGE_VarArgs: TypedTag<APTR> = TypedTag::new(crate::FUELGAUGE_VarArgs);
+    pub const FUELGAUGE_Justification: TypedTag<UWORD> =
+        TypedTag::new(crate::FUELGAUGE_Justification);
+}
+
+/// Attributes of space.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod space_gadget {
+    use crate::*;
+
+    pub const SPACE_MinHeight: TypedTag<WORD> = TypedTag::new(crate::SPACE_MinHeight);
+    pub const SPACE_MinWidth: TypedTag<WORD> = TypedTag::new(crate::SPACE_MinWidth);
+    pub const SPACE_MouseX: TypedTag<WORD> = TypedTag::new(crate::SPACE_MouseX);
+    pub const SPACE_MouseY: TypedTag<WORD> = TypedTag::new(crate::SPACE_MouseY);
+    pub const SPACE_Transparent: TypedTag<BOOL> = TypedTag::new(crate::SPACE_Transparent);
+    pub const SPACE_AreaBox: TypedTag<*mut IBox> = TypedTag::new(crate::SPACE_AreaBox);
+    pub const SPACE_RenderBox: TypedTag<*mut IBox> = TypedTag::new(crate::SPACE_RenderBox);
+    pub const SPACE_RenderHook: TypedTag<*mut Hook> = TypedTag::new(crate::SPACE_RenderHook);
+    pub const SPACE_BevelStyle: TypedTag<UWORD> = TypedTag::new(crate::SPACE_BevelStyle);
+}
+
+/// Attributes of bevel.image (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod bevel_image {
+    use crate::*;
+
+    pub const BEVEL_Style: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_Style);
+    pub const BEVEL_LabelPlace: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_LabelPlace);
+    pub const BEVEL_InnerTop: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_InnerTop);
+    pub const BEVEL_InnerLeft: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_InnerLeft);
+    pub const BEVEL_InnerWidth: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_InnerWidth);
+    pub const BEVEL_InnerHeight: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_InnerHeight);
+    pub const BEVEL_HorizSize: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_HorizSize);
+    pub const BEVEL_VertSize: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_VertSize);
+    pub const BEVEL_Flags: TypedTag<UWORD> = TypedTag::new(crate::BEVEL_Flags);
+    pub const BEVEL_Label: TypedTag<CONST_STRPTR> = TypedTag::new(crate::BEVEL_Label);
+    pub const BEVEL_LabelImage: TypedTag<*mut Image> = TypedTag::new(crate::BEVEL_LabelImage);
+    pub const BEVEL_FillPen: TypedTag<WORD> = TypedTag::new(crate::BEVEL_FillPen);
+    pub const BEVEL_TextPen: TypedTag<WORD> = TypedTag::new(crate::BEVEL_TextPen);
+    pub const BEVEL_FillPattern: TypedTag<*mut UWORD> = TypedTag::new(crate::BEVEL_FillPattern);
+    pub const BEVEL_Transparent: TypedTag<BOOL> = TypedTag::new(crate::BEVEL_Transparent);
+    pub const BEVEL_SoftStyle: TypedTag<UBYTE> = TypedTag::new(crate::BEVEL_SoftStyle);
+    pub const BEVEL_ColorMap: TypedTag<*mut ColorMap> = TypedTag::new(crate::BEVEL_ColorMap);
+}
+
+/// Attributes of label.image (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod label_image {
+    use crate::*;
+
+    pub const LABEL_DrawInfo: TypedTag<*mut DrawInfo> = TypedTag::new(crate::LABEL_DrawInfo);
+    pub const LABEL_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::LABEL_Text);
+    pub const LABEL_Image: TypedTag<*mut Image> = TypedTag::new(crate::LABEL_Image);
+    pub const LABEL_Mapping: TypedTag<*mut UWORD> = TypedTag::new(crate::LABEL_Mapping);
+    pub const LABEL_Justification: TypedTag<UWORD> = TypedTag::new(crate::LABEL_Justification);
+    pub const LABEL_Key: TypedTag<UWORD> = TypedTag::new(crate::LABEL_Key);
+    pub const LABEL_VerticalSpacing: TypedTag<UWORD> = TypedTag::new(crate::LABEL_VerticalSpacing);
+    pub const LABEL_Underscore: TypedTag<UBYTE> = TypedTag::new(crate::LABEL_Underscore);
+    pub const LABEL_SoftStyle: TypedTag<UBYTE> = TypedTag::new(crate::LABEL_SoftStyle);
+    pub const LABEL_DisposeImage: TypedTag<BOOL> = TypedTag::new(crate::LABEL_DisposeImage);
+}
+
+/// Attributes of bitmap.image (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod bitmap_image {
+    use crate::*;
+
+    pub const BITMAP_SourceFile: TypedTag<CONST_STRPTR> = TypedTag::new(crate::BITMAP_SourceFile);
+    pub const BITMAP_SelectSourceFile: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::BITMAP_SelectSourceFile);
+    pub const BITMAP_DisabledSourceFile: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::BITMAP_DisabledSourceFile);
+    pub const BITMAP_Screen: TypedTag<*mut Screen> = TypedTag::new(crate::BITMAP_Screen);
+    pub const BITMAP_Precision: TypedTag<ULONG> = TypedTag::new(crate::BITMAP_Precision);
+    pub const BITMAP_Masking: TypedTag<BOOL> = TypedTag::new(crate::BITMAP_Masking);
+    pub const BITMAP_Transparent: TypedTag<BOOL> = TypedTag::new(crate::BITMAP_Transparent);
+    pub const BITMAP_BitMap: TypedTag<*mut BitMap> = TypedTag::new(crate::BITMAP_BitMap);
+    pub const BITMAP_SelectBitMap: TypedTag<*mut BitMap> =
+        TypedTag::new(crate::BITMAP_SelectBitMap);
+    pub const BITMAP_DisabledBitMap: TypedTag<*mut BitMap> =
+        TypedTag::new(crate::BITMAP_DisabledBitMap);
+    pub const BITMAP_Width: TypedTag<WORD> = TypedTag::new(crate::BITMAP_Width);
+    pub const BITMAP_Height: TypedTag<WORD> = TypedTag::new(crate::BITMAP_Height);
+    pub const BITMAP_SelectWidth: TypedTag<WORD> = TypedTag::new(crate::BITMAP_SelectWidth);
+    pub const BITMAP_SelectHeight: TypedTag<WORD> = TypedTag::new(crate::BITMAP_SelectHeight);
+    pub const BITMAP_DisabledWidth: TypedTag<WORD> = TypedTag::new(crate::BITMAP_DisabledWidth);
+    pub const BITMAP_DisabledHeight: TypedTag<WORD> = TypedTag::new(crate::BITMAP_DisabledHeight);
+    pub const BITMAP_OffsetX: TypedTag<WORD> = TypedTag::new(crate::BITMAP_OffsetX);
+    pub const BITMAP_OffsetY: TypedTag<WORD> = TypedTag::new(crate::BITMAP_OffsetY);
+    pub const BITMAP_SelectOffsetX: TypedTag<WORD> = TypedTag::new(crate::BITMAP_SelectOffsetX);
+    pub const BITMAP_SelectOffsetY: TypedTag<WORD> = TypedTag::new(crate::BITMAP_SelectOffsetY);
+    pub const BITMAP_DisabledOffsetX: TypedTag<WORD> = TypedTag::new(crate::BITMAP_DisabledOffsetX);
+    pub const BITMAP_DisabledOffsetY: TypedTag<WORD> = TypedTag::new(crate::BITMAP_DisabledOffsetY);
+    pub const BITMAP_MaskPlane: TypedTag<PLANEPTR> = TypedTag::new(crate::BITMAP_MaskPlane);
+    pub const BITMAP_SelectMaskPlane: TypedTag<PLANEPTR> =
+        TypedTag::new(crate::BITMAP_SelectMaskPlane);
+    pub const BITMAP_DisabledMaskPlane: TypedTag<PLANEPTR> =
+        TypedTag::new(crate::BITMAP_DisabledMaskPlane);
+}
+
+/// Attributes of listbrowser.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod listbrowser_gadget {
+    use crate::*;
+
+    pub const LISTBROWSER_Top: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_Top);
+    pub const LISTBROWSER_Selected: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_Selected);
+    pub const LISTBROWSER_MakeVisible: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_MakeVisible);
+    pub const LISTBROWSER_EditNode: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_EditNode);
+    pub const LISTBROWSER_NumSelected: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_NumSelected);
+    pub const LISTBROWSER_VPropTotal: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_VPropTotal);
+    pub const LISTBROWSER_VPropTop: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_VPropTop);
+    pub const LISTBROWSER_VPropVisible: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_VPropVisible);
+    pub const LISTBROWSER_HPropTotal: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_HPropTotal);
+    pub const LISTBROWSER_HPropTop: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_HPropTop);
+    pub const LISTBROWSER_HPropVisible: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_HPropVisible);
+    pub const LISTBROWSER_TotalNodes: TypedTag<LONG> = TypedTag::new(crate::LISTBROWSER_TotalNodes);
+    pub const LISTBROWSER_CursorSelect: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_CursorSelect);
+    pub const LISTBROWSER_TotalVisibleNodes: TypedTag<LONG> =
+        TypedTag::new(crate::LISTBROWSER_TotalVisibleNodes);
+    pub const LISTBROWSER_Labels: TypedTag<*mut List> = TypedTag::new(crate::LISTBROWSER_Labels);
+    pub const LISTBROWSER_SelectedNode: TypedTag<*mut Node> =
+        TypedTag::new(crate::LISTBROWSER_SelectedNode);
+    pub const LISTBROWSER_CursorNode: TypedTag<*mut Node> =
+        TypedTag::new(crate::LISTBROWSER_CursorNode);
+    pub const LISTBROWSER_MultiSelect: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_MultiSelect);
+    pub const LISTBROWSER_VertSeparators: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_VertSeparators);
+    pub const LISTBROWSER_Separators: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_Separators);
+    pub const LISTBROWSER_Borderless: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_Borderless);
+    pub const LISTBROWSER_VerticalProp: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_VerticalProp);
+    pub const LISTBROWSER_HorizontalProp: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_HorizontalProp);
+    pub const LISTBROWSER_AutoFit: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_AutoFit);
+    pub const LISTBROWSER_ColumnTitles: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_ColumnTitles);
+    pub const LISTBROWSER_ShowSelected: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_ShowSelected);
+    pub const LISTBROWSER_Hierarchical: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_Hierarchical);
+    pub const LISTBROWSER_ScrollRaster: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_ScrollRaster);
+    pub const LISTBROWSER_Editable: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_Editable);
+    pub const LISTBROWSER_HorizSeparators: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_HorizSeparators);
+    pub const LISTBROWSER_TitleClickable: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_TitleClickable);
+    pub const LISTBROWSER_PersistSelect: TypedTag<BOOL> =
+        TypedTag::new(crate::LISTBROWSER_PersistSelect);
+    pub const LISTBROWSER_FastRender: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_FastRender);
+    pub const LISTBROWSER_WrapText: TypedTag<BOOL> = TypedTag::new(crate::LISTBROWSER_WrapText);
+    #[cfg(not(feature = "kickstart-v44"))]
+    pub const LISTBROWSER_ColumnInfo: TypedTag<*mut ColumnInfo> =
+        TypedTag::new(crate::LISTBROWSER_ColumnInfo);
+    pub const LISTBROWSER_VirtualWidth: TypedTag<WORD> =
+        TypedTag::new(crate::LISTBROWSER_VirtualWidth);
+    pub const LISTBROWSER_Left: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_Left);
+    pub const LISTBROWSER_MouseX: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_MouseX);
+    pub const LISTBROWSER_MouseY: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_MouseY);
+    pub const LISTBROWSER_Spacing: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_Spacing);
+    pub const LISTBROWSER_EditColumn: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_EditColumn);
+    pub const LISTBROWSER_RelColumn: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_RelColumn);
+    pub const LISTBROWSER_MinNodeSize: TypedTag<WORD> =
+        TypedTag::new(crate::LISTBROWSER_MinNodeSize);
+    pub const LISTBROWSER_MinVisible: TypedTag<WORD> = TypedTag::new(crate::LISTBROWSER_MinVisible);
+    pub const LISTBROWSER_ShowImage: TypedTag<*mut Image> =
+        TypedTag::new(crate::LISTBROWSER_ShowImage);
+    pub const LISTBROWSER_HideImage: TypedTag<*mut Image> =
+        TypedTag::new(crate::LISTBROWSER_HideImage);
+    pub const LISTBROWSER_LeafImage: TypedTag<*mut Image> =
+        TypedTag::new(crate::LISTBROWSER_LeafImage);
+    pub const LISTBROWSER_CheckImage: TypedTag<*mut Image> =
+        TypedTag::new(crate::LISTBROWSER_CheckImage);
+    pub const LISTBROWSER_UncheckedImage: TypedTag<*mut Image> =
+        TypedTag::new(crate::LISTBROWSER_UncheckedImage);
+    pub const LISTBROWSER_EditTags: TypedTag<*mut TagItem> =
+        TypedTag::new(crate::LISTBROWSER_EditTags);
+    pub const LISTBROWSER_Position: TypedTag<ULONG> = TypedTag::new(crate::LISTBROWSER_Position);
+    pub const LISTBROWSER_RelEvent: TypedTag<ULONG> = TypedTag::new(crate::LISTBROWSER_RelEvent);
+    pub const LISTBROWSER_MemPool: TypedTag<APTR> = TypedTag::new(crate::LISTBROWSER_MemPool);
+}
+
+/// Tags of `AllocListBrowserNodeA()` and `SetListBrowserNodeAttrsA()` (listbrowser.gadget)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod listbrowser_node {
+    use crate::*;
+
+    pub const LBNA_Selected: TypedTag<BOOL> = TypedTag::new(crate::LBNA_Selected);
+    pub const LBNA_CheckBox: TypedTag<BOOL> = TypedTag::new(crate::LBNA_CheckBox);
+    pub const LBNA_Checked: TypedTag<BOOL> = TypedTag::new(crate::LBNA_Checked);
+    pub const LBNA_Flags: TypedTag<ULONG> = TypedTag::new(crate::LBNA_Flags);
+    pub const LBNA_NodeSize: TypedTag<ULONG> = TypedTag::new(crate::LBNA_NodeSize);
+    pub const LBNA_UserData: TypedTag<APTR> = TypedTag::new(crate::LBNA_UserData);
+    pub const LBNA_MemPool: TypedTag<APTR> = TypedTag::new(crate::LBNA_MemPool);
+    pub const LBNA_Column: TypedTag<WORD> = TypedTag::new(crate::LBNA_Column);
+    pub const LBNA_Generation: TypedTag<WORD> = TypedTag::new(crate::LBNA_Generation);
+    pub const LBNCA_Text: TypedTag<CONST_STRPTR> = TypedTag::new(crate::LBNCA_Text);
+    pub const LBNCA_Integer: TypedTag<*mut LONG> = TypedTag::new(crate::LBNCA_Integer);
+    pub const LBNCA_FGPen: TypedTag<WORD> = TypedTag::new(crate::LBNCA_FGPen);
+    pub const LBNCA_BGPen: TypedTag<WORD> = TypedTag::new(crate::LBNCA_BGPen);
+    pub const LBNCA_FillPen: TypedTag<WORD> = TypedTag::new(crate::LBNCA_FillPen);
+    pub const LBNCA_MaxChars: TypedTag<WORD> = TypedTag::new(crate::LBNCA_MaxChars);
+    pub const LBNCA_HookHeight: TypedTag<WORD> = TypedTag::new(crate::LBNCA_HookHeight);
+    pub const LBNCA_Image: TypedTag<*mut Image> = TypedTag::new(crate::LBNCA_Image);
+    pub const LBNCA_SelImage: TypedTag<*mut Image> = TypedTag::new(crate::LBNCA_SelImage);
+    pub const LBNCA_HorizJustify: TypedTag<ULONG> = TypedTag::new(crate::LBNCA_HorizJustify);
+    pub const LBNCA_Justification: TypedTag<ULONG> = TypedTag::new(crate::LBNCA_Justification);
+    pub const LBNCA_VertJustify: TypedTag<ULONG> = TypedTag::new(crate::LBNCA_VertJustify);
+    pub const LBNCA_Editable: TypedTag<BOOL> = TypedTag::new(crate::LBNCA_Editable);
+    pub const LBNCA_CopyText: TypedTag<BOOL> = TypedTag::new(crate::LBNCA_CopyText);
+    pub const LBNCA_CopyInteger: TypedTag<BOOL> = TypedTag::new(crate::LBNCA_CopyInteger);
+    pub const LBNCA_WordWrap: TypedTag<BOOL> = TypedTag::new(crate::LBNCA_WordWrap);
+    pub const LBNCA_EditTags: TypedTag<*mut TagItem> = TypedTag::new(crate::LBNCA_EditTags);
+    pub const LBNCA_RenderHook: TypedTag<*mut Hook> = TypedTag::new(crate::LBNCA_RenderHook);
+    pub const LBNCA_SoftStyle: TypedTag<UBYTE> = TypedTag::new(crate::LBNCA_SoftStyle);
+}
+
+/// Attributes of getfile.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod getfile_gadget {
+    use crate::*;
+
+    pub const GETFILE_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFILE_TitleText);
+    pub const GETFILE_File: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFILE_File);
+    pub const GETFILE_Drawer: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFILE_Drawer);
+    pub const GETFILE_FullFile: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFILE_FullFile);
+    pub const GETFILE_Pattern: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFILE_Pattern);
+    pub const GETFILE_LeftEdge: TypedTag<WORD> = TypedTag::new(crate::GETFILE_LeftEdge);
+    pub const GETFILE_TopEdge: TypedTag<WORD> = TypedTag::new(crate::GETFILE_TopEdge);
+    pub const GETFILE_Width: TypedTag<WORD> = TypedTag::new(crate::GETFILE_Width);
+    pub const GETFILE_Height: TypedTag<WORD> = TypedTag::new(crate::GETFILE_Height);
+    pub const GETFILE_FullFileExpand: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_FullFileExpand);
+    pub const GETFILE_DoSaveMode: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_DoSaveMode);
+    pub const GETFILE_DoMultiSelect: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_DoMultiSelect);
+    pub const GETFILE_DoPatterns: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_DoPatterns);
+    pub const GETFILE_DrawersOnly: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_DrawersOnly);
+    pub const GETFILE_RejectIcons: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_RejectIcons);
+    pub const GETFILE_FilterDrawers: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_FilterDrawers);
+    pub const GETFILE_LBNodeStructs: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_LBNodeStructs);
+    pub const GETFILE_ReadOnly: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_ReadOnly);
+    pub const GETFILE_FilePartOnly: TypedTag<BOOL> = TypedTag::new(crate::GETFILE_FilePartOnly);
+    pub const GETFILE_FilterFunc: TypedTag<*mut Hook> = TypedTag::new(crate::GETFILE_FilterFunc);
+    pub const GETFILE_RejectPattern: TypedTag<*mut UBYTE> =
+        TypedTag::new(crate::GETFILE_RejectPattern);
+    pub const GETFILE_AcceptPattern: TypedTag<*mut UBYTE> =
+        TypedTag::new(crate::GETFILE_AcceptPattern);
+}
+
+/// Attributes of getfont.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod getfont_gadget {
+    use crate::*;
+
+    pub const GETFONT_TextAttr: TypedTag<*mut TextAttr> = TypedTag::new(crate::GETFONT_TextAttr);
+    pub const GETFONT_DoFrontPen: TypedTag<BOOL> = TypedTag::new(crate::GETFONT_DoFrontPen);
+    pub const GETFONT_DoBackPen: TypedTag<BOOL> = TypedTag::new(crate::GETFONT_DoBackPen);
+    pub const GETFONT_DoStyle: TypedTag<BOOL> = TypedTag::new(crate::GETFONT_DoStyle);
+    pub const GETFONT_DoDrawMode: TypedTag<BOOL> = TypedTag::new(crate::GETFONT_DoDrawMode);
+    pub const GETFONT_FixedWidthOnly: TypedTag<BOOL> = TypedTag::new(crate::GETFONT_FixedWidthOnly);
+    pub const GETFONT_MinHeight: TypedTag<UWORD> = TypedTag::new(crate::GETFONT_MinHeight);
+    pub const GETFONT_MaxHeight: TypedTag<UWORD> = TypedTag::new(crate::GETFONT_MaxHeight);
+    pub const GETFONT_MaxFrontPen: TypedTag<UWORD> = TypedTag::new(crate::GETFONT_MaxFrontPen);
+    pub const GETFONT_MaxBackPen: TypedTag<UWORD> = TypedTag::new(crate::GETFONT_MaxBackPen);
+    pub const GETFONT_TitleText: TypedTag<CONST_STRPTR> = TypedTag::new(crate::GETFONT_TitleText);
+    pub const GETFONT_Height: TypedTag<WORD> = TypedTag::new(crate::GETFONT_Height);
+    pub const GETFONT_Width: TypedTag<WORD> = TypedTag::new(crate::GETFONT_Width);
+    pub const GETFONT_LeftEdge: TypedTag<WORD> = TypedTag::new(crate::GETFONT_LeftEdge);
+    pub const GETFONT_TopEdge: TypedTag<WORD> = TypedTag::new(crate::GETFONT_TopEdge);
+    pub const GETFONT_FrontPen: TypedTag<UBYTE> = TypedTag::new(crate::GETFONT_FrontPen);
+    pub const GETFONT_BackPen: TypedTag<UBYTE> = TypedTag::new(crate::GETFONT_BackPen);
+    pub const GETFONT_DrawMode: TypedTag<UBYTE> = TypedTag::new(crate::GETFONT_DrawMode);
+    pub const GETFONT_ModeList: TypedTag<*mut CONST_STRPTR> =
+        TypedTag::new(crate::GETFONT_ModeList);
+    pub const GETFONT_FrontPens: TypedTag<*mut UBYTE> = TypedTag::new(crate::GETFONT_FrontPens);
+    pub const GETFONT_BackPens: TypedTag<*mut UBYTE> = TypedTag::new(crate::GETFONT_BackPens);
+}
+
+/// Attributes of getscreenmode.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod getscreenmode_gadget {
+    use crate::*;
+
+    pub const GETSCREENMODE_TitleText: TypedTag<CONST_STRPTR> =
+        TypedTag::new(crate::GETSCREENMODE_TitleText);
+    pub const GETSCREENMODE_Height: TypedTag<WORD> = TypedTag::new(crate::GETSCREENMODE_Height);
+    pub const GETSCREENMODE_Width: TypedTag<WORD> = TypedTag::new(crate::GETSCREENMODE_Width);
+    pub const GETSCREENMODE_LeftEdge: TypedTag<WORD> = TypedTag::new(crate::GETSCREENMODE_LeftEdge);
+    pub const GETSCREENMODE_TopEdge: TypedTag<WORD> = TypedTag::new(crate::GETSCREENMODE_TopEdge);
+    pub const GETSCREENMODE_InfoLeftEdge: TypedTag<WORD> =
+        TypedTag::new(crate::GETSCREENMODE_InfoLeftEdge);
+    pub const GETSCREENMODE_InfoTopEdge: TypedTag<WORD> =
+        TypedTag::new(crate::GETSCREENMODE_InfoTopEdge);
+    pub const GETSCREENMODE_DisplayID: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_DisplayID);
+    pub const GETSCREENMODE_DisplayWidth: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_DisplayWidth);
+    pub const GETSCREENMODE_DisplayHeight: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_DisplayHeight);
+    pub const GETSCREENMODE_DisplayDepth: TypedTag<UWORD> =
+        TypedTag::new(crate::GETSCREENMODE_DisplayDepth);
+    pub const GETSCREENMODE_OverscanType: TypedTag<UWORD> =
+        TypedTag::new(crate::GETSCREENMODE_OverscanType);
+    pub const GETSCREENMODE_MinDepth: TypedTag<UWORD> =
+        TypedTag::new(crate::GETSCREENMODE_MinDepth);
+    pub const GETSCREENMODE_MaxDepth: TypedTag<UWORD> =
+        TypedTag::new(crate::GETSCREENMODE_MaxDepth);
+    pub const GETSCREENMODE_AutoScroll: TypedTag<BOOL> =
+        TypedTag::new(crate::GETSCREENMODE_AutoScroll);
+    pub const GETSCREENMODE_InfoOpened: TypedTag<BOOL> =
+        TypedTag::new(crate::GETSCREENMODE_InfoOpened);
+    pub const GETSCREENMODE_DoWidth: TypedTag<BOOL> = TypedTag::new(crate::GETSCREENMODE_DoWidth);
+    pub const GETSCREENMODE_DoHeight: TypedTag<BOOL> = TypedTag::new(crate::GETSCREENMODE_DoHeight);
+    pub const GETSCREENMODE_DoDepth: TypedTag<BOOL> = TypedTag::new(crate::GETSCREENMODE_DoDepth);
+    pub const GETSCREENMODE_DoOverscanType: TypedTag<BOOL> =
+        TypedTag::new(crate::GETSCREENMODE_DoOverscanType);
+    pub const GETSCREENMODE_DoAutoScroll: TypedTag<BOOL> =
+        TypedTag::new(crate::GETSCREENMODE_DoAutoScroll);
+    pub const GETSCREENMODE_PropertyFlags: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_PropertyFlags);
+    pub const GETSCREENMODE_PropertyMask: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_PropertyMask);
+    pub const GETSCREENMODE_MinWidth: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_MinWidth);
+    pub const GETSCREENMODE_MaxWidth: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_MaxWidth);
+    pub const GETSCREENMODE_MinHeight: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_MinHeight);
+    pub const GETSCREENMODE_MaxHeight: TypedTag<ULONG> =
+        TypedTag::new(crate::GETSCREENMODE_MaxHeight);
+    pub const GETSCREENMODE_FilterFunc: TypedTag<*mut Hook> =
+        TypedTag::new(crate::GETSCREENMODE_FilterFunc);
+    pub const GETSCREENMODE_CustomSMList: TypedTag<*mut List> =
+        TypedTag::new(crate::GETSCREENMODE_CustomSMList);
+}
+
+/// Attributes of radiobutton.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod radiobutton_gadget {
+    use crate::*;
+
+    pub const RADIOBUTTON_Labels: TypedTag<*mut List> = TypedTag::new(crate::RADIOBUTTON_Labels);
+    pub const RADIOBUTTON_Strings: TypedTag<*mut CONST_STRPTR> =
+        TypedTag::new(crate::RADIOBUTTON_Strings);
+    pub const RADIOBUTTON_Spacing: TypedTag<WORD> = TypedTag::new(crate::RADIOBUTTON_Spacing);
+    pub const RADIOBUTTON_Selected: TypedTag<WORD> = TypedTag::new(crate::RADIOBUTTON_Selected);
+    pub const RADIOBUTTON_LabelPlace: TypedTag<ULONG> =
+        TypedTag::new(crate::RADIOBUTTON_LabelPlace);
+}
+
+/// Attributes of palette.gadget (ReAction)
+#[cfg(not(feature = "kickstart-v34"))]
+pub mod palette_gadget {
+    use crate::*;
+
+    pub const PALETTE_Colour: TypedTag<UBYTE> = TypedTag::new(crate::PALETTE_Colour);
+    pub const PALETTE_ColourOffset: TypedTag<UBYTE> = TypedTag::new(crate::PALETTE_ColourOffset);
+    pub const PALETTE_ColourTable: TypedTag<*mut UBYTE> = TypedTag::new(crate::PALETTE_ColourTable);
+    pub const PALETTE_NumColours: TypedTag<UWORD> = TypedTag::new(crate::PALETTE_NumColours);
+}